@@ -12,6 +12,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
 use tracing::{debug, warn};
 use trader_core::{
     domain::{
@@ -92,6 +93,40 @@ impl AnalyticsProviderImpl {
             })
     }
 
+    /// 기준 시점까지의 캔들 데이터 조회.
+    ///
+    /// `as_of`가 None이면 최신 캔들을, Some이면 그 시점 이전에 마감된 캔들 중
+    /// 최근 `limit`개를 반환합니다 (백테스트 Look-Ahead Bias 방지).
+    async fn get_candles_as_of(
+        &self,
+        ticker: &str,
+        limit: usize,
+        as_of: Option<DateTime<Utc>>,
+    ) -> Result<Vec<trader_core::Kline>, AnalyticsError> {
+        let Some(as_of) = as_of else {
+            return self.get_candles(ticker, limit).await;
+        };
+
+        // 주말/휴장일을 고려해 캔들 수의 2배 기간을 조회
+        let span = Duration::seconds((self.default_timeframe.as_secs() * limit as u64 * 2) as i64);
+        let mut candles = self
+            .data_provider
+            .get_klines_range(
+                ticker,
+                self.default_timeframe,
+                (as_of - span).date_naive(),
+                as_of.date_naive(),
+            )
+            .await
+            .map_err(|e| {
+                AnalyticsError::DataFetch(format!("Failed to fetch candles for {}: {}", ticker, e))
+            })?;
+
+        candles.retain(|k| k.close_time <= as_of);
+        let skip = candles.len().saturating_sub(limit);
+        Ok(candles.split_off(skip))
+    }
+
     /// 다중 타임프레임 캔들 데이터 로드 (Phase 1.4.2).
     ///
     /// 지정된 타임프레임들의 캔들 데이터를 병렬로 로드합니다.
@@ -221,10 +256,11 @@ impl AnalyticsProvider for AnalyticsProviderImpl {
     async fn fetch_global_scores(
         &self,
         market_type: MarketType,
+        as_of: Option<DateTime<Utc>>,
     ) -> Result<Vec<GlobalScoreResult>, AnalyticsError> {
-        // TODO: Phase 1.2에서 구현
+        // TODO: Phase 1.2에서 구현 (as_of 시점의 캔들/펀더멘털 이력 기반)
         // 현재는 빈 결과 반환
-        debug!(market_type = ?market_type, "fetch_global_scores called (not yet implemented)");
+        debug!(market_type = ?market_type, as_of = ?as_of, "fetch_global_scores called (not yet implemented)");
         Ok(Vec::new())
     }
 
@@ -266,11 +302,12 @@ impl AnalyticsProvider for AnalyticsProviderImpl {
     async fn fetch_features(
         &self,
         tickers: &[&str],
+        as_of: Option<DateTime<Utc>>,
     ) -> Result<HashMap<String, StructuralFeatures>, AnalyticsError> {
         let mut results = HashMap::new();
 
         for ticker in tickers {
-            match self.get_candles(ticker, 60).await {
+            match self.get_candles_as_of(ticker, 60, as_of).await {
                 Ok(candles) => {
                     // IndicatorEngine 기반 StructuralFeatures 계산
                     let indicator_engine = IndicatorEngine::new();
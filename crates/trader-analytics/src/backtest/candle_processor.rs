@@ -7,7 +7,7 @@
 //! 두 엔진의 유일한 차이는 캔들이 한꺼번에(backtest) vs 스트리밍(simulation)으로 제공되는 것입니다.
 //! CandleProcessor는 단일 캔들 시점의 처리 로직을 공통화하여:
 //! - StrategyContext 업데이트 (지표, 스크리닝)
-//! - 시그널 생성 (멀티 심볼/멀티 타임프레임)
+//! - 시그널 생성 (멀티 심볼/멀티 타임프레임, 일정 이벤트)
 //! - 포지션 동기화
 //!
 //! 를 한 곳에서 관리합니다. 이를 통해 StrategyContext 관련 수정 시 한 곳만 변경하면 됩니다.
@@ -42,10 +42,11 @@ use rust_decimal::Decimal;
 use tokio::sync::RwLock;
 use tracing::debug;
 use trader_core::{
-    unrealized_pnl, Kline, MarketData, MarketType, Position, RouteState, ScreeningCalculator, Side,
-    Signal, SignalType, StrategyContext, Timeframe,
+    unrealized_pnl, DecisionTrace, Kline, MarketData, MarketType, Position, RouteState,
+    ScreeningCalculator, Side, Signal, SignalType, StrategyContext, Timeframe,
 };
 use trader_execution::ProcessorPosition;
+use trader_strategy::{link_signal_traces, ScheduleClock, ScheduleEvent, TradingCalendars};
 use uuid::Uuid;
 
 use super::BacktestError;
@@ -68,6 +69,10 @@ pub struct CandleProcessor {
     current_prices: HashMap<String, Decimal>,
     /// 현재 처리 중인 시간
     current_time: DateTime<Utc>,
+    /// 일정 이벤트용 거래 캘린더
+    calendars: TradingCalendars,
+    /// 일정 이벤트 커서 (캔들 시각 기준)
+    schedule_clock: ScheduleClock,
 }
 
 /// 시그널 생성 결과
@@ -78,6 +83,8 @@ pub struct PartitionedSignals {
     pub entry_signals: Vec<Signal>,
     /// 청산 시그널 (Exit, ReducePosition)
     pub exit_signals: Vec<Signal>,
+    /// 이번 캔들의 결정 추적 (발생 신호마다 하나 이상 포함)
+    pub decision_traces: Vec<DecisionTrace>,
 }
 
 impl PartitionedSignals {
@@ -121,9 +128,17 @@ impl CandleProcessor {
             indicator_engine: IndicatorEngine::new(),
             current_prices: HashMap::new(),
             current_time: Utc::now(),
+            calendars: TradingCalendars::new(),
+            schedule_clock: ScheduleClock::new(),
         }
     }
 
+    /// 일정 이벤트용 거래 캘린더 설정 (미설정 국가는 휴장일 없는 기본 캘린더)
+    pub fn with_calendars(mut self, calendars: TradingCalendars) -> Self {
+        self.calendars = calendars;
+        self
+    }
+
     /// 현재 심볼별 가격 맵 참조
     pub fn current_prices(&self) -> &HashMap<String, Decimal> {
         &self.current_prices
@@ -187,6 +202,8 @@ impl CandleProcessor {
     ///
     /// 주 심볼과 다른 심볼들에 대해 MarketData를 전달하고,
     /// 멀티 타임프레임 전략이면 on_multi_timeframe_data를 호출합니다.
+    /// 일정을 선언한 전략이면 캔들 시작 시각까지 발생한 이벤트를 캔들 전달 전에,
+    /// 캔들 구간 중 발생한 이벤트를 전달 후에 `on_schedule`로 전달합니다.
     /// 결과는 Entry/Exit로 파티셔닝되어 반환됩니다.
     ///
     /// # 인자
@@ -197,7 +214,7 @@ impl CandleProcessor {
     /// * `ticker` - 메인 티커
     /// * `exchange_name` - 거래소 이름
     pub async fn generate_signals<S>(
-        &self,
+        &mut self,
         strategy: &mut S,
         kline: &Kline,
         context: &Arc<RwLock<StrategyContext>>,
@@ -209,6 +226,16 @@ impl CandleProcessor {
     {
         let mut all_signals = Vec::new();
 
+        // 0. 일정 이벤트 (실거래 타이머와 같은 캘린더 규칙)
+        let (events_before, events_during) = match strategy.schedule() {
+            Some(schedule) => {
+                let calendar = self.calendars.get(schedule.country);
+                self.schedule_clock.advance_bar(&schedule, &calendar, kline)
+            }
+            None => Default::default(),
+        };
+        all_signals.extend(Self::dispatch_schedule(strategy, &events_before).await?);
+
         // 1. 주 심볼의 시장 데이터 전달
         let market_data = MarketData::from_kline(exchange_name, kline.clone());
 
@@ -273,6 +300,11 @@ impl CandleProcessor {
             all_signals.extend(symbol_signals);
         }
 
+        // 3. 캔들 구간 중 발생한 일정 이벤트
+        all_signals.extend(Self::dispatch_schedule(strategy, &events_during).await?);
+
+        let decision_traces = link_signal_traces(&all_signals, strategy.take_decision_traces());
+
         // Entry/Exit 파티셔닝
         let (entry_signals, exit_signals): (Vec<_>, Vec<_>) =
             all_signals.into_iter().partition(|s| {
@@ -285,6 +317,7 @@ impl CandleProcessor {
         Ok(PartitionedSignals {
             entry_signals,
             exit_signals,
+            decision_traces,
         })
     }
 
@@ -409,6 +442,25 @@ impl CandleProcessor {
     // 내부 헬퍼 메서드
     // =========================================================================
 
+    /// 일정 이벤트를 순서대로 전략에 전달
+    async fn dispatch_schedule<S>(
+        strategy: &mut S,
+        events: &[ScheduleEvent],
+    ) -> Result<Vec<Signal>, BacktestError>
+    where
+        S: trader_strategy::Strategy + ?Sized,
+    {
+        let mut signals = Vec::new();
+        for event in events {
+            let event_signals = strategy
+                .on_schedule(event)
+                .await
+                .map_err(|e| BacktestError::StrategyError(e.to_string()))?;
+            signals.extend(event_signals);
+        }
+        Ok(signals)
+    }
+
     /// 멀티 심볼 klines 업데이트 (현재 시점까지 필터링)
     async fn update_multi_symbol_klines(
         &mut self,
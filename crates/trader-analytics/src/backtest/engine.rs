@@ -8,6 +8,9 @@
 //! - **주문 체결 시뮬레이션**: 슬리피지, 수수료 등 현실적인 체결 모델
 //! - **성과 분석**: PerformanceTracker와 통합된 상세한 성과 지표
 //! - **자산 곡선**: 시간에 따른 자산 가치 변화 추적
+//! - **합성 옵션 가격**: 옵션 신호는 기초자산 가격과 내재변동성 입력으로 가격 결정 및 만기 정산
+//! - **이벤트 블랙아웃**: 과거 경제 이벤트(FOMC, CPI, 옵션 만기 등) 전후 진입 차단 또는 축소
+//! - **기준 통화 환산**: 과거 환율로 자산 곡선을 기준 통화로 환산하고 환차손익을 분리
 //!
 //! # 사용 예시
 //!
@@ -41,14 +44,21 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tokio::sync::RwLock;
 use trader_core::{
-    unrealized_pnl, Kline, MarketData, ScreeningCalculator, Side, Signal, SignalMarker, SignalType,
-    StrategyContext, Trade,
+    normalize_currency, ticker_currency, unrealized_pnl, BlackoutAction, DecisionTrace,
+    EconomicCalendar, EventBlackoutConfig, FxPnl, FxRateTable, Kline, MarketData, OptionContract,
+    ScreeningCalculator, Side, Signal, SignalMarker, SignalType, StrategyContext, Trade,
+    TradingCalendar,
 };
-use trader_execution::{ProcessorConfig, SignalProcessor, SimulatedExecutor, TradeResult};
+use trader_data::{BacktestEquityRow, BacktestTradeRow, OhlcvQualityReport};
+use trader_execution::{
+    signal_position_size, ProcessorConfig, SignalProcessor, SimulatedExecutor, TradeResult,
+};
+use trader_strategy::{link_signal_traces, TradingCalendars};
 use uuid::Uuid;
 
 use crate::{
     backtest::{candle_processor::CandleProcessor, slippage::SlippageModel},
+    options::SyntheticOptionPricing,
     performance::{EquityPoint, PerformanceMetrics, PerformanceTracker, RoundTrip},
 };
 
@@ -154,6 +164,22 @@ pub struct BacktestConfig {
     /// 최소 신호 강도 (기본값: 0.0 = 모든 신호 허용)
     #[serde(default)]
     pub min_strength: f64,
+
+    /// 옵션 신호용 합성 가격 설정 (내재변동성 입력)
+    #[serde(default)]
+    pub option_pricing: SyntheticOptionPricing,
+
+    /// 경제 이벤트 블랙아웃 (None이면 비활성화)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_blackout: Option<EventBlackoutConfig>,
+
+    /// 계좌 통화 (None이면 주 심볼 티커로 추론, 예: "BTC/USDT" → USDT)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+
+    /// 기준 통화 (None이면 환산 리포트 비활성화)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_currency: Option<String>,
 }
 
 // 설정 기본값 함수들 (serde default용)
@@ -204,6 +230,10 @@ impl Default for BacktestConfig {
             stop_loss_pct: default_stop_loss_pct(),
             take_profit_pct: default_take_profit_pct(),
             min_strength: 0.0,
+            option_pricing: SyntheticOptionPricing::default(),
+            event_blackout: None,
+            currency: None,
+            base_currency: None,
         }
     }
 }
@@ -300,6 +330,30 @@ impl BacktestConfig {
         self
     }
 
+    /// 합성 옵션 가격 설정
+    pub fn with_option_pricing(mut self, pricing: SyntheticOptionPricing) -> Self {
+        self.option_pricing = pricing;
+        self
+    }
+
+    /// 경제 이벤트 블랙아웃 설정
+    pub fn with_event_blackout(mut self, blackout: EventBlackoutConfig) -> Self {
+        self.event_blackout = Some(blackout);
+        self
+    }
+
+    /// 계좌 통화 설정
+    pub fn with_currency(mut self, currency: &str) -> Self {
+        self.currency = Some(normalize_currency(currency));
+        self
+    }
+
+    /// 기준 통화 설정 (환율 테이블은 [`BacktestEngine::with_fx_rates`]로 전달)
+    pub fn with_base_currency(mut self, currency: &str) -> Self {
+        self.base_currency = Some(normalize_currency(currency));
+        self
+    }
+
     /// 설정 검증
     pub fn validate(&self) -> BacktestResult<()> {
         if self.initial_capital <= Decimal::ZERO {
@@ -317,6 +371,15 @@ impl BacktestConfig {
                 "슬리피지율은 0 이상이어야 합니다".to_string(),
             ));
         }
+        if let Some(BlackoutAction::ReduceSize { factor }) =
+            self.event_blackout.as_ref().map(|b| &b.action)
+        {
+            if *factor <= Decimal::ZERO || *factor > Decimal::ONE {
+                return Err(BacktestError::ConfigError(
+                    "이벤트 블랙아웃 축소 비율은 0 초과 1 이하여야 합니다".to_string(),
+                ));
+            }
+        }
         Ok(())
     }
 }
@@ -357,9 +420,13 @@ pub struct BacktestReport {
     /// 심볼별 성과
     pub performance_by_symbol: HashMap<String, PerformanceMetrics>,
 
-    /// 신호 마커 (차트 표시 및 분석용)
+    /// 신호 마커 (차트 표시 및 분석용, 결정 추적 포함)
     pub signal_markers: Vec<SignalMarker>,
 
+    /// 신호로 이어지지 않은 결정 추적 (발생 신호의 추적은 `signal_markers`에 포함)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decision_traces: Vec<DecisionTrace>,
+
     /// 캔들 데이터 (차트 표시용)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub klines: Vec<Kline>,
@@ -371,6 +438,115 @@ pub struct BacktestReport {
     /// 모든 거래 기록 (매수/매도 포함) - 매매일지용
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub all_trades: Vec<TradeResult>,
+
+    /// 입력 데이터 품질 경고 (품질 점수가 기준 미만인 시계열)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub data_quality_warnings: Vec<String>,
+
+    /// 기준 통화 환산 결과 (기준 통화 미설정 시 None)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fx: Option<BacktestFxReport>,
+}
+
+/// 백테스트 기준 통화 환산 리포트.
+///
+/// 계좌 통화 손익을 기준 통화로 환산하고, 자산 손익과 환차손익으로 분리합니다.
+/// 초기 자본 전체를 기간 동안 계좌 통화로 보유한 것으로 보고
+/// 환차손익 = 초기 자본 × (종료 환율 - 시작 환율)로 계산합니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestFxReport {
+    /// 계좌 통화
+    pub currency: String,
+    /// 기준 통화
+    pub base_currency: String,
+    /// 시작 환율 (1 계좌 통화 = N 기준 통화)
+    pub start_rate: Decimal,
+    /// 종료 환율
+    pub end_rate: Decimal,
+    /// 초기 자본 (기준 통화)
+    pub initial_capital_base: Decimal,
+    /// 최종 자산 (기준 통화)
+    pub final_equity_base: Decimal,
+    /// 손익 분해 (자산 손익 / 환차손익)
+    pub pnl: FxPnl,
+    /// 기준 통화 수익률 (%)
+    pub total_return_pct_base: Decimal,
+    /// 기준 통화 자산 곡선 (환율이 없는 시점 제외)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub equity_curve: Vec<EquityPoint>,
+}
+
+impl BacktestFxReport {
+    /// 자산 곡선과 환율 테이블로 환산 리포트를 계산합니다.
+    ///
+    /// 시작 또는 종료 시점 환율이 없으면 [`BacktestError::DataError`]를 반환합니다.
+    pub fn calculate(
+        rates: &FxRateTable,
+        currency: &str,
+        base_currency: &str,
+        initial_capital: Decimal,
+        equity_curve: &[EquityPoint],
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> BacktestResult<Self> {
+        let require = |time: DateTime<Utc>| {
+            rates
+                .require_rate(currency, base_currency, time.date_naive())
+                .map_err(|e| BacktestError::DataError(e.to_string()))
+        };
+        let start_rate = require(start_time)?;
+        let end_rate = require(end_time)?;
+
+        let final_equity = equity_curve
+            .last()
+            .map(|point| point.equity)
+            .unwrap_or(initial_capital);
+        let pnl = FxPnl::decompose(
+            initial_capital,
+            final_equity - initial_capital,
+            start_rate,
+            end_rate,
+        );
+        let initial_capital_base = initial_capital * start_rate;
+        let final_equity_base = final_equity * end_rate;
+        let total_return_pct_base = if initial_capital_base.is_zero() {
+            Decimal::ZERO
+        } else {
+            (final_equity_base - initial_capital_base) / initial_capital_base * Decimal::ONE_HUNDRED
+        };
+
+        let mut peak = Decimal::ZERO;
+        let equity_curve = equity_curve
+            .iter()
+            .filter_map(|point| {
+                let rate = rates.rate_on(currency, base_currency, point.timestamp.date_naive())?;
+                let equity = point.equity * rate;
+                peak = peak.max(equity);
+                let drawdown_pct = if peak.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    (peak - equity) / peak * Decimal::ONE_HUNDRED
+                };
+                Some(EquityPoint {
+                    timestamp: point.timestamp,
+                    equity,
+                    drawdown_pct,
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            currency: normalize_currency(currency),
+            base_currency: normalize_currency(base_currency),
+            start_rate,
+            end_rate,
+            initial_capital_base,
+            final_equity_base,
+            pnl,
+            total_return_pct_base,
+            equity_curve,
+        })
+    }
 }
 
 impl BacktestReport {
@@ -378,7 +554,7 @@ impl BacktestReport {
     pub fn summary(&self) -> String {
         let duration_days = (self.end_time - self.start_time).num_days();
 
-        format!(
+        let mut summary = format!(
             "백테스트 결과 요약\n\
              ═══════════════════════════════════════\n\
              기간: {} → {} ({} 일)\n\
@@ -420,10 +596,71 @@ impl BacktestReport {
             self.metrics.calmar_ratio,
             self.total_commission,
             self.total_slippage,
-        )
+        );
+
+        if let Some(fx) = &self.fx {
+            summary.push_str(&format!(
+                "\n기준 통화 환산 ({} → {}, 환율 {} → {}): 최종 자산 {:.2}, 자산 손익 {:.2}, 환차손익 {:.2}",
+                fx.currency,
+                fx.base_currency,
+                fx.start_rate,
+                fx.end_rate,
+                fx.final_equity_base,
+                fx.pnl.asset_pnl,
+                fx.pnl.fx_pnl,
+            ));
+        }
+        for warning in &self.data_quality_warnings {
+            summary.push_str("\n⚠ 데이터 품질: ");
+            summary.push_str(warning);
+        }
+        summary
+    }
+
+    /// 라운드트립 거래를 Parquet 내보내기용 행으로 변환.
+    pub fn trade_rows(&self, run_id: &str) -> Vec<BacktestTradeRow> {
+        self.trades
+            .iter()
+            .map(|trade| BacktestTradeRow {
+                run_id: run_id.to_string(),
+                symbol: trade.symbol.clone(),
+                side: trade.side,
+                entry_time: trade.entry_time,
+                exit_time: trade.exit_time,
+                entry_price: trade.entry_price,
+                exit_price: trade.exit_price,
+                quantity: trade.quantity,
+                fees: trade.fees,
+                pnl: trade.pnl,
+                return_pct: trade.return_pct,
+                strategy_id: trade.strategy_id.clone(),
+            })
+            .collect()
+    }
+
+    /// 자산 곡선을 Parquet 내보내기용 행으로 변환 (주 심볼 파티션).
+    pub fn equity_rows(&self, run_id: &str) -> Vec<BacktestEquityRow> {
+        self.equity_curve
+            .iter()
+            .map(|point| BacktestEquityRow {
+                run_id: run_id.to_string(),
+                symbol: self.symbol.clone(),
+                timestamp: point.timestamp,
+                equity: point.equity,
+                drawdown_pct: point.drawdown_pct,
+            })
+            .collect()
     }
 }
 
+/// 경제 이벤트 블랙아웃 판정 결과.
+enum BlackoutDecision {
+    /// 진입 차단
+    Block,
+    /// 수량을 축소한 신호로 진입
+    Reduce(Signal),
+}
+
 /// 백테스팅 엔진
 ///
 /// 과거 데이터로 전략을 시뮬레이션하고 성과를 분석합니다.
@@ -458,8 +695,29 @@ pub struct BacktestEngine {
     /// 신호 마커 (차트 표시 및 분석용)
     signal_markers: Vec<SignalMarker>,
 
+    /// 신호 처리 대기 중인 결정 추적 (signal_id -> 추적)
+    pending_traces: HashMap<Uuid, DecisionTrace>,
+
+    /// 신호로 이어지지 않은 결정 추적
+    decision_traces: Vec<DecisionTrace>,
+
     /// 총 슬리피지 (executor와 별도 추적 - 기존 호환성)
     total_slippage: Decimal,
+
+    /// 일정 이벤트용 거래 캘린더 (휴장일 반영)
+    calendars: TradingCalendars,
+
+    /// 신호로 확인된 옵션 계약 (옵션 티커 -> 계약)
+    option_contracts: HashMap<String, OptionContract>,
+
+    /// 입력 캔들의 데이터 품질 리포트 (수집기 품질 검사 결과)
+    data_quality: Vec<OhlcvQualityReport>,
+
+    /// 과거 경제 이벤트 캘린더 (이벤트 블랙아웃 및 전략 컨텍스트용)
+    economic_calendar: EconomicCalendar,
+
+    /// 과거 환율 테이블 (기준 통화 환산용)
+    fx_rates: FxRateTable,
 }
 
 impl BacktestEngine {
@@ -492,10 +750,101 @@ impl BacktestEngine {
             current_time: Utc::now(),
             current_prices: HashMap::new(),
             signal_markers: Vec::new(),
+            pending_traces: HashMap::new(),
+            decision_traces: Vec::new(),
             total_slippage: Decimal::ZERO,
+            calendars: TradingCalendars::new(),
+            option_contracts: HashMap::new(),
+            data_quality: Vec::new(),
+            economic_calendar: EconomicCalendar::new(),
+            fx_rates: FxRateTable::new().with_stablecoin_pegs(),
+        }
+    }
+
+    /// 일정 이벤트용 거래 캘린더를 설정합니다 (같은 국가는 교체).
+    ///
+    /// 휴장일을 반영하면 `on_schedule` 이벤트가 실거래와 같은 거래일에 발생합니다.
+    pub fn with_calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendars.insert(calendar);
+        self
+    }
+
+    /// 과거 경제 이벤트 캘린더를 설정합니다.
+    ///
+    /// 실행 시 StrategyContext에 전달되며, [`BacktestConfig::event_blackout`]이 설정되면
+    /// 이벤트 전후 구간의 신규 진입을 차단하거나 축소합니다.
+    pub fn with_economic_calendar(mut self, calendar: EconomicCalendar) -> Self {
+        self.economic_calendar = calendar;
+        self
+    }
+
+    /// 과거 환율 테이블을 설정합니다.
+    ///
+    /// [`BacktestConfig::base_currency`]가 설정되면 리포트의 자산 곡선과 손익을
+    /// 기준 통화로 환산하고 환차손익을 분리합니다 ([`BacktestReport::fx`]).
+    pub fn with_fx_rates(mut self, rates: FxRateTable) -> Self {
+        self.fx_rates.merge(&rates);
+        self
+    }
+
+    /// 입력 데이터의 품질 리포트를 설정합니다.
+    ///
+    /// 품질 점수가 기준 미만인 리포트는 실행 시 경고 로그를 남기고
+    /// [`BacktestReport::data_quality_warnings`]에 기록됩니다.
+    pub fn with_data_quality_reports(
+        mut self,
+        reports: impl IntoIterator<Item = OhlcvQualityReport>,
+    ) -> Self {
+        self.data_quality = reports.into_iter().collect();
+        self
+    }
+
+    /// 기준 통화 환산 리포트.
+    ///
+    /// 기준 통화 미설정 시 None이며, 환율이 없으면 경고 로그를 남기고 환산을 생략합니다.
+    fn fx_report(
+        &self,
+        symbol: &str,
+        equity_curve: &[EquityPoint],
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Option<BacktestFxReport> {
+        let base_currency = self.config.base_currency.as_deref()?;
+        let currency = self
+            .config
+            .currency
+            .clone()
+            .unwrap_or_else(|| ticker_currency(symbol));
+        match BacktestFxReport::calculate(
+            &self.fx_rates,
+            &currency,
+            base_currency,
+            self.config.initial_capital,
+            equity_curve,
+            start_time,
+            end_time,
+        ) {
+            Ok(report) => Some(report),
+            Err(e) => {
+                tracing::warn!("기준 통화 환산 생략: {}", e);
+                None
+            }
         }
     }
 
+    /// 품질 저하 리포트의 경고 메시지 (경고 로그 출력 포함).
+    fn data_quality_warnings(&self) -> Vec<String> {
+        self.data_quality
+            .iter()
+            .filter(|report| report.is_degraded())
+            .map(|report| {
+                let message = report.warning_message();
+                tracing::warn!("백테스트 입력 데이터 품질 저하: {}", message);
+                message
+            })
+            .collect()
+    }
+
     // === 위임 메서드 (기존 API 호환성 유지) ===
 
     /// 현재 잔고 조회 (executor에서 위임)
@@ -569,12 +918,21 @@ impl BacktestEngine {
         let start_time = klines.first().unwrap().open_time;
         let end_time = klines.last().unwrap().close_time;
         let data_points = klines.len();
+        let data_quality_warnings = self.data_quality_warnings();
 
         // 백테스트 시작 시간으로 equity curve 초기 timestamp 설정
         self.tracker.set_initial_timestamp(start_time);
 
+        // 경제 이벤트 캘린더 전달 (전략의 이벤트 일정 조회용)
+        if !self.economic_calendar.is_empty() {
+            context
+                .write()
+                .await
+                .update_economic_calendar(self.economic_calendar.clone());
+        }
+
         // 공통 캔들 프로세서 (SimulationEngine과 동일한 로직 공유)
-        let mut candle_processor = CandleProcessor::new();
+        let mut candle_processor = CandleProcessor::new().with_calendars(self.calendars.clone());
         let exchange_name = self.config.exchange_name.clone();
 
         // 각 캔들에 대해 시뮬레이션
@@ -603,12 +961,10 @@ impl BacktestEngine {
                 .await?;
 
             // 3. 시그널 처리 (BacktestEngine 고유: PerformanceTracker/SignalMarker 기록)
-            for signal in &signals.entry_signals {
-                self.process_signal(signal, kline).await?;
-            }
-            for signal in &signals.exit_signals {
-                self.process_signal(signal, kline).await?;
-            }
+            self.record_decision_traces(signals.decision_traces);
+            self.process_signals(&signals.entry_signals, kline).await?;
+            self.process_signals(&signals.exit_signals, kline).await?;
+            self.settle_expired_options(kline).await?;
 
             // 4. 포지션 동기화 (공통: 전략에 현재 포지션 상태 알림)
             candle_processor
@@ -644,11 +1000,14 @@ impl BacktestEngine {
         let mut metrics = self.tracker.get_metrics();
         metrics.max_drawdown_pct = self.tracker.max_drawdown_pct();
 
+        let equity_curve = self.tracker.get_equity_curve().to_vec();
+        let fx = self.fx_report(ticker, &equity_curve, start_time, end_time);
+
         Ok(BacktestReport {
             config: self.config.clone(),
             metrics,
             trades: self.tracker.get_round_trips().to_vec(),
-            equity_curve: self.tracker.get_equity_curve().to_vec(),
+            equity_curve,
             total_orders: self.total_orders(),
             total_commission: self.total_commission(),
             total_slippage: self.total_slippage,
@@ -657,12 +1016,118 @@ impl BacktestEngine {
             data_points,
             performance_by_symbol,
             signal_markers: self.signal_markers.clone(),
+            decision_traces: self.decision_traces.clone(),
             klines: klines.to_vec(),
             symbol: ticker.to_string(),
             all_trades: self.executor.trades().to_vec(),
+            data_quality_warnings,
+            fx,
         })
     }
 
+    /// 신호 목록을 처리합니다.
+    ///
+    /// 다중 레그 신호(페어 트레이딩 등)는 group_id 단위로 묶어 함께 처리하고,
+    /// 나머지 신호는 순서대로 개별 처리합니다.
+    async fn process_signals(&mut self, signals: &[Signal], kline: &Kline) -> BacktestResult<()> {
+        let mut handled_groups: Vec<&str> = Vec::new();
+
+        for signal in signals {
+            if let Some(contract) = signal.option_contract() {
+                self.option_contracts
+                    .insert(signal.ticker.clone(), contract);
+            }
+
+            let group_id = match (signal.leg_count(), signal.group_id.as_deref()) {
+                (Some(_), Some(group_id)) => group_id,
+                _ => {
+                    self.process_signal(signal, kline).await?;
+                    continue;
+                }
+            };
+            if handled_groups.contains(&group_id) {
+                continue;
+            }
+            handled_groups.push(group_id);
+
+            let legs: Vec<Signal> = signals
+                .iter()
+                .filter(|s| s.group_id.as_deref() == Some(group_id))
+                .cloned()
+                .collect();
+            self.process_signal_group(legs, kline).await?;
+        }
+
+        Ok(())
+    }
+
+    /// 다중 레그 신호 그룹을 처리합니다.
+    ///
+    /// 레그가 함께 체결되지 못하면 백테스트를 중단하지 않고 모든 레그를
+    /// 미체결로 기록합니다. 되돌림 청산이 발생했다면 그 거래도 기록합니다.
+    async fn process_signal_group(
+        &mut self,
+        legs: Vec<Signal>,
+        kline: &Kline,
+    ) -> BacktestResult<()> {
+        let priced: Vec<(Signal, Decimal)> = legs
+            .into_iter()
+            .map(|signal| {
+                let price = self.get_price_for_signal(&signal, kline);
+                (signal, price)
+            })
+            .collect();
+
+        let trades_before = self.executor.trades().len();
+        let result = self
+            .executor
+            .process_signal_group(&priced, kline.close_time)
+            .await;
+        let new_trades = self.executor.trades()[trades_before..].to_vec();
+
+        let executed_tickers: Vec<&str> = match &result {
+            Ok(trades) => trades.iter().map(|t| t.symbol.as_str()).collect(),
+            Err(e) => {
+                tracing::warn!(error = %e, "레그 그룹 미체결");
+                Vec::new()
+            }
+        };
+
+        for (signal, price) in &priced {
+            let trace = self.pending_traces.remove(&signal.id);
+            let marker =
+                SignalMarker::from_signal(signal, *price, kline.open_time, &signal.strategy_id)
+                    .with_executed(executed_tickers.contains(&signal.ticker.as_str()))
+                    .with_decision_trace(trace);
+            self.signal_markers.push(marker);
+        }
+
+        for trade_result in &new_trades {
+            let Some((leg, _)) = priced.iter().find(|(s, _)| s.ticker == trade_result.symbol)
+            else {
+                continue;
+            };
+            let signal = if trade_result.signal_type == leg.signal_type {
+                leg.clone()
+            } else {
+                // 되돌림 청산 거래
+                let mut unwind = Signal::new(
+                    leg.strategy_id.clone(),
+                    leg.ticker.clone(),
+                    trade_result.side,
+                    trade_result.signal_type,
+                )
+                .with_metadata("reason", serde_json::json!("leg_unwind"));
+                unwind.position_id = leg.position_id.clone();
+                unwind
+            };
+            self.record_trade_result(trade_result, &signal)?;
+            self.total_slippage += trade_result.slippage;
+        }
+
+        Ok(())
+    }
+
     /// 신호를 처리합니다.
     ///
     /// SimulatedExecutor에 위임하여 포지션을 관리합니다.
@@ -670,6 +1135,8 @@ impl BacktestEngine {
         // 실행 가격 결정
         let current_price = self.get_price_for_signal(signal, kline);
 
+        let trace = self.pending_traces.remove(&signal.id);
+
         // Alert는 실행하지 않음 - marker만 저장
         if signal.signal_type == SignalType::Alert {
             let marker = SignalMarker::from_signal(
@@ -677,11 +1144,34 @@ impl BacktestEngine {
                 current_price,
                 kline.open_time,
                 &signal.strategy_id,
-            );
+            )
+            .with_decision_trace(trace);
             self.signal_markers.push(marker);
             return Ok(());
         }
 
+        // 경제 이벤트 블랙아웃 (신규 진입만 차단/축소)
+        let reduced;
+        let signal = match self.apply_event_blackout(signal, current_price, kline) {
+            Some(BlackoutDecision::Reduce(adjusted)) => {
+                reduced = adjusted;
+                &reduced
+            }
+            Some(BlackoutDecision::Block) => {
+                let marker = SignalMarker::from_signal(
+                    signal,
+                    current_price,
+                    kline.open_time,
+                    &signal.strategy_id,
+                )
+                .with_executed(false)
+                .with_decision_trace(trace);
+                self.signal_markers.push(marker);
+                return Ok(());
+            }
+            None => signal,
+        };
+
         // SimulatedExecutor에 Signal 처리 위임
         let result = self
             .executor
@@ -693,7 +1183,8 @@ impl BacktestEngine {
         let executed = result.is_some();
         let marker =
             SignalMarker::from_signal(signal, current_price, kline.open_time, &signal.strategy_id)
-                .with_executed(executed);
+                .with_executed(executed)
+                .with_decision_trace(trace);
         self.signal_markers.push(marker);
 
         // 거래가 발생한 경우 tracker에 기록
@@ -707,24 +1198,138 @@ impl BacktestEngine {
         Ok(())
     }
 
+    /// 경제 이벤트 블랙아웃 적용.
+    ///
+    /// 블랙아웃 구간이 아니거나 신규 진입 신호가 아니면 None을 반환합니다.
+    fn apply_event_blackout(
+        &self,
+        signal: &Signal,
+        price: Decimal,
+        kline: &Kline,
+    ) -> Option<BlackoutDecision> {
+        let blackout = self.config.event_blackout.as_ref()?;
+        if signal.signal_type != SignalType::Entry {
+            return None;
+        }
+        let event = blackout.active_event(&self.economic_calendar, kline.close_time)?;
+
+        match &blackout.action {
+            BlackoutAction::Block => {
+                tracing::debug!(
+                    ticker = %signal.ticker,
+                    event = %event.title,
+                    "이벤트 블랙아웃: 진입 차단"
+                );
+                Some(BlackoutDecision::Block)
+            }
+            BlackoutAction::ReduceSize { factor } => {
+                let (_, quantity) = signal_position_size(
+                    signal,
+                    self.executor.balance(),
+                    self.config.max_position_size_pct,
+                    price,
+                );
+                let reduced = quantity * *factor;
+                tracing::debug!(
+                    ticker = %signal.ticker,
+                    event = %event.title,
+                    quantity = %quantity,
+                    reduced = %reduced,
+                    "이벤트 블랙아웃: 진입 크기 축소"
+                );
+                if reduced <= Decimal::ZERO {
+                    return Some(BlackoutDecision::Block);
+                }
+                Some(BlackoutDecision::Reduce(
+                    signal.clone().with_fixed_quantity(reduced),
+                ))
+            }
+        }
+    }
+
+    /// 결정 추적 분류.
+    ///
+    /// 발생 신호의 추적은 신호 처리 시 마커에 붙이고, 나머지는 리포트에 그대로 남깁니다.
+    fn record_decision_traces(&mut self, traces: Vec<DecisionTrace>) {
+        for trace in traces {
+            match trace.signal_id {
+                Some(signal_id) => {
+                    self.pending_traces.insert(signal_id, trace);
+                }
+                None => self.decision_traces.push(trace),
+            }
+        }
+    }
+
     /// Signal에 대한 현재 가격 조회
     ///
     /// 다중 자산 전략에서는 신호 심볼과 현재 kline 심볼이 다를 수 있음:
     /// 1. signal.suggested_price가 있으면 사용
-    /// 2. current_prices에서 해당 심볼의 가격 사용
-    /// 3. fallback: kline.close (단일 자산 전략)
+    /// 2. 옵션 계약이면 합성 가격 사용
+    /// 3. current_prices에서 해당 심볼의 가격 사용
+    /// 4. fallback: kline.close (단일 자산 전략)
     fn get_price_for_signal(&self, signal: &Signal, kline: &Kline) -> Decimal {
-        let key = &signal.ticker;
-        // 티커 형식 정규화: "TLT/USD" → "TLT"
-        let base_ticker = key.split('/').next().unwrap_or(key);
-
         signal
             .suggested_price
-            .or_else(|| self.current_prices.get(key).copied())
-            .or_else(|| self.current_prices.get(base_ticker).copied())
+            .unwrap_or_else(|| self.mark_price(&signal.ticker, kline))
+    }
+
+    /// 심볼의 현재 평가 가격.
+    ///
+    /// 옵션 티커는 기초자산 가격으로 합성 가격을 계산합니다.
+    fn mark_price(&self, symbol: &str, kline: &Kline) -> Decimal {
+        if let Some(contract) = self.option_contracts.get(symbol) {
+            let spot = self.underlying_price(&contract.underlying, kline);
+            return self
+                .config
+                .option_pricing
+                .price(contract, spot, self.current_time);
+        }
+        self.underlying_price(symbol, kline)
+    }
+
+    /// current_prices 기준 심볼 가격 (없으면 kline.close).
+    fn underlying_price(&self, symbol: &str, kline: &Kline) -> Decimal {
+        // 티커 형식 정규화: "TLT/USD" → "TLT"
+        let base_ticker = symbol.split('/').next().unwrap_or(symbol);
+        self.current_prices
+            .get(symbol)
+            .or_else(|| self.current_prices.get(base_ticker))
+            .copied()
             .unwrap_or(kline.close)
     }
 
+    /// 만기가 지난 옵션 포지션을 내재가치로 정산합니다.
+    async fn settle_expired_options(&mut self, kline: &Kline) -> BacktestResult<()> {
+        let today = self.current_time.date_naive();
+        let expired: Vec<_> = self
+            .executor
+            .positions()
+            .values()
+            .filter(|pos| {
+                self.option_contracts
+                    .get(&pos.symbol)
+                    .is_some_and(|contract| contract.is_expired(today))
+            })
+            .map(|pos| (pos.symbol.clone(), pos.position_id.clone(), pos.side))
+            .collect();
+
+        for (symbol, position_id, side) in expired {
+            let exit_side = match side {
+                Side::Buy => Side::Sell,
+                Side::Sell => Side::Buy,
+            };
+            let mut signal = Signal::exit("backtest_option_expiry", symbol, exit_side)
+                .with_metadata("reason", serde_json::json!("option_expiry"));
+            if let Some(pid) = position_id {
+                signal = signal.with_position_id(pid);
+            }
+            self.process_signal(&signal, kline).await?;
+        }
+
+        Ok(())
+    }
+
     /// TradeResult를 PerformanceTracker에 기록
     fn record_trade_result(
         &mut self,
@@ -794,13 +1399,7 @@ impl BacktestEngine {
             };
 
             // 청산 가격 조회
-            let base_ticker = symbol.split('/').next().unwrap_or(&symbol);
-            let close_price = self
-                .current_prices
-                .get(&symbol)
-                .or_else(|| self.current_prices.get(base_ticker))
-                .copied()
-                .unwrap_or(kline.close);
+            let close_price = self.mark_price(&symbol, kline);
 
             tracing::debug!(
                 symbol = %symbol,
@@ -839,15 +1438,8 @@ impl BacktestEngine {
     fn calculate_equity(&self, kline: &Kline) -> Decimal {
         let mut equity = self.executor.balance();
 
-        for (symbol, position) in self.executor.positions().iter() {
-            // 티커 형식 정규화: "TLT/USD" → "TLT"
-            let base_ticker = symbol.split('/').next().unwrap_or(symbol);
-            let current_price = self
-                .current_prices
-                .get(symbol)
-                .or_else(|| self.current_prices.get(base_ticker))
-                .copied()
-                .unwrap_or(kline.close);
+        for position in self.executor.positions().values() {
+            let current_price = self.mark_price(&position.symbol, kline);
 
             let position_value = match position.side {
                 Side::Buy => current_price * position.quantity,
@@ -998,6 +1590,7 @@ impl BacktestEngine {
         let start_time = primary_klines.first().unwrap().open_time;
         let end_time = primary_klines.last().unwrap().close_time;
         let data_points = primary_klines.len();
+        let data_quality_warnings = self.data_quality_warnings();
 
         // 백테스트 시작 시간으로 equity curve 초기 timestamp 설정
         self.tracker.set_initial_timestamp(start_time);
@@ -1034,9 +1627,11 @@ impl BacktestEngine {
             };
 
             // 신호 처리
-            for signal in signals {
-                self.process_signal(&signal, kline).await?;
-            }
+            self.record_decision_traces(link_signal_traces(
+                &signals,
+                strategy.take_decision_traces(),
+            ));
+            self.process_signals(&signals, kline).await?;
 
             // 미실현 손익 반영하여 자산 업데이트
             let equity = self.calculate_equity(kline);
@@ -1061,11 +1656,18 @@ impl BacktestEngine {
         let mut metrics = self.tracker.get_metrics();
         metrics.max_drawdown_pct = self.tracker.max_drawdown_pct();
 
+        let symbol = primary_klines
+            .first()
+            .map(|k| k.ticker.to_string())
+            .unwrap_or_default();
+        let equity_curve = self.tracker.get_equity_curve().to_vec();
+        let fx = self.fx_report(&symbol, &equity_curve, start_time, end_time);
+
         Ok(BacktestReport {
             config: self.config.clone(),
             metrics,
             trades: self.tracker.get_round_trips().to_vec(),
-            equity_curve: self.tracker.get_equity_curve().to_vec(),
+            equity_curve,
             total_orders: self.total_orders(),
             total_commission: self.total_commission(),
             total_slippage: self.total_slippage,
@@ -1074,12 +1676,12 @@ impl BacktestEngine {
             data_points,
             performance_by_symbol,
             signal_markers: self.signal_markers.clone(),
+            decision_traces: self.decision_traces.clone(),
             klines: primary_klines.to_vec(),
-            symbol: primary_klines
-                .first()
-                .map(|k| k.ticker.to_string())
-                .unwrap_or_default(),
+            symbol,
             all_trades: self.executor.trades().to_vec(),
+            data_quality_warnings,
+            fx,
         })
     }
 }
@@ -1383,6 +1985,48 @@ mod tests {
         assert!(report.total_commission > Decimal::ZERO);
     }
 
+    #[tokio::test]
+    async fn test_backtest_data_quality_warnings() {
+        let quality = |symbol: &str, score: f64| OhlcvQualityReport {
+            symbol: symbol.to_string(),
+            timeframe: "1h".to_string(),
+            checked_at: Utc::now(),
+            range_start: None,
+            range_end: None,
+            total_bars: 100,
+            zero_volume_bars: 0,
+            invalid_ohlc_bars: 0,
+            duplicate_bars: 0,
+            missing_days: 5,
+            spike_bars: 0,
+            repaired_bars: 0,
+            repair_sources: Vec::new(),
+            score,
+            issues: Vec::new(),
+        };
+
+        let mut engine = BacktestEngine::new(BacktestConfig::new(dec!(100000)))
+            .with_data_quality_reports([quality("BTC/USDT", 76.2), quality("ETH/USDT", 99.0)]);
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let klines = create_test_klines(10, dec!(50000), dec!(100));
+
+        let report = engine
+            .run(
+                &mut strategy,
+                &klines,
+                create_test_context(),
+                "BTC/USDT",
+                None,
+            )
+            .await
+            .unwrap();
+
+        // 기준 미만 리포트만 경고
+        assert_eq!(report.data_quality_warnings.len(), 1);
+        assert!(report.data_quality_warnings[0].starts_with("BTC/USDT"));
+        assert!(report.summary().contains("데이터 품질"));
+    }
+
     #[tokio::test]
     async fn test_backtest_sma_strategy() {
         let config = BacktestConfig::new(dec!(1000000))
@@ -1439,4 +2083,127 @@ mod tests {
         assert_eq!(config.initial_capital, dec!(10000000));
         assert!(config.validate().is_ok());
     }
+    #[tokio::test]
+    async fn test_option_synthetic_pricing_and_expiry() {
+        use chrono::{NaiveDate, TimeZone};
+
+        let config = BacktestConfig::new(dec!(100000)).with_option_pricing(
+            SyntheticOptionPricing::default()
+                .with_implied_volatility(0.2)
+                .with_risk_free_rate(0.0),
+        );
+        let mut engine = BacktestEngine::new(config);
+        let kline = create_test_klines(1, dec!(100), dec!(0)).remove(0);
+
+        let expiry = NaiveDate::from_ymd_opt(2026, 11, 20).unwrap();
+        let contract = OptionContract::put("SPY", dec!(100), expiry);
+        let signal = Signal::entry("overlay", contract.ticker(), Side::Buy)
+            .with_option_contract(&contract)
+            .with_fixed_quantity(dec!(100));
+
+        // 진입: 기초자산 가격으로 합성 가격 결정
+        engine.current_time = Utc.with_ymd_and_hms(2026, 10, 21, 0, 0, 0).unwrap();
+        engine.current_prices.insert("SPY".to_string(), dec!(100));
+        engine
+            .process_signals(std::slice::from_ref(&signal), &kline)
+            .await
+            .unwrap();
+        let premium = engine.mark_price(&contract.ticker(), &kline);
+        assert!(premium > dec!(1) && premium < dec!(5));
+        assert_eq!(engine.positions_count(), 1);
+
+        // 만기: 내재가치로 정산
+        engine.current_time = Utc.with_ymd_and_hms(2026, 11, 20, 21, 0, 0).unwrap();
+        engine.current_prices.insert("SPY".to_string(), dec!(90));
+        assert_eq!(engine.mark_price(&contract.ticker(), &kline), dec!(10));
+        engine.settle_expired_options(&kline).await.unwrap();
+        assert_eq!(engine.positions_count(), 0);
+        assert!(engine.balance() > dec!(100000));
+    }
+
+    #[tokio::test]
+    async fn test_event_blackout() {
+        use trader_core::{Country, EconomicEvent, EconomicEventKind};
+
+        let kline = create_test_klines(1, dec!(100), dec!(0)).remove(0);
+        let calendar = EconomicCalendar::from_events(vec![EconomicEvent::new(
+            EconomicEventKind::Fomc,
+            "FOMC 금리 결정",
+            Country::US,
+            kline.close_time + Duration::minutes(30),
+        )]);
+        let signal = Signal::entry("test", "BTC/USDT", Side::Buy).with_strength(1.0);
+
+        // 차단: 진입 없이 미실행 마커만 기록
+        let config = BacktestConfig::new(dec!(100000))
+            .with_slippage_rate(dec!(0))
+            .with_event_blackout(EventBlackoutConfig::default());
+        let mut engine = BacktestEngine::new(config).with_economic_calendar(calendar.clone());
+        engine
+            .process_signals(std::slice::from_ref(&signal), &kline)
+            .await
+            .unwrap();
+        assert_eq!(engine.positions_count(), 0);
+        assert!(!engine.signal_markers[0].executed);
+
+        // 축소: 기본 크기(20% = 200개)의 절반으로 진입
+        let config = BacktestConfig::new(dec!(100000))
+            .with_slippage_rate(dec!(0))
+            .with_event_blackout(EventBlackoutConfig::default().with_reduce_size(dec!(0.5)));
+        let mut engine = BacktestEngine::new(config).with_economic_calendar(calendar);
+        engine
+            .process_signals(std::slice::from_ref(&signal), &kline)
+            .await
+            .unwrap();
+        let position = engine.executor.positions().values().next().unwrap();
+        assert_eq!(position.quantity, dec!(100));
+    }
+
+    #[test]
+    fn test_fx_report() {
+        let start = Utc::now() - Duration::days(10);
+        let end = Utc::now();
+        let mut rates = FxRateTable::new().with_stablecoin_pegs();
+        rates.insert("USD", "KRW", start.date_naive(), dec!(1300));
+        rates.insert("USD", "KRW", end.date_naive(), dec!(1400));
+
+        let curve = vec![
+            EquityPoint {
+                timestamp: start,
+                equity: dec!(1000),
+                drawdown_pct: dec!(0),
+            },
+            EquityPoint {
+                timestamp: end,
+                equity: dec!(1100),
+                drawdown_pct: dec!(0),
+            },
+        ];
+        let report =
+            BacktestFxReport::calculate(&rates, "USDT", "KRW", dec!(1000), &curve, start, end)
+                .unwrap();
+
+        // 자산 손익 100 USDT × 1400 + 환차손익 1000 × (1400 - 1300)
+        assert_eq!(report.pnl.asset_pnl, dec!(140000));
+        assert_eq!(report.pnl.fx_pnl, dec!(100000));
+        assert_eq!(report.initial_capital_base, dec!(1300000));
+        assert_eq!(report.final_equity_base, dec!(1540000));
+        assert_eq!(
+            report.final_equity_base - report.initial_capital_base,
+            report.pnl.total()
+        );
+        assert_eq!(report.equity_curve.len(), 2);
+
+        // 시작 시점 환율이 없으면 오류
+        assert!(BacktestFxReport::calculate(
+            &rates,
+            "USD",
+            "KRW",
+            dec!(1000),
+            &curve,
+            start - Duration::days(1),
+            end
+        )
+        .is_err());
+    }
 }
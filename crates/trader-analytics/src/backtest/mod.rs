@@ -7,6 +7,7 @@
 //! - [`BacktestConfig`]: 백테스트 설정 (초기 자본, 수수료, 슬리피지 등)
 //! - [`BacktestEngine`]: 백테스트 실행 엔진
 //! - [`BacktestReport`]: 백테스트 결과 리포트
+//! - [`BacktestFxReport`]: 기준 통화 환산 및 환차손익 분리 리포트
 //! - [`SlippageModel`]: 동적 슬리피지 모델 (Fixed/Linear/VolatilityBased/Tiered)
 //! - [`BacktestScreeningProvider`]: 백테스트용 스크리닝 결과 제공자
 //! - [`CandleProcessor`]: 캔들 처리 공통 프로세서 (BacktestEngine/SimulationEngine 공유)
@@ -19,7 +20,9 @@ pub mod slippage;
 pub use candle_processor::{
     CandleProcessor, PartitionedSignals, ProcessCandleContext, MIN_CANDLES_FOR_INDICATORS,
 };
-pub use engine::{BacktestConfig, BacktestEngine, BacktestError, BacktestReport, BacktestResult};
+pub use engine::{
+    BacktestConfig, BacktestEngine, BacktestError, BacktestFxReport, BacktestReport, BacktestResult,
+};
 pub use screening_provider::{
     BacktestScreeningConfig, BacktestScreeningProvider, MIN_CANDLES_FOR_SCREENING,
 };
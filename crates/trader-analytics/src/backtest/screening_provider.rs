@@ -2,23 +2,32 @@
 //!
 //! 백테스트 환경에서 캔들 데이터만으로 스크리닝 결과를 생성합니다.
 //! 실거래의 AnalyticsProvider 역할을 대신합니다.
+//!
+//! 펀더멘털 이력([`FundamentalHistory`])을 주입하면 각 시점까지 알려진
+//! PER/PBR/ROE 등만으로 가치/품질/성장 점수를 계산하여 종합 점수에 반영합니다.
 
 use std::collections::HashMap;
 
 use chrono::{DateTime, Datelike, Utc};
+use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use trader_core::domain::{Kline, RouteState, ScreeningResult};
 // trader-core에서 정의된 trait과 타입 사용
 use trader_core::{ScreeningCalculator, ScreeningCalculatorConfig, ScreeningUpdateFrequency};
+use trader_data::{FundamentalHistory, FundamentalSnapshot};
 
 use crate::{
     global_scorer::{GlobalScorer, GlobalScorerParams},
     route_state_calculator::RouteStateCalculator,
+    seven_factor::{SevenFactorCalculator, SevenFactorInput},
 };
 
 /// 백테스트용 스크리닝 결과를 계산하는 최소 캔들 수
 pub const MIN_CANDLES_FOR_SCREENING: usize = 50;
 
+/// 펀더멘털 점수(가치/품질/성장 평균)의 종합 점수 반영 비중
+const FUNDAMENTAL_WEIGHT: Decimal = dec!(0.3);
+
 // ================================================================================================
 // 하위 호환성을 위한 타입 별칭
 // ================================================================================================
@@ -50,6 +59,8 @@ pub struct BacktestScreeningProvider {
     global_scorer: GlobalScorer,
     route_calculator: RouteStateCalculator,
     config: ScreeningCalculatorConfig,
+    /// 시점별 펀더멘털 이력 (None이면 기술적 지표만 사용)
+    fundamentals: Option<FundamentalHistory>,
 }
 
 impl Default for BacktestScreeningProvider {
@@ -65,6 +76,7 @@ impl BacktestScreeningProvider {
             global_scorer: GlobalScorer::new(),
             route_calculator: RouteStateCalculator::new(),
             config: ScreeningCalculatorConfig::default(),
+            fundamentals: None,
         }
     }
 
@@ -74,9 +86,18 @@ impl BacktestScreeningProvider {
             global_scorer: GlobalScorer::new(),
             route_calculator: RouteStateCalculator::new(),
             config,
+            fundamentals: None,
         }
     }
 
+    /// 펀더멘털 이력 설정.
+    ///
+    /// 각 스크리닝 시점에는 `as_of_date`가 그 날짜 이하인 스냅샷만 사용합니다.
+    pub fn with_fundamentals(mut self, history: FundamentalHistory) -> Self {
+        self.fundamentals = Some(history);
+        self
+    }
+
     /// 캔들 데이터 기반 스크리닝 결과 생성 (기존 API 하위 호환용)
     ///
     /// 새 코드에서는 `ScreeningCalculator::calculate_from_klines()`를 사용하세요.
@@ -142,7 +163,7 @@ impl BacktestScreeningProvider {
             Err(_) => return None,
         };
 
-        let overall_score = score_result.overall_score;
+        let mut overall_score = score_result.overall_score;
 
         // 2. RouteState 계산
         let route_state = self
@@ -152,10 +173,6 @@ impl BacktestScreeningProvider {
 
         // 3. criteria_results 구성 (기술적 지표 기반)
         let mut criteria_results = HashMap::new();
-        criteria_results.insert(
-            "global_score".to_string(),
-            overall_score >= config.min_score,
-        );
         criteria_results.insert(
             "route_state_favorable".to_string(),
             matches!(route_state, RouteState::Attack | RouteState::Armed),
@@ -166,7 +183,28 @@ impl BacktestScreeningProvider {
             criteria_results.insert(format!("score_{}", key), *value >= dec!(50));
         }
 
-        // 4. ScreeningResult 생성
+        // 4. 시점 펀더멘털 반영 (현재 시점까지 알려진 스냅샷만)
+        if let Some(snapshot) = self
+            .fundamentals
+            .as_ref()
+            .and_then(|history| history.as_of(ticker, current_time.date_naive()))
+        {
+            let (value, quality, growth) = Self::fundamental_scores(snapshot);
+            criteria_results.insert("fundamental_value".to_string(), value >= dec!(50));
+            criteria_results.insert("fundamental_quality".to_string(), quality >= dec!(50));
+            criteria_results.insert("fundamental_growth".to_string(), growth >= dec!(50));
+
+            let fundamental_score = (value + quality + growth) / dec!(3);
+            overall_score = (overall_score * (Decimal::ONE - FUNDAMENTAL_WEIGHT)
+                + fundamental_score * FUNDAMENTAL_WEIGHT)
+                .round_dp(2);
+        }
+        criteria_results.insert(
+            "global_score".to_string(),
+            overall_score >= config.min_score,
+        );
+
+        // 5. ScreeningResult 생성
         Some(ScreeningResult {
             ticker: ticker.to_string(),
             preset_name: config.preset_name.clone(),
@@ -182,6 +220,24 @@ impl BacktestScreeningProvider {
         })
     }
 
+    /// 펀더멘털 스냅샷의 가치/품질/성장 점수 (각 0~100).
+    fn fundamental_scores(snapshot: &FundamentalSnapshot) -> (Decimal, Decimal, Decimal) {
+        let input = SevenFactorInput {
+            per: snapshot.per,
+            pbr: snapshot.pbr,
+            psr: snapshot.psr,
+            roe: snapshot.roe,
+            roa: snapshot.roa,
+            operating_margin: snapshot.operating_margin,
+            net_profit_margin: snapshot.net_profit_margin,
+            revenue_growth_yoy: snapshot.revenue_growth_yoy,
+            earnings_growth_yoy: snapshot.earnings_growth_yoy,
+            ..Default::default()
+        };
+        let scores = SevenFactorCalculator::calculate(&input);
+        (scores.norm_value, scores.norm_quality, scores.norm_growth)
+    }
+
     /// 스크리닝 업데이트 필요 여부 판단 (static 메서드)
     ///
     /// 하위 호환성을 위해 유지됩니다.
@@ -263,8 +319,7 @@ impl ScreeningCalculator for BacktestScreeningProvider {
 
 #[cfg(test)]
 mod tests {
-    use chrono::TimeZone;
-    use rust_decimal::Decimal;
+    use chrono::{NaiveDate, TimeZone};
     use trader_core::Timeframe;
 
     use super::*;
@@ -369,6 +424,83 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    fn snapshot(as_of: NaiveDate, per: Decimal, roe: Decimal) -> FundamentalSnapshot {
+        FundamentalSnapshot {
+            ticker: "TEST".to_string(),
+            as_of_date: as_of,
+            report_date: None,
+            market_cap: None,
+            per: Some(per),
+            pbr: Some(per / dec!(10)),
+            psr: None,
+            eps: None,
+            bps: None,
+            dividend_yield: None,
+            roe: Some(roe),
+            roa: None,
+            operating_margin: None,
+            net_profit_margin: None,
+            debt_ratio: None,
+            revenue_growth_yoy: None,
+            earnings_growth_yoy: None,
+            data_source: None,
+        }
+    }
+
+    #[test]
+    fn test_fundamentals_are_point_in_time() {
+        let jan = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        let feb = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        // 1월에는 저평가/고수익, 2월 수집분부터 고평가/저수익
+        let history = FundamentalHistory::new(vec![
+            snapshot(feb, dec!(40), dec!(1)),
+            snapshot(jan, dec!(5), dec!(25)),
+        ]);
+        assert_eq!(history.as_of("TEST", jan).unwrap().per, Some(dec!(5)));
+        assert_eq!(
+            history
+                .as_of("TEST", NaiveDate::from_ymd_opt(2024, 1, 31).unwrap())
+                .unwrap()
+                .per,
+            Some(dec!(5))
+        );
+        assert_eq!(history.as_of("TEST", feb).unwrap().per, Some(dec!(40)));
+        assert!(history
+            .as_of("TEST", NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .is_none());
+
+        let mut all_klines = HashMap::new();
+        all_klines.insert("TEST".to_string(), create_test_klines(60, 100.0));
+        let config = ScreeningCalculatorConfig::default();
+        let plain = BacktestScreeningProvider::with_config(config.clone());
+        let provider = BacktestScreeningProvider::with_config(config).with_fundamentals(history);
+
+        // 1월 중순: 2월 스냅샷(미래)은 보이지 않아야 함
+        let mid_jan = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let base = &plain.calculate_from_klines(&all_klines, mid_jan)[0];
+        let result = &provider.calculate_from_klines(&all_klines, mid_jan)[0];
+        assert_eq!(
+            result.criteria_results.get("fundamental_value"),
+            Some(&true)
+        );
+        assert_eq!(
+            result.criteria_results.get("fundamental_quality"),
+            Some(&true)
+        );
+        assert!(result.overall_score > base.overall_score);
+
+        let mid_feb = Utc.with_ymd_and_hms(2024, 2, 15, 0, 0, 0).unwrap();
+        let result = &provider.calculate_from_klines(&all_klines, mid_feb)[0];
+        assert_eq!(
+            result.criteria_results.get("fundamental_value"),
+            Some(&false)
+        );
+        assert_eq!(
+            result.criteria_results.get("fundamental_quality"),
+            Some(&false)
+        );
+    }
+
     #[test]
     fn test_top_n_filter() {
         let results = vec![
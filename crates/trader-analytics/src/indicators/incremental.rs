@@ -0,0 +1,1112 @@
+//! 증분 지표 상태 저장소.
+//!
+//! `IndicatorEngine`은 매번 전체 이력을 다시 계산하지만, 이 모듈의 상태 객체는
+//! 롤링 상태(EMA 시드, Wilder 평균, ATR, SuperTrend 밴드 등)를 보관하여
+//! 새 캔들마다 O(1)로 갱신합니다. 볼린저 밴드만 `period` 크기의 윈도우를 유지하므로
+//! 갱신 비용이 기간에 비례하지만 전체 이력 길이와는 무관합니다.
+//!
+//! 계산 순서와 시드 규칙은 전체 재계산 구현(`trend`, `momentum`, `volatility`,
+//! `supertrend`)과 동일하게 맞춰져 있어, 같은 캔들을 순서대로 넣으면 같은 값을 얻습니다.
+//!
+//! 상태는 serde로 직렬화되어 `indicator_state` 테이블에 저장되며,
+//! 수집기(`indicator_sync`)와 실시간 `StrategyEngine`이
+//! [`IndicatorStateCache`]를 통해 같은 상태를 공유합니다.
+//!
+//! # 사용 예시
+//!
+//! ```ignore
+//! use trader_analytics::indicators::{IndicatorStateCache, IndicatorStateConfig};
+//!
+//! let cache = Arc::new(IndicatorStateCache::new(IndicatorStateConfig::default()));
+//! let engine = StrategyEngine::new(config).with_indicator_updater(cache.clone());
+//!
+//! // 캔들마다 O(1) 갱신 (진행 중인 캔들은 같은 open_time으로 들어오면 교체)
+//! if let Some(snapshot) = cache.on_kline(&kline) {
+//!     println!("RSI: {:?}", snapshot.rsi);
+//! }
+//! ```
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use trader_core::{
+    domain::{IndicatorSnapshot, IndicatorUpdater},
+    Kline, Timeframe,
+};
+use trader_data::{IndicatorStateRepository, StoredIndicatorState};
+
+use super::{
+    AtrParams, BollingerBandsParams, BollingerBandsResult, MacdParams, MacdResult, RsiParams,
+    SuperTrendParams, SuperTrendResult, VolatilityIndicators,
+};
+
+/// Decimal 윈도우를 문자열 배열로 직렬화.
+///
+/// 워크스페이스의 rust_decimal은 `serde-float`로 설정되어 있어 기본 직렬화가 f64를 거칩니다.
+/// 상태 복원 후에도 전체 재계산과 같은 값을 내도록 모든 상태 값은 문자열로 저장합니다.
+mod decimal_seq {
+    use std::collections::VecDeque;
+
+    use rust_decimal::Decimal;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(values: &VecDeque<Decimal>, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(values.iter().map(Decimal::to_string))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<VecDeque<Decimal>, D::Error> {
+        Vec::<String>::deserialize(d)?
+            .iter()
+            .map(|v| v.parse().map_err(D::Error::custom))
+            .collect()
+    }
+}
+
+// ==================== 기본 상태 ====================
+
+/// 증분 EMA (첫 값은 SMA로 시드).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalEma {
+    period: usize,
+    count: usize,
+    #[serde(with = "rust_decimal::serde::str")]
+    seed_sum: Decimal,
+    #[serde(with = "rust_decimal::serde::str_option")]
+    value: Option<Decimal>,
+}
+
+impl IncrementalEma {
+    /// 새 EMA 상태 생성.
+    pub fn new(period: usize) -> Self {
+        Self {
+            period: period.max(1),
+            count: 0,
+            seed_sum: Decimal::ZERO,
+            value: None,
+        }
+    }
+
+    /// 새 값 반영 (워밍업 중이면 None).
+    pub fn update(&mut self, price: Decimal) -> Option<Decimal> {
+        self.count += 1;
+        self.value = match self.value {
+            Some(prev) => {
+                let multiplier = dec!(2) / Decimal::from(self.period + 1);
+                Some((price * multiplier) + (prev * (Decimal::ONE - multiplier)))
+            }
+            None => {
+                self.seed_sum += price;
+                (self.count == self.period).then(|| self.seed_sum / Decimal::from(self.period))
+            }
+        };
+        self.value
+    }
+
+    /// 현재 값.
+    pub fn value(&self) -> Option<Decimal> {
+        self.value
+    }
+}
+
+/// 증분 Wilder 평균 (alpha = 1/period, 첫 값은 단순 평균으로 시드).
+///
+/// RSI의 평균 상승/하락폭과 ATR에 사용됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalWilder {
+    period: usize,
+    count: usize,
+    #[serde(with = "rust_decimal::serde::str")]
+    seed_sum: Decimal,
+    #[serde(with = "rust_decimal::serde::str_option")]
+    value: Option<Decimal>,
+}
+
+impl IncrementalWilder {
+    /// 새 Wilder 평균 상태 생성.
+    pub fn new(period: usize) -> Self {
+        Self {
+            period: period.max(1),
+            count: 0,
+            seed_sum: Decimal::ZERO,
+            value: None,
+        }
+    }
+
+    /// 새 값 반영 (워밍업 중이면 None).
+    pub fn update(&mut self, value: Decimal) -> Option<Decimal> {
+        self.count += 1;
+        self.value = match self.value {
+            Some(prev) => {
+                let alpha = Decimal::ONE / Decimal::from(self.period);
+                Some((value * alpha) + (prev * (Decimal::ONE - alpha)))
+            }
+            None => {
+                self.seed_sum += value;
+                (self.count == self.period).then(|| self.seed_sum / Decimal::from(self.period))
+            }
+        };
+        self.value
+    }
+
+    /// 현재 값.
+    pub fn value(&self) -> Option<Decimal> {
+        self.value
+    }
+}
+
+/// 직전 종가 기준 True Range.
+fn true_range(high: Decimal, low: Decimal, prev_close: Option<Decimal>) -> Decimal {
+    let hl = high - low;
+    match prev_close {
+        Some(prev) => hl.max((high - prev).abs()).max((low - prev).abs()),
+        None => hl,
+    }
+}
+
+// ==================== 지표별 상태 ====================
+
+/// 증분 RSI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalRsi {
+    #[serde(with = "rust_decimal::serde::str_option")]
+    prev_close: Option<Decimal>,
+    avg_gain: IncrementalWilder,
+    avg_loss: IncrementalWilder,
+}
+
+impl IncrementalRsi {
+    /// 새 RSI 상태 생성.
+    pub fn new(params: RsiParams) -> Self {
+        Self {
+            prev_close: None,
+            avg_gain: IncrementalWilder::new(params.period),
+            avg_loss: IncrementalWilder::new(params.period),
+        }
+    }
+
+    /// 종가 반영.
+    pub fn update(&mut self, close: Decimal) -> Option<Decimal> {
+        // 첫 캔들의 변화량은 0 (전체 계산과 동일)
+        let delta = self.prev_close.map_or(Decimal::ZERO, |prev| close - prev);
+        self.prev_close = Some(close);
+
+        self.avg_gain.update(delta.max(Decimal::ZERO));
+        self.avg_loss.update((-delta).max(Decimal::ZERO));
+        self.value()
+    }
+
+    /// 현재 값.
+    pub fn value(&self) -> Option<Decimal> {
+        let gain = self.avg_gain.value()?;
+        let loss = self.avg_loss.value()?;
+        if loss == Decimal::ZERO {
+            Some(dec!(100))
+        } else {
+            Some(dec!(100) - (dec!(100) / (Decimal::ONE + gain / loss)))
+        }
+    }
+}
+
+/// 증분 MACD.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalMacd {
+    fast: IncrementalEma,
+    slow: IncrementalEma,
+    signal: IncrementalEma,
+}
+
+impl IncrementalMacd {
+    /// 새 MACD 상태 생성.
+    pub fn new(params: MacdParams) -> Self {
+        Self {
+            fast: IncrementalEma::new(params.fast_period),
+            slow: IncrementalEma::new(params.slow_period),
+            signal: IncrementalEma::new(params.signal_period),
+        }
+    }
+
+    /// 종가 반영.
+    pub fn update(&mut self, close: Decimal) -> Option<MacdResult> {
+        let fast = self.fast.update(close);
+        let slow = self.slow.update(close);
+        // 시그널 라인은 MACD 값이 생긴 이후부터 EMA 계산
+        if let (Some(fast), Some(slow)) = (fast, slow) {
+            self.signal.update(fast - slow);
+        }
+        self.value()
+    }
+
+    /// 현재 값.
+    pub fn value(&self) -> Option<MacdResult> {
+        let macd = self.fast.value()? - self.slow.value()?;
+        let signal = self.signal.value();
+        Some(MacdResult {
+            macd: Some(macd),
+            signal,
+            histogram: signal.map(|s| macd - s),
+        })
+    }
+}
+
+/// 증분 볼린저 밴드 (최근 `period`개 종가 윈도우 유지).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalBollinger {
+    period: usize,
+    #[serde(with = "rust_decimal::serde::str")]
+    std_dev_multiplier: Decimal,
+    #[serde(with = "decimal_seq")]
+    window: VecDeque<Decimal>,
+}
+
+impl IncrementalBollinger {
+    /// 새 볼린저 밴드 상태 생성.
+    pub fn new(params: BollingerBandsParams) -> Self {
+        let period = params.period.max(1);
+        Self {
+            period,
+            std_dev_multiplier: params.std_dev_multiplier,
+            window: VecDeque::with_capacity(period),
+        }
+    }
+
+    /// 종가 반영.
+    pub fn update(&mut self, close: Decimal) -> Option<BollingerBandsResult> {
+        if self.window.len() == self.period {
+            self.window.pop_front();
+        }
+        self.window.push_back(close);
+        self.value()
+    }
+
+    /// 현재 값 (윈도우가 차지 않았으면 None).
+    pub fn value(&self) -> Option<BollingerBandsResult> {
+        if self.window.len() < self.period {
+            return None;
+        }
+        let close = *self.window.back()?;
+        let period_decimal = Decimal::from(self.period);
+        let ma = self.window.iter().sum::<Decimal>() / period_decimal;
+        let variance = self
+            .window
+            .iter()
+            .map(|&p| {
+                let diff = p - ma;
+                diff * diff
+            })
+            .sum::<Decimal>()
+            / period_decimal;
+        let std_dev = VolatilityIndicators::new().sqrt_decimal(variance);
+
+        let deviation = self.std_dev_multiplier * std_dev;
+        let upper = ma + deviation;
+        let lower = ma - deviation;
+        let percent_b = if upper != lower {
+            (close - lower) / (upper - lower)
+        } else {
+            dec!(0.5)
+        };
+
+        Some(BollingerBandsResult {
+            upper: Some(upper),
+            middle: Some(ma),
+            lower: Some(lower),
+            percent_b: Some(percent_b),
+            bandwidth: (ma != Decimal::ZERO).then(|| (upper - lower) / ma),
+        })
+    }
+}
+
+/// 증분 ATR (Wilder 평활).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalAtr {
+    #[serde(with = "rust_decimal::serde::str_option")]
+    prev_close: Option<Decimal>,
+    average: IncrementalWilder,
+}
+
+impl IncrementalAtr {
+    /// 새 ATR 상태 생성.
+    pub fn new(params: AtrParams) -> Self {
+        Self {
+            prev_close: None,
+            average: IncrementalWilder::new(params.period),
+        }
+    }
+
+    /// 고가/저가/종가 반영.
+    pub fn update(&mut self, high: Decimal, low: Decimal, close: Decimal) -> Option<Decimal> {
+        let tr = true_range(high, low, self.prev_close);
+        self.prev_close = Some(close);
+        self.average.update(tr)
+    }
+
+    /// 현재 값.
+    pub fn value(&self) -> Option<Decimal> {
+        self.average.value()
+    }
+}
+
+/// 증분 SuperTrend.
+///
+/// 전체 계산과 같이 ATR은 EMA 방식(2/(n+1))으로 평활하며,
+/// 직전 최종 밴드와 추세 방향을 상태로 보관합니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalSuperTrend {
+    atr_period: usize,
+    #[serde(with = "rust_decimal::serde::str")]
+    multiplier: Decimal,
+    bars: usize,
+    #[serde(with = "rust_decimal::serde::str_option")]
+    prev_close: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::str")]
+    atr_seed_sum: Decimal,
+    #[serde(with = "rust_decimal::serde::str_option")]
+    atr: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::str")]
+    prev_upper_band: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    prev_lower_band: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    prev_supertrend: Decimal,
+    prev_is_uptrend: bool,
+    buy_signal: bool,
+    sell_signal: bool,
+}
+
+impl IncrementalSuperTrend {
+    /// 새 SuperTrend 상태 생성.
+    pub fn new(params: SuperTrendParams) -> Self {
+        Self {
+            atr_period: params.atr_period.max(1),
+            multiplier: params.multiplier,
+            bars: 0,
+            prev_close: None,
+            atr_seed_sum: Decimal::ZERO,
+            atr: None,
+            prev_upper_band: Decimal::ZERO,
+            prev_lower_band: Decimal::ZERO,
+            prev_supertrend: Decimal::ZERO,
+            prev_is_uptrend: true,
+            buy_signal: false,
+            sell_signal: false,
+        }
+    }
+
+    /// 고가/저가/종가 반영.
+    pub fn update(
+        &mut self,
+        high: Decimal,
+        low: Decimal,
+        close: Decimal,
+    ) -> Option<SuperTrendResult> {
+        let period = self.atr_period;
+        let first = self.bars == 0;
+        let prev_close = self.prev_close;
+        let tr = true_range(high, low, prev_close);
+        self.bars += 1;
+        self.prev_close = Some(close);
+
+        self.atr = match self.atr {
+            Some(prev) => {
+                let multiplier = dec!(2) / Decimal::from(period + 1);
+                Some((tr - prev) * multiplier + prev)
+            }
+            None => {
+                self.atr_seed_sum += tr;
+                (self.bars == period).then(|| self.atr_seed_sum / Decimal::from(period))
+            }
+        };
+        let Some(atr) = self.atr else {
+            return self.value();
+        };
+
+        let hl_avg = (high + low) / dec!(2);
+        let basic_upper = hl_avg + self.multiplier * atr;
+        let basic_lower = hl_avg - self.multiplier * atr;
+
+        // 최종 밴드 (이전 종가 기준 조정)
+        let final_upper = if first
+            || basic_upper < self.prev_upper_band
+            || prev_close.is_some_and(|c| c > self.prev_upper_band)
+        {
+            basic_upper
+        } else {
+            self.prev_upper_band
+        };
+        let final_lower = if first
+            || basic_lower > self.prev_lower_band
+            || prev_close.is_some_and(|c| c < self.prev_lower_band)
+        {
+            basic_lower
+        } else {
+            self.prev_lower_band
+        };
+
+        let is_uptrend = if first {
+            close > hl_avg
+        } else if self.prev_supertrend == self.prev_upper_band {
+            close <= final_upper
+        } else {
+            close >= final_lower
+        };
+
+        self.buy_signal = !first && is_uptrend && !self.prev_is_uptrend;
+        self.sell_signal = !first && !is_uptrend && self.prev_is_uptrend;
+        self.prev_upper_band = final_upper;
+        self.prev_lower_band = final_lower;
+        self.prev_supertrend = if is_uptrend { final_lower } else { final_upper };
+        self.prev_is_uptrend = is_uptrend;
+        self.value()
+    }
+
+    /// 현재 값 (캔들이 없으면 None, ATR 워밍업 중이면 값 없는 상승 추세).
+    pub fn value(&self) -> Option<SuperTrendResult> {
+        if self.bars == 0 {
+            return None;
+        }
+        Some(match self.atr {
+            Some(_) => SuperTrendResult {
+                value: Some(self.prev_supertrend),
+                is_uptrend: self.prev_is_uptrend,
+                buy_signal: self.buy_signal,
+                sell_signal: self.sell_signal,
+            },
+            None => SuperTrendResult {
+                value: None,
+                is_uptrend: true,
+                buy_signal: false,
+                sell_signal: false,
+            },
+        })
+    }
+}
+
+// ==================== 종목 상태 ====================
+
+/// 증분 지표 파라미터 세트.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct IndicatorStateConfig {
+    /// RSI 파라미터
+    pub rsi: RsiParams,
+    /// MACD 파라미터
+    pub macd: MacdParams,
+    /// 볼린저 밴드 파라미터
+    pub bollinger: BollingerBandsParams,
+    /// ATR 파라미터
+    pub atr: AtrParams,
+    /// SuperTrend 파라미터
+    pub supertrend: SuperTrendParams,
+}
+
+impl IndicatorStateConfig {
+    /// 파라미터 식별 키.
+    ///
+    /// 저장된 상태의 키가 현재 설정과 다르면 상태를 버리고 전체 이력으로 다시 워밍업합니다.
+    pub fn key(&self) -> String {
+        format!(
+            "rsi{}_macd{}-{}-{}_bb{}x{}_atr{}_st{}x{}",
+            self.rsi.period,
+            self.macd.fast_period,
+            self.macd.slow_period,
+            self.macd.signal_period,
+            self.bollinger.period,
+            self.bollinger.std_dev_multiplier.normalize(),
+            self.atr.period,
+            self.supertrend.atr_period,
+            self.supertrend.multiplier.normalize(),
+        )
+    }
+}
+
+/// 종목/타임프레임별 증분 지표 상태.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndicatorState {
+    ticker: String,
+    timeframe: Timeframe,
+    config_key: String,
+    bars: u64,
+    last_open_time: Option<DateTime<Utc>>,
+    #[serde(with = "rust_decimal::serde::str")]
+    last_close: Decimal,
+    rsi: IncrementalRsi,
+    macd: IncrementalMacd,
+    bollinger: IncrementalBollinger,
+    atr: IncrementalAtr,
+    supertrend: IncrementalSuperTrend,
+    /// 마지막 캔들 반영 직전 상태 (같은 `open_time`의 갱신이 오면 되돌린 뒤 다시 반영)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pending: Option<Box<PendingBar>>,
+}
+
+/// 아직 마감되지 않았을 수 있는 마지막 캔들의 반영 직전 상태.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PendingBar {
+    #[serde(with = "rust_decimal::serde::str")]
+    high: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    low: Decimal,
+    rsi: IncrementalRsi,
+    macd: IncrementalMacd,
+    bollinger: IncrementalBollinger,
+    atr: IncrementalAtr,
+    supertrend: IncrementalSuperTrend,
+}
+
+impl IndicatorState {
+    /// 빈 상태 생성.
+    pub fn new(
+        ticker: impl Into<String>,
+        timeframe: Timeframe,
+        config: &IndicatorStateConfig,
+    ) -> Self {
+        Self {
+            ticker: ticker.into(),
+            timeframe,
+            config_key: config.key(),
+            bars: 0,
+            last_open_time: None,
+            last_close: Decimal::ZERO,
+            rsi: IncrementalRsi::new(config.rsi),
+            macd: IncrementalMacd::new(config.macd),
+            bollinger: IncrementalBollinger::new(config.bollinger),
+            atr: IncrementalAtr::new(config.atr),
+            supertrend: IncrementalSuperTrend::new(config.supertrend),
+            pending: None,
+        }
+    }
+
+    /// 종목 티커.
+    pub fn ticker(&self) -> &str {
+        &self.ticker
+    }
+
+    /// 타임프레임.
+    pub fn timeframe(&self) -> Timeframe {
+        self.timeframe
+    }
+
+    /// 상태 생성에 사용된 파라미터 키.
+    pub fn config_key(&self) -> &str {
+        &self.config_key
+    }
+
+    /// 반영된 캔들 수.
+    pub fn bars(&self) -> u64 {
+        self.bars
+    }
+
+    /// 마지막으로 반영된 캔들 시작 시각.
+    pub fn last_open_time(&self) -> Option<DateTime<Utc>> {
+        self.last_open_time
+    }
+
+    /// 캔들 반영.
+    ///
+    /// 실시간 스트림은 진행 중인 캔들을 같은 `open_time`으로 여러 번 보내므로,
+    /// 마지막 캔들과 `open_time`이 같으면 그 캔들 반영 직전 상태로 되돌린 뒤
+    /// 새 값으로 다시 반영합니다. 마감 후 다음 캔들이 오면 마지막 값이 확정됩니다.
+    ///
+    /// 종목/타임프레임이 다르거나, 이전 `open_time`이거나, 마지막 캔들과 값이
+    /// 같으면 상태를 바꾸지 않고 None을 반환합니다.
+    pub fn update(&mut self, kline: &Kline) -> Option<IndicatorSnapshot> {
+        if kline.ticker != self.ticker || kline.timeframe != self.timeframe {
+            return None;
+        }
+        match self.last_open_time {
+            Some(last) if kline.open_time < last => return None,
+            Some(last) if kline.open_time == last => {
+                let pending = self.pending.as_deref()?;
+                if pending.high == kline.high
+                    && pending.low == kline.low
+                    && self.last_close == kline.close
+                {
+                    return None;
+                }
+                self.rsi = pending.rsi.clone();
+                self.macd = pending.macd.clone();
+                self.bollinger = pending.bollinger.clone();
+                self.atr = pending.atr.clone();
+                self.supertrend = pending.supertrend.clone();
+                self.bars -= 1;
+            }
+            _ => {}
+        }
+
+        self.pending = Some(Box::new(PendingBar {
+            high: kline.high,
+            low: kline.low,
+            rsi: self.rsi.clone(),
+            macd: self.macd.clone(),
+            bollinger: self.bollinger.clone(),
+            atr: self.atr.clone(),
+            supertrend: self.supertrend.clone(),
+        }));
+        self.rsi.update(kline.close);
+        self.macd.update(kline.close);
+        self.bollinger.update(kline.close);
+        self.atr.update(kline.high, kline.low, kline.close);
+        self.supertrend.update(kline.high, kline.low, kline.close);
+
+        self.bars += 1;
+        self.last_open_time = Some(kline.open_time);
+        self.last_close = kline.close;
+        self.snapshot()
+    }
+
+    /// 캔들 목록을 순서대로 반영하고 반영된 캔들 수를 반환합니다.
+    pub fn update_all<'a>(&mut self, klines: impl IntoIterator<Item = &'a Kline>) -> usize {
+        klines
+            .into_iter()
+            .filter(|kline| self.update(kline).is_some())
+            .count()
+    }
+
+    /// 현재 지표 스냅샷 (캔들이 하나도 없으면 None).
+    pub fn snapshot(&self) -> Option<IndicatorSnapshot> {
+        let open_time = self.last_open_time?;
+        let macd = self.macd.value();
+        let bollinger = self.bollinger.value();
+        let supertrend = self.supertrend.value();
+
+        Some(IndicatorSnapshot {
+            ticker: self.ticker.clone(),
+            timeframe: self.timeframe,
+            open_time,
+            close: self.last_close,
+            bars: self.bars,
+            rsi: self.rsi.value(),
+            macd: macd.and_then(|m| m.macd),
+            macd_signal: macd.and_then(|m| m.signal),
+            macd_histogram: macd.and_then(|m| m.histogram),
+            bb_upper: bollinger.and_then(|b| b.upper),
+            bb_middle: bollinger.and_then(|b| b.middle),
+            bb_lower: bollinger.and_then(|b| b.lower),
+            bb_percent_b: bollinger.and_then(|b| b.percent_b),
+            atr: self.atr.value(),
+            supertrend: supertrend.and_then(|s| s.value),
+            supertrend_uptrend: supertrend.map_or(true, |s| s.is_uptrend),
+        })
+    }
+}
+
+// ==================== 저장소 연동 ====================
+
+impl IndicatorState {
+    /// 저장된 상태 복원.
+    ///
+    /// 파라미터 키가 현재 설정과 다르거나 역직렬화에 실패하면 None을 반환하며,
+    /// 호출자는 전체 이력으로 다시 워밍업해야 합니다.
+    pub fn from_stored(
+        stored: &StoredIndicatorState,
+        config: &IndicatorStateConfig,
+    ) -> Option<Self> {
+        if stored.config_key != config.key() {
+            return None;
+        }
+        match serde_json::from_value::<Self>(stored.state.clone()) {
+            Ok(state) if state.config_key == stored.config_key => Some(state),
+            Ok(_) => None,
+            Err(e) => {
+                warn!(
+                    ticker = %stored.ticker,
+                    timeframe = %stored.timeframe,
+                    error = %e,
+                    "지표 상태 역직렬화 실패, 재계산 필요"
+                );
+                None
+            }
+        }
+    }
+
+    /// 상태 저장.
+    pub async fn save(&self, repo: &IndicatorStateRepository) -> trader_data::Result<()> {
+        let state = serde_json::to_value(self)?;
+        repo.save(
+            &self.ticker,
+            &self.timeframe.to_string(),
+            &self.config_key,
+            self.bars as i64,
+            self.last_open_time,
+            &state,
+        )
+        .await
+    }
+}
+
+// ==================== 공유 캐시 ====================
+
+type StateKey = (String, Timeframe);
+
+/// 캐시 항목 (마지막 저장 이후 변경 여부 포함).
+#[derive(Debug)]
+struct CachedState {
+    state: IndicatorState,
+    dirty: bool,
+}
+
+/// 종목/타임프레임별 증분 지표 상태 캐시.
+///
+/// 수집기와 실시간 `StrategyEngine`이 공유하며, `IndicatorUpdater`로 엔진에 주입됩니다.
+/// 저장소에서 복원한 뒤 변경된 상태만 주기적으로 다시 저장합니다.
+#[derive(Debug, Default)]
+pub struct IndicatorStateCache {
+    config: IndicatorStateConfig,
+    states: Mutex<HashMap<StateKey, CachedState>>,
+}
+
+impl IndicatorStateCache {
+    /// 새 캐시 생성.
+    pub fn new(config: IndicatorStateConfig) -> Self {
+        Self {
+            config,
+            states: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 지표 파라미터.
+    pub fn config(&self) -> &IndicatorStateConfig {
+        &self.config
+    }
+
+    /// 저장소에서 복원한 상태 등록.
+    ///
+    /// 파라미터 키가 현재 설정과 다르면 등록하지 않고 false를 반환합니다.
+    pub fn insert(&self, state: IndicatorState) -> bool {
+        if state.config_key != self.config.key() {
+            return false;
+        }
+        let key = (state.ticker.clone(), state.timeframe);
+        self.lock().insert(
+            key,
+            CachedState {
+                state,
+                dirty: false,
+            },
+        );
+        true
+    }
+
+    /// 상태 복사본 조회.
+    pub fn get(&self, ticker: &str, timeframe: Timeframe) -> Option<IndicatorState> {
+        self.lock()
+            .get(&(ticker.to_string(), timeframe))
+            .map(|cached| cached.state.clone())
+    }
+
+    /// 보관 중인 종목/타임프레임 수.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// 비어 있는지 여부.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// 전체 스냅샷 (티커, 타임프레임 순).
+    pub fn snapshots(&self) -> Vec<IndicatorSnapshot> {
+        let mut snapshots: Vec<_> = self
+            .lock()
+            .values()
+            .filter_map(|cached| cached.state.snapshot())
+            .collect();
+        snapshots.sort_by(|a, b| {
+            a.ticker
+                .cmp(&b.ticker)
+                .then_with(|| a.timeframe.as_secs().cmp(&b.timeframe.as_secs()))
+        });
+        snapshots
+    }
+
+    /// 마지막 저장 이후 변경된 상태를 꺼내고 변경 표시를 지웁니다.
+    pub fn take_dirty(&self) -> Vec<IndicatorState> {
+        self.lock()
+            .values_mut()
+            .filter(|cached| cached.dirty)
+            .map(|cached| {
+                cached.dirty = false;
+                cached.state.clone()
+            })
+            .collect()
+    }
+
+    /// 저장소의 상태 복원 (타임프레임 필터 선택).
+    ///
+    /// # 반환
+    ///
+    /// 복원된 상태 수 (파라미터가 바뀐 상태는 건너뜀)
+    pub async fn restore(
+        &self,
+        repo: &IndicatorStateRepository,
+        timeframe: Option<Timeframe>,
+    ) -> trader_data::Result<usize> {
+        let timeframe = timeframe.map(|tf| tf.to_string());
+        let stored = repo.load_all(timeframe.as_deref()).await?;
+        Ok(stored
+            .iter()
+            .filter_map(|s| IndicatorState::from_stored(s, &self.config))
+            .filter(|state| self.insert(state.clone()))
+            .count())
+    }
+
+    /// 변경된 상태 저장.
+    ///
+    /// 저장에 실패한 상태는 다시 변경 표시하여 다음 호출에서 재시도합니다.
+    ///
+    /// # 반환
+    ///
+    /// 저장된 상태 수
+    pub async fn persist(&self, repo: &IndicatorStateRepository) -> trader_data::Result<usize> {
+        let mut saved = 0;
+        let mut last_error = None;
+        for state in self.take_dirty() {
+            match state.save(repo).await {
+                Ok(()) => saved += 1,
+                Err(e) => {
+                    if let Some(cached) = self
+                        .lock()
+                        .get_mut(&(state.ticker.clone(), state.timeframe))
+                    {
+                        cached.dirty = true;
+                    }
+                    last_error = Some(e);
+                }
+            }
+        }
+        match last_error {
+            Some(e) if saved == 0 => Err(e),
+            _ => Ok(saved),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<StateKey, CachedState>> {
+        // 갱신 중 패닉이 나도 상태 자체는 일관적이므로 poison을 무시
+        self.states
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl IndicatorUpdater for IndicatorStateCache {
+    fn on_kline(&self, kline: &Kline) -> Option<IndicatorSnapshot> {
+        let mut states = self.lock();
+        let cached = states
+            .entry((kline.ticker.clone(), kline.timeframe))
+            .or_insert_with(|| CachedState {
+                state: IndicatorState::new(&kline.ticker, kline.timeframe, &self.config),
+                dirty: false,
+            });
+        let snapshot = cached.state.update(kline)?;
+        cached.dirty = true;
+        Some(snapshot)
+    }
+
+    fn snapshot(&self, ticker: &str, timeframe: Timeframe) -> Option<IndicatorSnapshot> {
+        self.lock()
+            .get(&(ticker.to_string(), timeframe))
+            .and_then(|cached| cached.state.snapshot())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+    use crate::indicators::IndicatorEngine;
+
+    /// 결정적 의사 난수 워크로 일봉 생성 (상승/하락 구간 포함).
+    fn sample_klines(count: usize) -> Vec<Kline> {
+        let start = Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap();
+        let mut seed: u64 = 42;
+        let mut close = dec!(50000);
+        (0..count)
+            .map(|i| {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let step = Decimal::from((seed >> 33) % 2001) - dec!(1000);
+                let drift = if (i / 40) % 2 == 0 {
+                    dec!(150)
+                } else {
+                    dec!(-150)
+                };
+                let open = close;
+                close = (close + step + drift).max(dec!(1000));
+                let high = open.max(close) + Decimal::from((seed >> 20) % 500);
+                let low = open.min(close) - Decimal::from((seed >> 10) % 500);
+                let open_time = start + Duration::days(i as i64);
+                Kline::new(
+                    "005930".to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    open,
+                    high,
+                    low,
+                    close,
+                    Decimal::from(1000 + i as i64),
+                    open_time + Duration::days(1),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_incremental_matches_full_recompute() {
+        let klines = sample_klines(200);
+        let config = IndicatorStateConfig::default();
+        let mut state = IndicatorState::new("005930", Timeframe::D1, &config);
+        let snapshots: Vec<_> = klines.iter().map(|k| state.update(k).unwrap()).collect();
+
+        let high: Vec<_> = klines.iter().map(|k| k.high).collect();
+        let low: Vec<_> = klines.iter().map(|k| k.low).collect();
+        let close: Vec<_> = klines.iter().map(|k| k.close).collect();
+        let engine = IndicatorEngine::new();
+        let rsi = engine.rsi(&close, config.rsi).unwrap();
+        let macd = engine.macd(&close, config.macd).unwrap();
+        let bollinger = engine.bollinger_bands(&close, config.bollinger).unwrap();
+        let atr = engine.atr(&high, &low, &close, config.atr).unwrap();
+        let supertrend = engine
+            .supertrend(&high, &low, &close, config.supertrend)
+            .unwrap();
+
+        for (i, snapshot) in snapshots.iter().enumerate() {
+            assert_eq!(snapshot.bars, i as u64 + 1);
+            assert_eq!(snapshot.rsi, rsi[i], "RSI mismatch at {i}");
+            assert_eq!(snapshot.macd, macd[i].macd, "MACD mismatch at {i}");
+            assert_eq!(
+                snapshot.macd_signal, macd[i].signal,
+                "signal mismatch at {i}"
+            );
+            assert_eq!(snapshot.macd_histogram, macd[i].histogram);
+            assert_eq!(snapshot.bb_upper, bollinger[i].upper, "BB mismatch at {i}");
+            assert_eq!(snapshot.bb_middle, bollinger[i].middle);
+            assert_eq!(snapshot.bb_lower, bollinger[i].lower);
+            assert_eq!(snapshot.bb_percent_b, bollinger[i].percent_b);
+            assert_eq!(snapshot.atr, atr[i], "ATR mismatch at {i}");
+            assert_eq!(
+                snapshot.supertrend, supertrend[i].value,
+                "ST mismatch at {i}"
+            );
+            assert_eq!(snapshot.supertrend_uptrend, supertrend[i].is_uptrend);
+        }
+
+        let last = snapshots.last().unwrap();
+        assert!(last.rsi.is_some() && last.macd_signal.is_some() && last.supertrend.is_some());
+    }
+
+    #[test]
+    fn test_restored_state_continues_identically() {
+        let klines = sample_klines(120);
+        let config = IndicatorStateConfig::default();
+
+        let mut full = IndicatorState::new("005930", Timeframe::D1, &config);
+        full.update_all(&klines);
+
+        // 중간까지 반영한 상태를 직렬화/복원한 뒤 나머지를 이어서 반영
+        let mut partial = IndicatorState::new("005930", Timeframe::D1, &config);
+        partial.update_all(&klines[..70]);
+        let json = serde_json::to_value(&partial).unwrap();
+        let mut restored: IndicatorState = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.update_all(&klines[60..]), 50);
+
+        assert_eq!(restored.snapshot(), full.snapshot());
+    }
+
+    #[test]
+    fn test_stale_and_foreign_klines_are_ignored() {
+        let klines = sample_klines(30);
+        let mut state =
+            IndicatorState::new("005930", Timeframe::D1, &IndicatorStateConfig::default());
+        state.update_all(&klines);
+        let before = state.snapshot();
+
+        assert!(state.update(&klines[29]).is_none());
+        assert!(state.update(&klines[10]).is_none());
+        let mut other = klines[0].clone();
+        other.ticker = "000660".to_string();
+        other.open_time = klines[29].open_time + Duration::days(1);
+        assert!(state.update(&other).is_none());
+        assert_eq!(state.snapshot(), before);
+        assert_eq!(state.bars(), 30);
+    }
+
+    #[test]
+    fn test_cache_rejects_state_with_other_params() {
+        let cache = IndicatorStateCache::default();
+        let klines = sample_klines(40);
+        for kline in &klines {
+            cache.on_kline(kline);
+        }
+        assert_eq!(cache.len(), 1);
+        let snapshot = cache.snapshot("005930", Timeframe::D1).unwrap();
+        assert_eq!(snapshot.bars, 40);
+        assert_eq!(cache.snapshots(), vec![snapshot]);
+
+        // 갱신된 상태만 저장 대상
+        assert_eq!(cache.take_dirty().len(), 1);
+        assert!(cache.take_dirty().is_empty());
+
+        let mut config = IndicatorStateConfig::default();
+        config.rsi.period = 7;
+        assert_ne!(config.key(), IndicatorStateConfig::default().key());
+        let foreign = IndicatorState::new("000660", Timeframe::D1, &config);
+        assert!(!cache.insert(foreign));
+        assert!(cache.insert(cache.get("005930", Timeframe::D1).unwrap()));
+        assert_eq!(cache.len(), 1);
+
+        // 다른 파라미터로 저장된 상태는 복원하지 않음
+        let stored = StoredIndicatorState {
+            ticker: "005930".to_string(),
+            timeframe: "1d".to_string(),
+            config_key: config.key(),
+            bars: 0,
+            last_open_time: None,
+            state: serde_json::to_value(IndicatorState::new("005930", Timeframe::D1, &config))
+                .unwrap(),
+            updated_at: Utc::now(),
+        };
+        assert!(IndicatorState::from_stored(&stored, &IndicatorStateConfig::default()).is_none());
+        assert!(IndicatorState::from_stored(&stored, &config).is_some());
+    }
+
+    #[test]
+    fn test_partial_bar_is_replaced_until_next_bar() {
+        let klines = sample_klines(60);
+        let config = IndicatorStateConfig::default();
+        let mut full = IndicatorState::new("005930", Timeframe::D1, &config);
+        full.update_all(&klines);
+
+        // 마지막 두 캔들은 진행 중 값이 먼저 들어온 뒤 확정값으로 교체됨
+        let mut live = IndicatorState::new("005930", Timeframe::D1, &config);
+        live.update_all(&klines[..58]);
+        for kline in &klines[58..] {
+            let mut partial = kline.clone();
+            partial.close += dec!(7);
+            partial.high += dec!(7);
+            assert!(live.update(&partial).is_some());
+            assert!(live.update(kline).is_some());
+            assert!(live.update(kline).is_none());
+        }
+
+        assert_eq!(live.bars(), 60);
+        assert_eq!(live.snapshot(), full.snapshot());
+
+        // 진행 중 상태도 직렬화 후 이어서 교체 가능
+        let json = serde_json::to_value(&live).unwrap();
+        let mut restored: IndicatorState = serde_json::from_value(json).unwrap();
+        let mut revised = klines[59].clone();
+        revised.close += dec!(10);
+        revised.high = revised.high.max(revised.close);
+        assert!(restored.update(&revised).is_some());
+        assert!(restored.update(&klines[59]).is_some());
+        assert_eq!(restored.snapshot(), full.snapshot());
+    }
+}
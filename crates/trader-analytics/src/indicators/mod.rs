@@ -30,6 +30,9 @@
 //! ## 패턴 인식 (Pattern Recognition)
 //! - **Candle Patterns**: 캔들스틱 패턴 감지 (망치형, 장악형 등)
 //!
+//! ## 증분 계산 (Incremental)
+//! - **IndicatorStateCache**: RSI/MACD/볼린저/ATR/SuperTrend 롤링 상태를 캔들 단위로 갱신
+//!
 //! # 사용 예시
 //!
 //! ```ignore
@@ -46,6 +49,7 @@
 
 pub mod candle_patterns;
 pub mod hma;
+pub mod incremental;
 pub mod momentum;
 pub mod structural;
 pub mod supertrend;
@@ -58,11 +62,17 @@ pub use candle_patterns::{
     CandlePatternIndicator, CandlePatternParams, CandlePatternResult, CandlePatternType,
 };
 pub use hma::{HmaIndicator, HmaParams};
+pub use incremental::{
+    IncrementalAtr, IncrementalBollinger, IncrementalEma, IncrementalMacd, IncrementalRsi,
+    IncrementalSuperTrend, IncrementalWilder, IndicatorState, IndicatorStateCache,
+    IndicatorStateConfig,
+};
 pub use momentum::{MomentumCalculator, RsiParams, StochasticParams, StochasticResult};
 use rust_decimal::Decimal;
 pub use structural::StructuralFeatures;
 pub use supertrend::{SuperTrendIndicator, SuperTrendParams, SuperTrendResult};
 use thiserror::Error;
+use trader_core::domain::{BollingerValue, IndicatorCalculator, MacdValue};
 pub use trend::{EmaParams, MacdParams, MacdResult, SmaParams, TrendIndicators};
 pub use volatility::{
     AtrParams, BollingerBandsParams, BollingerBandsResult, KeltnerChannelParams,
@@ -487,6 +497,80 @@ impl IndicatorEngine {
     }
 }
 
+/// 마지막 봉 기준 값만 필요한 소비자(스크립트 전략 등)용 구현.
+impl IndicatorCalculator for IndicatorEngine {
+    fn sma(&self, closes: &[Decimal], period: usize) -> Option<Decimal> {
+        IndicatorEngine::sma(self, closes, SmaParams { period })
+            .ok()?
+            .last()
+            .copied()
+            .flatten()
+    }
+
+    fn ema(&self, closes: &[Decimal], period: usize) -> Option<Decimal> {
+        IndicatorEngine::ema(self, closes, EmaParams { period })
+            .ok()?
+            .last()
+            .copied()
+            .flatten()
+    }
+
+    fn rsi(&self, closes: &[Decimal], period: usize) -> Option<Decimal> {
+        IndicatorEngine::rsi(self, closes, RsiParams { period })
+            .ok()?
+            .last()
+            .copied()
+            .flatten()
+    }
+
+    fn atr(
+        &self,
+        highs: &[Decimal],
+        lows: &[Decimal],
+        closes: &[Decimal],
+        period: usize,
+    ) -> Option<Decimal> {
+        IndicatorEngine::atr(self, highs, lows, closes, AtrParams { period })
+            .ok()?
+            .last()
+            .copied()
+            .flatten()
+    }
+
+    fn bollinger(&self, closes: &[Decimal], period: usize, k: Decimal) -> Option<BollingerValue> {
+        let params = BollingerBandsParams {
+            period,
+            std_dev_multiplier: k,
+        };
+        let last = *self.bollinger_bands(closes, params).ok()?.last()?;
+        Some(BollingerValue {
+            upper: last.upper?,
+            middle: last.middle?,
+            lower: last.lower?,
+        })
+    }
+
+    fn macd(
+        &self,
+        closes: &[Decimal],
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    ) -> Option<MacdValue> {
+        let params = MacdParams {
+            fast_period: fast,
+            slow_period: slow,
+            signal_period: signal,
+        };
+        let last = *IndicatorEngine::macd(self, closes, params).ok()?.last()?;
+        Some(MacdValue {
+            macd: last.macd?,
+            signal: last.signal?,
+            histogram: last.histogram?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rust_decimal_macros::dec;
@@ -635,6 +719,23 @@ mod tests {
         assert!(result[result.len() - 1].value.is_some());
     }
 
+    #[test]
+    fn test_indicator_calculator_returns_latest_values() {
+        let engine = IndicatorEngine::new();
+        let prices = sample_prices();
+
+        let sma = engine.sma(&prices, SmaParams { period: 5 }).unwrap();
+        assert_eq!(
+            IndicatorCalculator::sma(&engine, &prices, 5),
+            *sma.last().unwrap()
+        );
+        assert!(IndicatorCalculator::rsi(&engine, &prices, 14).is_some());
+        assert!(IndicatorCalculator::bollinger(&engine, &prices, 5, dec!(2)).is_some());
+        // 데이터 부족 시 None
+        assert!(IndicatorCalculator::sma(&engine, &prices, 50).is_none());
+        assert!(IndicatorCalculator::macd(&engine, &prices, 12, 26, 9).is_none());
+    }
+
     #[test]
     fn test_candle_patterns_detection() {
         let engine = IndicatorEngine::new();
@@ -525,7 +525,7 @@ impl VolatilityIndicators {
     /// Decimal 제곱근 계산 (Newton-Raphson 방법).
     ///
     /// Decimal 타입은 기본 제곱근 함수가 없으므로 직접 구현합니다.
-    fn sqrt_decimal(&self, value: Decimal) -> Decimal {
+    pub(crate) fn sqrt_decimal(&self, value: Decimal) -> Decimal {
         if value <= Decimal::ZERO {
             return Decimal::ZERO;
         }
@@ -6,6 +6,8 @@
 //! - 백테스팅 엔진
 //! - ML/AI 모델 추론 (ONNX) - `ml` feature 필요
 //! - 기술적 지표
+//! - 페어 트레이딩 공적분 분석
+//! - 옵션 가격 결정 (Black-Scholes, 그릭스, 내재변동성)
 //!
 //! # Re-exports
 //!
@@ -26,6 +28,8 @@ pub mod market_regime_calculator;
 #[cfg(feature = "ml")]
 pub mod ml;
 pub mod multi_timeframe_helpers;
+pub mod options;
+pub mod pairs;
 pub mod performance;
 pub mod portfolio;
 pub mod route_state_calculator;
@@ -43,8 +47,9 @@ pub use analytics_provider_impl::AnalyticsProviderImpl;
 // Backtest 모듈 re-exports (backtest feature 필요)
 #[cfg(feature = "backtest")]
 pub use backtest::{
-    BacktestConfig, BacktestEngine, BacktestError, BacktestReport, BacktestResult, CandleProcessor,
-    PartitionedSignals, ProcessCandleContext, MIN_CANDLES_FOR_INDICATORS,
+    BacktestConfig, BacktestEngine, BacktestError, BacktestFxReport, BacktestReport,
+    BacktestResult, CandleProcessor, PartitionedSignals, ProcessCandleContext,
+    MIN_CANDLES_FOR_INDICATORS,
 };
 // Correlation re-export
 pub use correlation::{
@@ -63,6 +68,10 @@ pub use indicators::{
     IndicatorEngine,
     IndicatorError,
     IndicatorResult,
+    // 증분 지표 상태
+    IndicatorState,
+    IndicatorStateCache,
+    IndicatorStateConfig,
     // Keltner Channel
     KeltnerChannelParams,
     KeltnerChannelResult,
@@ -140,6 +149,12 @@ pub use multi_timeframe_helpers::{
     analyze_trend, combine_signals, default_weights, detect_divergence, CombinedSignal,
     DivergenceType, SignalDirection, TrendAnalysis, TrendDirection,
 };
+// Options re-export
+pub use options::{BlackScholes, SyntheticOptionPricing};
+// Pairs Trading re-export
+pub use pairs::{
+    evaluate_pair, scan_cointegrated_pairs, PairCandidate, PairScanConfig, PriceSeries,
+};
 pub use performance::{
     metrics::{
         PerformanceMetrics, RollingMetrics, RoundTrip, DEFAULT_RISK_FREE_RATE,
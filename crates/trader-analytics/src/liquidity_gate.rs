@@ -60,7 +60,7 @@ impl LiquidityGate {
                 relaxed_min: dec!(5_000_000),        // $5M
             },
             // 선물/파생상품 - 높은 레버리지로 유동성 중요
-            MarketType::Futures => Self {
+            MarketType::Futures | MarketType::Options => Self {
                 market_type,
                 min_volume_amount: dec!(50_000_000), // $50M
                 relaxed_min: dec!(25_000_000),       // $25M
@@ -0,0 +1,496 @@
+//! 옵션 가격 결정 모듈.
+//!
+//! Black-Scholes(-Merton) 모형으로 유럽형 옵션 가격과 그릭스를 계산합니다.
+//! 옵션 계약/시세 타입은 `trader_core`에 있으며, 이 모듈에서 함께 재노출합니다.
+//!
+//! # 주요 기능
+//!
+//! - **이론가**: 배당수익률을 반영한 Black-Scholes-Merton 가격
+//! - **그릭스**: 델타, 감마, 세타(1일), 베가(1%p), 로(1%p)
+//! - **내재변동성**: 뉴턴-랩슨 + 이분법 역산
+//! - **합성 옵션 가격**: 내재변동성 입력으로 백테스트용 옵션 가격 생성
+//!
+//! 미국형 옵션은 조기행사 프리미엄을 무시하고 유럽형으로 근사합니다.
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::options::{BlackScholes, OptionRight};
+//!
+//! let bs = BlackScholes::new(100.0, 105.0, 30.0 / 365.0, 0.2).with_rate(0.03);
+//! let premium = bs.price(OptionRight::Call);
+//! let greeks = bs.greeks(OptionRight::Call);
+//! println!("콜 {:.2}, 델타 {:.3}", premium, greeks.delta);
+//! ```
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use serde::{Deserialize, Serialize};
+pub use trader_core::{
+    ExerciseStyle, OptionChain, OptionContract, OptionExpiryRule, OptionGreeks, OptionQuote,
+    OptionRight,
+};
+
+/// 내재변동성 탐색 하한.
+const MIN_VOLATILITY: f64 = 1e-4;
+/// 내재변동성 탐색 상한.
+const MAX_VOLATILITY: f64 = 5.0;
+
+/// 표준정규분포 확률밀도함수.
+fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// 표준정규분포 누적분포함수.
+///
+/// 상보 오차함수 근사 (Numerical Recipes `erfcc`, 상대오차 < 1.2e-7).
+fn norm_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let erfc = t
+        * (-z * z - 1.265_512_23
+            + t * (1.000_023_68
+                + t * (0.374_091_96
+                    + t * (0.096_784_18
+                        + t * (-0.186_288_06
+                            + t * (0.278_868_07
+                                + t * (-1.135_203_98
+                                    + t * (1.488_515_87
+                                        + t * (-0.822_152_23 + t * 0.170_872_77)))))))))
+            .exp();
+    if x >= 0.0 {
+        1.0 - 0.5 * erfc
+    } else {
+        0.5 * erfc
+    }
+}
+
+/// Black-Scholes-Merton 모형 입력.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlackScholes {
+    /// 기초자산 가격
+    pub spot: f64,
+    /// 행사가
+    pub strike: f64,
+    /// 만기까지 기간 (연)
+    pub time: f64,
+    /// 변동성 (연율, 0.2 = 20%)
+    pub volatility: f64,
+    /// 무위험 이자율 (연율, 연속복리)
+    pub rate: f64,
+    /// 배당수익률 (연율, 연속복리)
+    pub dividend_yield: f64,
+}
+
+impl BlackScholes {
+    /// 새 모형 입력 생성 (금리/배당 0).
+    pub fn new(spot: f64, strike: f64, time: f64, volatility: f64) -> Self {
+        Self {
+            spot,
+            strike,
+            time,
+            volatility,
+            rate: 0.0,
+            dividend_yield: 0.0,
+        }
+    }
+
+    /// 옵션 계약 기준으로 생성.
+    pub fn for_contract(
+        contract: &OptionContract,
+        spot: Decimal,
+        now: DateTime<Utc>,
+        volatility: f64,
+    ) -> Self {
+        Self::new(
+            spot.to_f64().unwrap_or(0.0),
+            contract.strike.to_f64().unwrap_or(0.0),
+            contract.time_to_expiry(now),
+            volatility,
+        )
+    }
+
+    /// 무위험 이자율 설정.
+    pub fn with_rate(mut self, rate: f64) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// 배당수익률 설정.
+    pub fn with_dividend_yield(mut self, dividend_yield: f64) -> Self {
+        self.dividend_yield = dividend_yield;
+        self
+    }
+
+    /// 변동성 설정.
+    pub fn with_volatility(mut self, volatility: f64) -> Self {
+        self.volatility = volatility;
+        self
+    }
+
+    /// d1, d2 (만기 경과/변동성 0/가격 비정상이면 None).
+    fn d1_d2(&self) -> Option<(f64, f64)> {
+        if self.time <= 0.0 || self.volatility <= 0.0 || self.spot <= 0.0 || self.strike <= 0.0 {
+            return None;
+        }
+        let vol_sqrt_t = self.volatility * self.time.sqrt();
+        let d1 = ((self.spot / self.strike).ln()
+            + (self.rate - self.dividend_yield + 0.5 * self.volatility.powi(2)) * self.time)
+            / vol_sqrt_t;
+        Some((d1, d1 - vol_sqrt_t))
+    }
+
+    /// 선도가격 기준 내재가치의 현재가치 (만기/변동성 0일 때의 가격).
+    fn discounted_intrinsic(&self, right: OptionRight) -> f64 {
+        let time = self.time.max(0.0);
+        let forward = self.spot * (-self.dividend_yield * time).exp();
+        let strike = self.strike * (-self.rate * time).exp();
+        match right {
+            OptionRight::Call => (forward - strike).max(0.0),
+            OptionRight::Put => (strike - forward).max(0.0),
+        }
+    }
+
+    /// 이론가 (기초자산 1단위 기준).
+    pub fn price(&self, right: OptionRight) -> f64 {
+        let Some((d1, d2)) = self.d1_d2() else {
+            return self.discounted_intrinsic(right);
+        };
+        let spot_discount = (-self.dividend_yield * self.time).exp();
+        let strike_discount = (-self.rate * self.time).exp();
+        match right {
+            OptionRight::Call => {
+                self.spot * spot_discount * norm_cdf(d1)
+                    - self.strike * strike_discount * norm_cdf(d2)
+            }
+            OptionRight::Put => {
+                self.strike * strike_discount * norm_cdf(-d2)
+                    - self.spot * spot_discount * norm_cdf(-d1)
+            }
+        }
+    }
+
+    /// 그릭스 (세타 1일, 베가/로 1%p 기준).
+    pub fn greeks(&self, right: OptionRight) -> OptionGreeks {
+        let Some((d1, d2)) = self.d1_d2() else {
+            // 만기: 델타만 내가격 여부로 결정
+            let itm = match right {
+                OptionRight::Call => self.spot > self.strike,
+                OptionRight::Put => self.spot < self.strike,
+            };
+            let delta = match (right, itm) {
+                (OptionRight::Call, true) => 1.0,
+                (OptionRight::Put, true) => -1.0,
+                _ => 0.0,
+            };
+            return OptionGreeks {
+                delta,
+                ..OptionGreeks::default()
+            };
+        };
+
+        let sqrt_t = self.time.sqrt();
+        let spot_discount = (-self.dividend_yield * self.time).exp();
+        let strike_discount = (-self.rate * self.time).exp();
+        let pdf_d1 = norm_pdf(d1);
+
+        let gamma = spot_discount * pdf_d1 / (self.spot * self.volatility * sqrt_t);
+        let vega = self.spot * spot_discount * pdf_d1 * sqrt_t;
+        let decay = -self.spot * spot_discount * pdf_d1 * self.volatility / (2.0 * sqrt_t);
+
+        let (delta, theta, rho) = match right {
+            OptionRight::Call => (
+                spot_discount * norm_cdf(d1),
+                decay - self.rate * self.strike * strike_discount * norm_cdf(d2)
+                    + self.dividend_yield * self.spot * spot_discount * norm_cdf(d1),
+                self.strike * self.time * strike_discount * norm_cdf(d2),
+            ),
+            OptionRight::Put => (
+                spot_discount * (norm_cdf(d1) - 1.0),
+                decay + self.rate * self.strike * strike_discount * norm_cdf(-d2)
+                    - self.dividend_yield * self.spot * spot_discount * norm_cdf(-d1),
+                -self.strike * self.time * strike_discount * norm_cdf(-d2),
+            ),
+        };
+
+        OptionGreeks {
+            delta,
+            gamma,
+            theta: theta / 365.0,
+            vega: vega / 100.0,
+            rho: rho / 100.0,
+        }
+    }
+
+    /// 시장가로부터 내재변동성 역산.
+    ///
+    /// 뉴턴-랩슨으로 수렴하지 않으면 이분법으로 탐색합니다.
+    /// 가격이 무차익 범위를 벗어나면 None.
+    pub fn implied_volatility(&self, right: OptionRight, market_price: f64) -> Option<f64> {
+        if self.time <= 0.0 || market_price <= 0.0 {
+            return None;
+        }
+        let lower = self.with_volatility(MIN_VOLATILITY).price(right);
+        let upper = self.with_volatility(MAX_VOLATILITY).price(right);
+        if market_price < lower || market_price > upper {
+            return None;
+        }
+
+        const TOLERANCE: f64 = 1e-8;
+
+        // 뉴턴-랩슨
+        let mut vol = 0.3;
+        for _ in 0..50 {
+            let model = self.with_volatility(vol);
+            let diff = model.price(right) - market_price;
+            if diff.abs() < TOLERANCE {
+                return Some(vol);
+            }
+            let vega = model.greeks(right).vega * 100.0;
+            if vega < 1e-10 {
+                break;
+            }
+            vol -= diff / vega;
+            if !(MIN_VOLATILITY..=MAX_VOLATILITY).contains(&vol) {
+                break;
+            }
+        }
+
+        // 이분법
+        let (mut low, mut high) = (MIN_VOLATILITY, MAX_VOLATILITY);
+        for _ in 0..200 {
+            let mid = 0.5 * (low + high);
+            let diff = self.with_volatility(mid).price(right) - market_price;
+            if diff.abs() < TOLERANCE {
+                return Some(mid);
+            }
+            if diff > 0.0 {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+        Some(0.5 * (low + high))
+    }
+}
+
+fn default_implied_volatility() -> f64 {
+    0.2
+}
+
+fn default_risk_free_rate() -> f64 {
+    0.03
+}
+
+/// 합성 옵션 가격 설정.
+///
+/// 옵션 시세가 없는 백테스트에서 기초자산 가격과 내재변동성 입력으로
+/// 옵션 가격을 생성합니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyntheticOptionPricing {
+    /// 기본 내재변동성 (0.2 = 20%)
+    #[serde(default = "default_implied_volatility")]
+    pub implied_volatility: f64,
+    /// 기초자산별 내재변동성
+    #[serde(default)]
+    pub volatility_overrides: HashMap<String, f64>,
+    /// 변동성 스큐 (로그 머니니스 1단위당 변동성 감소폭)
+    ///
+    /// 0.1이면 행사가가 기초자산보다 10% 낮을 때 변동성이 약 1%p 높아집니다.
+    #[serde(default)]
+    pub skew: f64,
+    /// 무위험 이자율 (연율)
+    #[serde(default = "default_risk_free_rate")]
+    pub risk_free_rate: f64,
+    /// 배당수익률 (연율)
+    #[serde(default)]
+    pub dividend_yield: f64,
+}
+
+impl Default for SyntheticOptionPricing {
+    fn default() -> Self {
+        Self {
+            implied_volatility: default_implied_volatility(),
+            volatility_overrides: HashMap::new(),
+            skew: 0.0,
+            risk_free_rate: default_risk_free_rate(),
+            dividend_yield: 0.0,
+        }
+    }
+}
+
+impl SyntheticOptionPricing {
+    /// 기본 내재변동성 설정.
+    pub fn with_implied_volatility(mut self, volatility: f64) -> Self {
+        self.implied_volatility = volatility;
+        self
+    }
+
+    /// 기초자산별 내재변동성 설정.
+    pub fn with_volatility_for(mut self, underlying: impl Into<String>, volatility: f64) -> Self {
+        self.volatility_overrides
+            .insert(underlying.into(), volatility);
+        self
+    }
+
+    /// 변동성 스큐 설정.
+    pub fn with_skew(mut self, skew: f64) -> Self {
+        self.skew = skew;
+        self
+    }
+
+    /// 무위험 이자율 설정.
+    pub fn with_risk_free_rate(mut self, rate: f64) -> Self {
+        self.risk_free_rate = rate;
+        self
+    }
+
+    /// 배당수익률 설정.
+    pub fn with_dividend_yield(mut self, dividend_yield: f64) -> Self {
+        self.dividend_yield = dividend_yield;
+        self
+    }
+
+    /// 계약에 적용할 내재변동성 (스큐 반영, 최소 1%).
+    pub fn volatility_for(&self, contract: &OptionContract, spot: Decimal) -> f64 {
+        let base = self
+            .volatility_overrides
+            .get(&contract.underlying)
+            .copied()
+            .unwrap_or(self.implied_volatility);
+        let (Some(strike), Some(spot)) = (contract.strike.to_f64(), spot.to_f64()) else {
+            return base;
+        };
+        if strike <= 0.0 || spot <= 0.0 {
+            return base;
+        }
+        (base - self.skew * (strike / spot).ln()).max(0.01)
+    }
+
+    /// 모형 입력 생성.
+    pub fn model(
+        &self,
+        contract: &OptionContract,
+        spot: Decimal,
+        now: DateTime<Utc>,
+    ) -> BlackScholes {
+        BlackScholes::for_contract(contract, spot, now, self.volatility_for(contract, spot))
+            .with_rate(self.risk_free_rate)
+            .with_dividend_yield(self.dividend_yield)
+    }
+
+    /// 합성 옵션 가격 (기초자산 1단위 기준, 만기 이후는 내재가치).
+    pub fn price(&self, contract: &OptionContract, spot: Decimal, now: DateTime<Utc>) -> Decimal {
+        if contract.is_expired(now.date_naive()) {
+            return contract.intrinsic_value(spot);
+        }
+        let premium = self.model(contract, spot, now).price(contract.right);
+        Decimal::from_f64(premium)
+            .unwrap_or(Decimal::ZERO)
+            .round_dp(4)
+    }
+
+    /// 합성 그릭스.
+    pub fn greeks(
+        &self,
+        contract: &OptionContract,
+        spot: Decimal,
+        now: DateTime<Utc>,
+    ) -> OptionGreeks {
+        self.model(contract, spot, now).greeks(contract.right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "actual={actual}, expected={expected}"
+        );
+    }
+
+    #[test]
+    fn test_price_matches_reference() {
+        // Hull 예제: S=42, K=40, r=10%, σ=20%, T=0.5 → 콜 4.76, 풋 0.81
+        let bs = BlackScholes::new(42.0, 40.0, 0.5, 0.2).with_rate(0.1);
+        assert_close(bs.price(OptionRight::Call), 4.7594, 1e-3);
+        assert_close(bs.price(OptionRight::Put), 0.8086, 1e-3);
+
+        // 풋-콜 패리티: C - P = S·e^(-qT) - K·e^(-rT)
+        let bs = bs.with_dividend_yield(0.02);
+        let parity = 42.0 * (-0.02_f64 * 0.5).exp() - 40.0 * (-0.1_f64 * 0.5).exp();
+        assert_close(
+            bs.price(OptionRight::Call) - bs.price(OptionRight::Put),
+            parity,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_greeks() {
+        let bs = BlackScholes::new(100.0, 100.0, 0.25, 0.2).with_rate(0.05);
+        let call = bs.greeks(OptionRight::Call);
+        let put = bs.greeks(OptionRight::Put);
+
+        assert_close(call.delta, 0.5695, 1e-3);
+        assert_close(call.delta - put.delta, 1.0, 1e-9);
+        assert_close(call.gamma, put.gamma, 1e-12);
+        assert!(call.theta < 0.0 && call.vega > 0.0 && call.rho > 0.0 && put.rho < 0.0);
+
+        // 베가 = 변동성 1%p 상승 시 가격 변화
+        let bumped =
+            bs.with_volatility(0.21).price(OptionRight::Call) - bs.price(OptionRight::Call);
+        assert_close(call.vega, bumped, 2e-3);
+
+        // 만기: 델타는 내가격 여부만 반영
+        let expired = BlackScholes::new(110.0, 100.0, 0.0, 0.2);
+        assert_eq!(expired.greeks(OptionRight::Call).delta, 1.0);
+        assert_eq!(expired.price(OptionRight::Call), 10.0);
+    }
+
+    #[test]
+    fn test_implied_volatility_roundtrip() {
+        for vol in [0.08, 0.25, 0.9] {
+            let bs = BlackScholes::new(100.0, 110.0, 0.4, vol).with_rate(0.03);
+            for right in [OptionRight::Call, OptionRight::Put] {
+                let implied = bs.implied_volatility(right, bs.price(right)).unwrap();
+                assert_close(implied, vol, 1e-5);
+            }
+        }
+        // 무차익 범위를 벗어난 가격
+        let bs = BlackScholes::new(100.0, 100.0, 0.5, 0.2);
+        assert!(bs.implied_volatility(OptionRight::Call, 150.0).is_none());
+    }
+
+    #[test]
+    fn test_synthetic_pricing() {
+        let expiry = NaiveDate::from_ymd_opt(2026, 11, 20).unwrap();
+        let contract = OptionContract::call("SPY", dec!(450), expiry);
+        let pricing = SyntheticOptionPricing::default()
+            .with_volatility_for("SPY", 0.15)
+            .with_skew(0.2);
+
+        let now = Utc.with_ymd_and_hms(2026, 10, 21, 0, 0, 0).unwrap();
+        let premium = pricing.price(&contract, dec!(440), now);
+        assert!(premium > Decimal::ZERO && premium < dec!(10));
+
+        // 스큐: 외가격 콜은 기본 변동성보다 낮게
+        assert!(pricing.volatility_for(&contract, dec!(440)) < 0.15);
+
+        // 만기 이후는 내재가치
+        let after = Utc.with_ymd_and_hms(2026, 11, 20, 21, 0, 0).unwrap();
+        assert_eq!(pricing.price(&contract, dec!(460), after), dec!(10));
+        assert_eq!(pricing.price(&contract, dec!(440), after), Decimal::ZERO);
+    }
+}
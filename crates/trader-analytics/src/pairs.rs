@@ -0,0 +1,301 @@
+//! 페어 트레이딩 분석 모듈.
+//!
+//! 종목 쌍의 공적분 관계를 검정하여 페어 트레이딩 후보를 발굴합니다.
+//! 통계 루틴(ADF, Engle-Granger, 칼만 필터 등)은 전략과 공유하기 위해
+//! `trader_core`에 있으며, 이 모듈에서 함께 재노출합니다.
+//!
+//! # 주요 기능
+//!
+//! - **공적분 검정**: Engle-Granger 2단계 검정 (양방향 회귀 중 강한 쪽 채택)
+//! - **헤지 비율**: OLS / 칼만 필터
+//! - **반감기 필터**: 평균회귀 속도가 너무 느리거나 빠른 페어 제외
+//! - **페어 스캔**: 섹터 내 모든 종목 쌍 검사
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::pairs::{scan_cointegrated_pairs, PairScanConfig, PriceSeries};
+//!
+//! let series = vec![
+//!     PriceSeries::new("005930", samsung_closes),
+//!     PriceSeries::new("000660", hynix_closes),
+//! ];
+//! let candidates = scan_cointegrated_pairs(&series, &PairScanConfig::default());
+//! for pair in &candidates {
+//!     println!("{}/{}: β={:.3}, HL={:.1}", pair.ticker_a, pair.ticker_b,
+//!         pair.hedge_ratio, pair.half_life);
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+pub use trader_core::{
+    adf_test, engle_granger, half_life, kalman_hedge_ratios, ols, spread_series, zscore, AdfResult,
+    CointegrationResult, CriticalValues, KalmanEstimate, KalmanHedgeRatio, LinearFit, Significance,
+};
+
+use crate::correlation::calculate_correlation;
+
+/// 종목별 종가 시계열.
+#[derive(Debug, Clone)]
+pub struct PriceSeries {
+    /// 종목 티커
+    pub ticker: String,
+    /// (시각, 종가) 목록
+    pub closes: Vec<(DateTime<Utc>, f64)>,
+}
+
+impl PriceSeries {
+    /// 새 시계열 생성.
+    pub fn new(ticker: impl Into<String>, closes: Vec<(DateTime<Utc>, f64)>) -> Self {
+        Self {
+            ticker: ticker.into(),
+            closes,
+        }
+    }
+}
+
+/// 페어 스캔 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairScanConfig {
+    /// 최소 공통 관측치 수
+    pub min_observations: usize,
+    /// 공적분 유의수준
+    pub significance: Significance,
+    /// 최소 반감기 (봉)
+    pub min_half_life: f64,
+    /// 최대 반감기 (봉)
+    pub max_half_life: f64,
+    /// ADF 최대 래그 (None이면 자동)
+    pub max_lags: Option<usize>,
+}
+
+impl Default for PairScanConfig {
+    fn default() -> Self {
+        Self {
+            min_observations: 60,
+            significance: Significance::FivePercent,
+            min_half_life: 1.0,
+            max_half_life: 30.0,
+            max_lags: None,
+        }
+    }
+}
+
+impl PairScanConfig {
+    /// 최소 관측치 수 설정.
+    pub fn with_min_observations(mut self, min_observations: usize) -> Self {
+        self.min_observations = min_observations;
+        self
+    }
+
+    /// 유의수준 설정.
+    pub fn with_significance(mut self, significance: Significance) -> Self {
+        self.significance = significance;
+        self
+    }
+
+    /// 반감기 범위 설정.
+    pub fn with_half_life_range(mut self, min: f64, max: f64) -> Self {
+        self.min_half_life = min;
+        self.max_half_life = max;
+        self
+    }
+}
+
+/// 공적분 페어 후보.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairCandidate {
+    /// 종속 종목 (스프레드의 y)
+    pub ticker_a: String,
+    /// 헤지 종목 (스프레드의 x)
+    pub ticker_b: String,
+    /// 헤지 비율 (β)
+    pub hedge_ratio: f64,
+    /// 절편 (α)
+    pub intercept: f64,
+    /// 잔차 ADF 통계량
+    pub adf_statistic: f64,
+    /// 선택한 유의수준의 임계값
+    pub critical_value: f64,
+    /// 스프레드 반감기 (봉)
+    pub half_life: f64,
+    /// 현재 스프레드 Z-Score
+    pub current_zscore: f64,
+    /// 가격 상관계수
+    pub correlation: f64,
+    /// 공통 관측치 수
+    pub observations: usize,
+}
+
+/// 두 시계열을 공통 시각으로 정렬합니다 (시간순).
+pub fn align_series(a: &PriceSeries, b: &PriceSeries) -> (Vec<f64>, Vec<f64>) {
+    let b_by_time: HashMap<DateTime<Utc>, f64> = b.closes.iter().copied().collect();
+
+    let mut pairs: Vec<(DateTime<Utc>, f64, f64)> = a
+        .closes
+        .iter()
+        .filter_map(|(time, close_a)| {
+            b_by_time
+                .get(time)
+                .map(|close_b| (*time, *close_a, *close_b))
+        })
+        .collect();
+    pairs.sort_by_key(|(time, _, _)| *time);
+
+    pairs.into_iter().map(|(_, a, b)| (a, b)).unzip()
+}
+
+/// 한 종목 쌍의 공적분 검정.
+///
+/// `a ~ b`, `b ~ a` 두 방향으로 회귀하여 ADF 통계량이 더 강한 쪽을 채택합니다.
+/// 공적분이 아니거나 반감기가 범위를 벗어나면 None.
+pub fn evaluate_pair(
+    a: &PriceSeries,
+    b: &PriceSeries,
+    config: &PairScanConfig,
+) -> Option<PairCandidate> {
+    let (prices_a, prices_b) = align_series(a, b);
+    if prices_a.len() < config.min_observations {
+        return None;
+    }
+
+    let forward = engle_granger(&prices_a, &prices_b, config.max_lags);
+    let backward = engle_granger(&prices_b, &prices_a, config.max_lags);
+
+    let (result, (y_ticker, y), (x_ticker, x)) = match (forward, backward) {
+        (Some(f), Some(r)) if r.adf.statistic < f.adf.statistic => {
+            (r, (&b.ticker, &prices_b), (&a.ticker, &prices_a))
+        }
+        (Some(f), _) => (f, (&a.ticker, &prices_a), (&b.ticker, &prices_b)),
+        (None, Some(r)) => (r, (&b.ticker, &prices_b), (&a.ticker, &prices_a)),
+        (None, None) => return None,
+    };
+
+    if !result.is_cointegrated(config.significance) {
+        return None;
+    }
+    let half_life = result.half_life?;
+    if half_life < config.min_half_life || half_life > config.max_half_life {
+        return None;
+    }
+
+    let spread = spread_series(y, x, result.hedge_ratio, result.intercept);
+
+    Some(PairCandidate {
+        ticker_a: y_ticker.clone(),
+        ticker_b: x_ticker.clone(),
+        hedge_ratio: result.hedge_ratio,
+        intercept: result.intercept,
+        adf_statistic: result.adf.statistic,
+        critical_value: result.adf.critical_values.at(config.significance),
+        half_life,
+        current_zscore: zscore(&spread).unwrap_or(0.0),
+        correlation: calculate_correlation(y, x).unwrap_or(0.0),
+        observations: y.len(),
+    })
+}
+
+/// 모든 종목 쌍을 검사하여 공적분 페어를 찾습니다.
+///
+/// 결과는 ADF 통계량 오름차순 (공적분이 강한 순)으로 정렬됩니다.
+pub fn scan_cointegrated_pairs(
+    series: &[PriceSeries],
+    config: &PairScanConfig,
+) -> Vec<PairCandidate> {
+    let mut candidates = Vec::new();
+    for (i, a) in series.iter().enumerate() {
+        for b in &series[i + 1..] {
+            if let Some(candidate) = evaluate_pair(a, b, config) {
+                candidates.push(candidate);
+            }
+        }
+    }
+
+    candidates.sort_by(|x, y| x.adf_statistic.total_cmp(&y.adf_statistic));
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self) -> f64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+        }
+    }
+
+    fn series(ticker: &str, values: &[f64], offset: i64) -> PriceSeries {
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        PriceSeries::new(
+            ticker,
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (start + Duration::days(i as i64 + offset), *v))
+                .collect(),
+        )
+    }
+
+    fn sector(rng: &mut Lcg) -> Vec<PriceSeries> {
+        let (mut base, mut noise, mut other) = (50.0, 0.0, 80.0);
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        let mut c = Vec::new();
+        for _ in 0..250 {
+            base += rng.next() * 2.0;
+            other += rng.next() * 2.0;
+            noise = 0.6 * noise + rng.next() * 2.0;
+            a.push(1.5 * base + 20.0 + noise);
+            b.push(base);
+            c.push(other);
+        }
+        vec![
+            series("AAA", &a, 0),
+            series("BBB", &b, 0),
+            series("CCC", &c, 0),
+        ]
+    }
+
+    #[test]
+    fn test_align_series_uses_common_timestamps() {
+        let a = series("A", &[1.0, 2.0, 3.0, 4.0], 0);
+        let b = series("B", &[10.0, 20.0, 30.0], 2);
+
+        let (xa, xb) = align_series(&a, &b);
+        assert_eq!(xa, vec![3.0, 4.0]);
+        assert_eq!(xb, vec![10.0, 20.0]);
+    }
+
+    #[test]
+    fn test_scan_finds_cointegrated_pair() {
+        let mut rng = Lcg(9);
+        let candidates = scan_cointegrated_pairs(&sector(&mut rng), &PairScanConfig::default());
+
+        assert_eq!(candidates.len(), 1);
+        let pair = &candidates[0];
+        let tickers = [pair.ticker_a.as_str(), pair.ticker_b.as_str()];
+        assert!(tickers.contains(&"AAA") && tickers.contains(&"BBB"));
+        assert!(pair.adf_statistic < pair.critical_value);
+        assert!(pair.half_life <= 30.0);
+        assert_eq!(pair.observations, 250);
+    }
+
+    #[test]
+    fn test_scan_respects_min_observations() {
+        let mut rng = Lcg(9);
+        let config = PairScanConfig::default().with_min_observations(500);
+        assert!(scan_cointegrated_pairs(&sector(&mut rng), &config).is_empty());
+    }
+}
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 종목별 기준 통화 손익 항목.
+ */
+export type CurrencyPnLItem = { symbol: string, 
+/**
+ * 거래 통화
+ */
+currency: string, 
+/**
+ * 자산 손익 (현지 통화 손익 × 매도일 환율)
+ */
+asset_pnl: string, 
+/**
+ * 환차손익 (매수 원가 × 매수일 대비 매도일 환율 변동)
+ */
+fx_pnl: string, total_pnl: string, };
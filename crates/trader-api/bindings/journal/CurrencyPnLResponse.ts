@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CurrencyPnLItem } from "./CurrencyPnLItem";
+
+/**
+ * 기준 통화 손익 응답.
+ */
+export type CurrencyPnLResponse = { 
+/**
+ * 기준 통화 (`BASE_CURRENCY`)
+ */
+base_currency: string, symbols: Array<CurrencyPnLItem>, 
+/**
+ * 자산 손익 합계 (기준 통화)
+ */
+total_asset_pnl: string, 
+/**
+ * 환차손익 합계 (기준 통화)
+ */
+total_fx_pnl: string, 
+/**
+ * 기준 통화 총 실현 손익
+ */
+total_pnl: string, 
+/**
+ * 환율이 없어 합계에서 제외된 종목
+ */
+unconverted: Array<string>, };
@@ -19,7 +19,10 @@ use trader_api::{
     metrics::setup_metrics_recorder,
     middleware::{metrics_layer, rate_limit_middleware, RateLimitConfig, RateLimitState},
     openapi::swagger_ui_router,
-    repository::StrategyRepository,
+    repository::{
+        DecisionTraceRepository, StrategyDeploymentRepository, StrategyRepository,
+        StrategySnapshotRepository,
+    },
     routes::create_api_router,
     services::ApiBotHandler,
     state::AppState,
@@ -27,8 +30,11 @@ use trader_api::{
         create_subscription_manager, standalone_websocket_router, start_simulator, WsState,
     },
 };
-use trader_core::crypto::CredentialEncryptor;
-use trader_data::{cache::CachedHistoricalDataProvider, Database, DatabaseConfig, RedisCache};
+use trader_core::{crypto::CredentialEncryptor, Country, EventBlackoutConfig, EventImpact};
+use trader_data::{
+    cache::CachedHistoricalDataProvider, Database, DatabaseConfig, IndicatorStateRepository,
+    RedisCache,
+};
 use trader_execution::{ConversionConfig, OrderExecutor};
 use trader_notification::{NotificationManager, TelegramConfig, TelegramSender};
 use trader_risk::{RiskConfig, RiskManager};
@@ -128,12 +134,16 @@ async fn create_app_state(config: &ServerConfig) -> AppState {
     // 전략 엔진 생성
     let strategy_engine = StrategyEngine::new(EngineConfig::default());
 
-    // 리스크 매니저 생성
-    let risk_manager = RiskManager::new(RiskConfig::default(), config.initial_balance);
+    // 리스크 매니저 생성 (경제 이벤트 블랙아웃은 환경변수로 활성화)
+    let risk_config = RiskConfig {
+        event_blackout: event_blackout_config(),
+        ..RiskConfig::default()
+    };
+    let risk_manager = RiskManager::new(risk_config.clone(), config.initial_balance);
 
     // 주문 실행기 생성
     let executor = OrderExecutor::new_complete(
-        RiskManager::new(RiskConfig::default(), config.initial_balance),
+        RiskManager::new(risk_config, config.initial_balance),
         "default_exchange",
         ConversionConfig::default(),
     );
@@ -141,6 +151,18 @@ async fn create_app_state(config: &ServerConfig) -> AppState {
     // AppState 빌드
     let mut state = AppState::new(strategy_engine, risk_manager, executor);
 
+    // 증분 지표 상태 캐시를 전략 엔진에 연결 (캔들마다 O(1) 갱신 후 컨텍스트에 반영)
+    state
+        .strategy_engine
+        .write()
+        .await
+        .set_indicator_updater(state.indicator_states.clone());
+
+    // 스크립트 전략 지표 함수는 IndicatorEngine으로 계산
+    trader_strategy::scripting::set_indicator_calculator(Arc::new(
+        trader_analytics::IndicatorEngine::new(),
+    ));
+
     // Redis 캐시 연결 설정 (REDIS_URL 환경변수에서)
     // trader-data의 RedisCache를 사용하여 API 응답 캐싱 및 OHLCV 캐싱 활성화
     // DB 연결 전에 Redis를 먼저 연결하여 data_provider에서 사용할 수 있도록 함
@@ -170,6 +192,31 @@ async fn create_app_state(config: &ServerConfig) -> AppState {
                     } else {
                         CachedHistoricalDataProvider::new(pool.clone())
                     };
+                    // 전략 상태 체크포인트 저장소 (재시작 시 전략 내부 상태 복원),
+                    // 전략 버전 배포 이력 저장소 및 신호 결정 추적 저장소
+                    {
+                        let mut engine = state.strategy_engine.write().await;
+                        engine.set_state_store(Arc::new(StrategySnapshotRepository::new(
+                            pool.clone(),
+                        )));
+                        engine.set_deployment_store(Arc::new(StrategyDeploymentRepository::new(
+                            pool.clone(),
+                        )));
+                        engine.set_trace_recorder(Arc::new(DecisionTraceRepository::new(
+                            pool.clone(),
+                        )));
+                    }
+
+                    // 수집기/이전 실행이 저장한 증분 지표 상태 복원
+                    match state
+                        .indicator_states
+                        .restore(&IndicatorStateRepository::new(pool.clone()), None)
+                        .await
+                    {
+                        Ok(count) => info!(count, "증분 지표 상태 복원"),
+                        Err(e) => warn!("증분 지표 상태 복원 실패: {}", e),
+                    }
+
                     state = state
                         .with_db_pool(pool)
                         .with_data_provider(data_provider)
@@ -255,10 +302,49 @@ async fn create_app_state(config: &ServerConfig) -> AppState {
         info!("텔레그램 설정 없음, 알림 기능 비활성화");
     }
 
+    // 김치 프리미엄 모니터 설정 (KIMCHI_PREMIUM_ENABLED=true일 때만)
+    if let Some(premium_config) = trader_api::services::KimchiPremiumConfig::from_env() {
+        state = state.with_kimchi_premium(premium_config);
+        info!("김치 프리미엄 모니터 활성화");
+    }
+
+    // DART 공시 알림 설정 (DISCLOSURE_ALERT_ENABLED=true일 때만)
+    if let Some(disclosure_config) = trader_api::services::DisclosureAlertConfig::from_env() {
+        state = state.with_disclosure_alert(disclosure_config);
+        info!("DART 공시 알림 활성화");
+    }
+
+    // 실시간 바 집계 설정 (LIVE_BARS_ENABLED=true일 때만)
+    if let Some(live_bar_config) = trader_api::services::LiveBarConfig::from_env() {
+        state = state.with_live_bars(live_bar_config);
+        info!("실시간 바 집계 활성화");
+    }
+
+    // 스마트 주문 라우터 설정 (SMART_ROUTER_ENABLED=true일 때만)
+    if let (Some(pool), Some(router_config)) = (
+        state.db_pool.clone(),
+        trader_api::services::SmartRouterConfig::from_env(),
+    ) {
+        let encryptor_ref = state.encryptor.as_ref().map(|e| e.as_ref());
+        match trader_api::services::build_smart_router(&pool, encryptor_ref, &router_config).await {
+            Some(router) => {
+                state = state.with_smart_router(router);
+                info!("스마트 주문 라우팅 활성화");
+            }
+            None => warn!("스마트 주문 라우팅 비활성화: 구성 가능한 거래소 없음"),
+        }
+    }
+
+    // 환율 서비스 설정 (기준 통화 환산)
+    state = state.with_fx_rates();
+    info!(base_currency = %state.fx_rates.base_currency(), "환율 서비스 설정");
+
     // ExchangeProvider 및 MarketDataProvider 설정 (거래소 중립)
     // DB 기반 credential만 사용 (레거시 환경변수 방식 제거됨)
     if let Some(pool) = &state.db_pool {
-        use trader_api::repository::{create_provider_bundle, get_active_credential_id};
+        use trader_api::repository::{
+            create_provider_bundle, get_active_credential_id, wrap_market_data_with_failover,
+        };
 
         match get_active_credential_id(pool).await {
             Ok(credential_id) => {
@@ -273,9 +359,25 @@ async fn create_app_state(config: &ServerConfig) -> AppState {
                             bundle.exchange.exchange_name(),
                             bundle.market_data.provider_name()
                         );
+                        // 보조 시세 소스 (예: MARKET_DATA_FAILOVER=ls_sec,db_investment,naver)
+                        let market_data = match std::env::var("MARKET_DATA_FAILOVER") {
+                            Ok(list) => {
+                                let secondaries: Vec<String> =
+                                    list.split(',').map(|s| s.trim().to_string()).collect();
+                                wrap_market_data_with_failover(
+                                    pool,
+                                    encryptor_ref,
+                                    credential_id,
+                                    bundle.market_data,
+                                    &secondaries,
+                                )
+                                .await
+                            }
+                            Err(_) => bundle.market_data,
+                        };
                         state = state
                             .with_exchange_provider(bundle.exchange)
-                            .with_market_data_provider(bundle.market_data);
+                            .with_market_data_provider(market_data);
                     }
                     Err(e) => {
                         warn!("Provider 생성 실패: {}", e);
@@ -375,6 +477,50 @@ fn rate_limit_config() -> RateLimitConfig {
     RateLimitConfig::new(requests_per_minute)
 }
 
+/// 경제 이벤트 블랙아웃 설정 로드 (`EVENT_BLACKOUT_ENABLED=true`가 아니면 None).
+///
+/// `EVENT_BLACKOUT_REDUCE_FACTOR`가 설정되면 차단 대신 신규 진입 수량을 축소합니다.
+fn event_blackout_config() -> Option<EventBlackoutConfig> {
+    let enabled = std::env::var("EVENT_BLACKOUT_ENABLED")
+        .map(|v| v == "true" || v == "1")
+        .unwrap_or(false);
+    if !enabled {
+        return None;
+    }
+
+    let env = |key: &str| std::env::var(key).ok().filter(|v| !v.trim().is_empty());
+    let mut blackout = EventBlackoutConfig::default();
+    if let Some(impact) = env("EVENT_BLACKOUT_MIN_IMPACT").and_then(|v| EventImpact::parse(&v)) {
+        blackout = blackout.with_min_impact(impact);
+    }
+    let before = env("EVENT_BLACKOUT_BEFORE_MINUTES")
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(blackout.before_minutes);
+    let after = env("EVENT_BLACKOUT_AFTER_MINUTES")
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(blackout.after_minutes);
+    blackout = blackout.with_window(before, after);
+    if let Some(factor) = env("EVENT_BLACKOUT_REDUCE_FACTOR").and_then(|v| v.trim().parse().ok()) {
+        blackout = blackout.with_reduce_size(factor);
+    }
+    if let Some(countries) = env("EVENT_BLACKOUT_COUNTRIES") {
+        blackout = blackout.with_countries(
+            countries
+                .split(',')
+                .filter_map(|c| Country::from_code(c.trim())),
+        );
+    }
+
+    info!(
+        min_impact = blackout.min_impact.as_str(),
+        before_minutes = blackout.before_minutes,
+        after_minutes = blackout.after_minutes,
+        action = ?blackout.action,
+        "Event blackout configured"
+    );
+    Some(blackout)
+}
+
 /// 전체 라우터 생성.
 fn create_router(
     state: Arc<AppState>,
@@ -569,7 +715,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let shutdown_token = CancellationToken::new();
 
     // ContextSyncService 시작 (ExchangeProvider + AnalyticsProvider가 모두 설정된 경우)
-    if let Some(_sync_handle) = state.start_context_sync(shutdown_token.clone()) {
+    if let Some(_sync_handle) = state.start_context_sync(shutdown_token.clone()).await {
         info!("ContextSyncService 시작됨 (거래소: 5초, 분석: 1분 주기)");
     } else {
         warn!("ContextSyncService 시작 실패: ExchangeProvider 또는 AnalyticsProvider 미설정");
@@ -589,6 +735,46 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         warn!("ConflictBroadcastService 시작 실패: WebSocket 미설정 또는 conflict_rx 이미 사용됨");
     }
 
+    // KimchiPremiumService 시작 (업비트/빗썸 vs 바이낸스 프리미엄 감시)
+    if let Some(_premium_handle) = state.start_kimchi_premium(shutdown_token.clone()) {
+        info!("KimchiPremiumService 시작됨");
+    }
+
+    // DisclosureAlertService 시작 (DART 주요사항 공시 알림)
+    if let Some(_disclosure_handle) = state.start_disclosure_alert(shutdown_token.clone()) {
+        info!("DisclosureAlertService 시작됨");
+    }
+
+    // LiveBarService 시작 (실시간 체결 → 캔들 집계)
+    if let Some(_live_bar_handle) = state.start_live_bars(shutdown_token.clone()) {
+        info!("LiveBarService 시작됨");
+    }
+
+    // FxRateRefreshService 시작 (fx_rates 테이블/매크로 데이터 환율 주기적 갱신)
+    if let Some(_fx_rate_handle) = state.start_fx_rate_refresh(shutdown_token.clone()) {
+        info!("FxRateRefreshService 시작됨");
+    }
+
+    // StrategyCheckpointService 시작 (전략 상태 주기적 스냅샷)
+    if let Some(_checkpoint_handle) = state
+        .start_strategy_checkpoint(shutdown_token.clone())
+        .await
+    {
+        info!("StrategyCheckpointService 시작됨");
+    }
+
+    // IndicatorStatePersistService 시작 (변경된 증분 지표 상태 주기적 저장)
+    if let Some(_indicator_state_handle) =
+        state.start_indicator_state_persist(shutdown_token.clone())
+    {
+        info!("IndicatorStatePersistService 시작됨");
+    }
+
+    // StrategySchedulerService 시작 (장 개장/마감, 월말 등 일정 이벤트)
+    if let Some(_scheduler_handle) = state.start_strategy_scheduler(shutdown_token.clone()).await {
+        info!("StrategySchedulerService 시작됨");
+    }
+
     // 데이터베이스에서 저장된 전략 로드
     if let Some(ref pool) = state.db_pool {
         let engine = state.strategy_engine.read().await;
@@ -620,6 +806,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         });
     }
 
+    // 종료 시 전략 상태 저장용 엔진 참조
+    let strategy_engine = Arc::clone(&state.strategy_engine);
+
     // 라우터 생성
     let app = create_router(state, metrics_handle, ws_state);
 
@@ -647,6 +836,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 정리 작업에 최대 10초 대기
     let cleanup_timeout = tokio::time::timeout(Duration::from_secs(10), async {
+        // 실행 중인 전략 상태 저장
+        strategy_engine.read().await.shutdown().await;
+
         // 진행 중인 요청 완료 대기
         tokio::time::sleep(Duration::from_millis(500)).await;
         info!("Cleanup completed");
@@ -23,7 +23,10 @@ use axum::Router;
 use trader_analytics::ml::{CandlestickPatternInfo, ChartPatternInfo, PatternDetectionResult};
 // trader-core 도메인 타입 (ToSchema 지원)
 use trader_core::types::{MarketType, Symbol};
-use trader_core::{OrderStatusType, OrderType, Side, SignalIndicators, TimeInForce};
+use trader_core::{
+    domain::IndicatorSnapshot, DecisionOutcome, DecisionTrace, OrderStatusType, OrderType, Side,
+    SignalIndicators, TimeInForce, TraceStep, TraceStepKind,
+};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
@@ -39,9 +42,9 @@ use crate::routes::{
     // Analytics 모듈
     analytics::types::{
         AvailableIndicatorsResponse, ChartQuery, ChartResponse, CorrelationResponse,
-        EquityCurveResponse, IndicatorDataResponse, IndicatorQuery, KeltnerResponse,
-        MonthlyReturnsResponse, ObvResponse, PerformanceResponse, PeriodQuery, SuperTrendResponse,
-        VolumeProfileQuery, VolumeProfileResponse, VwapResponse,
+        EquityCurveResponse, IndicatorDataResponse, IndicatorQuery, IndicatorStateResponse,
+        KeltnerResponse, MonthlyReturnsResponse, ObvResponse, PerformanceResponse, PeriodQuery,
+        SuperTrendResponse, VolumeProfileQuery, VolumeProfileResponse, VwapResponse,
     },
     // Credentials 모듈
     credentials::{
@@ -56,8 +59,8 @@ use crate::routes::{
     },
     // Market 모듈
     market::{
-        MacroEnvironmentResponse, MarketBreadthResponse, MarketOverviewResponse,
-        MarketStatusResponse,
+        KimchiPremiumResponse, MacroEnvironmentResponse, MarketBreadthResponse,
+        MarketOverviewResponse, MarketStatusResponse, PremiumHistoryResponse,
     },
     // Patterns 모듈
     patterns::{
@@ -77,8 +80,9 @@ use crate::routes::{
     },
     // Signals 모듈
     signals::{
-        CreateSignalRequest, CreateSignalResponse, SignalMarkerDto, SignalSearchRequest,
-        SignalSearchResponse, StrategySignalsQuery, SymbolSignalsQuery,
+        CreateSignalRequest, CreateSignalResponse, DecisionTraceSearchQuery,
+        DecisionTraceSearchResponse, SignalMarkerDto, SignalSearchRequest, SignalSearchResponse,
+        StrategySignalsQuery, SymbolSignalsQuery,
     },
     // Strategies 모듈
     strategies::{ApiError, StrategyListItem},
@@ -159,7 +163,7 @@ use crate::routes::{
         (name = "screening", description = "스크리닝 - 종목 필터링"),
         (name = "simulation", description = "시뮬레이션 - 모의 거래"),
         (name = "monitoring", description = "모니터링 - 에러 추적 및 시스템 상태"),
-        (name = "signals", description = "신호 마커 - 백테스트/실거래 신호 조회 및 검색"),
+        (name = "signals", description = "신호 마커 - 백테스트/실거래 신호 조회 및 검색, 결정 추적 (조건 단계의 억제 추적은 평균회귀 계열 전략만 기록)"),
         (name = "ranking", description = "랭킹 - GlobalScore 기반 종목 랭킹 및 7Factor 분석"),
         (name = "reality_check", description = "실제 검증 - 백테스트와 실거래 비교"),
         (name = "signal-alerts", description = "신호 알림 - 신호 기반 알림 규칙 관리"),
@@ -187,11 +191,19 @@ use crate::routes::{
             ErrorsResponse,
             ErrorRecordDto,
             StatsResponse,
+            crate::routes::monitoring::ExchangeHealthResponse,
+            crate::routes::monitoring::ProviderHealthDto,
+            crate::routes::monitoring::FailoverGroupDto,
+            crate::routes::monitoring::CollectorJobRunDto,
+            crate::routes::monitoring::CollectorJobsResponse,
+            crate::routes::monitoring::CollectorRunsResponse,
 
             // ===== Screening =====
             ScreeningRequest,
             ScreeningResponse,
             MomentumResponse,
+            crate::routes::screening::SectorPairsResponse,
+            crate::routes::screening::PairCandidateDto,
 
             // ===== Signals =====
             SignalMarkerDto,
@@ -199,6 +211,8 @@ use crate::routes::{
             SignalSearchResponse,
             SymbolSignalsQuery,
             StrategySignalsQuery,
+            DecisionTraceSearchQuery,
+            DecisionTraceSearchResponse,
 
             // ===== Core Domain Types =====
             Side,
@@ -208,6 +222,10 @@ use crate::routes::{
             Symbol,
             MarketType,
             SignalIndicators,
+            DecisionTrace,
+            TraceStep,
+            TraceStepKind,
+            DecisionOutcome,
 
             // ===== Ranking =====
             CalculateResponse,
@@ -247,6 +265,8 @@ use crate::routes::{
             KeltnerResponse,
             ObvResponse,
             SuperTrendResponse,
+            IndicatorStateResponse,
+            IndicatorSnapshot,
 
             // ===== Credentials (Notification Providers) =====
             NotificationSettingsConfig,
@@ -289,6 +309,10 @@ use crate::routes::{
             MarketStatusResponse,
             MarketBreadthResponse,
             MacroEnvironmentResponse,
+            KimchiPremiumResponse,
+            PremiumHistoryResponse,
+            crate::services::PremiumSnapshot,
+            crate::services::DomesticVenue,
         )
     ),
     // ==================== 경로 등록 ====================
@@ -311,6 +335,24 @@ use crate::routes::{
         crate::routes::strategies::get_engine_stats,
         crate::routes::strategies::get_strategy_timeframes,
         crate::routes::strategies::update_strategy_timeframes,
+        crate::routes::strategies::list_strategy_snapshots,
+        crate::routes::strategies::create_strategy_snapshot,
+        crate::routes::strategies::get_strategy_snapshot,
+        crate::routes::strategies::export_strategy_snapshot,
+        crate::routes::strategies::rollback_strategy_snapshot,
+        crate::routes::strategies::preview_strategy_deployment,
+        crate::routes::strategies::deploy_strategy_version,
+        crate::routes::strategies::list_strategy_deployments,
+        crate::routes::strategies::get_strategy_deployment,
+        crate::routes::strategies::rollback_strategy_deployment,
+        crate::routes::strategies::start_strategy_shadow,
+        crate::routes::strategies::list_strategy_shadows,
+        crate::routes::strategies::get_strategy_shadow,
+        crate::routes::strategies::stop_strategy_shadow,
+        crate::routes::strategies::promote_strategy_shadow,
+        crate::routes::strategies::validate_script,
+        crate::routes::strategies::get_strategy_script,
+        crate::routes::strategies::update_strategy_script,
 
         // ===== Monitoring =====
         crate::routes::monitoring::list_errors,
@@ -320,6 +362,9 @@ use crate::routes::{
         crate::routes::monitoring::reset_stats,
         crate::routes::monitoring::clear_errors,
         crate::routes::monitoring::get_summary,
+        crate::routes::monitoring::get_exchange_health,
+        crate::routes::monitoring::get_collector_jobs,
+        crate::routes::monitoring::list_collector_runs,
 
         // ===== Screening =====
         crate::routes::screening::run_screening,
@@ -327,6 +372,7 @@ use crate::routes::{
         crate::routes::screening::run_preset_screening,
         crate::routes::screening::run_momentum_screening,
         crate::routes::screening::get_sector_ranking,
+        crate::routes::screening::get_sector_pairs,
 
         // ===== Signals =====
         crate::routes::signals::search_signals,
@@ -337,6 +383,8 @@ use crate::routes::{
         crate::routes::signals::get_signal_performance,
         crate::routes::signals::get_signal_scatter,
         crate::routes::signals::get_symbol_signal_performance,
+        crate::routes::signals::search_decision_traces,
+        crate::routes::signals::get_decision_trace,
 
         // ===== Ranking =====
         crate::routes::ranking::calculate_global,
@@ -374,6 +422,7 @@ use crate::routes::{
         crate::routes::analytics::indicators::get_keltner_indicator,
         crate::routes::analytics::indicators::get_obv_indicator,
         crate::routes::analytics::indicators::get_supertrend_indicator,
+        crate::routes::analytics::indicators::get_indicator_state,
         // Performance
         crate::routes::analytics::performance::get_performance,
         // Sync
@@ -411,6 +460,7 @@ use crate::routes::{
         crate::routes::journal::get_pnl_summary,
         crate::routes::journal::get_daily_pnl,
         crate::routes::journal::get_symbol_pnl,
+        crate::routes::journal::get_currency_pnl,
         crate::routes::journal::get_weekly_pnl,
         crate::routes::journal::get_monthly_pnl,
         crate::routes::journal::get_yearly_pnl,
@@ -426,6 +476,7 @@ use crate::routes::{
         crate::routes::dataset::list_datasets,
         crate::routes::dataset::fetch_dataset,
         crate::routes::dataset::get_candles,
+        crate::routes::dataset::download_parquet,
         crate::routes::dataset::delete_dataset,
         crate::routes::dataset::search_symbols,
         crate::routes::dataset::get_symbols_batch,
@@ -469,6 +520,8 @@ use crate::routes::{
 
         // ===== Market =====
         crate::routes::market::get_market_overview,
+        crate::routes::market::get_kimchi_premium,
+        crate::routes::market::get_kimchi_premium_history,
 
         // ===== Signal Alerts =====
         crate::routes::signal_alerts::create_alert_rule,
@@ -7,6 +7,7 @@
 //! - **가중평균 매입가**: 물타기(추가 매수) 시 자동 계산
 //! - **FIFO 실현손익**: 선입선출 방식으로 매도 시 실현 손익 계산
 //! - **로트(Lot) 추적**: 개별 매수 건별 추적으로 정확한 비용 기준 관리
+//! - **환차손익 분리**: 외화 종목의 실현 손익을 기준 통화로 환산하고 자산/환율 손익으로 분해
 //!
 //! # 예시
 //!
@@ -33,7 +34,7 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
-use trader_core::{Side, TradeInfo};
+use trader_core::{FxPnl, FxRateTable, MissingFxRate, Side, TradeInfo};
 use uuid::Uuid;
 
 /// 매수 로트(Lot).
@@ -125,6 +126,8 @@ pub struct LotUsage {
     pub quantity_used: Decimal,
     /// 해당 로트의 매수 가격
     pub purchase_price: Decimal,
+    /// 해당 로트의 매수 시간
+    pub acquired_at: DateTime<Utc>,
     /// 보유 기간 (일)
     pub holding_days: i64,
     /// 개별 손익
@@ -228,6 +231,7 @@ impl CostBasisTracker {
                 lot_id: lot.id,
                 quantity_used: used_qty,
                 purchase_price: lot.price,
+                acquired_at: lot.acquired_at,
                 holding_days,
                 pnl,
             });
@@ -500,6 +504,57 @@ pub fn build_tracker_from_executions(
     tracker
 }
 
+/// 체결 내역의 실현 손익을 기준 통화로 환산하여 자산/환차손익으로 분해.
+///
+/// FIFO로 매도에 사용된 로트마다 매수일 환율을 진입 환율, 매도일 환율을 청산 환율로 적용합니다.
+/// 매도 수수료는 청산 환율로 환산하여 자산 손익에서 차감합니다.
+///
+/// # Arguments
+/// * `currency` - 종목 거래 통화 (예: "USD")
+/// * `base_currency` - 기준 통화 (예: "KRW")
+pub fn realized_fx_pnl(
+    executions: Vec<TradeExecution>,
+    currency: &str,
+    base_currency: &str,
+    rates: &FxRateTable,
+) -> Result<FxPnl, MissingFxRate> {
+    let mut tracker = CostBasisTracker::default();
+    let mut pnl = FxPnl::default();
+
+    let mut sorted_executions = executions;
+    sorted_executions.sort_by_key(|e| e.executed_at);
+
+    for exec in sorted_executions {
+        match exec.side {
+            Side::Buy => tracker.buy(exec.quantity, exec.price, exec.fee, exec.executed_at),
+            Side::Sell => {
+                let Ok(sale) = tracker.sell(exec.quantity, exec.price, exec.fee, exec.executed_at)
+                else {
+                    continue;
+                };
+                let exit_rate =
+                    rates.require_rate(currency, base_currency, exec.executed_at.date_naive())?;
+                for usage in &sale.lots_used {
+                    let entry_rate = rates.require_rate(
+                        currency,
+                        base_currency,
+                        usage.acquired_at.date_naive(),
+                    )?;
+                    pnl += FxPnl::decompose(
+                        usage.quantity_used * usage.purchase_price,
+                        usage.pnl,
+                        entry_rate,
+                        exit_rate,
+                    );
+                }
+                pnl.asset_pnl -= exec.fee * exit_rate;
+            }
+        }
+    }
+
+    Ok(pnl)
+}
+
 #[cfg(test)]
 mod tests {
     use rust_decimal_macros::dec;
@@ -678,4 +733,37 @@ mod tests {
         assert!(summary.market_value.is_some());
         assert!(summary.unrealized_pnl.is_some());
     }
+
+    #[test]
+    fn test_realized_fx_pnl() {
+        use chrono::TimeZone;
+
+        let day = |d: u32| Utc.with_ymd_and_hms(2025, 3, d, 15, 0, 0).unwrap();
+        let mut rates = FxRateTable::new();
+        rates.insert("USD", "KRW", day(3).date_naive(), dec!(1300));
+        rates.insert("USD", "KRW", day(10).date_naive(), dec!(1400));
+
+        let execution = |side, price, d| TradeExecution {
+            id: Uuid::new_v4(),
+            symbol: "AAPL".to_string(),
+            side,
+            quantity: dec!(10),
+            price,
+            fee: Decimal::ZERO,
+            executed_at: day(d),
+        };
+        let executions = vec![
+            execution(Side::Buy, dec!(100), 3),
+            execution(Side::Sell, dec!(110), 10),
+        ];
+
+        // 자산: $100 × 1400, 환율: $1000 × (1400 - 1300)
+        let pnl = realized_fx_pnl(executions.clone(), "USD", "KRW", &rates).unwrap();
+        assert_eq!(pnl.asset_pnl, dec!(140000));
+        assert_eq!(pnl.fx_pnl, dec!(100000));
+        assert_eq!(pnl.total(), dec!(1540000) - dec!(1300000));
+
+        // 매수일 이전 환율이 없으면 에러
+        assert!(realized_fx_pnl(executions, "JPY", "KRW", &rates).is_err());
+    }
 }
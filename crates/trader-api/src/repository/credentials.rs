@@ -10,18 +10,26 @@
 use std::{collections::HashMap, sync::Arc};
 
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use sqlx::PgPool;
 use tracing::{debug, info, warn};
-use trader_core::{CredentialEncryptor, ExchangeProvider, MarketDataProvider};
+use trader_core::{
+    CredentialEncryptor, ExchangeProvider, MarketDataProvider, OrderExecutionProvider,
+};
 use trader_exchange::{
-    connector::kis::{KisAccountType, KisClient, KisConfig, KisOAuth},
+    connector::{
+        binance::{BinanceClient, BinanceConfig},
+        kis::{KisAccountType, KisClient, KisConfig, KisOAuth},
+    },
     provider::{
-        BithumbProvider, DbInvestmentProvider, KisProvider, LsSecProvider, MockConfig,
-        MockExchangeProvider, UpbitProvider,
+        BinanceProvider, BithumbProvider, DbInvestmentProvider, FailoverMarketDataProvider,
+        KisProvider, LsSecProvider, MockConfig, MockExchangeProvider, NaverQuoteProvider,
+        UpbitProvider,
     },
-    BithumbClient, BithumbConfig, DbInvestmentClient, DbInvestmentConfig, LsSecClient, LsSecConfig,
-    UpbitClient, UpbitConfig,
+    BithumbClient, BithumbConfig, DbInvestmentClient, DbInvestmentConfig, FixClient, FixConfig,
+    FixExchangeProvider, LsSecClient, LsSecConfig, UpbitClient, UpbitConfig,
 };
+use trader_execution::{RouterVenue, VenueConfig};
 
 use super::kis_token::KisTokenRepository;
 
@@ -808,6 +816,247 @@ pub async fn create_provider_bundle(
     }
 }
 
+/// 거래소별 활성 credential ID 조회 (가장 최근 갱신된 것).
+pub async fn find_active_credential_by_exchange(
+    pool: &PgPool,
+    exchange_id: &str,
+) -> Result<Option<Uuid>, String> {
+    sqlx::query_scalar(
+        "SELECT id FROM exchange_credentials \
+         WHERE exchange_id = $1 AND is_active = true \
+         ORDER BY updated_at DESC LIMIT 1",
+    )
+    .bind(exchange_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("credential 조회 실패: {}", e))
+}
+
+/// 주문 실행 Provider 생성
+///
+/// exchange_id에 따라 `OrderExecutionProvider`를 생성합니다.
+/// 조회 기능이 없는 주문 전용 게이트웨이도 여기서 생성합니다.
+/// - fix: FIX 4.4 프라임 브로커 게이트웨이 (로그온까지 완료한 세션)
+/// - upbit, bithumb: 암호화폐 거래소
+pub async fn create_order_provider_for_credential(
+    pool: &PgPool,
+    encryptor: &CredentialEncryptor,
+    credential_id: Uuid,
+) -> Result<Arc<dyn OrderExecutionProvider>, String> {
+    let exchange_id: String =
+        sqlx::query_scalar("SELECT exchange_id FROM exchange_credentials WHERE id = $1")
+            .bind(credential_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("exchange_id 조회 실패: {}", e))?
+            .ok_or_else(|| "해당 credential을 찾을 수 없습니다.".to_string())?;
+
+    let (creds, row) = load_and_decrypt_credential(pool, encryptor, credential_id).await?;
+    match exchange_id.as_str() {
+        "fix" => {
+            let config = fix_config_from_credentials(&creds, &row.exchange_name)?;
+            let client = FixClient::connect(config)
+                .await
+                .map_err(|e| format!("FIX 세션 연결 실패: {}", e))?;
+            info!("FIX Provider 생성 완료: credential_id={}", credential_id);
+            Ok(Arc::new(FixExchangeProvider::new(Arc::new(client))))
+        }
+        "upbit" => {
+            let config = UpbitConfig {
+                access_key: creds.api_key,
+                secret_key: creds.api_secret,
+            };
+            Ok(Arc::new(UpbitProvider::new(Arc::new(UpbitClient::new(
+                config,
+            )))))
+        }
+        "bithumb" => {
+            let config = BithumbConfig {
+                access_key: creds.api_key,
+                secret_key: creds.api_secret,
+            };
+            Ok(Arc::new(BithumbProvider::new(Arc::new(
+                BithumbClient::new(config),
+            ))))
+        }
+        _ => Err(format!("주문 Provider 미지원 거래소: {}", exchange_id)),
+    }
+}
+
+/// FIX credential에서 게이트웨이 설정 구성
+///
+/// api_key/api_secret은 Username(553)/Password(554)로 사용하고, 접속 정보는
+/// additional 필드(`host`, `port`, `sender_comp_id`, `target_comp_id`, 선택: `account`,
+/// `heartbeat_secs`, `seq_store_path`)에서 읽습니다.
+fn fix_config_from_credentials(
+    creds: &EncryptedCredentials,
+    exchange_name: &str,
+) -> Result<FixConfig, String> {
+    let additional = creds
+        .additional
+        .as_ref()
+        .ok_or("FIX credential에 접속 정보(additional)가 없습니다.")?;
+    let field = |name: &str| {
+        additional
+            .get(name)
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+    };
+    let required =
+        |name: &str| field(name).ok_or_else(|| format!("FIX credential에 {}가 없습니다.", name));
+
+    let port: u16 = required("port")?
+        .parse()
+        .map_err(|e| format!("FIX port 파싱 실패: {}", e))?;
+    let mut config = FixConfig::new(
+        required("host")?,
+        port,
+        required("sender_comp_id")?,
+        required("target_comp_id")?,
+    )
+    .with_exchange_name(exchange_name);
+
+    config = config.with_credentials(creds.api_key.clone(), creds.api_secret.clone());
+    if let Some(account) = creds.account_number.as_deref().or(field("account")) {
+        config = config.with_account(account);
+    }
+    if let Some(secs) = field("heartbeat_secs").and_then(|v| v.parse().ok()) {
+        config = config.with_heartbeat_interval(secs);
+    }
+    if let Some(path) = field("seq_store_path") {
+        config = config.with_seq_store_path(path);
+    }
+    Ok(config)
+}
+
+/// 스마트 주문 라우팅 거래소 생성.
+///
+/// 해당 exchange_id의 활성 credential로 시세/주문 제공자를 만들어 라우터 거래소로 구성합니다.
+/// 원화 마켓 거래소(`upbit`, `bithumb`)와 USDT 마켓 거래소(`binance`)를 지원합니다.
+pub async fn create_router_venue(
+    pool: &PgPool,
+    encryptor: Option<&CredentialEncryptor>,
+    exchange_id: &str,
+) -> Result<RouterVenue, String> {
+    let credential_id = find_active_credential_by_exchange(pool, exchange_id)
+        .await?
+        .ok_or_else(|| format!("{}: 활성 credential 없음", exchange_id))?;
+    let encryptor = encryptor.ok_or("스마트 라우팅은 encryptor가 필요합니다.")?;
+    let (creds, _row) = load_and_decrypt_credential(pool, encryptor, credential_id).await?;
+
+    match exchange_id {
+        "upbit" => {
+            let config = UpbitConfig {
+                access_key: creds.api_key,
+                secret_key: creds.api_secret,
+            };
+            let provider = Arc::new(UpbitProvider::new(Arc::new(UpbitClient::new(config))));
+            Ok(RouterVenue::new(
+                VenueConfig::new("upbit", "KRW-{base}", "KRW", dec!(0.0005)),
+                provider.clone(),
+            )
+            .with_order_provider(provider))
+        }
+        "bithumb" => {
+            let config = BithumbConfig {
+                access_key: creds.api_key,
+                secret_key: creds.api_secret,
+            };
+            let provider = Arc::new(BithumbProvider::new(Arc::new(BithumbClient::new(config))));
+            Ok(RouterVenue::new(
+                VenueConfig::new("bithumb", "KRW-{base}", "KRW", dec!(0.0004)),
+                provider.clone(),
+            )
+            .with_order_provider(provider))
+        }
+        "binance" => {
+            let config = BinanceConfig::new(creds.api_key, creds.api_secret);
+            let client = BinanceClient::new(config)
+                .map_err(|e| format!("Binance 클라이언트 생성 실패: {}", e))?;
+            let provider = Arc::new(BinanceProvider::new(Arc::new(client)));
+            Ok(RouterVenue::new(
+                VenueConfig::new("binance", "{base}USDT", "USDT", dec!(0.001)),
+                provider.clone(),
+            )
+            .with_order_provider(provider))
+        }
+        _ => Err(format!("스마트 라우팅 미지원 거래소: {}", exchange_id)),
+    }
+}
+
+/// 시세 제공자를 장애 조치 제공자로 래핑.
+///
+/// `secondaries`에 지정된 순서대로 보조 소스를 구성합니다.
+/// - `"naver"`: 네이버 금융 지연 시세 (credential 불필요)
+/// - 그 외: 해당 exchange_id의 활성 credential로 생성한 시세 제공자
+///
+/// 보조 소스를 하나도 만들 수 없으면 주 제공자를 그대로 반환합니다.
+pub async fn wrap_market_data_with_failover(
+    pool: &PgPool,
+    encryptor: Option<&CredentialEncryptor>,
+    primary_credential_id: Uuid,
+    primary: Arc<dyn MarketDataProvider>,
+    secondaries: &[String],
+) -> Arc<dyn MarketDataProvider> {
+    let primary_exchange: Option<String> =
+        sqlx::query_scalar("SELECT exchange_id FROM exchange_credentials WHERE id = $1")
+            .bind(primary_credential_id)
+            .fetch_optional(pool)
+            .await
+            .ok()
+            .flatten();
+    let Some(primary_exchange) = primary_exchange else {
+        return primary;
+    };
+
+    let mut failover = FailoverMarketDataProvider::new(
+        format!("{}_market_data", primary_exchange),
+        primary_exchange.clone(),
+        primary.clone(),
+        trader_exchange::global_health_registry(),
+    );
+
+    for exchange_id in secondaries {
+        let exchange_id = exchange_id.trim();
+        if exchange_id.is_empty() || exchange_id == primary_exchange {
+            continue;
+        }
+        if exchange_id == "naver" {
+            failover = failover.with_secondary("naver", Arc::new(NaverQuoteProvider::new()));
+            continue;
+        }
+
+        let credential_id = match find_active_credential_by_exchange(pool, exchange_id).await {
+            Ok(Some(id)) => id,
+            Ok(None) => {
+                warn!("보조 시세 소스 {}: 활성 credential 없음", exchange_id);
+                continue;
+            }
+            Err(e) => {
+                warn!("보조 시세 소스 {}: {}", exchange_id, e);
+                continue;
+            }
+        };
+        match create_provider_bundle(pool, encryptor, credential_id).await {
+            Ok(bundle) => {
+                failover = failover.with_secondary(exchange_id, bundle.market_data);
+            }
+            Err(e) => warn!("보조 시세 소스 {} 생성 실패: {}", exchange_id, e),
+        }
+    }
+
+    if failover.secondary_count() == 0 {
+        return primary;
+    }
+
+    info!(
+        primary = %primary_exchange,
+        secondaries = failover.secondary_count(),
+        "시세 장애 조치 활성화"
+    );
+    Arc::new(failover)
+}
+
 /// KIS 통합 클라이언트 생성 (공유 OAuth 사용)
 async fn create_kis_client(
     pool: &PgPool,
@@ -912,9 +1161,15 @@ async fn create_kis_client(
     }
 
     // 통합 KisClient 생성 (내부적으로 KR/US 클라이언트 자동 생성)
-    let client =
+    let mut client =
         KisClient::new(oauth_arc).map_err(|e| format!("KIS 클라이언트 생성 실패: {}", e))?;
 
+    // 헬스 레지스트리의 "kis" Breaker를 커넥터 Breaker로 공유 (이중 기록 방지)
+    let registry = trader_exchange::global_health_registry();
+    let breaker = registry.breaker("kis");
+    registry.register_breaker(breaker.clone());
+    client.set_circuit_breaker(breaker);
+
     Ok(Arc::new(client))
 }
 
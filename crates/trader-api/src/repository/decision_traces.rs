@@ -0,0 +1,127 @@
+//! 신호 결정 추적 리포지토리
+//!
+//! `StrategyEngine`이 수거한 결정 추적을 `decision_traces` 테이블에 저장하여
+//! 재시작 후에도 신호가 발생하거나 억제된 이유를 조회할 수 있게 합니다.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use trader_core::{DecisionOutcome, DecisionTrace, DecisionTraceQuery};
+use trader_strategy::{DecisionTraceRecorder, TraceError};
+use uuid::Uuid;
+
+fn outcome_str(outcome: DecisionOutcome) -> &'static str {
+    match outcome {
+        DecisionOutcome::Emitted => "emitted",
+        DecisionOutcome::Suppressed => "suppressed",
+    }
+}
+
+fn parse_traces(rows: Vec<(serde_json::Value,)>) -> Result<Vec<DecisionTrace>, sqlx::Error> {
+    rows.into_iter()
+        .map(|(trace,)| serde_json::from_value(trace).map_err(|e| sqlx::Error::Decode(e.into())))
+        .collect()
+}
+
+/// 신호 결정 추적 리포지토리
+pub struct DecisionTraceRepository {
+    pool: PgPool,
+}
+
+impl DecisionTraceRepository {
+    /// 새 리포지토리 생성
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 결정 추적 일괄 저장 (같은 ID는 무시)
+    pub async fn save_all(&self, traces: &[DecisionTrace]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        for trace in traces {
+            sqlx::query(
+                r#"
+                INSERT INTO decision_traces (
+                    id, strategy_id, ticker, evaluated_at, outcome, signal_id, side, trace
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (id) DO NOTHING
+                "#,
+            )
+            .bind(trace.id)
+            .bind(&trace.strategy_id)
+            .bind(&trace.ticker)
+            .bind(trace.timestamp)
+            .bind(outcome_str(trace.outcome))
+            .bind(trace.signal_id)
+            .bind(trace.side.map(|side| side.to_string()))
+            .bind(serde_json::to_value(trace).unwrap_or_default())
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await
+    }
+
+    /// 신호 ID로 결정 추적 조회
+    pub async fn get_by_signal(
+        &self,
+        signal_id: Uuid,
+    ) -> Result<Option<DecisionTrace>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, (serde_json::Value,)>(
+            r#"
+            SELECT trace
+            FROM decision_traces
+            WHERE signal_id = $1
+            ORDER BY evaluated_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(signal_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(parse_traces(rows)?.pop())
+    }
+
+    /// 조건에 맞는 결정 추적을 최신순으로 최대 `limit`개 조회
+    pub async fn search(
+        &self,
+        query: &DecisionTraceQuery,
+        limit: i64,
+    ) -> Result<Vec<DecisionTrace>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, (serde_json::Value,)>(
+            r#"
+            SELECT trace
+            FROM decision_traces
+            WHERE ($1::VARCHAR IS NULL OR strategy_id = $1)
+                AND ($2::VARCHAR IS NULL OR ticker = $2)
+                AND ($3::DATE IS NULL OR (evaluated_at AT TIME ZONE 'UTC')::DATE = $3)
+                AND ($4::TIMESTAMPTZ IS NULL OR evaluated_at >= $4)
+                AND ($5::TIMESTAMPTZ IS NULL OR evaluated_at <= $5)
+                AND ($6::VARCHAR IS NULL OR outcome = $6)
+                AND ($7::VARCHAR IS NULL OR side = $7)
+            ORDER BY evaluated_at DESC
+            LIMIT $8
+            "#,
+        )
+        .bind(&query.strategy_id)
+        .bind(&query.ticker)
+        .bind(query.date)
+        .bind(query.from)
+        .bind(query.to)
+        .bind(query.outcome.map(outcome_str))
+        .bind(query.side.map(|side| side.to_string()))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        parse_traces(rows)
+    }
+}
+
+#[async_trait]
+impl DecisionTraceRecorder for DecisionTraceRepository {
+    async fn record(&self, traces: &[DecisionTrace]) -> Result<(), TraceError> {
+        self.save_all(traces)
+            .await
+            .map_err(|e| TraceError::Storage(e.to_string()))
+    }
+}
@@ -10,6 +10,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::{PgPool, Row};
 use tracing::{info, warn};
+use trader_core::{CurrencyBalances, FxRateTable};
 use uuid::Uuid;
 
 // ==================== 타입 정의 ====================
@@ -261,51 +262,35 @@ impl EquityHistoryRepository {
 
     /// 모든 자격증명의 통합 자산 곡선 데이터 조회.
     ///
-    /// 일별로 각 credential의 마지막 스냅샷을 합산하여 전체 포트폴리오를 계산합니다.
+    /// 일별로 각 credential의 마지막 스냅샷을 통화별로 합산한 뒤, 해당 일자 환율로
+    /// 기준 통화(`base_currency`)로 환산하여 전체 포트폴리오를 계산합니다.
+    /// 환율이 없는 통화가 있는 날짜는 합계가 왜곡되므로 제외합니다.
     pub async fn get_aggregated_equity_curve(
         pool: &PgPool,
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
+        fx_rates: &FxRateTable,
+        base_currency: &str,
     ) -> Result<Vec<EquityPoint>, sqlx::Error> {
         let rows = sqlx::query(
             r#"
-            WITH daily_totals AS (
-                SELECT
-                    date_trunc('day', snapshot_time) as day,
-                    SUM(total_equity) as total_equity
-                FROM (
-                    SELECT DISTINCT ON (credential_id, date_trunc('day', snapshot_time))
-                        credential_id,
-                        snapshot_time,
-                        total_equity
-                    FROM portfolio_equity_history
-                    WHERE snapshot_time >= $1
-                      AND snapshot_time <= $2
-                    ORDER BY credential_id, date_trunc('day', snapshot_time), snapshot_time DESC
-                ) sub
-                GROUP BY date_trunc('day', snapshot_time)
-            ),
-            ordered_data AS (
-                SELECT
-                    day as snapshot_time,
-                    total_equity,
-                    FIRST_VALUE(total_equity) OVER (ORDER BY day) as initial_equity,
-                    MAX(total_equity) OVER (ORDER BY day ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) as peak_equity
-                FROM daily_totals
-                ORDER BY day
-            )
             SELECT
-                snapshot_time,
-                total_equity,
-                CASE
-                    WHEN peak_equity > 0 THEN ((peak_equity - total_equity) / peak_equity * 100)
-                    ELSE 0
-                END as drawdown_pct,
-                CASE
-                    WHEN initial_equity > 0 THEN ((total_equity - initial_equity) / initial_equity * 100)
-                    ELSE 0
-                END as return_pct
-            FROM ordered_data
+                date_trunc('day', snapshot_time) as day,
+                currency,
+                SUM(total_equity) as total_equity
+            FROM (
+                SELECT DISTINCT ON (credential_id, date_trunc('day', snapshot_time))
+                    credential_id,
+                    snapshot_time,
+                    total_equity,
+                    COALESCE(currency, 'KRW') as currency
+                FROM portfolio_equity_history
+                WHERE snapshot_time >= $1
+                  AND snapshot_time <= $2
+                ORDER BY credential_id, date_trunc('day', snapshot_time), snapshot_time DESC
+            ) sub
+            GROUP BY date_trunc('day', snapshot_time), currency
+            ORDER BY day
             "#,
         )
         .bind(start_time)
@@ -313,15 +298,23 @@ impl EquityHistoryRepository {
         .fetch_all(pool)
         .await?;
 
-        Ok(rows
-            .into_iter()
-            .map(|r| EquityPoint {
-                timestamp: r.get("snapshot_time"),
-                equity: r.get("total_equity"),
-                drawdown_pct: r.get("drawdown_pct"),
-                return_pct: r.get("return_pct"),
-            })
-            .collect())
+        let mut daily: BTreeMap<DateTime<Utc>, CurrencyBalances> = BTreeMap::new();
+        for row in rows {
+            let currency: String = row.get("currency");
+            daily
+                .entry(row.get("day"))
+                .or_default()
+                .add(&currency, row.get("total_equity"));
+        }
+
+        let mut totals = Vec::with_capacity(daily.len());
+        for (day, balances) in daily {
+            match balances.total_in(base_currency, fx_rates, day.date_naive()) {
+                Ok(total) => totals.push((day, total)),
+                Err(e) => warn!("통합 자산 곡선 환산 실패, 일자 제외: {}", e),
+            }
+        }
+        Ok(equity_points_from_totals(totals))
     }
 
     /// 월별 수익률 조회.
@@ -1034,6 +1027,37 @@ impl EquityHistoryRepository {
     }
 }
 
+/// 시간순 자산 합계로 drawdown/return을 계산합니다 (첫 값 대비 수익률, 누적 고점 대비 낙폭).
+fn equity_points_from_totals(totals: Vec<(DateTime<Utc>, Decimal)>) -> Vec<EquityPoint> {
+    let hundred = Decimal::from(100);
+    let initial = totals
+        .first()
+        .map(|(_, equity)| *equity)
+        .unwrap_or_default();
+    let mut peak = Decimal::ZERO;
+
+    totals
+        .into_iter()
+        .map(|(timestamp, equity)| {
+            peak = peak.max(equity);
+            EquityPoint {
+                timestamp,
+                equity,
+                drawdown_pct: if peak > Decimal::ZERO {
+                    (peak - equity) / peak * hundred
+                } else {
+                    Decimal::ZERO
+                },
+                return_pct: if initial > Decimal::ZERO {
+                    (equity - initial) / initial * hundred
+                } else {
+                    Decimal::ZERO
+                },
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use rust_decimal_macros::dec;
@@ -1069,4 +1093,18 @@ mod tests {
 
         assert_eq!(point.return_pct, dec!(10.0));
     }
+
+    #[test]
+    fn test_equity_points_from_totals() {
+        let day = |d: u32| Utc.with_ymd_and_hms(2025, 1, d, 0, 0, 0).unwrap();
+        let points = equity_points_from_totals(vec![
+            (day(1), dec!(100)),
+            (day(2), dec!(120)),
+            (day(3), dec!(90)),
+        ]);
+
+        assert_eq!(points[1].return_pct, dec!(20));
+        assert_eq!(points[2].drawdown_pct, dec!(25));
+        assert_eq!(points[2].return_pct, dec!(-10));
+    }
 }
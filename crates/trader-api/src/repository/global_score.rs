@@ -303,6 +303,7 @@ impl GlobalScoreRepository {
             "CRYPTO" => MarketType::Crypto,
             "FOREX" => MarketType::Forex,
             "FUTURES" => MarketType::Futures,
+            "OPTIONS" => MarketType::Options,
             _ => MarketType::Stock,
         };
 
@@ -15,7 +15,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sqlx::{FromRow, PgPool};
-use trader_core::{unrealized_pnl, Side};
+use trader_core::{ticker_currency, unrealized_pnl, FxPnl, FxRateTable, MissingFxRate, Side};
 use uuid::Uuid;
 
 use super::cost_basis::{self, CostBasisSummary, CostBasisTracker};
@@ -792,6 +792,16 @@ pub struct PnLSummary {
     pub last_trade_at: Option<DateTime<Utc>>,
 }
 
+/// 종목별 기준 통화 실현 손익.
+#[derive(Debug, Clone)]
+pub struct SymbolFxPnL {
+    pub symbol: String,
+    /// 거래 통화 (심볼 패턴으로 추론)
+    pub currency: String,
+    /// 기준 통화 손익 (환율이 없으면 에러)
+    pub pnl: Result<FxPnl, MissingFxRate>,
+}
+
 /// 동기화 결과.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyncResult {
@@ -1145,6 +1155,59 @@ impl JournalRepository {
         Ok(summaries)
     }
 
+    /// 종목별 기준 통화 실현 손익 (자산/환차손익 분리).
+    ///
+    /// 종목 거래 통화는 심볼 패턴으로 추론하며, 환율이 없는 종목은 `Err`로 반환합니다.
+    pub async fn calculate_fx_pnl(
+        pool: &PgPool,
+        credential_id: Uuid,
+        rates: &FxRateTable,
+        base_currency: &str,
+    ) -> Result<Vec<SymbolFxPnL>, sqlx::Error> {
+        let all_executions = sqlx::query_as::<_, ExecutionRow>(
+            r#"
+            SELECT id, symbol, side, quantity, price,
+                   COALESCE(fee, 0) as fee, executed_at
+            FROM v_journal_executions
+            WHERE credential_id = $1
+            ORDER BY symbol, executed_at ASC
+            "#,
+        )
+        .bind(credential_id)
+        .fetch_all(pool)
+        .await?;
+
+        let mut grouped: std::collections::BTreeMap<String, Vec<cost_basis::TradeExecution>> =
+            std::collections::BTreeMap::new();
+        for e in all_executions {
+            grouped
+                .entry(e.symbol.clone())
+                .or_default()
+                .push(cost_basis::TradeExecution {
+                    id: e.id,
+                    symbol: e.symbol,
+                    side: e.side,
+                    quantity: e.quantity,
+                    price: e.price,
+                    fee: e.fee,
+                    executed_at: e.executed_at,
+                });
+        }
+
+        Ok(grouped
+            .into_iter()
+            .map(|(symbol, executions)| {
+                let currency = ticker_currency(&symbol);
+                let pnl = cost_basis::realized_fx_pnl(executions, &currency, base_currency, rates);
+                SymbolFxPnL {
+                    symbol,
+                    currency,
+                    pnl,
+                }
+            })
+            .collect())
+    }
+
     /// 비용 기준 추적기 반환 (상세 분석용).
     ///
     /// 로트별 상세 정보가 필요한 경우 사용합니다.
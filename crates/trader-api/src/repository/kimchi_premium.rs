@@ -0,0 +1,143 @@
+//! 김치 프리미엄 이력 리포지토리
+//!
+//! 김치 프리미엄 모니터가 샘플링한 스냅샷을 `kimchi_premium_history` 테이블에 저장하여
+//! 재시작 후에도 이력을 조회하고 모니터 메모리 이력을 복원할 수 있게 합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+
+use crate::services::{DomesticVenue, PremiumSnapshot};
+
+#[derive(Debug, FromRow)]
+struct PremiumHistoryRow {
+    venue: String,
+    base: String,
+    recorded_at: DateTime<Utc>,
+    domestic_price_krw: Decimal,
+    foreign_price_usd: Decimal,
+    usd_krw: Decimal,
+    foreign_price_krw: Decimal,
+    premium_pct: Decimal,
+}
+
+impl PremiumHistoryRow {
+    fn into_snapshot(self) -> Option<PremiumSnapshot> {
+        Some(PremiumSnapshot {
+            base: self.base,
+            venue: DomesticVenue::parse(&self.venue)?,
+            domestic_price_krw: self.domestic_price_krw,
+            foreign_price_usd: self.foreign_price_usd,
+            usd_krw: self.usd_krw,
+            foreign_price_krw: self.foreign_price_krw,
+            premium_pct: self.premium_pct,
+            timestamp: self.recorded_at,
+        })
+    }
+}
+
+/// 김치 프리미엄 이력 리포지토리
+#[derive(Clone)]
+pub struct KimchiPremiumRepository {
+    pool: PgPool,
+}
+
+impl KimchiPremiumRepository {
+    /// 새 리포지토리 생성
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 스냅샷 일괄 저장 (같은 시각은 무시)
+    pub async fn save_all(&self, snapshots: &[PremiumSnapshot]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        for snapshot in snapshots {
+            sqlx::query(
+                r#"
+                INSERT INTO kimchi_premium_history (
+                    venue, base, recorded_at, domestic_price_krw, foreign_price_usd,
+                    usd_krw, foreign_price_krw, premium_pct
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (venue, base, recorded_at) DO NOTHING
+                "#,
+            )
+            .bind(snapshot.venue.as_str())
+            .bind(&snapshot.base)
+            .bind(snapshot.timestamp)
+            .bind(snapshot.domestic_price_krw)
+            .bind(snapshot.foreign_price_usd)
+            .bind(snapshot.usd_krw)
+            .bind(snapshot.foreign_price_krw)
+            .bind(snapshot.premium_pct)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await
+    }
+
+    /// 페어의 최근 이력 (오래된 순, 최근 `limit`개)
+    pub async fn history(
+        &self,
+        venue: DomesticVenue,
+        base: &str,
+        limit: i64,
+    ) -> Result<Vec<PremiumSnapshot>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, PremiumHistoryRow>(
+            r#"
+            SELECT * FROM (
+                SELECT venue, base, recorded_at, domestic_price_krw, foreign_price_usd,
+                       usd_krw, foreign_price_krw, premium_pct
+                FROM kimchi_premium_history
+                WHERE venue = $1 AND base = $2
+                ORDER BY recorded_at DESC
+                LIMIT $3
+            ) recent
+            ORDER BY recorded_at ASC
+            "#,
+        )
+        .bind(venue.as_str())
+        .bind(base.to_uppercase())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(PremiumHistoryRow::into_snapshot)
+            .collect())
+    }
+
+    /// `since` 이후 모든 페어의 이력 (시각 오름차순, 모니터 복원용)
+    pub async fn load_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<PremiumSnapshot>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, PremiumHistoryRow>(
+            r#"
+            SELECT venue, base, recorded_at, domestic_price_krw, foreign_price_usd,
+                   usd_krw, foreign_price_krw, premium_pct
+            FROM kimchi_premium_history
+            WHERE recorded_at >= $1
+            ORDER BY recorded_at ASC
+            "#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(PremiumHistoryRow::into_snapshot)
+            .collect())
+    }
+
+    /// 보존 기간이 지난 이력 삭제
+    pub async fn delete_before(&self, cutoff: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM kimchi_premium_history WHERE recorded_at < $1")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
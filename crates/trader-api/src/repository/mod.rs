@@ -7,12 +7,15 @@ pub mod alerts;
 pub mod backtest_results;
 pub mod cost_basis;
 pub mod credentials;
+pub mod decision_traces;
 pub mod equity_history;
 pub mod execution_cache;
 pub mod global_score;
 pub mod journal;
+pub mod kimchi_premium;
 pub mod kis_token;
 pub mod klines;
+pub mod order_routing;
 pub mod orders;
 pub mod portfolio;
 pub mod positions;
@@ -23,6 +26,8 @@ pub mod signal_alert_rule;
 pub mod signal_marker;
 pub mod signal_performance;
 pub mod strategies;
+pub mod strategy_deployments;
+pub mod strategy_snapshots;
 pub mod strategy_watched_tickers;
 pub mod symbol_fundamental;
 pub mod symbol_info;
@@ -37,15 +42,18 @@ pub use backtest_results::{
     ListResultsFilter, ListResultsResponse as BacktestListResponse,
 };
 pub use cost_basis::{
-    build_tracker_from_executions, CostBasisSummary, CostBasisTracker, FifoSaleResult, Lot,
-    LotUsage, TradeExecution,
+    build_tracker_from_executions, realized_fx_pnl, CostBasisSummary, CostBasisTracker,
+    FifoSaleResult, Lot, LotUsage, TradeExecution,
 };
 pub use credentials::{
     create_exchange_providers_from_credential, create_kis_client_from_credential,
-    create_kis_provider_for_sync, create_mock_provider_concrete, create_provider_bundle,
-    create_provider_for_credential, create_provider_for_mock_credential, get_active_credential_id,
-    get_credential_info, CredentialInfo, ExchangeProviderArc, ProviderBundle,
+    create_kis_provider_for_sync, create_mock_provider_concrete,
+    create_order_provider_for_credential, create_provider_bundle, create_provider_for_credential,
+    create_provider_for_mock_credential, create_router_venue, find_active_credential_by_exchange,
+    get_active_credential_id, get_credential_info, wrap_market_data_with_failover, CredentialInfo,
+    ExchangeProviderArc, ProviderBundle,
 };
+pub use decision_traces::DecisionTraceRepository;
 pub use equity_history::{
     EquityHistoryRepository, EquityPoint, ExecutionForSync, MonthlyReturn, PortfolioSnapshot,
     SyncResult,
@@ -72,6 +80,7 @@ pub use journal::{
     // 손익 재계산
     RecalculateResult,
     StrategyPerformance,
+    SymbolFxPnL,
     SymbolPnL,
     SyncResult as JournalSyncResult,
     TradeExecutionInput,
@@ -82,8 +91,10 @@ pub use journal::{
     WeeklyPnL,
     YearlyPnL,
 };
+pub use kimchi_premium::KimchiPremiumRepository;
 pub use kis_token::KisTokenRepository;
 pub use klines::{CacheMetadata, KlineRecord, KlinesRepository, NewKline};
+pub use order_routing::{OrderRoutingRepository, RoutingDecisionSummary};
 pub use orders::{Order, OrderInput, OrderRepository, OrderStatus};
 pub use portfolio::{PortfolioRepository, Position, PositionUpdate};
 pub use positions::{
@@ -99,7 +110,7 @@ pub use score_history::{
 };
 pub use screening::{
     CreatePresetRequest, MomentumScreenResult, ScreeningFilter, ScreeningPreset,
-    ScreeningPresetRecord, ScreeningRepository, ScreeningResult, SectorRsResult,
+    ScreeningPresetRecord, ScreeningRepository, ScreeningResult, SectorCloseRow, SectorRsResult,
 };
 pub use signal_alert_rule::{
     CreateAlertRuleRequest, SignalAlertRule, SignalAlertRuleRepository, UpdateAlertRuleRequest,
@@ -110,6 +121,8 @@ pub use signal_performance::{
     SignalStrengthStats, SignalSymbolStats, SignalTypeStats,
 };
 pub use strategies::StrategyRepository;
+pub use strategy_deployments::StrategyDeploymentRepository;
+pub use strategy_snapshots::StrategySnapshotRepository;
 pub use strategy_watched_tickers::StrategyWatchedTickersRepository;
 pub use symbol_fundamental::{
     IndicatorUpdate, NewSymbolFundamental, SymbolFundamental, SymbolFundamentalRepository,
@@ -0,0 +1,134 @@
+//! 스마트 주문 라우팅 기록 리포지토리
+//!
+//! `SmartOrderRouter`의 라우팅 결정과 실현 체결을 저장하여
+//! 거래소별 실효 비용과 슬리피지를 사후 분석할 수 있게 합니다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sqlx::{FromRow, PgPool};
+use trader_execution::{RoutingError, RoutingRecord, RoutingRecorder, VenueFill};
+use uuid::Uuid;
+
+/// 라우팅 결정 요약 (분석 조회용)
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct RoutingDecisionSummary {
+    pub id: Uuid,
+    pub base_asset: String,
+    pub side: String,
+    pub base_currency: String,
+    pub requested_quantity: Decimal,
+    pub expected_avg_price: Option<Decimal>,
+    pub filled_quantity: Option<Decimal>,
+    pub realised_avg_price: Option<Decimal>,
+    pub slippage_bps: Option<Decimal>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 스마트 주문 라우팅 리포지토리
+pub struct OrderRoutingRepository {
+    pool: PgPool,
+}
+
+impl OrderRoutingRepository {
+    /// 새 리포지토리 생성
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 라우팅 결정 저장 (같은 ID면 주문 결과 갱신)
+    pub async fn save_decision(&self, record: &RoutingRecord) -> Result<(), sqlx::Error> {
+        let plan = &record.plan;
+        let to_json = |v: serde_json::Result<serde_json::Value>| v.unwrap_or_default();
+
+        sqlx::query(
+            r#"
+            INSERT INTO order_routing_decisions (
+                id, base_asset, side, requested_quantity, allocated_quantity,
+                unfilled_quantity, base_currency, expected_avg_price, expected_fee,
+                allocations, venue_quotes, orders, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (id) DO UPDATE SET orders = EXCLUDED.orders
+            "#,
+        )
+        .bind(plan.id)
+        .bind(&plan.base_asset)
+        .bind(plan.side.to_string())
+        .bind(plan.requested_quantity)
+        .bind(plan.allocated_quantity())
+        .bind(plan.unfilled_quantity)
+        .bind(&plan.base_currency)
+        .bind(plan.expected_avg_price_base())
+        .bind(plan.expected_fee_base())
+        .bind(to_json(serde_json::to_value(&plan.allocations)))
+        .bind(to_json(serde_json::to_value(&plan.venue_quotes)))
+        .bind(to_json(serde_json::to_value(&record.orders)))
+        .bind(plan.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 실현 체결 저장
+    pub async fn save_fill(&self, decision_id: Uuid, fill: &VenueFill) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO order_routing_fills (decision_id, venue, quantity, price, price_base, filled_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(decision_id)
+        .bind(&fill.venue)
+        .bind(fill.quantity)
+        .bind(fill.price)
+        .bind(fill.price_base)
+        .bind(fill.filled_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 최근 라우팅 결정 및 실현 슬리피지 조회
+    pub async fn list_recent(
+        &self,
+        base_asset: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<RoutingDecisionSummary>, sqlx::Error> {
+        sqlx::query_as::<_, RoutingDecisionSummary>(
+            r#"
+            SELECT
+                s.decision_id AS id, s.base_asset, s.side, s.base_currency,
+                d.requested_quantity, s.expected_avg_price, s.filled_quantity,
+                s.realised_avg_price, s.slippage_bps, s.created_at
+            FROM v_order_routing_slippage s
+            JOIN order_routing_decisions d ON d.id = s.decision_id
+            WHERE ($1::VARCHAR IS NULL OR s.base_asset = $1)
+            ORDER BY s.created_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(base_asset)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+}
+
+#[async_trait]
+impl RoutingRecorder for OrderRoutingRepository {
+    async fn record_decision(&self, record: &RoutingRecord) -> Result<(), RoutingError> {
+        self.save_decision(record)
+            .await
+            .map_err(|e| RoutingError::Recorder(e.to_string()))
+    }
+
+    async fn record_fill(&self, plan_id: Uuid, fill: &VenueFill) -> Result<(), RoutingError> {
+        self.save_fill(plan_id, fill)
+            .await
+            .map_err(|e| RoutingError::Recorder(e.to_string()))
+    }
+}
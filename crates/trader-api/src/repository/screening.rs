@@ -13,7 +13,7 @@ use tracing::{debug, warn};
 use trader_analytics::indicators::IndicatorEngine;
 use trader_analytics::StructuralFeaturesCalculator;
 use trader_core::{types::Timeframe, Kline};
-use trader_data::{cache::CachedHistoricalDataProvider, RedisCache};
+use trader_data::{cache::CachedHistoricalDataProvider, RedisCache, ScreeningSnapshotRow};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -122,6 +122,30 @@ pub struct ScreeningResult {
     pub confidence: Option<String>,
 }
 
+impl ScreeningResult {
+    /// Parquet 내보내기용 스냅샷 행으로 변환.
+    pub fn to_snapshot_row(&self, snapshot_time: DateTime<Utc>) -> ScreeningSnapshotRow {
+        ScreeningSnapshotRow {
+            snapshot_time,
+            ticker: self.ticker.clone(),
+            name: self.name.clone(),
+            sector: self.sector.clone(),
+            current_price: self.current_price,
+            market_cap: self.market_cap,
+            per: self.per,
+            pbr: self.pbr,
+            roe: self.roe,
+            dividend_yield: self.dividend_yield,
+            price_change_20d: self.price_change_20d,
+            rsi_14: self.rsi_14,
+            route_state: self.route_state.clone(),
+            regime: self.regime.clone(),
+            overall_score: self.overall_score,
+            grade: self.grade.clone(),
+        }
+    }
+}
+
 /// 스크리닝 필터 조건
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ScreeningFilter {
@@ -1004,6 +1028,87 @@ impl ScreeningRepository {
         Ok(results)
     }
 
+    /// 섹터 내 종목의 일봉 종가 조회 (페어 스캔용)
+    ///
+    /// 시가총액 상위 `max_symbols` 종목의 최근 `days`일 종가를 반환합니다.
+    ///
+    /// # Arguments
+    /// * `pool` - Database pool
+    /// * `sector` - 섹터명
+    /// * `market` - 시장 필터 (옵션, `KR-KOSPI` 형식 지원)
+    /// * `days` - 조회 기간 (일)
+    /// * `max_symbols` - 최대 종목 수
+    pub async fn fetch_sector_closes(
+        pool: &PgPool,
+        sector: &str,
+        market: Option<&str>,
+        days: i32,
+        max_symbols: i64,
+    ) -> Result<Vec<SectorCloseRow>, sqlx::Error> {
+        let lookback_date = Utc::now() - Duration::days(days.into());
+
+        // KR-KOSPI 형식 파싱
+        let (market_code, exchange_code) = match market {
+            Some(m) if m.contains('-') => {
+                let parts: Vec<&str> = m.split('-').collect();
+                (Some(parts[0].to_string()), Some(parts[1].to_string()))
+            }
+            Some(m) => (Some(m.to_string()), None),
+            None => (None, None),
+        };
+
+        let market_condition = match (&market_code, &exchange_code) {
+            (Some(_), Some(_)) => "AND sf.market = $4 AND sf.exchange = $5",
+            (Some(_), None) => "AND sf.market = $4",
+            _ => "",
+        };
+
+        let query = format!(
+            r#"
+            WITH sector_symbols AS (
+                SELECT DISTINCT ON (sf.ticker) sf.ticker, sf.market_cap
+                FROM v_symbol_with_fundamental sf
+                WHERE sf.sector = $2
+                  {}
+                ORDER BY sf.ticker
+            ),
+            top_symbols AS (
+                SELECT ticker
+                FROM sector_symbols
+                ORDER BY market_cap DESC NULLS LAST
+                LIMIT $3
+            )
+            SELECT o.symbol as ticker, o.open_time, o.close
+            FROM ohlcv o
+            JOIN top_symbols t ON o.symbol = t.ticker
+            WHERE o.timeframe = '1d'
+              AND o.open_time >= $1
+              AND o.close > 0
+            ORDER BY o.symbol, o.open_time
+        "#,
+            market_condition
+        );
+
+        let mut q = sqlx::query_as::<_, SectorCloseRow>(&query)
+            .bind(lookback_date)
+            .bind(sector)
+            .bind(max_symbols);
+        if let Some(m) = &market_code {
+            q = q.bind(m);
+        }
+        if let Some(e) = &exchange_code {
+            q = q.bind(e);
+        }
+        let rows = q.fetch_all(pool).await?;
+
+        debug!(
+            "섹터 종가 조회 완료: sector={}, rows={}",
+            sector,
+            rows.len()
+        );
+        Ok(rows)
+    }
+
     /// 사전 정의된 스크리닝 프리셋 실행
     pub async fn screen_preset(
         pool: &PgPool,
@@ -1363,6 +1468,17 @@ pub struct SectorRsResult {
     pub total_market_cap: Option<Decimal>,
 }
 
+/// 섹터 종목 일봉 종가 (페어 스캔용)
+#[derive(Debug, Clone, FromRow)]
+pub struct SectorCloseRow {
+    /// 종목 티커
+    pub ticker: String,
+    /// 봉 시작 시각
+    pub open_time: DateTime<Utc>,
+    /// 종가
+    pub close: Decimal,
+}
+
 /// 스크리닝 프리셋 정보 (레거시 - 하드코딩 프리셋용)
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ScreeningPreset {
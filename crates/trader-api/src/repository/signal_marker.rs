@@ -1,6 +1,7 @@
 //! SignalMarker 리포지토리
 //!
 //! 백테스트 및 실거래에서 발생한 기술적 신호를 저장하고 조회합니다.
+//! 결정 추적은 별도 컬럼 없이 `metadata.decision_trace`에 함께 저장합니다.
 
 use std::collections::HashMap;
 
@@ -9,11 +10,14 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde_json::Value as JsonValue;
 use sqlx::PgPool;
-use trader_core::{Side, SignalIndicators, SignalMarker, SignalType, Symbol};
+use trader_core::{DecisionTrace, Side, SignalIndicators, SignalMarker, SignalType, Symbol};
 use uuid::Uuid;
 
 use crate::error::{internal_error, not_found, ApiErrorResponse, ApiResult, BoxedApiError};
 
+/// 결정 추적을 저장하는 metadata 키
+const DECISION_TRACE_KEY: &str = "decision_trace";
+
 /// SignalMarker 리포지토리
 pub struct SignalMarkerRepository {
     pool: PgPool,
@@ -56,8 +60,21 @@ impl SignalMarkerRepository {
             ))
         })?;
 
-        // metadata를 JSONB로 변환
-        let metadata_json = serde_json::to_value(&marker.metadata).map_err(|e| {
+        // metadata를 JSONB로 변환 (결정 추적 포함)
+        let mut metadata = marker.metadata.clone();
+        if let Some(trace) = &marker.decision_trace {
+            let trace_json = serde_json::to_value(trace).map_err(|e| {
+                BoxedApiError::from((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiErrorResponse::new(
+                        "SERIALIZATION_ERROR",
+                        format!("Failed to serialize decision trace: {}", e),
+                    )),
+                ))
+            })?;
+            metadata.insert(DECISION_TRACE_KEY.to_string(), trace_json);
+        }
+        let metadata_json = serde_json::to_value(&metadata).map_err(|e| {
             BoxedApiError::from((
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ApiErrorResponse::new(
@@ -355,8 +372,8 @@ impl SignalMarkerRow {
             })?;
 
         // Metadata 역직렬화
-        let metadata: HashMap<String, JsonValue> =
-            serde_json::from_value(self.metadata).map_err(|e| {
+        let mut metadata: HashMap<String, JsonValue> = serde_json::from_value(self.metadata)
+            .map_err(|e| {
                 BoxedApiError::from((
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(ApiErrorResponse::new(
@@ -366,6 +383,11 @@ impl SignalMarkerRow {
                 ))
             })?;
 
+        // 결정 추적 분리 (형식이 맞지 않으면 무시)
+        let decision_trace = metadata
+            .remove(DECISION_TRACE_KEY)
+            .and_then(|v| serde_json::from_value::<DecisionTrace>(v).ok());
+
         Ok(SignalMarker {
             id: self.id,
             ticker: symbol.to_string(),
@@ -380,6 +402,7 @@ impl SignalMarkerRow {
             strategy_name: self.strategy_name,
             executed: self.executed,
             metadata,
+            decision_trace,
         })
     }
 }
@@ -338,34 +338,43 @@ impl SignalPerformanceRepository {
         .fetch_all(pool)
         .await?;
 
-        let stats = rows
-            .iter()
-            .map(|row| SignalStrategyStats {
-                strategy_id: row.get("strategy_id"),
-                total_signals: row.get("total_signals"),
-                win_count: row.get("win_count"),
-                win_rate: row
-                    .get::<Option<Decimal>, _>("win_rate")
-                    .map(|d| d.to_string().parse().unwrap_or(0.0)),
-                avg_return_1d: row
-                    .get::<Option<Decimal>, _>("avg_return_1d")
-                    .map(|d| d.to_string().parse().unwrap_or(0.0)),
-                avg_return_5d: row
-                    .get::<Option<Decimal>, _>("avg_return_5d")
-                    .map(|d| d.to_string().parse().unwrap_or(0.0)),
-                avg_strength: row
-                    .get::<Option<Decimal>, _>("avg_strength")
-                    .map(|d| d.to_string().parse().unwrap_or(0.0)),
-                avg_mfe: row
-                    .get::<Option<Decimal>, _>("avg_mfe")
-                    .map(|d| d.to_string().parse().unwrap_or(0.0)),
-                avg_mae: row
-                    .get::<Option<Decimal>, _>("avg_mae")
-                    .map(|d| d.to_string().parse().unwrap_or(0.0)),
-            })
-            .collect();
+        Ok(rows.iter().map(strategy_stats_from_row).collect())
+    }
 
-        Ok(stats)
+    /// 최근 N일 신호만 대상으로 한 전략별 신호 통계 조회
+    ///
+    /// 앙상블 전략의 적응형 가중치처럼 최근 성과가 중요한 경우에 사용합니다.
+    pub async fn get_recent_strategy_stats(
+        pool: &PgPool,
+        lookback_days: i32,
+    ) -> Result<Vec<SignalStrategyStats>, sqlx::Error> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                strategy_id,
+                COUNT(*) as total_signals,
+                COUNT(*) FILTER (WHERE is_winner = true) as win_count,
+                ROUND(
+                    100.0 * COUNT(*) FILTER (WHERE is_winner = true)
+                        / NULLIF(COUNT(*) FILTER (WHERE is_winner IS NOT NULL), 0),
+                    2
+                ) as win_rate,
+                ROUND(AVG(return_1d)::NUMERIC, 4) as avg_return_1d,
+                ROUND(AVG(return_5d)::NUMERIC, 4) as avg_return_5d,
+                ROUND(AVG(strength)::NUMERIC, 4) as avg_strength,
+                ROUND(AVG(max_return)::NUMERIC, 4) as avg_mfe,
+                ROUND(AVG(max_drawdown)::NUMERIC, 4) as avg_mae
+            FROM signal_performance
+            WHERE calculated_at IS NOT NULL
+              AND created_at >= NOW() - make_interval(days => $1)
+            GROUP BY strategy_id
+            "#,
+        )
+        .bind(lookback_days)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.iter().map(strategy_stats_from_row).collect())
     }
 
     /// 특정 심볼의 신호-수익률 상관관계 데이터 조회 (산점도용)
@@ -521,3 +530,30 @@ impl SignalPerformanceRepository {
         }))
     }
 }
+
+/// `strategy_id`별 집계 행을 통계로 변환
+fn strategy_stats_from_row(row: &sqlx::postgres::PgRow) -> SignalStrategyStats {
+    SignalStrategyStats {
+        strategy_id: row.get("strategy_id"),
+        total_signals: row.get("total_signals"),
+        win_count: row.get("win_count"),
+        win_rate: row
+            .get::<Option<Decimal>, _>("win_rate")
+            .map(|d| d.to_string().parse().unwrap_or(0.0)),
+        avg_return_1d: row
+            .get::<Option<Decimal>, _>("avg_return_1d")
+            .map(|d| d.to_string().parse().unwrap_or(0.0)),
+        avg_return_5d: row
+            .get::<Option<Decimal>, _>("avg_return_5d")
+            .map(|d| d.to_string().parse().unwrap_or(0.0)),
+        avg_strength: row
+            .get::<Option<Decimal>, _>("avg_strength")
+            .map(|d| d.to_string().parse().unwrap_or(0.0)),
+        avg_mfe: row
+            .get::<Option<Decimal>, _>("avg_mfe")
+            .map(|d| d.to_string().parse().unwrap_or(0.0)),
+        avg_mae: row
+            .get::<Option<Decimal>, _>("avg_mae")
+            .map(|d| d.to_string().parse().unwrap_or(0.0)),
+    }
+}
@@ -486,6 +486,9 @@ impl StrategyRepository {
                     .await
                 {
                     Ok(_) => {
+                        // 전략 타임프레임 캔들만 전달
+                        let timeframe = record.timeframe.as_deref().and_then(|tf| tf.parse().ok());
+                        let _ = engine.set_strategy_timeframe(&record.id, timeframe).await;
                         tracing::info!("Loaded strategy from DB: {} ({})", record.name, record.id);
                         loaded_count += 1;
                     }
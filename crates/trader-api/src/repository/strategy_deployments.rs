@@ -0,0 +1,255 @@
+//! 전략 배포 이력 리포지토리
+//!
+//! `StrategyEngine`의 버전 배포(검증/드라이런/교체/롤백)를 `strategy_deployments`
+//! 테이블에 버전별로 기록합니다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use trader_strategy::{
+    DeploymentError, DeploymentStatus, NewStrategyDeployment, StrategyDeployment,
+    StrategyDeploymentStore,
+};
+
+const DEPLOYMENT_COLUMNS: &str = "strategy_id, version, strategy_type, strategy_version, source, \
+     status, config, changes, dry_run, state_carried_over, rollback_of, message, created_at";
+
+/// 배포 레코드
+#[derive(Debug, Clone, FromRow)]
+struct DeploymentRow {
+    strategy_id: String,
+    version: i64,
+    strategy_type: String,
+    strategy_version: String,
+    source: String,
+    status: String,
+    config: serde_json::Value,
+    changes: serde_json::Value,
+    dry_run: Option<serde_json::Value>,
+    state_carried_over: bool,
+    rollback_of: Option<i64>,
+    message: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<DeploymentRow> for StrategyDeployment {
+    type Error = DeploymentError;
+
+    fn try_from(row: DeploymentRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            strategy_id: row.strategy_id,
+            version: row.version,
+            strategy_type: row.strategy_type,
+            strategy_version: row.strategy_version,
+            source: row.source.parse()?,
+            status: row.status.parse()?,
+            config: row.config,
+            changes: serde_json::from_value(row.changes).map_err(serialization_error)?,
+            dry_run: row
+                .dry_run
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(serialization_error)?,
+            state_carried_over: row.state_carried_over,
+            rollback_of: row.rollback_of,
+            message: row.message,
+            created_at: row.created_at,
+        })
+    }
+}
+
+fn storage_error(e: sqlx::Error) -> DeploymentError {
+    DeploymentError::Storage(e.to_string())
+}
+
+fn serialization_error(e: serde_json::Error) -> DeploymentError {
+    DeploymentError::Serialization(e.to_string())
+}
+
+/// 배포 레코드 삽입 (버전은 전략별 최대값 + 1, 동시 저장 충돌은 PK가 막음)
+async fn insert_deployment<'e, E>(
+    executor: E,
+    deployment: NewStrategyDeployment,
+) -> Result<StrategyDeployment, DeploymentError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let changes = serde_json::to_value(&deployment.changes).map_err(serialization_error)?;
+    let dry_run = deployment
+        .dry_run
+        .as_ref()
+        .map(serde_json::to_value)
+        .transpose()
+        .map_err(serialization_error)?;
+
+    let row = sqlx::query_as::<_, DeploymentRow>(&format!(
+        r#"
+        INSERT INTO strategy_deployments (
+            strategy_id, version, strategy_type, strategy_version, source, status,
+            config, changes, dry_run, state_carried_over, rollback_of, message
+        )
+        SELECT $1::VARCHAR, COALESCE(MAX(version), 0) + 1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11
+        FROM strategy_deployments
+        WHERE strategy_id = $1
+        RETURNING {DEPLOYMENT_COLUMNS}
+        "#
+    ))
+    .bind(&deployment.strategy_id)
+    .bind(&deployment.strategy_type)
+    .bind(&deployment.strategy_version)
+    .bind(deployment.source.as_str())
+    .bind(deployment.status.as_str())
+    .bind(&deployment.config)
+    .bind(changes)
+    .bind(dry_run)
+    .bind(deployment.state_carried_over)
+    .bind(deployment.rollback_of)
+    .bind(&deployment.message)
+    .fetch_one(executor)
+    .await
+    .map_err(storage_error)?;
+
+    row.try_into()
+}
+
+/// 전략 배포 이력 리포지토리
+pub struct StrategyDeploymentRepository {
+    pool: PgPool,
+}
+
+impl StrategyDeploymentRepository {
+    /// 새 리포지토리 생성
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 전략의 모든 배포 이력 삭제 (전략 삭제 시)
+    pub async fn delete_all(&self, strategy_id: &str) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM strategy_deployments WHERE strategy_id = $1")
+            .bind(strategy_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[async_trait]
+impl StrategyDeploymentStore for StrategyDeploymentRepository {
+    async fn save(
+        &self,
+        deployment: NewStrategyDeployment,
+    ) -> Result<StrategyDeployment, DeploymentError> {
+        insert_deployment(&self.pool, deployment).await
+    }
+
+    async fn get(
+        &self,
+        strategy_id: &str,
+        version: i64,
+    ) -> Result<Option<StrategyDeployment>, DeploymentError> {
+        sqlx::query_as::<_, DeploymentRow>(&format!(
+            "SELECT {DEPLOYMENT_COLUMNS} FROM strategy_deployments \
+             WHERE strategy_id = $1 AND version = $2"
+        ))
+        .bind(strategy_id)
+        .bind(version)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(storage_error)?
+        .map(TryInto::try_into)
+        .transpose()
+    }
+
+    async fn list(
+        &self,
+        strategy_id: &str,
+        limit: usize,
+    ) -> Result<Vec<StrategyDeployment>, DeploymentError> {
+        sqlx::query_as::<_, DeploymentRow>(&format!(
+            "SELECT {DEPLOYMENT_COLUMNS} FROM strategy_deployments \
+             WHERE strategy_id = $1 ORDER BY version DESC LIMIT $2"
+        ))
+        .bind(strategy_id)
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(storage_error)?
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect()
+    }
+
+    async fn set_status(
+        &self,
+        strategy_id: &str,
+        version: i64,
+        status: DeploymentStatus,
+    ) -> Result<(), DeploymentError> {
+        let result = sqlx::query(
+            "UPDATE strategy_deployments SET status = $3 WHERE strategy_id = $1 AND version = $2",
+        )
+        .bind(strategy_id)
+        .bind(version)
+        .bind(status.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(storage_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DeploymentError::NotFound {
+                strategy_id: strategy_id.to_string(),
+                version,
+            });
+        }
+        Ok(())
+    }
+
+    async fn activate(
+        &self,
+        deployment: NewStrategyDeployment,
+        replaced_version: i64,
+        replaced_status: DeploymentStatus,
+    ) -> Result<StrategyDeployment, DeploymentError> {
+        let mut tx = self.pool.begin().await.map_err(storage_error)?;
+
+        // 다른 배포가 먼저 대체했으면 아무것도 기록하지 않음 (tx drop 시 롤백)
+        let result = sqlx::query(
+            "UPDATE strategy_deployments SET status = $3 \
+             WHERE strategy_id = $1 AND version = $2 AND status = 'active'",
+        )
+        .bind(&deployment.strategy_id)
+        .bind(replaced_version)
+        .bind(replaced_status.as_str())
+        .execute(&mut *tx)
+        .await
+        .map_err(storage_error)?;
+        if result.rows_affected() == 0 {
+            return Err(DeploymentError::Conflict(format!(
+                "{} v{}는 활성 버전이 아님",
+                deployment.strategy_id, replaced_version
+            )));
+        }
+
+        let activated = insert_deployment(&mut *tx, deployment).await?;
+        tx.commit().await.map_err(storage_error)?;
+
+        Ok(activated)
+    }
+
+    async fn active(
+        &self,
+        strategy_id: &str,
+    ) -> Result<Option<StrategyDeployment>, DeploymentError> {
+        sqlx::query_as::<_, DeploymentRow>(&format!(
+            "SELECT {DEPLOYMENT_COLUMNS} FROM strategy_deployments \
+             WHERE strategy_id = $1 AND status = 'active' ORDER BY version DESC LIMIT 1"
+        ))
+        .bind(strategy_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(storage_error)?
+        .map(TryInto::try_into)
+        .transpose()
+    }
+}
@@ -0,0 +1,200 @@
+//! 전략 상태 스냅샷 리포지토리
+//!
+//! `StrategyEngine`의 체크포인트를 `strategy_snapshots` 테이블에 버전별로 저장하여
+//! 재시작 시 전략 내부 상태를 복원하고 과거 버전으로 롤백할 수 있게 합니다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use trader_strategy::{
+    CheckpointError, NewStrategySnapshot, SnapshotSummary, StrategySnapshot, StrategyStateStore,
+};
+
+/// 스냅샷 레코드
+#[derive(Debug, Clone, FromRow)]
+struct SnapshotRow {
+    strategy_id: String,
+    version: i64,
+    strategy_name: String,
+    strategy_version: String,
+    reason: String,
+    source_version: Option<i64>,
+    state: Vec<u8>,
+    diagnostics: serde_json::Value,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<SnapshotRow> for StrategySnapshot {
+    type Error = CheckpointError;
+
+    fn try_from(row: SnapshotRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            strategy_id: row.strategy_id,
+            version: row.version,
+            strategy_name: row.strategy_name,
+            strategy_version: row.strategy_version,
+            reason: row.reason.parse()?,
+            source_version: row.source_version,
+            state: row.state,
+            diagnostics: row.diagnostics,
+            created_at: row.created_at,
+        })
+    }
+}
+
+/// 스냅샷 요약 레코드
+#[derive(Debug, Clone, FromRow)]
+struct SummaryRow {
+    strategy_id: String,
+    version: i64,
+    strategy_name: String,
+    strategy_version: String,
+    reason: String,
+    source_version: Option<i64>,
+    size_bytes: i64,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<SummaryRow> for SnapshotSummary {
+    type Error = CheckpointError;
+
+    fn try_from(row: SummaryRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            strategy_id: row.strategy_id,
+            version: row.version,
+            strategy_name: row.strategy_name,
+            strategy_version: row.strategy_version,
+            reason: row.reason.parse()?,
+            source_version: row.source_version,
+            size_bytes: row.size_bytes,
+            created_at: row.created_at,
+        })
+    }
+}
+
+fn storage_error(e: sqlx::Error) -> CheckpointError {
+    CheckpointError::Storage(e.to_string())
+}
+
+/// 전략 상태 스냅샷 리포지토리
+pub struct StrategySnapshotRepository {
+    pool: PgPool,
+}
+
+impl StrategySnapshotRepository {
+    /// 새 리포지토리 생성
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 전략의 모든 스냅샷 삭제 (전략 삭제 시)
+    pub async fn delete_all(&self, strategy_id: &str) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM strategy_snapshots WHERE strategy_id = $1")
+            .bind(strategy_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[async_trait]
+impl StrategyStateStore for StrategySnapshotRepository {
+    async fn save(
+        &self,
+        snapshot: NewStrategySnapshot,
+    ) -> Result<StrategySnapshot, CheckpointError> {
+        // 버전은 전략별 최대값 + 1 (동시 저장 충돌은 PK가 막음)
+        let row = sqlx::query_as::<_, SnapshotRow>(
+            r#"
+            INSERT INTO strategy_snapshots (
+                strategy_id, version, strategy_name, strategy_version,
+                reason, source_version, state, diagnostics
+            )
+            SELECT $1::VARCHAR, COALESCE(MAX(version), 0) + 1, $2, $3, $4, $5, $6, $7
+            FROM strategy_snapshots
+            WHERE strategy_id = $1
+            RETURNING strategy_id, version, strategy_name, strategy_version,
+                      reason, source_version, state, diagnostics, created_at
+            "#,
+        )
+        .bind(&snapshot.strategy_id)
+        .bind(&snapshot.strategy_name)
+        .bind(&snapshot.strategy_version)
+        .bind(snapshot.reason.as_str())
+        .bind(snapshot.source_version)
+        .bind(&snapshot.state)
+        .bind(&snapshot.diagnostics)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(storage_error)?;
+
+        row.try_into()
+    }
+
+    async fn latest(&self, strategy_id: &str) -> Result<Option<StrategySnapshot>, CheckpointError> {
+        sqlx::query_as::<_, SnapshotRow>(
+            r#"
+            SELECT strategy_id, version, strategy_name, strategy_version,
+                   reason, source_version, state, diagnostics, created_at
+            FROM strategy_snapshots
+            WHERE strategy_id = $1
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(strategy_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(storage_error)?
+        .map(TryInto::try_into)
+        .transpose()
+    }
+
+    async fn get(
+        &self,
+        strategy_id: &str,
+        version: i64,
+    ) -> Result<Option<StrategySnapshot>, CheckpointError> {
+        sqlx::query_as::<_, SnapshotRow>(
+            r#"
+            SELECT strategy_id, version, strategy_name, strategy_version,
+                   reason, source_version, state, diagnostics, created_at
+            FROM strategy_snapshots
+            WHERE strategy_id = $1 AND version = $2
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(version)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(storage_error)?
+        .map(TryInto::try_into)
+        .transpose()
+    }
+
+    async fn list(
+        &self,
+        strategy_id: &str,
+        limit: usize,
+    ) -> Result<Vec<SnapshotSummary>, CheckpointError> {
+        sqlx::query_as::<_, SummaryRow>(
+            r#"
+            SELECT strategy_id, version, strategy_name, strategy_version,
+                   reason, source_version, octet_length(state)::BIGINT AS size_bytes, created_at
+            FROM strategy_snapshots
+            WHERE strategy_id = $1
+            ORDER BY version DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(storage_error)?
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect()
+    }
+}
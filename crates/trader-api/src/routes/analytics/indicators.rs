@@ -2,23 +2,35 @@
 //!
 //! SMA, EMA, RSI, MACD, 볼린저 밴드, 스토캐스틱, ATR 등의 지표 API를 제공합니다.
 
-use axum::{extract::Query, response::IntoResponse, Json};
+use std::{collections::HashSet, sync::Arc};
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
 use chrono::{Duration, Utc};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use trader_analytics::{
-    AtrParams, BollingerBandsParams, EmaParams, IndicatorEngine, KeltnerChannelParams, MacdParams,
-    ObvParams, RsiParams, SmaParams, StochasticParams, SuperTrendParams, VwapParams,
+    AtrParams, BollingerBandsParams, EmaParams, IndicatorEngine, IndicatorState,
+    KeltnerChannelParams, MacdParams, ObvParams, RsiParams, SmaParams, StochasticParams,
+    SuperTrendParams, VwapParams,
 };
+use trader_core::{domain::IndicatorUpdater, Timeframe};
+use trader_data::IndicatorStateRepository;
 
 use super::types::{
     AtrQuery, AvailableIndicatorsResponse, BollingerQuery, CalculateIndicatorsRequest,
     CalculateIndicatorsResponse, EmaQuery, IndicatorDataResponse, IndicatorInfo, IndicatorPoint,
-    IndicatorSeries, KeltnerParamsResponse, KeltnerPointResponse, KeltnerQuery, KeltnerResponse,
-    MacdQuery, ObvPointResponse, ObvQuery, ObvResponse, RsiQuery, SmaQuery, StochasticQuery,
-    SuperTrendParamsResponse, SuperTrendPointResponse, SuperTrendQuery, SuperTrendResponse,
-    VwapParamsResponse, VwapPointResponse, VwapQuery, VwapResponse,
+    IndicatorSeries, IndicatorStateQuery, IndicatorStateResponse, KeltnerParamsResponse,
+    KeltnerPointResponse, KeltnerQuery, KeltnerResponse, MacdQuery, ObvPointResponse, ObvQuery,
+    ObvResponse, RsiQuery, SmaQuery, StochasticQuery, SuperTrendParamsResponse,
+    SuperTrendPointResponse, SuperTrendQuery, SuperTrendResponse, VwapParamsResponse,
+    VwapPointResponse, VwapQuery, VwapResponse,
 };
+use crate::{routes::strategies::ApiError, state::AppState};
 
 /// 사용 가능한 지표 목록 조회.
 #[utoipa::path(
@@ -1590,3 +1602,82 @@ pub async fn get_supertrend_indicator(Query(query): Query<SuperTrendQuery>) -> i
         }
     }
 }
+
+/// 증분 지표 상태 조회.
+///
+/// 전략 엔진이 캔들마다 갱신하는 RSI/MACD/볼린저/ATR/SuperTrend 최신 값을 재계산 없이 반환합니다.
+/// 메모리 캐시에 없는 종목은 수집기가 저장한 `indicator_state`에서 읽습니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/analytics/indicators/state",
+    tag = "analytics",
+    params(IndicatorStateQuery),
+    responses(
+        (status = 200, description = "증분 지표 상태 조회 성공", body = IndicatorStateResponse),
+        (status = 400, description = "잘못된 타임프레임", body = ApiError),
+        (status = 500, description = "서버 오류", body = ApiError)
+    )
+)]
+pub async fn get_indicator_state(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<IndicatorStateQuery>,
+) -> Result<Json<IndicatorStateResponse>, (StatusCode, Json<ApiError>)> {
+    let timeframe = match query.timeframe.as_deref() {
+        Some(tf) => Some(tf.parse::<Timeframe>().map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new("INVALID_TIMEFRAME", e)),
+            )
+        })?),
+        None => None,
+    };
+    let matches = |ticker: &str, tf: Timeframe| {
+        query.symbol.as_deref().map_or(true, |s| s == ticker) && timeframe.map_or(true, |t| t == tf)
+    };
+
+    let cache = &state.indicator_states;
+    let mut snapshots: Vec<_> = match (query.symbol.as_deref(), timeframe) {
+        (Some(symbol), Some(tf)) => cache.snapshot(symbol, tf).into_iter().collect(),
+        _ => cache
+            .snapshots()
+            .into_iter()
+            .filter(|s| matches(&s.ticker, s.timeframe))
+            .collect(),
+    };
+
+    // 캐시에 없는 종목은 수집기가 저장한 상태로 보완
+    if let Some(pool) = &state.db_pool {
+        let cached: HashSet<_> = snapshots
+            .iter()
+            .map(|s| (s.ticker.clone(), s.timeframe))
+            .collect();
+        let tf_filter = timeframe.map(|tf| tf.to_string());
+        let stored = IndicatorStateRepository::new(pool.clone())
+            .load_all(tf_filter.as_deref())
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new("DB_ERROR", e.to_string())),
+                )
+            })?;
+        snapshots.extend(
+            stored
+                .iter()
+                .filter_map(|s| IndicatorState::from_stored(s, cache.config()))
+                .filter(|s| matches(s.ticker(), s.timeframe()))
+                .filter(|s| !cached.contains(&(s.ticker().to_string(), s.timeframe())))
+                .filter_map(|s| s.snapshot()),
+        );
+        snapshots.sort_by(|a, b| {
+            a.ticker
+                .cmp(&b.ticker)
+                .then_with(|| a.timeframe.as_secs().cmp(&b.timeframe.as_secs()))
+        });
+    }
+
+    Ok(Json(IndicatorStateResponse {
+        count: snapshots.len(),
+        snapshots,
+    }))
+}
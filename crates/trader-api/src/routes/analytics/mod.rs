@@ -21,6 +21,7 @@
 //! - `GET /api/v1/analytics/indicators/stochastic` - 스토캐스틱
 //! - `GET /api/v1/analytics/indicators/atr` - ATR
 //! - `POST /api/v1/analytics/indicators/calculate` - 다중 지표 계산
+//! - `GET /api/v1/analytics/indicators/state` - 증분 지표 상태 (최신 값, 재계산 없음)
 
 pub mod charts;
 pub mod indicators;
@@ -38,9 +39,9 @@ use charts::{
 };
 use indicators::{
     calculate_indicators, get_atr_indicator, get_available_indicators, get_bollinger_indicator,
-    get_correlation, get_ema_indicator, get_keltner_indicator, get_macd_indicator,
-    get_obv_indicator, get_rsi_indicator, get_sma_indicator, get_stochastic_indicator,
-    get_supertrend_indicator, get_volume_profile, get_vwap_indicator,
+    get_correlation, get_ema_indicator, get_indicator_state, get_keltner_indicator,
+    get_macd_indicator, get_obv_indicator, get_rsi_indicator, get_sma_indicator,
+    get_stochastic_indicator, get_supertrend_indicator, get_volume_profile, get_vwap_indicator,
 };
 // Re-export manager
 pub use manager::AnalyticsManager;
@@ -85,5 +86,6 @@ pub fn analytics_router() -> Router<Arc<AppState>> {
         .route("/indicators/keltner", get(get_keltner_indicator))
         .route("/indicators/obv", get(get_obv_indicator))
         .route("/indicators/supertrend", get(get_supertrend_indicator))
+        .route("/indicators/state", get(get_indicator_state))
         .route("/correlation", get(get_correlation))
 }
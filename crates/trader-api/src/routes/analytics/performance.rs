@@ -142,8 +142,15 @@ pub async fn get_performance(
             EquityHistoryRepository::get_equity_curve(db_pool, cred_id, start_time, end_time).await
         } else {
             debug!("전체 계좌 통합 성과 조회");
-            EquityHistoryRepository::get_aggregated_equity_curve(db_pool, start_time, end_time)
-                .await
+            let fx_rates = state.fx_rates.table().await;
+            EquityHistoryRepository::get_aggregated_equity_curve(
+                db_pool,
+                start_time,
+                end_time,
+                &fx_rates,
+                state.fx_rates.base_currency(),
+            )
+            .await
         };
 
         match data_result {
@@ -5,6 +5,7 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use trader_analytics::portfolio::{ChartPoint, MonthlyReturnCell, PerformanceSummary};
+use trader_core::domain::IndicatorSnapshot;
 use utoipa::{IntoParams, ToSchema};
 
 // ==================== 쿼리 파라미터 ====================
@@ -874,6 +875,24 @@ pub struct SuperTrendParamsResponse {
     pub multiplier: f64,
 }
 
+/// 증분 지표 상태 조회 쿼리.
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct IndicatorStateQuery {
+    /// 종목 코드 (없으면 전체)
+    pub symbol: Option<String>,
+    /// 타임프레임 (예: 1d, 1h; 없으면 전체)
+    pub timeframe: Option<String>,
+}
+
+/// 증분 지표 상태 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IndicatorStateResponse {
+    /// 스냅샷 수
+    pub count: usize,
+    /// 종목/타임프레임별 최신 지표 스냅샷 (종목, 타임프레임 순)
+    pub snapshots: Vec<IndicatorSnapshot>,
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
@@ -23,8 +23,12 @@ use chrono::{NaiveDate, TimeZone, Utc};
 use rust_decimal::Decimal;
 use tokio::sync::RwLock;
 use tracing::debug;
-use trader_analytics::backtest::{BacktestConfig, BacktestEngine, BacktestReport};
-use trader_core::{Kline, MarketType, StrategyContext, Symbol, Timeframe};
+use trader_analytics::backtest::{
+    BacktestConfig, BacktestEngine, BacktestReport, BacktestScreeningProvider,
+};
+use trader_core::{
+    Kline, MarketType, ScreeningCalculator, StrategyContext, Symbol, Timeframe, TradingCalendar,
+};
 use trader_strategy::StrategyRegistry;
 
 use super::{
@@ -39,11 +43,17 @@ use super::{
 ///
 /// CPU-intensive 백테스트 계산을 `spawn_blocking`으로 별도 thread pool에서 실행하여
 /// Tokio async runtime의 worker thread를 블로킹하지 않습니다.
+/// `calendars`는 라이브 스케줄러와 같은 KIS 휴장일 캘린더로, 일정 이벤트가
+/// 실거래와 같은 거래일에 발생하도록 합니다.
+/// `screening`은 스크리닝 기반 전략의 Provider로, 펀더멘털 시점 이력이 있으면
+/// 각 시점까지 알려진 값만 스크리닝에 반영합니다.
 pub async fn run_strategy_backtest(
     strategy_id: &str,
     config: BacktestConfig,
     klines: &[Kline],
     params: &Option<serde_json::Value>,
+    calendars: Vec<TradingCalendar>,
+    screening: Option<BacktestScreeningProvider>,
 ) -> Result<BacktestReport, String> {
     // 데이터를 owned 타입으로 변환하여 spawn_blocking으로 이동
     let strategy_id = strategy_id.to_string();
@@ -63,6 +73,8 @@ pub async fn run_strategy_backtest(
             config,
             &klines,
             &params,
+            calendars,
+            screening,
         ))
     })
     .await
@@ -71,6 +83,13 @@ pub async fn run_strategy_backtest(
     Ok(report)
 }
 
+/// 거래 캘린더를 적용한 백테스트 엔진 생성
+fn new_engine(config: BacktestConfig, calendars: Vec<TradingCalendar>) -> BacktestEngine {
+    calendars
+        .into_iter()
+        .fold(BacktestEngine::new(config), BacktestEngine::with_calendar)
+}
+
 /// SDUI params에 ticker 주입
 ///
 /// SDUI에서 ticker가 제공되지 않은 경우, klines에서 추출한 ticker를 주입합니다.
@@ -103,8 +122,10 @@ async fn run_strategy_backtest_inner(
     config: BacktestConfig,
     klines: &[Kline],
     params: &Option<serde_json::Value>,
+    calendars: Vec<TradingCalendar>,
+    screening: Option<BacktestScreeningProvider>,
 ) -> Result<BacktestReport, String> {
-    let mut engine = BacktestEngine::new(config);
+    let mut engine = new_engine(config, calendars);
 
     // 심볼 추출 (klines에서)
     let symbol_str = if let Some(first_kline) = klines.first() {
@@ -142,7 +163,13 @@ async fn run_strategy_backtest_inner(
 
     // run 사용: RouteState, GlobalScore, StructuralFeatures 등 지표 계산
     engine
-        .run(&mut *strategy, klines, context, &symbol_str, None)
+        .run(
+            &mut *strategy,
+            klines,
+            context,
+            &symbol_str,
+            screening.as_ref().map(|p| p as &dyn ScreeningCalculator),
+        )
         .await
         .map_err(|e| e.to_string())
 }
@@ -157,6 +184,8 @@ pub async fn run_multi_strategy_backtest(
     merged_klines: &[Kline],
     multi_klines: &HashMap<String, Vec<Kline>>,
     params: &Option<serde_json::Value>,
+    calendars: Vec<TradingCalendar>,
+    screening: Option<BacktestScreeningProvider>,
 ) -> Result<BacktestReport, String> {
     // 데이터를 owned 타입으로 변환하여 spawn_blocking으로 이동
     let strategy_id = strategy_id.to_string();
@@ -178,6 +207,8 @@ pub async fn run_multi_strategy_backtest(
             &merged_klines,
             &multi_klines,
             &params,
+            calendars,
+            screening,
         ))
     })
     .await
@@ -238,9 +269,11 @@ async fn run_multi_strategy_backtest_inner(
     merged_klines: &[Kline],
     multi_klines: &HashMap<String, Vec<Kline>>,
     params: &Option<serde_json::Value>,
+    calendars: Vec<TradingCalendar>,
+    screening: Option<BacktestScreeningProvider>,
 ) -> Result<BacktestReport, String> {
     let initial_capital = config.initial_capital;
-    let mut engine = BacktestEngine::new(config);
+    let mut engine = new_engine(config, calendars);
 
     // 심볼 목록 추출
     let symbols: Vec<String> = multi_klines.keys().cloned().collect();
@@ -280,7 +313,13 @@ async fn run_multi_strategy_backtest_inner(
     // run 사용: RouteState, GlobalScore, StructuralFeatures 등 지표 계산
     // multi_klines는 이미 StrategyContext에 등록됨 (위 코드 참조)
     engine
-        .run(&mut *strategy, merged_klines, context, primary_ticker, None)
+        .run(
+            &mut *strategy,
+            merged_klines,
+            context,
+            primary_ticker,
+            screening.as_ref().map(|p| p as &dyn ScreeningCalculator),
+        )
         .await
         .map_err(|e| e.to_string())
 }
@@ -35,6 +35,7 @@ use loader::{
 use rust_decimal::Decimal;
 use tracing::{debug, warn};
 use trader_analytics::backtest::BacktestConfig;
+use trader_core::TradingCalendar;
 use trader_strategy::StrategyRegistry;
 pub use types::{
     BacktestApiError,
@@ -299,6 +300,18 @@ pub async fn run_backtest(
             .with_commission_rate(commission_rate)
             .with_slippage_rate(slippage_rate);
 
+        // 라이브와 같은 KIS 휴장일 캘린더
+        let calendars = state.load_trading_calendars(start_date, end_date).await;
+        // 스크리닝 기반 전략은 펀더멘털 시점 이력 적용
+        let screening = state
+            .load_screening_provider(
+                &request.strategy_id,
+                &expanded_symbols,
+                start_date,
+                end_date,
+            )
+            .await;
+
         // 모든 전략은 동일한 run_strategy_backtest 함수로 처리 (하드코딩 방지)
         // 병합된 캔들 데이터를 전달하여 전략이 필요한 심볼 데이터를 자체적으로 처리
         let report = run_strategy_backtest(
@@ -306,6 +319,8 @@ pub async fn run_backtest(
             config,
             &merged_klines,
             &request.parameters,
+            calendars,
+            screening,
         )
         .await
         .map_err(|e| {
@@ -390,15 +405,34 @@ pub async fn run_backtest(
         .with_commission_rate(commission_rate)
         .with_slippage_rate(slippage_rate);
 
+    // 라이브와 같은 KIS 휴장일 캘린더
+    let calendars = state.load_trading_calendars(start_date, end_date).await;
+    // 스크리닝 기반 전략은 펀더멘털 시점 이력 적용
+    let screening = state
+        .load_screening_provider(
+            &request.strategy_id,
+            std::slice::from_ref(&request.symbol),
+            start_date,
+            end_date,
+        )
+        .await;
+
     // 전략별 백테스트 실행
-    let report = run_strategy_backtest(&request.strategy_id, config, &klines, &request.parameters)
-        .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(BacktestApiError::new("BACKTEST_ERROR", e.to_string())),
-            )
-        })?;
+    let report = run_strategy_backtest(
+        &request.strategy_id,
+        config,
+        &klines,
+        &request.parameters,
+        calendars,
+        screening,
+    )
+    .await
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(BacktestApiError::new("BACKTEST_ERROR", e.to_string())),
+        )
+    })?;
 
     // BacktestReport를 API 응답으로 변환
     let response = convert_report_to_response(
@@ -631,6 +665,18 @@ pub async fn run_multi_backtest(
         .with_commission_rate(commission_rate)
         .with_slippage_rate(slippage_rate);
 
+    // 라이브와 같은 KIS 휴장일 캘린더
+    let calendars = state.load_trading_calendars(start_date, end_date).await;
+    // 스크리닝 기반 전략은 펀더멘털 시점 이력 적용
+    let screening = state
+        .load_screening_provider(
+            &request.strategy_id,
+            &expanded_symbols,
+            start_date,
+            end_date,
+        )
+        .await;
+
     // 전략별 백테스트 실행 (다중 심볼 지원)
     let report = run_multi_strategy_backtest(
         &request.strategy_id,
@@ -638,6 +684,8 @@ pub async fn run_multi_backtest(
         &merged_klines,
         &multi_klines,
         &request.parameters,
+        calendars,
+        screening,
     )
     .await
     .map_err(|e| {
@@ -760,12 +808,16 @@ pub async fn run_batch_backtest(
     let commission_rate = request.commission_rate.unwrap_or(Decimal::new(1, 3));
     let slippage_rate = request.slippage_rate.unwrap_or(Decimal::new(5, 4));
 
+    // 라이브와 같은 KIS 휴장일 캘린더 (모든 전략 공통)
+    let calendars = state.load_trading_calendars(start_date, end_date).await;
+
     // 각 전략에 대한 백테스트 Future 생성
     let backtest_futures: Vec<_> = request
         .strategies
         .into_iter()
         .map(|item| {
             let state = Arc::clone(&state);
+            let calendars = calendars.clone();
             let initial_capital = request.initial_capital;
             let strategy_id = item.strategy_id.clone();
 
@@ -786,6 +838,7 @@ pub async fn run_batch_backtest(
                         commission_rate,
                         slippage_rate,
                         &item.parameters,
+                        calendars,
                     )
                     .await
                 } else {
@@ -800,6 +853,7 @@ pub async fn run_batch_backtest(
                         commission_rate,
                         slippage_rate,
                         &item.parameters,
+                        calendars,
                     )
                     .await
                 };
@@ -867,6 +921,7 @@ async fn run_single_strategy_internal(
     commission_rate: Decimal,
     slippage_rate: Decimal,
     params: &Option<serde_json::Value>,
+    calendars: Vec<TradingCalendar>,
 ) -> Result<BacktestMetricsResponse, String> {
     use trader_analytics::backtest::BacktestConfig;
 
@@ -903,8 +958,13 @@ async fn run_single_strategy_internal(
         .with_commission_rate(commission_rate)
         .with_slippage_rate(slippage_rate);
 
+    // 스크리닝 기반 전략은 펀더멘털 시점 이력 적용
+    let screening = state
+        .load_screening_provider(strategy_id, &[symbol.to_string()], start_date, end_date)
+        .await;
+
     // 백테스트 실행
-    let report = run_strategy_backtest(strategy_id, config, &klines, params)
+    let report = run_strategy_backtest(strategy_id, config, &klines, params, calendars, screening)
         .await
         .map_err(|e| e.to_string())?;
 
@@ -924,6 +984,7 @@ async fn run_multi_strategy_internal(
     commission_rate: Decimal,
     slippage_rate: Decimal,
     params: &Option<serde_json::Value>,
+    calendars: Vec<TradingCalendar>,
 ) -> Result<BacktestMetricsResponse, String> {
     use trader_analytics::backtest::BacktestConfig;
 
@@ -973,11 +1034,23 @@ async fn run_multi_strategy_internal(
         .with_commission_rate(commission_rate)
         .with_slippage_rate(slippage_rate);
 
+    // 스크리닝 기반 전략은 펀더멘털 시점 이력 적용
+    let screening = state
+        .load_screening_provider(strategy_id, &expanded_symbols, start_date, end_date)
+        .await;
+
     // 백테스트 실행
-    let report =
-        run_multi_strategy_backtest(strategy_id, config, &merged_klines, &multi_klines, params)
-            .await
-            .map_err(|e| e.to_string())?;
+    let report = run_multi_strategy_backtest(
+        strategy_id,
+        config,
+        &merged_klines,
+        &multi_klines,
+        params,
+        calendars,
+        screening,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
 
     // 메트릭만 반환
     Ok(convert_report_to_metrics(&report))
@@ -1026,6 +1099,7 @@ fn convert_core_schema_to_ui_schema(schema: &trader_core::StrategyUISchema) -> U
                 trader_core::FieldType::Symbol => UiFieldType::SymbolPicker,
                 trader_core::FieldType::Symbols => UiFieldType::SymbolPicker,
                 trader_core::FieldType::MultiTimeframe => UiFieldType::Timeframe,
+                trader_core::FieldType::RuleGroup => UiFieldType::RuleGroup,
             };
 
             // options 변환 (Select 타입용)
@@ -95,6 +95,8 @@ pub enum UiFieldType {
     Date,
     /// 시간대 선택
     Timeframe,
+    /// 조건 트리 편집기 (규칙 기반 전략용)
+    RuleGroup,
 }
 
 /// 유효성 검사 규칙
@@ -197,6 +197,7 @@ fn convert_field_type(type_str: &str) -> UiFieldType {
         "Range" => UiFieldType::Range,
         "SymbolPicker" => UiFieldType::SymbolPicker,
         "SymbolCategoryGroup" => UiFieldType::SymbolCategoryGroup,
+        "RuleGroup" => UiFieldType::RuleGroup,
         _ => UiFieldType::Text,
     }
 }
@@ -175,13 +175,13 @@ pub async fn set_active_account(
             }
             Some((_, exchange_id)) => {
                 // 데이터 제공자는 활성 계정으로 설정 불가
-                // KRX Open API는 시세 데이터 제공자이므로 거래소가 아님
-                if exchange_id == "krx" {
+                // KRX Open API(시세), DART OpenAPI(공시)는 데이터 제공자이므로 거래소가 아님
+                if exchange_id == "krx" || exchange_id == "dart" {
                     return Err((
                         StatusCode::BAD_REQUEST,
                         Json(ApiError::new(
                             "INVALID_EXCHANGE",
-                            "KRX Open API는 데이터 제공자입니다. 거래소 계정만 활성화할 수 있습니다.",
+                            "KRX/DART OpenAPI는 데이터 제공자입니다. 거래소 계정만 활성화할 수 있습니다.",
                         )),
                     ));
                 }
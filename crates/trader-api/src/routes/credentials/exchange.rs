@@ -153,6 +153,27 @@ pub async fn get_supported_exchanges() -> impl IntoResponse {
             docs_url: Some("https://data.krx.co.kr/contents/MDC/MAIN/main/index.cmd".to_string()),
             is_data_provider: true, // 데이터 제공자 - 활성 계정으로 설정 불가
         },
+        SupportedExchange {
+            exchange_id: "dart".to_string(),
+            display_name: "DART OpenAPI".to_string(),
+            market_type: "data_provider".to_string(),
+            supports_testnet: false,
+            required_fields: vec![CredentialField {
+                name: "api_key".to_string(),
+                label: "인증키 (crtfc_key)".to_string(),
+                field_type: "password".to_string(),
+                placeholder: Some("DART OpenAPI 인증키 (40자리)".to_string()),
+                help_text: Some(
+                    "opendart.fss.or.kr에서 발급받은 인증키. 공시 목록, 분기 재무제표, 대량보유 보고 조회에 사용됩니다."
+                        .to_string(),
+                ),
+            }],
+            optional_fields: vec![],
+            description: "금융감독원 전자공시시스템. 국내 상장사 공시(유상증자, CB 발행, 최대주주 변경 등) 및 재무제표 제공"
+                .to_string(),
+            docs_url: Some("https://opendart.fss.or.kr/guide/main.do".to_string()),
+            is_data_provider: true, // 데이터 제공자 - 활성 계정으로 설정 불가
+        },
         SupportedExchange {
             exchange_id: "upbit".to_string(),
             display_name: "업비트".to_string(),
@@ -257,6 +278,83 @@ pub async fn get_supported_exchanges() -> impl IntoResponse {
             docs_url: Some("https://openapi.ls-sec.co.kr/".to_string()),
             is_data_provider: false,
         },
+        SupportedExchange {
+            exchange_id: "fix".to_string(),
+            display_name: "FIX 게이트웨이".to_string(),
+            market_type: "unknown".to_string(),
+            supports_testnet: false,
+            required_fields: vec![
+                CredentialField {
+                    name: "host".to_string(),
+                    label: "Acceptor 호스트".to_string(),
+                    field_type: "text".to_string(),
+                    placeholder: Some("fix.broker.example.com".to_string()),
+                    help_text: Some("프라임 브로커 FIX Acceptor 주소".to_string()),
+                },
+                CredentialField {
+                    name: "port".to_string(),
+                    label: "Acceptor 포트".to_string(),
+                    field_type: "number".to_string(),
+                    placeholder: Some("9876".to_string()),
+                    help_text: Some("FIX 세션 TCP 포트".to_string()),
+                },
+                CredentialField {
+                    name: "sender_comp_id".to_string(),
+                    label: "SenderCompID".to_string(),
+                    field_type: "text".to_string(),
+                    placeholder: Some("ZQ".to_string()),
+                    help_text: Some("브로커가 발급한 SenderCompID(49)".to_string()),
+                },
+                CredentialField {
+                    name: "target_comp_id".to_string(),
+                    label: "TargetCompID".to_string(),
+                    field_type: "text".to_string(),
+                    placeholder: Some("BROKER".to_string()),
+                    help_text: Some("브로커 측 TargetCompID(56)".to_string()),
+                },
+                CredentialField {
+                    name: "api_key".to_string(),
+                    label: "Username".to_string(),
+                    field_type: "text".to_string(),
+                    placeholder: None,
+                    help_text: Some("브로커가 발급한 Logon Username(553)".to_string()),
+                },
+                CredentialField {
+                    name: "api_secret".to_string(),
+                    label: "Password".to_string(),
+                    field_type: "password".to_string(),
+                    placeholder: None,
+                    help_text: Some("Logon Password(554)".to_string()),
+                },
+            ],
+            optional_fields: vec![
+                CredentialField {
+                    name: "account".to_string(),
+                    label: "Account".to_string(),
+                    field_type: "text".to_string(),
+                    placeholder: None,
+                    help_text: Some("주문에 사용할 Account(1)".to_string()),
+                },
+                CredentialField {
+                    name: "heartbeat_secs".to_string(),
+                    label: "하트비트 간격 (초)".to_string(),
+                    field_type: "number".to_string(),
+                    placeholder: Some("30".to_string()),
+                    help_text: Some("HeartBtInt(108), 기본 30초".to_string()),
+                },
+                CredentialField {
+                    name: "seq_store_path".to_string(),
+                    label: "시퀀스 저장 파일".to_string(),
+                    field_type: "text".to_string(),
+                    placeholder: Some("/var/lib/zeroquant/fix_seq.json".to_string()),
+                    help_text: Some("재시작 후에도 MsgSeqNum을 이어가기 위한 파일 경로 (미입력 시 메모리)".to_string()),
+                },
+            ],
+            description: "FIX 4.4 주문 게이트웨이. 주문 전용이며 시세/잔고 조회는 지원하지 않습니다."
+                .to_string(),
+            docs_url: None,
+            is_data_provider: false,
+        },
         SupportedExchange {
             exchange_id: "mock".to_string(),
             display_name: "Mock Exchange (테스트용)".to_string(),
@@ -390,8 +488,8 @@ pub async fn list_exchange_credentials(
         let permissions: Option<Vec<String>> =
             row.permissions.and_then(|v| serde_json::from_value(v).ok());
 
-        // 데이터 제공자 여부 확인 (krx는 시세 데이터만 제공)
-        let is_data_provider = row.exchange_id == "krx";
+        // 데이터 제공자 여부 확인 (krx는 시세, dart는 공시 데이터만 제공)
+        let is_data_provider = matches!(row.exchange_id.as_str(), "krx" | "dart");
 
         credentials.push(ExchangeCredentialResponse {
             id: row.id,
@@ -571,8 +669,8 @@ pub async fn create_exchange_credential(
         ));
     }
 
-    // KRX 이외의 거래소는 api_secret도 필수
-    if request.exchange_id != "krx" && api_secret.is_empty() {
+    // 데이터 제공자(KRX, DART) 이외의 거래소는 api_secret도 필수
+    if !matches!(request.exchange_id.as_str(), "krx" | "dart") && api_secret.is_empty() {
         return Err((
             StatusCode::BAD_REQUEST,
             Json(ApiError::new(
@@ -1035,6 +1133,22 @@ pub async fn test_exchange_credential(
                 )
             }
         }
+        "dart" => {
+            // DART OpenAPI 인증키 검증 (40자리, api_secret 불필요)
+            if credentials.api_key.len() == 40 {
+                (
+                    true,
+                    "DART OpenAPI 인증키가 유효합니다.".to_string(),
+                    Some(vec!["read".to_string()]), // 데이터 조회 권한만
+                )
+            } else {
+                (
+                    false,
+                    "DART 인증키 형식이 올바르지 않습니다.".to_string(),
+                    None,
+                )
+            }
+        }
         "upbit" | "bithumb" => {
             if credentials.api_key.len() >= 10 && credentials.api_secret.len() >= 10 {
                 (
@@ -1158,8 +1272,8 @@ pub async fn test_new_exchange_credential(
         ));
     }
 
-    // KRX 이외의 거래소는 api_secret도 필수
-    if request.exchange_id != "krx" && api_secret.is_empty() {
+    // 데이터 제공자(KRX, DART) 이외의 거래소는 api_secret도 필수
+    if !matches!(request.exchange_id.as_str(), "krx" | "dart") && api_secret.is_empty() {
         return Err((
             StatusCode::BAD_REQUEST,
             Json(ApiError::new(
@@ -1208,6 +1322,17 @@ pub async fn test_new_exchange_credential(
                 )
             }
         }
+        "dart" => {
+            if api_key.len() == 40 {
+                (
+                    true,
+                    "DART OpenAPI 인증키 형식이 유효합니다.".to_string(),
+                    Some(vec!["read".to_string()]),
+                )
+            } else {
+                (false, "DART 인증키는 40자리여야 합니다.".to_string(), None)
+            }
+        }
         _ => (
             true,
             format!("{} API 키 형식이 유효합니다.", request.exchange_id),
@@ -769,7 +769,7 @@ pub(crate) fn mask_api_key(key: &str) -> String {
 /// - `"stock_kr"`: 한국 주식 (kis)
 /// - `"stock_us"`: 미국 주식 (interactive_brokers, ib)
 /// - `"forex"`: 외환 (oanda)
-/// - `"data_provider"`: 데이터 제공자 (krx - KRX Open API)
+/// - `"data_provider"`: 데이터 제공자 (krx - KRX Open API, dart - DART OpenAPI)
 /// - `"unknown"`: 알 수 없는 거래소
 pub(crate) fn infer_market_type(exchange_id: &str) -> &'static str {
     match exchange_id {
@@ -777,7 +777,7 @@ pub(crate) fn infer_market_type(exchange_id: &str) -> &'static str {
         "kis" | "db_investment" | "ls_sec" => "stock_kr",
         "interactive_brokers" | "ib" => "stock_us",
         "oanda" => "forex",
-        "krx" => "data_provider",
+        "krx" | "dart" => "data_provider",
         "mock" => "mock",
         _ => "unknown",
     }
@@ -849,6 +849,7 @@ mod tests {
         assert_eq!(infer_market_type("ib"), "stock_us");
         assert_eq!(infer_market_type("oanda"), "forex");
         assert_eq!(infer_market_type("krx"), "data_provider");
+        assert_eq!(infer_market_type("dart"), "data_provider");
         assert_eq!(infer_market_type("unknown_exchange"), "unknown");
     }
 
@@ -9,6 +9,7 @@
 //! - `GET /api/v1/dataset/search` - 심볼 검색
 //! - `POST /api/v1/dataset/symbols/batch` - 여러 티커의 심볼 정보 일괄 조회
 //! - `GET /api/v1/dataset/:symbol` - 특정 심볼의 캔들 데이터 조회
+//! - `GET /api/v1/dataset/:symbol/parquet` - 캔들 데이터 Parquet 스트리밍 다운로드
 //! - `DELETE /api/v1/dataset/:symbol` - 특정 심볼 캐시 삭제
 //! - `DELETE /api/v1/dataset/symbols/:ticker` - 심볼 삭제 + 연쇄 정리
 //! - `POST /api/v1/dataset/symbols/cleanup-orphans` - 고아 데이터 일괄 정리
@@ -16,15 +17,18 @@
 use std::sync::Arc;
 
 use axum::{
+    body::Body,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{header, StatusCode},
+    response::IntoResponse,
     routing::{delete, get, post},
     Json, Router,
 };
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, error};
-use trader_core::Timeframe;
+use trader_core::{Kline, Timeframe};
+use trader_data::{OhlcvCache, ParquetStreamEncoder};
 use utoipa::{IntoParams, ToSchema};
 
 use crate::{
@@ -203,6 +207,19 @@ pub struct DeleteQuery {
     pub timeframe: Option<String>,
 }
 
+/// Parquet 다운로드 쿼리.
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ParquetDownloadQuery {
+    /// 타임프레임 (기본: 1d)
+    #[serde(default = "default_timeframe")]
+    pub timeframe: String,
+    /// 시작 날짜 (YYYY-MM-DD, 기본: 캐시된 첫 캔들)
+    pub start: Option<NaiveDate>,
+    /// 종료 날짜 (YYYY-MM-DD, 포함, 기본: 현재)
+    pub end: Option<NaiveDate>,
+}
+
 // ==================== Handler ====================
 
 /// 캐시된 데이터셋 목록 조회.
@@ -737,6 +754,193 @@ pub async fn get_candles(
     }))
 }
 
+/// 캔들 데이터를 Parquet 파일로 스트리밍 다운로드.
+///
+/// 기간을 연 단위로 나누어 DB에서 읽고, 각 구간을 row group으로 인코딩해 바로 전송하므로
+/// 긴 기간도 전체를 메모리에 올리지 않습니다. 결과는 Polars에서
+/// `pl.read_parquet()`로 바로 읽을 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/dataset/{symbol}/parquet",
+    tag = "dataset",
+    params(
+        ("symbol" = String, Path, description = "심볼 (예: 005930, AAPL)"),
+        ParquetDownloadQuery
+    ),
+    responses(
+        (status = 200, description = "Parquet 파일 스트림 (application/vnd.apache.parquet)"),
+        (status = 400, description = "잘못된 기간", body = ApiError),
+        (status = 404, description = "심볼 또는 데이터 없음", body = ApiError),
+        (status = 500, description = "서버 오류", body = ApiError),
+        (status = 503, description = "DB 연결 없음", body = ApiError)
+    )
+)]
+pub async fn download_parquet(
+    State(state): State<Arc<AppState>>,
+    Path(symbol): Path<String>,
+    Query(query): Query<ParquetDownloadQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ApiError::new(
+                "DB_NOT_AVAILABLE",
+                "데이터베이스 연결이 없습니다",
+            )),
+        )
+    })?;
+
+    let symbol_info = SymbolInfoRepository::get_by_ticker(pool, &symbol, None)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new("DB_ERROR", format!("심볼 조회 실패: {}", e))),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiError::new(
+                    "SYMBOL_NOT_FOUND",
+                    format!("심볼을 찾을 수 없습니다: {}", symbol),
+                )),
+            )
+        })?;
+
+    let ticker = symbol_info.ticker;
+    let timeframe = parse_timeframe(&query.timeframe);
+    let cache = OhlcvCache::new(pool.clone());
+
+    let start = match query.start {
+        Some(date) => Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default()),
+        None => cache
+            .get_cache_metadata(&ticker, timeframe)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new(
+                        "DB_ERROR",
+                        format!("메타데이터 조회 실패: {}", e),
+                    )),
+                )
+            })?
+            .and_then(|meta| meta.first_cached_time)
+            .ok_or_else(|| {
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ApiError::new(
+                        "NO_DATA",
+                        format!("캐시된 데이터가 없습니다: {} ({})", ticker, query.timeframe),
+                    )),
+                )
+            })?,
+    };
+    // 종료 날짜는 포함 (다음 날 0시 미만까지)
+    let end = match query.end {
+        Some(date) => Utc.from_utc_datetime(
+            &date
+                .succ_opt()
+                .unwrap_or(date)
+                .and_hms_opt(0, 0, 0)
+                .unwrap_or_default(),
+        ),
+        None => Utc::now(),
+    };
+    if start >= end {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new(
+                "INVALID_RANGE",
+                "시작 날짜는 종료 날짜보다 이전이어야 합니다",
+            )),
+        ));
+    }
+
+    let encoder = ParquetStreamEncoder::<Kline>::new().map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("EXPORT_ERROR", e.to_string())),
+        )
+    })?;
+
+    // 연 단위 구간 [from, to)
+    let mut windows = Vec::new();
+    let mut from = start;
+    while from < end {
+        let to = Utc
+            .with_ymd_and_hms(from.year() + 1, 1, 1, 0, 0, 0)
+            .single()
+            .map_or(end, |next_year| next_year.min(end));
+        windows.push((from, to));
+        from = to;
+    }
+
+    debug!(
+        ticker = %ticker,
+        timeframe = %query.timeframe,
+        start = %start,
+        end = %end,
+        chunks = windows.len(),
+        "Parquet 스트리밍 다운로드 시작"
+    );
+
+    let disposition = format!(
+        "attachment; filename=\"{}_{}.parquet\"",
+        ticker,
+        timeframe_to_db_string(&query.timeframe)
+    );
+
+    struct ExportState {
+        cache: OhlcvCache,
+        ticker: String,
+        timeframe: Timeframe,
+        windows: std::vec::IntoIter<(DateTime<Utc>, DateTime<Utc>)>,
+        encoder: ParquetStreamEncoder<Kline>,
+    }
+
+    let initial = ExportState {
+        cache,
+        ticker,
+        timeframe,
+        windows: windows.into_iter(),
+        encoder,
+    };
+
+    let stream = futures::stream::unfold(Some(initial), |state| async move {
+        let mut state = state?;
+        let chunk = match state.windows.next() {
+            Some((from, to)) => match state
+                .cache
+                .get_cached_klines_range(&state.ticker, state.timeframe, from, to)
+                .await
+            {
+                Ok(klines) => state.encoder.encode(&klines),
+                Err(e) => Err(e),
+            },
+            // 모든 구간을 보낸 뒤 파일 푸터 전송
+            None => return Some((state.encoder.finish(), None)),
+        };
+        if let Err(e) = &chunk {
+            error!(ticker = %state.ticker, error = %e, "Parquet 스트리밍 중단");
+            return Some((chunk, None));
+        }
+        Some((chunk, Some(state)))
+    });
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                "application/vnd.apache.parquet".to_string(),
+            ),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        Body::from_stream(stream),
+    ))
+}
+
 /// 특정 심볼 캐시 삭제.
 #[utoipa::path(
     delete,
@@ -1119,6 +1323,7 @@ pub fn dataset_router() -> Router<Arc<AppState>> {
         // 심볼별 조회/삭제
         .route("/{symbol}", get(get_candles))
         .route("/{symbol}", delete(delete_dataset))
+        .route("/{symbol}/parquet", get(download_parquet))
 }
 
 // ==================== 심볼 상태 관리 ====================
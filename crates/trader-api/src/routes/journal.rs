@@ -9,6 +9,7 @@
 //! - `GET /api/v1/journal/pnl` - 손익 요약 조회
 //! - `GET /api/v1/journal/pnl/daily` - 일별 손익 조회
 //! - `GET /api/v1/journal/pnl/symbol` - 종목별 손익 조회
+//! - `GET /api/v1/journal/pnl/currency` - 기준 통화 손익 조회 (자산/환차손익 분리)
 //! - `POST /api/v1/journal/sync` - 거래소 체결 내역 동기화
 //! - `PATCH /api/v1/journal/executions/{id}` - 체결 내역 메모/태그 수정
 //! - `GET /api/v1/journal/cost-basis/{symbol}` - FIFO 원가 계산
@@ -24,7 +25,7 @@ use axum::{
 use chrono::{DateTime, NaiveDate, TimeZone, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use trader_core::Side;
+use trader_core::{FxPnl, Side};
 use ts_rs::TS;
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
@@ -131,8 +132,8 @@ use crate::{
         create_provider_for_mock_credential, CostBasisSummary, CumulativePnL,
         CurrentPosition as RepoCurrentPosition, DailySummary, EquityHistoryRepository,
         ExecutionCacheRepository, ExecutionFilter, JournalRepository, MonthlyPnL, NewExecution,
-        PnLSummary, PositionRepository, StrategyPerformance, SymbolPnL, TradeExecution,
-        TradeExecutionRecord, TradingInsights, WeeklyPnL, YearlyPnL,
+        PnLSummary, PositionRepository, StrategyPerformance, SymbolFxPnL, SymbolPnL,
+        TradeExecution, TradeExecutionRecord, TradingInsights, WeeklyPnL, YearlyPnL,
     },
     routes::strategies::ApiError,
     state::AppState,
@@ -462,6 +463,74 @@ impl From<SymbolPnL> for SymbolPnLItem {
     }
 }
 
+/// 기준 통화 손익 응답.
+#[derive(Debug, Serialize, TS, ToSchema)]
+#[ts(export, export_to = "journal/")]
+pub struct CurrencyPnLResponse {
+    /// 기준 통화 (`BASE_CURRENCY`)
+    pub base_currency: String,
+    pub symbols: Vec<CurrencyPnLItem>,
+    /// 자산 손익 합계 (기준 통화)
+    pub total_asset_pnl: String,
+    /// 환차손익 합계 (기준 통화)
+    pub total_fx_pnl: String,
+    /// 기준 통화 총 실현 손익
+    pub total_pnl: String,
+    /// 환율이 없어 합계에서 제외된 종목
+    pub unconverted: Vec<String>,
+}
+
+/// 종목별 기준 통화 손익 항목.
+#[derive(Debug, Serialize, TS, ToSchema)]
+#[ts(export, export_to = "journal/")]
+pub struct CurrencyPnLItem {
+    pub symbol: String,
+    /// 거래 통화
+    pub currency: String,
+    /// 자산 손익 (현지 통화 손익 × 매도일 환율)
+    pub asset_pnl: String,
+    /// 환차손익 (매수 원가 × 매수일 대비 매도일 환율 변동)
+    pub fx_pnl: String,
+    pub total_pnl: String,
+}
+
+impl CurrencyPnLResponse {
+    /// 종목별 손익에서 응답 생성 (환율이 없는 종목은 `unconverted`로 분리).
+    fn from_symbols(base_currency: &str, symbols: Vec<SymbolFxPnL>) -> Self {
+        let mut total = FxPnl::default();
+        let mut items = Vec::new();
+        let mut unconverted = Vec::new();
+
+        for s in symbols {
+            match s.pnl {
+                Ok(pnl) => {
+                    total += pnl;
+                    items.push(CurrencyPnLItem {
+                        symbol: s.symbol,
+                        currency: s.currency,
+                        asset_pnl: pnl.asset_pnl.to_string(),
+                        fx_pnl: pnl.fx_pnl.to_string(),
+                        total_pnl: pnl.total().to_string(),
+                    });
+                }
+                Err(e) => {
+                    warn!("기준 통화 손익 환산 실패 ({}): {}", s.symbol, e);
+                    unconverted.push(s.symbol);
+                }
+            }
+        }
+
+        Self {
+            base_currency: base_currency.to_string(),
+            symbols: items,
+            total_asset_pnl: total.asset_pnl.to_string(),
+            total_fx_pnl: total.fx_pnl.to_string(),
+            total_pnl: total.total().to_string(),
+            unconverted,
+        }
+    }
+}
+
 /// 동기화 응답.
 #[derive(Debug, Serialize, TS, ToSchema)]
 #[ts(export, export_to = "journal/")]
@@ -782,6 +851,45 @@ pub async fn get_symbol_pnl(
     }))
 }
 
+/// 기준 통화 손익 조회.
+///
+/// 종목별 실현 손익을 기준 통화(`BASE_CURRENCY`)로 환산하고,
+/// 자산 가격 변동분과 환율 변동분(환차손익)을 분리하여 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/journal/pnl/currency",
+    tag = "journal",
+    responses(
+        (status = 200, description = "기준 통화 손익 조회 성공", body = CurrencyPnLResponse),
+        (status = 500, description = "서버 오류", body = ApiError)
+    )
+)]
+pub async fn get_currency_pnl(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<CurrencyPnLResponse>, (StatusCode, Json<ApiError>)> {
+    let pool = get_db_pool(&state)?;
+    let credential_id = get_active_credential_id(&state).await?;
+    let base_currency = state.fx_rates.base_currency();
+    let rates = state.fx_rates.table().await;
+
+    let symbols = JournalRepository::calculate_fx_pnl(pool, credential_id, &rates, base_currency)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new(
+                    "DB_ERROR",
+                    format!("Failed to get currency PnL: {}", e),
+                )),
+            )
+        })?;
+
+    Ok(Json(CurrencyPnLResponse::from_symbols(
+        base_currency,
+        symbols,
+    )))
+}
+
 // ==================== 기간별 손익 API ====================
 
 /// 주별 손익 응답.
@@ -2014,6 +2122,7 @@ pub fn journal_router() -> Router<Arc<AppState>> {
         .route("/pnl/monthly", get(get_monthly_pnl))
         .route("/pnl/yearly", get(get_yearly_pnl))
         .route("/pnl/symbol", get(get_symbol_pnl))
+        .route("/pnl/currency", get(get_currency_pnl))
         .route("/pnl/cumulative", get(get_cumulative_pnl))
         // 인사이트 API
         .route("/insights", get(get_trading_insights))
@@ -2157,4 +2266,42 @@ mod tests {
         assert!(api_err.message.contains("bad-date"));
         assert!(api_err.message.contains("YYYY-MM-DD"));
     }
+
+    #[test]
+    fn test_currency_pnl_response_totals() {
+        let symbols = vec![
+            SymbolFxPnL {
+                symbol: "AAPL".to_string(),
+                currency: "USD".to_string(),
+                pnl: Ok(FxPnl {
+                    asset_pnl: Decimal::from(140000),
+                    fx_pnl: Decimal::from(100000),
+                }),
+            },
+            SymbolFxPnL {
+                symbol: "005930".to_string(),
+                currency: "KRW".to_string(),
+                pnl: Ok(FxPnl {
+                    asset_pnl: Decimal::from(50000),
+                    fx_pnl: Decimal::ZERO,
+                }),
+            },
+            SymbolFxPnL {
+                symbol: "7203.T".to_string(),
+                currency: "JPY".to_string(),
+                pnl: Err(trader_core::MissingFxRate {
+                    from: "JPY".to_string(),
+                    to: "KRW".to_string(),
+                    date: NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
+                }),
+            },
+        ];
+
+        let response = CurrencyPnLResponse::from_symbols("KRW", symbols);
+        assert_eq!(response.symbols.len(), 2);
+        assert_eq!(response.total_asset_pnl, "190000");
+        assert_eq!(response.total_fx_pnl, "100000");
+        assert_eq!(response.total_pnl, "290000");
+        assert_eq!(response.unconverted, vec!["7203.T".to_string()]);
+    }
 }
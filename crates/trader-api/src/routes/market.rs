@@ -7,6 +7,8 @@
 //! - `GET /api/v1/market/overview` - 시장 상태 통합 조회 (status + breadth + macro)
 //! - `GET /api/v1/market/klines` - 캔들스틱 데이터 조회 (실시간 거래소 데이터)
 //! - `GET /api/v1/market/ticker` - 현재가 조회
+//! - `GET /api/v1/market/premium` - 김치 프리미엄 최신값 조회
+//! - `GET /api/v1/market/premium/history` - 김치 프리미엄 이력 조회
 
 use std::sync::Arc;
 
@@ -23,8 +25,12 @@ use trader_core::Timeframe;
 use utoipa::{IntoParams, ToSchema};
 
 // API 서버는 ohlcv 테이블에서만 읽음 (외부 API 호출 없음)
-use crate::repository::KlinesRepository;
-use crate::{routes::strategies::ApiError, state::AppState};
+use crate::repository::{KimchiPremiumRepository, KlinesRepository};
+use crate::{
+    routes::strategies::ApiError,
+    services::{DomesticVenue, PremiumSnapshot},
+    state::AppState,
+};
 
 // ==================== 응답 타입 ====================
 
@@ -693,6 +699,149 @@ pub async fn get_ticker(
     }
 }
 
+// ==================== 김치 프리미엄 ====================
+
+/// 김치 프리미엄 최신값 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KimchiPremiumResponse {
+    /// 적용 중인 USD/KRW 환율 (아직 수신 전이면 null)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usd_krw: Option<String>,
+    /// 상단 임계값 (%)
+    pub upper_threshold_pct: String,
+    /// 하단 임계값 (%)
+    pub lower_threshold_pct: String,
+    /// 페어별 최신 프리미엄
+    pub premiums: Vec<PremiumSnapshot>,
+}
+
+/// 김치 프리미엄 이력 쿼리.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PremiumHistoryQuery {
+    /// 기준 코인 (예: BTC)
+    pub base: String,
+    /// 국내 거래소 (upbit/bithumb, 기본: upbit)
+    #[serde(default)]
+    pub venue: Option<String>,
+    /// 최대 개수 (기본: 360, DB가 있으면 저장 이력 기준)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// 김치 프리미엄 이력 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumHistoryResponse {
+    /// 기준 코인
+    pub base: String,
+    /// 국내 거래소
+    pub venue: DomesticVenue,
+    /// 프리미엄 시계열 (오래된 순)
+    pub points: Vec<PremiumSnapshot>,
+}
+
+fn premium_not_configured() -> (StatusCode, Json<ApiError>) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ApiError::new(
+            "PREMIUM_MONITOR_NOT_CONFIGURED",
+            "김치 프리미엄 모니터가 비활성화되어 있습니다. KIMCHI_PREMIUM_ENABLED=true로 설정하세요.",
+        )),
+    )
+}
+
+/// 김치 프리미엄 최신값 조회.
+///
+/// GET /api/v1/market/premium
+#[utoipa::path(
+    get,
+    path = "/api/v1/market/premium",
+    responses(
+        (status = 200, description = "페어별 최신 프리미엄", body = KimchiPremiumResponse),
+        (status = 503, description = "모니터 비활성화")
+    ),
+    tag = "market"
+)]
+pub async fn get_kimchi_premium(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<KimchiPremiumResponse>, (StatusCode, Json<ApiError>)> {
+    let monitor = state
+        .kimchi_premium
+        .as_ref()
+        .ok_or_else(premium_not_configured)?
+        .read()
+        .await;
+    let (upper, lower) = monitor.thresholds();
+
+    Ok(Json(KimchiPremiumResponse {
+        usd_krw: monitor.usd_krw().map(|r| r.to_string()),
+        upper_threshold_pct: upper.to_string(),
+        lower_threshold_pct: lower.to_string(),
+        premiums: monitor.latest(),
+    }))
+}
+
+/// 김치 프리미엄 이력 조회.
+///
+/// GET /api/v1/market/premium/history
+#[utoipa::path(
+    get,
+    path = "/api/v1/market/premium/history",
+    params(PremiumHistoryQuery),
+    responses(
+        (status = 200, description = "프리미엄 시계열", body = PremiumHistoryResponse),
+        (status = 400, description = "잘못된 거래소"),
+        (status = 500, description = "이력 조회 실패"),
+        (status = 503, description = "모니터 비활성화")
+    ),
+    tag = "market"
+)]
+pub async fn get_kimchi_premium_history(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<PremiumHistoryQuery>,
+) -> Result<Json<PremiumHistoryResponse>, (StatusCode, Json<ApiError>)> {
+    let monitor = state
+        .kimchi_premium
+        .as_ref()
+        .ok_or_else(premium_not_configured)?;
+
+    let venue = match query.venue.as_deref() {
+        None => DomesticVenue::Upbit,
+        Some(v) => DomesticVenue::parse(v).ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new(
+                    "INVALID_VENUE",
+                    format!("지원하지 않는 거래소: {} (upbit/bithumb)", v),
+                )),
+            )
+        })?,
+    };
+    let base = query.base.to_uppercase();
+    let limit = query.limit.unwrap_or(360);
+    // DB가 있으면 재시작 전 이력까지 포함된 저장 이력을 조회
+    let points = match &state.db_pool {
+        Some(pool) => KimchiPremiumRepository::new(pool.clone())
+            .history(venue, &base, limit as i64)
+            .await
+            .map_err(|e| {
+                error!("김치 프리미엄 이력 조회 실패: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new("DB_ERROR", format!("조회 실패: {}", e))),
+                )
+            })?,
+        None => monitor.read().await.history(venue, &base, limit),
+    };
+
+    Ok(Json(PremiumHistoryResponse {
+        base,
+        venue,
+        points,
+    }))
+}
+
 // ==================== Market Breadth ====================
 
 /// Market Breadth 응답.
@@ -768,6 +917,8 @@ pub fn market_router() -> Router<Arc<AppState>> {
         .route("/klines", get(get_klines))
         .route("/klines/multi", get(get_multi_klines))
         .route("/ticker", get(get_ticker))
+        .route("/premium", get(get_kimchi_premium))
+        .route("/premium/history", get(get_kimchi_premium_history))
 }
 
 // ==================== 테스트 ====================
@@ -815,4 +966,85 @@ mod tests {
         assert!(overview.breadth.is_none());
         assert!(overview.macro_env.is_none());
     }
+
+    #[tokio::test]
+    async fn test_kimchi_premium_routes() {
+        use chrono::TimeZone;
+        use rust_decimal_macros::dec;
+
+        use crate::{services::KimchiPremiumConfig, state::create_test_state};
+
+        // 모니터 미설정 시 503
+        let app = market_router().with_state(Arc::new(create_test_state()));
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/premium")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let state = create_test_state().with_kimchi_premium(KimchiPremiumConfig {
+            usd_krw_source: crate::services::kimchi_premium::UsdKrwSource::Fixed(dec!(1300)),
+            ..Default::default()
+        });
+        {
+            let mut monitor = state.kimchi_premium.as_ref().unwrap().write().await;
+            let at = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+            monitor.update_foreign("BTC", dec!(50000), at);
+            monitor.update_domestic(DomesticVenue::Bithumb, "BTC", dec!(66300000), at);
+        }
+        let app = market_router().with_state(Arc::new(state));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/premium")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let premium: KimchiPremiumResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(premium.usd_krw.as_deref(), Some("1300"));
+        assert_eq!(premium.premiums.len(), 1);
+        assert_eq!(premium.premiums[0].premium_pct, dec!(2));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/premium/history?base=btc&venue=bithumb")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let history: PremiumHistoryResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(history.base, "BTC");
+        assert_eq!(history.points.len(), 1);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/premium/history?base=BTC&venue=coinone")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 }
@@ -10,6 +10,9 @@
 //! - `GET /api/v1/monitoring/stats` - 에러 통계 조회
 //! - `POST /api/v1/monitoring/stats/reset` - 통계 초기화
 //! - `DELETE /api/v1/monitoring/errors` - 에러 히스토리 삭제
+//! - `GET /api/v1/monitoring/exchanges` - 거래소/시세 제공자 헬스 및 장애 조치 상태
+//! - `GET /api/v1/monitoring/collector/jobs` - 수집기 작업별 최근 실행 상태
+//! - `GET /api/v1/monitoring/collector/runs` - 수집기 작업 실행 이력
 
 use std::sync::Arc;
 
@@ -21,10 +24,14 @@ use axum::{
     Json, Router,
 };
 use serde::{Deserialize, Serialize};
+use tracing::warn;
+use trader_data::{CollectorJobRun, CollectorJobRunRepository, JobRunStatus};
+use trader_exchange::{global_health_registry, FailoverGroupSnapshot, ProviderHealthSnapshot};
 use utoipa::ToSchema;
 
 use crate::{
     monitoring::{global_tracker, ErrorCategory, ErrorRecord, ErrorSeverity, ErrorStats},
+    routes::strategies::ApiError,
     state::AppState,
 };
 
@@ -137,6 +144,195 @@ impl From<ErrorStats> for StatsResponse {
     }
 }
 
+/// 제공자 헬스 DTO (API 응답용).
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProviderHealthDto {
+    /// 제공자 이름 (exchange_id 또는 "naver")
+    pub name: String,
+    /// 판정 상태 (healthy, degraded, down)
+    pub status: String,
+    /// Circuit 상태 (CLOSED, OPEN, HALF_OPEN)
+    pub circuit_state: String,
+    /// 현재 연속 실패 횟수
+    pub consecutive_failures: u32,
+    /// 누적 실패 횟수
+    pub total_failures: u64,
+    /// 누적 성공 횟수
+    pub total_successes: u64,
+    /// Circuit Open 횟수
+    pub open_count: u64,
+    /// Circuit을 Open시킨 에러 카테고리
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tripped_by: Option<String>,
+    /// 집계 구간 내 REST 요청 수
+    pub recent_requests: usize,
+    /// 집계 구간 내 REST 에러율 (0.0 ~ 1.0)
+    pub error_rate: f64,
+    /// 집계 구간 내 평균 지연시간 (밀리초)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_latency_ms: Option<f64>,
+    /// 마지막 WebSocket 하트비트 지연시간 (밀리초)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heartbeat_latency_ms: Option<u64>,
+    /// 마지막 하트비트 수신 시간 (ISO 8601)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_heartbeat_at: Option<String>,
+    /// 하트비트가 오래되었는지 여부
+    pub heartbeat_stale: bool,
+    /// 마지막 에러 메시지
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl From<ProviderHealthSnapshot> for ProviderHealthDto {
+    fn from(snapshot: ProviderHealthSnapshot) -> Self {
+        Self {
+            name: snapshot.name,
+            status: snapshot.status.to_string(),
+            circuit_state: snapshot.circuit_state.to_string(),
+            consecutive_failures: snapshot.consecutive_failures,
+            total_failures: snapshot.total_failures,
+            total_successes: snapshot.total_successes,
+            open_count: snapshot.open_count,
+            tripped_by: snapshot.tripped_by.map(|c| c.to_string()),
+            recent_requests: snapshot.recent_requests,
+            error_rate: snapshot.error_rate,
+            avg_latency_ms: snapshot.avg_latency_ms,
+            heartbeat_latency_ms: snapshot.heartbeat_latency_ms,
+            last_heartbeat_at: snapshot.last_heartbeat_at.map(|t| t.to_rfc3339()),
+            heartbeat_stale: snapshot.heartbeat_stale,
+            last_error: snapshot.last_error,
+        }
+    }
+}
+
+/// 장애 조치 그룹 DTO (API 응답용).
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FailoverGroupDto {
+    /// 그룹 이름
+    pub name: String,
+    /// 주 소스
+    pub primary: String,
+    /// 우선순위 순 제공자 목록
+    pub providers: Vec<String>,
+    /// 현재 활성 소스
+    pub active: String,
+    /// 보조 소스로 전환된 상태인지 여부
+    pub failed_over: bool,
+    /// 전환 횟수 (failover + failback)
+    pub switch_count: u64,
+    /// 마지막 전환 시간 (ISO 8601)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_switch_at: Option<String>,
+}
+
+impl From<FailoverGroupSnapshot> for FailoverGroupDto {
+    fn from(snapshot: FailoverGroupSnapshot) -> Self {
+        Self {
+            name: snapshot.name,
+            primary: snapshot.primary,
+            providers: snapshot.providers,
+            active: snapshot.active,
+            failed_over: snapshot.failed_over,
+            switch_count: snapshot.switch_count,
+            last_switch_at: snapshot.last_switch_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+/// 거래소 헬스 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExchangeHealthResponse {
+    /// 제공자별 헬스 상태
+    pub providers: Vec<ProviderHealthDto>,
+    /// 장애 조치 그룹 상태
+    pub groups: Vec<FailoverGroupDto>,
+}
+
+/// 수집기 작업 실행 DTO (API 응답용).
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CollectorJobRunDto {
+    /// 실행 ID
+    pub id: i64,
+    /// 작업 이름 (symbol_sync, ohlcv_collect, indicator_sync, ...)
+    pub job_name: String,
+    /// 실행 원인 (schedule, dependency, manual)
+    pub trigger: String,
+    /// 상태 (running, success, failed, skipped, interrupted)
+    pub status: String,
+    /// 시도 횟수
+    pub attempt: i32,
+    /// 시작 시간 (ISO 8601)
+    pub started_at: String,
+    /// 종료 시간 (ISO 8601)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<String>,
+    /// 소요 시간 (밀리초)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+    /// 삽입/갱신된 행 수
+    pub rows_touched: i64,
+    /// 개별 항목 오류 수
+    pub error_count: i64,
+    /// 실패/건너뜀 사유
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl From<CollectorJobRun> for CollectorJobRunDto {
+    fn from(run: CollectorJobRun) -> Self {
+        Self {
+            id: run.id,
+            duration_ms: run.duration_ms(),
+            job_name: run.job_name,
+            trigger: run.trigger.as_str().to_string(),
+            status: run.status.to_string(),
+            attempt: run.attempt,
+            started_at: run.started_at.to_rfc3339(),
+            finished_at: run.finished_at.map(|t| t.to_rfc3339()),
+            rows_touched: run.rows_touched,
+            error_count: run.error_count,
+            error_message: run.error_message,
+        }
+    }
+}
+
+/// 수집기 작업 상태 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CollectorJobsResponse {
+    /// 작업별 최근 실행
+    pub jobs: Vec<CollectorJobRunDto>,
+    /// 최근 실행이 실패한 작업 수
+    pub failing: usize,
+    /// 실행 중인 작업 수
+    pub running: usize,
+}
+
+/// 수집기 작업 실행 이력 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CollectorRunsResponse {
+    /// 실행 이력 (최신순)
+    pub runs: Vec<CollectorJobRunDto>,
+    /// 조회된 실행 수
+    pub count: usize,
+}
+
+/// 수집기 작업 실행 이력 조회 쿼리 파라미터.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CollectorRunsQuery {
+    /// 작업 이름 필터
+    pub job: Option<String>,
+    /// 상태 필터 (running, success, failed, skipped, interrupted)
+    pub status: Option<String>,
+    /// 조회할 최대 실행 수 (기본값: 50, 최대: 500)
+    #[serde(default = "default_runs_limit")]
+    pub limit: i64,
+}
+
+fn default_runs_limit() -> i64 {
+    50
+}
+
 /// 최근 에러 목록 조회.
 ///
 /// GET /api/v1/monitoring/errors
@@ -326,6 +522,142 @@ pub async fn clear_errors() -> impl IntoResponse {
     }))
 }
 
+/// 거래소/시세 제공자 헬스 조회.
+///
+/// GET /api/v1/monitoring/exchanges
+#[utoipa::path(
+    get,
+    path = "/api/v1/monitoring/exchanges",
+    tag = "monitoring",
+    responses(
+        (status = 200, description = "제공자 헬스 및 장애 조치 상태", body = ExchangeHealthResponse)
+    )
+)]
+pub async fn get_exchange_health() -> Json<ExchangeHealthResponse> {
+    let registry = global_health_registry();
+    Json(ExchangeHealthResponse {
+        providers: registry
+            .snapshots()
+            .into_iter()
+            .map(ProviderHealthDto::from)
+            .collect(),
+        groups: registry
+            .groups()
+            .into_iter()
+            .map(FailoverGroupDto::from)
+            .collect(),
+    })
+}
+
+/// 수집기 작업 실행 이력 저장소 (DB 미구성 시 에러).
+fn collector_job_runs(
+    state: &AppState,
+) -> Result<CollectorJobRunRepository, (StatusCode, Json<ApiError>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스가 구성되지 않았습니다",
+            )),
+        )
+    })?;
+    Ok(CollectorJobRunRepository::new(pool.clone()))
+}
+
+/// 조회 실패 응답.
+fn collector_query_error(e: trader_data::DataError) -> (StatusCode, Json<ApiError>) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiError::new(
+            "DB_ERROR",
+            format!("작업 실행 이력 조회 실패: {}", e),
+        )),
+    )
+}
+
+/// 수집기 작업별 최근 실행 상태 조회.
+///
+/// GET /api/v1/monitoring/collector/jobs
+#[utoipa::path(
+    get,
+    path = "/api/v1/monitoring/collector/jobs",
+    tag = "monitoring",
+    responses(
+        (status = 200, description = "작업별 최근 실행 상태", body = CollectorJobsResponse),
+        (status = 500, description = "서버 오류", body = ApiError)
+    )
+)]
+pub async fn get_collector_jobs(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<CollectorJobsResponse>, (StatusCode, Json<ApiError>)> {
+    let runs = collector_job_runs(&state)?
+        .latest_by_job()
+        .await
+        .map_err(collector_query_error)?;
+
+    let failing = runs
+        .iter()
+        .filter(|run| run.status == JobRunStatus::Failed)
+        .count();
+    let running = runs
+        .iter()
+        .filter(|run| run.status == JobRunStatus::Running)
+        .count();
+
+    Ok(Json(CollectorJobsResponse {
+        jobs: runs.into_iter().map(CollectorJobRunDto::from).collect(),
+        failing,
+        running,
+    }))
+}
+
+/// 수집기 작업 실행 이력 조회.
+///
+/// GET /api/v1/monitoring/collector/runs
+#[utoipa::path(
+    get,
+    path = "/api/v1/monitoring/collector/runs",
+    tag = "monitoring",
+    params(
+        ("job" = Option<String>, Query, description = "작업 이름 필터"),
+        ("status" = Option<String>, Query, description = "상태 필터 (running, success, failed, skipped, interrupted)"),
+        ("limit" = Option<i64>, Query, description = "조회할 최대 실행 수 (기본값: 50)")
+    ),
+    responses(
+        (status = 200, description = "작업 실행 이력", body = CollectorRunsResponse),
+        (status = 400, description = "잘못된 상태 필터", body = ApiError),
+        (status = 500, description = "서버 오류", body = ApiError)
+    )
+)]
+pub async fn list_collector_runs(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<CollectorRunsQuery>,
+) -> Result<Json<CollectorRunsResponse>, (StatusCode, Json<ApiError>)> {
+    let status = match query.status.as_deref() {
+        Some(s) => Some(JobRunStatus::parse(s).ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new(
+                    "INVALID_STATUS",
+                    format!("알 수 없는 작업 상태: {}", s),
+                )),
+            )
+        })?),
+        None => None,
+    };
+
+    let runs = collector_job_runs(&state)?
+        .list(query.job.as_deref(), status, query.limit.clamp(1, 500))
+        .await
+        .map_err(collector_query_error)?;
+
+    Ok(Json(CollectorRunsResponse {
+        count: runs.len(),
+        runs: runs.into_iter().map(CollectorJobRunDto::from).collect(),
+    }))
+}
+
 /// 시스템 요약 정보 (디버깅용).
 ///
 /// GET /api/v1/monitoring/summary
@@ -360,6 +692,33 @@ pub async fn get_summary(State(state): State<Arc<AppState>>) -> impl IntoRespons
         })
         .collect();
 
+    // 수집기 작업 상태 (DB 미구성 또는 조회 실패 시 null)
+    let collector_jobs = match state.db_pool.as_ref() {
+        Some(pool) => match CollectorJobRunRepository::new(pool.clone())
+            .latest_by_job()
+            .await
+        {
+            Ok(runs) => {
+                let failing: Vec<&str> = runs
+                    .iter()
+                    .filter(|run| run.status == JobRunStatus::Failed)
+                    .map(|run| run.job_name.as_str())
+                    .collect();
+                let last_run_at = runs.iter().map(|run| run.started_at).max();
+                serde_json::json!({
+                    "total": runs.len(),
+                    "failing": failing,
+                    "last_run_at": last_run_at.map(|t| t.to_rfc3339()),
+                })
+            }
+            Err(e) => {
+                warn!(error = %e, "수집기 작업 상태 조회 실패");
+                serde_json::Value::Null
+            }
+        },
+        None => serde_json::Value::Null,
+    };
+
     Json(serde_json::json!({
         "uptime_secs": state.uptime_secs(),
         "version": state.version,
@@ -373,6 +732,7 @@ pub async fn get_summary(State(state): State<Arc<AppState>>) -> impl IntoRespons
         },
         "recent_errors": recent_errors,
         "top_categories": stats.by_category,
+        "collector_jobs": collector_jobs,
     }))
 }
 
@@ -385,6 +745,9 @@ pub fn monitoring_router() -> Router<Arc<AppState>> {
         .route("/stats", get(get_stats))
         .route("/stats/reset", post(reset_stats))
         .route("/summary", get(get_summary))
+        .route("/exchanges", get(get_exchange_health))
+        .route("/collector/jobs", get(get_collector_jobs))
+        .route("/collector/runs", get(list_collector_runs))
 }
 
 #[cfg(test)]
@@ -454,4 +817,50 @@ mod tests {
 
         assert!(!stats.stats_since.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_get_exchange_health() {
+        let registry = global_health_registry();
+        registry.register("test_monitoring_primary");
+        registry.record_heartbeat("test_monitoring_primary", Some(120));
+        registry.register_group(
+            "test_monitoring_group",
+            vec!["test_monitoring_primary".to_string(), "naver".to_string()],
+        );
+
+        let app = Router::new().route("/exchanges", get(get_exchange_health));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/exchanges")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let health: ExchangeHealthResponse = serde_json::from_slice(&body).unwrap();
+
+        let provider = health
+            .providers
+            .iter()
+            .find(|p| p.name == "test_monitoring_primary")
+            .unwrap();
+        assert_eq!(provider.status, "healthy");
+        assert_eq!(provider.heartbeat_latency_ms, Some(120));
+
+        let group = health
+            .groups
+            .iter()
+            .find(|g| g.name == "test_monitoring_group")
+            .unwrap();
+        assert_eq!(group.active, "test_monitoring_primary");
+        assert!(!group.failed_over);
+    }
 }
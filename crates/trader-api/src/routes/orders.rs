@@ -238,6 +238,8 @@ pub async fn create_order(
         price: request.price,
         stop_price: None,
         time_in_force: TimeInForce::GTC,
+        expire_time: None,
+        trailing_percent: None,
         client_order_id: None,
         strategy_id: None,
     };
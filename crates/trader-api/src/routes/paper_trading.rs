@@ -1373,6 +1373,7 @@ pub async fn start_paper_trading(
             &state.mock_providers,
             credential_id,
             state.subscriptions.as_ref(),
+            None, // RandomWalk 시세는 실시간 바 집계/저장 대상 아님
         )
         .await
         {
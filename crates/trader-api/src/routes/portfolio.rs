@@ -26,8 +26,8 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, error, warn};
 use trader_core::{
-    ExecutionHistoryRequest, ExecutionHistoryResponse, ExecutionRecord, StrategyAccountInfo,
-    StrategyPositionInfo,
+    ExecutionHistoryRequest, ExecutionHistoryResponse, ExecutionRecord, FxRateTable,
+    StrategyAccountInfo, StrategyPositionInfo,
 };
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
@@ -178,6 +178,22 @@ pub struct PortfolioSummaryResponse {
     pub cash_balance: Decimal,
     /// 사용 중인 마진/증거금
     pub margin_used: Decimal,
+    /// 계좌 통화 (위 금액의 통화)
+    pub currency: String,
+    /// 기준 통화 (`BASE_CURRENCY`)
+    pub base_currency: String,
+    /// 적용 환율 (1 계좌 통화 = N 기준 통화, 환율이 없으면 생략)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fx_rate: Option<Decimal>,
+    /// 기준 통화 총 자산 가치
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_value_base: Option<Decimal>,
+    /// 기준 통화 총 손익
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_pnl_base: Option<Decimal>,
+    /// 기준 통화 현금 잔고
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cash_balance_base: Option<Decimal>,
 }
 
 /// 상세 잔고 응답.
@@ -218,6 +234,11 @@ pub struct HoldingsResponse {
     pub holdings: Vec<HoldingInfo>,
     /// 총 보유 종목 수
     pub total_count: usize,
+    /// 기준 통화 (`BASE_CURRENCY`)
+    pub base_currency: String,
+    /// 기준 통화 총 평가금액 (환율이 없는 종목이 있으면 생략)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_eval_amount_base: Option<Decimal>,
 }
 
 /// 개별 보유 종목 정보.
@@ -245,6 +266,14 @@ pub struct HoldingInfo {
     pub profit_loss_rate: Decimal,
     /// 시장 (KR/US)
     pub market: String,
+    /// 거래 통화 (KRW, USD, USDT 등)
+    pub currency: String,
+    /// 기준 통화 평가금액 (환율이 없으면 생략)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eval_amount_base: Option<Decimal>,
+    /// 기준 통화 평가손익 (환율이 없으면 생략)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profit_loss_base: Option<Decimal>,
 }
 
 // ==================== 쿼리 파라미터 ====================
@@ -556,6 +585,19 @@ pub async fn get_portfolio_summary(
         }
     }
 
+    // 기준 통화 환산 (최신 환율)
+    let base_currency = state.fx_rates.base_currency().to_string();
+    let fx_rate = state
+        .fx_rates
+        .latest_rate(&account_currency, &base_currency)
+        .await;
+    if fx_rate.is_none() {
+        warn!(
+            "기준 통화 환율 없음: {}/{} (fx_rates 수집 확인 필요)",
+            account_currency, base_currency
+        );
+    }
+
     Ok(Json(PortfolioSummaryResponse {
         total_value,
         total_pnl,
@@ -564,6 +606,12 @@ pub async fn get_portfolio_summary(
         daily_pnl_percent: Decimal::ZERO,
         cash_balance,
         margin_used: Decimal::ZERO, // 현금 계좌는 마진 없음
+        currency: account_currency,
+        base_currency,
+        fx_rate,
+        total_value_base: fx_rate.map(|rate| total_value * rate),
+        total_pnl_base: fx_rate.map(|rate| total_pnl * rate),
+        cash_balance_base: fx_rate.map(|rate| cash_balance * rate),
     }))
 }
 
@@ -731,6 +779,8 @@ pub async fn get_holdings(
 ) -> Result<Json<HoldingsResponse>, (StatusCode, Json<ApiError>)> {
     let mut holdings: Vec<HoldingInfo> = Vec::new();
     let mut cache_hit = false;
+    let base_currency = state.fx_rates.base_currency().to_string();
+    let fx_rates = state.fx_rates.table().await;
 
     // credential_id가 제공된 경우 동적으로 클라이언트 생성
     if let Some(credential_id) = params.credential_id {
@@ -746,7 +796,8 @@ pub async fn get_holdings(
                         credential_id,
                         cached_positions.len()
                     );
-                    holdings = convert_positions_to_holdings(&cached_positions);
+                    holdings =
+                        convert_positions_to_holdings(&cached_positions, &fx_rates, &base_currency);
                     cache_hit = true;
                 }
                 Ok(None) => {
@@ -787,7 +838,11 @@ pub async fn get_holdings(
                                 }
                             }
 
-                            holdings = convert_positions_to_holdings(&positions);
+                            holdings = convert_positions_to_holdings(
+                                &positions,
+                                &fx_rates,
+                                &base_currency,
+                            );
                         }
                         Err(e) => {
                             warn!("보유종목 조회 실패 (credential {}): {:?}", credential_id, e);
@@ -815,6 +870,10 @@ pub async fn get_holdings(
     }
 
     let total_count = holdings.len();
+    let total_eval_amount_base = holdings
+        .iter()
+        .map(|h| h.eval_amount_base)
+        .sum::<Option<Decimal>>();
 
     // 거래소 데이터를 positions 테이블에 동기화
     if let (Some(db_pool), Some(credential_id)) = (&state.db_pool, params.credential_id) {
@@ -876,6 +935,8 @@ pub async fn get_holdings(
     Ok(Json(HoldingsResponse {
         holdings,
         total_count,
+        base_currency,
+        total_eval_amount_base,
     }))
 }
 
@@ -1170,10 +1231,18 @@ fn detect_market_from_ticker(ticker: &str) -> String {
 }
 
 /// StrategyPositionInfo를 HoldingInfo로 변환하는 헬퍼 함수.
-fn convert_positions_to_holdings(positions: &[StrategyPositionInfo]) -> Vec<HoldingInfo> {
+///
+/// 평가금액/손익은 거래 통화 기준이며, 최신 환율로 기준 통화 금액을 함께 채웁니다.
+fn convert_positions_to_holdings(
+    positions: &[StrategyPositionInfo],
+    fx_rates: &FxRateTable,
+    base_currency: &str,
+) -> Vec<HoldingInfo> {
     positions
         .iter()
         .map(|position| {
+            let currency = position.quote_currency();
+            let fx_rate = fx_rates.latest_rate(&currency, base_currency);
             let symbol_str = position.ticker.clone();
             let eval_amount = position.quantity * position.current_price;
             let profit_loss_rate = if position.avg_entry_price > Decimal::ZERO {
@@ -1194,6 +1263,9 @@ fn convert_positions_to_holdings(positions: &[StrategyPositionInfo]) -> Vec<Hold
                 profit_loss: position.unrealized_pnl,
                 profit_loss_rate,
                 market: detect_market_from_ticker(&symbol_str),
+                currency,
+                eval_amount_base: fx_rate.map(|rate| eval_amount * rate),
+                profit_loss_base: fx_rate.map(|rate| position.unrealized_pnl * rate),
             }
         })
         .collect()
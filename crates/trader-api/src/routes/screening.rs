@@ -8,6 +8,8 @@
 //! - `GET /api/v1/screening/presets` - 사용 가능한 프리셋 목록
 //! - `GET /api/v1/screening/presets/{preset}` - 프리셋 스크리닝 실행
 //! - `GET /api/v1/screening/momentum` - 모멘텀 기반 스크리닝
+//! - `GET /api/v1/sectors/ranking` - 섹터 상대강도 순위
+//! - `GET /api/v1/sectors/{sector}/pairs` - 섹터 내 공적분 페어 스캔
 
 use std::sync::Arc;
 
@@ -18,9 +20,12 @@ use axum::{
     routing::{get, post},
     Json, Router,
 };
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info, warn};
+use trader_analytics::pairs::{
+    scan_cointegrated_pairs, PairCandidate, PairScanConfig, PriceSeries, Significance,
+};
 use trader_core::MacroEnvironment;
 use trader_data::{cache::MacroData, RedisCache};
 use ts_rs::TS;
@@ -29,7 +34,7 @@ use utoipa::ToSchema;
 use crate::{
     repository::{
         MomentumScreenResult, ScreeningFilter, ScreeningPreset, ScreeningRepository,
-        ScreeningResult,
+        ScreeningResult, SectorCloseRow,
     },
     state::AppState,
 };
@@ -523,6 +528,101 @@ fn to_sector_rs_dto(r: crate::repository::SectorRsResult) -> SectorRsDto {
     }
 }
 
+/// 섹터 페어 스캔 쿼리
+#[derive(Debug, Clone, Deserialize)]
+pub struct SectorPairsQuery {
+    /// 시장 필터 (KR, US, KR-KOSPI 등)
+    #[serde(default)]
+    pub market: Option<String>,
+    /// 조회 기간 (일, 기본: 180)
+    #[serde(default)]
+    pub days: Option<i32>,
+    /// 공적분 유의수준 (one_percent, five_percent, ten_percent)
+    #[serde(default)]
+    pub significance: Option<Significance>,
+    /// 최대 반감기 (봉, 기본: 30)
+    #[serde(default)]
+    pub max_half_life: Option<f64>,
+    /// 스캔할 최대 종목 수 (시가총액 상위, 기본: 30)
+    #[serde(default)]
+    pub max_symbols: Option<i64>,
+    /// 최대 결과 수 (기본: 20)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// 섹터 페어 스캔 응답
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct SectorPairsResponse {
+    /// 섹터명
+    pub sector: String,
+    /// 스캔한 종목 수
+    pub symbols_scanned: usize,
+    /// 발견된 페어 수 (limit 적용 전)
+    pub total: usize,
+    /// 조회 기간 (일)
+    pub days: i32,
+    /// 페어 목록 (공적분 강도 순)
+    pub results: Vec<PairCandidateDto>,
+}
+
+/// 공적분 페어 DTO
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct PairCandidateDto {
+    /// 종속 종목 (스프레드의 y)
+    pub ticker_a: String,
+    /// 헤지 종목 (스프레드의 x)
+    pub ticker_b: String,
+    /// 헤지 비율 (β)
+    pub hedge_ratio: f64,
+    /// 절편 (α)
+    pub intercept: f64,
+    /// 잔차 ADF 통계량
+    pub adf_statistic: f64,
+    /// 유의수준 임계값
+    pub critical_value: f64,
+    /// 스프레드 반감기 (일)
+    pub half_life: f64,
+    /// 현재 스프레드 Z-Score
+    pub current_zscore: f64,
+    /// 가격 상관계수
+    pub correlation: f64,
+    /// 공통 관측치 수
+    pub observations: usize,
+}
+
+fn to_pair_candidate_dto(p: PairCandidate) -> PairCandidateDto {
+    PairCandidateDto {
+        ticker_a: p.ticker_a,
+        ticker_b: p.ticker_b,
+        hedge_ratio: p.hedge_ratio,
+        intercept: p.intercept,
+        adf_statistic: p.adf_statistic,
+        critical_value: p.critical_value,
+        half_life: p.half_life,
+        current_zscore: p.current_zscore,
+        correlation: p.correlation,
+        observations: p.observations,
+    }
+}
+
+/// 종목별 종가 행을 페어 스캔용 시계열로 묶습니다 (행은 티커 순 정렬 가정).
+fn group_price_series(rows: Vec<SectorCloseRow>) -> Vec<PriceSeries> {
+    let mut series: Vec<PriceSeries> = Vec::new();
+    for row in rows {
+        let Some(close) = row.close.to_f64() else {
+            continue;
+        };
+        match series.last_mut() {
+            Some(last) if last.ticker == row.ticker => last.closes.push((row.open_time, close)),
+            _ => series.push(PriceSeries::new(row.ticker, vec![(row.open_time, close)])),
+        }
+    }
+    series
+}
+
 fn error_response(code: &str, message: &str) -> (StatusCode, Json<ErrorResponse>) {
     (
         StatusCode::INTERNAL_SERVER_ERROR,
@@ -1038,6 +1138,117 @@ pub async fn get_sector_ranking(
     .into_response()
 }
 
+/// 섹터 내 공적분 페어 스캔
+///
+/// GET /api/v1/sectors/{sector}/pairs
+///
+/// 섹터 시가총액 상위 종목의 일봉 종가로 모든 종목 쌍에 Engle-Granger 검정을 수행하고,
+/// 반감기 범위를 만족하는 페어를 공적분 강도 순으로 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/sectors/{sector}/pairs",
+    params(
+        ("sector" = String, Path, description = "섹터명 (섹터 순위의 sector 값)"),
+        ("market" = Option<String>, Query, description = "시장 필터 (KR, US, KR-KOSPI)"),
+        ("days" = Option<i32>, Query, description = "조회 기간 (일, 기본: 180)"),
+        ("significance" = Option<String>, Query, description = "유의수준 (one_percent, five_percent, ten_percent)"),
+        ("max_half_life" = Option<f64>, Query, description = "최대 반감기 (일, 기본: 30)"),
+        ("max_symbols" = Option<i64>, Query, description = "스캔할 최대 종목 수 (기본: 30)"),
+        ("limit" = Option<usize>, Query, description = "최대 결과 수 (기본: 20)")
+    ),
+    responses(
+        (status = 200, description = "페어 스캔 성공", body = SectorPairsResponse),
+        (status = 500, description = "서버 오류", body = ErrorResponse)
+    ),
+    tag = "sectors"
+)]
+pub async fn get_sector_pairs(
+    State(state): State<Arc<AppState>>,
+    Path(sector): Path<String>,
+    Query(query): Query<SectorPairsQuery>,
+) -> impl IntoResponse {
+    debug!(
+        "섹터 페어 스캔 요청: sector={}, market={:?}, days={:?}",
+        sector, query.market, query.days
+    );
+
+    let db_pool = match &state.db_pool {
+        Some(pool) => pool,
+        None => {
+            return error_response("DATABASE_ERROR", "Database not available").into_response();
+        }
+    };
+
+    let days = query.days.unwrap_or(180);
+    let max_symbols = query.max_symbols.unwrap_or(30).clamp(2, 100);
+    let limit = query.limit.unwrap_or(20);
+
+    let rows = match ScreeningRepository::fetch_sector_closes(
+        db_pool,
+        &sector,
+        query.market.as_deref(),
+        days,
+        max_symbols,
+    )
+    .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            warn!("섹터 종가 조회 실패: {}", e);
+            return error_response("SECTOR_PAIRS_ERROR", &format!("섹터 페어 스캔 실패: {}", e))
+                .into_response();
+        }
+    };
+
+    let series = group_price_series(rows);
+    let symbols_scanned = series.len();
+
+    let defaults = PairScanConfig::default();
+    let config = PairScanConfig::default()
+        .with_significance(query.significance.unwrap_or_default())
+        .with_half_life_range(
+            defaults.min_half_life,
+            query.max_half_life.unwrap_or(defaults.max_half_life),
+        );
+
+    // O(n²) 회귀는 블로킹 스레드에서 수행
+    let candidates = match tokio::task::spawn_blocking(move || {
+        scan_cointegrated_pairs(&series, &config)
+    })
+    .await
+    {
+        Ok(c) => c,
+        Err(e) => {
+            warn!("섹터 페어 스캔 실패: {}", e);
+            return error_response("SECTOR_PAIRS_ERROR", &format!("섹터 페어 스캔 실패: {}", e))
+                .into_response();
+        }
+    };
+
+    info!(
+        "섹터 페어 스캔 완료: sector={}, symbols={}, pairs={}",
+        sector,
+        symbols_scanned,
+        candidates.len()
+    );
+
+    let total = candidates.len();
+    let results = candidates
+        .into_iter()
+        .take(limit)
+        .map(to_pair_candidate_dto)
+        .collect();
+
+    Json(SectorPairsResponse {
+        sector,
+        symbols_scanned,
+        total,
+        days,
+        results,
+    })
+    .into_response()
+}
+
 /// 매크로 경제 지표 조회 및 환경 평가 (Redis 캐시 전용).
 ///
 /// Collector가 5분마다 Redis에 캐시한 매크로 데이터를 읽습니다.
@@ -1123,5 +1334,7 @@ pub fn screening_router() -> Router<Arc<AppState>> {
 
 /// 섹터 분석 라우터 생성
 pub fn sectors_router() -> Router<Arc<AppState>> {
-    Router::new().route("/ranking", get(get_sector_ranking))
+    Router::new()
+        .route("/ranking", get(get_sector_ranking))
+        .route("/{sector}/pairs", get(get_sector_pairs))
 }
@@ -2,6 +2,16 @@
 //!
 //! 백테스트 및 실거래에서 발생한 기술 신호를 조회하고 검색합니다.
 //! 시그널 생성 시 텔레그램 알림 전송 기능을 포함합니다.
+//! 전략 엔진이 기록한 신호 결정 추적(발생/억제 이유)도 조회할 수 있습니다.
+//!
+//! # 결정 추적 범위
+//!
+//! - 발생한 신호는 모든 전략에서 추적이 있습니다 (단계별 추적이 없으면 신호 `reason`만 담은 기본 추적).
+//! - 엔진 충돌 검증(미체결 주문, 중복 포지션, 레그 그룹)으로 제외된 신호는 모든 전략에서 억제로 기록됩니다.
+//! - 조건·필터·리스크·스크리닝 단계에서 억제된 평가는 단계별 추적을 기록하는 전략만 남깁니다.
+//!   현재 평균회귀 계열(`mean_reversion`)과 이를 기반 전략으로 쓰는 옵션 오버레이(`option_overlay`)뿐이며,
+//!   다른 내장 전략의 자체 진입 검사(`can_enter` 등)와 `signal_filters`/`risk_checks`/
+//!   `screening_integration`의 추적 없는 헬퍼 호출은 억제 추적을 남기지 않습니다.
 
 use std::sync::Arc;
 
@@ -11,20 +21,24 @@ use axum::{
     routing::{get, post},
     Json, Router,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use tracing::{debug, info, warn};
-use trader_core::{Side, SignalIndicators, SignalMarker, SignalType};
+use trader_core::{
+    DecisionOutcome, DecisionTrace, DecisionTraceQuery, Side, SignalIndicators, SignalMarker,
+    SignalType,
+};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::{
     error::{ApiErrorResponse, ApiResult, BoxedApiError},
     repository::{
-        BacktestResultsRepository, SignalMarkerRepository, SignalPerformanceRepository,
-        SignalPerformanceResponse, SignalReturnPoint, SignalSymbolStats,
+        BacktestResultsRepository, DecisionTraceRepository, SignalMarkerRepository,
+        SignalPerformanceRepository, SignalPerformanceResponse, SignalReturnPoint,
+        SignalSymbolStats,
     },
     AppState,
 };
@@ -203,6 +217,10 @@ pub struct SignalMarkerDto {
 
     /// 실행 여부
     pub executed: bool,
+
+    /// 결정 추적 (기록된 경우)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision_trace: Option<DecisionTrace>,
 }
 
 impl From<SignalMarker> for SignalMarkerDto {
@@ -220,6 +238,7 @@ impl From<SignalMarker> for SignalMarkerDto {
             strategy_id: marker.strategy_id,
             strategy_name: marker.strategy_name,
             executed: marker.executed,
+            decision_trace: marker.decision_trace,
         }
     }
 }
@@ -234,6 +253,68 @@ pub struct SignalSearchResponse {
     pub signals: Vec<SignalMarkerDto>,
 }
 
+/// 결정 추적 검색 요청
+#[derive(Debug, Clone, Deserialize, IntoParams, ToSchema)]
+pub struct DecisionTraceSearchQuery {
+    /// 전략 ID
+    #[serde(default)]
+    pub strategy_id: Option<String>,
+
+    /// 종목 (예: "005930")
+    #[serde(default)]
+    pub ticker: Option<String>,
+
+    /// 평가 날짜 (UTC, 예: "2026-03-04")
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+
+    /// 시작 시각 (ISO 8601)
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+
+    /// 종료 시각 (ISO 8601)
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+
+    /// 결정 결과 ("emitted", "suppressed")
+    ///
+    /// `suppressed`의 범위는 모듈 문서의 "결정 추적 범위"를 참고하세요.
+    #[serde(default)]
+    pub outcome: Option<DecisionOutcome>,
+
+    /// 방향 ("buy", "sell")
+    #[serde(default)]
+    pub side: Option<Side>,
+
+    /// 최대 결과 개수 (기본 100, 최대 1000)
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+impl From<&DecisionTraceSearchQuery> for DecisionTraceQuery {
+    fn from(query: &DecisionTraceSearchQuery) -> Self {
+        Self {
+            strategy_id: query.strategy_id.clone(),
+            ticker: query.ticker.clone(),
+            date: query.date,
+            from: query.from,
+            to: query.to,
+            outcome: query.outcome,
+            side: query.side,
+        }
+    }
+}
+
+/// 결정 추적 검색 응답
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DecisionTraceSearchResponse {
+    /// 총 결과 수
+    pub total: usize,
+
+    /// 결정 추적 목록 (최신순)
+    pub traces: Vec<DecisionTrace>,
+}
+
 // ==================== API 핸들러 ====================
 
 /// 지표 기반 신호 검색
@@ -548,6 +629,7 @@ pub async fn create_signal(
         strategy_name: req.strategy_name.clone(),
         executed: false,
         metadata: std::collections::HashMap::new(),
+        decision_trace: None,
     };
 
     let signal_id = marker.id.to_string();
@@ -784,6 +866,113 @@ pub async fn get_symbol_signal_performance(
     }
 }
 
+/// 결정 추적 검색
+///
+/// 전략 엔진이 남긴 결정 추적을 조건으로 검색합니다. DB가 있으면 `decision_traces`
+/// 테이블에서, 없으면 엔진이 메모리에 보관 중인 최근 추적에서 조회합니다.
+/// 예: `?ticker=005930&date=2026-03-04&outcome=suppressed` - 해당일 매수하지 않은 이유
+///
+/// 조건·필터·리스크·스크리닝 단계의 억제 추적은 단계별 추적을 기록하는 전략(평균회귀 계열,
+/// 이를 기반으로 한 옵션 오버레이)에서만 남습니다. 다른 전략은 충돌로 제외된 신호만
+/// `suppressed`로 검색되므로, 신호가 없던 날의 결과가 비어 있을 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/signals/traces",
+    params(DecisionTraceSearchQuery),
+    responses(
+        (status = 200, description = "검색 성공", body = DecisionTraceSearchResponse),
+        (status = 400, description = "잘못된 요청", body = ApiErrorResponse),
+        (status = 500, description = "서버 오류", body = ApiErrorResponse)
+    ),
+    tag = "signals"
+)]
+pub async fn search_decision_traces(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<DecisionTraceSearchQuery>,
+) -> Result<Json<DecisionTraceSearchResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let limit = query.limit.clamp(1, 1000);
+    let trace_query = DecisionTraceQuery::from(&query);
+
+    let traces = match &state.db_pool {
+        Some(pool) => DecisionTraceRepository::new(pool.clone())
+            .search(&trace_query, limit)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "결정 추적 검색 실패");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiErrorResponse::new("DATABASE_ERROR", e.to_string())),
+                )
+            })?,
+        None => {
+            state
+                .strategy_engine
+                .read()
+                .await
+                .search_decision_traces(&trace_query, limit as usize)
+                .await
+        }
+    };
+
+    Ok(Json(DecisionTraceSearchResponse {
+        total: traces.len(),
+        traces,
+    }))
+}
+
+/// 신호 ID로 결정 추적 조회
+///
+/// DB가 있으면 `decision_traces` 테이블에서, 없으면 엔진 메모리에서 조회합니다.
+/// 단계별 추적을 기록하지 않는 전략의 신호는 `reason`만 담은 기본 추적을 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/signals/traces/{signal_id}",
+    params(
+        ("signal_id" = Uuid, Path, description = "신호 ID")
+    ),
+    responses(
+        (status = 200, description = "조회 성공", body = DecisionTrace),
+        (status = 404, description = "결정 추적을 찾을 수 없음", body = ApiErrorResponse),
+        (status = 500, description = "서버 오류", body = ApiErrorResponse)
+    ),
+    tag = "signals"
+)]
+pub async fn get_decision_trace(
+    State(state): State<Arc<AppState>>,
+    Path(signal_id): Path<Uuid>,
+) -> Result<Json<DecisionTrace>, (StatusCode, Json<ApiErrorResponse>)> {
+    let trace = match &state.db_pool {
+        Some(pool) => DecisionTraceRepository::new(pool.clone())
+            .get_by_signal(signal_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "결정 추적 조회 실패");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiErrorResponse::new("DATABASE_ERROR", e.to_string())),
+                )
+            })?,
+        None => {
+            state
+                .strategy_engine
+                .read()
+                .await
+                .get_decision_trace(signal_id)
+                .await
+        }
+    };
+
+    trace.map(Json).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ApiErrorResponse::new(
+                "NOT_FOUND",
+                format!("신호 {}의 결정 추적이 없습니다", signal_id),
+            )),
+        )
+    })
+}
+
 // ==================== 라우터 ====================
 
 /// SignalMarker API 라우터
@@ -794,6 +983,9 @@ pub fn signals_router() -> Router<Arc<AppState>> {
         .route("/by-symbol", get(get_signals_by_symbol))
         .route("/by-strategy", get(get_signals_by_strategy))
         .route("/markers/backtest/{id}", get(get_backtest_signals))
+        // 결정 추적 API
+        .route("/traces", get(search_decision_traces))
+        .route("/traces/{signal_id}", get(get_decision_trace))
         // 신호 성과 API
         .route("/performance", get(get_signal_performance))
         .route("/performance/scatter", get(get_signal_scatter))
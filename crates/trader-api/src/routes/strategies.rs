@@ -11,12 +11,15 @@
 //! - `POST /api/v1/strategies/{id}/start` - 전략 시작
 //! - `POST /api/v1/strategies/{id}/stop` - 전략 중지
 //! - `PUT /api/v1/strategies/{id}/config` - 전략 설정 변경
+//! - `GET|PUT /api/v1/strategies/{id}/script` - 스크립트 전략 조회/변경
+//! - `POST /api/v1/strategies/scripts/validate` - 스크립트 검증
+//! - `GET|POST /api/v1/strategies/{id}/shadows` - 섀도(페이퍼) 실행 조회/시작
 
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
     response::IntoResponse,
     routing::{get, post, put},
     Json, Router,
@@ -26,7 +29,13 @@ use futures::FutureExt;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use trader_strategy::{EngineError, EngineStats, Strategy, StrategyStatus};
+use trader_strategy::{
+    scripting::SCRIPT_STRATEGY_ID, ConfigChange, DeploymentCandidate, DeploymentOptions,
+    DeploymentPreview, DryRunReport, EngineError, EngineStats, ScriptError, ScriptHost, ScriptInfo,
+    ScriptLimits, ScriptStrategyConfig, ShadowComparison, ShadowConfig, ShadowSummary,
+    SnapshotReason, SnapshotSummary, Strategy, StrategyDeployment, StrategySnapshot,
+    StrategyStatus,
+};
 use ts_rs::TS;
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -418,6 +427,22 @@ fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ApiError>) {
         EngineError::AlreadyRunning(_) => (StatusCode::BAD_REQUEST, "ALREADY_RUNNING"),
         EngineError::ChannelError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "CHANNEL_ERROR"),
         EngineError::InternalError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR"),
+        EngineError::StateStoreNotConfigured => {
+            (StatusCode::SERVICE_UNAVAILABLE, "STATE_STORE_UNAVAILABLE")
+        }
+        EngineError::SnapshotNotFound(_) => (StatusCode::NOT_FOUND, "SNAPSHOT_NOT_FOUND"),
+        EngineError::CheckpointFailed(_) => {
+            (StatusCode::INTERNAL_SERVER_ERROR, "CHECKPOINT_FAILED")
+        }
+        EngineError::DeploymentRejected(_) => {
+            (StatusCode::UNPROCESSABLE_ENTITY, "DEPLOYMENT_REJECTED")
+        }
+        EngineError::DeploymentNotFound(_) => (StatusCode::NOT_FOUND, "DEPLOYMENT_NOT_FOUND"),
+        EngineError::DeploymentFailed(_) => {
+            (StatusCode::INTERNAL_SERVER_ERROR, "DEPLOYMENT_FAILED")
+        }
+        EngineError::ShadowNotFound(_) => (StatusCode::NOT_FOUND, "SHADOW_NOT_FOUND"),
+        EngineError::ShadowRejected(_) => (StatusCode::UNPROCESSABLE_ENTITY, "SHADOW_REJECTED"),
     };
 
     (status, Json(ApiError::new(code, err.to_string())))
@@ -571,6 +596,11 @@ pub async fn create_strategy(
         )
         .await
         .map_err(engine_error_to_response)?;
+    // 전략 타임프레임 캔들만 전달
+    engine
+        .set_strategy_timeframe(&strategy_id, timeframe.parse().ok())
+        .await
+        .map_err(engine_error_to_response)?;
 
     // WebSocket 브로드캐스트: 전략 생성 알림
     state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
@@ -637,6 +667,22 @@ pub async fn delete_strategy(
         {
             tracing::warn!(strategy_id = %id, error = %e, "전략 관심 종목 DB 정리 실패");
         }
+
+        // 상태 스냅샷 정리 (같은 ID로 재생성 시 과거 상태가 복원되지 않도록)
+        if let Err(e) = crate::repository::StrategySnapshotRepository::new(pool.clone())
+            .delete_all(&id)
+            .await
+        {
+            tracing::warn!(strategy_id = %id, error = %e, "전략 상태 스냅샷 정리 실패");
+        }
+
+        // 배포 이력 정리
+        if let Err(e) = crate::repository::StrategyDeploymentRepository::new(pool.clone())
+            .delete_all(&id)
+            .await
+        {
+            tracing::warn!(strategy_id = %id, error = %e, "전략 배포 이력 정리 실패");
+        }
     }
 
     // WebSocket 브로드캐스트: 전략 삭제 알림
@@ -1055,6 +1101,7 @@ async fn subscribe_strategy_symbols_to_stream(
         &state.mock_providers,
         credential_id,
         state.subscriptions.as_ref(),
+        state.live_bars.as_ref(),
     )
     .await
     {
@@ -1719,6 +1766,8 @@ pub async fn clone_strategy(
         })
         .unwrap_or_default();
 
+    let timeframe = source.timeframe.clone().unwrap_or_else(|| "1d".to_string());
+
     // 새 전략 생성 (원본 전략의 credential_id 복사)
     let input = CreateStrategyInput {
         id: new_id.clone(),
@@ -1727,7 +1776,7 @@ pub async fn clone_strategy(
         strategy_type: strategy_type.clone(),
         symbols,
         market: source.market.clone().unwrap_or_else(|| "KR".to_string()),
-        timeframe: source.timeframe.clone().unwrap_or_else(|| "1d".to_string()),
+        timeframe: timeframe.clone(),
         config: merged_config.clone(),
         risk_config: Some(merged_risk),
         allocated_capital,
@@ -1750,7 +1799,7 @@ pub async fn clone_strategy(
     // 전략 인스턴스 생성 및 엔진에 등록 (공유 StrategyContext 전달)
     if let Ok(strategy) = create_strategy_instance(&strategy_type) {
         let engine = state.strategy_engine.read().await;
-        let _ = engine
+        let registered = engine
             .register_strategy(
                 &new_id,
                 strategy,
@@ -1759,6 +1808,11 @@ pub async fn clone_strategy(
                 state.strategy_context.clone(),
             )
             .await;
+        if registered.is_ok() {
+            let _ = engine
+                .set_strategy_timeframe(&new_id, timeframe.parse().ok())
+                .await;
+        }
     }
 
     // WebSocket 브로드캐스트: 전략 복사 알림
@@ -2031,153 +2085,1188 @@ pub async fn update_strategy_timeframes(
     }))
 }
 
-// ==================== router ====================
+// ==================== 상태 스냅샷 ====================
 
-/// 전략 관리 라우터 생성.
-pub fn strategies_router() -> Router<Arc<AppState>> {
-    use super::schema::{get_strategy_schema, list_strategy_meta};
+/// 스냅샷 목록 조회 쿼리 파라미터.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SnapshotListQuery {
+    /// 최대 조회 개수 (기본 20, 최대 200)
+    pub limit: Option<usize>,
+}
 
-    Router::new()
-        // 목록, 생성, 통계
-        .route("/", get(list_strategies).post(create_strategy))
-        .route("/stats", get(get_engine_stats))
-        // 전략 메타데이터 (SDUI 스키마용)
-        .route("/meta", get(list_strategy_meta))
-        // 개별 전략 조작
-        .route("/{id}", get(get_strategy).delete(delete_strategy))
-        .route("/{id}/start", post(start_strategy))
-        .route("/{id}/stop", post(stop_strategy))
-        .route("/{id}/config", put(update_config))
-        .route("/{id}/risk", put(update_risk_settings))
-        .route("/{id}/symbols", put(update_symbols))
-        .route("/{id}/credential", put(update_credential))
-        .route("/{id}/clone", post(clone_strategy))
-        // 전략 스키마 (SDUI)
-        .route("/{id}/schema", get(get_strategy_schema))
-        // 다중 타임프레임 설정
-        .route("/{id}/timeframes", get(get_strategy_timeframes).put(update_strategy_timeframes))
+/// 스냅샷 목록 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SnapshotListResponse {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 스냅샷 요약 목록 (최신순)
+    #[schema(value_type = Vec<Object>)]
+    pub snapshots: Vec<SnapshotSummary>,
 }
 
-// ==================== 테스트 ====================
+/// 스냅샷 상세 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SnapshotDetailResponse {
+    /// 스냅샷 요약
+    #[schema(value_type = Object)]
+    pub snapshot: SnapshotSummary,
+    /// 저장된 상태 (JSON으로 해석 가능한 경우)
+    #[schema(value_type = Option<Object>)]
+    pub state: Option<Value>,
+    /// 스냅샷 시점의 전략 진단 상태
+    #[schema(value_type = Object)]
+    pub diagnostics: Value,
+}
 
-#[cfg(test)]
-mod tests {
-    use axum::{
-        body::Body,
-        http::{Request, StatusCode},
-    };
-    use tower::ServiceExt;
+impl From<StrategySnapshot> for SnapshotDetailResponse {
+    fn from(snapshot: StrategySnapshot) -> Self {
+        Self {
+            snapshot: snapshot.summary(),
+            state: snapshot.state_json(),
+            diagnostics: snapshot.diagnostics,
+        }
+    }
+}
 
-    use super::*;
+/// 전략 상태 스냅샷 목록 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/snapshots",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "전략 ID"),
+        ("limit" = Option<usize>, Query, description = "최대 조회 개수 (기본 20)")
+    ),
+    responses(
+        (status = 200, description = "스냅샷 목록 조회 성공", body = SnapshotListResponse),
+        (status = 503, description = "상태 저장소 미설정", body = ApiError)
+    )
+)]
+pub async fn list_strategy_snapshots(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<SnapshotListQuery>,
+) -> Result<Json<SnapshotListResponse>, (StatusCode, Json<ApiError>)> {
+    let limit = query.limit.unwrap_or(20).clamp(1, 200);
+    let engine = state.strategy_engine.read().await;
 
-    #[tokio::test]
-    async fn test_list_strategies_empty() {
-        use crate::state::create_test_state;
+    let snapshots = engine
+        .list_snapshots(&id, limit)
+        .await
+        .map_err(engine_error_to_response)?;
 
-        let state = Arc::new(create_test_state());
-        let app = Router::new()
-            .route("/strategies", get(list_strategies))
-            .with_state(state);
+    Ok(Json(SnapshotListResponse {
+        strategy_id: id,
+        snapshots,
+    }))
+}
 
-        let response = app
-            .oneshot(
-                Request::builder()
-                    .uri("/strategies")
-                    .body(Body::empty())
-                    .unwrap(),
+/// 수동 상태 스냅샷 저장.
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/{id}/snapshots",
+    tag = "strategies",
+    params(("id" = String, Path, description = "전략 ID")),
+    responses(
+        (status = 200, description = "스냅샷 저장 성공", body = SnapshotDetailResponse),
+        (status = 400, description = "상태 저장 미지원 전략", body = ApiError),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError),
+        (status = 503, description = "상태 저장소 미설정", body = ApiError)
+    )
+)]
+pub async fn create_strategy_snapshot(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<SnapshotDetailResponse>, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
+
+    let snapshot = engine
+        .checkpoint_strategy(&id, SnapshotReason::Manual)
+        .await
+        .map_err(engine_error_to_response)?
+        .ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new(
+                    "STATE_NOT_SUPPORTED",
+                    format!("Strategy '{}' does not persist state", id),
+                )),
             )
-            .await
-            .unwrap();
+        })?;
 
-        assert_eq!(response.status(), StatusCode::OK);
+    Ok(Json(snapshot.into()))
+}
 
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let list: StrategiesListResponse = serde_json::from_slice(&body).unwrap();
+/// 전략 상태 스냅샷 상세 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/snapshots/{version}",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "전략 ID"),
+        ("version" = i64, Path, description = "스냅샷 버전")
+    ),
+    responses(
+        (status = 200, description = "스냅샷 조회 성공", body = SnapshotDetailResponse),
+        (status = 404, description = "스냅샷을 찾을 수 없음", body = ApiError),
+        (status = 503, description = "상태 저장소 미설정", body = ApiError)
+    )
+)]
+pub async fn get_strategy_snapshot(
+    State(state): State<Arc<AppState>>,
+    Path((id, version)): Path<(String, i64)>,
+) -> Result<Json<SnapshotDetailResponse>, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
 
-        assert_eq!(list.total, 0);
-        assert_eq!(list.running, 0);
-        assert!(list.strategies.is_empty());
-    }
+    let snapshot = engine
+        .get_snapshot(&id, Some(version))
+        .await
+        .map_err(engine_error_to_response)?;
 
-    #[tokio::test]
-    async fn test_get_strategy_not_found() {
-        use crate::state::create_test_state;
+    Ok(Json(snapshot.into()))
+}
 
-        let state = Arc::new(create_test_state());
-        let app = Router::new()
-            .route("/strategies/{id}", get(get_strategy))
-            .with_state(state);
+/// 전략 상태 스냅샷 내보내기.
+///
+/// `Strategy::save_state()`가 만든 원본 바이트를 그대로 내려받습니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/snapshots/{version}/export",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "전략 ID"),
+        ("version" = i64, Path, description = "스냅샷 버전")
+    ),
+    responses(
+        (status = 200, description = "스냅샷 원본 바이트 (application/octet-stream)"),
+        (status = 404, description = "스냅샷을 찾을 수 없음", body = ApiError),
+        (status = 503, description = "상태 저장소 미설정", body = ApiError)
+    )
+)]
+pub async fn export_strategy_snapshot(
+    State(state): State<Arc<AppState>>,
+    Path((id, version)): Path<(String, i64)>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
 
-        let response = app
-            .oneshot(
-                Request::builder()
-                    .uri("/strategies/nonexistent")
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+    let snapshot = engine
+        .get_snapshot(&id, Some(version))
+        .await
+        .map_err(engine_error_to_response)?;
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let disposition = format!(
+        "attachment; filename=\"{}-v{}.state\"",
+        snapshot.strategy_id, snapshot.version
+    );
 
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let error: ApiError = serde_json::from_slice(&body).unwrap();
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/octet-stream".to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        snapshot.state,
+    ))
+}
 
-        assert_eq!(error.code, "STRATEGY_NOT_FOUND");
-    }
+/// 전략 상태를 과거 스냅샷 버전으로 롤백.
+///
+/// 롤백된 상태는 새 버전으로 저장되어 재시작 후에도 유지됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/{id}/snapshots/{version}/rollback",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "전략 ID"),
+        ("version" = i64, Path, description = "롤백할 스냅샷 버전")
+    ),
+    responses(
+        (status = 200, description = "롤백 성공 (새 스냅샷)", body = SnapshotDetailResponse),
+        (status = 404, description = "전략 또는 스냅샷을 찾을 수 없음", body = ApiError),
+        (status = 500, description = "상태 복원 실패", body = ApiError),
+        (status = 503, description = "상태 저장소 미설정", body = ApiError)
+    )
+)]
+pub async fn rollback_strategy_snapshot(
+    State(state): State<Arc<AppState>>,
+    Path((id, version)): Path<(String, i64)>,
+) -> Result<Json<SnapshotDetailResponse>, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
 
-    #[tokio::test]
-    async fn test_start_strategy_not_found() {
-        use crate::state::create_test_state;
+    let snapshot = engine
+        .rollback_strategy(&id, version)
+        .await
+        .map_err(engine_error_to_response)?;
 
-        let state = Arc::new(create_test_state());
-        let app = Router::new()
-            .route("/strategies/{id}/start", post(start_strategy))
-            .with_state(state);
+    tracing::info!(
+        strategy_id = %id,
+        from_version = version,
+        new_version = snapshot.version,
+        "전략 상태 롤백"
+    );
 
-        let response = app
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/strategies/nonexistent/start")
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+    Ok(Json(snapshot.into()))
+}
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+// ==================== 버전 배포 ====================
+
+/// 전략 버전 배포 요청.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DeployStrategyRequest {
+    /// 새 설정 (JSON)
+    #[schema(value_type = Object)]
+    pub config: Value,
+    /// 전략 타입 (없으면 현재 타입 유지)
+    pub strategy_type: Option<String>,
+    /// 드라이런에 재생할 최근 시장 데이터 수 (기본 200)
+    pub dry_run_bars: Option<usize>,
+    /// 드라이런 신호 일치율 하한 (0.0 ~ 1.0, 미만이면 배포 거부)
+    pub min_agreement: Option<f64>,
+}
+
+impl DeployStrategyRequest {
+    fn options(&self) -> DeploymentOptions {
+        let defaults = DeploymentOptions::default();
+        DeploymentOptions {
+            dry_run_bars: self.dry_run_bars.unwrap_or(defaults.dry_run_bars).min(5000),
+            min_agreement: self.min_agreement,
+        }
     }
+}
 
-    #[tokio::test]
-    async fn test_get_engine_stats() {
-        use crate::state::create_test_state;
+/// 배포 사전 평가 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeploymentPreviewResponse {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 검증 통과 여부
+    pub valid: bool,
+    /// 검증 실패 사유
+    pub validation_errors: Vec<String>,
+    /// 현재 설정 대비 변경 목록
+    #[schema(value_type = Vec<Object>)]
+    pub changes: Vec<ConfigChange>,
+    /// 드라이런 결과
+    #[schema(value_type = Option<Object>)]
+    pub dry_run: Option<DryRunReport>,
+    /// 드라이런 신호 일치율 (현재 버전을 재생할 수 없으면 null)
+    pub agreement: Option<f64>,
+}
 
-        let state = Arc::new(create_test_state());
-        let app = Router::new()
-            .route("/strategies/stats", get(get_engine_stats))
-            .with_state(state);
+impl From<DeploymentPreview> for DeploymentPreviewResponse {
+    fn from(preview: DeploymentPreview) -> Self {
+        Self {
+            valid: preview.is_valid(),
+            agreement: preview.dry_run.as_ref().and_then(DryRunReport::agreement),
+            strategy_id: preview.strategy_id,
+            validation_errors: preview.validation_errors,
+            changes: preview.changes,
+            dry_run: preview.dry_run,
+        }
+    }
+}
 
-        let response = app
-            .oneshot(
-                Request::builder()
-                    .uri("/strategies/stats")
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+/// 배포 버전 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeploymentResponse {
+    /// 배포 버전
+    #[schema(value_type = Object)]
+    pub deployment: StrategyDeployment,
+}
 
-        assert_eq!(response.status(), StatusCode::OK);
+/// 배포 이력 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeploymentListResponse {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 배포 이력 (최신순)
+    #[schema(value_type = Vec<Object>)]
+    pub deployments: Vec<StrategyDeployment>,
+}
 
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let stats: EngineStatsResponse = serde_json::from_slice(&body).unwrap();
+/// 배포 롤백 쿼리 파라미터.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RollbackDeploymentQuery {
+    /// 되돌릴 버전 (없으면 직전 버전)
+    pub version: Option<i64>,
+}
+
+/// 요청으로 배포 대상 생성 (전략 타입 미지정 시 현재 타입).
+async fn deployment_candidate(
+    state: &AppState,
+    id: &str,
+    strategy_type: Option<&str>,
+    config: &Value,
+) -> Result<DeploymentCandidate, (StatusCode, Json<ApiError>)> {
+    let strategy_type = match strategy_type {
+        Some(strategy_type) => strategy_type.to_string(),
+        None => state
+            .strategy_engine
+            .read()
+            .await
+            .get_strategy_type(id)
+            .await
+            .map_err(engine_error_to_response)?,
+    };
+
+    DeploymentCandidate::from_registry(&strategy_type, config.clone()).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new("INVALID_STRATEGY_TYPE", e.to_string())),
+        )
+    })
+}
+
+/// 배포/롤백으로 바뀐 설정을 DB에 반영하고 변경을 브로드캐스트.
+async fn publish_deployment(state: &AppState, id: &str, config: Value, event: &str) {
+    if let Some(pool) = state.db_pool.as_ref() {
+        if let Err(e) = StrategyRepository::update_config(pool, id, config.clone()).await {
+            tracing::warn!(strategy_id = %id, error = %e, "Failed to persist deployed config to DB");
+        }
+    }
+
+    let status = state
+        .strategy_engine
+        .read()
+        .await
+        .get_strategy_status(id)
+        .await;
+    let (name, running) = status
+        .map(|s| (s.name, s.running))
+        .unwrap_or_else(|_| (id.to_string(), false));
+
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: id.to_string(),
+        name,
+        running,
+        event: event.to_string(),
+        data: Some(config),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+}
+
+/// 새 전략 버전 사전 평가 (검증, 설정 diff, 드라이런).
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/{id}/deployments/preview",
+    tag = "strategies",
+    params(("id" = String, Path, description = "전략 ID")),
+    request_body = DeployStrategyRequest,
+    responses(
+        (status = 200, description = "사전 평가 결과", body = DeploymentPreviewResponse),
+        (status = 400, description = "알 수 없는 전략 타입", body = ApiError),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError)
+    )
+)]
+pub async fn preview_strategy_deployment(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(request): Json<DeployStrategyRequest>,
+) -> Result<Json<DeploymentPreviewResponse>, (StatusCode, Json<ApiError>)> {
+    let candidate = deployment_candidate(
+        &state,
+        &id,
+        request.strategy_type.as_deref(),
+        &request.config,
+    )
+    .await?;
+
+    let engine = state.strategy_engine.read().await;
+    let preview = engine
+        .preview_deployment(&id, candidate, request.options().dry_run_bars)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    Ok(Json(preview.into()))
+}
+
+/// 새 전략 버전 배포.
+///
+/// 검증과 드라이런을 통과하면 기존 상태를 이어받아 교체하고 새 버전으로 기록합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/{id}/deployments",
+    tag = "strategies",
+    params(("id" = String, Path, description = "전략 ID")),
+    request_body = DeployStrategyRequest,
+    responses(
+        (status = 200, description = "배포 성공", body = DeploymentResponse),
+        (status = 400, description = "알 수 없는 전략 타입", body = ApiError),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError),
+        (status = 422, description = "검증/드라이런/상태 이전 실패로 거부", body = ApiError)
+    )
+)]
+pub async fn deploy_strategy_version(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(request): Json<DeployStrategyRequest>,
+) -> Result<Json<DeploymentResponse>, (StatusCode, Json<ApiError>)> {
+    let candidate = deployment_candidate(
+        &state,
+        &id,
+        request.strategy_type.as_deref(),
+        &request.config,
+    )
+    .await?;
+
+    let deployment = state
+        .strategy_engine
+        .read()
+        .await
+        .deploy_strategy(&id, candidate, request.options())
+        .await
+        .map_err(engine_error_to_response)?;
+
+    tracing::info!(
+        strategy_id = %id,
+        version = deployment.version,
+        state_carried_over = deployment.state_carried_over,
+        "전략 버전 배포"
+    );
+    publish_deployment(&state, &id, request.config, "deployed").await;
+
+    Ok(Json(DeploymentResponse { deployment }))
+}
+
+/// 전략 배포 이력 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/deployments",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "전략 ID"),
+        ("limit" = Option<usize>, Query, description = "최대 조회 개수 (기본 20)")
+    ),
+    responses(
+        (status = 200, description = "배포 이력 조회 성공", body = DeploymentListResponse)
+    )
+)]
+pub async fn list_strategy_deployments(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<SnapshotListQuery>,
+) -> Result<Json<DeploymentListResponse>, (StatusCode, Json<ApiError>)> {
+    let limit = query.limit.unwrap_or(20).clamp(1, 200);
+
+    let deployments = state
+        .strategy_engine
+        .read()
+        .await
+        .list_deployments(&id, limit)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    Ok(Json(DeploymentListResponse {
+        strategy_id: id,
+        deployments,
+    }))
+}
+
+/// 전략 배포 버전 상세 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/deployments/{version}",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "전략 ID"),
+        ("version" = i64, Path, description = "배포 버전")
+    ),
+    responses(
+        (status = 200, description = "배포 조회 성공", body = DeploymentResponse),
+        (status = 404, description = "배포를 찾을 수 없음", body = ApiError)
+    )
+)]
+pub async fn get_strategy_deployment(
+    State(state): State<Arc<AppState>>,
+    Path((id, version)): Path<(String, i64)>,
+) -> Result<Json<DeploymentResponse>, (StatusCode, Json<ApiError>)> {
+    let deployment = state
+        .strategy_engine
+        .read()
+        .await
+        .get_deployment(&id, version)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    Ok(Json(DeploymentResponse { deployment }))
+}
+
+/// 이전 배포 버전으로 롤백.
+///
+/// `version`을 생략하면 직전 버전으로 되돌리며, 롤백도 새 버전으로 기록됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/{id}/deployments/rollback",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "전략 ID"),
+        ("version" = Option<i64>, Query, description = "되돌릴 배포 버전 (기본: 직전 버전)")
+    ),
+    responses(
+        (status = 200, description = "롤백 성공 (새 배포 버전)", body = DeploymentResponse),
+        (status = 404, description = "전략 또는 배포를 찾을 수 없음", body = ApiError),
+        (status = 422, description = "롤백할 수 없는 버전", body = ApiError)
+    )
+)]
+pub async fn rollback_strategy_deployment(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<RollbackDeploymentQuery>,
+) -> Result<Json<DeploymentResponse>, (StatusCode, Json<ApiError>)> {
+    let deployment = state
+        .strategy_engine
+        .read()
+        .await
+        .rollback_deployment(&id, query.version)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    tracing::info!(
+        strategy_id = %id,
+        rollback_of = ?deployment.rollback_of,
+        new_version = deployment.version,
+        "전략 배포 롤백"
+    );
+    publish_deployment(&state, &id, deployment.config.clone(), "rolled_back").await;
+
+    Ok(Json(DeploymentResponse { deployment }))
+}
+
+// ==================== 섀도(페이퍼) 모드 ====================
+
+/// 섀도 시작 요청.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct StartShadowRequest {
+    /// 후보 설정 (JSON)
+    #[schema(value_type = Object)]
+    pub config: Value,
+    /// 전략 타입 (없으면 라이브 전략 타입)
+    pub strategy_type: Option<String>,
+    /// 가상 체결 설정 (initial_balance, processor, history_limit)
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub shadow: ShadowConfig,
+}
+
+/// 섀도 승격 쿼리 파라미터.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PromoteShadowQuery {
+    /// 드라이런에 재생할 최근 시장 데이터 수 (기본 200)
+    pub dry_run_bars: Option<usize>,
+    /// 드라이런 신호 일치율 하한 (0.0 ~ 1.0)
+    pub min_agreement: Option<f64>,
+}
+
+/// 라이브 대 섀도 비교 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ShadowComparisonResponse {
+    /// 신호, 가상 체결, 손익 추이 비교
+    #[schema(value_type = Object)]
+    pub comparison: ShadowComparison,
+}
+
+/// 섀도 목록 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ShadowListResponse {
+    /// 라이브 전략 ID
+    pub strategy_id: String,
+    /// 섀도 요약 (시작 순)
+    #[schema(value_type = Vec<Object>)]
+    pub shadows: Vec<ShadowSummary>,
+}
+
+/// 라이브 전략에 속한 섀도의 비교 결과 조회 (다른 전략의 섀도면 404).
+async fn shadow_comparison(
+    state: &AppState,
+    id: &str,
+    shadow_id: &str,
+) -> Result<ShadowComparison, (StatusCode, Json<ApiError>)> {
+    let comparison = state
+        .strategy_engine
+        .read()
+        .await
+        .get_shadow_comparison(shadow_id)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    if comparison.live_strategy_id != id {
+        return Err(engine_error_to_response(EngineError::ShadowNotFound(
+            shadow_id.to_string(),
+        )));
+    }
+    Ok(comparison)
+}
+
+/// 실행 중인 전략 옆에서 후보 설정을 섀도로 시작.
+///
+/// 섀도는 같은 시장 데이터를 받지만 신호는 브로커 대신 가상 체결기로 보냅니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/{id}/shadows",
+    tag = "strategies",
+    params(("id" = String, Path, description = "라이브 전략 ID")),
+    request_body = StartShadowRequest,
+    responses(
+        (status = 200, description = "섀도 시작 성공", body = ShadowComparisonResponse),
+        (status = 400, description = "알 수 없는 전략 타입 또는 라이브 전략 미실행", body = ApiError),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError),
+        (status = 422, description = "설정 검증/초기화 실패", body = ApiError)
+    )
+)]
+pub async fn start_strategy_shadow(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(request): Json<StartShadowRequest>,
+) -> Result<Json<ShadowComparisonResponse>, (StatusCode, Json<ApiError>)> {
+    let candidate = deployment_candidate(
+        &state,
+        &id,
+        request.strategy_type.as_deref(),
+        &request.config,
+    )
+    .await?;
+
+    let engine = state.strategy_engine.read().await;
+    let shadow_id = engine
+        .start_shadow(&id, candidate, request.shadow)
+        .await
+        .map_err(engine_error_to_response)?;
+    let comparison = engine
+        .get_shadow_comparison(&shadow_id)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    tracing::info!(strategy_id = %id, shadow_id = %shadow_id, "섀도 전략 시작");
+
+    Ok(Json(ShadowComparisonResponse { comparison }))
+}
+
+/// 전략의 섀도 목록 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/shadows",
+    tag = "strategies",
+    params(("id" = String, Path, description = "라이브 전략 ID")),
+    responses(
+        (status = 200, description = "섀도 목록 조회 성공", body = ShadowListResponse)
+    )
+)]
+pub async fn list_strategy_shadows(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Json<ShadowListResponse> {
+    let shadows = state
+        .strategy_engine
+        .read()
+        .await
+        .list_shadows(Some(&id))
+        .await;
+
+    Json(ShadowListResponse {
+        strategy_id: id,
+        shadows,
+    })
+}
+
+/// 라이브 대 섀도 신호/체결/손익 비교 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/shadows/{shadow_id}",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "라이브 전략 ID"),
+        ("shadow_id" = String, Path, description = "섀도 ID")
+    ),
+    responses(
+        (status = 200, description = "비교 조회 성공", body = ShadowComparisonResponse),
+        (status = 404, description = "섀도를 찾을 수 없음", body = ApiError)
+    )
+)]
+pub async fn get_strategy_shadow(
+    State(state): State<Arc<AppState>>,
+    Path((id, shadow_id)): Path<(String, String)>,
+) -> Result<Json<ShadowComparisonResponse>, (StatusCode, Json<ApiError>)> {
+    let comparison = shadow_comparison(&state, &id, &shadow_id).await?;
+    Ok(Json(ShadowComparisonResponse { comparison }))
+}
+
+/// 섀도 중지 (최종 비교 결과 반환).
+#[utoipa::path(
+    delete,
+    path = "/api/v1/strategies/{id}/shadows/{shadow_id}",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "라이브 전략 ID"),
+        ("shadow_id" = String, Path, description = "섀도 ID")
+    ),
+    responses(
+        (status = 200, description = "섀도 중지 성공", body = ShadowComparisonResponse),
+        (status = 404, description = "섀도를 찾을 수 없음", body = ApiError)
+    )
+)]
+pub async fn stop_strategy_shadow(
+    State(state): State<Arc<AppState>>,
+    Path((id, shadow_id)): Path<(String, String)>,
+) -> Result<Json<ShadowComparisonResponse>, (StatusCode, Json<ApiError>)> {
+    shadow_comparison(&state, &id, &shadow_id).await?;
+
+    let comparison = state
+        .strategy_engine
+        .read()
+        .await
+        .stop_shadow(&shadow_id)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    tracing::info!(
+        strategy_id = %id,
+        shadow_id = %shadow_id,
+        bars = comparison.bars_processed,
+        "섀도 전략 중지"
+    );
+
+    Ok(Json(ShadowComparisonResponse { comparison }))
+}
+
+/// 섀도 설정을 라이브 전략으로 승격.
+///
+/// 섀도 설정을 버전 배포 절차(검증, 드라이런, 상태 이전)로 배포하고,
+/// 배포에 성공하면 섀도를 중지합니다. 배포가 거부되면 섀도는 계속 실행됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/{id}/shadows/{shadow_id}/promote",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "라이브 전략 ID"),
+        ("shadow_id" = String, Path, description = "섀도 ID"),
+        ("dry_run_bars" = Option<usize>, Query, description = "드라이런 데이터 수 (기본 200)"),
+        ("min_agreement" = Option<f64>, Query, description = "드라이런 신호 일치율 하한")
+    ),
+    responses(
+        (status = 200, description = "승격 성공 (새 배포 버전)", body = DeploymentResponse),
+        (status = 404, description = "섀도를 찾을 수 없음", body = ApiError),
+        (status = 422, description = "배포 거부", body = ApiError)
+    )
+)]
+pub async fn promote_strategy_shadow(
+    State(state): State<Arc<AppState>>,
+    Path((id, shadow_id)): Path<(String, String)>,
+    Query(query): Query<PromoteShadowQuery>,
+) -> Result<Json<DeploymentResponse>, (StatusCode, Json<ApiError>)> {
+    let comparison = shadow_comparison(&state, &id, &shadow_id).await?;
+    let candidate = deployment_candidate(
+        &state,
+        &id,
+        Some(comparison.strategy_type.as_str()),
+        &comparison.config,
+    )
+    .await?;
+    let options = DeployStrategyRequest {
+        config: comparison.config.clone(),
+        strategy_type: None,
+        dry_run_bars: query.dry_run_bars,
+        min_agreement: query.min_agreement,
+    }
+    .options();
+
+    let deployment = {
+        let engine = state.strategy_engine.read().await;
+        let deployment = engine
+            .deploy_strategy(&id, candidate, options)
+            .await
+            .map_err(engine_error_to_response)?;
+        if let Err(e) = engine.stop_shadow(&shadow_id).await {
+            tracing::warn!(shadow_id = %shadow_id, error = %e, "Failed to stop promoted shadow");
+        }
+        deployment
+    };
+
+    tracing::info!(
+        strategy_id = %id,
+        shadow_id = %shadow_id,
+        version = deployment.version,
+        "섀도 설정 승격"
+    );
+    publish_deployment(&state, &id, comparison.config, "deployed").await;
+
+    Ok(Json(DeploymentResponse { deployment }))
+}
+
+// ==================== 스크립트 전략 ====================
+
+/// 스크립트 검증 요청.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ValidateScriptRequest {
+    /// Rhai 스크립트 원문
+    pub script: String,
+}
+
+/// 스크립트 검증 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ScriptValidationResponse {
+    /// 검증 통과 여부
+    pub valid: bool,
+    /// 스크립트에 정의된 함수 목록
+    pub functions: Vec<String>,
+    /// 검증 실패 사유
+    pub error: Option<String>,
+}
+
+impl From<Result<ScriptInfo, ScriptError>> for ScriptValidationResponse {
+    fn from(result: Result<ScriptInfo, ScriptError>) -> Self {
+        match result {
+            Ok(info) => Self {
+                valid: true,
+                functions: info.functions,
+                error: None,
+            },
+            Err(e) => Self {
+                valid: false,
+                functions: Vec::new(),
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+/// 스크립트 전략 조회 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StrategyScriptResponse {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// Rhai 스크립트 원문
+    pub script: String,
+    /// 스크립트 파라미터
+    #[schema(value_type = Object)]
+    pub params: Value,
+    /// 리소스 제한
+    #[schema(value_type = Object)]
+    pub limits: ScriptLimits,
+}
+
+/// 스크립트 변경 요청.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateScriptRequest {
+    /// 새 Rhai 스크립트 원문
+    pub script: String,
+    /// 스크립트 파라미터 (생략 시 기존 값 유지)
+    #[schema(value_type = Option<Object>)]
+    pub params: Option<Value>,
+    /// 리소스 제한 (생략 시 기존 값 유지)
+    #[schema(value_type = Option<Object>)]
+    pub limits: Option<ScriptLimits>,
+}
+
+/// 스크립트 전략의 현재 설정 조회 (스크립트 전략이 아니면 400).
+async fn script_strategy_config(
+    state: &AppState,
+    id: &str,
+) -> Result<ScriptStrategyConfig, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
+    let strategy_type = engine
+        .get_strategy_type(id)
+        .await
+        .map_err(engine_error_to_response)?;
+    let config = engine
+        .get_strategy_config(id)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    let is_script = trader_strategy::StrategyRegistry::find(&strategy_type)
+        .is_some_and(|meta| meta.id == SCRIPT_STRATEGY_ID);
+    let not_script = || {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new(
+                "NOT_SCRIPT_STRATEGY",
+                format!("Strategy '{}' is not a script strategy", id),
+            )),
+        )
+    };
+    if !is_script {
+        return Err(not_script());
+    }
+    serde_json::from_value(config).map_err(|_| not_script())
+}
+
+/// 스크립트 검증 (컴파일 및 필수 함수 확인, 실행하지 않음).
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/scripts/validate",
+    tag = "strategies",
+    request_body = ValidateScriptRequest,
+    responses(
+        (status = 200, description = "검증 결과", body = ScriptValidationResponse)
+    )
+)]
+pub async fn validate_script(
+    Json(request): Json<ValidateScriptRequest>,
+) -> Json<ScriptValidationResponse> {
+    Json(ScriptHost::validate(&request.script).into())
+}
+
+/// 스크립트 전략의 스크립트 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/script",
+    tag = "strategies",
+    params(("id" = String, Path, description = "전략 ID")),
+    responses(
+        (status = 200, description = "스크립트 조회 성공", body = StrategyScriptResponse),
+        (status = 400, description = "스크립트 전략이 아님", body = ApiError),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError)
+    )
+)]
+pub async fn get_strategy_script(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<StrategyScriptResponse>, (StatusCode, Json<ApiError>)> {
+    let config = script_strategy_config(&state, &id).await?;
+
+    Ok(Json(StrategyScriptResponse {
+        strategy_id: id,
+        script: config.script,
+        params: Value::Object(config.params),
+        limits: config.limits,
+    }))
+}
+
+/// 스크립트 전략의 스크립트 변경.
+///
+/// 컴파일에 실패한 스크립트는 저장하지 않습니다. 실행 중인 전략은
+/// 설정 변경과 동일하게 즉시 재초기화됩니다.
+#[utoipa::path(
+    put,
+    path = "/api/v1/strategies/{id}/script",
+    tag = "strategies",
+    params(("id" = String, Path, description = "전략 ID")),
+    request_body = UpdateScriptRequest,
+    responses(
+        (status = 200, description = "스크립트 변경 성공", body = StrategyActionResponse),
+        (status = 400, description = "스크립트 오류, 허용 범위를 벗어난 제한 또는 스크립트 전략이 아님", body = ApiError),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError)
+    )
+)]
+pub async fn update_strategy_script(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateScriptRequest>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    let mut config = script_strategy_config(&state, &id).await?;
+
+    let limits = request.limits.unwrap_or(config.limits);
+    // 0(무제한)이나 서버 상한을 넘는 제한은 저장하지 않음
+    limits.validate().map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new("VALIDATION_ERROR", e.to_string())),
+        )
+    })?;
+    ScriptHost::compile(&request.script, limits.clone()).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new("SCRIPT_INVALID", e.to_string())),
+        )
+    })?;
+
+    config.script = request.script;
+    config.limits = limits;
+    if let Some(params) = request.params {
+        config.params = match params {
+            Value::Object(map) => map,
+            _ => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiError::new(
+                        "VALIDATION_ERROR",
+                        "params must be an object",
+                    )),
+                ))
+            }
+        };
+    }
+
+    let config = serde_json::to_value(&config).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("INTERNAL_ERROR", e.to_string())),
+        )
+    })?;
+    update_config(State(state), Path(id), Json(UpdateConfigRequest { config })).await
+}
+
+// ==================== router ====================
+
+/// 전략 관리 라우터 생성.
+pub fn strategies_router() -> Router<Arc<AppState>> {
+    use super::schema::{get_strategy_schema, list_strategy_meta};
+
+    Router::new()
+        // 목록, 생성, 통계
+        .route("/", get(list_strategies).post(create_strategy))
+        .route("/stats", get(get_engine_stats))
+        // 전략 메타데이터 (SDUI 스키마용)
+        .route("/meta", get(list_strategy_meta))
+        // 개별 전략 조작
+        .route("/{id}", get(get_strategy).delete(delete_strategy))
+        .route("/{id}/start", post(start_strategy))
+        .route("/{id}/stop", post(stop_strategy))
+        .route("/{id}/config", put(update_config))
+        .route("/{id}/risk", put(update_risk_settings))
+        .route("/{id}/symbols", put(update_symbols))
+        .route("/{id}/credential", put(update_credential))
+        .route("/{id}/clone", post(clone_strategy))
+        // 전략 스키마 (SDUI)
+        .route("/{id}/schema", get(get_strategy_schema))
+        // 다중 타임프레임 설정
+        .route("/{id}/timeframes", get(get_strategy_timeframes).put(update_strategy_timeframes))
+        // 상태 스냅샷 (체크포인트)
+        .route(
+            "/{id}/snapshots",
+            get(list_strategy_snapshots).post(create_strategy_snapshot),
+        )
+        .route("/{id}/snapshots/{version}", get(get_strategy_snapshot))
+        .route("/{id}/snapshots/{version}/export", get(export_strategy_snapshot))
+        .route("/{id}/snapshots/{version}/rollback", post(rollback_strategy_snapshot))
+        // 버전 배포
+        .route(
+            "/{id}/deployments",
+            get(list_strategy_deployments).post(deploy_strategy_version),
+        )
+        .route("/{id}/deployments/preview", post(preview_strategy_deployment))
+        .route("/{id}/deployments/rollback", post(rollback_strategy_deployment))
+        .route("/{id}/deployments/{version}", get(get_strategy_deployment))
+        // 섀도(페이퍼) 모드
+        .route("/{id}/shadows", get(list_strategy_shadows).post(start_strategy_shadow))
+        .route(
+            "/{id}/shadows/{shadow_id}",
+            get(get_strategy_shadow).delete(stop_strategy_shadow),
+        )
+        .route("/{id}/shadows/{shadow_id}/promote", post(promote_strategy_shadow))
+        // 스크립트 전략
+        .route("/scripts/validate", post(validate_script))
+        .route("/{id}/script", get(get_strategy_script).put(update_strategy_script))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_list_strategies_empty() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/strategies", get(list_strategies))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/strategies")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let list: StrategiesListResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(list.total, 0);
+        assert_eq!(list.running, 0);
+        assert!(list.strategies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_strategy_not_found() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/strategies/{id}", get(get_strategy))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/strategies/nonexistent")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(error.code, "STRATEGY_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn test_start_strategy_not_found() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/strategies/{id}/start", post(start_strategy))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/strategies/nonexistent/start")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_get_engine_stats() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/strategies/stats", get(get_engine_stats))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/strategies/stats")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let stats: EngineStatsResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(stats.total_strategies, 0);
         assert_eq!(stats.running_strategies, 0);
@@ -2205,4 +3294,226 @@ mod tests {
         assert_eq!(response.running_strategies, 2);
         assert_eq!(response.total_signals_generated, 100);
     }
+
+    #[tokio::test]
+    async fn test_list_snapshots_without_state_store() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/strategies/{id}/snapshots", get(list_strategy_snapshots))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/strategies/dca_1/snapshots")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(error.code, "STATE_STORE_UNAVAILABLE");
+    }
+
+    #[tokio::test]
+    async fn test_deployment_endpoints_without_strategy() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route(
+                "/strategies/{id}/deployments",
+                get(list_strategy_deployments),
+            )
+            .route(
+                "/strategies/{id}/deployments/rollback",
+                post(rollback_strategy_deployment),
+            )
+            .with_state(state);
+
+        // 배포 이력은 기본 메모리 저장소에서 빈 목록으로 조회
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/strategies/dca_1/deployments")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let list: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(list["deployments"], serde_json::json!([]));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/strategies/dca_1/deployments/rollback")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_shadow_endpoints_without_strategy() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route(
+                "/strategies/{id}/shadows",
+                get(list_strategy_shadows).post(start_strategy_shadow),
+            )
+            .route(
+                "/strategies/{id}/shadows/{shadow_id}",
+                get(get_strategy_shadow),
+            )
+            .with_state(state);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/strategies/haa_1/shadows")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let list: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(list["shadows"], serde_json::json!([]));
+
+        // 라이브 전략이 없으면 섀도를 시작할 수 없음
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/strategies/haa_1/shadows")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "strategy_type": "haa", "config": {} }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/strategies/haa_1/shadows/haa_1_shadow_1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "SHADOW_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn test_script_endpoints() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        state
+            .strategy_engine
+            .read()
+            .await
+            .register_strategy(
+                "script_1",
+                Box::new(trader_strategy::ScriptStrategy::new()),
+                serde_json::json!({ "script": "fn on_bar(m) { }" }),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let app = Router::new()
+            .route(
+                "/strategies/{id}/script",
+                get(get_strategy_script).put(update_strategy_script),
+            )
+            .route("/strategies/scripts/validate", post(validate_script))
+            .with_state(state);
+
+        // 컴파일 실패 스크립트는 저장되지 않음
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/strategies/script_1/script")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"script": "fn init(p) { }"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/strategies/script_1/script")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let script: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(script["script"], "fn on_bar(m) { }");
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/strategies/scripts/validate")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"script": "fn on_bar(m) { let = 1; }"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["valid"], false);
+    }
 }
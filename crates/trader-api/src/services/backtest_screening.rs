@@ -0,0 +1,96 @@
+//! 백테스트 스크리닝 설정.
+//!
+//! 스크리닝 기반 전략의 백테스트(API, CLI)가 같은 [`BacktestScreeningProvider`]를 쓰도록
+//! 전략별 스크리닝 설정과 시점별 펀더멘털 이력(`symbol_fundamental_history`) 로드를 제공합니다.
+
+use chrono::NaiveDate;
+use rust_decimal_macros::dec;
+use sqlx::PgPool;
+use tracing::warn;
+use trader_analytics::backtest::{BacktestScreeningProvider, ScreeningCalculatorConfig};
+use trader_data::{FundamentalHistory, FundamentalHistoryRepository};
+
+/// 스크리닝 기반 전략 ID 목록
+pub const SCREENING_BASED_STRATEGIES: &[&str] = &[
+    "small_cap_quant_v2",
+    "pension_bot_v2",
+    "dynamic_universe",
+    // 필요 시 추가
+];
+
+/// 스크리닝 기반 전략이 사용하는 기본 preset_name
+/// (ScreeningBasedConfig의 default_preset_name()과 일치해야 함)
+const SCREENING_PRESET_NAME: &str = "screening_based";
+
+/// 전략 ID에 따라 BacktestScreeningProvider 생성
+///
+/// 스크리닝 기반 전략인 경우 적절한 설정으로 Provider를 생성합니다.
+/// 전략들은 기본값으로 "screening_based" preset을 사용합니다.
+/// 일반 전략인 경우 None을 반환합니다.
+pub fn screening_provider_for_strategy(strategy_id: &str) -> Option<BacktestScreeningProvider> {
+    if !SCREENING_BASED_STRATEGIES.contains(&strategy_id) {
+        return None;
+    }
+
+    // 전략별 스크리닝 설정
+    // 모든 스크리닝 기반 전략은 기본값으로 "screening_based" preset을 사용
+    // (serde default = "default_preset_name" → "screening_based")
+    //
+    // 주의: 펀더멘털 이력이 없으면 GlobalScore 계산 시 기술적 지표만 반영됩니다.
+    // 따라서 min_score를 낮게 설정해야 스크리닝 결과가 나옵니다.
+    let config = match strategy_id {
+        "small_cap_quant_v2" => ScreeningCalculatorConfig::monthly(SCREENING_PRESET_NAME, dec!(0)),
+        "pension_bot_v2" => ScreeningCalculatorConfig::weekly(SCREENING_PRESET_NAME, dec!(0)),
+        "dynamic_universe" => ScreeningCalculatorConfig::monthly(SCREENING_PRESET_NAME, dec!(0)),
+        _ => ScreeningCalculatorConfig::default(),
+    };
+
+    Some(BacktestScreeningProvider::with_config(config))
+}
+
+/// 백테스트 기간의 펀더멘털 시점 이력 로드.
+///
+/// 스크리닝 시점마다 그때까지 수집된 PER/PBR/ROE만 사용하도록
+/// `symbol_fundamental_history`에서 조회합니다. 이력이 없으면 None (기술적 지표만 사용).
+pub async fn load_fundamental_history(
+    pool: &PgPool,
+    symbols: &[String],
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Option<FundamentalHistory> {
+    let repo = FundamentalHistoryRepository::new(pool.clone());
+    match repo.load_history(symbols, start, end).await {
+        Ok(history) if !history.is_empty() => Some(history),
+        Ok(_) => {
+            warn!("펀더멘털 이력 없음: 스크리닝은 기술적 지표만 사용합니다");
+            None
+        }
+        Err(e) => {
+            warn!("펀더멘털 이력 조회 실패 (기술적 지표만 사용): {}", e);
+            None
+        }
+    }
+}
+
+/// 펀더멘털 시점 이력을 적용한 스크리닝 Provider 생성.
+///
+/// 스크리닝 기반 전략이 아니면 None, DB가 없거나 이력이 없으면 기술적 지표만 사용하는
+/// Provider를 반환합니다.
+pub async fn load_screening_provider(
+    pool: Option<&PgPool>,
+    strategy_id: &str,
+    symbols: &[String],
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Option<BacktestScreeningProvider> {
+    let provider = screening_provider_for_strategy(strategy_id)?;
+    let Some(pool) = pool else {
+        return Some(provider);
+    };
+    Some(
+        match load_fundamental_history(pool, symbols, start, end).await {
+            Some(history) => provider.with_fundamentals(history),
+            None => provider,
+        },
+    )
+}
@@ -11,23 +11,42 @@
 
 use std::{sync::Arc, time::Duration};
 
+use sqlx::PgPool;
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
 use trader_core::{
-    AnalyticsProvider, ExchangeProvider, MarketType, ScreeningPreset, StrategyContext,
+    domain::StrategySignalPerformance, AnalyticsProvider, EconomicCalendar, ExchangeProvider,
+    MarketType, ScreeningPreset, StrategyContext,
 };
+use trader_data::EconomicEventRepository;
+use trader_risk::RiskManager;
+
+use crate::repository::SignalPerformanceRepository;
+
+/// 적응형 가중치에 반영할 신호 성과 기간 (일).
+const SIGNAL_PERFORMANCE_LOOKBACK_DAYS: i32 = 60;
+
+/// 컨텍스트에 적재할 경제 이벤트 범위 - 과거 (시간, 이벤트 직후 블랙아웃 판단용).
+const ECONOMIC_CALENDAR_LOOKBACK_HOURS: i64 = 24;
+
+/// 컨텍스트에 적재할 경제 이벤트 범위 - 미래 (일).
+const ECONOMIC_CALENDAR_HORIZON_DAYS: i64 = 30;
 
 /// 전략 컨텍스트 동기화 서비스.
 ///
 /// 두 가지 독립적인 동기화 주기를 사용합니다:
 /// - 거래소 정보: 5초마다 (계좌, 포지션, 주문)
-/// - 분석 결과: 1분마다 (Global Score, RouteState, 스크리닝, 피처)
+/// - 분석 결과: 1분마다 (Global Score, RouteState, 스크리닝, 피처, 신호 성과, 경제 캘린더)
 pub struct ContextSyncService {
     exchange_provider: Arc<dyn ExchangeProvider>,
     analytics_provider: Arc<dyn AnalyticsProvider>,
     context: Arc<RwLock<StrategyContext>>,
     exchange_sync_interval: Duration,
     analytics_sync_interval: Duration,
+    /// 신호 성과/경제 캘린더 조회용 DB (None이면 해당 동기화 생략)
+    db_pool: Option<PgPool>,
+    /// 경제 캘린더를 전달할 리스크 관리자 (이벤트 블랙아웃)
+    risk_managers: Vec<Arc<RwLock<RiskManager>>>,
 }
 
 impl ContextSyncService {
@@ -53,9 +72,23 @@ impl ContextSyncService {
             context,
             exchange_sync_interval,
             analytics_sync_interval,
+            db_pool: None,
+            risk_managers: Vec::new(),
         }
     }
 
+    /// 전략별 신호 성과(`signal_performance`) 및 경제 캘린더 동기화 활성화.
+    pub fn with_signal_performance(mut self, pool: PgPool) -> Self {
+        self.db_pool = Some(pool);
+        self
+    }
+
+    /// 경제 캘린더를 전달할 리스크 관리자 설정.
+    pub fn with_risk_managers(mut self, risk_managers: Vec<Arc<RwLock<RiskManager>>>) -> Self {
+        self.risk_managers = risk_managers;
+        self
+    }
+
     /// 서비스 시작 (메인 루프).
     ///
     /// 두 개의 독립적인 타이머로 거래소 정보와 분석 결과를 주기적으로 동기화합니다.
@@ -158,11 +191,12 @@ impl ContextSyncService {
     /// - MarketRegime (종목별)
     /// - MacroEnvironment (글로벌)
     /// - MarketBreadth (글로벌)
+    /// - 경제 이벤트 캘린더 (글로벌, 리스크 관리자에도 전달)
     async fn sync_analytics(&self) -> Result<(), String> {
         // 1. Global Score 조회 (시장별 - 예: KR Stock)
         let scores = self
             .analytics_provider
-            .fetch_global_scores(MarketType::Stock)
+            .fetch_global_scores(MarketType::Stock, None)
             .await
             .map_err(|e| format!("Global Score 조회 실패: {}", e))?;
 
@@ -193,7 +227,7 @@ impl ContextSyncService {
         // 5. 구조적 피처 조회
         let features = self
             .analytics_provider
-            .fetch_features(&ticker_refs)
+            .fetch_features(&ticker_refs, None)
             .await
             .map_err(|e| format!("Features 조회 실패: {}", e))?;
 
@@ -218,7 +252,13 @@ impl ContextSyncService {
             .await
             .map_err(|e| format!("MarketBreadth 조회 실패: {}", e))?;
 
-        // 9. 컨텍스트 업데이트
+        // 9. 전략별 신호 성과 조회 (실패해도 나머지 분석 결과는 반영)
+        let performance = self.fetch_signal_performance().await;
+
+        // 10. 경제 이벤트 캘린더 조회 (실패해도 나머지 분석 결과는 반영)
+        let calendar = self.fetch_economic_calendar().await;
+
+        // 11. 컨텍스트 업데이트
         let mut ctx = self.context.write().await;
         ctx.update_global_scores(scores);
         ctx.update_route_states(states);
@@ -227,11 +267,74 @@ impl ContextSyncService {
         ctx.update_market_regime(regimes);
         ctx.update_macro_environment(macro_env);
         ctx.update_market_breadth(breadth);
+        if let Some(performance) = performance {
+            ctx.update_signal_performance(performance);
+        }
+        if let Some(calendar) = &calendar {
+            ctx.update_economic_calendar(calendar.clone());
+        }
+        drop(ctx);
+
+        // 12. 리스크 관리자 이벤트 블랙아웃 캘린더 갱신
+        if let Some(calendar) = calendar {
+            for risk_manager in &self.risk_managers {
+                risk_manager
+                    .write()
+                    .await
+                    .update_economic_calendar(calendar.clone());
+            }
+        }
 
         tracing::debug!(ticker_count = tickers.len(), "분석 결과 동기화 완료");
 
         Ok(())
     }
+
+    /// 경제 이벤트 캘린더 조회 (DB 미설정 또는 조회 실패 시 None).
+    async fn fetch_economic_calendar(&self) -> Option<EconomicCalendar> {
+        let pool = self.db_pool.as_ref()?;
+        let now = chrono::Utc::now();
+        match EconomicEventRepository::new(pool.clone())
+            .load_calendar(
+                now - chrono::Duration::hours(ECONOMIC_CALENDAR_LOOKBACK_HOURS),
+                now + chrono::Duration::days(ECONOMIC_CALENDAR_HORIZON_DAYS),
+            )
+            .await
+        {
+            Ok(calendar) => Some(calendar),
+            Err(e) => {
+                tracing::warn!("경제 캘린더 조회 실패: {}", e);
+                None
+            }
+        }
+    }
+
+    /// 전략별 신호 성과 조회 (DB 미설정 또는 조회 실패 시 None).
+    async fn fetch_signal_performance(&self) -> Option<Vec<StrategySignalPerformance>> {
+        let pool = self.db_pool.as_ref()?;
+        match SignalPerformanceRepository::get_recent_strategy_stats(
+            pool,
+            SIGNAL_PERFORMANCE_LOOKBACK_DAYS,
+        )
+        .await
+        {
+            Ok(stats) => Some(
+                stats
+                    .into_iter()
+                    .map(|s| StrategySignalPerformance {
+                        strategy_id: s.strategy_id,
+                        total_signals: s.total_signals.max(0) as u64,
+                        win_rate: s.win_rate,
+                        avg_return_5d: s.avg_return_5d,
+                    })
+                    .collect(),
+            ),
+            Err(e) => {
+                tracing::warn!("신호 성과 조회 실패: {}", e);
+                None
+            }
+        }
+    }
 }
 
 /// ContextSyncService를 백그라운드 task로 시작.
@@ -245,6 +348,8 @@ impl ContextSyncService {
 /// * `exchange_provider` - 거래소 정보 제공자
 /// * `analytics_provider` - 분석 결과 제공자
 /// * `context` - 공유 컨텍스트
+/// * `db_pool` - 신호 성과/경제 캘린더 조회용 DB (None이면 해당 동기화 생략)
+/// * `risk_managers` - 경제 캘린더를 전달할 리스크 관리자 (이벤트 블랙아웃)
 /// * `shutdown` - Graceful shutdown을 위한 CancellationToken
 ///
 /// # Returns
@@ -254,15 +359,21 @@ pub fn start_context_sync_service(
     exchange_provider: Arc<dyn ExchangeProvider>,
     analytics_provider: Arc<dyn AnalyticsProvider>,
     context: Arc<RwLock<StrategyContext>>,
+    db_pool: Option<PgPool>,
+    risk_managers: Vec<Arc<RwLock<RiskManager>>>,
     shutdown: CancellationToken,
 ) -> tokio::task::JoinHandle<()> {
-    let service = ContextSyncService::new(
+    let mut service = ContextSyncService::new(
         exchange_provider,
         analytics_provider,
         context,
         Duration::from_secs(5),  // 거래소: 5초
         Duration::from_secs(60), // 분석: 1분
-    );
+    )
+    .with_risk_managers(risk_managers);
+    if let Some(pool) = db_pool {
+        service = service.with_signal_performance(pool);
+    }
 
     tokio::spawn(async move {
         service.run(shutdown).await;
@@ -0,0 +1,323 @@
+//! DART 공시 알림 서비스.
+//!
+//! `trader-collector sync-dart`가 저장한 `dart_disclosure` 중 아직 처리되지 않은
+//! 주요사항 공시(유상증자, CB/BW 발행, 최대주주 변경 등)를 주기적으로 조회하여
+//! `SignalType::Alert` 신호 마커로 변환하고, 활성화된 `signal_alert_rule` 규칙으로
+//! 필터링하여 알림을 전송합니다.
+//!
+//! 규칙은 `filter_conditions.disclosure_types`로 공시 유형을 지정합니다:
+//!
+//! ```json
+//! {
+//!   "min_strength": 0.0,
+//!   "strategy_ids": ["dart_disclosure"],
+//!   "entry_only": false,
+//!   "disclosure_types": ["capital_increase", "convertible_bond", "largest_shareholder_change"]
+//! }
+//! ```
+//!
+//! 접수일이 `DISCLOSURE_ALERT_MAX_AGE_DAYS`보다 오래된 공시는 알림 대상에서 제외하여
+//! 과거 공시 백필 시 알림이 쏟아지지 않도록 합니다.
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_core::{SignalMarker, SignalType};
+use trader_data::{provider::dart::DisclosureKind, DisclosureRepository, PendingDisclosure};
+use trader_notification::NotificationManager;
+
+use super::SignalAlertService;
+use crate::repository::SignalAlertRuleRepository;
+
+/// 공시 알림에 사용하는 전략 ID (`signal_alert_rule`의 strategy_ids 필터와 매칭).
+pub const DISCLOSURE_STRATEGY_ID: &str = "dart_disclosure";
+
+/// 공시 알림 설정.
+#[derive(Debug, Clone)]
+pub struct DisclosureAlertConfig {
+    /// 폴링 주기 (초)
+    pub poll_interval_secs: u64,
+    /// 알림 대상 최대 경과 일수 (접수일 기준, 0이면 당일 공시만)
+    pub max_age_days: i64,
+    /// 폴링당 최대 처리 공시 수
+    pub batch_size: i64,
+}
+
+impl Default for DisclosureAlertConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: 60,
+            max_age_days: 1,
+            batch_size: 100,
+        }
+    }
+}
+
+impl DisclosureAlertConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `DISCLOSURE_ALERT_ENABLED=true`가 아니면 `None`을 반환합니다.
+    ///
+    /// # 환경변수
+    ///
+    /// - `DISCLOSURE_ALERT_INTERVAL_SECS`: 폴링 주기 (기본: 60)
+    /// - `DISCLOSURE_ALERT_MAX_AGE_DAYS`: 알림 대상 최대 경과 일수 (기본: 1)
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("DISCLOSURE_ALERT_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+        if !enabled {
+            return None;
+        }
+
+        let mut config = Self::default();
+        if let Some(secs) = env_parse("DISCLOSURE_ALERT_INTERVAL_SECS") {
+            config.poll_interval_secs = secs;
+        }
+        if let Some(days) = env_parse("DISCLOSURE_ALERT_MAX_AGE_DAYS") {
+            config.max_age_days = days;
+        }
+
+        Some(config)
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+}
+
+/// 공시를 알림용 신호 마커로 변환.
+///
+/// 가격은 최근 일봉 종가(없으면 0)이며, 공시 유형은 `metadata.disclosure_type`에 기록됩니다.
+pub fn disclosure_marker(pending: &PendingDisclosure) -> SignalMarker {
+    let disclosure = &pending.disclosure;
+    let timestamp = disclosure
+        .receipt_date
+        .and_hms_opt(0, 0, 0)
+        .map(|dt| dt.and_utc())
+        .unwrap_or_else(Utc::now);
+    let strength = if disclosure.kind.is_material() {
+        1.0
+    } else {
+        0.5
+    };
+
+    SignalMarker::new(
+        disclosure.ticker.clone().unwrap_or_default(),
+        timestamp,
+        SignalType::Alert,
+        pending.last_close.unwrap_or(Decimal::ZERO),
+        DISCLOSURE_STRATEGY_ID,
+        "DART 공시",
+    )
+    .with_strength(strength)
+    .with_reason(format!(
+        "[{}] {} - {}\n{}",
+        disclosure.kind.display_name(),
+        disclosure.corp_name,
+        disclosure.report_name,
+        disclosure.url()
+    ))
+    .with_metadata(
+        "disclosure_type",
+        serde_json::json!(disclosure.kind.as_str()),
+    )
+    .with_metadata("rcept_no", serde_json::json!(disclosure.rcept_no))
+    .with_metadata("corp_name", serde_json::json!(disclosure.corp_name))
+    .with_metadata("url", serde_json::json!(disclosure.url()))
+}
+
+/// 공시 알림 서비스.
+pub struct DisclosureAlertService {
+    config: DisclosureAlertConfig,
+    disclosures: DisclosureRepository,
+    rules: SignalAlertRuleRepository,
+    notification_manager: Arc<NotificationManager>,
+}
+
+impl DisclosureAlertService {
+    /// 새 서비스 생성.
+    pub fn new(
+        config: DisclosureAlertConfig,
+        disclosures: DisclosureRepository,
+        rules: SignalAlertRuleRepository,
+        notification_manager: Arc<NotificationManager>,
+    ) -> Self {
+        Self {
+            config,
+            disclosures,
+            rules,
+            notification_manager,
+        }
+    }
+
+    /// 알림 대기 공시 1회 처리.
+    ///
+    /// 규칙과 매칭되지 않은 공시도 처리 완료로 표시하며,
+    /// 전송에 실패한 공시는 다음 폴링에서 다시 시도합니다.
+    ///
+    /// # 반환
+    ///
+    /// 전송된 알림 수
+    pub async fn poll_once(&self, today: NaiveDate) -> usize {
+        let kinds: Vec<DisclosureKind> = DisclosureKind::ALL
+            .into_iter()
+            .filter(DisclosureKind::is_material)
+            .collect();
+        let since = today - chrono::Duration::days(self.config.max_age_days.max(0));
+
+        let pending = match self
+            .disclosures
+            .pending_alerts(&kinds, since, self.config.batch_size)
+            .await
+        {
+            Ok(pending) => pending,
+            Err(e) => {
+                warn!("알림 대기 공시 조회 실패: {}", e);
+                return 0;
+            }
+        };
+        if pending.is_empty() {
+            return 0;
+        }
+
+        let filters = match self.rules.get_enabled_filters().await {
+            Ok(filters) => filters,
+            Err(e) => {
+                warn!("알림 규칙 조회 실패: {:?}", e);
+                return 0;
+            }
+        };
+
+        let mut sent = 0;
+        let mut processed = Vec::with_capacity(pending.len());
+        for item in &pending {
+            let marker = disclosure_marker(item);
+            let Some(filter) = filters.iter().find(|f| f.matches(&marker)) else {
+                debug!(rcept_no = %item.disclosure.rcept_no, "매칭되는 알림 규칙 없음");
+                processed.push(item.disclosure.rcept_no.clone());
+                continue;
+            };
+
+            let service = SignalAlertService::from_shared(self.notification_manager.clone())
+                .with_filter(filter.clone());
+            match service.notify_signal(&marker).await {
+                Ok(notified) => {
+                    if notified {
+                        sent += 1;
+                    }
+                    processed.push(item.disclosure.rcept_no.clone());
+                }
+                Err(e) => warn!(
+                    rcept_no = %item.disclosure.rcept_no,
+                    "공시 알림 전송 실패: {}", e
+                ),
+            }
+        }
+
+        if let Err(e) = self.disclosures.mark_alerted(&processed).await {
+            warn!("공시 알림 처리 표시 실패: {}", e);
+        }
+
+        info!(pending = pending.len(), sent, "DART 공시 알림 처리 완료");
+        sent
+    }
+}
+
+/// 공시 알림 백그라운드 서비스 시작.
+pub fn start_disclosure_alert_service(
+    service: DisclosureAlertService,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            service.config.poll_interval_secs.max(1),
+        ));
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => {
+                    info!("DART 공시 알림 서비스 종료");
+                    break;
+                }
+                _ = interval.tick() => {
+                    // 공시 접수일은 KST 기준
+                    let today = (Utc::now() + chrono::Duration::hours(9)).date_naive();
+                    service.poll_once(today).await;
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use trader_data::provider::dart::Disclosure;
+
+    use super::*;
+    use crate::services::SignalAlertFilter;
+
+    fn pending(report_name: &str) -> PendingDisclosure {
+        PendingDisclosure {
+            disclosure: Disclosure {
+                rcept_no: "20240315000123".to_string(),
+                corp_code: "01160363".to_string(),
+                corp_name: "에코프로비엠".to_string(),
+                ticker: Some("247540".to_string()),
+                corp_cls: "K".to_string(),
+                report_name: report_name.to_string(),
+                kind: DisclosureKind::classify(report_name),
+                receipt_date: NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
+                filer_name: "에코프로비엠".to_string(),
+                remark: None,
+            },
+            last_close: Some(dec!(251500)),
+        }
+    }
+
+    #[test]
+    fn test_disclosure_marker() {
+        let marker = disclosure_marker(&pending("주요사항보고서(유상증자결정)"));
+
+        assert_eq!(marker.ticker, "247540");
+        assert_eq!(marker.signal_type, SignalType::Alert);
+        assert_eq!(marker.price, dec!(251500));
+        assert_eq!(marker.strategy_id, DISCLOSURE_STRATEGY_ID);
+        assert_eq!(
+            marker.metadata.get("disclosure_type"),
+            Some(&serde_json::json!("capital_increase"))
+        );
+        assert!(marker.reason.contains("유상증자"));
+        assert!(marker.reason.contains("rcpNo=20240315000123"));
+    }
+
+    #[test]
+    fn test_default_rule_matches_material_disclosures() {
+        // migrations/30_dart_disclosures.sql의 기본 규칙
+        let filter: SignalAlertFilter = serde_json::from_value(serde_json::json!({
+            "min_strength": 0.0,
+            "strategy_ids": ["dart_disclosure"],
+            "entry_only": false,
+            "disclosure_types": [
+                "capital_increase",
+                "convertible_bond",
+                "bond_with_warrant",
+                "largest_shareholder_change"
+            ]
+        }))
+        .unwrap();
+
+        assert!(filter.matches(&disclosure_marker(&pending("주요사항보고서(유상증자결정)"))));
+        assert!(filter.matches(&disclosure_marker(&pending(
+            "주요사항보고서(전환사채권발행결정)"
+        ))));
+        assert!(filter.matches(&disclosure_marker(&pending("최대주주변경"))));
+        assert!(!filter.matches(&disclosure_marker(&pending(
+            "주요사항보고서(자기주식취득결정)"
+        ))));
+    }
+}
@@ -0,0 +1,209 @@
+//! 환율 서비스.
+//!
+//! 포트폴리오 요약, 자산 곡선, 매매일지 손익을 기준 통화(`BASE_CURRENCY`, 기본 KRW)로
+//! 환산할 때 사용하는 환율 테이블을 관리합니다.
+//!
+//! # 환율 출처
+//!
+//! - `fx_rates` 테이블: 수집기(`fx_rate_sync`)가 저장한 일별 환율 이력
+//! - Redis 매크로 데이터(`macro:data`): `macro_data_sync`가 기록한 최신 USD/KRW
+//! - 거래소 시세: [`FxRateService::record_quote`]로 기록 (예: 업비트 KRW-USDT)
+//!
+//! USDT/USDC는 USD와 1:1로 연동하여 환산합니다.
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_core::{normalize_currency, FxRateTable, MissingFxRate, DEFAULT_BASE_CURRENCY};
+use trader_data::{cache::MacroData, FxRateRepository, RedisCache};
+
+/// 매크로 데이터 캐시 키 (`macro_data_sync`가 기록).
+const MACRO_DATA_CACHE_KEY: &str = "macro:data";
+
+/// 거래소 시세로 기록한 환율의 출처 표시.
+const FX_SOURCE_PROVIDER: &str = "provider";
+
+/// 기본 환율 갱신 주기 (초).
+const DEFAULT_REFRESH_SECS: u64 = 600;
+
+/// 환율 서비스.
+pub struct FxRateService {
+    base_currency: String,
+    table: RwLock<FxRateTable>,
+    repo: Option<FxRateRepository>,
+    cache: Option<Arc<RedisCache>>,
+}
+
+impl FxRateService {
+    /// 기준 통화로 서비스 생성.
+    pub fn new(base_currency: &str) -> Self {
+        Self {
+            base_currency: normalize_currency(base_currency),
+            table: RwLock::new(FxRateTable::new().with_stablecoin_pegs()),
+            repo: None,
+            cache: None,
+        }
+    }
+
+    /// 환경변수(`BASE_CURRENCY`)로 서비스 생성.
+    pub fn from_env() -> Self {
+        let base = std::env::var("BASE_CURRENCY")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_BASE_CURRENCY.to_string());
+        Self::new(&base)
+    }
+
+    /// 환율 이력 저장소 설정.
+    pub fn with_repository(mut self, repo: FxRateRepository) -> Self {
+        self.repo = Some(repo);
+        self
+    }
+
+    /// 매크로 데이터 캐시 설정.
+    pub fn with_cache(mut self, cache: Arc<RedisCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// 기준 통화.
+    pub fn base_currency(&self) -> &str {
+        &self.base_currency
+    }
+
+    /// 현재 환율 테이블 복사본.
+    pub async fn table(&self) -> FxRateTable {
+        self.table.read().await.clone()
+    }
+
+    /// 최신 환율 (1 `from` = N `to`).
+    pub async fn latest_rate(&self, from: &str, to: &str) -> Option<Decimal> {
+        self.table.read().await.latest_rate(from, to)
+    }
+
+    /// 기준일 시점 기준 통화 환율 (1 `from` = N 기준 통화).
+    pub async fn rate_to_base(
+        &self,
+        from: &str,
+        date: NaiveDate,
+    ) -> Result<Decimal, MissingFxRate> {
+        self.table
+            .read()
+            .await
+            .require_rate(from, &self.base_currency, date)
+    }
+
+    /// 저장소와 매크로 데이터 캐시에서 환율 테이블을 다시 읽습니다.
+    ///
+    /// # 반환
+    ///
+    /// 테이블에 적재된 환율 수
+    pub async fn refresh(&self) -> usize {
+        let mut table = FxRateTable::new();
+
+        if let Some(repo) = &self.repo {
+            match repo.load_table(None, None).await {
+                Ok(loaded) => table.merge(&loaded),
+                Err(e) => warn!(error = %e, "환율 이력 조회 실패"),
+            }
+        }
+
+        if let Some(cache) = &self.cache {
+            match cache.get::<MacroData>(MACRO_DATA_CACHE_KEY).await {
+                Ok(Some(data)) if data.usd_krw > Decimal::ZERO => {
+                    table.insert("USD", "KRW", Utc::now().date_naive(), data.usd_krw);
+                }
+                Ok(_) => {}
+                Err(e) => warn!(error = %e, "매크로 데이터 조회 실패"),
+            }
+        }
+
+        // 기존 테이블 위에 덮어써서 저장소 반영 전인 거래소 시세 환율도 유지
+        let mut current = self.table.write().await;
+        current.merge(&table);
+        current.len()
+    }
+
+    /// 거래소 시세 환율 기록 (1 `base` = `rate` `quote`).
+    ///
+    /// 메모리 테이블에 즉시 반영하고, 저장소가 있으면 당일 환율로 저장합니다.
+    pub async fn record_quote(&self, base: &str, quote: &str, rate: Decimal) {
+        let today = Utc::now().date_naive();
+        self.table.write().await.insert(base, quote, today, rate);
+
+        if let Some(repo) = &self.repo {
+            if let Err(e) = repo
+                .upsert(base, quote, today, rate, FX_SOURCE_PROVIDER)
+                .await
+            {
+                warn!(base, quote, error = %e, "거래소 환율 저장 실패");
+            }
+        }
+    }
+
+    /// 주기적 환율 갱신 태스크 시작.
+    ///
+    /// `FX_RATE_REFRESH_SECS`(기본 600초, 0이면 시작 시 한 번만 갱신)마다 환율을 다시 읽습니다.
+    pub fn start(self: Arc<Self>, shutdown: CancellationToken) -> tokio::task::JoinHandle<()> {
+        let secs = std::env::var("FX_RATE_REFRESH_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_REFRESH_SECS);
+
+        tokio::spawn(async move {
+            let loaded = self.refresh().await;
+            info!(base = %self.base_currency, rates = loaded, "환율 테이블 로드");
+            if secs == 0 {
+                return;
+            }
+
+            let period = Duration::from_secs(secs);
+            let mut timer = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            loop {
+                tokio::select! {
+                    _ = timer.tick() => {
+                        let loaded = self.refresh().await;
+                        debug!(rates = loaded, "환율 테이블 갱신");
+                    }
+                    _ = shutdown.cancelled() => {
+                        info!("FxRateService 종료");
+                        break;
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl Default for FxRateService {
+    fn default() -> Self {
+        Self::new(DEFAULT_BASE_CURRENCY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_quote_and_stablecoin_peg() {
+        let service = FxRateService::new("krw");
+        assert_eq!(service.base_currency(), "KRW");
+
+        service.record_quote("USD", "KRW", dec!(1350)).await;
+        let today = Utc::now().date_naive();
+        assert_eq!(service.rate_to_base("USDT", today).await, Ok(dec!(1350)));
+        assert_eq!(service.rate_to_base("KRW", today).await, Ok(Decimal::ONE));
+        assert!(service.rate_to_base("JPY", today).await.is_err());
+
+        // 저장소/캐시 없이 갱신해도 기록한 환율 유지
+        assert_eq!(service.refresh().await, 1);
+        assert_eq!(service.latest_rate("USD", "KRW").await, Some(dec!(1350)));
+    }
+}
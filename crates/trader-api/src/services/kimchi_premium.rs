@@ -0,0 +1,1082 @@
+//! 김치 프리미엄(국내-해외 거래소 가격 괴리) 모니터링 서비스.
+//!
+//! 업비트/빗썸의 KRW 마켓과 바이낸스 USDT 마켓의 동일 코인 시세를 실시간으로 구독하고,
+//! USD/KRW 환율로 환산하여 프리미엄을 계산합니다.
+//!
+//! # 주요 컴포넌트
+//!
+//! - [`KimchiPremiumConfig`]: 감시 대상 코인/거래소, 환율 소스, 임계값 설정
+//! - [`KimchiPremiumMonitor`]: 프리미엄 계산, 이력 보관, 임계값 돌파 감지 (순수 로직)
+//! - [`PremiumAlertDispatcher`]: 임계값 돌파를 `signal_alert_rule` 규칙으로 필터링하여 알림 전송
+//! - [`start_kimchi_premium_service`]: WebSocket 구독 및 환율 갱신 백그라운드 태스크
+//!
+//! DB가 있으면 샘플링한 이력을 `kimchi_premium_history` 테이블에 저장하고
+//! (`retention_days` 보존), 서비스 시작 시 최근 이력을 모니터 메모리로 복원합니다.
+//!
+//! # 프리미엄 계산
+//!
+//! ```text
+//! premium(%) = (국내가 KRW / (해외가 USDT × USD/KRW) - 1) × 100
+//! ```
+//!
+//! USDT는 USD와 1:1로 간주합니다.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_core::{Side, SignalMarker, SignalType};
+use trader_data::cache::{MacroData, RedisCache};
+use trader_exchange::{
+    connector::binance::BinanceConfig,
+    stream::{BithumbMarketStream, UpbitMarketStream},
+    traits::{MarketEvent, MarketStream},
+    websocket::BinanceMarketStream,
+};
+use trader_notification::NotificationManager;
+use utoipa::ToSchema;
+
+use super::SignalAlertService;
+use crate::repository::{KimchiPremiumRepository, SignalAlertRuleRepository};
+
+/// 프리미엄 알림에 사용하는 전략 ID (`signal_alert_rule`의 strategy_ids 필터와 매칭).
+pub const KIMCHI_PREMIUM_STRATEGY_ID: &str = "kimchi_premium";
+
+/// 매크로 데이터 캐시 키 (`macro_data_sync`가 기록).
+const MACRO_DATA_CACHE_KEY: &str = "macro:data";
+
+// ==================== 설정 ====================
+
+/// 국내 거래소.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DomesticVenue {
+    /// 업비트 (`KRW-BTC`)
+    Upbit,
+    /// 빗썸 (`BTC_KRW`)
+    Bithumb,
+}
+
+impl DomesticVenue {
+    /// 거래소 식별자 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DomesticVenue::Upbit => "upbit",
+            DomesticVenue::Bithumb => "bithumb",
+        }
+    }
+
+    /// 거래소 표시 이름.
+    fn display_name(&self) -> &'static str {
+        match self {
+            DomesticVenue::Upbit => "업비트",
+            DomesticVenue::Bithumb => "빗썸",
+        }
+    }
+
+    /// 문자열에서 파싱 (대소문자 무시).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "upbit" => Some(DomesticVenue::Upbit),
+            "bithumb" => Some(DomesticVenue::Bithumb),
+            _ => None,
+        }
+    }
+
+    /// 기준 코인의 KRW 마켓 심볼.
+    pub fn symbol(&self, base: &str) -> String {
+        match self {
+            DomesticVenue::Upbit => format!("KRW-{}", base),
+            DomesticVenue::Bithumb => format!("{}_KRW", base),
+        }
+    }
+
+    /// 거래소 심볼에서 기준 코인 추출.
+    pub fn base_from_symbol(&self, symbol: &str) -> Option<String> {
+        let base = match self {
+            DomesticVenue::Upbit => symbol.strip_prefix("KRW-"),
+            DomesticVenue::Bithumb => symbol.strip_suffix("_KRW"),
+        }?;
+        Some(base.to_uppercase())
+    }
+}
+
+/// USD/KRW 환율 소스.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UsdKrwSource {
+    /// 고정 환율
+    Fixed(Decimal),
+    /// `macro_data_sync`가 Redis에 기록한 매크로 데이터 (`macro:data`)
+    Macro,
+}
+
+/// 김치 프리미엄 모니터 설정.
+#[derive(Debug, Clone)]
+pub struct KimchiPremiumConfig {
+    /// 감시 대상 기준 코인 (예: BTC, ETH)
+    pub bases: Vec<String>,
+    /// 비교할 국내 거래소
+    pub venues: Vec<DomesticVenue>,
+    /// 해외 거래소(바이낸스) 견적 통화
+    pub foreign_quote: String,
+    /// 환율 소스
+    pub usd_krw_source: UsdKrwSource,
+    /// 환율 갱신 주기 (초)
+    pub rate_refresh_secs: u64,
+    /// 상단 임계값 (%) - 프리미엄이 이 값 이상이면 알림
+    pub upper_threshold_pct: Decimal,
+    /// 하단 임계값 (%) - 프리미엄이 이 값 이하이면 알림 (역프리미엄)
+    pub lower_threshold_pct: Decimal,
+    /// 이력 샘플링 간격 (초)
+    pub history_interval_secs: i64,
+    /// 페어별 최대 이력 개수 (메모리)
+    pub max_history: usize,
+    /// DB 이력 보존 일수
+    pub retention_days: i64,
+}
+
+impl Default for KimchiPremiumConfig {
+    fn default() -> Self {
+        Self {
+            bases: vec!["BTC".to_string(), "ETH".to_string()],
+            venues: vec![DomesticVenue::Upbit, DomesticVenue::Bithumb],
+            foreign_quote: "USDT".to_string(),
+            usd_krw_source: UsdKrwSource::Macro,
+            rate_refresh_secs: 60,
+            upper_threshold_pct: dec!(5),
+            lower_threshold_pct: dec!(-1),
+            history_interval_secs: 10,
+            max_history: 8640, // 10초 간격 기준 24시간
+            retention_days: 30,
+        }
+    }
+}
+
+impl KimchiPremiumConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `KIMCHI_PREMIUM_ENABLED=true`가 아니면 `None`을 반환합니다.
+    ///
+    /// # 환경변수
+    ///
+    /// - `KIMCHI_PREMIUM_BASES`: 기준 코인 목록 (쉼표 구분, 기본: BTC,ETH)
+    /// - `KIMCHI_PREMIUM_VENUES`: 국내 거래소 목록 (기본: upbit,bithumb)
+    /// - `KIMCHI_PREMIUM_USD_KRW`: 고정 환율 (미설정 시 매크로 데이터 사용)
+    /// - `KIMCHI_PREMIUM_UPPER_PCT` / `KIMCHI_PREMIUM_LOWER_PCT`: 임계값 (%)
+    /// - `KIMCHI_PREMIUM_RETENTION_DAYS`: DB 이력 보존 일수 (기본: 30)
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("KIMCHI_PREMIUM_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+        if !enabled {
+            return None;
+        }
+
+        let mut config = Self::default();
+
+        if let Ok(bases) = std::env::var("KIMCHI_PREMIUM_BASES") {
+            config.bases = bases
+                .split(',')
+                .map(|b| b.trim().to_uppercase())
+                .filter(|b| !b.is_empty())
+                .collect();
+        }
+        if let Ok(venues) = std::env::var("KIMCHI_PREMIUM_VENUES") {
+            config.venues = venues.split(',').filter_map(DomesticVenue::parse).collect();
+        }
+        if let Some(rate) = env_decimal("KIMCHI_PREMIUM_USD_KRW") {
+            config.usd_krw_source = UsdKrwSource::Fixed(rate);
+        }
+        if let Some(upper) = env_decimal("KIMCHI_PREMIUM_UPPER_PCT") {
+            config.upper_threshold_pct = upper;
+        }
+        if let Some(lower) = env_decimal("KIMCHI_PREMIUM_LOWER_PCT") {
+            config.lower_threshold_pct = lower;
+        }
+        if let Some(days) = std::env::var("KIMCHI_PREMIUM_RETENTION_DAYS")
+            .ok()
+            .and_then(|v| v.trim().parse::<i64>().ok())
+        {
+            config.retention_days = days.max(1);
+        }
+
+        Some(config)
+    }
+
+    /// 바이낸스 심볼 (예: BTC/USDT).
+    fn foreign_symbol(&self, base: &str) -> String {
+        format!("{}/{}", base, self.foreign_quote)
+    }
+}
+
+fn env_decimal(key: &str) -> Option<Decimal> {
+    std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+}
+
+// ==================== 계산 결과 ====================
+
+/// 프리미엄 스냅샷.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumSnapshot {
+    /// 기준 코인 (예: BTC)
+    pub base: String,
+    /// 국내 거래소
+    pub venue: DomesticVenue,
+    /// 국내 가격 (KRW)
+    #[schema(value_type = String)]
+    pub domestic_price_krw: Decimal,
+    /// 해외 가격 (USDT)
+    #[schema(value_type = String)]
+    pub foreign_price_usd: Decimal,
+    /// 적용 환율 (USD/KRW)
+    #[schema(value_type = String)]
+    pub usd_krw: Decimal,
+    /// 해외 가격의 원화 환산값
+    #[schema(value_type = String)]
+    pub foreign_price_krw: Decimal,
+    /// 프리미엄 (%)
+    #[schema(value_type = String)]
+    pub premium_pct: Decimal,
+    /// 계산 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 프리미엄 구간.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PremiumZone {
+    /// 임계값 사이
+    Normal,
+    /// 상단 임계값 이상
+    Above,
+    /// 하단 임계값 이하
+    Below,
+}
+
+/// 임계값 돌파 이벤트.
+#[derive(Debug, Clone)]
+pub struct PremiumCrossing {
+    /// 돌파 시점 스냅샷
+    pub snapshot: PremiumSnapshot,
+    /// 이전 구간
+    pub from: PremiumZone,
+    /// 새 구간
+    pub to: PremiumZone,
+    /// 기준 임계값 (%)
+    pub threshold_pct: Decimal,
+}
+
+impl PremiumCrossing {
+    /// 알림 규칙 평가용 SignalMarker로 변환.
+    ///
+    /// - 임계 구간 진입은 `Entry`, 정상 구간 복귀는 `Exit`
+    /// - 상단 돌파는 국내 매도(`Sell`), 하단 돌파는 국내 매수(`Buy`) 방향
+    /// - 강도는 `|premium| / (2 × |threshold|)`를 0~1로 제한 (임계값에서 0.5, 두 배 이상에서 1.0)
+    pub fn to_signal_marker(&self) -> SignalMarker {
+        let snapshot = &self.snapshot;
+        let zone = if self.to == PremiumZone::Normal {
+            self.from
+        } else {
+            self.to
+        };
+
+        let (signal_type, action) = if self.to == PremiumZone::Normal {
+            (SignalType::Exit, "복귀")
+        } else {
+            (SignalType::Entry, "돌파")
+        };
+        let (side, band) = match zone {
+            PremiumZone::Below => (Side::Buy, "하단"),
+            _ => (Side::Sell, "상단"),
+        };
+
+        let strength = if self.threshold_pct.is_zero() {
+            1.0
+        } else {
+            (snapshot.premium_pct.abs() / (self.threshold_pct.abs() * dec!(2)))
+                .to_f64()
+                .unwrap_or(0.0)
+                .clamp(0.0, 1.0)
+        };
+
+        let reason = format!(
+            "{} {} 김치 프리미엄 {}% ({} 임계값 {}% {})",
+            snapshot.venue.display_name(),
+            snapshot.base,
+            snapshot.premium_pct.round_dp(2),
+            band,
+            self.threshold_pct,
+            action
+        );
+
+        let mut marker = SignalMarker::new(
+            format!("{}/KRW", snapshot.base),
+            snapshot.timestamp,
+            signal_type,
+            snapshot.domestic_price_krw,
+            KIMCHI_PREMIUM_STRATEGY_ID,
+            "Kimchi Premium Monitor",
+        )
+        .with_side(side)
+        .with_strength(strength)
+        .with_reason(reason);
+
+        marker.metadata.insert(
+            "venue".to_string(),
+            serde_json::json!(snapshot.venue.as_str()),
+        );
+        marker.metadata.insert(
+            "premium_pct".to_string(),
+            serde_json::json!(snapshot.premium_pct.to_string()),
+        );
+        marker.metadata.insert(
+            "usd_krw".to_string(),
+            serde_json::json!(snapshot.usd_krw.to_string()),
+        );
+        marker
+    }
+}
+
+// ==================== 모니터 ====================
+
+type PairKey = (DomesticVenue, String);
+
+/// 김치 프리미엄 계산기.
+///
+/// 가격/환율 업데이트를 받아 최신 프리미엄과 이력을 유지하고,
+/// 임계 구간이 바뀔 때 [`PremiumCrossing`]을 반환합니다.
+#[derive(Debug)]
+pub struct KimchiPremiumMonitor {
+    upper_threshold_pct: Decimal,
+    lower_threshold_pct: Decimal,
+    history_interval_secs: i64,
+    max_history: usize,
+    usd_krw: Option<Decimal>,
+    domestic: HashMap<PairKey, (Decimal, DateTime<Utc>)>,
+    foreign: HashMap<String, (Decimal, DateTime<Utc>)>,
+    latest: HashMap<PairKey, PremiumSnapshot>,
+    history: HashMap<PairKey, VecDeque<PremiumSnapshot>>,
+    /// 저장 대기 중인 신규 이력 샘플
+    recorded: Vec<PremiumSnapshot>,
+    zones: HashMap<PairKey, PremiumZone>,
+}
+
+impl KimchiPremiumMonitor {
+    /// 설정으로 모니터 생성.
+    pub fn new(config: &KimchiPremiumConfig) -> Self {
+        let usd_krw = match &config.usd_krw_source {
+            UsdKrwSource::Fixed(rate) => Some(*rate),
+            UsdKrwSource::Macro => None,
+        };
+
+        Self {
+            upper_threshold_pct: config.upper_threshold_pct,
+            lower_threshold_pct: config.lower_threshold_pct,
+            history_interval_secs: config.history_interval_secs,
+            max_history: config.max_history.max(1),
+            usd_krw,
+            domestic: HashMap::new(),
+            foreign: HashMap::new(),
+            latest: HashMap::new(),
+            history: HashMap::new(),
+            recorded: Vec::new(),
+            zones: HashMap::new(),
+        }
+    }
+
+    /// 저장된 이력으로 메모리 이력 복원 (시각 오름차순 입력).
+    ///
+    /// 페어별 최근 `max_history`개만 유지하며, 저장 대기 목록에는 넣지 않습니다.
+    pub fn restore_history(&mut self, snapshots: Vec<PremiumSnapshot>) {
+        for snapshot in snapshots {
+            let key = (snapshot.venue, snapshot.base.to_uppercase());
+            let series = self.history.entry(key).or_default();
+            if series
+                .back()
+                .is_some_and(|last| last.timestamp >= snapshot.timestamp)
+            {
+                continue;
+            }
+            series.push_back(snapshot);
+            while series.len() > self.max_history {
+                series.pop_front();
+            }
+        }
+    }
+
+    /// 마지막 호출 이후 새로 적재된 이력 샘플 (DB 저장용).
+    ///
+    /// 호출하지 않으면 샘플이 계속 쌓이므로 서비스 루프에서 매 갱신마다 비웁니다.
+    pub fn take_recorded(&mut self) -> Vec<PremiumSnapshot> {
+        std::mem::take(&mut self.recorded)
+    }
+
+    /// 현재 적용 중인 USD/KRW 환율.
+    pub fn usd_krw(&self) -> Option<Decimal> {
+        self.usd_krw
+    }
+
+    /// 상단/하단 임계값 (%).
+    pub fn thresholds(&self) -> (Decimal, Decimal) {
+        (self.upper_threshold_pct, self.lower_threshold_pct)
+    }
+
+    /// 환율 갱신 후 모든 페어 재계산.
+    pub fn set_usd_krw(&mut self, rate: Decimal, at: DateTime<Utc>) -> Vec<PremiumCrossing> {
+        if rate <= Decimal::ZERO {
+            return Vec::new();
+        }
+        self.usd_krw = Some(rate);
+
+        let keys: Vec<PairKey> = self.domestic.keys().cloned().collect();
+        keys.into_iter()
+            .filter_map(|key| self.recompute(key, at))
+            .collect()
+    }
+
+    /// 국내 거래소 가격 갱신.
+    pub fn update_domestic(
+        &mut self,
+        venue: DomesticVenue,
+        base: &str,
+        price: Decimal,
+        at: DateTime<Utc>,
+    ) -> Option<PremiumCrossing> {
+        let key = (venue, base.to_uppercase());
+        self.domestic.insert(key.clone(), (price, at));
+        self.recompute(key, at)
+    }
+
+    /// 해외 거래소 가격 갱신 (해당 코인의 모든 국내 페어 재계산).
+    pub fn update_foreign(
+        &mut self,
+        base: &str,
+        price: Decimal,
+        at: DateTime<Utc>,
+    ) -> Vec<PremiumCrossing> {
+        let base = base.to_uppercase();
+        self.foreign.insert(base.clone(), (price, at));
+
+        let keys: Vec<PairKey> = self
+            .domestic
+            .keys()
+            .filter(|(_, b)| *b == base)
+            .cloned()
+            .collect();
+        keys.into_iter()
+            .filter_map(|key| self.recompute(key, at))
+            .collect()
+    }
+
+    /// 모든 페어의 최신 프리미엄 (코인, 거래소 순 정렬).
+    pub fn latest(&self) -> Vec<PremiumSnapshot> {
+        let mut snapshots: Vec<_> = self.latest.values().cloned().collect();
+        snapshots.sort_by(|a, b| {
+            a.base
+                .cmp(&b.base)
+                .then_with(|| a.venue.as_str().cmp(b.venue.as_str()))
+        });
+        snapshots
+    }
+
+    /// 페어의 프리미엄 이력 (오래된 순, 최근 `limit`개).
+    pub fn history(&self, venue: DomesticVenue, base: &str, limit: usize) -> Vec<PremiumSnapshot> {
+        self.history
+            .get(&(venue, base.to_uppercase()))
+            .map(|series| {
+                let skip = series.len().saturating_sub(limit);
+                series.iter().skip(skip).cloned().collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn zone_of(&self, premium_pct: Decimal) -> PremiumZone {
+        if premium_pct >= self.upper_threshold_pct {
+            PremiumZone::Above
+        } else if premium_pct <= self.lower_threshold_pct {
+            PremiumZone::Below
+        } else {
+            PremiumZone::Normal
+        }
+    }
+
+    fn recompute(&mut self, key: PairKey, at: DateTime<Utc>) -> Option<PremiumCrossing> {
+        let usd_krw = self.usd_krw?;
+        let (domestic_price, _) = *self.domestic.get(&key)?;
+        let (foreign_price, _) = *self.foreign.get(&key.1)?;
+
+        let foreign_price_krw = foreign_price * usd_krw;
+        if foreign_price_krw.is_zero() {
+            return None;
+        }
+        let premium_pct =
+            ((domestic_price / foreign_price_krw - Decimal::ONE) * dec!(100)).round_dp(4);
+
+        let snapshot = PremiumSnapshot {
+            base: key.1.clone(),
+            venue: key.0,
+            domestic_price_krw: domestic_price,
+            foreign_price_usd: foreign_price,
+            usd_krw,
+            foreign_price_krw,
+            premium_pct,
+            timestamp: at,
+        };
+
+        // 이력: 샘플링 간격마다 한 번만 적재
+        let series = self.history.entry(key.clone()).or_default();
+        let should_record = series
+            .back()
+            .map(|last| (at - last.timestamp).num_seconds() >= self.history_interval_secs)
+            .unwrap_or(true);
+        if should_record {
+            series.push_back(snapshot.clone());
+            self.recorded.push(snapshot.clone());
+            while series.len() > self.max_history {
+                series.pop_front();
+            }
+        }
+        self.latest.insert(key.clone(), snapshot.clone());
+
+        // 구간 변화 감지
+        let to = self.zone_of(premium_pct);
+        let from = self.zones.insert(key, to).unwrap_or(PremiumZone::Normal);
+        if from == to {
+            return None;
+        }
+
+        let threshold_pct = match if to == PremiumZone::Normal { from } else { to } {
+            PremiumZone::Below => self.lower_threshold_pct,
+            _ => self.upper_threshold_pct,
+        };
+
+        Some(PremiumCrossing {
+            snapshot,
+            from,
+            to,
+            threshold_pct,
+        })
+    }
+}
+
+// ==================== 알림 ====================
+
+/// 임계값 돌파 알림 전송기.
+///
+/// 활성화된 `signal_alert_rule` 중 하나라도 매칭되면 알림을 전송합니다.
+/// DB 또는 NotificationManager가 없으면 로그만 남깁니다.
+pub struct PremiumAlertDispatcher {
+    rules: Option<SignalAlertRuleRepository>,
+    notification_manager: Option<Arc<NotificationManager>>,
+}
+
+impl PremiumAlertDispatcher {
+    /// 새 전송기 생성.
+    pub fn new(
+        rules: Option<SignalAlertRuleRepository>,
+        notification_manager: Option<Arc<NotificationManager>>,
+    ) -> Self {
+        Self {
+            rules,
+            notification_manager,
+        }
+    }
+
+    /// 돌파 이벤트 알림.
+    ///
+    /// # 반환
+    ///
+    /// 알림 전송 여부
+    pub async fn dispatch(&self, crossing: &PremiumCrossing) -> bool {
+        let marker = crossing.to_signal_marker();
+        info!(
+            base = %crossing.snapshot.base,
+            venue = crossing.snapshot.venue.as_str(),
+            premium_pct = %crossing.snapshot.premium_pct,
+            from = ?crossing.from,
+            to = ?crossing.to,
+            "김치 프리미엄 임계값 변화"
+        );
+
+        let (Some(rules), Some(manager)) = (&self.rules, &self.notification_manager) else {
+            return false;
+        };
+
+        let filters = match rules.get_enabled_filters().await {
+            Ok(filters) => filters,
+            Err(e) => {
+                warn!("알림 규칙 조회 실패: {:?}", e);
+                return false;
+            }
+        };
+
+        let Some(filter) = filters.into_iter().find(|f| f.matches(&marker)) else {
+            debug!(reason = %marker.reason, "매칭되는 알림 규칙 없음");
+            return false;
+        };
+
+        let service = SignalAlertService::from_shared(manager.clone()).with_filter(filter);
+        match service.notify_signal(&marker).await {
+            Ok(sent) => sent,
+            Err(e) => {
+                warn!("김치 프리미엄 알림 전송 실패: {}", e);
+                false
+            }
+        }
+    }
+}
+
+// ==================== 백그라운드 서비스 ====================
+
+/// 매크로 데이터 캐시에서 USD/KRW 환율 조회.
+async fn fetch_macro_usd_krw(cache: &RedisCache) -> Option<Decimal> {
+    match cache.get::<MacroData>(MACRO_DATA_CACHE_KEY).await {
+        Ok(Some(data)) if data.usd_krw > Decimal::ZERO => Some(data.usd_krw),
+        Ok(_) => None,
+        Err(e) => {
+            warn!("매크로 데이터 조회 실패: {}", e);
+            None
+        }
+    }
+}
+
+/// 스트림 시작 후 심볼 구독.
+async fn open_stream(
+    mut stream: Box<dyn MarketStream>,
+    name: &str,
+    symbols: &[String],
+) -> Option<Box<dyn MarketStream>> {
+    if let Err(e) = stream.start().await {
+        warn!("{} 스트림 시작 실패: {}", name, e);
+        return None;
+    }
+    for symbol in symbols {
+        if let Err(e) = stream.subscribe_ticker(symbol).await {
+            warn!("{} 구독 실패 ({}): {}", name, symbol, e);
+        }
+    }
+    Some(stream)
+}
+
+/// 스트림이 없으면 영원히 대기 (select 분기 비활성화용).
+async fn next_event(stream: &mut Option<Box<dyn MarketStream>>) -> Option<MarketEvent> {
+    match stream {
+        Some(s) => s.next_event().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// 이벤트 루프에서 처리할 갱신 종류.
+enum PremiumUpdate {
+    Rate,
+    Domestic(DomesticVenue, Option<MarketEvent>),
+    Foreign(Option<MarketEvent>),
+}
+
+/// 저장된 이력으로 모니터 메모리 이력 복원.
+async fn restore_history(
+    repository: &KimchiPremiumRepository,
+    monitor: &RwLock<KimchiPremiumMonitor>,
+    config: &KimchiPremiumConfig,
+) {
+    let window = chrono::Duration::seconds(
+        config
+            .history_interval_secs
+            .max(1)
+            .saturating_mul(config.max_history as i64),
+    );
+    match repository.load_since(Utc::now() - window).await {
+        Ok(snapshots) => {
+            let count = snapshots.len();
+            monitor.write().await.restore_history(snapshots);
+            info!(count, "김치 프리미엄 이력 복원");
+        }
+        Err(e) => warn!("김치 프리미엄 이력 복원 실패: {}", e),
+    }
+}
+
+/// 김치 프리미엄 모니터링 서비스 시작.
+///
+/// 업비트/빗썸/바이낸스 티커를 구독하고 환율을 주기적으로 갱신하며,
+/// 임계값 돌파 시 [`PremiumAlertDispatcher`]로 알림을 전송합니다.
+/// `repository`가 있으면 시작 시 이력을 복원하고, 새 이력 샘플을 저장하며
+/// 보존 기간(`retention_days`)이 지난 이력을 한 시간마다 정리합니다.
+pub fn start_kimchi_premium_service(
+    config: KimchiPremiumConfig,
+    monitor: Arc<RwLock<KimchiPremiumMonitor>>,
+    cache: Option<Arc<RedisCache>>,
+    repository: Option<KimchiPremiumRepository>,
+    dispatcher: PremiumAlertDispatcher,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        if let Some(repository) = &repository {
+            restore_history(repository, &monitor, &config).await;
+        }
+        let mut last_prune: Option<DateTime<Utc>> = None;
+
+        let symbols_for = |venue: DomesticVenue| -> Vec<String> {
+            config.bases.iter().map(|b| venue.symbol(b)).collect()
+        };
+
+        let mut upbit = if config.venues.contains(&DomesticVenue::Upbit) {
+            open_stream(
+                Box::new(UpbitMarketStream::new()),
+                "Upbit",
+                &symbols_for(DomesticVenue::Upbit),
+            )
+            .await
+        } else {
+            None
+        };
+        let mut bithumb = if config.venues.contains(&DomesticVenue::Bithumb) {
+            open_stream(
+                Box::new(BithumbMarketStream::new()),
+                "Bithumb",
+                &symbols_for(DomesticVenue::Bithumb),
+            )
+            .await
+        } else {
+            None
+        };
+        let foreign_symbols: Vec<String> = config
+            .bases
+            .iter()
+            .map(|b| config.foreign_symbol(b))
+            .collect();
+        // 공개 시세 스트림은 API 키가 필요하지 않음
+        let mut binance = open_stream(
+            Box::new(BinanceMarketStream::new(BinanceConfig::new(
+                String::new(),
+                String::new(),
+            ))),
+            "Binance",
+            &foreign_symbols,
+        )
+        .await;
+
+        info!(
+            bases = ?config.bases,
+            venues = ?config.venues,
+            "김치 프리미엄 모니터 시작"
+        );
+
+        let mut rate_interval =
+            tokio::time::interval(Duration::from_secs(config.rate_refresh_secs.max(1)));
+
+        loop {
+            let update = tokio::select! {
+                _ = shutdown.cancelled() => {
+                    info!("김치 프리미엄 모니터 종료");
+                    break;
+                }
+                _ = rate_interval.tick() => PremiumUpdate::Rate,
+                event = next_event(&mut upbit) => PremiumUpdate::Domestic(DomesticVenue::Upbit, event),
+                event = next_event(&mut bithumb) => {
+                    PremiumUpdate::Domestic(DomesticVenue::Bithumb, event)
+                }
+                event = next_event(&mut binance) => PremiumUpdate::Foreign(event),
+            };
+
+            let crossings = match update {
+                PremiumUpdate::Rate => {
+                    let rate = match (&config.usd_krw_source, &cache) {
+                        (UsdKrwSource::Fixed(rate), _) => Some(*rate),
+                        (UsdKrwSource::Macro, Some(cache)) => fetch_macro_usd_krw(cache).await,
+                        (UsdKrwSource::Macro, None) => None,
+                    };
+                    match rate {
+                        Some(rate) => monitor.write().await.set_usd_krw(rate, Utc::now()),
+                        None => {
+                            warn!("USD/KRW 환율을 가져올 수 없습니다 (macro_data_sync 확인 필요)");
+                            Vec::new()
+                        }
+                    }
+                }
+                PremiumUpdate::Domestic(venue, Some(MarketEvent::Ticker(t))) => {
+                    match venue.base_from_symbol(&t.ticker) {
+                        Some(base) => monitor
+                            .write()
+                            .await
+                            .update_domestic(venue, &base, t.last, t.timestamp)
+                            .into_iter()
+                            .collect(),
+                        None => Vec::new(),
+                    }
+                }
+                PremiumUpdate::Domestic(venue, None) => {
+                    warn!("{} 스트림 종료", venue.as_str());
+                    match venue {
+                        DomesticVenue::Upbit => upbit = None,
+                        DomesticVenue::Bithumb => bithumb = None,
+                    }
+                    Vec::new()
+                }
+                PremiumUpdate::Foreign(Some(MarketEvent::Ticker(t))) => {
+                    match t.ticker.split_once('/') {
+                        Some((base, quote)) if quote == config.foreign_quote => monitor
+                            .write()
+                            .await
+                            .update_foreign(base, t.last, t.timestamp),
+                        _ => Vec::new(),
+                    }
+                }
+                PremiumUpdate::Foreign(None) => {
+                    warn!("Binance 스트림 종료");
+                    binance = None;
+                    Vec::new()
+                }
+                _ => Vec::new(),
+            };
+
+            for crossing in &crossings {
+                dispatcher.dispatch(crossing).await;
+            }
+
+            // 저장 대기 샘플은 항상 비워 메모리가 늘지 않도록 함
+            let recorded = monitor.write().await.take_recorded();
+            if let Some(repository) = &repository {
+                if !recorded.is_empty() {
+                    let repository = repository.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = repository.save_all(&recorded).await {
+                            warn!("김치 프리미엄 이력 저장 실패: {}", e);
+                        }
+                    });
+                }
+
+                let now = Utc::now();
+                if last_prune.map_or(true, |at| now - at >= chrono::Duration::hours(1)) {
+                    last_prune = Some(now);
+                    let repository = repository.clone();
+                    let cutoff = now - chrono::Duration::days(config.retention_days.max(1));
+                    tokio::spawn(async move {
+                        match repository.delete_before(cutoff).await {
+                            Ok(deleted) if deleted > 0 => {
+                                debug!(deleted, "보존 기간이 지난 김치 프리미엄 이력 삭제")
+                            }
+                            Ok(_) => {}
+                            Err(e) => warn!("김치 프리미엄 이력 정리 실패: {}", e),
+                        }
+                    });
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn config() -> KimchiPremiumConfig {
+        KimchiPremiumConfig {
+            usd_krw_source: UsdKrwSource::Fixed(dec!(1300)),
+            upper_threshold_pct: dec!(5),
+            lower_threshold_pct: dec!(-1),
+            history_interval_secs: 10,
+            max_history: 3,
+            ..Default::default()
+        }
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_venue_symbols() {
+        assert_eq!(DomesticVenue::Upbit.symbol("BTC"), "KRW-BTC");
+        assert_eq!(DomesticVenue::Bithumb.symbol("BTC"), "BTC_KRW");
+        assert_eq!(
+            DomesticVenue::Upbit.base_from_symbol("KRW-ETH").as_deref(),
+            Some("ETH")
+        );
+        assert_eq!(
+            DomesticVenue::Bithumb
+                .base_from_symbol("ETH_KRW")
+                .as_deref(),
+            Some("ETH")
+        );
+        assert!(DomesticVenue::Upbit.base_from_symbol("BTC-ETH").is_none());
+        assert_eq!(DomesticVenue::parse(" Upbit "), Some(DomesticVenue::Upbit));
+    }
+
+    #[test]
+    fn test_premium_calculation() {
+        let mut monitor = KimchiPremiumMonitor::new(&config());
+
+        // 해외가만 있으면 계산 불가
+        assert!(monitor.update_foreign("BTC", dec!(50000), at(0)).is_empty());
+        assert!(monitor.latest().is_empty());
+
+        // 50000 USDT × 1300 = 65,000,000 KRW → 66,300,000 KRW는 2% 프리미엄
+        monitor.update_domestic(DomesticVenue::Upbit, "BTC", dec!(66300000), at(1));
+        let latest = monitor.latest();
+        assert_eq!(latest.len(), 1);
+        assert_eq!(latest[0].foreign_price_krw, dec!(65000000));
+        assert_eq!(latest[0].premium_pct, dec!(2));
+    }
+
+    #[test]
+    fn test_macro_source_waits_for_rate() {
+        let mut config = config();
+        config.usd_krw_source = UsdKrwSource::Macro;
+        let mut monitor = KimchiPremiumMonitor::new(&config);
+
+        monitor.update_foreign("BTC", dec!(50000), at(0));
+        monitor.update_domestic(DomesticVenue::Bithumb, "BTC", dec!(65000000), at(0));
+        assert!(monitor.latest().is_empty());
+
+        monitor.set_usd_krw(dec!(1300), at(1));
+        assert_eq!(monitor.usd_krw(), Some(dec!(1300)));
+        assert_eq!(monitor.latest()[0].premium_pct, dec!(0));
+    }
+
+    #[test]
+    fn test_threshold_crossings() {
+        let mut monitor = KimchiPremiumMonitor::new(&config());
+        monitor.update_foreign("BTC", dec!(50000), at(0));
+
+        // 2% → 정상 구간
+        assert!(monitor
+            .update_domestic(DomesticVenue::Upbit, "BTC", dec!(66300000), at(0))
+            .is_none());
+
+        // 6% → 상단 돌파
+        let crossing = monitor
+            .update_domestic(DomesticVenue::Upbit, "BTC", dec!(68900000), at(1))
+            .unwrap();
+        assert_eq!(crossing.from, PremiumZone::Normal);
+        assert_eq!(crossing.to, PremiumZone::Above);
+        assert_eq!(crossing.threshold_pct, dec!(5));
+
+        // 계속 상단 구간이면 재알림 없음
+        assert!(monitor
+            .update_domestic(DomesticVenue::Upbit, "BTC", dec!(69000000), at(2))
+            .is_none());
+
+        // 해외가 상승으로 역프리미엄 (69,000,000 / (54,200 × 1300) ≈ -2.07%)
+        let crossings = monitor.update_foreign("BTC", dec!(54200), at(3));
+        assert_eq!(crossings.len(), 1);
+        assert_eq!(crossings[0].to, PremiumZone::Below);
+        assert_eq!(crossings[0].threshold_pct, dec!(-1));
+    }
+
+    #[test]
+    fn test_history_sampling_and_capacity() {
+        let mut monitor = KimchiPremiumMonitor::new(&config());
+        monitor.update_foreign("BTC", dec!(50000), at(0));
+
+        for (i, secs) in [0, 5, 10, 20, 30].iter().enumerate() {
+            let price = dec!(65000000) + Decimal::from(i as i64 * 1000);
+            monitor.update_domestic(DomesticVenue::Upbit, "BTC", price, at(*secs));
+        }
+
+        // 5초 샘플은 간격 미달로 제외, 최대 3개 유지
+        let history = monitor.history(DomesticVenue::Upbit, "btc", 100);
+        let times: Vec<_> = history.iter().map(|s| s.timestamp).collect();
+        assert_eq!(times, vec![at(10), at(20), at(30)]);
+
+        assert_eq!(monitor.history(DomesticVenue::Upbit, "BTC", 1).len(), 1);
+        assert!(monitor
+            .history(DomesticVenue::Bithumb, "BTC", 10)
+            .is_empty());
+        // 최신값은 샘플링과 무관하게 갱신
+        assert_eq!(monitor.latest()[0].domestic_price_krw, dec!(65004000));
+
+        // 저장 대기 목록은 용량과 무관하게 적재된 샘플 전체
+        let recorded: Vec<_> = monitor
+            .take_recorded()
+            .iter()
+            .map(|s| s.timestamp)
+            .collect();
+        assert_eq!(recorded, vec![at(0), at(10), at(20), at(30)]);
+        assert!(monitor.take_recorded().is_empty());
+    }
+
+    #[test]
+    fn test_restore_history() {
+        let mut source = KimchiPremiumMonitor::new(&config());
+        source.update_foreign("BTC", dec!(50000), at(0));
+        for secs in [0, 10, 20, 30] {
+            source.update_domestic(DomesticVenue::Upbit, "BTC", dec!(65000000), at(secs));
+        }
+        let saved = source.take_recorded();
+
+        let mut monitor = KimchiPremiumMonitor::new(&config());
+        monitor.restore_history(saved);
+        let times: Vec<_> = monitor
+            .history(DomesticVenue::Upbit, "BTC", 100)
+            .iter()
+            .map(|s| s.timestamp)
+            .collect();
+        assert_eq!(times, vec![at(10), at(20), at(30)]);
+        // 복원한 이력은 다시 저장하지 않음
+        assert!(monitor.take_recorded().is_empty());
+
+        // 복원 이후 샘플링 간격은 복원된 마지막 시각 기준
+        monitor.update_foreign("BTC", dec!(50000), at(35));
+        monitor.update_domestic(DomesticVenue::Upbit, "BTC", dec!(65000000), at(35));
+        assert!(monitor.take_recorded().is_empty());
+        monitor.update_domestic(DomesticVenue::Upbit, "BTC", dec!(65000000), at(40));
+        assert_eq!(monitor.take_recorded().len(), 1);
+    }
+
+    #[test]
+    fn test_crossing_to_signal_marker_matches_alert_filter() {
+        use crate::services::SignalAlertFilter;
+
+        let mut monitor = KimchiPremiumMonitor::new(&config());
+        monitor.update_foreign("BTC", dec!(50000), at(0));
+        let crossing = monitor
+            .update_domestic(DomesticVenue::Upbit, "BTC", dec!(71500000), at(0))
+            .unwrap();
+
+        let marker = crossing.to_signal_marker();
+        assert_eq!(marker.strategy_id, KIMCHI_PREMIUM_STRATEGY_ID);
+        assert_eq!(marker.ticker, "BTC/KRW");
+        assert_eq!(marker.signal_type, SignalType::Entry);
+        assert_eq!(marker.side, Some(Side::Sell));
+        // 10% / (2 × 5%) = 1.0
+        assert!((marker.strength - 1.0).abs() < f64::EPSILON);
+        assert_eq!(marker.metadata["venue"], "upbit");
+
+        // 임계값의 두 배를 넘어도 강도는 1.0으로 제한
+        let extreme = PremiumCrossing {
+            snapshot: PremiumSnapshot {
+                premium_pct: dec!(30),
+                ..crossing.snapshot.clone()
+            },
+            ..crossing.clone()
+        }
+        .to_signal_marker();
+        assert!((extreme.strength - 1.0).abs() < f64::EPSILON);
+
+        let filter = SignalAlertFilter::new()
+            .with_strategies(vec![KIMCHI_PREMIUM_STRATEGY_ID.to_string()])
+            .with_symbols(vec!["BTC".to_string()])
+            .entry_only();
+        assert!(filter.matches(&marker));
+
+        // 정상 구간 복귀는 Exit
+        let exit = monitor
+            .update_domestic(DomesticVenue::Upbit, "BTC", dec!(65000000), at(1))
+            .unwrap()
+            .to_signal_marker();
+        assert_eq!(exit.signal_type, SignalType::Exit);
+        assert!(!filter.matches(&exit));
+    }
+}
@@ -0,0 +1,300 @@
+//! 실시간 바 집계 서비스.
+//!
+//! MarketStream 이벤트(체결/시세)를 [`BarBuilder`]로 집계하여 거래소가 푸시하는
+//! 캔들 주기와 무관하게 일관된 캔들을 만듭니다.
+//!
+//! - 마감된 시간 캔들 → StrategyEngine (`process_market_data`, 전략별 구독 타임프레임만 전달)
+//! - 체결 틱 / 시간 캔들 → DB (`LiveBarStore`: trade_ticks, ohlcv)
+//! - 모든 바(거래량/틱/거래대금 바 포함) → [`LiveBarService::subscribe`] 구독자
+//!
+//! 종목이 처음 구독될 때 거래소 REST 분봉으로 진행 중인 캔들을 백필하며,
+//! 주기적인 flush로 체결이 없어도 장 마감 시각에 캔들을 닫습니다.
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use tokio::sync::{broadcast, Mutex, RwLock};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_core::{Country, MarketData, Timeframe, TradeTick, TradingCalendar};
+use trader_data::{BarBuilder, BarBuilderConfig, BarSpec, BuiltBar, KlineSource, LiveBarStore};
+use trader_exchange::traits::MarketEvent;
+use trader_strategy::StrategyEngine;
+
+/// 바 브로드캐스트 채널 용량.
+const BAR_CHANNEL_CAPACITY: usize = 1024;
+
+/// 실시간 바 집계 설정.
+#[derive(Debug, Clone)]
+pub struct LiveBarConfig {
+    /// 바 빌더 설정 (바 종류, 정규장 전용 여부)
+    pub builder: BarBuilderConfig,
+    /// 체결 틱/시간 캔들 DB 저장 여부
+    pub persist: bool,
+    /// flush 주기 (캔들 마감 확인, 체결 틱 저장)
+    pub flush_interval: Duration,
+}
+
+impl Default for LiveBarConfig {
+    fn default() -> Self {
+        Self {
+            builder: BarBuilderConfig::default(),
+            persist: true,
+            flush_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl LiveBarConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `LIVE_BARS_ENABLED=true`가 아니면 `None`을 반환합니다.
+    ///
+    /// # 환경변수
+    ///
+    /// - `LIVE_BARS_TIMEFRAMES`: 시간 캔들 (쉼표 구분, 기본: 1m,5m,15m,1h,1d)
+    /// - `LIVE_BARS_VOLUME` / `LIVE_BARS_TICKS` / `LIVE_BARS_DOLLAR`: 정보 바 기준 (미설정 시 생성 안 함)
+    /// - `LIVE_BARS_EXTENDED_HOURS`: 장외 체결도 집계 (기본: false)
+    /// - `LIVE_BARS_PERSIST`: DB 저장 여부 (기본: true)
+    /// - `LIVE_BARS_FLUSH_INTERVAL_SECS`: flush 주기 (기본: 1)
+    pub fn from_env() -> Option<Self> {
+        if !env_bool("LIVE_BARS_ENABLED", false) {
+            return None;
+        }
+
+        let mut config = Self::default();
+        let mut specs: Vec<BarSpec> = match std::env::var("LIVE_BARS_TIMEFRAMES") {
+            Ok(list) => list
+                .split(',')
+                .filter_map(|tf| tf.trim().parse::<Timeframe>().ok())
+                .map(BarSpec::Time)
+                .collect(),
+            Err(_) => config.builder.specs.clone(),
+        };
+        if let Some(volume) = env_decimal("LIVE_BARS_VOLUME") {
+            specs.push(BarSpec::Volume(volume));
+        }
+        if let Some(ticks) = std::env::var("LIVE_BARS_TICKS")
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+        {
+            specs.push(BarSpec::Tick(ticks));
+        }
+        if let Some(dollar) = env_decimal("LIVE_BARS_DOLLAR") {
+            specs.push(BarSpec::Dollar(dollar));
+        }
+
+        config.builder = config
+            .builder
+            .with_specs(specs)
+            .with_session_only(!env_bool("LIVE_BARS_EXTENDED_HOURS", false));
+        config.persist = env_bool("LIVE_BARS_PERSIST", true);
+        if let Some(secs) = std::env::var("LIVE_BARS_FLUSH_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+        {
+            config.flush_interval = Duration::from_secs(secs.max(1));
+        }
+        Some(config)
+    }
+}
+
+fn env_bool(key: &str, default: bool) -> bool {
+    std::env::var(key)
+        .map(|v| v == "true" || v == "1")
+        .unwrap_or(default)
+}
+
+fn env_decimal(key: &str) -> Option<Decimal> {
+    std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+}
+
+/// 실시간 바 집계 서비스.
+pub struct LiveBarService {
+    builder: Mutex<BarBuilder>,
+    engine: Arc<RwLock<StrategyEngine>>,
+    store: Option<LiveBarStore>,
+    flush_interval: Duration,
+    /// 티커 → 거래소 ID (flush로 마감된 캔들의 출처 표기용)
+    exchanges: RwLock<HashMap<String, String>>,
+    /// 저장 대기 중인 체결 틱 (거래소 ID, 틱)
+    pending_trades: Mutex<Vec<(String, TradeTick)>>,
+    bar_tx: broadcast::Sender<BuiltBar>,
+}
+
+impl LiveBarService {
+    /// 새 서비스 생성.
+    pub fn new(config: LiveBarConfig, engine: Arc<RwLock<StrategyEngine>>) -> Self {
+        let (bar_tx, _) = broadcast::channel(BAR_CHANNEL_CAPACITY);
+        Self {
+            builder: Mutex::new(BarBuilder::new(config.builder)),
+            engine,
+            store: None,
+            flush_interval: config.flush_interval,
+            exchanges: RwLock::new(HashMap::new()),
+            pending_trades: Mutex::new(Vec::new()),
+            bar_tx,
+        }
+    }
+
+    /// DB 저장소 설정.
+    pub fn with_store(mut self, store: LiveBarStore) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// 완성된 바 구독.
+    pub fn subscribe(&self) -> broadcast::Receiver<BuiltBar> {
+        self.bar_tx.subscribe()
+    }
+
+    /// 종목 등록 (캘린더 지정 및 REST 백필).
+    ///
+    /// 국내 6자리 종목은 KRX, 암호화폐 거래소는 24시간, 그 외는 미국 정규장 캘린더를 사용하며,
+    /// 휴장일은 StrategyEngine에 등록된 캘린더를 따릅니다.
+    pub async fn register_ticker(
+        &self,
+        exchange_id: &str,
+        ticker: &str,
+        backfill: Option<&dyn KlineSource>,
+    ) {
+        let calendar = self.calendar_for(exchange_id, ticker).await;
+        self.exchanges
+            .write()
+            .await
+            .insert(ticker.to_string(), exchange_id.to_string());
+
+        let limit = {
+            let mut builder = self.builder.lock().await;
+            builder.set_calendar(ticker, calendar);
+            builder.backfill_limit(ticker, Utc::now())
+        };
+        let (Some(source), Some(limit)) = (backfill, limit) else {
+            return;
+        };
+
+        // REST 조회 중에는 빌더 잠금을 잡지 않음 (다른 종목 집계 지연 방지)
+        match source.recent_klines(ticker, Timeframe::M1, limit).await {
+            Ok(klines) => {
+                let seeded = self
+                    .builder
+                    .lock()
+                    .await
+                    .backfill(ticker, &klines, Utc::now());
+                debug!(ticker, seeded, "실시간 바 백필 완료");
+            }
+            Err(e) => warn!(ticker, error = %e, "실시간 바 백필 실패 (체결부터 집계)"),
+        }
+    }
+
+    /// 시장 이벤트 처리 (체결/시세만 집계).
+    pub async fn handle_event(&self, exchange_id: &str, event: &MarketEvent) {
+        let bars = {
+            let mut builder = self.builder.lock().await;
+            match event {
+                MarketEvent::Trade(trade) => builder.on_trade(trade),
+                MarketEvent::Ticker(ticker) => builder.on_ticker(ticker),
+                _ => return,
+            }
+        };
+
+        if let MarketEvent::Trade(trade) = event {
+            if self.store.is_some() {
+                self.pending_trades
+                    .lock()
+                    .await
+                    .push((exchange_id.to_string(), trade.clone()));
+            }
+        }
+
+        if !bars.is_empty() {
+            self.dispatch(bars).await;
+        }
+    }
+
+    /// 마감 시각이 지난 캔들을 닫고 대기 중인 체결 틱을 저장합니다.
+    pub async fn flush(&self, now: DateTime<Utc>) {
+        let bars = self.builder.lock().await.flush(now);
+        if !bars.is_empty() {
+            self.dispatch(bars).await;
+        }
+
+        let Some(store) = &self.store else {
+            return;
+        };
+        let trades = std::mem::take(&mut *self.pending_trades.lock().await);
+        let mut by_exchange: HashMap<String, Vec<TradeTick>> = HashMap::new();
+        for (exchange_id, trade) in trades {
+            by_exchange.entry(exchange_id).or_default().push(trade);
+        }
+        for (exchange_id, trades) in by_exchange {
+            if let Err(e) = store.record_trades(&exchange_id, &trades).await {
+                warn!(exchange_id = %exchange_id, count = trades.len(), error = %e, "체결 틱 저장 실패");
+            }
+        }
+    }
+
+    /// 주기적 flush 태스크 시작.
+    pub fn start(self: Arc<Self>, shutdown: CancellationToken) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            info!(
+                interval_secs = self.flush_interval.as_secs(),
+                "LiveBarService 시작"
+            );
+            let mut timer = tokio::time::interval(self.flush_interval);
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => {
+                        // 종료 전 대기 중인 체결 틱 저장
+                        self.flush(Utc::now()).await;
+                        info!("LiveBarService 종료");
+                        break;
+                    }
+                    _ = timer.tick() => {
+                        self.flush(Utc::now()).await;
+                    }
+                }
+            }
+        })
+    }
+
+    /// 완성된 바 전달: 엔진(시간 캔들), 저장소(시간 캔들), 구독자(전체).
+    async fn dispatch(&self, bars: Vec<BuiltBar>) {
+        let exchanges = self.exchanges.read().await.clone();
+        {
+            let engine = self.engine.read().await;
+            for bar in bars.iter().filter(|bar| bar.spec.is_time()) {
+                let exchange = exchanges
+                    .get(&bar.kline.ticker)
+                    .map(String::as_str)
+                    .unwrap_or("live");
+                let data = MarketData::from_kline(exchange, bar.kline.clone());
+                if let Err(e) = engine.process_market_data(data).await {
+                    warn!(ticker = %bar.kline.ticker, spec = %bar.spec, error = %e, "실시간 캔들 전략 처리 실패");
+                }
+            }
+        }
+
+        if let Some(store) = &self.store {
+            if let Err(e) = store.record_bars(&bars).await {
+                warn!(count = bars.len(), error = %e, "실시간 캔들 저장 실패");
+            }
+        }
+
+        for bar in bars {
+            let _ = self.bar_tx.send(bar);
+        }
+    }
+
+    /// 거래소/종목에 맞는 거래 캘린더.
+    async fn calendar_for(&self, exchange_id: &str, ticker: &str) -> TradingCalendar {
+        match exchange_id {
+            "upbit" | "bithumb" | "binance" => TradingCalendar::always_open(),
+            _ if ticker.len() == 6 && ticker.chars().all(|c| c.is_ascii_digit()) => {
+                self.engine.read().await.calendar(Country::KR).await
+            }
+            _ => self.engine.read().await.calendar(Country::US).await,
+        }
+    }
+}
@@ -21,18 +21,24 @@ use std::{collections::HashMap, sync::Arc};
 use tokio::sync::RwLock;
 use tracing::{info, warn};
 use trader_core::crypto::CredentialEncryptor;
+use trader_data::KlineSource;
 use trader_exchange::{
-    connector::kis::{KisConfig, KisOAuth},
+    connector::kis::{KisClient, KisConfig, KisOAuth},
+    global_health_registry,
     provider::MockExchangeProvider,
     stream::{
         BithumbMarketStream, KisKrMarketStream, KisUsMarketStream, LsSecMarketStream,
         UnifiedMarketStream, UpbitMarketStream,
     },
-    traits::MarketStream,
+    traits::{MarketEvent, MarketStream},
+    UnifiedHistoricalProvider,
 };
 use uuid::Uuid;
 
-use crate::websocket::{aggregator::MarketDataAggregator, SharedSubscriptionManager};
+use crate::{
+    services::LiveBarService,
+    websocket::{aggregator::MarketDataAggregator, SharedSubscriptionManager},
+};
 
 /// 거래소별 WebSocket 스트림 핸들.
 ///
@@ -46,6 +52,12 @@ pub struct MarketStreamHandle {
     subscribed_symbols: Arc<RwLock<HashMap<String, usize>>>,
     /// 연결된 credential ID
     credential_id: Uuid,
+    /// 거래소 ID
+    exchange_id: String,
+    /// 실시간 바 집계 서비스 (신규 심볼 등록용)
+    live_bars: Option<Arc<LiveBarService>>,
+    /// 실시간 바 백필용 REST 분봉 소스 (KIS만 지원)
+    backfill: Option<Arc<dyn KlineSource>>,
 }
 
 impl MarketStreamHandle {
@@ -65,6 +77,12 @@ impl MarketStreamHandle {
                 .await
                 .map_err(|e| format!("구독 실패 ({}): {}", symbol, e))?;
             info!(symbol = %symbol, credential_id = %self.credential_id, "심볼 구독 추가");
+
+            if let Some(live_bars) = &self.live_bars {
+                live_bars
+                    .register_ticker(&self.exchange_id, symbol, self.backfill.as_deref())
+                    .await;
+            }
         }
 
         *count += 1;
@@ -132,6 +150,8 @@ impl MarketStreamHandle {
 /// * `mock_providers` - Mock 거래소 프로바이더 캐시
 /// * `credential_id` - 거래소 자격증명 ID
 /// * `subscriptions` - WebSocket 구독 관리자 (이벤트 브로드캐스트용)
+/// * `live_bars` - 실시간 바 집계 서비스 (체결/시세 → 캔들)
+#[allow(clippy::too_many_arguments)]
 pub async fn get_or_create_market_stream(
     market_streams: &Arc<RwLock<HashMap<Uuid, Arc<MarketStreamHandle>>>>,
     exchange_id: &str,
@@ -141,6 +161,7 @@ pub async fn get_or_create_market_stream(
     mock_providers: &Arc<RwLock<HashMap<Uuid, Arc<MockExchangeProvider>>>>,
     credential_id: Uuid,
     subscriptions: Option<&SharedSubscriptionManager>,
+    live_bars: Option<&Arc<LiveBarService>>,
 ) -> Result<Arc<MarketStreamHandle>, String> {
     // 1. 캐시 확인
     {
@@ -151,6 +172,7 @@ pub async fn get_or_create_market_stream(
     }
 
     // 2. exchange_id에 따라 UnifiedMarketStream 생성
+    let mut backfill: Option<Arc<dyn KlineSource>> = None;
     let mut stream = match exchange_id {
         "kis" => {
             let pool = pool.ok_or("KIS 스트림에 DB 풀이 필요합니다")?;
@@ -164,6 +186,14 @@ pub async fn get_or_create_market_stream(
             let kr_stream = KisKrMarketStream::new(oauth_kr);
             let us_stream = KisUsMarketStream::new(oauth_us);
 
+            // 실시간 바 백필용 REST 분봉 클라이언트
+            if live_bars.is_some() {
+                let oauth = create_oauth_instance(kis_oauth_cache, &kis_config, "bars").await?;
+                let client = KisClient::new(Arc::new(oauth))
+                    .map_err(|e| format!("KIS 클라이언트 생성 실패: {}", e))?;
+                backfill = Some(Arc::new(UnifiedHistoricalProvider::new(Arc::new(client))));
+            }
+
             UnifiedMarketStream::new()
                 .with_kr_stream(kr_stream)
                 .with_us_stream(us_stream)
@@ -205,25 +235,32 @@ pub async fn get_or_create_market_stream(
         .await
         .map_err(|e| format!("MarketStream 시작 실패: {}", e))?;
 
-    // 4. Aggregator 연결 (stream → WebSocket 브로드캐스트)
+    // 4. Aggregator 연결 (stream → WebSocket 브로드캐스트, 실시간 바 집계)
     let stream = Arc::new(RwLock::new(stream));
 
-    if let Some(subs) = subscriptions {
-        let subs = subs.clone();
+    if subscriptions.is_some() || live_bars.is_some() {
+        let aggregator = subscriptions.cloned().map(MarketDataAggregator::new);
+        let live_bars_for_bridge = live_bars.cloned();
         let stream_for_aggregator = stream.clone();
         let cred_id = credential_id;
         let ex_id = exchange_id.to_string();
         tokio::spawn(async move {
             info!(credential_id = %cred_id, exchange_id = %ex_id, "MarketStream aggregator bridge 시작");
-            let aggregator = MarketDataAggregator::new(subs);
             loop {
                 let event = {
                     let mut stream = stream_for_aggregator.write().await;
                     stream.next_event().await
                 };
+                let received_at = chrono::Utc::now();
                 match event {
                     Some(event) => {
-                        aggregator.handle_event(event);
+                        record_heartbeat(&ex_id, &event, received_at);
+                        if let Some(live_bars) = &live_bars_for_bridge {
+                            live_bars.handle_event(&ex_id, &event).await;
+                        }
+                        if let Some(aggregator) = &aggregator {
+                            aggregator.handle_event(event);
+                        }
                     }
                     None => {
                         warn!(credential_id = %cred_id, exchange_id = %ex_id, "MarketStream 이벤트 스트림 종료");
@@ -239,6 +276,9 @@ pub async fn get_or_create_market_stream(
         stream,
         subscribed_symbols: Arc::new(RwLock::new(HashMap::new())),
         credential_id,
+        exchange_id: exchange_id.to_string(),
+        live_bars: live_bars.cloned(),
+        backfill,
     });
 
     // 6. 캐시에 저장
@@ -252,6 +292,25 @@ pub async fn get_or_create_market_stream(
     Ok(handle)
 }
 
+/// 시장 이벤트 수신을 WebSocket 하트비트로 헬스 레지스트리에 기록합니다.
+///
+/// 지연시간은 이벤트 발생 시각과 `received_at`(스트림에서 이벤트를 꺼낸 시각)의
+/// 차이입니다. 캔들은 `close_time`이 미래일 수 있어 수신 여부만 기록합니다.
+fn record_heartbeat(
+    exchange_id: &str,
+    event: &MarketEvent,
+    received_at: chrono::DateTime<chrono::Utc>,
+) {
+    let event_time = match event {
+        MarketEvent::Ticker(ticker) => Some(ticker.timestamp),
+        MarketEvent::OrderBook(book) => Some(book.timestamp),
+        MarketEvent::Trade(trade) => Some(trade.timestamp),
+        _ => None,
+    };
+    let latency_ms = event_time.map(|time| (received_at - time).num_milliseconds().max(0) as u64);
+    global_health_registry().record_heartbeat(exchange_id, latency_ms);
+}
+
 /// DB에서 자격증명을 로드하여 KisConfig를 생성합니다.
 async fn load_kis_config_from_credential(
     pool: &sqlx::PgPool,
@@ -2,14 +2,37 @@
 //!
 //! 전략 실행, 컨텍스트 동기화 등 백그라운드에서 실행되는 서비스들을 제공합니다.
 
+pub mod backtest_screening;
 pub mod context_sync;
+pub mod disclosure_alert;
+pub mod fx_rate;
+pub mod kimchi_premium;
+pub mod live_bars;
 pub mod market_stream;
 pub mod signal_alert;
 pub mod signal_processor;
+pub mod smart_routing;
 pub mod telegram_bot;
+pub mod trading_calendar;
 
+pub use backtest_screening::{
+    load_fundamental_history, load_screening_provider, screening_provider_for_strategy,
+    SCREENING_BASED_STRATEGIES,
+};
 pub use context_sync::start_context_sync_service;
+pub use disclosure_alert::{
+    start_disclosure_alert_service, DisclosureAlertConfig, DisclosureAlertService,
+    DISCLOSURE_STRATEGY_ID,
+};
+pub use fx_rate::FxRateService;
+pub use kimchi_premium::{
+    start_kimchi_premium_service, DomesticVenue, KimchiPremiumConfig, KimchiPremiumMonitor,
+    PremiumAlertDispatcher, PremiumSnapshot,
+};
+pub use live_bars::{LiveBarConfig, LiveBarService};
 pub use market_stream::{get_or_create_market_stream, MarketStreamHandle};
 pub use signal_alert::{SignalAlertFilter, SignalAlertService};
 pub use signal_processor::{start_signal_processing_service, SignalProcessingService};
+pub use smart_routing::{build_smart_router, SmartRouterConfig};
 pub use telegram_bot::ApiBotHandler;
+pub use trading_calendar::{load_trading_calendars, months_spanned};
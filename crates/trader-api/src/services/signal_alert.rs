@@ -3,6 +3,8 @@
 //! 백테스트 및 실거래에서 발생한 신호 마커를 필터링하고
 //! 텔레그램 등 알림 채널로 전송합니다.
 
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 use trader_core::SignalMarker;
 use trader_notification::{NotificationManager, NotificationResult};
@@ -18,6 +20,11 @@ pub struct SignalAlertFilter {
     pub symbols: Option<Vec<String>>,
     /// 진입 신호만 (true면 Entry만, false면 모든 신호)
     pub entry_only: bool,
+    /// 공시 유형 필터 (Some이면 `metadata.disclosure_type`이 일치하는 신호만)
+    ///
+    /// DART 공시 알림용: `capital_increase`, `convertible_bond`, `largest_shareholder_change` 등
+    #[serde(default)]
+    pub disclosure_types: Option<Vec<String>>,
 }
 
 impl Default for SignalAlertFilter {
@@ -27,6 +34,7 @@ impl Default for SignalAlertFilter {
             strategy_ids: None,
             symbols: None,
             entry_only: false,
+            disclosure_types: None,
         }
     }
 }
@@ -61,6 +69,12 @@ impl SignalAlertFilter {
         self
     }
 
+    /// 공시 유형 필터 설정.
+    pub fn with_disclosure_types(mut self, disclosure_types: Vec<String>) -> Self {
+        self.disclosure_types = Some(disclosure_types);
+        self
+    }
+
     /// 신호 마커가 필터 조건을 만족하는지 확인.
     pub fn matches(&self, marker: &SignalMarker) -> bool {
         // 최소 강도 확인
@@ -90,6 +104,17 @@ impl SignalAlertFilter {
             return false;
         }
 
+        // 공시 유형 필터 확인
+        if let Some(ref disclosure_types) = self.disclosure_types {
+            let disclosure_type = marker
+                .metadata
+                .get("disclosure_type")
+                .and_then(|v| v.as_str());
+            if !disclosure_type.is_some_and(|t| disclosure_types.iter().any(|d| d == t)) {
+                return false;
+            }
+        }
+
         true
     }
 }
@@ -98,13 +123,20 @@ impl SignalAlertFilter {
 ///
 /// SignalMarker를 받아서 필터링하고 알림을 전송합니다.
 pub struct SignalAlertService {
-    notification_manager: NotificationManager,
+    notification_manager: Arc<NotificationManager>,
     filter: SignalAlertFilter,
 }
 
 impl SignalAlertService {
     /// 새 알림 서비스 생성.
     pub fn new(notification_manager: NotificationManager) -> Self {
+        Self::from_shared(Arc::new(notification_manager))
+    }
+
+    /// 공유 NotificationManager로 알림 서비스 생성.
+    ///
+    /// `AppState::notification_manager`처럼 이미 Arc로 공유 중인 관리자를 재사용합니다.
+    pub fn from_shared(notification_manager: Arc<NotificationManager>) -> Self {
         Self {
             notification_manager,
             filter: SignalAlertFilter::default(),
@@ -269,4 +301,40 @@ mod tests {
         assert!(filter.matches(&matching_marker));
         assert!(!filter.matches(&non_matching_marker));
     }
+
+    #[test]
+    fn test_filter_disclosure_types() {
+        let filter = SignalAlertFilter::new()
+            .with_min_strength(0.0)
+            .with_disclosure_types(vec![
+                "capital_increase".to_string(),
+                "convertible_bond".to_string(),
+            ]);
+
+        let marker = |disclosure_type: &str| {
+            SignalMarker::new(
+                "247540".to_string(),
+                Utc::now(),
+                SignalType::Alert,
+                dec!(250000),
+                "dart_disclosure",
+                "DART 공시",
+            )
+            .with_metadata("disclosure_type", serde_json::json!(disclosure_type))
+        };
+
+        assert!(filter.matches(&marker("capital_increase")));
+        assert!(!filter.matches(&marker("periodic_report")));
+
+        // 공시 유형 메타데이터가 없는 일반 신호는 제외
+        let plain = SignalMarker::new(
+            "BTC/USDT".to_string(),
+            Utc::now(),
+            SignalType::Entry,
+            dec!(50000),
+            "rsi_strategy",
+            "RSI Strategy",
+        );
+        assert!(!filter.matches(&plain));
+    }
 }
@@ -18,26 +18,48 @@
 //! # 거래소 라우팅
 //!
 //! - Mock 거래소: MockExchangeProvider.process_signal() 호출
-//! - 실제 거래소: (향후) KIS/Binance Provider의 주문 API 호출
+//! - 스마트 라우터 거래소 (Upbit, Bithumb): `SmartOrderRouter`로 거래소 간 분할 주문
+//!   (Signal의 고정 수량 필요, 결정/결과는 `order_routing_decisions`에 기록)
+//! - FIX 게이트웨이 (`fix`): `create_order_provider_for_credential`로 로그온한 세션에
+//!   `SignalConverter`로 변환한 시장가 주문 제출 (Signal의 고정 수량 필요)
+//! - 그 외 실제 거래소: (향후) KIS/Binance Provider의 주문 API 호출
+//!
+//! # 다중 레그 신호
+//!
+//! 페어 트레이딩 등 `leg_count`가 지정된 신호는 같은 group_id의 레그가
+//! 모두 도착할 때까지 버퍼링한 뒤 `SignalProcessor::process_signal_group`으로
+//! 함께 처리합니다. 한 레그가 실패하면 이미 체결된 진입 레그를 반대 매매로 되돌립니다.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use chrono::Utc;
+use rust_decimal::Decimal;
 use sqlx::PgPool;
 use tokio::sync::{mpsc, RwLock};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
-use trader_core::Signal;
+use trader_core::{CredentialEncryptor, OrderExecutionProvider, OrderResponse, Signal, SignalType};
 use trader_exchange::provider::MockExchangeProvider;
+use trader_execution::{ConversionConfig, SignalConverter, SignalProcessor, SmartOrderRouter};
 use uuid::Uuid;
 
-use crate::repository::create_mock_provider_concrete;
+use crate::repository::{create_mock_provider_concrete, create_order_provider_for_credential};
 
 /// 거래소별 Provider 캐시.
 ///
 /// credential_id를 키로 사용하여 Provider 인스턴스를 캐싱합니다.
 type ProviderCache = HashMap<Uuid, Arc<RwLock<MockExchangeProvider>>>;
 
+/// 주문 전용 Provider 캐시 (credential_id → 로그온한 FIX 세션 등).
+type OrderProviderCache = HashMap<Uuid, Arc<dyn OrderExecutionProvider>>;
+
+/// 미완성 레그 그룹 보관 시간 (초과 시 폐기).
+const LEG_BUFFER_TTL: Duration = Duration::from_secs(60);
+
 /// Signal 처리 서비스.
 ///
 /// 전략에서 생성된 Signal을 수신하여 해당 거래소로 라우팅합니다.
@@ -48,6 +70,14 @@ pub struct SignalProcessingService {
     db_pool: PgPool,
     /// Provider 캐시 (credential_id → MockExchangeProvider)
     provider_cache: Arc<RwLock<ProviderCache>>,
+    /// 도착 대기 중인 레그 그룹 (group_id → (첫 수신 시각, 레그))
+    pending_legs: HashMap<String, (Instant, Vec<Signal>)>,
+    /// 스마트 주문 라우터 (라우터 거래소에 연결된 전략의 Signal 처리)
+    router: Option<Arc<SmartOrderRouter>>,
+    /// Credential 복호화 관리자 (주문 전용 Provider 생성에 필요)
+    encryptor: Option<Arc<CredentialEncryptor>>,
+    /// 주문 전용 Provider 캐시
+    order_provider_cache: Arc<RwLock<OrderProviderCache>>,
 }
 
 impl SignalProcessingService {
@@ -57,9 +87,27 @@ impl SignalProcessingService {
             signal_rx,
             db_pool,
             provider_cache: Arc::new(RwLock::new(HashMap::new())),
+            pending_legs: HashMap::new(),
+            router: None,
+            encryptor: None,
+            order_provider_cache: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    /// 스마트 주문 라우터 설정.
+    pub fn with_router(mut self, router: Arc<SmartOrderRouter>) -> Self {
+        self.router = Some(router);
+        self
+    }
+
+    /// Credential 복호화 관리자 설정.
+    ///
+    /// 설정하지 않으면 FIX 게이트웨이에 연결된 전략의 Signal은 처리되지 않습니다.
+    pub fn with_encryptor(mut self, encryptor: Arc<CredentialEncryptor>) -> Self {
+        self.encryptor = Some(encryptor);
+        self
+    }
+
     /// 서비스 시작.
     pub async fn run(mut self, shutdown: CancellationToken) {
         info!("SignalProcessingService 시작");
@@ -67,7 +115,18 @@ impl SignalProcessingService {
         loop {
             tokio::select! {
                 Some(signal) = self.signal_rx.recv() => {
-                    if let Err(e) = self.process_signal(&signal).await {
+                    if signal.leg_count().is_some() {
+                        if let Some(legs) = self.buffer_leg(signal) {
+                            if let Err(e) = self.process_signal_group(&legs).await {
+                                error!(
+                                    strategy_id = %legs[0].strategy_id,
+                                    group_id = ?legs[0].group_id,
+                                    error = %e,
+                                    "레그 그룹 처리 실패"
+                                );
+                            }
+                        }
+                    } else if let Err(e) = self.process_signal(&signal).await {
                         error!(
                             strategy_id = %signal.strategy_id,
                             ticker = %signal.ticker,
@@ -89,7 +148,9 @@ impl SignalProcessingService {
     ///
     /// 1. strategy_id에서 credential_id 조회
     /// 2. credential_id에서 exchange_id 확인
-    /// 3. Mock 거래소면 MockExchangeProvider로 체결 처리
+    /// 3. Mock 거래소면 MockExchangeProvider로 체결 처리,
+    ///    라우터 거래소면 SmartOrderRouter로 분할 주문,
+    ///    FIX 게이트웨이면 주문 전용 Provider로 주문 제출
     async fn process_signal(&self, signal: &Signal) -> Result<(), String> {
         debug!(
             strategy_id = %signal.strategy_id,
@@ -119,16 +180,203 @@ impl SignalProcessingService {
         // 3. 거래소별 처리
         match exchange_id.as_str() {
             "mock" => self.process_mock_signal(credential_id, signal).await,
+            "fix" => self.process_order_signal(credential_id, signal).await,
+            id => match self.routed_venue(id) {
+                Some(router) => self.process_routed_signal(router, signal).await,
+                None => {
+                    warn!(
+                        exchange_id = %exchange_id,
+                        "지원하지 않는 거래소 - Signal 무시"
+                    );
+                    Ok(())
+                }
+            },
+        }
+    }
+
+    /// exchange_id가 라우터 거래소면 라우터 반환.
+    fn routed_venue(&self, exchange_id: &str) -> Option<&SmartOrderRouter> {
+        self.router
+            .as_deref()
+            .filter(|router| router.venue_names().contains(&exchange_id))
+    }
+
+    /// 스마트 라우팅 Signal 처리.
+    ///
+    /// 라우터 거래소들의 호가와 수수료를 비교해 Signal 수량을 분할 주문합니다.
+    /// 알림 Signal과 고정 수량이 없는 Signal은 무시합니다.
+    async fn process_routed_signal(
+        &self,
+        router: &SmartOrderRouter,
+        signal: &Signal,
+    ) -> Result<(), String> {
+        if signal.signal_type == SignalType::Alert {
+            return Ok(());
+        }
+        let Some(quantity) = signal.fixed_quantity() else {
+            warn!(
+                strategy_id = %signal.strategy_id,
+                ticker = %signal.ticker,
+                "라우팅 수량 없음 - Signal 무시"
+            );
+            return Ok(());
+        };
+
+        let base_asset = base_asset(&signal.ticker);
+        let record = router
+            .route(base_asset, signal.side, quantity)
+            .await
+            .map_err(|e| format!("스마트 라우팅 실패: {}", e))?;
+
+        for order in &record.orders {
+            match (&order.order_no, &order.error) {
+                (Some(order_no), _) => info!(
+                    plan_id = %record.plan.id,
+                    venue = %order.venue,
+                    symbol = %order.symbol,
+                    quantity = %order.quantity,
+                    limit_price = %order.limit_price,
+                    order_no = %order_no,
+                    "라우팅 주문 제출"
+                ),
+                (None, error) => warn!(
+                    plan_id = %record.plan.id,
+                    venue = %order.venue,
+                    error = ?error,
+                    "라우팅 주문 실패"
+                ),
+            }
+        }
+        if record.plan.unfilled_quantity > Decimal::ZERO {
+            warn!(
+                plan_id = %record.plan.id,
+                unfilled = %record.plan.unfilled_quantity,
+                "라우팅 호가 잔량 부족 - 일부 미배분"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// 주문 전용 거래소(FIX) Signal 처리.
+    async fn process_order_signal(
+        &self,
+        credential_id: Uuid,
+        signal: &Signal,
+    ) -> Result<(), String> {
+        let provider = self.get_or_create_order_provider(credential_id).await?;
+        if let Some(response) = submit_signal_order(provider.as_ref(), signal).await? {
+            info!(
+                strategy_id = %signal.strategy_id,
+                ticker = %signal.ticker,
+                side = ?signal.side,
+                exchange = %provider.exchange_name(),
+                order_no = %response.order_no,
+                "주문 제출 완료"
+            );
+        }
+        Ok(())
+    }
+
+    /// 레그 신호를 버퍼에 추가하고, 그룹이 완성되면 모든 레그를 반환합니다.
+    fn buffer_leg(&mut self, signal: Signal) -> Option<Vec<Signal>> {
+        let now = Instant::now();
+        self.pending_legs.retain(|group_id, (received_at, legs)| {
+            let fresh = now.duration_since(*received_at) < LEG_BUFFER_TTL;
+            if !fresh {
+                warn!(
+                    group_id = %group_id,
+                    received = legs.len(),
+                    "레그 그룹 미완성 - 폐기"
+                );
+            }
+            fresh
+        });
+
+        let expected = signal.leg_count()?;
+        let group_id = signal.group_id.clone()?;
+        let (_, legs) = self
+            .pending_legs
+            .entry(group_id.clone())
+            .or_insert_with(|| (now, Vec::new()));
+        legs.push(signal);
+
+        if legs.len() < expected {
+            return None;
+        }
+        self.pending_legs.remove(&group_id).map(|(_, legs)| legs)
+    }
+
+    /// 레그 그룹 처리.
+    ///
+    /// 첫 레그의 전략으로 계정을 조회하고, 모든 레그를 같은 거래소에서 처리합니다.
+    async fn process_signal_group(&self, legs: &[Signal]) -> Result<(), String> {
+        let Some(first) = legs.first() else {
+            return Ok(());
+        };
+
+        let Some(credential_id) = self.get_credential_id(&first.strategy_id).await? else {
+            debug!(
+                strategy_id = %first.strategy_id,
+                "전략에 연결된 계정 없음 - 레그 그룹 무시"
+            );
+            return Ok(());
+        };
+
+        let exchange_id = self.get_exchange_id(credential_id).await?;
+        match exchange_id.as_str() {
+            "mock" => self.process_mock_signal_group(credential_id, legs).await,
             _ => {
                 warn!(
                     exchange_id = %exchange_id,
-                    "지원하지 않는 거래소 - Signal 무시"
+                    "지원하지 않는 거래소 - 레그 그룹 무시"
                 );
                 Ok(())
             }
         }
     }
 
+    /// Mock 거래소 레그 그룹 처리.
+    ///
+    /// 모든 레그의 현재가를 먼저 확인한 뒤 [`SignalProcessor::process_signal_group`]으로
+    /// 체결합니다 (숏 레그 우선, 미체결 진입 레그는 실패로 보고 이미 체결된 레그를 되돌림).
+    async fn process_mock_signal_group(
+        &self,
+        credential_id: Uuid,
+        legs: &[Signal],
+    ) -> Result<(), String> {
+        let provider = self.get_or_create_provider(credential_id).await?;
+        let provider = provider.read().await;
+
+        // 현재가를 모두 확보한 뒤에만 주문 (한쪽 레그만 체결되는 상황 방지)
+        let mut priced = Vec::with_capacity(legs.len());
+        for leg in legs {
+            let price = provider
+                .get_current_price(&leg.ticker)
+                .await
+                .map_err(|e| format!("현재가 조회 실패 ({}): {:?}", leg.ticker, e))?;
+            priced.push((leg.clone(), price));
+        }
+
+        let mut processor = provider.strategy_processor(&legs[0].strategy_id).await;
+        let trades = processor
+            .process_signal_group(&priced, Utc::now())
+            .await
+            .map_err(|e| format!("레그 그룹 체결 실패: {}", e))?;
+
+        for trade in trades {
+            info!(
+                symbol = %trade.symbol,
+                side = ?trade.side,
+                quantity = %trade.quantity,
+                price = %trade.price,
+                "Mock 레그 체결 완료"
+            );
+        }
+
+        Ok(())
+    }
+
     /// Mock 거래소 Signal 처리.
     async fn process_mock_signal(
         &self,
@@ -240,17 +488,197 @@ impl SignalProcessingService {
 
         Ok(provider)
     }
+
+    /// 주문 전용 Provider 가져오기 또는 생성.
+    ///
+    /// FIX 세션은 생성 시 로그온하므로 credential별로 한 번만 만들어 재사용합니다.
+    async fn get_or_create_order_provider(
+        &self,
+        credential_id: Uuid,
+    ) -> Result<Arc<dyn OrderExecutionProvider>, String> {
+        if let Some(provider) = self.order_provider_cache.read().await.get(&credential_id) {
+            return Ok(Arc::clone(provider));
+        }
+
+        let encryptor = self
+            .encryptor
+            .as_deref()
+            .ok_or("주문 Provider 생성에는 encryptor가 필요합니다.")?;
+        let mut cache = self.order_provider_cache.write().await;
+        if let Some(provider) = cache.get(&credential_id) {
+            return Ok(Arc::clone(provider));
+        }
+        let provider =
+            create_order_provider_for_credential(&self.db_pool, encryptor, credential_id).await?;
+        cache.insert(credential_id, Arc::clone(&provider));
+        Ok(provider)
+    }
+}
+
+/// Signal을 시장가 주문으로 변환해 주문 전용 Provider에 제출.
+///
+/// 알림 Signal과 고정 수량이 없는 Signal은 주문하지 않고 `None`을 반환합니다.
+/// 전략이 이미 신호 강도를 판단했으므로 최소 강도 필터는 적용하지 않습니다.
+async fn submit_signal_order(
+    provider: &dyn OrderExecutionProvider,
+    signal: &Signal,
+) -> Result<Option<OrderResponse>, String> {
+    if signal.signal_type == SignalType::Alert {
+        return Ok(None);
+    }
+    let Some(quantity) = signal.fixed_quantity() else {
+        warn!(
+            strategy_id = %signal.strategy_id,
+            ticker = %signal.ticker,
+            "주문 수량 없음 - Signal 무시"
+        );
+        return Ok(None);
+    };
+
+    let converter = SignalConverter::new(ConversionConfig {
+        min_strength: 0.0,
+        auto_stop_loss: false,
+        auto_take_profit: false,
+        ..ConversionConfig::default()
+    });
+    let price = signal.suggested_price.unwrap_or(Decimal::ZERO);
+    let request = converter
+        .convert(signal, price, Some(quantity))
+        .map_err(|e| format!("주문 변환 실패: {}", e))?;
+
+    provider
+        .place_order(&request)
+        .await
+        .map(Some)
+        .map_err(|e| format!("주문 제출 실패 ({}): {}", provider.exchange_name(), e))
+}
+
+/// 티커에서 기초 자산 추출 (`BTC/KRW`, `KRW-BTC`, `BTCUSDT` → `BTC`).
+fn base_asset(ticker: &str) -> &str {
+    if let Some((base, _)) = ticker.split_once('/') {
+        base
+    } else if let Some((_, base)) = ticker.split_once('-') {
+        base
+    } else {
+        ticker
+            .strip_suffix("USDT")
+            .filter(|base| !base.is_empty())
+            .unwrap_or(ticker)
+    }
 }
 
 /// SignalProcessingService 시작 헬퍼 함수.
 pub fn start_signal_processing_service(
     signal_rx: mpsc::Receiver<Signal>,
     db_pool: PgPool,
+    router: Option<Arc<SmartOrderRouter>>,
+    encryptor: Option<Arc<CredentialEncryptor>>,
     shutdown: CancellationToken,
 ) -> tokio::task::JoinHandle<()> {
-    let service = SignalProcessingService::new(signal_rx, db_pool);
+    let mut service = SignalProcessingService::new(signal_rx, db_pool);
+    if let Some(router) = router {
+        service = service.with_router(router);
+    }
+    if let Some(encryptor) = encryptor {
+        service = service.with_encryptor(encryptor);
+    }
 
     tokio::spawn(async move {
         service.run(shutdown).await;
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use trader_core::{OrderRequest, OrderType, ProviderError, Side};
+
+    use super::*;
+
+    /// 제출된 주문을 기록하는 테스트용 주문 제공자.
+    #[derive(Default)]
+    struct RecordingOrderProvider {
+        orders: Mutex<Vec<OrderRequest>>,
+    }
+
+    #[async_trait]
+    impl OrderExecutionProvider for RecordingOrderProvider {
+        async fn place_order(
+            &self,
+            request: &OrderRequest,
+        ) -> Result<OrderResponse, ProviderError> {
+            self.orders.lock().unwrap().push(request.clone());
+            Ok(OrderResponse {
+                order_no: "FIX_001".to_string(),
+                order_time: "090000".to_string(),
+            })
+        }
+
+        async fn cancel_order(&self, _order_id: &str, _ticker: &str) -> Result<(), ProviderError> {
+            Ok(())
+        }
+
+        async fn modify_order(
+            &self,
+            _order_id: &str,
+            _ticker: &str,
+            _quantity: Option<Decimal>,
+            _price: Option<Decimal>,
+        ) -> Result<OrderResponse, ProviderError> {
+            Err(ProviderError::Unsupported("modify".to_string()))
+        }
+
+        fn exchange_name(&self) -> &str {
+            "fix"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_signal_order_places_market_order() {
+        let provider = RecordingOrderProvider::default();
+        let signal = Signal::entry("rsi_test", "AAPL".to_string(), Side::Buy)
+            .with_strength(0.2)
+            .with_fixed_quantity(dec!(10));
+
+        let response = submit_signal_order(&provider, &signal).await.unwrap();
+        assert_eq!(response.unwrap().order_no, "FIX_001");
+
+        let orders = provider.orders.lock().unwrap();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].ticker, "AAPL");
+        assert_eq!(orders[0].side, Side::Buy);
+        assert_eq!(orders[0].order_type, OrderType::Market);
+        assert_eq!(orders[0].quantity, dec!(10));
+        assert_eq!(orders[0].strategy_id.as_deref(), Some("rsi_test"));
+    }
+
+    #[tokio::test]
+    async fn test_submit_signal_order_skips_without_quantity() {
+        let provider = RecordingOrderProvider::default();
+        let signal = Signal::exit("rsi_test", "AAPL".to_string(), Side::Sell);
+        let alert = Signal::new("rsi_test", "AAPL".to_string(), Side::Buy, SignalType::Alert)
+            .with_fixed_quantity(dec!(10));
+
+        assert!(submit_signal_order(&provider, &signal)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(submit_signal_order(&provider, &alert)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(provider.orders.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_base_asset_from_ticker() {
+        assert_eq!(base_asset("BTC/KRW"), "BTC");
+        assert_eq!(base_asset("KRW-ETH"), "ETH");
+        assert_eq!(base_asset("XRP"), "XRP");
+        assert_eq!(base_asset("BTCUSDT"), "BTC");
+        assert_eq!(base_asset("USDT"), "USDT");
+    }
+}
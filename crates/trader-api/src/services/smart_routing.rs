@@ -0,0 +1,116 @@
+//! 스마트 주문 라우팅 설정.
+//!
+//! 원화 마켓 거래소(Upbit, Bithumb)와 USDT 마켓 거래소(Binance)의 활성 credential로
+//! [`SmartOrderRouter`]를 구성합니다. USDT 호가는 원화 거래소의 `KRW-USDT` 현재가로
+//! 계획마다 원화 환산합니다.
+//! 라우터 거래소에 연결된 전략의 Signal은 `SignalProcessingService`가 라우터로 분할 주문하고,
+//! 라우팅 결정과 주문 결과는 `order_routing_decisions` 테이블에 기록됩니다.
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use tracing::{info, warn};
+use trader_core::CredentialEncryptor;
+use trader_execution::{QuoteConverter, RouterConfig, SmartOrderRouter};
+
+use crate::repository::{create_router_venue, OrderRoutingRepository};
+
+/// 스마트 주문 라우팅 설정.
+#[derive(Debug, Clone)]
+pub struct SmartRouterConfig {
+    /// 라우팅 대상 거래소 (exchange_id)
+    pub venues: Vec<String>,
+    /// 거래소별 최소 배분 수량
+    pub min_allocation_quantity: Decimal,
+}
+
+impl Default for SmartRouterConfig {
+    fn default() -> Self {
+        Self {
+            venues: vec!["upbit".to_string(), "bithumb".to_string()],
+            min_allocation_quantity: Decimal::ZERO,
+        }
+    }
+}
+
+impl SmartRouterConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `SMART_ROUTER_ENABLED=true`가 아니면 `None`을 반환합니다.
+    ///
+    /// # 환경변수
+    ///
+    /// - `SMART_ROUTER_VENUES`: 라우팅 거래소 (쉼표 구분, 기본: upbit,bithumb, 지원: upbit,bithumb,binance)
+    /// - `SMART_ROUTER_MIN_ALLOCATION`: 거래소별 최소 배분 수량 (기본: 0)
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("SMART_ROUTER_ENABLED")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+        if !enabled {
+            return None;
+        }
+
+        let mut config = Self::default();
+        if let Ok(venues) = std::env::var("SMART_ROUTER_VENUES") {
+            config.venues = venues
+                .split(',')
+                .map(|v| v.trim().to_lowercase())
+                .filter(|v| !v.is_empty())
+                .collect();
+        }
+        if let Some(min) = std::env::var("SMART_ROUTER_MIN_ALLOCATION")
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+        {
+            config.min_allocation_quantity = min;
+        }
+
+        Some(config)
+    }
+}
+
+/// 스마트 주문 라우터 생성.
+///
+/// 거래소를 하나도 구성하지 못하면 `None`을 반환합니다.
+pub async fn build_smart_router(
+    pool: &PgPool,
+    encryptor: Option<&CredentialEncryptor>,
+    config: &SmartRouterConfig,
+) -> Option<SmartOrderRouter> {
+    let router_config = RouterConfig {
+        min_allocation_quantity: config.min_allocation_quantity,
+        ..RouterConfig::default()
+    };
+    let mut venues = Vec::new();
+    for exchange_id in &config.venues {
+        match create_router_venue(pool, encryptor, exchange_id).await {
+            Ok(venue) => venues.push(venue),
+            Err(e) => warn!("스마트 라우팅 거래소 {} 생성 실패: {}", exchange_id, e),
+        }
+    }
+
+    if venues.is_empty() {
+        return None;
+    }
+
+    let mut router = SmartOrderRouter::new(router_config, QuoteConverter::new("KRW"))
+        .with_recorder(Arc::new(OrderRoutingRepository::new(pool.clone())));
+
+    // USDT 거래소가 있으면 원화 거래소의 KRW-USDT 현재가를 환산율 소스로 사용
+    if venues.iter().any(|v| v.config.quote_currency == "USDT") {
+        match venues.iter().find(|v| v.config.quote_currency == "KRW") {
+            Some(krw_venue) => {
+                router = router.with_rate_source("USDT", krw_venue.market_data(), "KRW-USDT");
+            }
+            None => warn!("USDT 환산율 소스(원화 거래소)가 없어 USDT 거래소는 배분에서 제외됩니다"),
+        }
+    }
+
+    for venue in venues {
+        router = router.with_venue(venue);
+    }
+
+    info!(venues = ?router.venue_names(), "스마트 주문 라우터 구성");
+    Some(router)
+}
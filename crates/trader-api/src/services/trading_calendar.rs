@@ -0,0 +1,85 @@
+//! KIS 휴장일 기반 거래 캘린더 로드.
+//!
+//! 라이브 전략 스케줄러와 백테스트(API, CLI)가 같은 국내/미국 휴장일 캘린더를
+//! 사용하도록 활성 KIS credential로 휴장일을 조회합니다.
+
+use std::sync::Arc;
+
+use chrono::{Datelike, NaiveDate};
+use sqlx::PgPool;
+use tracing::{info, warn};
+use trader_core::{crypto::CredentialEncryptor, Country, TradingCalendar};
+use trader_exchange::connector::kis::HolidayChecker;
+
+use crate::repository::{create_kis_client_from_credential, find_active_credential_by_exchange};
+
+/// KIS 휴장일 API로 국내/미국 거래 캘린더 조회.
+///
+/// `from`이 속한 달부터 `months`개월치 휴장일을 불러옵니다.
+/// KIS credential이 없거나 조회에 실패한 국가는 제외되며, 호출자는
+/// 해당 국가의 기본 캘린더(주말만 휴장)를 그대로 사용합니다.
+pub async fn load_trading_calendars(
+    pool: &PgPool,
+    encryptor: &CredentialEncryptor,
+    from: NaiveDate,
+    months: u32,
+) -> Vec<TradingCalendar> {
+    let credential_id = match find_active_credential_by_exchange(pool, "kis").await {
+        Ok(Some(id)) => id,
+        Ok(None) => return Vec::new(),
+        Err(e) => {
+            warn!(error = %e, "휴장일 캘린더용 KIS 자격증명 조회 실패");
+            return Vec::new();
+        }
+    };
+    let checker = match create_kis_client_from_credential(pool, encryptor, credential_id)
+        .await
+        .and_then(|client| {
+            HolidayChecker::with_shared_oauth(Arc::clone(client.oauth())).map_err(|e| e.to_string())
+        }) {
+        Ok(checker) => checker,
+        Err(e) => {
+            warn!(error = %e, "휴장일 확인기 생성 실패");
+            return Vec::new();
+        }
+    };
+
+    let mut calendars = Vec::new();
+    for country in [Country::KR, Country::US] {
+        match checker.trading_calendar(country, from, months).await {
+            Ok(calendar) => {
+                info!(
+                    country = %country,
+                    holidays = calendar.holidays.len(),
+                    "거래 캘린더 로드"
+                );
+                calendars.push(calendar);
+            }
+            Err(e) => warn!(country = %country, error = %e, "휴장일 조회 실패"),
+        }
+    }
+    calendars
+}
+
+/// `from`이 속한 달부터 `to`가 속한 달까지의 개월 수 (최소 1).
+pub fn months_spanned(from: NaiveDate, to: NaiveDate) -> u32 {
+    let months = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32 + 1;
+    months.max(1) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_months_spanned() {
+        assert_eq!(months_spanned(date(2024, 1, 15), date(2024, 1, 20)), 1);
+        assert_eq!(months_spanned(date(2024, 1, 31), date(2024, 2, 1)), 2);
+        assert_eq!(months_spanned(date(2023, 11, 1), date(2024, 2, 29)), 4);
+        assert_eq!(months_spanned(date(2024, 3, 1), date(2024, 1, 1)), 1);
+    }
+}
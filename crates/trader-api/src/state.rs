@@ -7,22 +7,31 @@ use std::{collections::HashMap, sync::Arc};
 
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
-use trader_analytics::{ml::MlService, AnalyticsProviderImpl};
+use trader_analytics::{
+    backtest::BacktestScreeningProvider, ml::MlService, AnalyticsProviderImpl, IndicatorStateCache,
+};
 use trader_core::{
     crypto::CredentialEncryptor, AnalyticsProvider, ExchangeProvider, MarketDataProvider,
-    StrategyContext,
+    StrategyContext, TradingCalendar,
+};
+use trader_data::{
+    cache::CachedHistoricalDataProvider, Database, FxRateRepository, IndicatorStateRepository,
+    LiveBarStore, RedisCache, RedisConfig, SymbolResolver,
 };
-use trader_data::{cache::CachedHistoricalDataProvider, RedisCache, RedisConfig, SymbolResolver};
 use trader_exchange::{connector::kis::KisOAuth, provider::MockExchangeProvider};
-use trader_execution::OrderExecutor;
+use trader_execution::{OrderExecutor, SmartOrderRouter};
 use trader_notification::NotificationManager;
 use trader_risk::RiskManager;
-use trader_strategy::{SignalConflictEvent, StrategyEngine};
+use trader_strategy::{SignalConflictEvent, SnapshotReason, StrategyEngine};
 use uuid::Uuid;
 
 use crate::{
     repository::ExchangeProviderArc,
-    services::{context_sync::start_context_sync_service, MarketStreamHandle},
+    services::{
+        context_sync::start_context_sync_service, load_screening_provider, load_trading_calendars,
+        months_spanned, DisclosureAlertConfig, FxRateService, KimchiPremiumConfig,
+        KimchiPremiumMonitor, LiveBarConfig, LiveBarService, MarketStreamHandle,
+    },
     websocket::{ServerMessage, SharedSubscriptionManager},
 };
 
@@ -123,6 +132,38 @@ pub struct AppState {
     /// 동일 계좌의 여러 전략이 하나의 WebSocket 스트림을 공유합니다.
     /// `get_or_create_market_stream()`으로 생성/조회합니다.
     pub market_streams: Arc<RwLock<HashMap<Uuid, Arc<MarketStreamHandle>>>>,
+
+    /// 김치 프리미엄 모니터 설정 (KIMCHI_PREMIUM_ENABLED 시에만 Some).
+    pub kimchi_premium_config: Option<KimchiPremiumConfig>,
+
+    /// 김치 프리미엄 모니터 (최신값 및 이력).
+    ///
+    /// 백그라운드 서비스가 갱신하고 `/api/v1/market/premium`에서 조회합니다.
+    pub kimchi_premium: Option<Arc<RwLock<KimchiPremiumMonitor>>>,
+
+    /// 실시간 바 집계 서비스 (LIVE_BARS_ENABLED 시에만 Some).
+    ///
+    /// MarketStream 체결/시세를 캔들로 집계하여 전략 엔진과 DB로 전달합니다.
+    pub live_bars: Option<Arc<LiveBarService>>,
+
+    /// 스마트 주문 라우터 (SMART_ROUTER_ENABLED 시에만 Some).
+    ///
+    /// 라우터 거래소에 연결된 전략의 Signal을 거래소별 호가/수수료에 따라 분할 주문합니다.
+    pub smart_router: Option<Arc<SmartOrderRouter>>,
+
+    /// DART 공시 알림 설정 (DISCLOSURE_ALERT_ENABLED 시에만 Some).
+    pub disclosure_alert_config: Option<DisclosureAlertConfig>,
+
+    /// 증분 지표 상태 캐시.
+    ///
+    /// 전략 엔진이 캔들마다 갱신하고, `/api/v1/analytics/indicators/state`에서 조회합니다.
+    /// DB가 있으면 시작 시 `indicator_state` 테이블에서 복원하고 주기적으로 저장합니다.
+    pub indicator_states: Arc<IndicatorStateCache>,
+
+    /// 환율 서비스.
+    ///
+    /// 포트폴리오 요약, 자산 곡선, 매매일지 손익을 기준 통화(`BASE_CURRENCY`)로 환산합니다.
+    pub fx_rates: Arc<FxRateService>,
 }
 
 impl AppState {
@@ -168,6 +209,13 @@ impl AppState {
             notification_manager: None,
             mock_providers: Arc::new(RwLock::new(HashMap::new())),
             market_streams: Arc::new(RwLock::new(HashMap::new())),
+            kimchi_premium_config: None,
+            kimchi_premium: None,
+            live_bars: None,
+            smart_router: None,
+            disclosure_alert_config: None,
+            indicator_states: Arc::new(IndicatorStateCache::default()),
+            fx_rates: Arc::new(FxRateService::from_env()),
         }
     }
 
@@ -182,6 +230,37 @@ impl AppState {
         self.encryptor.is_some()
     }
 
+    /// 백테스트 기간의 국내/미국 거래 캘린더 조회.
+    ///
+    /// 라이브 스케줄러와 같은 KIS 휴장일 캘린더를 사용합니다.
+    /// DB나 암호화 관리자가 없으면 빈 목록을 반환합니다.
+    pub async fn load_trading_calendars(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Vec<TradingCalendar> {
+        match (&self.db_pool, &self.encryptor) {
+            (Some(pool), Some(encryptor)) => {
+                load_trading_calendars(pool, encryptor, from, months_spanned(from, to)).await
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// 백테스트용 스크리닝 Provider 조회.
+    ///
+    /// 스크리닝 기반 전략이면 `symbol_fundamental_history`의 시점 이력을 적용한
+    /// Provider를, 그 외 전략이면 None을 반환합니다. DB가 없으면 기술적 지표만 사용합니다.
+    pub async fn load_screening_provider(
+        &self,
+        strategy_id: &str,
+        symbols: &[String],
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Option<BacktestScreeningProvider> {
+        load_screening_provider(self.db_pool.as_ref(), strategy_id, symbols, from, to).await
+    }
+
     /// 데이터베이스 연결 설정.
     ///
     /// DB 연결이 설정되면 SymbolResolver도 자동으로 생성됩니다.
@@ -283,7 +362,7 @@ impl AppState {
     /// # Returns
     ///
     /// 백그라운드 태스크의 JoinHandle. None이면 필요한 provider가 설정되지 않은 것입니다.
-    pub fn start_context_sync(
+    pub async fn start_context_sync(
         &self,
         shutdown: CancellationToken,
     ) -> Option<tokio::task::JoinHandle<()>> {
@@ -291,10 +370,18 @@ impl AppState {
         let analytics_provider = self.analytics_provider.clone()?;
         let strategy_context = self.strategy_context.clone()?;
 
+        // 경제 캘린더(이벤트 블랙아웃)는 주문 검증에 쓰이는 executor의 리스크 관리자에도 전달
+        let risk_managers = vec![
+            self.risk_manager.clone(),
+            self.executor.read().await.risk_manager().clone(),
+        ];
+
         Some(start_context_sync_service(
             exchange_provider,
             analytics_provider,
             strategy_context,
+            self.db_pool.clone(),
+            risk_managers,
             shutdown,
         ))
     }
@@ -325,10 +412,165 @@ impl AppState {
         tracing::info!("SignalProcessingService 시작");
 
         Some(crate::services::start_signal_processing_service(
-            signal_rx, db_pool, shutdown,
+            signal_rx,
+            db_pool,
+            self.smart_router.clone(),
+            self.encryptor.clone(),
+            shutdown,
+        ))
+    }
+
+    /// 스마트 주문 라우터 설정.
+    ///
+    /// `start_signal_processing` 이전에 호출해야 라우팅이 적용됩니다.
+    pub fn with_smart_router(mut self, router: SmartOrderRouter) -> Self {
+        self.smart_router = Some(Arc::new(router));
+        self
+    }
+
+    /// 김치 프리미엄 모니터 설정.
+    pub fn with_kimchi_premium(mut self, config: KimchiPremiumConfig) -> Self {
+        self.kimchi_premium = Some(Arc::new(RwLock::new(KimchiPremiumMonitor::new(&config))));
+        self.kimchi_premium_config = Some(config);
+        self
+    }
+
+    /// 김치 프리미엄 모니터링 서비스 시작.
+    ///
+    /// 업비트/빗썸/바이낸스 시세를 구독하고, 임계값 돌파 시 활성화된
+    /// 신호 알림 규칙(`signal_alert_rule`)에 따라 알림을 전송합니다.
+    /// DB가 있으면 프리미엄 이력을 `kimchi_premium_history`에 저장합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 모니터가 설정되지 않은 것입니다.
+    pub fn start_kimchi_premium(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let config = self.kimchi_premium_config.clone()?;
+        let monitor = self.kimchi_premium.clone()?;
+        let dispatcher = crate::services::PremiumAlertDispatcher::new(
+            self.db_pool
+                .clone()
+                .map(crate::repository::SignalAlertRuleRepository::new),
+            self.notification_manager.clone(),
+        );
+
+        Some(crate::services::start_kimchi_premium_service(
+            config,
+            monitor,
+            self.cache.clone(),
+            self.db_pool
+                .clone()
+                .map(crate::repository::KimchiPremiumRepository::new),
+            dispatcher,
+            shutdown,
+        ))
+    }
+
+    /// DART 공시 알림 설정.
+    pub fn with_disclosure_alert(mut self, config: DisclosureAlertConfig) -> Self {
+        self.disclosure_alert_config = Some(config);
+        self
+    }
+
+    /// DART 공시 알림 서비스 시작.
+    ///
+    /// 수집기가 저장한 주요사항 공시를 주기적으로 조회하여 활성화된
+    /// 신호 알림 규칙(`signal_alert_rule`)에 따라 알림을 전송합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 설정, DB 또는 NotificationManager가 없는 것입니다.
+    pub fn start_disclosure_alert(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let config = self.disclosure_alert_config.clone()?;
+        let db_pool = self.db_pool.clone()?;
+        let Some(manager) = self.notification_manager.clone() else {
+            tracing::warn!("NotificationManager가 없어 DART 공시 알림을 시작하지 않습니다");
+            return None;
+        };
+
+        let service = crate::services::DisclosureAlertService::new(
+            config,
+            trader_data::DisclosureRepository::new(db_pool.clone()),
+            crate::repository::SignalAlertRuleRepository::new(db_pool),
+            manager,
+        );
+
+        Some(crate::services::start_disclosure_alert_service(
+            service, shutdown,
         ))
     }
 
+    /// 환율 서비스 설정.
+    ///
+    /// DB와 Redis 캐시가 있으면 `fx_rates` 테이블과 매크로 데이터를 환율 출처로 사용합니다.
+    /// `with_db_pool`, `with_cache` 이후에 호출해야 합니다.
+    pub fn with_fx_rates(mut self) -> Self {
+        let mut service = FxRateService::from_env();
+        if let Some(pool) = self.db_pool.clone() {
+            service = service.with_repository(FxRateRepository::new(pool));
+        }
+        if let Some(cache) = self.cache.clone() {
+            service = service.with_cache(cache);
+        }
+        self.fx_rates = Arc::new(service);
+        self
+    }
+
+    /// 환율 갱신 서비스 시작.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 DB와 Redis 캐시가 모두 없는 것입니다.
+    pub fn start_fx_rate_refresh(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        if self.db_pool.is_none() && self.cache.is_none() {
+            return None;
+        }
+        Some(Arc::clone(&self.fx_rates).start(shutdown))
+    }
+
+    /// 실시간 바 집계 서비스 설정.
+    ///
+    /// DB 연결이 있고 `persist`가 켜져 있으면 체결 틱과 마감된 캔들을 저장합니다.
+    /// `with_db_pool` 이후에 호출해야 합니다.
+    pub fn with_live_bars(mut self, config: LiveBarConfig) -> Self {
+        let store = if config.persist {
+            self.db_pool
+                .clone()
+                .map(|pool| LiveBarStore::new(Database::from_pool(pool)))
+        } else {
+            None
+        };
+
+        let mut service = LiveBarService::new(config, Arc::clone(&self.strategy_engine));
+        if let Some(store) = store {
+            service = service.with_store(store);
+        }
+        self.live_bars = Some(Arc::new(service));
+        self
+    }
+
+    /// 실시간 바 flush 서비스 시작.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 실시간 바가 설정되지 않은 것입니다.
+    pub fn start_live_bars(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let service = self.live_bars.clone()?;
+        Some(service.start(shutdown))
+    }
+
     /// Signal 충돌 브로드캐스트 서비스 시작.
     ///
     /// StrategyEngine에서 발생한 SignalConflictEvent를 WebSocket으로 브로드캐스트합니다.
@@ -396,6 +638,130 @@ impl AppState {
         }
     }
 
+    /// 전략 상태 체크포인트 서비스 시작.
+    ///
+    /// 엔진 설정의 간격마다 실행 중인 전략의 내부 상태를 스냅샷으로 저장합니다.
+    /// 종료 시 마지막 저장은 `StrategyEngine::shutdown()`이 담당합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 상태 저장소가 없거나 주기가 비활성화되었습니다.
+    pub async fn start_strategy_checkpoint(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let period = {
+            let engine = self.strategy_engine.read().await;
+            if !engine.has_state_store() {
+                return None;
+            }
+            engine.checkpoint_interval()?
+        };
+        let engine = Arc::clone(&self.strategy_engine);
+
+        Some(tokio::spawn(async move {
+            let mut timer = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            loop {
+                tokio::select! {
+                    _ = timer.tick() => {
+                        engine.read().await.checkpoint_all(SnapshotReason::Periodic).await;
+                    }
+                    _ = shutdown.cancelled() => {
+                        tracing::info!("StrategyCheckpointService 종료");
+                        break;
+                    }
+                }
+            }
+        }))
+    }
+
+    /// 증분 지표 상태 저장 서비스 시작.
+    ///
+    /// `INDICATOR_STATE_PERSIST_SECS`(기본 300초, 0이면 비활성화)마다 변경된 지표 상태를
+    /// `indicator_state` 테이블에 저장하고, 종료 시 마지막으로 한 번 더 저장합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 DB가 없거나 주기가 비활성화되었습니다.
+    pub fn start_indicator_state_persist(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let secs = std::env::var("INDICATOR_STATE_PERSIST_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(300);
+        if secs == 0 {
+            return None;
+        }
+        let repo = IndicatorStateRepository::new(self.db_pool.clone()?);
+        let cache = Arc::clone(&self.indicator_states);
+        let period = std::time::Duration::from_secs(secs);
+
+        Some(tokio::spawn(async move {
+            let mut timer = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            loop {
+                let stopping = tokio::select! {
+                    _ = timer.tick() => false,
+                    _ = shutdown.cancelled() => true,
+                };
+                match cache.persist(&repo).await {
+                    Ok(0) => {}
+                    Ok(saved) => tracing::debug!(saved, "증분 지표 상태 저장"),
+                    Err(e) => tracing::warn!(error = %e, "증분 지표 상태 저장 실패"),
+                }
+                if stopping {
+                    tracing::info!("IndicatorStatePersistService 종료");
+                    break;
+                }
+            }
+        }))
+    }
+
+    /// 전략 일정 이벤트 서비스 시작.
+    ///
+    /// 엔진 설정의 간격마다 `process_schedules()`를 호출하여 장 개장/마감, 월초/월말,
+    /// cron 일정 이벤트를 전략에 전달합니다. DB와 KIS 자격증명이 있으면
+    /// 하루 한 번 국내/미국 휴장일을 조회하여 엔진 캘린더를 갱신합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 일정 간격이 비활성화되었습니다.
+    pub async fn start_strategy_scheduler(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let period = self.strategy_engine.read().await.schedule_interval()?;
+        let engine = Arc::clone(&self.strategy_engine);
+        let db_pool = self.db_pool.clone();
+        let encryptor = self.encryptor.clone();
+
+        Some(tokio::spawn(async move {
+            let mut timer = tokio::time::interval(period);
+            let mut calendar_timer =
+                tokio::time::interval(std::time::Duration::from_secs(CALENDAR_REFRESH_SECS));
+            loop {
+                tokio::select! {
+                    _ = calendar_timer.tick() => {
+                        if let (Some(pool), Some(encryptor)) = (&db_pool, &encryptor) {
+                            refresh_trading_calendars(&engine, pool, encryptor).await;
+                        }
+                    }
+                    _ = timer.tick() => {
+                        let engine = engine.read().await;
+                        if let Err(e) = engine.process_schedules(chrono::Utc::now()).await {
+                            tracing::warn!(error = %e, "전략 일정 이벤트 처리 실패");
+                        }
+                    }
+                    _ = shutdown.cancelled() => {
+                        tracing::info!("StrategySchedulerService 종료");
+                        break;
+                    }
+                }
+            }
+        }))
+    }
+
     /// Redis 캐시 설정.
     ///
     /// trader-data의 RedisCache를 사용하여 API 응답 캐싱을 활성화합니다.
@@ -660,6 +1026,24 @@ impl AppState {
     }
 }
 
+/// 휴장일 캘린더 갱신 주기(초).
+const CALENDAR_REFRESH_SECS: u64 = 24 * 60 * 60;
+
+/// KIS 휴장일 API로 엔진의 국내/미국 거래 캘린더 갱신.
+///
+/// 월초/월말 판정을 위해 지난달부터 3개월치 휴장일을 불러옵니다.
+/// 실패하면 기존 캘린더(또는 휴장일 없는 기본 캘린더)를 그대로 사용합니다.
+async fn refresh_trading_calendars(
+    engine: &RwLock<StrategyEngine>,
+    pool: &sqlx::PgPool,
+    encryptor: &CredentialEncryptor,
+) {
+    let from = (chrono::Utc::now() - chrono::Duration::days(31)).date_naive();
+    for calendar in load_trading_calendars(pool, encryptor, from, 3).await {
+        engine.read().await.set_calendar(calendar).await;
+    }
+}
+
 /// 테스트용 AppState 생성 헬퍼.
 ///
 /// 실제 DB 연결 없이 테스트할 수 있는 최소한의 상태를 생성합니다.
@@ -27,8 +27,12 @@ use serde::Deserialize;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 use trader_analytics::backtest::{BacktestConfig, BacktestEngine, BacktestReport};
-use trader_core::{Kline, StrategyContext, Timeframe};
-use trader_data::{Database, DatabaseConfig, OhlcvCache};
+use trader_core::{
+    crypto::CredentialEncryptor, Kline, StrategyContext, Timeframe, TradingCalendar,
+};
+use trader_data::{
+    Database, DatabaseConfig, OhlcvCache, OhlcvQualityReport, OhlcvQualityRepository,
+};
 use trader_strategy::{
     strategies::{
         AssetAllocationStrategy, CompoundMomentumStrategy, DayTradingStrategy, DcaStrategy,
@@ -284,6 +288,38 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
         );
     }
 
+    // 6. 데이터 품질 리포트 조회 (collector check-quality 결과, 없으면 경고 생략)
+    let mut quality_symbols = vec![used_symbol.clone()];
+    quality_symbols.extend(
+        multi_asset_klines
+            .keys()
+            .filter(|symbol| **symbol != used_symbol)
+            .cloned(),
+    );
+    let quality_reports = match OhlcvQualityRepository::new(db.pool().clone())
+        .get_many(&quality_symbols, default_tf)
+        .await
+    {
+        Ok(reports) => reports,
+        Err(e) => {
+            warn!("데이터 품질 리포트 조회 실패 (무시): {}", e);
+            Vec::new()
+        }
+    };
+
+    // 라이브와 같은 KIS 휴장일 캘린더 (일정 이벤트 거래일 판정)
+    let calendars = match (klines.first(), klines.last()) {
+        (Some(first), Some(last)) => {
+            load_trading_calendars(
+                db.pool(),
+                first.open_time.date_naive(),
+                last.open_time.date_naive(),
+            )
+            .await
+        }
+        _ => Vec::new(),
+    };
+
     // 7. 백테스트 엔진 설정
     // 전략별 max_positions 추출
     let max_positions = extract_max_positions(&strategy_type, &strategy_config.parameters);
@@ -339,6 +375,8 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
             &multi_asset_klines,
             &strategy_config.parameters,
             config.initial_capital,
+            quality_reports,
+            calendars,
         )
         .await?
     } else {
@@ -347,6 +385,8 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
             backtest_config,
             &klines,
             &strategy_config.parameters,
+            quality_reports,
+            calendars,
         )
         .await?
     };
@@ -562,12 +602,53 @@ fn inject_variant(params: &serde_json::Value, variant: &str) -> serde_json::Valu
     params
 }
 
+/// KIS 휴장일 API로 백테스트 기간의 국내/미국 거래 캘린더 조회.
+///
+/// 라이브 스케줄러와 같은 캘린더를 사용합니다. `ENCRYPTION_MASTER_KEY`나
+/// KIS 자격증명이 없으면 빈 목록(주말만 휴장하는 기본 캘린더)을 반환합니다.
+pub(crate) async fn load_trading_calendars(
+    pool: &sqlx::PgPool,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Vec<TradingCalendar> {
+    let Some(encryptor) = std::env::var("ENCRYPTION_MASTER_KEY")
+        .ok()
+        .and_then(|key| CredentialEncryptor::new(&key).ok())
+    else {
+        warn!("ENCRYPTION_MASTER_KEY 없음: 휴장일 캘린더 없이 백테스트합니다");
+        return Vec::new();
+    };
+    let calendars = trader_api::services::load_trading_calendars(
+        pool,
+        &encryptor,
+        from,
+        trader_api::services::months_spanned(from, to),
+    )
+    .await;
+    info!("휴장일 캘린더 적용: {} 개 시장", calendars.len());
+    calendars
+}
+
+/// 데이터 품질 리포트와 거래 캘린더를 적용한 백테스트 엔진 생성
+fn new_engine(
+    backtest_config: BacktestConfig,
+    quality_reports: Vec<OhlcvQualityReport>,
+    calendars: Vec<TradingCalendar>,
+) -> BacktestEngine {
+    calendars.into_iter().fold(
+        BacktestEngine::new(backtest_config).with_data_quality_reports(quality_reports),
+        BacktestEngine::with_calendar,
+    )
+}
+
 /// 전략별 백테스트 실행 (제네릭 문제 해결을 위한 매크로 대신 개별 함수)
 async fn run_strategy_backtest(
     strategy_type: StrategyType,
     backtest_config: BacktestConfig,
     klines: &[Kline],
     params: &serde_json::Value,
+    quality_reports: Vec<OhlcvQualityReport>,
+    calendars: Vec<TradingCalendar>,
 ) -> Result<BacktestReport> {
     // StrategyContext 기반 전략은 run() 사용
     let ticker = params
@@ -598,7 +679,7 @@ async fn run_strategy_backtest(
                 .await
                 .map_err(|e| anyhow!("Failed to initialize strategy: {}", e))?;
             strategy.set_context(context.clone());
-            let mut engine = BacktestEngine::new(backtest_config);
+            let mut engine = new_engine(backtest_config, quality_reports, calendars);
             engine
                 .run(&mut strategy, klines, context, ticker, None)
                 .await
@@ -612,7 +693,7 @@ async fn run_strategy_backtest(
                 .map_err(|e| anyhow!("Failed to initialize strategy: {}", e))?;
             // RSI 전략은 StrategyContext 필요 (StructuralFeatures에서 RSI 가져옴)
             strategy.set_context(context.clone());
-            let mut engine = BacktestEngine::new(backtest_config);
+            let mut engine = new_engine(backtest_config, quality_reports, calendars);
             engine
                 .run(&mut strategy, klines, context, ticker, None)
                 .await
@@ -626,7 +707,7 @@ async fn run_strategy_backtest(
                 .map_err(|e| anyhow!("Failed to initialize strategy: {}", e))?;
             // Bollinger 전략도 StrategyContext 필요
             strategy.set_context(context.clone());
-            let mut engine = BacktestEngine::new(backtest_config);
+            let mut engine = new_engine(backtest_config, quality_reports, calendars);
             engine
                 .run(&mut strategy, klines, context, ticker, None)
                 .await
@@ -640,7 +721,7 @@ async fn run_strategy_backtest(
                 .map_err(|e| anyhow!("Failed to initialize strategy: {}", e))?;
             // 변동성 돌파 전략도 StrategyContext 필요
             strategy.set_context(context.clone());
-            let mut engine = BacktestEngine::new(backtest_config);
+            let mut engine = new_engine(backtest_config, quality_reports, calendars);
             engine
                 .run(&mut strategy, klines, context, ticker, None)
                 .await
@@ -653,7 +734,7 @@ async fn run_strategy_backtest(
                 .await
                 .map_err(|e| anyhow!("Failed to initialize strategy: {}", e))?;
             strategy.set_context(context.clone());
-            let mut engine = BacktestEngine::new(backtest_config);
+            let mut engine = new_engine(backtest_config, quality_reports, calendars);
             engine
                 .run(&mut strategy, klines, context, ticker, None)
                 .await
@@ -666,7 +747,7 @@ async fn run_strategy_backtest(
                 .await
                 .map_err(|e| anyhow!("Failed to initialize strategy: {}", e))?;
             strategy.set_context(context.clone());
-            let mut engine = BacktestEngine::new(backtest_config);
+            let mut engine = new_engine(backtest_config, quality_reports, calendars);
             engine
                 .run(&mut strategy, klines, context, ticker, None)
                 .await
@@ -689,6 +770,8 @@ async fn run_multi_asset_backtest(
     multi_asset_klines: &HashMap<String, Vec<Kline>>,
     params: &serde_json::Value,
     initial_capital: Decimal,
+    quality_reports: Vec<OhlcvQualityReport>,
+    calendars: Vec<TradingCalendar>,
 ) -> Result<BacktestReport> {
     use trader_core::StrategyAccountInfo;
 
@@ -738,7 +821,7 @@ async fn run_multi_asset_backtest(
         .unwrap_or("SPY");
 
     // 5. 전략별 백테스트 실행
-    let mut engine = BacktestEngine::new(backtest_config);
+    let mut engine = new_engine(backtest_config, quality_reports, calendars);
 
     match strategy_type {
         StrategyType::CompoundMomentum => {
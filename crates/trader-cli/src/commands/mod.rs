@@ -3,6 +3,7 @@
 pub mod backtest;
 pub mod chart_gen;
 pub mod download;
+pub mod export;
 pub mod fetch_symbols;
 pub mod health;
 pub mod import;
@@ -22,11 +22,11 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
-use trader_core::{Kline, MarketType, StrategyContext, Timeframe, SignalType};
+use trader_core::{Kline, MarketData, MarketType, Signal, StrategyContext, Timeframe, SignalType, TradingCalendar};
 use trader_data::cache::CachedHistoricalDataProvider;
 use trader_data::storage::ohlcv::OhlcvCache;
 use trader_data::{Database, DatabaseConfig};
-use trader_strategy::StrategyRegistry;
+use trader_strategy::{ScheduleClock, ScheduleEvent, Strategy, StrategyRegistry};
 
 use crate::commands::download::Market;
 
@@ -106,6 +106,26 @@ pub struct TradeDetail {
     pub pnl: Option<Decimal>,
 }
 
+/// 캔들 하나의 신호 수집.
+///
+/// 캔들 시작 전 일정 이벤트 → 캔들 → 캔들 구간 중 일정 이벤트 순서로 전달합니다.
+async fn bar_signals(
+    strategy: &mut dyn Strategy,
+    events_before: &[ScheduleEvent],
+    market_data: &MarketData,
+    events_during: &[ScheduleEvent],
+) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+    let mut signals = Vec::new();
+    for event in events_before {
+        signals.extend(strategy.on_schedule(event).await?);
+    }
+    signals.extend(strategy.on_market_data(market_data).await?);
+    for event in events_during {
+        signals.extend(strategy.on_schedule(event).await?);
+    }
+    Ok(signals)
+}
+
 /// 시뮬레이션 테스트 실행
 pub async fn run_sim_test(config: SimTestConfig) -> Result<SimTestResult> {
     println!("\n🔬 시뮬레이션 엔진 테스트 시작");
@@ -249,11 +269,20 @@ pub async fn run_sim_test(config: SimTestConfig) -> Result<SimTestResult> {
     let mut position_entry_price = Decimal::ZERO;
     let commission_rate = dec!(0.001); // 0.1%
 
+    // 일정 이벤트 (월초 리밸런싱 등, 백테스트와 같은 캘린더 규칙)
+    let schedule = strategy.schedule();
+    let calendar = schedule.as_ref().map(|s| TradingCalendar::for_country(s.country));
+    let mut schedule_clock = ScheduleClock::new();
+
     for (idx, kline) in klines.iter().enumerate() {
         // 전략에 캔들 전달
-        let market_data = trader_core::MarketData::from_kline("simulation", kline.clone());
+        let market_data = MarketData::from_kline("simulation", kline.clone());
+        let (events_before, events_during) = match (&schedule, &calendar) {
+            (Some(schedule), Some(calendar)) => schedule_clock.advance_bar(schedule, calendar, kline),
+            _ => Default::default(),
+        };
 
-        match strategy.on_market_data(&market_data).await {
+        match bar_signals(strategy.as_mut(), &events_before, &market_data, &events_during).await {
             Ok(signals) => {
                 for signal in &signals {
                     let grid_level = signal.metadata.get("grid_level")
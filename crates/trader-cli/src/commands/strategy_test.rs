@@ -29,25 +29,28 @@
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::{prelude::FromPrimitive, Decimal};
-use rust_decimal_macros::dec;
 use tokio::sync::RwLock;
 use tracing::{debug, warn};
 use trader_analytics::{
-    backtest::{
-        BacktestConfig, BacktestEngine, BacktestReport, BacktestScreeningProvider,
-        ScreeningCalculatorConfig,
-    },
+    backtest::{BacktestConfig, BacktestEngine, BacktestReport},
     AnalyticsProviderImpl,
 };
-use trader_core::{AnalyticsProvider, Kline, MarketType, StrategyContext, Timeframe};
+use trader_api::services::{
+    load_fundamental_history, load_screening_provider, screening_provider_for_strategy,
+};
+use trader_core::{
+    AnalyticsProvider, EconomicCalendar, FxRateTable, Kline, MarketType, StrategyContext,
+    Timeframe, DEFAULT_BASE_CURRENCY,
+};
 use trader_data::{
     cache::CachedHistoricalDataProvider, storage::ohlcv::OhlcvCache, Database, DatabaseConfig,
+    EconomicEventRepository, FxRateRepository,
 };
 use trader_strategy::StrategyRegistry;
 
-use crate::commands::download::Market;
+use crate::commands::{backtest::load_trading_calendars, download::Market};
 
 /// 전략 테스트 CLI 설정
 #[derive(Debug, Clone)]
@@ -104,56 +107,49 @@ pub struct TestResult {
     pub diagnostics: Vec<String>,
 }
 
-// ================================================================================================
-// 스크리닝 기반 전략 지원
-// ================================================================================================
-
-/// 스크리닝 기반 전략 ID 목록
-const SCREENING_BASED_STRATEGIES: &[&str] = &[
-    "small_cap_quant_v2",
-    "pension_bot_v2",
-    "dynamic_universe",
-    // 필요 시 추가
-];
-
-/// 스크리닝 기반 전략이 사용하는 기본 preset_name
-/// (ScreeningBasedConfig의 default_preset_name()과 일치해야 함)
-const SCREENING_PRESET_NAME: &str = "screening_based";
-
-/// 전략 ID에 따라 BacktestScreeningProvider 생성
+/// 백테스트 기간의 과거 경제 이벤트 캘린더 로드.
 ///
-/// 스크리닝 기반 전략인 경우 적절한 설정으로 Provider를 생성합니다.
-/// 전략들은 기본값으로 "screening_based" preset을 사용합니다.
-/// 일반 전략인 경우 None을 반환합니다.
-fn create_screening_provider_for_strategy(strategy_id: &str) -> Option<BacktestScreeningProvider> {
-    if !SCREENING_BASED_STRATEGIES.contains(&strategy_id) {
-        return None;
-    }
-
-    // 전략별 스크리닝 설정
-    // 모든 스크리닝 기반 전략은 기본값으로 "screening_based" preset을 사용
-    // (serde default = "default_preset_name" → "screening_based")
-    //
-    // 주의: 백테스트에서는 Fundamental 데이터(목표가, 손절가, 추천가)가 없어서
-    // GlobalScore 계산 시 기술적 지표(33%)만 반영됩니다.
-    // 따라서 min_score를 낮게 설정해야 스크리닝 결과가 나옵니다.
-    let config = match strategy_id {
-        "small_cap_quant_v2" => ScreeningCalculatorConfig::monthly(
-            SCREENING_PRESET_NAME,
-            dec!(0), // 백테스트: Fundamental 없이 기술적 지표만 계산되므로 min_score 0
-        ),
-        "pension_bot_v2" => ScreeningCalculatorConfig::weekly(
-            SCREENING_PRESET_NAME,
-            dec!(0), // 백테스트: Fundamental 없이 기술적 지표만 계산되므로 min_score 0
-        ),
-        "dynamic_universe" => ScreeningCalculatorConfig::monthly(
-            SCREENING_PRESET_NAME,
-            dec!(0), // 백테스트: Fundamental 없이 기술적 지표만 계산되므로 min_score 0
-        ),
-        _ => ScreeningCalculatorConfig::default(),
+/// `economic_event` 테이블의 이벤트(FOMC, CPI 등)에 규칙 기반 정기 일정(옵션 만기,
+/// 지수 정기변경)을 합칩니다. DB 조회에 실패해도 정기 일정은 사용합니다.
+async fn load_economic_calendar(
+    pool: &sqlx::PgPool,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> EconomicCalendar {
+    let repo = EconomicEventRepository::new(pool.clone());
+    let mut calendar = match repo.load_calendar(start, end).await {
+        Ok(calendar) => calendar,
+        Err(e) => {
+            warn!("경제 이벤트 조회 실패 (정기 일정만 사용): {}", e);
+            EconomicCalendar::new()
+        }
     };
+    calendar.extend(EconomicCalendar::recurring(
+        start.date_naive(),
+        end.date_naive(),
+    ));
+    calendar
+}
 
-    Some(BacktestScreeningProvider::with_config(config))
+/// 백테스트 기간의 과거 환율 테이블 로드.
+///
+/// `fx_rates` 테이블에서 조회하며, 실패하면 빈 테이블(기준 통화 환산 생략)을 사용합니다.
+async fn load_fx_rates(
+    pool: &sqlx::PgPool,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> FxRateTable {
+    let repo = FxRateRepository::new(pool.clone());
+    match repo
+        .load_table(Some(start.date_naive()), Some(end.date_naive()))
+        .await
+    {
+        Ok(table) => table,
+        Err(e) => {
+            warn!("환율 이력 조회 실패 (기준 통화 환산 생략): {}", e);
+            FxRateTable::new()
+        }
+    }
 }
 
 /// 전략별 BacktestConfig 생성
@@ -193,11 +189,18 @@ fn create_backtest_config_for_strategy(
         _ => 10,
     };
 
+    // 기준 통화 환산 (BASE_CURRENCY, 기본 KRW)
+    let base_currency = std::env::var("BASE_CURRENCY")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_BASE_CURRENCY.to_string());
+
     BacktestConfig::new(initial_capital)
         .with_commission_rate(commission_rate)
         .with_slippage_rate(slippage_rate)
         .with_allow_short(allow_short)
         .with_max_positions(max_positions)
+        .with_base_currency(&base_currency)
 }
 
 /// 전략 테스트 실행
@@ -409,7 +412,7 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
 
     // 4. StrategyContext 생성 및 분석 데이터 로드
     println!("\n📋 [4/6] StrategyContext 생성 및 분석 데이터 로드...");
-    let context = create_strategy_context(pool.clone(), &config).await?;
+    let context = create_strategy_context(pool.clone(), &config, start).await?;
     {
         let ctx_read = context.read().await;
         println!("  ✅ StrategyContext 생성 완료");
@@ -513,13 +516,38 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
         config.symbols.len(),
     );
 
-    let mut engine = BacktestEngine::new(backtest_config);
+    let economic_calendar = load_economic_calendar(pool, start, end).await;
+    println!("  📅 경제 이벤트 캘린더: {} 건", economic_calendar.len());
+    let fx_rates = load_fx_rates(pool, start, end).await;
+    let calendars = load_trading_calendars(pool, start.date_naive(), end.date_naive()).await;
+    let mut engine = calendars.into_iter().fold(
+        BacktestEngine::new(backtest_config)
+            .with_economic_calendar(economic_calendar)
+            .with_fx_rates(fx_rates),
+        BacktestEngine::with_calendar,
+    );
     let ticker = config.symbols[0].clone();
 
     // 스크리닝 기반 전략용 Provider 생성 (해당하는 경우만)
-    let screening_provider = create_screening_provider_for_strategy(&config.strategy_id);
-    if screening_provider.is_some() {
+    let mut screening_provider = screening_provider_for_strategy(&config.strategy_id);
+    if let Some(provider) = screening_provider.take() {
         println!("  📊 스크리닝 기반 전략 감지: 동적 유니버스 스크리닝 활성화");
+        screening_provider = Some(
+            match load_fundamental_history(
+                pool,
+                &config.symbols,
+                start.date_naive(),
+                end.date_naive(),
+            )
+            .await
+            {
+                Some(history) => {
+                    println!("  📊 펀더멘털 시점 이력 적용: {} 종목", history.len());
+                    provider.with_fundamentals(history)
+                }
+                None => provider,
+            },
+        );
     }
 
     let report = engine
@@ -569,6 +597,20 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
     println!("  수익 팩터: {:.2}", report.metrics.profit_factor);
     println!("  샤프 비율: {:.2}", report.metrics.sharpe_ratio);
     println!("  최대 낙폭: {:.2}%", report.metrics.max_drawdown_pct);
+    if let Some(fx) = report
+        .fx
+        .as_ref()
+        .filter(|fx| fx.currency != fx.base_currency)
+    {
+        println!(
+            "  기준 통화 환산 ({} → {}): 자산 손익 {:+.0}, 환차손익 {:+.0}, 수익률 {:.2}%",
+            fx.currency,
+            fx.base_currency,
+            fx.pnl.asset_pnl,
+            fx.pnl.fx_pnl,
+            fx.total_return_pct_base
+        );
+    }
 
     // 거래 내역 출력 (디버그 모드 또는 거래 수가 적을 때)
     if config.debug || report.trades.len() <= 20 {
@@ -944,9 +986,11 @@ fn inject_asset_allocation_defaults(
 ///
 /// 실제 trader-api와 동일한 방식으로 AnalyticsProvider를 사용하여
 /// GlobalScore, RouteState, StructuralFeatures 등을 로드합니다.
+/// GlobalScore와 StructuralFeatures는 백테스트 시작 시점(`as_of`) 기준으로 조회합니다.
 async fn create_strategy_context(
     pool: sqlx::PgPool,
     config: &StrategyTestConfig,
+    as_of: DateTime<Utc>,
 ) -> Result<Arc<RwLock<StrategyContext>>> {
     // 데이터 제공자 생성
     let data_provider = Arc::new(CachedHistoricalDataProvider::new(pool.clone()));
@@ -962,7 +1006,10 @@ async fn create_strategy_context(
     };
 
     // Global Score 로드
-    match analytics_provider.fetch_global_scores(market_type).await {
+    match analytics_provider
+        .fetch_global_scores(market_type, Some(as_of))
+        .await
+    {
         Ok(scores) => {
             ctx.update_global_scores(scores);
             debug!("GlobalScore 로드 완료: {} 개", ctx.global_scores.len());
@@ -996,7 +1043,10 @@ async fn create_strategy_context(
     }
 
     // StructuralFeatures 로드
-    match analytics_provider.fetch_features(&tickers).await {
+    match analytics_provider
+        .fetch_features(&tickers, Some(as_of))
+        .await
+    {
         Ok(features) => {
             ctx.update_features(features);
             debug!("StructuralFeatures 로드 완료");
@@ -1627,7 +1677,7 @@ async fn run_strategy_test_quiet(config: StrategyTestConfig) -> Result<TestResul
     }
 
     // StrategyContext 생성
-    let context = create_strategy_context(pool.clone(), &config).await?;
+    let context = create_strategy_context(pool.clone(), &config, start).await?;
 
     // 모든 심볼의 klines를 StrategyContext에 저장
     // (멀티 자산 전략이 context.get_klines()로 접근 가능)
@@ -1683,11 +1733,26 @@ async fn run_strategy_test_quiet(config: StrategyTestConfig) -> Result<TestResul
         config.symbols.len(),
     );
 
-    let mut engine = BacktestEngine::new(backtest_config);
+    let economic_calendar = load_economic_calendar(pool, start, end).await;
+    let fx_rates = load_fx_rates(pool, start, end).await;
+    let calendars = load_trading_calendars(pool, start.date_naive(), end.date_naive()).await;
+    let mut engine = calendars.into_iter().fold(
+        BacktestEngine::new(backtest_config)
+            .with_economic_calendar(economic_calendar)
+            .with_fx_rates(fx_rates),
+        BacktestEngine::with_calendar,
+    );
     let ticker = primary_symbol.clone();
 
-    // 스크리닝 기반 전략용 Provider 생성
-    let screening_provider = create_screening_provider_for_strategy(&config.strategy_id);
+    // 스크리닝 기반 전략용 Provider 생성 (펀더멘털 시점 이력 포함)
+    let screening_provider = load_screening_provider(
+        Some(pool),
+        &config.strategy_id,
+        &config.symbols,
+        start.date_naive(),
+        end.date_naive(),
+    )
+    .await;
 
     let report = engine
         .run(
@@ -15,6 +15,10 @@
 //! # 인기 종목 목록 보기
 //! trader list -m KR
 //! trader list -m US
+//!
+//! # Parquet 내보내기/가져오기 (Python/Polars 연동)
+//! trader export -d ohlcv -m KR -s 005930 -f 2020-01-01
+//! trader import-parquet -d ohlcv -m KR -s 005930
 //! ```
 
 use clap::{Parser, Subcommand};
@@ -26,6 +30,7 @@ use commands::{
     download::{
         download_data, parse_date, print_available_symbols, DownloadConfig, Interval, Market,
     },
+    export::{export_parquet, import_parquet, parse_dataset, ExportConfig, ImportParquetConfig},
     import::{import_to_db, ImportDbConfig},
     strategy_test::{run_strategy_test, StrategyTestConfig},
 };
@@ -133,6 +138,88 @@ enum Commands {
         db_url: Option<String>,
     },
 
+    /// 데이터를 Parquet으로 내보내기 (market/symbol/year 분할)
+    Export {
+        /// 데이터셋 (ohlcv, trade_ticks, backtest_trades, backtest_equity, screening)
+        #[arg(short, long, default_value = "ohlcv")]
+        dataset: String,
+
+        /// 시장 (KR, US, CRYPTO 등, 파티션 키)
+        #[arg(short, long)]
+        market: String,
+
+        /// 종목 코드/심볼 (ohlcv, trade_ticks 필수)
+        #[arg(short, long)]
+        symbol: Option<String>,
+
+        /// 타임프레임 (ohlcv 전용, 예: 1d, 1h, 1w)
+        #[arg(short, long, default_value = "1d")]
+        interval: String,
+
+        /// 시작 날짜 (YYYY-MM-DD)
+        #[arg(short = 'f', long)]
+        from: Option<String>,
+
+        /// 종료 날짜 (YYYY-MM-DD)
+        #[arg(short, long)]
+        to: Option<String>,
+
+        /// 백테스트 리포트 JSON (backtest_trades, backtest_equity 전용)
+        #[arg(long)]
+        report: Option<String>,
+
+        /// 스크리닝 프리셋 (screening 전용, 생략 시 시장 전체)
+        #[arg(long)]
+        preset: Option<String>,
+
+        /// 거래소 (trade_ticks 전용)
+        #[arg(long, default_value = "binance")]
+        exchange: String,
+
+        /// 호가 통화 (trade_ticks 전용)
+        #[arg(long, default_value = "USDT")]
+        quote: String,
+
+        /// 출력 루트 디렉토리
+        #[arg(short, long, default_value = "data/parquet")]
+        output: String,
+
+        /// 데이터베이스 URL (기본: DATABASE_URL 환경변수)
+        #[arg(long)]
+        db_url: Option<String>,
+    },
+
+    /// Parquet 파일을 DB로 가져오기 (ohlcv, trade_ticks)
+    ImportParquet {
+        /// 데이터셋 (ohlcv, trade_ticks)
+        #[arg(short, long, default_value = "ohlcv")]
+        dataset: String,
+
+        /// 시장 (파티션 키)
+        #[arg(short, long)]
+        market: String,
+
+        /// 종목 코드/심볼 (생략 시 시장의 모든 심볼)
+        #[arg(short, long)]
+        symbol: Option<String>,
+
+        /// 거래소 (trade_ticks 전용)
+        #[arg(long, default_value = "binance")]
+        exchange: String,
+
+        /// 호가 통화 (trade_ticks 전용)
+        #[arg(long, default_value = "USDT")]
+        quote: String,
+
+        /// 입력 루트 디렉토리
+        #[arg(short, long, default_value = "data/parquet")]
+        input: String,
+
+        /// 데이터베이스 URL (기본: DATABASE_URL 환경변수)
+        #[arg(long)]
+        db_url: Option<String>,
+    },
+
     /// DB에서 종목 목록 조회
     ListSymbols {
         /// 시장 필터 (KR, US, CRYPTO, ALL 등)
@@ -378,6 +465,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 트레이싱 초기화
     tracing_subscriber::fmt::init();
 
+    // 스크립트 전략 지표 함수는 IndicatorEngine으로 계산
+    trader_strategy::scripting::set_indicator_calculator(std::sync::Arc::new(
+        trader_analytics::IndicatorEngine::new(),
+    ));
+
     let cli = Cli::parse();
 
     match cli.command {
@@ -567,6 +659,100 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
+        Commands::Export {
+            dataset,
+            market,
+            symbol,
+            interval,
+            from,
+            to,
+            report,
+            preset,
+            exchange,
+            quote,
+            output,
+            db_url,
+        } => {
+            let dataset = parse_dataset(&dataset)?;
+            let timeframe = interval
+                .parse::<trader_core::Timeframe>()
+                .map_err(|e| format!("{}. Supported: 1m, 5m, 1h, 4h, 1d, 1w, 1M ...", e))?;
+            let start_date = from.as_ref().map(|d| parse_date(d)).transpose()?;
+            let end_date = to.as_ref().map(|d| parse_date(d)).transpose()?;
+
+            let config = ExportConfig {
+                dataset,
+                market: market.to_uppercase(),
+                symbol,
+                timeframe,
+                start_date,
+                end_date,
+                report_path: report,
+                preset,
+                exchange,
+                quote,
+                output_dir: output.clone(),
+                db_url,
+            };
+
+            println!(
+                "\n📦 Parquet 내보내기: {} ({})",
+                dataset,
+                market.to_uppercase()
+            );
+
+            match export_parquet(config).await {
+                Ok(rows) => {
+                    info!("✅ Exported {} rows to {}", rows, output);
+                    println!("\n✅ 내보내기 완료: {} 행 → {}", rows, output);
+                }
+                Err(e) => {
+                    error!("Parquet export failed: {}", e);
+                    return Err(e.into());
+                }
+            }
+        }
+
+        Commands::ImportParquet {
+            dataset,
+            market,
+            symbol,
+            exchange,
+            quote,
+            input,
+            db_url,
+        } => {
+            let dataset = parse_dataset(&dataset)?;
+
+            let config = ImportParquetConfig {
+                dataset,
+                market: market.to_uppercase(),
+                symbol,
+                exchange,
+                quote,
+                input_dir: input.clone(),
+                db_url,
+            };
+
+            println!(
+                "\n📥 Parquet 가져오기: {} ({}) ← {}",
+                dataset,
+                market.to_uppercase(),
+                input
+            );
+
+            match import_parquet(config).await {
+                Ok(rows) => {
+                    info!("✅ Imported {} rows from Parquet", rows);
+                    println!("\n✅ 가져오기 완료: {} 행", rows);
+                }
+                Err(e) => {
+                    error!("Parquet import failed: {}", e);
+                    return Err(e.into());
+                }
+            }
+        }
+
         Commands::ListSymbols {
             market,
             active_only,
@@ -1,6 +1,6 @@
 //! 환경변수 기반 설정 모듈.
 
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
 use crate::Result;
 
@@ -23,6 +23,16 @@ pub struct CollectorConfig {
     pub scheduling: SchedulingConfig,
     /// 신호 성과 설정
     pub signal_performance: SignalPerformanceConfig,
+    /// 데이터 품질 검사 설정
+    pub data_quality: DataQualityConfig,
+    /// DART 전자공시 수집 설정
+    pub dart: DartConfig,
+    /// 경제 이벤트 캘린더 수집 설정
+    pub economic_calendar: EconomicCalendarConfig,
+    /// 환율 이력 수집 설정
+    pub fx_rate: FxRateConfig,
+    /// 계층형 저장소 (분봉 롤업/아카이브) 설정
+    pub storage_tiering: StorageTieringConfig,
     /// 관심종목 우선 처리 여부
     pub prioritize_watchlist: bool,
 }
@@ -122,6 +132,17 @@ pub struct SchedulingConfig {
     pub skip_weekends: bool,
     /// 공휴일 건너뛰기
     pub skip_holidays: bool,
+    /// 데몬 모드에서 작업 오케스트레이터(DAG + cron) 사용 여부
+    /// (false면 기존 그룹 A/B 고정 주기 워크플로우)
+    pub orchestrator_enabled: bool,
+    /// 작업별 cron 일정 (작업 이름 → 표현식, `off`면 선행 작업 완료 시에만 실행)
+    pub job_schedules: HashMap<String, String>,
+    /// 작업 최대 시도 횟수 (첫 시도 포함)
+    pub job_max_attempts: u32,
+    /// 첫 재시도 대기 시간 (초, 시도마다 2배)
+    pub job_retry_backoff_secs: u64,
+    /// 최대 재시도 대기 시간 (초)
+    pub job_retry_max_backoff_secs: u64,
 }
 
 /// 신호 성과 계산 설정
@@ -135,6 +156,73 @@ pub struct SignalPerformanceConfig {
     pub max_days: u32,
 }
 
+/// OHLCV 데이터 품질 검사 설정
+#[derive(Debug, Clone)]
+pub struct DataQualityConfig {
+    /// 검사 기간 (일, 최근 N일 캔들만 검사)
+    pub lookback_days: i64,
+    /// 급등락 판정 기준 (전 캔들 종가 대비 변화율, 0.35 = 35%)
+    pub spike_threshold: f64,
+    /// 누락 거래일 자동 복구 (Yahoo → KIS → 네이버)
+    pub auto_repair: bool,
+    /// 복구 요청 간 딜레이 (밀리초)
+    pub request_delay_ms: u64,
+}
+
+/// DART 전자공시 수집 설정
+#[derive(Debug, Clone)]
+pub struct DartConfig {
+    /// 워크플로우에 DART 동기화 포함 여부
+    /// 기본값: false (API 키 등록 후 활성화)
+    pub enabled: bool,
+    /// 공시 조회 기간 (일, 최근 N일 접수 공시)
+    pub lookback_days: i64,
+    /// API 요청 간 딜레이 (밀리초, DART 분당 요청 제한 대응)
+    pub request_delay_ms: u64,
+}
+
+/// 경제 이벤트 캘린더 수집 설정
+#[derive(Debug, Clone)]
+pub struct EconomicCalendarConfig {
+    /// 워크플로우에 경제 캘린더 동기화 포함 여부
+    /// 기본값: false
+    pub enabled: bool,
+    /// ICS/CSV 피드 목록 (`COUNTRY=URL` 또는 `URL`, 로컬 파일 경로 허용)
+    pub feeds: Vec<String>,
+    /// 규칙 기반 정기 일정(옵션 만기, 지수 정기변경) 생성 기간 - 과거 (년)
+    pub recurring_years_back: i32,
+    /// 규칙 기반 정기 일정 생성 기간 - 미래 (개월)
+    pub recurring_months_ahead: u32,
+}
+
+/// 환율 이력 수집 설정
+#[derive(Debug, Clone)]
+pub struct FxRateConfig {
+    /// 워크플로우에 환율 동기화 포함 여부
+    /// 기본값: true
+    pub enabled: bool,
+    /// 수집할 통화쌍 (`BASE/QUOTE`, 예: `USD/KRW`)
+    pub pairs: Vec<String>,
+    /// 정기 수집 조회 기간 (Yahoo range, 예: `5d`, `1mo`)
+    pub range: String,
+}
+
+/// 계층형 저장소 설정
+#[derive(Debug, Clone)]
+pub struct StorageTieringConfig {
+    /// 워크플로우에 계층형 저장소 작업 포함 여부
+    /// 기본값: false
+    pub enabled: bool,
+    /// Parquet 아카이브 루트 디렉토리 (API 서버와 같은 경로를 사용해야 함)
+    pub archive_dir: Option<String>,
+    /// 분봉을 롤업/아카이브하기까지의 보존 기간 (일)
+    /// 기본값: 30
+    pub rollup_after_days: i64,
+    /// 체결 틱을 아카이브하기까지의 보존 기간 (일)
+    /// 기본값: 30
+    pub tick_archive_after_days: i64,
+}
+
 impl CollectorConfig {
     /// 환경변수에서 설정 로드
     pub fn from_env() -> Result<Self> {
@@ -196,12 +284,63 @@ impl CollectorConfig {
                 krx_delay_after_close_minutes: env_var_parse("SCHEDULING_KRX_DELAY_MINUTES", 60),
                 skip_weekends: env_var_bool("SCHEDULING_SKIP_WEEKENDS", true),
                 skip_holidays: env_var_bool("SCHEDULING_SKIP_HOLIDAYS", true),
+                orchestrator_enabled: env_var_bool("SCHEDULING_ORCHESTRATOR_ENABLED", false),
+                job_schedules: env_var_prefixed("SCHEDULING_JOB_SCHEDULE_"),
+                job_max_attempts: env_var_parse("SCHEDULING_JOB_MAX_ATTEMPTS", 3),
+                job_retry_backoff_secs: env_var_parse("SCHEDULING_JOB_RETRY_BACKOFF_SECS", 30),
+                job_retry_max_backoff_secs: env_var_parse(
+                    "SCHEDULING_JOB_RETRY_MAX_BACKOFF_SECS",
+                    600,
+                ),
             },
             signal_performance: SignalPerformanceConfig {
                 batch_size: env_var_parse("SIGNAL_PERFORMANCE_BATCH_SIZE", 100),
                 min_days_after: env_var_parse("SIGNAL_PERFORMANCE_MIN_DAYS", 1),
                 max_days: env_var_parse("SIGNAL_PERFORMANCE_MAX_DAYS", 20),
             },
+            data_quality: DataQualityConfig {
+                lookback_days: env_var_parse("QUALITY_LOOKBACK_DAYS", 365),
+                spike_threshold: env_var_parse("QUALITY_SPIKE_THRESHOLD", 0.35),
+                auto_repair: env_var_bool("QUALITY_AUTO_REPAIR", true),
+                request_delay_ms: env_var_parse("QUALITY_REQUEST_DELAY_MS", 300),
+            },
+            dart: DartConfig {
+                enabled: env_var_bool("DART_SYNC_ENABLED", false),
+                lookback_days: env_var_parse("DART_LOOKBACK_DAYS", 7),
+                request_delay_ms: env_var_parse("DART_REQUEST_DELAY_MS", 100),
+            },
+            economic_calendar: EconomicCalendarConfig {
+                enabled: env_var_bool("ECONOMIC_CALENDAR_SYNC_ENABLED", false),
+                // URL은 대소문자를 보존해야 하므로 env_var_list를 사용하지 않음
+                feeds: std::env::var("ECONOMIC_CALENDAR_FEEDS")
+                    .map(|v| {
+                        v.split(',')
+                            .map(|s| s.trim().to_string())
+                            .filter(|s| !s.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                recurring_years_back: env_var_parse("ECONOMIC_CALENDAR_RECURRING_YEARS_BACK", 3),
+                recurring_months_ahead: env_var_parse(
+                    "ECONOMIC_CALENDAR_RECURRING_MONTHS_AHEAD",
+                    12,
+                ),
+            },
+            fx_rate: FxRateConfig {
+                enabled: env_var_bool("FX_SYNC_ENABLED", true),
+                pairs: Some(env_var_list("FX_SYNC_PAIRS"))
+                    .filter(|pairs| !pairs.is_empty())
+                    .unwrap_or_else(|| vec!["USD/KRW".to_string()]),
+                range: std::env::var("FX_SYNC_RANGE").unwrap_or_else(|_| "5d".to_string()),
+            },
+            storage_tiering: StorageTieringConfig {
+                enabled: env_var_bool("STORAGE_TIERING_ENABLED", false),
+                archive_dir: std::env::var("STORAGE_ARCHIVE_DIR")
+                    .ok()
+                    .filter(|v| !v.trim().is_empty()),
+                rollup_after_days: env_var_parse("STORAGE_ROLLUP_AFTER_DAYS", 30),
+                tick_archive_after_days: env_var_parse("STORAGE_TICK_ARCHIVE_AFTER_DAYS", 30),
+            },
             prioritize_watchlist: env_var_bool("PRIORITIZE_WATCHLIST", true),
         })
     }
@@ -221,6 +360,20 @@ impl FundamentalCollectConfig {
     }
 }
 
+impl DataQualityConfig {
+    /// 복구 요청 간 딜레이를 Duration으로 반환
+    pub fn request_delay(&self) -> Duration {
+        Duration::from_millis(self.request_delay_ms)
+    }
+}
+
+impl DartConfig {
+    /// API 요청 간 딜레이를 Duration으로 반환
+    pub fn request_delay(&self) -> Duration {
+        Duration::from_millis(self.request_delay_ms)
+    }
+}
+
 impl DaemonConfig {
     /// 워크플로우 실행 주기를 Duration으로 반환
     pub fn interval(&self) -> Duration {
@@ -266,3 +419,15 @@ fn env_var_list_or_default(key: &str, default: Vec<String>) -> Vec<String> {
         })
         .unwrap_or(default)
 }
+
+/// 접두어가 같은 환경변수 수집 (키는 접두어를 뗀 소문자)
+///
+/// 예: `SCHEDULING_JOB_SCHEDULE_OHLCV_COLLECT=30 16 * * *` → `ohlcv_collect`
+fn env_var_prefixed(prefix: &str) -> HashMap<String, String> {
+    std::env::vars()
+        .filter_map(|(key, value)| {
+            let name = key.strip_prefix(prefix)?;
+            Some((name.to_lowercase(), value.trim().to_string()))
+        })
+        .collect()
+}
@@ -4,6 +4,11 @@
 //! - 심볼 정보 동기화 (KRX, Binance, Yahoo Finance)
 //! - OHLCV 데이터 수집 (일봉)
 //! - Fundamental 데이터 수집 (재무 지표)
+//! - OHLCV 데이터 품질 검사 및 누락 거래일 복구
+//! - DART 전자공시 수집 (공시 목록, 분기 재무제표, 대량보유상황보고)
+//! - 경제 이벤트 캘린더 수집 (FOMC, CPI, 금통위 ICS/CSV 피드, 옵션 만기/지수 정기변경)
+//! - 계층형 저장소 (오래된 분봉 롤업, 분봉/체결 틱 Parquet 아카이브)
+//! - 작업 오케스트레이션 (의존성 DAG, cron 일정, 재시도, 실행 이력 기록)
 
 pub mod config;
 pub mod error;
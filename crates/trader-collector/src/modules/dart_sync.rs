@@ -0,0 +1,308 @@
+//! DART 전자공시 수집 모듈.
+//!
+//! 금융감독원 DART OpenAPI에서 다음 데이터를 수집하여 저장합니다:
+//! - 고유번호(corp_code) ↔ 종목코드 매핑 (`corpCode.xml`, 최초 1회 또는 `--refresh-corp-codes`)
+//! - 최근 N일 접수 공시 목록 (유상증자, CB/BW 발행, 최대주주 변경 등 유형 분류 포함)
+//! - 정기보고서(분기/반기/사업보고서) 공시가 접수된 종목의 주요계정 재무제표
+//! - 대량보유/최대주주 변경 공시가 접수된 종목의 대량보유상황보고
+//!
+//! 주요사항 공시 알림은 API 서버가 `dart_disclosure` 테이블을 폴링하여 전송합니다.
+
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Asia::Seoul;
+use sqlx::PgPool;
+use tracing::{debug, info, warn};
+use trader_core::CredentialEncryptor;
+use trader_data::{
+    provider::dart::{DartClient, DartError, Disclosure, DisclosureKind, ReportPeriod},
+    DisclosureRepository,
+};
+
+use crate::{CollectorConfig, CollectorError, Result};
+
+/// 전체 회사 공시 조회 시 최대 기간 (DART 제한: 3개월).
+const MAX_WINDOW_DAYS: i64 = 90;
+
+/// DART 동기화 옵션.
+#[derive(Debug, Clone, Default)]
+pub struct DartSyncOptions {
+    /// 특정 종목만 동기화 (None이면 전체 회사)
+    pub ticker: Option<String>,
+    /// 공시 조회 기간 (일, None이면 `DART_LOOKBACK_DAYS`)
+    pub lookback_days: Option<i64>,
+    /// 고유번호 매핑 강제 갱신
+    pub refresh_corp_codes: bool,
+}
+
+/// DART 동기화 통계.
+#[derive(Debug, Default)]
+pub struct DartSyncStats {
+    /// 갱신된 고유번호 매핑 수
+    pub corp_codes: usize,
+    /// 조회된 공시 수
+    pub disclosures_fetched: usize,
+    /// 새로 저장된 공시 수
+    pub disclosures_saved: usize,
+    /// 조회된 주요사항 공시 수 (상장사)
+    pub material: usize,
+    /// 저장된 재무제표 수
+    pub statements_saved: usize,
+    /// 새로 저장된 대량보유상황보고 수
+    pub shareholder_changes_saved: usize,
+    /// 실패 수
+    pub failed: usize,
+    /// 소요 시간
+    pub elapsed: Duration,
+}
+
+impl DartSyncStats {
+    /// 통계 요약 로그 출력
+    pub fn log_summary(&self, operation: &str) {
+        info!(
+            operation = operation,
+            corp_codes = self.corp_codes,
+            disclosures_fetched = self.disclosures_fetched,
+            disclosures_saved = self.disclosures_saved,
+            material = self.material,
+            statements_saved = self.statements_saved,
+            shareholder_changes_saved = self.shareholder_changes_saved,
+            failed = self.failed,
+            elapsed = format!("{:.1}s", self.elapsed.as_secs_f64()),
+            "DART 동기화 완료"
+        );
+    }
+}
+
+/// DART 공시/재무제표/대량보유 동기화.
+pub async fn sync_dart(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    options: DartSyncOptions,
+) -> Result<DartSyncStats> {
+    let start_time = Instant::now();
+    let mut stats = DartSyncStats::default();
+
+    let client = match load_client(pool).await? {
+        Some(client) => client.with_request_delay(config.dart.request_delay()),
+        None => {
+            warn!("DART API 키가 등록되지 않았습니다. 동기화를 건너뜁니다.");
+            return Ok(stats);
+        }
+    };
+    let repository = DisclosureRepository::new(pool.clone());
+
+    // 1. 고유번호 매핑 (비어 있거나 강제 갱신 시)
+    let mapped: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM dart_corp_code")
+        .fetch_one(pool)
+        .await?;
+    if mapped == 0 || options.refresh_corp_codes {
+        info!("DART 고유번호 목록 다운로드 중...");
+        let codes = client.fetch_corp_codes().await.map_err(dart_error)?;
+        stats.corp_codes = repository
+            .save_corp_codes(&codes)
+            .await
+            .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+        info!(count = stats.corp_codes, "DART 고유번호 매핑 갱신 완료");
+    }
+
+    // 2. 대상 회사
+    let corp_code = match options.ticker.as_deref() {
+        Some(ticker) => match repository
+            .corp_code_for(ticker)
+            .await
+            .map_err(|e| CollectorError::DataSource(e.to_string()))?
+        {
+            Some(code) => Some(code),
+            None => {
+                return Err(CollectorError::Config(format!(
+                    "{}의 DART 고유번호를 찾을 수 없습니다 (--refresh-corp-codes로 매핑 갱신)",
+                    ticker
+                )))
+            }
+        },
+        None => None,
+    };
+
+    // 3. 공시 목록
+    let lookback_days = options
+        .lookback_days
+        .unwrap_or(config.dart.lookback_days)
+        .max(1);
+    let end = Utc::now().with_timezone(&Seoul).date_naive();
+    let start = end - chrono::Duration::days(lookback_days - 1);
+    info!(%start, %end, ticker = ?options.ticker, "DART 공시 목록 수집 중...");
+
+    let mut disclosures = Vec::new();
+    for (window_start, window_end) in date_windows(start, end) {
+        match client
+            .fetch_all_disclosures(window_start, window_end, corp_code.as_deref())
+            .await
+        {
+            Ok(items) => disclosures.extend(items),
+            Err(DartError::RateLimited) => {
+                warn!("DART 요청 한도 초과. 공시 목록 수집을 중단합니다.");
+                stats.failed += 1;
+                break;
+            }
+            Err(e) => {
+                warn!(%window_start, %window_end, error = %e, "DART 공시 목록 조회 실패");
+                stats.failed += 1;
+            }
+        }
+    }
+    stats.disclosures_fetched = disclosures.len();
+
+    // 상장사 공시만 저장 (비상장사는 종목 매핑 불가)
+    disclosures.retain(|d| d.ticker.is_some());
+    stats.disclosures_saved = repository
+        .save_disclosures(&disclosures)
+        .await
+        .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+    stats.material = disclosures.iter().filter(|d| d.kind.is_material()).count();
+
+    // 4. 정기보고서 → 재무제표
+    for (corp_code, fiscal_year, period) in periodic_targets(&disclosures) {
+        match client
+            .fetch_financial_statement(&corp_code, fiscal_year, period)
+            .await
+        {
+            Ok(Some(statement)) => match repository.save_financial_statement(&statement).await {
+                Ok(()) => stats.statements_saved += 1,
+                Err(e) => {
+                    warn!(corp_code, error = %e, "재무제표 저장 실패");
+                    stats.failed += 1;
+                }
+            },
+            Ok(None) => debug!(corp_code, fiscal_year, "재무제표 없음"),
+            Err(DartError::RateLimited) => {
+                warn!("DART 요청 한도 초과. 재무제표 수집을 중단합니다.");
+                stats.failed += 1;
+                break;
+            }
+            Err(e) => {
+                warn!(corp_code, fiscal_year, error = %e, "재무제표 조회 실패");
+                stats.failed += 1;
+            }
+        }
+    }
+
+    // 5. 대량보유/최대주주 변경 공시 → 대량보유상황보고
+    for (corp_code, ticker) in shareholder_targets(&disclosures) {
+        match client.fetch_major_shareholder_changes(&corp_code).await {
+            Ok(changes) => {
+                let recent: Vec<_> = changes
+                    .into_iter()
+                    .filter(|c| c.receipt_date >= start)
+                    .collect();
+                match repository
+                    .save_shareholder_changes(Some(&ticker), &recent)
+                    .await
+                {
+                    Ok(count) => stats.shareholder_changes_saved += count,
+                    Err(e) => {
+                        warn!(corp_code, error = %e, "대량보유상황보고 저장 실패");
+                        stats.failed += 1;
+                    }
+                }
+            }
+            Err(DartError::RateLimited) => {
+                warn!("DART 요청 한도 초과. 대량보유상황보고 수집을 중단합니다.");
+                stats.failed += 1;
+                break;
+            }
+            Err(e) => {
+                warn!(corp_code, error = %e, "대량보유상황보고 조회 실패");
+                stats.failed += 1;
+            }
+        }
+    }
+
+    stats.elapsed = start_time.elapsed();
+    Ok(stats)
+}
+
+/// DART 클라이언트 생성 (credential 우선, 암호화키가 없으면 환경변수).
+async fn load_client(pool: &PgPool) -> Result<Option<DartClient>> {
+    let master_key = match std::env::var("ENCRYPTION_MASTER_KEY") {
+        Ok(key) => key,
+        Err(_) => return Ok(DartClient::from_env()),
+    };
+
+    let encryptor = CredentialEncryptor::new(&master_key)
+        .map_err(|e| CollectorError::DataSource(format!("암호화키 로드 실패: {}", e)))?;
+
+    DartClient::from_credential(pool, &encryptor)
+        .await
+        .map_err(|e| CollectorError::DataSource(format!("DART 클라이언트 생성 실패: {}", e)))
+}
+
+fn dart_error(e: DartError) -> CollectorError {
+    CollectorError::DataSource(format!("DART API 오류: {}", e))
+}
+
+/// 조회 기간을 DART 제한(3개월) 이내 구간으로 분할.
+fn date_windows(start: NaiveDate, end: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut windows = Vec::new();
+    let mut window_start = start;
+    while window_start <= end {
+        let window_end = (window_start + chrono::Duration::days(MAX_WINDOW_DAYS - 1)).min(end);
+        windows.push((window_start, window_end));
+        window_start = window_end + chrono::Duration::days(1);
+    }
+    windows
+}
+
+/// 재무제표를 조회할 (고유번호, 사업연도, 보고서 구분) 목록 (중복 제거).
+fn periodic_targets(disclosures: &[Disclosure]) -> Vec<(String, i32, ReportPeriod)> {
+    let mut seen = HashSet::new();
+    disclosures
+        .iter()
+        .filter_map(|d| {
+            d.periodic_report()
+                .map(|(year, period)| (d.corp_code.clone(), year, period))
+        })
+        .filter(|target| seen.insert(target.clone()))
+        .collect()
+}
+
+/// 대량보유상황보고를 조회할 (고유번호, 종목코드) 목록 (중복 제거).
+fn shareholder_targets(disclosures: &[Disclosure]) -> Vec<(String, String)> {
+    let mut seen = HashSet::new();
+    disclosures
+        .iter()
+        .filter(|d| {
+            matches!(
+                d.kind,
+                DisclosureKind::MajorShareholding | DisclosureKind::LargestShareholderChange
+            )
+        })
+        .filter_map(|d| d.ticker.clone().map(|t| (d.corp_code.clone(), t)))
+        .filter(|target| seen.insert(target.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_date_windows_split_at_90_days() {
+        let windows = date_windows(date(2024, 1, 1), date(2024, 6, 30));
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[0], (date(2024, 1, 1), date(2024, 3, 30)));
+        assert_eq!(windows[1].0, date(2024, 3, 31));
+        assert_eq!(windows[2].1, date(2024, 6, 30));
+
+        let single = date_windows(date(2024, 1, 1), date(2024, 1, 7));
+        assert_eq!(single, vec![(date(2024, 1, 1), date(2024, 1, 7))]);
+    }
+}
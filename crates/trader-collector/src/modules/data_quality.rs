@@ -0,0 +1,833 @@
+//! OHLCV 데이터 품질 검사 및 갭 복구 모듈.
+//!
+//! `ohlcv_collect`가 채운 캔들 시계열을 심볼/타임프레임별로 검사하여 점수를 매기고,
+//! 결과를 `ohlcv_quality_report` 테이블에 저장합니다. 백테스트는 이 리포트를 조회하여
+//! 품질이 낮은 데이터로 실행될 때 경고합니다.
+//!
+//! # 검사 항목
+//!
+//! - **거래량 0**: 거래정지 또는 프로바이더 누락
+//! - **OHLC 오류**: high < low, 시가/종가가 고저 범위 밖, 0 이하 가격
+//! - **중복 캔들**: 같은 거래일(일봉 이상) 또는 같은 시각(분/시간봉)에 2개 이상
+//! - **누락 거래일**: 거래 캘린더(KRX 휴장일 반영) 기준 일봉이 없는 거래일
+//! - **급등락**: 전 캔들 종가 대비 변화율이 기준 초과 (데이터 오류 의심)
+//!
+//! 누락 거래일은 휴장일 정보가 등록된 연도에서만 검사합니다 (휴장일을 누락으로 오판하지 않도록).
+//!
+//! # 갭 복구
+//!
+//! 누락 거래일은 대체 프로바이더에서 다시 가져옵니다: Yahoo Finance → KIS → 네이버 금융.
+//! KIS와 네이버는 국내(KR) 종목만 지원하며, KIS 일봉 API는 최근 30영업일까지만 제공합니다.
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use sqlx::PgPool;
+use trader_core::{Kline, Timeframe, TradingCalendar};
+use trader_data::{
+    cache::historical::YahooProviderWrapper, storage::ohlcv::timeframe_to_string,
+    NaverFinanceFetcher, OhlcvCache, OhlcvQualityReport, OhlcvQualityRepository, QualityIssue,
+    QualityIssueKind, SymbolResolver,
+};
+use trader_exchange::{
+    connector::kis::{KisConfig, KisOAuth},
+    KisClient,
+};
+
+use super::scheduler::Scheduler;
+use crate::{config::DataQualityConfig, CollectorConfig, CollectorError, Result};
+
+/// 문제 유형별 리포트에 보관할 최대 샘플 수.
+const MAX_ISSUE_SAMPLES: usize = 20;
+
+/// 문제 비율 대비 감점 배율 (문제 비율 2%에서 경고 기준 90점에 도달).
+const PENALTY_SCALE: f64 = 5.0;
+
+/// 갭 복구 프로바이더 (시도 순서대로).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepairProvider {
+    /// Yahoo Finance
+    Yahoo,
+    /// 한국투자증권 (국내 전용)
+    Kis,
+    /// 네이버 금융 차트 (국내 전용)
+    Naver,
+}
+
+impl RepairProvider {
+    /// 복구 시도 순서.
+    pub const CHAIN: [RepairProvider; 3] = [Self::Yahoo, Self::Kis, Self::Naver];
+
+    /// 문자열 표현.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Yahoo => "yahoo",
+            Self::Kis => "kis",
+            Self::Naver => "naver",
+        }
+    }
+
+    /// 국내(KR) 종목 전용 여부.
+    fn kr_only(&self) -> bool {
+        matches!(self, Self::Kis | Self::Naver)
+    }
+}
+
+/// 단일 시계열 검사 결과.
+#[derive(Debug, Clone)]
+pub struct SeriesCheck {
+    /// 품질 리포트
+    pub report: OhlcvQualityReport,
+    /// 누락 거래일 전체 목록 (리포트의 issues는 샘플만 보관)
+    pub missing_dates: Vec<NaiveDate>,
+}
+
+/// 품질 검사 통계.
+#[derive(Debug, Default)]
+pub struct DataQualityStats {
+    /// 검사한 시계열 수
+    pub checked: usize,
+    /// 품질 저하 시계열 수
+    pub degraded: usize,
+    /// 복구한 캔들 수
+    pub repaired_bars: usize,
+    /// 실패 수
+    pub failed: usize,
+    /// 점수 합계 (평균 계산용)
+    score_sum: f64,
+    /// 소요 시간
+    pub elapsed: Duration,
+}
+
+impl DataQualityStats {
+    /// 평균 품질 점수.
+    pub fn average_score(&self) -> f64 {
+        if self.checked == 0 {
+            0.0
+        } else {
+            self.score_sum / self.checked as f64
+        }
+    }
+
+    /// 통계 요약 로그 출력
+    pub fn log_summary(&self, operation: &str) {
+        tracing::info!(
+            operation = operation,
+            checked = self.checked,
+            degraded = self.degraded,
+            repaired_bars = self.repaired_bars,
+            failed = self.failed,
+            average_score = format!("{:.1}", self.average_score()),
+            elapsed = format!("{:.1}s", self.elapsed.as_secs_f64()),
+            "품질 검사 완료"
+        );
+    }
+}
+
+/// 캔들 시계열 품질 검사 (DB 접근 없음).
+///
+/// `klines`는 시간순으로 정렬되어 있어야 합니다.
+/// 누락 거래일은 일봉(`Timeframe::D1`)에서만 검사합니다.
+pub fn check_series(
+    symbol: &str,
+    timeframe: Timeframe,
+    klines: &[Kline],
+    calendar: &TradingCalendar,
+    spike_threshold: f64,
+) -> SeriesCheck {
+    let mut report = OhlcvQualityReport {
+        symbol: symbol.to_string(),
+        timeframe: timeframe_to_string(timeframe),
+        checked_at: Utc::now(),
+        range_start: klines.first().map(|k| k.open_time),
+        range_end: klines.last().map(|k| k.open_time),
+        total_bars: klines.len(),
+        zero_volume_bars: 0,
+        invalid_ohlc_bars: 0,
+        duplicate_bars: 0,
+        missing_days: 0,
+        spike_bars: 0,
+        repaired_bars: 0,
+        repair_sources: Vec::new(),
+        score: 0.0,
+        issues: Vec::new(),
+    };
+    let mut samples: HashMap<QualityIssueKind, usize> = HashMap::new();
+    let mut push_issue = |issues: &mut Vec<QualityIssue>, kind, time, detail: String| {
+        let count = samples.entry(kind).or_insert(0);
+        if *count < MAX_ISSUE_SAMPLES {
+            issues.push(QualityIssue { kind, time, detail });
+        }
+        *count += 1;
+    };
+
+    let daily_or_longer = timeframe.as_secs() >= Timeframe::D1.as_secs();
+    let mut seen: HashSet<i64> = HashSet::new();
+    let mut dates: BTreeSet<NaiveDate> = BTreeSet::new();
+    let mut prev_close: Option<Decimal> = None;
+
+    for kline in klines {
+        let date = calendar.local_date(kline.open_time);
+        dates.insert(date);
+
+        // 일봉 이상은 거래일 단위, 그 외는 시각 단위로 중복 판정
+        let key = if daily_or_longer {
+            date.num_days_from_ce() as i64
+        } else {
+            kline.open_time.timestamp()
+        };
+        if !seen.insert(key) {
+            report.duplicate_bars += 1;
+            push_issue(
+                &mut report.issues,
+                QualityIssueKind::DuplicateTimestamp,
+                kline.open_time,
+                format!("{} 중복 캔들", date),
+            );
+            continue;
+        }
+
+        if kline.volume.is_zero() {
+            report.zero_volume_bars += 1;
+            push_issue(
+                &mut report.issues,
+                QualityIssueKind::ZeroVolume,
+                kline.open_time,
+                format!("{} 거래량 0", date),
+            );
+        }
+
+        if let Some(reason) = ohlc_violation(kline) {
+            report.invalid_ohlc_bars += 1;
+            push_issue(
+                &mut report.issues,
+                QualityIssueKind::InvalidOhlc,
+                kline.open_time,
+                format!("{} {}", date, reason),
+            );
+            // 잘못된 가격은 급등락 기준에서 제외
+            continue;
+        }
+
+        if let Some(prev) = prev_close {
+            let change = (kline.close / prev - Decimal::ONE).abs();
+            if change.to_f64().unwrap_or(0.0) > spike_threshold {
+                report.spike_bars += 1;
+                push_issue(
+                    &mut report.issues,
+                    QualityIssueKind::PriceSpike,
+                    kline.open_time,
+                    format!(
+                        "{} 종가 {} → {} ({:.1}%)",
+                        date,
+                        prev,
+                        kline.close,
+                        change * Decimal::ONE_HUNDRED
+                    ),
+                );
+            }
+        }
+        prev_close = Some(kline.close);
+    }
+
+    let mut missing_dates = Vec::new();
+    if timeframe == Timeframe::D1 {
+        if let (Some(first), Some(last)) = (dates.first(), dates.last()) {
+            let covered_years: HashSet<i32> = calendar.holidays.iter().map(|d| d.year()).collect();
+            let mut date = *first;
+            while date < *last {
+                let checked = calendar.trades_on_weekends || covered_years.contains(&date.year());
+                if checked && calendar.is_trading_day(date) && !dates.contains(&date) {
+                    missing_dates.push(date);
+                }
+                date = date.succ_opt().unwrap_or(*last);
+            }
+        }
+    }
+    report.missing_days = missing_dates.len();
+    for date in &missing_dates {
+        push_issue(
+            &mut report.issues,
+            QualityIssueKind::MissingTradingDay,
+            date_time(*date),
+            format!("{} 거래일 캔들 없음", date),
+        );
+    }
+
+    report.score = quality_score(&report);
+    SeriesCheck {
+        report,
+        missing_dates,
+    }
+}
+
+/// 품질 점수 (0 ~ 100).
+///
+/// 기대 캔들 수(보유 + 누락) 대비 가중 문제 비율에 비례하여 감점합니다.
+/// 거래량 0은 거래정지일 수 있어 낮은 가중치를 적용합니다.
+fn quality_score(report: &OhlcvQualityReport) -> f64 {
+    if report.total_bars == 0 {
+        return 0.0;
+    }
+    let expected = (report.total_bars + report.missing_days) as f64;
+    let weighted = report.invalid_ohlc_bars as f64
+        + report.missing_days as f64
+        + report.spike_bars as f64
+        + report.duplicate_bars as f64 * 0.5
+        + report.zero_volume_bars as f64 * 0.3;
+    (100.0 - PENALTY_SCALE * 100.0 * weighted / expected).clamp(0.0, 100.0)
+}
+
+/// OHLC 관계 위반 사유.
+fn ohlc_violation(kline: &Kline) -> Option<&'static str> {
+    if kline.low <= Decimal::ZERO || kline.close <= Decimal::ZERO {
+        Some("0 이하 가격")
+    } else if kline.high < kline.low {
+        Some("high < low")
+    } else if kline.open > kline.high || kline.open < kline.low {
+        Some("시가가 고저 범위 밖")
+    } else if kline.close > kline.high || kline.close < kline.low {
+        Some("종가가 고저 범위 밖")
+    } else {
+        None
+    }
+}
+
+fn date_time(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()
+}
+
+/// 시장별 거래 캘린더 (국내는 스케줄러에 등록된 KRX 휴장일 반영).
+fn market_calendar(market: &str, scheduler: &Scheduler) -> TradingCalendar {
+    match market {
+        "KR" => TradingCalendar::krx().with_holidays(scheduler.holidays("KR")),
+        "US" => TradingCalendar::us(),
+        "CRYPTO" => TradingCalendar::always_open(),
+        // 휴장일 정보가 없는 시장은 누락 거래일 검사를 하지 않음
+        _ => TradingCalendar::us(),
+    }
+}
+
+/// DB 타임프레임 문자열 파싱 ("1d", "1wk" 등).
+fn parse_timeframe(s: &str) -> Option<Timeframe> {
+    match s {
+        "d1" => Some(Timeframe::D1),
+        "1w" | "w1" | "1wk" => Some(Timeframe::W1),
+        "1mo" => Some(Timeframe::MN1),
+        other => Timeframe::from_binance_interval(other),
+    }
+}
+
+// ============================================================================
+// 갭 복구
+// ============================================================================
+
+/// 누락 거래일을 대체 프로바이더에서 다시 가져오는 복구기.
+pub struct GapRepairer {
+    yahoo: Option<YahooProviderWrapper>,
+    kis: Option<KisClient>,
+    naver: NaverFinanceFetcher,
+    request_delay: Duration,
+}
+
+impl GapRepairer {
+    /// 설정에 따라 프로바이더 초기화.
+    ///
+    /// KIS는 `KIS_*` 환경변수(KisConfig::from_env)가 있을 때만 사용합니다.
+    pub fn new(pool: &PgPool, config: &CollectorConfig) -> Self {
+        let yahoo = if config.providers.yahoo_enabled {
+            YahooProviderWrapper::new(SymbolResolver::new(pool.clone()))
+                .map_err(|e| tracing::warn!(error = %e, "Yahoo 복구 프로바이더 초기화 실패"))
+                .ok()
+        } else {
+            None
+        };
+
+        let kis = KisConfig::from_env().and_then(|kis_config| {
+            KisOAuth::new(kis_config)
+                .and_then(|oauth| KisClient::new(Arc::new(oauth)))
+                .map_err(|e| tracing::warn!(error = %e, "KIS 복구 프로바이더 초기화 실패"))
+                .ok()
+        });
+        if kis.is_none() {
+            tracing::debug!("KIS 인증 정보 없음 - KIS 복구 건너뜀");
+        }
+
+        Self {
+            yahoo,
+            kis,
+            naver: NaverFinanceFetcher::with_delay(Duration::from_millis(
+                config.providers.naver_request_delay_ms,
+            )),
+            request_delay: config.data_quality.request_delay(),
+        }
+    }
+
+    /// 누락 거래일 복구용 일봉 조회.
+    ///
+    /// 프로바이더 체인을 순서대로 시도하며, 앞 프로바이더가 채우지 못한 날짜만
+    /// 다음 프로바이더에서 찾습니다. 반환 캔들의 시각은 `reference`(기존 시계열의 첫 캔들)와
+    /// 같은 규칙으로 맞춰 중복 캔들이 생기지 않도록 합니다.
+    pub async fn repair(
+        &self,
+        ticker: &str,
+        market: &str,
+        missing: &[NaiveDate],
+        calendar: &TradingCalendar,
+        reference: Option<&Kline>,
+    ) -> (Vec<Kline>, Vec<String>) {
+        let mut remaining: BTreeSet<NaiveDate> = missing.iter().copied().collect();
+        let mut repaired = Vec::new();
+        let mut sources = Vec::new();
+
+        for provider in RepairProvider::CHAIN {
+            let (Some(start), Some(end)) = (remaining.first().copied(), remaining.last().copied())
+            else {
+                break;
+            };
+            if provider.kr_only() && market != "KR" {
+                continue;
+            }
+
+            let bars = match self.fetch(provider, ticker, start, end, calendar).await {
+                Ok(bars) => bars,
+                Err(e) => {
+                    tracing::debug!(ticker, provider = provider.as_str(), error = %e, "복구 조회 실패");
+                    continue;
+                }
+            };
+
+            let before = repaired.len();
+            for (date, kline) in bars {
+                if ohlc_violation(&kline).is_none() && remaining.remove(&date) {
+                    repaired.push(align_to_series(kline, date, calendar, reference));
+                }
+            }
+            if repaired.len() > before {
+                sources.push(provider.as_str().to_string());
+            }
+
+            tokio::time::sleep(self.request_delay).await;
+        }
+
+        (repaired, sources)
+    }
+
+    /// 프로바이더에서 일봉 조회 (거래일, 캔들) 목록 반환.
+    async fn fetch(
+        &self,
+        provider: RepairProvider,
+        ticker: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+        calendar: &TradingCalendar,
+    ) -> std::result::Result<Vec<(NaiveDate, Kline)>, String> {
+        match provider {
+            RepairProvider::Yahoo => {
+                let yahoo = self.yahoo.as_ref().ok_or("Yahoo 비활성화")?;
+                // Yahoo 종료일은 미포함이므로 하루 연장
+                let end = end.succ_opt().unwrap_or(end);
+                let klines = yahoo
+                    .get_klines_range(ticker, Timeframe::D1, start, end)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok(klines
+                    .into_iter()
+                    .map(|k| (calendar.local_date(k.open_time), k))
+                    .collect())
+            }
+            RepairProvider::Kis => {
+                let kis = self.kis.as_ref().ok_or("KIS 인증 정보 없음")?;
+                let bars = kis
+                    .get_kr_daily_price(
+                        ticker,
+                        "D",
+                        &start.format("%Y%m%d").to_string(),
+                        &end.format("%Y%m%d").to_string(),
+                        true,
+                    )
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok(bars
+                    .into_iter()
+                    .filter_map(|bar| {
+                        let date = NaiveDate::parse_from_str(&bar.datetime, "%Y%m%d").ok()?;
+                        Some((
+                            date,
+                            daily_kline(
+                                ticker,
+                                date,
+                                [bar.open, bar.high, bar.low, bar.close],
+                                bar.volume,
+                            ),
+                        ))
+                    })
+                    .collect())
+            }
+            RepairProvider::Naver => {
+                let candles = self
+                    .naver
+                    .fetch_daily_ohlcv(ticker, start, end)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok(candles
+                    .into_iter()
+                    .map(|c| {
+                        (
+                            c.date,
+                            daily_kline(ticker, c.date, [c.open, c.high, c.low, c.close], c.volume),
+                        )
+                    })
+                    .collect())
+            }
+        }
+    }
+}
+
+/// 일봉 캔들 생성 (OHLC 순서의 가격 배열).
+fn daily_kline(ticker: &str, date: NaiveDate, ohlc: [Decimal; 4], volume: Decimal) -> Kline {
+    let open_time = date_time(date);
+    Kline {
+        ticker: ticker.to_string(),
+        timeframe: Timeframe::D1,
+        open_time,
+        open: ohlc[0],
+        high: ohlc[1],
+        low: ohlc[2],
+        close: ohlc[3],
+        volume,
+        close_time: open_time + chrono::Duration::days(1) - chrono::Duration::seconds(1),
+        quote_volume: None,
+        num_trades: None,
+    }
+}
+
+/// 복구 캔들 시각을 기존 시계열 규칙에 맞춤.
+///
+/// 프로바이더마다 일봉 시각이 다르므로(KRX 00:00 UTC, Yahoo 00:00 KST 등)
+/// 기존 캔들의 "거래일 00:00 UTC 대비 오프셋"을 그대로 적용합니다.
+fn align_to_series(
+    kline: Kline,
+    date: NaiveDate,
+    calendar: &TradingCalendar,
+    reference: Option<&Kline>,
+) -> Kline {
+    let offset = reference
+        .map(|r| r.open_time - date_time(calendar.local_date(r.open_time)))
+        .unwrap_or_else(chrono::Duration::zero);
+    let open_time = date_time(date) + offset;
+    Kline {
+        open_time,
+        close_time: open_time + chrono::Duration::days(1) - chrono::Duration::seconds(1),
+        ..kline
+    }
+}
+
+// ============================================================================
+// 워크플로우
+// ============================================================================
+
+/// OHLCV 품질 검사 실행.
+///
+/// `ohlcv_metadata`에 있는 심볼/타임프레임(설정된 타임프레임만)을 검사하고,
+/// `repair`가 true이면 일봉 누락 거래일을 대체 프로바이더로 복구한 뒤 다시 검사합니다.
+/// 최종 리포트는 `ohlcv_quality_report`에 저장됩니다.
+///
+/// # 인자
+/// * `symbols` - 특정 심볼만 검사 (쉼표로 구분, 예: "005930,000660")
+/// * `repair` - 누락 거래일 자동 복구 여부
+pub async fn check_ohlcv_quality(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    symbols: Option<String>,
+    repair: bool,
+) -> Result<DataQualityStats> {
+    let start = Instant::now();
+    let mut stats = DataQualityStats::default();
+    let quality_config: &DataQualityConfig = &config.data_quality;
+
+    let timeframes: Vec<String> = config
+        .ohlcv_collect
+        .timeframes
+        .iter()
+        .filter_map(|tf| parse_timeframe(tf))
+        .map(timeframe_to_string)
+        .collect();
+
+    let targets: Vec<(String, String, String)> = match symbols {
+        Some(ref s) => {
+            let tickers: Vec<&str> = s.split(',').map(|s| s.trim()).collect();
+            sqlx::query_as(
+                r#"
+                SELECT DISTINCT ON (m.symbol, m.timeframe) m.symbol, m.timeframe, COALESCE(si.market, '')
+                FROM ohlcv_metadata m
+                LEFT JOIN symbol_info si ON si.ticker = m.symbol
+                WHERE m.timeframe = ANY($1) AND m.symbol = ANY($2)
+                ORDER BY m.symbol, m.timeframe
+                "#,
+            )
+            .bind(&timeframes)
+            .bind(&tickers)
+            .fetch_all(pool)
+            .await?
+        }
+        None => {
+            sqlx::query_as(
+                r#"
+                SELECT DISTINCT ON (m.symbol, m.timeframe) m.symbol, m.timeframe, COALESCE(si.market, '')
+                FROM ohlcv_metadata m
+                LEFT JOIN symbol_info si ON si.ticker = m.symbol
+                WHERE m.timeframe = ANY($1)
+                ORDER BY m.symbol, m.timeframe
+                "#,
+            )
+            .bind(&timeframes)
+            .fetch_all(pool)
+            .await?
+        }
+    };
+
+    tracing::info!(
+        count = targets.len(),
+        timeframes = ?timeframes,
+        repair,
+        "OHLCV 품질 검사 시작"
+    );
+
+    let mut scheduler = Scheduler::new(&config.scheduling);
+    scheduler.load_kr_holidays_2025();
+    scheduler.load_kr_holidays_2026();
+
+    let cache = OhlcvCache::new(pool.clone());
+    let repository = OhlcvQualityRepository::new(pool.clone());
+    let repairer = repair.then(|| GapRepairer::new(pool, config));
+
+    let end = Utc::now();
+    let range_start = end - chrono::Duration::days(quality_config.lookback_days);
+
+    for (symbol, tf_str, market) in targets {
+        let Some(timeframe) = parse_timeframe(&tf_str) else {
+            continue;
+        };
+        let calendar = market_calendar(&market, &scheduler);
+
+        let result: Result<OhlcvQualityReport> = async {
+            let klines = cache
+                .get_cached_klines_range(&symbol, timeframe, range_start, end)
+                .await
+                .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+            let mut check = check_series(
+                &symbol,
+                timeframe,
+                &klines,
+                &calendar,
+                quality_config.spike_threshold,
+            );
+
+            if let Some(ref repairer) = repairer {
+                if !check.missing_dates.is_empty() {
+                    let (repaired, sources) = repairer
+                        .repair(
+                            &symbol,
+                            &market,
+                            &check.missing_dates,
+                            &calendar,
+                            klines.first(),
+                        )
+                        .await;
+                    if !repaired.is_empty() {
+                        cache
+                            .save_klines(&symbol, timeframe, &repaired)
+                            .await
+                            .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+
+                        // 복구 후 재검사
+                        let klines = cache
+                            .get_cached_klines_range(&symbol, timeframe, range_start, end)
+                            .await
+                            .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+                        check = check_series(
+                            &symbol,
+                            timeframe,
+                            &klines,
+                            &calendar,
+                            quality_config.spike_threshold,
+                        );
+                        check.report.repaired_bars = repaired.len();
+                        check.report.repair_sources = sources;
+                    }
+                }
+            }
+
+            repository
+                .save(&check.report)
+                .await
+                .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+            Ok(check.report)
+        }
+        .await;
+
+        match result {
+            Ok(report) => {
+                stats.checked += 1;
+                stats.score_sum += report.score;
+                stats.repaired_bars += report.repaired_bars;
+                if report.is_degraded() {
+                    stats.degraded += 1;
+                    tracing::warn!("{}", report.warning_message());
+                } else {
+                    tracing::debug!(
+                        symbol = %report.symbol,
+                        timeframe = %report.timeframe,
+                        score = report.score,
+                        "품질 검사 통과"
+                    );
+                }
+            }
+            Err(e) => {
+                stats.failed += 1;
+                tracing::error!(symbol = %symbol, timeframe = %tf_str, error = %e, "품질 검사 실패");
+            }
+        }
+    }
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn bar(date: NaiveDate, ohlc: [Decimal; 4], volume: Decimal) -> Kline {
+        daily_kline("005930", date, ohlc, volume)
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 10, d).unwrap()
+    }
+
+    fn kr_calendar() -> TradingCalendar {
+        let config = crate::config::SchedulingConfig {
+            enabled: true,
+            krx_delay_after_close_minutes: 60,
+            skip_weekends: true,
+            skip_holidays: true,
+            orchestrator_enabled: false,
+            job_schedules: Default::default(),
+            job_max_attempts: 3,
+            job_retry_backoff_secs: 30,
+            job_retry_max_backoff_secs: 600,
+        };
+        let mut scheduler = Scheduler::new(&config);
+        scheduler.load_kr_holidays_2025();
+        market_calendar("KR", &scheduler)
+    }
+
+    #[test]
+    fn test_clean_series_scores_full() {
+        // 2025-10-13(월) ~ 2025-10-17(금)
+        let klines: Vec<Kline> = (13..=17)
+            .map(|d| {
+                bar(
+                    day(d),
+                    [dec!(100), dec!(102), dec!(99), dec!(101)],
+                    dec!(1000),
+                )
+            })
+            .collect();
+
+        let check = check_series("005930", Timeframe::D1, &klines, &kr_calendar(), 0.35);
+        assert_eq!(check.report.issue_count(), 0);
+        assert_eq!(check.report.score, 100.0);
+        assert!(check.missing_dates.is_empty());
+    }
+
+    #[test]
+    fn test_detects_each_issue_kind() {
+        let normal = [dec!(100), dec!(102), dec!(99), dec!(101)];
+        let klines = vec![
+            bar(day(13), normal, dec!(1000)),
+            bar(day(13), normal, dec!(1000)), // 중복
+            bar(day(14), normal, dec!(0)),    // 거래량 0
+            bar(
+                day(15),
+                [dec!(100), dec!(98), dec!(99), dec!(99)],
+                dec!(1000),
+            ), // high < low
+            bar(
+                day(17),
+                [dec!(200), dec!(205), dec!(199), dec!(202)],
+                dec!(1000),
+            ), // 16일 누락 + 급등
+        ];
+
+        let check = check_series("005930", Timeframe::D1, &klines, &kr_calendar(), 0.35);
+        let report = &check.report;
+        assert_eq!(report.duplicate_bars, 1);
+        assert_eq!(report.zero_volume_bars, 1);
+        assert_eq!(report.invalid_ohlc_bars, 1);
+        assert_eq!(report.spike_bars, 1);
+        assert_eq!(check.missing_dates, vec![day(16)]);
+        assert!(report.is_degraded());
+    }
+
+    #[test]
+    fn test_holidays_are_not_missing() {
+        // 2025-10-03(금) 개천절, 10-06~07 추석 연휴, 10-09(목) 한글날
+        let normal = [dec!(100), dec!(102), dec!(99), dec!(101)];
+        let klines: Vec<Kline> = [2, 8, 10]
+            .into_iter()
+            .map(|d| bar(day(d), normal, dec!(1000)))
+            .collect();
+
+        let check = check_series("005930", Timeframe::D1, &klines, &kr_calendar(), 0.35);
+        assert!(check.missing_dates.is_empty());
+    }
+
+    #[test]
+    fn test_years_without_holidays_skip_missing_check() {
+        // 2024년은 휴장일 미등록 → 누락 거래일 검사 안 함
+        let normal = [dec!(100), dec!(102), dec!(99), dec!(101)];
+        let klines = vec![
+            bar(
+                NaiveDate::from_ymd_opt(2024, 9, 13).unwrap(),
+                normal,
+                dec!(1000),
+            ),
+            bar(
+                NaiveDate::from_ymd_opt(2024, 9, 19).unwrap(),
+                normal,
+                dec!(1000),
+            ),
+        ];
+
+        let check = check_series("005930", Timeframe::D1, &klines, &kr_calendar(), 0.35);
+        assert!(check.missing_dates.is_empty());
+    }
+
+    #[test]
+    fn test_align_to_series_uses_reference_offset() {
+        let calendar = kr_calendar();
+        // Yahoo 규칙: 거래일 00:00 KST = 전일 15:00 UTC
+        let reference = Kline {
+            open_time: day(13).and_hms_opt(0, 0, 0).unwrap().and_utc() - chrono::Duration::hours(9),
+            ..bar(day(13), [dec!(1), dec!(1), dec!(1), dec!(1)], dec!(1))
+        };
+        let repaired = bar(day(16), [dec!(1), dec!(1), dec!(1), dec!(1)], dec!(1));
+
+        let aligned = align_to_series(repaired, day(16), &calendar, Some(&reference));
+        assert_eq!(calendar.local_date(aligned.open_time), day(16));
+        assert_eq!(
+            aligned.open_time,
+            day(16).and_hms_opt(0, 0, 0).unwrap().and_utc() - chrono::Duration::hours(9)
+        );
+    }
+}
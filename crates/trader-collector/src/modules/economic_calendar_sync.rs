@@ -0,0 +1,170 @@
+//! 경제 이벤트 캘린더 수집 모듈.
+//!
+//! 다음 일정을 수집하여 `economic_event` 테이블에 저장합니다:
+//! - ICS/CSV 피드 (`ECONOMIC_CALENDAR_FEEDS`): FOMC, CPI, 한국은행 금통위, 고용지표 등
+//! - 규칙 기반 정기 일정: 옵션 만기일(KR 둘째 목요일, US 셋째 금요일), 지수 정기변경일
+//!
+//! 정기 일정은 과거 구간도 생성하므로 백테스트에서 과거 이벤트 날짜로 사용할 수 있습니다.
+//! 저장된 일정은 API 서버가 전략 컨텍스트와 리스크 관리자(이벤트 블랙아웃)에 전달합니다.
+
+use std::time::{Duration, Instant};
+
+use chrono::{Datelike, Months, NaiveDate, Utc};
+use sqlx::PgPool;
+use tracing::{info, warn};
+use trader_core::EconomicCalendar;
+use trader_data::{
+    provider::{EconomicCalendarFeed, EconomicCalendarFetcher},
+    EconomicEventRepository,
+};
+
+use crate::{CollectorConfig, CollectorError, Result};
+
+/// 경제 캘린더 동기화 옵션.
+#[derive(Debug, Clone, Default)]
+pub struct EconomicCalendarSyncOptions {
+    /// 피드 목록 (비어 있으면 `ECONOMIC_CALENDAR_FEEDS`)
+    pub feeds: Vec<String>,
+    /// 정기 일정 생성 시작일 (None이면 `ECONOMIC_CALENDAR_RECURRING_YEARS_BACK`년 전 1월 1일)
+    pub recurring_from: Option<NaiveDate>,
+    /// 정기 일정 생성 건너뛰기
+    pub skip_recurring: bool,
+}
+
+/// 경제 캘린더 동기화 통계.
+#[derive(Debug, Default)]
+pub struct EconomicCalendarSyncStats {
+    /// 처리한 피드 수
+    pub feeds: usize,
+    /// 피드에서 읽은 이벤트 수
+    pub feed_events: usize,
+    /// 생성된 정기 일정 수
+    pub recurring_events: usize,
+    /// 저장(삽입/갱신)된 이벤트 수
+    pub saved: usize,
+    /// 실패한 피드 수
+    pub failed: usize,
+    /// 소요 시간
+    pub elapsed: Duration,
+}
+
+impl EconomicCalendarSyncStats {
+    /// 통계 요약 로그 출력
+    pub fn log_summary(&self, operation: &str) {
+        info!(
+            operation = operation,
+            feeds = self.feeds,
+            feed_events = self.feed_events,
+            recurring_events = self.recurring_events,
+            saved = self.saved,
+            failed = self.failed,
+            elapsed = format!("{:.1}s", self.elapsed.as_secs_f64()),
+            "경제 캘린더 동기화 완료"
+        );
+    }
+}
+
+/// 경제 이벤트 캘린더 동기화.
+pub async fn sync_economic_calendar(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    options: EconomicCalendarSyncOptions,
+) -> Result<EconomicCalendarSyncStats> {
+    let start_time = Instant::now();
+    let mut stats = EconomicCalendarSyncStats::default();
+    let mut calendar = EconomicCalendar::new();
+
+    // 1. ICS/CSV 피드
+    let specs = if options.feeds.is_empty() {
+        &config.economic_calendar.feeds
+    } else {
+        &options.feeds
+    };
+    let feeds: Vec<EconomicCalendarFeed> = specs
+        .iter()
+        .filter_map(|spec| {
+            let feed = EconomicCalendarFeed::parse(spec);
+            if feed.is_none() {
+                warn!(spec = %spec, "잘못된 경제 캘린더 피드 설정");
+            }
+            feed
+        })
+        .collect();
+
+    let fetcher = EconomicCalendarFetcher::new();
+    for feed in &feeds {
+        stats.feeds += 1;
+        match fetcher.fetch(feed).await {
+            Ok(events) => {
+                info!(feed = %feed.location, count = events.len(), "경제 캘린더 피드 수집");
+                stats.feed_events += events.len();
+                calendar.extend(events);
+            }
+            Err(e) => {
+                warn!(feed = %feed.location, error = %e, "경제 캘린더 피드 수집 실패");
+                stats.failed += 1;
+            }
+        }
+    }
+
+    // 2. 규칙 기반 정기 일정 (옵션 만기, 지수 정기변경)
+    if !options.skip_recurring {
+        let (from, to) = recurring_range(
+            Utc::now().date_naive(),
+            options.recurring_from,
+            config.economic_calendar.recurring_years_back,
+            config.economic_calendar.recurring_months_ahead,
+        );
+        let recurring = EconomicCalendar::recurring(from, to);
+        info!(%from, %to, count = recurring.len(), "정기 일정 생성");
+        stats.recurring_events = recurring.len();
+        calendar.extend(recurring);
+    }
+
+    // 3. 저장
+    stats.saved = EconomicEventRepository::new(pool.clone())
+        .upsert_events(calendar.events())
+        .await
+        .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+
+    stats.elapsed = start_time.elapsed();
+    Ok(stats)
+}
+
+/// 정기 일정 생성 기간 (시작일 ~ 오늘 + N개월).
+fn recurring_range(
+    today: NaiveDate,
+    from: Option<NaiveDate>,
+    years_back: i32,
+    months_ahead: u32,
+) -> (NaiveDate, NaiveDate) {
+    let from = from.unwrap_or_else(|| {
+        NaiveDate::from_ymd_opt(today.year() - years_back.max(0), 1, 1).unwrap_or(today)
+    });
+    let to = today
+        .checked_add_months(Months::new(months_ahead))
+        .unwrap_or(today);
+    (from, to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_recurring_range() {
+        let today = date(2024, 8, 31);
+        assert_eq!(
+            recurring_range(today, None, 3, 12),
+            (date(2021, 1, 1), date(2025, 8, 31))
+        );
+        assert_eq!(
+            recurring_range(today, Some(date(2019, 6, 1)), 3, 1),
+            (date(2019, 6, 1), date(2024, 9, 30))
+        );
+    }
+}
@@ -145,10 +145,7 @@ async fn sync_market_data(
         }
     };
 
-    let all_trades: Vec<KrxDailyTrade> = kospi_trades
-        .into_iter()
-        .chain(kosdaq_trades.into_iter())
-        .collect();
+    let all_trades: Vec<KrxDailyTrade> = kospi_trades.into_iter().chain(kosdaq_trades).collect();
 
     info!(count = all_trades.len(), "일별 매매정보 조회 완료");
 
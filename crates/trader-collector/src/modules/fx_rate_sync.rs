@@ -0,0 +1,155 @@
+//! 환율 이력 수집 모듈.
+//!
+//! Yahoo Finance에서 통화쌍(`FX_SYNC_PAIRS`, 기본 `USD/KRW`)의 일별 종가를 조회하여
+//! `fx_rates` 테이블에 저장합니다. API 서버는 이 이력으로 포트폴리오/저널/자산 곡선을
+//! 기준 통화로 환산하고, 백테스트는 과거 환율로 환차손익을 분리합니다.
+//!
+//! 정기 실행은 최근 구간(`FX_SYNC_RANGE`)만 갱신하며, 과거 이력은
+//! `sync-fx-rates --range 10y`로 백필합니다.
+
+use std::time::{Duration, Instant};
+
+use sqlx::PgPool;
+use tracing::{info, warn};
+use trader_data::{cache::MacroDataProvider, FxRateRepository};
+
+use crate::{CollectorConfig, CollectorError, Result};
+
+/// 저장 시 출처 표시.
+const FX_SOURCE_MACRO: &str = "macro";
+
+/// 환율 동기화 옵션.
+#[derive(Debug, Clone, Default)]
+pub struct FxRateSyncOptions {
+    /// 통화쌍 목록 (`BASE/QUOTE`, 비어 있으면 `FX_SYNC_PAIRS`)
+    pub pairs: Vec<String>,
+    /// 조회 기간 (None이면 `FX_SYNC_RANGE`)
+    pub range: Option<String>,
+}
+
+/// 환율 동기화 통계.
+#[derive(Debug, Default)]
+pub struct FxRateSyncStats {
+    /// 처리한 통화쌍 수
+    pub pairs: usize,
+    /// 수신한 일별 환율 수
+    pub fetched: usize,
+    /// 저장(삽입/갱신)된 행 수
+    pub saved: usize,
+    /// 실패한 통화쌍 수
+    pub failed: usize,
+    /// 소요 시간
+    pub elapsed: Duration,
+}
+
+impl FxRateSyncStats {
+    /// 통계 요약 로그 출력
+    pub fn log_summary(&self, operation: &str) {
+        info!(
+            operation = operation,
+            pairs = self.pairs,
+            fetched = self.fetched,
+            saved = self.saved,
+            failed = self.failed,
+            elapsed = format!("{:.1}s", self.elapsed.as_secs_f64()),
+            "환율 동기화 완료"
+        );
+    }
+}
+
+/// 환율 이력 동기화.
+///
+/// 통화쌍 하나가 실패해도 나머지는 계속 수집하며, 모두 실패한 경우에만 에러를 반환합니다.
+pub async fn sync_fx_rates(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    options: FxRateSyncOptions,
+) -> Result<FxRateSyncStats> {
+    let start_time = Instant::now();
+    let mut stats = FxRateSyncStats::default();
+
+    let specs = if options.pairs.is_empty() {
+        &config.fx_rate.pairs
+    } else {
+        &options.pairs
+    };
+    let range = options.range.as_deref().unwrap_or(&config.fx_rate.range);
+
+    let provider =
+        MacroDataProvider::new().map_err(|e| CollectorError::DataSource(e.to_string()))?;
+    let repo = FxRateRepository::new(pool.clone());
+
+    for spec in specs {
+        let Some((base, quote)) = parse_pair(spec) else {
+            warn!(pair = %spec, "잘못된 통화쌍 설정 (BASE/QUOTE 형식 필요)");
+            stats.failed += 1;
+            continue;
+        };
+        stats.pairs += 1;
+
+        let rates = match provider.fetch_fx_history(&base, &quote, range).await {
+            Ok(rates) => rates,
+            Err(e) => {
+                warn!(pair = %spec, error = %e, "환율 조회 실패");
+                stats.failed += 1;
+                continue;
+            }
+        };
+        stats.fetched += rates.len();
+
+        match repo
+            .upsert_many(&base, &quote, &rates, FX_SOURCE_MACRO)
+            .await
+        {
+            Ok(saved) => {
+                info!(pair = %spec, fetched = rates.len(), saved, "환율 저장");
+                stats.saved += saved;
+            }
+            Err(e) => {
+                warn!(pair = %spec, error = %e, "환율 저장 실패");
+                stats.failed += 1;
+            }
+        }
+    }
+
+    stats.elapsed = start_time.elapsed();
+    if stats.saved == 0 && stats.failed > 0 {
+        return Err(CollectorError::DataSource(format!(
+            "환율 동기화 실패 ({}개 통화쌍)",
+            stats.failed
+        )));
+    }
+    Ok(stats)
+}
+
+/// `BASE/QUOTE` 통화쌍 파싱 (`USDKRW`처럼 6자리 붙여쓰기도 허용).
+fn parse_pair(spec: &str) -> Option<(String, String)> {
+    let spec = spec.trim().to_uppercase();
+    let (base, quote) = match spec.split_once('/') {
+        Some((base, quote)) => (base.trim().to_string(), quote.trim().to_string()),
+        None if spec.len() == 6 && spec.chars().all(|c| c.is_ascii_alphabetic()) => {
+            (spec[..3].to_string(), spec[3..].to_string())
+        }
+        None => return None,
+    };
+    (!base.is_empty() && !quote.is_empty() && base != quote).then_some((base, quote))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pair() {
+        assert_eq!(
+            parse_pair("usd/krw"),
+            Some(("USD".to_string(), "KRW".to_string()))
+        );
+        assert_eq!(
+            parse_pair("JPYKRW"),
+            Some(("JPY".to_string(), "KRW".to_string()))
+        );
+        assert_eq!(parse_pair("KRW/KRW"), None);
+        assert_eq!(parse_pair("USD"), None);
+    }
+}
@@ -1,6 +1,7 @@
 //! 분석 지표 동기화 모듈.
 //!
 //! RouteState, MarketRegime, TTM Squeeze 지표를 계산하여 symbol_fundamental 테이블에 저장합니다.
+//! RSI/MACD/볼린저/ATR/SuperTrend는 증분 지표 상태(`indicator_state`)에 새 캔들만 반영합니다.
 
 use std::time::Instant;
 
@@ -8,8 +9,12 @@ use chrono::{Duration, Utc};
 use rust_decimal::Decimal;
 use sqlx::{PgPool, Postgres, QueryBuilder};
 use tracing::{debug, info, warn};
-use trader_analytics::{indicators::IndicatorEngine, MarketRegimeCalculator, RouteStateCalculator};
+use trader_analytics::{
+    indicators::{IndicatorEngine, IndicatorState, IndicatorStateConfig},
+    MarketRegimeCalculator, RouteStateCalculator,
+};
 use trader_core::{Kline, Timeframe};
+use trader_data::IndicatorStateRepository;
 use uuid::Uuid;
 
 use super::{
@@ -19,6 +24,9 @@ use super::{
 };
 use crate::{config::CollectorConfig, error::CollectorError, stats::CollectionStats, Result};
 
+/// 증분 지표 상태 워밍업 시 조회할 최대 일봉 수 (사실상 전체 이력).
+const INDICATOR_STATE_WARMUP_CANDLES: i64 = 10_000;
+
 /// 지표 동기화 옵션
 #[derive(Debug, Default)]
 pub struct IndicatorSyncOptions {
@@ -36,7 +44,8 @@ pub struct IndicatorSyncOptions {
 /// 1. 지표가 오래된 심볼 목록 조회
 /// 2. 각 심볼에 대해 OHLCV 데이터 조회
 /// 3. RouteState, MarketRegime, TTM Squeeze 계산
-/// 4. DB에 저장
+/// 4. 증분 지표 상태에 새 캔들 반영 (상태가 없거나 공백이 있으면 전체 이력으로 워밍업)
+/// 5. DB에 저장
 ///
 /// # 인자
 /// * `pool` - 데이터베이스 연결 풀
@@ -65,6 +74,10 @@ pub async fn sync_indicators_with_options(
     let route_state_calc = RouteStateCalculator::new();
     let market_regime_calc = MarketRegimeCalculator::new();
     let indicator_engine = IndicatorEngine::new();
+    let state_repo = IndicatorStateRepository::new(pool.clone());
+    let state_config = IndicatorStateConfig::default();
+    let mut state_applied = 0usize;
+    let mut state_rewarmed = 0usize;
 
     // 체크포인트 로드 (resume 모드)
     let resume_ticker = if options.resume {
@@ -230,6 +243,13 @@ pub async fn sync_indicators_with_options(
             None
         };
 
+        // 증분 지표 상태 갱신 (실패해도 나머지 지표는 저장)
+        match update_indicator_state(pool, &state_repo, &state_config, &ticker, &candles).await {
+            Ok(IndicatorStateUpdate::Applied(n)) => state_applied += n,
+            Ok(IndicatorStateUpdate::Rewarmed) => state_rewarmed += 1,
+            Err(e) => warn!(ticker = %ticker, error = %e, "증분 지표 상태 갱신 실패"),
+        }
+
         // TTM Squeeze 계산 (20개 이상 필요)
         let (ttm_squeeze, ttm_squeeze_cnt) = if candles.len() >= 20 {
             calculate_ttm_squeeze(&indicator_engine, &candles)
@@ -266,6 +286,12 @@ pub async fn sync_indicators_with_options(
         }
     }
 
+    info!(
+        applied_candles = state_applied,
+        rewarmed = state_rewarmed,
+        "증분 지표 상태 갱신 완료"
+    );
+
     // 완료 상태 저장
     checkpoint::save_checkpoint(
         pool,
@@ -282,6 +308,65 @@ pub async fn sync_indicators_with_options(
 
 // to_screaming_snake_case, calculate_ttm_squeeze는 utils.rs로 이동됨
 
+/// 증분 지표 상태 갱신 결과.
+enum IndicatorStateUpdate {
+    /// 저장된 상태에 새 캔들 N개 반영
+    Applied(usize),
+    /// 상태가 없거나 사용할 수 없어 전체 이력으로 다시 계산
+    Rewarmed,
+}
+
+/// 증분 지표 상태 갱신.
+///
+/// 저장된 상태의 마지막 캔들이 `recent` 범위 안에 있으면 그 이후 캔들만 O(1)씩 반영합니다.
+/// 상태가 없거나, 파라미터가 바뀌었거나, 수집 공백으로 이어 붙일 수 없으면
+/// 전체 일봉 이력으로 다시 워밍업합니다.
+async fn update_indicator_state(
+    pool: &PgPool,
+    repo: &IndicatorStateRepository,
+    config: &IndicatorStateConfig,
+    ticker: &str,
+    recent: &[Kline],
+) -> Result<IndicatorStateUpdate> {
+    let timeframe = Timeframe::D1.to_string();
+    let stored = repo
+        .load(ticker, &timeframe)
+        .await
+        .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+    let restored = stored
+        .as_ref()
+        .and_then(|s| IndicatorState::from_stored(s, config))
+        .filter(|state| {
+            // 최근 캔들 범위와 겹쳐야 빠진 캔들 없이 이어 붙일 수 있음
+            matches!(
+                (state.last_open_time(), recent.first()),
+                (Some(last), Some(first)) if first.open_time <= last
+            )
+        });
+
+    let (state, result) = match restored {
+        Some(mut state) => {
+            let applied = state.update_all(recent);
+            if applied == 0 {
+                return Ok(IndicatorStateUpdate::Applied(0));
+            }
+            (state, IndicatorStateUpdate::Applied(applied))
+        }
+        None => {
+            let history = get_candles(pool, ticker, None, INDICATOR_STATE_WARMUP_CANDLES).await?;
+            let mut state = IndicatorState::new(ticker, Timeframe::D1, config);
+            state.update_all(&history);
+            (state, IndicatorStateUpdate::Rewarmed)
+        }
+    };
+
+    state
+        .save(repo)
+        .await
+        .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+    Ok(result)
+}
+
 /// 특정 티커로 심볼 조회.
 async fn get_symbols_by_tickers(
     pool: &PgPool,
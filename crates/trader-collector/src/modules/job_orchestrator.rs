@@ -0,0 +1,956 @@
+//! 수집 작업 오케스트레이터.
+//!
+//! 수집 작업을 의존성 DAG로 선언하고 cron 일정에 따라 실행합니다:
+//!
+//! ```text
+//! symbol_sync → ohlcv_collect → indicator_sync → global_score_sync → screening_refresh → sector_rs_refresh
+//! symbol_sync → fundamental_sync ────────────────↗
+//! symbol_sync → dart_sync
+//! ohlcv_collect → signal_performance_sync
+//! economic_calendar_sync (독립)
+//! fx_rate_sync (독립)
+//! storage_tiering (독립)
+//! ```
+//!
+//! - 일정(`SchedulingConfig`)이 도래한 작업과 그 하위 작업을 위상 순서로 실행합니다.
+//! - 선행 작업이 실패하면 하위 작업은 `skipped`로 기록하고 실행하지 않습니다.
+//! - 실패한 작업은 지수 백오프로 재시도합니다.
+//! - 모든 시도는 `collector_job_run` 테이블에 기록되어 API 서버에서 조회할 수 있습니다.
+//!
+//! cron 일정은 KRX 현지 시각 기준이며, 주말/공휴일 건너뛰기 설정을 따릅니다.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::{error, info, warn};
+use trader_core::{Country, TradingCalendar};
+use trader_data::{CollectorJobRunRepository, JobRunStatus, JobTrigger};
+use trader_strategy::{CronSchedule, ScheduleClock, ScheduleTrigger, StrategySchedule};
+
+use crate::{
+    config::SchedulingConfig,
+    modules::{self, Scheduler},
+    CollectionStats, CollectorConfig, CollectorError, Result,
+};
+
+/// 일정 확인 주기 (cron 최소 단위).
+const TICK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 수집 작업.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CollectorJob {
+    /// 심볼 정보 동기화 (KRX, Binance, Yahoo)
+    SymbolSync,
+    /// Fundamental 동기화 (KRX 또는 네이버, Yahoo)
+    FundamentalSync,
+    /// DART 전자공시 동기화
+    DartSync,
+    /// 경제 이벤트 캘린더 동기화
+    EconomicCalendarSync,
+    /// 환율 이력 동기화
+    FxRateSync,
+    /// OHLCV 수집 (24시간 증분)
+    OhlcvCollect,
+    /// 분석 지표 동기화
+    IndicatorSync,
+    /// GlobalScore 동기화
+    GlobalScoreSync,
+    /// 스크리닝 Materialized View 갱신
+    ScreeningRefresh,
+    /// 섹터 RS Materialized View 갱신
+    SectorRsRefresh,
+    /// 신호 성과 동기화
+    SignalPerformanceSync,
+    /// 계층형 저장소 (분봉 롤업, 분봉/체결 틱 아카이브)
+    StorageTiering,
+}
+
+impl CollectorJob {
+    /// 전체 작업 (선언 순서 = 기본 실행 순서).
+    pub const ALL: [CollectorJob; 12] = [
+        CollectorJob::SymbolSync,
+        CollectorJob::EconomicCalendarSync,
+        CollectorJob::FxRateSync,
+        CollectorJob::FundamentalSync,
+        CollectorJob::DartSync,
+        CollectorJob::OhlcvCollect,
+        CollectorJob::IndicatorSync,
+        CollectorJob::GlobalScoreSync,
+        CollectorJob::ScreeningRefresh,
+        CollectorJob::SectorRsRefresh,
+        CollectorJob::SignalPerformanceSync,
+        CollectorJob::StorageTiering,
+    ];
+
+    /// 작업 이름 (실행 이력 및 설정 키).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SymbolSync => "symbol_sync",
+            Self::FundamentalSync => "fundamental_sync",
+            Self::DartSync => "dart_sync",
+            Self::EconomicCalendarSync => "economic_calendar_sync",
+            Self::FxRateSync => "fx_rate_sync",
+            Self::OhlcvCollect => "ohlcv_collect",
+            Self::IndicatorSync => "indicator_sync",
+            Self::GlobalScoreSync => "global_score_sync",
+            Self::ScreeningRefresh => "screening_refresh",
+            Self::SectorRsRefresh => "sector_rs_refresh",
+            Self::SignalPerformanceSync => "signal_performance_sync",
+            Self::StorageTiering => "storage_tiering",
+        }
+    }
+
+    /// 이름에서 파싱 (`-` 구분자 허용).
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase().replace('-', "_");
+        Self::ALL.into_iter().find(|job| job.name() == name)
+    }
+
+    /// 선행 작업.
+    pub fn dependencies(&self) -> &'static [CollectorJob] {
+        match self {
+            Self::SymbolSync
+            | Self::EconomicCalendarSync
+            | Self::FxRateSync
+            | Self::StorageTiering => &[],
+            Self::FundamentalSync | Self::DartSync | Self::OhlcvCollect => &[Self::SymbolSync],
+            Self::IndicatorSync | Self::SignalPerformanceSync => &[Self::OhlcvCollect],
+            Self::GlobalScoreSync => &[Self::IndicatorSync, Self::FundamentalSync],
+            Self::ScreeningRefresh => &[Self::GlobalScoreSync],
+            Self::SectorRsRefresh => &[Self::ScreeningRefresh],
+        }
+    }
+
+    /// 설정상 활성화 여부 (비활성 작업은 실행하지 않고 하위 작업의 의존성에서 제외).
+    pub fn is_enabled(&self, config: &CollectorConfig) -> bool {
+        match self {
+            Self::DartSync => config.dart.enabled,
+            Self::EconomicCalendarSync => config.economic_calendar.enabled,
+            Self::FxRateSync => config.fx_rate.enabled,
+            Self::StorageTiering => {
+                config.storage_tiering.enabled && config.storage_tiering.archive_dir.is_some()
+            }
+            _ => true,
+        }
+    }
+
+    /// 기본 cron 일정 (None이면 선행 작업 완료 시에만 실행).
+    ///
+    /// OHLCV 수집은 KRX 장 마감(15:30) 후 `krx_delay_after_close_minutes`가 지난 시각입니다.
+    fn default_schedule(&self, config: &SchedulingConfig) -> Option<String> {
+        match self {
+            Self::SymbolSync => Some("0 8 * * *".to_string()),
+            Self::EconomicCalendarSync => Some("0 6 * * *".to_string()),
+            Self::FxRateSync => Some("0 7 * * *".to_string()),
+            Self::StorageTiering => Some("0 4 * * *".to_string()),
+            Self::DartSync => Some("0 9-18 * * *".to_string()),
+            Self::OhlcvCollect => {
+                let minutes =
+                    (15 * 60 + 30 + config.krx_delay_after_close_minutes).min(23 * 60 + 59);
+                Some(format!("{} {} * * *", minutes % 60, minutes / 60))
+            }
+            Self::IndicatorSync => Some("*/5 * * * *".to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CollectorJob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// DAG의 작업 명세.
+#[derive(Debug, Clone)]
+pub struct JobSpec {
+    /// 작업
+    pub job: CollectorJob,
+    /// 선행 작업
+    pub depends_on: Vec<CollectorJob>,
+    /// cron 일정 (None이면 선행 작업 완료 시에만 실행)
+    pub schedule: Option<CronSchedule>,
+    /// 활성화 여부
+    pub enabled: bool,
+}
+
+impl JobSpec {
+    /// 기본 선행 작업으로 명세 생성.
+    pub fn new(job: CollectorJob) -> Self {
+        Self {
+            job,
+            depends_on: job.dependencies().to_vec(),
+            schedule: None,
+            enabled: true,
+        }
+    }
+
+    /// cron 일정 설정.
+    pub fn with_schedule(mut self, schedule: CronSchedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    /// 선행 작업 교체.
+    pub fn with_dependencies(mut self, depends_on: Vec<CollectorJob>) -> Self {
+        self.depends_on = depends_on;
+        self
+    }
+}
+
+/// 작업 의존성 DAG (위상 정렬 순서로 보관).
+#[derive(Debug, Clone)]
+pub struct JobDag {
+    specs: Vec<JobSpec>,
+}
+
+impl JobDag {
+    /// 명세 목록으로 DAG 생성 (순환 또는 미등록 선행 작업이면 에러).
+    pub fn new(specs: Vec<JobSpec>) -> Result<Self> {
+        for spec in &specs {
+            if let Some(missing) = spec
+                .depends_on
+                .iter()
+                .find(|dep| !specs.iter().any(|s| s.job == **dep))
+            {
+                return Err(CollectorError::Scheduling(format!(
+                    "작업 {}의 선행 작업 {}이(가) 등록되지 않았습니다",
+                    spec.job, missing
+                )));
+            }
+        }
+
+        // Kahn 알고리즘 (같은 단계에서는 입력 순서 유지)
+        let mut remaining = specs;
+        let mut sorted: Vec<JobSpec> = Vec::with_capacity(remaining.len());
+        while !remaining.is_empty() {
+            let ready = remaining.iter().position(|spec| {
+                spec.depends_on
+                    .iter()
+                    .all(|dep| sorted.iter().any(|s| s.job == *dep))
+            });
+            match ready {
+                Some(idx) => sorted.push(remaining.remove(idx)),
+                None => {
+                    let cycle: Vec<&str> = remaining.iter().map(|s| s.job.name()).collect();
+                    return Err(CollectorError::Scheduling(format!(
+                        "작업 의존성에 순환이 있습니다: {}",
+                        cycle.join(", ")
+                    )));
+                }
+            }
+        }
+
+        Ok(Self { specs: sorted })
+    }
+
+    /// 수집기 설정으로 DAG 생성.
+    ///
+    /// `SchedulingConfig::job_schedules`의 작업별 cron이 기본 일정을 대체하며,
+    /// `off`/`none`을 지정하면 선행 작업 완료 시에만 실행합니다.
+    pub fn from_config(config: &CollectorConfig) -> Result<Self> {
+        let specs = CollectorJob::ALL
+            .into_iter()
+            .map(|job| {
+                let expr = match config.scheduling.job_schedules.get(job.name()) {
+                    Some(expr) if matches!(expr.trim(), "" | "off" | "none") => None,
+                    Some(expr) => Some(expr.clone()),
+                    None => job.default_schedule(&config.scheduling),
+                };
+                let mut spec = JobSpec::new(job);
+                spec.enabled = job.is_enabled(config);
+                if let Some(expr) = expr {
+                    let schedule = expr.parse::<CronSchedule>().map_err(|e| {
+                        CollectorError::Scheduling(format!("{} 일정 오류: {}", job, e))
+                    })?;
+                    spec = spec.with_schedule(schedule);
+                }
+                Ok(spec)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(specs)
+    }
+
+    /// 위상 정렬된 작업 명세.
+    pub fn specs(&self) -> &[JobSpec] {
+        &self.specs
+    }
+
+    /// 작업 명세 조회.
+    pub fn get(&self, job: CollectorJob) -> Option<&JobSpec> {
+        self.specs.iter().find(|spec| spec.job == job)
+    }
+
+    /// 실행 계획 (위상 순서).
+    ///
+    /// `include_downstream`이면 시작 작업의 모든 하위 작업을 포함합니다.
+    /// 비활성 작업은 제외됩니다.
+    pub fn plan(&self, roots: &[CollectorJob], include_downstream: bool) -> Vec<CollectorJob> {
+        let mut selected: Vec<CollectorJob> = roots.to_vec();
+        if include_downstream {
+            for spec in &self.specs {
+                if !selected.contains(&spec.job)
+                    && spec.depends_on.iter().any(|dep| selected.contains(dep))
+                {
+                    selected.push(spec.job);
+                }
+            }
+        }
+
+        self.specs
+            .iter()
+            .filter(|spec| spec.enabled && selected.contains(&spec.job))
+            .map(|spec| spec.job)
+            .collect()
+    }
+
+    /// cron 일정이 있는 작업의 일정 (트리거 이름 = 작업 이름).
+    fn schedule(&self) -> StrategySchedule {
+        self.specs
+            .iter()
+            .filter(|spec| spec.enabled)
+            .filter_map(|spec| {
+                let schedule = spec.schedule.clone()?;
+                Some(ScheduleTrigger::Cron {
+                    name: spec.job.name().to_string(),
+                    schedule,
+                })
+            })
+            .fold(
+                StrategySchedule::new(Country::KR),
+                StrategySchedule::with_trigger,
+            )
+    }
+}
+
+/// 재시도 정책 (지수 백오프).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// 최대 시도 횟수 (첫 시도 포함)
+    pub max_attempts: u32,
+    /// 첫 재시도 대기 시간
+    pub initial_backoff: Duration,
+    /// 최대 대기 시간
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// 스케줄링 설정에서 생성.
+    pub fn from_config(config: &SchedulingConfig) -> Self {
+        Self {
+            max_attempts: config.job_max_attempts.max(1),
+            initial_backoff: Duration::from_secs(config.job_retry_backoff_secs),
+            max_backoff: Duration::from_secs(config.job_retry_max_backoff_secs),
+        }
+    }
+
+    /// `attempt`번째 시도 실패 후 대기 시간 (매 시도마다 2배, 최대값 제한).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// 작업 실행 결과.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JobOutcome {
+    /// 삽입/갱신된 행 수
+    pub rows_touched: i64,
+    /// 개별 항목 오류 수
+    pub error_count: i64,
+}
+
+impl From<&CollectionStats> for JobOutcome {
+    fn from(stats: &CollectionStats) -> Self {
+        let rows = if stats.total_klines > 0 {
+            stats.total_klines
+        } else {
+            stats.success
+        };
+        Self {
+            rows_touched: rows as i64,
+            error_count: stats.errors as i64,
+        }
+    }
+}
+
+/// 작업 하나를 실행합니다 (재시도/이력 기록 없음).
+///
+/// 데몬의 고정 그룹 워크플로우와 오케스트레이터가 같은 실행 경로를 공유합니다.
+pub async fn run_job(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    job: CollectorJob,
+) -> Result<JobOutcome> {
+    match job {
+        CollectorJob::SymbolSync => {
+            let stats = modules::sync_symbols(pool, config).await?;
+            stats.log_summary("심볼 동기화");
+            Ok(JobOutcome::from(&stats))
+        }
+        CollectorJob::FundamentalSync => sync_fundamentals(pool, config).await,
+        CollectorJob::DartSync => {
+            let stats =
+                modules::sync_dart(pool, config, modules::DartSyncOptions::default()).await?;
+            stats.log_summary("DART 동기화");
+            Ok(JobOutcome {
+                rows_touched: (stats.disclosures_saved
+                    + stats.statements_saved
+                    + stats.shareholder_changes_saved) as i64,
+                error_count: stats.failed as i64,
+            })
+        }
+        CollectorJob::EconomicCalendarSync => {
+            let stats = modules::sync_economic_calendar(
+                pool,
+                config,
+                modules::EconomicCalendarSyncOptions::default(),
+            )
+            .await?;
+            stats.log_summary("경제 캘린더 동기화");
+            Ok(JobOutcome {
+                rows_touched: stats.saved as i64,
+                error_count: stats.failed as i64,
+            })
+        }
+        CollectorJob::FxRateSync => {
+            let stats =
+                modules::sync_fx_rates(pool, config, modules::FxRateSyncOptions::default()).await?;
+            stats.log_summary("환율 동기화");
+            Ok(JobOutcome {
+                rows_touched: stats.saved as i64,
+                error_count: stats.failed as i64,
+            })
+        }
+        CollectorJob::StorageTiering => {
+            let stats = modules::run_storage_tiering(
+                pool,
+                config,
+                modules::StorageTieringOptions::default(),
+            )
+            .await?;
+            stats.log_summary("계층형 저장소");
+            Ok(JobOutcome {
+                rows_touched: stats.tiering.deleted_rows as i64,
+                error_count: stats.tiering.failed as i64,
+            })
+        }
+        CollectorJob::OhlcvCollect => {
+            // 24시간 증분 수집
+            let stats = modules::collect_ohlcv(pool, config, None, Some(24)).await?;
+            stats.log_summary("OHLCV 수집");
+            Ok(JobOutcome::from(&stats))
+        }
+        CollectorJob::IndicatorSync => {
+            let options = modules::IndicatorSyncOptions {
+                batch_size: Some(0), // 제한 없음: DB 계산 워크플로우
+                ..Default::default()
+            };
+            let stats = modules::sync_indicators_with_options(pool, config, None, options).await?;
+            log_if_processed(&stats, "지표 동기화");
+            Ok(JobOutcome::from(&stats))
+        }
+        CollectorJob::GlobalScoreSync => {
+            let options = modules::GlobalScoreSyncOptions {
+                batch_size: Some(0), // 제한 없음: DB 계산 워크플로우
+                ..Default::default()
+            };
+            let stats =
+                modules::sync_global_scores_with_options(pool, config, None, options).await?;
+            log_if_processed(&stats, "GlobalScore 동기화");
+            Ok(JobOutcome::from(&stats))
+        }
+        CollectorJob::ScreeningRefresh => {
+            let stats = modules::refresh_screening_view(pool).await?;
+            log_if_processed(&stats, "스크리닝 뷰 갱신");
+            Ok(JobOutcome::from(&stats))
+        }
+        CollectorJob::SectorRsRefresh => {
+            let stats = modules::refresh_sector_rs_view(pool).await?;
+            log_if_processed(&stats, "섹터 RS 뷰 갱신");
+            Ok(JobOutcome::from(&stats))
+        }
+        CollectorJob::SignalPerformanceSync => {
+            let options = modules::SignalPerformanceSyncOptions::from(&config.signal_performance);
+            let stats = modules::sync_signal_performance(pool, options).await?;
+            log_if_processed(&stats, "신호 성과 동기화");
+            Ok(JobOutcome::from(&stats))
+        }
+    }
+}
+
+/// 처리 건이 있을 때만 요약 로그 출력 (짧은 주기의 DB 계산 작업용).
+fn log_if_processed(stats: &CollectionStats, operation: &str) {
+    if stats.success > 0 {
+        stats.log_summary(operation);
+    }
+}
+
+/// Fundamental 동기화 (KRX API 우선, 없으면 네이버 금융 + Yahoo Finance).
+///
+/// KR 동기화가 실패해도 Yahoo 동기화는 실행하고, 그 후 KR 실패를 반환합니다.
+async fn sync_fundamentals(pool: &PgPool, config: &CollectorConfig) -> Result<JobOutcome> {
+    let mut outcome = JobOutcome::default();
+
+    let kr_result = if config.providers.krx_api_enabled {
+        Some(modules::sync_krx_fundamentals(pool, &config.fundamental_collect).await)
+    } else if config.providers.naver_enabled {
+        let naver_options = modules::NaverSyncOptions {
+            request_delay_ms: config.providers.naver_request_delay_ms,
+            batch_size: None,
+            resume: false,
+            stale_hours: Some(config.fundamental_collect.stale_days as u32 * 24),
+            force: false, // 기존 값 보존
+            concurrent_limit: None,
+        };
+        Some(modules::sync_naver_fundamentals_with_options(pool, naver_options).await)
+    } else {
+        None
+    };
+    let kr_error = match kr_result {
+        Some(Ok(stats)) => {
+            info!(
+                source = %stats.data_source,
+                processed = stats.processed,
+                valuation = stats.valuation_updated,
+                sector = stats.sector_updated,
+                "KR Fundamental 동기화 완료"
+            );
+            outcome.rows_touched += stats.processed as i64;
+            outcome.error_count += stats.failed as i64;
+            None
+        }
+        Some(Err(e)) => Some(e),
+        None => None,
+    };
+
+    // US/글로벌 시장
+    if config.providers.yahoo_enabled {
+        let yahoo_options = modules::YahooSyncOptions {
+            request_delay_ms: config.fundamental_collect.request_delay_ms,
+            batch_size: Some(100),
+            market_filter: None,
+            resume: false,
+            stale_hours: Some(config.fundamental_collect.stale_days as u32 * 24),
+            force: false, // 기존 값 보존
+        };
+        let stats = modules::sync_yahoo_fundamentals(pool, yahoo_options).await?;
+        info!(
+            processed = stats.processed,
+            valuation = stats.valuation_updated,
+            market_cap = stats.market_cap_updated,
+            "Yahoo Fundamental 동기화 완료"
+        );
+        outcome.rows_touched += stats.processed as i64;
+        outcome.error_count += stats.failed as i64;
+    }
+
+    match kr_error {
+        Some(e) => Err(e),
+        None => Ok(outcome),
+    }
+}
+
+/// 실행 계획 결과.
+#[derive(Debug, Default)]
+pub struct JobPlanReport {
+    /// 작업별 최종 상태 (실행 순서)
+    pub results: Vec<(CollectorJob, JobRunStatus)>,
+}
+
+impl JobPlanReport {
+    /// 최종 상태 조회.
+    pub fn status(&self, job: CollectorJob) -> Option<JobRunStatus> {
+        self.results
+            .iter()
+            .find(|(j, _)| *j == job)
+            .map(|(_, status)| *status)
+    }
+
+    /// 실패한 작업 수.
+    pub fn failed(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|(_, status)| *status == JobRunStatus::Failed)
+            .count()
+    }
+
+    /// 요약 로그 출력.
+    pub fn log_summary(&self) {
+        let summary: Vec<String> = self
+            .results
+            .iter()
+            .map(|(job, status)| format!("{}={}", job, status))
+            .collect();
+        if self.failed() > 0 {
+            warn!(jobs = %summary.join(", "), "작업 실행 계획 완료 (실패 포함)");
+        } else {
+            info!(jobs = %summary.join(", "), "작업 실행 계획 완료");
+        }
+    }
+}
+
+/// 수집 작업 오케스트레이터.
+pub struct JobOrchestrator {
+    pool: PgPool,
+    config: CollectorConfig,
+    dag: JobDag,
+    retry: RetryPolicy,
+    calendar: TradingCalendar,
+    schedule: StrategySchedule,
+    clock: ScheduleClock,
+    runs: CollectorJobRunRepository,
+}
+
+impl JobOrchestrator {
+    /// 설정으로 오케스트레이터 생성 (cron 일정 오류 시 에러).
+    pub fn new(pool: PgPool, config: CollectorConfig) -> Result<Self> {
+        let dag = JobDag::from_config(&config)?;
+        let retry = RetryPolicy::from_config(&config.scheduling);
+        let calendar = schedule_calendar(&config.scheduling);
+        let schedule = dag.schedule();
+        Ok(Self {
+            runs: CollectorJobRunRepository::new(pool.clone()),
+            pool,
+            config,
+            dag,
+            retry,
+            calendar,
+            schedule,
+            clock: ScheduleClock::new(),
+        })
+    }
+
+    /// 작업 DAG.
+    pub fn dag(&self) -> &JobDag {
+        &self.dag
+    }
+
+    /// 실행 이력 저장소.
+    pub fn runs(&self) -> &CollectorJobRunRepository {
+        &self.runs
+    }
+
+    /// `now`까지 일정이 도래한 작업과 하위 작업을 실행합니다.
+    ///
+    /// 첫 호출은 기준 시각만 설정하며 과거 일정을 소급 실행하지 않습니다.
+    pub async fn run_due(&mut self, now: DateTime<Utc>) -> Option<JobPlanReport> {
+        let due: Vec<CollectorJob> = self
+            .clock
+            .advance(&self.schedule, &self.calendar, now)
+            .iter()
+            .filter_map(|event| CollectorJob::parse(event.name()))
+            .collect();
+        if due.is_empty() {
+            return None;
+        }
+        Some(self.run(&due, true, JobTrigger::Schedule).await)
+    }
+
+    /// 작업 실행 (위상 순서, 선행 작업 실패 시 하위 작업 건너뜀).
+    ///
+    /// 시작 작업은 `trigger`로, 연쇄 실행되는 하위 작업은 `dependency`로 기록합니다.
+    pub async fn run(
+        &self,
+        roots: &[CollectorJob],
+        include_downstream: bool,
+        trigger: JobTrigger,
+    ) -> JobPlanReport {
+        let plan = self.dag.plan(roots, include_downstream);
+        let mut report = JobPlanReport::default();
+
+        for job in plan {
+            let job_trigger = if roots.contains(&job) {
+                trigger
+            } else {
+                JobTrigger::Dependency
+            };
+
+            let blocked = self.dag.get(job).and_then(|spec| {
+                spec.depends_on.iter().find_map(|dep| {
+                    report
+                        .status(*dep)
+                        .filter(|status| *status != JobRunStatus::Success)
+                        .map(|status| (*dep, status))
+                })
+            });
+            if let Some((dep, status)) = blocked {
+                let reason = format!("선행 작업 {} {}", dep, status);
+                warn!(job = %job, reason = %reason, "작업 건너뜀");
+                if let Err(e) = self
+                    .runs
+                    .record_skipped(job.name(), job_trigger, &reason)
+                    .await
+                {
+                    warn!(job = %job, error = %e, "작업 실행 이력 기록 실패");
+                }
+                report.results.push((job, JobRunStatus::Skipped));
+                continue;
+            }
+
+            let status = self.run_with_retry(job, job_trigger).await;
+            report.results.push((job, status));
+        }
+
+        report
+    }
+
+    /// 재시도를 포함하여 작업 하나를 실행하고 시도마다 이력을 기록합니다.
+    async fn run_with_retry(&self, job: CollectorJob, trigger: JobTrigger) -> JobRunStatus {
+        for attempt in 1..=self.retry.max_attempts {
+            let run_id = match self.runs.start(job.name(), trigger, attempt as i32).await {
+                Ok(id) => Some(id),
+                Err(e) => {
+                    warn!(job = %job, error = %e, "작업 실행 이력 기록 실패");
+                    None
+                }
+            };
+
+            info!(job = %job, attempt, trigger = trigger.as_str(), "작업 시작");
+            let result = run_job(&self.pool, &self.config, job).await;
+
+            let (status, outcome, message) = match &result {
+                Ok(outcome) => (JobRunStatus::Success, *outcome, None),
+                Err(e) => (
+                    JobRunStatus::Failed,
+                    JobOutcome::default(),
+                    Some(e.to_string()),
+                ),
+            };
+            if let Some(id) = run_id {
+                if let Err(e) = self
+                    .runs
+                    .finish(
+                        id,
+                        status,
+                        outcome.rows_touched,
+                        outcome.error_count,
+                        message.as_deref(),
+                    )
+                    .await
+                {
+                    warn!(job = %job, error = %e, "작업 실행 이력 기록 실패");
+                }
+            }
+
+            match result {
+                Ok(_) => return JobRunStatus::Success,
+                Err(e) if attempt < self.retry.max_attempts => {
+                    let backoff = self.retry.backoff(attempt);
+                    warn!(
+                        job = %job,
+                        attempt,
+                        error = %e,
+                        retry_in = format!("{}s", backoff.as_secs()),
+                        "작업 실패, 재시도 예정"
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+                Err(e) => {
+                    error!(job = %job, attempt, error = %e, "작업 실패 (재시도 소진)");
+                }
+            }
+        }
+        JobRunStatus::Failed
+    }
+
+    /// 데몬 루프: 1분마다 일정을 확인하여 도래한 작업을 실행합니다.
+    ///
+    /// 이전 프로세스에서 종료되지 않은 실행은 시작 시 `interrupted`로 표시합니다.
+    pub async fn run_daemon(mut self, mut shutdown: tokio::sync::broadcast::Receiver<()>) {
+        match self.runs.mark_interrupted().await {
+            Ok(count) if count > 0 => warn!(count, "이전 실행 중단 처리"),
+            Ok(_) => {}
+            Err(e) => warn!(error = %e, "중단된 작업 실행 정리 실패"),
+        }
+
+        self.clock.reset(Utc::now());
+        let scheduled: Vec<String> = self
+            .dag
+            .specs()
+            .iter()
+            .filter(|spec| spec.enabled)
+            .map(|spec| match &spec.schedule {
+                Some(schedule) => format!("{} [{}]", spec.job, schedule),
+                None => format!("{} [← {}]", spec.job, join_jobs(&spec.depends_on)),
+            })
+            .collect();
+        info!(
+            "[Jobs] 작업 오케스트레이터 시작:\n  {}",
+            scheduled.join("\n  ")
+        );
+
+        let mut interval = tokio::time::interval(TICK_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.recv() => {
+                    info!("[Jobs] 종료 신호 수신");
+                    break;
+                }
+                _ = interval.tick() => {
+                    tokio::select! {
+                        report = self.run_due(Utc::now()) => {
+                            if let Some(report) = report {
+                                report.log_summary();
+                            }
+                        }
+                        _ = shutdown.recv() => {
+                            info!("[Jobs] 작업 실행 중 종료 신호 수신");
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 작업 이름 목록 문자열.
+fn join_jobs(jobs: &[CollectorJob]) -> String {
+    jobs.iter().map(|j| j.name()).collect::<Vec<_>>().join(", ")
+}
+
+/// cron 일정용 KRX 캘린더 (주말/공휴일 건너뛰기 설정 반영).
+fn schedule_calendar(config: &SchedulingConfig) -> TradingCalendar {
+    let mut calendar = TradingCalendar::krx();
+    calendar.trades_on_weekends = !config.skip_weekends;
+    if config.skip_holidays {
+        let mut scheduler = Scheduler::new(config);
+        scheduler.load_kr_holidays_2025();
+        scheduler.load_kr_holidays_2026();
+        calendar.add_holidays(scheduler.holidays("KR"));
+    }
+    calendar
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn scheduling() -> SchedulingConfig {
+        SchedulingConfig {
+            enabled: true,
+            krx_delay_after_close_minutes: 60,
+            skip_weekends: true,
+            skip_holidays: false,
+            orchestrator_enabled: true,
+            job_schedules: Default::default(),
+            job_max_attempts: 3,
+            job_retry_backoff_secs: 30,
+            job_retry_max_backoff_secs: 600,
+        }
+    }
+
+    fn default_dag() -> JobDag {
+        let config = scheduling();
+        let specs = CollectorJob::ALL
+            .into_iter()
+            .map(|job| match job.default_schedule(&config) {
+                Some(expr) => JobSpec::new(job).with_schedule(expr.parse().unwrap()),
+                None => JobSpec::new(job),
+            })
+            .collect();
+        JobDag::new(specs).unwrap()
+    }
+
+    #[test]
+    fn test_dag_order_and_plan() {
+        let dag = default_dag();
+        let order: Vec<CollectorJob> = dag.specs().iter().map(|s| s.job).collect();
+        for spec in dag.specs() {
+            let idx = order.iter().position(|j| *j == spec.job).unwrap();
+            for dep in &spec.depends_on {
+                assert!(order.iter().position(|j| j == dep).unwrap() < idx);
+            }
+        }
+
+        // OHLCV 수집 후 지표 → GlobalScore → 스크리닝 → 섹터 RS, 신호 성과가 연쇄 실행
+        assert_eq!(
+            dag.plan(&[CollectorJob::OhlcvCollect], true),
+            vec![
+                CollectorJob::OhlcvCollect,
+                CollectorJob::IndicatorSync,
+                CollectorJob::GlobalScoreSync,
+                CollectorJob::ScreeningRefresh,
+                CollectorJob::SectorRsRefresh,
+                CollectorJob::SignalPerformanceSync,
+            ]
+        );
+        assert_eq!(
+            dag.plan(&[CollectorJob::ScreeningRefresh], false),
+            vec![CollectorJob::ScreeningRefresh]
+        );
+        assert_eq!(
+            CollectorJob::parse("global-score-sync"),
+            Some(CollectorJob::GlobalScoreSync)
+        );
+        assert_eq!(
+            dag.plan(&[CollectorJob::FxRateSync], true),
+            vec![CollectorJob::FxRateSync]
+        );
+        assert_eq!(
+            dag.plan(&[CollectorJob::StorageTiering], true),
+            vec![CollectorJob::StorageTiering]
+        );
+    }
+
+    #[test]
+    fn test_dag_rejects_cycle() {
+        let specs = vec![
+            JobSpec::new(CollectorJob::SymbolSync)
+                .with_dependencies(vec![CollectorJob::OhlcvCollect]),
+            JobSpec::new(CollectorJob::OhlcvCollect),
+        ];
+        assert!(JobDag::new(specs).is_err());
+
+        // 미등록 선행 작업
+        let specs = vec![JobSpec::new(CollectorJob::OhlcvCollect)];
+        assert!(JobDag::new(specs).is_err());
+    }
+
+    #[test]
+    fn test_default_schedule_and_due_jobs() {
+        let dag = default_dag();
+        let schedule = dag.schedule();
+        let calendar = schedule_calendar(&scheduling());
+        let mut clock =
+            ScheduleClock::starting_at(Utc.with_ymd_and_hms(2025, 3, 4, 7, 20, 0).unwrap());
+
+        // 16:30 KST (07:30 UTC) - 장 마감 60분 후 OHLCV 수집, 5분 주기 지표 동기화
+        let events = clock.advance(
+            &schedule,
+            &calendar,
+            Utc.with_ymd_and_hms(2025, 3, 4, 7, 30, 0).unwrap(),
+        );
+        let names: Vec<&str> = events.iter().map(|e| e.name()).collect();
+        assert!(names.contains(&"ohlcv_collect"));
+        assert!(names.contains(&"indicator_sync"));
+
+        // 주말에는 실행하지 않음
+        let mut clock =
+            ScheduleClock::starting_at(Utc.with_ymd_and_hms(2025, 3, 8, 7, 20, 0).unwrap());
+        assert!(clock
+            .advance(
+                &schedule,
+                &calendar,
+                Utc.with_ymd_and_hms(2025, 3, 8, 7, 30, 0).unwrap()
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        let policy = RetryPolicy::from_config(&scheduling());
+        assert_eq!(policy.max_attempts, 3);
+        assert_eq!(policy.backoff(1), Duration::from_secs(30));
+        assert_eq!(policy.backoff(2), Duration::from_secs(60));
+        assert_eq!(policy.backoff(10), Duration::from_secs(600));
+    }
+}
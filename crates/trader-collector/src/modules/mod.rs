@@ -1,15 +1,21 @@
 //! 데이터 수집 모듈.
 
 pub mod checkpoint;
+pub mod dart_sync;
+pub mod data_quality;
+pub mod economic_calendar_sync;
 pub mod fundamental_sync;
+pub mod fx_rate_sync;
 pub mod global_score_sync;
 pub mod indicator_sync;
+pub mod job_orchestrator;
 pub mod macro_data_sync;
 pub mod market_breadth_sync;
 pub mod ohlcv_collect;
 pub mod scheduler;
 pub mod screening_refresh;
 pub mod signal_performance_sync;
+pub mod storage_tiering;
 pub mod symbol_sync;
 pub mod utils;
 pub mod watchlist_helper;
@@ -17,15 +23,26 @@ pub mod watchlist_helper;
 pub use checkpoint::{
     clear_checkpoint, list_checkpoints, mark_interrupted, CheckpointInfo, CheckpointStatus,
 };
+pub use dart_sync::{sync_dart, DartSyncOptions, DartSyncStats};
+pub use data_quality::{
+    check_ohlcv_quality, check_series, DataQualityStats, GapRepairer, RepairProvider, SeriesCheck,
+};
+pub use economic_calendar_sync::{
+    sync_economic_calendar, EconomicCalendarSyncOptions, EconomicCalendarSyncStats,
+};
 pub use fundamental_sync::{
     fetch_and_save_naver_fundamental, sync_krx_fundamentals, sync_naver_fundamentals,
     sync_naver_fundamentals_with_options, sync_yahoo_fundamentals, FundamentalSyncStats,
     NaverSyncOptions, YahooSyncOptions,
 };
+pub use fx_rate_sync::{sync_fx_rates, FxRateSyncOptions, FxRateSyncStats};
 pub use global_score_sync::{
     sync_global_scores, sync_global_scores_with_options, GlobalScoreSyncOptions,
 };
 pub use indicator_sync::{sync_indicators, sync_indicators_with_options, IndicatorSyncOptions};
+pub use job_orchestrator::{
+    run_job, CollectorJob, JobDag, JobOrchestrator, JobOutcome, JobPlanReport, JobSpec, RetryPolicy,
+};
 pub use macro_data_sync::{sync_macro_data, sync_macro_data_arc, MacroSyncResult};
 pub use market_breadth_sync::{sync_market_breadth, MarketBreadthSyncResult};
 pub use ohlcv_collect::collect_ohlcv;
@@ -34,4 +51,5 @@ pub use screening_refresh::{
     get_screening_view_stats, refresh_screening_view, refresh_sector_rs_view, ScreeningViewStats,
 };
 pub use signal_performance_sync::{sync_signal_performance, SignalPerformanceSyncOptions};
+pub use storage_tiering::{run_storage_tiering, StorageTieringOptions, StorageTieringStats};
 pub use symbol_sync::sync_symbols;
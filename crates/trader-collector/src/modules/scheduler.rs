@@ -168,6 +168,19 @@ impl Scheduler {
         self.holidays.contains(&key)
     }
 
+    /// 특정 시장의 등록된 공휴일 목록 (날짜순)
+    pub fn holidays(&self, market: &str) -> Vec<NaiveDate> {
+        let prefix = format!("{}:", market);
+        let mut dates: Vec<NaiveDate> = self
+            .holidays
+            .iter()
+            .filter_map(|key| key.strip_prefix(&prefix))
+            .filter_map(|date| date.parse().ok())
+            .collect();
+        dates.sort();
+        dates
+    }
+
     /// 시장 상태 조회
     pub fn get_market_status(&self, market: &str, now: DateTime<Utc>) -> MarketStatus {
         let market_hours = match self.get_market_hours(market) {
@@ -329,6 +342,11 @@ mod tests {
             krx_delay_after_close_minutes: 60,
             skip_weekends: true,
             skip_holidays: true,
+            orchestrator_enabled: false,
+            job_schedules: Default::default(),
+            job_max_attempts: 3,
+            job_retry_backoff_secs: 30,
+            job_retry_max_backoff_secs: 600,
         };
         let mut scheduler = Scheduler::new(&config);
         scheduler.load_kr_holidays_2025();
@@ -135,6 +135,7 @@ pub async fn sync_signal_performance(
 
 /// 미완료 신호 조회.
 /// signal_performance 테이블에 calculated_at이 NULL인 신호만 조회.
+/// 앙상블 신호의 전략 ID는 `metadata.ensemble.source_strategy_id`(하위 전략)를 사용합니다.
 async fn get_pending_signals(
     pool: &PgPool,
     min_days_after: u32,
@@ -166,7 +167,8 @@ async fn get_pending_signals(
             sm.side,
             sm.price,
             sm.strength,
-            sm.strategy_id
+            -- 앙상블 신호는 대표 신호를 낸 하위 전략으로 성과를 귀속
+            COALESCE(sm.metadata->'ensemble'->>'source_strategy_id', sm.strategy_id)
         FROM signal_marker sm
         JOIN symbol_info si ON sm.symbol_id = si.id
         LEFT JOIN signal_performance sp ON sm.id = sp.signal_id
@@ -0,0 +1,137 @@
+//! 계층형 저장소 모듈.
+//!
+//! 보존 기간(`STORAGE_ROLLUP_AFTER_DAYS`)이 지난 분봉을 1시간봉/일봉으로 롤업하고,
+//! 원본 분봉과 체결 틱을 `STORAGE_ARCHIVE_DIR` 아래 Parquet 파일로 옮긴 뒤 DB에서 삭제합니다.
+//! API 서버도 같은 `STORAGE_ARCHIVE_DIR`을 설정해야 백테스트가 아카이브된 구간을 읽을 수 있습니다.
+
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use sqlx::PgPool;
+use tracing::info;
+use trader_data::{ParquetStore, StorageTiering, TieringPolicy, TieringStats};
+
+use crate::{config::StorageTieringConfig, CollectorConfig, CollectorError, Result};
+
+/// 계층형 저장소 실행 옵션.
+#[derive(Debug, Clone, Default)]
+pub struct StorageTieringOptions {
+    /// 분봉 보관 일수 (None이면 `STORAGE_ROLLUP_AFTER_DAYS`)
+    pub rollup_after_days: Option<i64>,
+    /// 체결 틱 보관 일수 (None이면 `STORAGE_TICK_ARCHIVE_AFTER_DAYS`)
+    pub tick_archive_after_days: Option<i64>,
+    /// 체결 틱 아카이브 건너뛰기
+    pub skip_ticks: bool,
+}
+
+/// 계층형 저장소 실행 통계.
+#[derive(Debug, Default)]
+pub struct StorageTieringStats {
+    /// 롤업/아카이브 통계
+    pub tiering: TieringStats,
+    /// 소요 시간
+    pub elapsed: Duration,
+}
+
+impl StorageTieringStats {
+    /// 통계 요약 로그 출력
+    pub fn log_summary(&self, operation: &str) {
+        info!(
+            operation = operation,
+            series = self.tiering.series,
+            archived_candles = self.tiering.archived_candles,
+            rolled_up_candles = self.tiering.rolled_up_candles,
+            archived_ticks = self.tiering.archived_ticks,
+            deleted_rows = self.tiering.deleted_rows,
+            failed = self.tiering.failed,
+            elapsed = format!("{:.1}s", self.elapsed.as_secs_f64()),
+            "계층형 저장소 완료"
+        );
+    }
+}
+
+/// 보존 정책 적용 (분봉 롤업/아카이브, 체결 틱 아카이브).
+///
+/// 아카이브 루트(`STORAGE_ARCHIVE_DIR`)가 설정되지 않으면 데이터를 삭제하지 않고 에러를 반환합니다.
+pub async fn run_storage_tiering(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    options: StorageTieringOptions,
+) -> Result<StorageTieringStats> {
+    let start_time = Instant::now();
+
+    let archive_dir = config
+        .storage_tiering
+        .archive_dir
+        .as_deref()
+        .ok_or_else(|| {
+            CollectorError::Config("STORAGE_ARCHIVE_DIR 환경변수가 설정되지 않았습니다".to_string())
+        })?;
+    let policy = tiering_policy(&config.storage_tiering, &options);
+    info!(
+        archive_dir = archive_dir,
+        rollup_after_days = policy.rollup_after_days,
+        tick_archive_after_days = policy.tick_archive_after_days,
+        archive_ticks = policy.archive_ticks,
+        "계층형 저장소 시작"
+    );
+
+    let tiering =
+        StorageTiering::new(pool.clone(), ParquetStore::new(archive_dir)).with_policy(policy);
+    let stats = tiering
+        .run(Utc::now())
+        .await
+        .map_err(|e| CollectorError::Other(Box::new(e)))?;
+
+    Ok(StorageTieringStats {
+        tiering: stats,
+        elapsed: start_time.elapsed(),
+    })
+}
+
+/// 설정과 실행 옵션으로 보존 정책 생성 (옵션 우선).
+fn tiering_policy(config: &StorageTieringConfig, options: &StorageTieringOptions) -> TieringPolicy {
+    TieringPolicy::default()
+        .with_rollup_after_days(
+            options
+                .rollup_after_days
+                .unwrap_or(config.rollup_after_days),
+        )
+        .with_tick_archive_after_days(
+            options
+                .tick_archive_after_days
+                .unwrap_or(config.tick_archive_after_days),
+        )
+        .with_archive_ticks(!options.skip_ticks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiering_policy_overrides() {
+        let config = StorageTieringConfig {
+            enabled: true,
+            archive_dir: Some("/data/archive".to_string()),
+            rollup_after_days: 45,
+            tick_archive_after_days: 14,
+        };
+
+        let policy = tiering_policy(&config, &StorageTieringOptions::default());
+        assert_eq!(policy.rollup_after_days, 45);
+        assert_eq!(policy.tick_archive_after_days, 14);
+        assert!(policy.archive_ticks);
+
+        let options = StorageTieringOptions {
+            rollup_after_days: Some(0),
+            tick_archive_after_days: Some(7),
+            skip_ticks: true,
+        };
+        let policy = tiering_policy(&config, &options);
+        // 보관 일수는 최소 1일
+        assert_eq!(policy.rollup_after_days, 1);
+        assert_eq!(policy.tick_archive_after_days, 7);
+        assert!(!policy.archive_ticks);
+    }
+}
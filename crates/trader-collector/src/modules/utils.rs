@@ -45,6 +45,7 @@ pub fn market_to_market_type(market: &str) -> MarketType {
         "CRYPTO" => MarketType::Crypto,
         "FOREX" => MarketType::Forex,
         "FUTURES" => MarketType::Futures,
+        "OPTIONS" => MarketType::Options,
         _ => MarketType::Stock,
     }
 }
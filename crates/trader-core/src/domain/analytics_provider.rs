@@ -237,12 +237,15 @@ pub trait AnalyticsProvider: Send + Sync {
     ///
     /// # Arguments
     /// * `market_type` - 조회할 시장 유형
+    /// * `as_of` - 기준 시점 (None이면 최신). 백테스트에서는 시뮬레이션 시점을 전달하여
+    ///   그 시점까지 알려진 캔들/펀더멘털만 사용합니다 (Look-Ahead Bias 방지).
     ///
     /// # Returns
     /// GlobalScoreResult 리스트
     async fn fetch_global_scores(
         &self,
         market_type: MarketType,
+        as_of: Option<DateTime<Utc>>,
     ) -> Result<Vec<GlobalScoreResult>, AnalyticsError>;
 
     /// RouteState 조회 (종목별).
@@ -279,12 +282,14 @@ pub trait AnalyticsProvider: Send + Sync {
     ///
     /// # Arguments
     /// * `tickers` - 조회할 종목 티커 목록
+    /// * `as_of` - 기준 시점 (None이면 최신). 해당 시점 이전에 마감된 캔들만 사용합니다.
     ///
     /// # Returns
     /// ticker -> StructuralFeatures 매핑
     async fn fetch_features(
         &self,
         tickers: &[&str],
+        as_of: Option<DateTime<Utc>>,
     ) -> Result<HashMap<String, StructuralFeatures>, AnalyticsError>;
 
     /// MarketRegime 조회 (종목별).
@@ -0,0 +1,305 @@
+//! 거래일 캘린더.
+//!
+//! 시장별 거래 시간(현지 시각), 주말 거래 여부, 휴장일을 기반으로
+//! 거래일/장 개장·마감 시각을 계산합니다.
+//!
+//! 휴장일 목록은 외부(예: KIS 휴장일 API)에서 주입합니다.
+//! 캘린더 자체는 네트워크 의존성이 없어 백테스트와 실거래에서 동일하게 동작합니다.
+
+use std::collections::BTreeSet;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc, Weekday,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::types::Country;
+
+/// 캘린더 탐색 최대 일수 (무한 루프 방지).
+const MAX_SEARCH_DAYS: u32 = 366;
+
+/// 시장 현지 시간대.
+///
+/// 외부 시간대 DB 없이 주요 시장의 UTC 오프셋을 계산합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketTimezone {
+    /// UTC (암호화폐 등 24시간 시장)
+    Utc,
+    /// 한국 표준시 (UTC+9, 서머타임 없음)
+    Seoul,
+    /// 미국 동부 (UTC-5, 서머타임 시 UTC-4)
+    NewYork,
+}
+
+impl MarketTimezone {
+    /// 해당 현지 날짜의 UTC 오프셋.
+    ///
+    /// 미국 서머타임은 3월 둘째 일요일 ~ 11월 첫째 일요일 규칙을 따릅니다.
+    pub fn utc_offset(&self, date: NaiveDate) -> FixedOffset {
+        let hours = match self {
+            MarketTimezone::Utc => 0,
+            MarketTimezone::Seoul => 9,
+            MarketTimezone::NewYork => {
+                if is_us_dst(date) {
+                    -4
+                } else {
+                    -5
+                }
+            }
+        };
+        FixedOffset::east_opt(hours * 3600).expect("유효한 UTC 오프셋")
+    }
+
+    /// 현지 날짜/시각을 UTC로 변환.
+    pub fn to_utc(&self, date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+        let offset = self.utc_offset(date);
+        offset
+            .from_local_datetime(&date.and_time(time))
+            .single()
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|| Utc.from_utc_datetime(&date.and_time(time)))
+    }
+
+    /// UTC 시각을 현지 시각으로 변환.
+    pub fn to_local(&self, ts: DateTime<Utc>) -> DateTime<FixedOffset> {
+        // 서머타임 전환일 경계는 UTC 날짜 기준 오프셋으로 근사합니다.
+        let approx = ts.with_timezone(&self.utc_offset(ts.date_naive()));
+        ts.with_timezone(&self.utc_offset(approx.date_naive()))
+    }
+}
+
+/// 미국 서머타임 여부 (3월 둘째 일요일 ~ 11월 첫째 일요일).
+fn is_us_dst(date: NaiveDate) -> bool {
+    let year = date.year();
+    let start = NaiveDate::from_weekday_of_month_opt(year, 3, Weekday::Sun, 2);
+    let end = NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Sun, 1);
+    match (start, end) {
+        (Some(start), Some(end)) => date >= start && date < end,
+        _ => false,
+    }
+}
+
+/// 거래일 캘린더.
+///
+/// # 예시
+///
+/// ```rust,ignore
+/// let calendar = TradingCalendar::krx().with_holidays(holidays);
+/// if calendar.is_last_trading_day_of_month(today) {
+///     // 월말 리밸런싱
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradingCalendar {
+    /// 시장 국가
+    pub country: Country,
+    /// 현지 시간대
+    pub timezone: MarketTimezone,
+    /// 정규장 개장 시각 (현지)
+    pub open: NaiveTime,
+    /// 정규장 마감 시각 (현지)
+    pub close: NaiveTime,
+    /// 주말 거래 여부 (암호화폐 등)
+    pub trades_on_weekends: bool,
+    /// 휴장일 (현지 날짜)
+    #[serde(default)]
+    pub holidays: BTreeSet<NaiveDate>,
+}
+
+impl TradingCalendar {
+    /// 한국 거래소 (09:00 ~ 15:30 KST).
+    pub fn krx() -> Self {
+        Self {
+            country: Country::KR,
+            timezone: MarketTimezone::Seoul,
+            open: hm(9, 0),
+            close: hm(15, 30),
+            trades_on_weekends: false,
+            holidays: BTreeSet::new(),
+        }
+    }
+
+    /// 미국 정규장 (09:30 ~ 16:00 ET).
+    pub fn us() -> Self {
+        Self {
+            country: Country::US,
+            timezone: MarketTimezone::NewYork,
+            open: hm(9, 30),
+            close: hm(16, 0),
+            trades_on_weekends: false,
+            holidays: BTreeSet::new(),
+        }
+    }
+
+    /// 24시간/365일 시장 (UTC 자정 기준 하루).
+    pub fn always_open() -> Self {
+        Self {
+            country: Country::Global,
+            timezone: MarketTimezone::Utc,
+            open: hm(0, 0),
+            close: NaiveTime::from_hms_opt(23, 59, 59).expect("유효한 시각"),
+            trades_on_weekends: true,
+            holidays: BTreeSet::new(),
+        }
+    }
+
+    /// 국가별 기본 캘린더 (휴장일 없음).
+    ///
+    /// 한국/미국 외 국가는 UTC 기준 상시 거래 캘린더를 사용합니다.
+    pub fn for_country(country: Country) -> Self {
+        match country {
+            Country::KR => Self::krx(),
+            Country::US => Self::us(),
+            other => Self {
+                country: other,
+                ..Self::always_open()
+            },
+        }
+    }
+
+    /// 휴장일 목록 교체.
+    pub fn with_holidays(mut self, holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays = holidays.into_iter().collect();
+        self
+    }
+
+    /// 휴장일 추가.
+    pub fn add_holidays(&mut self, holidays: impl IntoIterator<Item = NaiveDate>) {
+        self.holidays.extend(holidays);
+    }
+
+    /// 거래일 여부 (현지 날짜).
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        if !self.trades_on_weekends && matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            return false;
+        }
+        !self.holidays.contains(&date)
+    }
+
+    /// 주어진 날짜 이후의 첫 거래일 (해당 날짜 제외).
+    pub fn next_trading_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        (1..=MAX_SEARCH_DAYS)
+            .map(|d| date + Duration::days(d as i64))
+            .find(|d| self.is_trading_day(*d))
+    }
+
+    /// 주어진 날짜 이전의 마지막 거래일 (해당 날짜 제외).
+    pub fn previous_trading_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        (1..=MAX_SEARCH_DAYS)
+            .map(|d| date - Duration::days(d as i64))
+            .find(|d| self.is_trading_day(*d))
+    }
+
+    /// 해당 월의 첫 거래일인지 확인.
+    pub fn is_first_trading_day_of_month(&self, date: NaiveDate) -> bool {
+        self.is_trading_day(date)
+            && self
+                .previous_trading_day(date)
+                .map_or(true, |prev| prev.month() != date.month())
+    }
+
+    /// 해당 월의 마지막 거래일인지 확인.
+    pub fn is_last_trading_day_of_month(&self, date: NaiveDate) -> bool {
+        self.is_trading_day(date)
+            && self
+                .next_trading_day(date)
+                .map_or(true, |next| next.month() != date.month())
+    }
+
+    /// 해당 날짜의 정규장 개장 시각 (UTC).
+    pub fn market_open(&self, date: NaiveDate) -> DateTime<Utc> {
+        self.timezone.to_utc(date, self.open)
+    }
+
+    /// 해당 날짜의 정규장 마감 시각 (UTC).
+    pub fn market_close(&self, date: NaiveDate) -> DateTime<Utc> {
+        self.timezone.to_utc(date, self.close)
+    }
+
+    /// UTC 시각의 현지 날짜.
+    pub fn local_date(&self, ts: DateTime<Utc>) -> NaiveDate {
+        self.timezone.to_local(ts).date_naive()
+    }
+
+    /// 해당 시각에 정규장이 열려 있는지 확인.
+    pub fn is_market_open(&self, ts: DateTime<Utc>) -> bool {
+        let date = self.local_date(ts);
+        self.is_trading_day(date) && ts >= self.market_open(date) && ts < self.market_close(date)
+    }
+}
+
+impl Default for TradingCalendar {
+    fn default() -> Self {
+        Self::always_open()
+    }
+}
+
+fn hm(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).expect("유효한 시각")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_weekends_and_holidays() {
+        // 2024-05-06 (월) 대체공휴일
+        let cal = TradingCalendar::krx().with_holidays([date(2024, 5, 6)]);
+
+        assert!(cal.is_trading_day(date(2024, 5, 3)));
+        assert!(!cal.is_trading_day(date(2024, 5, 4)));
+        assert!(!cal.is_trading_day(date(2024, 5, 6)));
+        assert_eq!(
+            cal.next_trading_day(date(2024, 5, 3)),
+            Some(date(2024, 5, 7))
+        );
+        assert_eq!(
+            cal.previous_trading_day(date(2024, 5, 7)),
+            Some(date(2024, 5, 3))
+        );
+
+        assert!(TradingCalendar::always_open().is_trading_day(date(2024, 5, 4)));
+    }
+
+    #[test]
+    fn test_month_boundaries() {
+        // 2024-03-29 (금), 2024-04-01 (월)
+        let cal = TradingCalendar::us().with_holidays([date(2024, 3, 29)]);
+
+        assert!(cal.is_last_trading_day_of_month(date(2024, 3, 28)));
+        assert!(!cal.is_last_trading_day_of_month(date(2024, 3, 27)));
+        assert!(cal.is_first_trading_day_of_month(date(2024, 4, 1)));
+        assert!(!cal.is_first_trading_day_of_month(date(2024, 4, 2)));
+    }
+
+    #[test]
+    fn test_session_times() {
+        let krx = TradingCalendar::krx();
+        let open = krx.market_open(date(2024, 1, 2));
+        assert_eq!(open, Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap());
+        assert!(krx.is_market_open(Utc.with_ymd_and_hms(2024, 1, 2, 3, 0, 0).unwrap()));
+        assert!(!krx.is_market_open(Utc.with_ymd_and_hms(2024, 1, 2, 7, 0, 0).unwrap()));
+
+        // 미국: 겨울 UTC-5, 여름 UTC-4
+        let us = TradingCalendar::us();
+        assert_eq!(
+            us.market_open(date(2024, 1, 2)),
+            Utc.with_ymd_and_hms(2024, 1, 2, 14, 30, 0).unwrap()
+        );
+        assert_eq!(
+            us.market_open(date(2024, 7, 1)),
+            Utc.with_ymd_and_hms(2024, 7, 1, 13, 30, 0).unwrap()
+        );
+        // 뉴욕 현지 날짜는 UTC 자정 이후에도 전날
+        assert_eq!(
+            us.local_date(Utc.with_ymd_and_hms(2024, 7, 2, 2, 0, 0).unwrap()),
+            date(2024, 7, 1)
+        );
+    }
+}
@@ -0,0 +1,713 @@
+//! 공적분 및 스프레드 통계 공통 로직.
+//!
+//! 페어 트레이딩 전략과 페어 스크리너에서 공유하는 통계 루틴을 제공합니다.
+//!
+//! # 주요 기능
+//!
+//! - **OLS 헤지 비율**: `y = α + β·x` 최소자승 추정
+//! - **ADF 검정**: AIC 기반 래그 선택, MacKinnon 임계값
+//! - **Engle-Granger 공적분 검정**: OLS 잔차에 대한 ADF 검정
+//! - **칼만 필터 헤지 비율**: 시변(time-varying) β/α 온라인 추정
+//! - **반감기 / Z-Score**: 평균회귀 속도 및 진입/청산 기준
+
+use serde::{Deserialize, Serialize};
+
+/// 유의수준.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Significance {
+    /// 1%
+    OnePercent,
+    /// 5%
+    #[default]
+    FivePercent,
+    /// 10%
+    TenPercent,
+}
+
+/// 검정 통계량 임계값 (1%, 5%, 10%).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct CriticalValues {
+    /// 1% 임계값
+    pub one_percent: f64,
+    /// 5% 임계값
+    pub five_percent: f64,
+    /// 10% 임계값
+    pub ten_percent: f64,
+}
+
+impl CriticalValues {
+    /// 유의수준에 해당하는 임계값.
+    pub fn at(&self, level: Significance) -> f64 {
+        match level {
+            Significance::OnePercent => self.one_percent,
+            Significance::FivePercent => self.five_percent,
+            Significance::TenPercent => self.ten_percent,
+        }
+    }
+}
+
+/// MacKinnon (2010) 반응면 계수: ADF, 상수항 포함, 변수 1개.
+const ADF_CONSTANT_COEFFS: [[f64; 4]; 3] = [
+    [-3.43035, -6.5393, -16.786, -79.433],
+    [-2.86154, -2.8903, -4.234, -40.040],
+    [-2.56677, -1.5384, -2.809, 0.0],
+];
+
+/// MacKinnon (2010) 반응면 계수: Engle-Granger, 상수항 포함, 변수 2개.
+const ENGLE_GRANGER_COEFFS: [[f64; 4]; 3] = [
+    [-3.89644, -10.9519, -22.527, 0.0],
+    [-3.33613, -6.1101, -6.823, 0.0],
+    [-3.04445, -4.2412, -2.720, 0.0],
+];
+
+fn mackinnon_critical_values(coeffs: &[[f64; 4]; 3], n_obs: usize) -> CriticalValues {
+    let t = n_obs as f64;
+    let crit = |c: &[f64; 4]| c[0] + c[1] / t + c[2] / (t * t) + c[3] / (t * t * t);
+    CriticalValues {
+        one_percent: crit(&coeffs[0]),
+        five_percent: crit(&coeffs[1]),
+        ten_percent: crit(&coeffs[2]),
+    }
+}
+
+/// 단순 선형회귀 결과 (`y = intercept + slope·x`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LinearFit {
+    /// 절편 (α)
+    pub intercept: f64,
+    /// 기울기 (β, 헤지 비율)
+    pub slope: f64,
+    /// 결정계수
+    pub r_squared: f64,
+}
+
+impl LinearFit {
+    /// 잔차 시계열 (`y - α - β·x`).
+    pub fn residuals(&self, y: &[f64], x: &[f64]) -> Vec<f64> {
+        spread_series(y, x, self.slope, self.intercept)
+    }
+}
+
+/// OLS 헤지 비율 추정.
+///
+/// 길이가 다르거나 3개 미만이거나 `x`의 분산이 0이면 None.
+pub fn ols(y: &[f64], x: &[f64]) -> Option<LinearFit> {
+    if y.len() != x.len() || y.len() < 3 {
+        return None;
+    }
+
+    let n = y.len() as f64;
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+
+    let mut sxx = 0.0;
+    let mut sxy = 0.0;
+    let mut syy = 0.0;
+    for (xi, yi) in x.iter().zip(y) {
+        let dx = xi - mean_x;
+        let dy = yi - mean_y;
+        sxx += dx * dx;
+        sxy += dx * dy;
+        syy += dy * dy;
+    }
+
+    if sxx == 0.0 {
+        return None;
+    }
+
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    let r_squared = if syy == 0.0 {
+        1.0
+    } else {
+        (sxy * sxy) / (sxx * syy)
+    };
+
+    Some(LinearFit {
+        intercept,
+        slope,
+        r_squared,
+    })
+}
+
+/// 스프레드 시계열 (`y - β·x - α`).
+pub fn spread_series(y: &[f64], x: &[f64], hedge_ratio: f64, intercept: f64) -> Vec<f64> {
+    y.iter()
+        .zip(x)
+        .map(|(yi, xi)| yi - hedge_ratio * xi - intercept)
+        .collect()
+}
+
+/// 마지막 값의 Z-Score (표본 평균/표준편차 기준).
+///
+/// 2개 미만이거나 표준편차가 0이면 None.
+pub fn zscore(series: &[f64]) -> Option<f64> {
+    let last = *series.last()?;
+    let (mean, std) = mean_std(series)?;
+    if std == 0.0 {
+        return None;
+    }
+    Some((last - mean) / std)
+}
+
+fn mean_std(series: &[f64]) -> Option<(f64, f64)> {
+    if series.len() < 2 {
+        return None;
+    }
+    let n = series.len() as f64;
+    let mean = series.iter().sum::<f64>() / n;
+    let var = series.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    Some((mean, var.sqrt()))
+}
+
+/// 평균회귀 반감기 (봉 단위).
+///
+/// `Δs_t = c + λ·s_{t-1}`를 회귀하여 `-ln(2) / ln(1 + λ)`를 반환합니다.
+/// 평균회귀하지 않으면 (λ ≥ 0) None.
+pub fn half_life(spread: &[f64]) -> Option<f64> {
+    if spread.len() < 4 {
+        return None;
+    }
+
+    let lagged = &spread[..spread.len() - 1];
+    let delta: Vec<f64> = spread.windows(2).map(|w| w[1] - w[0]).collect();
+    let fit = ols(&delta, lagged)?;
+
+    let decay = 1.0 + fit.slope;
+    if fit.slope >= 0.0 || decay <= 0.0 {
+        return None;
+    }
+    Some(-std::f64::consts::LN_2 / decay.ln())
+}
+
+/// ADF 검정 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct AdfResult {
+    /// 검정 통계량 (γ의 t-값)
+    pub statistic: f64,
+    /// 선택된 래그 수
+    pub lags: usize,
+    /// 회귀에 사용된 관측치 수
+    pub observations: usize,
+    /// 임계값
+    pub critical_values: CriticalValues,
+}
+
+impl AdfResult {
+    /// 단위근 귀무가설을 기각하는지 (정상 시계열 여부).
+    pub fn rejects_unit_root(&self, level: Significance) -> bool {
+        self.statistic < self.critical_values.at(level)
+    }
+}
+
+/// Augmented Dickey-Fuller 검정 (상수항 포함).
+///
+/// `max_lags`가 None이면 Schwert 규칙 `12·(n/100)^¼`을 사용하고,
+/// 0..=max_lags 중 AIC가 최소인 래그를 선택합니다.
+/// 데이터가 부족하면 None.
+pub fn adf_test(series: &[f64], max_lags: Option<usize>) -> Option<AdfResult> {
+    let (statistic, lags, observations) = adf_statistic(series, max_lags, true)?;
+    Some(AdfResult {
+        statistic,
+        lags,
+        observations,
+        critical_values: mackinnon_critical_values(&ADF_CONSTANT_COEFFS, observations),
+    })
+}
+
+/// Engle-Granger 공적분 검정 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct CointegrationResult {
+    /// 헤지 비율 (β)
+    pub hedge_ratio: f64,
+    /// 절편 (α)
+    pub intercept: f64,
+    /// 결정계수
+    pub r_squared: f64,
+    /// 잔차 ADF 검정 (Engle-Granger 임계값)
+    pub adf: AdfResult,
+    /// 잔차 평균회귀 반감기 (봉 단위)
+    pub half_life: Option<f64>,
+}
+
+impl CointegrationResult {
+    /// 공적분 관계 존재 여부.
+    pub fn is_cointegrated(&self, level: Significance) -> bool {
+        self.adf.rejects_unit_root(level)
+    }
+}
+
+/// Engle-Granger 2단계 공적분 검정.
+///
+/// 1단계에서 `y = α + β·x`를 OLS로 추정하고,
+/// 2단계에서 잔차에 ADF 검정을 적용합니다 (변수 2개 MacKinnon 임계값).
+pub fn engle_granger(y: &[f64], x: &[f64], max_lags: Option<usize>) -> Option<CointegrationResult> {
+    let fit = ols(y, x)?;
+    let residuals = fit.residuals(y, x);
+    // 잔차는 평균이 0이므로 상수항 없이 검정
+    let (statistic, lags, observations) = adf_statistic(&residuals, max_lags, false)?;
+
+    Some(CointegrationResult {
+        hedge_ratio: fit.slope,
+        intercept: fit.intercept,
+        r_squared: fit.r_squared,
+        adf: AdfResult {
+            statistic,
+            lags,
+            observations,
+            critical_values: mackinnon_critical_values(&ENGLE_GRANGER_COEFFS, observations),
+        },
+        half_life: half_life(&residuals),
+    })
+}
+
+/// ADF 회귀: `Δy_t = [c] + γ·y_{t-1} + Σ φ_i·Δy_{t-i}`.
+///
+/// (γ의 t-값, 래그, 관측치 수) 반환.
+fn adf_statistic(
+    series: &[f64],
+    max_lags: Option<usize>,
+    constant: bool,
+) -> Option<(f64, usize, usize)> {
+    let n = series.len();
+    if n < 10 {
+        return None;
+    }
+
+    let diffs: Vec<f64> = series.windows(2).map(|w| w[1] - w[0]).collect();
+    let deterministic = usize::from(constant);
+
+    let max_lag =
+        max_lags.unwrap_or_else(|| (12.0 * (n as f64 / 100.0).powf(0.25)).floor() as usize);
+    // 표본 길이 이상의 래그 요청은 절반으로 제한 (diffs.len() - max_lag 언더플로 방지)
+    let mut max_lag = max_lag.min(diffs.len() / 2);
+    // 회귀 자유도 확보: 관측치가 회귀변수 수의 2배 이상
+    while max_lag > 0 && diffs.len() - max_lag < 2 * (max_lag + 1 + deterministic) + 2 {
+        max_lag -= 1;
+    }
+
+    let build = |lag: usize, start: usize| -> (Vec<f64>, Vec<Vec<f64>>) {
+        let mut target = Vec::with_capacity(diffs.len() - start);
+        let mut rows = Vec::with_capacity(diffs.len() - start);
+        for t in start..diffs.len() {
+            let mut row = Vec::with_capacity(lag + 1 + deterministic);
+            if constant {
+                row.push(1.0);
+            }
+            row.push(series[t]);
+            for i in 1..=lag {
+                row.push(diffs[t - i]);
+            }
+            target.push(diffs[t]);
+            rows.push(row);
+        }
+        (target, rows)
+    };
+
+    // 공통 표본에서 AIC 최소 래그 선택
+    let mut best: Option<(usize, f64)> = None;
+    for lag in 0..=max_lag {
+        let (target, rows) = build(lag, max_lag);
+        let Some(fit) = ols_multi(&target, &rows) else {
+            continue;
+        };
+        let obs = target.len() as f64;
+        if fit.ssr <= 0.0 {
+            continue;
+        }
+        let aic = obs * (fit.ssr / obs).ln() + 2.0 * rows[0].len() as f64;
+        if best.map_or(true, |(_, b)| aic < b) {
+            best = Some((lag, aic));
+        }
+    }
+    let (lag, _) = best?;
+
+    // 선택된 래그로 전체 표본 재추정
+    let (target, rows) = build(lag, lag);
+    let fit = ols_multi(&target, &rows)?;
+    let gamma = deterministic;
+    if fit.std_errors[gamma] == 0.0 || !fit.std_errors[gamma].is_finite() {
+        return None;
+    }
+
+    Some((
+        fit.coefficients[gamma] / fit.std_errors[gamma],
+        lag,
+        target.len(),
+    ))
+}
+
+struct MultiFit {
+    coefficients: Vec<f64>,
+    std_errors: Vec<f64>,
+    ssr: f64,
+}
+
+/// 다중 OLS (정규방정식 + 가우스-조던 역행렬).
+fn ols_multi(y: &[f64], rows: &[Vec<f64>]) -> Option<MultiFit> {
+    let k = rows.first()?.len();
+    let n = y.len();
+    if n <= k {
+        return None;
+    }
+
+    let mut xtx = vec![vec![0.0; k]; k];
+    let mut xty = vec![0.0; k];
+    for (row, yi) in rows.iter().zip(y) {
+        for i in 0..k {
+            xty[i] += row[i] * yi;
+            for j in 0..k {
+                xtx[i][j] += row[i] * row[j];
+            }
+        }
+    }
+
+    let inv = invert(xtx)?;
+    let coefficients: Vec<f64> = (0..k)
+        .map(|i| (0..k).map(|j| inv[i][j] * xty[j]).sum())
+        .collect();
+
+    let ssr: f64 = rows
+        .iter()
+        .zip(y)
+        .map(|(row, yi)| {
+            let fitted: f64 = row.iter().zip(&coefficients).map(|(a, b)| a * b).sum();
+            (yi - fitted).powi(2)
+        })
+        .sum();
+
+    let sigma2 = ssr / (n - k) as f64;
+    let std_errors = (0..k).map(|i| (sigma2 * inv[i][i]).sqrt()).collect();
+
+    Some(MultiFit {
+        coefficients,
+        std_errors,
+        ssr,
+    })
+}
+
+fn invert(mut m: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    let k = m.len();
+    let mut inv: Vec<Vec<f64>> = (0..k)
+        .map(|i| (0..k).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    for col in 0..k {
+        let pivot = (col..k).max_by(|a, b| m[*a][col].abs().total_cmp(&m[*b][col].abs()))?;
+        if m[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        m.swap(col, pivot);
+        inv.swap(col, pivot);
+
+        let p = m[col][col];
+        for j in 0..k {
+            m[col][j] /= p;
+            inv[col][j] /= p;
+        }
+        for row in 0..k {
+            if row != col {
+                let factor = m[row][col];
+                if factor != 0.0 {
+                    for j in 0..k {
+                        m[row][j] -= factor * m[col][j];
+                        inv[row][j] -= factor * inv[col][j];
+                    }
+                }
+            }
+        }
+    }
+
+    Some(inv)
+}
+
+/// 칼만 필터 1스텝 추정치.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct KalmanEstimate {
+    /// 헤지 비율 (β)
+    pub hedge_ratio: f64,
+    /// 절편 (α)
+    pub intercept: f64,
+    /// 예측 오차 (관측 전 상태 기준 스프레드)
+    pub spread: f64,
+    /// 예측 오차 표준편차
+    pub spread_std: f64,
+}
+
+impl KalmanEstimate {
+    /// 예측 오차의 Z-Score.
+    pub fn zscore(&self) -> Option<f64> {
+        (self.spread_std > 0.0).then(|| self.spread / self.spread_std)
+    }
+}
+
+/// 칼만 필터 기반 시변 헤지 비율 추정기.
+///
+/// 상태 `θ = [β, α]`는 랜덤워크를 따르고, 관측식은 `y = β·x + α + ε`입니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KalmanHedgeRatio {
+    /// 상태 전이 잡음 비율 (작을수록 β가 천천히 변함)
+    delta: f64,
+    /// 관측 잡음 분산
+    observation_variance: f64,
+    /// 상태 [β, α]
+    state: [f64; 2],
+    /// 상태 공분산
+    covariance: [[f64; 2]; 2],
+    /// 처리한 관측치 수
+    updates: usize,
+}
+
+impl Default for KalmanHedgeRatio {
+    fn default() -> Self {
+        Self::new(1e-4, 1e-3)
+    }
+}
+
+impl KalmanHedgeRatio {
+    /// 새 추정기 생성.
+    pub fn new(delta: f64, observation_variance: f64) -> Self {
+        Self {
+            delta: delta.clamp(f64::EPSILON, 1.0 - f64::EPSILON),
+            observation_variance: observation_variance.max(f64::EPSILON),
+            state: [0.0, 0.0],
+            covariance: [[1.0, 0.0], [0.0, 1.0]],
+            updates: 0,
+        }
+    }
+
+    /// 초기 상태 설정 (예: OLS 추정치로 워밍업).
+    pub fn with_initial_state(mut self, hedge_ratio: f64, intercept: f64) -> Self {
+        self.state = [hedge_ratio, intercept];
+        self
+    }
+
+    /// 현재 헤지 비율.
+    pub fn hedge_ratio(&self) -> f64 {
+        self.state[0]
+    }
+
+    /// 현재 절편.
+    pub fn intercept(&self) -> f64 {
+        self.state[1]
+    }
+
+    /// 처리한 관측치 수.
+    pub fn updates(&self) -> usize {
+        self.updates
+    }
+
+    /// 관측치 `(x, y)`로 상태를 갱신합니다.
+    pub fn update(&mut self, x: f64, y: f64) -> KalmanEstimate {
+        let q = self.delta / (1.0 - self.delta);
+        let [[p00, p01], [p10, p11]] = self.covariance;
+
+        // 예측 단계 (상태 랜덤워크)
+        let (p00, p11) = (p00 + q, p11 + q);
+
+        // 관측 F = [x, 1]
+        let predicted = self.state[0] * x + self.state[1];
+        let error = y - predicted;
+        let pf0 = p00 * x + p01;
+        let pf1 = p10 * x + p11;
+        let variance = x * pf0 + pf1 + self.observation_variance;
+
+        let k0 = pf0 / variance;
+        let k1 = pf1 / variance;
+        self.state[0] += k0 * error;
+        self.state[1] += k1 * error;
+
+        // P = P - K·F·P
+        self.covariance = [
+            [p00 - k0 * pf0, p01 - k0 * pf1],
+            [p10 - k1 * pf0, p11 - k1 * pf1],
+        ];
+        self.updates += 1;
+
+        KalmanEstimate {
+            hedge_ratio: self.state[0],
+            intercept: self.state[1],
+            spread: error,
+            spread_std: variance.sqrt(),
+        }
+    }
+}
+
+/// 시계열 전체에 칼만 필터를 적용합니다.
+pub fn kalman_hedge_ratios(
+    y: &[f64],
+    x: &[f64],
+    delta: f64,
+    observation_variance: f64,
+) -> Vec<KalmanEstimate> {
+    let mut filter = KalmanHedgeRatio::new(delta, observation_variance);
+    y.iter()
+        .zip(x)
+        .map(|(yi, xi)| filter.update(*xi, *yi))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 결정적 의사난수 (표준정규 근사: 균등분포 12개 합 - 6).
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn uniform(&mut self) -> f64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 11) as f64 / (1u64 << 53) as f64
+        }
+
+        fn normal(&mut self) -> f64 {
+            (0..12).map(|_| self.uniform()).sum::<f64>() - 6.0
+        }
+    }
+
+    fn random_walk(rng: &mut Lcg, n: usize, start: f64) -> Vec<f64> {
+        let mut value = start;
+        (0..n)
+            .map(|_| {
+                value += rng.normal();
+                value
+            })
+            .collect()
+    }
+
+    fn ar1(rng: &mut Lcg, n: usize, phi: f64) -> Vec<f64> {
+        let mut value = 0.0;
+        (0..n)
+            .map(|_| {
+                value = phi * value + rng.normal();
+                value
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ols_recovers_linear_relation() {
+        let x: Vec<f64> = (0..50).map(|i| i as f64).collect();
+        let y: Vec<f64> = x.iter().map(|v| 3.0 + 2.0 * v).collect();
+
+        let fit = ols(&y, &x).unwrap();
+        assert!((fit.slope - 2.0).abs() < 1e-9);
+        assert!((fit.intercept - 3.0).abs() < 1e-9);
+        assert!((fit.r_squared - 1.0).abs() < 1e-9);
+        assert!(fit.residuals(&y, &x).iter().all(|r| r.abs() < 1e-9));
+
+        assert!(ols(&y, &[1.0; 50]).is_none());
+        assert!(ols(&y[..10], &x).is_none());
+    }
+
+    #[test]
+    fn test_adf_distinguishes_stationary_and_random_walk() {
+        let mut rng = Lcg(7);
+        let stationary = ar1(&mut rng, 300, 0.5);
+        let walk = random_walk(&mut rng, 300, 100.0);
+
+        let adf = adf_test(&stationary, None).unwrap();
+        assert!(adf.rejects_unit_root(Significance::OnePercent));
+        assert!(adf.critical_values.one_percent < adf.critical_values.five_percent);
+
+        let adf = adf_test(&walk, None).unwrap();
+        assert!(!adf.rejects_unit_root(Significance::FivePercent));
+
+        assert!(adf_test(&[1.0, 2.0, 3.0], None).is_none());
+    }
+
+    #[test]
+    fn test_adf_clamps_lags_on_short_series() {
+        let mut rng = Lcg(5);
+        let short = ar1(&mut rng, 12, 0.5);
+
+        // 표본 길이 이상의 래그 요청도 패닉 없이 처리
+        for max_lags in [11, 12, 100] {
+            if let Some(adf) = adf_test(&short, Some(max_lags)) {
+                assert!(adf.lags <= short.len() / 2);
+            }
+        }
+        let x: Vec<f64> = (0..12).map(|i| i as f64).collect();
+        let _ = engle_granger(&short, &x, Some(usize::MAX));
+    }
+
+    #[test]
+    fn test_mackinnon_critical_values_approach_asymptotic() {
+        let cv = mackinnon_critical_values(&ADF_CONSTANT_COEFFS, 100_000);
+        assert!((cv.five_percent + 2.8615).abs() < 1e-3);
+
+        let cv = mackinnon_critical_values(&ENGLE_GRANGER_COEFFS, 100);
+        assert!((cv.five_percent + 3.3979).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_engle_granger_detects_cointegration() {
+        let mut rng = Lcg(42);
+        let x = random_walk(&mut rng, 400, 50.0);
+        let noise = ar1(&mut rng, 400, 0.3);
+        let y: Vec<f64> = x
+            .iter()
+            .zip(&noise)
+            .map(|(a, e)| 1.5 * a + 10.0 + e)
+            .collect();
+
+        let result = engle_granger(&y, &x, None).unwrap();
+        assert!(result.is_cointegrated(Significance::OnePercent));
+        assert!((result.hedge_ratio - 1.5).abs() < 0.05);
+        assert!(result.half_life.unwrap() < 5.0);
+
+        // 독립적인 두 랜덤워크는 공적분이 아님
+        let z = random_walk(&mut rng, 400, 50.0);
+        let result = engle_granger(&z, &x, None).unwrap();
+        assert!(!result.is_cointegrated(Significance::FivePercent));
+    }
+
+    #[test]
+    fn test_half_life_of_ar1() {
+        let mut rng = Lcg(3);
+        // φ = 0.9 → 반감기 = -ln2 / ln(0.9) ≈ 6.58
+        let spread = ar1(&mut rng, 2000, 0.9);
+        let hl = half_life(&spread).unwrap();
+        assert!((hl - 6.58).abs() < 1.5, "half life {}", hl);
+
+        let trending: Vec<f64> = (0..50).map(|i| (i as f64).powi(2)).collect();
+        assert!(half_life(&trending).is_none());
+    }
+
+    #[test]
+    fn test_zscore() {
+        let series = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let z = zscore(&series).unwrap();
+        assert!((z - 2.0 / 1.5811388).abs() < 1e-6);
+        assert!(zscore(&[1.0, 1.0, 1.0]).is_none());
+        assert!(zscore(&[1.0]).is_none());
+    }
+
+    #[test]
+    fn test_kalman_tracks_hedge_ratio() {
+        let mut rng = Lcg(11);
+        let x = random_walk(&mut rng, 500, 100.0);
+        let y: Vec<f64> = x
+            .iter()
+            .map(|a| 0.8 * a + 5.0 + 0.1 * rng.normal())
+            .collect();
+
+        let estimates = kalman_hedge_ratios(&y, &x, 1e-4, 1e-2);
+        let last = estimates.last().unwrap();
+        assert!((last.hedge_ratio - 0.8).abs() < 0.05);
+        assert!(last.zscore().unwrap().abs() < 4.0);
+
+        let mut filter = KalmanHedgeRatio::default().with_initial_state(0.8, 5.0);
+        filter.update(x[0], y[0]);
+        assert_eq!(filter.updates(), 1);
+        assert!((filter.hedge_ratio() - 0.8).abs() < 0.05);
+    }
+}
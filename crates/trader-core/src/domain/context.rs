@@ -15,12 +15,15 @@ use super::{
         GlobalScoreResult, MacroEnvironment, MarketBreadth, MarketRegime, RouteState,
         ScreeningResult, StructuralFeatures,
     },
+    economic_calendar::{EconomicCalendar, EconomicEvent},
+    fx::ticker_currency,
+    indicator_snapshot::IndicatorSnapshot,
     market_data::Kline,
     order::{OrderStatusType, Side},
     signal::{Signal, SignalType},
     trigger::TriggerResult,
 };
-use crate::Timeframe;
+use crate::{Money, Timeframe};
 
 // =============================================================================
 // 충돌 방지 에러 타입
@@ -171,6 +174,29 @@ impl Default for StrategyAccountInfo {
     }
 }
 
+impl StrategyAccountInfo {
+    /// 총 자산을 계좌 통화 금액으로 반환.
+    pub fn total_balance_money(&self) -> Money {
+        Money::new(self.total_balance, self.currency.clone())
+    }
+
+    /// 매수 가능 금액을 계좌 통화 금액으로 반환.
+    pub fn available_balance_money(&self) -> Money {
+        Money::new(self.available_balance, self.currency.clone())
+    }
+
+    /// 환율을 적용해 다른 통화 기준 계좌 정보로 환산 (1 계좌 통화 = `rate` 대상 통화).
+    pub fn converted(&self, currency: &str, rate: Decimal) -> Self {
+        Self {
+            total_balance: self.total_balance * rate,
+            available_balance: self.available_balance * rate,
+            margin_used: self.margin_used * rate,
+            unrealized_pnl: self.unrealized_pnl * rate,
+            currency: currency.trim().to_uppercase(),
+        }
+    }
+}
+
 // =============================================================================
 // 포지션 정보
 // =============================================================================
@@ -198,6 +224,9 @@ pub struct StrategyPositionInfo {
     pub created_at: DateTime<Utc>,
     /// 마지막 업데이트 시각
     pub updated_at: DateTime<Utc>,
+    /// 거래 통화 (None이면 티커 패턴으로 추론)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
 }
 
 impl StrategyPositionInfo {
@@ -215,9 +244,33 @@ impl StrategyPositionInfo {
             liquidation_price: None,
             created_at: now,
             updated_at: now,
+            currency: None,
         }
     }
 
+    /// 거래 통화 설정.
+    pub fn with_currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into().to_uppercase());
+        self
+    }
+
+    /// 거래 통화 (미설정 시 티커 패턴으로 추론).
+    pub fn quote_currency(&self) -> String {
+        self.currency
+            .clone()
+            .unwrap_or_else(|| ticker_currency(&self.ticker))
+    }
+
+    /// 평가 금액 (거래 통화).
+    pub fn market_value(&self) -> Money {
+        Money::new(self.quantity * self.current_price, self.quote_currency())
+    }
+
+    /// 진입 원가 (거래 통화).
+    pub fn cost_basis(&self) -> Money {
+        Money::new(self.quantity * self.avg_entry_price, self.quote_currency())
+    }
+
     /// 현재가 업데이트 및 미실현 손익 재계산.
     pub fn update_price(&mut self, current_price: Decimal) {
         self.current_price = current_price;
@@ -307,6 +360,26 @@ impl Default for ExchangeConstraints {
     }
 }
 
+// =============================================================================
+// 신호 성과
+// =============================================================================
+
+/// 전략별 신호 성과 요약.
+///
+/// `signal_performance` 집계(전략별 승률, 평균 수익률)를 전략에서 참조할 수 있도록
+/// 컨텍스트에 담는 값입니다. 앙상블 전략의 적응형 가중치 계산에 사용됩니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StrategySignalPerformance {
+    /// 신호를 생성한 전략 ID
+    pub strategy_id: String,
+    /// 성과가 계산된 신호 수
+    pub total_signals: u64,
+    /// 승률 (%)
+    pub win_rate: Option<f64>,
+    /// 평균 5일 수익률 (%)
+    pub avg_return_5d: Option<f64>,
+}
+
 // =============================================================================
 // 전략 컨텍스트
 // =============================================================================
@@ -351,12 +424,18 @@ pub struct StrategyContext {
     /// 시장 폭 (20일선 상회 비율 등)
     pub market_breadth: Option<MarketBreadth>,
 
+    /// 경제 이벤트 캘린더 (FOMC, CPI, 금통위, 옵션 만기 등)
+    pub economic_calendar: EconomicCalendar,
+
     /// 진입 트리거 결과 (ticker → TriggerResult)
     ///
     /// 각 종목의 진입 신호 강도와 트리거 라벨을 제공합니다.
     /// TriggerCalculator에서 계산된 결과가 여기에 저장됩니다.
     pub trigger_results: HashMap<String, TriggerResult>,
 
+    /// 전략별 신호 성과 (strategy_id → 성과)
+    pub signal_performance: HashMap<String, StrategySignalPerformance>,
+
     // ===== 다중 타임프레임 데이터 (Phase 1.4.2) =====
     /// 타임프레임별 캔들 데이터 (ticker → (timeframe → klines))
     ///
@@ -373,6 +452,11 @@ pub struct StrategyContext {
     /// ```
     pub klines_by_timeframe: HashMap<String, HashMap<Timeframe, Vec<Kline>>>,
 
+    /// 증분 지표 스냅샷 (ticker → (timeframe → 스냅샷))
+    ///
+    /// `StrategyEngine`에 `IndicatorUpdater`가 주입된 경우 마감 캔들마다 갱신됩니다.
+    pub indicator_snapshots: HashMap<String, HashMap<Timeframe, IndicatorSnapshot>>,
+
     // ===== 관심 종목 =====
     /// 전략이 관심을 가지는 종목 목록.
     ///
@@ -407,8 +491,11 @@ impl Default for StrategyContext {
             market_regime: HashMap::new(),
             macro_environment: None,
             market_breadth: None,
+            economic_calendar: EconomicCalendar::default(),
             trigger_results: HashMap::new(),
+            signal_performance: HashMap::new(),
             klines_by_timeframe: HashMap::new(),
+            indicator_snapshots: HashMap::new(),
             watched_tickers: HashSet::new(),
             last_exchange_sync: now,
             last_analytics_sync: now,
@@ -810,6 +897,31 @@ impl StrategyContext {
         self.last_analytics_sync = Utc::now();
     }
 
+    /// 경제 이벤트 캘린더 업데이트.
+    pub fn update_economic_calendar(&mut self, calendar: EconomicCalendar) {
+        self.economic_calendar = calendar;
+        self.last_analytics_sync = Utc::now();
+    }
+
+    /// 증분 지표 스냅샷 업데이트 (종목/타임프레임 단위 교체).
+    pub fn update_indicator_snapshot(&mut self, snapshot: IndicatorSnapshot) {
+        self.indicator_snapshots
+            .entry(snapshot.ticker.clone())
+            .or_default()
+            .insert(snapshot.timeframe, snapshot);
+    }
+
+    /// 전략별 신호 성과 업데이트.
+    ///
+    /// 기존 성과를 모두 지우고 새 집계로 교체합니다.
+    pub fn update_signal_performance(&mut self, stats: Vec<StrategySignalPerformance>) {
+        self.signal_performance = stats
+            .into_iter()
+            .map(|s| (s.strategy_id.clone(), s))
+            .collect();
+        self.last_analytics_sync = Utc::now();
+    }
+
     // =============================================================================
     // 분석 결과 조회 헬퍼
     // =============================================================================
@@ -834,6 +946,17 @@ impl StrategyContext {
         self.market_regime.get(ticker)
     }
 
+    /// 특정 종목/타임프레임의 증분 지표 스냅샷 조회.
+    pub fn get_indicator_snapshot(
+        &self,
+        ticker: &str,
+        timeframe: Timeframe,
+    ) -> Option<&IndicatorSnapshot> {
+        self.indicator_snapshots
+            .get(ticker)
+            .and_then(|by_tf| by_tf.get(&timeframe))
+    }
+
     /// 매크로 환경 조회.
     pub fn get_macro_environment(&self) -> Option<&MacroEnvironment> {
         self.macro_environment.as_ref()
@@ -844,6 +967,25 @@ impl StrategyContext {
         self.market_breadth.as_ref()
     }
 
+    /// 경제 이벤트 캘린더 조회.
+    pub fn get_economic_calendar(&self) -> &EconomicCalendar {
+        &self.economic_calendar
+    }
+
+    /// `now` 이후 `horizon` 이내의 예정 경제 이벤트 조회.
+    pub fn upcoming_events(
+        &self,
+        now: DateTime<Utc>,
+        horizon: chrono::Duration,
+    ) -> Vec<&EconomicEvent> {
+        self.economic_calendar.upcoming(now, horizon).collect()
+    }
+
+    /// 특정 전략의 신호 성과 조회.
+    pub fn get_signal_performance(&self, strategy_id: &str) -> Option<&StrategySignalPerformance> {
+        self.signal_performance.get(strategy_id)
+    }
+
     /// 특정 종목의 진입 트리거 조회.
     ///
     /// # 인자
@@ -916,6 +1058,27 @@ mod tests {
         assert!(pos.unrealized_pnl_pct < Decimal::ZERO);
     }
 
+    #[test]
+    fn test_position_and_account_currency() {
+        let pos = StrategyPositionInfo::new("AAPL".to_string(), Side::Buy, dec!(10), dec!(150));
+        assert_eq!(pos.quote_currency(), "USD");
+        assert_eq!(pos.market_value(), Money::usd(dec!(1500)));
+
+        let pos = StrategyPositionInfo::new("BTCUSDT".to_string(), Side::Buy, dec!(1), dec!(1))
+            .with_currency("usdt");
+        assert_eq!(pos.cost_basis(), Money::usdt(dec!(1)));
+
+        let account = StrategyAccountInfo {
+            total_balance: dec!(1000),
+            available_balance: dec!(400),
+            currency: "USD".to_string(),
+            ..Default::default()
+        };
+        let krw = account.converted("KRW", dec!(1300));
+        assert_eq!(krw.total_balance_money(), Money::krw(dec!(1_300_000)));
+        assert_eq!(krw.available_balance_money(), Money::krw(dec!(520_000)));
+    }
+
     #[test]
     fn test_strategy_context_position_query() {
         let mut ctx = StrategyContext::new();
@@ -1098,4 +1261,31 @@ mod tests {
         assert_eq!(valid.len(), 2); // MSFT Entry, AAPL Exit
         assert_eq!(conflicts.len(), 2); // AAPL Entry, GOOG Exit
     }
+
+    #[test]
+    fn test_upcoming_economic_events() {
+        use crate::{Country, EconomicEvent, EconomicEventKind};
+
+        let now = Utc::now();
+        let mut ctx = StrategyContext::new();
+        ctx.update_economic_calendar(EconomicCalendar::from_events([
+            EconomicEvent::new(
+                EconomicEventKind::Fomc,
+                "FOMC",
+                Country::US,
+                now + chrono::Duration::hours(2),
+            ),
+            EconomicEvent::new(
+                EconomicEventKind::BokRateDecision,
+                "금통위",
+                Country::KR,
+                now + chrono::Duration::days(10),
+            ),
+        ]));
+
+        let upcoming = ctx.upcoming_events(now, chrono::Duration::days(1));
+        assert_eq!(upcoming.len(), 1);
+        assert_eq!(upcoming[0].kind, EconomicEventKind::Fomc);
+        assert_eq!(ctx.get_economic_calendar().len(), 2);
+    }
 }
@@ -0,0 +1,414 @@
+//! 신호 결정 추적 (설명 가능성).
+//!
+//! 전략이 신호를 발생시키거나 억제한 이유를 단계별로 기록합니다:
+//! - `DecisionTrace` - 한 번의 평가(종목·시점)에 대한 결정 기록
+//! - `TraceStep` - 지표 값, 필터/리스크/스크리닝 검사 결과와 기준값
+//! - `DecisionTraceQuery` - "2026-03-04에 005930을 왜 사지 않았나" 같은 검색 조건
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::domain::{Side, Signal, SignalType};
+
+/// 추적 단계 유형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TraceStepKind {
+    /// 지표 값 기록 (판정 없음)
+    Indicator,
+    /// 신호 조건/필터 검사
+    Filter,
+    /// 리스크 한도 검사
+    Risk,
+    /// 스크리닝 (RouteState, GlobalScore) 검사
+    Screening,
+    /// 엔진의 충돌 검증 (미체결 주문, 중복 포지션 등)
+    Conflict,
+    /// 기타 메모
+    Note,
+}
+
+/// 결정 추적의 단일 단계.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct TraceStep {
+    /// 단계 유형
+    pub kind: TraceStepKind,
+    /// 단계 이름 (예: "rsi", "volume_filter")
+    pub name: String,
+    /// 검사 통과 여부 (지표/메모는 None)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passed: Option<bool>,
+    /// 관측 값
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    /// 기준값 (임계값)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<Value>,
+    /// 사람이 읽을 수 있는 설명
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl TraceStep {
+    /// 새 단계 생성.
+    pub fn new(kind: TraceStepKind, name: impl Into<String>) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+            passed: None,
+            value: None,
+            threshold: None,
+            detail: None,
+        }
+    }
+
+    /// 통과 여부 설정.
+    pub fn with_passed(mut self, passed: bool) -> Self {
+        self.passed = Some(passed);
+        self
+    }
+
+    /// 관측 값 설정 (직렬화 실패 시 무시).
+    pub fn with_value(mut self, value: impl Serialize) -> Self {
+        self.value = serde_json::to_value(value).ok();
+        self
+    }
+
+    /// 기준값 설정 (직렬화 실패 시 무시).
+    pub fn with_threshold(mut self, threshold: impl Serialize) -> Self {
+        self.threshold = serde_json::to_value(threshold).ok();
+        self
+    }
+
+    /// 설명 설정.
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// 실패한 검사인지 확인.
+    pub fn is_failure(&self) -> bool {
+        self.passed == Some(false)
+    }
+
+    /// 단계 요약 문자열.
+    pub fn describe(&self) -> String {
+        let mut text = self.name.clone();
+        if let Some(value) = &self.value {
+            text.push_str(&format!(" = {}", value));
+        }
+        if let Some(threshold) = &self.threshold {
+            text.push_str(&format!(" (기준 {})", threshold));
+        }
+        if let Some(detail) = &self.detail {
+            text.push_str(&format!(": {}", detail));
+        }
+        text
+    }
+}
+
+/// 결정 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionOutcome {
+    /// 신호 발생
+    Emitted,
+    /// 신호 억제 (조건 미충족 또는 충돌)
+    ///
+    /// 조건 미충족은 단계별 추적을 기록하는 전략에서만 남고,
+    /// 충돌로 인한 억제는 엔진이 모든 전략에 대해 기록합니다.
+    Suppressed,
+}
+
+/// 한 번의 신호 평가에 대한 결정 추적.
+///
+/// 전략은 평가마다 추적을 만들고 단계를 기록한 뒤, 신호를 만들면
+/// [`emit`](Self::emit)으로 신호 ID를 연결합니다. 연결하지 않은 추적은
+/// 억제된 결정으로 남습니다.
+///
+/// # 예시
+///
+/// ```rust,ignore
+/// let mut trace = DecisionTrace::new("mean_reversion", "005930", timestamp);
+/// trace.indicator("rsi", rsi);
+/// if trace.filter("rsi_oversold", rsi < oversold, rsi, oversold) {
+///     let signal = Signal::entry("mean_reversion", "005930".into(), Side::Buy);
+///     trace.emit(&signal);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct DecisionTrace {
+    /// 추적 ID
+    pub id: Uuid,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 평가 종목
+    pub ticker: String,
+    /// 평가 시각 (데이터 기준)
+    pub timestamp: DateTime<Utc>,
+    /// 결정 결과
+    pub outcome: DecisionOutcome,
+    /// 발생한 신호 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signal_id: Option<Uuid>,
+    /// 신호 방향 (발생 시 또는 평가 의도)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<Side>,
+    /// 신호 유형 (발생 시 또는 평가 의도)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signal_type: Option<SignalType>,
+    /// 평가 단계 (기록 순)
+    #[serde(default)]
+    pub steps: Vec<TraceStep>,
+}
+
+impl DecisionTrace {
+    /// 새 추적 생성 (신호 연결 전까지 억제 상태).
+    pub fn new(
+        strategy_id: impl Into<String>,
+        ticker: impl Into<String>,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            strategy_id: strategy_id.into(),
+            ticker: ticker.into(),
+            timestamp,
+            outcome: DecisionOutcome::Suppressed,
+            signal_id: None,
+            side: None,
+            signal_type: None,
+            steps: Vec::new(),
+        }
+    }
+
+    /// 추적 없이 발생한 신호의 기본 추적.
+    ///
+    /// 신호 메타데이터의 `reason`을 메모로 남깁니다.
+    pub fn for_signal(signal: &Signal) -> Self {
+        let mut trace = Self::new(&signal.strategy_id, &signal.ticker, signal.timestamp);
+        if let Some(reason) = signal.metadata.get("reason").and_then(|v| v.as_str()) {
+            trace.note(reason);
+        }
+        trace.emit(signal);
+        trace
+    }
+
+    /// 평가 의도 (방향, 신호 유형) 설정.
+    pub fn with_intent(mut self, side: Side, signal_type: SignalType) -> Self {
+        self.side = Some(side);
+        self.signal_type = Some(signal_type);
+        self
+    }
+
+    /// 단계 추가.
+    pub fn push(&mut self, step: TraceStep) -> &mut Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// 지표 값 기록.
+    pub fn indicator(&mut self, name: impl Into<String>, value: impl Serialize) -> &mut Self {
+        self.push(TraceStep::new(TraceStepKind::Indicator, name).with_value(value))
+    }
+
+    /// 검사 결과 기록 후 통과 여부 반환.
+    ///
+    /// 조건식 안에서 바로 사용할 수 있도록 `passed`를 그대로 돌려줍니다.
+    pub fn check(
+        &mut self,
+        kind: TraceStepKind,
+        name: impl Into<String>,
+        passed: bool,
+        value: impl Serialize,
+        threshold: impl Serialize,
+    ) -> bool {
+        self.push(
+            TraceStep::new(kind, name)
+                .with_passed(passed)
+                .with_value(value)
+                .with_threshold(threshold),
+        );
+        passed
+    }
+
+    /// 신호 조건/필터 검사 기록.
+    pub fn filter(
+        &mut self,
+        name: impl Into<String>,
+        passed: bool,
+        value: impl Serialize,
+        threshold: impl Serialize,
+    ) -> bool {
+        self.check(TraceStepKind::Filter, name, passed, value, threshold)
+    }
+
+    /// 값 없는 조건 검사 기록 (예: 쿨다운, 포지션 보유).
+    pub fn condition(&mut self, name: impl Into<String>, passed: bool) -> bool {
+        self.push(TraceStep::new(TraceStepKind::Filter, name).with_passed(passed));
+        passed
+    }
+
+    /// 메모 기록.
+    pub fn note(&mut self, detail: impl Into<String>) -> &mut Self {
+        self.push(TraceStep::new(TraceStepKind::Note, "note").with_detail(detail))
+    }
+
+    /// 발생한 신호 연결.
+    pub fn emit(&mut self, signal: &Signal) {
+        self.outcome = DecisionOutcome::Emitted;
+        self.signal_id = Some(signal.id);
+        self.side = Some(signal.side);
+        self.signal_type = Some(signal.signal_type);
+    }
+
+    /// 신호를 억제로 전환하고 사유 단계 추가.
+    ///
+    /// 전략이 만든 신호가 엔진 충돌 검증 등에서 제외된 경우 사용합니다.
+    /// 신호 ID는 조회용으로 유지됩니다.
+    pub fn suppress(
+        &mut self,
+        kind: TraceStepKind,
+        name: impl Into<String>,
+        reason: impl Into<String>,
+    ) {
+        self.outcome = DecisionOutcome::Suppressed;
+        self.push(
+            TraceStep::new(kind, name)
+                .with_passed(false)
+                .with_detail(reason),
+        );
+    }
+
+    /// 신호 발생 여부.
+    pub fn is_emitted(&self) -> bool {
+        self.outcome == DecisionOutcome::Emitted
+    }
+
+    /// 처음 실패한 검사 단계.
+    pub fn first_failure(&self) -> Option<&TraceStep> {
+        self.steps.iter().find(|step| step.is_failure())
+    }
+
+    /// 결정 요약 문자열.
+    pub fn summary(&self) -> String {
+        match (self.outcome, self.first_failure()) {
+            (DecisionOutcome::Emitted, _) => "신호 발생".to_string(),
+            (DecisionOutcome::Suppressed, Some(step)) => {
+                format!("신호 억제 - {} 미충족", step.describe())
+            }
+            (DecisionOutcome::Suppressed, None) => "신호 억제 - 조건 미충족".to_string(),
+        }
+    }
+}
+
+/// 결정 추적 검색 조건.
+///
+/// 지정한 조건을 모두 만족하는 추적만 일치합니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct DecisionTraceQuery {
+    /// 전략 ID
+    #[serde(default)]
+    pub strategy_id: Option<String>,
+    /// 종목
+    #[serde(default)]
+    pub ticker: Option<String>,
+    /// 평가 날짜 (UTC)
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+    /// 시작 시각 (포함)
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+    /// 종료 시각 (포함)
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+    /// 결정 결과
+    #[serde(default)]
+    pub outcome: Option<DecisionOutcome>,
+    /// 신호 방향 (평가 의도 포함)
+    #[serde(default)]
+    pub side: Option<Side>,
+}
+
+impl DecisionTraceQuery {
+    /// 추적이 조건과 일치하는지 확인.
+    pub fn matches(&self, trace: &DecisionTrace) -> bool {
+        self.strategy_id
+            .as_ref()
+            .map_or(true, |id| &trace.strategy_id == id)
+            && self.ticker.as_ref().map_or(true, |t| &trace.ticker == t)
+            && self
+                .date
+                .map_or(true, |date| trace.timestamp.date_naive() == date)
+            && self.from.map_or(true, |from| trace.timestamp >= from)
+            && self.to.map_or(true, |to| trace.timestamp <= to)
+            && self
+                .outcome
+                .map_or(true, |outcome| trace.outcome == outcome)
+            && self.side.map_or(true, |side| trace.side == Some(side))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_trace_records_failed_filter() {
+        let timestamp = Utc.with_ymd_and_hms(2026, 3, 4, 0, 0, 0).unwrap();
+        let mut trace = DecisionTrace::new("mean_reversion", "005930", timestamp)
+            .with_intent(Side::Buy, SignalType::Entry);
+        trace.indicator("rsi", dec!(42.5));
+        assert!(trace.condition("no_position", true));
+        assert!(!trace.filter("rsi_oversold", false, dec!(42.5), dec!(30)));
+
+        assert!(!trace.is_emitted());
+        assert_eq!(trace.first_failure().unwrap().name, "rsi_oversold");
+        assert_eq!(
+            trace.summary(),
+            "신호 억제 - rsi_oversold = 42.5 (기준 30.0) 미충족"
+        );
+
+        let query = DecisionTraceQuery {
+            ticker: Some("005930".to_string()),
+            date: NaiveDate::from_ymd_opt(2026, 3, 4),
+            outcome: Some(DecisionOutcome::Suppressed),
+            side: Some(Side::Buy),
+            ..Default::default()
+        };
+        assert!(query.matches(&trace));
+        assert!(!DecisionTraceQuery {
+            date: NaiveDate::from_ymd_opt(2026, 3, 5),
+            ..Default::default()
+        }
+        .matches(&trace));
+    }
+
+    #[test]
+    fn test_emitted_then_suppressed_by_conflict() {
+        let signal = Signal::entry("grid", "BTC/USDT".to_string(), Side::Buy)
+            .with_metadata("reason", serde_json::json!("grid_buy"));
+        let mut trace = DecisionTrace::for_signal(&signal);
+        assert!(trace.is_emitted());
+        assert_eq!(trace.signal_id, Some(signal.id));
+
+        trace.suppress(
+            TraceStepKind::Conflict,
+            "signal_conflict",
+            "미체결 주문 존재",
+        );
+        assert_eq!(trace.outcome, DecisionOutcome::Suppressed);
+        assert_eq!(trace.signal_id, Some(signal.id));
+        assert_eq!(trace.first_failure().unwrap().kind, TraceStepKind::Conflict);
+    }
+}
@@ -0,0 +1,671 @@
+//! 경제 이벤트 캘린더.
+//!
+//! FOMC, CPI, 한국은행 금융통화위원회, 옵션 만기일, 지수 정기변경일 등
+//! 시장 변동성을 키우는 일정을 보관하고, 이벤트 전후 구간의 신규 진입을
+//! 차단하거나 주문 수량을 줄이는 블랙아웃 규칙을 평가합니다.
+//!
+//! 일정은 외부(ICS/CSV 피드, DB)에서 주입하며, 만기일/정기변경일처럼 규칙으로
+//! 결정되는 일정은 [`EconomicCalendar::recurring`]으로 생성할 수 있습니다.
+//! 캘린더 자체는 네트워크 의존성이 없어 백테스트와 실거래에서 동일하게 동작합니다.
+
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc, Weekday};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::calendar::MarketTimezone;
+use crate::types::Country;
+
+/// 경제 이벤트 유형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EconomicEventKind {
+    /// 미 연준 FOMC 금리 결정
+    Fomc,
+    /// 소비자물가지수 발표
+    Cpi,
+    /// 한국은행 금융통화위원회 기준금리 결정
+    BokRateDecision,
+    /// 고용지표 (비농업 고용 등)
+    Employment,
+    /// 옵션/선물 만기일
+    OptionsExpiry,
+    /// 지수 정기변경 (KOSPI200, S&P 500 리밸런싱)
+    IndexRebalance,
+    /// 기타
+    Other,
+}
+
+impl EconomicEventKind {
+    /// 전체 유형 목록.
+    pub const ALL: [EconomicEventKind; 7] = [
+        EconomicEventKind::Fomc,
+        EconomicEventKind::Cpi,
+        EconomicEventKind::BokRateDecision,
+        EconomicEventKind::Employment,
+        EconomicEventKind::OptionsExpiry,
+        EconomicEventKind::IndexRebalance,
+        EconomicEventKind::Other,
+    ];
+
+    /// DB/설정 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EconomicEventKind::Fomc => "fomc",
+            EconomicEventKind::Cpi => "cpi",
+            EconomicEventKind::BokRateDecision => "bok_rate_decision",
+            EconomicEventKind::Employment => "employment",
+            EconomicEventKind::OptionsExpiry => "options_expiry",
+            EconomicEventKind::IndexRebalance => "index_rebalance",
+            EconomicEventKind::Other => "other",
+        }
+    }
+
+    /// 저장용 문자열에서 변환.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(s))
+    }
+
+    /// 이벤트 제목으로 유형 분류.
+    ///
+    /// ICS/CSV 피드에 유형 컬럼이 없을 때 사용하며, 매칭되지 않으면 [`Other`](Self::Other)입니다.
+    pub fn classify(title: &str) -> Self {
+        let upper = title.to_uppercase();
+        let has = |keywords: &[&str]| keywords.iter().any(|k| upper.contains(k));
+
+        if has(&["FOMC", "FEDERAL FUNDS", "FED INTEREST RATE"]) {
+            EconomicEventKind::Fomc
+        } else if has(&[
+            "금융통화위원회",
+            "금통위",
+            "한국은행",
+            "BOK",
+            "BANK OF KOREA",
+        ]) {
+            EconomicEventKind::BokRateDecision
+        } else if has(&["CPI", "CONSUMER PRICE", "소비자물가"]) {
+            EconomicEventKind::Cpi
+        } else if has(&["NONFARM", "NON-FARM", "PAYROLL", "UNEMPLOYMENT", "고용"]) {
+            EconomicEventKind::Employment
+        } else if has(&["EXPIRY", "EXPIRATION", "WITCHING", "만기"]) {
+            EconomicEventKind::OptionsExpiry
+        } else if has(&["REBALANC", "RECONSTITUTION", "정기변경", "리밸런싱"]) {
+            EconomicEventKind::IndexRebalance
+        } else {
+            EconomicEventKind::Other
+        }
+    }
+
+    /// 한글 표시명.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            EconomicEventKind::Fomc => "FOMC",
+            EconomicEventKind::Cpi => "CPI",
+            EconomicEventKind::BokRateDecision => "금통위",
+            EconomicEventKind::Employment => "고용지표",
+            EconomicEventKind::OptionsExpiry => "옵션 만기",
+            EconomicEventKind::IndexRebalance => "지수 정기변경",
+            EconomicEventKind::Other => "기타",
+        }
+    }
+
+    /// 피드에 영향도가 없을 때 사용하는 기본 영향도.
+    pub fn default_impact(&self) -> EventImpact {
+        match self {
+            EconomicEventKind::Fomc
+            | EconomicEventKind::Cpi
+            | EconomicEventKind::BokRateDecision
+            | EconomicEventKind::Employment => EventImpact::High,
+            EconomicEventKind::OptionsExpiry | EconomicEventKind::IndexRebalance => {
+                EventImpact::Medium
+            }
+            EconomicEventKind::Other => EventImpact::Low,
+        }
+    }
+}
+
+impl fmt::Display for EconomicEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// 이벤트 영향도 (Low < Medium < High).
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum EventImpact {
+    /// 낮음
+    Low,
+    /// 보통
+    Medium,
+    /// 높음
+    #[default]
+    High,
+}
+
+impl EventImpact {
+    /// DB/설정 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventImpact::Low => "low",
+            EventImpact::Medium => "medium",
+            EventImpact::High => "high",
+        }
+    }
+
+    /// 문자열에서 변환 (`high`, `3`, `★★★` 등 피드별 표기 허용).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "low" | "1" | "★" | "하" => Some(EventImpact::Low),
+            "medium" | "med" | "moderate" | "2" | "★★" | "중" => Some(EventImpact::Medium),
+            "high" | "3" | "★★★" | "상" => Some(EventImpact::High),
+            _ => None,
+        }
+    }
+}
+
+/// 경제 이벤트.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EconomicEvent {
+    /// 이벤트 유형
+    pub kind: EconomicEventKind,
+    /// 제목 (예: "FOMC Rate Decision", "한국은행 기준금리 결정")
+    pub title: String,
+    /// 해당 국가
+    pub country: Country,
+    /// 영향도
+    pub impact: EventImpact,
+    /// 발표/발생 시각 (UTC)
+    pub scheduled_at: DateTime<Utc>,
+    /// 출처 (피드 URL, 파일명, "recurring" 등)
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+impl EconomicEvent {
+    /// 새 이벤트 생성 (영향도는 유형별 기본값).
+    pub fn new(
+        kind: EconomicEventKind,
+        title: impl Into<String>,
+        country: Country,
+        scheduled_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            kind,
+            title: title.into(),
+            country,
+            impact: kind.default_impact(),
+            scheduled_at,
+            source: None,
+        }
+    }
+
+    /// 영향도 설정.
+    pub fn with_impact(mut self, impact: EventImpact) -> Self {
+        self.impact = impact;
+        self
+    }
+
+    /// 출처 설정.
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+}
+
+/// 경제 이벤트 캘린더 (발생 시각 오름차순 유지).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EconomicCalendar {
+    events: Vec<EconomicEvent>,
+}
+
+impl EconomicCalendar {
+    /// 빈 캘린더 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 이벤트 목록으로 캘린더 생성.
+    pub fn from_events(events: impl IntoIterator<Item = EconomicEvent>) -> Self {
+        let mut calendar = Self::new();
+        calendar.extend(events);
+        calendar
+    }
+
+    /// 이벤트 추가 (같은 유형/국가/시각의 이벤트는 교체).
+    pub fn insert(&mut self, event: EconomicEvent) {
+        self.extend([event]);
+    }
+
+    /// 여러 이벤트 추가 (같은 유형/국가/시각의 이벤트는 교체).
+    pub fn extend(&mut self, events: impl IntoIterator<Item = EconomicEvent>) {
+        for event in events {
+            match self.events.iter_mut().find(|e| {
+                e.kind == event.kind
+                    && e.country == event.country
+                    && e.scheduled_at == event.scheduled_at
+            }) {
+                Some(existing) => *existing = event,
+                None => self.events.push(event),
+            }
+        }
+        self.events.sort_by_key(|e| e.scheduled_at);
+    }
+
+    /// 전체 이벤트 (시각 오름차순).
+    pub fn events(&self) -> &[EconomicEvent] {
+        &self.events
+    }
+
+    /// 이벤트 수.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// 이벤트가 없는지 여부.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// 구간 내 이벤트 (`start <= scheduled_at <= end`).
+    pub fn events_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> impl Iterator<Item = &EconomicEvent> {
+        let from = self.events.partition_point(|e| e.scheduled_at < start);
+        self.events[from..]
+            .iter()
+            .take_while(move |e| e.scheduled_at <= end)
+    }
+
+    /// `now` 이후 `horizon` 이내의 예정 이벤트.
+    pub fn upcoming(
+        &self,
+        now: DateTime<Utc>,
+        horizon: Duration,
+    ) -> impl Iterator<Item = &EconomicEvent> {
+        self.events_between(now, now + horizon)
+    }
+
+    /// 다음 예정 이벤트 (영향도 `min_impact` 이상).
+    pub fn next_event(
+        &self,
+        now: DateTime<Utc>,
+        min_impact: EventImpact,
+    ) -> Option<&EconomicEvent> {
+        let from = self.events.partition_point(|e| e.scheduled_at < now);
+        self.events[from..].iter().find(|e| e.impact >= min_impact)
+    }
+
+    /// 규칙 기반 정기 일정 생성 (`from..=to` 현지 날짜 기준).
+    ///
+    /// - 한국 옵션 만기: 매월 둘째 목요일 15:20 KST (3/6/9/12월은 선물 동시만기로 High)
+    /// - 미국 옵션 만기: 매월 셋째 금요일 16:00 ET (3/6/9/12월 쿼드러플 위칭은 High)
+    /// - KOSPI200 정기변경: 6/12월 선물 동시만기일
+    /// - S&P 500 분기 리밸런싱: 3/6/9/12월 셋째 금요일
+    ///
+    /// 휴장일로 인한 만기일 변경은 반영하지 않습니다.
+    pub fn recurring(from: NaiveDate, to: NaiveDate) -> Vec<EconomicEvent> {
+        let mut events = Vec::new();
+        let mut month = NaiveDate::from_ymd_opt(from.year(), from.month(), 1);
+
+        while let Some(first) = month.filter(|m| *m <= to) {
+            let quarterly = first.month() % 3 == 0;
+            let semiannual = first.month() == 6 || first.month() == 12;
+
+            if let Some(kr) = nth_weekday(first, Weekday::Thu, 2).filter(|d| *d >= from && *d <= to)
+            {
+                let at = MarketTimezone::Seoul.to_utc(kr, hm(15, 20));
+                let title = if quarterly {
+                    "KOSPI200 선물/옵션 동시만기"
+                } else {
+                    "KOSPI200 옵션 만기"
+                };
+                let impact = if quarterly {
+                    EventImpact::High
+                } else {
+                    EventImpact::Medium
+                };
+                events.push(
+                    EconomicEvent::new(EconomicEventKind::OptionsExpiry, title, Country::KR, at)
+                        .with_impact(impact)
+                        .with_source(RECURRING_SOURCE),
+                );
+                if semiannual {
+                    events.push(
+                        EconomicEvent::new(
+                            EconomicEventKind::IndexRebalance,
+                            "KOSPI200 정기변경",
+                            Country::KR,
+                            at,
+                        )
+                        .with_source(RECURRING_SOURCE),
+                    );
+                }
+            }
+
+            if let Some(us) = nth_weekday(first, Weekday::Fri, 3).filter(|d| *d >= from && *d <= to)
+            {
+                let at = MarketTimezone::NewYork.to_utc(us, hm(16, 0));
+                let (title, impact) = if quarterly {
+                    ("Quadruple Witching", EventImpact::High)
+                } else {
+                    ("Monthly Options Expiration", EventImpact::Medium)
+                };
+                events.push(
+                    EconomicEvent::new(EconomicEventKind::OptionsExpiry, title, Country::US, at)
+                        .with_impact(impact)
+                        .with_source(RECURRING_SOURCE),
+                );
+                if quarterly {
+                    events.push(
+                        EconomicEvent::new(
+                            EconomicEventKind::IndexRebalance,
+                            "S&P 500 Quarterly Rebalance",
+                            Country::US,
+                            at,
+                        )
+                        .with_source(RECURRING_SOURCE),
+                    );
+                }
+            }
+
+            month = first.checked_add_months(chrono::Months::new(1));
+        }
+
+        events.sort_by_key(|e| e.scheduled_at);
+        events
+    }
+}
+
+/// 규칙 기반 정기 일정의 출처 표기.
+pub const RECURRING_SOURCE: &str = "recurring";
+
+/// 이벤트 블랙아웃 시 조치.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BlackoutAction {
+    /// 신규 진입 차단
+    #[default]
+    Block,
+    /// 신규 진입 수량 축소 (factor: 0 초과 1 이하 배율)
+    ReduceSize {
+        /// 수량 배율 (예: 0.5면 절반)
+        factor: Decimal,
+    },
+}
+
+/// 이벤트 전후 신규 진입 블랙아웃 설정.
+///
+/// 영향도가 `min_impact` 이상인 이벤트의 `before_minutes` 전부터 `after_minutes` 후까지
+/// 신규 진입을 차단하거나 수량을 줄입니다. 청산 주문에는 적용되지 않습니다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventBlackoutConfig {
+    /// 적용 최소 영향도
+    #[serde(default)]
+    pub min_impact: EventImpact,
+
+    /// 이벤트 전 블랙아웃 시간 (분)
+    #[serde(default = "default_before_minutes")]
+    pub before_minutes: i64,
+
+    /// 이벤트 후 블랙아웃 시간 (분)
+    #[serde(default = "default_after_minutes")]
+    pub after_minutes: i64,
+
+    /// 블랙아웃 조치
+    #[serde(default)]
+    pub action: BlackoutAction,
+
+    /// 대상 이벤트 유형 (비어 있으면 전체)
+    #[serde(default)]
+    pub kinds: Vec<EconomicEventKind>,
+
+    /// 대상 국가 (비어 있으면 전체)
+    #[serde(default)]
+    pub countries: Vec<Country>,
+}
+
+fn default_before_minutes() -> i64 {
+    60
+}
+
+fn default_after_minutes() -> i64 {
+    30
+}
+
+impl Default for EventBlackoutConfig {
+    fn default() -> Self {
+        Self {
+            min_impact: EventImpact::High,
+            before_minutes: default_before_minutes(),
+            after_minutes: default_after_minutes(),
+            action: BlackoutAction::Block,
+            kinds: Vec::new(),
+            countries: Vec::new(),
+        }
+    }
+}
+
+impl EventBlackoutConfig {
+    /// 수량 축소 조치로 설정.
+    pub fn with_reduce_size(mut self, factor: Decimal) -> Self {
+        self.action = BlackoutAction::ReduceSize { factor };
+        self
+    }
+
+    /// 블랙아웃 구간 설정 (분).
+    pub fn with_window(mut self, before_minutes: i64, after_minutes: i64) -> Self {
+        self.before_minutes = before_minutes;
+        self.after_minutes = after_minutes;
+        self
+    }
+
+    /// 적용 최소 영향도 설정.
+    pub fn with_min_impact(mut self, min_impact: EventImpact) -> Self {
+        self.min_impact = min_impact;
+        self
+    }
+
+    /// 대상 국가 설정.
+    pub fn with_countries(mut self, countries: impl IntoIterator<Item = Country>) -> Self {
+        self.countries = countries.into_iter().collect();
+        self
+    }
+
+    /// 이벤트가 블랙아웃 대상인지 여부 (구간 제외).
+    pub fn applies_to(&self, event: &EconomicEvent) -> bool {
+        event.impact >= self.min_impact
+            && (self.kinds.is_empty() || self.kinds.contains(&event.kind))
+            && (self.countries.is_empty() || self.countries.contains(&event.country))
+    }
+
+    /// `ts` 시점에 블랙아웃을 유발하는 이벤트 (가장 가까운 이벤트).
+    pub fn active_event<'a>(
+        &self,
+        calendar: &'a EconomicCalendar,
+        ts: DateTime<Utc>,
+    ) -> Option<&'a EconomicEvent> {
+        // 이벤트 시각이 [ts - after, ts + before] 안에 있으면 블랙아웃
+        let start = ts - Duration::minutes(self.after_minutes.max(0));
+        let end = ts + Duration::minutes(self.before_minutes.max(0));
+        calendar
+            .events_between(start, end)
+            .filter(|e| self.applies_to(e))
+            .min_by_key(|e| (e.scheduled_at - ts).num_seconds().abs())
+    }
+}
+
+/// 해당 월의 n번째 요일.
+fn nth_weekday(first_of_month: NaiveDate, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(first_of_month.year(), first_of_month.month(), weekday, n)
+}
+
+fn hm(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).expect("유효한 시각")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn fomc() -> EconomicEvent {
+        // 2024-03-20 14:00 ET = 18:00 UTC
+        EconomicEvent::new(
+            EconomicEventKind::Fomc,
+            "FOMC Rate Decision",
+            Country::US,
+            utc(2024, 3, 20, 18, 0),
+        )
+    }
+
+    #[test]
+    fn test_classify_and_parse() {
+        assert_eq!(
+            EconomicEventKind::classify("FOMC Statement"),
+            EconomicEventKind::Fomc
+        );
+        assert_eq!(
+            EconomicEventKind::classify("한국은행 금융통화위원회 기준금리 결정"),
+            EconomicEventKind::BokRateDecision
+        );
+        assert_eq!(
+            EconomicEventKind::classify("US Core CPI (MoM)"),
+            EconomicEventKind::Cpi
+        );
+        assert_eq!(
+            EconomicEventKind::classify("Nonfarm Payrolls"),
+            EconomicEventKind::Employment
+        );
+        assert_eq!(
+            EconomicEventKind::classify("GDP Growth Rate"),
+            EconomicEventKind::Other
+        );
+
+        for kind in EconomicEventKind::ALL {
+            assert_eq!(EconomicEventKind::parse(kind.as_str()), Some(kind));
+        }
+        assert_eq!(EventImpact::parse("★★★"), Some(EventImpact::High));
+        assert_eq!(EventImpact::parse("Medium"), Some(EventImpact::Medium));
+        assert!(EventImpact::High > EventImpact::Medium);
+    }
+
+    #[test]
+    fn test_calendar_sorted_and_deduplicated() {
+        let cpi = EconomicEvent::new(
+            EconomicEventKind::Cpi,
+            "CPI",
+            Country::US,
+            utc(2024, 3, 12, 12, 30),
+        );
+        let mut calendar = EconomicCalendar::from_events([fomc(), cpi.clone()]);
+        calendar.insert(fomc().with_impact(EventImpact::Medium));
+
+        assert_eq!(calendar.len(), 2);
+        assert_eq!(calendar.events()[0], cpi);
+        assert_eq!(calendar.events()[1].impact, EventImpact::Medium);
+
+        let upcoming: Vec<_> = calendar
+            .upcoming(utc(2024, 3, 13, 0, 0), Duration::days(8))
+            .collect();
+        assert_eq!(upcoming.len(), 1);
+        assert_eq!(upcoming[0].kind, EconomicEventKind::Fomc);
+        assert_eq!(
+            calendar
+                .next_event(utc(2024, 3, 1, 0, 0), EventImpact::High)
+                .map(|e| e.kind),
+            Some(EconomicEventKind::Cpi)
+        );
+    }
+
+    #[test]
+    fn test_recurring_expiry_dates() {
+        let events = EconomicCalendar::recurring(date(2024, 3, 1), date(2024, 4, 30));
+
+        // 2024-03-14 (둘째 목요일) 동시만기 + 2024-04-11 옵션 만기
+        let kr: Vec<_> = events
+            .iter()
+            .filter(|e| e.country == Country::KR && e.kind == EconomicEventKind::OptionsExpiry)
+            .collect();
+        assert_eq!(kr.len(), 2);
+        assert_eq!(kr[0].scheduled_at, utc(2024, 3, 14, 6, 20));
+        assert_eq!(kr[0].impact, EventImpact::High);
+        assert_eq!(kr[1].scheduled_at, utc(2024, 4, 11, 6, 20));
+        assert_eq!(kr[1].impact, EventImpact::Medium);
+
+        // 2024-03-15 (셋째 금요일) 16:00 EDT = 20:00 UTC, S&P 분기 리밸런싱 포함
+        assert!(events.iter().any(|e| e.country == Country::US
+            && e.kind == EconomicEventKind::IndexRebalance
+            && e.scheduled_at == utc(2024, 3, 15, 20, 0)));
+        // KOSPI200 정기변경은 6/12월만
+        assert!(!events
+            .iter()
+            .any(|e| e.country == Country::KR && e.kind == EconomicEventKind::IndexRebalance));
+    }
+
+    #[test]
+    fn test_blackout_window() {
+        let calendar = EconomicCalendar::from_events([fomc()]);
+        let config = EventBlackoutConfig::default().with_window(60, 30);
+
+        assert!(config
+            .active_event(&calendar, utc(2024, 3, 20, 16, 59))
+            .is_none());
+        assert!(config
+            .active_event(&calendar, utc(2024, 3, 20, 17, 0))
+            .is_some());
+        assert!(config
+            .active_event(&calendar, utc(2024, 3, 20, 18, 30))
+            .is_some());
+        assert!(config
+            .active_event(&calendar, utc(2024, 3, 20, 18, 31))
+            .is_none());
+
+        // 국가/영향도 필터
+        let kr_only = EventBlackoutConfig::default().with_countries([Country::KR]);
+        assert!(kr_only
+            .active_event(&calendar, utc(2024, 3, 20, 18, 0))
+            .is_none());
+        let low = EconomicCalendar::from_events([fomc().with_impact(EventImpact::Low)]);
+        assert!(config.active_event(&low, utc(2024, 3, 20, 18, 0)).is_none());
+    }
+
+    #[test]
+    fn test_blackout_config_serde() {
+        let config: EventBlackoutConfig = serde_json::from_value(serde_json::json!({
+            "before_minutes": 120,
+            "action": { "type": "reduce_size", "factor": "0.5" },
+            "kinds": ["fomc", "bok_rate_decision"],
+            "countries": ["US", "KR"]
+        }))
+        .unwrap();
+
+        assert_eq!(config.min_impact, EventImpact::High);
+        assert_eq!(config.before_minutes, 120);
+        assert_eq!(config.after_minutes, 30);
+        assert_eq!(
+            config.action,
+            BlackoutAction::ReduceSize { factor: dec!(0.5) }
+        );
+        assert_eq!(config.kinds.len(), 2);
+        assert_eq!(config.countries, vec![Country::US, Country::KR]);
+    }
+}
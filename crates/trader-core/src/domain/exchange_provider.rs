@@ -7,7 +7,9 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use super::{PendingOrder, StrategyAccountInfo, StrategyPositionInfo, Trade};
+use super::{
+    OrderBook, OrderStatus, PendingOrder, StrategyAccountInfo, StrategyPositionInfo, Trade,
+};
 
 // =============================================================================
 // 요청/응답 타입
@@ -338,6 +340,26 @@ pub trait MarketDataProvider: Send + Sync {
         }
         results
     }
+
+    /// 호가창 조회.
+    ///
+    /// 스마트 주문 라우팅 등 호가 깊이가 필요한 곳에서 사용합니다.
+    ///
+    /// # Errors
+    ///
+    /// - `ProviderError::Network`: 네트워크 연결 실패
+    /// - `ProviderError::Api`: 거래소 API 에러
+    /// - `ProviderError::Unsupported`: 호가창 조회 미지원 데이터 소스
+    ///
+    /// # 기본 구현
+    ///
+    /// 기본적으로 `Unsupported` 에러를 반환합니다.
+    async fn get_order_book(&self, _symbol: &str) -> Result<OrderBook, ProviderError> {
+        Err(ProviderError::Unsupported(format!(
+            "{}는 호가창 조회를 지원하지 않습니다",
+            self.provider_name()
+        )))
+    }
 }
 
 // =============================================================================
@@ -434,6 +456,30 @@ pub trait OrderExecutionProvider: Send + Sync {
         price: Option<Decimal>,
     ) -> Result<super::OrderResponse, ProviderError>;
 
+    /// 주문 상태 조회.
+    ///
+    /// 제출한 주문의 체결 수량과 평균 체결가를 확인합니다.
+    /// 스마트 주문 라우팅이 실현 체결가를 기록할 때 사용합니다.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_id` - 조회할 주문번호
+    /// * `ticker` - 종목 심볼 (거래소별 필요 여부 다름)
+    ///
+    /// # 기본 구현
+    ///
+    /// 기본적으로 `Unsupported` 에러를 반환합니다.
+    async fn get_order_status(
+        &self,
+        _order_id: &str,
+        _ticker: &str,
+    ) -> Result<OrderStatus, ProviderError> {
+        Err(ProviderError::Unsupported(format!(
+            "{}는 주문 상태 조회를 지원하지 않습니다",
+            self.exchange_name()
+        )))
+    }
+
     /// 거래소 이름.
     fn exchange_name(&self) -> &str;
 }
@@ -0,0 +1,447 @@
+//! 다중 통화 환율 및 기준 통화 환산.
+//!
+//! KRW 주식, USD 주식(`KisUsMarketStream`), USDT 암호화폐를 함께 운용할 때
+//! 통화별 금액을 기준 통화로 합산하기 위한 환율 테이블과 환차손익 분해를 제공합니다.
+//!
+//! 환율 이력은 수집기가 `fx_rates` 테이블에 저장하며, 이 모듈은 저장소와 무관한
+//! 계산만 담당하므로 포트폴리오 요약, 매매일지, 백테스트에서 동일하게 사용합니다.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    ops::{Add, AddAssign},
+};
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::types::Money;
+
+/// 기본 기준 통화.
+pub const DEFAULT_BASE_CURRENCY: &str = "KRW";
+
+/// 통화 코드 정규화 (공백 제거, 대문자).
+pub fn normalize_currency(code: &str) -> String {
+    code.trim().to_uppercase()
+}
+
+/// 티커 패턴으로 거래 통화를 추론합니다.
+///
+/// - `BASE/QUOTE` (예: "BTC/USDT"): QUOTE
+/// - `QUOTE-BASE` (Upbit/Bithumb 형식, 예: "KRW-BTC"): QUOTE
+/// - 숫자 종목 코드 또는 `.KS`/`.KQ` 접미사 (예: "005930"): KRW
+/// - 그 외 (예: "AAPL", "BRK-B"): USD
+pub fn ticker_currency(ticker: &str) -> String {
+    let ticker = ticker.trim();
+    if let Some((_, quote)) = ticker.split_once('/') {
+        return normalize_currency(quote);
+    }
+    if let Some((quote, _)) = ticker.split_once('-') {
+        let quote = normalize_currency(quote);
+        if matches!(quote.as_str(), "KRW" | "USDT" | "USDC" | "BTC" | "ETH") {
+            return quote;
+        }
+    }
+    let upper = ticker.to_uppercase();
+    if (!ticker.is_empty() && ticker.chars().all(|c| c.is_ascii_digit()))
+        || upper.ends_with(".KS")
+        || upper.ends_with(".KQ")
+    {
+        return "KRW".to_string();
+    }
+    "USD".to_string()
+}
+
+/// 환율 조회 실패.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("환율 없음: {from}/{to} ({date})")]
+pub struct MissingFxRate {
+    /// 원 통화
+    pub from: String,
+    /// 대상 통화
+    pub to: String,
+    /// 조회 기준일
+    pub date: NaiveDate,
+}
+
+/// 통화쌍별 일자 환율 이력.
+///
+/// `(base, quote, rate)`는 "1 base = rate quote"를 뜻합니다 (예: USD/KRW 1350).
+/// 조회일에 환율이 없으면 그 이전 가장 최근 환율을 사용하며(as-of), 역방향 통화쌍과
+/// 한 단계 교차 환산(예: USDT → USD → KRW)도 지원합니다.
+#[derive(Debug, Clone, Default)]
+pub struct FxRateTable {
+    /// (base, quote) → 일자별 환율
+    rates: HashMap<(String, String), BTreeMap<NaiveDate, Decimal>>,
+    /// 고정 연동 통화 (예: USDT → USD)
+    pegs: HashMap<String, String>,
+}
+
+impl FxRateTable {
+    /// 빈 환율 테이블 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 고정 연동 통화 설정 (예: `with_peg("USDT", "USD")`).
+    ///
+    /// 연동 통화는 환율 조회 시 연동 대상 통화와 1:1로 취급되며,
+    /// 해당 통화쌍의 환율이 직접 입력되어 있으면 입력된 환율이 우선합니다.
+    pub fn with_peg(mut self, currency: &str, pegged_to: &str) -> Self {
+        self.pegs
+            .insert(normalize_currency(currency), normalize_currency(pegged_to));
+        self
+    }
+
+    /// USDT/USDC를 USD에 연동합니다.
+    pub fn with_stablecoin_pegs(self) -> Self {
+        self.with_peg("USDT", "USD").with_peg("USDC", "USD")
+    }
+
+    /// 환율 추가 (같은 날짜는 교체, 0 이하 환율과 동일 통화쌍은 무시).
+    pub fn insert(&mut self, base: &str, quote: &str, date: NaiveDate, rate: Decimal) {
+        let (base, quote) = (normalize_currency(base), normalize_currency(quote));
+        if rate <= Decimal::ZERO || base == quote {
+            return;
+        }
+        self.rates
+            .entry((base, quote))
+            .or_default()
+            .insert(date, rate);
+    }
+
+    /// 다른 테이블의 환율과 연동 설정을 병합합니다 (같은 날짜는 `other` 우선).
+    pub fn merge(&mut self, other: &FxRateTable) {
+        for ((base, quote), series) in &other.rates {
+            for (date, rate) in series {
+                self.insert(base, quote, *date, *rate);
+            }
+        }
+        for (currency, pegged_to) in &other.pegs {
+            self.pegs.insert(currency.clone(), pegged_to.clone());
+        }
+    }
+
+    /// 저장된 환율 포인트 수.
+    pub fn len(&self) -> usize {
+        self.rates.values().map(BTreeMap::len).sum()
+    }
+
+    /// 환율이 비어있는지 확인.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 저장된 통화쌍 목록 (정렬).
+    pub fn pairs(&self) -> Vec<(String, String)> {
+        let mut pairs: Vec<_> = self.rates.keys().cloned().collect();
+        pairs.sort();
+        pairs
+    }
+
+    /// 통화쌍의 최신 환율과 기준일 (직접 입력된 통화쌍만).
+    pub fn latest(&self, base: &str, quote: &str) -> Option<(NaiveDate, Decimal)> {
+        self.rates
+            .get(&(normalize_currency(base), normalize_currency(quote)))
+            .and_then(|series| series.iter().next_back())
+            .map(|(date, rate)| (*date, *rate))
+    }
+
+    /// 기준일 시점 환율 (1 `from` = N `to`).
+    pub fn rate_on(&self, from: &str, to: &str, date: NaiveDate) -> Option<Decimal> {
+        let (from, to) = (normalize_currency(from), normalize_currency(to));
+        if from == to {
+            return Some(Decimal::ONE);
+        }
+        if let Some(rate) = self.lookup(&from, &to, date) {
+            return Some(rate);
+        }
+
+        // 연동 통화 치환 후 재조회
+        let from_resolved = self.pegs.get(&from).cloned().unwrap_or(from);
+        let to_resolved = self.pegs.get(&to).cloned().unwrap_or(to);
+        if from_resolved == to_resolved {
+            return Some(Decimal::ONE);
+        }
+        if let Some(rate) = self.lookup(&from_resolved, &to_resolved, date) {
+            return Some(rate);
+        }
+
+        // 한 단계 교차 환산 (from → via → to)
+        let vias: BTreeSet<&String> = self
+            .rates
+            .keys()
+            .filter_map(|(base, quote)| {
+                if *base == from_resolved {
+                    Some(quote)
+                } else if *quote == from_resolved {
+                    Some(base)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        vias.into_iter().find_map(|via| {
+            let first = self.lookup(&from_resolved, via, date)?;
+            let second = self.lookup(via, &to_resolved, date)?;
+            Some(first * second)
+        })
+    }
+
+    /// 최신 환율 (1 `from` = N `to`).
+    pub fn latest_rate(&self, from: &str, to: &str) -> Option<Decimal> {
+        self.rate_on(from, to, NaiveDate::MAX)
+    }
+
+    /// 기준일 시점 환율 (없으면 [`MissingFxRate`]).
+    pub fn require_rate(
+        &self,
+        from: &str,
+        to: &str,
+        date: NaiveDate,
+    ) -> Result<Decimal, MissingFxRate> {
+        self.rate_on(from, to, date).ok_or_else(|| MissingFxRate {
+            from: normalize_currency(from),
+            to: normalize_currency(to),
+            date,
+        })
+    }
+
+    /// 금액을 기준일 환율로 환산합니다.
+    pub fn convert(&self, money: &Money, to: &str, date: NaiveDate) -> Option<Money> {
+        self.rate_on(&money.currency, to, date)
+            .map(|rate| money.convert(to, rate))
+    }
+
+    /// 직접 또는 역방향 통화쌍의 as-of 환율.
+    fn lookup(&self, from: &str, to: &str, date: NaiveDate) -> Option<Decimal> {
+        let as_of = |base: &str, quote: &str| {
+            self.rates
+                .get(&(base.to_string(), quote.to_string()))
+                .and_then(|series| series.range(..=date).next_back())
+                .map(|(_, rate)| *rate)
+        };
+        as_of(from, to).or_else(|| as_of(to, from).map(|rate| Decimal::ONE / rate))
+    }
+}
+
+/// 통화별 금액 합계.
+///
+/// 서로 다른 통화의 금액을 그대로 더하지 않고 통화별로 보관했다가
+/// [`CurrencyBalances::total_in`]으로 기준 통화 합계를 계산합니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct CurrencyBalances(BTreeMap<String, Decimal>);
+
+impl CurrencyBalances {
+    /// 빈 합계 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 통화별 금액 추가.
+    pub fn add(&mut self, currency: &str, amount: Decimal) {
+        *self.0.entry(normalize_currency(currency)).or_default() += amount;
+    }
+
+    /// [`Money`] 추가.
+    pub fn add_money(&mut self, money: &Money) {
+        self.add(&money.currency, money.amount);
+    }
+
+    /// 통화별 금액 (없으면 0).
+    pub fn get(&self, currency: &str) -> Decimal {
+        self.0
+            .get(&normalize_currency(currency))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// 통화 코드와 금액 순회 (통화 코드순).
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Decimal)> {
+        self.0
+            .iter()
+            .map(|(currency, amount)| (currency.as_str(), *amount))
+    }
+
+    /// 비어있는지 확인.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// 기준 통화 합계.
+    ///
+    /// 환율이 없는 통화가 하나라도 있으면 [`MissingFxRate`]를 반환합니다.
+    pub fn total_in(
+        &self,
+        base: &str,
+        rates: &FxRateTable,
+        date: NaiveDate,
+    ) -> Result<Decimal, MissingFxRate> {
+        self.iter()
+            .try_fold(Decimal::ZERO, |total, (currency, amount)| {
+                Ok(total + amount * rates.require_rate(currency, base, date)?)
+            })
+    }
+}
+
+/// 기준 통화 손익 분해.
+///
+/// 외화 자산의 기준 통화 손익을 자산 가격 변동분과 환율 변동분으로 나눕니다.
+/// `asset_pnl + fx_pnl`은 `청산 평가액 × 청산 환율 - 진입 원가 × 진입 환율`과 같습니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct FxPnl {
+    /// 자산 손익 (현지 통화 손익 × 청산 환율)
+    pub asset_pnl: Decimal,
+    /// 환차손익 (진입 원가 × 환율 변동)
+    pub fx_pnl: Decimal,
+}
+
+impl FxPnl {
+    /// 손익 분해.
+    ///
+    /// # 인자
+    ///
+    /// * `cost_basis` - 진입 원가 (현지 통화)
+    /// * `local_pnl` - 현지 통화 손익
+    /// * `entry_rate` - 진입 시점 환율 (1 현지 통화 = N 기준 통화)
+    /// * `exit_rate` - 청산(평가) 시점 환율
+    pub fn decompose(
+        cost_basis: Decimal,
+        local_pnl: Decimal,
+        entry_rate: Decimal,
+        exit_rate: Decimal,
+    ) -> Self {
+        Self {
+            asset_pnl: local_pnl * exit_rate,
+            fx_pnl: cost_basis * (exit_rate - entry_rate),
+        }
+    }
+
+    /// 기준 통화 총손익.
+    pub fn total(&self) -> Decimal {
+        self.asset_pnl + self.fx_pnl
+    }
+}
+
+impl Add for FxPnl {
+    type Output = FxPnl;
+
+    fn add(self, rhs: FxPnl) -> FxPnl {
+        FxPnl {
+            asset_pnl: self.asset_pnl + rhs.asset_pnl,
+            fx_pnl: self.fx_pnl + rhs.fx_pnl,
+        }
+    }
+}
+
+impl AddAssign for FxPnl {
+    fn add_assign(&mut self, rhs: FxPnl) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn table() -> FxRateTable {
+        let mut table = FxRateTable::new().with_stablecoin_pegs();
+        table.insert("USD", "KRW", date(2024, 1, 2), dec!(1300));
+        table.insert("USD", "KRW", date(2024, 1, 5), dec!(1320));
+        table.insert("jpy", "krw", date(2024, 1, 2), dec!(9));
+        table
+    }
+
+    #[test]
+    fn test_ticker_currency() {
+        assert_eq!(ticker_currency("005930"), "KRW");
+        assert_eq!(ticker_currency("005930.KS"), "KRW");
+        assert_eq!(ticker_currency("AAPL"), "USD");
+        assert_eq!(ticker_currency("BRK-B"), "USD");
+        assert_eq!(ticker_currency("BTC/USDT"), "USDT");
+        assert_eq!(ticker_currency("KRW-BTC"), "KRW");
+    }
+
+    #[test]
+    fn test_rate_as_of_inverse_and_cross() {
+        let table = table();
+        assert_eq!(table.len(), 3);
+
+        // as-of: 조회일 이전 가장 최근 환율
+        assert_eq!(
+            table.rate_on("USD", "KRW", date(2024, 1, 4)),
+            Some(dec!(1300))
+        );
+        assert_eq!(
+            table.rate_on("usd", "krw", date(2024, 1, 5)),
+            Some(dec!(1320))
+        );
+        assert_eq!(table.rate_on("USD", "KRW", date(2024, 1, 1)), None);
+        assert_eq!(table.latest_rate("USD", "KRW"), Some(dec!(1320)));
+
+        // 역방향
+        assert_eq!(
+            table.rate_on("KRW", "USD", date(2024, 1, 2)),
+            Some(Decimal::ONE / dec!(1300))
+        );
+
+        // 연동 통화 + 교차 환산
+        assert_eq!(
+            table.rate_on("USDT", "KRW", date(2024, 1, 5)),
+            Some(dec!(1320))
+        );
+        assert_eq!(
+            table.rate_on("USDT", "USD", date(2024, 1, 5)),
+            Some(Decimal::ONE)
+        );
+        let usd_jpy = table.rate_on("USD", "JPY", date(2024, 1, 2)).unwrap();
+        assert_eq!(usd_jpy, dec!(1300) * (Decimal::ONE / dec!(9)));
+
+        assert!(table.rate_on("EUR", "KRW", date(2024, 1, 5)).is_none());
+        let err = table
+            .require_rate("EUR", "KRW", date(2024, 1, 5))
+            .unwrap_err();
+        assert_eq!(err.from, "EUR");
+    }
+
+    #[test]
+    fn test_currency_balances_total() {
+        let table = table();
+        let mut balances = CurrencyBalances::new();
+        balances.add("KRW", dec!(1_000_000));
+        balances.add_money(&Money::usd(dec!(100)));
+        balances.add_money(&Money::usdt(dec!(50)));
+
+        assert_eq!(balances.get("usd"), dec!(100));
+        assert_eq!(
+            balances.total_in("KRW", &table, date(2024, 1, 5)),
+            Ok(dec!(1_000_000) + dec!(150) * dec!(1320))
+        );
+
+        balances.add("EUR", dec!(1));
+        assert!(balances.total_in("KRW", &table, date(2024, 1, 5)).is_err());
+    }
+
+    #[test]
+    fn test_fx_pnl_decompose() {
+        // 100 USD 매수 (1300원) → 110 USD 청산 (1320원)
+        let pnl = FxPnl::decompose(dec!(100), dec!(10), dec!(1300), dec!(1320));
+        assert_eq!(pnl.asset_pnl, dec!(13200));
+        assert_eq!(pnl.fx_pnl, dec!(2000));
+        assert_eq!(pnl.total(), dec!(110) * dec!(1320) - dec!(100) * dec!(1300));
+
+        let mut sum = FxPnl::default();
+        sum += pnl;
+        sum += pnl;
+        assert_eq!(sum.total(), pnl.total() * dec!(2));
+    }
+}
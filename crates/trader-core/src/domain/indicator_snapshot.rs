@@ -0,0 +1,129 @@
+//! 증분 지표 스냅샷 및 갱신 trait.
+//!
+//! 지표 상태(EMA 시드, Wilder 평균, ATR 등)는 `trader-analytics::indicators::incremental`에서
+//! 캔들 단위로 O(1) 갱신되며, 이 모듈은 그 결과를 전략 컨텍스트로 전달하기 위한
+//! 공용 타입만 정의합니다. (`trader-strategy`는 `trader-analytics`에 의존하지 않으므로
+//! 엔진은 [`IndicatorUpdater`] trait 객체로 갱신기를 주입받습니다.)
+//!
+//! 캔들 이력 전체로 지표를 계산하는 [`IndicatorCalculator`]도 같은 이유로
+//! 이 모듈에 정의하며, `trader-analytics::IndicatorEngine`이 구현합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::market_data::Kline;
+use crate::Timeframe;
+
+/// 특정 종목/타임프레임의 최신 지표 값.
+///
+/// 워밍업이 끝나지 않은 지표는 `None`입니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct IndicatorSnapshot {
+    /// 종목 티커
+    pub ticker: String,
+    /// 타임프레임
+    pub timeframe: Timeframe,
+    /// 마지막으로 반영된 캔들 시작 시각
+    pub open_time: DateTime<Utc>,
+    /// 마지막 종가
+    pub close: Decimal,
+    /// 반영된 캔들 수
+    pub bars: u64,
+    /// RSI
+    pub rsi: Option<Decimal>,
+    /// MACD 라인
+    pub macd: Option<Decimal>,
+    /// MACD 시그널 라인
+    pub macd_signal: Option<Decimal>,
+    /// MACD 히스토그램
+    pub macd_histogram: Option<Decimal>,
+    /// 볼린저 상단
+    pub bb_upper: Option<Decimal>,
+    /// 볼린저 중간 (SMA)
+    pub bb_middle: Option<Decimal>,
+    /// 볼린저 하단
+    pub bb_lower: Option<Decimal>,
+    /// 볼린저 %B
+    pub bb_percent_b: Option<Decimal>,
+    /// ATR (Wilder)
+    pub atr: Option<Decimal>,
+    /// SuperTrend 값
+    pub supertrend: Option<Decimal>,
+    /// SuperTrend 상승 추세 여부
+    pub supertrend_uptrend: bool,
+}
+
+/// 캔들 단위 증분 지표 갱신기.
+///
+/// 수집기와 실시간 `StrategyEngine`이 같은 구현을 공유하며,
+/// 구현체는 종목/타임프레임별 지표 상태를 내부에 보관합니다.
+pub trait IndicatorUpdater: Send + Sync {
+    /// 캔들을 반영하고 최신 스냅샷을 반환합니다.
+    ///
+    /// 마지막 캔들과 `open_time`이 같으면 진행 중인 캔들로 보고 그 값을 교체합니다.
+    /// 이전 `open_time`이거나 마지막 캔들과 값이 같으면 상태를 바꾸지 않고 `None`을 반환합니다.
+    fn on_kline(&self, kline: &Kline) -> Option<IndicatorSnapshot>;
+
+    /// 현재 보관 중인 스냅샷 조회.
+    fn snapshot(&self, ticker: &str, timeframe: Timeframe) -> Option<IndicatorSnapshot>;
+}
+
+/// 볼린저 밴드 최신 값.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BollingerValue {
+    /// 상단 밴드
+    pub upper: Decimal,
+    /// 중간 밴드 (SMA)
+    pub middle: Decimal,
+    /// 하단 밴드
+    pub lower: Decimal,
+}
+
+/// MACD 최신 값.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MacdValue {
+    /// MACD 라인
+    pub macd: Decimal,
+    /// 시그널 라인
+    pub signal: Decimal,
+    /// 히스토그램
+    pub histogram: Decimal,
+}
+
+/// 캔들 이력 기반 지표 계산기.
+///
+/// 입력 이력의 마지막 봉 기준 값을 반환하며, 데이터가 부족하면 `None`입니다.
+/// 스크립트 전략처럼 `trader-analytics`에 의존할 수 없는 곳에 주입됩니다.
+pub trait IndicatorCalculator: Send + Sync {
+    /// 단순 이동평균.
+    fn sma(&self, closes: &[Decimal], period: usize) -> Option<Decimal>;
+
+    /// 지수 이동평균.
+    fn ema(&self, closes: &[Decimal], period: usize) -> Option<Decimal>;
+
+    /// RSI (0-100).
+    fn rsi(&self, closes: &[Decimal], period: usize) -> Option<Decimal>;
+
+    /// ATR.
+    fn atr(
+        &self,
+        highs: &[Decimal],
+        lows: &[Decimal],
+        closes: &[Decimal],
+        period: usize,
+    ) -> Option<Decimal>;
+
+    /// 볼린저 밴드 (`k`: 표준편차 배수).
+    fn bollinger(&self, closes: &[Decimal], period: usize, k: Decimal) -> Option<BollingerValue>;
+
+    /// MACD.
+    fn macd(
+        &self,
+        closes: &[Decimal],
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    ) -> Option<MacdValue>;
+}
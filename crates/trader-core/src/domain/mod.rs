@@ -5,13 +5,20 @@ mod alert;
 // 거래소 중립 타입 (OHLCV, 호가, 주문 응답 등)
 mod analytics_provider;
 mod calculations;
+mod calendar;
+mod cointegration;
 mod context;
+mod decision_trace;
+mod economic_calendar;
 mod exchange_provider;
 mod exchange_types;
+mod fx;
+mod indicator_snapshot;
 mod macro_environment;
 mod market_breadth;
 mod market_data;
 mod market_regime;
+mod options;
 mod order;
 mod position;
 mod route_state;
@@ -27,13 +34,20 @@ pub use account::*;
 pub use alert::*;
 pub use analytics_provider::*;
 pub use calculations::*;
+pub use calendar::*;
+pub use cointegration::*;
 pub use context::*;
+pub use decision_trace::*;
+pub use economic_calendar::*;
 pub use exchange_provider::*;
 pub use exchange_types::*;
+pub use fx::*;
+pub use indicator_snapshot::*;
 pub use macro_environment::*;
 pub use market_breadth::*;
 pub use market_data::*;
 pub use market_regime::*;
+pub use options::*;
 pub use order::*;
 pub use position::*;
 pub use route_state::*;
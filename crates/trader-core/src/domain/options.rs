@@ -0,0 +1,440 @@
+//! 옵션 계약 데이터 모델.
+//!
+//! KOSPI200 옵션과 미국 주식/ETF 옵션을 표현하는 거래소 중립 타입입니다.
+//! 가격 결정(Black-Scholes, 그릭스 계산)은 `trader-analytics::options`에서 수행합니다.
+//!
+//! # 주요 타입
+//!
+//! - [`OptionContract`]: 기초자산, 행사가, 만기, 권리 유형, 승수
+//! - [`OptionExpiryRule`]: 월물 만기일 규칙 (KRX 둘째 목요일, 미국 셋째 금요일)
+//! - [`OptionQuote`] / [`OptionChain`]: 옵션 시세 및 체인
+//! - [`OptionGreeks`]: 델타, 감마, 세타, 베가, 로
+//!
+//! # 티커 규칙
+//!
+//! 백테스트/포지션 키로 쓰이는 티커는 OCC 심볼 형식을 따릅니다.
+//! 예: `SPY261120C00450000` (SPY 2026-11-20 만기 행사가 450 콜)
+
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc, Weekday};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+
+use super::TradingCalendar;
+
+/// 옵션 권리 유형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OptionRight {
+    /// 콜 (매수 권리)
+    Call,
+    /// 풋 (매도 권리)
+    Put,
+}
+
+impl OptionRight {
+    /// OCC 심볼 코드 (C/P).
+    pub fn code(&self) -> char {
+        match self {
+            OptionRight::Call => 'C',
+            OptionRight::Put => 'P',
+        }
+    }
+}
+
+impl fmt::Display for OptionRight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionRight::Call => write!(f, "call"),
+            OptionRight::Put => write!(f, "put"),
+        }
+    }
+}
+
+/// 권리 행사 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ExerciseStyle {
+    /// 유럽형 (만기일에만 행사, KOSPI200 옵션)
+    #[default]
+    European,
+    /// 미국형 (만기 전 언제든 행사, 미국 주식 옵션)
+    American,
+}
+
+/// 월물 만기일 규칙.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OptionExpiryRule {
+    /// KRX: 매월 둘째 목요일
+    Krx,
+    /// 미국 월물: 매월 셋째 금요일
+    #[default]
+    UsMonthly,
+}
+
+impl OptionExpiryRule {
+    /// 해당 월의 만기일 (휴장일 미반영).
+    pub fn expiry_in(&self, year: i32, month: u32) -> Option<NaiveDate> {
+        match self {
+            OptionExpiryRule::Krx => {
+                NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Thu, 2)
+            }
+            OptionExpiryRule::UsMonthly => {
+                NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Fri, 3)
+            }
+        }
+    }
+
+    /// 해당 월의 만기일 (휴장일이면 직전 거래일).
+    pub fn expiry_in_calendar(
+        &self,
+        year: i32,
+        month: u32,
+        calendar: &TradingCalendar,
+    ) -> Option<NaiveDate> {
+        let expiry = self.expiry_in(year, month)?;
+        if calendar.is_trading_day(expiry) {
+            Some(expiry)
+        } else {
+            calendar.previous_trading_day(expiry)
+        }
+    }
+
+    /// `date`로부터 최소 `min_days`일 이상 남은 가장 가까운 월물 만기일.
+    pub fn next_expiry(&self, date: NaiveDate, min_days: i64) -> Option<NaiveDate> {
+        let first_of_month = date.with_day(1)?;
+        (0..24).find_map(|offset| {
+            let month = first_of_month.checked_add_months(Months::new(offset))?;
+            self.expiry_in(month.year(), month.month())
+                .filter(|expiry| (*expiry - date).num_days() >= min_days)
+        })
+    }
+}
+
+/// 옵션 계약.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct OptionContract {
+    /// 기초자산 티커 (예: "SPY", "KOSPI200")
+    pub underlying: String,
+    /// 권리 유형
+    pub right: OptionRight,
+    /// 행사가
+    pub strike: Decimal,
+    /// 만기일
+    pub expiry: NaiveDate,
+    /// 계약 승수 (미국 주식 옵션 100, KOSPI200 옵션 250,000)
+    pub multiplier: Decimal,
+    /// 행사 방식
+    #[serde(default)]
+    pub style: ExerciseStyle,
+    /// 거래소 종목코드 (KIS 옵션 단축코드 등)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exchange_code: Option<String>,
+}
+
+impl OptionContract {
+    /// 새 옵션 계약 생성 (승수 1, 유럽형).
+    pub fn new(
+        underlying: impl Into<String>,
+        right: OptionRight,
+        strike: Decimal,
+        expiry: NaiveDate,
+    ) -> Self {
+        Self {
+            underlying: underlying.into(),
+            right,
+            strike,
+            expiry,
+            multiplier: Decimal::ONE,
+            style: ExerciseStyle::European,
+            exchange_code: None,
+        }
+    }
+
+    /// 콜 옵션 생성.
+    pub fn call(underlying: impl Into<String>, strike: Decimal, expiry: NaiveDate) -> Self {
+        Self::new(underlying, OptionRight::Call, strike, expiry)
+    }
+
+    /// 풋 옵션 생성.
+    pub fn put(underlying: impl Into<String>, strike: Decimal, expiry: NaiveDate) -> Self {
+        Self::new(underlying, OptionRight::Put, strike, expiry)
+    }
+
+    /// 계약 승수 설정.
+    pub fn with_multiplier(mut self, multiplier: Decimal) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// 행사 방식 설정.
+    pub fn with_style(mut self, style: ExerciseStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 거래소 종목코드 설정.
+    pub fn with_exchange_code(mut self, code: impl Into<String>) -> Self {
+        self.exchange_code = Some(code.into());
+        self
+    }
+
+    /// OCC 심볼 형식 티커.
+    ///
+    /// `{기초자산}{YYMMDD}{C|P}{행사가 × 1000, 8자리}` (예: `SPY261120C00450000`)
+    pub fn ticker(&self) -> String {
+        let strike_milli = (self.strike * Decimal::from(1000))
+            .trunc()
+            .to_u64()
+            .unwrap_or(0);
+        format!(
+            "{}{}{}{:08}",
+            self.underlying,
+            self.expiry.format("%y%m%d"),
+            self.right.code(),
+            strike_milli
+        )
+    }
+
+    /// 만기 시 내재가치 (기초자산 1단위 기준).
+    pub fn intrinsic_value(&self, spot: Decimal) -> Decimal {
+        let value = match self.right {
+            OptionRight::Call => spot - self.strike,
+            OptionRight::Put => self.strike - spot,
+        };
+        value.max(Decimal::ZERO)
+    }
+
+    /// 내가격(ITM) 여부.
+    pub fn is_in_the_money(&self, spot: Decimal) -> bool {
+        self.intrinsic_value(spot) > Decimal::ZERO
+    }
+
+    /// 만기 경과 여부 (만기일 당일 포함).
+    pub fn is_expired(&self, date: NaiveDate) -> bool {
+        date >= self.expiry
+    }
+
+    /// 만기까지 남은 기간 (연 단위, ACT/365).
+    ///
+    /// 만기일 당일 이후는 0을 반환합니다.
+    pub fn time_to_expiry(&self, now: DateTime<Utc>) -> f64 {
+        let days = (self.expiry - now.date_naive()).num_days();
+        days.max(0) as f64 / 365.0
+    }
+
+    /// 계약당 금액 (프리미엄 × 승수).
+    pub fn contract_value(&self, premium: Decimal) -> Decimal {
+        premium * self.multiplier
+    }
+}
+
+impl fmt::Display for OptionContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.underlying, self.expiry, self.strike, self.right
+        )
+    }
+}
+
+/// 옵션 민감도 (그릭스).
+///
+/// 기초자산 1단위 기준이며, 세타는 1일, 베가/로는 1%p 변화 기준입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct OptionGreeks {
+    /// 델타 (기초자산 가격 민감도)
+    pub delta: f64,
+    /// 감마 (델타 변화율)
+    pub gamma: f64,
+    /// 세타 (1일 시간가치 감소)
+    pub theta: f64,
+    /// 베가 (변동성 1%p 민감도)
+    pub vega: f64,
+    /// 로 (금리 1%p 민감도)
+    pub rho: f64,
+}
+
+/// 옵션 시세.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct OptionQuote {
+    /// 옵션 계약
+    pub contract: OptionContract,
+    /// 현재가
+    pub last: Decimal,
+    /// 매수호가
+    pub bid: Decimal,
+    /// 매도호가
+    pub ask: Decimal,
+    /// 누적 거래량
+    pub volume: Decimal,
+    /// 미결제약정
+    pub open_interest: Decimal,
+    /// 내재변동성 (0.25 = 25%)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub implied_volatility: Option<f64>,
+    /// 거래소 제공 그릭스
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub greeks: Option<OptionGreeks>,
+}
+
+impl OptionQuote {
+    /// 중간가 (호가가 없으면 현재가).
+    pub fn mid(&self) -> Decimal {
+        if self.bid > Decimal::ZERO && self.ask > Decimal::ZERO {
+            (self.bid + self.ask) / Decimal::TWO
+        } else {
+            self.last
+        }
+    }
+}
+
+/// 한 만기의 옵션 체인.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct OptionChain {
+    /// 기초자산 티커
+    pub underlying: String,
+    /// 기초자산 현재가 (제공되는 경우)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underlying_price: Option<Decimal>,
+    /// 만기일
+    pub expiry: NaiveDate,
+    /// 콜/풋 시세 (행사가 오름차순)
+    pub quotes: Vec<OptionQuote>,
+}
+
+impl OptionChain {
+    /// 콜 시세 목록.
+    pub fn calls(&self) -> impl Iterator<Item = &OptionQuote> {
+        self.quotes
+            .iter()
+            .filter(|q| q.contract.right == OptionRight::Call)
+    }
+
+    /// 풋 시세 목록.
+    pub fn puts(&self) -> impl Iterator<Item = &OptionQuote> {
+        self.quotes
+            .iter()
+            .filter(|q| q.contract.right == OptionRight::Put)
+    }
+
+    /// 행사가 목록 (중복 제거, 오름차순).
+    pub fn strikes(&self) -> Vec<Decimal> {
+        let mut strikes: Vec<Decimal> = self.quotes.iter().map(|q| q.contract.strike).collect();
+        strikes.sort();
+        strikes.dedup();
+        strikes
+    }
+
+    /// 특정 권리/행사가의 시세.
+    pub fn quote(&self, right: OptionRight, strike: Decimal) -> Option<&OptionQuote> {
+        self.quotes
+            .iter()
+            .find(|q| q.contract.right == right && q.contract.strike == strike)
+    }
+
+    /// 기초자산 가격에 가장 가까운 행사가 (ATM).
+    pub fn atm_strike(&self, spot: Decimal) -> Option<Decimal> {
+        self.strikes()
+            .into_iter()
+            .min_by_key(|strike| (*strike - spot).abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_expiry_rules() {
+        // 2026년 11월: 첫 목요일 5일, 첫 금요일 6일
+        assert_eq!(
+            OptionExpiryRule::Krx.expiry_in(2026, 11),
+            Some(date(2026, 11, 12))
+        );
+        assert_eq!(
+            OptionExpiryRule::UsMonthly.expiry_in(2026, 11),
+            Some(date(2026, 11, 20))
+        );
+
+        // 만기까지 20일 미만이면 다음 월물
+        let rule = OptionExpiryRule::UsMonthly;
+        assert_eq!(
+            rule.next_expiry(date(2026, 11, 2), 14),
+            Some(date(2026, 11, 20))
+        );
+        assert_eq!(
+            rule.next_expiry(date(2026, 11, 2), 20),
+            Some(date(2026, 12, 18))
+        );
+        assert_eq!(
+            rule.next_expiry(date(2026, 12, 20), 0),
+            Some(date(2027, 1, 15))
+        );
+    }
+
+    #[test]
+    fn test_contract_ticker_and_values() {
+        let call =
+            OptionContract::call("SPY", dec!(450), date(2026, 11, 20)).with_multiplier(dec!(100));
+        assert_eq!(call.ticker(), "SPY261120C00450000");
+        assert_eq!(call.intrinsic_value(dec!(460)), dec!(10));
+        assert_eq!(call.intrinsic_value(dec!(440)), Decimal::ZERO);
+        assert_eq!(call.contract_value(dec!(3.5)), dec!(350));
+
+        let put = OptionContract::put("SPY", dec!(432.5), date(2026, 11, 20));
+        assert_eq!(put.ticker(), "SPY261120P00432500");
+        assert!(put.is_in_the_money(dec!(430)));
+        assert!(put.is_expired(date(2026, 11, 20)));
+        assert!(!put.is_expired(date(2026, 11, 19)));
+    }
+
+    #[test]
+    fn test_chain_lookup() {
+        let expiry = date(2026, 11, 12);
+        let quote = |right, strike| OptionQuote {
+            contract: OptionContract::new("KOSPI200", right, strike, expiry),
+            last: dec!(1.0),
+            bid: dec!(0.9),
+            ask: dec!(1.1),
+            volume: Decimal::ZERO,
+            open_interest: Decimal::ZERO,
+            implied_volatility: None,
+            greeks: None,
+        };
+        let chain = OptionChain {
+            underlying: "KOSPI200".to_string(),
+            underlying_price: Some(dec!(351)),
+            expiry,
+            quotes: vec![
+                quote(OptionRight::Call, dec!(350)),
+                quote(OptionRight::Call, dec!(352.5)),
+                quote(OptionRight::Put, dec!(350)),
+            ],
+        };
+
+        assert_eq!(chain.calls().count(), 2);
+        assert_eq!(chain.strikes(), vec![dec!(350), dec!(352.5)]);
+        assert_eq!(chain.atm_strike(dec!(351)), Some(dec!(350)));
+        assert_eq!(
+            chain.quote(OptionRight::Put, dec!(350)).unwrap().mid(),
+            dec!(1.0)
+        );
+    }
+}
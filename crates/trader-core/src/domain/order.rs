@@ -252,6 +252,14 @@ pub struct OrderRequest {
     pub stop_price: Option<Price>,
     /// 주문 유효 기간
     pub time_in_force: TimeInForce,
+    /// 만료 시각 (GTD 주문에 필수)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expire_time: Option<DateTime<Utc>>,
+    /// 트레일링 폭 (%, 트레일링 스톱 주문에 필수)
+    ///
+    /// 트레일링 스톱에서 `stop_price`는 활성화 가격으로 사용됩니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_percent: Option<Decimal>,
     /// 클라이언트 주문 ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_order_id: Option<String>,
@@ -271,6 +279,8 @@ impl OrderRequest {
             price: None,
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         }
@@ -286,6 +296,8 @@ impl OrderRequest {
             price: None,
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         }
@@ -301,6 +313,8 @@ impl OrderRequest {
             price: Some(price),
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         }
@@ -316,11 +330,51 @@ impl OrderRequest {
             price: Some(price),
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         }
     }
 
+    /// 트레일링 스톱 주문을 생성합니다.
+    ///
+    /// 활성화 가격이 없으면 제출 시점 가격부터 추적을 시작합니다.
+    pub fn trailing_stop(
+        ticker: String,
+        side: Side,
+        quantity: Quantity,
+        trailing_percent: Decimal,
+        activation_price: Option<Price>,
+    ) -> Self {
+        Self {
+            ticker,
+            side,
+            order_type: OrderType::TrailingStop,
+            quantity,
+            price: None,
+            stop_price: activation_price,
+            time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: Some(trailing_percent),
+            client_order_id: None,
+            strategy_id: None,
+        }
+    }
+
+    /// 주문 유효 기간을 설정합니다.
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = time_in_force;
+        self
+    }
+
+    /// 만료 시각을 설정합니다 (유효 기간은 GTD로 전환).
+    pub fn with_expire_time(mut self, expire_time: DateTime<Utc>) -> Self {
+        self.time_in_force = TimeInForce::GTD;
+        self.expire_time = Some(expire_time);
+        self
+    }
+
     /// 전략 ID를 설정합니다.
     pub fn with_strategy(mut self, strategy_id: impl Into<String>) -> Self {
         self.strategy_id = Some(strategy_id.into());
@@ -448,6 +502,32 @@ mod tests {
         assert_eq!(order.strategy_id, Some("grid_trading".to_string()));
     }
 
+    #[test]
+    fn test_order_request_gtd_and_trailing() {
+        let expire = Utc::now() + chrono::Duration::hours(1);
+        let order = OrderRequest::limit_sell("BTC/USDT".to_string(), dec!(1), dec!(51000))
+            .with_expire_time(expire);
+        assert_eq!(order.time_in_force, TimeInForce::GTD);
+        assert_eq!(order.expire_time, Some(expire));
+
+        let trailing = OrderRequest::trailing_stop(
+            "BTC/USDT".to_string(),
+            Side::Sell,
+            dec!(1),
+            dec!(2.5),
+            Some(dec!(52000)),
+        );
+        assert_eq!(trailing.order_type, OrderType::TrailingStop);
+        assert_eq!(trailing.trailing_percent, Some(dec!(2.5)));
+        assert_eq!(trailing.stop_price, Some(dec!(52000)));
+
+        // 이전 형식(신규 필드 없음) 역직렬화 호환
+        let json = r#"{"ticker":"BTC/USDT","side":"buy","order_type":"market","quantity":"1","time_in_force":"GTC"}"#;
+        let parsed: OrderRequest = serde_json::from_str(json).unwrap();
+        assert!(parsed.expire_time.is_none());
+        assert!(parsed.trailing_percent.is_none());
+    }
+
     #[test]
     fn test_order_from_request() {
         let symbol = "ETH/USDT".to_string();
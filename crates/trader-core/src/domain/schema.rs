@@ -55,6 +55,9 @@ pub enum FieldType {
     /// 다중 타임프레임 (Primary + Secondary)
     #[serde(rename = "multi_timeframe")]
     MultiTimeframe,
+    /// 조건 트리 (규칙 빌더)
+    #[serde(rename = "rule_group")]
+    RuleGroup,
 }
 
 /// 필드 스키마.
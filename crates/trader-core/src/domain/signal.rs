@@ -4,6 +4,7 @@
 //! - `SignalType` - 신호 유형 (진입, 청산 등)
 //! - `Signal` - 매매 신호 엔티티
 //! - `SignalValidation` - 신호 검증 결과
+//! - `SignalMarker` - 차트/분석용 신호 기록 (결정 추적 포함)
 
 use std::collections::HashMap;
 
@@ -12,7 +13,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::{RouteState, Side};
+use crate::domain::{DecisionTrace, OptionContract, RouteState, Side};
 
 /// 수행할 액션의 종류를 나타내는 신호 유형.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -190,8 +191,75 @@ impl Signal {
         self.group_id = Some(group_id.into());
         self
     }
+
+    /// 다중 레그 주문의 한 레그로 지정합니다 (페어 트레이딩 등).
+    ///
+    /// 같은 group_id를 가진 `leg_count`개의 신호는 Executor에서
+    /// 함께 체결되거나 함께 거부됩니다.
+    pub fn with_legs(mut self, group_id: impl Into<String>, leg_count: usize) -> Self {
+        self.group_id = Some(group_id.into());
+        self.metadata
+            .insert(LEG_COUNT_KEY.to_string(), serde_json::json!(leg_count));
+        self
+    }
+
+    /// 다중 레그 그룹의 레그 수 (레그 신호가 아니면 None).
+    pub fn leg_count(&self) -> Option<usize> {
+        self.group_id.as_ref()?;
+        self.metadata
+            .get(LEG_COUNT_KEY)
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize)
+            .filter(|count| *count > 1)
+    }
+
+    /// 고정 주문 수량 설정.
+    ///
+    /// 설정 시 Executor는 신호 강도 기반 포지션 크기 계산 대신 이 수량으로 진입합니다.
+    pub fn with_fixed_quantity(mut self, quantity: Decimal) -> Self {
+        self.metadata.insert(
+            FIXED_QUANTITY_KEY.to_string(),
+            serde_json::json!(quantity.to_string()),
+        );
+        self
+    }
+
+    /// 고정 주문 수량 (설정되지 않았거나 0 이하이면 None).
+    pub fn fixed_quantity(&self) -> Option<Decimal> {
+        self.metadata
+            .get(FIXED_QUANTITY_KEY)
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<Decimal>().ok())
+            .filter(|q| *q > Decimal::ZERO)
+    }
+
+    /// 옵션 계약 정보 첨부.
+    ///
+    /// 옵션 신호의 ticker는 `OptionContract::ticker()`여야 합니다.
+    pub fn with_option_contract(mut self, contract: &OptionContract) -> Self {
+        if let Ok(value) = serde_json::to_value(contract) {
+            self.metadata.insert(OPTION_CONTRACT_KEY.to_string(), value);
+        }
+        self
+    }
+
+    /// 첨부된 옵션 계약 (옵션 신호가 아니면 None).
+    pub fn option_contract(&self) -> Option<OptionContract> {
+        self.metadata
+            .get(OPTION_CONTRACT_KEY)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
 }
 
+/// 다중 레그 신호의 레그 수를 담는 메타데이터 키.
+pub const LEG_COUNT_KEY: &str = "leg_count";
+
+/// 고정 주문 수량을 담는 메타데이터 키.
+pub const FIXED_QUANTITY_KEY: &str = "fixed_quantity";
+
+/// 옵션 계약 정보를 담는 메타데이터 키.
+pub const OPTION_CONTRACT_KEY: &str = "option_contract";
+
 /// 신호 검증 결과.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignalValidation {
@@ -276,6 +344,10 @@ pub struct SignalMarker {
     /// 메타데이터 (확장용)
     #[serde(default)]
     pub metadata: HashMap<String, serde_json::Value>,
+
+    /// 신호 결정 추적 (지표 값, 필터 통과 여부)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decision_trace: Option<DecisionTrace>,
 }
 
 impl SignalMarker {
@@ -302,6 +374,7 @@ impl SignalMarker {
             strategy_name: strategy_name.into(),
             executed: false,
             metadata: HashMap::new(),
+            decision_trace: None,
         }
     }
 
@@ -332,6 +405,7 @@ impl SignalMarker {
             strategy_name: strategy_name.into(),
             executed: false,
             metadata: signal.metadata.clone(),
+            decision_trace: None,
         }
     }
 
@@ -459,6 +533,12 @@ impl SignalMarker {
         self
     }
 
+    /// 결정 추적 설정.
+    pub fn with_decision_trace(mut self, trace: Option<DecisionTrace>) -> Self {
+        self.decision_trace = trace;
+        self
+    }
+
     /// 강한 신호인지 확인 (강도 >= 0.8).
     pub fn is_strong(&self) -> bool {
         self.strength >= 0.8
@@ -594,6 +674,34 @@ mod tests {
         assert_eq!(signal.strength, 1.0);
     }
 
+    #[test]
+    fn test_signal_legs() {
+        let leg = Signal::entry("pairs", "KO".to_string(), Side::Buy).with_legs("pair-1", 2);
+        assert_eq!(leg.group_id.as_deref(), Some("pair-1"));
+        assert_eq!(leg.leg_count(), Some(2));
+
+        let grouped = Signal::entry("grid", "BTC".to_string(), Side::Buy).with_group_id("grid");
+        assert_eq!(grouped.leg_count(), None);
+    }
+
+    #[test]
+    fn test_signal_option_contract() {
+        use rust_decimal_macros::dec;
+
+        let expiry = chrono::NaiveDate::from_ymd_opt(2026, 11, 20).unwrap();
+        let contract = OptionContract::call("SPY", dec!(450), expiry).with_multiplier(dec!(100));
+        let signal = Signal::entry("overlay", contract.ticker(), Side::Sell)
+            .with_option_contract(&contract)
+            .with_fixed_quantity(dec!(200));
+
+        assert_eq!(signal.option_contract(), Some(contract));
+        assert_eq!(signal.fixed_quantity(), Some(dec!(200)));
+
+        let plain = Signal::entry("overlay", "SPY".to_string(), Side::Buy);
+        assert!(plain.option_contract().is_none());
+        assert!(plain.fixed_quantity().is_none());
+    }
+
     #[test]
     fn test_signal_marker_creation() {
         use rust_decimal_macros::dec;
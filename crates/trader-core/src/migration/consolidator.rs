@@ -299,41 +299,37 @@ impl MigrationConsolidator {
         let sql = stmt.raw_sql.trim().to_string();
 
         match &stmt.statement_type {
-            StatementType::CreateTable => {
-                if !stmt.if_not_exists {
-                    // CREATE TABLE → CREATE TABLE IF NOT EXISTS
-                    let sql_upper = sql.to_uppercase();
-                    if let Some(pos) = sql_upper.find("CREATE TABLE") {
-                        let insert_pos = pos + "CREATE TABLE".len();
-                        let mut modified = sql.clone();
-                        modified.insert_str(insert_pos, " IF NOT EXISTS");
-                        return modified;
-                    }
+            StatementType::CreateTable if !stmt.if_not_exists => {
+                // CREATE TABLE → CREATE TABLE IF NOT EXISTS
+                let sql_upper = sql.to_uppercase();
+                if let Some(pos) = sql_upper.find("CREATE TABLE") {
+                    let insert_pos = pos + "CREATE TABLE".len();
+                    let mut modified = sql.clone();
+                    modified.insert_str(insert_pos, " IF NOT EXISTS");
+                    return modified;
                 }
             }
-            StatementType::CreateIndex => {
-                if !stmt.if_not_exists {
-                    let sql_upper = sql.to_uppercase();
-                    // CREATE INDEX → CREATE INDEX IF NOT EXISTS
-                    // CREATE UNIQUE INDEX → CREATE UNIQUE INDEX IF NOT EXISTS
-                    if let Some(pos) = sql_upper.find("CREATE UNIQUE INDEX") {
-                        let insert_pos = pos + "CREATE UNIQUE INDEX".len();
-                        let mut modified = sql.clone();
-                        modified.insert_str(insert_pos, " IF NOT EXISTS");
-                        return modified;
-                    } else if let Some(pos) = sql_upper.find("CREATE INDEX") {
-                        let insert_pos = pos + "CREATE INDEX".len();
-                        let mut modified = sql.clone();
-                        modified.insert_str(insert_pos, " IF NOT EXISTS");
-                        return modified;
-                    }
+            StatementType::CreateIndex if !stmt.if_not_exists => {
+                let sql_upper = sql.to_uppercase();
+                // CREATE INDEX → CREATE INDEX IF NOT EXISTS
+                // CREATE UNIQUE INDEX → CREATE UNIQUE INDEX IF NOT EXISTS
+                if let Some(pos) = sql_upper.find("CREATE UNIQUE INDEX") {
+                    let insert_pos = pos + "CREATE UNIQUE INDEX".len();
+                    let mut modified = sql.clone();
+                    modified.insert_str(insert_pos, " IF NOT EXISTS");
+                    return modified;
+                } else if let Some(pos) = sql_upper.find("CREATE INDEX") {
+                    let insert_pos = pos + "CREATE INDEX".len();
+                    let mut modified = sql.clone();
+                    modified.insert_str(insert_pos, " IF NOT EXISTS");
+                    return modified;
                 }
             }
-            StatementType::CreateType => {
+            StatementType::CreateType
+                if !sql.to_uppercase().contains("DO $$") && !stmt.if_not_exists =>
+            {
                 // pg_type 존재 확인 + ALTER TYPE ADD VALUE IF NOT EXISTS 패턴
-                if !sql.to_uppercase().contains("DO $$") && !stmt.if_not_exists {
-                    return self.generate_enum_idempotent_sql(&sql, &stmt.object_name);
-                }
+                return self.generate_enum_idempotent_sql(&sql, &stmt.object_name);
             }
             StatementType::CreateView => {
                 // CREATE VIEW → CREATE OR REPLACE VIEW
@@ -359,15 +355,13 @@ impl MigrationConsolidator {
                     }
                 }
             }
-            StatementType::CreateExtension => {
-                if !stmt.if_not_exists {
-                    let sql_upper = sql.to_uppercase();
-                    if let Some(pos) = sql_upper.find("CREATE EXTENSION") {
-                        let insert_pos = pos + "CREATE EXTENSION".len();
-                        let mut modified = sql.clone();
-                        modified.insert_str(insert_pos, " IF NOT EXISTS");
-                        return modified;
-                    }
+            StatementType::CreateExtension if !stmt.if_not_exists => {
+                let sql_upper = sql.to_uppercase();
+                if let Some(pos) = sql_upper.find("CREATE EXTENSION") {
+                    let insert_pos = pos + "CREATE EXTENSION".len();
+                    let mut modified = sql.clone();
+                    modified.insert_str(insert_pos, " IF NOT EXISTS");
+                    return modified;
                 }
             }
             _ => {}
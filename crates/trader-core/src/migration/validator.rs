@@ -133,39 +133,39 @@ impl<'a> MigrationValidator<'a> {
                 match &stmt.statement_type {
                     StatementType::CreateTable
                     | StatementType::CreateIndex
-                    | StatementType::CreateType => {
-                        if !stmt.if_not_exists {
-                            let issue = ValidationIssue::new(
-                                Severity::Info,
-                                "IDEM001",
-                                "IF NOT EXISTS 누락 - 재실행 시 오류 발생 가능",
-                            )
-                            .with_file(&file.name)
-                            .with_line(stmt.line_number)
-                            .with_object(&stmt.object_name)
-                            .with_suggestion("CREATE ... IF NOT EXISTS 사용 권장.");
-
-                            report.add_issue(issue);
-                        }
+                    | StatementType::CreateType
+                        if !stmt.if_not_exists =>
+                    {
+                        let issue = ValidationIssue::new(
+                            Severity::Info,
+                            "IDEM001",
+                            "IF NOT EXISTS 누락 - 재실행 시 오류 발생 가능",
+                        )
+                        .with_file(&file.name)
+                        .with_line(stmt.line_number)
+                        .with_object(&stmt.object_name)
+                        .with_suggestion("CREATE ... IF NOT EXISTS 사용 권장.");
+
+                        report.add_issue(issue);
                     }
                     StatementType::DropTable
                     | StatementType::DropView
                     | StatementType::DropIndex
                     | StatementType::DropFunction
-                    | StatementType::DropType => {
-                        if !stmt.if_exists {
-                            let issue = ValidationIssue::new(
-                                Severity::Info,
-                                "IDEM002",
-                                "IF EXISTS 누락 - 재실행 시 오류 발생 가능",
-                            )
-                            .with_file(&file.name)
-                            .with_line(stmt.line_number)
-                            .with_object(&stmt.object_name)
-                            .with_suggestion("DROP ... IF EXISTS 사용 권장.");
-
-                            report.add_issue(issue);
-                        }
+                    | StatementType::DropType
+                        if !stmt.if_exists =>
+                    {
+                        let issue = ValidationIssue::new(
+                            Severity::Info,
+                            "IDEM002",
+                            "IF EXISTS 누락 - 재실행 시 오류 발생 가능",
+                        )
+                        .with_file(&file.name)
+                        .with_line(stmt.line_number)
+                        .with_object(&stmt.object_name)
+                        .with_suggestion("DROP ... IF EXISTS 사용 권장.");
+
+                        report.add_issue(issue);
                     }
                     _ => {}
                 }
@@ -91,6 +91,27 @@ impl Money {
     pub fn krw(amount: Decimal) -> Self {
         Self::new(amount, "KRW")
     }
+
+    /// 0 금액을 생성합니다.
+    pub fn zero(currency: impl Into<String>) -> Self {
+        Self::new(Decimal::ZERO, currency)
+    }
+
+    /// 같은 통화인지 확인합니다 (대소문자 무시).
+    pub fn is_currency(&self, currency: &str) -> bool {
+        self.currency.eq_ignore_ascii_case(currency.trim())
+    }
+
+    /// 같은 통화끼리 더합니다 (통화가 다르면 `None`).
+    pub fn checked_add(&self, other: &Money) -> Option<Money> {
+        self.is_currency(&other.currency)
+            .then(|| Money::new(self.amount + other.amount, self.currency.clone()))
+    }
+
+    /// 환율을 적용해 다른 통화로 환산합니다 (1 현재 통화 = `rate` 대상 통화).
+    pub fn convert(&self, to: impl Into<String>, rate: Decimal) -> Money {
+        Money::new(self.amount * rate, to)
+    }
 }
 
 impl std::fmt::Display for Money {
@@ -116,4 +137,17 @@ mod tests {
         let m = Money::usdt(dec!(1000.50));
         assert_eq!(m.to_string(), "1000.50 USDT");
     }
+
+    #[test]
+    fn test_money_currency_ops() {
+        let usd = Money::usd(dec!(10));
+        assert!(usd.is_currency("usd"));
+        assert_eq!(
+            usd.checked_add(&Money::new(dec!(5), "usd")),
+            Some(Money::usd(dec!(15)))
+        );
+        assert_eq!(usd.checked_add(&Money::krw(dec!(5))), None);
+        assert_eq!(usd.convert("krw", dec!(1300)), Money::krw(dec!(13000)));
+        assert_eq!(Money::zero("KRW"), Money::krw(Decimal::ZERO));
+    }
 }
@@ -1,7 +1,7 @@
 //! 심볼 및 시장 유형 정의.
 //!
 //! 이 모듈은 트레이딩 심볼 관련 타입을 정의합니다:
-//! - `MarketType` - 시장 유형 (암호화폐, 주식, 외환 등)
+//! - `MarketType` - 시장 유형 (암호화폐, 주식, 외환, 선물, 옵션 등)
 //! - `Country` - 국가/지역 코드
 //! - `Symbol` - 거래 가능한 상품을 나타내는 심볼
 
@@ -24,6 +24,8 @@ pub enum MarketType {
     Forex,
     /// 선물/파생상품 시장
     Futures,
+    /// 옵션 시장 (KOSPI200 옵션, 미국 주식 옵션)
+    Options,
     /// 지수
     Index,
     /// 미국 주식 시장 (향후 Stock + Country::US로 대체 예정)
@@ -65,6 +67,7 @@ impl fmt::Display for MarketType {
             MarketType::Stock => write!(f, "stock"),
             MarketType::Forex => write!(f, "forex"),
             MarketType::Futures => write!(f, "futures"),
+            MarketType::Options => write!(f, "options"),
             MarketType::Index => write!(f, "index"),
             MarketType::UsStock => write!(f, "us_stock"),
             MarketType::KrStock => write!(f, "kr_stock"),
@@ -0,0 +1,767 @@
+//! 실시간 체결/시세 → 캔들 집계 모듈.
+//!
+//! 거래소가 푸시하는 캔들 주기에 의존하지 않고, 체결 틱(`TradeTick`)과 시세(`Ticker`)에서
+//! 일관된 시간 캔들(M1 ~ D1)과 정보 바(거래량/틱/거래대금 바)를 직접 만듭니다.
+//!
+//! # 세션 기준 캔들
+//!
+//! 시간 캔들은 종목의 [`TradingCalendar`] 정규장 시각에 맞춰 열리고 닫힙니다.
+//! - 분/시간봉은 개장 시각 기준으로 정렬 (미국 1시간봉: 09:30, 10:30, ...)
+//! - 마지막 캔들은 마감 시각에 잘림 (KRX 15:00 1시간봉은 15:30에 마감)
+//! - 일봉은 정규장 전체이며, 시각은 OhlcvCache 일봉과 같은 "현지 날짜 00:00 UTC"
+//! - 장외 체결/시세는 무시 (`session_only = false`이면 현지 자정 기준으로 집계)
+//!
+//! 체결이 없어도 캔들이 제때 닫히도록 [`BarBuilder::flush`]를 주기적으로 호출해야 합니다.
+//!
+//! # 시작 시 백필
+//!
+//! 장중에 시작하면 진행 중인 캔들의 앞부분이 비어 있으므로, [`KlineSource`](REST)에서
+//! 최근 1분봉을 받아 [`BarBuilder::backfill`]로 채웁니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! let mut builder = BarBuilder::new(BarBuilderConfig::default());
+//! builder.set_calendar("005930", TradingCalendar::krx());
+//! builder.backfill_from(&rest_source, "005930", Utc::now()).await?;
+//!
+//! for bar in builder.on_trade(&tick) {
+//!     if bar.spec.is_time() {
+//!         engine.process_market_data(MarketData::from_kline("kis", bar.kline)).await?;
+//!     }
+//! }
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Timelike, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use trader_core::{
+    Kline, MarketData, MarketDataType, Ticker, Timeframe, TradeTick, TradingCalendar,
+};
+
+use crate::error::Result;
+
+/// REST 백필 시 한 번에 요청할 최대 1분봉 수.
+const MAX_BACKFILL_BARS: usize = 1_000;
+
+/// 바 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum BarSpec {
+    /// 시간 캔들 (D1 이하)
+    Time(Timeframe),
+    /// 거래량 바 (누적 거래량이 기준 이상이면 마감)
+    Volume(Decimal),
+    /// 틱 바 (체결 수가 기준 이상이면 마감)
+    Tick(u32),
+    /// 거래대금 바 (누적 가격×수량이 기준 이상이면 마감)
+    Dollar(Decimal),
+}
+
+impl BarSpec {
+    /// 시간 캔들 여부.
+    pub fn is_time(&self) -> bool {
+        matches!(self, Self::Time(_))
+    }
+
+    /// 시간 캔들의 타임프레임.
+    pub fn timeframe(&self) -> Option<Timeframe> {
+        match self {
+            Self::Time(tf) => Some(*tf),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BarSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Time(tf) => write!(f, "{}", tf),
+            Self::Volume(threshold) => write!(f, "volume:{}", threshold),
+            Self::Tick(count) => write!(f, "tick:{}", count),
+            Self::Dollar(threshold) => write!(f, "dollar:{}", threshold),
+        }
+    }
+}
+
+/// 완성된 바.
+///
+/// 정보 바는 시간 주기가 없으므로 `kline.timeframe`은 의미가 없습니다 (M1로 채움).
+/// 바 종류는 `spec`으로 구분하세요. `kline.quote_volume`은 거래대금, `kline.num_trades`는 체결 수입니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuiltBar {
+    /// 바 종류
+    pub spec: BarSpec,
+    /// 캔들
+    pub kline: Kline,
+}
+
+/// 바 빌더 설정.
+#[derive(Debug, Clone)]
+pub struct BarBuilderConfig {
+    /// 생성할 바 종류
+    pub specs: Vec<BarSpec>,
+    /// 종목별 캘린더가 없을 때 사용할 기본 캘린더
+    pub calendar: TradingCalendar,
+    /// 정규장 체결만 집계 (장외 체결 무시)
+    pub session_only: bool,
+}
+
+impl Default for BarBuilderConfig {
+    fn default() -> Self {
+        Self {
+            specs: vec![
+                BarSpec::Time(Timeframe::M1),
+                BarSpec::Time(Timeframe::M5),
+                BarSpec::Time(Timeframe::M15),
+                BarSpec::Time(Timeframe::H1),
+                BarSpec::Time(Timeframe::D1),
+            ],
+            calendar: TradingCalendar::always_open(),
+            session_only: true,
+        }
+    }
+}
+
+impl BarBuilderConfig {
+    /// 생성할 바 종류 설정.
+    pub fn with_specs(mut self, specs: impl IntoIterator<Item = BarSpec>) -> Self {
+        self.specs = specs.into_iter().collect();
+        self
+    }
+
+    /// 바 종류 추가.
+    pub fn with_spec(mut self, spec: BarSpec) -> Self {
+        if !self.specs.contains(&spec) {
+            self.specs.push(spec);
+        }
+        self
+    }
+
+    /// 기본 캘린더 설정.
+    pub fn with_calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 정규장 체결만 집계할지 설정.
+    pub fn with_session_only(mut self, session_only: bool) -> Self {
+        self.session_only = session_only;
+        self
+    }
+}
+
+/// 백필용 REST 캔들 소스.
+#[async_trait]
+pub trait KlineSource: Send + Sync {
+    /// 최근 캔들 조회 (최대 `limit`개, 정렬 순서 무관).
+    async fn recent_klines(
+        &self,
+        ticker: &str,
+        timeframe: Timeframe,
+        limit: usize,
+    ) -> Result<Vec<Kline>>;
+}
+
+/// 시간 캔들 구간.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bucket {
+    /// 캔들 시각 (일봉은 현지 날짜 00:00 UTC)
+    open_time: DateTime<Utc>,
+    /// 실제 집계 시작 시각
+    start: DateTime<Utc>,
+    /// 마감 시각 (미포함)
+    end: DateTime<Utc>,
+}
+
+/// 집계 중인 바.
+#[derive(Debug, Clone)]
+struct PartialBar {
+    open_time: DateTime<Utc>,
+    close_time: DateTime<Utc>,
+    open: Decimal,
+    high: Decimal,
+    low: Decimal,
+    close: Decimal,
+    volume: Decimal,
+    quote_volume: Decimal,
+    trades: u32,
+}
+
+impl PartialBar {
+    fn new(open_time: DateTime<Utc>, close_time: DateTime<Utc>, price: Decimal) -> Self {
+        Self {
+            open_time,
+            close_time,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: Decimal::ZERO,
+            quote_volume: Decimal::ZERO,
+            trades: 0,
+        }
+    }
+
+    fn update(&mut self, price: Decimal, quantity: Decimal) {
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+        self.volume += quantity;
+        self.quote_volume += price * quantity;
+        self.trades += 1;
+    }
+
+    /// 하위 캔들 병합 (백필용).
+    fn merge(&mut self, kline: &Kline) {
+        self.high = self.high.max(kline.high);
+        self.low = self.low.min(kline.low);
+        self.close = kline.close;
+        self.volume += kline.volume;
+        self.quote_volume += kline
+            .quote_volume
+            .unwrap_or_else(|| kline.close * kline.volume);
+        self.trades += kline.num_trades.unwrap_or(0);
+    }
+
+    fn to_kline(&self, ticker: &str, timeframe: Timeframe) -> Kline {
+        Kline {
+            ticker: ticker.to_string(),
+            timeframe,
+            open_time: self.open_time,
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            volume: self.volume,
+            close_time: self.close_time,
+            quote_volume: Some(self.quote_volume),
+            num_trades: Some(self.trades),
+        }
+    }
+}
+
+/// 종목별 집계 상태.
+#[derive(Debug, Default)]
+struct SeriesState {
+    /// 바 종류별 집계 중인 바 (설정 순서)
+    bars: Vec<Option<PartialBar>>,
+    /// 직전 시세의 누적 거래량 (시세 → 체결량 환산용)
+    last_cumulative_volume: Option<Decimal>,
+}
+
+/// 체결/시세 스트림에서 바를 만드는 빌더.
+///
+/// 종목마다 독립적으로 집계하며, 입력이 시간순이라고 가정합니다.
+/// 이미 닫힌 구간의 늦은 체결은 버립니다.
+pub struct BarBuilder {
+    config: BarBuilderConfig,
+    calendars: HashMap<String, TradingCalendar>,
+    series: HashMap<String, SeriesState>,
+}
+
+impl BarBuilder {
+    /// 새 빌더 생성.
+    ///
+    /// D1보다 긴 시간 캔들은 지원하지 않으므로 설정에서 제외됩니다.
+    pub fn new(mut config: BarBuilderConfig) -> Self {
+        config.specs.retain(|spec| match spec {
+            BarSpec::Time(tf) if tf.as_secs() > Timeframe::D1.as_secs() => {
+                warn!(timeframe = %tf, "일봉보다 긴 타임프레임은 바 빌더에서 지원하지 않음");
+                false
+            }
+            _ => true,
+        });
+        Self {
+            config,
+            calendars: HashMap::new(),
+            series: HashMap::new(),
+        }
+    }
+
+    /// 설정 조회.
+    pub fn config(&self) -> &BarBuilderConfig {
+        &self.config
+    }
+
+    /// 종목별 캘린더 지정 (없으면 기본 캘린더 사용).
+    pub fn set_calendar(&mut self, ticker: impl Into<String>, calendar: TradingCalendar) {
+        self.calendars.insert(ticker.into(), calendar);
+    }
+
+    /// 종목의 캘린더.
+    pub fn calendar(&self, ticker: &str) -> &TradingCalendar {
+        self.calendars.get(ticker).unwrap_or(&self.config.calendar)
+    }
+
+    /// 집계 중인 종목 목록.
+    pub fn tickers(&self) -> Vec<String> {
+        self.series.keys().cloned().collect()
+    }
+
+    /// 체결 틱 반영. 이 체결로 마감된 바를 반환합니다.
+    pub fn on_trade(&mut self, trade: &TradeTick) -> Vec<BuiltBar> {
+        self.apply(&trade.ticker, trade.timestamp, trade.price, trade.quantity)
+    }
+
+    /// 시세 반영. 이 시세로 마감된 바를 반환합니다.
+    ///
+    /// 시세에는 체결량이 없으므로 누적 거래량(`volume_24h`)의 증가분을 체결량으로 사용합니다.
+    /// 누적 거래량이 줄어들면(일간 리셋) 새 누적값 전체를, 첫 시세는 0을 사용합니다.
+    pub fn on_ticker(&mut self, ticker: &Ticker) -> Vec<BuiltBar> {
+        let state = self.series.entry(ticker.ticker.clone()).or_default();
+        let quantity = match state.last_cumulative_volume {
+            Some(prev) if ticker.volume_24h >= prev => ticker.volume_24h - prev,
+            Some(_) => ticker.volume_24h,
+            None => Decimal::ZERO,
+        };
+        state.last_cumulative_volume = Some(ticker.volume_24h);
+        self.apply(&ticker.ticker, ticker.timestamp, ticker.last, quantity)
+    }
+
+    /// 시장 데이터 반영 (체결/시세만 처리, 그 외는 무시).
+    pub fn on_market_data(&mut self, data: &MarketData) -> Vec<BuiltBar> {
+        match &data.data {
+            MarketDataType::Trade(trade) => self.on_trade(trade),
+            MarketDataType::Ticker(ticker) => self.on_ticker(ticker),
+            _ => Vec::new(),
+        }
+    }
+
+    /// `now` 기준으로 마감 시각이 지난 시간 캔들을 닫습니다.
+    ///
+    /// 체결이 없는 구간이나 장 마감 시점에도 캔들이 닫히도록 주기적으로 호출합니다.
+    /// 정보 바는 체결로만 마감되므로 여기서 닫지 않습니다.
+    pub fn flush(&mut self, now: DateTime<Utc>) -> Vec<BuiltBar> {
+        let mut completed = Vec::new();
+        for (ticker, state) in self.series.iter_mut() {
+            for (spec, slot) in self.config.specs.iter().zip(state.bars.iter_mut()) {
+                let BarSpec::Time(tf) = spec else {
+                    continue;
+                };
+                if slot.as_ref().is_some_and(|bar| bar.close_time <= now) {
+                    if let Some(bar) = slot.take() {
+                        completed.push(BuiltBar {
+                            spec: *spec,
+                            kline: bar.to_kline(ticker, *tf),
+                        });
+                    }
+                }
+            }
+        }
+        completed.sort_by_key(|bar| bar.kline.close_time);
+        completed
+    }
+
+    /// 집계 중인 바 조회.
+    pub fn partial(&self, ticker: &str, spec: BarSpec) -> Option<Kline> {
+        let index = self.config.specs.iter().position(|s| *s == spec)?;
+        let bar = self.series.get(ticker)?.bars.get(index)?.as_ref()?;
+        Some(bar.to_kline(ticker, spec.timeframe().unwrap_or(Timeframe::M1)))
+    }
+
+    /// 하위 캔들(REST)로 진행 중인 시간 캔들을 채웁니다.
+    ///
+    /// `now`가 속한 구간의 캔들만 병합하며, 이미 집계 중인 바는 건드리지 않습니다
+    /// (체결 반영 후 백필하면 같은 구간이 중복 집계되므로).
+    /// `klines`는 해당 종목의 캔들이어야 하며, 대상 타임프레임보다 긴 캔들은 무시합니다.
+    /// 채운 바 수를 반환합니다.
+    pub fn backfill(&mut self, ticker: &str, klines: &[Kline], now: DateTime<Utc>) -> usize {
+        let calendar = self.calendar(ticker).clone();
+        let session_only = self.config.session_only;
+        let specs = self.config.specs.clone();
+
+        let mut sorted: Vec<&Kline> = klines.iter().collect();
+        sorted.sort_by_key(|k| k.open_time);
+
+        let state = self.series.entry(ticker.to_string()).or_default();
+        state.bars.resize(specs.len(), None);
+
+        let mut seeded = 0;
+        for (spec, slot) in specs.iter().zip(state.bars.iter_mut()) {
+            let BarSpec::Time(tf) = spec else {
+                continue;
+            };
+            if slot.is_some() {
+                continue;
+            }
+            let Some(bucket) = time_bucket(&calendar, *tf, now, session_only) else {
+                continue;
+            };
+
+            let mut bar: Option<PartialBar> = None;
+            for kline in sorted.iter().filter(|k| {
+                k.timeframe.as_secs() <= tf.as_secs()
+                    && k.open_time >= bucket.start
+                    && k.open_time < bucket.end
+                    && k.open_time <= now
+            }) {
+                bar.get_or_insert_with(|| {
+                    PartialBar::new(bucket.open_time, bucket.end, kline.open)
+                })
+                .merge(kline);
+            }
+            if bar.is_some() {
+                *slot = bar;
+                seeded += 1;
+            }
+        }
+
+        debug!(ticker, seeded, source_bars = sorted.len(), "바 빌더 백필");
+        seeded
+    }
+
+    /// 백필에 필요한 1분봉 수 (진행 중인 시간 캔들이 없으면 `None`).
+    ///
+    /// 가장 긴 진행 중 구간(보통 일봉)의 시작부터 `now`까지이며, 최대 1,000개입니다.
+    pub fn backfill_limit(&self, ticker: &str, now: DateTime<Utc>) -> Option<usize> {
+        let calendar = self.calendar(ticker);
+        let earliest = self
+            .config
+            .specs
+            .iter()
+            .filter_map(|spec| spec.timeframe())
+            .filter_map(|tf| time_bucket(calendar, tf, now, self.config.session_only))
+            .map(|bucket| bucket.start)
+            .min()?;
+        Some(((now - earliest).num_minutes() as usize + 1).min(MAX_BACKFILL_BARS))
+    }
+
+    /// REST 소스에서 최근 1분봉을 받아 [`backfill`](Self::backfill)합니다.
+    pub async fn backfill_from(
+        &mut self,
+        source: &dyn KlineSource,
+        ticker: &str,
+        now: DateTime<Utc>,
+    ) -> Result<usize> {
+        let Some(limit) = self.backfill_limit(ticker, now) else {
+            return Ok(0);
+        };
+        let klines = source.recent_klines(ticker, Timeframe::M1, limit).await?;
+        Ok(self.backfill(ticker, &klines, now))
+    }
+
+    /// 체결 1건을 모든 바에 반영.
+    fn apply(
+        &mut self,
+        ticker: &str,
+        ts: DateTime<Utc>,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Vec<BuiltBar> {
+        let calendar = self.calendars.get(ticker).unwrap_or(&self.config.calendar);
+        let session_only = self.config.session_only;
+        if session_only && !calendar.is_market_open(ts) {
+            return Vec::new();
+        }
+
+        let state = self.series.entry(ticker.to_string()).or_default();
+        state.bars.resize(self.config.specs.len(), None);
+
+        let mut completed = Vec::new();
+        for (spec, slot) in self.config.specs.iter().zip(state.bars.iter_mut()) {
+            match spec {
+                BarSpec::Time(tf) => {
+                    let Some(bucket) = time_bucket(calendar, *tf, ts, session_only) else {
+                        continue;
+                    };
+                    match slot {
+                        Some(bar) if bar.open_time == bucket.open_time => {}
+                        Some(bar) if bar.open_time > bucket.open_time => {
+                            debug!(ticker, timeframe = %tf, %ts, "마감된 구간의 늦은 체결 무시");
+                            continue;
+                        }
+                        _ => {
+                            if let Some(bar) = slot.take() {
+                                completed.push(BuiltBar {
+                                    spec: *spec,
+                                    kline: bar.to_kline(ticker, *tf),
+                                });
+                            }
+                            *slot = Some(PartialBar::new(bucket.open_time, bucket.end, price));
+                        }
+                    }
+                    if let Some(bar) = slot.as_mut() {
+                        bar.update(price, quantity);
+                    }
+                }
+                BarSpec::Volume(_) | BarSpec::Tick(_) | BarSpec::Dollar(_) => {
+                    let bar = slot.get_or_insert_with(|| PartialBar::new(ts, ts, price));
+                    bar.update(price, quantity);
+                    bar.close_time = ts;
+
+                    let full = match spec {
+                        BarSpec::Volume(threshold) => bar.volume >= *threshold,
+                        BarSpec::Tick(count) => bar.trades >= *count,
+                        BarSpec::Dollar(threshold) => bar.quote_volume >= *threshold,
+                        BarSpec::Time(_) => false,
+                    };
+                    if full {
+                        if let Some(bar) = slot.take() {
+                            completed.push(BuiltBar {
+                                spec: *spec,
+                                kline: bar.to_kline(ticker, Timeframe::M1),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        completed
+    }
+}
+
+/// `ts`가 속한 시간 캔들 구간.
+///
+/// `session_only`이면 정규장 기준(장외는 `None`), 아니면 현지 자정 기준으로 나눕니다.
+fn time_bucket(
+    calendar: &TradingCalendar,
+    timeframe: Timeframe,
+    ts: DateTime<Utc>,
+    session_only: bool,
+) -> Option<Bucket> {
+    let date = calendar.local_date(ts);
+    let (start, end) = if session_only {
+        if !calendar.is_trading_day(date) {
+            return None;
+        }
+        session_bounds(calendar, date)
+    } else {
+        let midnight = calendar.timezone.to_utc(date, NaiveTime::MIN);
+        (midnight, midnight + Duration::days(1))
+    };
+    if ts < start || ts >= end {
+        return None;
+    }
+
+    if timeframe.as_secs() >= Timeframe::D1.as_secs() {
+        return Some(Bucket {
+            open_time: date.and_time(NaiveTime::MIN).and_utc(),
+            start,
+            end,
+        });
+    }
+
+    let step = timeframe.as_secs() as i64;
+    let index = (ts - start).num_seconds() / step;
+    let open_time = start + Duration::seconds(index * step);
+    Some(Bucket {
+        open_time,
+        start: open_time,
+        end: (open_time + Duration::seconds(step)).min(end),
+    })
+}
+
+/// 현지 날짜의 정규장 구간 (UTC).
+///
+/// 상시 거래 캘린더(23:59 이후 마감)는 다음 날 자정까지로 봅니다.
+fn session_bounds(calendar: &TradingCalendar, date: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+    let open = calendar.market_open(date);
+    let close = if calendar.close.hour() == 23 && calendar.close.minute() == 59 {
+        date.succ_opt()
+            .map(|next| calendar.timezone.to_utc(next, NaiveTime::MIN))
+            .unwrap_or_else(|| calendar.market_close(date))
+    } else {
+        calendar.market_close(date)
+    };
+    (open, close)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+
+    use super::*;
+
+    fn trade(ts: DateTime<Utc>, price: Decimal, quantity: Decimal) -> TradeTick {
+        TradeTick {
+            ticker: "005930".to_string(),
+            id: ts.timestamp_millis().to_string(),
+            price,
+            quantity,
+            side: Side::Buy,
+            timestamp: ts,
+        }
+    }
+
+    /// KST 현지 시각 (2025-10-13 월요일).
+    fn kst(hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 10, 13, hour, minute, second)
+            .unwrap()
+            - Duration::hours(9)
+    }
+
+    fn krx_builder(specs: Vec<BarSpec>) -> BarBuilder {
+        let mut builder = BarBuilder::new(BarBuilderConfig::default().with_specs(specs));
+        builder.set_calendar("005930", TradingCalendar::krx());
+        builder
+    }
+
+    #[test]
+    fn test_time_bars_close_on_next_bucket() {
+        let mut builder = krx_builder(vec![BarSpec::Time(Timeframe::M1)]);
+
+        assert!(builder
+            .on_trade(&trade(kst(9, 0, 5), dec!(100), dec!(10)))
+            .is_empty());
+        assert!(builder
+            .on_trade(&trade(kst(9, 0, 30), dec!(103), dec!(5)))
+            .is_empty());
+        assert!(builder
+            .on_trade(&trade(kst(9, 0, 50), dec!(99), dec!(1)))
+            .is_empty());
+
+        let done = builder.on_trade(&trade(kst(9, 1, 2), dec!(101), dec!(2)));
+        assert_eq!(done.len(), 1);
+        let kline = &done[0].kline;
+        assert_eq!(kline.open_time, kst(9, 0, 0));
+        assert_eq!(kline.close_time, kst(9, 1, 0));
+        assert_eq!(
+            (kline.open, kline.high, kline.low, kline.close),
+            (dec!(100), dec!(103), dec!(99), dec!(99))
+        );
+        assert_eq!(kline.volume, dec!(16));
+        assert_eq!(kline.num_trades, Some(3));
+    }
+
+    #[test]
+    fn test_session_alignment_and_close() {
+        let mut builder = krx_builder(vec![
+            BarSpec::Time(Timeframe::H1),
+            BarSpec::Time(Timeframe::D1),
+        ]);
+
+        // 장 시작 전 체결은 무시
+        builder.on_trade(&trade(kst(8, 59, 0), dec!(90), dec!(1)));
+        builder.on_trade(&trade(kst(15, 10, 0), dec!(100), dec!(1)));
+        assert_eq!(
+            builder
+                .partial("005930", BarSpec::Time(Timeframe::H1))
+                .unwrap()
+                .open_time,
+            kst(15, 0, 0)
+        );
+
+        // 15:30 마감 시 마지막 1시간봉과 일봉이 함께 닫힘
+        assert!(builder.flush(kst(15, 29, 59)).is_empty());
+        let done = builder.flush(kst(15, 30, 0));
+        assert_eq!(done.len(), 2);
+        let hourly = done
+            .iter()
+            .find(|b| b.spec == BarSpec::Time(Timeframe::H1))
+            .unwrap();
+        assert_eq!(hourly.kline.close_time, kst(15, 30, 0));
+        let daily = done
+            .iter()
+            .find(|b| b.spec == BarSpec::Time(Timeframe::D1))
+            .unwrap();
+        assert_eq!(
+            daily.kline.open_time,
+            Utc.with_ymd_and_hms(2025, 10, 13, 0, 0, 0).unwrap()
+        );
+        assert_eq!(daily.kline.open, dec!(100));
+    }
+
+    #[test]
+    fn test_information_bars() {
+        let mut builder = krx_builder(vec![
+            BarSpec::Volume(dec!(10)),
+            BarSpec::Tick(3),
+            BarSpec::Dollar(dec!(1000)),
+        ]);
+
+        let mut done = Vec::new();
+        for (i, qty) in [dec!(4), dec!(4), dec!(4)].into_iter().enumerate() {
+            done.extend(builder.on_trade(&trade(kst(10, 0, i as u32), dec!(100), qty)));
+        }
+
+        // 3번째 체결에서 세 종류 모두 마감 (거래량 12, 체결 3, 대금 1200)
+        assert_eq!(done.len(), 3);
+        let volume_bar = done
+            .iter()
+            .find(|b| matches!(b.spec, BarSpec::Volume(_)))
+            .unwrap();
+        assert_eq!(volume_bar.kline.volume, dec!(12));
+        assert_eq!(volume_bar.kline.open_time, kst(10, 0, 0));
+        assert_eq!(volume_bar.kline.close_time, kst(10, 0, 2));
+        assert!(builder.partial("005930", BarSpec::Tick(3)).is_none());
+    }
+
+    #[test]
+    fn test_ticker_volume_delta() {
+        let mut builder = krx_builder(vec![BarSpec::Time(Timeframe::M1)]);
+        let ticker = |ts, last, cumulative| Ticker {
+            ticker: "005930".to_string(),
+            bid: last,
+            ask: last,
+            last,
+            volume_24h: cumulative,
+            high_24h: last,
+            low_24h: last,
+            change_24h: Decimal::ZERO,
+            change_24h_percent: Decimal::ZERO,
+            timestamp: ts,
+        };
+
+        builder.on_ticker(&ticker(kst(9, 0, 1), dec!(100), dec!(1000)));
+        builder.on_ticker(&ticker(kst(9, 0, 20), dec!(101), dec!(1250)));
+        let bar = builder
+            .partial("005930", BarSpec::Time(Timeframe::M1))
+            .unwrap();
+        assert_eq!(bar.volume, dec!(250));
+        assert_eq!(bar.close, dec!(101));
+    }
+
+    #[test]
+    fn test_backfill_seeds_current_buckets() {
+        let mut builder = krx_builder(vec![
+            BarSpec::Time(Timeframe::M5),
+            BarSpec::Time(Timeframe::D1),
+        ]);
+        let minute = |h, m, close| {
+            Kline::new(
+                "005930".to_string(),
+                Timeframe::M1,
+                kst(h, m, 0),
+                close,
+                close + dec!(1),
+                close - dec!(1),
+                close,
+                dec!(10),
+                kst(h, m + 1, 0),
+            )
+        };
+        let klines = vec![
+            minute(9, 0, dec!(100)),
+            minute(9, 5, dec!(105)),
+            minute(9, 6, dec!(106)),
+        ];
+
+        let seeded = builder.backfill("005930", &klines, kst(9, 7, 30));
+        assert_eq!(seeded, 2);
+
+        let five = builder
+            .partial("005930", BarSpec::Time(Timeframe::M5))
+            .unwrap();
+        assert_eq!(five.open_time, kst(9, 5, 0));
+        assert_eq!(five.volume, dec!(20));
+        let daily = builder
+            .partial("005930", BarSpec::Time(Timeframe::D1))
+            .unwrap();
+        assert_eq!((daily.open, daily.close), (dec!(100), dec!(106)));
+        assert_eq!(daily.volume, dec!(30));
+
+        // 이후 체결은 백필된 바에 이어서 집계
+        builder.on_trade(&trade(kst(9, 8, 0), dec!(110), dec!(5)));
+        let five = builder
+            .partial("005930", BarSpec::Time(Timeframe::M5))
+            .unwrap();
+        assert_eq!((five.high, five.volume), (dec!(110), dec!(25)));
+    }
+}
@@ -25,6 +25,7 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{NaiveDate, TimeZone, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -292,6 +293,68 @@ impl MacroDataProvider {
         debug!("VIX: {} (전일: {})", current_price, prev_price);
         Ok((current_price, prev_price))
     }
+
+    /// 통화쌍의 일별 환율 종가 이력 조회.
+    ///
+    /// # 인자
+    ///
+    /// * `base` / `quote` - 통화쌍 (1 base = rate quote, 예: USD/KRW)
+    /// * `range` - Yahoo 조회 기간 (예: "5d", "1y", "10y")
+    ///
+    /// # 반환
+    ///
+    /// (기준일, 종가) 목록 (날짜 오름차순)
+    pub async fn fetch_fx_history(
+        &self,
+        base: &str,
+        quote: &str,
+        range: &str,
+    ) -> Result<Vec<(NaiveDate, Decimal)>, MacroDataError> {
+        let symbol = fx_yahoo_symbol(base, quote);
+        let response = self
+            .connector
+            .get_quote_range(&symbol, "1d", range)
+            .await
+            .map_err(|e| MacroDataError::ApiError {
+                symbol: symbol.clone(),
+                message: format!("{}", e),
+            })?;
+        let quotes = response
+            .quotes()
+            .map_err(|e| MacroDataError::ParseError(format!("{}", e)))?;
+
+        let mut rates: Vec<(NaiveDate, Decimal)> = quotes
+            .iter()
+            .filter(|q| q.close.is_finite() && q.close > 0.0)
+            .filter_map(|q| {
+                let date = Utc.timestamp_opt(q.timestamp, 0).single()?.date_naive();
+                Some((date, Decimal::from_f64_retain(q.close)?.round_dp(6)))
+            })
+            .collect();
+        // 같은 날짜가 여러 번 오면 마지막 값 사용 (장중 갱신분)
+        rates.reverse();
+        rates.dedup_by_key(|(date, _)| *date);
+        rates.reverse();
+
+        if rates.is_empty() {
+            return Err(MacroDataError::NoData(format!(
+                "환율 {} 데이터 없음",
+                symbol
+            )));
+        }
+        debug!("{} 환율 {} 일 수신", symbol, rates.len());
+        Ok(rates)
+    }
+}
+
+/// 통화쌍의 Yahoo Finance 심볼 (USD 기준은 "KRW=X", 그 외 "JPYKRW=X").
+pub fn fx_yahoo_symbol(base: &str, quote: &str) -> String {
+    let (base, quote) = (base.trim().to_uppercase(), quote.trim().to_uppercase());
+    if base == "USD" {
+        format!("{}=X", quote)
+    } else {
+        format!("{}{}=X", base, quote)
+    }
 }
 
 #[async_trait]
@@ -418,6 +481,12 @@ impl MacroDataProviderTrait for CachedMacroDataProvider {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fx_yahoo_symbol() {
+        assert_eq!(fx_yahoo_symbol("USD", "KRW"), "KRW=X");
+        assert_eq!(fx_yahoo_symbol("jpy", "krw"), "JPYKRW=X");
+    }
+
     #[test]
     fn test_calculate_change_pct() {
         // 정상 상승
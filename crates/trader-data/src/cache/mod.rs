@@ -12,7 +12,8 @@ pub mod macro_data;
 pub use fundamental::{FetchResult, FundamentalData, FundamentalFetcher};
 pub use historical::{CacheStats as HistoricalCacheStats, CachedHistoricalDataProvider};
 pub use macro_data::{
-    CachedMacroDataProvider, MacroData, MacroDataError, MacroDataProvider, MacroDataProviderTrait,
+    fx_yahoo_symbol, CachedMacroDataProvider, MacroData, MacroDataError, MacroDataProvider,
+    MacroDataProviderTrait,
 };
 
 pub use crate::storage::redis::{CacheStats, MetricsCache, RedisCache, RedisConfig};
@@ -76,6 +76,10 @@ pub enum DataError {
     /// 지원되지 않는 거래소
     #[error("Unsupported exchange: {0}")]
     UnsupportedExchange(String),
+
+    /// 파일 입출력 오류
+    #[error("IO error: {0}")]
+    IoError(String),
 }
 
 impl From<sqlx::Error> for DataError {
@@ -109,4 +113,10 @@ impl From<serde_json::Error> for DataError {
     }
 }
 
+impl From<std::io::Error> for DataError {
+    fn from(err: std::io::Error) -> Self {
+        DataError::IoError(err.to_string())
+    }
+}
+
 pub type Result<T> = std::result::Result<T, DataError>;
@@ -5,8 +5,17 @@
 //! - TimescaleDB 저장소
 //! - Redis 캐싱
 //! - OHLCV 캔들 데이터 캐싱 (증분 업데이트 지원)
+//! - Parquet(Arrow) 내보내기/가져오기 (market/symbol/year 분할)
+//! - OHLCV 데이터 품질 리포트 저장
+//! - 실시간 체결/시세 → 캔들 집계 (시간/거래량/틱/거래대금 바)
+//! - 펀더멘털 시점(Point-in-Time) 이력 조회
+//! - DART 전자공시/분기 재무제표 저장
+//! - 경제 이벤트 캘린더(ICS/CSV 피드) 수집 및 저장
+//! - 수집기 작업 실행 이력 저장
+//! - 계층형 저장소 (분봉 롤업, 분봉/체결 틱 Parquet 아카이브)
 //! - 데이터 가져오기 유틸리티
 
+pub mod bar_builder;
 pub mod cache;
 pub mod error;
 pub mod manager;
@@ -14,6 +23,8 @@ pub mod market_breadth;
 pub mod provider;
 pub mod storage;
 
+// 실시간 바 빌더 재내보내기
+pub use bar_builder::{BarBuilder, BarBuilderConfig, BarSpec, BuiltBar, KlineSource};
 // Fundamental 데이터 수집 재내보내기
 pub use cache::fundamental::{FetchResult, FundamentalData, FundamentalFetcher};
 // OHLCV 캔들 캐시 재내보내기
@@ -32,7 +43,26 @@ pub use storage::krx::KrxDataSource;
 // 저장소 타입 재내보내기
 pub use storage::redis::{CacheStats, MetricsCache, RedisCache, RedisConfig};
 pub use storage::{
+    disclosure::{DisclosureRepository, PendingDisclosure},
+    economic_event::EconomicEventRepository,
+    fundamental_history::{FundamentalHistory, FundamentalHistoryRepository, FundamentalSnapshot},
+    fx_rate::{FxRateRepository, StoredFxRate},
+    indicator_state::{IndicatorStateRepository, StoredIndicatorState},
+    job_run::{CollectorJobRun, CollectorJobRunRepository, JobRunStatus, JobTrigger},
+    live_bars::LiveBarStore,
     ohlcv::{OhlcvCache, OhlcvMetadataRecord, OhlcvRecord},
+    parquet::{
+        read_parquet, write_parquet, BacktestEquityRow, BacktestTradeRow, ParquetDataset,
+        ParquetPartition, ParquetRecord, ParquetStore, ParquetStreamEncoder, ScreeningSnapshotRow,
+    },
+    quality::{
+        OhlcvQualityReport, OhlcvQualityRepository, QualityIssue, QualityIssueKind,
+        QUALITY_WARNING_SCORE,
+    },
+    tiering::{
+        archive_store_from_env, market_timezone, rollup_klines, ArchiveManifestRepository,
+        ArchivedRange, StorageTiering, TieringPolicy, TieringStats,
+    },
     timescale::{
         Database, DatabaseConfig, OrderRecord, OrderRepository, PositionRecord, PositionRepository,
         SymbolRecord, SymbolRepository, TradeRecord, TradeRepository, TradeTickRecord,
@@ -0,0 +1,1232 @@
+//! DART(전자공시시스템) OpenAPI 클라이언트.
+//!
+//! 금융감독원 DART OpenAPI에서 국내 상장사의 공식 공시 데이터를 수집합니다.
+//! 네이버/KRX 스크래핑 값과 달리 회사가 제출한 원본 공시를 기준으로 합니다.
+//!
+//! ## 데이터 소스
+//! - `list.json`: 공시 목록 (유상증자, 전환사채 발행, 최대주주 변경 등)
+//! - `fnlttSinglAcnt.json`: 단일회사 주요계정 (분기/반기/사업보고서 재무제표)
+//! - `majorstock.json`: 주식등의 대량보유상황보고 (5% 이상 주주 지분 변동)
+//! - `corpCode.xml`: 고유번호(corp_code) ↔ 종목코드 매핑 (ZIP 압축)
+//!
+//! ## 사용 예시
+//! ```rust,ignore
+//! let client = DartClient::from_credential(&pool, &encryptor).await?
+//!     .ok_or("DART API credential이 등록되지 않았습니다")?;
+//!
+//! let disclosures = client.fetch_all_disclosures(start, end, None).await?;
+//! for d in disclosures.iter().filter(|d| d.kind.is_material()) {
+//!     println!("{} {}: {}", d.receipt_date, d.corp_name, d.report_name);
+//! }
+//! ```
+
+use std::{
+    io::{Cursor, Read},
+    time::Duration,
+};
+
+use chrono::NaiveDate;
+use reqwest::Client;
+use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sqlx::PgPool;
+use thiserror::Error;
+use trader_core::CredentialEncryptor;
+
+/// DART OpenAPI Base URL.
+const DART_BASE_URL: &str = "https://opendart.fss.or.kr/api";
+
+/// DART 공시 뷰어 URL (접수번호로 원문 조회).
+const DART_VIEWER_URL: &str = "https://dart.fss.or.kr/dsaf001/main.do";
+
+/// 공시 목록 페이지당 최대 건수 (API 제한).
+const MAX_PAGE_COUNT: u32 = 100;
+
+/// DART 응답 상태 코드: 정상.
+const STATUS_OK: &str = "000";
+/// DART 응답 상태 코드: 조회된 데이터 없음.
+const STATUS_NO_DATA: &str = "013";
+/// DART 응답 상태 코드: 요청 제한 초과.
+const STATUS_RATE_LIMITED: &str = "020";
+
+/// DART API 에러
+#[derive(Debug, Error)]
+pub enum DartError {
+    #[error("HTTP 요청 실패: {0}")]
+    HttpError(#[from] reqwest::Error),
+
+    #[error("DART API 오류 [{status}]: {message}")]
+    ApiError { status: String, message: String },
+
+    #[error("응답 파싱 실패: {0}")]
+    ParseError(String),
+
+    #[error("Rate limit 초과")]
+    RateLimited,
+}
+
+// ==================== 공시 ====================
+
+/// 공시 유형 (보고서명 기준 분류).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisclosureKind {
+    /// 유상증자결정 (유무상증자 포함)
+    CapitalIncrease,
+    /// 무상증자결정
+    BonusIssue,
+    /// 감자결정
+    CapitalReduction,
+    /// 전환사채권발행결정
+    ConvertibleBond,
+    /// 신주인수권부사채권발행결정
+    BondWithWarrant,
+    /// 교환사채권발행결정
+    ExchangeableBond,
+    /// 최대주주 변경
+    LargestShareholderChange,
+    /// 주식등의 대량보유상황보고 (5% 룰)
+    MajorShareholding,
+    /// 자기주식 취득결정 (신탁계약 포함)
+    TreasuryStockAcquisition,
+    /// 자기주식 처분결정
+    TreasuryStockDisposal,
+    /// 회사합병결정
+    Merger,
+    /// 회사분할결정
+    Split,
+    /// 정기보고서 (분기/반기/사업보고서)
+    PeriodicReport,
+    /// 기타
+    Other,
+}
+
+impl DisclosureKind {
+    /// 전체 유형 목록.
+    pub const ALL: [DisclosureKind; 14] = [
+        Self::CapitalIncrease,
+        Self::BonusIssue,
+        Self::CapitalReduction,
+        Self::ConvertibleBond,
+        Self::BondWithWarrant,
+        Self::ExchangeableBond,
+        Self::LargestShareholderChange,
+        Self::MajorShareholding,
+        Self::TreasuryStockAcquisition,
+        Self::TreasuryStockDisposal,
+        Self::Merger,
+        Self::Split,
+        Self::PeriodicReport,
+        Self::Other,
+    ];
+
+    /// 보고서명으로 공시 유형 분류.
+    ///
+    /// `주요사항보고서(유상증자결정)`, `[기재정정]최대주주변경`처럼 접두어/괄호가 붙어도
+    /// 공백을 제거한 보고서명의 키워드로 판정합니다.
+    pub fn classify(report_name: &str) -> Self {
+        let name: String = report_name.chars().filter(|c| !c.is_whitespace()).collect();
+        // "[기재정정]" 같은 대괄호 접두어를 제외한 보고서명
+        let base = name
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once(']'))
+            .map_or(name.as_str(), |(_, rest)| rest);
+
+        // 유무상증자는 유상증자로 분류 (신주 발행에 따른 희석)
+        if name.contains("유무상증자결정") || name.contains("유상증자결정") {
+            Self::CapitalIncrease
+        } else if name.contains("무상증자결정") {
+            Self::BonusIssue
+        } else if name.contains("감자결정") {
+            Self::CapitalReduction
+        } else if name.contains("전환사채권발행결정") {
+            Self::ConvertibleBond
+        } else if name.contains("신주인수권부사채권발행결정") {
+            Self::BondWithWarrant
+        } else if name.contains("교환사채권발행결정") {
+            Self::ExchangeableBond
+        } else if name.contains("최대주주변경") {
+            Self::LargestShareholderChange
+        } else if name.contains("대량보유상황보고") {
+            Self::MajorShareholding
+        } else if name.contains("자기주식취득") {
+            Self::TreasuryStockAcquisition
+        } else if name.contains("자기주식처분") {
+            Self::TreasuryStockDisposal
+        } else if name.contains("합병결정") {
+            Self::Merger
+        } else if name.contains("분할결정") {
+            Self::Split
+        } else if ["분기보고서", "반기보고서", "사업보고서"]
+            .iter()
+            .any(|report| base.starts_with(report))
+        {
+            Self::PeriodicReport
+        } else {
+            Self::Other
+        }
+    }
+
+    /// 저장/필터용 식별자 (`signal_alert_rule`의 `disclosure_types`와 매칭).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CapitalIncrease => "capital_increase",
+            Self::BonusIssue => "bonus_issue",
+            Self::CapitalReduction => "capital_reduction",
+            Self::ConvertibleBond => "convertible_bond",
+            Self::BondWithWarrant => "bond_with_warrant",
+            Self::ExchangeableBond => "exchangeable_bond",
+            Self::LargestShareholderChange => "largest_shareholder_change",
+            Self::MajorShareholding => "major_shareholding",
+            Self::TreasuryStockAcquisition => "treasury_stock_acquisition",
+            Self::TreasuryStockDisposal => "treasury_stock_disposal",
+            Self::Merger => "merger",
+            Self::Split => "split",
+            Self::PeriodicReport => "periodic_report",
+            Self::Other => "other",
+        }
+    }
+
+    /// 식별자 문자열에서 파싱.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+
+    /// 표시 이름.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::CapitalIncrease => "유상증자",
+            Self::BonusIssue => "무상증자",
+            Self::CapitalReduction => "감자",
+            Self::ConvertibleBond => "전환사채(CB) 발행",
+            Self::BondWithWarrant => "신주인수권부사채(BW) 발행",
+            Self::ExchangeableBond => "교환사채(EB) 발행",
+            Self::LargestShareholderChange => "최대주주 변경",
+            Self::MajorShareholding => "대량보유 보고",
+            Self::TreasuryStockAcquisition => "자기주식 취득",
+            Self::TreasuryStockDisposal => "자기주식 처분",
+            Self::Merger => "합병",
+            Self::Split => "분할",
+            Self::PeriodicReport => "정기보고서",
+            Self::Other => "기타",
+        }
+    }
+
+    /// 주가에 직접 영향을 주는 주요사항 공시인지 여부 (알림 대상).
+    pub fn is_material(&self) -> bool {
+        !matches!(
+            self,
+            Self::MajorShareholding | Self::PeriodicReport | Self::Other
+        )
+    }
+}
+
+impl std::fmt::Display for DisclosureKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// 공시 1건.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Disclosure {
+    /// 접수번호 (14자리, 공시 고유 ID)
+    pub rcept_no: String,
+    /// DART 고유번호 (8자리)
+    pub corp_code: String,
+    /// 회사명
+    pub corp_name: String,
+    /// 종목코드 (비상장사는 None)
+    pub ticker: Option<String>,
+    /// 법인구분 (Y: 유가증권, K: 코스닥, N: 코넥스, E: 기타)
+    pub corp_cls: String,
+    /// 보고서명
+    pub report_name: String,
+    /// 공시 유형
+    pub kind: DisclosureKind,
+    /// 접수일
+    pub receipt_date: NaiveDate,
+    /// 공시 제출인명
+    pub filer_name: String,
+    /// 비고 (유: 유가증권 공시, 코: 코스닥 공시, 정: 정정 등)
+    pub remark: Option<String>,
+}
+
+impl Disclosure {
+    /// DART 원문 URL.
+    pub fn url(&self) -> String {
+        format!("{}?rcpNo={}", DART_VIEWER_URL, self.rcept_no)
+    }
+
+    /// 정정 공시 여부 (`[기재정정]`, `[발행조건확정]` 등 대괄호 접두어 중 정정).
+    pub fn is_amendment(&self) -> bool {
+        self.report_name.starts_with('[') && self.report_name.contains("정정]")
+    }
+
+    /// 정기보고서인 경우 사업연도와 보고서 구분.
+    pub fn periodic_report(&self) -> Option<(i32, ReportPeriod)> {
+        if self.kind != DisclosureKind::PeriodicReport {
+            return None;
+        }
+        ReportPeriod::from_report_name(&self.report_name)
+    }
+}
+
+/// 공시 목록 페이지.
+#[derive(Debug, Clone, Default)]
+pub struct DisclosurePage {
+    /// 공시 목록
+    pub items: Vec<Disclosure>,
+    /// 현재 페이지 (1부터)
+    pub page_no: u32,
+    /// 전체 페이지 수
+    pub total_page: u32,
+    /// 전체 건수
+    pub total_count: u32,
+}
+
+// ==================== 재무제표 ====================
+
+/// 정기보고서 구분 (`reprt_code`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReportPeriod {
+    /// 1분기보고서
+    Q1,
+    /// 반기보고서
+    H1,
+    /// 3분기보고서
+    Q3,
+    /// 사업보고서
+    Annual,
+}
+
+impl ReportPeriod {
+    /// DART 보고서 코드.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Q1 => "11013",
+            Self::H1 => "11012",
+            Self::Q3 => "11014",
+            Self::Annual => "11011",
+        }
+    }
+
+    /// 보고서 코드에서 파싱.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim() {
+            "11013" => Some(Self::Q1),
+            "11012" => Some(Self::H1),
+            "11014" => Some(Self::Q3),
+            "11011" => Some(Self::Annual),
+            _ => None,
+        }
+    }
+
+    /// 보고서명(`분기보고서 (2024.03)`)에서 사업연도와 구분 추출.
+    ///
+    /// 12월 결산법인 기준으로 판정하며, 결산월이 다른 법인의 분기보고서는 `None`을 반환합니다.
+    pub fn from_report_name(report_name: &str) -> Option<(i32, Self)> {
+        let open = report_name.rfind('(')?;
+        let close = report_name[open..].find(')')? + open;
+        let (year, month) = report_name[open + 1..close].trim().split_once('.')?;
+        let year: i32 = year.trim().parse().ok()?;
+        let month: u32 = month.trim().parse().ok()?;
+
+        let period = if report_name.contains("사업보고서") {
+            Self::Annual
+        } else if report_name.contains("반기보고서") {
+            Self::H1
+        } else if report_name.contains("분기보고서") {
+            match month {
+                3 => Self::Q1,
+                9 => Self::Q3,
+                _ => return None,
+            }
+        } else {
+            return None;
+        };
+        Some((year, period))
+    }
+}
+
+/// 단일회사 주요계정 재무제표 (분기/반기/사업보고서).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinancialStatement {
+    /// DART 고유번호
+    pub corp_code: String,
+    /// 종목코드
+    pub ticker: Option<String>,
+    /// 사업연도
+    pub fiscal_year: i32,
+    /// 보고서 구분
+    pub period: ReportPeriod,
+    /// 연결재무제표 여부 (false면 별도재무제표)
+    pub consolidated: bool,
+    /// 접수번호
+    pub rcept_no: String,
+    /// 통화 단위
+    pub currency: String,
+    /// 매출액 (당기)
+    pub revenue: Option<Decimal>,
+    /// 영업이익 (당기)
+    pub operating_income: Option<Decimal>,
+    /// 당기순이익 (당기)
+    pub net_income: Option<Decimal>,
+    /// 자산총계
+    pub total_assets: Option<Decimal>,
+    /// 부채총계
+    pub total_liabilities: Option<Decimal>,
+    /// 자본총계
+    pub total_equity: Option<Decimal>,
+}
+
+impl FinancialStatement {
+    /// 부채비율 (%) = 부채총계 / 자본총계 × 100.
+    pub fn debt_ratio(&self) -> Option<Decimal> {
+        let equity = self.total_equity.filter(|e| !e.is_zero())?;
+        Some(self.total_liabilities? / equity * Decimal::ONE_HUNDRED)
+    }
+
+    /// 영업이익률 (%) = 영업이익 / 매출액 × 100.
+    pub fn operating_margin(&self) -> Option<Decimal> {
+        let revenue = self.revenue.filter(|r| !r.is_zero())?;
+        Some(self.operating_income? / revenue * Decimal::ONE_HUNDRED)
+    }
+}
+
+// ==================== 대량보유 ====================
+
+/// 주식등의 대량보유상황보고 (5% 이상 주주 지분 변동).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MajorShareholderChange {
+    /// 접수번호
+    pub rcept_no: String,
+    /// DART 고유번호
+    pub corp_code: String,
+    /// 회사명
+    pub corp_name: String,
+    /// 접수일
+    pub receipt_date: NaiveDate,
+    /// 보고구분 (일반/약식)
+    pub report_type: String,
+    /// 대표보고자
+    pub reporter: String,
+    /// 보유주식등의 수
+    pub shares: Option<Decimal>,
+    /// 보유주식등의 증감
+    pub shares_change: Option<Decimal>,
+    /// 보유비율 (%)
+    pub ownership_pct: Option<Decimal>,
+    /// 보유비율 증감 (%p)
+    pub ownership_change_pct: Option<Decimal>,
+    /// 보고사유
+    pub reason: Option<String>,
+}
+
+// ==================== 고유번호 ====================
+
+/// DART 고유번호 매핑.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CorpCode {
+    /// DART 고유번호 (8자리)
+    pub corp_code: String,
+    /// 회사명
+    pub corp_name: String,
+    /// 종목코드 (비상장사는 None)
+    pub ticker: Option<String>,
+    /// 최종 변경일
+    pub modified_date: Option<NaiveDate>,
+}
+
+// ==================== 클라이언트 ====================
+
+/// DART OpenAPI 클라이언트.
+#[derive(Clone)]
+pub struct DartClient {
+    client: Client,
+    api_key: String,
+    base_url: String,
+    request_delay: Duration,
+}
+
+/// 암호화된 DART credential (API 키).
+#[derive(Deserialize)]
+struct DartCredentials {
+    api_key: String,
+}
+
+/// DB에서 조회한 credential row.
+#[derive(sqlx::FromRow)]
+struct CredentialRow {
+    encrypted_credentials: Vec<u8>,
+    encryption_nonce: Vec<u8>,
+}
+
+impl DartClient {
+    /// API 키로 클라이언트 생성.
+    ///
+    /// # Note
+    /// 직접 API 키를 하드코딩하지 마세요.
+    /// `from_credential()` 또는 `from_env()`를 사용하세요.
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            client: Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .expect("HTTP 클라이언트 생성 실패"),
+            api_key: api_key.into(),
+            base_url: DART_BASE_URL.to_string(),
+            request_delay: Duration::ZERO,
+        }
+    }
+
+    /// Base URL 변경 (프록시/테스트 서버용).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// 요청 간 딜레이 설정 (분당 요청 제한 대응).
+    pub fn with_request_delay(mut self, delay: Duration) -> Self {
+        self.request_delay = delay;
+        self
+    }
+
+    /// Credential 시스템에서 API 키를 읽어 클라이언트 생성 (권장).
+    ///
+    /// `exchange_credentials` 테이블에서 `exchange_id = 'dart'`인 credential을 복호화하고,
+    /// 없으면 `DART_API_KEY` 환경변수로 폴백합니다.
+    ///
+    /// # Returns
+    /// - `Ok(Some(client))`: API 키 로드 성공
+    /// - `Ok(None)`: credential과 환경변수 모두 없음
+    /// - `Err(...)`: DB 조회 또는 복호화 실패
+    pub async fn from_credential(
+        pool: &PgPool,
+        encryptor: &CredentialEncryptor,
+    ) -> Result<Option<Self>, String> {
+        let result: Option<CredentialRow> = sqlx::query_as(
+            r#"
+            SELECT encrypted_credentials, encryption_nonce
+            FROM exchange_credentials
+            WHERE exchange_id = 'dart' AND is_active = true
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("DART credential 조회 실패: {}", e))?;
+
+        if let Some(row) = result {
+            let credentials: DartCredentials = encryptor
+                .decrypt_json(&row.encrypted_credentials, &row.encryption_nonce)
+                .map_err(|e| format!("DART credential 복호화 실패: {}", e))?;
+
+            tracing::info!("DART API 키 로드: credential 시스템에서 로드됨");
+            return Ok(Some(Self::new(credentials.api_key)));
+        }
+
+        if let Some(client) = Self::from_env() {
+            tracing::info!("DART API 키 로드: 환경변수에서 로드됨 (폴백)");
+            return Ok(Some(client));
+        }
+
+        tracing::warn!(
+            "DART API credential이 등록되지 않았습니다. \
+            Settings에서 DART API 키를 등록하세요."
+        );
+        Ok(None)
+    }
+
+    /// 환경변수 `DART_API_KEY`에서 API 키를 읽어 클라이언트 생성 (폴백용).
+    pub fn from_env() -> Option<Self> {
+        std::env::var("DART_API_KEY")
+            .ok()
+            .filter(|key| !key.trim().is_empty())
+            .map(Self::new)
+    }
+
+    /// API 요청 실행 (응답 본문 반환).
+    async fn request(
+        &self,
+        endpoint: &str,
+        params: &[(&str, String)],
+    ) -> Result<Vec<u8>, DartError> {
+        if !self.request_delay.is_zero() {
+            tokio::time::sleep(self.request_delay).await;
+        }
+
+        let url = format!("{}/{}", self.base_url, endpoint);
+        tracing::debug!(endpoint, "DART API 요청");
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&[("crtfc_key", self.api_key.as_str())])
+            .query(params)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(DartError::RateLimited);
+        }
+        if !status.is_success() {
+            return Err(DartError::ApiError {
+                status: status.as_u16().to_string(),
+                message: response.text().await.unwrap_or_default(),
+            });
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// 공시 목록 조회 (1페이지).
+    ///
+    /// `corp_code` 없이 조회하면 전체 회사 공시를 반환하며, 이 경우 조회 기간은 3개월 이내여야 합니다.
+    pub async fn fetch_disclosures(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        corp_code: Option<&str>,
+        page_no: u32,
+    ) -> Result<DisclosurePage, DartError> {
+        let mut params = vec![
+            ("bgn_de", start.format("%Y%m%d").to_string()),
+            ("end_de", end.format("%Y%m%d").to_string()),
+            ("page_no", page_no.max(1).to_string()),
+            ("page_count", MAX_PAGE_COUNT.to_string()),
+        ];
+        if let Some(corp_code) = corp_code {
+            params.push(("corp_code", corp_code.to_string()));
+        }
+
+        let body = self.request("list.json", &params).await?;
+        parse_disclosure_list(&String::from_utf8_lossy(&body))
+    }
+
+    /// 기간 내 공시 전체 조회 (모든 페이지).
+    pub async fn fetch_all_disclosures(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        corp_code: Option<&str>,
+    ) -> Result<Vec<Disclosure>, DartError> {
+        let mut disclosures = Vec::new();
+        let mut page_no = 1;
+        loop {
+            let page = self
+                .fetch_disclosures(start, end, corp_code, page_no)
+                .await?;
+            disclosures.extend(page.items);
+            if page_no >= page.total_page {
+                break;
+            }
+            page_no += 1;
+        }
+        Ok(disclosures)
+    }
+
+    /// 단일회사 주요계정 재무제표 조회 (연결 우선, 없으면 별도).
+    pub async fn fetch_financial_statement(
+        &self,
+        corp_code: &str,
+        fiscal_year: i32,
+        period: ReportPeriod,
+    ) -> Result<Option<FinancialStatement>, DartError> {
+        let params = [
+            ("corp_code", corp_code.to_string()),
+            ("bsns_year", fiscal_year.to_string()),
+            ("reprt_code", period.code().to_string()),
+        ];
+        let body = self.request("fnlttSinglAcnt.json", &params).await?;
+        let statements = parse_financial_statements(&String::from_utf8_lossy(&body))?;
+        Ok(preferred_statement(statements))
+    }
+
+    /// 주식등의 대량보유상황보고 조회.
+    pub async fn fetch_major_shareholder_changes(
+        &self,
+        corp_code: &str,
+    ) -> Result<Vec<MajorShareholderChange>, DartError> {
+        let params = [("corp_code", corp_code.to_string())];
+        let body = self.request("majorstock.json", &params).await?;
+        parse_major_shareholder_changes(&String::from_utf8_lossy(&body))
+    }
+
+    /// 전체 고유번호 목록 조회 (`corpCode.xml`, ZIP 압축).
+    pub async fn fetch_corp_codes(&self) -> Result<Vec<CorpCode>, DartError> {
+        let body = self.request("corpCode.xml", &[]).await?;
+        parse_corp_code_zip(&body)
+    }
+}
+
+// ==================== 응답 파싱 ====================
+
+/// DART 공통 응답 envelope.
+#[derive(Deserialize)]
+struct DartResponse<T> {
+    status: String,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    page_no: Option<u32>,
+    #[serde(default)]
+    total_count: Option<u32>,
+    #[serde(default)]
+    total_page: Option<u32>,
+    #[serde(default = "Vec::new")]
+    list: Vec<T>,
+}
+
+/// 응답 상태 코드 확인 (데이터 없음은 `None`).
+fn parse_envelope<T: DeserializeOwned>(body: &str) -> Result<Option<DartResponse<T>>, DartError> {
+    let response: DartResponse<T> = serde_json::from_str(body)
+        .map_err(|e| DartError::ParseError(format!("JSON 파싱 실패: {}", e)))?;
+
+    match response.status.as_str() {
+        STATUS_OK => Ok(Some(response)),
+        STATUS_NO_DATA => Ok(None),
+        STATUS_RATE_LIMITED => Err(DartError::RateLimited),
+        _ => Err(DartError::ApiError {
+            status: response.status,
+            message: response.message,
+        }),
+    }
+}
+
+#[derive(Deserialize)]
+struct RawDisclosure {
+    #[serde(default)]
+    corp_code: String,
+    #[serde(default)]
+    corp_name: String,
+    #[serde(default)]
+    stock_code: String,
+    #[serde(default)]
+    corp_cls: String,
+    #[serde(default)]
+    report_nm: String,
+    #[serde(default)]
+    rcept_no: String,
+    #[serde(default)]
+    flr_nm: String,
+    #[serde(default)]
+    rcept_dt: String,
+    #[serde(default)]
+    rm: String,
+}
+
+/// 공시 목록 응답(`list.json`) 파싱.
+pub fn parse_disclosure_list(body: &str) -> Result<DisclosurePage, DartError> {
+    let Some(response) = parse_envelope::<RawDisclosure>(body)? else {
+        return Ok(DisclosurePage::default());
+    };
+
+    let items = response
+        .list
+        .into_iter()
+        .map(|raw| {
+            let receipt_date = parse_date(&raw.rcept_dt).ok_or_else(|| {
+                DartError::ParseError(format!("접수일 파싱 실패: {}", raw.rcept_dt))
+            })?;
+            let report_name = raw.report_nm.trim().to_string();
+            Ok(Disclosure {
+                kind: DisclosureKind::classify(&report_name),
+                rcept_no: raw.rcept_no.trim().to_string(),
+                corp_code: raw.corp_code.trim().to_string(),
+                corp_name: raw.corp_name.trim().to_string(),
+                ticker: non_empty(&raw.stock_code),
+                corp_cls: raw.corp_cls.trim().to_string(),
+                report_name,
+                receipt_date,
+                filer_name: raw.flr_nm.trim().to_string(),
+                remark: non_empty(&raw.rm),
+            })
+        })
+        .collect::<Result<Vec<_>, DartError>>()?;
+
+    Ok(DisclosurePage {
+        items,
+        page_no: response.page_no.unwrap_or(1),
+        total_page: response.total_page.unwrap_or(1),
+        total_count: response.total_count.unwrap_or(0),
+    })
+}
+
+#[derive(Deserialize)]
+struct RawAccount {
+    #[serde(default)]
+    rcept_no: String,
+    #[serde(default)]
+    reprt_code: String,
+    #[serde(default)]
+    bsns_year: String,
+    #[serde(default)]
+    corp_code: String,
+    #[serde(default)]
+    stock_code: String,
+    #[serde(default)]
+    fs_div: String,
+    #[serde(default)]
+    account_nm: String,
+    #[serde(default)]
+    thstrm_amount: String,
+    #[serde(default)]
+    currency: String,
+}
+
+/// 주요계정 응답(`fnlttSinglAcnt.json`) 파싱.
+///
+/// 연결(CFS)/별도(OFS) 재무제표를 각각 하나의 [`FinancialStatement`]로 묶어 반환합니다.
+pub fn parse_financial_statements(body: &str) -> Result<Vec<FinancialStatement>, DartError> {
+    let Some(response) = parse_envelope::<RawAccount>(body)? else {
+        return Ok(Vec::new());
+    };
+
+    let mut statements: Vec<FinancialStatement> = Vec::new();
+    for raw in response.list {
+        let consolidated = raw.fs_div.trim() == "CFS";
+        let idx = match statements
+            .iter()
+            .position(|s| s.consolidated == consolidated)
+        {
+            Some(idx) => idx,
+            None => {
+                let period = ReportPeriod::from_code(&raw.reprt_code).ok_or_else(|| {
+                    DartError::ParseError(format!("보고서 코드 파싱 실패: {}", raw.reprt_code))
+                })?;
+                let fiscal_year = raw.bsns_year.trim().parse().map_err(|_| {
+                    DartError::ParseError(format!("사업연도 파싱 실패: {}", raw.bsns_year))
+                })?;
+                statements.push(FinancialStatement {
+                    corp_code: raw.corp_code.trim().to_string(),
+                    ticker: non_empty(&raw.stock_code),
+                    fiscal_year,
+                    period,
+                    consolidated,
+                    rcept_no: raw.rcept_no.trim().to_string(),
+                    currency: non_empty(&raw.currency).unwrap_or_else(|| "KRW".to_string()),
+                    revenue: None,
+                    operating_income: None,
+                    net_income: None,
+                    total_assets: None,
+                    total_liabilities: None,
+                    total_equity: None,
+                });
+                statements.len() - 1
+            }
+        };
+
+        let statement = &mut statements[idx];
+        let amount = parse_amount(&raw.thstrm_amount);
+        // 계정명은 회사별로 "영업이익(손실)", "수익(매출액)"처럼 표기가 다름
+        let account: String = raw
+            .account_nm
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let slot = if account == "매출액" || account == "수익(매출액)" || account == "영업수익"
+        {
+            &mut statement.revenue
+        } else if account.starts_with("영업이익") {
+            &mut statement.operating_income
+        } else if account.starts_with("당기순이익") {
+            &mut statement.net_income
+        } else if account == "자산총계" {
+            &mut statement.total_assets
+        } else if account == "부채총계" {
+            &mut statement.total_liabilities
+        } else if account == "자본총계" {
+            &mut statement.total_equity
+        } else {
+            continue;
+        };
+        if slot.is_none() {
+            *slot = amount;
+        }
+    }
+
+    Ok(statements)
+}
+
+/// 연결재무제표 우선 선택 (없으면 별도재무제표).
+pub fn preferred_statement(statements: Vec<FinancialStatement>) -> Option<FinancialStatement> {
+    let mut separate = None;
+    for statement in statements {
+        if statement.consolidated {
+            return Some(statement);
+        }
+        separate.get_or_insert(statement);
+    }
+    separate
+}
+
+#[derive(Deserialize)]
+struct RawMajorStock {
+    #[serde(default)]
+    rcept_no: String,
+    #[serde(default)]
+    rcept_dt: String,
+    #[serde(default)]
+    corp_code: String,
+    #[serde(default)]
+    corp_name: String,
+    #[serde(default)]
+    report_tp: String,
+    #[serde(default)]
+    repror: String,
+    #[serde(default)]
+    stkqy: String,
+    #[serde(default)]
+    stkqy_irds: String,
+    #[serde(default)]
+    stkrt: String,
+    #[serde(default)]
+    stkrt_irds: String,
+    #[serde(default)]
+    report_resn: String,
+}
+
+/// 대량보유상황보고 응답(`majorstock.json`) 파싱.
+pub fn parse_major_shareholder_changes(
+    body: &str,
+) -> Result<Vec<MajorShareholderChange>, DartError> {
+    let Some(response) = parse_envelope::<RawMajorStock>(body)? else {
+        return Ok(Vec::new());
+    };
+
+    response
+        .list
+        .into_iter()
+        .map(|raw| {
+            let receipt_date = parse_date(&raw.rcept_dt).ok_or_else(|| {
+                DartError::ParseError(format!("접수일 파싱 실패: {}", raw.rcept_dt))
+            })?;
+            Ok(MajorShareholderChange {
+                rcept_no: raw.rcept_no.trim().to_string(),
+                corp_code: raw.corp_code.trim().to_string(),
+                corp_name: raw.corp_name.trim().to_string(),
+                receipt_date,
+                report_type: raw.report_tp.trim().to_string(),
+                reporter: raw.repror.trim().to_string(),
+                shares: parse_amount(&raw.stkqy),
+                shares_change: parse_amount(&raw.stkqy_irds),
+                ownership_pct: parse_amount(&raw.stkrt),
+                ownership_change_pct: parse_amount(&raw.stkrt_irds),
+                reason: non_empty(raw.report_resn.trim_start_matches('-')),
+            })
+        })
+        .collect()
+}
+
+/// 고유번호 ZIP(`corpCode.xml`) 파싱.
+///
+/// 키가 잘못된 경우 DART는 ZIP 대신 JSON/XML 에러 본문을 반환하므로 이를 먼저 확인합니다.
+pub fn parse_corp_code_zip(bytes: &[u8]) -> Result<Vec<CorpCode>, DartError> {
+    if !bytes.starts_with(b"PK") {
+        let body = String::from_utf8_lossy(bytes);
+        let status = extract_tag(&body, "status").unwrap_or_default();
+        if status == STATUS_RATE_LIMITED {
+            return Err(DartError::RateLimited);
+        }
+        return Err(DartError::ApiError {
+            status,
+            message: extract_tag(&body, "message")
+                .unwrap_or_else(|| body.chars().take(200).collect()),
+        });
+    }
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| DartError::ParseError(format!("ZIP 열기 실패: {}", e)))?;
+    let mut file = archive
+        .by_index(0)
+        .map_err(|e| DartError::ParseError(format!("ZIP 항목 읽기 실패: {}", e)))?;
+    let mut xml = String::new();
+    file.read_to_string(&mut xml)
+        .map_err(|e| DartError::ParseError(format!("CORPCODE.xml 읽기 실패: {}", e)))?;
+
+    Ok(parse_corp_code_xml(&xml))
+}
+
+/// `CORPCODE.xml` 본문 파싱.
+pub fn parse_corp_code_xml(xml: &str) -> Vec<CorpCode> {
+    xml.split("<list>")
+        .skip(1)
+        .filter_map(|item| {
+            let item = item.split("</list>").next()?;
+            Some(CorpCode {
+                corp_code: extract_tag(item, "corp_code")?,
+                corp_name: extract_tag(item, "corp_name").unwrap_or_default(),
+                ticker: extract_tag(item, "stock_code"),
+                modified_date: extract_tag(item, "modify_date").and_then(|d| parse_date(&d)),
+            })
+        })
+        .collect()
+}
+
+/// XML 태그 값 추출 (빈 값은 `None`, 기본 엔티티 디코딩).
+fn extract_tag(xml: &str, tag: &str) -> Option<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let start = xml.find(&open)? + open.len();
+    let end = xml[start..].find(&close)? + start;
+    let value = xml[start..end]
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&");
+    non_empty(&value)
+}
+
+/// 공백 제거 후 빈 문자열이면 `None`.
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// 날짜 파싱 (`20240516` 또는 `2024-05-16`).
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y.%m.%d"))
+        .ok()
+}
+
+/// 금액/수량 파싱 (`1,234`, `-1,234`, `(1,234)`; `-`/빈 값은 `None`).
+fn parse_amount(value: &str) -> Option<Decimal> {
+    let value = value.trim();
+    if value.is_empty() || value == "-" {
+        return None;
+    }
+    let (negative, value) = match value.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+        Some(inner) => (true, inner),
+        None => (false, value),
+    };
+    let cleaned: String = value.chars().filter(|c| *c != ',').collect();
+    let amount: Decimal = cleaned.parse().ok()?;
+    Some(if negative { -amount } else { amount })
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    const LIST_FIXTURE: &str = include_str!("../../tests/fixtures/dart/list.json");
+    const LIST_NO_DATA_FIXTURE: &str = include_str!("../../tests/fixtures/dart/list_no_data.json");
+    const LIST_RATE_LIMITED_FIXTURE: &str =
+        include_str!("../../tests/fixtures/dart/list_rate_limited.json");
+    const FNLTT_FIXTURE: &str = include_str!("../../tests/fixtures/dart/fnltt_single_acnt.json");
+    const MAJORSTOCK_FIXTURE: &str = include_str!("../../tests/fixtures/dart/majorstock.json");
+    const CORP_CODE_FIXTURE: &[u8] = include_bytes!("../../tests/fixtures/dart/corp_code.zip");
+
+    #[test]
+    fn test_classify_disclosure_kind() {
+        let cases = [
+            (
+                "주요사항보고서(유상증자결정)",
+                DisclosureKind::CapitalIncrease,
+            ),
+            (
+                "주요사항보고서(유무상증자결정)",
+                DisclosureKind::CapitalIncrease,
+            ),
+            ("주요사항보고서(무상증자결정)", DisclosureKind::BonusIssue),
+            ("주요사항보고서(감자결정)", DisclosureKind::CapitalReduction),
+            (
+                "주요사항보고서(전환사채권발행결정)",
+                DisclosureKind::ConvertibleBond,
+            ),
+            (
+                "주요사항보고서(신주인수권부사채권발행결정)",
+                DisclosureKind::BondWithWarrant,
+            ),
+            (
+                "[기재정정]최대주주변경",
+                DisclosureKind::LargestShareholderChange,
+            ),
+            (
+                "최대주주 변경을 수반하는 주식 양수도 계약 체결",
+                DisclosureKind::LargestShareholderChange,
+            ),
+            (
+                "주식등의대량보유상황보고서(일반)",
+                DisclosureKind::MajorShareholding,
+            ),
+            (
+                "주요사항보고서(자기주식취득신탁계약체결결정)",
+                DisclosureKind::TreasuryStockAcquisition,
+            ),
+            ("주요사항보고서(회사합병결정)", DisclosureKind::Merger),
+            ("분기보고서 (2024.03)", DisclosureKind::PeriodicReport),
+            (
+                "[기재정정]사업보고서 (2023.12)",
+                DisclosureKind::PeriodicReport,
+            ),
+            (
+                "임원ㆍ주요주주특정증권등소유상황보고서",
+                DisclosureKind::Other,
+            ),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(DisclosureKind::classify(name), expected, "{}", name);
+        }
+
+        for kind in DisclosureKind::ALL {
+            assert_eq!(DisclosureKind::parse(kind.as_str()), Some(kind));
+        }
+        assert!(DisclosureKind::ConvertibleBond.is_material());
+        assert!(!DisclosureKind::PeriodicReport.is_material());
+    }
+
+    #[test]
+    fn test_parse_disclosure_list_fixture() {
+        let page = parse_disclosure_list(LIST_FIXTURE).unwrap();
+        assert_eq!(page.items.len(), 6);
+        assert_eq!(page.total_page, 1);
+        assert_eq!(page.total_count, 6);
+
+        let cb = &page.items[1];
+        assert_eq!(cb.ticker.as_deref(), Some("000660"));
+        assert_eq!(cb.kind, DisclosureKind::ConvertibleBond);
+        assert_eq!(
+            cb.receipt_date,
+            NaiveDate::from_ymd_opt(2024, 5, 16).unwrap()
+        );
+        assert_eq!(
+            cb.url(),
+            "https://dart.fss.or.kr/dsaf001/main.do?rcpNo=20240516000392"
+        );
+
+        let rights = &page.items[2];
+        assert_eq!(rights.kind, DisclosureKind::CapitalIncrease);
+        assert!(rights.is_amendment());
+        assert_eq!(rights.remark.as_deref(), Some("코"));
+
+        assert_eq!(page.items[3].kind, DisclosureKind::LargestShareholderChange);
+        assert_eq!(
+            page.items[0].periodic_report(),
+            Some((2024, ReportPeriod::Q1))
+        );
+
+        // 비상장사는 종목코드가 공백
+        let unlisted = &page.items[5];
+        assert_eq!(unlisted.ticker, None);
+        assert_eq!(unlisted.kind, DisclosureKind::BondWithWarrant);
+    }
+
+    #[test]
+    fn test_parse_status_codes() {
+        let page = parse_disclosure_list(LIST_NO_DATA_FIXTURE).unwrap();
+        assert!(page.items.is_empty());
+
+        assert!(matches!(
+            parse_disclosure_list(LIST_RATE_LIMITED_FIXTURE),
+            Err(DartError::RateLimited)
+        ));
+
+        let invalid_key = r#"{"status":"010","message":"등록되지 않은 키입니다."}"#;
+        match parse_disclosure_list(invalid_key) {
+            Err(DartError::ApiError { status, .. }) => assert_eq!(status, "010"),
+            other => panic!("unexpected: {:?}", other.map(|p| p.items.len())),
+        }
+    }
+
+    #[test]
+    fn test_parse_financial_statements_fixture() {
+        let statements = parse_financial_statements(FNLTT_FIXTURE).unwrap();
+        assert_eq!(statements.len(), 2);
+
+        let separate = statements.iter().find(|s| !s.consolidated).unwrap();
+        assert_eq!(separate.operating_income, Some(dec!(-1250000000)));
+        assert_eq!(separate.total_equity, None);
+
+        let statement = preferred_statement(statements).unwrap();
+        assert!(statement.consolidated);
+        assert_eq!(statement.fiscal_year, 2024);
+        assert_eq!(statement.period, ReportPeriod::Q1);
+        assert_eq!(statement.ticker.as_deref(), Some("005930"));
+        assert_eq!(statement.revenue, Some(dec!(71915601000000)));
+        assert_eq!(statement.operating_income, Some(dec!(6606060000000)));
+        assert_eq!(statement.net_income, Some(dec!(6754712000000)));
+        assert_eq!(statement.total_assets, Some(dec!(460087986000000)));
+        assert_eq!(statement.total_liabilities, Some(dec!(93750524000000)));
+        assert_eq!(statement.total_equity, Some(dec!(366337462000000)));
+        assert_eq!(statement.debt_ratio().unwrap().round_dp(2), dec!(25.59));
+        assert_eq!(
+            statement.operating_margin().unwrap().round_dp(2),
+            dec!(9.19)
+        );
+    }
+
+    #[test]
+    fn test_parse_major_shareholder_fixture() {
+        let changes = parse_major_shareholder_changes(MAJORSTOCK_FIXTURE).unwrap();
+        assert_eq!(changes.len(), 2);
+
+        let nps = &changes[0];
+        assert_eq!(nps.reporter, "국민연금공단");
+        assert_eq!(
+            nps.receipt_date,
+            NaiveDate::from_ymd_opt(2024, 5, 16).unwrap()
+        );
+        assert_eq!(nps.shares, Some(dec!(458208523)));
+        assert_eq!(nps.shares_change, Some(dec!(-5962100)));
+        assert_eq!(nps.ownership_pct, Some(dec!(7.68)));
+        assert_eq!(nps.ownership_change_pct, Some(dec!(-0.10)));
+        assert_eq!(nps.reason.as_deref(), Some("단순처분"));
+
+        // "-"는 값 없음
+        assert_eq!(changes[1].shares_change, None);
+        assert_eq!(changes[1].reason.as_deref(), Some("보유주식등의 수 변동"));
+    }
+
+    #[test]
+    fn test_parse_corp_code_fixture() {
+        let codes = parse_corp_code_zip(CORP_CODE_FIXTURE).unwrap();
+        assert_eq!(codes.len(), 4);
+
+        assert_eq!(codes[0].corp_code, "00434003");
+        assert_eq!(codes[0].ticker, None);
+
+        let samsung = codes.iter().find(|c| c.corp_code == "00126380").unwrap();
+        assert_eq!(samsung.ticker.as_deref(), Some("005930"));
+        assert_eq!(samsung.modified_date, NaiveDate::from_ymd_opt(2024, 3, 22));
+        assert_eq!(codes[3].corp_name, "에코프로비엠 & 파트너스");
+
+        // ZIP 대신 에러 본문이 오는 경우
+        let error = r#"<?xml version="1.0" encoding="UTF-8"?><result><status>010</status><message>등록되지 않은 키입니다.</message></result>"#;
+        match parse_corp_code_zip(error.as_bytes()) {
+            Err(DartError::ApiError { status, message }) => {
+                assert_eq!(status, "010");
+                assert_eq!(message, "등록되지 않은 키입니다.");
+            }
+            other => panic!("unexpected: {:?}", other.map(|c| c.len())),
+        }
+    }
+
+    #[test]
+    fn test_report_period_from_report_name() {
+        assert_eq!(
+            ReportPeriod::from_report_name("사업보고서 (2023.12)"),
+            Some((2023, ReportPeriod::Annual))
+        );
+        assert_eq!(
+            ReportPeriod::from_report_name("반기보고서 (2024.06)"),
+            Some((2024, ReportPeriod::H1))
+        );
+        assert_eq!(
+            ReportPeriod::from_report_name("[기재정정]분기보고서 (2024.09)"),
+            Some((2024, ReportPeriod::Q3))
+        );
+        // 12월 결산이 아닌 분기보고서
+        assert_eq!(ReportPeriod::from_report_name("분기보고서 (2024.06)"), None);
+        assert_eq!(ReportPeriod::from_report_name("최대주주변경"), None);
+        assert_eq!(ReportPeriod::from_code("11014"), Some(ReportPeriod::Q3));
+    }
+}
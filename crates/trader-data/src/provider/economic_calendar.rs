@@ -0,0 +1,512 @@
+//! 경제 이벤트 캘린더 피드 (ICS/CSV).
+//!
+//! FOMC, CPI, 한국은행 금통위 등 경제 일정을 ICS(iCalendar) 또는 CSV 피드에서 읽어
+//! [`EconomicEvent`] 목록으로 변환합니다. 피드는 HTTP(S) URL 또는 로컬 파일 경로입니다.
+//!
+//! ## ICS
+//! `VEVENT`의 `SUMMARY`, `DTSTART`를 사용하며, 유형은 `CATEGORIES`(없으면 제목 키워드),
+//! 영향도는 `X-IMPACT` 또는 `PRIORITY`(1~4 High, 5 Medium, 6~9 Low), 국가는 `X-COUNTRY`에서 읽습니다.
+//! `TZID` 파라미터는 IANA 시간대로 해석하고, 시간대가 없는 시각은 피드 국가의 현지 시각으로 간주합니다.
+//!
+//! ## CSV
+//! 첫 줄은 헤더이며 다음 컬럼을 인식합니다 (대소문자 무시, 순서 무관):
+//!
+//! | 컬럼 | 필수 | 설명 |
+//! |------|------|------|
+//! | `date` | ✅ | `YYYY-MM-DD` 또는 RFC 3339 일시 |
+//! | `time` | | `HH:MM` (현지 시각, 없으면 00:00) |
+//! | `timezone` | | IANA 시간대 (없으면 국가 기본 시간대) |
+//! | `title` / `event` | ✅ | 이벤트 제목 |
+//! | `country` | | 국가 코드 (없으면 피드 기본 국가) |
+//! | `impact` | | `low`/`medium`/`high` 또는 1~3 |
+//! | `kind` / `type` | | 이벤트 유형 (없으면 제목 키워드로 분류) |
+//!
+//! ## 사용 예시
+//! ```rust,ignore
+//! let feed = EconomicCalendarFeed::parse("KR=https://example.com/bok.ics").unwrap();
+//! let events = EconomicCalendarFetcher::new().fetch(&feed).await?;
+//! ```
+
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use reqwest::Client;
+use thiserror::Error;
+use trader_core::{Country, EconomicEvent, EconomicEventKind, EventImpact};
+
+/// 경제 캘린더 피드 에러.
+#[derive(Debug, Error)]
+pub enum EconomicCalendarError {
+    #[error("HTTP 요청 실패: {0}")]
+    HttpError(#[from] reqwest::Error),
+
+    #[error("파일 읽기 실패: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("피드 파싱 실패: {0}")]
+    ParseError(String),
+}
+
+/// 피드 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    /// iCalendar (.ics)
+    Ics,
+    /// CSV (.csv)
+    Csv,
+}
+
+impl FeedFormat {
+    /// 경로/URL 확장자로 형식 추론 (알 수 없으면 ICS).
+    pub fn from_location(location: &str) -> Self {
+        let path = location.split(['?', '#']).next().unwrap_or(location);
+        if path.to_lowercase().ends_with(".csv") {
+            FeedFormat::Csv
+        } else {
+            FeedFormat::Ics
+        }
+    }
+}
+
+/// 경제 캘린더 피드 설정.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EconomicCalendarFeed {
+    /// HTTP(S) URL 또는 로컬 파일 경로
+    pub location: String,
+    /// 피드 형식
+    pub format: FeedFormat,
+    /// 국가 정보가 없는 이벤트의 기본 국가
+    pub country: Country,
+}
+
+impl EconomicCalendarFeed {
+    /// 새 피드 생성 (형식은 확장자로 추론).
+    pub fn new(location: impl Into<String>, country: Country) -> Self {
+        let location = location.into();
+        Self {
+            format: FeedFormat::from_location(&location),
+            location,
+            country,
+        }
+    }
+
+    /// `COUNTRY=location` 또는 `location` 문자열에서 피드 생성 (기본 국가: Global).
+    pub fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        if spec.is_empty() {
+            return None;
+        }
+        match spec.split_once('=') {
+            Some((code, location)) if !code.contains('/') && !code.contains(':') => {
+                let country = Country::from_code(code.trim())?;
+                Some(Self::new(location.trim(), country))
+            }
+            _ => Some(Self::new(spec, Country::Global)),
+        }
+    }
+
+    /// 콤마로 구분된 피드 목록 파싱 (잘못된 항목은 무시).
+    pub fn parse_list(specs: &str) -> Vec<Self> {
+        specs.split(',').filter_map(Self::parse).collect()
+    }
+
+    /// 출처 표기 (URL/경로).
+    pub fn source(&self) -> &str {
+        &self.location
+    }
+}
+
+/// 경제 캘린더 피드 수집기.
+#[derive(Debug, Clone)]
+pub struct EconomicCalendarFetcher {
+    client: Client,
+}
+
+impl Default for EconomicCalendarFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EconomicCalendarFetcher {
+    /// 새 수집기 생성.
+    pub fn new() -> Self {
+        Self {
+            client: Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .expect("HTTP 클라이언트 생성 실패"),
+        }
+    }
+
+    /// 피드를 읽어 이벤트 목록으로 변환.
+    pub async fn fetch(
+        &self,
+        feed: &EconomicCalendarFeed,
+    ) -> Result<Vec<EconomicEvent>, EconomicCalendarError> {
+        let content =
+            if feed.location.starts_with("http://") || feed.location.starts_with("https://") {
+                self.client
+                    .get(&feed.location)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .text()
+                    .await?
+            } else {
+                tokio::fs::read_to_string(&feed.location).await?
+            };
+
+        let events = match feed.format {
+            FeedFormat::Ics => parse_ics(&content, feed.country)?,
+            FeedFormat::Csv => parse_csv(&content, feed.country)?,
+        };
+        Ok(events
+            .into_iter()
+            .map(|e| e.with_source(feed.source()))
+            .collect())
+    }
+}
+
+// ==================== ICS ====================
+
+/// ICS(iCalendar) 본문 파싱.
+pub fn parse_ics(
+    content: &str,
+    default_country: Country,
+) -> Result<Vec<EconomicEvent>, EconomicCalendarError> {
+    if !content.contains("BEGIN:VCALENDAR") {
+        return Err(EconomicCalendarError::ParseError(
+            "VCALENDAR가 없습니다".to_string(),
+        ));
+    }
+
+    let mut events = Vec::new();
+    let mut current: Option<Vec<(String, String, String)>> = None;
+
+    for line in unfold_ics_lines(content) {
+        let Some((head, value)) = line.split_once(':') else {
+            continue;
+        };
+        let (name, params) = match head.split_once(';') {
+            Some((name, params)) => (name.to_uppercase(), params.to_string()),
+            None => (head.to_uppercase(), String::new()),
+        };
+
+        match (name.as_str(), value.trim()) {
+            ("BEGIN", "VEVENT") => current = Some(Vec::new()),
+            ("END", "VEVENT") => {
+                if let Some(props) = current.take() {
+                    if let Some(event) = ics_event(&props, default_country) {
+                        events.push(event);
+                    }
+                }
+            }
+            _ => {
+                if let Some(props) = current.as_mut() {
+                    props.push((name, params, value.to_string()));
+                }
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+/// 접힌 줄(공백/탭으로 시작) 펼치기.
+fn unfold_ics_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in content.lines() {
+        let raw = raw.trim_end_matches('\r');
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+fn ics_event(
+    props: &[(String, String, String)],
+    default_country: Country,
+) -> Option<EconomicEvent> {
+    let get = |key: &str| props.iter().find(|(name, _, _)| name == key);
+
+    let title = unescape_ics(&get("SUMMARY")?.2);
+    let country = get("X-COUNTRY")
+        .and_then(|(_, _, v)| Country::from_code(v.trim()))
+        .unwrap_or(default_country);
+
+    let (_, params, value) = get("DTSTART")?;
+    let tzid = params
+        .split(';')
+        .find_map(|p| p.strip_prefix("TZID="))
+        .map(|tz| tz.trim_matches('"'));
+    let scheduled_at = parse_ics_datetime(value.trim(), tzid, country)?;
+
+    let kind = get("CATEGORIES")
+        .and_then(|(_, _, v)| v.split(',').find_map(EconomicEventKind::parse))
+        .unwrap_or_else(|| EconomicEventKind::classify(&title));
+    let impact = get("X-IMPACT")
+        .and_then(|(_, _, v)| EventImpact::parse(v))
+        .or_else(|| {
+            get("PRIORITY")
+                .and_then(|(_, _, v)| v.trim().parse::<u8>().ok())
+                .and_then(priority_impact)
+        })
+        .unwrap_or_else(|| kind.default_impact());
+
+    Some(EconomicEvent::new(kind, title, country, scheduled_at).with_impact(impact))
+}
+
+/// RFC 5545 PRIORITY (1 최고 ~ 9 최저, 0 미정의) → 영향도.
+fn priority_impact(priority: u8) -> Option<EventImpact> {
+    match priority {
+        1..=4 => Some(EventImpact::High),
+        5 => Some(EventImpact::Medium),
+        6..=9 => Some(EventImpact::Low),
+        _ => None,
+    }
+}
+
+/// `DTSTART` 값 파싱 (`...Z` UTC, TZID 현지, 날짜만 있는 종일 이벤트).
+fn parse_ics_datetime(value: &str, tzid: Option<&str>, country: Country) -> Option<DateTime<Utc>> {
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(naive.and_utc());
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y%m%d")
+                .ok()
+                .map(|d| d.and_time(NaiveTime::MIN))
+        })?;
+    let tz = tzid
+        .and_then(|id| id.parse::<Tz>().ok())
+        .unwrap_or_else(|| country_timezone(country));
+    localize(naive, tz)
+}
+
+fn unescape_ics(value: &str) -> String {
+    value
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+        .trim()
+        .to_string()
+}
+
+// ==================== CSV ====================
+
+/// CSV 본문 파싱 (헤더 필수).
+pub fn parse_csv(
+    content: &str,
+    default_country: Country,
+) -> Result<Vec<EconomicEvent>, EconomicCalendarError> {
+    let mut rows = content
+        .lines()
+        .map(|l| l.trim_end_matches('\r'))
+        .filter(|l| !l.trim().is_empty());
+    let header: Vec<String> = split_csv_row(rows.next().unwrap_or_default())
+        .into_iter()
+        .map(|h| h.trim().to_lowercase())
+        .collect();
+    let column = |names: &[&str]| header.iter().position(|h| names.contains(&h.as_str()));
+
+    let date_col = column(&["date", "datetime"])
+        .ok_or_else(|| EconomicCalendarError::ParseError("date 컬럼이 없습니다".to_string()))?;
+    let title_col = column(&["title", "event", "name"])
+        .ok_or_else(|| EconomicCalendarError::ParseError("title 컬럼이 없습니다".to_string()))?;
+    let time_col = column(&["time"]);
+    let tz_col = column(&["timezone", "tz"]);
+    let country_col = column(&["country"]);
+    let impact_col = column(&["impact", "importance"]);
+    let kind_col = column(&["kind", "type", "category"]);
+
+    let mut events = Vec::new();
+    for (line_no, row) in rows.enumerate() {
+        let fields = split_csv_row(row);
+        let field = |col: Option<usize>| {
+            col.and_then(|c| fields.get(c))
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+        };
+
+        let Some(title) = field(Some(title_col)) else {
+            continue;
+        };
+        let country = field(country_col)
+            .and_then(Country::from_code)
+            .unwrap_or(default_country);
+        let Some(scheduled_at) = field(Some(date_col))
+            .and_then(|date| csv_datetime(date, field(time_col), field(tz_col), country))
+        else {
+            return Err(EconomicCalendarError::ParseError(format!(
+                "{}행: 일시를 해석할 수 없습니다",
+                line_no + 2
+            )));
+        };
+
+        let kind = field(kind_col)
+            .and_then(EconomicEventKind::parse)
+            .unwrap_or_else(|| EconomicEventKind::classify(title));
+        let impact = field(impact_col)
+            .and_then(EventImpact::parse)
+            .unwrap_or_else(|| kind.default_impact());
+
+        events.push(EconomicEvent::new(kind, title, country, scheduled_at).with_impact(impact));
+    }
+
+    Ok(events)
+}
+
+fn csv_datetime(
+    date: &str,
+    time: Option<&str>,
+    tz: Option<&str>,
+    country: Country,
+) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(date) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    let time = match time {
+        Some(t) => NaiveTime::parse_from_str(t, "%H:%M")
+            .or_else(|_| NaiveTime::parse_from_str(t, "%H:%M:%S"))
+            .ok()?,
+        None => NaiveTime::MIN,
+    };
+    let tz = match tz {
+        Some(id) => id.parse::<Tz>().ok()?,
+        None => country_timezone(country),
+    };
+    localize(date.and_time(time), tz)
+}
+
+/// 따옴표(`"`)를 지원하는 CSV 행 분할.
+fn split_csv_row(row: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = row.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+// ==================== 공통 ====================
+
+/// 국가 기본 시간대 (시간대 정보가 없는 시각 해석용).
+fn country_timezone(country: Country) -> Tz {
+    match country {
+        Country::KR => chrono_tz::Asia::Seoul,
+        Country::US => chrono_tz::America::New_York,
+        Country::JP => chrono_tz::Asia::Tokyo,
+        Country::CN => chrono_tz::Asia::Shanghai,
+        Country::HK => chrono_tz::Asia::Hong_Kong,
+        Country::SG => chrono_tz::Asia::Singapore,
+        Country::GB => chrono_tz::Europe::London,
+        Country::AU => chrono_tz::Australia::Sydney,
+        Country::CA => chrono_tz::America::Toronto,
+        Country::Global => chrono_tz::UTC,
+    }
+}
+
+fn localize(naive: NaiveDateTime, tz: Tz) -> Option<DateTime<Utc>> {
+    tz.from_local_datetime(&naive)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ICS_FIXTURE: &str = include_str!("../../tests/fixtures/economic_calendar/events.ics");
+    const CSV_FIXTURE: &str = include_str!("../../tests/fixtures/economic_calendar/events.csv");
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_ics() {
+        let events = parse_ics(ICS_FIXTURE, Country::US).unwrap();
+        assert_eq!(events.len(), 3);
+
+        // TZID=America/New_York 14:00 (EDT) → 18:00 UTC
+        let fomc = &events[0];
+        assert_eq!(fomc.kind, EconomicEventKind::Fomc);
+        assert_eq!(fomc.title, "FOMC Rate Decision, Press Conference");
+        assert_eq!(fomc.country, Country::US);
+        assert_eq!(fomc.impact, EventImpact::High);
+        assert_eq!(fomc.scheduled_at, utc(2024, 3, 20, 18, 0));
+
+        // UTC 표기 + 접힌 줄 + CATEGORIES/PRIORITY
+        let cpi = &events[1];
+        assert_eq!(cpi.kind, EconomicEventKind::Cpi);
+        assert_eq!(cpi.impact, EventImpact::Medium);
+        assert_eq!(cpi.scheduled_at, utc(2024, 4, 10, 12, 30));
+
+        // X-COUNTRY, 시간대 없는 시각은 국가 현지 시각 (KST 10:00 → 01:00 UTC)
+        let bok = &events[2];
+        assert_eq!(bok.kind, EconomicEventKind::BokRateDecision);
+        assert_eq!(bok.country, Country::KR);
+        assert_eq!(bok.scheduled_at, utc(2024, 4, 12, 1, 0));
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let events = parse_csv(CSV_FIXTURE, Country::KR).unwrap();
+        assert_eq!(events.len(), 3);
+
+        assert_eq!(events[0].kind, EconomicEventKind::BokRateDecision);
+        assert_eq!(events[0].country, Country::KR);
+        assert_eq!(events[0].scheduled_at, utc(2024, 5, 23, 1, 0));
+
+        // 따옴표 안의 콤마, 명시적 시간대
+        assert_eq!(events[1].title, "Nonfarm Payrolls, Unemployment Rate");
+        assert_eq!(events[1].kind, EconomicEventKind::Employment);
+        assert_eq!(events[1].scheduled_at, utc(2024, 6, 7, 12, 30));
+
+        // RFC 3339 일시, 명시적 유형/영향도
+        assert_eq!(events[2].kind, EconomicEventKind::IndexRebalance);
+        assert_eq!(events[2].impact, EventImpact::High);
+        assert_eq!(events[2].scheduled_at, utc(2024, 6, 13, 6, 20));
+
+        assert!(parse_csv("title\nFOMC", Country::US).is_err());
+    }
+
+    #[test]
+    fn test_feed_spec() {
+        let feed = EconomicCalendarFeed::parse("KR=/data/bok.csv").unwrap();
+        assert_eq!(feed.country, Country::KR);
+        assert_eq!(feed.format, FeedFormat::Csv);
+        assert_eq!(feed.location, "/data/bok.csv");
+
+        let feeds = EconomicCalendarFeed::parse_list(
+            "https://example.com/fomc.ics?key=a=b, US=https://example.com/us.csv,,",
+        );
+        assert_eq!(feeds.len(), 2);
+        assert_eq!(feeds[0].country, Country::Global);
+        assert_eq!(feeds[0].format, FeedFormat::Ics);
+        assert_eq!(feeds[1].country, Country::US);
+    }
+}
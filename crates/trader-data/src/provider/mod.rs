@@ -7,6 +7,15 @@
 //! - KOSPI/KOSDAQ 종목 기본 정보, PER/PBR, OHLCV 데이터
 //! - Yahoo Finance 국내 주식 의존성 대체
 //!
+//! ## DART 전자공시
+//! - `DartClient`: 금융감독원 DART OpenAPI 클라이언트 (인증키 필요)
+//! - 공시 목록(유상증자, CB 발행, 최대주주 변경 등), 분기 재무제표, 대량보유 보고
+//! - 국내 주식 공식 공시/재무 데이터
+//!
+//! ## 경제 이벤트 캘린더
+//! - `EconomicCalendarFetcher`: ICS/CSV 경제 일정 피드 수집기 (URL 또는 로컬 파일)
+//! - FOMC, CPI, 한국은행 금통위, 고용지표 등 이벤트 일정
+//!
 //! ## 네이버 금융
 //! - `NaverFinanceFetcher`: 네이버 금융 크롤러
 //! - 시가총액, PER/PBR, ROE, 섹터, KOSPI/KOSDAQ/ETF 구분
@@ -23,13 +32,22 @@
 //! - `YahooSymbolProvider`: Yahoo Finance 미국/글로벌 주식 정보
 //! - `CompositeSymbolProvider`: 모든 Provider 통합
 
+pub mod dart;
+pub mod economic_calendar;
 pub mod krx_api;
 pub mod naver;
 pub mod symbol_info;
 pub mod yahoo_fundamental;
 
+pub use dart::{
+    CorpCode, DartClient, DartError, Disclosure, DisclosureKind, DisclosurePage,
+    FinancialStatement, MajorShareholderChange, ReportPeriod,
+};
+pub use economic_calendar::{
+    EconomicCalendarError, EconomicCalendarFeed, EconomicCalendarFetcher, FeedFormat,
+};
 pub use krx_api::{KrxApiClient, KrxEtfInfo, KrxOhlcv, KrxStockInfo, KrxValuation};
-pub use naver::{KrMarketType, NaverError, NaverFinanceFetcher, NaverFundamentalData};
+pub use naver::{KrMarketType, NaverError, NaverFinanceFetcher, NaverFundamentalData, NaverOhlcv};
 pub use symbol_info::{
     BinanceSymbolProvider, CompositeSymbolProvider, KrxSymbolProvider, SymbolInfoProvider,
     SymbolMetadata, SymbolResolver, YahooSymbolProvider,
@@ -6,6 +6,7 @@
 //! ## 데이터 소스
 //! - `/item/main.naver`: 시가총액, 52주 고저, 거래량, 업종
 //! - `/item/coinfo.naver`: PER, PBR, ROE, EPS, BPS, 배당수익률
+//! - `fchart.stock.naver.com/sise.nhn`: 일봉 OHLCV (데이터 품질 갭 복구용)
 //!
 //! ## 사용 예시
 //! ```rust,ignore
@@ -16,6 +17,7 @@
 
 use std::time::Duration;
 
+use chrono::NaiveDate;
 use reqwest::Client;
 use rust_decimal::Decimal;
 use scraper::{Html, Selector};
@@ -183,6 +185,23 @@ pub struct NaverFundamentalData {
     pub currency: String,
 }
 
+/// 네이버 차트 일봉 데이터
+#[derive(Debug, Clone, PartialEq)]
+pub struct NaverOhlcv {
+    /// 거래일
+    pub date: NaiveDate,
+    /// 시가
+    pub open: Decimal,
+    /// 고가
+    pub high: Decimal,
+    /// 저가
+    pub low: Decimal,
+    /// 종가
+    pub close: Decimal,
+    /// 거래량
+    pub volume: Decimal,
+}
+
 /// 네이버 금융 크롤러
 ///
 /// HTML 파싱을 통해 네이버 금융에서 주식 데이터를 수집합니다.
@@ -1003,6 +1022,53 @@ impl NaverFinanceFetcher {
         None
     }
 
+    /// 일봉 OHLCV 수집 (차트 API)
+    ///
+    /// 차트 API는 최근 N개 캔들만 반환하므로 `start`까지 포함하도록 개수를 잡고
+    /// 응답에서 `[start, end]` 구간만 남깁니다.
+    ///
+    /// # Arguments
+    /// * `ticker` - 종목 코드 (예: "005930")
+    /// * `start` - 시작일 (포함)
+    /// * `end` - 종료일 (포함)
+    pub async fn fetch_daily_ohlcv(
+        &self,
+        ticker: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<NaverOhlcv>, NaverError> {
+        // 달력 일수는 거래일 수보다 항상 크므로 start까지 충분히 포함됨
+        let today = chrono::Utc::now().date_naive();
+        let count = (today - start).num_days().max(1) + 1;
+        let url = format!(
+            "https://fchart.stock.naver.com/sise.nhn?symbol={}&timeframe=day&count={}&requestType=0",
+            ticker, count
+        );
+
+        let response = self.client.get(&url).send().await?;
+
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(NaverError::RateLimited);
+        }
+
+        // 응답은 EUC-KR XML이지만 캔들 값은 ASCII이므로 손실 변환으로 충분
+        let bytes = response.bytes().await?;
+        let xml = String::from_utf8_lossy(&bytes);
+
+        let candles: Vec<NaverOhlcv> = parse_fchart_items(&xml)
+            .into_iter()
+            .filter(|c| c.date >= start && c.date <= end)
+            .collect();
+
+        if candles.is_empty() {
+            return Err(NaverError::NoData {
+                ticker: ticker.to_string(),
+            });
+        }
+
+        Ok(candles)
+    }
+
     /// 배치 수집 (여러 종목)
     ///
     /// # Arguments
@@ -1165,6 +1231,31 @@ fn parse_financial_value(text: &str) -> Option<Decimal> {
     }
 }
 
+/// 차트 API XML에서 일봉 파싱
+///
+/// `<item data="20240102|78200|79800|78200|79600|17142847" />` 형식
+/// (날짜|시가|고가|저가|종가|거래량)의 항목을 추출합니다.
+fn parse_fchart_items(xml: &str) -> Vec<NaverOhlcv> {
+    xml.split("<item data=\"")
+        .skip(1)
+        .filter_map(|chunk| {
+            let data = chunk.split('"').next()?;
+            let fields: Vec<&str> = data.split('|').collect();
+            if fields.len() < 6 {
+                return None;
+            }
+            Some(NaverOhlcv {
+                date: NaiveDate::parse_from_str(fields[0], "%Y%m%d").ok()?,
+                open: parse_decimal_value(fields[1])?,
+                high: parse_decimal_value(fields[2])?,
+                low: parse_decimal_value(fields[3])?,
+                close: parse_decimal_value(fields[4])?,
+                volume: parse_decimal_value(fields[5])?,
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1205,6 +1296,28 @@ mod tests {
         assert_eq!(parse_percentage("-5.5%"), Some(Decimal::new(-55, 1)));
     }
 
+    #[test]
+    fn test_parse_fchart_items() {
+        let xml = r#"<?xml version="1.0" encoding="EUC-KR" ?>
+<protocol>
+<chartdata symbol="005930" name="삼성전자" count="3" timeframe="day" precision="0" origintime="19900103">
+<item data="20240102|78200|79800|78200|79600|17142847" />
+<item data="20240103|78500|78800|77000|77000|21753644" />
+<item data="broken" />
+</chartdata>
+</protocol>"#;
+
+        let candles = parse_fchart_items(xml);
+        assert_eq!(candles.len(), 2);
+        assert_eq!(
+            candles[0].date,
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()
+        );
+        assert_eq!(candles[0].high, Decimal::from(79800));
+        assert_eq!(candles[1].low, Decimal::from(77000));
+        assert_eq!(candles[1].volume, Decimal::from(21753644));
+    }
+
     #[tokio::test]
     #[ignore] // 실제 네트워크 테스트는 ignore
     async fn test_fetch_samsung() {
@@ -0,0 +1,312 @@
+//! DART 공시 저장소.
+//!
+//! 수집기(`trader-collector sync-dart`)가 [`DartClient`](crate::provider::DartClient)로 가져온
+//! 공시 목록, 분기 재무제표, 대량보유 보고를 저장하고, API 서버는 알림 대기 중인
+//! 주요사항 공시를 조회하여 알림 규칙에 따라 전송한 뒤 처리 완료로 표시합니다.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::{postgres::PgPool, FromRow};
+use tracing::instrument;
+
+use crate::{
+    error::{DataError, Result},
+    provider::dart::{
+        CorpCode, Disclosure, DisclosureKind, FinancialStatement, MajorShareholderChange,
+    },
+};
+
+/// 알림 대기 중인 공시 (최근 종가 포함).
+#[derive(Debug, Clone)]
+pub struct PendingDisclosure {
+    /// 공시
+    pub disclosure: Disclosure,
+    /// 최근 일봉 종가 (OHLCV가 없으면 None)
+    pub last_close: Option<Decimal>,
+}
+
+/// DB 조회용 공시 레코드.
+#[derive(Debug, FromRow)]
+struct DisclosureRecord {
+    rcept_no: String,
+    corp_code: String,
+    corp_name: String,
+    ticker: Option<String>,
+    corp_cls: Option<String>,
+    report_name: String,
+    disclosure_type: String,
+    receipt_date: NaiveDate,
+    filer_name: Option<String>,
+    remark: Option<String>,
+    last_close: Option<Decimal>,
+}
+
+impl DisclosureRecord {
+    fn into_pending(self) -> PendingDisclosure {
+        PendingDisclosure {
+            disclosure: Disclosure {
+                kind: DisclosureKind::parse(&self.disclosure_type)
+                    .unwrap_or_else(|| DisclosureKind::classify(&self.report_name)),
+                rcept_no: self.rcept_no,
+                corp_code: self.corp_code,
+                corp_name: self.corp_name,
+                ticker: self.ticker,
+                corp_cls: self.corp_cls.unwrap_or_default(),
+                report_name: self.report_name,
+                receipt_date: self.receipt_date,
+                filer_name: self.filer_name.unwrap_or_default(),
+                remark: self.remark,
+            },
+            last_close: self.last_close,
+        }
+    }
+}
+
+/// DART 공시 저장소.
+#[derive(Clone)]
+pub struct DisclosureRepository {
+    pool: PgPool,
+}
+
+impl DisclosureRepository {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 고유번호 매핑 저장 (상장사만, 기존 매핑은 갱신).
+    #[instrument(skip(self, codes), fields(count = codes.len()))]
+    pub async fn save_corp_codes(&self, codes: &[CorpCode]) -> Result<usize> {
+        let mut saved = 0;
+        let listed: Vec<&CorpCode> = codes.iter().filter(|c| c.ticker.is_some()).collect();
+
+        for chunk in listed.chunks(1000) {
+            let mut tx = self.pool.begin().await?;
+            for code in chunk {
+                sqlx::query(
+                    r#"
+                    INSERT INTO dart_corp_code (corp_code, ticker, corp_name, modified_date, updated_at)
+                    VALUES ($1, $2, $3, $4, NOW())
+                    ON CONFLICT (corp_code) DO UPDATE SET
+                        ticker = EXCLUDED.ticker,
+                        corp_name = EXCLUDED.corp_name,
+                        modified_date = EXCLUDED.modified_date,
+                        updated_at = NOW()
+                    "#,
+                )
+                .bind(&code.corp_code)
+                .bind(&code.ticker)
+                .bind(&code.corp_name)
+                .bind(code.modified_date)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DataError::InsertError(e.to_string()))?;
+                saved += 1;
+            }
+            tx.commit().await?;
+        }
+
+        Ok(saved)
+    }
+
+    /// 종목코드로 DART 고유번호 조회.
+    pub async fn corp_code_for(&self, ticker: &str) -> Result<Option<String>> {
+        sqlx::query_scalar(
+            "SELECT corp_code FROM dart_corp_code WHERE ticker = $1 ORDER BY updated_at DESC LIMIT 1",
+        )
+        .bind(ticker)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 공시 저장 (이미 저장된 접수번호는 건너뜀).
+    ///
+    /// # 반환
+    ///
+    /// 새로 저장된 공시 수
+    #[instrument(skip(self, disclosures), fields(count = disclosures.len()))]
+    pub async fn save_disclosures(&self, disclosures: &[Disclosure]) -> Result<usize> {
+        let mut inserted = 0;
+
+        for chunk in disclosures.chunks(1000) {
+            let mut tx = self.pool.begin().await?;
+            for disclosure in chunk {
+                let result = sqlx::query(
+                    r#"
+                    INSERT INTO dart_disclosure
+                        (rcept_no, corp_code, corp_name, ticker, corp_cls, report_name,
+                         disclosure_type, receipt_date, filer_name, remark)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                    ON CONFLICT (rcept_no) DO NOTHING
+                    "#,
+                )
+                .bind(&disclosure.rcept_no)
+                .bind(&disclosure.corp_code)
+                .bind(&disclosure.corp_name)
+                .bind(&disclosure.ticker)
+                .bind(&disclosure.corp_cls)
+                .bind(&disclosure.report_name)
+                .bind(disclosure.kind.as_str())
+                .bind(disclosure.receipt_date)
+                .bind(&disclosure.filer_name)
+                .bind(&disclosure.remark)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DataError::InsertError(e.to_string()))?;
+                inserted += result.rows_affected() as usize;
+            }
+            tx.commit().await?;
+        }
+
+        Ok(inserted)
+    }
+
+    /// 분기 재무제표 저장 (같은 회사/연도/보고서/연결구분은 교체).
+    #[instrument(skip(self, statement), fields(corp_code = %statement.corp_code, year = statement.fiscal_year))]
+    pub async fn save_financial_statement(&self, statement: &FinancialStatement) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO dart_financial_statement
+                (corp_code, ticker, fiscal_year, report_code, consolidated, rcept_no, currency,
+                 revenue, operating_income, net_income, total_assets, total_liabilities, total_equity)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (corp_code, fiscal_year, report_code, consolidated) DO UPDATE SET
+                ticker = EXCLUDED.ticker,
+                rcept_no = EXCLUDED.rcept_no,
+                currency = EXCLUDED.currency,
+                revenue = EXCLUDED.revenue,
+                operating_income = EXCLUDED.operating_income,
+                net_income = EXCLUDED.net_income,
+                total_assets = EXCLUDED.total_assets,
+                total_liabilities = EXCLUDED.total_liabilities,
+                total_equity = EXCLUDED.total_equity,
+                fetched_at = NOW()
+            "#,
+        )
+        .bind(&statement.corp_code)
+        .bind(&statement.ticker)
+        .bind(statement.fiscal_year)
+        .bind(statement.period.code())
+        .bind(statement.consolidated)
+        .bind(&statement.rcept_no)
+        .bind(&statement.currency)
+        .bind(statement.revenue)
+        .bind(statement.operating_income)
+        .bind(statement.net_income)
+        .bind(statement.total_assets)
+        .bind(statement.total_liabilities)
+        .bind(statement.total_equity)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// 대량보유상황보고 저장 (이미 저장된 접수번호는 건너뜀).
+    #[instrument(skip(self, changes), fields(count = changes.len()))]
+    pub async fn save_shareholder_changes(
+        &self,
+        ticker: Option<&str>,
+        changes: &[MajorShareholderChange],
+    ) -> Result<usize> {
+        let mut inserted = 0;
+        let mut tx = self.pool.begin().await?;
+
+        for change in changes {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO dart_major_shareholder_change
+                    (rcept_no, corp_code, ticker, receipt_date, report_type, reporter,
+                     shares, shares_change, ownership_pct, ownership_change_pct, reason)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                ON CONFLICT (rcept_no) DO NOTHING
+                "#,
+            )
+            .bind(&change.rcept_no)
+            .bind(&change.corp_code)
+            .bind(ticker)
+            .bind(change.receipt_date)
+            .bind(&change.report_type)
+            .bind(&change.reporter)
+            .bind(change.shares)
+            .bind(change.shares_change)
+            .bind(change.ownership_pct)
+            .bind(change.ownership_change_pct)
+            .bind(&change.reason)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+            inserted += result.rows_affected() as usize;
+        }
+
+        tx.commit().await?;
+        Ok(inserted)
+    }
+
+    /// 알림 대기 중인 공시 조회 (접수일 오름차순).
+    ///
+    /// `since` 이전 접수 공시는 제외하여 과거 공시 백필 시 알림이 쏟아지지 않도록 합니다.
+    pub async fn pending_alerts(
+        &self,
+        kinds: &[DisclosureKind],
+        since: NaiveDate,
+        limit: i64,
+    ) -> Result<Vec<PendingDisclosure>> {
+        if kinds.is_empty() {
+            return Ok(Vec::new());
+        }
+        let kinds: Vec<&str> = kinds.iter().map(DisclosureKind::as_str).collect();
+
+        let records: Vec<DisclosureRecord> = sqlx::query_as(
+            r#"
+            SELECT d.rcept_no, d.corp_code, d.corp_name, d.ticker, d.corp_cls, d.report_name,
+                   d.disclosure_type, d.receipt_date, d.filer_name, d.remark,
+                   price.close AS last_close
+            FROM dart_disclosure d
+            LEFT JOIN LATERAL (
+                SELECT o.close
+                FROM ohlcv o
+                WHERE o.symbol = d.ticker AND o.timeframe = '1d'
+                ORDER BY o.open_time DESC
+                LIMIT 1
+            ) price ON true
+            WHERE d.alerted_at IS NULL
+              AND d.ticker IS NOT NULL
+              AND d.disclosure_type = ANY($1)
+              AND d.receipt_date >= $2
+            ORDER BY d.receipt_date, d.rcept_no
+            LIMIT $3
+            "#,
+        )
+        .bind(&kinds)
+        .bind(since)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(DisclosureRecord::into_pending)
+            .collect())
+    }
+
+    /// 공시 알림 처리 완료 표시.
+    pub async fn mark_alerted(&self, rcept_nos: &[String]) -> Result<u64> {
+        if rcept_nos.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query(
+            "UPDATE dart_disclosure SET alerted_at = NOW() WHERE rcept_no = ANY($1) AND alerted_at IS NULL",
+        )
+        .bind(rcept_nos)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,128 @@
+//! 경제 이벤트 캘린더 저장소.
+//!
+//! 수집기(`trader-collector sync-economic-calendar`)가 ICS/CSV 피드와 규칙 기반 정기 일정에서
+//! 가져온 [`EconomicEvent`]를 저장하고, API 서버(전략 컨텍스트)와 백테스트는 기간별로
+//! 조회하여 [`EconomicCalendar`]로 사용합니다.
+
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgPool, FromRow};
+use tracing::instrument;
+use trader_core::{Country, EconomicCalendar, EconomicEvent, EconomicEventKind, EventImpact};
+
+use crate::error::{DataError, Result};
+
+/// DB 조회용 이벤트 레코드.
+#[derive(Debug, FromRow)]
+struct EconomicEventRecord {
+    kind: String,
+    title: String,
+    country: String,
+    impact: String,
+    scheduled_at: DateTime<Utc>,
+    source: Option<String>,
+}
+
+impl EconomicEventRecord {
+    fn into_event(self) -> EconomicEvent {
+        let kind = EconomicEventKind::parse(&self.kind)
+            .unwrap_or_else(|| EconomicEventKind::classify(&self.title));
+        EconomicEvent {
+            impact: EventImpact::parse(&self.impact).unwrap_or_else(|| kind.default_impact()),
+            country: Country::from_code(&self.country).unwrap_or(Country::Global),
+            kind,
+            title: self.title,
+            scheduled_at: self.scheduled_at,
+            source: self.source,
+        }
+    }
+}
+
+/// 경제 이벤트 저장소.
+#[derive(Clone)]
+pub struct EconomicEventRepository {
+    pool: PgPool,
+}
+
+impl EconomicEventRepository {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 이벤트 저장 (같은 유형/국가/시각은 제목/영향도/출처 갱신).
+    ///
+    /// # 반환
+    ///
+    /// 저장(삽입 또는 갱신)된 이벤트 수
+    #[instrument(skip(self, events), fields(count = events.len()))]
+    pub async fn upsert_events(&self, events: &[EconomicEvent]) -> Result<usize> {
+        let mut saved = 0;
+
+        for chunk in events.chunks(1000) {
+            let mut tx = self.pool.begin().await?;
+            for event in chunk {
+                let result = sqlx::query(
+                    r#"
+                    INSERT INTO economic_event (kind, title, country, impact, scheduled_at, source)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    ON CONFLICT (kind, country, scheduled_at) DO UPDATE SET
+                        title = EXCLUDED.title,
+                        impact = EXCLUDED.impact,
+                        source = EXCLUDED.source,
+                        updated_at = NOW()
+                    "#,
+                )
+                .bind(event.kind.as_str())
+                .bind(&event.title)
+                .bind(event.country.to_string())
+                .bind(event.impact.as_str())
+                .bind(event.scheduled_at)
+                .bind(&event.source)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DataError::InsertError(e.to_string()))?;
+                saved += result.rows_affected() as usize;
+            }
+            tx.commit().await?;
+        }
+
+        Ok(saved)
+    }
+
+    /// 기간 내 이벤트 조회 (시각 오름차순).
+    pub async fn events_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<EconomicEvent>> {
+        let records: Vec<EconomicEventRecord> = sqlx::query_as(
+            r#"
+            SELECT kind, title, country, impact, scheduled_at, source
+            FROM economic_event
+            WHERE scheduled_at BETWEEN $1 AND $2
+            ORDER BY scheduled_at, kind
+            "#,
+        )
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(EconomicEventRecord::into_event)
+            .collect())
+    }
+
+    /// 기간 내 이벤트로 캘린더 생성.
+    pub async fn load_calendar(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<EconomicCalendar> {
+        Ok(EconomicCalendar::from_events(
+            self.events_between(start, end).await?,
+        ))
+    }
+}
@@ -0,0 +1,186 @@
+//! 펀더멘털 시점(Point-in-Time) 이력 저장소.
+//!
+//! `symbol_fundamental`은 심볼당 최신값만 유지하므로, 갱신될 때마다 트리거가
+//! `symbol_fundamental_history`에 수집일(`as_of_date`) 기준 스냅샷을 남깁니다.
+//! 백테스트는 [`FundamentalHistory`]로 시뮬레이션 시점까지 알려진 값만 사용합니다.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgPool, FromRow};
+use tracing::instrument;
+
+use crate::error::{DataError, Result};
+
+/// 특정 시점의 펀더멘털 스냅샷.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct FundamentalSnapshot {
+    /// 종목 티커
+    pub ticker: String,
+    /// 값을 알 수 있게 된 날 (수집일)
+    pub as_of_date: NaiveDate,
+    /// 값이 가리키는 결산 기준일
+    pub report_date: Option<NaiveDate>,
+    /// 시가총액
+    pub market_cap: Option<Decimal>,
+    /// PER
+    pub per: Option<Decimal>,
+    /// PBR
+    pub pbr: Option<Decimal>,
+    /// PSR
+    pub psr: Option<Decimal>,
+    /// EPS
+    pub eps: Option<Decimal>,
+    /// BPS
+    pub bps: Option<Decimal>,
+    /// 배당수익률 (%)
+    pub dividend_yield: Option<Decimal>,
+    /// ROE (%)
+    pub roe: Option<Decimal>,
+    /// ROA (%)
+    pub roa: Option<Decimal>,
+    /// 영업이익률 (%)
+    pub operating_margin: Option<Decimal>,
+    /// 순이익률 (%)
+    pub net_profit_margin: Option<Decimal>,
+    /// 부채비율 (%)
+    pub debt_ratio: Option<Decimal>,
+    /// 매출 성장률 YoY (%)
+    pub revenue_growth_yoy: Option<Decimal>,
+    /// 이익 성장률 YoY (%)
+    pub earnings_growth_yoy: Option<Decimal>,
+    /// 데이터 소스
+    pub data_source: Option<String>,
+}
+
+/// 메모리 내 펀더멘털 이력 (티커별 `as_of_date` 오름차순).
+///
+/// 백테스트 시작 전에 한 번 로드하고, 매 시점 [`as_of`](Self::as_of)로 조회합니다.
+#[derive(Debug, Clone, Default)]
+pub struct FundamentalHistory {
+    by_ticker: HashMap<String, Vec<FundamentalSnapshot>>,
+}
+
+impl FundamentalHistory {
+    /// 스냅샷 목록으로 이력 생성 (순서 무관).
+    pub fn new(snapshots: Vec<FundamentalSnapshot>) -> Self {
+        let mut by_ticker: HashMap<String, Vec<FundamentalSnapshot>> = HashMap::new();
+        for snapshot in snapshots {
+            by_ticker
+                .entry(snapshot.ticker.clone())
+                .or_default()
+                .push(snapshot);
+        }
+        for snapshots in by_ticker.values_mut() {
+            snapshots.sort_by_key(|s| s.as_of_date);
+        }
+        Self { by_ticker }
+    }
+
+    /// `date` 시점까지 알려진 최신 스냅샷 (`as_of_date <= date`).
+    pub fn as_of(&self, ticker: &str, date: NaiveDate) -> Option<&FundamentalSnapshot> {
+        let snapshots = self.by_ticker.get(ticker)?;
+        let idx = snapshots.partition_point(|s| s.as_of_date <= date);
+        idx.checked_sub(1).map(|i| &snapshots[i])
+    }
+
+    /// 이력이 있는 티커 수.
+    pub fn len(&self) -> usize {
+        self.by_ticker.len()
+    }
+
+    /// 이력이 비어 있는지 여부.
+    pub fn is_empty(&self) -> bool {
+        self.by_ticker.is_empty()
+    }
+}
+
+const SNAPSHOT_COLUMNS: &str = r#"
+    si.ticker, h.as_of_date, h.report_date,
+    h.market_cap, h.per, h.pbr, h.psr, h.eps, h.bps, h.dividend_yield,
+    h.roe, h.roa, h.operating_margin, h.net_profit_margin, h.debt_ratio,
+    h.revenue_growth_yoy, h.earnings_growth_yoy, h.data_source
+"#;
+
+const SNAPSHOT_FROM: &str = r#"
+    FROM symbol_fundamental_history h
+    JOIN symbol_info si ON si.id = h.symbol_info_id
+"#;
+
+/// 펀더멘털 이력 저장소.
+#[derive(Clone)]
+pub struct FundamentalHistoryRepository {
+    pool: PgPool,
+}
+
+impl FundamentalHistoryRepository {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 기준일까지 알려진 티커별 최신 스냅샷 조회 (이력이 없는 티커는 제외).
+    #[instrument(skip(self, tickers), fields(count = tickers.len()))]
+    pub async fn as_of(
+        &self,
+        tickers: &[String],
+        date: NaiveDate,
+    ) -> Result<HashMap<String, FundamentalSnapshot>> {
+        if tickers.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let snapshots: Vec<FundamentalSnapshot> = sqlx::query_as(&format!(
+            "SELECT DISTINCT ON (si.ticker) {} {} \
+             WHERE si.ticker = ANY($1) AND h.as_of_date <= $2 \
+             ORDER BY si.ticker, h.as_of_date DESC",
+            SNAPSHOT_COLUMNS, SNAPSHOT_FROM
+        ))
+        .bind(tickers)
+        .bind(date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(snapshots
+            .into_iter()
+            .map(|s| (s.ticker.clone(), s))
+            .collect())
+    }
+
+    /// 백테스트 기간의 이력 로드.
+    ///
+    /// 기간 내 스냅샷과 함께 시작일 직전의 스냅샷을 포함하여
+    /// 시작 시점에도 그때까지 알려진 값을 사용할 수 있게 합니다.
+    #[instrument(skip(self, tickers), fields(count = tickers.len()))]
+    pub async fn load_history(
+        &self,
+        tickers: &[String],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<FundamentalHistory> {
+        if tickers.is_empty() {
+            return Ok(FundamentalHistory::default());
+        }
+        let mut snapshots: Vec<FundamentalSnapshot> =
+            self.as_of(tickers, start).await?.into_values().collect();
+
+        let in_range: Vec<FundamentalSnapshot> = sqlx::query_as(&format!(
+            "SELECT {} {} \
+             WHERE si.ticker = ANY($1) AND h.as_of_date > $2 AND h.as_of_date <= $3 \
+             ORDER BY si.ticker, h.as_of_date",
+            SNAPSHOT_COLUMNS, SNAPSHOT_FROM
+        ))
+        .bind(tickers)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        snapshots.extend(in_range);
+        Ok(FundamentalHistory::new(snapshots))
+    }
+}
@@ -0,0 +1,178 @@
+//! 환율 이력 저장소.
+//!
+//! 수집기가 기록한 일자별 환율을 저장하고, API 서버와 백테스트는 기간별로 조회하여
+//! [`FxRateTable`]로 사용합니다.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgPool, FromRow};
+use tracing::instrument;
+use trader_core::{normalize_currency, FxRateTable};
+
+use crate::error::{DataError, Result};
+
+/// 저장된 환율.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct StoredFxRate {
+    /// 기준 통화 (예: "USD")
+    pub base_currency: String,
+    /// 표시 통화 (예: "KRW")
+    pub quote_currency: String,
+    /// 환율 기준일
+    pub rate_date: NaiveDate,
+    /// 1 기준 통화 = rate 표시 통화
+    pub rate: Decimal,
+    /// 출처 (macro, provider, manual)
+    pub source: String,
+    /// 갱신 시각
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 환율 이력 저장소.
+#[derive(Clone)]
+pub struct FxRateRepository {
+    pool: PgPool,
+}
+
+impl FxRateRepository {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 환율 저장 (같은 통화쌍/기준일은 갱신).
+    pub async fn upsert(
+        &self,
+        base: &str,
+        quote: &str,
+        date: NaiveDate,
+        rate: Decimal,
+        source: &str,
+    ) -> Result<()> {
+        self.upsert_many(base, quote, &[(date, rate)], source)
+            .await
+            .map(|_| ())
+    }
+
+    /// 통화쌍의 일자별 환율 일괄 저장.
+    ///
+    /// # 반환
+    ///
+    /// 저장(삽입 또는 갱신)된 행 수
+    #[instrument(skip(self, rates), fields(count = rates.len()))]
+    pub async fn upsert_many(
+        &self,
+        base: &str,
+        quote: &str,
+        rates: &[(NaiveDate, Decimal)],
+        source: &str,
+    ) -> Result<usize> {
+        let (base, quote) = (normalize_currency(base), normalize_currency(quote));
+        let mut saved = 0;
+
+        for chunk in rates.chunks(1000) {
+            let mut tx = self.pool.begin().await?;
+            for (date, rate) in chunk.iter().filter(|(_, rate)| *rate > Decimal::ZERO) {
+                let result = sqlx::query(
+                    r#"
+                    INSERT INTO fx_rates (base_currency, quote_currency, rate_date, rate, source)
+                    VALUES ($1, $2, $3, $4, $5)
+                    ON CONFLICT (base_currency, quote_currency, rate_date) DO UPDATE SET
+                        rate = EXCLUDED.rate,
+                        source = EXCLUDED.source,
+                        updated_at = NOW()
+                    "#,
+                )
+                .bind(&base)
+                .bind(&quote)
+                .bind(date)
+                .bind(rate)
+                .bind(source)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DataError::InsertError(e.to_string()))?;
+                saved += result.rows_affected() as usize;
+            }
+            tx.commit().await?;
+        }
+
+        Ok(saved)
+    }
+
+    /// 기간 내 환율 조회 (`start`가 없으면 전체).
+    pub async fn rates_between(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<StoredFxRate>> {
+        sqlx::query_as(
+            r#"
+            SELECT base_currency, quote_currency, rate_date, rate, source, updated_at
+            FROM fx_rates
+            WHERE ($1::DATE IS NULL OR rate_date >= $1)
+              AND ($2::DATE IS NULL OR rate_date <= $2)
+            ORDER BY base_currency, quote_currency, rate_date
+            "#,
+        )
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 통화쌍별 최신 환율 조회.
+    pub async fn latest_rates(&self) -> Result<Vec<StoredFxRate>> {
+        sqlx::query_as(
+            r#"
+            SELECT DISTINCT ON (base_currency, quote_currency)
+                base_currency, quote_currency, rate_date, rate, source, updated_at
+            FROM fx_rates
+            ORDER BY base_currency, quote_currency, rate_date DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 기간 내 환율로 환율 테이블 생성.
+    ///
+    /// as-of 조회가 기간 시작일에도 동작하도록 `start` 이전 가장 최근 환율을 함께 포함합니다.
+    pub async fn load_table(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<FxRateTable> {
+        let mut table = FxRateTable::new();
+        let mut rates = self.rates_between(start, end).await?;
+
+        if let Some(start) = start {
+            let anchors: Vec<StoredFxRate> = sqlx::query_as(
+                r#"
+                SELECT DISTINCT ON (base_currency, quote_currency)
+                    base_currency, quote_currency, rate_date, rate, source, updated_at
+                FROM fx_rates
+                WHERE rate_date < $1
+                ORDER BY base_currency, quote_currency, rate_date DESC
+                "#,
+            )
+            .bind(start)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DataError::QueryError(e.to_string()))?;
+            rates.extend(anchors);
+        }
+
+        for rate in &rates {
+            table.insert(
+                &rate.base_currency,
+                &rate.quote_currency,
+                rate.rate_date,
+                rate.rate,
+            );
+        }
+        Ok(table)
+    }
+}
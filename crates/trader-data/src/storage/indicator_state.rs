@@ -0,0 +1,134 @@
+//! 증분 지표 상태 저장소.
+//!
+//! `trader-analytics::indicators::IndicatorState`의 직렬화 값을 종목/타임프레임 단위로
+//! 보관합니다. 상태 구조는 analytics 크레이트가 소유하므로 여기서는 JSON 값으로만 다룹니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgPool, FromRow};
+
+use crate::error::{DataError, Result};
+
+/// 저장된 지표 상태.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct StoredIndicatorState {
+    /// 종목 티커
+    pub ticker: String,
+    /// 타임프레임 (예: "1d")
+    pub timeframe: String,
+    /// 지표 파라미터 키
+    pub config_key: String,
+    /// 반영된 캔들 수
+    pub bars: i64,
+    /// 마지막으로 반영된 캔들 시작 시각
+    pub last_open_time: Option<DateTime<Utc>>,
+    /// 직렬화된 상태
+    pub state: serde_json::Value,
+    /// 갱신 시각
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 증분 지표 상태 저장소.
+#[derive(Clone)]
+pub struct IndicatorStateRepository {
+    pool: PgPool,
+}
+
+impl IndicatorStateRepository {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 특정 종목/타임프레임 상태 조회.
+    pub async fn load(
+        &self,
+        ticker: &str,
+        timeframe: &str,
+    ) -> Result<Option<StoredIndicatorState>> {
+        sqlx::query_as(
+            r#"
+            SELECT ticker, timeframe, config_key, bars, last_open_time, state, updated_at
+            FROM indicator_state
+            WHERE ticker = $1 AND timeframe = $2
+            "#,
+        )
+        .bind(ticker)
+        .bind(timeframe)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 전체 상태 조회 (타임프레임 필터 선택).
+    pub async fn load_all(&self, timeframe: Option<&str>) -> Result<Vec<StoredIndicatorState>> {
+        sqlx::query_as(
+            r#"
+            SELECT ticker, timeframe, config_key, bars, last_open_time, state, updated_at
+            FROM indicator_state
+            WHERE ($1::TEXT IS NULL OR timeframe = $1)
+            ORDER BY ticker, timeframe
+            "#,
+        )
+        .bind(timeframe)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 상태 저장 (종목/타임프레임 단위 upsert).
+    pub async fn save(
+        &self,
+        ticker: &str,
+        timeframe: &str,
+        config_key: &str,
+        bars: i64,
+        last_open_time: Option<DateTime<Utc>>,
+        state: &serde_json::Value,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO indicator_state
+                (ticker, timeframe, config_key, bars, last_open_time, state, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, NOW())
+            ON CONFLICT (ticker, timeframe) DO UPDATE SET
+                config_key = EXCLUDED.config_key,
+                bars = EXCLUDED.bars,
+                last_open_time = EXCLUDED.last_open_time,
+                state = EXCLUDED.state,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(ticker)
+        .bind(timeframe)
+        .bind(config_key)
+        .bind(bars)
+        .bind(last_open_time)
+        .bind(state)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// 상태 삭제 (다음 동기화에서 전체 이력으로 다시 워밍업).
+    ///
+    /// # 반환
+    ///
+    /// 삭제된 행 수
+    pub async fn delete(&self, ticker: Option<&str>, timeframe: Option<&str>) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM indicator_state
+            WHERE ($1::TEXT IS NULL OR ticker = $1)
+              AND ($2::TEXT IS NULL OR timeframe = $2)
+            "#,
+        )
+        .bind(ticker)
+        .bind(timeframe)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::DeleteError(e.to_string()))?;
+        Ok(result.rows_affected())
+    }
+}
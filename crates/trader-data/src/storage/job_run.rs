@@ -0,0 +1,334 @@
+//! 수집기 작업 실행 이력 저장소.
+//!
+//! 수집기 작업 오케스트레이터(`trader-collector`)가 작업 시도마다 시작/종료, 처리 행 수,
+//! 오류를 기록하고, API 서버는 작업별 최근 상태와 실행 이력을 조회합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgPool, FromRow};
+
+use crate::error::{DataError, Result};
+
+/// 작업 실행 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobRunStatus {
+    /// 실행 중
+    Running,
+    /// 성공 (개별 항목 오류가 있어도 작업 자체는 완료)
+    Success,
+    /// 실패 (재시도 소진 전 시도 포함)
+    Failed,
+    /// 선행 작업 실패로 건너뜀
+    Skipped,
+    /// 프로세스 종료 등으로 중단됨
+    Interrupted,
+}
+
+impl JobRunStatus {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Success => "success",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+            Self::Interrupted => "interrupted",
+        }
+    }
+
+    /// 문자열에서 파싱.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "running" => Some(Self::Running),
+            "success" => Some(Self::Success),
+            "failed" => Some(Self::Failed),
+            "skipped" => Some(Self::Skipped),
+            "interrupted" => Some(Self::Interrupted),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for JobRunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 작업 실행 원인.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobTrigger {
+    /// cron 일정
+    Schedule,
+    /// 선행 작업 완료에 따른 연쇄 실행
+    Dependency,
+    /// CLI 수동 실행
+    Manual,
+}
+
+impl JobTrigger {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Schedule => "schedule",
+            Self::Dependency => "dependency",
+            Self::Manual => "manual",
+        }
+    }
+
+    /// 문자열에서 파싱.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "schedule" => Some(Self::Schedule),
+            "dependency" => Some(Self::Dependency),
+            "manual" => Some(Self::Manual),
+            _ => None,
+        }
+    }
+}
+
+/// 작업 실행 기록 (시도 단위).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectorJobRun {
+    /// 실행 ID
+    pub id: i64,
+    /// 작업 이름
+    pub job_name: String,
+    /// 실행 원인
+    pub trigger: JobTrigger,
+    /// 상태
+    pub status: JobRunStatus,
+    /// 시도 횟수 (1부터)
+    pub attempt: i32,
+    /// 시작 시각
+    pub started_at: DateTime<Utc>,
+    /// 종료 시각 (실행 중이면 None)
+    pub finished_at: Option<DateTime<Utc>>,
+    /// 삽입/갱신된 행 수
+    pub rows_touched: i64,
+    /// 개별 항목 오류 수
+    pub error_count: i64,
+    /// 실패/건너뜀 사유
+    pub error_message: Option<String>,
+}
+
+impl CollectorJobRun {
+    /// 소요 시간 (밀리초, 실행 중이면 None).
+    pub fn duration_ms(&self) -> Option<i64> {
+        self.finished_at
+            .map(|finished| (finished - self.started_at).num_milliseconds())
+    }
+}
+
+/// DB 조회용 실행 레코드.
+#[derive(Debug, FromRow)]
+struct JobRunRecord {
+    id: i64,
+    job_name: String,
+    trigger: String,
+    status: String,
+    attempt: i32,
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+    rows_touched: i64,
+    error_count: i64,
+    error_message: Option<String>,
+}
+
+impl From<JobRunRecord> for CollectorJobRun {
+    fn from(record: JobRunRecord) -> Self {
+        Self {
+            id: record.id,
+            job_name: record.job_name,
+            trigger: JobTrigger::parse(&record.trigger).unwrap_or(JobTrigger::Manual),
+            status: JobRunStatus::parse(&record.status).unwrap_or(JobRunStatus::Interrupted),
+            attempt: record.attempt,
+            started_at: record.started_at,
+            finished_at: record.finished_at,
+            rows_touched: record.rows_touched,
+            error_count: record.error_count,
+            error_message: record.error_message,
+        }
+    }
+}
+
+const JOB_RUN_COLUMNS: &str = "id, job_name, trigger, status, attempt, started_at, finished_at, \
+                               rows_touched, error_count, error_message";
+
+/// 수집기 작업 실행 이력 저장소.
+#[derive(Clone)]
+pub struct CollectorJobRunRepository {
+    pool: PgPool,
+}
+
+impl CollectorJobRunRepository {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 실행 시작 기록.
+    ///
+    /// # 반환
+    ///
+    /// 실행 ID (종료 시 [`finish`](Self::finish)에 전달)
+    pub async fn start(&self, job_name: &str, trigger: JobTrigger, attempt: i32) -> Result<i64> {
+        sqlx::query_scalar(
+            r#"
+            INSERT INTO collector_job_run (job_name, trigger, status, attempt)
+            VALUES ($1, $2, 'running', $3)
+            RETURNING id
+            "#,
+        )
+        .bind(job_name)
+        .bind(trigger.as_str())
+        .bind(attempt)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))
+    }
+
+    /// 실행 종료 기록.
+    pub async fn finish(
+        &self,
+        id: i64,
+        status: JobRunStatus,
+        rows_touched: i64,
+        error_count: i64,
+        error_message: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE collector_job_run
+            SET status = $2, finished_at = NOW(), rows_touched = $3,
+                error_count = $4, error_message = $5
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(status.as_str())
+        .bind(rows_touched)
+        .bind(error_count)
+        .bind(error_message)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// 건너뛴 실행 기록 (시작과 동시에 종료).
+    pub async fn record_skipped(
+        &self,
+        job_name: &str,
+        trigger: JobTrigger,
+        reason: &str,
+    ) -> Result<i64> {
+        sqlx::query_scalar(
+            r#"
+            INSERT INTO collector_job_run
+                (job_name, trigger, status, attempt, finished_at, error_message)
+            VALUES ($1, $2, 'skipped', 0, NOW(), $3)
+            RETURNING id
+            "#,
+        )
+        .bind(job_name)
+        .bind(trigger.as_str())
+        .bind(reason)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))
+    }
+
+    /// 종료되지 않은 실행을 중단 상태로 표시 (프로세스 재시작 시).
+    ///
+    /// # 반환
+    ///
+    /// 중단 처리된 실행 수
+    pub async fn mark_interrupted(&self) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            UPDATE collector_job_run
+            SET status = 'interrupted', finished_at = NOW(),
+                error_message = COALESCE(error_message, 'collector restarted')
+            WHERE status = 'running'
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+        Ok(result.rows_affected())
+    }
+
+    /// 실행 이력 조회 (최신순).
+    pub async fn list(
+        &self,
+        job_name: Option<&str>,
+        status: Option<JobRunStatus>,
+        limit: i64,
+    ) -> Result<Vec<CollectorJobRun>> {
+        let query = format!(
+            r#"
+            SELECT {JOB_RUN_COLUMNS}
+            FROM collector_job_run
+            WHERE ($1::TEXT IS NULL OR job_name = $1)
+              AND ($2::TEXT IS NULL OR status = $2)
+            ORDER BY started_at DESC, id DESC
+            LIMIT $3
+            "#
+        );
+        let records: Vec<JobRunRecord> = sqlx::query_as(&query)
+            .bind(job_name)
+            .bind(status.map(|s| s.as_str()))
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records.into_iter().map(CollectorJobRun::from).collect())
+    }
+
+    /// 작업별 가장 최근 실행 (작업 이름순).
+    pub async fn latest_by_job(&self) -> Result<Vec<CollectorJobRun>> {
+        let query = format!(
+            r#"
+            SELECT DISTINCT ON (job_name) {JOB_RUN_COLUMNS}
+            FROM collector_job_run
+            ORDER BY job_name, started_at DESC, id DESC
+            "#
+        );
+        let records: Vec<JobRunRecord> = sqlx::query_as(&query)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records.into_iter().map(CollectorJobRun::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_and_trigger_round_trip() {
+        for status in [
+            JobRunStatus::Running,
+            JobRunStatus::Success,
+            JobRunStatus::Failed,
+            JobRunStatus::Skipped,
+            JobRunStatus::Interrupted,
+        ] {
+            assert_eq!(JobRunStatus::parse(status.as_str()), Some(status));
+        }
+        for trigger in [
+            JobTrigger::Schedule,
+            JobTrigger::Dependency,
+            JobTrigger::Manual,
+        ] {
+            assert_eq!(JobTrigger::parse(trigger.as_str()), Some(trigger));
+        }
+        assert_eq!(JobRunStatus::parse("unknown"), None);
+    }
+}
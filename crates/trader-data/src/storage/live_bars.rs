@@ -0,0 +1,104 @@
+//! 실시간 바 빌더 출력 저장소.
+//!
+//! [`BarBuilder`](crate::bar_builder::BarBuilder)가 집계한 결과를 저장합니다.
+//! - 체결 틱 → `trade_ticks` (TradeTickRepository)
+//! - 마감된 시간 캔들 → `ohlcv` (OhlcvCache, 수집기 캔들과 같은 테이블)
+//!
+//! 정보 바(거래량/틱/거래대금)는 시간 키가 없어 `ohlcv`에 저장하지 않습니다.
+//! 필요하면 저장된 체결 틱에서 다시 만들 수 있습니다.
+
+use std::collections::{BTreeMap, HashMap};
+
+use tokio::sync::RwLock;
+use tracing::debug;
+use trader_core::{Kline, Timeframe, TradeTick};
+use uuid::Uuid;
+
+use crate::{
+    bar_builder::BuiltBar,
+    error::Result,
+    storage::{
+        ohlcv::OhlcvCache,
+        timescale::{Database, SymbolRepository, TradeTickRepository},
+    },
+};
+
+/// 실시간 체결/캔들 저장소.
+pub struct LiveBarStore {
+    symbols: SymbolRepository,
+    trade_ticks: TradeTickRepository,
+    ohlcv: OhlcvCache,
+    /// (거래소, 티커) → 심볼 ID 캐시
+    symbol_ids: RwLock<HashMap<(String, String), Uuid>>,
+}
+
+impl LiveBarStore {
+    /// 새 저장소 생성.
+    pub fn new(db: Database) -> Self {
+        Self {
+            symbols: SymbolRepository::new(db.clone()),
+            trade_ticks: TradeTickRepository::new(db.clone()),
+            ohlcv: OhlcvCache::new(db.pool().clone()),
+            symbol_ids: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 체결 틱 저장 (티커별 일괄 삽입). 새로 저장된 틱 수를 반환합니다.
+    pub async fn record_trades(&self, exchange: &str, trades: &[TradeTick]) -> Result<usize> {
+        let mut by_ticker: BTreeMap<&str, Vec<TradeTick>> = BTreeMap::new();
+        for trade in trades {
+            by_ticker
+                .entry(trade.ticker.as_str())
+                .or_default()
+                .push(trade.clone());
+        }
+
+        let mut inserted = 0;
+        for (ticker, ticks) in by_ticker {
+            let symbol_id = self.symbol_id(ticker, exchange).await?;
+            inserted += self.trade_ticks.insert_batch(symbol_id, &ticks).await?;
+        }
+        Ok(inserted)
+    }
+
+    /// 마감된 시간 캔들 저장 (티커/타임프레임별 일괄 저장). 저장된 캔들 수를 반환합니다.
+    ///
+    /// `symbol_info`에 없는 티커는 OhlcvCache 규칙대로 저장하지 않습니다.
+    pub async fn record_bars(&self, bars: &[BuiltBar]) -> Result<usize> {
+        let mut by_series: BTreeMap<(&str, String), (Timeframe, Vec<Kline>)> = BTreeMap::new();
+        for bar in bars {
+            let Some(timeframe) = bar.spec.timeframe() else {
+                continue;
+            };
+            by_series
+                .entry((bar.kline.ticker.as_str(), timeframe.to_string()))
+                .or_insert_with(|| (timeframe, Vec::new()))
+                .1
+                .push(bar.kline.clone());
+        }
+
+        let mut saved = 0;
+        for ((ticker, _), (timeframe, klines)) in by_series {
+            saved += self.ohlcv.save_klines(ticker, timeframe, &klines).await?;
+        }
+        debug!(bars = bars.len(), saved, "실시간 캔들 저장");
+        Ok(saved)
+    }
+
+    /// 심볼 ID 조회 (없으면 생성).
+    async fn symbol_id(&self, ticker: &str, exchange: &str) -> Result<Uuid> {
+        let key = (exchange.to_string(), ticker.to_string());
+        if let Some(id) = self.symbol_ids.read().await.get(&key) {
+            return Ok(*id);
+        }
+
+        // DataManager::get_symbol_id와 같은 기본값 (quote는 거래소 기준 추정)
+        let quote = if exchange == "kis" { "KRW" } else { "USD" };
+        let id = self
+            .symbols
+            .get_or_create(ticker, quote, "stock", exchange)
+            .await?;
+        self.symbol_ids.write().await.insert(key, id);
+        Ok(id)
+    }
+}
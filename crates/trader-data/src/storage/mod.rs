@@ -1,6 +1,16 @@
 //! 데이터 저장소 구현.
 
+pub mod disclosure;
+pub mod economic_event;
+pub mod fundamental_history;
+pub mod fx_rate;
+pub mod indicator_state;
+pub mod job_run;
 pub mod krx;
+pub mod live_bars;
 pub mod ohlcv;
+pub mod parquet;
+pub mod quality;
 pub mod redis;
+pub mod tiering;
 pub mod timescale;
@@ -10,6 +10,9 @@
 //! 3. 새 데이터를 DB에 저장 (증분 업데이트)
 //! 4. 캐시된 데이터 반환
 //!
+//! 아카이브 루트(`STORAGE_ARCHIVE_DIR`)가 설정되면 기간 조회는 계층형 저장소
+//! ([`super::tiering`])가 Parquet으로 옮긴 과거 분봉도 함께 읽습니다.
+//!
 //! # 사용 예제
 //!
 //! ```rust,ignore
@@ -19,13 +22,21 @@
 //! let klines = cache.get_klines("AAPL", Timeframe::D1, 100).await?;
 //! ```
 
-use chrono::{DateTime, Duration, Utc};
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Duration, Utc};
 use rust_decimal::Decimal;
 use sqlx::{postgres::PgPool, FromRow};
 use tracing::{debug, info, instrument, warn};
 use trader_core::{Kline, Timeframe};
 
-use crate::error::{DataError, Result};
+use crate::{
+    error::{DataError, Result},
+    storage::{
+        parquet::{ParquetDataset, ParquetStore},
+        tiering::{archive_store_from_env, ArchiveManifestRepository},
+    },
+};
 
 /// OHLCV 캔들 데이터베이스 레코드.
 #[derive(Debug, Clone, FromRow)]
@@ -122,12 +133,31 @@ pub struct OhlcvMetadataRecord {
 #[derive(Clone)]
 pub struct OhlcvCache {
     pool: PgPool,
+    /// 계층형 저장소 아카이브 (None이면 DB만 조회)
+    archive: Option<ParquetStore>,
 }
 
 impl OhlcvCache {
     /// 새로운 캐시 서비스 생성.
+    ///
+    /// `STORAGE_ARCHIVE_DIR`이 설정되어 있으면 아카이브 조회를 활성화합니다.
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            archive: archive_store_from_env(),
+        }
+    }
+
+    /// 아카이브 저장소 설정.
+    pub fn with_archive(mut self, store: ParquetStore) -> Self {
+        self.archive = Some(store);
+        self
+    }
+
+    /// 아카이브 조회 비활성화 (DB만 조회).
+    pub fn without_archive(mut self) -> Self {
+        self.archive = None;
+        self
     }
 
     /// 캐시에서 캔들 데이터 조회.
@@ -255,6 +285,9 @@ impl OhlcvCache {
     }
 
     /// 특정 시간 범위의 캔들 조회.
+    ///
+    /// 아카이브가 설정되어 있고 기간이 아카이브 구간과 겹치면 Parquet 아카이브를 함께 읽어
+    /// 합칩니다 (같은 시각은 DB 캔들 우선). 아카이브 조회에 실패하면 DB 데이터만 반환합니다.
     #[instrument(skip(self))]
     pub async fn get_cached_klines_range(
         &self,
@@ -262,6 +295,42 @@ impl OhlcvCache {
         timeframe: Timeframe,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
+    ) -> Result<Vec<Kline>> {
+        let klines = self
+            .get_stored_klines_range(symbol, timeframe, start, end)
+            .await?;
+
+        let Some(store) = &self.archive else {
+            return Ok(klines);
+        };
+        match self
+            .read_archived_klines(store, symbol, timeframe, start, end)
+            .await
+        {
+            Ok(archived) if !archived.is_empty() => {
+                debug!(
+                    symbol = symbol,
+                    archived = archived.len(),
+                    stored = klines.len(),
+                    "아카이브 캔들 병합"
+                );
+                Ok(merge_klines(archived, klines))
+            }
+            Ok(_) => Ok(klines),
+            Err(e) => {
+                warn!(symbol = symbol, error = %e, "아카이브 캔들 조회 실패 (DB 데이터만 사용)");
+                Ok(klines)
+            }
+        }
+    }
+
+    /// 특정 시간 범위의 캔들 조회 (DB만, 아카이브 제외).
+    pub async fn get_stored_klines_range(
+        &self,
+        symbol: &str,
+        timeframe: Timeframe,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
     ) -> Result<Vec<Kline>> {
         let tf_str = timeframe_to_string(timeframe);
 
@@ -286,6 +355,74 @@ impl OhlcvCache {
         Ok(klines)
     }
 
+    /// 아카이브된 캔들 조회 (아카이브 목록에 겹치는 구간이 없으면 빈 목록).
+    async fn read_archived_klines(
+        &self,
+        store: &ParquetStore,
+        symbol: &str,
+        timeframe: Timeframe,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Kline>> {
+        let tf_str = timeframe_to_string(timeframe);
+        let Some(market) = ArchiveManifestRepository::new(self.pool.clone())
+            .find_market(ParquetDataset::Ohlcv, symbol, &tf_str, start, end)
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+
+        // Parquet 읽기는 파일 입출력이므로 blocking 스레드에서 실행
+        let store = store.clone();
+        let symbol = symbol.to_string();
+        let klines: Vec<Kline> = tokio::task::spawn_blocking(move || {
+            store.read(
+                ParquetDataset::Ohlcv,
+                &market,
+                &symbol,
+                Some((start.year(), end.year())),
+            )
+        })
+        .await
+        .map_err(|e| DataError::IoError(e.to_string()))??;
+
+        Ok(klines
+            .into_iter()
+            .filter(|k| {
+                timeframe_to_string(k.timeframe) == tf_str
+                    && k.open_time >= start
+                    && k.open_time < end
+            })
+            .collect())
+    }
+
+    /// 특정 시간 범위의 캔들 삭제 (계층형 저장소 아카이브 후 정리용).
+    pub async fn delete_stored_klines_range(
+        &self,
+        symbol: &str,
+        timeframe: Timeframe,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<u64> {
+        let tf_str = timeframe_to_string(timeframe);
+
+        let result = sqlx::query(
+            r#"
+            DELETE FROM ohlcv
+            WHERE symbol = $1 AND timeframe = $2 AND open_time >= $3 AND open_time < $4
+            "#,
+        )
+        .bind(symbol)
+        .bind(&tf_str)
+        .bind(start)
+        .bind(end)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::DeleteError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+
     /// 캔들 데이터를 캐시에 저장.
     ///
     /// ON CONFLICT로 중복 데이터 자동 처리.
@@ -590,6 +727,15 @@ fn timeframe_to_duration(timeframe: Timeframe) -> Duration {
     }
 }
 
+/// 아카이브 캔들과 DB 캔들을 시간순으로 병합 (같은 시각은 DB 캔들 우선).
+fn merge_klines(archived: Vec<Kline>, stored: Vec<Kline>) -> Vec<Kline> {
+    let mut merged: BTreeMap<DateTime<Utc>, Kline> = BTreeMap::new();
+    for kline in archived.into_iter().chain(stored) {
+        merged.insert(kline.open_time, kline);
+    }
+    merged.into_values().collect()
+}
+
 /// 분봉/시간봉인지 확인.
 fn is_intraday(timeframe: Timeframe) -> bool {
     matches!(
@@ -634,6 +780,32 @@ mod tests {
         assert!(!is_intraday(Timeframe::W1));
     }
 
+    #[test]
+    fn test_merge_klines() {
+        let kline = |minute: i64, close: Decimal| {
+            let open_time = DateTime::<Utc>::UNIX_EPOCH + Duration::minutes(minute);
+            Kline {
+                ticker: "005930".to_string(),
+                timeframe: Timeframe::M1,
+                open_time,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                volume: Decimal::ONE,
+                close_time: open_time + Duration::minutes(1),
+                quote_volume: None,
+                num_trades: None,
+            }
+        };
+
+        let archived = vec![kline(0, Decimal::ONE), kline(1, Decimal::ONE)];
+        let stored = vec![kline(1, Decimal::TWO), kline(2, Decimal::TWO)];
+        let merged = merge_klines(archived, stored);
+        let closes: Vec<Decimal> = merged.iter().map(|k| k.close).collect();
+        assert_eq!(closes, vec![Decimal::ONE, Decimal::TWO, Decimal::TWO]);
+    }
+
     #[test]
     fn test_guess_currency() {
         assert_eq!(guess_currency("005930.KS"), "KRW");
@@ -0,0 +1,1282 @@
+//! Parquet(Arrow) 내보내기/가져오기.
+//!
+//! 리서치 환경(Python/Polars)과 데이터를 주고받기 위한 컬럼 포맷 저장소입니다.
+//! 파일은 Hive 스타일로 분할되어 `pl.scan_parquet(..., hive_partitioning=True)`로
+//! 바로 읽을 수 있습니다.
+//!
+//! ```text
+//! {root}/{dataset}/market={market}/symbol={symbol}/year={year}/data.parquet
+//! {root}/{dataset}/market={market}/symbol={symbol}/year={year}/{year}-{month}.parquet
+//! ```
+//!
+//! 월 단위 파일은 [`ParquetStore::write_monthly`]가 만들며(계층형 저장소 아카이브),
+//! 해당 월 파일만 다시 쓰므로 같은 연도의 다른 월 데이터를 읽고 쓰지 않습니다.
+//!
+//! # 컬럼 규칙
+//!
+//! - 시각 컬럼은 `Datetime[ms]` (UTC, 타임존 없음)
+//! - 가격/수량 등 `Decimal` 값은 `Float64`로 저장 (가져올 때 `Decimal`로 복원)
+//! - 같은 파티션에 다시 쓰면 기존 파일과 병합하며, 키가 같은 행은 새 값으로 대체
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! let store = ParquetStore::new("./exports");
+//! store.write_klines("KR", &klines)?;
+//! let loaded = store.read_klines("KR", "005930", Timeframe::D1, start, end)?;
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs::{self, File},
+    io::Write,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, Datelike, Utc};
+use polars::{
+    io::{mmap::MmapBytesReader, parquet::write::BatchedWriter},
+    prelude::*,
+};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use trader_core::{Kline, Side, Timeframe, TradeTick};
+
+use crate::error::{DataError, Result};
+
+/// 파티션 파일 이름.
+const PARTITION_FILE: &str = "data.parquet";
+
+// ==================== 데이터셋/파티션 ====================
+
+/// 내보내기 데이터셋 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParquetDataset {
+    /// OHLCV 캔들
+    Ohlcv,
+    /// 체결 틱
+    TradeTicks,
+    /// 백테스트 라운드트립 거래
+    BacktestTrades,
+    /// 백테스트 자산 곡선
+    BacktestEquity,
+    /// 스크리닝 스냅샷
+    Screening,
+}
+
+impl ParquetDataset {
+    /// 모든 데이터셋.
+    pub const ALL: [ParquetDataset; 5] = [
+        Self::Ohlcv,
+        Self::TradeTicks,
+        Self::BacktestTrades,
+        Self::BacktestEquity,
+        Self::Screening,
+    ];
+
+    /// 디렉토리 이름으로 사용되는 식별자.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ohlcv => "ohlcv",
+            Self::TradeTicks => "trade_ticks",
+            Self::BacktestTrades => "backtest_trades",
+            Self::BacktestEquity => "backtest_equity",
+            Self::Screening => "screening",
+        }
+    }
+}
+
+impl fmt::Display for ParquetDataset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ParquetDataset {
+    type Err = DataError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let normalized = s.trim().to_ascii_lowercase().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|dataset| dataset.as_str() == normalized)
+            .ok_or_else(|| {
+                DataError::InvalidData(format!(
+                    "알 수 없는 데이터셋: {} (지원: ohlcv, trade_ticks, backtest_trades, backtest_equity, screening)",
+                    s
+                ))
+            })
+    }
+}
+
+/// 단일 Parquet 파티션 (market/symbol/year, 월 단위 파일이면 month 포함).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParquetPartition {
+    /// 데이터셋
+    pub dataset: ParquetDataset,
+    /// 시장 (예: KR, US, CRYPTO)
+    pub market: String,
+    /// 심볼
+    pub symbol: String,
+    /// 연도
+    pub year: i32,
+    /// 월 (월 단위 파일인 경우)
+    pub month: Option<u32>,
+}
+
+impl ParquetPartition {
+    /// 새 파티션 생성.
+    pub fn new(
+        dataset: ParquetDataset,
+        market: impl Into<String>,
+        symbol: impl Into<String>,
+        year: i32,
+    ) -> Self {
+        Self {
+            dataset,
+            market: market.into(),
+            symbol: symbol.into(),
+            year,
+            month: None,
+        }
+    }
+
+    /// 월 단위 파일로 지정.
+    pub fn with_month(mut self, month: u32) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// 루트 기준 상대 경로.
+    ///
+    /// 심볼의 경로 구분자(`/`, `\`, `:`)는 `_`로 치환됩니다 (예: `BTC/USDT` → `BTC_USDT`).
+    pub fn relative_path(&self) -> PathBuf {
+        let file = match self.month {
+            Some(month) => monthly_file_name(self.year, month),
+            None => PARTITION_FILE.to_string(),
+        };
+        symbol_dir(self.dataset, &self.market, &self.symbol)
+            .join(format!("year={}", self.year))
+            .join(file)
+    }
+}
+
+fn monthly_file_name(year: i32, month: u32) -> String {
+    format!("{}-{:02}.parquet", year, month)
+}
+
+/// 월 단위 파일 이름(`{year}-{month}.parquet`)에서 월 추출.
+fn parse_monthly_file_name(year: i32, name: &str) -> Option<u32> {
+    let month = name
+        .strip_prefix(&format!("{}-", year))?
+        .strip_suffix(".parquet")?;
+    month
+        .parse()
+        .ok()
+        .filter(|m| (1..=12).contains(m) && month.len() == 2)
+}
+
+fn market_dir(dataset: ParquetDataset, market: &str) -> PathBuf {
+    PathBuf::from(dataset.as_str()).join(format!("market={}", market.to_uppercase()))
+}
+
+fn symbol_dir(dataset: ParquetDataset, market: &str, symbol: &str) -> PathBuf {
+    market_dir(dataset, market).join(format!("symbol={}", sanitize_symbol(symbol)))
+}
+
+fn sanitize_symbol(symbol: &str) -> String {
+    symbol
+        .trim()
+        .chars()
+        .map(|c| {
+            if matches!(c, '/' | '\\' | ':') {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+// ==================== 레코드 ====================
+
+/// Parquet 파일로 저장할 수 있는 레코드.
+///
+/// 각 레코드는 자신이 속할 파티션(심볼/연도)과 병합 시 중복을 판별할 키를 제공합니다.
+pub trait ParquetRecord: Sized {
+    /// 파티션 심볼.
+    fn partition_symbol(&self) -> &str;
+
+    /// 파티션 연도 기준 시각.
+    fn partition_time(&self) -> DateTime<Utc>;
+
+    /// 병합 시 정렬/중복 제거 키 (같은 키는 새 레코드로 대체).
+    fn merge_key(&self) -> (i64, String);
+
+    /// 레코드를 DataFrame으로 변환.
+    fn to_frame(rows: &[Self]) -> PolarsResult<DataFrame>;
+
+    /// DataFrame에서 레코드 복원.
+    fn from_frame(df: &DataFrame) -> PolarsResult<Vec<Self>>;
+}
+
+/// 백테스트 라운드트립 거래 행.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BacktestTradeRow {
+    /// 백테스트 실행 식별자
+    pub run_id: String,
+    /// 거래 심볼
+    pub symbol: String,
+    /// 거래 방향
+    pub side: Side,
+    /// 진입 시각
+    pub entry_time: DateTime<Utc>,
+    /// 청산 시각
+    pub exit_time: DateTime<Utc>,
+    /// 진입 가격
+    pub entry_price: Decimal,
+    /// 청산 가격
+    pub exit_price: Decimal,
+    /// 수량
+    pub quantity: Decimal,
+    /// 수수료 합계
+    pub fees: Decimal,
+    /// 실현 손익
+    pub pnl: Decimal,
+    /// 수익률 (%)
+    pub return_pct: Decimal,
+    /// 전략 ID
+    pub strategy_id: Option<String>,
+}
+
+/// 백테스트 자산 곡선 행.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BacktestEquityRow {
+    /// 백테스트 실행 식별자
+    pub run_id: String,
+    /// 백테스트 대상 심볼 (파티션 키)
+    pub symbol: String,
+    /// 시각
+    pub timestamp: DateTime<Utc>,
+    /// 자산 가치
+    pub equity: Decimal,
+    /// 고점 대비 낙폭 (%)
+    pub drawdown_pct: Decimal,
+}
+
+/// 스크리닝 스냅샷 행.
+///
+/// 특정 시점의 스크리닝 결과 중 리서치에 주로 쓰이는 지표만 평탄화해 저장합니다.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScreeningSnapshotRow {
+    /// 스냅샷 시각
+    pub snapshot_time: DateTime<Utc>,
+    /// 티커
+    pub ticker: String,
+    /// 종목명
+    pub name: String,
+    /// 섹터
+    pub sector: Option<String>,
+    /// 현재가
+    pub current_price: Option<Decimal>,
+    /// 시가총액
+    pub market_cap: Option<Decimal>,
+    /// PER
+    pub per: Option<Decimal>,
+    /// PBR
+    pub pbr: Option<Decimal>,
+    /// ROE (%)
+    pub roe: Option<Decimal>,
+    /// 배당수익률 (%)
+    pub dividend_yield: Option<Decimal>,
+    /// 20일 가격 변화율 (%)
+    pub price_change_20d: Option<Decimal>,
+    /// RSI(14)
+    pub rsi_14: Option<f64>,
+    /// 매매 단계 (RouteState)
+    pub route_state: Option<String>,
+    /// 시장 레짐
+    pub regime: Option<String>,
+    /// 종합 점수 (GlobalScore)
+    pub overall_score: Option<Decimal>,
+    /// 등급
+    pub grade: Option<String>,
+}
+
+impl ParquetRecord for Kline {
+    fn partition_symbol(&self) -> &str {
+        &self.ticker
+    }
+
+    fn partition_time(&self) -> DateTime<Utc> {
+        self.open_time
+    }
+
+    fn merge_key(&self) -> (i64, String) {
+        (
+            self.open_time.timestamp_millis(),
+            self.timeframe.to_string(),
+        )
+    }
+
+    fn to_frame(rows: &[Self]) -> PolarsResult<DataFrame> {
+        frame(vec![
+            str_series("ticker", rows.iter().map(|k| Some(k.ticker.clone()))),
+            str_series(
+                "timeframe",
+                rows.iter().map(|k| Some(k.timeframe.to_string())),
+            ),
+            time_series("open_time", rows.iter().map(|k| Some(k.open_time)))?,
+            decimal_series("open", rows.iter().map(|k| Some(k.open))),
+            decimal_series("high", rows.iter().map(|k| Some(k.high))),
+            decimal_series("low", rows.iter().map(|k| Some(k.low))),
+            decimal_series("close", rows.iter().map(|k| Some(k.close))),
+            decimal_series("volume", rows.iter().map(|k| Some(k.volume))),
+            time_series("close_time", rows.iter().map(|k| Some(k.close_time)))?,
+            decimal_series("quote_volume", rows.iter().map(|k| k.quote_volume)),
+            Series::new(
+                "num_trades".into(),
+                rows.iter()
+                    .map(|k| k.num_trades.map(i64::from))
+                    .collect::<Vec<_>>(),
+            ),
+        ])
+    }
+
+    fn from_frame(df: &DataFrame) -> PolarsResult<Vec<Self>> {
+        let ticker = str_values(df, "ticker")?;
+        let timeframe = str_values(df, "timeframe")?;
+        let open_time = time_values(df, "open_time")?;
+        let open = decimal_values(df, "open")?;
+        let high = decimal_values(df, "high")?;
+        let low = decimal_values(df, "low")?;
+        let close = decimal_values(df, "close")?;
+        let volume = decimal_values(df, "volume")?;
+        let close_time = time_values(df, "close_time")?;
+        let quote_volume = optional(decimal_values(df, "quote_volume"), df.height());
+        let num_trades = optional(i64_values(df, "num_trades"), df.height());
+
+        (0..df.height())
+            .map(|i| {
+                let timeframe = required(&timeframe[i], "timeframe", i)?;
+                Ok(Kline {
+                    ticker: required(&ticker[i], "ticker", i)?,
+                    timeframe: Timeframe::from_str(&timeframe)
+                        .map_err(|e| polars_err!(ComputeError: "{}", e))?,
+                    open_time: required(&open_time[i], "open_time", i)?,
+                    open: required(&open[i], "open", i)?,
+                    high: required(&high[i], "high", i)?,
+                    low: required(&low[i], "low", i)?,
+                    close: required(&close[i], "close", i)?,
+                    volume: required(&volume[i], "volume", i)?,
+                    close_time: required(&close_time[i], "close_time", i)?,
+                    quote_volume: quote_volume[i],
+                    num_trades: num_trades[i].and_then(|n| u32::try_from(n).ok()),
+                })
+            })
+            .collect()
+    }
+}
+
+impl ParquetRecord for TradeTick {
+    fn partition_symbol(&self) -> &str {
+        &self.ticker
+    }
+
+    fn partition_time(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    fn merge_key(&self) -> (i64, String) {
+        (self.timestamp.timestamp_millis(), self.id.clone())
+    }
+
+    fn to_frame(rows: &[Self]) -> PolarsResult<DataFrame> {
+        frame(vec![
+            str_series("ticker", rows.iter().map(|t| Some(t.ticker.clone()))),
+            str_series("trade_id", rows.iter().map(|t| Some(t.id.clone()))),
+            decimal_series("price", rows.iter().map(|t| Some(t.price))),
+            decimal_series("quantity", rows.iter().map(|t| Some(t.quantity))),
+            str_series("side", rows.iter().map(|t| Some(t.side.to_string()))),
+            time_series("timestamp", rows.iter().map(|t| Some(t.timestamp)))?,
+        ])
+    }
+
+    fn from_frame(df: &DataFrame) -> PolarsResult<Vec<Self>> {
+        let ticker = str_values(df, "ticker")?;
+        let trade_id = str_values(df, "trade_id")?;
+        let price = decimal_values(df, "price")?;
+        let quantity = decimal_values(df, "quantity")?;
+        let side = str_values(df, "side")?;
+        let timestamp = time_values(df, "timestamp")?;
+
+        (0..df.height())
+            .map(|i| {
+                Ok(TradeTick {
+                    ticker: required(&ticker[i], "ticker", i)?,
+                    id: required(&trade_id[i], "trade_id", i)?,
+                    price: required(&price[i], "price", i)?,
+                    quantity: required(&quantity[i], "quantity", i)?,
+                    side: parse_side(&required(&side[i], "side", i)?)?,
+                    timestamp: required(&timestamp[i], "timestamp", i)?,
+                })
+            })
+            .collect()
+    }
+}
+
+impl ParquetRecord for BacktestTradeRow {
+    fn partition_symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    fn partition_time(&self) -> DateTime<Utc> {
+        self.entry_time
+    }
+
+    fn merge_key(&self) -> (i64, String) {
+        (
+            self.entry_time.timestamp_millis(),
+            format!("{}:{}", self.run_id, self.exit_time.timestamp_millis()),
+        )
+    }
+
+    fn to_frame(rows: &[Self]) -> PolarsResult<DataFrame> {
+        frame(vec![
+            str_series("run_id", rows.iter().map(|r| Some(r.run_id.clone()))),
+            str_series("symbol", rows.iter().map(|r| Some(r.symbol.clone()))),
+            str_series("side", rows.iter().map(|r| Some(r.side.to_string()))),
+            time_series("entry_time", rows.iter().map(|r| Some(r.entry_time)))?,
+            time_series("exit_time", rows.iter().map(|r| Some(r.exit_time)))?,
+            decimal_series("entry_price", rows.iter().map(|r| Some(r.entry_price))),
+            decimal_series("exit_price", rows.iter().map(|r| Some(r.exit_price))),
+            decimal_series("quantity", rows.iter().map(|r| Some(r.quantity))),
+            decimal_series("fees", rows.iter().map(|r| Some(r.fees))),
+            decimal_series("pnl", rows.iter().map(|r| Some(r.pnl))),
+            decimal_series("return_pct", rows.iter().map(|r| Some(r.return_pct))),
+            str_series("strategy_id", rows.iter().map(|r| r.strategy_id.clone())),
+        ])
+    }
+
+    fn from_frame(df: &DataFrame) -> PolarsResult<Vec<Self>> {
+        let run_id = str_values(df, "run_id")?;
+        let symbol = str_values(df, "symbol")?;
+        let side = str_values(df, "side")?;
+        let entry_time = time_values(df, "entry_time")?;
+        let exit_time = time_values(df, "exit_time")?;
+        let entry_price = decimal_values(df, "entry_price")?;
+        let exit_price = decimal_values(df, "exit_price")?;
+        let quantity = decimal_values(df, "quantity")?;
+        let fees = decimal_values(df, "fees")?;
+        let pnl = decimal_values(df, "pnl")?;
+        let return_pct = decimal_values(df, "return_pct")?;
+        let strategy_id = optional(str_values(df, "strategy_id"), df.height());
+
+        (0..df.height())
+            .map(|i| {
+                Ok(BacktestTradeRow {
+                    run_id: required(&run_id[i], "run_id", i)?,
+                    symbol: required(&symbol[i], "symbol", i)?,
+                    side: parse_side(&required(&side[i], "side", i)?)?,
+                    entry_time: required(&entry_time[i], "entry_time", i)?,
+                    exit_time: required(&exit_time[i], "exit_time", i)?,
+                    entry_price: required(&entry_price[i], "entry_price", i)?,
+                    exit_price: required(&exit_price[i], "exit_price", i)?,
+                    quantity: required(&quantity[i], "quantity", i)?,
+                    fees: fees[i].unwrap_or_default(),
+                    pnl: required(&pnl[i], "pnl", i)?,
+                    return_pct: return_pct[i].unwrap_or_default(),
+                    strategy_id: strategy_id[i].clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl ParquetRecord for BacktestEquityRow {
+    fn partition_symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    fn partition_time(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    fn merge_key(&self) -> (i64, String) {
+        (self.timestamp.timestamp_millis(), self.run_id.clone())
+    }
+
+    fn to_frame(rows: &[Self]) -> PolarsResult<DataFrame> {
+        frame(vec![
+            str_series("run_id", rows.iter().map(|r| Some(r.run_id.clone()))),
+            str_series("symbol", rows.iter().map(|r| Some(r.symbol.clone()))),
+            time_series("timestamp", rows.iter().map(|r| Some(r.timestamp)))?,
+            decimal_series("equity", rows.iter().map(|r| Some(r.equity))),
+            decimal_series("drawdown_pct", rows.iter().map(|r| Some(r.drawdown_pct))),
+        ])
+    }
+
+    fn from_frame(df: &DataFrame) -> PolarsResult<Vec<Self>> {
+        let run_id = str_values(df, "run_id")?;
+        let symbol = str_values(df, "symbol")?;
+        let timestamp = time_values(df, "timestamp")?;
+        let equity = decimal_values(df, "equity")?;
+        let drawdown_pct = decimal_values(df, "drawdown_pct")?;
+
+        (0..df.height())
+            .map(|i| {
+                Ok(BacktestEquityRow {
+                    run_id: required(&run_id[i], "run_id", i)?,
+                    symbol: required(&symbol[i], "symbol", i)?,
+                    timestamp: required(&timestamp[i], "timestamp", i)?,
+                    equity: required(&equity[i], "equity", i)?,
+                    drawdown_pct: drawdown_pct[i].unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl ParquetRecord for ScreeningSnapshotRow {
+    fn partition_symbol(&self) -> &str {
+        &self.ticker
+    }
+
+    fn partition_time(&self) -> DateTime<Utc> {
+        self.snapshot_time
+    }
+
+    fn merge_key(&self) -> (i64, String) {
+        (self.snapshot_time.timestamp_millis(), self.ticker.clone())
+    }
+
+    fn to_frame(rows: &[Self]) -> PolarsResult<DataFrame> {
+        frame(vec![
+            time_series("snapshot_time", rows.iter().map(|r| Some(r.snapshot_time)))?,
+            str_series("ticker", rows.iter().map(|r| Some(r.ticker.clone()))),
+            str_series("name", rows.iter().map(|r| Some(r.name.clone()))),
+            str_series("sector", rows.iter().map(|r| r.sector.clone())),
+            decimal_series("current_price", rows.iter().map(|r| r.current_price)),
+            decimal_series("market_cap", rows.iter().map(|r| r.market_cap)),
+            decimal_series("per", rows.iter().map(|r| r.per)),
+            decimal_series("pbr", rows.iter().map(|r| r.pbr)),
+            decimal_series("roe", rows.iter().map(|r| r.roe)),
+            decimal_series("dividend_yield", rows.iter().map(|r| r.dividend_yield)),
+            decimal_series("price_change_20d", rows.iter().map(|r| r.price_change_20d)),
+            Series::new(
+                "rsi_14".into(),
+                rows.iter().map(|r| r.rsi_14).collect::<Vec<_>>(),
+            ),
+            str_series("route_state", rows.iter().map(|r| r.route_state.clone())),
+            str_series("regime", rows.iter().map(|r| r.regime.clone())),
+            decimal_series("overall_score", rows.iter().map(|r| r.overall_score)),
+            str_series("grade", rows.iter().map(|r| r.grade.clone())),
+        ])
+    }
+
+    fn from_frame(df: &DataFrame) -> PolarsResult<Vec<Self>> {
+        let height = df.height();
+        let snapshot_time = time_values(df, "snapshot_time")?;
+        let ticker = str_values(df, "ticker")?;
+        let name = optional(str_values(df, "name"), height);
+        let sector = optional(str_values(df, "sector"), height);
+        let current_price = optional(decimal_values(df, "current_price"), height);
+        let market_cap = optional(decimal_values(df, "market_cap"), height);
+        let per = optional(decimal_values(df, "per"), height);
+        let pbr = optional(decimal_values(df, "pbr"), height);
+        let roe = optional(decimal_values(df, "roe"), height);
+        let dividend_yield = optional(decimal_values(df, "dividend_yield"), height);
+        let price_change_20d = optional(decimal_values(df, "price_change_20d"), height);
+        let rsi_14 = optional(f64_values(df, "rsi_14"), height);
+        let route_state = optional(str_values(df, "route_state"), height);
+        let regime = optional(str_values(df, "regime"), height);
+        let overall_score = optional(decimal_values(df, "overall_score"), height);
+        let grade = optional(str_values(df, "grade"), height);
+
+        (0..height)
+            .map(|i| {
+                Ok(ScreeningSnapshotRow {
+                    snapshot_time: required(&snapshot_time[i], "snapshot_time", i)?,
+                    ticker: required(&ticker[i], "ticker", i)?,
+                    name: name[i].clone().unwrap_or_default(),
+                    sector: sector[i].clone(),
+                    current_price: current_price[i],
+                    market_cap: market_cap[i],
+                    per: per[i],
+                    pbr: pbr[i],
+                    roe: roe[i],
+                    dividend_yield: dividend_yield[i],
+                    price_change_20d: price_change_20d[i],
+                    rsi_14: rsi_14[i],
+                    route_state: route_state[i].clone(),
+                    regime: regime[i].clone(),
+                    overall_score: overall_score[i],
+                    grade: grade[i].clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+// ==================== 단일 파일 읽기/쓰기 ====================
+
+/// 레코드를 Parquet으로 직렬화해 `writer`에 기록.
+///
+/// 기록한 바이트 수를 반환합니다.
+pub fn write_parquet<T: ParquetRecord, W: Write>(writer: W, rows: &[T]) -> Result<u64> {
+    let mut df = T::to_frame(rows).map_err(to_data_error)?;
+    ParquetWriter::new(writer)
+        .with_compression(ParquetCompression::Zstd(None))
+        .finish(&mut df)
+        .map_err(to_data_error)
+}
+
+/// Parquet 파일에서 레코드 읽기.
+pub fn read_parquet<T: ParquetRecord, R: MmapBytesReader>(reader: R) -> Result<Vec<T>> {
+    let df = ParquetReader::new(reader).finish().map_err(to_data_error)?;
+    T::from_frame(&df).map_err(to_data_error)
+}
+
+/// 공유 버퍼 (스트리밍 인코더가 row group 단위로 바이트를 꺼내기 위해 사용).
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.0.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// row group 단위 Parquet 스트리밍 인코더.
+///
+/// 전체 데이터를 메모리에 올리지 않고 배치마다 인코딩된 바이트 조각을 돌려주므로
+/// HTTP 응답 본문을 스트리밍할 때 사용합니다. 모든 조각을 순서대로 이어 붙이면
+/// 하나의 유효한 Parquet 파일이 됩니다.
+pub struct ParquetStreamEncoder<T: ParquetRecord> {
+    writer: BatchedWriter<SharedBuffer>,
+    buffer: SharedBuffer,
+    _marker: PhantomData<fn(T)>,
+}
+
+impl<T: ParquetRecord> ParquetStreamEncoder<T> {
+    /// 새 인코더 생성.
+    pub fn new() -> Result<Self> {
+        let buffer = SharedBuffer::default();
+        let schema = T::to_frame(&[]).map_err(to_data_error)?.schema().clone();
+        let writer = ParquetWriter::new(buffer.clone())
+            .with_compression(ParquetCompression::Zstd(None))
+            .batched(&schema)
+            .map_err(to_data_error)?;
+        Ok(Self {
+            writer,
+            buffer,
+            _marker: PhantomData,
+        })
+    }
+
+    /// 배치를 row group으로 인코딩하고 지금까지 생성된 바이트를 반환.
+    pub fn encode(&mut self, rows: &[T]) -> Result<Vec<u8>> {
+        if !rows.is_empty() {
+            let df = T::to_frame(rows).map_err(to_data_error)?;
+            self.writer.write_batch(&df).map_err(to_data_error)?;
+        }
+        Ok(self.buffer.take())
+    }
+
+    /// 파일 푸터를 기록하고 남은 바이트를 반환.
+    pub fn finish(self) -> Result<Vec<u8>> {
+        self.writer.finish().map_err(to_data_error)?;
+        Ok(self.buffer.take())
+    }
+}
+
+// ==================== 분할 저장소 ====================
+
+/// Hive 스타일로 분할된 Parquet 저장소.
+#[derive(Debug, Clone)]
+pub struct ParquetStore {
+    root: PathBuf,
+}
+
+impl ParquetStore {
+    /// 루트 디렉토리로 저장소 생성.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// 루트 디렉토리.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// 파티션 파일의 절대 경로.
+    pub fn partition_path(&self, partition: &ParquetPartition) -> PathBuf {
+        self.root.join(partition.relative_path())
+    }
+
+    /// 레코드를 market/symbol/year 파티션으로 나누어 저장.
+    ///
+    /// 기존 파티션 파일이 있으면 병합하고(같은 키는 새 레코드로 대체) 시간순으로 정렬합니다.
+    /// 기록된 파티션 목록을 반환합니다.
+    pub fn write<T: ParquetRecord>(
+        &self,
+        dataset: ParquetDataset,
+        market: &str,
+        rows: Vec<T>,
+    ) -> Result<Vec<ParquetPartition>> {
+        self.write_partitions(dataset, market, rows, false)
+    }
+
+    /// 레코드를 market/symbol/year 아래 월 단위 파일로 나누어 저장.
+    ///
+    /// 병합 규칙은 [`write`](Self::write)와 같지만 해당 월 파일만 다시 쓰므로,
+    /// 월 단위로 반복 저장해도 연도 전체를 다시 읽고 쓰지 않습니다.
+    pub fn write_monthly<T: ParquetRecord>(
+        &self,
+        dataset: ParquetDataset,
+        market: &str,
+        rows: Vec<T>,
+    ) -> Result<Vec<ParquetPartition>> {
+        self.write_partitions(dataset, market, rows, true)
+    }
+
+    fn write_partitions<T: ParquetRecord>(
+        &self,
+        dataset: ParquetDataset,
+        market: &str,
+        rows: Vec<T>,
+        monthly: bool,
+    ) -> Result<Vec<ParquetPartition>> {
+        let mut groups: BTreeMap<(String, i32, Option<u32>), Vec<T>> = BTreeMap::new();
+        for row in rows {
+            let time = row.partition_time();
+            let key = (
+                row.partition_symbol().to_string(),
+                time.year(),
+                monthly.then(|| time.month()),
+            );
+            groups.entry(key).or_default().push(row);
+        }
+
+        let mut written = Vec::with_capacity(groups.len());
+        for ((symbol, year, month), rows) in groups {
+            let mut partition = ParquetPartition::new(dataset, market, symbol, year);
+            if let Some(month) = month {
+                partition = partition.with_month(month);
+            }
+            let path = self.partition_path(&partition);
+
+            let mut merged: BTreeMap<(i64, String), T> = BTreeMap::new();
+            if path.exists() {
+                for row in read_parquet::<T, _>(File::open(&path)?)? {
+                    merged.insert(row.merge_key(), row);
+                }
+            }
+            for row in rows {
+                merged.insert(row.merge_key(), row);
+            }
+            let rows: Vec<T> = merged.into_values().collect();
+
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            // 쓰기 도중 실패해도 기존 파일이 깨지지 않도록 임시 파일에 쓴 뒤 교체
+            let tmp_path = path.with_extension("parquet.tmp");
+            write_parquet(File::create(&tmp_path)?, &rows)?;
+            fs::rename(&tmp_path, &path)?;
+
+            debug!(
+                dataset = %dataset,
+                path = %path.display(),
+                rows = rows.len(),
+                "Parquet 파티션 저장"
+            );
+            written.push(partition);
+        }
+
+        Ok(written)
+    }
+
+    /// 심볼의 파티션을 읽어 시간순으로 반환.
+    ///
+    /// `years`가 주어지면 해당 연도 범위의 파티션만 읽습니다.
+    pub fn read<T: ParquetRecord>(
+        &self,
+        dataset: ParquetDataset,
+        market: &str,
+        symbol: &str,
+        years: Option<(i32, i32)>,
+    ) -> Result<Vec<T>> {
+        let mut rows = Vec::new();
+        for partition in self.partitions(dataset, market, symbol)? {
+            if years.is_some_and(|(from, to)| partition.year < from || partition.year > to) {
+                continue;
+            }
+            rows.extend(read_parquet::<T, _>(File::open(
+                self.partition_path(&partition),
+            )?)?);
+        }
+        rows.sort_by_key(|row| row.merge_key());
+        Ok(rows)
+    }
+
+    /// 심볼의 저장된 파티션 목록 (연도, 월 오름차순).
+    ///
+    /// 연도 파일(`data.parquet`)과 월 단위 파일을 모두 포함합니다.
+    pub fn partitions(
+        &self,
+        dataset: ParquetDataset,
+        market: &str,
+        symbol: &str,
+    ) -> Result<Vec<ParquetPartition>> {
+        let dir = self.root.join(symbol_dir(dataset, market, symbol));
+        let mut partitions = Vec::new();
+        for year in list_keyed_dirs(&dir, "year")?
+            .into_iter()
+            .filter_map(|year| year.parse::<i32>().ok())
+        {
+            let yearly = ParquetPartition::new(dataset, market, symbol, year);
+            if self.partition_path(&yearly).is_file() {
+                partitions.push(yearly);
+            }
+            for entry in fs::read_dir(dir.join(format!("year={}", year)))? {
+                let entry = entry?;
+                if !entry.file_type()?.is_file() {
+                    continue;
+                }
+                if let Some(month) = entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|name| parse_monthly_file_name(year, name))
+                {
+                    partitions.push(
+                        ParquetPartition::new(dataset, market, symbol, year).with_month(month),
+                    );
+                }
+            }
+        }
+        partitions.sort_by_key(|partition| (partition.year, partition.month));
+        Ok(partitions)
+    }
+
+    /// 시장에 저장된 심볼 목록 (디렉토리 이름 기준).
+    pub fn symbols(&self, dataset: ParquetDataset, market: &str) -> Result<Vec<String>> {
+        let mut symbols = list_keyed_dirs(&self.root.join(market_dir(dataset, market)), "symbol")?;
+        symbols.sort();
+        Ok(symbols)
+    }
+
+    /// 캔들 저장.
+    pub fn write_klines(&self, market: &str, klines: &[Kline]) -> Result<Vec<ParquetPartition>> {
+        self.write(ParquetDataset::Ohlcv, market, klines.to_vec())
+    }
+
+    /// 기간 내 캔들 조회.
+    pub fn read_klines(
+        &self,
+        market: &str,
+        symbol: &str,
+        timeframe: Timeframe,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Kline>> {
+        let klines: Vec<Kline> = self.read(
+            ParquetDataset::Ohlcv,
+            market,
+            symbol,
+            Some((start.year(), end.year())),
+        )?;
+        Ok(klines
+            .into_iter()
+            .filter(|k| k.timeframe == timeframe && k.open_time >= start && k.open_time <= end)
+            .collect())
+    }
+
+    /// 체결 틱 저장.
+    pub fn write_trade_ticks(
+        &self,
+        market: &str,
+        ticks: &[TradeTick],
+    ) -> Result<Vec<ParquetPartition>> {
+        self.write(ParquetDataset::TradeTicks, market, ticks.to_vec())
+    }
+
+    /// 기간 내 체결 틱 조회.
+    pub fn read_trade_ticks(
+        &self,
+        market: &str,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<TradeTick>> {
+        let ticks: Vec<TradeTick> = self.read(
+            ParquetDataset::TradeTicks,
+            market,
+            symbol,
+            Some((start.year(), end.year())),
+        )?;
+        Ok(ticks
+            .into_iter()
+            .filter(|t| t.timestamp >= start && t.timestamp <= end)
+            .collect())
+    }
+}
+
+/// `{key}={value}` 형태의 하위 디렉토리 값 목록.
+fn list_keyed_dirs(dir: &Path, key: &str) -> Result<Vec<String>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let prefix = format!("{}=", key);
+    let mut values = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        if let Some(value) = entry
+            .file_name()
+            .to_str()
+            .and_then(|n| n.strip_prefix(&prefix))
+        {
+            values.push(value.to_string());
+        }
+    }
+    Ok(values)
+}
+
+// ==================== 컬럼 변환 헬퍼 ====================
+
+fn to_data_error(e: PolarsError) -> DataError {
+    DataError::SerializationError(format!("Parquet 변환 실패: {}", e))
+}
+
+/// 같은 길이의 컬럼으로 DataFrame 구성.
+fn frame(columns: Vec<Series>) -> PolarsResult<DataFrame> {
+    Ok(columns.into_iter().collect())
+}
+
+fn str_series(name: &str, values: impl Iterator<Item = Option<String>>) -> Series {
+    Series::new(name.into(), values.collect::<Vec<_>>())
+}
+
+fn decimal_series(name: &str, values: impl Iterator<Item = Option<Decimal>>) -> Series {
+    Series::new(
+        name.into(),
+        values
+            .map(|v| v.and_then(|d| d.to_f64()))
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn time_series(
+    name: &str,
+    values: impl Iterator<Item = Option<DateTime<Utc>>>,
+) -> PolarsResult<Series> {
+    Series::new(
+        name.into(),
+        values
+            .map(|v| v.map(|t| t.timestamp_millis()))
+            .collect::<Vec<_>>(),
+    )
+    .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+}
+
+fn str_values(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<String>>> {
+    Ok(df
+        .column(name)?
+        .cast(&DataType::String)?
+        .str()?
+        .into_iter()
+        .map(|v| v.map(str::to_string))
+        .collect())
+}
+
+fn f64_values(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<f64>>> {
+    Ok(df
+        .column(name)?
+        .cast(&DataType::Float64)?
+        .f64()?
+        .into_iter()
+        .collect())
+}
+
+fn i64_values(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<i64>>> {
+    Ok(df
+        .column(name)?
+        .cast(&DataType::Int64)?
+        .i64()?
+        .into_iter()
+        .collect())
+}
+
+fn decimal_values(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<Decimal>>> {
+    Ok(f64_values(df, name)?
+        .into_iter()
+        .map(|v| v.and_then(Decimal::from_f64))
+        .collect())
+}
+
+/// 시각 컬럼은 `Datetime[ms]`/정수(ms) 모두 허용.
+fn time_values(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<DateTime<Utc>>>> {
+    let column = df.column(name)?;
+    let column = match column.dtype() {
+        DataType::Datetime(TimeUnit::Milliseconds, _) => column.clone(),
+        DataType::Datetime(_, _) => {
+            column.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
+        }
+        _ => column.clone(),
+    };
+    Ok(column
+        .cast(&DataType::Int64)?
+        .i64()?
+        .into_iter()
+        .map(|v| v.and_then(DateTime::from_timestamp_millis))
+        .collect())
+}
+
+/// 선택 컬럼: 파일에 없으면 모두 `None`.
+fn optional<V: Clone>(values: PolarsResult<Vec<Option<V>>>, height: usize) -> Vec<Option<V>> {
+    values.unwrap_or_else(|_| vec![None; height])
+}
+
+fn required<V: Clone>(value: &Option<V>, column: &str, row: usize) -> PolarsResult<V> {
+    value
+        .clone()
+        .ok_or_else(|| polars_err!(ComputeError: "{} 컬럼 {}번째 값이 비어 있습니다", column, row))
+}
+
+fn parse_side(value: &str) -> PolarsResult<Side> {
+    Side::from_str(value).map_err(|e| polars_err!(ComputeError: "{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn temp_root(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("trader-parquet-{}-{}", name, uuid::Uuid::new_v4()))
+    }
+
+    fn kline(ticker: &str, day: DateTime<Utc>, close: Decimal) -> Kline {
+        Kline {
+            ticker: ticker.to_string(),
+            timeframe: Timeframe::D1,
+            open_time: day,
+            open: close - dec!(100),
+            high: close + dec!(200),
+            low: close - dec!(300),
+            close,
+            volume: dec!(12345),
+            close_time: day + chrono::Duration::days(1),
+            quote_volume: None,
+            num_trades: Some(42),
+        }
+    }
+
+    #[test]
+    fn test_dataset_parse() {
+        assert_eq!(
+            ParquetDataset::from_str("trade-ticks").unwrap(),
+            ParquetDataset::TradeTicks
+        );
+        assert_eq!(ParquetDataset::BacktestEquity.as_str(), "backtest_equity");
+        assert!(ParquetDataset::from_str("orders").is_err());
+    }
+
+    #[test]
+    fn test_partition_path() {
+        let partition = ParquetPartition::new(ParquetDataset::Ohlcv, "crypto", "BTC/USDT", 2025);
+        assert_eq!(
+            partition.relative_path(),
+            PathBuf::from("ohlcv/market=CRYPTO/symbol=BTC_USDT/year=2025/data.parquet")
+        );
+    }
+
+    #[test]
+    fn test_monthly_partition_path() {
+        let partition =
+            ParquetPartition::new(ParquetDataset::Ohlcv, "KR", "005930", 2024).with_month(3);
+        assert_eq!(
+            partition.relative_path(),
+            PathBuf::from("ohlcv/market=KR/symbol=005930/year=2024/2024-03.parquet")
+        );
+        assert_eq!(parse_monthly_file_name(2024, "2024-03.parquet"), Some(3));
+        assert_eq!(parse_monthly_file_name(2024, "2023-03.parquet"), None);
+        assert_eq!(parse_monthly_file_name(2024, "data.parquet"), None);
+    }
+
+    #[test]
+    fn test_write_monthly_keeps_other_months() {
+        let root = temp_root("monthly");
+        let store = ParquetStore::new(&root);
+        let jan = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let feb = Utc.with_ymd_and_hms(2024, 2, 15, 0, 0, 0).unwrap();
+
+        store
+            .write_monthly(
+                ParquetDataset::Ohlcv,
+                "KR",
+                vec![kline("005930", jan, dec!(70000))],
+            )
+            .unwrap();
+        let jan_path = store.partition_path(
+            &ParquetPartition::new(ParquetDataset::Ohlcv, "KR", "005930", 2024).with_month(1),
+        );
+        let jan_modified = fs::metadata(&jan_path).unwrap().modified().unwrap();
+
+        let written = store
+            .write_monthly(
+                ParquetDataset::Ohlcv,
+                "KR",
+                vec![kline("005930", feb, dec!(71000))],
+            )
+            .unwrap();
+        assert_eq!(written[0].month, Some(2));
+        assert_eq!(
+            fs::metadata(&jan_path).unwrap().modified().unwrap(),
+            jan_modified
+        );
+
+        // 연도 파일과 월 파일을 함께 읽음
+        let dec_2024 = Utc.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap();
+        store
+            .write_klines("KR", &[kline("005930", dec_2024, dec!(72000))])
+            .unwrap();
+        let months: Vec<Option<u32>> = store
+            .partitions(ParquetDataset::Ohlcv, "KR", "005930")
+            .unwrap()
+            .iter()
+            .map(|p| p.month)
+            .collect();
+        assert_eq!(months, vec![None, Some(1), Some(2)]);
+        let loaded = store
+            .read_klines("KR", "005930", Timeframe::D1, jan, dec_2024)
+            .unwrap();
+        assert_eq!(loaded.len(), 3);
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn test_kline_roundtrip_partitioned_and_merged() {
+        let root = temp_root("ohlcv");
+        let store = ParquetStore::new(&root);
+        let d1 = Utc.with_ymd_and_hms(2024, 12, 30, 0, 0, 0).unwrap();
+        let d2 = Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap();
+
+        let written = store
+            .write_klines(
+                "KR",
+                &[
+                    kline("005930", d1, dec!(53000)),
+                    kline("005930", d2, dec!(53500.5)),
+                ],
+            )
+            .unwrap();
+        assert_eq!(written.len(), 2);
+
+        // 같은 캔들을 다시 쓰면 대체되고 새 캔들은 병합됨
+        let d3 = Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap();
+        store
+            .write_klines(
+                "KR",
+                &[
+                    kline("005930", d2, dec!(54000)),
+                    kline("005930", d3, dec!(54100)),
+                ],
+            )
+            .unwrap();
+
+        let years: Vec<i32> = store
+            .partitions(ParquetDataset::Ohlcv, "KR", "005930")
+            .unwrap()
+            .iter()
+            .map(|p| p.year)
+            .collect();
+        assert_eq!(years, vec![2024, 2025]);
+
+        let loaded = store
+            .read_klines("KR", "005930", Timeframe::D1, d1, d3)
+            .unwrap();
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(loaded[0].close, dec!(53000));
+        assert_eq!(loaded[1].close, dec!(54000));
+        assert_eq!(loaded[1].num_trades, Some(42));
+        assert_eq!(loaded[2].open_time, d3);
+
+        let only_2025 = store
+            .read_klines("KR", "005930", Timeframe::D1, d2, d3)
+            .unwrap();
+        assert_eq!(only_2025.len(), 2);
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn test_stream_encoder_produces_valid_file() {
+        let day = Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap();
+        let mut encoder = ParquetStreamEncoder::<Kline>::new().unwrap();
+        let mut bytes = encoder.encode(&[kline("AAPL", day, dec!(180.25))]).unwrap();
+        bytes.extend(
+            encoder
+                .encode(&[kline("AAPL", day + chrono::Duration::days(1), dec!(181))])
+                .unwrap(),
+        );
+        bytes.extend(encoder.finish().unwrap());
+
+        let klines: Vec<Kline> = read_parquet(std::io::Cursor::new(bytes)).unwrap();
+        assert_eq!(klines.len(), 2);
+        assert_eq!(klines[0].close, dec!(180.25));
+        assert_eq!(klines[1].close, dec!(181));
+    }
+
+    #[test]
+    fn test_backtest_rows_roundtrip() {
+        let entry = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+        let trade = BacktestTradeRow {
+            run_id: "run-1".to_string(),
+            symbol: "SPY".to_string(),
+            side: Side::Buy,
+            entry_time: entry,
+            exit_time: entry + chrono::Duration::days(3),
+            entry_price: dec!(500),
+            exit_price: dec!(510),
+            quantity: dec!(10),
+            fees: dec!(1.5),
+            pnl: dec!(98.5),
+            return_pct: dec!(1.97),
+            strategy_id: None,
+        };
+        let mut buffer = Vec::new();
+        write_parquet(&mut buffer, std::slice::from_ref(&trade)).unwrap();
+        let loaded: Vec<BacktestTradeRow> = read_parquet(std::io::Cursor::new(buffer)).unwrap();
+        assert_eq!(loaded, vec![trade]);
+    }
+}
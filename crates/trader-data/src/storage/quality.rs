@@ -0,0 +1,308 @@
+//! OHLCV 데이터 품질 리포트 저장소.
+//!
+//! 수집기(`trader-collector`)가 심볼/타임프레임별로 캔들 시계열을 검사한 결과를
+//! `ohlcv_quality_report` 테이블에 저장합니다. 백테스트 엔진은 저장된 리포트를 받아
+//! 품질이 낮은 데이터로 실행될 때 경고를 남깁니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgPool, FromRow};
+use tracing::instrument;
+
+use crate::error::{DataError, Result};
+
+/// 이 점수 미만이면 품질 저하로 간주하여 백테스트에서 경고합니다.
+pub const QUALITY_WARNING_SCORE: f64 = 90.0;
+
+/// 데이터 품질 문제 유형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityIssueKind {
+    /// 거래량 0 캔들
+    ZeroVolume,
+    /// high < low 등 OHLC 관계 위반
+    InvalidOhlc,
+    /// 같은 거래일(또는 시각)에 중복된 캔들
+    DuplicateTimestamp,
+    /// 거래일인데 캔들이 없음
+    MissingTradingDay,
+    /// 비정상 가격 급등락 (데이터 오류 의심)
+    PriceSpike,
+}
+
+impl QualityIssueKind {
+    /// 문자열 표현.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ZeroVolume => "zero_volume",
+            Self::InvalidOhlc => "invalid_ohlc",
+            Self::DuplicateTimestamp => "duplicate_timestamp",
+            Self::MissingTradingDay => "missing_trading_day",
+            Self::PriceSpike => "price_spike",
+        }
+    }
+}
+
+impl std::fmt::Display for QualityIssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// 개별 품질 문제.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QualityIssue {
+    /// 문제 유형
+    pub kind: QualityIssueKind,
+    /// 문제가 발견된 캔들 시각 (누락 거래일은 해당일 00:00 UTC)
+    pub time: DateTime<Utc>,
+    /// 상세 설명
+    pub detail: String,
+}
+
+/// 심볼/타임프레임별 OHLCV 품질 리포트.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OhlcvQualityReport {
+    /// 심볼 (ohlcv.symbol)
+    pub symbol: String,
+    /// 타임프레임 (ohlcv.timeframe 형식, 예: "1d")
+    pub timeframe: String,
+    /// 검사 시각
+    pub checked_at: DateTime<Utc>,
+    /// 검사 구간 시작 (첫 캔들)
+    pub range_start: Option<DateTime<Utc>>,
+    /// 검사 구간 종료 (마지막 캔들)
+    pub range_end: Option<DateTime<Utc>>,
+    /// 검사한 캔들 수
+    pub total_bars: usize,
+    /// 거래량 0 캔들 수
+    pub zero_volume_bars: usize,
+    /// OHLC 관계 위반 캔들 수
+    pub invalid_ohlc_bars: usize,
+    /// 중복 캔들 수
+    pub duplicate_bars: usize,
+    /// 누락 거래일 수
+    pub missing_days: usize,
+    /// 급등락 의심 캔들 수
+    pub spike_bars: usize,
+    /// 대체 프로바이더로 복구한 캔들 수
+    pub repaired_bars: usize,
+    /// 복구에 사용한 프로바이더 (예: ["yahoo", "naver"])
+    #[serde(default)]
+    pub repair_sources: Vec<String>,
+    /// 품질 점수 (0 ~ 100)
+    pub score: f64,
+    /// 문제 상세 (유형별 최대 일부만 보관)
+    #[serde(default)]
+    pub issues: Vec<QualityIssue>,
+}
+
+impl OhlcvQualityReport {
+    /// 품질 저하 여부 (점수가 [`QUALITY_WARNING_SCORE`] 미만).
+    pub fn is_degraded(&self) -> bool {
+        self.score < QUALITY_WARNING_SCORE
+    }
+
+    /// 발견된 문제 수 합계.
+    pub fn issue_count(&self) -> usize {
+        self.zero_volume_bars
+            + self.invalid_ohlc_bars
+            + self.duplicate_bars
+            + self.missing_days
+            + self.spike_bars
+    }
+
+    /// 경고 메시지 (로그/리포트용).
+    pub fn warning_message(&self) -> String {
+        format!(
+            "{} {} 데이터 품질 {:.1}점 (누락 {}일, 거래량0 {}, OHLC오류 {}, 중복 {}, 급등락 {}; 검사 {})",
+            self.symbol,
+            self.timeframe,
+            self.score,
+            self.missing_days,
+            self.zero_volume_bars,
+            self.invalid_ohlc_bars,
+            self.duplicate_bars,
+            self.spike_bars,
+            self.checked_at.format("%Y-%m-%d %H:%M"),
+        )
+    }
+}
+
+/// 품질 리포트 데이터베이스 레코드.
+#[derive(Debug, Clone, FromRow)]
+struct QualityReportRecord {
+    symbol: String,
+    timeframe: String,
+    checked_at: DateTime<Utc>,
+    range_start: Option<DateTime<Utc>>,
+    range_end: Option<DateTime<Utc>>,
+    total_bars: i32,
+    zero_volume_bars: i32,
+    invalid_ohlc_bars: i32,
+    duplicate_bars: i32,
+    missing_days: i32,
+    spike_bars: i32,
+    repaired_bars: i32,
+    repair_sources: Vec<String>,
+    score: f64,
+    issues: serde_json::Value,
+}
+
+impl QualityReportRecord {
+    fn into_report(self) -> OhlcvQualityReport {
+        OhlcvQualityReport {
+            symbol: self.symbol,
+            timeframe: self.timeframe,
+            checked_at: self.checked_at,
+            range_start: self.range_start,
+            range_end: self.range_end,
+            total_bars: self.total_bars.max(0) as usize,
+            zero_volume_bars: self.zero_volume_bars.max(0) as usize,
+            invalid_ohlc_bars: self.invalid_ohlc_bars.max(0) as usize,
+            duplicate_bars: self.duplicate_bars.max(0) as usize,
+            missing_days: self.missing_days.max(0) as usize,
+            spike_bars: self.spike_bars.max(0) as usize,
+            repaired_bars: self.repaired_bars.max(0) as usize,
+            repair_sources: self.repair_sources,
+            score: self.score,
+            issues: serde_json::from_value(self.issues).unwrap_or_default(),
+        }
+    }
+}
+
+const SELECT_COLUMNS: &str = r#"
+    SELECT symbol, timeframe, checked_at, range_start, range_end,
+           total_bars, zero_volume_bars, invalid_ohlc_bars, duplicate_bars,
+           missing_days, spike_bars, repaired_bars, repair_sources, score, issues
+    FROM ohlcv_quality_report
+"#;
+
+/// OHLCV 품질 리포트 저장소.
+///
+/// 심볼/타임프레임별 최신 리포트 1건을 유지합니다.
+#[derive(Clone)]
+pub struct OhlcvQualityRepository {
+    pool: PgPool,
+}
+
+impl OhlcvQualityRepository {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 리포트 저장 (같은 심볼/타임프레임은 교체).
+    #[instrument(skip(self, report), fields(symbol = %report.symbol, timeframe = %report.timeframe))]
+    pub async fn save(&self, report: &OhlcvQualityReport) -> Result<()> {
+        let issues = serde_json::to_value(&report.issues)
+            .map_err(|e| DataError::SerializationError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO ohlcv_quality_report
+                (symbol, timeframe, checked_at, range_start, range_end,
+                 total_bars, zero_volume_bars, invalid_ohlc_bars, duplicate_bars,
+                 missing_days, spike_bars, repaired_bars, repair_sources, score, issues)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            ON CONFLICT (symbol, timeframe) DO UPDATE SET
+                checked_at = EXCLUDED.checked_at,
+                range_start = EXCLUDED.range_start,
+                range_end = EXCLUDED.range_end,
+                total_bars = EXCLUDED.total_bars,
+                zero_volume_bars = EXCLUDED.zero_volume_bars,
+                invalid_ohlc_bars = EXCLUDED.invalid_ohlc_bars,
+                duplicate_bars = EXCLUDED.duplicate_bars,
+                missing_days = EXCLUDED.missing_days,
+                spike_bars = EXCLUDED.spike_bars,
+                repaired_bars = EXCLUDED.repaired_bars,
+                repair_sources = EXCLUDED.repair_sources,
+                score = EXCLUDED.score,
+                issues = EXCLUDED.issues
+            "#,
+        )
+        .bind(&report.symbol)
+        .bind(&report.timeframe)
+        .bind(report.checked_at)
+        .bind(report.range_start)
+        .bind(report.range_end)
+        .bind(report.total_bars as i32)
+        .bind(report.zero_volume_bars as i32)
+        .bind(report.invalid_ohlc_bars as i32)
+        .bind(report.duplicate_bars as i32)
+        .bind(report.missing_days as i32)
+        .bind(report.spike_bars as i32)
+        .bind(report.repaired_bars as i32)
+        .bind(&report.repair_sources)
+        .bind(report.score)
+        .bind(issues)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// 심볼/타임프레임의 최신 리포트 조회.
+    pub async fn get(&self, symbol: &str, timeframe: &str) -> Result<Option<OhlcvQualityReport>> {
+        let record: Option<QualityReportRecord> = sqlx::query_as(&format!(
+            "{} WHERE symbol = $1 AND timeframe = $2",
+            SELECT_COLUMNS
+        ))
+        .bind(symbol)
+        .bind(timeframe)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(record.map(QualityReportRecord::into_report))
+    }
+
+    /// 여러 심볼의 리포트 일괄 조회 (리포트가 없는 심볼은 제외).
+    pub async fn get_many(
+        &self,
+        symbols: &[String],
+        timeframe: &str,
+    ) -> Result<Vec<OhlcvQualityReport>> {
+        if symbols.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let records: Vec<QualityReportRecord> = sqlx::query_as(&format!(
+            "{} WHERE symbol = ANY($1) AND timeframe = $2 ORDER BY symbol",
+            SELECT_COLUMNS
+        ))
+        .bind(symbols)
+        .bind(timeframe)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(QualityReportRecord::into_report)
+            .collect())
+    }
+
+    /// 품질 점수가 낮은 순으로 리포트 조회.
+    pub async fn list_degraded(
+        &self,
+        max_score: f64,
+        limit: i64,
+    ) -> Result<Vec<OhlcvQualityReport>> {
+        let records: Vec<QualityReportRecord> = sqlx::query_as(&format!(
+            "{} WHERE score < $1 ORDER BY score ASC, symbol LIMIT $2",
+            SELECT_COLUMNS
+        ))
+        .bind(max_score)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(QualityReportRecord::into_report)
+            .collect())
+    }
+}
@@ -0,0 +1,805 @@
+//! 계층형 저장소 (다운샘플링 및 아카이브).
+//!
+//! 보존 기간이 지난 분봉과 체결 틱을 DB에서 Parquet 아카이브로 옮기는 보존 정책 엔진입니다.
+//!
+//! # 동작 방식
+//!
+//! 1. 분봉(`1m`/`5m`/`15m`/`30m`) 중 `rollup_after_days`가 지난 구간을 1시간봉/일봉으로 롤업
+//!    (일봉은 시장 현지 날짜 기준, 같은 구간에 이미 있는 1시간봉/일봉은 유지)
+//! 2. 원본 분봉을 [`ParquetStore`]에 zstd 압축 Parquet 월 단위 파일로 저장
+//! 3. 아카이브 구간을 `storage_archive_manifest`에 기록한 뒤 DB에서 삭제
+//! 4. 체결 틱은 `tick_archive_after_days`가 지나면 같은 방식으로 아카이브 후 삭제
+//!
+//! 구간은 월 단위로 처리하며, 아카이브 기록이 성공한 구간만 DB에서 삭제합니다.
+//! [`OhlcvCache::get_cached_klines_range`]는 아카이브 루트(`STORAGE_ARCHIVE_DIR`)가 설정되면
+//! 목록을 확인하여 아카이브된 구간을 DB 데이터와 합쳐 반환합니다.
+//!
+//! ```text
+//! {STORAGE_ARCHIVE_DIR}/ohlcv/market=KR/symbol=005930/year=2024/2024-03.parquet
+//! {STORAGE_ARCHIVE_DIR}/trade_ticks/market=CRYPTO/symbol=BTC_USDT/year=2024/2024-03.parquet
+//! ```
+//!
+//! 일봉 롤업은 제공자 일봉과 겹치지 않도록 시장 현지 자정(KR은 KST, US는 ET)으로 묶고,
+//! 구간 안에 이미 캔들이 있으면(예: 다른 오프셋의 제공자 일봉) 저장하지 않습니다.
+
+use std::collections::{btree_map::Entry, BTreeMap};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgPool, FromRow};
+use tracing::{debug, info, instrument, warn};
+use trader_core::{Kline, MarketTimezone, Timeframe, TradeTick};
+use uuid::Uuid;
+
+use crate::{
+    error::{DataError, Result},
+    storage::{
+        ohlcv::{timeframe_to_string, OhlcvCache},
+        parquet::{ParquetDataset, ParquetRecord, ParquetStore},
+        timescale::TradeTickRecord,
+    },
+};
+
+/// 아카이브 루트 디렉토리 환경변수.
+pub const ARCHIVE_DIR_ENV: &str = "STORAGE_ARCHIVE_DIR";
+
+/// 체결 틱 아카이브 구간의 타임프레임 표시.
+pub const TICK_TIMEFRAME: &str = "tick";
+
+/// 롤업 대상 분봉 타임프레임 (DB 저장 문자열).
+const ROLLUP_SOURCE_TIMEFRAMES: [&str; 4] = ["1m", "5m", "15m", "30m"];
+
+/// 시장 정보가 없는 심볼의 아카이브 파티션 시장.
+const UNKNOWN_MARKET: &str = "UNKNOWN";
+
+/// 환경변수(`STORAGE_ARCHIVE_DIR`)로 아카이브 저장소 생성 (미설정 시 None).
+pub fn archive_store_from_env() -> Option<ParquetStore> {
+    std::env::var(ARCHIVE_DIR_ENV)
+        .ok()
+        .filter(|dir| !dir.trim().is_empty())
+        .map(ParquetStore::new)
+}
+
+// ==================== 보존 정책 ====================
+
+/// 계층형 저장소 보존 정책.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TieringPolicy {
+    /// 분봉을 롤업/아카이브하기까지의 보관 일수
+    pub rollup_after_days: i64,
+    /// 체결 틱을 아카이브하기까지의 보관 일수
+    pub tick_archive_after_days: i64,
+    /// 롤업 대상 타임프레임 (일봉 이하, 일봉은 시장 현지 날짜 기준)
+    pub rollup_targets: Vec<Timeframe>,
+    /// 체결 틱 아카이브 여부
+    pub archive_ticks: bool,
+}
+
+impl Default for TieringPolicy {
+    fn default() -> Self {
+        Self {
+            rollup_after_days: 30,
+            tick_archive_after_days: 30,
+            rollup_targets: vec![Timeframe::H1, Timeframe::D1],
+            archive_ticks: true,
+        }
+    }
+}
+
+impl TieringPolicy {
+    /// 분봉 보관 일수 설정.
+    pub fn with_rollup_after_days(mut self, days: i64) -> Self {
+        self.rollup_after_days = days.max(1);
+        self
+    }
+
+    /// 체결 틱 보관 일수 설정.
+    pub fn with_tick_archive_after_days(mut self, days: i64) -> Self {
+        self.tick_archive_after_days = days.max(1);
+        self
+    }
+
+    /// 체결 틱 아카이브 여부 설정.
+    pub fn with_archive_ticks(mut self, enabled: bool) -> Self {
+        self.archive_ticks = enabled;
+        self
+    }
+
+    /// 분봉 아카이브 기준 시각 (이 시각 이전 데이터 대상).
+    pub fn ohlcv_cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        day_start(now - Duration::days(self.rollup_after_days))
+    }
+
+    /// 체결 틱 아카이브 기준 시각.
+    pub fn tick_cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        day_start(now - Duration::days(self.tick_archive_after_days))
+    }
+}
+
+/// 계층형 저장소 실행 통계.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TieringStats {
+    /// 처리한 시계열 수 (분봉 심볼/타임프레임 + 틱 심볼)
+    pub series: usize,
+    /// 아카이브한 분봉 수
+    pub archived_candles: usize,
+    /// 새로 저장한 롤업 캔들 수
+    pub rolled_up_candles: usize,
+    /// 아카이브한 체결 틱 수
+    pub archived_ticks: usize,
+    /// DB에서 삭제한 행 수
+    pub deleted_rows: u64,
+    /// 실패한 시계열 수
+    pub failed: usize,
+}
+
+// ==================== 아카이브 목록 ====================
+
+/// 아카이브된 시계열 구간.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct ArchivedRange {
+    /// 데이터셋 (`ohlcv`, `trade_ticks`)
+    pub dataset: String,
+    /// 파티션 시장
+    pub market: String,
+    /// 심볼
+    pub symbol: String,
+    /// 타임프레임 (틱은 `tick`)
+    pub timeframe: String,
+    /// 구간 시작 (포함)
+    pub range_start: DateTime<Utc>,
+    /// 구간 종료 (미포함)
+    pub range_end: DateTime<Utc>,
+    /// 아카이브한 행 수
+    pub row_count: i64,
+    /// 아카이브 시각
+    pub archived_at: DateTime<Utc>,
+}
+
+/// 아카이브 구간 목록 저장소.
+#[derive(Clone)]
+pub struct ArchiveManifestRepository {
+    pool: PgPool,
+}
+
+impl ArchiveManifestRepository {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 아카이브 구간 기록 (같은 구간은 갱신).
+    pub async fn record(&self, range: &ArchivedRange) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO storage_archive_manifest
+                (dataset, market, symbol, timeframe, range_start, range_end, row_count, archived_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (dataset, symbol, timeframe, range_start) DO UPDATE SET
+                market = EXCLUDED.market,
+                range_end = GREATEST(storage_archive_manifest.range_end, EXCLUDED.range_end),
+                row_count = EXCLUDED.row_count,
+                archived_at = EXCLUDED.archived_at
+            "#,
+        )
+        .bind(&range.dataset)
+        .bind(&range.market)
+        .bind(&range.symbol)
+        .bind(&range.timeframe)
+        .bind(range.range_start)
+        .bind(range.range_end)
+        .bind(range.row_count)
+        .bind(range.archived_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// 조회 기간과 겹치는 아카이브 구간의 파티션 시장 (없으면 None).
+    pub async fn find_market(
+        &self,
+        dataset: ParquetDataset,
+        symbol: &str,
+        timeframe: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Option<String>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT market FROM storage_archive_manifest
+            WHERE dataset = $1 AND symbol = $2 AND timeframe = $3
+              AND range_start < $5 AND range_end > $4
+            LIMIT 1
+            "#,
+        )
+        .bind(dataset.as_str())
+        .bind(symbol)
+        .bind(timeframe)
+        .bind(start)
+        .bind(end)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 심볼의 아카이브 구간 목록 (시작 시각 오름차순).
+    pub async fn list(&self, dataset: ParquetDataset, symbol: &str) -> Result<Vec<ArchivedRange>> {
+        sqlx::query_as(
+            r#"
+            SELECT dataset, market, symbol, timeframe, range_start, range_end, row_count, archived_at
+            FROM storage_archive_manifest
+            WHERE dataset = $1 AND symbol = $2
+            ORDER BY timeframe, range_start
+            "#,
+        )
+        .bind(dataset.as_str())
+        .bind(symbol)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+}
+
+// ==================== 보존 정책 엔진 ====================
+
+/// 계층형 저장소 보존 정책 엔진.
+pub struct StorageTiering {
+    pool: PgPool,
+    cache: OhlcvCache,
+    store: ParquetStore,
+    manifest: ArchiveManifestRepository,
+    policy: TieringPolicy,
+}
+
+impl StorageTiering {
+    /// 아카이브 저장소로 엔진 생성 (기본 정책).
+    pub fn new(pool: PgPool, store: ParquetStore) -> Self {
+        Self {
+            cache: OhlcvCache::new(pool.clone()).without_archive(),
+            manifest: ArchiveManifestRepository::new(pool.clone()),
+            pool,
+            store,
+            policy: TieringPolicy::default(),
+        }
+    }
+
+    /// 보존 정책 설정.
+    pub fn with_policy(mut self, policy: TieringPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// 보존 정책.
+    pub fn policy(&self) -> &TieringPolicy {
+        &self.policy
+    }
+
+    /// 보존 정책 적용 (분봉 롤업/아카이브 → 체결 틱 아카이브).
+    ///
+    /// 시계열 하나가 실패해도 나머지는 계속 처리하며 `failed`에 집계합니다.
+    pub async fn run(&self, now: DateTime<Utc>) -> Result<TieringStats> {
+        let mut stats = TieringStats::default();
+        self.tier_ohlcv(self.policy.ohlcv_cutoff(now), &mut stats)
+            .await?;
+        if self.policy.archive_ticks {
+            self.archive_trade_ticks(self.policy.tick_cutoff(now), &mut stats)
+                .await?;
+        }
+        Ok(stats)
+    }
+
+    /// `cutoff` 이전 분봉을 롤업하고 아카이브합니다.
+    #[instrument(skip(self, stats))]
+    pub async fn tier_ohlcv(&self, cutoff: DateTime<Utc>, stats: &mut TieringStats) -> Result<()> {
+        let series: Vec<(String, String, DateTime<Utc>)> = sqlx::query_as(
+            r#"
+            SELECT symbol, timeframe, MIN(open_time)
+            FROM ohlcv
+            WHERE timeframe = ANY($1) AND open_time < $2
+            GROUP BY symbol, timeframe
+            ORDER BY symbol, timeframe
+            "#,
+        )
+        .bind(&ROLLUP_SOURCE_TIMEFRAMES[..])
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        for (symbol, timeframe, first) in series {
+            stats.series += 1;
+            if let Err(e) = self
+                .tier_ohlcv_series(&symbol, &timeframe, first, cutoff, stats)
+                .await
+            {
+                warn!(symbol = %symbol, timeframe = %timeframe, error = %e, "분봉 아카이브 실패");
+                stats.failed += 1;
+            }
+        }
+        Ok(())
+    }
+
+    async fn tier_ohlcv_series(
+        &self,
+        symbol: &str,
+        timeframe: &str,
+        first: DateTime<Utc>,
+        cutoff: DateTime<Utc>,
+        stats: &mut TieringStats,
+    ) -> Result<()> {
+        let tf: Timeframe = timeframe.parse().map_err(DataError::InvalidData)?;
+        let market = self.symbol_market(symbol).await?;
+        let timezone = market_timezone(&market);
+
+        for (start, end) in archive_windows(first, cutoff) {
+            let klines = self
+                .cache
+                .get_stored_klines_range(symbol, tf, start, end)
+                .await?;
+            if klines.is_empty() {
+                continue;
+            }
+
+            for target in &self.policy.rollup_targets {
+                let bars = rollup_klines(&klines, *target, timezone);
+                stats.rolled_up_candles += self.insert_rollups(symbol, *target, &bars).await?;
+            }
+
+            let rows = klines.len();
+            self.archive(ParquetDataset::Ohlcv, &market, klines).await?;
+            self.manifest
+                .record(&ArchivedRange {
+                    dataset: ParquetDataset::Ohlcv.as_str().to_string(),
+                    market: market.clone(),
+                    symbol: symbol.to_string(),
+                    timeframe: timeframe.to_string(),
+                    range_start: start,
+                    range_end: end,
+                    row_count: rows as i64,
+                    archived_at: Utc::now(),
+                })
+                .await?;
+            stats.deleted_rows += self
+                .cache
+                .delete_stored_klines_range(symbol, tf, start, end)
+                .await?;
+            stats.archived_candles += rows;
+
+            debug!(symbol, timeframe, %start, %end, rows, "분봉 구간 아카이브");
+        }
+        Ok(())
+    }
+
+    /// `cutoff` 이전 체결 틱을 아카이브합니다.
+    #[instrument(skip(self, stats))]
+    pub async fn archive_trade_ticks(
+        &self,
+        cutoff: DateTime<Utc>,
+        stats: &mut TieringStats,
+    ) -> Result<()> {
+        let symbols: Vec<(Uuid, String, String, String, DateTime<Utc>)> = sqlx::query_as(
+            r#"
+            SELECT t.symbol_id, s.base, s.quote, s.market_type::text, MIN(t.timestamp)
+            FROM trade_ticks t
+            JOIN symbols s ON s.id = t.symbol_id
+            WHERE t.timestamp < $1
+            GROUP BY t.symbol_id, s.base, s.quote, s.market_type
+            "#,
+        )
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        for (symbol_id, base, quote, market_type, first) in symbols {
+            stats.series += 1;
+            // 암호화폐는 BASE/QUOTE, 그 외는 종목 코드로 파티션
+            let ticker = if market_type == "crypto" {
+                format!("{}/{}", base, quote)
+            } else {
+                base
+            };
+            let market = market_type.to_uppercase();
+            if let Err(e) = self
+                .archive_tick_series(symbol_id, &ticker, &market, first, cutoff, stats)
+                .await
+            {
+                warn!(ticker = %ticker, error = %e, "체결 틱 아카이브 실패");
+                stats.failed += 1;
+            }
+        }
+        Ok(())
+    }
+
+    async fn archive_tick_series(
+        &self,
+        symbol_id: Uuid,
+        ticker: &str,
+        market: &str,
+        first: DateTime<Utc>,
+        cutoff: DateTime<Utc>,
+        stats: &mut TieringStats,
+    ) -> Result<()> {
+        for (start, end) in archive_windows(first, cutoff) {
+            let records: Vec<TradeTickRecord> = sqlx::query_as(
+                r#"
+                SELECT * FROM trade_ticks
+                WHERE symbol_id = $1 AND timestamp >= $2 AND timestamp < $3
+                ORDER BY timestamp ASC
+                "#,
+            )
+            .bind(symbol_id)
+            .bind(start)
+            .bind(end)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DataError::QueryError(e.to_string()))?;
+            if records.is_empty() {
+                continue;
+            }
+
+            let ticks = records
+                .iter()
+                .map(|record| record.to_trade_tick(ticker))
+                .collect::<Result<Vec<TradeTick>>>()?;
+            let rows = ticks.len();
+            self.archive(ParquetDataset::TradeTicks, market, ticks)
+                .await?;
+            self.manifest
+                .record(&ArchivedRange {
+                    dataset: ParquetDataset::TradeTicks.as_str().to_string(),
+                    market: market.to_string(),
+                    symbol: ticker.to_string(),
+                    timeframe: TICK_TIMEFRAME.to_string(),
+                    range_start: start,
+                    range_end: end,
+                    row_count: rows as i64,
+                    archived_at: Utc::now(),
+                })
+                .await?;
+
+            let deleted = sqlx::query(
+                r#"
+                DELETE FROM trade_ticks
+                WHERE symbol_id = $1 AND timestamp >= $2 AND timestamp < $3
+                "#,
+            )
+            .bind(symbol_id)
+            .bind(start)
+            .bind(end)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DataError::DeleteError(e.to_string()))?;
+            stats.deleted_rows += deleted.rows_affected();
+            stats.archived_ticks += rows;
+
+            debug!(ticker, %start, %end, rows, "체결 틱 구간 아카이브");
+        }
+        Ok(())
+    }
+
+    /// 레코드를 아카이브 파티션에 기록 (파일 입출력은 blocking 스레드에서 실행).
+    async fn archive<T>(&self, dataset: ParquetDataset, market: &str, rows: Vec<T>) -> Result<()>
+    where
+        T: ParquetRecord + Send + 'static,
+    {
+        let store = self.store.clone();
+        let market = market.to_string();
+        let partitions =
+            tokio::task::spawn_blocking(move || store.write_monthly(dataset, &market, rows))
+                .await
+                .map_err(|e| DataError::IoError(e.to_string()))??;
+        for partition in &partitions {
+            debug!(path = %self.store.partition_path(partition).display(), "아카이브 파티션 저장");
+        }
+        Ok(())
+    }
+
+    /// 롤업 캔들 저장.
+    ///
+    /// 구간 `[open_time, close_time)` 안에 이미 캔들이 있으면(제공자 캔들 포함) 저장하지 않습니다.
+    async fn insert_rollups(
+        &self,
+        symbol: &str,
+        timeframe: Timeframe,
+        bars: &[Kline],
+    ) -> Result<usize> {
+        let (Some(first), Some(last)) = (bars.first(), bars.last()) else {
+            return Ok(0);
+        };
+        let tf_str = timeframe_to_string(timeframe);
+
+        let existing: Vec<DateTime<Utc>> = sqlx::query_scalar(
+            r#"
+            SELECT open_time FROM ohlcv
+            WHERE symbol = $1 AND timeframe = $2 AND open_time >= $3 AND open_time < $4
+            ORDER BY open_time
+            "#,
+        )
+        .bind(symbol)
+        .bind(&tf_str)
+        .bind(first.open_time)
+        .bind(last.close_time)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+        let bars = uncovered_bars(bars, &existing);
+        let mut inserted = 0;
+
+        for chunk in bars.chunks(500) {
+            let open_times: Vec<DateTime<Utc>> = chunk.iter().map(|k| k.open_time).collect();
+            let opens: Vec<_> = chunk.iter().map(|k| k.open).collect();
+            let highs: Vec<_> = chunk.iter().map(|k| k.high).collect();
+            let lows: Vec<_> = chunk.iter().map(|k| k.low).collect();
+            let closes: Vec<_> = chunk.iter().map(|k| k.close).collect();
+            let volumes: Vec<_> = chunk.iter().map(|k| k.volume).collect();
+            let close_times: Vec<DateTime<Utc>> = chunk.iter().map(|k| k.close_time).collect();
+
+            let result = sqlx::query(
+                r#"
+                INSERT INTO ohlcv
+                    (symbol, timeframe, open_time, open, high, low, close, volume, close_time, fetched_at)
+                SELECT $1::text, $2::text, t.*, NOW() FROM UNNEST(
+                    $3::timestamptz[], $4::numeric[], $5::numeric[], $6::numeric[],
+                    $7::numeric[], $8::numeric[], $9::timestamptz[]
+                ) AS t
+                ON CONFLICT (symbol, timeframe, open_time) DO NOTHING
+                "#,
+            )
+            .bind(symbol)
+            .bind(&tf_str)
+            .bind(&open_times)
+            .bind(&opens)
+            .bind(&highs)
+            .bind(&lows)
+            .bind(&closes)
+            .bind(&volumes)
+            .bind(&close_times)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+            inserted += result.rows_affected() as usize;
+        }
+
+        if inserted > 0 {
+            info!(symbol, timeframe = %tf_str, inserted, "분봉 롤업 캔들 저장");
+        }
+        Ok(inserted)
+    }
+
+    /// 심볼의 시장 (symbol_info 기준, 없으면 UNKNOWN).
+    async fn symbol_market(&self, symbol: &str) -> Result<String> {
+        let market: Option<String> =
+            sqlx::query_scalar("SELECT market FROM symbol_info WHERE ticker = $1 LIMIT 1")
+                .bind(symbol)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| DataError::QueryError(e.to_string()))?;
+        Ok(market
+            .map(|m| m.to_uppercase())
+            .unwrap_or_else(|| UNKNOWN_MARKET.to_string()))
+    }
+}
+
+// ==================== 헬퍼 ====================
+
+/// 파티션 시장의 현지 시간대 (KR → KST, US → ET, 그 외 UTC).
+pub fn market_timezone(market: &str) -> MarketTimezone {
+    match market.to_uppercase().as_str() {
+        "KR" => MarketTimezone::Seoul,
+        "US" => MarketTimezone::NewYork,
+        _ => MarketTimezone::Utc,
+    }
+}
+
+/// 캔들을 상위 타임프레임으로 롤업합니다.
+///
+/// 일봉은 `timezone`의 현지 날짜(현지 자정 ~ 다음 날 자정)로, 그보다 짧은 타임프레임은
+/// UTC 기준 `target` 간격 경계로 묶습니다. 시가는 구간 첫 캔들, 종가는 마지막 캔들,
+/// 거래량은 합계입니다. 일봉보다 긴 타임프레임은 지원하지 않습니다.
+pub fn rollup_klines(klines: &[Kline], target: Timeframe, timezone: MarketTimezone) -> Vec<Kline> {
+    let secs = target.as_secs() as i64;
+    let daily = target == Timeframe::D1;
+    let mut sorted: Vec<&Kline> = klines.iter().collect();
+    sorted.sort_by_key(|k| k.open_time);
+
+    let mut buckets: BTreeMap<DateTime<Utc>, Kline> = BTreeMap::new();
+    for kline in sorted {
+        let (open_time, close_time) = if daily {
+            let date = timezone.to_local(kline.open_time).date_naive();
+            let next = date.succ_opt().unwrap_or(date);
+            (
+                timezone.to_utc(date, NaiveTime::MIN),
+                timezone.to_utc(next, NaiveTime::MIN),
+            )
+        } else {
+            let bucket = kline.open_time.timestamp().div_euclid(secs) * secs;
+            let open_time = DateTime::from_timestamp(bucket, 0).unwrap_or(kline.open_time);
+            (open_time, open_time + Duration::seconds(secs))
+        };
+        match buckets.entry(open_time) {
+            Entry::Vacant(entry) => {
+                entry.insert(Kline {
+                    ticker: kline.ticker.clone(),
+                    timeframe: target,
+                    open_time,
+                    open: kline.open,
+                    high: kline.high,
+                    low: kline.low,
+                    close: kline.close,
+                    volume: kline.volume,
+                    close_time,
+                    quote_volume: kline.quote_volume,
+                    num_trades: kline.num_trades,
+                });
+            }
+            Entry::Occupied(entry) => {
+                let bar = entry.into_mut();
+                bar.high = bar.high.max(kline.high);
+                bar.low = bar.low.min(kline.low);
+                bar.close = kline.close;
+                bar.volume += kline.volume;
+                bar.quote_volume = bar.quote_volume.zip(kline.quote_volume).map(|(a, b)| a + b);
+                bar.num_trades = bar
+                    .num_trades
+                    .zip(kline.num_trades)
+                    .map(|(a, b)| a.saturating_add(b));
+            }
+        }
+    }
+    buckets.into_values().collect()
+}
+
+/// 이미 있는 캔들(`existing` open_time)이 구간 `[open_time, close_time)`에 없는 롤업 캔들.
+fn uncovered_bars(bars: &[Kline], existing: &[DateTime<Utc>]) -> Vec<Kline> {
+    bars.iter()
+        .filter(|bar| {
+            !existing
+                .iter()
+                .any(|time| *time >= bar.open_time && *time < bar.close_time)
+        })
+        .cloned()
+        .collect()
+}
+
+/// `first`부터 `cutoff`까지의 월 단위 아카이브 구간 (`[start, end)`).
+pub fn archive_windows(
+    first: DateTime<Utc>,
+    cutoff: DateTime<Utc>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut windows = Vec::new();
+    let mut start = first;
+    while start < cutoff {
+        let end = next_month_start(start).min(cutoff);
+        windows.push((start, end));
+        start = end;
+    }
+    windows
+}
+
+fn next_month_start(time: DateTime<Utc>) -> DateTime<Utc> {
+    let date = time.date_naive();
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+fn day_start(time: DateTime<Utc>) -> DateTime<Utc> {
+    time.date_naive()
+        .and_hms_opt(0, 0, 0)
+        .map(|dt| dt.and_utc())
+        .unwrap_or(time)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn minute_bar(minute: i64, open: rust_decimal::Decimal, close: rust_decimal::Decimal) -> Kline {
+        let open_time =
+            Utc.with_ymd_and_hms(2024, 3, 4, 0, 0, 0).unwrap() + Duration::minutes(minute);
+        Kline {
+            ticker: "005930".to_string(),
+            timeframe: Timeframe::M30,
+            open_time,
+            open,
+            high: open.max(close) + dec!(1),
+            low: open.min(close) - dec!(1),
+            close,
+            volume: dec!(10),
+            close_time: open_time + Duration::minutes(30),
+            quote_volume: None,
+            num_trades: Some(5),
+        }
+    }
+
+    #[test]
+    fn test_rollup_klines() {
+        // 00:00, 00:30, 01:00 30분봉 → 1시간봉 2개, 일봉 1개
+        let bars = vec![
+            minute_bar(60, dec!(103), dec!(104)),
+            minute_bar(0, dec!(100), dec!(102)),
+            minute_bar(30, dec!(102), dec!(99)),
+        ];
+
+        let hourly = rollup_klines(&bars, Timeframe::H1, MarketTimezone::Utc);
+        assert_eq!(hourly.len(), 2);
+        assert_eq!(hourly[0].timeframe, Timeframe::H1);
+        assert_eq!((hourly[0].open, hourly[0].close), (dec!(100), dec!(99)));
+        assert_eq!((hourly[0].high, hourly[0].low), (dec!(103), dec!(98)));
+        assert_eq!(hourly[0].volume, dec!(20));
+        assert_eq!(hourly[0].num_trades, Some(10));
+        assert_eq!(hourly[0].close_time, hourly[1].open_time);
+
+        let daily = rollup_klines(&bars, Timeframe::D1, MarketTimezone::Utc);
+        assert_eq!(daily.len(), 1);
+        assert_eq!(
+            daily[0].open_time,
+            Utc.with_ymd_and_hms(2024, 3, 4, 0, 0, 0).unwrap()
+        );
+        assert_eq!((daily[0].open, daily[0].close), (dec!(100), dec!(104)));
+        assert_eq!(daily[0].volume, dec!(30));
+    }
+
+    #[test]
+    fn test_rollup_daily_by_session_date() {
+        // 2024-03-04 14:30 UTC, 15:00 UTC → KST 기준 2024-03-04 23:30, 03-05 00:00
+        let bars = vec![
+            minute_bar(14 * 60 + 30, dec!(100), dec!(101)),
+            minute_bar(15 * 60, dec!(101), dec!(102)),
+        ];
+        let daily = rollup_klines(&bars, Timeframe::D1, market_timezone("KR"));
+        assert_eq!(daily.len(), 2);
+        // KST 자정 = 전날 15:00 UTC
+        assert_eq!(
+            daily[0].open_time,
+            Utc.with_ymd_and_hms(2024, 3, 3, 15, 0, 0).unwrap()
+        );
+        assert_eq!(daily[0].close_time, daily[1].open_time);
+        assert_eq!(
+            daily[1].close_time,
+            Utc.with_ymd_and_hms(2024, 3, 5, 15, 0, 0).unwrap()
+        );
+
+        // 같은 KST 날짜의 제공자 일봉(다른 오프셋)이 있으면 롤업 일봉은 저장하지 않음
+        let provider = Utc.with_ymd_and_hms(2024, 3, 4, 0, 0, 0).unwrap();
+        let remaining = uncovered_bars(&daily, &[provider]);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].open_time, daily[1].open_time);
+    }
+
+    #[test]
+    fn test_archive_windows() {
+        let first = Utc.with_ymd_and_hms(2023, 11, 20, 9, 0, 0).unwrap();
+        let cutoff = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let windows = archive_windows(first, cutoff);
+        assert_eq!(
+            windows,
+            vec![
+                (first, Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap()),
+                (
+                    Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap(),
+                    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+                ),
+                (Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(), cutoff),
+            ]
+        );
+        assert!(archive_windows(cutoff, cutoff).is_empty());
+
+        // 기준 시각은 UTC 자정으로 내림
+        let policy = TieringPolicy::default().with_rollup_after_days(10);
+        let now = Utc.with_ymd_and_hms(2024, 1, 25, 13, 45, 0).unwrap();
+        assert_eq!(policy.ohlcv_cutoff(now), cutoff);
+    }
+}
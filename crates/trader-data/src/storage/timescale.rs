@@ -263,6 +263,23 @@ impl SymbolRepository {
         Ok(id)
     }
 
+    /// 심볼 ID를 조회합니다 (없으면 `None`, 생성하지 않음).
+    pub async fn find_id(&self, ticker: &str, quote: &str, exchange: &str) -> Result<Option<Uuid>> {
+        let existing: Option<(Uuid,)> = sqlx::query_as(
+            r#"
+            SELECT id FROM symbols
+            WHERE exchange = $1 AND base = $2 AND quote = $3
+            "#,
+        )
+        .bind(exchange)
+        .bind(ticker)
+        .bind(quote)
+        .fetch_optional(self.db.pool())
+        .await?;
+
+        Ok(existing.map(|(id,)| id))
+    }
+
     /// ID로 심볼을 조회합니다.
     pub async fn get_by_id(&self, id: Uuid) -> Result<SymbolRecord> {
         sqlx::query_as("SELECT * FROM symbols WHERE id = $1")
@@ -446,6 +463,20 @@ pub struct TradeTickRecord {
     pub timestamp: DateTime<Utc>,
 }
 
+impl TradeTickRecord {
+    /// 도메인 체결 틱으로 변환 (레코드에는 티커가 없으므로 인자로 받음).
+    pub fn to_trade_tick(&self, ticker: &str) -> Result<TradeTick> {
+        Ok(TradeTick {
+            ticker: ticker.to_string(),
+            id: self.trade_id.clone(),
+            price: self.price,
+            quantity: self.quantity,
+            side: self.side.parse().map_err(DataError::ParseError)?,
+            timestamp: self.timestamp,
+        })
+    }
+}
+
 // =============================================================================
 // Order Repository
 // =============================================================================
@@ -0,0 +1,963 @@
+//! 주문 유형 × 유효 기간 적합성(conformance) 테스트 스위트.
+//!
+//! 모든 `OrderType` × `TimeInForce` 조합에 대해 같은 봉 시나리오를 재생하고,
+//! 최종 주문 상태와 체결 수량이 기대값과 일치하는지 검증합니다.
+//! 시뮬레이션 엔진([`MatchingEngine`], [`MockOrderEngine`])은 물론
+//! 모의 서버에 연결한 실제 커넥터도 [`ConformanceVenue`]를 구현해 같은 스위트를 실행합니다.
+//!
+//! # 시나리오 규칙
+//!
+//! - 모든 주문은 기준 봉(`reference`) 종가에서 제출됩니다
+//! - 각 봉의 체결 가능 수량은 `거래량 × CONFORMANCE_PARTICIPATION`입니다
+//! - 봉 내부 가격 경로는 [`bar_path`]를 따릅니다
+//!
+//! # 예제
+//!
+//! ```ignore
+//! use trader_exchange::conformance::{run_conformance, MatchingEngineVenue};
+//!
+//! let mut venue = MatchingEngineVenue::new(CONFORMANCE_PARTICIPATION);
+//! run_conformance(&mut venue).await.assert_passed();
+//! ```
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use trader_core::{
+    Kline, OrderBook, OrderBookLevel, OrderRequest, OrderStatusType, OrderType, ProviderError,
+    Side, Ticker, TimeInForce, Timeframe,
+};
+
+use crate::{
+    provider::mock_order_engine::MockOrderEngine,
+    simulated::{
+        order_rules::{bar_path, validate_order_request},
+        MatchingEngine, OrderMatch,
+    },
+};
+
+/// 적합성 시나리오의 종목.
+pub const CONFORMANCE_TICKER: &str = "CONF/USDT";
+
+/// 적합성 시나리오의 봉 거래량 참여율 (봉당 체결 가능 수량 = 거래량 × 참여율).
+pub const CONFORMANCE_PARTICIPATION: Decimal = dec!(0.1);
+
+/// 적합성 시나리오의 전략 ID.
+const CONFORMANCE_STRATEGY: &str = "conformance";
+
+/// 적합성 테스트 케이스.
+#[derive(Debug, Clone)]
+pub struct ConformanceCase {
+    /// 케이스 이름
+    pub name: &'static str,
+    /// 제출할 주문
+    pub request: OrderRequest,
+    /// 제출 직전 봉 (종가에서 제출)
+    pub reference: Kline,
+    /// 제출 이후 재생할 봉
+    pub bars: Vec<Kline>,
+    /// 기대 최종 상태
+    pub expected_status: OrderStatusType,
+    /// 기대 누적 체결 수량
+    pub expected_filled: Decimal,
+}
+
+/// 주문 상태 스냅샷.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConformanceOrderState {
+    /// 주문 상태
+    pub status: OrderStatusType,
+    /// 누적 체결 수량
+    pub filled_quantity: Decimal,
+}
+
+/// 적합성 테스트 대상 거래소.
+///
+/// 한 케이스는 `reset` → `advance(reference)` → `submit` → `advance(bars...)` →
+/// `order_state` 순서로 실행됩니다.
+#[async_trait]
+pub trait ConformanceVenue: Send {
+    /// 거래소 이름 (리포트용).
+    fn name(&self) -> &str;
+
+    /// 이 거래소가 케이스의 주문 유형을 지원하는지 여부.
+    ///
+    /// 지원하지 않는 케이스는 실패가 아닌 건너뜀으로 기록됩니다.
+    fn supports(&self, _case: &ConformanceCase) -> bool {
+        true
+    }
+
+    /// 케이스 간 상태 초기화.
+    async fn reset(&mut self) -> Result<(), ProviderError>;
+
+    /// 봉 하나를 진행합니다.
+    async fn advance(&mut self, bar: &Kline) -> Result<(), ProviderError>;
+
+    /// 주문을 제출하고 주문 ID를 반환합니다.
+    async fn submit(&mut self, request: &OrderRequest) -> Result<String, ProviderError>;
+
+    /// 주문의 현재 상태.
+    async fn order_state(&mut self, order_id: &str) -> Option<ConformanceOrderState>;
+}
+
+/// 케이스 실패 내역.
+#[derive(Debug, Clone)]
+pub struct ConformanceFailure {
+    /// 케이스 이름
+    pub case: &'static str,
+    /// 기대 상태
+    pub expected: ConformanceOrderState,
+    /// 실제 상태 (조회 실패 시 `None`)
+    pub actual: Option<ConformanceOrderState>,
+    /// 에러 메시지
+    pub error: Option<String>,
+}
+
+/// 적합성 테스트 결과.
+#[derive(Debug, Clone, Default)]
+pub struct ConformanceReport {
+    /// 거래소 이름
+    pub venue: String,
+    /// 통과한 케이스
+    pub passed: Vec<&'static str>,
+    /// 건너뛴 케이스
+    pub skipped: Vec<&'static str>,
+    /// 실패한 케이스
+    pub failures: Vec<ConformanceFailure>,
+}
+
+impl ConformanceReport {
+    /// 모든 실행 케이스가 통과했는지 여부.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// 실패한 케이스가 있으면 내역과 함께 panic합니다.
+    pub fn assert_passed(&self) {
+        if self.is_success() {
+            return;
+        }
+
+        let details: Vec<String> = self
+            .failures
+            .iter()
+            .map(|f| match (&f.actual, &f.error) {
+                (_, Some(error)) => format!("  - {}: 에러 {}", f.case, error),
+                (Some(actual), None) => format!(
+                    "  - {}: 기대 {:?}/{}, 실제 {:?}/{}",
+                    f.case,
+                    f.expected.status,
+                    f.expected.filled_quantity,
+                    actual.status,
+                    actual.filled_quantity
+                ),
+                (None, None) => format!("  - {}: 주문 상태 없음", f.case),
+            })
+            .collect();
+
+        panic!(
+            "[{}] 적합성 테스트 실패 {}건 (통과 {}, 건너뜀 {}):\n{}",
+            self.venue,
+            self.failures.len(),
+            self.passed.len(),
+            self.skipped.len(),
+            details.join("\n")
+        );
+    }
+}
+
+/// 표준 케이스 전체를 실행합니다.
+pub async fn run_conformance<V: ConformanceVenue + ?Sized>(venue: &mut V) -> ConformanceReport {
+    run_cases(venue, &standard_cases()).await
+}
+
+/// 주어진 케이스를 실행합니다.
+pub async fn run_cases<V: ConformanceVenue + ?Sized>(
+    venue: &mut V,
+    cases: &[ConformanceCase],
+) -> ConformanceReport {
+    let mut report = ConformanceReport {
+        venue: venue.name().to_string(),
+        ..Default::default()
+    };
+
+    for case in cases {
+        if !venue.supports(case) {
+            report.skipped.push(case.name);
+            continue;
+        }
+
+        let expected = ConformanceOrderState {
+            status: case.expected_status,
+            filled_quantity: case.expected_filled,
+        };
+
+        match run_case(venue, case).await {
+            Ok(Some(actual)) if actual == expected => report.passed.push(case.name),
+            Ok(actual) => report.failures.push(ConformanceFailure {
+                case: case.name,
+                expected,
+                actual,
+                error: None,
+            }),
+            Err(e) => report.failures.push(ConformanceFailure {
+                case: case.name,
+                expected,
+                actual: None,
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+
+    report
+}
+
+async fn run_case<V: ConformanceVenue + ?Sized>(
+    venue: &mut V,
+    case: &ConformanceCase,
+) -> Result<Option<ConformanceOrderState>, ProviderError> {
+    venue.reset().await?;
+    venue.advance(&case.reference).await?;
+    let order_id = venue.submit(&case.request).await?;
+    for bar in &case.bars {
+        venue.advance(bar).await?;
+    }
+    Ok(venue.order_state(&order_id).await)
+}
+
+// ============================================================================
+// 표준 케이스
+// ============================================================================
+
+/// 시나리오 시작 시각.
+fn scenario_start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()
+}
+
+/// `index`번째 1분봉 (거래량 1000 → 체결 가능 100).
+fn bar(index: i64, open: Decimal, high: Decimal, low: Decimal, close: Decimal) -> Kline {
+    let open_time = scenario_start() + Duration::minutes(index);
+    Kline {
+        ticker: CONFORMANCE_TICKER.to_string(),
+        timeframe: Timeframe::M1,
+        open_time,
+        close_time: open_time + Duration::minutes(1),
+        open,
+        high,
+        low,
+        close,
+        volume: dec!(1000),
+        quote_volume: None,
+        num_trades: None,
+    }
+}
+
+/// 기준 봉: 종가 100.
+fn reference_bar() -> Kline {
+    bar(0, dec!(100), dec!(101), dec!(99), dec!(100))
+}
+
+/// 기준 봉 이후 `index`번째 봉.
+fn next_bar(index: i64, open: Decimal, high: Decimal, low: Decimal, close: Decimal) -> Kline {
+    bar(index + 1, open, high, low, close)
+}
+
+/// 지정가에 닿지 않는 횡보 봉 (99.5 ~ 100.5).
+fn flat_bar(index: i64) -> Kline {
+    next_bar(index, dec!(100), dec!(100.5), dec!(99.5), dec!(100))
+}
+
+fn request(
+    side: Side,
+    order_type: OrderType,
+    quantity: Decimal,
+    price: Option<Decimal>,
+    stop_price: Option<Decimal>,
+    time_in_force: TimeInForce,
+) -> OrderRequest {
+    OrderRequest {
+        ticker: CONFORMANCE_TICKER.to_string(),
+        side,
+        order_type,
+        quantity,
+        price,
+        stop_price,
+        time_in_force,
+        expire_time: None,
+        trailing_percent: None,
+        client_order_id: None,
+        strategy_id: None,
+    }
+}
+
+/// 2% 추적 트레일링 스톱 매도.
+fn trailing_sell(quantity: Decimal, activation_price: Decimal) -> OrderRequest {
+    OrderRequest::trailing_stop(
+        CONFORMANCE_TICKER.to_string(),
+        Side::Sell,
+        quantity,
+        dec!(2),
+        Some(activation_price),
+    )
+}
+
+fn case(
+    name: &'static str,
+    request: OrderRequest,
+    bars: Vec<Kline>,
+    expected_status: OrderStatusType,
+    expected_filled: Decimal,
+) -> ConformanceCase {
+    ConformanceCase {
+        name,
+        request,
+        reference: reference_bar(),
+        bars,
+        expected_status,
+        expected_filled,
+    }
+}
+
+/// 표준 적합성 케이스 (`OrderType` × `TimeInForce` 매트릭스).
+///
+/// 봉당 체결 가능 수량은 100이며, 수량 150 주문으로 부분 체결을 강제합니다.
+pub fn standard_cases() -> Vec<ConformanceCase> {
+    use OrderStatusType::*;
+    use OrderType::*;
+    use Side::*;
+    use TimeInForce::*;
+
+    let first_close = flat_bar(0).close_time;
+    let submit_time = reference_bar().close_time;
+
+    // 하락 봉: 99 → 99.5 → 96 → 96.5
+    let drop = |index| next_bar(index, dec!(99), dec!(99.5), dec!(96), dec!(96.5));
+    // 상승 봉: 100 → 99.8 → 103.5 → 103
+    let rally = |index| next_bar(index, dec!(100), dec!(103.5), dec!(99.8), dec!(103));
+    // 98까지 내려오는 봉
+    let dip = |index| next_bar(index, dec!(99), dec!(99.5), dec!(97.5), dec!(98.5));
+
+    let mut cases = vec![
+        // ==================== 시장가 ====================
+        case(
+            "market_gtc_full",
+            request(Buy, Market, dec!(50), None, None, GTC),
+            vec![],
+            Filled,
+            dec!(50),
+        ),
+        case(
+            "market_gtc_partial_expires_remainder",
+            request(Buy, Market, dec!(150), None, None, GTC),
+            vec![flat_bar(0)],
+            Expired,
+            dec!(100),
+        ),
+        case(
+            "market_ioc_partial",
+            request(Sell, Market, dec!(150), None, None, IOC),
+            vec![],
+            Expired,
+            dec!(100),
+        ),
+        case(
+            "market_fok_insufficient",
+            request(Buy, Market, dec!(150), None, None, FOK),
+            vec![],
+            Expired,
+            dec!(0),
+        ),
+        case(
+            "market_fok_full",
+            request(Sell, Market, dec!(50), None, None, FOK),
+            vec![],
+            Filled,
+            dec!(50),
+        ),
+        // ==================== 지정가 ====================
+        case(
+            "limit_gtc_marketable",
+            request(Buy, Limit, dec!(50), Some(dec!(101)), None, GTC),
+            vec![],
+            Filled,
+            dec!(50),
+        ),
+        case(
+            "limit_gtc_rests_until_touched",
+            request(Buy, Limit, dec!(50), Some(dec!(98)), None, GTC),
+            vec![flat_bar(0), dip(1)],
+            Filled,
+            dec!(50),
+        ),
+        case(
+            "limit_gtc_partial_against_bar_volume",
+            request(Buy, Limit, dec!(150), Some(dec!(98)), None, GTC),
+            vec![dip(0)],
+            PartiallyFilled,
+            dec!(100),
+        ),
+        case(
+            "limit_gtc_fills_across_bars",
+            request(Buy, Limit, dec!(150), Some(dec!(98)), None, GTC),
+            vec![dip(0), flat_bar(1), dip(2)],
+            Filled,
+            dec!(150),
+        ),
+        case(
+            "limit_ioc_not_marketable",
+            request(Buy, Limit, dec!(50), Some(dec!(98)), None, IOC),
+            vec![dip(0)],
+            Expired,
+            dec!(0),
+        ),
+        case(
+            "limit_ioc_partial",
+            request(Buy, Limit, dec!(150), Some(dec!(101)), None, IOC),
+            vec![],
+            Expired,
+            dec!(100),
+        ),
+        case(
+            "limit_fok_insufficient",
+            request(Buy, Limit, dec!(150), Some(dec!(101)), None, FOK),
+            vec![],
+            Expired,
+            dec!(0),
+        ),
+        case(
+            "limit_fok_full",
+            request(Sell, Limit, dec!(50), Some(dec!(99)), None, FOK),
+            vec![],
+            Filled,
+            dec!(50),
+        ),
+        case(
+            "limit_gtd_expires_untouched",
+            request(Buy, Limit, dec!(50), Some(dec!(95)), None, GTD).with_expire_time(first_close),
+            vec![flat_bar(0), dip(1)],
+            Expired,
+            dec!(0),
+        ),
+        case(
+            "limit_gtd_partial_then_expires",
+            request(Buy, Limit, dec!(150), Some(dec!(98)), None, GTD).with_expire_time(first_close),
+            vec![dip(0), dip(1)],
+            Expired,
+            dec!(100),
+        ),
+        case(
+            "limit_gtd_already_expired",
+            request(Buy, Limit, dec!(50), Some(dec!(101)), None, GTD).with_expire_time(submit_time),
+            vec![],
+            Expired,
+            dec!(0),
+        ),
+        case(
+            "limit_gtd_fills_before_expiry",
+            request(Buy, Limit, dec!(50), Some(dec!(98)), None, GTD)
+                .with_expire_time(first_close + Duration::minutes(1)),
+            vec![flat_bar(0), dip(1), flat_bar(2)],
+            Filled,
+            dec!(50),
+        ),
+        // ==================== 손절 (시장가 전환) ====================
+        case(
+            "stop_loss_sell_triggered",
+            request(Sell, StopLoss, dec!(50), None, Some(dec!(97)), GTC),
+            vec![flat_bar(0), drop(1)],
+            Filled,
+            dec!(50),
+        ),
+        case(
+            "stop_loss_not_triggered",
+            request(Sell, StopLoss, dec!(50), None, Some(dec!(95)), GTC),
+            vec![flat_bar(0), drop(1)],
+            Open,
+            dec!(0),
+        ),
+        case(
+            "stop_loss_partial_expires_remainder",
+            request(Sell, StopLoss, dec!(150), None, Some(dec!(97)), GTC),
+            vec![drop(0), flat_bar(1)],
+            Expired,
+            dec!(100),
+        ),
+        case(
+            "stop_loss_buy_fok_insufficient",
+            request(Buy, StopLoss, dec!(150), None, Some(dec!(103)), FOK),
+            vec![rally(0)],
+            Expired,
+            dec!(0),
+        ),
+        case(
+            "stop_loss_gtd_expires_untriggered",
+            request(Sell, StopLoss, dec!(50), None, Some(dec!(95)), GTD)
+                .with_expire_time(first_close),
+            vec![flat_bar(0), drop(1)],
+            Expired,
+            dec!(0),
+        ),
+        // ==================== 손절 지정가 ====================
+        case(
+            "stop_limit_gtc_rests_after_trigger",
+            request(
+                Sell,
+                StopLossLimit,
+                dec!(50),
+                Some(dec!(97)),
+                Some(dec!(97)),
+                GTC,
+            ),
+            vec![
+                next_bar(0, dec!(98), dec!(98.5), dec!(96), dec!(96.2)),
+                next_bar(1, dec!(96.2), dec!(97.5), dec!(96), dec!(97.2)),
+            ],
+            Filled,
+            dec!(50),
+        ),
+        case(
+            "stop_limit_ioc_not_marketable_at_trigger",
+            request(
+                Sell,
+                StopLossLimit,
+                dec!(50),
+                Some(dec!(97)),
+                Some(dec!(97)),
+                IOC,
+            ),
+            vec![
+                next_bar(0, dec!(98), dec!(98.5), dec!(96), dec!(96.2)),
+                next_bar(1, dec!(96.2), dec!(97.5), dec!(96), dec!(97.2)),
+            ],
+            Expired,
+            dec!(0),
+        ),
+        case(
+            "stop_limit_fok_full",
+            request(
+                Buy,
+                StopLossLimit,
+                dec!(50),
+                Some(dec!(104)),
+                Some(dec!(102)),
+                FOK,
+            ),
+            vec![rally(0)],
+            Filled,
+            dec!(50),
+        ),
+        case(
+            "stop_limit_gtd_triggered_partial_then_expires",
+            request(
+                Buy,
+                StopLossLimit,
+                dec!(150),
+                Some(dec!(104)),
+                Some(dec!(102)),
+                GTD,
+            )
+            .with_expire_time(first_close),
+            vec![rally(0), rally(1)],
+            Expired,
+            dec!(100),
+        ),
+        // ==================== 익절 ====================
+        case(
+            "take_profit_sell_triggered",
+            request(Sell, TakeProfit, dec!(50), None, Some(dec!(103)), GTC),
+            vec![flat_bar(0), rally(1)],
+            Filled,
+            dec!(50),
+        ),
+        case(
+            "take_profit_sell_not_triggered_on_drop",
+            request(Sell, TakeProfit, dec!(50), None, Some(dec!(103)), GTC),
+            vec![drop(0)],
+            Open,
+            dec!(0),
+        ),
+        case(
+            "take_profit_ioc_partial",
+            request(Sell, TakeProfit, dec!(150), None, Some(dec!(103)), IOC),
+            vec![rally(0)],
+            Expired,
+            dec!(100),
+        ),
+        case(
+            "take_profit_limit_buy_triggered",
+            request(
+                Buy,
+                TakeProfitLimit,
+                dec!(50),
+                Some(dec!(97.5)),
+                Some(dec!(97)),
+                GTC,
+            ),
+            vec![drop(0)],
+            Filled,
+            dec!(50),
+        ),
+        case(
+            "take_profit_limit_gtd_expires_untriggered",
+            request(
+                Buy,
+                TakeProfitLimit,
+                dec!(50),
+                Some(dec!(94.5)),
+                Some(dec!(94)),
+                GTD,
+            )
+            .with_expire_time(first_close),
+            vec![drop(0), drop(1)],
+            Expired,
+            dec!(0),
+        ),
+    ];
+
+    // ==================== 트레일링 스톱 ====================
+    // 활성화 102, 2% 추적: 105에서 활성화 → 102.9 이하에서 트리거
+    let trail_up = next_bar(0, dec!(100), dec!(105), dec!(99.9), dec!(104.5));
+    let trail_down = next_bar(1, dec!(104), dec!(104.2), dec!(102), dec!(102.5));
+
+    cases.extend([
+        case(
+            "trailing_stop_activates_and_triggers",
+            trailing_sell(dec!(50), dec!(102)),
+            vec![trail_up.clone(), trail_down.clone()],
+            Filled,
+            dec!(50),
+        ),
+        case(
+            "trailing_stop_not_activated",
+            trailing_sell(dec!(50), dec!(106)),
+            vec![trail_up.clone(), trail_down.clone()],
+            Open,
+            dec!(0),
+        ),
+        case(
+            "trailing_stop_partial_expires_remainder",
+            trailing_sell(dec!(150), dec!(102)),
+            vec![trail_up.clone(), trail_down.clone()],
+            Expired,
+            dec!(100),
+        ),
+        case(
+            "trailing_stop_gtd_expires_before_trigger",
+            trailing_sell(dec!(50), dec!(102)).with_expire_time(first_close),
+            vec![trail_up, trail_down],
+            Expired,
+            dec!(0),
+        ),
+    ]);
+
+    cases
+}
+
+// ============================================================================
+// 내장 거래소 구현
+// ============================================================================
+
+/// 주문별 누적 상태.
+#[derive(Debug, Default)]
+struct OrderLedger {
+    orders: HashMap<String, ConformanceOrderState>,
+}
+
+impl OrderLedger {
+    fn record(&mut self, order_id: &str, status: OrderStatusType, filled: Decimal) {
+        let entry = self
+            .orders
+            .entry(order_id.to_string())
+            .or_insert(ConformanceOrderState {
+                status,
+                filled_quantity: Decimal::ZERO,
+            });
+        entry.status = status;
+        entry.filled_quantity += filled;
+    }
+
+    fn get(&self, order_id: &str) -> Option<ConformanceOrderState> {
+        self.orders.get(order_id).copied()
+    }
+
+    fn clear(&mut self) {
+        self.orders.clear();
+    }
+}
+
+/// 백테스트 [`MatchingEngine`] 기반 거래소.
+pub struct MatchingEngineVenue {
+    engine: MatchingEngine,
+    last_bar: Option<Kline>,
+    ledger: OrderLedger,
+}
+
+impl MatchingEngineVenue {
+    /// 봉 거래량 참여율을 지정해 생성 (수수료/슬리피지 없음).
+    pub fn new(participation: Decimal) -> Self {
+        Self {
+            engine: MatchingEngine::new(Decimal::ZERO, Decimal::ZERO)
+                .with_volume_participation(participation),
+            last_bar: None,
+            ledger: OrderLedger::default(),
+        }
+    }
+
+    fn record(&mut self, order_match: &OrderMatch) {
+        self.ledger.record(
+            &order_match.order_id,
+            order_match.status,
+            order_match.filled_quantity,
+        );
+    }
+}
+
+#[async_trait]
+impl ConformanceVenue for MatchingEngineVenue {
+    fn name(&self) -> &str {
+        "matching_engine"
+    }
+
+    async fn reset(&mut self) -> Result<(), ProviderError> {
+        self.engine.clear();
+        self.last_bar = None;
+        self.ledger.clear();
+        Ok(())
+    }
+
+    async fn advance(&mut self, bar: &Kline) -> Result<(), ProviderError> {
+        for order_match in self.engine.process_kline(&bar.ticker, bar) {
+            self.record(&order_match);
+        }
+        self.last_bar = Some(bar.clone());
+        Ok(())
+    }
+
+    async fn submit(&mut self, request: &OrderRequest) -> Result<String, ProviderError> {
+        validate_order_request(request).map_err(ProviderError::Api)?;
+        let bar = self
+            .last_bar
+            .as_ref()
+            .ok_or_else(|| ProviderError::Other("기준 봉이 없습니다".to_string()))?;
+
+        let order_match = self.engine.submit_order(request, bar.close, bar.close_time);
+        self.record(&order_match);
+        Ok(order_match.order_id)
+    }
+
+    async fn order_state(&mut self, order_id: &str) -> Option<ConformanceOrderState> {
+        self.ledger.get(order_id)
+    }
+}
+
+/// 모의투자 [`MockOrderEngine`] 기반 거래소.
+///
+/// 봉 가격 경로의 각 지점을 틱으로 재생하며, 호가창은 해당 가격에 봉의
+/// 남은 체결 가능 수량만큼의 단일 레벨로 구성합니다.
+pub struct MockOrderEngineVenue {
+    engine: MockOrderEngine,
+    participation: Decimal,
+    liquidity: Decimal,
+    last_bar: Option<Kline>,
+    ledger: OrderLedger,
+    quantities: HashMap<String, Decimal>,
+}
+
+impl MockOrderEngineVenue {
+    /// 봉 거래량 참여율을 지정해 생성 (수수료/슬리피지 없음).
+    pub fn new(participation: Decimal) -> Self {
+        Self {
+            engine: MockOrderEngine::new(Decimal::ZERO, Decimal::ZERO),
+            participation,
+            liquidity: Decimal::ZERO,
+            last_bar: None,
+            ledger: OrderLedger::default(),
+            quantities: HashMap::new(),
+        }
+    }
+
+    fn ticker(symbol: &str, price: Decimal, timestamp: DateTime<Utc>) -> Ticker {
+        Ticker {
+            ticker: symbol.to_string(),
+            bid: price,
+            ask: price,
+            last: price,
+            volume_24h: Decimal::ZERO,
+            high_24h: price,
+            low_24h: price,
+            change_24h: Decimal::ZERO,
+            change_24h_percent: Decimal::ZERO,
+            timestamp,
+        }
+    }
+
+    fn order_book(&self, symbol: &str, price: Decimal, timestamp: DateTime<Utc>) -> OrderBook {
+        let levels = if self.liquidity > Decimal::ZERO {
+            vec![OrderBookLevel {
+                price,
+                quantity: self.liquidity,
+            }]
+        } else {
+            Vec::new()
+        };
+        OrderBook {
+            ticker: symbol.to_string(),
+            bids: levels.clone(),
+            asks: levels,
+            timestamp,
+        }
+    }
+
+    /// 체결 반영 (남은 체결 가능 수량 차감).
+    fn record_fill(&mut self, order_id: &str, filled: Decimal) {
+        self.liquidity = (self.liquidity - filled).max(Decimal::ZERO);
+        let quantity = self.quantities.get(order_id).copied().unwrap_or(filled);
+        let previous = self
+            .ledger
+            .get(order_id)
+            .map(|s| s.filled_quantity)
+            .unwrap_or(Decimal::ZERO);
+        let status = if previous + filled >= quantity {
+            OrderStatusType::Filled
+        } else {
+            OrderStatusType::PartiallyFilled
+        };
+        self.ledger.record(order_id, status, filled);
+    }
+
+    fn collect_expired(&mut self) {
+        for expired in self.engine.take_expired_orders() {
+            self.ledger
+                .record(&expired.order_id, OrderStatusType::Expired, Decimal::ZERO);
+        }
+    }
+}
+
+#[async_trait]
+impl ConformanceVenue for MockOrderEngineVenue {
+    fn name(&self) -> &str {
+        "mock_order_engine"
+    }
+
+    async fn reset(&mut self) -> Result<(), ProviderError> {
+        self.engine.clear();
+        self.liquidity = Decimal::ZERO;
+        self.last_bar = None;
+        self.ledger.clear();
+        self.quantities.clear();
+        Ok(())
+    }
+
+    async fn advance(&mut self, bar: &Kline) -> Result<(), ProviderError> {
+        self.liquidity = bar.volume * self.participation;
+
+        for price in bar_path(bar) {
+            let ticker = Self::ticker(&bar.ticker, price, bar.open_time);
+            let book = self.order_book(&bar.ticker, price, bar.open_time);
+            for fill in self.engine.on_price_tick(&bar.ticker, &ticker, &book) {
+                self.record_fill(&fill.order_id, fill.filled_quantity);
+            }
+            self.collect_expired();
+        }
+
+        // 봉 종료 시점까지 남은 GTD 주문 만료
+        self.engine.expire_orders(bar.close_time);
+        self.collect_expired();
+
+        self.last_bar = Some(bar.clone());
+        Ok(())
+    }
+
+    async fn submit(&mut self, request: &OrderRequest) -> Result<String, ProviderError> {
+        let bar = self
+            .last_bar
+            .as_ref()
+            .ok_or_else(|| ProviderError::Other("기준 봉이 없습니다".to_string()))?;
+        let ticker = Self::ticker(&request.ticker, bar.close, bar.close_time);
+        let book = self.order_book(&request.ticker, bar.close, bar.close_time);
+
+        let submission = self
+            .engine
+            .submit_order(request, &ticker, Some(&book), CONFORMANCE_STRATEGY)
+            .map_err(ProviderError::Api)?;
+
+        let filled = submission
+            .fill
+            .as_ref()
+            .map(|f| f.filled_quantity)
+            .unwrap_or(Decimal::ZERO);
+        self.liquidity = (self.liquidity - filled).max(Decimal::ZERO);
+        self.quantities
+            .insert(submission.order_id.clone(), request.quantity);
+        self.ledger
+            .record(&submission.order_id, submission.status, filled);
+        Ok(submission.order_id)
+    }
+
+    async fn order_state(&mut self, order_id: &str) -> Option<ConformanceOrderState> {
+        self.ledger.get(order_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_cases_are_valid() {
+        let cases = standard_cases();
+        let mut names: Vec<_> = cases.iter().map(|c| c.name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), cases.len(), "케이스 이름 중복");
+
+        for case in &cases {
+            assert!(
+                validate_order_request(&case.request).is_ok(),
+                "{}: 유효하지 않은 주문",
+                case.name
+            );
+            assert!(case.expected_filled <= case.request.quantity);
+        }
+
+        // 모든 주문 유형 × 유효 기간을 다룸
+        for order_type in [
+            OrderType::Market,
+            OrderType::Limit,
+            OrderType::StopLoss,
+            OrderType::StopLossLimit,
+            OrderType::TakeProfit,
+            OrderType::TakeProfitLimit,
+            OrderType::TrailingStop,
+        ] {
+            assert!(cases.iter().any(|c| c.request.order_type == order_type));
+        }
+        for tif in [
+            TimeInForce::GTC,
+            TimeInForce::IOC,
+            TimeInForce::FOK,
+            TimeInForce::GTD,
+        ] {
+            assert!(cases.iter().any(|c| c.request.time_in_force == tif));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_records_failures() {
+        let mut cases = standard_cases();
+        cases.truncate(1);
+        cases[0].expected_filled = dec!(49);
+
+        let mut venue = MatchingEngineVenue::new(CONFORMANCE_PARTICIPATION);
+        let report = run_cases(&mut venue, &cases).await;
+        assert!(!report.is_success());
+        assert_eq!(report.failures[0].case, "market_gtc_full");
+        assert_eq!(
+            report.failures[0].actual.map(|s| s.filled_quantity),
+            Some(dec!(50))
+        );
+    }
+}
@@ -20,7 +20,7 @@ use sha2::Sha256;
 use tracing::{debug, error, info, warn};
 use trader_core::{
     Kline, MarketType, OrderBook, OrderBookLevel, OrderRequest, OrderStatus, OrderType, Position,
-    RoundMethod, Side, Symbol, TickSizeProvider, Ticker, Timeframe, TradeTick,
+    RoundMethod, Side, Symbol, TickSizeProvider, Ticker, TimeInForce, Timeframe, TradeTick,
 };
 
 use crate::{
@@ -245,6 +245,9 @@ struct BinanceOrderResponse {
     price: String,
     orig_qty: String,
     executed_qty: String,
+    /// 누적 체결 대금 (평균 체결가 계산용)
+    #[serde(default)]
+    cummulative_quote_qty: Option<String>,
     status: String,
     #[serde(rename = "type")]
     order_type: String,
@@ -545,6 +548,23 @@ impl BinanceClient {
             _ => None,
         };
 
+        // 평균 체결가: 누적 체결 대금 / 체결 수량 (대금이 없으면 주문 가격)
+        let filled_quantity = Self::parse_decimal(&resp.executed_qty);
+        let average_price = if filled_quantity > Decimal::ZERO {
+            let quote_qty = resp
+                .cummulative_quote_qty
+                .as_deref()
+                .map(Self::parse_decimal)
+                .unwrap_or_default();
+            if quote_qty > Decimal::ZERO {
+                Some(quote_qty / filled_quantity)
+            } else {
+                Some(Self::parse_decimal(&resp.price))
+            }
+        } else {
+            None
+        };
+
         OrderStatus {
             order_id: resp.order_id.to_string(),
             client_order_id: Some(resp.client_order_id.clone()),
@@ -553,12 +573,8 @@ impl BinanceClient {
             quantity: Some(Self::parse_decimal(&resp.orig_qty)),
             price: Some(Self::parse_decimal(&resp.price)),
             status,
-            filled_quantity: Self::parse_decimal(&resp.executed_qty),
-            average_price: if Self::parse_decimal(&resp.executed_qty) > Decimal::ZERO {
-                Some(Self::parse_decimal(&resp.price))
-            } else {
-                None
-            },
+            filled_quantity,
+            average_price,
             updated_at: Utc::now(),
         }
     }
@@ -828,7 +844,12 @@ impl BinanceClient {
         if let Some(price) = request.price {
             let rounded_price = round_price(price, is_buy);
             params.push(("price", rounded_price.to_string()));
-            params.push(("timeInForce", "GTC".to_string()));
+            let time_in_force = match request.time_in_force {
+                TimeInForce::IOC => "IOC",
+                TimeInForce::FOK => "FOK",
+                TimeInForce::GTC | TimeInForce::GTD => "GTC",
+            };
+            params.push(("timeInForce", time_in_force.to_string()));
         }
 
         // 스톱 가격이 있으면 추가 (라운딩 적용)
@@ -11,10 +11,10 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use trader_core::{
     domain::{
-        ExchangeProvider, MarketDataProvider, OrderStatusType, PendingOrder, Side,
+        ExchangeProvider, MarketDataProvider, OrderStatus, OrderStatusType, PendingOrder, Side,
         StrategyAccountInfo, StrategyPositionInfo, Trade,
     },
-    ProviderError, QuoteData,
+    OrderBook, OrderBookLevel, ProviderError, QuoteData,
 };
 use uuid::Uuid;
 
@@ -99,6 +99,51 @@ pub struct BithumbTicker {
     pub timestamp: i64,
 }
 
+/// Bithumb 호가 단위.
+#[derive(Debug, Deserialize)]
+pub struct BithumbOrderbookUnit {
+    pub ask_price: f64,
+    pub bid_price: f64,
+    pub ask_size: f64,
+    pub bid_size: f64,
+}
+
+/// Bithumb 호가창 (`/orderbook` 응답).
+#[derive(Debug, Deserialize)]
+pub struct BithumbOrderbook {
+    pub market: String,
+    pub orderbook_units: Vec<BithumbOrderbookUnit>,
+}
+
+impl From<BithumbOrderbook> for OrderBook {
+    fn from(ob: BithumbOrderbook) -> Self {
+        let mut bids = Vec::with_capacity(ob.orderbook_units.len());
+        let mut asks = Vec::with_capacity(ob.orderbook_units.len());
+
+        for unit in ob.orderbook_units {
+            asks.push(OrderBookLevel {
+                price: Decimal::from_f64_retain(unit.ask_price).unwrap_or_default(),
+                quantity: Decimal::from_f64_retain(unit.ask_size).unwrap_or_default(),
+            });
+            bids.push(OrderBookLevel {
+                price: Decimal::from_f64_retain(unit.bid_price).unwrap_or_default(),
+                quantity: Decimal::from_f64_retain(unit.bid_size).unwrap_or_default(),
+            });
+        }
+
+        // 매도 호가는 가격 오름차순, 매수 호가는 가격 내림차순
+        asks.sort_by_key(|a| a.price);
+        bids.sort_by_key(|b| std::cmp::Reverse(b.price));
+
+        OrderBook {
+            ticker: ob.market,
+            bids,
+            asks,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BithumbOrder {
     pub uuid: String,
@@ -116,6 +161,67 @@ pub struct BithumbOrder {
     pub locked: Option<String>,
     pub executed_volume: Option<String>,
     pub trades_count: Option<u64>,
+    /// 개별 체결 내역 (주문 조회 응답에만 포함)
+    pub trades: Option<Vec<BithumbOrderTrade>>,
+}
+
+impl BithumbOrder {
+    /// 거래소 중립 주문 상태로 변환.
+    ///
+    /// 평균 체결가는 개별 체결의 체결 대금/수량으로 계산하고, 체결 내역이 없으면 주문 가격을 사용합니다.
+    pub fn to_order_status(&self) -> OrderStatus {
+        let parse = |v: &Option<String>| v.as_deref().and_then(|v| Decimal::from_str(v).ok());
+        let filled_quantity = parse(&self.executed_volume).unwrap_or_default();
+
+        let (funds, volume) = self.trades.iter().flatten().fold(
+            (Decimal::ZERO, Decimal::ZERO),
+            |(funds, volume), t| {
+                (
+                    funds + Decimal::from_str(&t.funds).unwrap_or_default(),
+                    volume + Decimal::from_str(&t.volume).unwrap_or_default(),
+                )
+            },
+        );
+        let average_price = if volume > Decimal::ZERO {
+            Some(funds / volume)
+        } else if filled_quantity > Decimal::ZERO {
+            parse(&self.price)
+        } else {
+            None
+        };
+
+        let status = match self.state.as_str() {
+            "done" => OrderStatusType::Filled,
+            "cancel" => OrderStatusType::Cancelled,
+            _ if filled_quantity > Decimal::ZERO => OrderStatusType::PartiallyFilled,
+            _ => OrderStatusType::Open,
+        };
+
+        OrderStatus {
+            order_id: self.uuid.clone(),
+            client_order_id: None,
+            ticker: Some(self.market.clone()),
+            side: match self.side.as_str() {
+                "bid" => Some(Side::Buy),
+                "ask" => Some(Side::Sell),
+                _ => None,
+            },
+            quantity: parse(&self.volume),
+            price: parse(&self.price),
+            status,
+            filled_quantity,
+            average_price,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+/// Bithumb 주문 조회 응답의 개별 체결 (GET /v1/order).
+#[derive(Debug, Deserialize, Clone)]
+pub struct BithumbOrderTrade {
+    pub price: String,
+    pub volume: String,
+    pub funds: String,
 }
 
 /// Bithumb 거래 내역 (§2.7 API 응답).
@@ -558,4 +664,20 @@ impl MarketDataProvider for BithumbClient {
     fn provider_name(&self) -> &str {
         "bithumb"
     }
+
+    async fn get_order_book(&self, symbol: &str) -> Result<OrderBook, ProviderError> {
+        let query = serde_json::json!({
+            "markets": symbol
+        });
+
+        let books: Vec<BithumbOrderbook> = self
+            .request(Method::GET, "/orderbook", Some(&query), None)
+            .await?;
+
+        books
+            .into_iter()
+            .next()
+            .map(OrderBook::from)
+            .ok_or_else(|| ProviderError::Api("Orderbook not found".to_string()))
+    }
 }
@@ -0,0 +1,742 @@
+//! FIX 4.4 주문 클라이언트.
+//!
+//! [`FixSession`] 위에서 NewOrderSingle / OrderCancelRequest /
+//! OrderCancelReplaceRequest를 송신하고, ExecutionReport를 [`OrderStatus`]로
+//! 변환하여 요청 응답 및 체결 알림 구독자에게 전달합니다.
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use tokio::{
+    sync::{broadcast, mpsc, oneshot},
+    task::JoinHandle,
+    time::timeout,
+};
+use tracing::{debug, info, warn};
+use trader_core::{
+    domain::{OrderRequest, OrderResponse},
+    OrderStatus, OrderStatusType, OrderType, ProviderError, Side, TimeInForce,
+};
+use uuid::Uuid;
+
+use super::{
+    message::{format_timestamp, msg_type, tags, FixMessage},
+    session::{FixSession, FixSessionConfig},
+    store::{FileSequenceStore, MemorySequenceStore, SequenceStore},
+};
+
+// ============================================================================
+// 설정
+// ============================================================================
+
+/// FIX 게이트웨이 설정.
+#[derive(Clone)]
+pub struct FixConfig {
+    /// Acceptor 호스트
+    pub host: String,
+    /// Acceptor 포트
+    pub port: u16,
+    /// SenderCompID(49)
+    pub sender_comp_id: String,
+    /// TargetCompID(56)
+    pub target_comp_id: String,
+    /// 하트비트 간격 (초)
+    pub heartbeat_interval_secs: u64,
+    /// 로그온 시 시퀀스 번호 초기화
+    pub reset_on_logon: bool,
+    /// Username(553)
+    pub username: Option<String>,
+    /// Password(554)
+    pub password: Option<String>,
+    /// Account(1)
+    pub account: Option<String>,
+    /// 시퀀스 번호 저장 파일 (None이면 메모리에만 보관)
+    pub seq_store_path: Option<PathBuf>,
+    /// 주문 응답 대기 시간 (밀리초)
+    pub response_timeout_ms: u64,
+    /// Provider가 보고할 거래소 이름
+    pub exchange_name: String,
+}
+
+impl std::fmt::Debug for FixConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FixConfig")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("sender_comp_id", &self.sender_comp_id)
+            .field("target_comp_id", &self.target_comp_id)
+            .field("heartbeat_interval_secs", &self.heartbeat_interval_secs)
+            .field("reset_on_logon", &self.reset_on_logon)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .field("account", &"***")
+            .field("seq_store_path", &self.seq_store_path)
+            .field("exchange_name", &self.exchange_name)
+            .finish()
+    }
+}
+
+impl FixConfig {
+    /// 새 설정 생성 (하트비트 30초, 응답 타임아웃 5초).
+    pub fn new(
+        host: impl Into<String>,
+        port: u16,
+        sender_comp_id: impl Into<String>,
+        target_comp_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            host: host.into(),
+            port,
+            sender_comp_id: sender_comp_id.into(),
+            target_comp_id: target_comp_id.into(),
+            heartbeat_interval_secs: 30,
+            reset_on_logon: false,
+            username: None,
+            password: None,
+            account: None,
+            seq_store_path: None,
+            response_timeout_ms: 5000,
+            exchange_name: "fix".to_string(),
+        }
+    }
+
+    /// 로그온 인증 정보 설정.
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.username = Some(username.into());
+        self.password = Some(password.into());
+        self
+    }
+
+    /// 계좌 설정.
+    pub fn with_account(mut self, account: impl Into<String>) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// 하트비트 간격 설정.
+    pub fn with_heartbeat_interval(mut self, secs: u64) -> Self {
+        self.heartbeat_interval_secs = secs;
+        self
+    }
+
+    /// 로그온 시 시퀀스 초기화 여부 설정.
+    pub fn with_reset_on_logon(mut self, reset: bool) -> Self {
+        self.reset_on_logon = reset;
+        self
+    }
+
+    /// 시퀀스 번호 저장 파일 설정.
+    pub fn with_seq_store_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.seq_store_path = Some(path.into());
+        self
+    }
+
+    /// 주문 응답 대기 시간 설정.
+    pub fn with_response_timeout_ms(mut self, ms: u64) -> Self {
+        self.response_timeout_ms = ms;
+        self
+    }
+
+    /// 거래소 이름 설정 (예: 프라임 브로커 식별자).
+    pub fn with_exchange_name(mut self, name: impl Into<String>) -> Self {
+        self.exchange_name = name.into();
+        self
+    }
+
+    fn session_config(&self) -> FixSessionConfig {
+        FixSessionConfig {
+            sender_comp_id: self.sender_comp_id.clone(),
+            target_comp_id: self.target_comp_id.clone(),
+            heartbeat_interval: Duration::from_secs(self.heartbeat_interval_secs),
+            reset_on_logon: self.reset_on_logon,
+            username: self.username.clone(),
+            password: self.password.clone(),
+            logon_timeout: Duration::from_millis(self.response_timeout_ms),
+        }
+    }
+}
+
+// ============================================================================
+// 클라이언트
+// ============================================================================
+
+/// 주문 정정/취소에 필요한 원주문 정보.
+#[derive(Debug, Clone)]
+struct TrackedOrder {
+    cl_ord_id: String,
+    ticker: String,
+    side: Side,
+    quantity: Decimal,
+    price: Option<Decimal>,
+    ord_type: &'static str,
+}
+
+type PendingMap = Arc<Mutex<HashMap<String, oneshot::Sender<Result<FixMessage, ProviderError>>>>>;
+
+/// FIX 주문 클라이언트.
+pub struct FixClient {
+    config: FixConfig,
+    session: Arc<FixSession>,
+    /// ClOrdID → 응답 대기자
+    pending: PendingMap,
+    /// OrderID → 원주문 정보
+    orders: Mutex<HashMap<String, TrackedOrder>>,
+    execution_tx: broadcast::Sender<OrderStatus>,
+    dispatcher: JoinHandle<()>,
+}
+
+impl FixClient {
+    /// Acceptor에 연결하고 로그온.
+    ///
+    /// `seq_store_path`가 설정되어 있으면 시퀀스 번호를 파일에 유지합니다.
+    pub async fn connect(config: FixConfig) -> Result<Self, ProviderError> {
+        let store: Arc<dyn SequenceStore> = match &config.seq_store_path {
+            Some(path) => Arc::new(FileSequenceStore::open(path)?),
+            None => Arc::new(MemorySequenceStore::new()),
+        };
+        Self::connect_with_store(config, store).await
+    }
+
+    /// 지정한 시퀀스 저장소로 연결.
+    pub async fn connect_with_store(
+        config: FixConfig,
+        store: Arc<dyn SequenceStore>,
+    ) -> Result<Self, ProviderError> {
+        let addr = format!("{}:{}", config.host, config.port);
+        let (app_tx, app_rx) = mpsc::unbounded_channel();
+        let session = FixSession::connect(&addr, config.session_config(), store, app_tx).await?;
+
+        let pending: PendingMap = Arc::new(Mutex::new(HashMap::new()));
+        let (execution_tx, _) = broadcast::channel(256);
+        let dispatcher = tokio::spawn(Self::dispatch_loop(
+            app_rx,
+            Arc::clone(&pending),
+            execution_tx.clone(),
+        ));
+
+        Ok(Self {
+            config,
+            session,
+            pending,
+            orders: Mutex::new(HashMap::new()),
+            execution_tx,
+            dispatcher,
+        })
+    }
+
+    /// 설정 참조.
+    pub fn config(&self) -> &FixConfig {
+        &self.config
+    }
+
+    /// 세션 참조.
+    pub fn session(&self) -> &FixSession {
+        &self.session
+    }
+
+    /// 로그온 상태인지 확인.
+    pub fn is_logged_on(&self) -> bool {
+        self.session.is_logged_on()
+    }
+
+    /// ExecutionReport 구독.
+    ///
+    /// 요청 응답뿐 아니라 비동기 체결/취소 알림도 모두 전달됩니다.
+    pub fn subscribe_execution_reports(&self) -> broadcast::Receiver<OrderStatus> {
+        self.execution_tx.subscribe()
+    }
+
+    /// Logout 후 연결 종료.
+    pub async fn logout(&self) -> Result<(), ProviderError> {
+        self.session.logout(None).await
+    }
+
+    /// NewOrderSingle 송신.
+    pub async fn place_order(
+        &self,
+        request: &OrderRequest,
+    ) -> Result<OrderResponse, ProviderError> {
+        let ord_type = fix_ord_type(request.order_type)?;
+        let cl_ord_id = request
+            .client_order_id
+            .clone()
+            .unwrap_or_else(new_cl_ord_id);
+
+        let mut message = FixMessage::new(msg_type::NEW_ORDER_SINGLE)
+            .with_field(tags::CL_ORD_ID, &cl_ord_id)
+            .with_field(tags::HANDL_INST, "1")
+            .with_field(tags::SYMBOL, &request.ticker)
+            .with_field(tags::SIDE, fix_side(request.side))
+            .with_field(tags::TRANSACT_TIME, format_timestamp(Utc::now()))
+            .with_field(tags::ORDER_QTY, request.quantity)
+            .with_field(tags::ORD_TYPE, ord_type)
+            .with_field(
+                tags::TIME_IN_FORCE,
+                fix_time_in_force(request.time_in_force),
+            );
+        if let Some(account) = &self.config.account {
+            message.set_field(tags::ACCOUNT, account);
+        }
+        if matches!(ord_type, "2" | "4") {
+            let price = request.price.ok_or_else(|| {
+                ProviderError::Api(format!("{} 주문에는 가격이 필요합니다", request.order_type))
+            })?;
+            message.set_field(tags::PRICE, price);
+        }
+        if matches!(ord_type, "3" | "4") {
+            let stop_price = request.stop_price.ok_or_else(|| {
+                ProviderError::Api(format!(
+                    "{} 주문에는 스톱 가격이 필요합니다",
+                    request.order_type
+                ))
+            })?;
+            message.set_field(tags::STOP_PX, stop_price);
+        }
+        if request.time_in_force == TimeInForce::GTD {
+            let expire_time = request.expire_time.ok_or_else(|| {
+                ProviderError::Api("GTD 주문에는 만료 시각이 필요합니다".to_string())
+            })?;
+            message.set_field(tags::EXPIRE_TIME, format_timestamp(expire_time));
+        }
+
+        info!(
+            ticker = %request.ticker,
+            side = %request.side,
+            ord_type = ord_type,
+            quantity = %request.quantity,
+            cl_ord_id = %cl_ord_id,
+            "FIX 신규 주문"
+        );
+
+        let report = self.request(message, &cl_ord_id).await?;
+        let status = execution_report_to_status(&report)?;
+        if status.status == OrderStatusType::Rejected {
+            return Err(ProviderError::Api(format!(
+                "FIX 주문 거부: {}",
+                report.get(tags::TEXT).unwrap_or("사유 없음")
+            )));
+        }
+
+        self.orders.lock().unwrap().insert(
+            status.order_id.clone(),
+            TrackedOrder {
+                cl_ord_id,
+                ticker: request.ticker.clone(),
+                side: request.side,
+                quantity: request.quantity,
+                price: request.price,
+                ord_type,
+            },
+        );
+
+        Ok(OrderResponse {
+            order_no: status.order_id,
+            order_time: status.updated_at.format("%H%M%S").to_string(),
+        })
+    }
+
+    /// OrderCancelRequest 송신.
+    pub async fn cancel_order(&self, order_id: &str, ticker: &str) -> Result<(), ProviderError> {
+        let tracked = self.tracked(order_id)?;
+        let cl_ord_id = new_cl_ord_id();
+        let symbol = if ticker.is_empty() {
+            tracked.ticker.as_str()
+        } else {
+            ticker
+        };
+
+        let message = FixMessage::new(msg_type::ORDER_CANCEL_REQUEST)
+            .with_field(tags::ORIG_CL_ORD_ID, &tracked.cl_ord_id)
+            .with_field(tags::ORDER_ID, order_id)
+            .with_field(tags::CL_ORD_ID, &cl_ord_id)
+            .with_field(tags::SYMBOL, symbol)
+            .with_field(tags::SIDE, fix_side(tracked.side))
+            .with_field(tags::TRANSACT_TIME, format_timestamp(Utc::now()))
+            .with_field(tags::ORDER_QTY, tracked.quantity);
+
+        info!(order_id = order_id, cl_ord_id = %cl_ord_id, "FIX 주문 취소");
+
+        let report = self.request(message, &cl_ord_id).await?;
+        let status = execution_report_to_status(&report)?;
+        if status.status == OrderStatusType::Rejected {
+            return Err(ProviderError::Api(format!(
+                "FIX 주문 취소 거부: {}",
+                report.get(tags::TEXT).unwrap_or("사유 없음")
+            )));
+        }
+
+        let mut orders = self.orders.lock().unwrap();
+        if status.status.is_final() {
+            orders.remove(order_id);
+        } else if let Some(order) = orders.get_mut(order_id) {
+            // PendingCancel: 이후 요청은 새 ClOrdID를 원주문으로 참조
+            order.cl_ord_id = cl_ord_id;
+        }
+        Ok(())
+    }
+
+    /// OrderCancelReplaceRequest 송신.
+    pub async fn modify_order(
+        &self,
+        order_id: &str,
+        ticker: &str,
+        quantity: Option<Decimal>,
+        price: Option<Decimal>,
+    ) -> Result<OrderResponse, ProviderError> {
+        if quantity.is_none() && price.is_none() {
+            return Err(ProviderError::Api(
+                "정정할 수량 또는 가격이 필요합니다".to_string(),
+            ));
+        }
+        let tracked = self.tracked(order_id)?;
+        let cl_ord_id = new_cl_ord_id();
+        let new_quantity = quantity.unwrap_or(tracked.quantity);
+        let new_price = price.or(tracked.price);
+        let symbol = if ticker.is_empty() {
+            tracked.ticker.clone()
+        } else {
+            ticker.to_string()
+        };
+
+        let mut message = FixMessage::new(msg_type::ORDER_CANCEL_REPLACE_REQUEST)
+            .with_field(tags::ORIG_CL_ORD_ID, &tracked.cl_ord_id)
+            .with_field(tags::ORDER_ID, order_id)
+            .with_field(tags::CL_ORD_ID, &cl_ord_id)
+            .with_field(tags::HANDL_INST, "1")
+            .with_field(tags::SYMBOL, &symbol)
+            .with_field(tags::SIDE, fix_side(tracked.side))
+            .with_field(tags::TRANSACT_TIME, format_timestamp(Utc::now()))
+            .with_field(tags::ORDER_QTY, new_quantity)
+            .with_field(tags::ORD_TYPE, tracked.ord_type);
+        if let Some(account) = &self.config.account {
+            message.set_field(tags::ACCOUNT, account);
+        }
+        if let Some(new_price) = new_price {
+            message.set_field(tags::PRICE, new_price);
+        }
+
+        info!(
+            order_id = order_id,
+            cl_ord_id = %cl_ord_id,
+            quantity = ?quantity,
+            price = ?price,
+            "FIX 주문 정정"
+        );
+
+        let report = self.request(message, &cl_ord_id).await?;
+        let status = execution_report_to_status(&report)?;
+        if status.status == OrderStatusType::Rejected {
+            return Err(ProviderError::Api(format!(
+                "FIX 주문 정정 거부: {}",
+                report.get(tags::TEXT).unwrap_or("사유 없음")
+            )));
+        }
+
+        {
+            let mut orders = self.orders.lock().unwrap();
+            orders.remove(order_id);
+            orders.insert(
+                status.order_id.clone(),
+                TrackedOrder {
+                    cl_ord_id,
+                    ticker: symbol,
+                    quantity: new_quantity,
+                    price: new_price,
+                    ..tracked
+                },
+            );
+        }
+
+        Ok(OrderResponse {
+            order_no: status.order_id,
+            order_time: status.updated_at.format("%H%M%S").to_string(),
+        })
+    }
+
+    fn tracked(&self, order_id: &str) -> Result<TrackedOrder, ProviderError> {
+        self.orders
+            .lock()
+            .unwrap()
+            .get(order_id)
+            .cloned()
+            .ok_or_else(|| {
+                ProviderError::Api(format!(
+                    "이 세션에서 제출하지 않은 주문입니다: {}",
+                    order_id
+                ))
+            })
+    }
+
+    /// 요청 송신 후 같은 ClOrdID의 첫 응답을 기다립니다.
+    async fn request(
+        &self,
+        message: FixMessage,
+        cl_ord_id: &str,
+    ) -> Result<FixMessage, ProviderError> {
+        if !self.session.is_logged_on() {
+            return Err(ProviderError::Network(
+                "FIX 세션이 로그온 상태가 아닙니다".to_string(),
+            ));
+        }
+
+        let (tx, rx) = oneshot::channel();
+        self.pending
+            .lock()
+            .unwrap()
+            .insert(cl_ord_id.to_string(), tx);
+
+        if let Err(e) = self.session.send(message).await {
+            self.pending.lock().unwrap().remove(cl_ord_id);
+            return Err(e);
+        }
+
+        let wait = Duration::from_millis(self.config.response_timeout_ms);
+        match timeout(wait, rx).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(ProviderError::Network(
+                "응답 수신 전에 FIX 세션이 종료되었습니다".to_string(),
+            )),
+            Err(_) => {
+                self.pending.lock().unwrap().remove(cl_ord_id);
+                Err(ProviderError::Network(format!(
+                    "FIX 응답 타임아웃 (ClOrdID {})",
+                    cl_ord_id
+                )))
+            }
+        }
+    }
+
+    async fn dispatch_loop(
+        mut app_rx: mpsc::UnboundedReceiver<FixMessage>,
+        pending: PendingMap,
+        execution_tx: broadcast::Sender<OrderStatus>,
+    ) {
+        while let Some(message) = app_rx.recv().await {
+            let cl_ord_id = message.get(tags::CL_ORD_ID).map(str::to_string);
+            let waiter = cl_ord_id
+                .as_deref()
+                .and_then(|id| pending.lock().unwrap().remove(id));
+
+            match message.msg_type() {
+                msg_type::EXECUTION_REPORT => {
+                    match execution_report_to_status(&message) {
+                        Ok(status) => {
+                            let _ = execution_tx.send(status);
+                        }
+                        Err(e) => warn!(error = %e, "ExecutionReport 변환 실패"),
+                    }
+                    if let Some(waiter) = waiter {
+                        let _ = waiter.send(Ok(message));
+                    }
+                }
+                msg_type::ORDER_CANCEL_REJECT => {
+                    let text = message.get(tags::TEXT).unwrap_or("사유 없음");
+                    let request = match message.get(tags::CXL_REJ_RESPONSE_TO) {
+                        Some("2") => "정정",
+                        _ => "취소",
+                    };
+                    warn!(cl_ord_id = ?cl_ord_id, reason = text, "FIX 주문 {} 거부", request);
+                    if let Some(waiter) = waiter {
+                        let _ = waiter.send(Err(ProviderError::Api(format!(
+                            "FIX 주문 {} 거부: {}",
+                            request, text
+                        ))));
+                    }
+                }
+                other => {
+                    debug!(msg_type = other, "처리하지 않는 FIX 애플리케이션 메시지");
+                    if let Some(waiter) = waiter {
+                        let _ = waiter.send(Ok(message));
+                    }
+                }
+            }
+        }
+
+        // 세션 종료: 대기 중인 요청을 즉시 실패시킴
+        pending.lock().unwrap().clear();
+    }
+}
+
+impl Drop for FixClient {
+    fn drop(&mut self) {
+        self.dispatcher.abort();
+    }
+}
+
+// ============================================================================
+// 변환
+// ============================================================================
+
+/// ExecutionReport(35=8)를 [`OrderStatus`]로 변환.
+pub fn execution_report_to_status(message: &FixMessage) -> Result<OrderStatus, ProviderError> {
+    if message.msg_type() != msg_type::EXECUTION_REPORT {
+        return Err(ProviderError::Parse(format!(
+            "ExecutionReport가 아닙니다: MsgType={}",
+            message.msg_type()
+        )));
+    }
+
+    let order_id = message
+        .get(tags::ORDER_ID)
+        .ok_or_else(|| ProviderError::Parse("ExecutionReport에 OrderID 없음".to_string()))?;
+    let ord_status = message
+        .get(tags::ORD_STATUS)
+        .ok_or_else(|| ProviderError::Parse("ExecutionReport에 OrdStatus 없음".to_string()))?;
+    let status = map_ord_status(ord_status)
+        .ok_or_else(|| ProviderError::Parse(format!("알 수 없는 OrdStatus: {}", ord_status)))?;
+
+    Ok(OrderStatus {
+        order_id: order_id.to_string(),
+        client_order_id: message.get(tags::CL_ORD_ID).map(str::to_string),
+        ticker: message.get(tags::SYMBOL).map(str::to_string),
+        side: message.get(tags::SIDE).and_then(parse_side),
+        quantity: message.get_decimal(tags::ORDER_QTY),
+        price: message.get_decimal(tags::PRICE),
+        status,
+        filled_quantity: message.get_decimal(tags::CUM_QTY).unwrap_or(Decimal::ZERO),
+        average_price: message.get_decimal(tags::AVG_PX).filter(|px| !px.is_zero()),
+        updated_at: message
+            .get_timestamp(tags::TRANSACT_TIME)
+            .unwrap_or_else(Utc::now),
+    })
+}
+
+/// OrdStatus(39) → [`OrderStatusType`].
+pub fn map_ord_status(value: &str) -> Option<OrderStatusType> {
+    let status = match value {
+        // PendingNew
+        "A" => OrderStatusType::Pending,
+        // New, Replaced, PendingCancel, Stopped, Suspended, Calculated,
+        // AcceptedForBidding, PendingReplace: 아직 주문장에 남아 있음
+        "0" | "5" | "6" | "7" | "9" | "B" | "D" | "E" => OrderStatusType::Open,
+        "1" => OrderStatusType::PartiallyFilled,
+        "2" => OrderStatusType::Filled,
+        "4" => OrderStatusType::Cancelled,
+        "8" => OrderStatusType::Rejected,
+        // DoneForDay, Expired
+        "3" | "C" => OrderStatusType::Expired,
+        _ => return None,
+    };
+    Some(status)
+}
+
+fn fix_side(side: Side) -> &'static str {
+    match side {
+        Side::Buy => "1",
+        Side::Sell => "2",
+    }
+}
+
+fn parse_side(value: &str) -> Option<Side> {
+    match value {
+        "1" => Some(Side::Buy),
+        "2" | "5" | "6" => Some(Side::Sell),
+        _ => None,
+    }
+}
+
+fn fix_ord_type(order_type: OrderType) -> Result<&'static str, ProviderError> {
+    match order_type {
+        OrderType::Market => Ok("1"),
+        OrderType::Limit => Ok("2"),
+        OrderType::StopLoss => Ok("3"),
+        OrderType::StopLossLimit => Ok("4"),
+        OrderType::TakeProfit | OrderType::TakeProfitLimit | OrderType::TrailingStop => {
+            Err(ProviderError::Unsupported(format!(
+                "FIX 4.4 표준 OrdType에 {} 주문이 없습니다",
+                order_type
+            )))
+        }
+    }
+}
+
+fn fix_time_in_force(tif: TimeInForce) -> &'static str {
+    match tif {
+        TimeInForce::GTC => "1",
+        TimeInForce::IOC => "3",
+        TimeInForce::FOK => "4",
+        TimeInForce::GTD => "6",
+    }
+}
+
+fn new_cl_ord_id() -> String {
+    format!("ZQ-{}", Uuid::new_v4().simple())
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn report(ord_status: &str) -> FixMessage {
+        FixMessage::new(msg_type::EXECUTION_REPORT)
+            .with_field(tags::ORDER_ID, "BRK-1")
+            .with_field(tags::CL_ORD_ID, "ZQ-1")
+            .with_field(tags::EXEC_ID, "EX-1")
+            .with_field(tags::EXEC_TYPE, "F")
+            .with_field(tags::ORD_STATUS, ord_status)
+            .with_field(tags::SYMBOL, "AAPL")
+            .with_field(tags::SIDE, "2")
+            .with_field(tags::ORDER_QTY, "100")
+            .with_field(tags::PRICE, "189.5")
+            .with_field(tags::CUM_QTY, "40")
+            .with_field(tags::AVG_PX, "189.45")
+            .with_field(tags::TRANSACT_TIME, "20240102-14:30:00.250")
+    }
+
+    #[test]
+    fn test_execution_report_to_status() {
+        let status = execution_report_to_status(&report("1")).unwrap();
+        assert_eq!(status.order_id, "BRK-1");
+        assert_eq!(status.client_order_id.as_deref(), Some("ZQ-1"));
+        assert_eq!(status.ticker.as_deref(), Some("AAPL"));
+        assert_eq!(status.side, Some(Side::Sell));
+        assert_eq!(status.status, OrderStatusType::PartiallyFilled);
+        assert_eq!(status.quantity, Some(dec!(100)));
+        assert_eq!(status.filled_quantity, dec!(40));
+        assert_eq!(status.average_price, Some(dec!(189.45)));
+        assert_eq!(status.updated_at.format("%H%M%S").to_string(), "143000");
+    }
+
+    #[test]
+    fn test_execution_report_requires_order_fields() {
+        let mut message = report("0");
+        message.remove_field(tags::ORD_STATUS);
+        assert!(execution_report_to_status(&message).is_err());
+        assert!(execution_report_to_status(&report("Z")).is_err());
+        assert!(execution_report_to_status(&FixMessage::new(msg_type::HEARTBEAT)).is_err());
+    }
+
+    #[test]
+    fn test_map_ord_status() {
+        assert_eq!(map_ord_status("A"), Some(OrderStatusType::Pending));
+        assert_eq!(map_ord_status("0"), Some(OrderStatusType::Open));
+        assert_eq!(map_ord_status("6"), Some(OrderStatusType::Open));
+        assert_eq!(map_ord_status("2"), Some(OrderStatusType::Filled));
+        assert_eq!(map_ord_status("4"), Some(OrderStatusType::Cancelled));
+        assert_eq!(map_ord_status("8"), Some(OrderStatusType::Rejected));
+        assert_eq!(map_ord_status("C"), Some(OrderStatusType::Expired));
+        assert_eq!(map_ord_status("X"), None);
+    }
+
+    #[test]
+    fn test_fix_ord_type() {
+        assert_eq!(fix_ord_type(OrderType::Market).unwrap(), "1");
+        assert_eq!(fix_ord_type(OrderType::StopLossLimit).unwrap(), "4");
+        assert!(matches!(
+            fix_ord_type(OrderType::TrailingStop),
+            Err(ProviderError::Unsupported(_))
+        ));
+    }
+}
@@ -0,0 +1,438 @@
+//! FIX 4.4 메시지 인코딩/디코딩.
+//!
+//! `tag=value<SOH>` 형식의 메시지를 만들고 파싱합니다.
+//! BodyLength(9)와 CheckSum(10)은 인코딩 시 자동 계산되며,
+//! 디코딩 시 검증됩니다.
+
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rust_decimal::Decimal;
+use trader_core::ProviderError;
+
+/// 필드 구분자 (SOH, 0x01).
+pub const SOH: u8 = 0x01;
+
+/// FIX 4.4 BeginString.
+pub const BEGIN_STRING: &str = "FIX.4.4";
+
+/// UTCTimestamp 형식 (밀리초 포함).
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H:%M:%S%.3f";
+
+/// FIX 태그 번호.
+pub mod tags {
+    pub const ACCOUNT: u32 = 1;
+    pub const AVG_PX: u32 = 6;
+    pub const BEGIN_SEQ_NO: u32 = 7;
+    pub const BEGIN_STRING: u32 = 8;
+    pub const BODY_LENGTH: u32 = 9;
+    pub const CHECKSUM: u32 = 10;
+    pub const CL_ORD_ID: u32 = 11;
+    pub const CUM_QTY: u32 = 14;
+    pub const END_SEQ_NO: u32 = 16;
+    pub const EXEC_ID: u32 = 17;
+    pub const HANDL_INST: u32 = 21;
+    pub const LAST_PX: u32 = 31;
+    pub const LAST_QTY: u32 = 32;
+    pub const MSG_SEQ_NUM: u32 = 34;
+    pub const MSG_TYPE: u32 = 35;
+    pub const NEW_SEQ_NO: u32 = 36;
+    pub const ORDER_ID: u32 = 37;
+    pub const ORDER_QTY: u32 = 38;
+    pub const ORD_STATUS: u32 = 39;
+    pub const ORD_TYPE: u32 = 40;
+    pub const ORIG_CL_ORD_ID: u32 = 41;
+    pub const POSS_DUP_FLAG: u32 = 43;
+    pub const PRICE: u32 = 44;
+    pub const REF_SEQ_NUM: u32 = 45;
+    pub const SENDER_COMP_ID: u32 = 49;
+    pub const SENDING_TIME: u32 = 52;
+    pub const SIDE: u32 = 54;
+    pub const SYMBOL: u32 = 55;
+    pub const TARGET_COMP_ID: u32 = 56;
+    pub const TEXT: u32 = 58;
+    pub const TIME_IN_FORCE: u32 = 59;
+    pub const TRANSACT_TIME: u32 = 60;
+    pub const ENCRYPT_METHOD: u32 = 98;
+    pub const STOP_PX: u32 = 99;
+    pub const CXL_REJ_REASON: u32 = 102;
+    pub const ORD_REJ_REASON: u32 = 103;
+    pub const HEART_BT_INT: u32 = 108;
+    pub const TEST_REQ_ID: u32 = 112;
+    pub const ORIG_SENDING_TIME: u32 = 122;
+    pub const GAP_FILL_FLAG: u32 = 123;
+    pub const EXPIRE_TIME: u32 = 126;
+    pub const RESET_SEQ_NUM_FLAG: u32 = 141;
+    pub const EXEC_TYPE: u32 = 150;
+    pub const LEAVES_QTY: u32 = 151;
+    pub const CXL_REJ_RESPONSE_TO: u32 = 434;
+    pub const USERNAME: u32 = 553;
+    pub const PASSWORD: u32 = 554;
+}
+
+/// FIX MsgType(35) 값.
+pub mod msg_type {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const RESEND_REQUEST: &str = "2";
+    pub const REJECT: &str = "3";
+    pub const SEQUENCE_RESET: &str = "4";
+    pub const LOGOUT: &str = "5";
+    pub const EXECUTION_REPORT: &str = "8";
+    pub const ORDER_CANCEL_REJECT: &str = "9";
+    pub const LOGON: &str = "A";
+    pub const NEW_ORDER_SINGLE: &str = "D";
+    pub const ORDER_CANCEL_REQUEST: &str = "F";
+    pub const ORDER_CANCEL_REPLACE_REQUEST: &str = "G";
+}
+
+/// 표준 헤더 태그 (인코딩 시 본문 필드보다 먼저 기록).
+const HEADER_TAGS: [u32; 6] = [
+    tags::SENDER_COMP_ID,
+    tags::TARGET_COMP_ID,
+    tags::MSG_SEQ_NUM,
+    tags::POSS_DUP_FLAG,
+    tags::SENDING_TIME,
+    tags::ORIG_SENDING_TIME,
+];
+
+/// FIX 메시지.
+///
+/// BeginString(8), BodyLength(9), MsgType(35), CheckSum(10)을 제외한
+/// 필드를 삽입 순서대로 보관합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixMessage {
+    msg_type: String,
+    fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    /// 새 메시지 생성.
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            msg_type: msg_type.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// MsgType(35) 반환.
+    pub fn msg_type(&self) -> &str {
+        &self.msg_type
+    }
+
+    /// 필드 추가 (빌더 패턴).
+    pub fn with_field(mut self, tag: u32, value: impl ToString) -> Self {
+        self.set_field(tag, value);
+        self
+    }
+
+    /// 필드 설정. 이미 있으면 값을 교체합니다.
+    pub fn set_field(&mut self, tag: u32, value: impl ToString) {
+        let value = value.to_string();
+        match self.fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some(field) => field.1 = value,
+            None => self.fields.push((tag, value)),
+        }
+    }
+
+    /// 필드 제거.
+    pub fn remove_field(&mut self, tag: u32) {
+        self.fields.retain(|(t, _)| *t != tag);
+    }
+
+    /// 필드 값 조회.
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(t, _)| *t == tag)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// 정수 필드 조회.
+    pub fn get_u64(&self, tag: u32) -> Option<u64> {
+        self.get(tag).and_then(|v| v.parse().ok())
+    }
+
+    /// 소수 필드 조회.
+    pub fn get_decimal(&self, tag: u32) -> Option<Decimal> {
+        self.get(tag).and_then(|v| Decimal::from_str(v).ok())
+    }
+
+    /// UTCTimestamp 필드 조회.
+    pub fn get_timestamp(&self, tag: u32) -> Option<DateTime<Utc>> {
+        self.get(tag).and_then(parse_timestamp)
+    }
+
+    /// MsgSeqNum(34) 조회.
+    pub fn seq_num(&self) -> Option<u64> {
+        self.get_u64(tags::MSG_SEQ_NUM)
+    }
+
+    /// PossDupFlag(43)가 설정되어 있는지 확인.
+    pub fn is_poss_dup(&self) -> bool {
+        self.get(tags::POSS_DUP_FLAG) == Some("Y")
+    }
+
+    /// 세션 레벨(admin) 메시지인지 확인.
+    pub fn is_admin(&self) -> bool {
+        matches!(
+            self.msg_type.as_str(),
+            msg_type::HEARTBEAT
+                | msg_type::TEST_REQUEST
+                | msg_type::RESEND_REQUEST
+                | msg_type::REJECT
+                | msg_type::SEQUENCE_RESET
+                | msg_type::LOGOUT
+                | msg_type::LOGON
+        )
+    }
+
+    /// 전송 가능한 바이트열로 인코딩.
+    ///
+    /// 헤더 태그를 먼저 기록한 뒤 나머지 필드를 삽입 순서대로 기록하고,
+    /// BodyLength와 CheckSum을 계산합니다.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut body = Vec::with_capacity(128);
+        push_field(&mut body, tags::MSG_TYPE, &self.msg_type);
+        for tag in HEADER_TAGS {
+            if let Some(value) = self.get(tag) {
+                push_field(&mut body, tag, value);
+            }
+        }
+        for (tag, value) in &self.fields {
+            if !HEADER_TAGS.contains(tag) {
+                push_field(&mut body, *tag, value);
+            }
+        }
+
+        let mut out = Vec::with_capacity(body.len() + 32);
+        push_field(&mut out, tags::BEGIN_STRING, BEGIN_STRING);
+        push_field(&mut out, tags::BODY_LENGTH, &body.len().to_string());
+        out.extend_from_slice(&body);
+        let checksum = checksum(&out);
+        push_field(&mut out, tags::CHECKSUM, &format!("{:03}", checksum));
+        out
+    }
+
+    /// 완전한 메시지 한 개를 디코딩.
+    ///
+    /// BeginString, BodyLength, CheckSum을 검증합니다.
+    pub fn decode(raw: &[u8]) -> Result<Self, ProviderError> {
+        let text = std::str::from_utf8(raw)
+            .map_err(|e| ProviderError::Parse(format!("FIX 메시지 UTF-8 오류: {}", e)))?;
+        let text = text.strip_suffix('\u{1}').ok_or_else(|| {
+            ProviderError::Parse("FIX 메시지가 SOH로 끝나지 않습니다".to_string())
+        })?;
+
+        let mut parsed = Vec::new();
+        for field in text.split('\u{1}') {
+            let (tag, value) = field
+                .split_once('=')
+                .ok_or_else(|| ProviderError::Parse(format!("잘못된 FIX 필드: {}", field)))?;
+            let tag: u32 = tag
+                .parse()
+                .map_err(|_| ProviderError::Parse(format!("잘못된 FIX 태그: {}", tag)))?;
+            parsed.push((tag, value));
+        }
+
+        match parsed.as_slice() {
+            [(tags::BEGIN_STRING, begin), (tags::BODY_LENGTH, _), .., (tags::CHECKSUM, _)]
+                if *begin == BEGIN_STRING => {}
+            _ => {
+                return Err(ProviderError::Parse(
+                    "FIX 메시지 헤더/트레일러 형식 오류".to_string(),
+                ))
+            }
+        }
+
+        // BodyLength: 9 필드 다음부터 10 필드 직전까지
+        let body_start = raw
+            .windows(3)
+            .position(|w| w == b"\x019=")
+            .and_then(|pos| {
+                raw[pos + 1..]
+                    .iter()
+                    .position(|b| *b == SOH)
+                    .map(|end| pos + 1 + end + 1)
+            })
+            .ok_or_else(|| ProviderError::Parse("BodyLength 필드 없음".to_string()))?;
+        let trailer_start = raw.len() - 7;
+        let declared: usize = parsed[1]
+            .1
+            .parse()
+            .map_err(|_| ProviderError::Parse("잘못된 BodyLength".to_string()))?;
+        if trailer_start < body_start || trailer_start - body_start != declared {
+            return Err(ProviderError::Parse(format!(
+                "BodyLength 불일치: 선언 {}, 실제 {}",
+                declared,
+                trailer_start.saturating_sub(body_start)
+            )));
+        }
+
+        let expected = checksum(&raw[..trailer_start]);
+        let actual: u32 = parsed[parsed.len() - 1]
+            .1
+            .parse()
+            .map_err(|_| ProviderError::Parse("잘못된 CheckSum".to_string()))?;
+        if expected != actual {
+            return Err(ProviderError::Parse(format!(
+                "CheckSum 불일치: 기대 {:03}, 실제 {:03}",
+                expected, actual
+            )));
+        }
+
+        let body = &parsed[2..parsed.len() - 1];
+        let msg_type = match body.first() {
+            Some((tags::MSG_TYPE, value)) => value.to_string(),
+            _ => return Err(ProviderError::Parse("MsgType 필드 없음".to_string())),
+        };
+
+        Ok(Self {
+            msg_type,
+            fields: body[1..]
+                .iter()
+                .map(|(tag, value)| (*tag, value.to_string()))
+                .collect(),
+        })
+    }
+
+    /// 버퍼 앞부분에 완전한 메시지가 있으면 그 길이를 반환.
+    ///
+    /// 데이터가 더 필요하면 `Ok(None)`을 반환합니다.
+    pub fn frame_len(buf: &[u8]) -> Result<Option<usize>, ProviderError> {
+        let prefix = b"8=FIX.4.4\x019=";
+        if buf.len() < prefix.len() {
+            return if prefix.starts_with(buf) {
+                Ok(None)
+            } else {
+                Err(ProviderError::Parse("FIX BeginString 불일치".to_string()))
+            };
+        }
+        if !buf.starts_with(prefix) {
+            return Err(ProviderError::Parse("FIX BeginString 불일치".to_string()));
+        }
+
+        let rest = &buf[prefix.len()..];
+        let Some(len_end) = rest.iter().position(|b| *b == SOH) else {
+            return Ok(None);
+        };
+        let body_len: usize = std::str::from_utf8(&rest[..len_end])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| ProviderError::Parse("잘못된 BodyLength".to_string()))?;
+
+        // "10=xxx<SOH>" = 7바이트
+        let total = prefix.len() + len_end + 1 + body_len + 7;
+        Ok((buf.len() >= total).then_some(total))
+    }
+}
+
+impl fmt::Display for FixMessage {
+    /// SOH를 `|`로 치환한 로그용 표현.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoded = self.encode();
+        let text: String = encoded
+            .iter()
+            .map(|b| if *b == SOH { '|' } else { *b as char })
+            .collect();
+        f.write_str(&text)
+    }
+}
+
+/// UTCTimestamp 형식으로 변환.
+pub fn format_timestamp(time: DateTime<Utc>) -> String {
+    time.format(TIMESTAMP_FORMAT).to_string()
+}
+
+/// UTCTimestamp 파싱 (밀리초 유무 모두 허용).
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value, "%Y%m%d-%H:%M:%S%.f")
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+fn push_field(out: &mut Vec<u8>, tag: u32, value: &str) {
+    out.extend_from_slice(tag.to_string().as_bytes());
+    out.push(b'=');
+    out.extend_from_slice(value.as_bytes());
+    out.push(SOH);
+}
+
+fn checksum(bytes: &[u8]) -> u32 {
+    bytes.iter().map(|b| *b as u32).sum::<u32>() % 256
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> FixMessage {
+        FixMessage::new(msg_type::NEW_ORDER_SINGLE)
+            .with_field(tags::CL_ORD_ID, "ORD-1")
+            .with_field(tags::SYMBOL, "AAPL")
+            .with_field(tags::SIDE, "1")
+            .with_field(tags::SENDER_COMP_ID, "ZQ")
+            .with_field(tags::TARGET_COMP_ID, "BROKER")
+            .with_field(tags::MSG_SEQ_NUM, 7)
+    }
+
+    #[test]
+    fn test_encode_places_header_first() {
+        let text = sample().to_string();
+        assert!(text.starts_with("8=FIX.4.4|9="));
+        assert!(text.contains("|35=D|49=ZQ|56=BROKER|34=7|11=ORD-1|55=AAPL|54=1|10="));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let msg = sample();
+        let encoded = msg.encode();
+        assert_eq!(
+            FixMessage::frame_len(&encoded).unwrap(),
+            Some(encoded.len())
+        );
+
+        let decoded = FixMessage::decode(&encoded).unwrap();
+        assert_eq!(decoded.msg_type(), msg_type::NEW_ORDER_SINGLE);
+        assert_eq!(decoded.get(tags::CL_ORD_ID), Some("ORD-1"));
+        assert_eq!(decoded.seq_num(), Some(7));
+        assert!(!decoded.is_admin());
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_checksum() {
+        let mut encoded = sample().encode();
+        let len = encoded.len();
+        // CheckSum 마지막 자리 변조
+        encoded[len - 2] = if encoded[len - 2] == b'0' { b'1' } else { b'0' };
+        assert!(matches!(
+            FixMessage::decode(&encoded),
+            Err(ProviderError::Parse(msg)) if msg.contains("CheckSum")
+        ));
+    }
+
+    #[test]
+    fn test_frame_len_partial_and_multiple() {
+        let first = sample().encode();
+        let second = FixMessage::new(msg_type::HEARTBEAT).encode();
+
+        assert_eq!(FixMessage::frame_len(&first[..5]).unwrap(), None);
+        assert_eq!(
+            FixMessage::frame_len(&first[..first.len() - 1]).unwrap(),
+            None
+        );
+
+        let mut buf = first.clone();
+        buf.extend_from_slice(&second);
+        assert_eq!(FixMessage::frame_len(&buf).unwrap(), Some(first.len()));
+        assert!(FixMessage::frame_len(b"9=12\x01").is_err());
+    }
+
+    #[test]
+    fn test_timestamp_roundtrip() {
+        let now = Utc::now();
+        let parsed = parse_timestamp(&format_timestamp(now)).unwrap();
+        assert_eq!(parsed.timestamp_millis(), now.timestamp_millis());
+        assert!(parse_timestamp("20240102-09:30:00").is_some());
+    }
+}
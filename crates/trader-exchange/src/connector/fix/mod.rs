@@ -0,0 +1,18 @@
+//! FIX 4.4 주문 게이트웨이.
+//!
+//! FIX만 지원하는 프라임 브로커와의 주문 연동을 위한 모듈입니다.
+//!
+//! - [`message`]: 메시지 인코딩/디코딩 (BodyLength, CheckSum)
+//! - [`store`]: 시퀀스 번호 저장소 (메모리/파일)
+//! - [`session`]: Logon, Heartbeat, ResendRequest 등 세션 프로토콜
+//! - [`client`]: NewOrderSingle/Cancel/Replace 및 ExecutionReport 변환
+
+pub mod client;
+pub mod message;
+pub mod session;
+pub mod store;
+
+pub use client::*;
+pub use message::FixMessage;
+pub use session::{FixSession, FixSessionConfig};
+pub use store::{FileSequenceStore, MemorySequenceStore, SequenceStore};
@@ -0,0 +1,626 @@
+//! FIX 4.4 세션 레이어 (initiator).
+//!
+//! TCP 연결 위에서 다음 세션 프로토콜을 처리합니다:
+//! - Logon / Logout 핸드셰이크
+//! - Heartbeat 송신 및 TestRequest를 이용한 상대방 생존 확인
+//! - MsgSeqNum 검증, 수신 갭 감지 시 ResendRequest 송신
+//! - 상대방 ResendRequest에 대한 재전송 (관리 메시지는 SequenceReset-GapFill)
+//!
+//! 세션 메시지가 아닌 애플리케이션 메시지는 채널로 전달됩니다.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex as StdMutex, Weak,
+    },
+    time::{Duration, Instant},
+};
+
+use chrono::Utc;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+        TcpStream,
+    },
+    sync::{mpsc, oneshot, watch, Mutex},
+    task::JoinHandle,
+    time::timeout,
+};
+use tracing::{debug, error, info, warn};
+use trader_core::ProviderError;
+
+use super::{
+    message::{format_timestamp, msg_type, tags, FixMessage},
+    store::SequenceStore,
+};
+
+/// Logout 응답 대기 시간.
+const LOGOUT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// 세션 설정.
+#[derive(Clone)]
+pub struct FixSessionConfig {
+    /// SenderCompID(49)
+    pub sender_comp_id: String,
+    /// TargetCompID(56)
+    pub target_comp_id: String,
+    /// 하트비트 간격
+    pub heartbeat_interval: Duration,
+    /// 로그온 시 시퀀스 번호 초기화 (ResetSeqNumFlag=Y)
+    pub reset_on_logon: bool,
+    /// Username(553)
+    pub username: Option<String>,
+    /// Password(554)
+    pub password: Option<String>,
+    /// 연결 및 로그온 응답 대기 시간
+    pub logon_timeout: Duration,
+}
+
+impl std::fmt::Debug for FixSessionConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FixSessionConfig")
+            .field("sender_comp_id", &self.sender_comp_id)
+            .field("target_comp_id", &self.target_comp_id)
+            .field("heartbeat_interval", &self.heartbeat_interval)
+            .field("reset_on_logon", &self.reset_on_logon)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+/// FIX 세션.
+///
+/// [`FixSession::connect`]가 로그온까지 완료한 세션을 반환합니다.
+/// 수신 및 하트비트 태스크는 세션이 drop되면 함께 종료됩니다.
+pub struct FixSession {
+    config: FixSessionConfig,
+    store: Arc<dyn SequenceStore>,
+    /// 송신 시 시퀀스 할당과 기록 순서를 보장하기 위해 writer를 잠급니다.
+    writer: Mutex<OwnedWriteHalf>,
+    logged_on: AtomicBool,
+    logout_sent: AtomicBool,
+    test_request_pending: AtomicBool,
+    /// 수신 갭 복구 중이면 복구가 끝나는 시퀀스 번호 (0이면 복구 중 아님)
+    resend_until: AtomicU64,
+    last_sent: StdMutex<Instant>,
+    last_received: StdMutex<Instant>,
+    closed: watch::Sender<bool>,
+    tasks: StdMutex<Vec<JoinHandle<()>>>,
+}
+
+impl FixSession {
+    /// 상대방(acceptor)에 연결하고 로그온.
+    ///
+    /// 애플리케이션 메시지는 `app_tx`로 전달됩니다.
+    pub async fn connect(
+        addr: &str,
+        config: FixSessionConfig,
+        store: Arc<dyn SequenceStore>,
+        app_tx: mpsc::UnboundedSender<FixMessage>,
+    ) -> Result<Arc<Self>, ProviderError> {
+        let stream = timeout(config.logon_timeout, TcpStream::connect(addr))
+            .await
+            .map_err(|_| ProviderError::Network(format!("FIX 연결 타임아웃: {}", addr)))?
+            .map_err(|e| ProviderError::Network(format!("FIX 연결 실패 ({}): {}", addr, e)))?;
+        stream.set_nodelay(true).ok();
+        let (reader, writer) = stream.into_split();
+
+        if config.reset_on_logon {
+            store.reset()?;
+        }
+
+        let logon_timeout = config.logon_timeout;
+        let heartbeat_secs = config.heartbeat_interval.as_secs().max(1);
+        let now = Instant::now();
+        let session = Arc::new(Self {
+            config,
+            store,
+            writer: Mutex::new(writer),
+            logged_on: AtomicBool::new(false),
+            logout_sent: AtomicBool::new(false),
+            test_request_pending: AtomicBool::new(false),
+            resend_until: AtomicU64::new(0),
+            last_sent: StdMutex::new(now),
+            last_received: StdMutex::new(now),
+            closed: watch::channel(false).0,
+            tasks: StdMutex::new(Vec::new()),
+        });
+
+        let (logon_tx, logon_rx) = oneshot::channel();
+        let reader_task = tokio::spawn(Self::read_loop(
+            Arc::downgrade(&session),
+            reader,
+            app_tx,
+            logon_tx,
+        ));
+        session.tasks.lock().unwrap().push(reader_task);
+
+        let mut logon = FixMessage::new(msg_type::LOGON)
+            .with_field(tags::ENCRYPT_METHOD, 0)
+            .with_field(tags::HEART_BT_INT, heartbeat_secs);
+        if session.config.reset_on_logon {
+            logon.set_field(tags::RESET_SEQ_NUM_FLAG, "Y");
+        }
+        if let Some(username) = &session.config.username {
+            logon.set_field(tags::USERNAME, username);
+        }
+        if let Some(password) = &session.config.password {
+            logon.set_field(tags::PASSWORD, password);
+        }
+        session.send(logon).await?;
+
+        match timeout(logon_timeout, logon_rx).await {
+            Ok(Ok(Ok(()))) => {}
+            Ok(Ok(Err(e))) => {
+                session.close().await;
+                return Err(e);
+            }
+            Ok(Err(_)) => {
+                session.close().await;
+                return Err(ProviderError::Network(
+                    "FIX 로그온 전에 연결이 종료되었습니다".to_string(),
+                ));
+            }
+            Err(_) => {
+                session.close().await;
+                return Err(ProviderError::Network(
+                    "FIX 로그온 응답 타임아웃".to_string(),
+                ));
+            }
+        }
+
+        let heartbeat_task = tokio::spawn(Self::heartbeat_loop(Arc::downgrade(&session)));
+        session.tasks.lock().unwrap().push(heartbeat_task);
+
+        info!(
+            sender = %session.config.sender_comp_id,
+            target = %session.config.target_comp_id,
+            next_sender_seq = session.store.next_sender_seq(),
+            next_target_seq = session.store.next_target_seq(),
+            "FIX 로그온 완료"
+        );
+        Ok(session)
+    }
+
+    /// 로그온 상태인지 확인.
+    pub fn is_logged_on(&self) -> bool {
+        self.logged_on.load(Ordering::SeqCst)
+    }
+
+    /// 다음 송신 MsgSeqNum.
+    pub fn next_sender_seq(&self) -> u64 {
+        self.store.next_sender_seq()
+    }
+
+    /// 다음 수신 기대 MsgSeqNum.
+    pub fn next_target_seq(&self) -> u64 {
+        self.store.next_target_seq()
+    }
+
+    /// 세션 설정.
+    pub fn config(&self) -> &FixSessionConfig {
+        &self.config
+    }
+
+    /// 메시지 송신.
+    ///
+    /// 헤더(CompID, MsgSeqNum, SendingTime)를 채우고 시퀀스 번호를 증가시킵니다.
+    /// 애플리케이션 메시지는 재전송을 위해 저장소에 기록됩니다.
+    pub async fn send(&self, message: FixMessage) -> Result<u64, ProviderError> {
+        let mut writer = self.writer.lock().await;
+        let seq = self.store.next_sender_seq();
+        let mut message = message;
+        self.stamp(&mut message, seq);
+
+        writer
+            .write_all(&message.encode())
+            .await
+            .map_err(|e| ProviderError::Network(format!("FIX 송신 실패: {}", e)))?;
+        self.store.set_next_sender_seq(seq + 1)?;
+        if !message.is_admin() {
+            self.store.store_message(seq, &message);
+        }
+        *self.last_sent.lock().unwrap() = Instant::now();
+
+        debug!(message = %message, "FIX 송신");
+        Ok(seq)
+    }
+
+    /// Logout 송신 후 상대방 응답(또는 연결 종료)을 기다린 뒤 연결을 닫습니다.
+    pub async fn logout(&self, text: Option<&str>) -> Result<(), ProviderError> {
+        if self.is_logged_on() {
+            self.send_logout(text).await?;
+            let mut closed = self.closed.subscribe();
+            if timeout(LOGOUT_TIMEOUT, closed.wait_for(|c| *c))
+                .await
+                .is_err()
+            {
+                warn!("FIX Logout 응답 타임아웃");
+            }
+        }
+        self.close().await;
+        Ok(())
+    }
+
+    /// 연결 종료까지 대기.
+    pub async fn wait_closed(&self) {
+        let mut closed = self.closed.subscribe();
+        let _ = closed.wait_for(|c| *c).await;
+    }
+
+    /// 연결을 즉시 닫고 백그라운드 태스크를 종료합니다.
+    async fn close(&self) {
+        self.logged_on.store(false, Ordering::SeqCst);
+        let _ = self.writer.lock().await.shutdown().await;
+        for task in self.tasks.lock().unwrap().drain(..) {
+            task.abort();
+        }
+        self.closed.send_replace(true);
+    }
+
+    fn stamp(&self, message: &mut FixMessage, seq: u64) {
+        message.set_field(tags::SENDER_COMP_ID, &self.config.sender_comp_id);
+        message.set_field(tags::TARGET_COMP_ID, &self.config.target_comp_id);
+        message.set_field(tags::MSG_SEQ_NUM, seq);
+        message.set_field(tags::SENDING_TIME, format_timestamp(Utc::now()));
+    }
+
+    async fn send_logout(&self, text: Option<&str>) -> Result<(), ProviderError> {
+        self.logout_sent.store(true, Ordering::SeqCst);
+        let mut logout = FixMessage::new(msg_type::LOGOUT);
+        if let Some(text) = text {
+            logout.set_field(tags::TEXT, text);
+        }
+        self.send(logout).await.map(|_| ())
+    }
+
+    // ========================================================================
+    // 수신 처리
+    // ========================================================================
+
+    async fn read_loop(
+        session: Weak<Self>,
+        mut reader: OwnedReadHalf,
+        app_tx: mpsc::UnboundedSender<FixMessage>,
+        logon_tx: oneshot::Sender<Result<(), ProviderError>>,
+    ) {
+        let mut logon_tx = Some(logon_tx);
+        let mut buf = Vec::with_capacity(8192);
+        let mut chunk = [0u8; 4096];
+
+        'outer: loop {
+            let n = match reader.read(&mut chunk).await {
+                Ok(0) => {
+                    info!("FIX 연결 종료 (상대방)");
+                    break;
+                }
+                Ok(n) => n,
+                Err(e) => {
+                    warn!(error = %e, "FIX 수신 오류");
+                    break;
+                }
+            };
+            buf.extend_from_slice(&chunk[..n]);
+
+            loop {
+                let len = match FixMessage::frame_len(&buf) {
+                    Ok(Some(len)) => len,
+                    Ok(None) => break,
+                    Err(e) => {
+                        error!(error = %e, "FIX 프레이밍 오류, 연결 종료");
+                        break 'outer;
+                    }
+                };
+                let frame: Vec<u8> = buf.drain(..len).collect();
+                let message = match FixMessage::decode(&frame) {
+                    Ok(message) => message,
+                    Err(e) => {
+                        // 체크섬/길이 오류 메시지는 무시 (시퀀스 갭으로 복구됨)
+                        warn!(error = %e, "FIX 메시지 디코딩 실패, 무시");
+                        continue;
+                    }
+                };
+
+                let Some(session) = session.upgrade() else {
+                    return;
+                };
+                debug!(message = %message, "FIX 수신");
+                match session
+                    .handle_inbound(message, &app_tx, &mut logon_tx)
+                    .await
+                {
+                    Ok(true) => {}
+                    Ok(false) => break 'outer,
+                    Err(e) => {
+                        error!(error = %e, "FIX 수신 처리 실패, 연결 종료");
+                        break 'outer;
+                    }
+                }
+            }
+        }
+
+        if let Some(tx) = logon_tx.take() {
+            let _ = tx.send(Err(ProviderError::Network(
+                "FIX 로그온 전에 연결이 종료되었습니다".to_string(),
+            )));
+        }
+        if let Some(session) = session.upgrade() {
+            session.logged_on.store(false, Ordering::SeqCst);
+            let _ = session.writer.lock().await.shutdown().await;
+            session.closed.send_replace(true);
+        }
+    }
+
+    /// 수신 메시지 처리. 세션을 계속 유지하면 `Ok(true)`.
+    async fn handle_inbound(
+        &self,
+        message: FixMessage,
+        app_tx: &mpsc::UnboundedSender<FixMessage>,
+        logon_tx: &mut Option<oneshot::Sender<Result<(), ProviderError>>>,
+    ) -> Result<bool, ProviderError> {
+        *self.last_received.lock().unwrap() = Instant::now();
+        self.test_request_pending.store(false, Ordering::SeqCst);
+
+        if message.get(tags::SENDER_COMP_ID) != Some(self.config.target_comp_id.as_str())
+            || message.get(tags::TARGET_COMP_ID) != Some(self.config.sender_comp_id.as_str())
+        {
+            self.send_logout(Some("CompID problem")).await?;
+            return Err(ProviderError::Authentication(format!(
+                "FIX CompID 불일치: {:?} -> {:?}",
+                message.get(tags::SENDER_COMP_ID),
+                message.get(tags::TARGET_COMP_ID)
+            )));
+        }
+
+        let Some(seq) = message.seq_num() else {
+            warn!(message = %message, "MsgSeqNum 없는 FIX 메시지 무시");
+            return Ok(true);
+        };
+
+        // SequenceReset-Reset 모드는 시퀀스 검사 없이 적용
+        if message.msg_type() == msg_type::SEQUENCE_RESET
+            && message.get(tags::GAP_FILL_FLAG) != Some("Y")
+        {
+            if let Some(new_seq) = message.get_u64(tags::NEW_SEQ_NO) {
+                info!(new_seq, "FIX SequenceReset (Reset)");
+                self.store.set_next_target_seq(new_seq)?;
+            }
+            return Ok(true);
+        }
+
+        // 상대방이 ResetSeqNumFlag로 로그온하면 수신 시퀀스도 1부터 다시 시작
+        if message.msg_type() == msg_type::LOGON
+            && message.get(tags::RESET_SEQ_NUM_FLAG) == Some("Y")
+        {
+            self.store.set_next_target_seq(1)?;
+        }
+
+        let expected = self.store.next_target_seq();
+        if seq < expected {
+            if message.is_poss_dup() {
+                debug!(seq, expected, "중복 FIX 메시지 무시");
+                return Ok(true);
+            }
+            let text = format!(
+                "MsgSeqNum too low, expecting {} but received {}",
+                expected, seq
+            );
+            error!("{}", text);
+            self.send_logout(Some(&text)).await?;
+            return Ok(false);
+        }
+
+        if message.msg_type() == msg_type::LOGON {
+            self.on_logon(logon_tx);
+        }
+
+        if seq > expected {
+            match message.msg_type() {
+                msg_type::LOGOUT => return self.on_logout(&message).await,
+                // 상대방의 ResendRequest는 갭 처리보다 먼저 응답해야
+                // 양쪽이 서로의 재전송만 기다리는 교착을 피할 수 있음
+                msg_type::RESEND_REQUEST => self.on_resend_request(&message).await?,
+                _ => {}
+            }
+            // 갭 감지: 이미 요청한 범위 안이면 다시 요청하지 않음
+            if self.resend_until.load(Ordering::SeqCst) < seq {
+                warn!(
+                    expected,
+                    received = seq,
+                    "FIX 시퀀스 갭 감지, ResendRequest 송신"
+                );
+                self.resend_until.store(seq, Ordering::SeqCst);
+                self.send(
+                    FixMessage::new(msg_type::RESEND_REQUEST)
+                        .with_field(tags::BEGIN_SEQ_NO, expected)
+                        .with_field(tags::END_SEQ_NO, 0),
+                )
+                .await?;
+            }
+            return Ok(true);
+        }
+
+        // seq == expected
+        let next = if message.msg_type() == msg_type::SEQUENCE_RESET {
+            message
+                .get_u64(tags::NEW_SEQ_NO)
+                .filter(|new_seq| *new_seq > seq)
+                .unwrap_or(seq + 1)
+        } else {
+            seq + 1
+        };
+        self.store.set_next_target_seq(next)?;
+        if next > self.resend_until.load(Ordering::SeqCst) {
+            self.resend_until.store(0, Ordering::SeqCst);
+        }
+
+        match message.msg_type() {
+            msg_type::LOGON | msg_type::HEARTBEAT | msg_type::SEQUENCE_RESET => {}
+            msg_type::TEST_REQUEST => {
+                let mut heartbeat = FixMessage::new(msg_type::HEARTBEAT);
+                if let Some(id) = message.get(tags::TEST_REQ_ID) {
+                    heartbeat.set_field(tags::TEST_REQ_ID, id);
+                }
+                self.send(heartbeat).await?;
+            }
+            msg_type::RESEND_REQUEST => self.on_resend_request(&message).await?,
+            msg_type::REJECT => {
+                warn!(
+                    ref_seq = ?message.get(tags::REF_SEQ_NUM),
+                    text = ?message.get(tags::TEXT),
+                    "FIX 세션 Reject 수신"
+                );
+            }
+            msg_type::LOGOUT => return self.on_logout(&message).await,
+            _ => {
+                if app_tx.send(message).is_err() {
+                    debug!("FIX 애플리케이션 수신자가 없어 메시지 폐기");
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn on_logon(&self, logon_tx: &mut Option<oneshot::Sender<Result<(), ProviderError>>>) {
+        self.logged_on.store(true, Ordering::SeqCst);
+        if let Some(tx) = logon_tx.take() {
+            let _ = tx.send(Ok(()));
+        }
+    }
+
+    async fn on_logout(&self, message: &FixMessage) -> Result<bool, ProviderError> {
+        info!(text = ?message.get(tags::TEXT), "FIX Logout 수신");
+        if !self.logout_sent.load(Ordering::SeqCst) {
+            self.send_logout(None).await?;
+        }
+        self.logged_on.store(false, Ordering::SeqCst);
+        Ok(false)
+    }
+
+    async fn on_resend_request(&self, message: &FixMessage) -> Result<(), ProviderError> {
+        let begin = message.get_u64(tags::BEGIN_SEQ_NO).unwrap_or(1);
+        let end = message.get_u64(tags::END_SEQ_NO).unwrap_or(0);
+        self.resend(begin, end).await
+    }
+
+    /// 상대방 ResendRequest 처리.
+    ///
+    /// 기록된 애플리케이션 메시지는 PossDupFlag=Y로 재전송하고,
+    /// 관리 메시지 및 기록이 없는 구간은 SequenceReset-GapFill로 건너뜁니다.
+    async fn resend(&self, begin: u64, end: u64) -> Result<(), ProviderError> {
+        let mut writer = self.writer.lock().await;
+        let last = self.store.next_sender_seq().saturating_sub(1);
+        let end = if end == 0 || end > last { last } else { end };
+        if begin > end {
+            return Ok(());
+        }
+        info!(begin, end, "FIX ResendRequest 처리");
+
+        let mut stored = self.store.messages(begin, end).into_iter().peekable();
+        let mut seq = begin;
+        while seq <= end {
+            match stored.peek() {
+                Some((stored_seq, _)) if *stored_seq == seq => {
+                    let (_, mut message) = stored.next().expect("peeked");
+                    if let Some(original) = message.get(tags::SENDING_TIME).map(str::to_string) {
+                        message.set_field(tags::ORIG_SENDING_TIME, original);
+                    }
+                    message.set_field(tags::POSS_DUP_FLAG, "Y");
+                    message.set_field(tags::SENDING_TIME, format_timestamp(Utc::now()));
+                    write_message(&mut writer, &message).await?;
+                    seq += 1;
+                }
+                next => {
+                    let new_seq = next.map(|(s, _)| *s).unwrap_or(end + 1);
+                    let mut gap_fill = FixMessage::new(msg_type::SEQUENCE_RESET)
+                        .with_field(tags::POSS_DUP_FLAG, "Y")
+                        .with_field(tags::GAP_FILL_FLAG, "Y")
+                        .with_field(tags::NEW_SEQ_NO, new_seq);
+                    self.stamp(&mut gap_fill, seq);
+                    write_message(&mut writer, &gap_fill).await?;
+                    seq = new_seq;
+                }
+            }
+        }
+
+        *self.last_sent.lock().unwrap() = Instant::now();
+        Ok(())
+    }
+
+    // ========================================================================
+    // 하트비트
+    // ========================================================================
+
+    async fn heartbeat_loop(session: Weak<Self>) {
+        let interval = match session.upgrade() {
+            Some(s) => s.config.heartbeat_interval.max(Duration::from_secs(1)),
+            None => return,
+        };
+        let mut ticker = tokio::time::interval(interval / 4);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            let Some(session) = session.upgrade() else {
+                return;
+            };
+            if !session.is_logged_on() {
+                return;
+            }
+
+            let since_received = session.last_received.lock().unwrap().elapsed();
+            let since_sent = session.last_sent.lock().unwrap().elapsed();
+            let grace = interval / 5;
+
+            if since_received > interval * 2 + grace {
+                error!(
+                    elapsed_ms = since_received.as_millis() as u64,
+                    "FIX 하트비트 타임아웃, 연결 종료"
+                );
+                session.close().await;
+                return;
+            }
+
+            let result = if since_received > interval + grace
+                && !session.test_request_pending.swap(true, Ordering::SeqCst)
+            {
+                let id = format!("TEST-{}", Utc::now().timestamp_millis());
+                session
+                    .send(FixMessage::new(msg_type::TEST_REQUEST).with_field(tags::TEST_REQ_ID, id))
+                    .await
+            } else if since_sent >= interval {
+                session.send(FixMessage::new(msg_type::HEARTBEAT)).await
+            } else {
+                continue;
+            };
+
+            if let Err(e) = result {
+                warn!(error = %e, "FIX 하트비트 송신 실패");
+            }
+        }
+    }
+}
+
+impl Drop for FixSession {
+    fn drop(&mut self) {
+        if let Ok(mut tasks) = self.tasks.lock() {
+            for task in tasks.drain(..) {
+                task.abort();
+            }
+        }
+    }
+}
+
+async fn write_message(
+    writer: &mut OwnedWriteHalf,
+    message: &FixMessage,
+) -> Result<(), ProviderError> {
+    debug!(message = %message, "FIX 재전송");
+    writer
+        .write_all(&message.encode())
+        .await
+        .map_err(|e| ProviderError::Network(format!("FIX 송신 실패: {}", e)))
+}
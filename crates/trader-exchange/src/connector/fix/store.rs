@@ -0,0 +1,318 @@
+//! FIX 시퀀스 번호 저장소.
+//!
+//! 세션이 재접속되어도 MsgSeqNum이 이어지도록 송신/수신 시퀀스 번호를
+//! 보관하고, ResendRequest에 응답하기 위해 송신한 애플리케이션 메시지를
+//! 기록합니다.
+//!
+//! 기록은 최근 [`DEFAULT_MESSAGE_WINDOW`]개만 보관하며, 그보다 오래된 메시지나
+//! 송신 시퀀스가 되돌려진 이후 번호의 메시지는 SequenceReset-GapFill로 응답합니다.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use trader_core::ProviderError;
+
+use super::message::FixMessage;
+
+/// 재전송용으로 보관하는 송신 메시지 기본 개수.
+pub const DEFAULT_MESSAGE_WINDOW: usize = 10_000;
+
+/// 시퀀스 번호 저장소 trait.
+pub trait SequenceStore: Send + Sync {
+    /// 다음에 송신할 MsgSeqNum.
+    fn next_sender_seq(&self) -> u64;
+
+    /// 다음에 수신할 것으로 기대하는 MsgSeqNum.
+    fn next_target_seq(&self) -> u64;
+
+    /// 다음 송신 시퀀스 번호 설정.
+    fn set_next_sender_seq(&self, seq: u64) -> Result<(), ProviderError>;
+
+    /// 다음 수신 시퀀스 번호 설정.
+    fn set_next_target_seq(&self, seq: u64) -> Result<(), ProviderError>;
+
+    /// 송신한 애플리케이션 메시지 기록 (재전송용).
+    fn store_message(&self, seq: u64, message: &FixMessage);
+
+    /// `begin..=end` 범위의 기록된 메시지 조회.
+    fn messages(&self, begin: u64, end: u64) -> Vec<(u64, FixMessage)>;
+
+    /// 시퀀스 번호를 1로 초기화하고 기록된 메시지를 삭제.
+    fn reset(&self) -> Result<(), ProviderError>;
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct SeqNums {
+    next_sender_seq: u64,
+    next_target_seq: u64,
+}
+
+impl Default for SeqNums {
+    fn default() -> Self {
+        Self {
+            next_sender_seq: 1,
+            next_target_seq: 1,
+        }
+    }
+}
+
+struct StoreState {
+    seqs: SeqNums,
+    messages: BTreeMap<u64, FixMessage>,
+    window: usize,
+}
+
+impl Default for StoreState {
+    fn default() -> Self {
+        Self {
+            seqs: SeqNums::default(),
+            messages: BTreeMap::new(),
+            window: DEFAULT_MESSAGE_WINDOW,
+        }
+    }
+}
+
+impl StoreState {
+    fn set_next_sender_seq(&mut self, seq: u64) {
+        // 송신 시퀀스가 되돌아가면 이후 번호의 기록은 다른 메시지로 대체됨
+        if seq < self.seqs.next_sender_seq {
+            self.messages.split_off(&seq);
+        }
+        self.seqs.next_sender_seq = seq;
+    }
+
+    fn store_message(&mut self, seq: u64, message: &FixMessage) {
+        self.messages.insert(seq, message.clone());
+        while self.messages.len() > self.window {
+            self.messages.pop_first();
+        }
+    }
+
+    fn messages(&self, begin: u64, end: u64) -> Vec<(u64, FixMessage)> {
+        if begin > end {
+            return Vec::new();
+        }
+        self.messages
+            .range(begin..=end)
+            .map(|(seq, msg)| (*seq, msg.clone()))
+            .collect()
+    }
+
+    fn reset(&mut self) {
+        self.seqs = SeqNums::default();
+        self.messages.clear();
+    }
+}
+
+/// 메모리 기반 저장소 (프로세스 재시작 시 초기화).
+#[derive(Default)]
+pub struct MemorySequenceStore {
+    state: Mutex<StoreState>,
+}
+
+impl MemorySequenceStore {
+    /// 새 메모리 저장소 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 재전송용 메시지 보관 개수 설정.
+    pub fn with_message_window(self, window: usize) -> Self {
+        self.state.lock().unwrap().window = window.max(1);
+        self
+    }
+}
+
+impl SequenceStore for MemorySequenceStore {
+    fn next_sender_seq(&self) -> u64 {
+        self.state.lock().unwrap().seqs.next_sender_seq
+    }
+
+    fn next_target_seq(&self) -> u64 {
+        self.state.lock().unwrap().seqs.next_target_seq
+    }
+
+    fn set_next_sender_seq(&self, seq: u64) -> Result<(), ProviderError> {
+        self.state.lock().unwrap().set_next_sender_seq(seq);
+        Ok(())
+    }
+
+    fn set_next_target_seq(&self, seq: u64) -> Result<(), ProviderError> {
+        self.state.lock().unwrap().seqs.next_target_seq = seq;
+        Ok(())
+    }
+
+    fn store_message(&self, seq: u64, message: &FixMessage) {
+        self.state.lock().unwrap().store_message(seq, message);
+    }
+
+    fn messages(&self, begin: u64, end: u64) -> Vec<(u64, FixMessage)> {
+        self.state.lock().unwrap().messages(begin, end)
+    }
+
+    fn reset(&self) -> Result<(), ProviderError> {
+        self.state.lock().unwrap().reset();
+        Ok(())
+    }
+}
+
+/// 파일 기반 저장소.
+///
+/// 시퀀스 번호를 JSON 파일에 기록하여 재시작 후에도 세션을 이어갑니다.
+/// 송신 메시지 기록은 메모리에만 보관하므로, 재시작 이전 메시지에 대한
+/// ResendRequest는 SequenceReset-GapFill로 응답합니다.
+pub struct FileSequenceStore {
+    path: PathBuf,
+    state: Mutex<StoreState>,
+}
+
+impl FileSequenceStore {
+    /// 파일 저장소 열기. 파일이 없으면 시퀀스 1부터 시작합니다.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ProviderError> {
+        let path = path.as_ref().to_path_buf();
+        let seqs = match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).map_err(|e| {
+                ProviderError::Parse(format!("FIX 시퀀스 파일 파싱 실패 ({:?}): {}", path, e))
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => SeqNums::default(),
+            Err(e) => {
+                return Err(ProviderError::Other(format!(
+                    "FIX 시퀀스 파일 읽기 실패 ({:?}): {}",
+                    path, e
+                )))
+            }
+        };
+
+        Ok(Self {
+            path,
+            state: Mutex::new(StoreState {
+                seqs,
+                ..StoreState::default()
+            }),
+        })
+    }
+
+    /// 재전송용 메시지 보관 개수 설정.
+    pub fn with_message_window(self, window: usize) -> Self {
+        self.state.lock().unwrap().window = window.max(1);
+        self
+    }
+
+    /// 저장 파일 경로.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn persist(&self, seqs: SeqNums) -> Result<(), ProviderError> {
+        let content = serde_json::to_string(&seqs)
+            .map_err(|e| ProviderError::Other(format!("FIX 시퀀스 직렬화 실패: {}", e)))?;
+
+        // 임시 파일에 쓴 뒤 rename하여 부분 기록을 방지
+        let tmp = self.path.with_extension("tmp");
+        std::fs::write(&tmp, content)
+            .and_then(|_| std::fs::rename(&tmp, &self.path))
+            .map_err(|e| {
+                warn!(path = ?self.path, error = %e, "FIX 시퀀스 파일 저장 실패");
+                ProviderError::Other(format!("FIX 시퀀스 파일 저장 실패: {}", e))
+            })
+    }
+
+    fn update(&self, f: impl FnOnce(&mut StoreState)) -> Result<(), ProviderError> {
+        let seqs = {
+            let mut state = self.state.lock().unwrap();
+            f(&mut state);
+            state.seqs
+        };
+        self.persist(seqs)
+    }
+}
+
+impl SequenceStore for FileSequenceStore {
+    fn next_sender_seq(&self) -> u64 {
+        self.state.lock().unwrap().seqs.next_sender_seq
+    }
+
+    fn next_target_seq(&self) -> u64 {
+        self.state.lock().unwrap().seqs.next_target_seq
+    }
+
+    fn set_next_sender_seq(&self, seq: u64) -> Result<(), ProviderError> {
+        self.update(|s| s.set_next_sender_seq(seq))
+    }
+
+    fn set_next_target_seq(&self, seq: u64) -> Result<(), ProviderError> {
+        self.update(|s| s.seqs.next_target_seq = seq)
+    }
+
+    fn store_message(&self, seq: u64, message: &FixMessage) {
+        self.state.lock().unwrap().store_message(seq, message);
+    }
+
+    fn messages(&self, begin: u64, end: u64) -> Vec<(u64, FixMessage)> {
+        self.state.lock().unwrap().messages(begin, end)
+    }
+
+    fn reset(&self) -> Result<(), ProviderError> {
+        self.update(StoreState::reset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connector::fix::message::msg_type;
+
+    #[test]
+    fn test_file_store_persists_across_reopen() {
+        let path = std::env::temp_dir().join(format!("fix_seq_{}.json", uuid::Uuid::new_v4()));
+
+        let store = FileSequenceStore::open(&path).unwrap();
+        assert_eq!(store.next_sender_seq(), 1);
+        store.set_next_sender_seq(42).unwrap();
+        store.set_next_target_seq(17).unwrap();
+        store.store_message(41, &FixMessage::new(msg_type::NEW_ORDER_SINGLE));
+
+        let reopened = FileSequenceStore::open(&path).unwrap();
+        assert_eq!(reopened.next_sender_seq(), 42);
+        assert_eq!(reopened.next_target_seq(), 17);
+        // 메시지 기록은 메모리에만 보관
+        assert!(reopened.messages(1, 100).is_empty());
+
+        reopened.reset().unwrap();
+        assert_eq!(FileSequenceStore::open(&path).unwrap().next_sender_seq(), 1);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_memory_store_message_range() {
+        let store = MemorySequenceStore::new();
+        for seq in [2, 3, 5] {
+            store.store_message(seq, &FixMessage::new(msg_type::NEW_ORDER_SINGLE));
+        }
+        let seqs: Vec<u64> = store.messages(3, 10).into_iter().map(|(s, _)| s).collect();
+        assert_eq!(seqs, vec![3, 5]);
+        assert!(store.messages(6, 4).is_empty());
+    }
+
+    #[test]
+    fn test_memory_store_trims_messages() {
+        let store = MemorySequenceStore::new().with_message_window(3);
+        for seq in 1..=5 {
+            store.store_message(seq, &FixMessage::new(msg_type::NEW_ORDER_SINGLE));
+        }
+        store.set_next_sender_seq(6).unwrap();
+        let seqs: Vec<u64> = store.messages(1, 10).into_iter().map(|(s, _)| s).collect();
+        assert_eq!(seqs, vec![3, 4, 5]);
+
+        // 송신 시퀀스를 되돌리면 이후 번호의 기록 삭제
+        store.set_next_sender_seq(4).unwrap();
+        let seqs: Vec<u64> = store.messages(1, 10).into_iter().map(|(s, _)| s).collect();
+        assert_eq!(seqs, vec![3]);
+    }
+}
@@ -43,7 +43,7 @@ use super::{
     },
     config::{KisAccountType, KisEnvironment},
 };
-use crate::{retry::RetryConfig, ExchangeError};
+use crate::{circuit_breaker::CircuitBreaker, retry::RetryConfig, ExchangeError};
 
 /// KIS 통합 클라이언트.
 ///
@@ -138,6 +138,11 @@ impl KisClient {
         self.kr_client.set_retry_config(config);
     }
 
+    /// 커넥터 서킷 브레이커 설정 (국내 시세 조회에 적용).
+    pub fn set_circuit_breaker(&mut self, breaker: Arc<CircuitBreaker>) {
+        self.kr_client.set_circuit_breaker(breaker);
+    }
+
     // ========================================
     // 시세 조회 (심볼 라우팅)
     // ========================================
@@ -8,6 +8,7 @@
 //! - 현재가 조회
 //! - 호가 조회
 //! - 현금 매수/매도 주문
+//! - KOSPI200 옵션 체인 조회
 
 #![allow(dead_code)] // KIS API 응답 필드 전체 매핑
 //! - 주문 정정/취소
@@ -21,7 +22,8 @@ use rust_decimal::Decimal;
 use serde::Deserialize;
 use tracing::{debug, error, info, warn};
 use trader_core::{
-    ExecutionHistory, ExecutionRecord, OrderStatusType, RoundMethod, Side, TickSizeProvider,
+    ExecutionHistory, ExecutionRecord, OptionChain, OptionContract, OptionExpiryRule, OptionGreeks,
+    OptionQuote, OptionRight, OrderStatusType, RoundMethod, Side, TickSizeProvider,
 };
 
 use super::{
@@ -29,7 +31,7 @@ use super::{
     config::{KisAccountType, KisEnvironment},
     tr_id,
 };
-use crate::{retry::RetryConfig, ExchangeError};
+use crate::{circuit_breaker::CircuitBreaker, retry::RetryConfig, ExchangeError};
 
 /// KIS API Rate Limit 에러 메시지 코드.
 /// KIS는 HTTP 500과 함께 이 코드를 반환합니다.
@@ -58,6 +60,8 @@ pub struct KisKrClient {
     retry_config: RetryConfig,
     /// 호가 단위 제공자 (옵션, 설정 시 주문 가격 자동 라운딩)
     tick_size_provider: Option<Arc<dyn TickSizeProvider>>,
+    /// 커넥터 소유 서킷 브레이커 (옵션, 설정 시 시세 조회 최종 결과를 기록)
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
 }
 
 impl KisKrClient {
@@ -108,6 +112,7 @@ impl KisKrClient {
             client,
             retry_config,
             tick_size_provider: None,
+            circuit_breaker: None,
         })
     }
 
@@ -134,6 +139,15 @@ impl KisKrClient {
         self.retry_config = config;
     }
 
+    /// 커넥터 서킷 브레이커 설정.
+    ///
+    /// 재시도를 모두 마친 시세 조회(GET)의 최종 결과가 브레이커에 기록됩니다.
+    /// 같은 브레이커를 `ProviderHealthRegistry::register_breaker`로 등록하면
+    /// 헬스 레지스트리가 별도 브레이커를 두지 않고 이 상태를 그대로 보고합니다.
+    pub fn set_circuit_breaker(&mut self, breaker: Arc<CircuitBreaker>) {
+        self.circuit_breaker = Some(breaker);
+    }
+
     /// 환경에 따른 적절한 tr_id 반환.
     fn get_tr_id<'a>(&self, real_id: &'a str, paper_id: &'a str) -> &'a str {
         match self.oauth.config().environment {
@@ -145,6 +159,7 @@ impl KisKrClient {
     /// 재시도 가능한 GET 요청 실행.
     ///
     /// 네트워크 오류, Rate Limit 등 일시적 오류 발생 시 자동 재시도합니다.
+    /// 커넥터 서킷 브레이커가 설정되어 있으면 최종 결과를 기록합니다.
     async fn execute_get_with_retry<T, F>(
         &self,
         url: &str,
@@ -152,6 +167,26 @@ impl KisKrClient {
         query: &[(&str, &str)],
         parse_response: F,
     ) -> Result<T, ExchangeError>
+    where
+        F: Fn(&str) -> Result<T, ExchangeError>,
+    {
+        let result = self
+            .send_get_with_retry(url, tr_id, query, parse_response)
+            .await;
+        if let Some(breaker) = &self.circuit_breaker {
+            breaker.record_result(&result);
+        }
+        result
+    }
+
+    /// GET 요청 재시도 루프.
+    async fn send_get_with_retry<T, F>(
+        &self,
+        url: &str,
+        tr_id: &str,
+        query: &[(&str, &str)],
+        parse_response: F,
+    ) -> Result<T, ExchangeError>
     where
         F: Fn(&str) -> Result<T, ExchangeError>,
     {
@@ -1000,6 +1035,54 @@ impl KisKrClient {
         Ok(filtered)
     }
 
+    // ========================================
+    // Option APIs (국내 옵션)
+    // ========================================
+
+    /// KOSPI200 옵션 체인 조회 (옵션 전광판 콜/풋).
+    ///
+    /// # 인자
+    /// * `expiry_month` - 만기 월물 (YYYYMM, 예: "202611")
+    ///
+    /// 기초자산 현재가는 제공되지 않으므로 `underlying_price`는 `None`입니다.
+    pub async fn get_option_chain(&self, expiry_month: &str) -> Result<OptionChain, ExchangeError> {
+        let tr_id = self.get_tr_id(tr_id::KR_OPTION_BOARD_REAL, tr_id::KR_OPTION_BOARD_PAPER);
+        let url = format!(
+            "{}/uapi/domestic-futureoption/v1/quotations/display-board-callput",
+            self.oauth.config().rest_base_url()
+        );
+
+        self.execute_get_with_retry(
+            &url,
+            tr_id,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", "O"), // O=옵션
+                ("FID_COND_SCR_DIV_CODE", "20503"),
+                ("FID_MRKT_CLS_CODE", "CO"), // CO=콜옵션
+                ("FID_MTRT_CNT", expiry_month),
+                ("FID_COND_MRKT_CLS_CODE", ""),
+                ("FID_MRKT_CLS_CODE1", "PO"), // PO=풋옵션
+            ],
+            |body| {
+                debug!("KR option board response: {}", body);
+
+                let resp: KisKrOptionBoardResponse = serde_json::from_str(body).map_err(|e| {
+                    ExchangeError::ParseError(format!("Failed to parse option board: {}", e))
+                })?;
+
+                if resp.rt_cd != "0" {
+                    return Err(ExchangeError::ApiError {
+                        code: resp.msg_cd.parse().unwrap_or(-1),
+                        message: resp.msg1,
+                    });
+                }
+
+                build_kospi200_option_chain(expiry_month, &resp.output1, &resp.output2)
+            },
+        )
+        .await
+    }
+
     // ========================================
     // Account APIs (계좌)
     // ========================================
@@ -1653,6 +1736,151 @@ impl KrOrderHistory {
     }
 }
 
+/// 국내 옵션 전광판 항목.
+#[derive(Debug, Clone, Deserialize)]
+pub struct KrOptionBoardItem {
+    /// 옵션 단축 종목코드
+    #[serde(rename = "optn_shrn_iscd", default)]
+    pub code: String,
+    /// 행사가
+    #[serde(rename = "acpr", default, deserialize_with = "deserialize_decimal")]
+    pub strike: Decimal,
+    /// 현재가
+    #[serde(
+        rename = "optn_prpr",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub price: Decimal,
+    /// 매수호가
+    #[serde(
+        rename = "optn_bidp",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub bid: Decimal,
+    /// 매도호가
+    #[serde(
+        rename = "optn_askp",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub ask: Decimal,
+    /// 누적 거래량
+    #[serde(rename = "acml_vol", default, deserialize_with = "deserialize_decimal")]
+    pub volume: Decimal,
+    /// 미결제약정
+    #[serde(
+        rename = "hts_otst_stpl_qty",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub open_interest: Decimal,
+    /// 내재변동성 (%)
+    #[serde(
+        rename = "hts_ints_vltl",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub implied_volatility: Decimal,
+    /// 델타
+    #[serde(
+        rename = "delta_val",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub delta: Decimal,
+    /// 감마
+    #[serde(rename = "gama", default, deserialize_with = "deserialize_decimal")]
+    pub gamma: Decimal,
+    /// 세타
+    #[serde(default, deserialize_with = "deserialize_decimal")]
+    pub theta: Decimal,
+    /// 베가
+    #[serde(default, deserialize_with = "deserialize_decimal")]
+    pub vega: Decimal,
+    /// 로
+    #[serde(default, deserialize_with = "deserialize_decimal")]
+    pub rho: Decimal,
+}
+
+impl KrOptionBoardItem {
+    /// 옵션 시세로 변환 (KOSPI200, 거래승수 25만원, 유럽형).
+    ///
+    /// 그릭스는 거래소 제공값을 그대로 사용합니다.
+    fn to_option_quote(&self, right: OptionRight, expiry: chrono::NaiveDate) -> OptionQuote {
+        use rust_decimal::prelude::ToPrimitive;
+
+        let contract = OptionContract::new(KOSPI200_UNDERLYING, right, self.strike, expiry)
+            .with_multiplier(Decimal::from(KOSPI200_OPTION_MULTIPLIER))
+            .with_exchange_code(self.code.clone());
+
+        let implied_volatility = (self.implied_volatility > Decimal::ZERO)
+            .then(|| self.implied_volatility.to_f64().map(|iv| iv / 100.0))
+            .flatten();
+        let greeks = (self.delta != Decimal::ZERO).then(|| OptionGreeks {
+            delta: self.delta.to_f64().unwrap_or(0.0),
+            gamma: self.gamma.to_f64().unwrap_or(0.0),
+            theta: self.theta.to_f64().unwrap_or(0.0),
+            vega: self.vega.to_f64().unwrap_or(0.0),
+            rho: self.rho.to_f64().unwrap_or(0.0),
+        });
+
+        OptionQuote {
+            contract,
+            last: self.price,
+            bid: self.bid,
+            ask: self.ask,
+            volume: self.volume,
+            open_interest: self.open_interest,
+            implied_volatility,
+            greeks,
+        }
+    }
+}
+
+/// KOSPI200 옵션 기초자산 티커.
+const KOSPI200_UNDERLYING: &str = "KOSPI200";
+
+/// KOSPI200 옵션 거래승수 (1포인트 = 25만원).
+const KOSPI200_OPTION_MULTIPLIER: i64 = 250_000;
+
+/// 전광판 콜/풋 목록으로 KOSPI200 옵션 체인 생성.
+///
+/// 만기일은 해당 월 둘째 목요일(KRX 규칙)로 계산합니다.
+fn build_kospi200_option_chain(
+    expiry_month: &str,
+    calls: &[KrOptionBoardItem],
+    puts: &[KrOptionBoardItem],
+) -> Result<OptionChain, ExchangeError> {
+    let expiry = expiry_month
+        .get(..4)
+        .zip(expiry_month.get(4..6))
+        .and_then(|(year, month)| Some((year.parse::<i32>().ok()?, month.parse::<u32>().ok()?)))
+        .and_then(|(year, month)| OptionExpiryRule::Krx.expiry_in(year, month))
+        .ok_or_else(|| {
+            ExchangeError::ParseError(format!("Invalid expiry month: {}", expiry_month))
+        })?;
+
+    let mut quotes: Vec<OptionQuote> = calls
+        .iter()
+        .map(|item| item.to_option_quote(OptionRight::Call, expiry))
+        .chain(
+            puts.iter()
+                .map(|item| item.to_option_quote(OptionRight::Put, expiry)),
+        )
+        .filter(|quote| quote.contract.strike > Decimal::ZERO)
+        .collect();
+    quotes.sort_by_key(|q| q.contract.strike);
+
+    Ok(OptionChain {
+        underlying: KOSPI200_UNDERLYING.to_string(),
+        underlying_price: None,
+        expiry,
+        quotes,
+    })
+}
+
 // ========================================
 // API 응답 래퍼
 // ========================================
@@ -1716,6 +1944,17 @@ struct KisKrMinuteChartResponse {
     output2: Vec<KrMinuteOhlcv>,
 }
 
+#[derive(Debug, Deserialize)]
+struct KisKrOptionBoardResponse {
+    rt_cd: String,
+    msg_cd: String,
+    msg1: String,
+    #[serde(default)]
+    output1: Vec<KrOptionBoardItem>, // 콜옵션
+    #[serde(default)]
+    output2: Vec<KrOptionBoardItem>, // 풋옵션
+}
+
 #[derive(Debug, Deserialize)]
 struct KisKrOrderHistoryResponse {
     rt_cd: String,
@@ -1773,4 +2012,48 @@ mod tests {
         let result: Test = serde_json::from_str(json).unwrap();
         assert_eq!(result.value, Decimal::ZERO);
     }
+
+    #[test]
+    fn test_build_kospi200_option_chain() {
+        let json = r#"{
+            "rt_cd": "0", "msg_cd": "MCA00000", "msg1": "정상처리",
+            "output1": [
+                {"optn_shrn_iscd": "B01611355", "acpr": "355.00", "optn_prpr": "2.15",
+                 "optn_bidp": "2.10", "optn_askp": "2.20", "acml_vol": "1200",
+                 "hts_otst_stpl_qty": "5400", "hts_ints_vltl": "18.5",
+                 "delta_val": "0.45", "gama": "0.03", "theta": "-0.12", "vega": "0.35", "rho": "0.05"}
+            ],
+            "output2": [
+                {"optn_shrn_iscd": "C01611350", "acpr": "350.00", "optn_prpr": "1.80",
+                 "optn_bidp": "", "optn_askp": "", "acml_vol": "800",
+                 "hts_otst_stpl_qty": "3000", "hts_ints_vltl": "", "delta_val": ""}
+            ]
+        }"#;
+        let resp: KisKrOptionBoardResponse = serde_json::from_str(json).unwrap();
+        let chain = build_kospi200_option_chain("202611", &resp.output1, &resp.output2).unwrap();
+
+        // 2026년 11월 둘째 목요일
+        assert_eq!(
+            chain.expiry,
+            chrono::NaiveDate::from_ymd_opt(2026, 11, 12).unwrap()
+        );
+        assert_eq!(
+            chain.strikes(),
+            vec![Decimal::new(350, 0), Decimal::new(355, 0)]
+        );
+
+        let call = chain
+            .quote(OptionRight::Call, Decimal::new(355, 0))
+            .unwrap();
+        assert_eq!(call.contract.multiplier, Decimal::from(250_000));
+        assert!((call.implied_volatility.unwrap() - 0.185).abs() < 1e-9);
+        assert_eq!(call.greeks.unwrap().delta, 0.45);
+
+        let put = chain.quote(OptionRight::Put, Decimal::new(350, 0)).unwrap();
+        assert_eq!(put.contract.exchange_code.as_deref(), Some("C01611350"));
+        assert!(put.implied_volatility.is_none() && put.greeks.is_none());
+        assert_eq!(put.mid(), Decimal::new(180, 2));
+
+        assert!(build_kospi200_option_chain("2026", &[], &[]).is_err());
+    }
 }
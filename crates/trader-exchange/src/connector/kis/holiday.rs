@@ -19,6 +19,7 @@ use reqwest::Client;
 use serde::Deserialize;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
+use trader_core::{Country, TradingCalendar};
 
 use super::auth::KisOAuth;
 use crate::ExchangeError;
@@ -135,7 +136,7 @@ struct HolidayCache {
 ///
 /// API 호출을 최소화하기 위한 캐싱을 제공합니다.
 pub struct HolidayChecker {
-    oauth: KisOAuth,
+    oauth: Arc<KisOAuth>,
     client: Client,
     /// 국내 시장 휴장일 캐시
     kr_cache: Arc<RwLock<Option<HolidayCache>>>,
@@ -149,6 +150,14 @@ impl HolidayChecker {
     /// # Errors
     /// HTTP 클라이언트 생성에 실패하면 `ExchangeError::NetworkError`를 반환합니다.
     pub fn new(oauth: KisOAuth) -> Result<Self, ExchangeError> {
+        Self::with_shared_oauth(Arc::new(oauth))
+    }
+
+    /// 공유 OAuth로 휴장일 확인기 생성 (클라이언트와 토큰 공유).
+    ///
+    /// # Errors
+    /// HTTP 클라이언트 생성에 실패하면 `ExchangeError::NetworkError`를 반환합니다.
+    pub fn with_shared_oauth(oauth: Arc<KisOAuth>) -> Result<Self, ExchangeError> {
         let client = Client::builder()
             .timeout(Duration::from_secs(oauth.config().timeout_secs))
             .build()
@@ -569,6 +578,52 @@ impl HolidayChecker {
         sorted.sort();
         Ok(sorted)
     }
+
+    /// 휴장일을 반영한 거래 캘린더 생성.
+    ///
+    /// `from`이 속한 달부터 `months`개월의 휴장일을 조회하여
+    /// 전략 일정 이벤트용 [`TradingCalendar`]를 만듭니다.
+    /// 한국/미국 외 국가는 `ExchangeError::NotSupported`를 반환합니다.
+    pub async fn trading_calendar(
+        &self,
+        country: Country,
+        from: NaiveDate,
+        months: u32,
+    ) -> Result<TradingCalendar, ExchangeError> {
+        let mut calendar = match country {
+            Country::KR => TradingCalendar::krx(),
+            Country::US => TradingCalendar::us(),
+            other => {
+                return Err(ExchangeError::NotSupported(format!(
+                    "{} 휴장일 캘린더 미지원",
+                    other
+                )))
+            }
+        };
+
+        let (mut year, mut month) = (from.year(), from.month());
+        for _ in 0..months.max(1) {
+            let holidays = match country {
+                Country::US => self.get_us_holidays_for_month(year, month).await?,
+                _ => self.get_kr_holidays_for_month(year, month).await?,
+            };
+            calendar.add_holidays(holidays);
+
+            if month == 12 {
+                year += 1;
+                month = 1;
+            } else {
+                month += 1;
+            }
+        }
+
+        debug!(
+            country = %country,
+            holidays = calendar.holidays.len(),
+            "Trading calendar loaded"
+        );
+        Ok(calendar)
+    }
 }
 
 /// 시장 상태 열거형.
@@ -8,6 +8,7 @@
 //! - OAuth 2.0 인증 및 자동 토큰 갱신
 //! - 국내 주식/ETF 거래
 //! - KIS를 통한 해외 주식/ETF 거래
+//! - KOSPI200 옵션 체인 조회
 //! - WebSocket을 통한 실시간 시세 수신
 //! - 모의투자 지원
 //!
@@ -131,6 +132,15 @@ pub mod tr_id {
     /// 국내 주식 일별 주문체결 조회 (실전 - ISA/연금저축 등 특수계좌, 1년 이내)
     pub const KR_ORDER_HISTORY_ISA_REAL: &str = "CTSC9115R";
 
+    // ========================================
+    // Korean Domestic Options (국내 옵션)
+    // ========================================
+
+    /// 국내 옵션 전광판 - 콜/풋 (실전)
+    pub const KR_OPTION_BOARD_REAL: &str = "FHPIF05030100";
+    /// 국내 옵션 전광판 - 콜/풋 (모의)
+    pub const KR_OPTION_BOARD_PAPER: &str = "FHPIF05030100";
+
     // ========================================
     // US Stock (해외 주식 - 미국)
     // ========================================
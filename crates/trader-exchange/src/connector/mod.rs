@@ -1,6 +1,7 @@
 pub mod binance;
 pub mod bithumb;
 pub mod db_investment;
+pub mod fix;
 pub mod kis;
 pub mod ls_sec;
 pub mod upbit;
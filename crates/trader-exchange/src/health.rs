@@ -0,0 +1,620 @@
+//! 데이터 제공자 헬스 레지스트리.
+//!
+//! 커넥터별 [`CircuitBreaker`] 메트릭, REST 요청 에러율/지연시간,
+//! WebSocket 하트비트 지연시간을 한 곳에 모아 제공자 상태를 판정합니다.
+//! [`FailoverMarketDataProvider`](crate::provider::FailoverMarketDataProvider)가
+//! 이 상태를 보고 보조 데이터 소스로 자동 전환합니다.
+//!
+//! 자체 Circuit Breaker를 가진 커넥터(예: `KisClient::set_circuit_breaker`)는
+//! [`ProviderHealthRegistry::register_breaker`]로 그 Breaker를 등록하며, 레지스트리는
+//! 별도 Breaker를 만들지 않고 커넥터가 기록한
+//! [`CircuitBreakerMetrics`](crate::circuit_breaker::CircuitBreakerMetrics)를 그대로 사용합니다.
+//! Breaker가 없는 제공자만 레지스트리가 만든 Breaker에 요청 결과를 기록합니다.
+//!
+//! # 상태 판정
+//!
+//! - **Down**: Circuit이 Open
+//! - **Degraded**: Circuit이 HalfOpen, 최근 에러율 초과, 하트비트 지연 초과,
+//!   또는 하트비트가 `heartbeat_stale_after` 이상 끊김
+//! - **Healthy**: 그 외
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, OnceLock, RwLock},
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState, ErrorCategory};
+
+/// 제공자 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    /// 정상
+    Healthy,
+    /// 성능 저하 (요청은 가능하나 보조 소스 우선)
+    Degraded,
+    /// 장애 (요청 차단)
+    Down,
+}
+
+impl std::fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealthStatus::Healthy => write!(f, "healthy"),
+            HealthStatus::Degraded => write!(f, "degraded"),
+            HealthStatus::Down => write!(f, "down"),
+        }
+    }
+}
+
+/// 상태 판정 임계치.
+#[derive(Debug, Clone)]
+pub struct HealthThresholds {
+    /// 에러율 집계 구간
+    pub error_window: Duration,
+    /// 에러율 판정에 필요한 최소 요청 수
+    pub min_samples: usize,
+    /// Degraded로 판정할 에러율 (0.0 ~ 1.0)
+    pub max_error_rate: f64,
+    /// Degraded로 판정할 하트비트 지연시간 (밀리초)
+    pub max_heartbeat_latency_ms: u64,
+    /// 하트비트가 이 시간 이상 없으면 stale로 판정 (Degraded)
+    pub heartbeat_stale_after: Duration,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            error_window: Duration::from_secs(60),
+            min_samples: 5,
+            max_error_rate: 0.5,
+            max_heartbeat_latency_ms: 5_000,
+            heartbeat_stale_after: Duration::from_secs(60),
+        }
+    }
+}
+
+/// REST 요청 샘플.
+#[derive(Debug, Clone, Copy)]
+struct RequestSample {
+    at: Instant,
+    success: bool,
+    latency_ms: u64,
+}
+
+/// 제공자별 내부 상태.
+struct ProviderEntry {
+    breaker: Arc<CircuitBreaker>,
+    /// 커넥터가 직접 결과를 기록하는 Breaker인지 여부 (레지스트리는 기록하지 않음)
+    connector_breaker: bool,
+    samples: VecDeque<RequestSample>,
+    last_error: Option<String>,
+    heartbeat_latency_ms: Option<u64>,
+    last_heartbeat: Option<(Instant, DateTime<Utc>)>,
+}
+
+impl ProviderEntry {
+    fn new(breaker: Arc<CircuitBreaker>) -> Self {
+        Self {
+            breaker,
+            connector_breaker: false,
+            samples: VecDeque::new(),
+            last_error: None,
+            heartbeat_latency_ms: None,
+            last_heartbeat: None,
+        }
+    }
+
+    fn prune(&mut self, window: Duration) {
+        let now = Instant::now();
+        while let Some(front) = self.samples.front() {
+            if now.duration_since(front.at) > window {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+/// 제공자 상태 스냅샷.
+#[derive(Debug, Clone)]
+pub struct ProviderHealthSnapshot {
+    /// 제공자 이름
+    pub name: String,
+    /// 판정 상태
+    pub status: HealthStatus,
+    /// Circuit 상태
+    pub circuit_state: CircuitState,
+    /// 현재 연속 실패 횟수
+    pub consecutive_failures: u32,
+    /// 누적 실패 횟수
+    pub total_failures: u64,
+    /// 누적 성공 횟수
+    pub total_successes: u64,
+    /// Circuit Open 횟수
+    pub open_count: u64,
+    /// Circuit을 Open시킨 에러 카테고리
+    pub tripped_by: Option<ErrorCategory>,
+    /// 집계 구간 내 요청 수
+    pub recent_requests: usize,
+    /// 집계 구간 내 에러율 (0.0 ~ 1.0)
+    pub error_rate: f64,
+    /// 집계 구간 내 평균 지연시간 (밀리초)
+    pub avg_latency_ms: Option<f64>,
+    /// 마지막 하트비트 지연시간 (밀리초)
+    pub heartbeat_latency_ms: Option<u64>,
+    /// 마지막 하트비트 수신 시각
+    pub last_heartbeat_at: Option<DateTime<Utc>>,
+    /// 하트비트가 오래되었는지 여부
+    pub heartbeat_stale: bool,
+    /// 마지막 에러 메시지
+    pub last_error: Option<String>,
+}
+
+/// 장애 조치 그룹 상태 (주 소스 + 보조 소스).
+#[derive(Debug, Clone)]
+pub struct FailoverGroupSnapshot {
+    /// 그룹 이름 (예: "kr_market_data")
+    pub name: String,
+    /// 주 소스
+    pub primary: String,
+    /// 우선순위 순 제공자 목록
+    pub providers: Vec<String>,
+    /// 현재 활성 소스
+    pub active: String,
+    /// 보조 소스로 전환된 상태인지 여부
+    pub failed_over: bool,
+    /// 전환 횟수 (failover + failback)
+    pub switch_count: u64,
+    /// 마지막 전환 시각
+    pub last_switch_at: Option<DateTime<Utc>>,
+}
+
+/// 제공자 헬스 레지스트리.
+pub struct ProviderHealthRegistry {
+    thresholds: HealthThresholds,
+    breaker_config: CircuitBreakerConfig,
+    providers: RwLock<HashMap<String, ProviderEntry>>,
+    groups: RwLock<HashMap<String, FailoverGroupSnapshot>>,
+}
+
+impl Default for ProviderHealthRegistry {
+    fn default() -> Self {
+        Self::new(HealthThresholds::default(), CircuitBreakerConfig::default())
+    }
+}
+
+impl ProviderHealthRegistry {
+    /// 새 레지스트리 생성.
+    pub fn new(thresholds: HealthThresholds, breaker_config: CircuitBreakerConfig) -> Self {
+        Self {
+            thresholds,
+            breaker_config,
+            providers: RwLock::new(HashMap::new()),
+            groups: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 제공자 등록 (이미 있으면 기존 Circuit Breaker 반환).
+    pub fn register(&self, name: &str) -> Arc<CircuitBreaker> {
+        let mut providers = self.providers.write().unwrap();
+        providers
+            .entry(name.to_string())
+            .or_insert_with(|| {
+                ProviderEntry::new(Arc::new(CircuitBreaker::new(
+                    name,
+                    self.breaker_config.clone(),
+                )))
+            })
+            .breaker
+            .clone()
+    }
+
+    /// 커넥터가 소유한 Circuit Breaker 등록 (이름은 `breaker.name()`).
+    ///
+    /// 커넥터가 요청 결과를 직접 기록하므로 레지스트리는 이 Breaker에 다시 기록하지 않고
+    /// 메트릭만 읽습니다. 이미 등록된 제공자면 집계 중인 요청 샘플은 유지합니다.
+    pub fn register_breaker(&self, breaker: Arc<CircuitBreaker>) {
+        let name = breaker.name().to_string();
+        let mut providers = self.providers.write().unwrap();
+        let entry = providers
+            .entry(name)
+            .or_insert_with(|| ProviderEntry::new(breaker.clone()));
+        entry.breaker = breaker;
+        entry.connector_breaker = true;
+    }
+
+    /// 제공자의 Circuit Breaker 조회 (미등록 시 등록).
+    pub fn breaker(&self, name: &str) -> Arc<CircuitBreaker> {
+        if let Some(entry) = self.providers.read().unwrap().get(name) {
+            return entry.breaker.clone();
+        }
+        self.register(name)
+    }
+
+    /// REST 요청 성공 기록.
+    pub fn record_success(&self, name: &str, latency: Duration) {
+        if let Some(breaker) = self.record_request(name, true, latency, None) {
+            breaker.record_success();
+        }
+    }
+
+    /// REST 요청 실패 기록.
+    ///
+    /// `category`가 None이면 Circuit에는 반영하지 않고 에러율에만 반영합니다.
+    pub fn record_failure(
+        &self,
+        name: &str,
+        latency: Duration,
+        category: Option<ErrorCategory>,
+        message: impl Into<String>,
+    ) {
+        let breaker = self.record_request(name, false, latency, Some(message.into()));
+        if let (Some(breaker), Some(category)) = (breaker, category) {
+            breaker.record_failure_with_category(category);
+        }
+    }
+
+    /// 요청 샘플 기록.
+    ///
+    /// 레지스트리가 결과를 기록해야 하는 Breaker(커넥터 소유가 아닌 경우)를 반환합니다.
+    fn record_request(
+        &self,
+        name: &str,
+        success: bool,
+        latency: Duration,
+        error: Option<String>,
+    ) -> Option<Arc<CircuitBreaker>> {
+        self.register(name);
+        let mut providers = self.providers.write().unwrap();
+        let entry = providers.get_mut(name)?;
+        entry.samples.push_back(RequestSample {
+            at: Instant::now(),
+            success,
+            latency_ms: latency.as_millis() as u64,
+        });
+        entry.prune(self.thresholds.error_window);
+        if error.is_some() {
+            entry.last_error = error;
+        }
+        (!entry.connector_breaker).then(|| entry.breaker.clone())
+    }
+
+    /// WebSocket 하트비트 기록.
+    ///
+    /// `latency_ms`는 이벤트 발생 시각과 수신 시각의 차이이며, 발생 시각을 알 수 없는
+    /// 이벤트(진행 중인 캔들 등)는 `None`으로 수신 여부만 기록합니다.
+    pub fn record_heartbeat(&self, name: &str, latency_ms: Option<u64>) {
+        self.register(name);
+        let mut providers = self.providers.write().unwrap();
+        if let Some(entry) = providers.get_mut(name) {
+            if latency_ms.is_some() {
+                entry.heartbeat_latency_ms = latency_ms;
+            }
+            entry.last_heartbeat = Some((Instant::now(), Utc::now()));
+        }
+    }
+
+    /// 제공자 상태 판정 (미등록 제공자는 Healthy).
+    pub fn status(&self, name: &str) -> HealthStatus {
+        self.snapshot(name)
+            .map(|s| s.status)
+            .unwrap_or(HealthStatus::Healthy)
+    }
+
+    /// 제공자 상태 스냅샷.
+    pub fn snapshot(&self, name: &str) -> Option<ProviderHealthSnapshot> {
+        let mut providers = self.providers.write().unwrap();
+        let entry = providers.get_mut(name)?;
+        entry.prune(self.thresholds.error_window);
+        Some(self.build_snapshot(name, entry))
+    }
+
+    /// 모든 제공자 상태 스냅샷 (이름순).
+    pub fn snapshots(&self) -> Vec<ProviderHealthSnapshot> {
+        let mut providers = self.providers.write().unwrap();
+        let mut snapshots: Vec<_> = providers
+            .iter_mut()
+            .map(|(name, entry)| {
+                entry.prune(self.thresholds.error_window);
+                self.build_snapshot(name, entry)
+            })
+            .collect();
+        snapshots.sort_by(|a, b| a.name.cmp(&b.name));
+        snapshots
+    }
+
+    fn build_snapshot(&self, name: &str, entry: &ProviderEntry) -> ProviderHealthSnapshot {
+        // state()는 Open → HalfOpen 타임아웃 전이를 반영하므로 메트릭보다 먼저 호출
+        entry.breaker.state();
+        let metrics = entry.breaker.metrics();
+        let circuit_state = metrics.state;
+
+        let recent_requests = entry.samples.len();
+        let failures = entry.samples.iter().filter(|s| !s.success).count();
+        let error_rate = if recent_requests == 0 {
+            0.0
+        } else {
+            failures as f64 / recent_requests as f64
+        };
+        let avg_latency_ms = (recent_requests > 0).then(|| {
+            entry
+                .samples
+                .iter()
+                .map(|s| s.latency_ms as f64)
+                .sum::<f64>()
+                / recent_requests as f64
+        });
+        let heartbeat_stale = entry
+            .last_heartbeat
+            .map(|(at, _)| at.elapsed() > self.thresholds.heartbeat_stale_after)
+            .unwrap_or(false);
+
+        let status = match circuit_state {
+            CircuitState::Open => HealthStatus::Down,
+            CircuitState::HalfOpen => HealthStatus::Degraded,
+            CircuitState::Closed => {
+                let error_rate_exceeded = recent_requests >= self.thresholds.min_samples
+                    && error_rate > self.thresholds.max_error_rate;
+                let heartbeat_slow = entry
+                    .heartbeat_latency_ms
+                    .map(|ms| ms > self.thresholds.max_heartbeat_latency_ms)
+                    .unwrap_or(false);
+                if error_rate_exceeded || heartbeat_slow || heartbeat_stale {
+                    HealthStatus::Degraded
+                } else {
+                    HealthStatus::Healthy
+                }
+            }
+        };
+
+        ProviderHealthSnapshot {
+            name: name.to_string(),
+            status,
+            circuit_state,
+            consecutive_failures: metrics.failure_count,
+            total_failures: metrics.total_failures,
+            total_successes: metrics.total_successes,
+            open_count: metrics.open_count,
+            tripped_by: metrics.tripped_by,
+            recent_requests,
+            error_rate,
+            avg_latency_ms,
+            heartbeat_latency_ms: entry.heartbeat_latency_ms,
+            last_heartbeat_at: entry.last_heartbeat.map(|(_, at)| at),
+            heartbeat_stale,
+            last_error: entry.last_error.clone(),
+        }
+    }
+
+    /// 장애 조치 그룹 등록.
+    ///
+    /// 이미 등록된 그룹이면 제공자 목록만 갱신합니다 (활성 소스 유지).
+    pub fn register_group(&self, name: &str, providers: Vec<String>) {
+        let primary = providers.first().cloned().unwrap_or_default();
+        let mut groups = self.groups.write().unwrap();
+        match groups.get_mut(name) {
+            Some(snapshot) => snapshot.providers = providers,
+            None => {
+                groups.insert(
+                    name.to_string(),
+                    FailoverGroupSnapshot {
+                        name: name.to_string(),
+                        primary: primary.clone(),
+                        providers,
+                        active: primary,
+                        failed_over: false,
+                        switch_count: 0,
+                        last_switch_at: None,
+                    },
+                );
+            }
+        }
+    }
+
+    /// 그룹의 활성 소스 갱신.
+    ///
+    /// 활성 소스가 바뀌었으면 `true`를 반환합니다.
+    pub fn set_active(&self, group: &str, active: &str) -> bool {
+        let mut groups = self.groups.write().unwrap();
+        let Some(snapshot) = groups.get_mut(group) else {
+            return false;
+        };
+        if snapshot.active == active {
+            return false;
+        }
+
+        let previous = std::mem::replace(&mut snapshot.active, active.to_string());
+        snapshot.failed_over = snapshot.active != snapshot.primary;
+        snapshot.switch_count += 1;
+        snapshot.last_switch_at = Some(Utc::now());
+
+        if snapshot.failed_over {
+            tracing::warn!(
+                group = %group,
+                from = %previous,
+                to = %active,
+                "데이터 제공자 장애 조치 (failover)"
+            );
+        } else {
+            tracing::info!(
+                group = %group,
+                from = %previous,
+                to = %active,
+                "주 데이터 제공자로 복귀 (failback)"
+            );
+        }
+        true
+    }
+
+    /// 장애 조치 그룹 목록 (이름순).
+    pub fn groups(&self) -> Vec<FailoverGroupSnapshot> {
+        let mut groups: Vec<_> = self.groups.read().unwrap().values().cloned().collect();
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
+        groups
+    }
+}
+
+static GLOBAL_REGISTRY: OnceLock<Arc<ProviderHealthRegistry>> = OnceLock::new();
+
+/// 전역 헬스 레지스트리 가져오기.
+///
+/// REST 제공자와 WebSocket 스트림이 같은 레지스트리에 상태를 기록합니다.
+pub fn global_health_registry() -> Arc<ProviderHealthRegistry> {
+    GLOBAL_REGISTRY
+        .get_or_init(|| Arc::new(ProviderHealthRegistry::default()))
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> ProviderHealthRegistry {
+        ProviderHealthRegistry::new(
+            HealthThresholds {
+                min_samples: 4,
+                max_error_rate: 0.5,
+                max_heartbeat_latency_ms: 1_000,
+                ..Default::default()
+            },
+            CircuitBreakerConfig::new(3, 60, 1),
+        )
+    }
+
+    #[test]
+    fn test_unknown_provider_is_healthy() {
+        let registry = registry();
+        assert_eq!(registry.status("kis"), HealthStatus::Healthy);
+        assert!(registry.snapshot("kis").is_none());
+    }
+
+    #[test]
+    fn test_error_rate_degrades() {
+        let registry = registry();
+        let latency = Duration::from_millis(20);
+
+        registry.record_success("kis", latency);
+        registry.record_failure("kis", latency, None, "HTTP 500");
+        registry.record_failure("kis", latency, None, "HTTP 500");
+        // 최소 샘플 수 미달
+        assert_eq!(registry.status("kis"), HealthStatus::Healthy);
+
+        registry.record_failure("kis", latency, None, "HTTP 502");
+        let snapshot = registry.snapshot("kis").unwrap();
+        assert_eq!(snapshot.status, HealthStatus::Degraded);
+        assert_eq!(snapshot.recent_requests, 4);
+        assert!((snapshot.error_rate - 0.75).abs() < 1e-9);
+        assert_eq!(snapshot.last_error.as_deref(), Some("HTTP 502"));
+        assert_eq!(snapshot.circuit_state, CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_open_circuit_is_down() {
+        let registry = registry();
+        for _ in 0..3 {
+            registry.record_failure(
+                "kis",
+                Duration::from_millis(5),
+                Some(ErrorCategory::Network),
+                "connection reset",
+            );
+        }
+
+        let snapshot = registry.snapshot("kis").unwrap();
+        assert_eq!(snapshot.status, HealthStatus::Down);
+        assert_eq!(snapshot.circuit_state, CircuitState::Open);
+        assert_eq!(snapshot.tripped_by, Some(ErrorCategory::Network));
+        assert!(!registry.breaker("kis").is_allowed());
+    }
+
+    #[test]
+    fn test_heartbeat_latency_degrades() {
+        let registry = registry();
+        registry.record_heartbeat("kis_ws", Some(200));
+        assert_eq!(registry.status("kis_ws"), HealthStatus::Healthy);
+
+        // 발생 시각이 없는 이벤트는 마지막 지연시간을 유지
+        registry.record_heartbeat("kis_ws", None);
+        assert_eq!(
+            registry.snapshot("kis_ws").unwrap().heartbeat_latency_ms,
+            Some(200)
+        );
+
+        registry.record_heartbeat("kis_ws", Some(3_000));
+        let snapshot = registry.snapshot("kis_ws").unwrap();
+        assert_eq!(snapshot.status, HealthStatus::Degraded);
+        assert_eq!(snapshot.heartbeat_latency_ms, Some(3_000));
+        assert!(snapshot.last_heartbeat_at.is_some());
+        assert!(!snapshot.heartbeat_stale);
+    }
+
+    #[test]
+    fn test_stale_heartbeat_degrades() {
+        let registry = ProviderHealthRegistry::new(
+            HealthThresholds {
+                heartbeat_stale_after: Duration::from_millis(20),
+                ..Default::default()
+            },
+            CircuitBreakerConfig::default(),
+        );
+        registry.record_heartbeat("kis_ws", Some(100));
+        assert_eq!(registry.status("kis_ws"), HealthStatus::Healthy);
+
+        std::thread::sleep(Duration::from_millis(40));
+        let snapshot = registry.snapshot("kis_ws").unwrap();
+        assert!(snapshot.heartbeat_stale);
+        assert_eq!(snapshot.status, HealthStatus::Degraded);
+
+        registry.record_heartbeat("kis_ws", Some(100));
+        assert_eq!(registry.status("kis_ws"), HealthStatus::Healthy);
+    }
+
+    #[test]
+    fn test_connector_breaker_is_reused() {
+        let registry = registry();
+        let breaker = Arc::new(CircuitBreaker::new(
+            "kis",
+            CircuitBreakerConfig::new(2, 60, 1),
+        ));
+        registry.register_breaker(breaker.clone());
+        assert!(Arc::ptr_eq(&registry.breaker("kis"), &breaker));
+
+        // 레지스트리 기록은 에러율에만 반영 (커넥터가 Breaker에 직접 기록)
+        let latency = Duration::from_millis(5);
+        registry.record_failure("kis", latency, Some(ErrorCategory::Network), "reset");
+        registry.record_failure("kis", latency, Some(ErrorCategory::Network), "reset");
+        assert_eq!(breaker.metrics().total_failures, 0);
+        assert_eq!(registry.snapshot("kis").unwrap().recent_requests, 2);
+
+        // 커넥터 Breaker가 Open되면 Down
+        breaker.record_failure_with_category(ErrorCategory::Network);
+        breaker.record_failure_with_category(ErrorCategory::Network);
+        let snapshot = registry.snapshot("kis").unwrap();
+        assert_eq!(snapshot.status, HealthStatus::Down);
+        assert_eq!(snapshot.total_failures, 2);
+    }
+
+    #[test]
+    fn test_group_switch_tracking() {
+        let registry = registry();
+        registry.register_group("kr", vec!["kis".into(), "ls_sec".into()]);
+
+        assert!(!registry.set_active("kr", "kis"));
+        assert!(registry.set_active("kr", "ls_sec"));
+        let group = &registry.groups()[0];
+        assert_eq!(group.active, "ls_sec");
+        assert!(group.failed_over);
+
+        assert!(registry.set_active("kr", "kis"));
+        let group = &registry.groups()[0];
+        assert!(!group.failed_over);
+        assert_eq!(group.switch_count, 2);
+        assert!(!registry.set_active("unknown", "kis"));
+    }
+}
@@ -18,6 +18,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Duration, NaiveDate, Utc};
 use tracing::{debug, info, warn};
 use trader_core::{Kline, Symbol, Timeframe};
+use trader_data::{DataError, KlineSource};
 
 use crate::{
     connector::kis::{KisClient, KrMinuteOhlcv, KrOhlcv, UsOhlcv},
@@ -344,6 +345,36 @@ impl HistoricalDataProvider for UnifiedHistoricalProvider {
     }
 }
 
+/// 실시간 바 빌더 백필 소스.
+///
+/// 해외 주식은 분봉 API가 없으므로 분봉 요청 시 빈 목록을 반환합니다
+/// (일봉 대체 조회는 백필에 쓸 수 없음).
+#[async_trait]
+impl KlineSource for UnifiedHistoricalProvider {
+    async fn recent_klines(
+        &self,
+        ticker: &str,
+        timeframe: Timeframe,
+        limit: usize,
+    ) -> trader_data::Result<Vec<Kline>> {
+        if !Self::is_korean_symbol(ticker) && Self::needs_minute_data(timeframe) {
+            return Ok(Vec::new());
+        }
+        let klines = self
+            .get_klines(ticker, timeframe, limit)
+            .await
+            .map_err(|e| DataError::FetchError(e.to_string()))?;
+        // 심볼 표기("005930/KRW") 대신 요청 티커로 통일
+        Ok(klines
+            .into_iter()
+            .map(|kline| Kline {
+                ticker: ticker.to_string(),
+                ..kline
+            })
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,14 +3,19 @@
 //! 이 크레이트는 다음을 제공합니다:
 //! - Exchange trait: 통합 거래소 인터페이스
 //! - Binance 커넥터 (REST + WebSocket)
+//! - FIX 4.4 주문 게이트웨이 (프라임 브로커 연동)
 //! - 시뮬레이션 거래소 (백테스팅 및 모의투자용)
+//! - 주문 유형 × 유효 기간 적합성 테스트 스위트 (엔진/커넥터 공용)
 //! - 시장 데이터 정규화
 //! - Rate limiting 및 에러 처리
 //! - Circuit breaker: 장애 허용을 위한 회로 차단기
+//! - Provider health: 제공자 상태 판정 및 시세 소스 장애 조치
 
 pub mod circuit_breaker;
+pub mod conformance;
 pub mod connector;
 pub mod error;
+pub mod health;
 pub mod historical;
 pub mod provider;
 pub mod retry;
@@ -27,16 +32,22 @@ pub use circuit_breaker::{
 pub use connector::{
     bithumb::{BithumbClient, BithumbConfig},
     db_investment::{DbInvestmentClient, DbInvestmentConfig},
+    fix::{FixClient, FixConfig},
     kis::client::KisClient,
     ls_sec::{LsSecClient, LsSecConfig},
     upbit::{UpbitClient, UpbitConfig},
 };
 pub use error::*;
+pub use health::{
+    global_health_registry, FailoverGroupSnapshot, HealthStatus, HealthThresholds,
+    ProviderHealthRegistry, ProviderHealthSnapshot,
+};
 pub use historical::{HistoricalDataProvider, UnifiedHistoricalProvider};
 pub use provider::{
     BinanceExchangeProvider, BinanceProvider, BithumbExchangeProvider, BithumbProvider,
-    DbInvestmentExchangeProvider, DbInvestmentProvider, KisExchangeProvider, KisProvider,
-    LsSecExchangeProvider, LsSecProvider, UpbitExchangeProvider, UpbitProvider,
+    DbInvestmentExchangeProvider, DbInvestmentProvider, FailoverMarketDataProvider,
+    FixExchangeProvider, KisExchangeProvider, KisProvider, LsSecExchangeProvider, LsSecProvider,
+    NaverQuoteProvider, UpbitExchangeProvider, UpbitProvider,
 };
 pub use retry::{
     with_retry, with_retry_context, with_retry_if, RetryConfig, RetryContext, RetryStats,
@@ -16,7 +16,8 @@
 //! ├── OrderExecutionProvider 구현
 //! │   ├── place_order() - 주문 제출
 //! │   ├── cancel_order() - 주문 취소
-//! │   └── modify_order() - Unsupported (Spot 미지원)
+//! │   ├── modify_order() - Unsupported (Spot 미지원)
+//! │   └── get_order_status() - 주문 체결 현황
 //! └── 내부
 //!     ├── client: Arc<BinanceClient>
 //!     └── cache: Arc<ExchangeCache>
@@ -32,8 +33,8 @@ use trader_core::{
     cache::ExchangeCache,
     domain::{
         ExchangeProvider, ExecutionHistoryRequest, ExecutionHistoryResponse, MarketDataProvider,
-        OrderExecutionProvider, OrderResponse, PendingOrder, ProviderError, QuoteData, Side,
-        StrategyAccountInfo, StrategyPositionInfo, Trade,
+        OrderBook, OrderExecutionProvider, OrderResponse, OrderStatus, PendingOrder, ProviderError,
+        QuoteData, Side, StrategyAccountInfo, StrategyPositionInfo, Trade,
     },
 };
 use uuid::Uuid;
@@ -384,6 +385,13 @@ impl MarketDataProvider for BinanceExchangeProvider {
         })
     }
 
+    async fn get_order_book(&self, symbol: &str) -> Result<OrderBook, ProviderError> {
+        self.client
+            .get_order_book(symbol, Some(50))
+            .await
+            .map_err(to_provider_error)
+    }
+
     fn provider_name(&self) -> &str {
         "Binance"
     }
@@ -446,6 +454,17 @@ impl OrderExecutionProvider for BinanceExchangeProvider {
         ))
     }
 
+    async fn get_order_status(
+        &self,
+        order_id: &str,
+        ticker: &str,
+    ) -> Result<OrderStatus, ProviderError> {
+        self.client
+            .get_order(ticker, order_id)
+            .await
+            .map_err(to_provider_error)
+    }
+
     fn exchange_name(&self) -> &str {
         "Binance"
     }
@@ -11,8 +11,8 @@ use trader_core::{
     cache::ExchangeCache,
     domain::{
         ExchangeProvider, ExecutionHistoryRequest, ExecutionHistoryResponse, MarketDataProvider,
-        OrderExecutionProvider, OrderRequest, OrderResponse, OrderType, PendingOrder,
-        ProviderError, QuoteData, Side, StrategyAccountInfo, StrategyPositionInfo,
+        OrderBook, OrderExecutionProvider, OrderRequest, OrderResponse, OrderStatus, OrderType,
+        PendingOrder, ProviderError, QuoteData, Side, StrategyAccountInfo, StrategyPositionInfo,
     },
 };
 
@@ -124,6 +124,10 @@ impl MarketDataProvider for BithumbExchangeProvider {
         self.client.get_quotes(symbols).await
     }
 
+    async fn get_order_book(&self, symbol: &str) -> Result<OrderBook, ProviderError> {
+        self.client.get_order_book(symbol).await
+    }
+
     fn provider_name(&self) -> &str {
         "bithumb"
     }
@@ -264,6 +268,14 @@ impl OrderExecutionProvider for BithumbExchangeProvider {
         })
     }
 
+    async fn get_order_status(
+        &self,
+        order_id: &str,
+        _ticker: &str,
+    ) -> Result<OrderStatus, ProviderError> {
+        Ok(self.client.get_order(order_id).await?.to_order_status())
+    }
+
     fn exchange_name(&self) -> &str {
         "bithumb"
     }
@@ -0,0 +1,380 @@
+//! 장애 조치(failover) MarketDataProvider.
+//!
+//! 주 데이터 소스(예: KIS)와 우선순위가 지정된 보조 소스들(LS증권, DB금융투자,
+//! 네이버 지연 시세 등)을 묶어 하나의 [`MarketDataProvider`]로 제공합니다.
+//!
+//! # 소스 선택
+//!
+//! 1. 주 소스가 Healthy이면 주 소스 사용
+//! 2. 주 소스의 Circuit이 HalfOpen이면 복구 확인을 위해 주 소스를 먼저 시도
+//! 3. 그 외에는 Healthy한 보조 소스 → Degraded 소스 순으로 시도
+//!
+//! 요청 결과는 [`ProviderHealthRegistry`]에 기록되며, 주 소스가 다시 Healthy가 되면
+//! 자동으로 복귀(failback)합니다.
+
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use trader_core::domain::{MarketDataProvider, OrderBook, ProviderError, QuoteData};
+
+use crate::{
+    circuit_breaker::{CircuitState, ErrorCategory},
+    health::{HealthStatus, ProviderHealthRegistry},
+};
+
+/// 이름이 붙은 데이터 소스.
+struct NamedSource {
+    name: String,
+    provider: Arc<dyn MarketDataProvider>,
+}
+
+/// 장애 조치 MarketDataProvider.
+pub struct FailoverMarketDataProvider {
+    group: String,
+    sources: Vec<NamedSource>,
+    registry: Arc<ProviderHealthRegistry>,
+}
+
+impl FailoverMarketDataProvider {
+    /// 주 소스로 생성.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - 장애 조치 그룹 이름 (모니터링 표시용)
+    /// * `primary_name` - 주 소스 이름 (레지스트리 키)
+    /// * `primary` - 주 소스
+    /// * `registry` - 헬스 레지스트리
+    pub fn new(
+        group: impl Into<String>,
+        primary_name: impl Into<String>,
+        primary: Arc<dyn MarketDataProvider>,
+        registry: Arc<ProviderHealthRegistry>,
+    ) -> Self {
+        let group = group.into();
+        let primary_name = primary_name.into();
+        registry.register(&primary_name);
+        registry.register_group(&group, vec![primary_name.clone()]);
+
+        Self {
+            group,
+            sources: vec![NamedSource {
+                name: primary_name,
+                provider: primary,
+            }],
+            registry,
+        }
+    }
+
+    /// 보조 소스 추가 (추가한 순서가 우선순위).
+    pub fn with_secondary(
+        mut self,
+        name: impl Into<String>,
+        provider: Arc<dyn MarketDataProvider>,
+    ) -> Self {
+        let name = name.into();
+        self.registry.register(&name);
+        self.sources.push(NamedSource { name, provider });
+
+        // 그룹 정보 갱신 (제공자 목록 변경)
+        let names: Vec<String> = self.sources.iter().map(|s| s.name.clone()).collect();
+        self.registry.register_group(&self.group, names);
+        self
+    }
+
+    /// 그룹 이름.
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    /// 보조 소스 개수.
+    pub fn secondary_count(&self) -> usize {
+        self.sources.len() - 1
+    }
+
+    /// 시도 순서 결정 (소스 인덱스 목록).
+    fn candidates(&self) -> Vec<usize> {
+        let statuses: Vec<HealthStatus> = self
+            .sources
+            .iter()
+            .map(|s| self.registry.status(&s.name))
+            .collect();
+        let primary_half_open =
+            self.registry.breaker(&self.sources[0].name).state() == CircuitState::HalfOpen;
+
+        let mut order = Vec::with_capacity(self.sources.len());
+        if statuses[0] == HealthStatus::Healthy || primary_half_open {
+            order.push(0);
+        }
+        order.extend((1..self.sources.len()).filter(|&i| statuses[i] == HealthStatus::Healthy));
+        let degraded: Vec<usize> = (0..self.sources.len())
+            .filter(|&i| statuses[i] == HealthStatus::Degraded && !order.contains(&i))
+            .collect();
+        order.extend(degraded);
+        // 모든 소스가 Down이면 순서대로 시도 (Circuit이 거부하면 건너뜀)
+        if order.is_empty() {
+            order.extend(0..self.sources.len());
+        }
+        order
+    }
+
+    /// 시도 순서대로 호출하여 첫 성공 결과 반환.
+    async fn call<T, F, Fut>(&self, op: F) -> Result<T, ProviderError>
+    where
+        F: Fn(Arc<dyn MarketDataProvider>) -> Fut,
+        Fut: Future<Output = Result<T, ProviderError>>,
+    {
+        let mut last_error = None;
+
+        for index in self.candidates() {
+            let source = &self.sources[index];
+            if !self.registry.breaker(&source.name).is_allowed() {
+                continue;
+            }
+
+            let started = Instant::now();
+            match op(source.provider.clone()).await {
+                Ok(value) => {
+                    self.registry
+                        .record_success(&source.name, started.elapsed());
+                    self.registry.set_active(&self.group, &source.name);
+                    return Ok(value);
+                }
+                // 미지원 기능은 장애가 아니므로 기록하지 않고 다음 소스 시도
+                Err(e @ ProviderError::Unsupported(_)) => {
+                    last_error = Some(e);
+                }
+                Err(e) => {
+                    self.record_error(&source.name, started.elapsed(), &e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            ProviderError::Network(format!(
+                "{}: 사용 가능한 데이터 소스가 없습니다 (모든 Circuit Open)",
+                self.group
+            ))
+        }))
+    }
+
+    fn record_error(&self, name: &str, latency: Duration, error: &ProviderError) {
+        tracing::debug!(provider = %name, error = %error, "데이터 소스 요청 실패");
+        self.registry
+            .record_failure(name, latency, error_category(error), error.to_string());
+    }
+}
+
+/// ProviderError를 Circuit Breaker 카테고리로 분류.
+///
+/// 인증/파싱 에러처럼 재시도해도 해결되지 않는 에러는 Circuit에 반영하지 않습니다.
+fn error_category(error: &ProviderError) -> Option<ErrorCategory> {
+    match error {
+        ProviderError::Network(msg) if msg.to_lowercase().contains("timeout") => {
+            Some(ErrorCategory::Timeout)
+        }
+        ProviderError::Network(_) => Some(ErrorCategory::Network),
+        ProviderError::Api(msg) if msg.to_lowercase().contains("rate") => {
+            Some(ErrorCategory::RateLimit)
+        }
+        ProviderError::Api(_) | ProviderError::Other(_) => Some(ErrorCategory::Service),
+        _ => None,
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for FailoverMarketDataProvider {
+    async fn get_quote(&self, symbol: &str) -> Result<QuoteData, ProviderError> {
+        self.call(|p| async move { p.get_quote(symbol).await })
+            .await
+    }
+
+    async fn get_quotes(&self, symbols: &[String]) -> Vec<QuoteData> {
+        // 일괄 조회는 부분 실패를 반환하지 않으므로 결과가 비어 있으면 실패로 간주
+        self.call(|p| async move {
+            let quotes = p.get_quotes(symbols).await;
+            if quotes.is_empty() && !symbols.is_empty() {
+                Err(ProviderError::Api("일괄 시세 조회 결과 없음".to_string()))
+            } else {
+                Ok(quotes)
+            }
+        })
+        .await
+        .unwrap_or_default()
+    }
+
+    async fn get_order_book(&self, symbol: &str) -> Result<OrderBook, ProviderError> {
+        self.call(|p| async move { p.get_order_book(symbol).await })
+            .await
+    }
+
+    fn provider_name(&self) -> &str {
+        &self.group
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{circuit_breaker::CircuitBreakerConfig, health::HealthThresholds};
+
+    struct FakeSource {
+        name: &'static str,
+        failing: AtomicBool,
+        calls: AtomicUsize,
+    }
+
+    impl FakeSource {
+        fn new(name: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                failing: AtomicBool::new(false),
+                calls: AtomicUsize::new(0),
+            })
+        }
+
+        fn set_failing(&self, failing: bool) {
+            self.failing.store(failing, Ordering::SeqCst);
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl MarketDataProvider for FakeSource {
+        async fn get_quote(&self, symbol: &str) -> Result<QuoteData, ProviderError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(ProviderError::Network("connection refused".to_string()));
+            }
+            Ok(QuoteData::new(
+                symbol,
+                dec!(70000),
+                dec!(0),
+                dec!(0),
+                dec!(70000),
+                dec!(70000),
+                dec!(70000),
+                dec!(70000),
+                dec!(0),
+                dec!(0),
+            ))
+        }
+
+        fn provider_name(&self) -> &str {
+            self.name
+        }
+    }
+
+    fn registry(reset_timeout_ms: u64) -> Arc<ProviderHealthRegistry> {
+        let mut breaker_config = CircuitBreakerConfig::default();
+        breaker_config.failure_threshold = 2;
+        breaker_config.reset_timeout_ms = reset_timeout_ms;
+        Arc::new(ProviderHealthRegistry::new(
+            HealthThresholds::default(),
+            breaker_config,
+        ))
+    }
+
+    fn active(registry: &ProviderHealthRegistry) -> String {
+        registry.groups()[0].active.clone()
+    }
+
+    #[tokio::test]
+    async fn test_uses_primary_when_healthy() {
+        let registry = registry(60_000);
+        let kis = FakeSource::new("kis");
+        let ls = FakeSource::new("ls_sec");
+        let provider = FailoverMarketDataProvider::new("kr", "kis", kis.clone(), registry.clone())
+            .with_secondary("ls_sec", ls.clone());
+
+        assert!(provider.get_quote("005930").await.is_ok());
+        assert_eq!(kis.calls(), 1);
+        assert_eq!(ls.calls(), 0);
+        assert_eq!(active(&registry), "kis");
+        assert_eq!(provider.secondary_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failover_and_failback() {
+        let registry = registry(50);
+        let kis = FakeSource::new("kis");
+        let ls = FakeSource::new("ls_sec");
+        let naver = FakeSource::new("naver");
+        let provider = FailoverMarketDataProvider::new("kr", "kis", kis.clone(), registry.clone())
+            .with_secondary("ls_sec", ls.clone())
+            .with_secondary("naver", naver.clone());
+
+        // 주 소스 장애: 요청마다 보조 소스로 넘어가고, 2회 실패 후 Circuit Open
+        kis.set_failing(true);
+        for _ in 0..2 {
+            assert!(provider.get_quote("005930").await.is_ok());
+        }
+        assert_eq!(registry.status("kis"), HealthStatus::Down);
+        assert_eq!(active(&registry), "ls_sec");
+
+        // Circuit Open 동안은 주 소스를 호출하지 않음
+        let kis_calls = kis.calls();
+        assert!(provider.get_quote("005930").await.is_ok());
+        assert_eq!(kis.calls(), kis_calls);
+        assert_eq!(naver.calls(), 0);
+
+        // 주 소스 복구 + 타임아웃 경과 → HalfOpen 탐색 요청 성공 → failback
+        kis.set_failing(false);
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert!(provider.get_quote("005930").await.is_ok());
+        assert_eq!(active(&registry), "kis");
+        assert_eq!(registry.groups()[0].switch_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_all_sources_failing() {
+        let registry = registry(60_000);
+        let kis = FakeSource::new("kis");
+        let ls = FakeSource::new("ls_sec");
+        kis.set_failing(true);
+        ls.set_failing(true);
+        let provider = FailoverMarketDataProvider::new("kr", "kis", kis.clone(), registry.clone())
+            .with_secondary("ls_sec", ls.clone());
+
+        assert!(matches!(
+            provider.get_quote("005930").await,
+            Err(ProviderError::Network(_))
+        ));
+        assert!(provider.get_quote("005930").await.is_err());
+
+        // 두 소스 모두 Circuit Open → 호출 없이 실패
+        let calls = (kis.calls(), ls.calls());
+        assert!(matches!(
+            provider.get_quote("005930").await,
+            Err(ProviderError::Network(msg)) if msg.contains("Circuit")
+        ));
+        assert_eq!((kis.calls(), ls.calls()), calls);
+    }
+
+    #[test]
+    fn test_error_category() {
+        assert_eq!(
+            error_category(&ProviderError::Network("Timeout".into())),
+            Some(ErrorCategory::Timeout)
+        );
+        assert_eq!(
+            error_category(&ProviderError::Api("rate limit exceeded".into())),
+            Some(ErrorCategory::RateLimit)
+        );
+        assert_eq!(
+            error_category(&ProviderError::Authentication("bad key".into())),
+            None
+        );
+    }
+}
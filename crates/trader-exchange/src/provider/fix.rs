@@ -0,0 +1,69 @@
+//! FIX 4.4 OrderExecutionProvider 구현.
+//!
+//! FixClient를 래핑하여 FIX 전용 프라임 브로커를 다른 거래소와 동일하게
+//! `OrderExecutor`/`LiveExecutor`에서 사용할 수 있도록 합니다.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use tokio::sync::broadcast;
+use trader_core::domain::{
+    OrderExecutionProvider, OrderRequest, OrderResponse, OrderStatus, ProviderError,
+};
+
+use crate::connector::fix::FixClient;
+
+/// FIX OrderExecutionProvider 구현.
+///
+/// 주문 응답은 ExecutionReport 첫 수신 시점에 반환되며,
+/// 이후 체결/취소 알림은 [`subscribe_execution_reports`](Self::subscribe_execution_reports)로 받습니다.
+pub struct FixExchangeProvider {
+    client: Arc<FixClient>,
+}
+
+impl FixExchangeProvider {
+    /// 새 FixExchangeProvider 생성.
+    pub fn new(client: Arc<FixClient>) -> Self {
+        Self { client }
+    }
+
+    /// ExecutionReport 기반 주문 상태 구독.
+    pub fn subscribe_execution_reports(&self) -> broadcast::Receiver<OrderStatus> {
+        self.client.subscribe_execution_reports()
+    }
+
+    /// FIX 클라이언트 참조.
+    pub fn client(&self) -> Arc<FixClient> {
+        Arc::clone(&self.client)
+    }
+}
+
+// ==================== OrderExecutionProvider ====================
+
+#[async_trait]
+impl OrderExecutionProvider for FixExchangeProvider {
+    async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ProviderError> {
+        self.client.place_order(request).await
+    }
+
+    async fn cancel_order(&self, order_id: &str, ticker: &str) -> Result<(), ProviderError> {
+        self.client.cancel_order(order_id, ticker).await
+    }
+
+    async fn modify_order(
+        &self,
+        order_id: &str,
+        ticker: &str,
+        quantity: Option<Decimal>,
+        price: Option<Decimal>,
+    ) -> Result<OrderResponse, ProviderError> {
+        self.client
+            .modify_order(order_id, ticker, quantity, price)
+            .await
+    }
+
+    fn exchange_name(&self) -> &str {
+        &self.client.config().exchange_name
+    }
+}
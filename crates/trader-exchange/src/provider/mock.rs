@@ -40,13 +40,15 @@ use trader_core::{
         OrderExecutionProvider, OrderRequest, OrderResponse, PendingOrder, ProviderError, Side,
         StrategyAccountInfo, StrategyPositionInfo, Trade,
     },
-    OrderType, Ticker, Timeframe,
+    OrderStatusType, OrderType, Ticker, Timeframe,
 };
-use trader_execution::{ProcessorPosition, TradeResult};
+use trader_execution::{ProcessorPosition, SignalProcessor, SignalProcessorError, TradeResult};
 use uuid::Uuid;
 
 use crate::{
-    historical::HistoricalDataProvider, simulated::EventBroadcaster, traits::MarketEvent,
+    historical::HistoricalDataProvider,
+    simulated::{order_rules::is_conditional, EventBroadcaster},
+    traits::MarketEvent,
     yahoo::YahooFinanceProvider,
 };
 
@@ -444,6 +446,7 @@ impl MockExchangeProvider {
                     "TakeProfit" => OrderType::TakeProfit,
                     "StopLossLimit" => OrderType::StopLossLimit,
                     "TakeProfitLimit" => OrderType::TakeProfitLimit,
+                    "TrailingStop" => OrderType::TrailingStop,
                     _ => OrderType::Limit,
                 };
 
@@ -560,6 +563,7 @@ impl MockExchangeProvider {
                 OrderType::TakeProfit => "TakeProfit",
                 OrderType::StopLossLimit => "StopLossLimit",
                 OrderType::TakeProfitLimit => "TakeProfitLimit",
+                OrderType::TrailingStop => "TrailingStop",
                 _ => "Limit",
             };
 
@@ -1037,6 +1041,87 @@ impl MockExchangeProvider {
         info!("Mock 전체 상태 초기화 완료");
         Ok(())
     }
+
+    /// 전략 하나의 체결을 [`SignalProcessor`]로 다루는 어댑터 생성.
+    ///
+    /// 다중 레그 그룹은 [`SignalProcessor::process_signal_group`]으로 처리하여
+    /// 숏 레그 우선 순서와 진입 레그 되돌림을 실거래/시뮬레이션과 동일하게 적용합니다.
+    pub async fn strategy_processor(&self, strategy_id: &str) -> MockStrategyProcessor<'_> {
+        let mut processor = MockStrategyProcessor {
+            provider: self,
+            strategy_id: strategy_id.to_string(),
+            balance: Decimal::ZERO,
+            positions: HashMap::new(),
+            trades: Vec::new(),
+        };
+        processor.refresh().await;
+        processor
+    }
+}
+
+/// 전략 하나의 Mock 체결 [`SignalProcessor`] 어댑터.
+///
+/// 체결은 [`MockExchangeProvider::process_signal`]에 위임하고, 잔고/포지션/거래 기록은
+/// 생성 시점과 각 체결 직후의 스냅샷을 반환합니다.
+pub struct MockStrategyProcessor<'a> {
+    provider: &'a MockExchangeProvider,
+    strategy_id: String,
+    balance: Decimal,
+    positions: HashMap<String, ProcessorPosition>,
+    trades: Vec<TradeResult>,
+}
+
+impl MockStrategyProcessor<'_> {
+    /// Provider 상태에서 스냅샷 갱신.
+    async fn refresh(&mut self) {
+        let state = self.provider.state.read().await;
+        if let Some(strategy) = state.get_strategy(&self.strategy_id) {
+            self.balance = strategy.balance;
+            self.positions = strategy.positions.clone();
+            self.trades = strategy.trades.clone();
+        }
+    }
+}
+
+#[async_trait]
+impl SignalProcessor for MockStrategyProcessor<'_> {
+    async fn process_signal(
+        &mut self,
+        signal: &trader_core::Signal,
+        current_price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Option<TradeResult>, SignalProcessorError> {
+        let result = self
+            .provider
+            .process_signal(signal, current_price, timestamp)
+            .await
+            .map_err(|e| SignalProcessorError::ExchangeError(e.to_string()));
+        self.refresh().await;
+        result
+    }
+
+    fn balance(&self) -> Decimal {
+        self.balance
+    }
+
+    fn positions(&self) -> &HashMap<String, ProcessorPosition> {
+        &self.positions
+    }
+
+    fn trades(&self) -> &[TradeResult] {
+        &self.trades
+    }
+
+    fn total_commission(&self) -> Decimal {
+        self.trades.iter().map(|t| t.commission).sum()
+    }
+
+    /// 스냅샷만 초기화합니다 (Provider 상태는 [`MockExchangeProvider::reset_strategy`] 사용).
+    fn reset(&mut self, initial_balance: Decimal) {
+        self.balance = initial_balance;
+        self.positions.clear();
+        self.trades.clear();
+    }
 }
 
 #[async_trait]
@@ -1520,12 +1605,22 @@ impl MockExchangeProvider {
                                 books.insert(symbol.clone(), orderbook.clone());
                             }
 
-                            // 4. 미체결 주문 매칭
-                            let fills = {
+                            // 4. 미체결 주문 매칭 (만료 주문 회수 포함)
+                            let (fills, expired) = {
                                 let mut engine = order_engine.write().await;
-                                engine.on_price_tick(symbol, &ticker, &orderbook)
+                                let fills = engine.on_price_tick(symbol, &ticker, &orderbook);
+                                (fills, engine.take_expired_orders())
                             };
 
+                            if !expired.is_empty() {
+                                let mut mock_state = state.write().await;
+                                for cancel in &expired {
+                                    if let Some(strategy_state) = mock_state.get_strategy_mut(&cancel.strategy_id) {
+                                        strategy_state.release_reservation(cancel.released_amount);
+                                    }
+                                }
+                            }
+
                             // 5. 체결 결과 처리 (잔고 업데이트)
                             if !fills.is_empty() {
                                 let mut mock_state = state.write().await;
@@ -1570,6 +1665,27 @@ impl MockExchangeProvider {
         Ok(())
     }
 
+    /// 큐에 등록된 주문의 예약금을 전략 잔고에 반영 (실패 시 주문 취소).
+    async fn reserve_for_order(
+        &self,
+        engine: &mut super::mock_order_engine::MockOrderEngine,
+        order_id: &str,
+        strategy_id: &str,
+    ) -> Result<(), ProviderError> {
+        let reserved = engine.get_reserved_amount(order_id);
+        if reserved > Decimal::ZERO {
+            let mut state = self.state.write().await;
+            if let Some(strategy_state) = state.get_strategy_mut(strategy_id) {
+                if let Err(e) = strategy_state.reserve(reserved) {
+                    // 예약 실패 → 주문 취소
+                    engine.cancel_order(order_id);
+                    return Err(ProviderError::Other(e));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// 최신 Ticker 캐시 조회.
     pub async fn get_latest_ticker(&self, symbol: &str) -> Option<Ticker> {
         self.latest_tickers.read().await.get(symbol).cloned()
@@ -1595,178 +1711,105 @@ impl MockExchangeProvider {
 #[async_trait]
 impl OrderExecutionProvider for MockExchangeProvider {
     async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ProviderError> {
-        use trader_core::OrderType;
-
         let strategy_id = request.strategy_id.clone().unwrap_or_default();
 
-        match request.order_type {
-            OrderType::Market => {
-                // 시장가: OrderBook VWAP 체결
-                let orderbook = self
-                    .latest_order_books
-                    .read()
-                    .await
-                    .get(&request.ticker)
-                    .cloned();
-
-                let mut engine = self.order_engine.write().await;
-                if let Some(ref ob) = orderbook {
-                    if let Some(fill) = engine.submit_market_order(request, ob, &strategy_id) {
-                        // 잔고 업데이트
-                        let mut state = self.state.write().await;
-                        if let Some(strategy_state) = state.get_strategy_mut(&strategy_id) {
-                            match fill.side {
-                                Side::Buy => {
-                                    let cost =
-                                        fill.fill_price * fill.filled_quantity + fill.commission;
-                                    if strategy_state.available_balance() < cost {
-                                        return Err(ProviderError::Other(format!(
-                                            "[{}] 자금 부족: 필요 {}, 가용 {}",
-                                            strategy_id,
-                                            cost,
-                                            strategy_state.available_balance()
-                                        )));
-                                    }
-                                    strategy_state.balance -= cost;
-                                }
-                                Side::Sell => {
-                                    let proceeds =
-                                        fill.fill_price * fill.filled_quantity - fill.commission;
-                                    strategy_state.balance += proceeds;
-                                }
-                            }
-                        }
+        let ticker_data = self
+            .latest_tickers
+            .read()
+            .await
+            .get(&request.ticker)
+            .cloned();
+        let orderbook = self
+            .latest_order_books
+            .read()
+            .await
+            .get(&request.ticker)
+            .cloned();
 
-                        return Ok(OrderResponse {
-                            order_no: fill.order_id,
-                            order_time: Utc::now().format("%H%M%S").to_string(),
-                        });
-                    }
-                }
+        let mut engine = self.order_engine.write().await;
 
-                // OrderBook 없으면 레거시 즉시 체결
-                let order_no = Uuid::new_v4().to_string();
-                debug!(
-                    "[Mock] 레거시 즉시 체결: {} {:?} {} @ {:?}",
-                    request.ticker, request.side, request.quantity, request.price
-                );
-                Ok(OrderResponse {
-                    order_no,
+        let Some(ticker) = ticker_data else {
+            // Ticker 없음: 조건부 주문은 큐 등록, 그 외는 레거시 처리
+            if is_conditional(request.order_type) {
+                let order_id = engine
+                    .submit_stop_order(request, &strategy_id)
+                    .map_err(ProviderError::Other)?;
+                self.reserve_for_order(&mut engine, &order_id, &strategy_id)
+                    .await?;
+                return Ok(OrderResponse {
+                    order_no: order_id,
                     order_time: Utc::now().format("%H%M%S").to_string(),
-                })
+                });
             }
 
-            OrderType::Limit => {
-                // 지정가: 즉시 체결 가능이면 체결, 아니면 큐 등록 + 잔고 예약
-                let ticker_data = self
-                    .latest_tickers
-                    .read()
-                    .await
-                    .get(&request.ticker)
-                    .cloned();
-
-                let mut engine = self.order_engine.write().await;
-                if let Some(ref t) = ticker_data {
-                    match engine.submit_limit_order(request, t, &strategy_id) {
-                        Ok((order_id, Some(fill))) => {
-                            // 즉시 체결
-                            let mut state = self.state.write().await;
-                            if let Some(strategy_state) = state.get_strategy_mut(&strategy_id) {
-                                match fill.side {
-                                    Side::Buy => {
-                                        let cost = fill.fill_price * fill.filled_quantity
-                                            + fill.commission;
-                                        strategy_state.balance -= cost;
-                                    }
-                                    Side::Sell => {
-                                        let proceeds = fill.fill_price * fill.filled_quantity
-                                            - fill.commission;
-                                        strategy_state.balance += proceeds;
-                                    }
-                                }
-                            }
-
-                            Ok(OrderResponse {
-                                order_no: order_id,
-                                order_time: Utc::now().format("%H%M%S").to_string(),
-                            })
-                        }
-                        Ok((order_id, None)) => {
-                            // 큐 등록됨 → 잔고 예약
-                            let reserved = engine.get_reserved_amount(&order_id);
-                            if reserved > Decimal::ZERO {
-                                let mut state = self.state.write().await;
-                                if let Some(strategy_state) = state.get_strategy_mut(&strategy_id) {
-                                    if let Err(e) = strategy_state.reserve(reserved) {
-                                        // 예약 실패 → 주문 취소
-                                        engine.cancel_order(&order_id);
-                                        return Err(ProviderError::Other(e));
-                                    }
-                                }
-                            }
+            let order_no = Uuid::new_v4().to_string();
+            debug!(
+                "[Mock] Ticker 없음, 레거시 처리: {} {:?} {:?} {} @ {:?}",
+                request.ticker, request.order_type, request.side, request.quantity, request.price
+            );
+            return Ok(OrderResponse {
+                order_no,
+                order_time: Utc::now().format("%H%M%S").to_string(),
+            });
+        };
 
-                            Ok(OrderResponse {
-                                order_no: order_id,
-                                order_time: Utc::now().format("%H%M%S").to_string(),
-                            })
+        // 시장가/지정가/조건부 주문 모두 엔진 규칙으로 처리
+        let submission = engine
+            .submit_order(request, &ticker, orderbook.as_ref(), &strategy_id)
+            .map_err(ProviderError::Other)?;
+
+        if let Some(fill) = &submission.fill {
+            // 즉시 체결분 잔고 반영
+            let mut state = self.state.write().await;
+            if let Some(strategy_state) = state.get_strategy_mut(&strategy_id) {
+                match fill.side {
+                    Side::Buy => {
+                        let cost = fill.fill_price * fill.filled_quantity + fill.commission;
+                        if request.order_type == OrderType::Market
+                            && strategy_state.available_balance() < cost
+                        {
+                            return Err(ProviderError::Other(format!(
+                                "[{}] 자금 부족: 필요 {}, 가용 {}",
+                                strategy_id,
+                                cost,
+                                strategy_state.available_balance()
+                            )));
                         }
-                        Err(e) => Err(ProviderError::Other(e)),
+                        strategy_state.balance -= cost;
                     }
-                } else {
-                    // Ticker 없으면 큐 등록만
-                    let order_no = Uuid::new_v4().to_string();
-                    debug!(
-                        "[Mock] Ticker 없음, 지정가 주문 보류: {} @ {:?}",
-                        request.ticker, request.price
-                    );
-                    Ok(OrderResponse {
-                        order_no,
-                        order_time: Utc::now().format("%H%M%S").to_string(),
-                    })
-                }
-            }
-
-            OrderType::StopLoss
-            | OrderType::StopLossLimit
-            | OrderType::TakeProfit
-            | OrderType::TakeProfitLimit => {
-                // 스톱 계열 주문: 큐 등록 + 잔고 예약
-                let mut engine = self.order_engine.write().await;
-                match engine.submit_stop_order(request, &strategy_id) {
-                    Ok(order_id) => {
-                        let reserved = engine.get_reserved_amount(&order_id);
-                        if reserved > Decimal::ZERO {
-                            let mut state = self.state.write().await;
-                            if let Some(strategy_state) = state.get_strategy_mut(&strategy_id) {
-                                if let Err(e) = strategy_state.reserve(reserved) {
-                                    engine.cancel_order(&order_id);
-                                    return Err(ProviderError::Other(e));
-                                }
-                            }
-                        }
-
-                        Ok(OrderResponse {
-                            order_no: order_id,
-                            order_time: Utc::now().format("%H%M%S").to_string(),
-                        })
+                    Side::Sell => {
+                        let proceeds = fill.fill_price * fill.filled_quantity - fill.commission;
+                        strategy_state.balance += proceeds;
                     }
-                    Err(e) => Err(ProviderError::Other(e)),
                 }
             }
+        }
 
-            _ => {
-                // 트레일링 스톱 등 미지원 주문 유형
-                let order_no = Uuid::new_v4().to_string();
-                debug!(
-                    "[Mock] 미지원 주문 유형, 레거시 체결: {:?}",
-                    request.order_type
+        match submission.status {
+            OrderStatusType::Open | OrderStatusType::PartiallyFilled => {
+                // 큐 등록됨 → 잔고 예약
+                self.reserve_for_order(&mut engine, &submission.order_id, &strategy_id)
+                    .await?;
+            }
+            OrderStatusType::Expired => {
+                info!(
+                    "[Mock] 주문 만료 ({:?}): {} (체결: {})",
+                    request.time_in_force,
+                    submission.order_id,
+                    submission
+                        .fill
+                        .as_ref()
+                        .map(|f| f.filled_quantity)
+                        .unwrap_or(Decimal::ZERO)
                 );
-                Ok(OrderResponse {
-                    order_no,
-                    order_time: Utc::now().format("%H%M%S").to_string(),
-                })
             }
+            _ => {}
         }
+
+        Ok(OrderResponse {
+            order_no: submission.order_id,
+            order_time: Utc::now().format("%H%M%S").to_string(),
+        })
     }
 
     async fn cancel_order(&self, order_id: &str, _ticker: &str) -> Result<(), ProviderError> {
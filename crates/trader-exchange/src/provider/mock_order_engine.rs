@@ -2,14 +2,16 @@
 //!
 //! 호가창(OrderBook) 기반 VWAP 체결 + 지정가/스톱 주문 미체결 큐 관리를 제공합니다.
 //! 기존 `MatchingEngine`은 백테스트용(Kline 기반)이며, 이 엔진은 Paper Trading 전용입니다.
+//! 두 엔진은 [`order_rules`](crate::simulated::order_rules)의 주문 의미를 공유합니다.
 //!
 //! # 핵심 기능
 //!
 //! - 시장가 주문: OrderBook ask/bid 레벨 순서대로 VWAP 체결
 //! - 지정가 주문: 즉시 체결 가능이면 체결, 아니면 큐 등록
-//! - 스톱 주문: stop_price 도달 시 시장가로 전환
+//! - 스톱/익절/트레일링 주문: 트리거 시 시장가 또는 지정가로 전환
+//! - 유효 기간: IOC/FOK는 제출(조건부 주문은 트리거) 시점에 적용, GTD는 만료 시각에 만료
 //! - 부분 체결: OrderBook 물량 부족 시 가능한 만큼만 체결
-//! - 잔고 예약: 지정가 주문 시 필요 자금 예약 (cancel 시 해제)
+//! - 잔고 예약: 지정가 주문 시 필요 자금 예약 (cancel/만료 시 해제)
 
 use std::{collections::HashMap, sync::Arc};
 
@@ -19,7 +21,12 @@ use rust_decimal_macros::dec;
 use tracing::{debug, info};
 use trader_core::{
     OrderBook, OrderBookLevel, OrderRequest, OrderStatusType, OrderType, PendingOrder, Side,
-    TickSizeProvider, Ticker,
+    TickSizeProvider, Ticker, TimeInForce,
+};
+
+use crate::simulated::order_rules::{
+    apply_time_in_force, is_conditional, is_expired, is_marketable, requires_limit_price,
+    validate_order_request, OrderTrigger,
 };
 
 // ==================== 체결 결과 ====================
@@ -60,6 +67,17 @@ pub struct MockCancelResult {
     pub order_id: String,
 }
 
+/// 주문 제출 결과.
+#[derive(Debug, Clone)]
+pub struct MockSubmission {
+    /// 주문 ID
+    pub order_id: String,
+    /// 즉시 체결분
+    pub fill: Option<MockOrderFill>,
+    /// 제출 직후 주문 상태 (Open/PartiallyFilled면 큐에 대기 중)
+    pub status: OrderStatusType,
+}
+
 /// DB 영속화용 미체결 주문 정보.
 ///
 /// `get_raw_pending_orders()`에서 반환됩니다.
@@ -77,9 +95,9 @@ pub struct RawPendingOrder {
     pub quantity: Decimal,
     /// 남은 수량
     pub remaining_quantity: Decimal,
-    /// 지정가
+    /// 지정가 (트레일링 스톱은 트레일링 폭 %)
     pub price: Option<Decimal>,
-    /// 스톱 가격
+    /// 스톱 가격 (트레일링 스톱은 활성화 가격)
     pub stop_price: Option<Decimal>,
     /// 예약 금액
     pub reserved_amount: Decimal,
@@ -99,7 +117,6 @@ struct MockPendingOrder {
     /// 방향
     side: Side,
     /// 주문 유형
-    #[allow(dead_code)]
     order_type: OrderType,
     /// 원래 수량
     original_quantity: Decimal,
@@ -109,14 +126,20 @@ struct MockPendingOrder {
     price: Option<Decimal>,
     /// 스톱 가격
     stop_price: Option<Decimal>,
+    /// 트레일링 폭 (%)
+    trailing_percent: Option<Decimal>,
+    /// 유효 기간
+    time_in_force: TimeInForce,
+    /// 만료 시각 (GTD)
+    expire_time: Option<DateTime<Utc>>,
+    /// 조건부 주문 트리거 상태
+    trigger: Option<OrderTrigger>,
     /// 전략 ID
     strategy_id: String,
     /// 예약 금액
     reserved_amount: Decimal,
     /// 생성 시각
     created_at: DateTime<Utc>,
-    /// 스톱 트리거 여부
-    stop_triggered: bool,
 }
 
 // ==================== MockOrderEngine ====================
@@ -132,6 +155,8 @@ pub struct MockOrderEngine {
     order_strategy_map: HashMap<String, String>,
     /// 주문 ID → 예약금 매핑
     reserved_amounts: HashMap<String, Decimal>,
+    /// 만료된 주문 (예약금 해제 대기)
+    expired_orders: Vec<MockCancelResult>,
     /// 수수료율
     fee_rate: Decimal,
     /// 슬리피지율
@@ -149,6 +174,7 @@ impl MockOrderEngine {
             pending_orders: HashMap::new(),
             order_strategy_map: HashMap::new(),
             reserved_amounts: HashMap::new(),
+            expired_orders: Vec::new(),
             fee_rate,
             slippage_rate,
             tick_size_provider: None,
@@ -169,6 +195,233 @@ impl MockOrderEngine {
         id
     }
 
+    // ==================== 통합 주문 제출 ====================
+
+    /// 주문 제출 (모든 `OrderType` × `TimeInForce`).
+    ///
+    /// - 시장가: 호가창 VWAP 체결, 잔량은 만료 (FOK는 전량 체결 불가 시 체결 없음)
+    /// - 지정가: 즉시 체결 가능한 물량(지정가 이내 호가) 체결 후 잔량은 유효 기간에 따라 대기/만료
+    /// - 조건부: 큐에 등록되어 `on_price_tick()`에서 트리거 판정
+    ///
+    /// 호가창이 없으면 Ticker 호가에서 전량 체결 가능한 것으로 간주합니다.
+    /// 만료 판정 시각은 `ticker.timestamp`입니다.
+    pub fn submit_order(
+        &mut self,
+        request: &OrderRequest,
+        ticker: &Ticker,
+        orderbook: Option<&OrderBook>,
+        strategy_id: &str,
+    ) -> Result<MockSubmission, String> {
+        validate_order_request(request)?;
+        let order_id = self.generate_order_id();
+
+        if is_expired(request.time_in_force, request.expire_time, ticker.timestamp) {
+            debug!(
+                "[MockEngine] 제출 시점 만료: {} ({})",
+                order_id, request.ticker
+            );
+            return Ok(MockSubmission {
+                order_id,
+                fill: None,
+                status: OrderStatusType::Expired,
+            });
+        }
+
+        if is_conditional(request.order_type) {
+            self.queue_order(
+                order_id.clone(),
+                request,
+                strategy_id,
+                request.quantity,
+                OrderTrigger::from_request(request, ticker.last),
+                Some(ticker.last),
+            );
+            return Ok(MockSubmission {
+                order_id,
+                fill: None,
+                status: OrderStatusType::Open,
+            });
+        }
+
+        let limit_price = request
+            .price
+            .filter(|_| request.order_type == OrderType::Limit);
+        let quote = match request.side {
+            Side::Buy => ticker.ask,
+            Side::Sell => ticker.bid,
+        };
+        let fallback = [OrderBookLevel {
+            price: limit_price.unwrap_or(quote),
+            quantity: request.quantity,
+        }];
+        let levels = match orderbook {
+            Some(book) => match request.side {
+                Side::Buy => book.asks.as_slice(),
+                Side::Sell => book.bids.as_slice(),
+            },
+            None => &fallback,
+        };
+
+        let marketable =
+            limit_price.map_or(true, |limit| is_marketable(request.side, limit, quote));
+        let (_, fillable) = if marketable {
+            Self::calculate_vwap_within(levels, request.side, limit_price, request.quantity)
+        } else {
+            (Decimal::ZERO, Decimal::ZERO)
+        };
+
+        let outcome = apply_time_in_force(
+            request.time_in_force,
+            limit_price.is_some(),
+            request.quantity,
+            fillable,
+        );
+
+        let fill = if outcome.fill_quantity.is_zero() {
+            None
+        } else {
+            let (vwap, filled) = Self::calculate_vwap_within(
+                levels,
+                request.side,
+                limit_price,
+                outcome.fill_quantity,
+            );
+            let execution_price = self.execution_price(request.side, vwap, limit_price);
+
+            info!(
+                "[MockEngine] 즉시 체결: {} {:?} {} @ {} ({:?}/{:?})",
+                request.ticker,
+                request.side,
+                filled,
+                execution_price,
+                request.order_type,
+                request.time_in_force
+            );
+
+            Some(MockOrderFill {
+                order_id: order_id.clone(),
+                symbol: request.ticker.clone(),
+                side: request.side,
+                filled_quantity: filled,
+                fill_price: execution_price,
+                commission: execution_price * filled * self.fee_rate,
+                timestamp: Utc::now(),
+                strategy_id: strategy_id.to_string(),
+                is_fully_filled: filled >= request.quantity,
+                released_reservation: Decimal::ZERO,
+            })
+        };
+
+        let remaining = request.quantity - outcome.fill_quantity;
+        let status = if remaining.is_zero() {
+            OrderStatusType::Filled
+        } else if outcome.expire_remainder {
+            OrderStatusType::Expired
+        } else {
+            self.queue_order(
+                order_id.clone(),
+                request,
+                strategy_id,
+                remaining,
+                None,
+                None,
+            );
+            if fill.is_some() {
+                OrderStatusType::PartiallyFilled
+            } else {
+                OrderStatusType::Open
+            }
+        };
+
+        Ok(MockSubmission {
+            order_id,
+            fill,
+            status,
+        })
+    }
+
+    /// 미체결 큐에 주문 등록 (예약금 계산 포함).
+    fn queue_order(
+        &mut self,
+        order_id: String,
+        request: &OrderRequest,
+        strategy_id: &str,
+        remaining: Decimal,
+        trigger: Option<OrderTrigger>,
+        reference_price: Option<Decimal>,
+    ) {
+        let reserved_amount = match request.side {
+            Side::Buy => {
+                let base = Decimal::ONE + self.fee_rate;
+                if let (true, Some(price)) =
+                    (requires_limit_price(request.order_type), request.price)
+                {
+                    price * remaining * base
+                } else {
+                    // 시장가 전환 주문은 트리거 가격 기준 5% 버퍼
+                    let reference = trigger
+                        .as_ref()
+                        .and_then(|t| t.trigger_level())
+                        .or(request.stop_price)
+                        .or(reference_price)
+                        .unwrap_or(Decimal::ZERO);
+                    reference * remaining * base * dec!(1.05)
+                }
+            }
+            Side::Sell => Decimal::ZERO, // 매도는 포지션이 담보
+        };
+
+        let pending = MockPendingOrder {
+            order_id: order_id.clone(),
+            symbol: request.ticker.clone(),
+            side: request.side,
+            order_type: request.order_type,
+            original_quantity: request.quantity,
+            remaining_quantity: remaining,
+            price: request.price,
+            stop_price: request.stop_price,
+            trailing_percent: request.trailing_percent,
+            time_in_force: request.time_in_force,
+            expire_time: request.expire_time,
+            trigger,
+            strategy_id: strategy_id.to_string(),
+            reserved_amount,
+            created_at: Utc::now(),
+        };
+
+        self.pending_orders
+            .entry(request.ticker.clone())
+            .or_default()
+            .push(pending);
+        self.order_strategy_map
+            .insert(order_id.clone(), strategy_id.to_string());
+        self.reserved_amounts
+            .insert(order_id.clone(), reserved_amount);
+
+        debug!(
+            "[MockEngine] 큐 등록: {} {:?} {:?} {} (price={:?}, stop={:?}, 예약금: {})",
+            request.ticker,
+            request.side,
+            request.order_type,
+            remaining,
+            request.price,
+            request.stop_price,
+            reserved_amount
+        );
+    }
+
+    /// 체결 가격 계산 (지정가 계열은 VWAP 그대로, 시장가 계열은 슬리피지 적용).
+    fn execution_price(&self, side: Side, vwap: Decimal, limit_price: Option<Decimal>) -> Decimal {
+        if limit_price.is_some() {
+            return vwap;
+        }
+        let slippage = vwap * self.slippage_rate;
+        match side {
+            Side::Buy => vwap + slippage,
+            Side::Sell => vwap - slippage,
+        }
+    }
+
     // ==================== 시장가 주문 ====================
 
     /// 시장가 주문 체결 (OrderBook VWAP).
@@ -176,7 +429,7 @@ impl MockOrderEngine {
     /// 매수: ask 레벨 순서대로 소진 (오름차순)
     /// 매도: bid 레벨 순서대로 소진 (내림차순)
     ///
-    /// 물량 부족 시 부분 체결 가능.
+    /// 물량 부족 시 부분 체결 가능 (잔량은 만료). FOK는 전량 체결 불가 시 `None`.
     pub fn submit_market_order(
         &mut self,
         request: &OrderRequest,
@@ -184,12 +437,19 @@ impl MockOrderEngine {
         strategy_id: &str,
     ) -> Option<MockOrderFill> {
         let order_id = self.generate_order_id();
+        if is_expired(request.time_in_force, request.expire_time, Utc::now()) {
+            return None;
+        }
+
         let levels = match request.side {
             Side::Buy => &orderbook.asks,
             Side::Sell => &orderbook.bids,
         };
 
-        let (fill_price, filled_qty) = Self::calculate_vwap(levels, request.quantity);
+        let (_, available) = Self::calculate_vwap(levels, request.quantity);
+        let outcome =
+            apply_time_in_force(request.time_in_force, false, request.quantity, available);
+        let (fill_price, filled_qty) = Self::calculate_vwap(levels, outcome.fill_quantity);
 
         if filled_qty.is_zero() {
             debug!(
@@ -200,12 +460,7 @@ impl MockOrderEngine {
         }
 
         // 슬리피지 적용
-        let slippage = fill_price * self.slippage_rate;
-        let execution_price = match request.side {
-            Side::Buy => fill_price + slippage,
-            Side::Sell => fill_price - slippage,
-        };
-
+        let execution_price = self.execution_price(request.side, fill_price, None);
         let commission = execution_price * filled_qty * self.fee_rate;
 
         info!(
@@ -232,10 +487,11 @@ impl MockOrderEngine {
     /// 지정가 주문 제출.
     ///
     /// 즉시 체결 가능한 가격이면 바로 체결하고, 아니면 큐에 등록합니다.
+    /// 호가창 없이 Ticker만으로 판정하므로 즉시 체결 시 전량 지정가로 체결됩니다.
     ///
     /// # Returns
-    /// - `Ok(Some(fill))`: 즉시 체결
-    /// - `Ok(None)`: 큐 등록됨
+    /// - `Ok((id, Some(fill)))`: 즉시 체결
+    /// - `Ok((id, None))`: 큐 등록됨 (IOC/FOK는 체결 불가 시 큐 등록 없이 만료)
     /// - `Err(...)`: 주문 실패
     pub fn submit_limit_order(
         &mut self,
@@ -243,127 +499,44 @@ impl MockOrderEngine {
         ticker: &Ticker,
         strategy_id: &str,
     ) -> Result<(String, Option<MockOrderFill>), String> {
-        let order_id = self.generate_order_id();
-        let limit_price = request.price.ok_or("지정가 주문에 가격 필수")?;
-
-        // 즉시 체결 가능 여부 확인
-        let can_fill_immediately = match request.side {
-            Side::Buy => ticker.ask <= limit_price,
-            Side::Sell => ticker.bid >= limit_price,
-        };
-
-        if can_fill_immediately {
-            // 즉시 체결 (슬리피지 없이 지정가로 체결)
-            let execution_price = limit_price;
-            let commission = execution_price * request.quantity * self.fee_rate;
-
-            info!(
-                "[MockEngine] 지정가 즉시 체결: {} {:?} {} @ {}",
-                request.ticker, request.side, request.quantity, execution_price
-            );
-
-            let fill = MockOrderFill {
-                order_id: order_id.clone(),
-                symbol: request.ticker.clone(),
-                side: request.side,
-                filled_quantity: request.quantity,
-                fill_price: execution_price,
-                commission,
-                timestamp: Utc::now(),
-                strategy_id: strategy_id.to_string(),
-                is_fully_filled: true,
-                released_reservation: Decimal::ZERO,
-            };
-
-            return Ok((order_id, Some(fill)));
+        if request.price.is_none() {
+            return Err("지정가 주문에 가격 필수".to_string());
         }
-
-        // 예약금 계산
-        let reserved_amount = match request.side {
-            Side::Buy => limit_price * request.quantity * (Decimal::ONE + self.fee_rate),
-            Side::Sell => Decimal::ZERO, // 매도는 포지션이 담보
-        };
-
-        // 큐에 등록
-        let pending = MockPendingOrder {
-            order_id: order_id.clone(),
-            symbol: request.ticker.clone(),
-            side: request.side,
-            order_type: OrderType::Limit,
-            original_quantity: request.quantity,
-            remaining_quantity: request.quantity,
-            price: Some(limit_price),
-            stop_price: None,
-            strategy_id: strategy_id.to_string(),
-            reserved_amount,
-            created_at: Utc::now(),
-            stop_triggered: false,
-        };
-
-        self.pending_orders
-            .entry(request.ticker.clone())
-            .or_default()
-            .push(pending);
-        self.order_strategy_map
-            .insert(order_id.clone(), strategy_id.to_string());
-        self.reserved_amounts
-            .insert(order_id.clone(), reserved_amount);
-
-        debug!(
-            "[MockEngine] 지정가 큐 등록: {} {:?} {} @ {} (예약금: {})",
-            request.ticker, request.side, request.quantity, limit_price, reserved_amount
-        );
-
-        Ok((order_id, None))
+        let submission = self.submit_order(request, ticker, None, strategy_id)?;
+        Ok((submission.order_id, submission.fill))
     }
 
     // ==================== 스톱 주문 ====================
 
-    /// 스톱 주문 제출.
+    /// 스톱/익절/트레일링 주문 제출.
     ///
-    /// stop_price 도달 전까지 큐에 대기하다가, 도달하면 시장가로 전환됩니다.
+    /// 트리거 전까지 큐에 대기하다가, 트리거되면 시장가(지정가 계열은 지정가)로 전환됩니다.
+    /// 활성화 가격 없는 트레일링 스톱은 첫 가격 틱부터 추적합니다.
     pub fn submit_stop_order(
         &mut self,
         request: &OrderRequest,
         strategy_id: &str,
     ) -> Result<String, String> {
-        let order_id = self.generate_order_id();
-        let stop_price = request.stop_price.ok_or("스톱 주문에 stop_price 필수")?;
-
-        let reserved_amount = match request.side {
-            Side::Buy => {
-                stop_price * request.quantity * (Decimal::ONE + self.fee_rate) * dec!(1.05)
-            } // 5% 버퍼
-            Side::Sell => Decimal::ZERO,
-        };
-
-        let pending = MockPendingOrder {
-            order_id: order_id.clone(),
-            symbol: request.ticker.clone(),
-            side: request.side,
-            order_type: request.order_type,
-            original_quantity: request.quantity,
-            remaining_quantity: request.quantity,
-            price: request.price,
-            stop_price: Some(stop_price),
-            strategy_id: strategy_id.to_string(),
-            reserved_amount,
-            created_at: Utc::now(),
-            stop_triggered: false,
-        };
-
-        self.pending_orders
-            .entry(request.ticker.clone())
-            .or_default()
-            .push(pending);
-        self.order_strategy_map
-            .insert(order_id.clone(), strategy_id.to_string());
-        self.reserved_amounts
-            .insert(order_id.clone(), reserved_amount);
+        if !is_conditional(request.order_type) {
+            return Err(format!("조건부 주문이 아닙니다: {}", request.order_type));
+        }
+        validate_order_request(request)?;
 
-        debug!(
-            "[MockEngine] 스톱 주문 등록: {} {:?} {} @ stop={} (예약금: {})",
-            request.ticker, request.side, request.quantity, stop_price, reserved_amount
+        let order_id = self.generate_order_id();
+        let trigger = OrderTrigger::new(
+            request.order_type,
+            request.side,
+            request.stop_price,
+            request.trailing_percent,
+            None,
+        );
+        self.queue_order(
+            order_id.clone(),
+            request,
+            strategy_id,
+            request.quantity,
+            trigger,
+            None,
         );
 
         Ok(order_id)
@@ -374,7 +547,8 @@ impl MockOrderEngine {
     /// 가격 틱 수신 시 미체결 주문 매칭.
     ///
     /// 매 틱마다 호출되어 미체결 큐의 주문을 검사하고, 체결 가능한 주문을 체결합니다.
-    /// 스톱 주문은 stop_price 도달 시 시장가로 전환 후 체결 시도합니다.
+    /// 조건부 주문은 `ticker.last`로 트리거를 판정하고, 트리거 시점에 유효 기간을 적용합니다.
+    /// 만료된 주문은 [`take_expired_orders`](Self::take_expired_orders)로 회수합니다.
     pub fn on_price_tick(
         &mut self,
         symbol: &str,
@@ -382,133 +556,173 @@ impl MockOrderEngine {
         orderbook: &OrderBook,
     ) -> Vec<MockOrderFill> {
         let mut fills = Vec::new();
-        let fee_rate = self.fee_rate;
-        let slippage_rate = self.slippage_rate;
 
-        let orders = match self.pending_orders.get_mut(symbol) {
+        let orders = match self.pending_orders.remove(symbol) {
             Some(orders) => orders,
             None => return fills,
         };
 
-        let mut to_remove = Vec::new();
-
-        for (idx, order) in orders.iter_mut().enumerate() {
-            // 1. 스톱 주문 트리거 확인
-            if let Some(stop_price) = order.stop_price {
-                if !order.stop_triggered {
-                    let triggered = match order.side {
-                        // 매수 스톱: 현재가가 stop_price 이상
-                        Side::Buy => ticker.last >= stop_price,
-                        // 매도 스톱: 현재가가 stop_price 이하
-                        Side::Sell => ticker.last <= stop_price,
-                    };
-
-                    if triggered {
-                        info!(
-                            "[MockEngine] 스톱 트리거: {} {:?} @ {} (stop={})",
-                            order.symbol, order.side, ticker.last, stop_price
-                        );
-                        order.stop_triggered = true;
-                        // 시장가로 전환하여 아래에서 체결 시도
-                    } else {
+        let mut still_pending = Vec::with_capacity(orders.len());
+
+        for mut order in orders {
+            // 1. GTD 만료
+            if is_expired(order.time_in_force, order.expire_time, ticker.timestamp) {
+                self.expire_order(order);
+                continue;
+            }
+
+            // 2. 조건부 주문 트리거 확인
+            if let Some(trigger) = order.trigger.as_mut() {
+                if !trigger.is_triggered() {
+                    if !trigger.observe(ticker.last) {
+                        still_pending.push(order);
                         continue; // 아직 미트리거
                     }
+                    info!(
+                        "[MockEngine] 트리거: {} {:?} {:?} @ {}",
+                        order.symbol, order.side, order.order_type, ticker.last
+                    );
                 }
             }
 
-            // 2. 체결 시도
-            let should_fill = if order.stop_triggered && order.price.is_none() {
-                // 스톱 시장가: 즉시 체결
-                true
-            } else if let Some(limit_price) = order.price {
-                // 지정가/스톱지정가: 가격 조건 확인
-                match order.side {
-                    Side::Buy => ticker.ask <= limit_price,
-                    Side::Sell => ticker.bid >= limit_price,
+            // 3. 체결 가능 물량 확인
+            let limit_price = if requires_limit_price(order.order_type) {
+                match order.price {
+                    Some(price) => Some(price),
+                    None => {
+                        still_pending.push(order);
+                        continue;
+                    }
                 }
             } else {
-                // 스톱 트리거 후 시장가
-                order.stop_triggered
+                None
             };
 
-            if !should_fill {
-                continue;
-            }
-
-            // 3. VWAP 체결
             let levels = match order.side {
                 Side::Buy => &orderbook.asks,
                 Side::Sell => &orderbook.bids,
             };
-
-            let (fill_price, filled_qty) = Self::calculate_vwap(levels, order.remaining_quantity);
-
-            if filled_qty.is_zero() {
-                continue;
-            }
-
-            // 지정가 주문은 지정가 이하로 체결 (매수 기준)
-            let execution_price = if let Some(limit_price) = order.price {
-                match order.side {
-                    Side::Buy => fill_price.min(limit_price),
-                    Side::Sell => fill_price.max(limit_price),
-                }
+            let quote = match order.side {
+                Side::Buy => ticker.ask,
+                Side::Sell => ticker.bid,
+            };
+            let marketable =
+                limit_price.map_or(true, |limit| is_marketable(order.side, limit, quote));
+            let (_, fillable) = if marketable {
+                Self::calculate_vwap_within(
+                    levels,
+                    order.side,
+                    limit_price,
+                    order.remaining_quantity,
+                )
             } else {
-                // 시장가/스톱 시장가는 슬리피지 적용
-                let slippage = fill_price * slippage_rate;
-                match order.side {
-                    Side::Buy => fill_price + slippage,
-                    Side::Sell => fill_price - slippage,
-                }
+                (Decimal::ZERO, Decimal::ZERO)
             };
 
-            let commission = execution_price * filled_qty * fee_rate;
-            let is_fully_filled = filled_qty >= order.remaining_quantity;
+            let outcome = apply_time_in_force(
+                order.time_in_force,
+                limit_price.is_some(),
+                order.remaining_quantity,
+                fillable,
+            );
+
+            // 4. VWAP 체결
+            if !outcome.fill_quantity.is_zero() {
+                let (vwap, filled_qty) = Self::calculate_vwap_within(
+                    levels,
+                    order.side,
+                    limit_price,
+                    outcome.fill_quantity,
+                );
+                let execution_price = self.execution_price(order.side, vwap, limit_price);
+                let is_fully_filled = filled_qty >= order.remaining_quantity;
 
-            // 예약금 해제 계산
-            let released = if is_fully_filled {
-                order.reserved_amount
-            } else {
-                let fill_ratio = filled_qty / order.original_quantity;
-                order.reserved_amount * fill_ratio
-            };
+                // 예약금 해제 계산 (남은 수량 대비 체결 비율)
+                let released = if is_fully_filled || outcome.expire_remainder {
+                    order.reserved_amount
+                } else {
+                    order.reserved_amount * filled_qty / order.remaining_quantity
+                };
 
-            order.remaining_quantity -= filled_qty;
-            if !is_fully_filled {
+                order.remaining_quantity -= filled_qty;
                 order.reserved_amount -= released;
+                self.reserved_amounts
+                    .insert(order.order_id.clone(), order.reserved_amount);
+
+                fills.push(MockOrderFill {
+                    order_id: order.order_id.clone(),
+                    symbol: order.symbol.clone(),
+                    side: order.side,
+                    filled_quantity: filled_qty,
+                    fill_price: execution_price,
+                    commission: execution_price * filled_qty * self.fee_rate,
+                    timestamp: Utc::now(),
+                    strategy_id: order.strategy_id.clone(),
+                    is_fully_filled,
+                    released_reservation: released,
+                });
             }
 
-            fills.push(MockOrderFill {
-                order_id: order.order_id.clone(),
-                symbol: order.symbol.clone(),
-                side: order.side,
-                filled_quantity: filled_qty,
-                fill_price: execution_price,
-                commission,
-                timestamp: Utc::now(),
-                strategy_id: order.strategy_id.clone(),
-                is_fully_filled,
-                released_reservation: released,
-            });
-
-            if is_fully_filled {
-                to_remove.push(idx);
+            // 5. 잔량 처리
+            if order.remaining_quantity.is_zero() {
+                self.order_strategy_map.remove(&order.order_id);
+                self.reserved_amounts.remove(&order.order_id);
+            } else if outcome.expire_remainder {
+                self.expire_order(order);
+            } else {
+                still_pending.push(order);
             }
         }
 
-        // 체결 완료된 주문 제거 (역순)
-        for idx in to_remove.into_iter().rev() {
-            let removed = orders.remove(idx);
-            self.order_strategy_map.remove(&removed.order_id);
-            self.reserved_amounts.remove(&removed.order_id);
+        if !still_pending.is_empty() {
+            self.pending_orders
+                .insert(symbol.to_string(), still_pending);
         }
 
-        // 빈 심볼 엔트리 정리
-        if orders.is_empty() {
-            self.pending_orders.remove(symbol);
+        fills
+    }
+
+    /// 주문을 만료 처리하고 예약금 해제 대기 목록에 추가.
+    fn expire_order(&mut self, order: MockPendingOrder) {
+        self.order_strategy_map.remove(&order.order_id);
+        self.reserved_amounts.remove(&order.order_id);
+
+        info!(
+            "[MockEngine] 주문 만료: {} (잔량: {}, 예약금 해제: {})",
+            order.order_id, order.remaining_quantity, order.reserved_amount
+        );
+
+        self.expired_orders.push(MockCancelResult {
+            strategy_id: order.strategy_id,
+            released_amount: order.reserved_amount,
+            order_id: order.order_id,
+        });
+    }
+
+    /// 만료 시각이 지난 GTD 주문을 만료 처리.
+    ///
+    /// 가격 틱 없이도 만료를 반영할 때 사용합니다.
+    pub fn expire_orders(&mut self, now: DateTime<Utc>) {
+        let symbols: Vec<String> = self.pending_orders.keys().cloned().collect();
+        for symbol in symbols {
+            let Some(orders) = self.pending_orders.remove(&symbol) else {
+                continue;
+            };
+            let (expired, active): (Vec<_>, Vec<_>) = orders
+                .into_iter()
+                .partition(|o| is_expired(o.time_in_force, o.expire_time, now));
+            for order in expired {
+                self.expire_order(order);
+            }
+            if !active.is_empty() {
+                self.pending_orders.insert(symbol, active);
+            }
         }
+    }
 
-        fills
+    /// 만료된 주문 목록 회수 (호출자가 예약금을 해제해야 함).
+    pub fn take_expired_orders(&mut self) -> Vec<MockCancelResult> {
+        std::mem::take(&mut self.expired_orders)
     }
 
     // ==================== 주문 취소/정정 ====================
@@ -654,6 +868,7 @@ impl MockOrderEngine {
         self.pending_orders.clear();
         self.order_strategy_map.clear();
         self.reserved_amounts.clear();
+        self.expired_orders.clear();
     }
 
     /// 특정 전략의 미체결 주문 초기화.
@@ -685,6 +900,7 @@ impl MockOrderEngine {
     /// DB에서 복원된 미체결 주문을 엔진에 등록.
     ///
     /// `load_state()` 시 사용. 주문 ID 카운터도 함께 업데이트합니다.
+    /// 유효 기간과 트리거 진행 상태는 저장되지 않으므로 GTC, 미트리거 상태로 복원됩니다.
     #[allow(clippy::too_many_arguments)]
     pub fn restore_pending_order(
         &mut self,
@@ -709,6 +925,13 @@ impl MockOrderEngine {
             }
         }
 
+        // 트레일링 스톱은 price 컬럼에 트레일링 폭(%)이 저장됨
+        let (price, trailing_percent) = if order_type == OrderType::TrailingStop {
+            (None, price)
+        } else {
+            (price, None)
+        };
+
         let pending = MockPendingOrder {
             order_id: order_id.clone(),
             symbol: symbol.clone(),
@@ -718,10 +941,13 @@ impl MockOrderEngine {
             remaining_quantity,
             price,
             stop_price,
+            trailing_percent,
+            time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trigger: OrderTrigger::new(order_type, side, stop_price, trailing_percent, None),
             strategy_id: strategy_id.clone(),
             reserved_amount,
             created_at,
-            stop_triggered: false,
         };
 
         self.pending_orders.entry(symbol).or_default().push(pending);
@@ -749,7 +975,11 @@ impl MockOrderEngine {
                 order_type: o.order_type,
                 quantity: o.original_quantity,
                 remaining_quantity: o.remaining_quantity,
-                price: o.price,
+                price: if o.order_type == OrderType::TrailingStop {
+                    o.trailing_percent
+                } else {
+                    o.price
+                },
                 stop_price: o.stop_price,
                 reserved_amount: o.reserved_amount,
                 created_at: o.created_at,
@@ -759,6 +989,25 @@ impl MockOrderEngine {
 
     // ==================== VWAP 계산 ====================
 
+    /// 지정가 이내 호가만 사용해 VWAP 계산 (`limit_price`가 없으면 전체 호가).
+    fn calculate_vwap_within(
+        levels: &[OrderBookLevel],
+        side: Side,
+        limit_price: Option<Decimal>,
+        target_quantity: Decimal,
+    ) -> (Decimal, Decimal) {
+        match limit_price {
+            Some(limit) => {
+                let eligible = levels
+                    .iter()
+                    .take_while(|level| is_marketable(side, limit, level.price))
+                    .count();
+                Self::calculate_vwap(&levels[..eligible], target_quantity)
+            }
+            None => Self::calculate_vwap(levels, target_quantity),
+        }
+    }
+
     /// OrderBook 레벨을 순서대로 소진하며 VWAP 계산.
     ///
     /// `levels`는 매수 시 asks (오름차순), 매도 시 bids (내림차순).
@@ -796,7 +1045,6 @@ impl MockOrderEngine {
 #[cfg(test)]
 mod tests {
     use rust_decimal_macros::dec;
-    use trader_core::TimeInForce;
 
     use super::*;
 
@@ -864,6 +1112,8 @@ mod tests {
             price,
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         }
@@ -999,4 +1249,132 @@ mod tests {
         let fills = engine.on_price_tick("005930", &ticker, &orderbook);
         assert_eq!(fills.len(), 1);
     }
+
+    #[test]
+    fn test_market_fok_requires_full_depth() {
+        let mut engine = MockOrderEngine::new(dec!(0.00015), Decimal::ZERO);
+        let orderbook = create_test_orderbook("005930", dec!(70000));
+        let mut request = create_buy_request("005930", dec!(1000), None);
+        request.time_in_force = TimeInForce::FOK;
+
+        // 호가창 총 잔량(600) 부족 → 체결 없음
+        assert!(engine
+            .submit_market_order(&request, &orderbook, "test_strategy")
+            .is_none());
+
+        request.quantity = dec!(600);
+        let fill = engine
+            .submit_market_order(&request, &orderbook, "test_strategy")
+            .unwrap();
+        assert!(fill.is_fully_filled);
+    }
+
+    #[test]
+    fn test_limit_partial_fill_rests_remainder() {
+        let mut engine = MockOrderEngine::new(dec!(0.00015), Decimal::ZERO);
+        let ticker = create_test_ticker("005930", dec!(70000));
+        let orderbook = create_test_orderbook("005930", dec!(70000));
+        // 지정가 70200 이내 호가: 70100(100) + 70200(200) = 300
+        let request = create_buy_request("005930", dec!(500), Some(dec!(70200)));
+
+        let submission = engine
+            .submit_order(&request, &ticker, Some(&orderbook), "test_strategy")
+            .unwrap();
+        assert_eq!(submission.status, OrderStatusType::PartiallyFilled);
+        let fill = submission.fill.unwrap();
+        assert_eq!(fill.filled_quantity, dec!(300));
+        assert!(fill.fill_price <= dec!(70200));
+
+        let pending = engine.get_pending_orders("test_strategy");
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].filled_quantity, dec!(300));
+        assert_eq!(pending[0].status, OrderStatusType::PartiallyFilled);
+    }
+
+    #[test]
+    fn test_limit_ioc_not_queued() {
+        let mut engine = MockOrderEngine::new(dec!(0.00015), Decimal::ZERO);
+        let ticker = create_test_ticker("005930", dec!(70000));
+        let mut request = create_buy_request("005930", dec!(10), Some(dec!(69500)));
+        request.time_in_force = TimeInForce::IOC;
+
+        let submission = engine
+            .submit_order(&request, &ticker, None, "test_strategy")
+            .unwrap();
+        assert_eq!(submission.status, OrderStatusType::Expired);
+        assert!(submission.fill.is_none());
+        assert!(engine.get_pending_orders("test_strategy").is_empty());
+    }
+
+    #[test]
+    fn test_gtd_expiry_releases_reservation() {
+        let mut engine = MockOrderEngine::new(dec!(0.00015), Decimal::ZERO);
+        let ticker = create_test_ticker("005930", dec!(70000));
+        let expire = ticker.timestamp + chrono::Duration::minutes(5);
+        let request =
+            create_buy_request("005930", dec!(10), Some(dec!(69500))).with_expire_time(expire);
+
+        let submission = engine
+            .submit_order(&request, &ticker, None, "test_strategy")
+            .unwrap();
+        assert_eq!(submission.status, OrderStatusType::Open);
+        let reserved = engine.get_reserved_amount(&submission.order_id);
+
+        engine.expire_orders(expire - chrono::Duration::seconds(1));
+        assert!(engine.take_expired_orders().is_empty());
+
+        engine.expire_orders(expire);
+        let expired = engine.take_expired_orders();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].released_amount, reserved);
+        assert!(engine.get_pending_orders("test_strategy").is_empty());
+    }
+
+    #[test]
+    fn test_take_profit_direction() {
+        let mut engine = MockOrderEngine::new(dec!(0.00015), Decimal::ZERO);
+        let mut request = create_buy_request("005930", dec!(10), None);
+        request.side = Side::Sell;
+        request.order_type = OrderType::TakeProfit;
+        request.stop_price = Some(dec!(72000));
+        engine.submit_stop_order(&request, "test_strategy").unwrap();
+
+        // 하락은 익절 매도 트리거가 아님
+        let ticker = create_test_ticker("005930", dec!(68000));
+        let orderbook = create_test_orderbook("005930", dec!(68000));
+        assert!(engine
+            .on_price_tick("005930", &ticker, &orderbook)
+            .is_empty());
+
+        let ticker = create_test_ticker("005930", dec!(72100));
+        let orderbook = create_test_orderbook("005930", dec!(72100));
+        assert_eq!(engine.on_price_tick("005930", &ticker, &orderbook).len(), 1);
+    }
+
+    #[test]
+    fn test_trailing_stop_on_ticks() {
+        let mut engine = MockOrderEngine::new(dec!(0.00015), Decimal::ZERO);
+        let ticker = create_test_ticker("005930", dec!(70000));
+        let request =
+            OrderRequest::trailing_stop("005930".to_string(), Side::Sell, dec!(10), dec!(5), None);
+        let submission = engine
+            .submit_order(&request, &ticker, None, "test_strategy")
+            .unwrap();
+        assert_eq!(submission.status, OrderStatusType::Open);
+
+        // 80000까지 상승 → 트리거 가격 76000
+        for price in [dec!(75000), dec!(80000), dec!(77000)] {
+            let ticker = create_test_ticker("005930", price);
+            let orderbook = create_test_orderbook("005930", price);
+            assert!(engine
+                .on_price_tick("005930", &ticker, &orderbook)
+                .is_empty());
+        }
+
+        let ticker = create_test_ticker("005930", dec!(75900));
+        let orderbook = create_test_orderbook("005930", dec!(75900));
+        let fills = engine.on_price_tick("005930", &ticker, &orderbook);
+        assert_eq!(fills.len(), 1);
+        assert!(fills[0].is_fully_filled);
+    }
 }
@@ -8,25 +8,34 @@
 //! - [`KisExchangeProvider`]: KIS 국내/해외/ISA 계좌 통합 Provider
 //! - [`BinanceProvider`]: Binance 거래소 Provider
 //! - [`MockExchangeProvider`]: 테스트/시뮬레이션용 Mock Provider
+//! - [`FixExchangeProvider`]: FIX 4.4 주문 게이트웨이 (주문 전용)
+//! - [`FailoverMarketDataProvider`]: 주/보조 시세 소스 자동 장애 조치
+//! - [`NaverQuoteProvider`]: 네이버 금융 지연 시세 (보조 소스용)
 
 mod binance;
 mod bithumb;
 mod db_investment;
+mod failover;
+mod fix;
 mod kis;
 mod ls_sec;
 mod mock;
 pub mod mock_order_engine;
 pub mod mock_streaming;
+mod naver;
 mod upbit;
 
 pub use binance::{BinanceExchangeProvider, BinanceProvider};
 pub use bithumb::{BithumbExchangeProvider, BithumbProvider};
 pub use db_investment::{DbInvestmentExchangeProvider, DbInvestmentProvider};
+pub use failover::FailoverMarketDataProvider;
+pub use fix::FixExchangeProvider;
 pub use kis::{KisExchangeProvider, KisProvider};
 pub use ls_sec::{LsSecExchangeProvider, LsSecProvider};
-pub use mock::{MockConfig, MockExchangeProvider, MockMarketStream};
+pub use mock::{MockConfig, MockExchangeProvider, MockMarketStream, MockStrategyProcessor};
 pub use mock_order_engine::{MockOrderEngine, RawPendingOrder};
 pub use mock_streaming::{
     MockOrderBookGenerator, MockPriceGenerator, MockPriceMode, MockStreamingConfig,
 };
+pub use naver::NaverQuoteProvider;
 pub use upbit::{UpbitExchangeProvider, UpbitProvider};
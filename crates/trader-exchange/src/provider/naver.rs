@@ -0,0 +1,72 @@
+//! 네이버 금융 지연 시세 MarketDataProvider 구현.
+//!
+//! 인증이 필요 없는 네이버 금융 페이지에서 국내 주식 현재가를 조회합니다.
+//! 실시간이 아닌 지연 시세이므로 증권사 API 장애 시 최후의 보조 소스로만 사용합니다.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use trader_core::domain::{MarketDataProvider, ProviderError, QuoteData};
+use trader_data::provider::naver::{NaverError, NaverFinanceFetcher};
+
+/// 네이버 금융 지연 시세 제공자.
+pub struct NaverQuoteProvider {
+    fetcher: NaverFinanceFetcher,
+}
+
+impl Default for NaverQuoteProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NaverQuoteProvider {
+    /// 새 제공자 생성.
+    pub fn new() -> Self {
+        Self {
+            fetcher: NaverFinanceFetcher::new(),
+        }
+    }
+}
+
+fn to_provider_error(error: NaverError) -> ProviderError {
+    match error {
+        NaverError::HttpError(e) => ProviderError::Network(e.to_string()),
+        NaverError::ParseError(msg) => ProviderError::Parse(msg),
+        NaverError::NoData { ticker } => ProviderError::Api(format!("시세 없음: {}", ticker)),
+        NaverError::RateLimited => ProviderError::Api("Rate limit 초과".to_string()),
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for NaverQuoteProvider {
+    async fn get_quote(&self, symbol: &str) -> Result<QuoteData, ProviderError> {
+        let data = self
+            .fetcher
+            .fetch_fundamental(symbol)
+            .await
+            .map_err(to_provider_error)?;
+
+        let current_price = data
+            .current_price
+            .ok_or_else(|| ProviderError::Parse(format!("현재가 없음: {}", symbol)))?;
+
+        Ok(QuoteData {
+            symbol: symbol.to_string(),
+            current_price,
+            price_change: Decimal::ZERO,
+            change_percent: Decimal::ZERO,
+            high: current_price,
+            low: current_price,
+            open: current_price,
+            prev_close: current_price,
+            volume: Decimal::from(data.volume.unwrap_or(0)),
+            trading_value: Decimal::ZERO,
+            timestamp: Utc::now(),
+        })
+    }
+
+    fn provider_name(&self) -> &str {
+        "naver"
+    }
+}
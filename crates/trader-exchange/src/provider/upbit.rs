@@ -11,8 +11,9 @@ use trader_core::{
     cache::ExchangeCache,
     domain::{
         ExchangeProvider, ExecutionHistoryRequest, ExecutionHistoryResponse, MarketDataProvider,
-        OrderExecutionProvider, OrderRequest, OrderResponse, OrderType, PendingOrder,
-        ProviderError, QuoteData, Side, StrategyAccountInfo, StrategyPositionInfo,
+        OrderBook, OrderExecutionProvider, OrderRequest, OrderResponse, OrderStatus, OrderType,
+        PendingOrder, ProviderError, QuoteData, Side, StrategyAccountInfo, StrategyPositionInfo,
+        TimeInForce,
     },
 };
 
@@ -177,6 +178,10 @@ impl MarketDataProvider for UpbitExchangeProvider {
         self.client.get_quotes(symbols).await
     }
 
+    async fn get_order_book(&self, symbol: &str) -> Result<OrderBook, ProviderError> {
+        self.client.get_order_book(symbol).await
+    }
+
     fn provider_name(&self) -> &str {
         "upbit"
     }
@@ -231,12 +236,20 @@ impl OrderExecutionProvider for UpbitExchangeProvider {
             None
         };
 
+        // 지정가 주문만 IOC/FOK 조건 지원
+        let time_in_force = match (ord_type, request.time_in_force) {
+            ("limit", TimeInForce::IOC) => Some("ioc"),
+            ("limit", TimeInForce::FOK) => Some("fok"),
+            _ => None,
+        };
+
         info!(
             ticker = %request.ticker,
             side = side,
             ord_type = ord_type,
             volume = ?volume_str,
             price = ?price_str,
+            time_in_force = ?time_in_force,
             "Upbit 주문 생성"
         );
 
@@ -248,6 +261,7 @@ impl OrderExecutionProvider for UpbitExchangeProvider {
                 ord_type,
                 volume_str.as_deref(),
                 price_str.as_deref(),
+                time_in_force,
             )
             .await?;
 
@@ -305,6 +319,7 @@ impl OrderExecutionProvider for UpbitExchangeProvider {
                 &original.ord_type,
                 volume.as_deref(),
                 new_price.as_deref(),
+                None,
             )
             .await?;
 
@@ -317,6 +332,14 @@ impl OrderExecutionProvider for UpbitExchangeProvider {
         })
     }
 
+    async fn get_order_status(
+        &self,
+        order_id: &str,
+        _ticker: &str,
+    ) -> Result<OrderStatus, ProviderError> {
+        Ok(self.client.get_order(order_id).await?.to_order_status())
+    }
+
     fn exchange_name(&self) -> &str {
         "upbit"
     }
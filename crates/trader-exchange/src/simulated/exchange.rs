@@ -10,13 +10,14 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 use trader_core::{
-    Kline, OrderBook, OrderBookLevel, OrderRequest, OrderStatus, OrderStatusType, OrderType,
-    Position, Side, Symbol, Ticker, Timeframe, TradeTick,
+    Kline, OrderBook, OrderBookLevel, OrderRequest, OrderStatus, OrderStatusType, Position, Side,
+    Symbol, Ticker, Timeframe, TradeTick,
 };
 
 use super::{
     data_feed::{DataFeed, DataFeedConfig},
     matching_engine::{FillType, MatchingEngine, OrderMatch},
+    order_rules::validate_order_request,
     stream::{EventBroadcaster, SimulatedMarketStream, SimulatedUserStream},
 };
 use crate::{
@@ -33,6 +34,9 @@ pub struct SimulatedConfig {
     pub fee_rate: Decimal,
     /// 시장가 주문의 슬리피지율
     pub slippage_rate: Decimal,
+    /// 봉 거래량 대비 최대 체결 비율 (None이면 무제한)
+    #[serde(default)]
+    pub volume_participation: Option<Decimal>,
     /// 포지션 추적 활성화 여부
     pub enable_positions: bool,
     /// 데이터 피드 설정
@@ -48,6 +52,7 @@ impl Default for SimulatedConfig {
             initial_balances,
             fee_rate: dec!(0.001),       // 0.1%
             slippage_rate: dec!(0.0005), // 0.05%
+            volume_participation: None,
             enable_positions: false,
             data_feed_config: DataFeedConfig::default(),
         }
@@ -72,6 +77,12 @@ impl SimulatedConfig {
         self.slippage_rate = rate;
         self
     }
+
+    /// 봉 거래량 참여율을 설정합니다 (부분 체결 시뮬레이션).
+    pub fn with_volume_participation(mut self, rate: Decimal) -> Self {
+        self.volume_participation = Some(rate);
+        self
+    }
 }
 
 /// 내부 계정 상태.
@@ -141,6 +152,12 @@ struct OrderState {
     filled_quantity: Decimal,
     /// 평균 체결 가격
     average_price: Option<Decimal>,
+    /// 잠금 자산 (매수: quote, 매도: base)
+    lock_asset: String,
+    /// 남은 잠금 수량
+    locked: Decimal,
+    /// 체결 수량 1단위당 잠금 해제량
+    unit_lock: Decimal,
     /// 생성 시각
     created_at: DateTime<Utc>,
     /// 갱신 시각
@@ -190,6 +207,11 @@ impl SimulatedExchange {
         ticker.split('/').nth(1).unwrap_or("USDT").to_string()
     }
 
+    /// ticker String에서 base 자산을 추출합니다 (예: "BTC/USDT" -> "BTC").
+    fn parse_base(ticker: &str) -> String {
+        ticker.split('/').next().unwrap_or(ticker).to_string()
+    }
+
     /// ticker String을 Symbol로 변환합니다 (시뮬레이션용).
     fn ticker_to_symbol(ticker: &str) -> Symbol {
         let parts: Vec<&str> = ticker.split('/').collect();
@@ -204,7 +226,10 @@ impl SimulatedExchange {
     pub fn new(config: SimulatedConfig) -> Self {
         let account = AccountState::new(&config.initial_balances);
         let data_feed = DataFeed::new(config.data_feed_config.clone());
-        let matching_engine = MatchingEngine::new(config.fee_rate, config.slippage_rate);
+        let mut matching_engine = MatchingEngine::new(config.fee_rate, config.slippage_rate);
+        if let Some(rate) = config.volume_participation {
+            matching_engine = matching_engine.with_volume_participation(rate);
+        }
 
         Self {
             config,
@@ -357,90 +382,75 @@ impl SimulatedExchange {
     }
 
     /// 주문 매칭 결과를 계정에 적용합니다.
+    ///
+    /// 체결분만큼 잠금을 해제한 뒤 잔고를 정산하고, 주문이 종료(체결/만료)되면
+    /// 남은 잠금을 모두 해제합니다.
     async fn apply_order_match(&self, order_match: &OrderMatch) {
+        let mut orders = self.orders.write().await;
         let mut account = self.account.write().await;
 
-        // 주문 상세 정보 가져오기
-        let order_state = {
-            let orders = self.orders.read().await;
-            orders.get(&order_match.order_id).cloned()
+        let Some(state) = orders.get_mut(&order_match.order_id) else {
+            return;
         };
+        let request = state.request.clone();
+        let base = Self::parse_base(&request.ticker);
+        let quote = Self::parse_quote(&request.ticker);
+        let filled = order_match.filled_quantity;
+
+        // 잠금 해제
+        let release = if order_match.status.is_final() {
+            state.locked
+        } else {
+            (filled * state.unit_lock).min(state.locked)
+        };
+        state.locked -= release;
+        account.update_balance(&state.lock_asset, release, -release);
 
-        if let Some(order_state) = order_state {
-            let request = &order_state.request;
-            let ticker = &request.ticker;
-
-            // ticker String을 Symbol로 변환 (예: "BTC/USDT" -> Symbol)
-            // TODO: SymbolResolver를 통해 Symbol을 가져와야 함
-            let parts: Vec<&str> = ticker.split('/').collect();
-            let (base, quote) = if parts.len() == 2 {
-                (parts[0], parts[1])
-            } else {
-                (ticker.as_str(), "USDT") // 기본값
-            };
-
+        if !filled.is_zero() {
             match request.side {
                 Side::Buy => {
                     // 견적 통화 차감, 기준 통화 추가
-                    let total_cost = order_match.filled_quantity * order_match.fill_price
-                        + order_match.commission;
-                    account.update_balance(quote, -total_cost, dec!(0));
-                    account.update_balance(base, order_match.filled_quantity, dec!(0));
+                    let total_cost = filled * order_match.fill_price + order_match.commission;
+                    account.update_balance(&quote, -total_cost, dec!(0));
+                    account.update_balance(&base, filled, dec!(0));
                 }
                 Side::Sell => {
                     // 기준 통화 차감, 견적 통화 추가
-                    let total_received = order_match.filled_quantity * order_match.fill_price
-                        - order_match.commission;
-                    account.update_balance(base, -order_match.filled_quantity, dec!(0));
-                    account.update_balance(quote, total_received, dec!(0));
+                    let total_received = filled * order_match.fill_price - order_match.commission;
+                    account.update_balance(&base, -filled, dec!(0));
+                    account.update_balance(&quote, total_received, dec!(0));
                 }
             }
 
-            // 주문 상태 업데이트
-            {
-                let mut orders = self.orders.write().await;
-                if let Some(state) = orders.get_mut(&order_match.order_id) {
-                    state.status_type = if order_match.fill_type == FillType::Full {
-                        OrderStatusType::Filled
-                    } else {
-                        OrderStatusType::PartiallyFilled
-                    };
-                    state.filled_quantity = order_match.filled_quantity;
-                    state.average_price = Some(order_match.fill_price);
-                    state.updated_at = order_match.timestamp;
-                }
-            }
+            // 가중 평균 체결가 갱신
+            let previous = state.filled_quantity;
+            let total = previous + filled;
+            let average =
+                state.average_price.unwrap_or(dec!(0)) * previous + order_match.fill_price * filled;
+            state.average_price = Some(average / total);
+            state.filled_quantity = total;
 
-            // 사용자 이벤트 브로드캐스트 - 주문 업데이트
-            let updated_status = OrderStatus {
-                order_id: order_match.order_id.clone(),
-                client_order_id: request.client_order_id.clone(),
-                ticker: Some(request.ticker.clone()),
-                side: Some(request.side),
-                quantity: Some(request.quantity),
-                price: request.price, // 이미 Option<Price>
-                status: if order_match.fill_type == FillType::Full {
-                    OrderStatusType::Filled
-                } else {
-                    OrderStatusType::PartiallyFilled
-                },
-                filled_quantity: order_match.filled_quantity,
-                average_price: Some(order_match.fill_price),
-                updated_at: order_match.timestamp,
-            };
-            self.user_broadcaster
-                .broadcast(UserEvent::OrderUpdate(updated_status))
-                .await;
-
-            // 잔고 업데이트 브로드캐스트
-            let quote_balance = account.get_balance(quote);
-            self.user_broadcaster
-                .broadcast(UserEvent::BalanceUpdate(quote_balance))
-                .await;
+            // 주문 이력에 추가
+            account.order_history.push(order_match.clone());
         }
 
-        // 주문 이력에 추가
-        account.order_history.push(order_match.clone());
+        state.status_type = order_match.status;
+        state.updated_at = order_match.timestamp;
+
+        // 사용자 이벤트 브로드캐스트 - 주문 업데이트
+        let updated_status = state.to_order_status();
+        let quote_balance = account.get_balance(&quote);
+        drop(account);
+        drop(orders);
+
+        self.user_broadcaster
+            .broadcast(UserEvent::OrderUpdate(updated_status))
+            .await;
+
+        // 잔고 업데이트 브로드캐스트
+        self.user_broadcaster
+            .broadcast(UserEvent::BalanceUpdate(quote_balance))
+            .await;
     }
 
     /// Kline을 티커로 변환합니다.
@@ -478,28 +488,7 @@ impl SimulatedExchange {
             ));
         }
 
-        match request.order_type {
-            OrderType::Limit => {
-                if request.price.is_none() {
-                    return Err(ExchangeError::OrderRejected(
-                        "Limit order requires price".into(),
-                    ));
-                }
-            }
-            OrderType::StopLoss
-            | OrderType::StopLossLimit
-            | OrderType::TakeProfit
-            | OrderType::TakeProfitLimit => {
-                if request.stop_price.is_none() {
-                    return Err(ExchangeError::OrderRejected(
-                        "Stop order requires stop price".into(),
-                    ));
-                }
-            }
-            _ => {}
-        }
-
-        Ok(())
+        validate_order_request(request).map_err(ExchangeError::OrderRejected)
     }
 }
 
@@ -647,116 +636,63 @@ impl SimulatedExchange {
         // 주문 검증
         self.validate_order(request, current_price)?;
 
-        // 잔고 확인
-        {
-            let account = self.account.read().await;
-            match request.side {
-                Side::Buy => {
-                    let required = request.quantity * request.price.unwrap_or(current_price);
-                    let balance = account.get_balance(&Self::parse_quote(&request.ticker));
-                    if balance.free < required {
-                        return Err(ExchangeError::InsufficientBalance(format!(
-                            "Need {} {}, have {}",
-                            required,
-                            Self::parse_quote(&request.ticker),
-                            balance.free
-                        )));
-                    }
-                }
-                Side::Sell => {
-                    let balance = account.get_balance(&request.ticker);
-                    if balance.free < request.quantity {
-                        return Err(ExchangeError::InsufficientBalance(format!(
-                            "Need {} {}, have {}",
-                            request.quantity, request.ticker, balance.free
-                        )));
-                    }
-                }
-            }
-        }
+        // 잠금 자산과 수량 (매수: quote × 지정가/현재가, 매도: base 수량)
+        let (lock_asset, unit_lock) = match request.side {
+            Side::Buy => (
+                Self::parse_quote(&request.ticker),
+                request.price.unwrap_or(current_price),
+            ),
+            Side::Sell => (Self::parse_base(&request.ticker), dec!(1)),
+        };
+        let required = request.quantity * unit_lock;
 
-        // 잔고 잠금
+        // 잔고 확인 후 잠금
         {
             let mut account = self.account.write().await;
-            match request.side {
-                Side::Buy => {
-                    let required = request.quantity * request.price.unwrap_or(current_price);
-                    account.update_balance(
-                        &Self::parse_quote(&request.ticker),
-                        -required,
-                        required,
-                    );
-                }
-                Side::Sell => {
-                    account.update_balance(&request.ticker, -request.quantity, request.quantity);
-                }
+            let balance = account.get_balance(&lock_asset);
+            if balance.free < required {
+                return Err(ExchangeError::InsufficientBalance(format!(
+                    "Need {} {}, have {}",
+                    required, lock_asset, balance.free
+                )));
             }
+            account.update_balance(&lock_asset, -required, required);
         }
 
         // 매칭 엔진에 제출
+        let timestamp = {
+            let feed = self.data_feed.read().await;
+            feed.current_time().unwrap_or_else(Utc::now)
+        };
         let order_match = {
             let mut engine = self.matching_engine.write().await;
-            let timestamp = {
-                let feed = self.data_feed.read().await;
-                feed.current_time().unwrap_or_else(Utc::now)
-            };
             engine.submit_order(request, current_price, timestamp)
         };
 
         let order_id = order_match.order_id.clone();
 
-        // 초기 상태 결정
-        let initial_status = match order_match.fill_type {
-            FillType::Full => OrderStatusType::Filled,
-            FillType::Partial => OrderStatusType::PartiallyFilled,
-            FillType::None => OrderStatusType::Open,
-        };
-
-        // 주문 상태 생성
-        let order_state = OrderState {
-            request: request.clone(),
-            order_id: order_id.clone(),
-            status_type: initial_status,
-            filled_quantity: order_match.filled_quantity,
-            average_price: if order_match.fill_type != FillType::None {
-                Some(order_match.fill_price)
-            } else {
-                None
-            },
-            created_at: order_match.timestamp,
-            updated_at: order_match.timestamp,
-        };
-
-        // 주문 상태 저장
+        // 주문 상태 저장 (접수 상태로 시작, 매칭 결과는 아래에서 적용)
         {
             let mut orders = self.orders.write().await;
-            orders.insert(order_id.clone(), order_state);
+            orders.insert(
+                order_id.clone(),
+                OrderState {
+                    request: request.clone(),
+                    order_id: order_id.clone(),
+                    status_type: OrderStatusType::Open,
+                    filled_quantity: dec!(0),
+                    average_price: None,
+                    lock_asset,
+                    locked: required,
+                    unit_lock,
+                    created_at: order_match.timestamp,
+                    updated_at: order_match.timestamp,
+                },
+            );
         }
 
-        // 즉시 체결된 경우, 매칭 적용
-        if order_match.fill_type != FillType::None {
-            // 먼저 잔고 잠금 해제 (위에서 잠갔으므로)
-            {
-                let mut account = self.account.write().await;
-                match request.side {
-                    Side::Buy => {
-                        let required = request.quantity * request.price.unwrap_or(current_price);
-                        account.update_balance(
-                            &Self::parse_quote(&request.ticker),
-                            required,
-                            -required,
-                        );
-                    }
-                    Side::Sell => {
-                        account.update_balance(
-                            &request.ticker,
-                            request.quantity,
-                            -request.quantity,
-                        );
-                    }
-                }
-            }
-
+        // 즉시 체결/만료된 경우 매칭 적용
+        if order_match.fill_type != FillType::None || order_match.status.is_final() {
             self.apply_order_match(&order_match).await;
         }
 
@@ -774,34 +710,15 @@ impl SimulatedExchange {
             return Err(ExchangeError::OrderNotFound(order_id.to_string()));
         }
 
-        // 주문 상태 업데이트
-        {
-            let mut orders = self.orders.write().await;
-            if let Some(state) = orders.get_mut(order_id) {
-                state.status_type = OrderStatusType::Cancelled;
-                state.updated_at = Utc::now();
-
-                // 잔고 잠금 해제
-                let mut account = self.account.write().await;
-                let request = &state.request;
-                match request.side {
-                    Side::Buy => {
-                        let locked = request.quantity * request.price.unwrap_or(dec!(0));
-                        account.update_balance(
-                            &Self::parse_quote(&request.ticker),
-                            locked,
-                            -locked,
-                        );
-                    }
-                    Side::Sell => {
-                        account.update_balance(
-                            &request.ticker,
-                            request.quantity,
-                            -request.quantity,
-                        );
-                    }
-                }
-            }
+        // 주문 상태 업데이트 및 남은 잠금 해제
+        let mut orders = self.orders.write().await;
+        if let Some(state) = orders.get_mut(order_id) {
+            state.status_type = OrderStatusType::Cancelled;
+            state.updated_at = Utc::now();
+
+            let mut account = self.account.write().await;
+            account.update_balance(&state.lock_asset, state.locked, -state.locked);
+            state.locked = dec!(0);
         }
 
         Ok(())
@@ -842,7 +759,7 @@ impl SimulatedExchange {
 
 #[cfg(test)]
 mod tests {
-    use trader_core::TimeInForce;
+    use trader_core::{OrderType, TimeInForce};
 
     use super::*;
     use crate::simulated::data_feed::generate_sample_klines;
@@ -930,6 +847,8 @@ mod tests {
             price: None,
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         };
@@ -976,6 +895,8 @@ mod tests {
             price: Some(dec!(40000)), // 현재 ~50000보다 낮음
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         };
@@ -1020,6 +941,8 @@ mod tests {
             price: Some(dec!(40000)),
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         };
@@ -1034,4 +957,96 @@ mod tests {
         let balance = exchange.get_balance("USDT").await.unwrap();
         assert_eq!(balance.free, dec!(100000));
     }
+
+    /// 가격이 고정된 1분봉 (지정 거래량).
+    fn flat_klines(ticker: &str, count: i64, price: Decimal, volume: Decimal) -> Vec<Kline> {
+        let start = Utc::now() - chrono::Duration::minutes(count);
+        (0..count)
+            .map(|i| {
+                let open_time = start + chrono::Duration::minutes(i);
+                Kline {
+                    ticker: ticker.to_string(),
+                    timeframe: Timeframe::M1,
+                    open_time,
+                    close_time: open_time + chrono::Duration::minutes(1),
+                    open: price,
+                    high: price,
+                    low: price,
+                    close: price,
+                    volume,
+                    quote_volume: None,
+                    num_trades: None,
+                }
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_market_order_partial_fill_against_volume() {
+        let config = SimulatedConfig::default()
+            .with_initial_balance("USDT", dec!(100000))
+            .with_fee_rate(dec!(0))
+            .with_volume_participation(dec!(0.1));
+
+        let exchange = SimulatedExchange::new(config);
+        let ticker = create_test_symbol().to_string();
+        exchange
+            .load_klines(
+                ticker.clone(),
+                Timeframe::M1,
+                flat_klines(&ticker, 3, dec!(50000), dec!(1)),
+            )
+            .await;
+        exchange.step(&ticker, Timeframe::M1).await;
+
+        // 봉당 체결 가능 수량 0.1 → 0.3 중 0.1만 체결되고 잔량은 만료
+        let request = OrderRequest::market_buy(ticker.clone(), dec!(0.3));
+        let order_id = exchange.place_order(&request).await.unwrap();
+        let status = exchange.get_order(&ticker, &order_id).await.unwrap();
+
+        assert_eq!(status.status, OrderStatusType::Expired);
+        assert_eq!(status.filled_quantity, dec!(0.1));
+
+        let btc = exchange.get_balance("BTC").await.unwrap();
+        assert_eq!(btc.free, dec!(0.1));
+        let usdt = exchange.get_balance("USDT").await.unwrap();
+        assert_eq!(usdt.locked, dec!(0));
+        assert!(usdt.free < dec!(100000));
+    }
+
+    #[tokio::test]
+    async fn test_gtd_limit_order_expires_and_releases_lock() {
+        let config = SimulatedConfig::default().with_initial_balance("USDT", dec!(100000));
+
+        let exchange = SimulatedExchange::new(config);
+        let ticker = create_test_symbol().to_string();
+        let klines = flat_klines(&ticker, 3, dec!(50000), dec!(10));
+        let expire_time = klines[1].close_time;
+        exchange
+            .load_klines(ticker.clone(), Timeframe::M1, klines)
+            .await;
+        exchange.step(&ticker, Timeframe::M1).await;
+
+        let request = OrderRequest::limit_buy(ticker.clone(), dec!(0.1), dec!(40000))
+            .with_expire_time(expire_time);
+        let order_id = exchange.place_order(&request).await.unwrap();
+        assert_eq!(
+            exchange.get_balance("USDT").await.unwrap().locked,
+            dec!(4000)
+        );
+
+        // 만료 시각이 포함된 봉이 끝나면 만료되고 잠금 해제
+        exchange.step(&ticker, Timeframe::M1).await;
+        let status = exchange.get_order(&ticker, &order_id).await.unwrap();
+        assert_eq!(status.status, OrderStatusType::Expired);
+
+        let usdt = exchange.get_balance("USDT").await.unwrap();
+        assert_eq!(usdt.free, dec!(100000));
+        assert_eq!(usdt.locked, dec!(0));
+        assert!(exchange
+            .get_open_orders(Some(&ticker))
+            .await
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -1,11 +1,21 @@
 //! 시뮬레이션 거래소를 위한 주문 매칭 엔진.
+//!
+//! 주문 유형과 유효 기간의 의미는 [`order_rules`](super::order_rules)를 따르며,
+//! Kline 거래량 참여율이 설정되면 봉마다 체결 가능한 수량이 제한되어 부분 체결이 발생합니다.
 
 use std::{collections::HashMap, sync::Arc};
 
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
-use trader_core::{Kline, OrderRequest, OrderType, RoundMethod, Side, TickSizeProvider};
+use trader_core::{
+    Kline, OrderRequest, OrderStatusType, OrderType, RoundMethod, Side, TickSizeProvider,
+    TimeInForce,
+};
+
+use super::order_rules::{
+    apply_time_in_force, bar_path, is_expired, is_marketable, requires_limit_price, OrderTrigger,
+};
 
 /// 주문 체결 유형.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -25,7 +35,7 @@ pub struct OrderMatch {
     pub order_id: String,
     /// 체결 유형
     pub fill_type: FillType,
-    /// 체결 수량
+    /// 체결 수량 (이번 매칭분)
     pub filled_quantity: Decimal,
     /// 평균 체결 가격
     pub fill_price: Decimal,
@@ -35,6 +45,10 @@ pub struct OrderMatch {
     pub commission_asset: String,
     /// 체결 타임스탬프
     pub timestamp: DateTime<Utc>,
+    /// 매칭 이후 잔여 수량
+    pub remaining_quantity: Decimal,
+    /// 매칭 이후 주문 상태
+    pub status: OrderStatusType,
 }
 
 /// 매칭 엔진의 대기 주문.
@@ -54,12 +68,25 @@ pub struct PendingOrder {
     pub remaining_quantity: Decimal,
     /// 지정가 (지정가 주문용)
     pub price: Option<Decimal>,
-    /// 스탑 가격 (스탑 주문용)
+    /// 스탑 가격 (스탑 주문용, 트레일링 스톱은 활성화 가격)
     pub stop_price: Option<Decimal>,
+    /// 주문 유효 기간
+    pub time_in_force: TimeInForce,
+    /// 만료 시각 (GTD)
+    pub expire_time: Option<DateTime<Utc>>,
+    /// 조건부 주문 트리거 상태
+    pub trigger: Option<OrderTrigger>,
     /// 생성 타임스탬프
     pub created_at: DateTime<Utc>,
 }
 
+/// 한 봉에서의 매칭 결과 (내부용).
+struct BarExecution {
+    filled: Decimal,
+    price: Decimal,
+    expire_remainder: bool,
+}
+
 /// 시뮬레이션 거래소를 위한 주문 매칭 엔진.
 pub struct MatchingEngine {
     /// 심볼별 대기 주문
@@ -70,6 +97,10 @@ pub struct MatchingEngine {
     slippage_rate: Decimal,
     /// 호가 단위 제공자 (옵션)
     tick_size_provider: Option<Arc<dyn TickSizeProvider>>,
+    /// 봉 거래량 대비 최대 체결 비율 (None이면 무제한)
+    volume_participation: Option<Decimal>,
+    /// 심볼별 현재 봉의 남은 체결 가능 수량
+    bar_liquidity: HashMap<String, Decimal>,
     /// 주문 ID 카운터
     next_order_id: u64,
 }
@@ -82,6 +113,8 @@ impl MatchingEngine {
             fee_rate,
             slippage_rate,
             tick_size_provider: None,
+            volume_participation: None,
+            bar_liquidity: HashMap::new(),
             next_order_id: 1,
         }
     }
@@ -97,6 +130,15 @@ impl MatchingEngine {
         self
     }
 
+    /// 봉 거래량 참여율을 설정합니다 (예: 10%의 경우 0.1).
+    ///
+    /// 한 봉에서 체결되는 수량의 합이 `거래량 × 참여율`을 넘지 않으며,
+    /// 초과분은 부분 체결로 남습니다. 봉 사이에 제출된 주문은 직전 봉의 남은 수량을 사용합니다.
+    pub fn with_volume_participation(mut self, rate: Decimal) -> Self {
+        self.volume_participation = Some(rate);
+        self
+    }
+
     /// 가격을 호가 단위로 라운딩합니다.
     fn round_price(&self, price: Decimal, method: RoundMethod) -> Decimal {
         if let Some(provider) = &self.tick_size_provider {
@@ -106,6 +148,86 @@ impl MatchingEngine {
         }
     }
 
+    /// 지정가를 호가 단위로 라운딩합니다 (매수는 내림, 매도는 올림).
+    fn round_limit_price(&self, side: Side, price: Decimal) -> Decimal {
+        match side {
+            Side::Buy => self.round_price(price, RoundMethod::Floor),
+            Side::Sell => self.round_price(price, RoundMethod::Ceil),
+        }
+    }
+
+    /// 기준 가격에 슬리피지를 적용하고 호가 단위로 라운딩합니다 (매수는 올림, 매도는 내림).
+    fn market_fill_price(&self, side: Side, reference: Decimal) -> Decimal {
+        let slippage = reference * self.slippage_rate;
+        match side {
+            Side::Buy => self.round_price(reference + slippage, RoundMethod::Ceil),
+            Side::Sell => self.round_price(reference - slippage, RoundMethod::Floor),
+        }
+    }
+
+    /// 현재 봉에서 체결 가능한 수량.
+    fn available_liquidity(&self, symbol: &str, wanted: Decimal) -> Decimal {
+        match self.bar_liquidity.get(symbol) {
+            Some(left) => wanted.min(*left),
+            None => wanted,
+        }
+    }
+
+    /// 체결 수량만큼 현재 봉의 유동성을 차감합니다.
+    fn consume_liquidity(&mut self, symbol: &str, quantity: Decimal) {
+        if let Some(left) = self.bar_liquidity.get_mut(symbol) {
+            *left = (*left - quantity).max(Decimal::ZERO);
+        }
+    }
+
+    /// 매칭 결과를 생성합니다.
+    #[allow(clippy::too_many_arguments)]
+    fn build_match(
+        &self,
+        order_id: &str,
+        symbol: &str,
+        original: Decimal,
+        remaining: Decimal,
+        filled: Decimal,
+        fill_price: Decimal,
+        expired: bool,
+        timestamp: DateTime<Utc>,
+    ) -> OrderMatch {
+        let fill_type = if filled.is_zero() {
+            FillType::None
+        } else if remaining.is_zero() {
+            FillType::Full
+        } else {
+            FillType::Partial
+        };
+
+        let status = if remaining.is_zero() {
+            OrderStatusType::Filled
+        } else if expired {
+            OrderStatusType::Expired
+        } else if remaining < original {
+            OrderStatusType::PartiallyFilled
+        } else {
+            OrderStatusType::Open
+        };
+
+        OrderMatch {
+            order_id: order_id.to_string(),
+            fill_type,
+            filled_quantity: filled,
+            fill_price: if filled.is_zero() {
+                dec!(0)
+            } else {
+                fill_price
+            },
+            commission: filled * fill_price * self.fee_rate,
+            commission_asset: Self::parse_quote(symbol),
+            timestamp,
+            remaining_quantity: remaining,
+            status,
+        }
+    }
+
     /// 다음 주문 ID를 생성합니다.
     pub fn generate_order_id(&mut self) -> String {
         let id = self.next_order_id;
@@ -114,7 +236,11 @@ impl MatchingEngine {
     }
 
     /// 새로운 주문을 제출합니다.
-    /// 시장가 주문의 경우 즉시 매칭 결과를 반환합니다.
+    ///
+    /// 시장가/지정가 주문은 제출 시점에 유효 기간 규칙을 적용해 즉시 매칭하고,
+    /// 조건부 주문(스톱/익절/트레일링)은 트리거될 때까지 대기합니다.
+    /// 요청 검증은 호출자가 [`validate_order_request`](super::order_rules::validate_order_request)로
+    /// 수행해야 합니다.
     pub fn submit_order(
         &mut self,
         request: &OrderRequest,
@@ -122,328 +248,288 @@ impl MatchingEngine {
         timestamp: DateTime<Utc>,
     ) -> OrderMatch {
         let order_id = self.generate_order_id();
+        let symbol = request.ticker.as_str();
+        let quantity = request.quantity;
+
+        // 이미 만료된 GTD 주문은 체결 없이 만료
+        if is_expired(request.time_in_force, request.expire_time, timestamp) {
+            return self.build_match(
+                &order_id,
+                symbol,
+                quantity,
+                quantity,
+                dec!(0),
+                dec!(0),
+                true,
+                timestamp,
+            );
+        }
 
         match request.order_type {
             OrderType::Market => {
-                // 시장가 주문은 슬리피지와 함께 즉시 체결됩니다
-                let slippage = current_price * self.slippage_rate;
-                let raw_fill_price = match request.side {
-                    Side::Buy => current_price + slippage,
-                    Side::Sell => current_price - slippage,
-                };
-
-                // 호가 단위로 라운딩 (매수는 올림, 매도는 내림)
-                let fill_price = match request.side {
-                    Side::Buy => self.round_price(raw_fill_price, RoundMethod::Ceil),
-                    Side::Sell => self.round_price(raw_fill_price, RoundMethod::Floor),
-                };
-
-                let commission = request.quantity * fill_price * self.fee_rate;
-                let commission_asset = Self::parse_quote(&request.ticker);
-
-                OrderMatch {
-                    order_id,
-                    fill_type: FillType::Full,
-                    filled_quantity: request.quantity,
+                // 시장가 주문은 대기하지 않음: 체결 가능한 만큼 체결하고 잔량은 만료
+                let fillable = self.available_liquidity(symbol, quantity);
+                let outcome = apply_time_in_force(request.time_in_force, false, quantity, fillable);
+                let fill_price = self.market_fill_price(request.side, current_price);
+                self.consume_liquidity(symbol, outcome.fill_quantity);
+
+                self.build_match(
+                    &order_id,
+                    symbol,
+                    quantity,
+                    quantity - outcome.fill_quantity,
+                    outcome.fill_quantity,
                     fill_price,
-                    commission,
-                    commission_asset,
+                    outcome.expire_remainder,
                     timestamp,
-                }
+                )
             }
             OrderType::Limit => {
-                // 지정가 주문: 즉시 체결 가능 여부 확인
-                let raw_limit_price = request.price.unwrap_or(current_price);
+                let limit_price =
+                    self.round_limit_price(request.side, request.price.unwrap_or(current_price));
 
-                // 지정가를 호가 단위로 라운딩 (매수는 내림, 매도는 올림)
-                let limit_price = match request.side {
-                    Side::Buy => self.round_price(raw_limit_price, RoundMethod::Floor),
-                    Side::Sell => self.round_price(raw_limit_price, RoundMethod::Ceil),
-                };
-
-                let can_fill = match request.side {
-                    Side::Buy => current_price <= limit_price,
-                    Side::Sell => current_price >= limit_price,
+                let fillable = if is_marketable(request.side, limit_price, current_price) {
+                    self.available_liquidity(symbol, quantity)
+                } else {
+                    dec!(0)
                 };
+                let outcome = apply_time_in_force(request.time_in_force, true, quantity, fillable);
+                self.consume_liquidity(symbol, outcome.fill_quantity);
 
-                if can_fill {
-                    // 지정가로 체결 (트레이더에게 더 유리함)
-                    let commission = request.quantity * limit_price * self.fee_rate;
-                    let commission_asset = Self::parse_quote(&request.ticker);
-
-                    OrderMatch {
-                        order_id,
-                        fill_type: FillType::Full,
-                        filled_quantity: request.quantity,
-                        fill_price: limit_price,
-                        commission,
-                        commission_asset,
-                        timestamp,
-                    }
-                } else {
-                    // 대기 주문에 추가
-                    let pending = PendingOrder {
+                let remaining = quantity - outcome.fill_quantity;
+                if !remaining.is_zero() && !outcome.expire_remainder {
+                    // 잔량은 대기 주문으로 등록
+                    self.push_pending(PendingOrder {
                         order_id: order_id.clone(),
                         symbol: request.ticker.clone(),
                         side: request.side,
                         order_type: request.order_type,
-                        original_quantity: request.quantity,
-                        remaining_quantity: request.quantity,
+                        original_quantity: quantity,
+                        remaining_quantity: remaining,
                         price: Some(limit_price),
                         stop_price: None,
+                        time_in_force: request.time_in_force,
+                        expire_time: request.expire_time,
+                        trigger: None,
                         created_at: timestamp,
-                    };
-
-                    self.pending_orders
-                        .entry(request.ticker.clone())
-                        .or_default()
-                        .push(pending);
-
-                    OrderMatch {
-                        order_id,
-                        fill_type: FillType::None,
-                        filled_quantity: dec!(0),
-                        fill_price: dec!(0),
-                        commission: dec!(0),
-                        commission_asset: Self::parse_quote(&request.ticker),
-                        timestamp,
-                    }
+                    });
                 }
-            }
-            OrderType::StopLoss | OrderType::StopLossLimit => {
-                // 대기 주문에 추가 (스탑 가격 도달 시 트리거됨)
-                let pending = PendingOrder {
-                    order_id: order_id.clone(),
-                    symbol: request.ticker.clone(),
-                    side: request.side,
-                    order_type: request.order_type,
-                    original_quantity: request.quantity,
-                    remaining_quantity: request.quantity,
-                    price: request.price,
-                    stop_price: request.stop_price,
-                    created_at: timestamp,
-                };
 
-                self.pending_orders
-                    .entry(request.ticker.clone())
-                    .or_default()
-                    .push(pending);
-
-                OrderMatch {
-                    order_id,
-                    fill_type: FillType::None,
-                    filled_quantity: dec!(0),
-                    fill_price: dec!(0),
-                    commission: dec!(0),
-                    commission_asset: Self::parse_quote(&request.ticker),
+                // 지정가로 체결 (트레이더에게 더 유리함)
+                self.build_match(
+                    &order_id,
+                    symbol,
+                    quantity,
+                    remaining,
+                    outcome.fill_quantity,
+                    limit_price,
+                    outcome.expire_remainder,
                     timestamp,
-                }
+                )
             }
-            OrderType::TakeProfit | OrderType::TakeProfitLimit => {
-                // 대기 주문에 추가 (이익실현 가격 도달 시 트리거됨)
-                let pending = PendingOrder {
+            OrderType::StopLoss
+            | OrderType::StopLossLimit
+            | OrderType::TakeProfit
+            | OrderType::TakeProfitLimit
+            | OrderType::TrailingStop => {
+                // 트리거될 때까지 대기 (유효 기간은 트리거 시점에 적용)
+                let price = request
+                    .price
+                    .map(|price| self.round_limit_price(request.side, price));
+
+                self.push_pending(PendingOrder {
                     order_id: order_id.clone(),
                     symbol: request.ticker.clone(),
                     side: request.side,
                     order_type: request.order_type,
-                    original_quantity: request.quantity,
-                    remaining_quantity: request.quantity,
-                    price: request.price,
+                    original_quantity: quantity,
+                    remaining_quantity: quantity,
+                    price,
                     stop_price: request.stop_price,
+                    time_in_force: request.time_in_force,
+                    expire_time: request.expire_time,
+                    trigger: OrderTrigger::from_request(request, current_price),
                     created_at: timestamp,
-                };
-
-                self.pending_orders
-                    .entry(request.ticker.clone())
-                    .or_default()
-                    .push(pending);
-
-                OrderMatch {
-                    order_id,
-                    fill_type: FillType::None,
-                    filled_quantity: dec!(0),
-                    fill_price: dec!(0),
-                    commission: dec!(0),
-                    commission_asset: Self::parse_quote(&request.ticker),
+                });
+
+                self.build_match(
+                    &order_id,
+                    symbol,
+                    quantity,
+                    quantity,
+                    dec!(0),
+                    dec!(0),
+                    false,
                     timestamp,
-                }
-            }
-            OrderType::TrailingStop => {
-                // 트레일링 스탑은 완전히 구현되지 않음 - 손절매로 처리
-                let pending = PendingOrder {
-                    order_id: order_id.clone(),
-                    symbol: request.ticker.clone(),
-                    side: request.side,
-                    order_type: request.order_type,
-                    original_quantity: request.quantity,
-                    remaining_quantity: request.quantity,
-                    price: request.price,
-                    stop_price: request.stop_price,
-                    created_at: timestamp,
-                };
-
-                self.pending_orders
-                    .entry(request.ticker.clone())
-                    .or_default()
-                    .push(pending);
-
-                OrderMatch {
-                    order_id,
-                    fill_type: FillType::None,
-                    filled_quantity: dec!(0),
-                    fill_price: dec!(0),
-                    commission: dec!(0),
-                    commission_asset: Self::parse_quote(&request.ticker),
-                    timestamp,
-                }
+                )
             }
         }
     }
 
+    /// 대기 주문을 등록합니다.
+    fn push_pending(&mut self, order: PendingOrder) {
+        self.pending_orders
+            .entry(order.symbol.clone())
+            .or_default()
+            .push(order);
+    }
+
     /// 새로운 Kline을 처리하고 주문 체결을 확인합니다.
-    /// 매칭된 주문 목록을 반환합니다.
+    ///
+    /// 체결되었거나 만료된 주문의 매칭 결과를 반환합니다.
+    /// 대기 주문은 등록 순서대로 봉의 유동성을 나눠 사용합니다.
     pub fn process_kline(&mut self, symbol: &String, kline: &Kline) -> Vec<OrderMatch> {
         let mut matches = Vec::new();
-        let mut to_remove = Vec::new();
 
-        if let Some(orders) = self.pending_orders.get(symbol) {
-            for (idx, order) in orders.iter().enumerate() {
-                if let Some(match_result) = self.try_match_order(order, kline) {
-                    if match_result.fill_type == FillType::Full {
-                        to_remove.push(idx);
-                    }
-                    matches.push(match_result);
-                }
+        // 새 봉의 체결 가능 수량
+        match self.volume_participation {
+            Some(rate) => {
+                self.bar_liquidity
+                    .insert(symbol.clone(), kline.volume * rate);
+            }
+            None => {
+                self.bar_liquidity.remove(symbol);
             }
         }
 
-        // 체결된 주문 제거 (인덱스 유지를 위해 역순으로)
-        if let Some(orders) = self.pending_orders.get_mut(symbol) {
-            for idx in to_remove.into_iter().rev() {
-                orders.remove(idx);
+        let orders = self.pending_orders.remove(symbol).unwrap_or_default();
+        let path = bar_path(kline);
+        let mut still_pending = Vec::with_capacity(orders.len());
+
+        for mut order in orders {
+            // 봉 시작 전에 만료된 GTD 주문
+            if is_expired(order.time_in_force, order.expire_time, kline.open_time) {
+                matches.push(self.build_match(
+                    &order.order_id,
+                    &order.symbol,
+                    order.original_quantity,
+                    order.remaining_quantity,
+                    dec!(0),
+                    dec!(0),
+                    true,
+                    kline.open_time,
+                ));
+                continue;
             }
-        }
 
-        matches
-    }
+            let execution = self.try_match_order(&mut order, &path);
+            let (filled, price, mut expired) = match execution {
+                Some(e) => (e.filled, e.price, e.expire_remainder),
+                None => (dec!(0), dec!(0), false),
+            };
+
+            order.remaining_quantity -= filled;
+            self.consume_liquidity(symbol, filled);
+
+            // 봉 종료 시점까지 남은 GTD 주문은 만료
+            if !order.remaining_quantity.is_zero()
+                && !expired
+                && is_expired(order.time_in_force, order.expire_time, kline.close_time)
+            {
+                expired = true;
+            }
 
-    /// 대기 주문을 Kline과 매칭 시도합니다.
-    fn try_match_order(&self, order: &PendingOrder, kline: &Kline) -> Option<OrderMatch> {
-        let high = kline.high;
-        let low = kline.low;
+            if !filled.is_zero() || expired {
+                matches.push(self.build_match(
+                    &order.order_id,
+                    &order.symbol,
+                    order.original_quantity,
+                    order.remaining_quantity,
+                    filled,
+                    price,
+                    expired,
+                    kline.close_time,
+                ));
+            }
 
-        match order.order_type {
-            OrderType::Limit => {
-                let limit_price = order.price?;
+            if !order.remaining_quantity.is_zero() && !expired {
+                still_pending.push(order);
+            }
+        }
 
-                let should_fill = match order.side {
-                    Side::Buy => low <= limit_price,
-                    Side::Sell => high >= limit_price,
-                };
+        if !still_pending.is_empty() {
+            self.pending_orders.insert(symbol.clone(), still_pending);
+        }
 
-                if should_fill {
-                    let commission = order.remaining_quantity * limit_price * self.fee_rate;
-
-                    Some(OrderMatch {
-                        order_id: order.order_id.clone(),
-                        fill_type: FillType::Full,
-                        filled_quantity: order.remaining_quantity,
-                        fill_price: limit_price,
-                        commission,
-                        commission_asset: order.symbol.clone(),
-                        timestamp: kline.close_time,
-                    })
-                } else {
-                    None
-                }
-            }
-            OrderType::StopLoss | OrderType::StopLossLimit => {
-                let stop_price = order.stop_price?;
-
-                // 손절매는 가격이 스탑 레벨을 교차할 때 트리거됩니다
-                let should_trigger = match order.side {
-                    // 손절매 매도는 가격이 스탑 가격 이하로 떨어질 때 트리거됩니다
-                    Side::Sell => low <= stop_price,
-                    // 손절매 매수는 가격이 스탑 가격 이상으로 오를 때 트리거됩니다
-                    Side::Buy => high >= stop_price,
-                };
+        matches
+    }
 
-                if should_trigger {
-                    // 스탑 가격으로 체결 (시장 스탑의 경우 슬리피지 포함)
-                    let fill_price = if order.order_type == OrderType::StopLoss {
-                        let slippage = stop_price * self.slippage_rate;
-                        match order.side {
-                            Side::Sell => stop_price - slippage,
-                            Side::Buy => stop_price + slippage,
-                        }
-                    } else {
-                        order.price.unwrap_or(stop_price)
-                    };
-
-                    let commission = order.remaining_quantity * fill_price * self.fee_rate;
-
-                    Some(OrderMatch {
-                        order_id: order.order_id.clone(),
-                        fill_type: FillType::Full,
-                        filled_quantity: order.remaining_quantity,
-                        fill_price,
-                        commission,
-                        commission_asset: Self::parse_quote(&order.symbol),
-                        timestamp: kline.close_time,
-                    })
-                } else {
-                    None
+    /// 대기 주문을 봉의 가격 경로와 매칭 시도합니다.
+    ///
+    /// 조건부 주문은 경로를 따라 트리거를 판정하고, 트리거 이후 구간에서만 체결됩니다.
+    /// 시가에서 트리거되면(갭) 시가, 그 외에는 트리거 가격을 기준으로 시장가 체결합니다.
+    fn try_match_order(
+        &self,
+        order: &mut PendingOrder,
+        path: &[Decimal; 4],
+    ) -> Option<BarExecution> {
+        let mut start = 0;
+        let mut trigger_price = None;
+
+        if let Some(trigger) = order.trigger.as_mut() {
+            if !trigger.is_triggered() {
+                let mut hit = None;
+                for (idx, price) in path.iter().enumerate() {
+                    let level = trigger.trigger_level();
+                    if trigger.observe(*price) {
+                        let reference = if idx == 0 {
+                            *price
+                        } else {
+                            level.unwrap_or(*price)
+                        };
+                        hit = Some((idx, reference));
+                        break;
+                    }
                 }
+                let (idx, reference) = hit?;
+                start = idx;
+                trigger_price = Some(reference);
             }
-            OrderType::TakeProfit | OrderType::TakeProfitLimit => {
-                let stop_price = order.stop_price?;
-
-                // 이익실현은 가격이 목표에 도달할 때 트리거됩니다
-                let should_trigger = match order.side {
-                    // 이익실현 매도는 가격이 목표 이상으로 오를 때 트리거됩니다
-                    Side::Sell => high >= stop_price,
-                    // 이익실현 매수는 가격이 목표 이하로 떨어질 때 트리거됩니다
-                    Side::Buy => low <= stop_price,
-                };
+        }
 
-                if should_trigger {
-                    let fill_price = if order.order_type == OrderType::TakeProfit {
-                        stop_price
-                    } else {
-                        order.price.unwrap_or(stop_price)
-                    };
-
-                    let commission = order.remaining_quantity * fill_price * self.fee_rate;
-
-                    Some(OrderMatch {
-                        order_id: order.order_id.clone(),
-                        fill_type: FillType::Full,
-                        filled_quantity: order.remaining_quantity,
-                        fill_price,
-                        commission,
-                        commission_asset: Self::parse_quote(&order.symbol),
-                        timestamp: kline.close_time,
-                    })
-                } else {
-                    None
-                }
-            }
-            OrderType::Market | OrderType::TrailingStop => {
-                // 시장가 주문은 대기 상태가 아니어야 합니다
-                None
-            }
+        let remaining = order.remaining_quantity;
+        let available = self.available_liquidity(&order.symbol, remaining);
+
+        if requires_limit_price(order.order_type) {
+            let limit_price = order.price?;
+            let marketable = path[start..]
+                .iter()
+                .any(|price| is_marketable(order.side, limit_price, *price));
+            let fillable = if marketable { available } else { dec!(0) };
+            let outcome = apply_time_in_force(order.time_in_force, true, remaining, fillable);
+
+            Some(BarExecution {
+                filled: outcome.fill_quantity,
+                price: limit_price,
+                expire_remainder: outcome.expire_remainder,
+            })
+        } else {
+            // 트리거된 시장가 계열: 이번 봉에서 체결하고 잔량은 만료
+            let reference = trigger_price?;
+            let outcome = apply_time_in_force(order.time_in_force, false, remaining, available);
+
+            Some(BarExecution {
+                filled: outcome.fill_quantity,
+                price: self.market_fill_price(order.side, reference),
+                expire_remainder: outcome.expire_remainder,
+            })
         }
     }
 
     /// 대기 주문을 취소합니다.
     pub fn cancel_order(&mut self, symbol: &String, order_id: &str) -> bool {
-        if let Some(orders) = self.pending_orders.get_mut(symbol) {
-            if let Some(pos) = orders.iter().position(|o| o.order_id == order_id) {
-                orders.remove(pos);
-                return true;
-            }
+        self.remove_order(symbol, order_id).is_some()
+    }
+
+    /// 대기 주문을 제거하고 반환합니다.
+    pub fn remove_order(&mut self, symbol: &String, order_id: &str) -> Option<PendingOrder> {
+        let orders = self.pending_orders.get_mut(symbol)?;
+        let pos = orders.iter().position(|o| o.order_id == order_id)?;
+        let removed = orders.remove(pos);
+        if orders.is_empty() {
+            self.pending_orders.remove(symbol);
         }
-        false
+        Some(removed)
     }
 
     /// 심볼의 모든 대기 주문을 가져옵니다.
@@ -473,12 +559,13 @@ impl MatchingEngine {
     /// 모든 대기 주문을 초기화합니다.
     pub fn clear(&mut self) {
         self.pending_orders.clear();
+        self.bar_liquidity.clear();
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use trader_core::{TimeInForce, Timeframe};
+    use trader_core::Timeframe;
 
     use super::*;
 
@@ -517,6 +604,8 @@ mod tests {
             price: None,
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         };
@@ -546,6 +635,8 @@ mod tests {
             price: Some(dec!(51000)),
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         };
@@ -571,6 +662,8 @@ mod tests {
             price: Some(dec!(49000)),
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         };
@@ -596,6 +689,8 @@ mod tests {
             price: Some(dec!(49000)),
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         };
@@ -626,6 +721,8 @@ mod tests {
             price: None,
             stop_price: Some(dec!(48000)),
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         };
@@ -655,6 +752,8 @@ mod tests {
             price: Some(dec!(49000)),
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: None,
             strategy_id: None,
         };
@@ -666,4 +765,214 @@ mod tests {
         assert!(cancelled);
         assert_eq!(engine.get_pending_orders(Some(&symbol)).len(), 0);
     }
+
+    fn request(
+        order_type: OrderType,
+        side: Side,
+        quantity: Decimal,
+        price: Option<Decimal>,
+        stop_price: Option<Decimal>,
+    ) -> OrderRequest {
+        OrderRequest {
+            ticker: create_test_symbol(),
+            side,
+            order_type,
+            quantity,
+            price,
+            stop_price,
+            time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
+            client_order_id: None,
+            strategy_id: None,
+        }
+    }
+
+    #[test]
+    fn test_partial_fill_against_bar_volume() {
+        // 봉 거래량 100의 10% = 봉당 최대 10
+        let mut engine =
+            MatchingEngine::new(dec!(0.001), dec!(0)).with_volume_participation(dec!(0.1));
+        let symbol = create_test_symbol();
+        let order = request(
+            OrderType::Limit,
+            Side::Buy,
+            dec!(25),
+            Some(dec!(49000)),
+            None,
+        );
+        let submitted = engine.submit_order(&order, dec!(50000), Utc::now());
+        assert_eq!(submitted.status, OrderStatusType::Open);
+
+        let kline = create_test_kline(50000.0, 50500.0, 48500.0, 49500.0);
+        let first = engine.process_kline(&symbol, &kline);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].fill_type, FillType::Partial);
+        assert_eq!(first[0].filled_quantity, dec!(10));
+        assert_eq!(first[0].remaining_quantity, dec!(15));
+        assert_eq!(first[0].status, OrderStatusType::PartiallyFilled);
+
+        engine.process_kline(&symbol, &kline);
+        let last = engine.process_kline(&symbol, &kline);
+        assert_eq!(last[0].fill_type, FillType::Full);
+        assert_eq!(last[0].filled_quantity, dec!(5));
+        assert!(engine.get_pending_orders(Some(&symbol)).is_empty());
+    }
+
+    #[test]
+    fn test_market_order_remainder_expires() {
+        let mut engine =
+            MatchingEngine::new(dec!(0.001), dec!(0)).with_volume_participation(dec!(0.1));
+        let symbol = create_test_symbol();
+        engine.process_kline(
+            &symbol,
+            &create_test_kline(50000.0, 50500.0, 49500.0, 50000.0),
+        );
+
+        let market = request(OrderType::Market, Side::Buy, dec!(15), None, None);
+        let result = engine.submit_order(&market, dec!(50000), Utc::now());
+        assert_eq!(result.filled_quantity, dec!(10));
+        assert_eq!(result.status, OrderStatusType::Expired);
+        assert!(engine.get_pending_orders(Some(&symbol)).is_empty());
+
+        // 같은 봉의 유동성은 이미 소진됨
+        let mut fok = request(OrderType::Market, Side::Buy, dec!(1), None, None);
+        fok.time_in_force = TimeInForce::FOK;
+        let result = engine.submit_order(&fok, dec!(50000), Utc::now());
+        assert_eq!(result.fill_type, FillType::None);
+        assert_eq!(result.status, OrderStatusType::Expired);
+    }
+
+    #[test]
+    fn test_limit_ioc_and_fok() {
+        let mut engine = MatchingEngine::new(dec!(0.001), dec!(0));
+        let symbol = create_test_symbol();
+
+        let mut ioc = request(
+            OrderType::Limit,
+            Side::Buy,
+            dec!(1),
+            Some(dec!(49000)),
+            None,
+        );
+        ioc.time_in_force = TimeInForce::IOC;
+        let result = engine.submit_order(&ioc, dec!(50000), Utc::now());
+        assert_eq!(result.status, OrderStatusType::Expired);
+
+        let mut fok = request(
+            OrderType::Limit,
+            Side::Buy,
+            dec!(1),
+            Some(dec!(51000)),
+            None,
+        );
+        fok.time_in_force = TimeInForce::FOK;
+        let result = engine.submit_order(&fok, dec!(50000), Utc::now());
+        assert_eq!(result.status, OrderStatusType::Filled);
+        assert!(engine.get_pending_orders(Some(&symbol)).is_empty());
+    }
+
+    #[test]
+    fn test_gtd_expiry() {
+        let mut engine = MatchingEngine::new(dec!(0.001), dec!(0));
+        let symbol = create_test_symbol();
+        let kline = create_test_kline(50000.0, 50500.0, 49500.0, 50000.0);
+
+        let order = request(
+            OrderType::Limit,
+            Side::Buy,
+            dec!(1),
+            Some(dec!(45000)),
+            None,
+        )
+        .with_expire_time(kline.close_time);
+        let submitted = engine.submit_order(&order, dec!(50000), kline.open_time);
+        assert_eq!(submitted.status, OrderStatusType::Open);
+
+        let matches = engine.process_kline(&symbol, &kline);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].status, OrderStatusType::Expired);
+        assert!(engine.get_pending_orders(Some(&symbol)).is_empty());
+
+        // 제출 시점에 이미 만료
+        let late = engine.submit_order(&order, dec!(50000), kline.close_time);
+        assert_eq!(late.status, OrderStatusType::Expired);
+    }
+
+    #[test]
+    fn test_take_profit_direction() {
+        let mut engine = MatchingEngine::new(dec!(0.001), dec!(0));
+        let symbol = create_test_symbol();
+        let order = request(
+            OrderType::TakeProfit,
+            Side::Sell,
+            dec!(1),
+            None,
+            Some(dec!(52000)),
+        );
+        engine.submit_order(&order, dec!(50000), Utc::now());
+
+        // 하락 봉에서는 트리거되지 않음
+        let down = create_test_kline(50000.0, 50200.0, 48000.0, 48500.0);
+        assert!(engine.process_kline(&symbol, &down).is_empty());
+
+        let up = create_test_kline(50000.0, 52500.0, 49800.0, 52300.0);
+        let matches = engine.process_kline(&symbol, &up);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].fill_price, dec!(52000));
+    }
+
+    #[test]
+    fn test_trailing_stop_activation() {
+        let mut engine = MatchingEngine::new(dec!(0.001), dec!(0));
+        let symbol = create_test_symbol();
+        let order = OrderRequest::trailing_stop(
+            symbol.clone(),
+            Side::Sell,
+            dec!(1),
+            dec!(5),
+            Some(dec!(52000)),
+        );
+        engine.submit_order(&order, dec!(50000), Utc::now());
+
+        // 활성화 전 하락은 무시
+        let down = create_test_kline(50000.0, 50100.0, 45000.0, 46000.0);
+        assert!(engine.process_kline(&symbol, &down).is_empty());
+
+        // 활성화 후 54000까지 상승 (트리거 가격 51300)
+        let up = create_test_kline(50000.0, 54000.0, 50000.0, 53500.0);
+        assert!(engine.process_kline(&symbol, &up).is_empty());
+        let pending = engine.get_pending_orders(Some(&symbol));
+        let trigger = pending[0].trigger.as_ref().unwrap();
+        assert!(trigger.is_activated());
+        assert_eq!(trigger.trigger_level(), Some(dec!(51300)));
+
+        let pullback = create_test_kline(53500.0, 53600.0, 51000.0, 51200.0);
+        let matches = engine.process_kline(&symbol, &pullback);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].status, OrderStatusType::Filled);
+        assert_eq!(matches[0].fill_price, dec!(51300));
+    }
+
+    #[test]
+    fn test_stop_limit_ioc_expires_when_not_marketable() {
+        let mut engine = MatchingEngine::new(dec!(0.001), dec!(0));
+        let symbol = create_test_symbol();
+        let mut order = request(
+            OrderType::StopLossLimit,
+            Side::Sell,
+            dec!(1),
+            Some(dec!(47900)),
+            Some(dec!(48000)),
+        );
+        order.time_in_force = TimeInForce::IOC;
+        engine.submit_order(&order, dec!(50000), Utc::now());
+
+        // 저가에서 트리거되었지만 이후 경로(종가 47000)가 지정가 미달 → 만료
+        let kline = create_test_kline(49000.0, 49500.0, 46000.0, 47000.0);
+        let matches = engine.process_kline(&symbol, &kline);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].status, OrderStatusType::Expired);
+        assert_eq!(matches[0].filled_quantity, dec!(0));
+    }
 }
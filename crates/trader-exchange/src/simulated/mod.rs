@@ -2,7 +2,7 @@
 //!
 //! 이 모듈은 다음 기능을 제공하는 시뮬레이션 거래소입니다:
 //! - 파일 또는 메모리에서 과거 데이터(Kline) 로드
-//! - 주문 매칭 및 체결 시뮬레이션
+//! - 주문 매칭 및 체결 시뮬레이션 (거래량 기반 부분 체결, 유효 기간, 트레일링 스톱)
 //! - 계정 잔고 및 포지션 추적
 //! - 전략 테스트를 위한 시장 이벤트 생성
 //!
@@ -26,9 +26,11 @@
 mod data_feed;
 mod exchange;
 mod matching_engine;
+pub mod order_rules;
 mod stream;
 
 pub use data_feed::{DataFeed, DataFeedConfig};
 pub use exchange::{SimulatedConfig, SimulatedExchange};
-pub use matching_engine::{FillType, MatchingEngine, OrderMatch};
+pub use matching_engine::{FillType, MatchingEngine, OrderMatch, PendingOrder};
+pub use order_rules::{validate_order_request, OrderTrigger};
 pub use stream::{EventBroadcaster, SimulatedMarketStream, SimulatedUserStream};
@@ -0,0 +1,421 @@
+//! 시뮬레이션 엔진 공통 주문 규칙.
+//!
+//! 백테스트용 `MatchingEngine`(Kline 기반)과 Paper Trading용 `MockOrderEngine`
+//! (호가창 기반)이 동일한 `OrderType` × `TimeInForce` 의미를 갖도록
+//! 검증, 트리거, 유효 기간 판정을 한 곳에서 정의합니다.
+//!
+//! # 주문 의미
+//!
+//! - 시장가 계열(`Market`, 트리거된 `StopLoss`/`TakeProfit`/`TrailingStop`)은
+//!   대기하지 않습니다. 유동성이 부족하면 체결 가능한 만큼만 체결하고 잔량은 만료됩니다.
+//! - 지정가 계열(`Limit`, 트리거된 `StopLossLimit`/`TakeProfitLimit`)은
+//!   즉시 체결 가능한 만큼 체결하고, 잔량은 유효 기간에 따라 처리합니다.
+//!   (GTC/GTD: 대기, IOC: 만료, FOK: 전량 체결 불가 시 체결 없이 만료)
+//! - `TimeInForce`는 일반 주문은 제출 시점, 조건부 주문은 트리거 시점에 적용됩니다.
+//! - GTD 주문은 `expire_time` 이후 관측 시점에 만료됩니다.
+//! - 트레일링 스톱은 `stop_price`를 활성화 가격으로 사용하며(없으면 즉시 활성화),
+//!   활성화 후 최고가(매도)/최저가(매수) 대비 `trailing_percent`만큼 되돌리면 트리거됩니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use trader_core::{Kline, OrderRequest, OrderType, Side, TimeInForce};
+
+/// 주문 요청을 검증합니다.
+///
+/// 두 엔진과 시뮬레이션 거래소가 같은 거부 규칙을 사용하도록 공유합니다.
+pub fn validate_order_request(request: &OrderRequest) -> Result<(), String> {
+    if request.quantity <= Decimal::ZERO {
+        return Err("주문 수량은 0보다 커야 합니다".to_string());
+    }
+
+    if requires_limit_price(request.order_type) {
+        match request.price {
+            Some(price) if price > Decimal::ZERO => {}
+            _ => return Err(format!("{} 주문에는 가격이 필요합니다", request.order_type)),
+        }
+    }
+
+    match request.order_type {
+        OrderType::StopLoss
+        | OrderType::StopLossLimit
+        | OrderType::TakeProfit
+        | OrderType::TakeProfitLimit => match request.stop_price {
+            Some(stop) if stop > Decimal::ZERO => {}
+            _ => {
+                return Err(format!(
+                    "{} 주문에는 스톱 가격이 필요합니다",
+                    request.order_type
+                ))
+            }
+        },
+        OrderType::TrailingStop => match request.trailing_percent {
+            Some(pct) if pct > Decimal::ZERO && pct < Decimal::ONE_HUNDRED => {}
+            _ => {
+                return Err(
+                    "트레일링 스톱 주문에는 0~100 사이의 trailing_percent가 필요합니다".to_string(),
+                )
+            }
+        },
+        OrderType::Market | OrderType::Limit => {}
+    }
+
+    if request.time_in_force == TimeInForce::GTD && request.expire_time.is_none() {
+        return Err("GTD 주문에는 expire_time이 필요합니다".to_string());
+    }
+
+    Ok(())
+}
+
+/// 지정가가 필요한 주문 유형인지 확인합니다.
+///
+/// 이 유형들은 트리거 후에도 지정가로 체결되며 잔량이 대기할 수 있습니다.
+pub fn requires_limit_price(order_type: OrderType) -> bool {
+    matches!(
+        order_type,
+        OrderType::Limit | OrderType::StopLossLimit | OrderType::TakeProfitLimit
+    )
+}
+
+/// 트리거 조건이 있는 주문 유형인지 확인합니다.
+pub fn is_conditional(order_type: OrderType) -> bool {
+    !matches!(order_type, OrderType::Market | OrderType::Limit)
+}
+
+/// 지정가 주문이 해당 가격에서 체결 가능한지 확인합니다.
+pub fn is_marketable(side: Side, limit_price: Decimal, price: Decimal) -> bool {
+    match side {
+        Side::Buy => price <= limit_price,
+        Side::Sell => price >= limit_price,
+    }
+}
+
+/// GTD 주문의 만료 여부를 확인합니다.
+pub fn is_expired(
+    time_in_force: TimeInForce,
+    expire_time: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> bool {
+    time_in_force == TimeInForce::GTD && expire_time.is_some_and(|expire| now >= expire)
+}
+
+/// 유효 기간 적용 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeInForceOutcome {
+    /// 실제 체결할 수량
+    pub fill_quantity: Decimal,
+    /// 잔량 만료 여부 (false면 잔량이 대기)
+    pub expire_remainder: bool,
+}
+
+/// 체결 가능 수량에 유효 기간 규칙을 적용합니다.
+///
+/// `resting`은 잔량이 대기할 수 있는 주문(지정가 계열)인지 여부입니다.
+/// 시장가 계열은 GTC/GTD여도 잔량이 대기하지 않습니다.
+pub fn apply_time_in_force(
+    time_in_force: TimeInForce,
+    resting: bool,
+    remaining: Decimal,
+    fillable: Decimal,
+) -> TimeInForceOutcome {
+    let fillable = fillable.min(remaining).max(Decimal::ZERO);
+
+    match time_in_force {
+        TimeInForce::FOK if fillable < remaining => TimeInForceOutcome {
+            fill_quantity: Decimal::ZERO,
+            expire_remainder: true,
+        },
+        TimeInForce::FOK | TimeInForce::IOC => TimeInForceOutcome {
+            fill_quantity: fillable,
+            expire_remainder: fillable < remaining,
+        },
+        TimeInForce::GTC | TimeInForce::GTD => TimeInForceOutcome {
+            fill_quantity: fillable,
+            expire_remainder: !resting && fillable < remaining,
+        },
+    }
+}
+
+/// Kline 내부 가격 경로를 추정합니다.
+///
+/// 양봉은 시가 → 저가 → 고가 → 종가, 음봉은 시가 → 고가 → 저가 → 종가 순으로
+/// 움직였다고 가정합니다. 두 엔진의 트리거 판정이 같은 경로를 따르도록 공유합니다.
+pub fn bar_path(kline: &Kline) -> [Decimal; 4] {
+    if kline.close >= kline.open {
+        [kline.open, kline.low, kline.high, kline.close]
+    } else {
+        [kline.open, kline.high, kline.low, kline.close]
+    }
+}
+
+/// 조건부 주문의 트리거 상태.
+#[derive(Debug, Clone)]
+pub struct OrderTrigger {
+    /// 주문 유형
+    order_type: OrderType,
+    /// 주문 방향
+    side: Side,
+    /// 스톱 가격 (트레일링 스톱은 활성화 가격)
+    stop_price: Option<Decimal>,
+    /// 트레일링 폭 (%)
+    trailing_percent: Option<Decimal>,
+    /// 트레일링 활성화 여부
+    activated: bool,
+    /// 활성화 이후 최고가(매도)/최저가(매수)
+    extreme: Option<Decimal>,
+    /// 트리거 여부
+    triggered: bool,
+}
+
+impl OrderTrigger {
+    /// 조건부 주문의 트리거를 생성합니다. 일반 주문은 `None`을 반환합니다.
+    ///
+    /// `reference_price`는 활성화 가격 없는 트레일링 스톱의 추적 시작 가격입니다.
+    pub fn from_request(request: &OrderRequest, reference_price: Decimal) -> Option<Self> {
+        Self::new(
+            request.order_type,
+            request.side,
+            request.stop_price,
+            request.trailing_percent,
+            Some(reference_price),
+        )
+    }
+
+    /// 주문 속성으로 트리거를 생성합니다. 일반 주문은 `None`을 반환합니다.
+    ///
+    /// 활성화 가격 없는 트레일링 스톱에서 `reference_price`가 없으면
+    /// 첫 관측 가격부터 추적합니다 (미체결 주문 복원용).
+    pub fn new(
+        order_type: OrderType,
+        side: Side,
+        stop_price: Option<Decimal>,
+        trailing_percent: Option<Decimal>,
+        reference_price: Option<Decimal>,
+    ) -> Option<Self> {
+        if !is_conditional(order_type) {
+            return None;
+        }
+
+        let immediate = order_type == OrderType::TrailingStop && stop_price.is_none();
+        Some(Self {
+            order_type,
+            side,
+            stop_price,
+            trailing_percent,
+            activated: immediate,
+            extreme: if immediate { reference_price } else { None },
+            triggered: false,
+        })
+    }
+
+    /// 트리거 여부.
+    pub fn is_triggered(&self) -> bool {
+        self.triggered
+    }
+
+    /// 트레일링 활성화 여부 (트레일링 스톱이 아니면 항상 false).
+    pub fn is_activated(&self) -> bool {
+        self.activated
+    }
+
+    /// 현재 트리거 가격.
+    ///
+    /// 트레일링 스톱은 활성화 후 추적 가격에서 계산되며, 활성화 전에는 `None`입니다.
+    pub fn trigger_level(&self) -> Option<Decimal> {
+        match self.order_type {
+            OrderType::TrailingStop => {
+                let extreme = self.extreme?;
+                let pct = self.trailing_percent? / Decimal::ONE_HUNDRED;
+                Some(match self.side {
+                    Side::Sell => extreme * (Decimal::ONE - pct),
+                    Side::Buy => extreme * (Decimal::ONE + pct),
+                })
+            }
+            _ => self.stop_price,
+        }
+    }
+
+    /// 가격을 관측하고 이번 관측에서 트리거되었는지 반환합니다.
+    ///
+    /// 이미 트리거된 주문은 false를 반환합니다.
+    pub fn observe(&mut self, price: Decimal) -> bool {
+        if self.triggered {
+            return false;
+        }
+
+        match self.order_type {
+            OrderType::StopLoss | OrderType::StopLossLimit => {
+                let Some(stop) = self.stop_price else {
+                    return false;
+                };
+                self.triggered = match self.side {
+                    Side::Buy => price >= stop,
+                    Side::Sell => price <= stop,
+                };
+            }
+            OrderType::TakeProfit | OrderType::TakeProfitLimit => {
+                let Some(stop) = self.stop_price else {
+                    return false;
+                };
+                self.triggered = match self.side {
+                    Side::Buy => price <= stop,
+                    Side::Sell => price >= stop,
+                };
+            }
+            OrderType::TrailingStop => {
+                if !self.activated {
+                    let Some(activation) = self.stop_price else {
+                        return false;
+                    };
+                    let reached = match self.side {
+                        Side::Sell => price >= activation,
+                        Side::Buy => price <= activation,
+                    };
+                    if reached {
+                        self.activated = true;
+                        self.extreme = Some(price);
+                    }
+                    return false;
+                }
+
+                // 기존 추적 가격 기준으로 먼저 판정한 뒤 추적 가격을 갱신
+                if let Some(level) = self.trigger_level() {
+                    self.triggered = match self.side {
+                        Side::Sell => price <= level,
+                        Side::Buy => price >= level,
+                    };
+                }
+                if !self.triggered {
+                    self.extreme = Some(match (self.side, self.extreme) {
+                        (Side::Sell, Some(high)) => high.max(price),
+                        (Side::Buy, Some(low)) => low.min(price),
+                        (_, None) => price,
+                    });
+                }
+            }
+            OrderType::Market | OrderType::Limit => {}
+        }
+
+        self.triggered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn request(order_type: OrderType, side: Side) -> OrderRequest {
+        OrderRequest {
+            ticker: "BTC/USDT".to_string(),
+            side,
+            order_type,
+            quantity: dec!(1),
+            price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
+            client_order_id: None,
+            strategy_id: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_order_request() {
+        let mut limit = request(OrderType::StopLossLimit, Side::Sell);
+        limit.stop_price = Some(dec!(100));
+        assert!(validate_order_request(&limit).is_err());
+        limit.price = Some(dec!(99));
+        assert!(validate_order_request(&limit).is_ok());
+
+        let mut trailing = request(OrderType::TrailingStop, Side::Sell);
+        assert!(validate_order_request(&trailing).is_err());
+        trailing.trailing_percent = Some(dec!(150));
+        assert!(validate_order_request(&trailing).is_err());
+        trailing.trailing_percent = Some(dec!(5));
+        assert!(validate_order_request(&trailing).is_ok());
+
+        let mut gtd = request(OrderType::Market, Side::Buy);
+        gtd.time_in_force = TimeInForce::GTD;
+        assert!(validate_order_request(&gtd).is_err());
+        gtd.expire_time = Some(Utc::now());
+        assert!(validate_order_request(&gtd).is_ok());
+    }
+
+    #[test]
+    fn test_apply_time_in_force() {
+        let gtc = apply_time_in_force(TimeInForce::GTC, true, dec!(10), dec!(4));
+        assert_eq!(gtc.fill_quantity, dec!(4));
+        assert!(!gtc.expire_remainder);
+
+        // 시장가 계열은 GTC여도 잔량이 대기하지 않음
+        let market = apply_time_in_force(TimeInForce::GTC, false, dec!(10), dec!(4));
+        assert!(market.expire_remainder);
+
+        let ioc = apply_time_in_force(TimeInForce::IOC, true, dec!(10), dec!(4));
+        assert_eq!(ioc.fill_quantity, dec!(4));
+        assert!(ioc.expire_remainder);
+
+        let fok = apply_time_in_force(TimeInForce::FOK, true, dec!(10), dec!(4));
+        assert_eq!(fok.fill_quantity, Decimal::ZERO);
+        assert!(fok.expire_remainder);
+
+        let fok_full = apply_time_in_force(TimeInForce::FOK, true, dec!(10), dec!(12));
+        assert_eq!(fok_full.fill_quantity, dec!(10));
+        assert!(!fok_full.expire_remainder);
+    }
+
+    #[test]
+    fn test_stop_and_take_profit_directions() {
+        let mut stop = request(OrderType::StopLoss, Side::Sell);
+        stop.stop_price = Some(dec!(95));
+        let mut trigger = OrderTrigger::from_request(&stop, dec!(100)).unwrap();
+        assert!(!trigger.observe(dec!(96)));
+        assert!(trigger.observe(dec!(95)));
+        assert!(trigger.is_triggered());
+
+        let mut take = request(OrderType::TakeProfit, Side::Sell);
+        take.stop_price = Some(dec!(110));
+        let mut trigger = OrderTrigger::from_request(&take, dec!(100)).unwrap();
+        assert!(!trigger.observe(dec!(90)));
+        assert!(trigger.observe(dec!(111)));
+
+        assert!(
+            OrderTrigger::from_request(&request(OrderType::Limit, Side::Buy), dec!(1)).is_none()
+        );
+    }
+
+    #[test]
+    fn test_trailing_stop_activation_and_trail() {
+        let mut trailing = request(OrderType::TrailingStop, Side::Sell);
+        trailing.trailing_percent = Some(dec!(10));
+        trailing.stop_price = Some(dec!(110));
+        let mut trigger = OrderTrigger::from_request(&trailing, dec!(100)).unwrap();
+
+        // 활성화 전에는 하락해도 트리거되지 않음
+        assert!(!trigger.observe(dec!(80)));
+        assert!(!trigger.is_activated());
+
+        assert!(!trigger.observe(dec!(110)));
+        assert!(trigger.is_activated());
+        assert_eq!(trigger.trigger_level(), Some(dec!(99)));
+
+        assert!(!trigger.observe(dec!(120)));
+        assert_eq!(trigger.trigger_level(), Some(dec!(108)));
+        assert!(!trigger.observe(dec!(109)));
+        assert!(trigger.observe(dec!(108)));
+    }
+
+    #[test]
+    fn test_trailing_buy_without_activation() {
+        let mut trailing = request(OrderType::TrailingStop, Side::Buy);
+        trailing.trailing_percent = Some(dec!(5));
+        let mut trigger = OrderTrigger::from_request(&trailing, dec!(100)).unwrap();
+        assert!(trigger.is_activated());
+
+        assert!(!trigger.observe(dec!(90)));
+        assert_eq!(trigger.trigger_level(), Some(dec!(94.5)));
+        assert!(trigger.observe(dec!(95)));
+    }
+}
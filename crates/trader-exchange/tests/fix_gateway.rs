@@ -0,0 +1,530 @@
+//! Integration tests for the FIX 4.4 gateway against an in-process acceptor.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::Utc;
+use rust_decimal_macros::dec;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{tcp::OwnedWriteHalf, TcpListener, TcpStream},
+    sync::{mpsc, Mutex as AsyncMutex},
+};
+use trader_core::{
+    OrderExecutionProvider, OrderRequest, OrderStatusType, OrderType, ProviderError,
+};
+use trader_exchange::{
+    connector::fix::{
+        message::{format_timestamp, msg_type, tags},
+        FixClient, FixConfig, FixMessage,
+    },
+    FixExchangeProvider,
+};
+
+/// Minimal FIX acceptor that acknowledges logons and order requests.
+///
+/// Symbols drive scripted behaviour: `BAD` orders are rejected and
+/// cancels/replaces for `LOCKED` orders are rejected.
+struct Acceptor {
+    port: u16,
+    state: Arc<AcceptorState>,
+    inject: mpsc::UnboundedSender<FixMessage>,
+}
+
+#[derive(Default)]
+struct AcceptorState {
+    received: Mutex<Vec<FixMessage>>,
+    next_seq: Mutex<u64>,
+    orders: Mutex<HashMap<String, (String, String)>>,
+    order_count: Mutex<u64>,
+}
+
+impl Acceptor {
+    async fn spawn() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let state = Arc::new(AcceptorState::default());
+        *state.next_seq.lock().unwrap() = 1;
+        let (inject, inject_rx) = mpsc::unbounded_channel();
+        let inject_rx = Arc::new(AsyncMutex::new(inject_rx));
+
+        let accept_state = Arc::clone(&state);
+        tokio::spawn(async move {
+            // 연결을 순차적으로 처리 (재접속 테스트용)
+            while let Ok((stream, _)) = listener.accept().await {
+                handle_connection(stream, Arc::clone(&accept_state), Arc::clone(&inject_rx)).await;
+            }
+        });
+
+        Self {
+            port,
+            state,
+            inject,
+        }
+    }
+
+    fn config(&self) -> FixConfig {
+        FixConfig::new("127.0.0.1", self.port, "ZQ", "BROKER")
+            .with_response_timeout_ms(2000)
+            .with_exchange_name("prime")
+    }
+
+    fn received(&self, msg_type: &str) -> Vec<FixMessage> {
+        self.state
+            .received
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|m| m.msg_type() == msg_type)
+            .cloned()
+            .collect()
+    }
+
+    async fn wait_for(&self, msg_type: &str, count: usize) -> Vec<FixMessage> {
+        for _ in 0..100 {
+            let messages = self.received(msg_type);
+            if messages.len() >= count {
+                return messages;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("acceptor did not receive {} x MsgType={}", count, msg_type);
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    state: Arc<AcceptorState>,
+    inject_rx: Arc<AsyncMutex<mpsc::UnboundedReceiver<FixMessage>>>,
+) {
+    let (mut reader, mut writer) = stream.into_split();
+    let mut inject_rx = inject_rx.lock().await;
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    loop {
+        tokio::select! {
+            read = reader.read(&mut chunk) => {
+                let n = match read {
+                    Ok(0) | Err(_) => return,
+                    Ok(n) => n,
+                };
+                buf.extend_from_slice(&chunk[..n]);
+                while let Some(len) = FixMessage::frame_len(&buf).unwrap() {
+                    let frame: Vec<u8> = buf.drain(..len).collect();
+                    let message = FixMessage::decode(&frame).unwrap();
+                    state.received.lock().unwrap().push(message.clone());
+                    for reply in respond(&message, &state) {
+                        send(&mut writer, reply, &state).await;
+                    }
+                    if message.msg_type() == msg_type::LOGOUT {
+                        return;
+                    }
+                }
+            }
+            Some(message) = inject_rx.recv() => {
+                send(&mut writer, message, &state).await;
+            }
+        }
+    }
+}
+
+async fn send(writer: &mut OwnedWriteHalf, mut message: FixMessage, state: &AcceptorState) {
+    let seq = {
+        let mut next_seq = state.next_seq.lock().unwrap();
+        // 명시적 MsgSeqNum이 있으면 그대로 사용 (갭/재전송 시나리오)
+        let seq = message.seq_num().unwrap_or(*next_seq);
+        if !message.is_poss_dup() {
+            *next_seq = seq + 1;
+        }
+        seq
+    };
+    message.set_field(tags::SENDER_COMP_ID, "BROKER");
+    message.set_field(tags::TARGET_COMP_ID, "ZQ");
+    message.set_field(tags::MSG_SEQ_NUM, seq);
+    message.set_field(tags::SENDING_TIME, format_timestamp(Utc::now()));
+    writer.write_all(&message.encode()).await.unwrap();
+}
+
+fn execution_report(request: &FixMessage, order_id: &str, status: &str) -> FixMessage {
+    let mut report = FixMessage::new(msg_type::EXECUTION_REPORT)
+        .with_field(tags::ORDER_ID, order_id)
+        .with_field(tags::CL_ORD_ID, request.get(tags::CL_ORD_ID).unwrap())
+        .with_field(
+            tags::EXEC_ID,
+            format!("EX-{}", Utc::now().timestamp_nanos_opt().unwrap()),
+        )
+        .with_field(tags::EXEC_TYPE, status)
+        .with_field(tags::ORD_STATUS, status)
+        .with_field(tags::SYMBOL, request.get(tags::SYMBOL).unwrap())
+        .with_field(tags::SIDE, request.get(tags::SIDE).unwrap())
+        .with_field(tags::ORDER_QTY, request.get(tags::ORDER_QTY).unwrap())
+        .with_field(tags::CUM_QTY, 0)
+        .with_field(tags::LEAVES_QTY, request.get(tags::ORDER_QTY).unwrap())
+        .with_field(tags::AVG_PX, 0)
+        .with_field(tags::TRANSACT_TIME, format_timestamp(Utc::now()));
+    if let Some(price) = request.get(tags::PRICE) {
+        report.set_field(tags::PRICE, price);
+    }
+    if let Some(orig) = request.get(tags::ORIG_CL_ORD_ID) {
+        report.set_field(tags::ORIG_CL_ORD_ID, orig);
+    }
+    report
+}
+
+fn respond(message: &FixMessage, state: &AcceptorState) -> Vec<FixMessage> {
+    match message.msg_type() {
+        msg_type::LOGON => {
+            if message.get(tags::RESET_SEQ_NUM_FLAG) == Some("Y") {
+                *state.next_seq.lock().unwrap() = 1;
+            }
+            vec![FixMessage::new(msg_type::LOGON)
+                .with_field(tags::ENCRYPT_METHOD, 0)
+                .with_field(tags::HEART_BT_INT, message.get(tags::HEART_BT_INT).unwrap())]
+        }
+        msg_type::TEST_REQUEST => vec![FixMessage::new(msg_type::HEARTBEAT)
+            .with_field(tags::TEST_REQ_ID, message.get(tags::TEST_REQ_ID).unwrap())],
+        msg_type::LOGOUT => vec![FixMessage::new(msg_type::LOGOUT)],
+        msg_type::RESEND_REQUEST => {
+            // 보관된 메시지가 없으므로 전 구간을 GapFill로 건너뜀
+            let begin = message.get_u64(tags::BEGIN_SEQ_NO).unwrap();
+            let next_seq = *state.next_seq.lock().unwrap();
+            vec![FixMessage::new(msg_type::SEQUENCE_RESET)
+                .with_field(tags::MSG_SEQ_NUM, begin)
+                .with_field(tags::POSS_DUP_FLAG, "Y")
+                .with_field(tags::GAP_FILL_FLAG, "Y")
+                .with_field(tags::NEW_SEQ_NO, next_seq)]
+        }
+        msg_type::NEW_ORDER_SINGLE => {
+            if message.get(tags::SYMBOL) == Some("BAD") {
+                return vec![execution_report(message, "NONE", "8")
+                    .with_field(tags::ORD_REJ_REASON, 1)
+                    .with_field(tags::TEXT, "unknown symbol")];
+            }
+            let order_id = {
+                let mut count = state.order_count.lock().unwrap();
+                *count += 1;
+                format!("BRK-{}", count)
+            };
+            state.orders.lock().unwrap().insert(
+                order_id.clone(),
+                (
+                    message.get(tags::CL_ORD_ID).unwrap().to_string(),
+                    message.get(tags::SYMBOL).unwrap().to_string(),
+                ),
+            );
+            vec![execution_report(message, &order_id, "0")]
+        }
+        msg_type::ORDER_CANCEL_REQUEST | msg_type::ORDER_CANCEL_REPLACE_REQUEST => {
+            let order_id = message.get(tags::ORDER_ID).unwrap().to_string();
+            let is_cancel = message.msg_type() == msg_type::ORDER_CANCEL_REQUEST;
+            let mut orders = state.orders.lock().unwrap();
+            let known = orders.get(&order_id).cloned();
+            match known {
+                Some((cl_ord_id, symbol))
+                    if symbol != "LOCKED"
+                        && Some(cl_ord_id.as_str()) == message.get(tags::ORIG_CL_ORD_ID) =>
+                {
+                    if is_cancel {
+                        orders.remove(&order_id);
+                        vec![execution_report(message, &order_id, "4")]
+                    } else {
+                        let new_cl = message.get(tags::CL_ORD_ID).unwrap().to_string();
+                        orders.insert(order_id.clone(), (new_cl, symbol));
+                        vec![execution_report(message, &order_id, "5")
+                            .with_field(tags::EXEC_TYPE, "5")
+                            .with_field(tags::ORD_STATUS, "0")]
+                    }
+                }
+                _ => vec![FixMessage::new(msg_type::ORDER_CANCEL_REJECT)
+                    .with_field(tags::ORDER_ID, &order_id)
+                    .with_field(tags::CL_ORD_ID, message.get(tags::CL_ORD_ID).unwrap())
+                    .with_field(
+                        tags::ORIG_CL_ORD_ID,
+                        message.get(tags::ORIG_CL_ORD_ID).unwrap(),
+                    )
+                    .with_field(tags::ORD_STATUS, "0")
+                    .with_field(tags::CXL_REJ_RESPONSE_TO, if is_cancel { "1" } else { "2" })
+                    .with_field(tags::TEXT, "order locked")],
+            }
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn limit_buy(ticker: &str) -> OrderRequest {
+    OrderRequest::limit_buy(ticker.to_string(), dec!(100), dec!(189.5))
+}
+
+#[tokio::test]
+async fn test_logon_and_order_lifecycle() {
+    let acceptor = Acceptor::spawn().await;
+    let client = Arc::new(FixClient::connect(acceptor.config()).await.unwrap());
+    let provider = FixExchangeProvider::new(Arc::clone(&client));
+    let mut reports = provider.subscribe_execution_reports();
+
+    assert!(client.is_logged_on());
+    assert_eq!(provider.exchange_name(), "prime");
+    let logon = &acceptor.received(msg_type::LOGON)[0];
+    assert_eq!(logon.seq_num(), Some(1));
+    assert_eq!(logon.get(tags::HEART_BT_INT), Some("30"));
+
+    // NewOrderSingle → ExecutionReport(New)
+    let placed = provider.place_order(&limit_buy("AAPL")).await.unwrap();
+    assert_eq!(placed.order_no, "BRK-1");
+    let status = reports.recv().await.unwrap();
+    assert_eq!(status.order_id, "BRK-1");
+    assert_eq!(status.status, OrderStatusType::Open);
+    assert_eq!(status.price, Some(dec!(189.5)));
+
+    let new_order = &acceptor.received(msg_type::NEW_ORDER_SINGLE)[0];
+    assert_eq!(new_order.get(tags::SIDE), Some("1"));
+    assert_eq!(new_order.get(tags::ORD_TYPE), Some("2"));
+    assert_eq!(new_order.get(tags::ORDER_QTY), Some("100"));
+    assert_eq!(new_order.get(tags::PRICE), Some("189.5"));
+    assert_eq!(new_order.get(tags::TIME_IN_FORCE), Some("1"));
+
+    // Replace → ExecutionReport(Replaced), ClOrdID 체인 유지
+    let replaced = provider
+        .modify_order("BRK-1", "AAPL", None, Some(dec!(190)))
+        .await
+        .unwrap();
+    assert_eq!(replaced.order_no, "BRK-1");
+    assert_eq!(reports.recv().await.unwrap().price, Some(dec!(190)));
+
+    let replace = &acceptor.received(msg_type::ORDER_CANCEL_REPLACE_REQUEST)[0];
+    assert_eq!(
+        replace.get(tags::ORIG_CL_ORD_ID),
+        new_order.get(tags::CL_ORD_ID)
+    );
+    assert_eq!(replace.get(tags::ORDER_QTY), Some("100"));
+
+    // Cancel → ExecutionReport(Canceled)
+    provider.cancel_order("BRK-1", "AAPL").await.unwrap();
+    assert_eq!(
+        reports.recv().await.unwrap().status,
+        OrderStatusType::Cancelled
+    );
+
+    let cancel = &acceptor.received(msg_type::ORDER_CANCEL_REQUEST)[0];
+    assert_eq!(
+        cancel.get(tags::ORIG_CL_ORD_ID),
+        replace.get(tags::CL_ORD_ID)
+    );
+
+    // 취소 완료된 주문은 더 이상 추적하지 않음
+    assert!(matches!(
+        provider.cancel_order("BRK-1", "AAPL").await,
+        Err(ProviderError::Api(_))
+    ));
+
+    // Logon(1), D(2), G(3), F(4) 이후 Logout(5)
+    client.logout().await.unwrap();
+    assert!(!client.is_logged_on());
+    assert_eq!(
+        acceptor.wait_for(msg_type::LOGOUT, 1).await[0].seq_num(),
+        Some(5)
+    );
+}
+
+#[tokio::test]
+async fn test_rejections_map_to_errors() {
+    let acceptor = Acceptor::spawn().await;
+    let client = Arc::new(FixClient::connect(acceptor.config()).await.unwrap());
+    let provider = FixExchangeProvider::new(Arc::clone(&client));
+
+    // 주문 거부 (OrdStatus=8)
+    let err = provider.place_order(&limit_buy("BAD")).await.unwrap_err();
+    assert!(matches!(err, ProviderError::Api(msg) if msg.contains("unknown symbol")));
+
+    // 취소/정정 거부 (OrderCancelReject)
+    let placed = provider.place_order(&limit_buy("LOCKED")).await.unwrap();
+    let err = provider
+        .cancel_order(&placed.order_no, "LOCKED")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ProviderError::Api(msg) if msg.contains("order locked")));
+    let err = provider
+        .modify_order(&placed.order_no, "LOCKED", Some(dec!(50)), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ProviderError::Api(msg) if msg.contains("정정")));
+
+    // 지정가 주문에 가격 누락
+    let mut no_price = limit_buy("AAPL");
+    no_price.price = None;
+    assert!(provider.place_order(&no_price).await.is_err());
+
+    // FIX 표준에 없는 주문 유형
+    let mut trailing = limit_buy("AAPL");
+    trailing.order_type = OrderType::TrailingStop;
+    assert!(matches!(
+        provider.place_order(&trailing).await,
+        Err(ProviderError::Unsupported(_))
+    ));
+
+    // 거부된 요청은 송신되지 않음: Logon + D(BAD) + D(LOCKED) + F + G
+    assert_eq!(client.session().next_sender_seq(), 6);
+}
+
+#[tokio::test]
+async fn test_sequence_numbers_persist_across_reconnect() {
+    let acceptor = Acceptor::spawn().await;
+    let path = std::env::temp_dir().join(format!("fix_gateway_{}.json", uuid::Uuid::new_v4()));
+    let config = acceptor.config().with_seq_store_path(&path);
+
+    let client = FixClient::connect(config.clone()).await.unwrap();
+    client.place_order(&limit_buy("AAPL")).await.unwrap();
+    client.logout().await.unwrap();
+    let (sender_seq, target_seq) = (
+        client.session().next_sender_seq(),
+        client.session().next_target_seq(),
+    );
+    drop(client);
+    assert_eq!(sender_seq, 4);
+
+    // 재접속 시 저장된 시퀀스 번호로 로그온
+    let client = FixClient::connect(config).await.unwrap();
+    let logons = acceptor.wait_for(msg_type::LOGON, 2).await;
+    assert_eq!(logons[1].seq_num(), Some(sender_seq));
+    assert_eq!(client.session().next_target_seq(), target_seq + 1);
+    assert!(acceptor.received(msg_type::RESEND_REQUEST).is_empty());
+
+    // 초기화 로그온은 1부터 다시 시작
+    client.logout().await.unwrap();
+    drop(client);
+    let client = FixClient::connect(
+        acceptor
+            .config()
+            .with_seq_store_path(&path)
+            .with_reset_on_logon(true),
+    )
+    .await
+    .unwrap();
+    let logons = acceptor.wait_for(msg_type::LOGON, 3).await;
+    assert_eq!(logons[2].seq_num(), Some(1));
+    assert_eq!(logons[2].get(tags::RESET_SEQ_NUM_FLAG), Some("Y"));
+    assert_eq!(client.session().next_target_seq(), 2);
+
+    client.logout().await.unwrap();
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_resend_request_replays_application_messages() {
+    let acceptor = Acceptor::spawn().await;
+    let client = FixClient::connect(acceptor.config()).await.unwrap();
+    client.place_order(&limit_buy("AAPL")).await.unwrap();
+
+    // Logon(1)은 GapFill, NewOrderSingle(2)은 PossDup 재전송
+    acceptor
+        .inject
+        .send(
+            FixMessage::new(msg_type::RESEND_REQUEST)
+                .with_field(tags::BEGIN_SEQ_NO, 1)
+                .with_field(tags::END_SEQ_NO, 0),
+        )
+        .unwrap();
+
+    let gap_fill = &acceptor.wait_for(msg_type::SEQUENCE_RESET, 1).await[0];
+    assert_eq!(gap_fill.seq_num(), Some(1));
+    assert_eq!(gap_fill.get(tags::GAP_FILL_FLAG), Some("Y"));
+    assert_eq!(gap_fill.get_u64(tags::NEW_SEQ_NO), Some(2));
+
+    let orders = acceptor.wait_for(msg_type::NEW_ORDER_SINGLE, 2).await;
+    assert_eq!(orders[1].seq_num(), Some(2));
+    assert!(orders[1].is_poss_dup());
+    assert_eq!(
+        orders[1].get(tags::CL_ORD_ID),
+        orders[0].get(tags::CL_ORD_ID)
+    );
+    assert_eq!(
+        orders[1].get(tags::ORIG_SENDING_TIME),
+        orders[0].get(tags::SENDING_TIME)
+    );
+
+    // 재전송은 새 시퀀스 번호를 소비하지 않음
+    assert_eq!(client.session().next_sender_seq(), 3);
+}
+
+#[tokio::test]
+async fn test_inbound_gap_triggers_resend_request() {
+    let acceptor = Acceptor::spawn().await;
+    let client = FixClient::connect(acceptor.config()).await.unwrap();
+    let expected = client.session().next_target_seq();
+
+    // 시퀀스 번호를 5 건너뛴 Heartbeat 주입
+    acceptor
+        .inject
+        .send(FixMessage::new(msg_type::HEARTBEAT).with_field(tags::MSG_SEQ_NUM, expected + 5))
+        .unwrap();
+
+    let resend = &acceptor.wait_for(msg_type::RESEND_REQUEST, 1).await[0];
+    assert_eq!(resend.get_u64(tags::BEGIN_SEQ_NO), Some(expected));
+    assert_eq!(resend.get_u64(tags::END_SEQ_NO), Some(0));
+
+    // Acceptor의 GapFill 이후 세션이 정상 동작
+    let placed = client.place_order(&limit_buy("AAPL")).await.unwrap();
+    assert_eq!(placed.order_no, "BRK-1");
+    assert_eq!(client.session().next_target_seq(), expected + 7);
+    assert!(client.is_logged_on());
+}
+
+#[tokio::test]
+async fn test_resend_request_with_gap_is_serviced_before_gap_recovery() {
+    let acceptor = Acceptor::spawn().await;
+    let client = FixClient::connect(acceptor.config()).await.unwrap();
+    client.place_order(&limit_buy("AAPL")).await.unwrap();
+    let expected = client.session().next_target_seq();
+
+    // 시퀀스 갭이 있는 ResendRequest: 재전송 후 갭 복구 요청
+    acceptor
+        .inject
+        .send(
+            FixMessage::new(msg_type::RESEND_REQUEST)
+                .with_field(tags::MSG_SEQ_NUM, expected + 3)
+                .with_field(tags::BEGIN_SEQ_NO, 2)
+                .with_field(tags::END_SEQ_NO, 0),
+        )
+        .unwrap();
+
+    let orders = acceptor.wait_for(msg_type::NEW_ORDER_SINGLE, 2).await;
+    assert!(orders[1].is_poss_dup());
+    let resend = &acceptor.wait_for(msg_type::RESEND_REQUEST, 1).await[0];
+    assert_eq!(resend.get_u64(tags::BEGIN_SEQ_NO), Some(expected));
+
+    let received = acceptor.state.received.lock().unwrap().clone();
+    let replayed_at = received
+        .iter()
+        .position(|m| m.msg_type() == msg_type::NEW_ORDER_SINGLE && m.is_poss_dup())
+        .unwrap();
+    let requested_at = received
+        .iter()
+        .position(|m| m.msg_type() == msg_type::RESEND_REQUEST)
+        .unwrap();
+    assert!(replayed_at < requested_at);
+}
+
+#[tokio::test]
+async fn test_heartbeat_and_test_request() {
+    let acceptor = Acceptor::spawn().await;
+    let client = FixClient::connect(acceptor.config().with_heartbeat_interval(1))
+        .await
+        .unwrap();
+
+    // 상대방 TestRequest에 TestReqID를 담은 Heartbeat로 응답
+    acceptor
+        .inject
+        .send(FixMessage::new(msg_type::TEST_REQUEST).with_field(tags::TEST_REQ_ID, "PING"))
+        .unwrap();
+    let heartbeats = acceptor.wait_for(msg_type::HEARTBEAT, 1).await;
+    assert_eq!(heartbeats[0].get(tags::TEST_REQ_ID), Some("PING"));
+
+    // 송신이 없으면 하트비트 간격마다 Heartbeat 송신
+    let heartbeats = acceptor.wait_for(msg_type::HEARTBEAT, 2).await;
+    assert_eq!(heartbeats[1].get(tags::TEST_REQ_ID), None);
+    assert!(client.is_logged_on());
+}
@@ -0,0 +1,351 @@
+//! Order type × time-in-force conformance suite run against the simulation
+//! engines and the FIX connector talking to a matching-engine backed acceptor.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{tcp::OwnedWriteHalf, TcpListener, TcpStream},
+    sync::{broadcast, mpsc},
+};
+use trader_core::{
+    Kline, OrderExecutionProvider, OrderRequest, OrderStatus, OrderStatusType, OrderType,
+    ProviderError, Side, TimeInForce,
+};
+use trader_exchange::{
+    conformance::{
+        run_conformance, ConformanceCase, ConformanceOrderState, ConformanceVenue,
+        MatchingEngineVenue, MockOrderEngineVenue, CONFORMANCE_PARTICIPATION,
+    },
+    connector::fix::{
+        message::{format_timestamp, msg_type, tags},
+        FixClient, FixConfig, FixMessage,
+    },
+    simulated::{MatchingEngine, OrderMatch},
+    FixExchangeProvider,
+};
+
+#[tokio::test]
+async fn test_matching_engine_conformance() {
+    let mut venue = MatchingEngineVenue::new(CONFORMANCE_PARTICIPATION);
+    let report = run_conformance(&mut venue).await;
+    report.assert_passed();
+    assert!(report.skipped.is_empty());
+}
+
+#[tokio::test]
+async fn test_mock_order_engine_conformance() {
+    let mut venue = MockOrderEngineVenue::new(CONFORMANCE_PARTICIPATION);
+    let report = run_conformance(&mut venue).await;
+    report.assert_passed();
+    assert!(report.skipped.is_empty());
+}
+
+#[tokio::test]
+async fn test_fix_connector_conformance() {
+    let mut venue = FixVenue::spawn().await;
+    let report = run_conformance(&mut venue).await;
+    report.assert_passed();
+
+    // FIX 4.4 표준 OrdType이 없는 익절/트레일링 케이스만 건너뜀
+    assert!(!report.passed.is_empty());
+    assert!(report
+        .skipped
+        .iter()
+        .all(|name| name.starts_with("take_profit") || name.starts_with("trailing_stop")));
+}
+
+// ============================================================================
+// MatchingEngine 기반 FIX acceptor
+// ============================================================================
+
+/// 접수한 주문 정보.
+struct AcceptedOrder {
+    cl_ord_id: String,
+    symbol: String,
+    side: &'static str,
+    quantity: Decimal,
+}
+
+/// acceptor 주문장.
+struct Book {
+    engine: MatchingEngine,
+    last_bar: Option<Kline>,
+    orders: HashMap<String, AcceptedOrder>,
+}
+
+impl Book {
+    fn report(&self, order_match: &OrderMatch) -> FixMessage {
+        let order = &self.orders[&order_match.order_id];
+        let ord_status = match order_match.status {
+            OrderStatusType::PartiallyFilled => "1",
+            OrderStatusType::Filled => "2",
+            OrderStatusType::Expired => "C",
+            _ => "0",
+        };
+        let leaves = if order_match.status.is_final() {
+            Decimal::ZERO
+        } else {
+            order_match.remaining_quantity
+        };
+
+        FixMessage::new(msg_type::EXECUTION_REPORT)
+            .with_field(tags::ORDER_ID, &order_match.order_id)
+            .with_field(tags::CL_ORD_ID, &order.cl_ord_id)
+            .with_field(
+                tags::EXEC_ID,
+                format!("EX-{}", Utc::now().timestamp_nanos_opt().unwrap()),
+            )
+            .with_field(tags::EXEC_TYPE, ord_status)
+            .with_field(tags::ORD_STATUS, ord_status)
+            .with_field(tags::SYMBOL, &order.symbol)
+            .with_field(tags::SIDE, order.side)
+            .with_field(tags::ORDER_QTY, order.quantity)
+            .with_field(
+                tags::CUM_QTY,
+                order.quantity - order_match.remaining_quantity,
+            )
+            .with_field(tags::LEAVES_QTY, leaves)
+            .with_field(tags::LAST_QTY, order_match.filled_quantity)
+            .with_field(tags::LAST_PX, order_match.fill_price)
+            .with_field(tags::AVG_PX, order_match.fill_price)
+            .with_field(tags::TRANSACT_TIME, format_timestamp(order_match.timestamp))
+    }
+
+    fn new_order(&mut self, message: &FixMessage) -> FixMessage {
+        let side = match message.get(tags::SIDE) {
+            Some("2") => Side::Sell,
+            _ => Side::Buy,
+        };
+        let order_type = match message.get(tags::ORD_TYPE) {
+            Some("2") => OrderType::Limit,
+            Some("3") => OrderType::StopLoss,
+            Some("4") => OrderType::StopLossLimit,
+            _ => OrderType::Market,
+        };
+        let time_in_force = match message.get(tags::TIME_IN_FORCE) {
+            Some("3") => TimeInForce::IOC,
+            Some("4") => TimeInForce::FOK,
+            Some("6") => TimeInForce::GTD,
+            _ => TimeInForce::GTC,
+        };
+        let request = OrderRequest {
+            ticker: message.get(tags::SYMBOL).unwrap().to_string(),
+            side,
+            order_type,
+            quantity: message.get_decimal(tags::ORDER_QTY).unwrap(),
+            price: message.get_decimal(tags::PRICE),
+            stop_price: message.get_decimal(tags::STOP_PX),
+            time_in_force,
+            expire_time: message.get_timestamp(tags::EXPIRE_TIME),
+            trailing_percent: None,
+            client_order_id: None,
+            strategy_id: None,
+        };
+
+        let bar = self.last_bar.clone().expect("기준 봉 없음");
+        let order_match = self
+            .engine
+            .submit_order(&request, bar.close, bar.close_time);
+        self.orders.insert(
+            order_match.order_id.clone(),
+            AcceptedOrder {
+                cl_ord_id: message.get(tags::CL_ORD_ID).unwrap().to_string(),
+                symbol: request.ticker,
+                side: if side == Side::Buy { "1" } else { "2" },
+                quantity: request.quantity,
+            },
+        );
+        self.report(&order_match)
+    }
+}
+
+struct Acceptor {
+    port: u16,
+    book: Arc<Mutex<Book>>,
+    inject: mpsc::UnboundedSender<FixMessage>,
+}
+
+impl Acceptor {
+    async fn spawn() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let book = Arc::new(Mutex::new(Book {
+            engine: MatchingEngine::new(Decimal::ZERO, Decimal::ZERO)
+                .with_volume_participation(CONFORMANCE_PARTICIPATION),
+            last_bar: None,
+            orders: HashMap::new(),
+        }));
+        let (inject, inject_rx) = mpsc::unbounded_channel();
+
+        let accept_book = Arc::clone(&book);
+        tokio::spawn(async move {
+            if let Ok((stream, _)) = listener.accept().await {
+                handle_connection(stream, accept_book, inject_rx).await;
+            }
+        });
+
+        Self { port, book, inject }
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    book: Arc<Mutex<Book>>,
+    mut inject_rx: mpsc::UnboundedReceiver<FixMessage>,
+) {
+    let (mut reader, mut writer) = stream.into_split();
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let mut next_seq = 1u64;
+
+    loop {
+        tokio::select! {
+            read = reader.read(&mut chunk) => {
+                let n = match read {
+                    Ok(0) | Err(_) => return,
+                    Ok(n) => n,
+                };
+                buf.extend_from_slice(&chunk[..n]);
+                while let Some(len) = FixMessage::frame_len(&buf).unwrap() {
+                    let frame: Vec<u8> = buf.drain(..len).collect();
+                    let message = FixMessage::decode(&frame).unwrap();
+                    let replies = match message.msg_type() {
+                        msg_type::LOGON => vec![FixMessage::new(msg_type::LOGON)
+                            .with_field(tags::ENCRYPT_METHOD, 0)
+                            .with_field(tags::HEART_BT_INT, message.get(tags::HEART_BT_INT).unwrap())],
+                        msg_type::TEST_REQUEST => vec![FixMessage::new(msg_type::HEARTBEAT)
+                            .with_field(tags::TEST_REQ_ID, message.get(tags::TEST_REQ_ID).unwrap())],
+                        msg_type::NEW_ORDER_SINGLE => vec![book.lock().unwrap().new_order(&message)],
+                        msg_type::LOGOUT => return,
+                        _ => Vec::new(),
+                    };
+                    for reply in replies {
+                        send(&mut writer, reply, &mut next_seq).await;
+                    }
+                }
+            }
+            Some(message) = inject_rx.recv() => {
+                send(&mut writer, message, &mut next_seq).await;
+            }
+        }
+    }
+}
+
+async fn send(writer: &mut OwnedWriteHalf, mut message: FixMessage, next_seq: &mut u64) {
+    message.set_field(tags::SENDER_COMP_ID, "BROKER");
+    message.set_field(tags::TARGET_COMP_ID, "ZQ");
+    message.set_field(tags::MSG_SEQ_NUM, *next_seq);
+    message.set_field(tags::SENDING_TIME, format_timestamp(Utc::now()));
+    *next_seq += 1;
+    writer.write_all(&message.encode()).await.unwrap();
+}
+
+// ============================================================================
+// FIX 커넥터 적합성 거래소
+// ============================================================================
+
+/// `FixExchangeProvider`로 주문하고 ExecutionReport로 상태를 추적하는 거래소.
+struct FixVenue {
+    acceptor: Acceptor,
+    provider: FixExchangeProvider,
+    reports: broadcast::Receiver<OrderStatus>,
+    states: HashMap<String, ConformanceOrderState>,
+}
+
+impl FixVenue {
+    async fn spawn() -> Self {
+        let acceptor = Acceptor::spawn().await;
+        let config = FixConfig::new("127.0.0.1", acceptor.port, "ZQ", "BROKER")
+            .with_response_timeout_ms(2000);
+        let client = Arc::new(FixClient::connect(config).await.unwrap());
+        let provider = FixExchangeProvider::new(client);
+        let reports = provider.subscribe_execution_reports();
+
+        Self {
+            acceptor,
+            provider,
+            reports,
+            states: HashMap::new(),
+        }
+    }
+
+    fn apply(&mut self, status: OrderStatus) {
+        self.states.insert(
+            status.order_id,
+            ConformanceOrderState {
+                status: status.status,
+                filled_quantity: status.filled_quantity,
+            },
+        );
+    }
+
+    /// ExecutionReport `count`건을 수신해 반영합니다.
+    async fn receive(&mut self, count: usize) -> Result<(), ProviderError> {
+        for _ in 0..count {
+            let status = tokio::time::timeout(Duration::from_secs(2), self.reports.recv())
+                .await
+                .map_err(|_| ProviderError::Network("ExecutionReport 타임아웃".to_string()))?
+                .map_err(|e| ProviderError::Network(e.to_string()))?;
+            self.apply(status);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ConformanceVenue for FixVenue {
+    fn name(&self) -> &str {
+        "fix"
+    }
+
+    fn supports(&self, case: &ConformanceCase) -> bool {
+        !matches!(
+            case.request.order_type,
+            OrderType::TakeProfit | OrderType::TakeProfitLimit | OrderType::TrailingStop
+        )
+    }
+
+    async fn reset(&mut self) -> Result<(), ProviderError> {
+        let mut book = self.acceptor.book.lock().unwrap();
+        book.engine.clear();
+        book.last_bar = None;
+        book.orders.clear();
+        self.states.clear();
+        while self.reports.try_recv().is_ok() {}
+        Ok(())
+    }
+
+    async fn advance(&mut self, bar: &Kline) -> Result<(), ProviderError> {
+        let reports: Vec<FixMessage> = {
+            let mut book = self.acceptor.book.lock().unwrap();
+            let matches = book.engine.process_kline(&bar.ticker, bar);
+            book.last_bar = Some(bar.clone());
+            matches.iter().map(|m| book.report(m)).collect()
+        };
+
+        let count = reports.len();
+        for report in reports {
+            self.acceptor.inject.send(report).unwrap();
+        }
+        self.receive(count).await
+    }
+
+    async fn submit(&mut self, request: &OrderRequest) -> Result<String, ProviderError> {
+        let response = self.provider.place_order(request).await?;
+        // 첫 ExecutionReport는 응답 반환 전에 브로드캐스트됨
+        self.receive(1).await?;
+        Ok(response.order_no)
+    }
+
+    async fn order_state(&mut self, order_id: &str) -> Option<ConformanceOrderState> {
+        self.states.get(order_id).copied()
+    }
+}
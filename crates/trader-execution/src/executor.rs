@@ -520,6 +520,8 @@ impl SignalConverter {
             price,
             stop_price,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: Some(format!("sig_{}", signal.id)),
             strategy_id: Some(signal.strategy_id.clone()),
         };
@@ -662,7 +664,7 @@ impl OrderExecutor {
         // 리스크 관리자로 검증
         let mut risk_manager = self.risk_manager.write().await;
 
-        let validation =
+        let mut validation =
             match risk_manager.validate_order(&order_request, &positions, current_price) {
                 Ok(v) => v,
                 Err(e) => return ExecutionResult::failure(signal.id, e.to_string()),
@@ -679,6 +681,9 @@ impl OrderExecutor {
 
         drop(risk_manager);
 
+        // 통과했지만 수량이 조정된 경우 (이벤트 블랙아웃 축소 등) 조정된 주문 사용
+        let order_request = validation.modified_order.take().unwrap_or(order_request);
+
         // OrderRequest에서 Order를 생성하고 OrderManager에 등록
         let order = Order::from_request(order_request.clone(), &self.exchange);
         let order_id = order.id;
@@ -972,6 +977,11 @@ impl OrderExecutor {
         position_tracker.get_position_for_symbol(symbol).cloned()
     }
 
+    /// 리스크 관리자 참조 조회.
+    pub fn risk_manager(&self) -> &Arc<RwLock<RiskManager>> {
+        &self.risk_manager
+    }
+
     /// 주문 관리자 참조 조회.
     pub fn order_manager(&self) -> &Arc<RwLock<OrderManager>> {
         &self.order_manager
@@ -5,6 +5,7 @@
 //! - 주문 상태 관리 및 추적
 //! - PnL 계산을 포함한 포지션 추적
 //! - 오류 복구 및 재시도 로직
+//! - 크로스 거래소 스마트 주문 라우팅
 //!
 //! # 예제
 //!
@@ -24,6 +25,7 @@ pub mod order_manager;
 pub mod position_tracker;
 pub mod signal_processor;
 pub mod simulated_executor;
+pub mod smart_router;
 
 // 주요 타입 재내보내기
 pub use executor::{
@@ -35,8 +37,15 @@ pub use order_manager::{OrderEvent, OrderFill, OrderManager, OrderManagerError,
 pub use position_tracker::{PositionEvent, PositionTracker, PositionTrackerError};
 pub use signal_processor::{
     apply_slippage, build_add_trade, build_entry_trade, build_exit_trade, calculate_position_size,
-    calculate_realized_pnl, convert_signal_metadata, determine_close_quantity,
-    update_position_average, validate_funds, ProcessorConfig, ProcessorPosition, SignalProcessor,
-    SignalProcessorError, TradeResult,
+    calculate_realized_pnl, convert_signal_metadata, determine_close_quantity, is_covered_call,
+    leg_unwind_signal, signal_position_size, update_position_average, validate_funds,
+    validate_leg_group, ProcessorConfig, ProcessorPosition, SignalProcessor, SignalProcessorError,
+    TradeResult,
 };
 pub use simulated_executor::SimulatedExecutor;
+// 크로스 거래소 스마트 주문 라우팅
+pub use smart_router::{
+    InMemoryRoutingRecorder, QuoteConverter, RouterConfig, RouterVenue, RoutingError, RoutingPlan,
+    RoutingRecord, RoutingRecorder, SmartOrderRouter, VenueAllocation, VenueConfig, VenueFill,
+    VenueOrderResult, VenueQuote,
+};
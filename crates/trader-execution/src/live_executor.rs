@@ -24,7 +24,7 @@ use crate::{
     executor::{BracketOrderManager, ConversionConfig},
     signal_processor::{
         apply_slippage, build_add_trade, build_entry_trade, build_exit_trade,
-        calculate_position_size, calculate_realized_pnl, determine_close_quantity,
+        calculate_realized_pnl, determine_close_quantity, is_covered_call, signal_position_size,
         update_position_average, validate_funds, ProcessorConfig, ProcessorPosition,
         SignalProcessor, SignalProcessorError, TradeResult,
     },
@@ -193,6 +193,8 @@ impl LiveExecutor {
                 price: None,
                 stop_price: None,
                 time_in_force: TimeInForce::GTC,
+                expire_time: None,
+                trailing_percent: None,
                 client_order_id: Some(format!("close_all_{}", key)),
                 strategy_id: None,
             };
@@ -300,10 +302,10 @@ impl LiveExecutor {
 
         // 포지션 크기 계산 (공통 유틸리티)
         let price = signal.suggested_price.unwrap_or(current_price);
-        let (position_amount, quantity) = calculate_position_size(
+        let (position_amount, quantity) = signal_position_size(
+            signal,
             self.balance,
             self.config.max_position_size_pct,
-            signal.strength,
             price,
         );
 
@@ -327,6 +329,8 @@ impl LiveExecutor {
             },
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: Some(format!("sig_{}", signal.id)),
             strategy_id: Some(signal.strategy_id.clone()),
         };
@@ -407,10 +411,10 @@ impl LiveExecutor {
         let price = signal.suggested_price.unwrap_or(current_price);
 
         // 포지션 크기 계산 (공통 유틸리티)
-        let (position_amount, add_quantity) = calculate_position_size(
+        let (position_amount, add_quantity) = signal_position_size(
+            signal,
             self.balance,
             self.config.max_position_size_pct,
-            signal.strength,
             price,
         );
 
@@ -435,6 +439,8 @@ impl LiveExecutor {
             },
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: Some(format!("sig_add_{}", signal.id)),
             strategy_id: Some(signal.strategy_id.clone()),
         };
@@ -502,6 +508,8 @@ impl LiveExecutor {
             price: None,
             stop_price: None,
             time_in_force: TimeInForce::GTC,
+            expire_time: None,
+            trailing_percent: None,
             client_order_id: Some(format!("sig_exit_{}", signal.id)),
             strategy_id: Some(signal.strategy_id.clone()),
         };
@@ -593,6 +601,8 @@ impl LiveExecutor {
                 price: None,
                 stop_price: Some(sl_price),
                 time_in_force: TimeInForce::GTC,
+                expire_time: None,
+                trailing_percent: None,
                 client_order_id: Some(format!("sl_{}", signal.id)),
                 strategy_id: Some(signal.strategy_id.clone()),
             };
@@ -628,6 +638,8 @@ impl LiveExecutor {
                 price: Some(tp_price),
                 stop_price: None,
                 time_in_force: TimeInForce::GTC,
+                expire_time: None,
+                trailing_percent: None,
                 client_order_id: Some(format!("tp_{}", signal.id)),
                 strategy_id: Some(signal.strategy_id.clone()),
             };
@@ -675,7 +687,10 @@ impl SignalProcessor for LiveExecutor {
         match signal.signal_type {
             SignalType::Entry | SignalType::AddToPosition => {
                 // 숏 포지션 확인
-                if signal.side == Side::Sell && !self.config.allow_short {
+                if signal.side == Side::Sell
+                    && !self.config.allow_short
+                    && !is_covered_call(signal, &self.positions)
+                {
                     return Err(SignalProcessorError::ShortNotAllowed);
                 }
                 self.open_position_internal(signal, current_price, timestamp)
@@ -692,7 +707,10 @@ impl SignalProcessor for LiveExecutor {
                     self.close_position_internal(signal, current_price, timestamp)
                         .await
                 } else {
-                    if signal.side == Side::Sell && !self.config.allow_short {
+                    if signal.side == Side::Sell
+                        && !self.config.allow_short
+                        && !is_covered_call(signal, &self.positions)
+                    {
                         return Err(SignalProcessorError::ShortNotAllowed);
                     }
                     self.open_position_internal(signal, current_price, timestamp)
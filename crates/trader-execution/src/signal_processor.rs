@@ -40,7 +40,7 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use trader_core::{Side, Signal, SignalType};
+use trader_core::{OptionRight, Side, Signal, SignalType};
 
 /// Signal 처리 에러
 #[derive(Debug, Clone, Error)]
@@ -62,6 +62,14 @@ pub enum SignalProcessorError {
     ExchangeError(String),
     #[error("주문 실패: {0}")]
     OrderFailed(String),
+    #[error("레그 그룹 불완전 ({group_id}): 필요 {expected}, 수신 {received}")]
+    IncompleteLegGroup {
+        group_id: String,
+        expected: usize,
+        received: usize,
+    },
+    #[error("레그 주문 실패 ({ticker}): {reason}")]
+    LegRejected { ticker: String, reason: String },
 }
 
 /// 거래 결과
@@ -187,6 +195,80 @@ pub trait SignalProcessor: Send + Sync {
         timestamp: DateTime<Utc>,
     ) -> Result<Option<TradeResult>, SignalProcessorError>;
 
+    /// 다중 레그 신호 그룹 처리 (페어 트레이딩 등)
+    ///
+    /// 같은 group_id를 가진 레그들을 함께 처리합니다. 진입 레그 중 하나라도
+    /// 체결되지 않으면 이미 체결된 진입 레그를 반대 매매로 되돌리고
+    /// `LegRejected` 에러를 반환하여 한쪽 레그만 보유하는 상황을 막습니다.
+    ///
+    /// 각 레그는 `(신호, 현재가)` 쌍으로 전달합니다.
+    async fn process_signal_group(
+        &mut self,
+        legs: &[(Signal, Decimal)],
+        timestamp: DateTime<Utc>,
+    ) -> Result<Vec<TradeResult>, SignalProcessorError> {
+        validate_leg_group(legs)?;
+
+        // 숏 레그를 먼저 처리: 숏 제한으로 거부될 경우 되돌릴 레그가 없도록
+        let mut ordered: Vec<&(Signal, Decimal)> = legs.iter().collect();
+        ordered.sort_by_key(|(signal, _)| signal.side != Side::Sell);
+
+        let mut results = Vec::with_capacity(legs.len());
+        let mut filled_entries: Vec<(&Signal, Decimal)> = Vec::new();
+        let mut failure: Option<SignalProcessorError> = None;
+
+        for (signal, price) in ordered {
+            let opens = matches!(
+                signal.signal_type,
+                SignalType::Entry | SignalType::AddToPosition
+            );
+            match self.process_signal(signal, *price, timestamp).await {
+                Ok(Some(trade)) => {
+                    if opens {
+                        filled_entries.push((signal, *price));
+                    }
+                    results.push(trade);
+                }
+                // 이미 청산된 레그는 무시
+                Ok(None) if !opens => {}
+                Ok(None) => {
+                    failure = Some(SignalProcessorError::LegRejected {
+                        ticker: signal.ticker.clone(),
+                        reason: "체결되지 않음".to_string(),
+                    });
+                }
+                Err(e) => {
+                    failure = Some(SignalProcessorError::LegRejected {
+                        ticker: signal.ticker.clone(),
+                        reason: e.to_string(),
+                    });
+                }
+            }
+
+            // 진입 실패 시 즉시 중단, 청산 실패는 나머지 레그 청산을 계속 시도
+            if failure.is_some() && opens {
+                break;
+            }
+        }
+
+        let Some(error) = failure else {
+            return Ok(results);
+        };
+
+        for (leg, price) in filled_entries.into_iter().rev() {
+            let unwind = leg_unwind_signal(leg, timestamp);
+            if let Err(e) = self.process_signal(&unwind, price, timestamp).await {
+                tracing::warn!(
+                    ticker = %leg.ticker,
+                    error = %e,
+                    "레그 되돌림 실패 - 수동 확인 필요"
+                );
+            }
+        }
+
+        Err(error)
+    }
+
     /// 현재 잔고 조회
     fn balance(&self) -> Decimal;
 
@@ -294,6 +376,43 @@ pub trait SignalProcessor: Send + Sync {
     }
 }
 
+/// 레그 그룹 완전성 검증
+///
+/// 모든 레그가 같은 group_id를 가지며, 레그 수가 `leg_count`와 일치해야 합니다.
+pub fn validate_leg_group(legs: &[(Signal, Decimal)]) -> Result<(), SignalProcessorError> {
+    let Some((first, _)) = legs.first() else {
+        return Ok(());
+    };
+    let group_id = first.group_id.clone().unwrap_or_default();
+    let expected = first.leg_count().unwrap_or(legs.len());
+
+    let consistent = legs
+        .iter()
+        .all(|(signal, _)| signal.group_id.as_deref() == first.group_id.as_deref());
+    if !consistent || expected != legs.len() {
+        return Err(SignalProcessorError::IncompleteLegGroup {
+            group_id,
+            expected,
+            received: legs.len(),
+        });
+    }
+    Ok(())
+}
+
+/// 체결된 진입 레그를 되돌리는 청산 신호 생성
+pub fn leg_unwind_signal(leg: &Signal, timestamp: DateTime<Utc>) -> Signal {
+    let mut unwind = Signal::exit(
+        leg.strategy_id.clone(),
+        leg.ticker.clone(),
+        leg.side.opposite(),
+    )
+    .with_metadata("reason", serde_json::json!("leg_unwind"));
+    unwind.position_id = leg.position_id.clone();
+    unwind.group_id = leg.group_id.clone();
+    unwind.timestamp = timestamp;
+    unwind
+}
+
 /// 슬리피지 적용 가격 계산
 pub fn apply_slippage(price: Decimal, slippage_rate: Decimal, side: Side) -> Decimal {
     let slippage = price * slippage_rate;
@@ -324,6 +443,48 @@ pub fn calculate_position_size(
     (position_amount, quantity)
 }
 
+/// 신호 기준 포지션 크기 계산.
+///
+/// 고정 수량(`Signal::fixed_quantity`)이 지정된 신호(옵션 등)는 그 수량을 사용하고,
+/// 나머지는 [`calculate_position_size`]로 계산합니다.
+///
+/// # Returns
+/// `(position_amount, quantity)` - 포지션 금액과 주문 수량
+pub fn signal_position_size(
+    signal: &Signal,
+    balance: Decimal,
+    max_position_size_pct: Decimal,
+    price: Decimal,
+) -> (Decimal, Decimal) {
+    match signal.fixed_quantity() {
+        Some(quantity) => (price * quantity, quantity),
+        None => calculate_position_size(balance, max_position_size_pct, signal.strength, price),
+    }
+}
+
+/// 커버드 콜 여부.
+///
+/// 콜 옵션 매도 신호의 수량이 기초자산 롱 보유 수량 이내이면 true입니다.
+/// 숏 포지션이 비허용이어도 커버드 콜은 허용됩니다.
+pub fn is_covered_call(signal: &Signal, positions: &HashMap<String, ProcessorPosition>) -> bool {
+    let Some(contract) = signal.option_contract() else {
+        return false;
+    };
+    let Some(quantity) = signal.fixed_quantity() else {
+        return false;
+    };
+    if signal.side != Side::Sell || contract.right != OptionRight::Call {
+        return false;
+    }
+
+    let held: Decimal = positions
+        .values()
+        .filter(|p| p.side == Side::Buy && p.symbol == contract.underlying)
+        .map(|p| p.quantity)
+        .sum();
+    held >= quantity
+}
+
 /// 자금 검증.
 ///
 /// 주문에 필요한 금액(포지션 금액 + 수수료)이 잔고를 초과하는지 확인합니다.
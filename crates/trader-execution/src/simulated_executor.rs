@@ -11,9 +11,10 @@ use rust_decimal::Decimal;
 use trader_core::{Side, Signal, SignalType};
 
 use crate::signal_processor::{
-    apply_slippage, build_add_trade, build_entry_trade, build_exit_trade, calculate_position_size,
-    calculate_realized_pnl, determine_close_quantity, update_position_average, validate_funds,
-    ProcessorConfig, ProcessorPosition, SignalProcessor, SignalProcessorError, TradeResult,
+    apply_slippage, build_add_trade, build_entry_trade, build_exit_trade, calculate_realized_pnl,
+    determine_close_quantity, is_covered_call, signal_position_size, update_position_average,
+    validate_funds, ProcessorConfig, ProcessorPosition, SignalProcessor, SignalProcessorError,
+    TradeResult,
 };
 
 /// 브라켓 주문 시뮬레이션 정보.
@@ -221,10 +222,10 @@ impl SimulatedExecutor {
         }
 
         // 포지션 크기 계산 (공통 유틸리티)
-        let (position_amount, quantity) = calculate_position_size(
+        let (position_amount, quantity) = signal_position_size(
+            signal,
             self.balance,
             self.config.max_position_size_pct,
-            signal.strength,
             execution_price,
         );
 
@@ -311,10 +312,10 @@ impl SimulatedExecutor {
         let key = signal.position_key();
 
         // 포지션 크기 계산 (공통 유틸리티)
-        let (position_amount, add_quantity) = calculate_position_size(
+        let (position_amount, add_quantity) = signal_position_size(
+            signal,
             self.balance,
             self.config.max_position_size_pct,
-            signal.strength,
             execution_price,
         );
 
@@ -480,7 +481,10 @@ impl SignalProcessor for SimulatedExecutor {
         match signal.signal_type {
             SignalType::Entry | SignalType::AddToPosition => {
                 // 숏 포지션 확인
-                if signal.side == Side::Sell && !self.config.allow_short {
+                if signal.side == Side::Sell
+                    && !self.config.allow_short
+                    && !is_covered_call(signal, &self.positions)
+                {
                     return Err(SignalProcessorError::ShortNotAllowed);
                 }
                 self.open_position_internal(signal, current_price, timestamp)
@@ -494,7 +498,10 @@ impl SignalProcessor for SimulatedExecutor {
                 if self.positions.contains_key(&key) {
                     self.close_position_internal(signal, current_price, timestamp)
                 } else {
-                    if signal.side == Side::Sell && !self.config.allow_short {
+                    if signal.side == Side::Sell
+                        && !self.config.allow_short
+                        && !is_covered_call(signal, &self.positions)
+                    {
                         return Err(SignalProcessorError::ShortNotAllowed);
                     }
                     self.open_position_internal(signal, current_price, timestamp)
@@ -652,6 +659,144 @@ mod tests {
         assert!(matches!(result, Err(SignalProcessorError::ShortNotAllowed)));
     }
 
+    #[tokio::test]
+    async fn test_covered_call_allowed_without_short() {
+        use trader_core::OptionContract;
+
+        let config = ProcessorConfig {
+            allow_short: false,
+            ..Default::default()
+        };
+        let mut executor = SimulatedExecutor::new(config, dec!(10_000_000));
+        let expiry = chrono::NaiveDate::from_ymd_opt(2026, 11, 20).unwrap();
+        let contract = OptionContract::call("SPY", dec!(450), expiry).with_multiplier(dec!(100));
+        let short_call = |contracts: Decimal| {
+            create_test_signal(&contract.ticker(), Side::Sell, SignalType::Entry)
+                .with_option_contract(&contract)
+                .with_fixed_quantity(contracts * contract.multiplier)
+        };
+
+        // 기초자산 미보유 → 네이키드 콜 거부
+        let result = executor
+            .process_signal(&short_call(dec!(1)), dec!(5), Utc::now())
+            .await;
+        assert!(matches!(result, Err(SignalProcessorError::ShortNotAllowed)));
+
+        // 기초자산 200주 보유 → 고정 수량으로 진입
+        let stock =
+            create_test_signal("SPY", Side::Buy, SignalType::Entry).with_fixed_quantity(dec!(200));
+        let trade = executor
+            .process_signal(&stock, dec!(440), Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(trade.quantity, dec!(200));
+
+        // 보유 수량 초과 → 거부, 이내 → 허용
+        let result = executor
+            .process_signal(&short_call(dec!(3)), dec!(5), Utc::now())
+            .await;
+        assert!(matches!(result, Err(SignalProcessorError::ShortNotAllowed)));
+        let trade = executor
+            .process_signal(&short_call(dec!(2)), dec!(5), Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(trade.quantity, dec!(200));
+        assert_eq!(executor.positions().len(), 2);
+    }
+
+    fn pair_legs(signal_type: SignalType) -> Vec<(Signal, Decimal)> {
+        let long = Signal::new("pairs", "KO".to_string(), Side::Buy, signal_type)
+            .with_strength(0.5)
+            .with_legs("pair-1", 2);
+        let short = Signal::new("pairs", "PEP".to_string(), Side::Sell, signal_type)
+            .with_strength(0.5)
+            .with_legs("pair-1", 2);
+        vec![(long, dec!(60)), (short, dec!(170))]
+    }
+
+    #[tokio::test]
+    async fn test_signal_group_enters_and_exits_together() {
+        let config = ProcessorConfig {
+            allow_short: true,
+            ..Default::default()
+        };
+        let mut executor = SimulatedExecutor::new(config, dec!(10_000_000));
+
+        let trades = executor
+            .process_signal_group(&pair_legs(SignalType::Entry), Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(executor.positions_by_group("pair-1").len(), 2);
+
+        let mut exits = pair_legs(SignalType::Exit);
+        for (signal, _) in exits.iter_mut() {
+            signal.side = signal.side.opposite();
+        }
+        let trades = executor
+            .process_signal_group(&exits, Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(trades.len(), 2);
+        assert!(executor.positions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_signal_group_rejected_without_partial_fill() {
+        // 숏 비허용: 숏 레그가 먼저 거부되어 롱 레그는 체결되지 않음
+        let mut executor = SimulatedExecutor::new(ProcessorConfig::default(), dec!(10_000_000));
+        let result = executor
+            .process_signal_group(&pair_legs(SignalType::Entry), Utc::now())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(SignalProcessorError::LegRejected { .. })
+        ));
+        assert!(executor.positions().is_empty());
+        assert!(executor.trades().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_signal_group_unwinds_filled_leg() {
+        // 최대 포지션 1개: 두 번째 레그 실패 시 첫 레그를 되돌림
+        let config = ProcessorConfig {
+            allow_short: true,
+            max_positions: 1,
+            ..Default::default()
+        };
+        let mut executor = SimulatedExecutor::new(config, dec!(10_000_000));
+        let result = executor
+            .process_signal_group(&pair_legs(SignalType::Entry), Utc::now())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(SignalProcessorError::LegRejected { .. })
+        ));
+        assert!(executor.positions().is_empty());
+        // 진입 + 되돌림 청산
+        assert_eq!(executor.trades().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_signal_group_incomplete() {
+        let mut executor = SimulatedExecutor::new(ProcessorConfig::default(), dec!(10_000_000));
+        let legs = pair_legs(SignalType::Entry);
+        let result = executor.process_signal_group(&legs[..1], Utc::now()).await;
+
+        assert!(matches!(
+            result,
+            Err(SignalProcessorError::IncompleteLegGroup {
+                expected: 2,
+                received: 1,
+                ..
+            })
+        ));
+    }
+
     #[tokio::test]
     async fn test_total_equity() {
         let config = ProcessorConfig::default();
@@ -0,0 +1,1468 @@
+//! 크로스 거래소 스마트 주문 라우터.
+//!
+//! 여러 거래소(Upbit, Bithumb, Binance 등)의 호가와 수수료를 비교하여
+//! 주문을 가장 유리한 거래소들로 분할합니다.
+//!
+//! # 동작 방식
+//!
+//! 1. 각 거래소의 `MarketDataProvider`에서 호가창을 동시에 조회
+//! 2. 호가를 기준 통화(예: KRW)로 환산하고 수수료를 반영한 실효 가격 계산
+//! 3. 모든 거래소의 호가 레벨을 실효 가격 순으로 병합하여 수량을 채움
+//! 4. 거래소별 배분 결과를 IOC 지정가 주문으로 제출 (IOC 미지원 거래소의 미체결 잔량은 취소)
+//! 5. 라우팅 결정과 실현 체결가를 `RoutingRecorder`에 기록
+//!
+//! 호가창을 제공하지 않는 거래소는 현재가를 비교용 시세로만 기록하고
+//! (`RouterConfig::use_quote_fallback`), 잔량을 알 수 없으므로 유동성 0으로 취급합니다.
+//! 최소 배분 수량에 못 미치는 거래소는 제외하고 부족분을 다른 거래소에 다시 배분합니다.
+//!
+//! # 사용 예시
+//!
+//! ```ignore
+//! let router = SmartOrderRouter::new(RouterConfig::default(), QuoteConverter::new("KRW"))
+//!     // 계획마다 Upbit KRW-USDT 현재가로 USDT 환산율 갱신
+//!     .with_rate_source("USDT", upbit_provider.clone(), "KRW-USDT")
+//!     .with_venue(RouterVenue::new(
+//!         VenueConfig::new("upbit", "KRW-{base}", "KRW", dec!(0.0005)),
+//!         upbit_provider.clone(),
+//!     ).with_order_provider(upbit_provider))
+//!     .with_venue(RouterVenue::new(
+//!         VenueConfig::new("binance", "{base}USDT", "USDT", dec!(0.001)),
+//!         binance_provider,
+//!     ));
+//!
+//! let plan = router.plan("BTC", Side::Buy, dec!(0.5)).await?;
+//! let record = router.execute(&plan).await;
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+use trader_core::{
+    MarketDataProvider, OrderBookLevel, OrderExecutionProvider, OrderRequest, OrderType, Side,
+    TimeInForce,
+};
+use uuid::Uuid;
+
+/// 스마트 라우팅 오류.
+#[derive(Debug, Error)]
+pub enum RoutingError {
+    #[error("No venue configured")]
+    NoVenue,
+
+    #[error("Invalid quantity: {0}")]
+    InvalidQuantity(Decimal),
+
+    #[error("No liquidity available for {0}")]
+    NoLiquidity(String),
+
+    #[error("Missing FX rate: {0}")]
+    MissingRate(String),
+
+    #[error("Plan not found: {0}")]
+    PlanNotFound(Uuid),
+
+    #[error("Recorder error: {0}")]
+    Recorder(String),
+}
+
+// ==================== 설정 ====================
+
+/// 라우팅 대상 거래소 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueConfig {
+    /// 거래소 이름 (기록/로깅용)
+    pub name: String,
+    /// 거래소 심볼 템플릿 (`{base}`가 기초 자산으로 치환됨, 예: "KRW-{base}", "{base}USDT")
+    pub symbol_template: String,
+    /// 호가 통화 (예: "KRW", "USDT")
+    pub quote_currency: String,
+    /// 테이커 수수료율 (예: 0.0005 = 0.05%)
+    pub taker_fee_rate: Decimal,
+    /// 라우팅에 사용할 최대 호가 레벨 수
+    pub max_levels: usize,
+    /// 활성화 여부
+    pub enabled: bool,
+}
+
+impl VenueConfig {
+    /// 새 거래소 설정 생성.
+    pub fn new(
+        name: impl Into<String>,
+        symbol_template: impl Into<String>,
+        quote_currency: impl Into<String>,
+        taker_fee_rate: Decimal,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            symbol_template: symbol_template.into(),
+            quote_currency: quote_currency.into(),
+            taker_fee_rate,
+            max_levels: 15,
+            enabled: true,
+        }
+    }
+
+    /// 기초 자산에 대한 거래소 심볼 생성.
+    pub fn symbol_for(&self, base_asset: &str) -> String {
+        self.symbol_template.replace("{base}", base_asset)
+    }
+}
+
+/// 라우터 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouterConfig {
+    /// 거래소별 최소 배분 수량 (이보다 작으면 해당 거래소를 제외하고 재배분)
+    pub min_allocation_quantity: Decimal,
+    /// 호가창 미지원 거래소의 현재가를 비교용 시세로 기록 (배분 대상은 아님)
+    pub use_quote_fallback: bool,
+}
+
+impl Default for RouterConfig {
+    fn default() -> Self {
+        Self {
+            min_allocation_quantity: Decimal::ZERO,
+            use_quote_fallback: true,
+        }
+    }
+}
+
+// ==================== 환산 ====================
+
+/// 호가 통화 환산기.
+///
+/// 각 통화의 기준 통화 환산율을 보관합니다.
+/// 예: 기준 통화 KRW, USDT 환산율 1380 → 1 USDT = 1380 KRW.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteConverter {
+    base_currency: String,
+    rates: HashMap<String, Decimal>,
+}
+
+impl QuoteConverter {
+    /// 기준 통화로 생성.
+    pub fn new(base_currency: impl Into<String>) -> Self {
+        Self {
+            base_currency: base_currency.into(),
+            rates: HashMap::new(),
+        }
+    }
+
+    /// 환산율 추가 (빌더).
+    pub fn with_rate(mut self, currency: impl Into<String>, rate: Decimal) -> Self {
+        self.set_rate(currency, rate);
+        self
+    }
+
+    /// 환산율 갱신.
+    pub fn set_rate(&mut self, currency: impl Into<String>, rate: Decimal) {
+        self.rates.insert(currency.into(), rate);
+    }
+
+    /// 기준 통화.
+    pub fn base_currency(&self) -> &str {
+        &self.base_currency
+    }
+
+    /// 통화의 기준 통화 환산율.
+    pub fn rate(&self, currency: &str) -> Option<Decimal> {
+        if currency == self.base_currency {
+            Some(Decimal::ONE)
+        } else {
+            self.rates.get(currency).copied()
+        }
+    }
+
+    /// 금액을 기준 통화로 환산.
+    pub fn to_base(&self, amount: Decimal, currency: &str) -> Option<Decimal> {
+        self.rate(currency).map(|r| amount * r)
+    }
+
+    /// 기준 통화 금액을 지정 통화로 환산.
+    pub fn from_base(&self, amount: Decimal, currency: &str) -> Option<Decimal> {
+        self.rate(currency)
+            .filter(|r| !r.is_zero())
+            .map(|r| amount / r)
+    }
+}
+
+// ==================== 라우팅 결과 ====================
+
+/// 거래소별 호가 스냅샷 (라우팅 결정 근거).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueQuote {
+    /// 거래소 이름
+    pub venue: String,
+    /// 거래소 심볼
+    pub symbol: String,
+    /// 최우선 호가 (거래소 통화)
+    pub best_price: Option<Decimal>,
+    /// 최우선 호가 (기준 통화)
+    pub best_price_base: Option<Decimal>,
+    /// 수수료 반영 실효 가격 (기준 통화)
+    pub effective_price_base: Option<Decimal>,
+    /// 조회된 호가 잔량 합계 (현재가 대체 시 None)
+    pub available_quantity: Option<Decimal>,
+    /// 조회 실패 사유
+    pub error: Option<String>,
+}
+
+/// 거래소별 배분 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueAllocation {
+    /// 거래소 이름
+    pub venue: String,
+    /// 거래소 심볼
+    pub symbol: String,
+    /// 호가 통화
+    pub quote_currency: String,
+    /// 배분 수량
+    pub quantity: Decimal,
+    /// 예상 평균가 (거래소 통화)
+    pub expected_avg_price: Decimal,
+    /// 예상 평균가 (기준 통화)
+    pub expected_avg_price_base: Decimal,
+    /// 주문 지정가 (사용한 가장 불리한 호가, 거래소 통화)
+    pub limit_price: Decimal,
+    /// 예상 수수료 (기준 통화)
+    pub expected_fee_base: Decimal,
+}
+
+/// 라우팅 계획.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoutingPlan {
+    /// 계획 ID
+    pub id: Uuid,
+    /// 기초 자산 (예: "BTC")
+    pub base_asset: String,
+    /// 주문 방향
+    pub side: Side,
+    /// 요청 수량
+    pub requested_quantity: Decimal,
+    /// 기준 통화
+    pub base_currency: String,
+    /// 거래소별 배분
+    pub allocations: Vec<VenueAllocation>,
+    /// 유동성 부족으로 배분하지 못한 수량
+    pub unfilled_quantity: Decimal,
+    /// 거래소별 호가 스냅샷
+    pub venue_quotes: Vec<VenueQuote>,
+    /// 생성 시각
+    pub created_at: DateTime<Utc>,
+}
+
+impl RoutingPlan {
+    /// 배분된 총 수량.
+    pub fn allocated_quantity(&self) -> Decimal {
+        self.allocations.iter().map(|a| a.quantity).sum()
+    }
+
+    /// 예상 평균가 (기준 통화, 수수료 제외).
+    pub fn expected_avg_price_base(&self) -> Option<Decimal> {
+        let qty = self.allocated_quantity();
+        if qty.is_zero() {
+            return None;
+        }
+        let notional: Decimal = self
+            .allocations
+            .iter()
+            .map(|a| a.expected_avg_price_base * a.quantity)
+            .sum();
+        Some(notional / qty)
+    }
+
+    /// 예상 총 수수료 (기준 통화).
+    pub fn expected_fee_base(&self) -> Decimal {
+        self.allocations.iter().map(|a| a.expected_fee_base).sum()
+    }
+}
+
+/// 거래소 주문 제출 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueOrderResult {
+    /// 거래소 이름
+    pub venue: String,
+    /// 거래소 심볼
+    pub symbol: String,
+    /// 주문 수량
+    pub quantity: Decimal,
+    /// 지정가 (거래소 통화)
+    pub limit_price: Decimal,
+    /// 거래소 주문번호 (성공 시)
+    pub order_no: Option<String>,
+    /// 미체결 잔량 취소 여부 (IOC 미지원 거래소)
+    #[serde(default)]
+    pub remainder_cancelled: bool,
+    /// 실패 사유
+    pub error: Option<String>,
+}
+
+/// 실현 체결 내역.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueFill {
+    /// 거래소 이름
+    pub venue: String,
+    /// 체결 수량
+    pub quantity: Decimal,
+    /// 체결 평균가 (거래소 통화)
+    pub price: Decimal,
+    /// 체결 평균가 (기준 통화)
+    pub price_base: Decimal,
+    /// 체결 시각
+    pub filled_at: DateTime<Utc>,
+}
+
+/// 라우팅 기록 (결정 + 주문 + 실현 체결).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoutingRecord {
+    /// 라우팅 계획
+    pub plan: RoutingPlan,
+    /// 주문 제출 결과
+    pub orders: Vec<VenueOrderResult>,
+    /// 실현 체결 내역
+    pub fills: Vec<VenueFill>,
+}
+
+impl RoutingRecord {
+    /// 계획만으로 기록 생성.
+    pub fn new(plan: RoutingPlan) -> Self {
+        Self {
+            plan,
+            orders: Vec::new(),
+            fills: Vec::new(),
+        }
+    }
+
+    /// 총 체결 수량.
+    pub fn filled_quantity(&self) -> Decimal {
+        self.fills.iter().map(|f| f.quantity).sum()
+    }
+
+    /// 실현 평균가 (기준 통화).
+    pub fn realised_avg_price_base(&self) -> Option<Decimal> {
+        let qty = self.filled_quantity();
+        if qty.is_zero() {
+            return None;
+        }
+        let notional: Decimal = self.fills.iter().map(|f| f.price_base * f.quantity).sum();
+        Some(notional / qty)
+    }
+
+    /// 예상 대비 실현 슬리피지 (bps, 양수 = 불리).
+    pub fn slippage_bps(&self) -> Option<Decimal> {
+        let expected = self.plan.expected_avg_price_base()?;
+        let realised = self.realised_avg_price_base()?;
+        if expected.is_zero() {
+            return None;
+        }
+        let diff = match self.plan.side {
+            Side::Buy => realised - expected,
+            Side::Sell => expected - realised,
+        };
+        Some(diff / expected * Decimal::from(10_000))
+    }
+}
+
+// ==================== 기록 ====================
+
+/// 라우팅 결정/체결 기록 저장소.
+///
+/// 사후 분석(거래소별 실효 비용, 슬리피지)을 위해 라우팅 결과를 저장합니다.
+#[async_trait]
+pub trait RoutingRecorder: Send + Sync {
+    /// 라우팅 결정 및 주문 제출 결과 기록.
+    async fn record_decision(&self, record: &RoutingRecord) -> Result<(), RoutingError>;
+
+    /// 실현 체결 기록.
+    async fn record_fill(&self, plan_id: Uuid, fill: &VenueFill) -> Result<(), RoutingError>;
+}
+
+/// 메모리 기반 라우팅 기록 저장소.
+#[derive(Debug, Default)]
+pub struct InMemoryRoutingRecorder {
+    records: RwLock<Vec<RoutingRecord>>,
+}
+
+impl InMemoryRoutingRecorder {
+    /// 새 저장소 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 모든 기록 조회.
+    pub async fn records(&self) -> Vec<RoutingRecord> {
+        self.records.read().await.clone()
+    }
+
+    /// 계획 ID로 기록 조회.
+    pub async fn get(&self, plan_id: Uuid) -> Option<RoutingRecord> {
+        self.records
+            .read()
+            .await
+            .iter()
+            .find(|r| r.plan.id == plan_id)
+            .cloned()
+    }
+}
+
+#[async_trait]
+impl RoutingRecorder for InMemoryRoutingRecorder {
+    async fn record_decision(&self, record: &RoutingRecord) -> Result<(), RoutingError> {
+        let mut records = self.records.write().await;
+        match records.iter_mut().find(|r| r.plan.id == record.plan.id) {
+            Some(existing) => *existing = record.clone(),
+            None => records.push(record.clone()),
+        }
+        Ok(())
+    }
+
+    async fn record_fill(&self, plan_id: Uuid, fill: &VenueFill) -> Result<(), RoutingError> {
+        let mut records = self.records.write().await;
+        let record = records
+            .iter_mut()
+            .find(|r| r.plan.id == plan_id)
+            .ok_or(RoutingError::PlanNotFound(plan_id))?;
+        record.fills.push(fill.clone());
+        Ok(())
+    }
+}
+
+// ==================== 라우터 ====================
+
+/// 라우팅 대상 거래소 (설정 + 시세/주문 제공자).
+pub struct RouterVenue {
+    /// 거래소 설정
+    pub config: VenueConfig,
+    market_data: Arc<dyn MarketDataProvider>,
+    order_provider: Option<Arc<dyn OrderExecutionProvider>>,
+}
+
+impl RouterVenue {
+    /// 시세 제공자로 생성 (주문 제공자 없이 생성하면 비교 전용).
+    pub fn new(config: VenueConfig, market_data: Arc<dyn MarketDataProvider>) -> Self {
+        Self {
+            config,
+            market_data,
+            order_provider: None,
+        }
+    }
+
+    /// 주문 제공자 설정.
+    pub fn with_order_provider(mut self, provider: Arc<dyn OrderExecutionProvider>) -> Self {
+        self.order_provider = Some(provider);
+        self
+    }
+
+    /// 시세 제공자 (환산율 시세 소스 등으로 재사용).
+    pub fn market_data(&self) -> Arc<dyn MarketDataProvider> {
+        Arc::clone(&self.market_data)
+    }
+}
+
+/// 환산율 시세 소스 (예: Upbit "KRW-USDT" 현재가 → USDT 환산율).
+struct RateSource {
+    currency: String,
+    provider: Arc<dyn MarketDataProvider>,
+    symbol: String,
+}
+
+/// 병합 전 호가 레벨 (거래소 인덱스 포함).
+#[derive(Debug, Clone)]
+struct RoutableLevel {
+    venue_idx: usize,
+    price: Decimal,
+    effective_price_base: Decimal,
+    quantity: Decimal,
+}
+
+/// 실효 가격 순으로 정렬된 레벨에서 수량을 탐욕적으로 배분.
+///
+/// 제외된 거래소의 레벨은 건너뛰며, 거래소별 (가격, 수량) 목록과 미배분 수량을 반환합니다.
+fn allocate(
+    levels: &[RoutableLevel],
+    quantity: Decimal,
+    excluded: &HashSet<usize>,
+) -> (HashMap<usize, Vec<(Decimal, Decimal)>>, Decimal) {
+    let mut remaining = quantity;
+    let mut fills: HashMap<usize, Vec<(Decimal, Decimal)>> = HashMap::new();
+    for level in levels {
+        if remaining <= Decimal::ZERO {
+            break;
+        }
+        if excluded.contains(&level.venue_idx) {
+            continue;
+        }
+        let take = level.quantity.min(remaining);
+        if take <= Decimal::ZERO {
+            continue;
+        }
+        fills
+            .entry(level.venue_idx)
+            .or_default()
+            .push((level.price, take));
+        remaining -= take;
+    }
+    (fills, remaining)
+}
+
+/// 크로스 거래소 스마트 주문 라우터.
+pub struct SmartOrderRouter {
+    config: RouterConfig,
+    venues: Vec<RouterVenue>,
+    converter: RwLock<QuoteConverter>,
+    rate_sources: Vec<RateSource>,
+    recorder: Arc<dyn RoutingRecorder>,
+}
+
+impl SmartOrderRouter {
+    /// 새 라우터 생성 (메모리 기록 저장소 사용).
+    pub fn new(config: RouterConfig, converter: QuoteConverter) -> Self {
+        Self {
+            config,
+            venues: Vec::new(),
+            converter: RwLock::new(converter),
+            rate_sources: Vec::new(),
+            recorder: Arc::new(InMemoryRoutingRecorder::new()),
+        }
+    }
+
+    /// 거래소 추가.
+    pub fn with_venue(mut self, venue: RouterVenue) -> Self {
+        self.venues.push(venue);
+        self
+    }
+
+    /// 환산율 시세 소스 추가.
+    ///
+    /// 계획 수립 시마다 `symbol`의 현재가로 `currency` 환산율을 갱신합니다.
+    /// 조회에 실패하면 마지막 환산율을 유지합니다.
+    pub fn with_rate_source(
+        mut self,
+        currency: impl Into<String>,
+        provider: Arc<dyn MarketDataProvider>,
+        symbol: impl Into<String>,
+    ) -> Self {
+        self.rate_sources.push(RateSource {
+            currency: currency.into(),
+            provider,
+            symbol: symbol.into(),
+        });
+        self
+    }
+
+    /// 기록 저장소 설정.
+    pub fn with_recorder(mut self, recorder: Arc<dyn RoutingRecorder>) -> Self {
+        self.recorder = recorder;
+        self
+    }
+
+    /// 등록된 거래소 이름 목록.
+    pub fn venue_names(&self) -> Vec<&str> {
+        self.venues.iter().map(|v| v.config.name.as_str()).collect()
+    }
+
+    /// 환산율 갱신 (예: USDT/KRW 실시간 환율).
+    pub async fn set_rate(&self, currency: impl Into<String>, rate: Decimal) {
+        self.converter.write().await.set_rate(currency, rate);
+    }
+
+    /// 환산율 시세로 갱신.
+    ///
+    /// 호가 통화 자체가 상장된 거래소(예: Upbit "KRW-USDT")의 현재가를 환산율로 사용합니다.
+    pub async fn refresh_rate_from_quote(
+        &self,
+        currency: &str,
+        provider: &dyn MarketDataProvider,
+        symbol: &str,
+    ) -> Result<Decimal, RoutingError> {
+        let quote = provider
+            .get_quote(symbol)
+            .await
+            .map_err(|e| RoutingError::MissingRate(format!("{}: {}", currency, e)))?;
+        if quote.current_price <= Decimal::ZERO {
+            return Err(RoutingError::MissingRate(currency.to_string()));
+        }
+        self.set_rate(currency, quote.current_price).await;
+        Ok(quote.current_price)
+    }
+
+    /// 라우팅 계획 수립.
+    ///
+    /// 각 거래소의 호가를 조회하여 수수료 반영 실효 가격 순으로 수량을 배분합니다.
+    pub async fn plan(
+        &self,
+        base_asset: &str,
+        side: Side,
+        quantity: Decimal,
+    ) -> Result<RoutingPlan, RoutingError> {
+        if quantity <= Decimal::ZERO {
+            return Err(RoutingError::InvalidQuantity(quantity));
+        }
+
+        let active: Vec<(usize, &RouterVenue)> = self
+            .venues
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.config.enabled)
+            .collect();
+        if active.is_empty() {
+            return Err(RoutingError::NoVenue);
+        }
+
+        for source in &self.rate_sources {
+            if let Err(e) = self
+                .refresh_rate_from_quote(&source.currency, source.provider.as_ref(), &source.symbol)
+                .await
+            {
+                warn!(currency = %source.currency, error = %e, "환산율 갱신 실패, 마지막 값 유지");
+            }
+        }
+        let converter = self.converter.read().await.clone();
+
+        // 1. 호가 동시 조회
+        let fetches = active.iter().map(|(idx, venue)| {
+            let symbol = venue.config.symbol_for(base_asset);
+            async move {
+                let levels = self.fetch_levels(venue, &symbol, side).await;
+                (*idx, symbol, levels)
+            }
+        });
+        let results = join_all(fetches).await;
+
+        // 2. 기준 통화 환산 및 실효 가격 계산
+        let mut venue_quotes = Vec::with_capacity(results.len());
+        let mut merged: Vec<RoutableLevel> = Vec::new();
+
+        for (idx, symbol, levels) in results {
+            let venue = &self.venues[idx];
+            let mut quote = VenueQuote {
+                venue: venue.config.name.clone(),
+                symbol,
+                best_price: None,
+                best_price_base: None,
+                effective_price_base: None,
+                available_quantity: None,
+                error: None,
+            };
+
+            let levels = match levels {
+                Ok(levels) => levels,
+                Err(e) => {
+                    warn!(venue = %venue.config.name, error = %e, "라우팅 호가 조회 실패");
+                    quote.error = Some(e);
+                    venue_quotes.push(quote);
+                    continue;
+                }
+            };
+
+            let Some(rate) = converter.rate(&venue.config.quote_currency) else {
+                quote.error = Some(
+                    RoutingError::MissingRate(venue.config.quote_currency.clone()).to_string(),
+                );
+                venue_quotes.push(quote);
+                continue;
+            };
+
+            let fee_factor = match side {
+                Side::Buy => Decimal::ONE + venue.config.taker_fee_rate,
+                Side::Sell => Decimal::ONE - venue.config.taker_fee_rate,
+            };
+
+            let mut available = Some(Decimal::ZERO);
+            for (price, qty) in levels {
+                let price_base = price * rate;
+                let effective_price_base = price_base * fee_factor;
+                if quote.best_price.is_none() {
+                    quote.best_price = Some(price);
+                    quote.best_price_base = Some(price_base);
+                    quote.effective_price_base = Some(effective_price_base);
+                }
+                // 현재가 대체 레벨은 잔량을 알 수 없으므로 유동성 0으로 취급
+                let Some(qty) = qty else {
+                    available = None;
+                    continue;
+                };
+                available = available.map(|sum| sum + qty);
+                merged.push(RoutableLevel {
+                    venue_idx: idx,
+                    price,
+                    effective_price_base,
+                    quantity: qty,
+                });
+            }
+            quote.available_quantity = available;
+            venue_quotes.push(quote);
+        }
+
+        if merged.is_empty() {
+            return Err(RoutingError::NoLiquidity(base_asset.to_string()));
+        }
+
+        // 3. 실효 가격 순 정렬 (매수: 낮은 순, 매도: 높은 순)
+        match side {
+            Side::Buy => merged.sort_by_key(|l| l.effective_price_base),
+            Side::Sell => merged.sort_by_key(|l| std::cmp::Reverse(l.effective_price_base)),
+        }
+
+        // 4. 탐욕적 배분
+        // 최소 배분 수량 미만인 거래소는 가장 작은 것부터 제외하고 부족분을 다시 배분
+        let mut excluded = HashSet::new();
+        let (mut fills, remaining) = loop {
+            let (fills, remaining) = allocate(&merged, quantity, &excluded);
+            let undersized = fills
+                .iter()
+                .map(|(idx, f)| (*idx, f.iter().map(|(_, q)| *q).sum::<Decimal>()))
+                .filter(|(_, qty)| *qty < self.config.min_allocation_quantity)
+                .min_by_key(|(_, qty)| *qty);
+            match undersized {
+                Some((idx, _)) => {
+                    excluded.insert(idx);
+                }
+                None => break (fills, remaining),
+            }
+        };
+
+        // 5. 거래소별 배분 집계
+        let mut allocations = Vec::new();
+        for (idx, venue) in &active {
+            let Some(venue_fills) = fills.remove(idx) else {
+                continue;
+            };
+            let qty: Decimal = venue_fills.iter().map(|(_, q)| *q).sum();
+            let notional: Decimal = venue_fills.iter().map(|(p, q)| *p * *q).sum();
+            let avg = notional / qty;
+            let limit_price = match side {
+                Side::Buy => venue_fills.iter().map(|(p, _)| *p).max(),
+                Side::Sell => venue_fills.iter().map(|(p, _)| *p).min(),
+            }
+            .unwrap_or(avg);
+            let rate = converter
+                .rate(&venue.config.quote_currency)
+                .unwrap_or(Decimal::ONE);
+
+            allocations.push(VenueAllocation {
+                venue: venue.config.name.clone(),
+                symbol: venue.config.symbol_for(base_asset),
+                quote_currency: venue.config.quote_currency.clone(),
+                quantity: qty,
+                expected_avg_price: avg,
+                expected_avg_price_base: avg * rate,
+                limit_price,
+                expected_fee_base: notional * rate * venue.config.taker_fee_rate,
+            });
+        }
+
+        if allocations.is_empty() {
+            return Err(RoutingError::NoLiquidity(base_asset.to_string()));
+        }
+
+        let plan = RoutingPlan {
+            id: Uuid::new_v4(),
+            base_asset: base_asset.to_string(),
+            side,
+            requested_quantity: quantity,
+            base_currency: converter.base_currency().to_string(),
+            allocations,
+            unfilled_quantity: remaining.max(Decimal::ZERO),
+            venue_quotes,
+            created_at: Utc::now(),
+        };
+
+        debug!(
+            plan_id = %plan.id,
+            base_asset = %plan.base_asset,
+            venues = plan.allocations.len(),
+            unfilled = %plan.unfilled_quantity,
+            "라우팅 계획 수립"
+        );
+
+        Ok(plan)
+    }
+
+    /// 라우팅 계획 실행.
+    ///
+    /// 거래소별 배분을 IOC 지정가 주문으로 제출하고 결과를 기록합니다.
+    /// 제출 후 주문 상태(`get_order_status`)를 조회하여 체결분을 실현 체결로 기록하고,
+    /// IOC를 지원하지 않아 호가창에 남은 잔량은 취소합니다.
+    /// 주문 제공자가 없는 거래소는 실패로 기록됩니다.
+    pub async fn execute(&self, plan: &RoutingPlan) -> RoutingRecord {
+        let submissions = plan.allocations.iter().map(|alloc| async move {
+            let mut result = VenueOrderResult {
+                venue: alloc.venue.clone(),
+                symbol: alloc.symbol.clone(),
+                quantity: alloc.quantity,
+                limit_price: alloc.limit_price,
+                order_no: None,
+                remainder_cancelled: false,
+                error: None,
+            };
+
+            let provider = self
+                .venues
+                .iter()
+                .find(|v| v.config.name == alloc.venue)
+                .and_then(|v| v.order_provider.clone());
+            let Some(provider) = provider else {
+                result.error = Some("주문 제공자가 설정되지 않았습니다".to_string());
+                return (result, None);
+            };
+
+            let request = OrderRequest {
+                ticker: alloc.symbol.clone(),
+                side: plan.side,
+                order_type: OrderType::Limit,
+                quantity: alloc.quantity,
+                price: Some(alloc.limit_price),
+                stop_price: None,
+                time_in_force: TimeInForce::IOC,
+                expire_time: None,
+                trailing_percent: None,
+                client_order_id: Some(format!("sor_{}_{}", plan.id, alloc.venue)),
+                strategy_id: None,
+            };
+
+            let order_no = match provider.place_order(&request).await {
+                Ok(resp) => resp.order_no,
+                Err(e) => {
+                    warn!(venue = %alloc.venue, error = %e, "라우팅 주문 제출 실패");
+                    result.error = Some(e.to_string());
+                    return (result, None);
+                }
+            };
+            result.order_no = Some(order_no.clone());
+
+            // 주문 상태를 다시 조회하여 실현 체결 수량/평균가 확인
+            let mut status = provider.get_order_status(&order_no, &alloc.symbol).await;
+
+            // IOC를 지원하지 않는 거래소에서 호가창에 남은 잔량은 즉시 취소
+            // (상태를 알 수 없으면 취소를 시도하고, 이미 종료된 주문의 취소 실패는 무시)
+            if status.as_ref().map_or(true, |s| s.status.is_active()) {
+                match provider.cancel_order(&order_no, &alloc.symbol).await {
+                    Ok(()) => {
+                        result.remainder_cancelled = true;
+                        if status.is_ok() {
+                            status = provider.get_order_status(&order_no, &alloc.symbol).await;
+                        }
+                    }
+                    Err(e) => {
+                        debug!(venue = %alloc.venue, error = %e, "라우팅 주문 잔량 취소 실패")
+                    }
+                }
+            }
+
+            let filled = match status {
+                Ok(status) => status
+                    .average_price
+                    .filter(|_| status.filled_quantity > Decimal::ZERO)
+                    .map(|price| (status.filled_quantity, price)),
+                Err(e) => {
+                    debug!(venue = %alloc.venue, error = %e, "라우팅 주문 체결 조회 실패");
+                    None
+                }
+            };
+            (result, filled)
+        });
+
+        let results = join_all(submissions).await;
+        let mut record = RoutingRecord::new(plan.clone());
+        record.orders = results.iter().map(|(order, _)| order.clone()).collect();
+
+        info!(
+            plan_id = %plan.id,
+            base_asset = %plan.base_asset,
+            submitted = record.orders.iter().filter(|o| o.order_no.is_some()).count(),
+            "라우팅 주문 제출 완료"
+        );
+
+        if let Err(e) = self.recorder.record_decision(&record).await {
+            warn!(plan_id = %plan.id, error = %e, "라우팅 결정 기록 실패");
+        }
+
+        for (order, filled) in results {
+            let Some((quantity, price)) = filled else {
+                continue;
+            };
+            match self
+                .record_fill(plan.id, &order.venue, quantity, price)
+                .await
+            {
+                Ok(fill) => record.fills.push(fill),
+                Err(e) => {
+                    warn!(plan_id = %plan.id, venue = %order.venue, error = %e, "라우팅 체결 기록 실패")
+                }
+            }
+        }
+
+        record
+    }
+
+    /// 계획 수립 후 즉시 실행.
+    pub async fn route(
+        &self,
+        base_asset: &str,
+        side: Side,
+        quantity: Decimal,
+    ) -> Result<RoutingRecord, RoutingError> {
+        let plan = self.plan(base_asset, side, quantity).await?;
+        Ok(self.execute(&plan).await)
+    }
+
+    /// 실현 체결 기록.
+    ///
+    /// 체결가를 기준 통화로 환산하여 기록 저장소에 전달합니다.
+    pub async fn record_fill(
+        &self,
+        plan_id: Uuid,
+        venue: &str,
+        quantity: Decimal,
+        price: Decimal,
+    ) -> Result<VenueFill, RoutingError> {
+        let currency = self
+            .venues
+            .iter()
+            .find(|v| v.config.name == venue)
+            .map(|v| v.config.quote_currency.clone())
+            .ok_or(RoutingError::NoVenue)?;
+        let price_base = self
+            .converter
+            .read()
+            .await
+            .to_base(price, &currency)
+            .ok_or(RoutingError::MissingRate(currency))?;
+
+        let fill = VenueFill {
+            venue: venue.to_string(),
+            quantity,
+            price,
+            price_base,
+            filled_at: Utc::now(),
+        };
+        self.recorder.record_fill(plan_id, &fill).await?;
+        Ok(fill)
+    }
+
+    /// 거래소 호가 레벨 조회 (가격, 잔량).
+    async fn fetch_levels(
+        &self,
+        venue: &RouterVenue,
+        symbol: &str,
+        side: Side,
+    ) -> Result<Vec<(Decimal, Option<Decimal>)>, String> {
+        match venue.market_data.get_order_book(symbol).await {
+            Ok(book) => {
+                // 매수는 매도 호가를, 매도는 매수 호가를 소진
+                let levels: &[OrderBookLevel] = match side {
+                    Side::Buy => &book.asks,
+                    Side::Sell => &book.bids,
+                };
+                let levels: Vec<_> = levels
+                    .iter()
+                    .filter(|l| l.price > Decimal::ZERO && l.quantity > Decimal::ZERO)
+                    .take(venue.config.max_levels)
+                    .map(|l| (l.price, Some(l.quantity)))
+                    .collect();
+                if levels.is_empty() {
+                    Err("호가 잔량 없음".to_string())
+                } else {
+                    Ok(levels)
+                }
+            }
+            Err(book_err) if self.config.use_quote_fallback => {
+                debug!(venue = %venue.config.name, error = %book_err, "호가창 미지원, 현재가 사용");
+                let quote = venue
+                    .market_data
+                    .get_quote(symbol)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                if quote.current_price <= Decimal::ZERO {
+                    return Err("유효하지 않은 현재가".to_string());
+                }
+                Ok(vec![(quote.current_price, None)])
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use trader_core::{
+        OrderBook, OrderResponse, OrderStatus, OrderStatusType, ProviderError, QuoteData,
+    };
+
+    use super::*;
+
+    /// 테스트용 호가 제공자.
+    struct MockVenue {
+        name: String,
+        asks: Vec<(Decimal, Decimal)>,
+        bids: Vec<(Decimal, Decimal)>,
+        last: Decimal,
+        has_book: bool,
+        fill_price: Option<Decimal>,
+        fill_quantity: Option<Decimal>,
+        placed: std::sync::Mutex<Vec<OrderRequest>>,
+        cancelled: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl MockVenue {
+        fn new(name: &str, asks: Vec<(Decimal, Decimal)>, bids: Vec<(Decimal, Decimal)>) -> Self {
+            let last = asks.first().map(|(p, _)| *p).unwrap_or_default();
+            Self {
+                name: name.to_string(),
+                asks,
+                bids,
+                last,
+                has_book: true,
+                fill_price: None,
+                fill_quantity: None,
+                placed: std::sync::Mutex::new(Vec::new()),
+                cancelled: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+
+        fn quote_only(name: &str, last: Decimal) -> Self {
+            Self {
+                name: name.to_string(),
+                asks: vec![],
+                bids: vec![],
+                last,
+                has_book: false,
+                fill_price: None,
+                fill_quantity: None,
+                placed: std::sync::Mutex::new(Vec::new()),
+                cancelled: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl MockVenue {
+        /// 제출한 주문이 전량 `price`에 체결된 것으로 응답.
+        fn with_fill_price(mut self, price: Decimal) -> Self {
+            self.fill_price = Some(price);
+            self
+        }
+
+        /// 제출한 주문 중 `quantity`만 `price`에 체결되고 잔량이 호가창에 남은 것으로 응답.
+        fn with_partial_fill(mut self, price: Decimal, quantity: Decimal) -> Self {
+            self.fill_price = Some(price);
+            self.fill_quantity = Some(quantity);
+            self
+        }
+    }
+
+    fn levels(raw: &[(Decimal, Decimal)]) -> Vec<OrderBookLevel> {
+        raw.iter()
+            .map(|(price, quantity)| OrderBookLevel {
+                price: *price,
+                quantity: *quantity,
+            })
+            .collect()
+    }
+
+    #[async_trait]
+    impl MarketDataProvider for MockVenue {
+        async fn get_quote(&self, symbol: &str) -> Result<QuoteData, ProviderError> {
+            Ok(QuoteData::new(
+                symbol,
+                self.last,
+                Decimal::ZERO,
+                Decimal::ZERO,
+                self.last,
+                self.last,
+                self.last,
+                self.last,
+                Decimal::ZERO,
+                Decimal::ZERO,
+            ))
+        }
+
+        async fn get_order_book(&self, symbol: &str) -> Result<OrderBook, ProviderError> {
+            if !self.has_book {
+                return Err(ProviderError::Unsupported("no book".to_string()));
+            }
+            Ok(OrderBook {
+                ticker: symbol.to_string(),
+                bids: levels(&self.bids),
+                asks: levels(&self.asks),
+                timestamp: Utc::now(),
+            })
+        }
+
+        fn provider_name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    #[async_trait]
+    impl OrderExecutionProvider for MockVenue {
+        async fn place_order(
+            &self,
+            request: &OrderRequest,
+        ) -> Result<OrderResponse, ProviderError> {
+            self.placed.lock().unwrap().push(request.clone());
+            Ok(OrderResponse {
+                order_no: format!("{}_001", self.name),
+                order_time: "090000".to_string(),
+            })
+        }
+
+        async fn cancel_order(&self, order_id: &str, _ticker: &str) -> Result<(), ProviderError> {
+            self.cancelled.lock().unwrap().push(order_id.to_string());
+            Ok(())
+        }
+
+        async fn modify_order(
+            &self,
+            _order_id: &str,
+            _ticker: &str,
+            _quantity: Option<Decimal>,
+            _price: Option<Decimal>,
+        ) -> Result<OrderResponse, ProviderError> {
+            Err(ProviderError::Unsupported("modify".to_string()))
+        }
+
+        async fn get_order_status(
+            &self,
+            order_id: &str,
+            _ticker: &str,
+        ) -> Result<OrderStatus, ProviderError> {
+            let price = self
+                .fill_price
+                .ok_or_else(|| ProviderError::Unsupported("status".to_string()))?;
+            let request = self.placed.lock().unwrap().last().cloned().unwrap();
+            let filled = self.fill_quantity.unwrap_or(request.quantity);
+            let status = if self
+                .cancelled
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|id| id == order_id)
+            {
+                OrderStatusType::Cancelled
+            } else if filled < request.quantity {
+                OrderStatusType::PartiallyFilled
+            } else {
+                OrderStatusType::Filled
+            };
+            Ok(OrderStatus {
+                order_id: order_id.to_string(),
+                client_order_id: request.client_order_id.clone(),
+                ticker: Some(request.ticker.clone()),
+                side: Some(request.side),
+                quantity: Some(request.quantity),
+                price: request.price,
+                status,
+                filled_quantity: filled,
+                average_price: Some(price),
+                updated_at: Utc::now(),
+            })
+        }
+
+        fn exchange_name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    fn venue(mock: Arc<MockVenue>, template: &str, currency: &str, fee: Decimal) -> RouterVenue {
+        RouterVenue::new(
+            VenueConfig::new(mock.name.clone(), template, currency, fee),
+            mock.clone(),
+        )
+        .with_order_provider(mock)
+    }
+
+    #[test]
+    fn test_venue_symbol_template() {
+        let config = VenueConfig::new("upbit", "KRW-{base}", "KRW", dec!(0.0005));
+        assert_eq!(config.symbol_for("BTC"), "KRW-BTC");
+
+        let config = VenueConfig::new("binance", "{base}USDT", "USDT", dec!(0.001));
+        assert_eq!(config.symbol_for("ETH"), "ETHUSDT");
+    }
+
+    #[test]
+    fn test_quote_converter() {
+        let converter = QuoteConverter::new("KRW").with_rate("USDT", dec!(1400));
+        assert_eq!(converter.to_base(dec!(10), "USDT"), Some(dec!(14000)));
+        assert_eq!(converter.to_base(dec!(10), "KRW"), Some(dec!(10)));
+        assert_eq!(converter.from_base(dec!(14000), "USDT"), Some(dec!(10)));
+        assert_eq!(converter.to_base(dec!(10), "BTC"), None);
+    }
+
+    #[tokio::test]
+    async fn test_plan_splits_by_depth_and_fee() {
+        // Upbit: 100,000,000 KRW에 0.3개, 이후 100,500,000
+        let upbit = Arc::new(MockVenue::new(
+            "upbit",
+            vec![(dec!(100000000), dec!(0.3)), (dec!(100500000), dec!(1))],
+            vec![],
+        ));
+        // Binance: 71,500 USDT × 1400 = 100,100,000 KRW에 1개
+        let binance = Arc::new(MockVenue::new(
+            "binance",
+            vec![(dec!(71500), dec!(1))],
+            vec![],
+        ));
+
+        let router = SmartOrderRouter::new(
+            RouterConfig::default(),
+            QuoteConverter::new("KRW").with_rate("USDT", dec!(1400)),
+        )
+        .with_venue(venue(upbit, "KRW-{base}", "KRW", dec!(0.0005)))
+        .with_venue(venue(binance, "{base}USDT", "USDT", dec!(0.001)));
+
+        let plan = router.plan("BTC", Side::Buy, dec!(0.5)).await.unwrap();
+
+        assert_eq!(plan.allocated_quantity(), dec!(0.5));
+        assert_eq!(plan.unfilled_quantity, Decimal::ZERO);
+
+        let upbit_alloc = plan
+            .allocations
+            .iter()
+            .find(|a| a.venue == "upbit")
+            .unwrap();
+        let binance_alloc = plan
+            .allocations
+            .iter()
+            .find(|a| a.venue == "binance")
+            .unwrap();
+
+        // Upbit 1호가 실효가 100,050,000 < Binance 100,200,100 < Upbit 2호가 100,550,250
+        assert_eq!(upbit_alloc.quantity, dec!(0.3));
+        assert_eq!(upbit_alloc.symbol, "KRW-BTC");
+        assert_eq!(binance_alloc.quantity, dec!(0.2));
+        assert_eq!(binance_alloc.symbol, "BTCUSDT");
+        assert_eq!(binance_alloc.expected_avg_price_base, dec!(100100000));
+    }
+
+    #[tokio::test]
+    async fn test_plan_refreshes_rate_from_source() {
+        let usdt = Arc::new(MockVenue::quote_only("upbit", dec!(1400)));
+        let binance = Arc::new(MockVenue::new(
+            "binance",
+            vec![(dec!(71500), dec!(1))],
+            vec![],
+        ));
+        let router = SmartOrderRouter::new(RouterConfig::default(), QuoteConverter::new("KRW"))
+            .with_venue(venue(binance, "{base}USDT", "USDT", Decimal::ZERO))
+            .with_rate_source("USDT", usdt, "KRW-USDT");
+
+        // 환산율을 직접 설정하지 않아도 시세 소스로 USDT 호가를 원화로 환산
+        let plan = router.plan("BTC", Side::Buy, dec!(1)).await.unwrap();
+        assert_eq!(plan.allocations[0].expected_avg_price_base, dec!(100100000));
+    }
+
+    #[tokio::test]
+    async fn test_plan_sell_side_prefers_highest_bid() {
+        let upbit = Arc::new(MockVenue::new(
+            "upbit",
+            vec![],
+            vec![(dec!(100000000), dec!(1))],
+        ));
+        let bithumb = Arc::new(MockVenue::new(
+            "bithumb",
+            vec![],
+            vec![(dec!(100300000), dec!(0.1)), (dec!(99000000), dec!(5))],
+        ));
+
+        let router = SmartOrderRouter::new(RouterConfig::default(), QuoteConverter::new("KRW"))
+            .with_venue(venue(upbit, "KRW-{base}", "KRW", dec!(0.0005)))
+            .with_venue(venue(bithumb, "KRW-{base}", "KRW", dec!(0.0004)));
+
+        let plan = router.plan("BTC", Side::Sell, dec!(0.5)).await.unwrap();
+
+        let bithumb_alloc = plan
+            .allocations
+            .iter()
+            .find(|a| a.venue == "bithumb")
+            .unwrap();
+        let upbit_alloc = plan
+            .allocations
+            .iter()
+            .find(|a| a.venue == "upbit")
+            .unwrap();
+        assert_eq!(bithumb_alloc.quantity, dec!(0.1));
+        assert_eq!(upbit_alloc.quantity, dec!(0.4));
+    }
+
+    #[tokio::test]
+    async fn test_plan_reports_unfilled_quantity() {
+        let upbit = Arc::new(MockVenue::new("upbit", vec![(dec!(1000), dec!(2))], vec![]));
+        let router = SmartOrderRouter::new(RouterConfig::default(), QuoteConverter::new("KRW"))
+            .with_venue(venue(upbit, "KRW-{base}", "KRW", Decimal::ZERO));
+
+        let plan = router.plan("XRP", Side::Buy, dec!(5)).await.unwrap();
+        assert_eq!(plan.allocated_quantity(), dec!(2));
+        assert_eq!(plan.unfilled_quantity, dec!(3));
+    }
+
+    #[tokio::test]
+    async fn test_plan_quote_fallback_and_missing_rate() {
+        let naver_like = Arc::new(MockVenue::quote_only("quote_only", dec!(1000)));
+        let unknown_ccy = Arc::new(MockVenue::new(
+            "btc_market",
+            vec![(dec!(0.00001), dec!(100))],
+            vec![],
+        ));
+
+        let router = SmartOrderRouter::new(RouterConfig::default(), QuoteConverter::new("KRW"))
+            .with_venue(venue(naver_like, "KRW-{base}", "KRW", Decimal::ZERO))
+            .with_venue(venue(unknown_ccy, "BTC-{base}", "BTC", Decimal::ZERO));
+
+        // 호가창 없는 거래소는 시세만 기록되고 유동성 0으로 취급
+        assert!(matches!(
+            router.plan("XRP", Side::Buy, dec!(10)).await,
+            Err(RoutingError::NoLiquidity(_))
+        ));
+
+        let krw = Arc::new(MockVenue::new("upbit", vec![(dec!(1010), dec!(4))], vec![]));
+        let router = router.with_venue(venue(krw, "KRW-{base}", "KRW", Decimal::ZERO));
+        let plan = router.plan("XRP", Side::Buy, dec!(10)).await.unwrap();
+        assert_eq!(plan.allocations.len(), 1);
+        assert_eq!(plan.allocations[0].venue, "upbit");
+        assert_eq!(plan.allocations[0].quantity, dec!(4));
+        assert_eq!(plan.unfilled_quantity, dec!(6));
+
+        let quote_only = plan
+            .venue_quotes
+            .iter()
+            .find(|q| q.venue == "quote_only")
+            .unwrap();
+        assert_eq!(quote_only.best_price, Some(dec!(1000)));
+        assert_eq!(quote_only.available_quantity, None);
+
+        let missing = plan
+            .venue_quotes
+            .iter()
+            .find(|q| q.venue == "btc_market")
+            .unwrap();
+        assert!(missing.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_plan_redistributes_below_min_allocation() {
+        // Bithumb 1호가가 가장 싸지만 0.05개뿐이라 최소 배분 0.1 미달
+        let upbit = Arc::new(MockVenue::new(
+            "upbit",
+            vec![(dec!(1000), dec!(0.3)), (dec!(1002), dec!(1))],
+            vec![],
+        ));
+        let bithumb = Arc::new(MockVenue::new(
+            "bithumb",
+            vec![(dec!(990), dec!(0.05))],
+            vec![],
+        ));
+
+        let config = RouterConfig {
+            min_allocation_quantity: dec!(0.1),
+            ..RouterConfig::default()
+        };
+        let router = SmartOrderRouter::new(config, QuoteConverter::new("KRW"))
+            .with_venue(venue(upbit, "KRW-{base}", "KRW", Decimal::ZERO))
+            .with_venue(venue(bithumb, "KRW-{base}", "KRW", Decimal::ZERO));
+
+        let plan = router.plan("XRP", Side::Buy, dec!(0.5)).await.unwrap();
+        assert_eq!(plan.allocations.len(), 1);
+        assert_eq!(plan.allocations[0].venue, "upbit");
+        // 제외된 0.05개는 Upbit 2호가로 재배분
+        assert_eq!(plan.allocations[0].quantity, dec!(0.5));
+        assert_eq!(plan.unfilled_quantity, Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_plan_rejects_invalid_input() {
+        let router = SmartOrderRouter::new(RouterConfig::default(), QuoteConverter::new("KRW"));
+        assert!(matches!(
+            router.plan("BTC", Side::Buy, dec!(1)).await,
+            Err(RoutingError::NoVenue)
+        ));
+        assert!(matches!(
+            router.plan("BTC", Side::Buy, Decimal::ZERO).await,
+            Err(RoutingError::InvalidQuantity(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execute_and_record_realised_price() {
+        let upbit = Arc::new(
+            MockVenue::new(
+                "upbit",
+                vec![(dec!(1000), dec!(5)), (dec!(1010), dec!(5))],
+                vec![],
+            )
+            .with_fill_price(dec!(1008)),
+        );
+        let recorder = Arc::new(InMemoryRoutingRecorder::new());
+        let router = SmartOrderRouter::new(RouterConfig::default(), QuoteConverter::new("KRW"))
+            .with_venue(venue(upbit.clone(), "KRW-{base}", "KRW", Decimal::ZERO))
+            .with_recorder(recorder.clone());
+
+        let record = router.route("XRP", Side::Buy, dec!(10)).await.unwrap();
+        assert_eq!(record.orders.len(), 1);
+        assert_eq!(record.orders[0].order_no.as_deref(), Some("upbit_001"));
+
+        // 지정가는 사용한 가장 불리한 호가, IOC
+        let placed = upbit.placed.lock().unwrap().clone();
+        assert_eq!(placed[0].price, Some(dec!(1010)));
+        assert_eq!(placed[0].time_in_force, TimeInForce::IOC);
+
+        // 제출 후 주문 상태를 조회하여 실현 체결을 기록 (전량 체결은 취소하지 않음)
+        assert!(!record.orders[0].remainder_cancelled);
+        assert!(upbit.cancelled.lock().unwrap().is_empty());
+        assert_eq!(record.fills.len(), 1);
+        assert_eq!(record.fills[0].price_base, dec!(1008));
+
+        let stored = recorder.get(record.plan.id).await.unwrap();
+        assert_eq!(stored.filled_quantity(), dec!(10));
+        assert_eq!(stored.realised_avg_price_base(), Some(dec!(1008)));
+        // 예상 1005 대비 실현 1008 → 약 29.85 bps 불리
+        assert!(stored.slippage_bps().unwrap() > dec!(29));
+    }
+
+    #[tokio::test]
+    async fn test_execute_without_order_status_records_no_fill() {
+        let bithumb = Arc::new(MockVenue::new(
+            "bithumb",
+            vec![(dec!(1000), dec!(5))],
+            vec![],
+        ));
+        let router = SmartOrderRouter::new(RouterConfig::default(), QuoteConverter::new("KRW"))
+            .with_venue(venue(bithumb, "KRW-{base}", "KRW", Decimal::ZERO));
+
+        // 주문 상태 조회 미지원 거래소는 잔량 취소만 시도하고 체결은 기록하지 않음
+        let record = router.route("XRP", Side::Buy, dec!(5)).await.unwrap();
+        assert!(record.orders[0].order_no.is_some());
+        assert!(record.orders[0].remainder_cancelled);
+        assert!(record.fills.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_cancels_resting_remainder() {
+        let bithumb = Arc::new(
+            MockVenue::new("bithumb", vec![(dec!(1000), dec!(5))], vec![])
+                .with_partial_fill(dec!(1000), dec!(2)),
+        );
+        let router = SmartOrderRouter::new(RouterConfig::default(), QuoteConverter::new("KRW"))
+            .with_venue(venue(bithumb.clone(), "KRW-{base}", "KRW", Decimal::ZERO));
+
+        // IOC를 무시하고 남은 3개는 취소, 체결된 2개만 기록
+        let record = router.route("XRP", Side::Buy, dec!(5)).await.unwrap();
+        assert!(record.orders[0].remainder_cancelled);
+        assert_eq!(
+            bithumb.cancelled.lock().unwrap().as_slice(),
+            ["bithumb_001".to_string()]
+        );
+        assert_eq!(record.filled_quantity(), dec!(2));
+    }
+}
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use trader_core::{BlackoutAction, EventBlackoutConfig};
 
 /// 전역 리스크 관리 설정.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,6 +57,11 @@ pub struct RiskConfig {
     /// 심볼별 리스크 설정 (전역 설정을 재정의함)
     #[serde(default)]
     pub symbol_configs: HashMap<String, SymbolRiskConfig>,
+
+    /// 경제 이벤트 블랙아웃 (None이면 비활성)
+    /// 고영향 이벤트(FOMC, CPI 등) 전후 구간에서 신규 진입을 차단하거나 수량을 줄입니다
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_blackout: Option<EventBlackoutConfig>,
 }
 
 /// 심볼별 리스크 설정.
@@ -138,6 +144,7 @@ impl Default for RiskConfig {
             enable_trailing_stop: false,
             trailing_stop_pct: default_trailing_stop_pct(),
             symbol_configs: HashMap::new(),
+            event_blackout: None,
         }
     }
 }
@@ -174,6 +181,7 @@ impl RiskConfig {
             enable_trailing_stop: true,
             trailing_stop_pct: 1.0,
             symbol_configs: HashMap::new(),
+            event_blackout: None,
         }
     }
 
@@ -191,6 +199,7 @@ impl RiskConfig {
             enable_trailing_stop: false,
             trailing_stop_pct: 2.0,
             symbol_configs: HashMap::new(),
+            event_blackout: None,
         }
     }
 
@@ -272,6 +281,21 @@ impl RiskConfig {
             ));
         }
 
+        if let Some(blackout) = &self.event_blackout {
+            if blackout.before_minutes < 0 || blackout.after_minutes < 0 {
+                return Err(ConfigValidationError::InvalidValue(
+                    "event_blackout window minutes must not be negative".into(),
+                ));
+            }
+            if let BlackoutAction::ReduceSize { factor } = blackout.action {
+                if factor <= Decimal::ZERO || factor > Decimal::ONE {
+                    return Err(ConfigValidationError::InvalidValue(
+                        "event_blackout reduce factor must be between 0 and 1".into(),
+                    ));
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -368,6 +392,13 @@ mod tests {
             ..Default::default()
         };
         assert!(invalid.validate().is_err());
+
+        // 유효하지 않은 블랙아웃 수량 배율
+        let invalid = RiskConfig {
+            event_blackout: Some(EventBlackoutConfig::default().with_reduce_size(Decimal::from(2))),
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
     }
 
     #[test]
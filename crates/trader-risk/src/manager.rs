@@ -5,11 +5,13 @@
 //! - 일일 손실 한도 추적
 //! - Stop-loss/Take-profit 주문 생성
 //! - 변동성 필터링
+//! - 경제 이벤트 블랙아웃 (FOMC, CPI 등 전후 신규 진입 차단/축소)
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use trader_core::{OrderRequest, Position, TraderResult};
+use trader_core::{BlackoutAction, EconomicCalendar, OrderRequest, Position, TraderResult};
 
 use crate::{
     config::RiskConfig,
@@ -88,6 +90,8 @@ pub struct RiskManager {
     volatility_data: HashMap<String, VolatilityData>,
     /// 활성 Trailing Stop (position_id -> state)
     trailing_stops: HashMap<String, TrailingStopState>,
+    /// 경제 이벤트 캘린더 (이벤트 블랙아웃 판단용)
+    economic_calendar: EconomicCalendar,
 }
 
 impl RiskManager {
@@ -105,6 +109,7 @@ impl RiskManager {
             balance: starting_balance,
             volatility_data: HashMap::new(),
             trailing_stops: HashMap::new(),
+            economic_calendar: EconomicCalendar::default(),
         }
     }
 
@@ -129,6 +134,16 @@ impl RiskManager {
         &self.config
     }
 
+    /// 경제 이벤트 캘린더 업데이트.
+    pub fn update_economic_calendar(&mut self, calendar: EconomicCalendar) {
+        self.economic_calendar = calendar;
+    }
+
+    /// 경제 이벤트 캘린더 조회.
+    pub fn economic_calendar(&self) -> &EconomicCalendar {
+        &self.economic_calendar
+    }
+
     // ==================== Order Validation ====================
 
     /// 모든 리스크 한도에 대해 주문 검증.
@@ -145,6 +160,20 @@ impl RiskManager {
         order: &OrderRequest,
         positions: &[Position],
         current_price: Decimal,
+    ) -> TraderResult<RiskValidation> {
+        self.validate_order_at(order, positions, current_price, Utc::now())
+    }
+
+    /// 지정 시각 기준으로 주문 검증.
+    ///
+    /// 이벤트 블랙아웃은 `now` 기준으로 판단하므로 백테스트/리플레이에서는
+    /// 캔들 시각을 전달합니다. 수량이 축소된 경우 `modified_order`에 담깁니다.
+    pub fn validate_order_at(
+        &mut self,
+        order: &OrderRequest,
+        positions: &[Position],
+        current_price: Decimal,
+        now: DateTime<Utc>,
     ) -> TraderResult<RiskValidation> {
         let symbol = order.ticker.clone();
         let mut warnings = Vec::new();
@@ -182,7 +211,46 @@ impl RiskManager {
             }
         }
 
-        // Check 4: Position sizing limits
+        // Check 4: Economic event blackout (entries only)
+        let mut reduced_order = None;
+        if let Some(blackout) = &self.config.event_blackout {
+            if is_entry_order(order, positions) {
+                if let Some(event) = blackout.active_event(&self.economic_calendar, now) {
+                    match blackout.action {
+                        BlackoutAction::Block => {
+                            return Ok(RiskValidation::invalid(format!(
+                                "Event blackout: {} ({}) at {}",
+                                event.title,
+                                event.country,
+                                event.scheduled_at.format("%Y-%m-%d %H:%M UTC")
+                            )));
+                        }
+                        BlackoutAction::ReduceSize { factor } => {
+                            let mut adjusted = order.clone();
+                            adjusted.quantity = order.quantity * factor;
+                            if order.quantity.fract().is_zero() {
+                                // 정수 단위 주문(주식)은 내림
+                                adjusted.quantity = adjusted.quantity.floor();
+                            }
+                            if adjusted.quantity <= Decimal::ZERO {
+                                return Ok(RiskValidation::invalid(format!(
+                                    "Event blackout: {} - reduced quantity is zero",
+                                    event.title
+                                )));
+                            }
+                            warnings.push(format!(
+                                "Event blackout: {} - quantity reduced {} -> {}",
+                                event.title, order.quantity, adjusted.quantity
+                            ));
+                            reduced_order = Some(adjusted);
+                        }
+                    }
+                }
+            }
+        }
+        let order = reduced_order.as_ref().unwrap_or(order);
+
+        // Check 5: Position sizing limits
         let sizing_result =
             self.position_sizer
                 .validate_order(order, positions, self.balance, current_price);
@@ -208,7 +276,7 @@ impl RiskManager {
             return Ok(validation);
         }
 
-        // Check 5: Daily limit status warning
+        // Check 6: Daily limit status warning
         let daily_status = self.daily_tracker.get_status();
         if let Some(warning) = daily_status.warning {
             warnings.push(warning);
@@ -219,6 +287,9 @@ impl RiskManager {
         for warning in warnings {
             result = result.with_warning(warning);
         }
+        if let Some(order) = reduced_order {
+            result = result.with_modified_order(order);
+        }
 
         Ok(result)
     }
@@ -427,6 +498,13 @@ impl Default for RiskManager {
     }
 }
 
+/// 신규 진입 주문 여부 (같은 종목의 반대 방향 열린 포지션이 없으면 진입).
+fn is_entry_order(order: &OrderRequest, positions: &[Position]) -> bool {
+    !positions
+        .iter()
+        .any(|p| p.ticker == order.ticker && p.is_open() && p.side != order.side)
+}
+
 #[cfg(test)]
 mod tests {
     use rust_decimal_macros::dec;
@@ -652,4 +730,70 @@ mod tests {
         assert!(manager.can_trade());
         assert_eq!(manager.daily_pnl(), dec!(0));
     }
+
+    #[test]
+    fn test_event_blackout() {
+        use chrono::TimeZone;
+        use trader_core::{
+            Country, EconomicCalendar, EconomicEvent, EconomicEventKind, EventBlackoutConfig,
+        };
+
+        let fomc_at = Utc.with_ymd_and_hms(2024, 3, 20, 18, 0, 0).unwrap();
+        let calendar = EconomicCalendar::from_events([EconomicEvent::new(
+            EconomicEventKind::Fomc,
+            "FOMC Rate Decision",
+            Country::US,
+            fomc_at,
+        )]);
+        let symbol = Symbol::crypto("BTC", "USDT");
+        let order = OrderRequest::market_buy(symbol.to_string(), dec!(0.01));
+        let in_window = fomc_at - chrono::Duration::minutes(30);
+
+        // 차단
+        let config = RiskConfig {
+            event_blackout: Some(EventBlackoutConfig::default()),
+            ..Default::default()
+        };
+        let mut manager = RiskManager::new(config, dec!(10000));
+        manager.update_economic_calendar(calendar.clone());
+
+        let result = manager
+            .validate_order_at(&order, &[], dec!(50000), in_window)
+            .unwrap();
+        assert!(!result.is_valid);
+        assert!(result.messages[0].contains("FOMC"));
+
+        // 구간 밖은 통과
+        let result = manager
+            .validate_order_at(
+                &order,
+                &[],
+                dec!(50000),
+                fomc_at - chrono::Duration::hours(3),
+            )
+            .unwrap();
+        assert!(result.is_valid);
+
+        // 청산 주문은 차단하지 않음
+        let position = create_test_position(&symbol, Side::Buy, dec!(0.01), dec!(50000));
+        let exit = OrderRequest::market_sell(symbol.to_string(), dec!(0.01));
+        let result = manager
+            .validate_order_at(&exit, &[position], dec!(50000), in_window)
+            .unwrap();
+        assert!(result.is_valid);
+
+        // 수량 축소
+        let config = RiskConfig {
+            event_blackout: Some(EventBlackoutConfig::default().with_reduce_size(dec!(0.5))),
+            ..Default::default()
+        };
+        let mut manager = RiskManager::new(config, dec!(10000));
+        manager.update_economic_calendar(calendar);
+
+        let result = manager
+            .validate_order_at(&order, &[], dec!(50000), in_window)
+            .unwrap();
+        assert!(result.is_valid);
+        assert_eq!(result.modified_order.unwrap().quantity, dec!(0.005));
+    }
 }
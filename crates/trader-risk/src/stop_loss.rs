@@ -146,6 +146,8 @@ impl StopOrder {
                 price: Some(limit),
                 stop_price: Some(self.trigger_price),
                 time_in_force: TimeInForce::GTC,
+                expire_time: None,
+                trailing_percent: None,
                 client_order_id: None,
                 strategy_id: None,
             },
@@ -157,6 +159,8 @@ impl StopOrder {
                 price: None,
                 stop_price: Some(self.trigger_price),
                 time_in_force: TimeInForce::GTC,
+                expire_time: None,
+                trailing_percent: None,
                 client_order_id: None,
                 strategy_id: None,
             },
@@ -123,6 +123,7 @@ pub fn derive_strategy_config(input: TokenStream) -> TokenStream {
                     "symbol" => quote! { trader_core::FieldType::Symbol },
                     "symbols" => quote! { trader_core::FieldType::Symbols },
                     "multi_timeframe" => quote! { trader_core::FieldType::MultiTimeframe },
+                    "rule_group" => quote! { trader_core::FieldType::RuleGroup },
                     _ => infer_field_type(&field.ty),
                 }
             } else {
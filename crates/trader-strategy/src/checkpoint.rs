@@ -0,0 +1,341 @@
+//! 전략 상태 체크포인트.
+//!
+//! `Strategy::save_state()`로 직렬화한 전략 내부 상태(DCA 사다리, 로테이션 보유 종목,
+//! 당일 매매 카운터 등)를 버전별 스냅샷으로 저장하고, 재시작 시
+//! `Strategy::load_state()`로 복원하기 위한 저장소 추상화를 제공합니다.
+//!
+//! 스냅샷은 전략별로 1부터 증가하는 버전을 가지며 덮어쓰지 않습니다.
+//! 롤백도 과거 버전의 상태를 새 버전으로 다시 저장하는 방식으로 기록됩니다.
+
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use tokio::sync::RwLock;
+
+/// 체크포인트 저장소 에러.
+#[derive(Error, Debug)]
+pub enum CheckpointError {
+    #[error("스냅샷을 찾을 수 없음: {strategy_id} v{version}")]
+    NotFound { strategy_id: String, version: i64 },
+
+    #[error("상태 직렬화 실패: {0}")]
+    Serialization(String),
+
+    #[error("저장소 에러: {0}")]
+    Storage(String),
+}
+
+/// 스냅샷 생성 사유.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotReason {
+    /// 주기적 체크포인트
+    Periodic,
+    /// 전략 중지
+    Stop,
+    /// 엔진 종료
+    Shutdown,
+    /// 수동 요청
+    Manual,
+    /// 과거 버전으로 롤백
+    Rollback,
+}
+
+impl SnapshotReason {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Periodic => "periodic",
+            Self::Stop => "stop",
+            Self::Shutdown => "shutdown",
+            Self::Manual => "manual",
+            Self::Rollback => "rollback",
+        }
+    }
+}
+
+impl fmt::Display for SnapshotReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SnapshotReason {
+    type Err = CheckpointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "periodic" => Ok(Self::Periodic),
+            "stop" => Ok(Self::Stop),
+            "shutdown" => Ok(Self::Shutdown),
+            "manual" => Ok(Self::Manual),
+            "rollback" => Ok(Self::Rollback),
+            other => Err(CheckpointError::Serialization(format!(
+                "알 수 없는 스냅샷 사유: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// 저장할 스냅샷 (버전 할당 전).
+#[derive(Debug, Clone)]
+pub struct NewStrategySnapshot {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 전략 이름 (`Strategy::name()`)
+    pub strategy_name: String,
+    /// 전략 구현 버전 (`Strategy::version()`)
+    pub strategy_version: String,
+    /// 생성 사유
+    pub reason: SnapshotReason,
+    /// 롤백 스냅샷인 경우 원본 버전
+    pub source_version: Option<i64>,
+    /// `Strategy::save_state()` 직렬화 결과
+    pub state: Vec<u8>,
+    /// 스냅샷 시점의 `Strategy::get_state()` (조회용)
+    pub diagnostics: Value,
+}
+
+/// 저장된 스냅샷.
+#[derive(Debug, Clone)]
+pub struct StrategySnapshot {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 전략별 스냅샷 버전 (1부터 증가)
+    pub version: i64,
+    /// 전략 이름
+    pub strategy_name: String,
+    /// 전략 구현 버전
+    pub strategy_version: String,
+    /// 생성 사유
+    pub reason: SnapshotReason,
+    /// 롤백 스냅샷인 경우 원본 버전
+    pub source_version: Option<i64>,
+    /// 직렬화된 전략 상태
+    pub state: Vec<u8>,
+    /// 스냅샷 시점의 진단 상태
+    pub diagnostics: Value,
+    /// 생성 시각
+    pub created_at: DateTime<Utc>,
+}
+
+impl StrategySnapshot {
+    /// 직렬화된 상태를 JSON으로 해석 (JSON이 아니면 None).
+    pub fn state_json(&self) -> Option<Value> {
+        serde_json::from_slice(&self.state).ok()
+    }
+
+    /// 상태 본문을 제외한 요약.
+    pub fn summary(&self) -> SnapshotSummary {
+        SnapshotSummary {
+            strategy_id: self.strategy_id.clone(),
+            version: self.version,
+            strategy_name: self.strategy_name.clone(),
+            strategy_version: self.strategy_version.clone(),
+            reason: self.reason,
+            source_version: self.source_version,
+            size_bytes: self.state.len() as i64,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// 스냅샷 요약 (목록 조회용).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotSummary {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 스냅샷 버전
+    pub version: i64,
+    /// 전략 이름
+    pub strategy_name: String,
+    /// 전략 구현 버전
+    pub strategy_version: String,
+    /// 생성 사유
+    pub reason: SnapshotReason,
+    /// 롤백 스냅샷인 경우 원본 버전
+    pub source_version: Option<i64>,
+    /// 상태 크기(바이트)
+    pub size_bytes: i64,
+    /// 생성 시각
+    pub created_at: DateTime<Utc>,
+}
+
+/// 전략 상태 스냅샷 저장소.
+#[async_trait]
+pub trait StrategyStateStore: Send + Sync {
+    /// 새 버전으로 스냅샷 저장.
+    async fn save(
+        &self,
+        snapshot: NewStrategySnapshot,
+    ) -> Result<StrategySnapshot, CheckpointError>;
+
+    /// 최신 스냅샷 조회.
+    async fn latest(&self, strategy_id: &str) -> Result<Option<StrategySnapshot>, CheckpointError>;
+
+    /// 특정 버전 스냅샷 조회.
+    async fn get(
+        &self,
+        strategy_id: &str,
+        version: i64,
+    ) -> Result<Option<StrategySnapshot>, CheckpointError>;
+
+    /// 스냅샷 목록 조회 (최신순).
+    async fn list(
+        &self,
+        strategy_id: &str,
+        limit: usize,
+    ) -> Result<Vec<SnapshotSummary>, CheckpointError>;
+}
+
+/// 메모리 기반 스냅샷 저장소.
+#[derive(Debug, Default)]
+pub struct InMemoryStateStore {
+    snapshots: RwLock<HashMap<String, Vec<StrategySnapshot>>>,
+}
+
+impl InMemoryStateStore {
+    /// 새 저장소 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 전략의 스냅샷 수.
+    pub async fn count(&self, strategy_id: &str) -> usize {
+        self.snapshots
+            .read()
+            .await
+            .get(strategy_id)
+            .map_or(0, Vec::len)
+    }
+}
+
+#[async_trait]
+impl StrategyStateStore for InMemoryStateStore {
+    async fn save(
+        &self,
+        snapshot: NewStrategySnapshot,
+    ) -> Result<StrategySnapshot, CheckpointError> {
+        let mut snapshots = self.snapshots.write().await;
+        let history = snapshots.entry(snapshot.strategy_id.clone()).or_default();
+        let version = history.last().map_or(1, |s| s.version + 1);
+
+        let stored = StrategySnapshot {
+            strategy_id: snapshot.strategy_id,
+            version,
+            strategy_name: snapshot.strategy_name,
+            strategy_version: snapshot.strategy_version,
+            reason: snapshot.reason,
+            source_version: snapshot.source_version,
+            state: snapshot.state,
+            diagnostics: snapshot.diagnostics,
+            created_at: Utc::now(),
+        };
+        history.push(stored.clone());
+        Ok(stored)
+    }
+
+    async fn latest(&self, strategy_id: &str) -> Result<Option<StrategySnapshot>, CheckpointError> {
+        Ok(self
+            .snapshots
+            .read()
+            .await
+            .get(strategy_id)
+            .and_then(|h| h.last().cloned()))
+    }
+
+    async fn get(
+        &self,
+        strategy_id: &str,
+        version: i64,
+    ) -> Result<Option<StrategySnapshot>, CheckpointError> {
+        Ok(self
+            .snapshots
+            .read()
+            .await
+            .get(strategy_id)
+            .and_then(|h| h.iter().find(|s| s.version == version).cloned()))
+    }
+
+    async fn list(
+        &self,
+        strategy_id: &str,
+        limit: usize,
+    ) -> Result<Vec<SnapshotSummary>, CheckpointError> {
+        Ok(self
+            .snapshots
+            .read()
+            .await
+            .get(strategy_id)
+            .map(|h| h.iter().rev().take(limit).map(|s| s.summary()).collect())
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn new_snapshot(strategy_id: &str, state: &[u8]) -> NewStrategySnapshot {
+        NewStrategySnapshot {
+            strategy_id: strategy_id.to_string(),
+            strategy_name: "test".to_string(),
+            strategy_version: "1.0.0".to_string(),
+            reason: SnapshotReason::Periodic,
+            source_version: None,
+            state: state.to_vec(),
+            diagnostics: json!({}),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_versions_increment_per_strategy() {
+        let store = InMemoryStateStore::new();
+
+        let a1 = store.save(new_snapshot("a", b"{}")).await.unwrap();
+        let a2 = store.save(new_snapshot("a", b"{}")).await.unwrap();
+        let b1 = store.save(new_snapshot("b", b"{}")).await.unwrap();
+
+        assert_eq!((a1.version, a2.version, b1.version), (1, 2, 1));
+        assert_eq!(store.latest("a").await.unwrap().unwrap().version, 2);
+        assert!(store.latest("c").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_list_newest_first_with_limit() {
+        let store = InMemoryStateStore::new();
+        for _ in 0..5 {
+            store.save(new_snapshot("a", b"[1,2]")).await.unwrap();
+        }
+
+        let list = store.list("a", 3).await.unwrap();
+        let versions: Vec<i64> = list.iter().map(|s| s.version).collect();
+        assert_eq!(versions, vec![5, 4, 3]);
+        assert_eq!(list[0].size_bytes, 5);
+
+        let v2 = store.get("a", 2).await.unwrap().unwrap();
+        assert_eq!(v2.state_json(), Some(json!([1, 2])));
+        assert!(store.get("a", 9).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_reason_round_trip() {
+        for reason in [
+            SnapshotReason::Periodic,
+            SnapshotReason::Stop,
+            SnapshotReason::Shutdown,
+            SnapshotReason::Manual,
+            SnapshotReason::Rollback,
+        ] {
+            assert_eq!(reason.as_str().parse::<SnapshotReason>().unwrap(), reason);
+        }
+        assert!("unknown".parse::<SnapshotReason>().is_err());
+    }
+}
@@ -0,0 +1,931 @@
+//! 전략 버전 배포.
+//!
+//! 실행 중인 전략의 설정(또는 플러그인 구현)을 새 버전으로 교체할 때 필요한
+//! 검증, 설정 diff, 드라이런 비교, 배포 이력 저장소 추상화를 제공합니다.
+//!
+//! 배포 버전은 전략별로 1부터 증가하며 덮어쓰지 않습니다. 거부된 배포와
+//! 롤백도 각각 새 버전으로 기록되어 전체 변경 이력을 추적할 수 있습니다.
+
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use tokio::sync::RwLock;
+use trader_core::{domain::StrategyUISchema, FieldType, Side, Signal, SignalType};
+
+use crate::{Strategy, StrategyRegistry};
+
+/// 배포 저장소 에러.
+#[derive(Error, Debug)]
+pub enum DeploymentError {
+    #[error("배포를 찾을 수 없음: {strategy_id} v{version}")]
+    NotFound { strategy_id: String, version: i64 },
+
+    #[error("배포 직렬화 실패: {0}")]
+    Serialization(String),
+
+    #[error("저장소 에러: {0}")]
+    Storage(String),
+
+    #[error("배포 대상 생성 실패: {0}")]
+    InvalidCandidate(String),
+
+    #[error("배포 상태 충돌: {0}")]
+    Conflict(String),
+}
+
+/// 배포 버전 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentStatus {
+    /// 현재 실행 중인 버전
+    Active,
+    /// 이후 배포로 대체된 버전
+    Superseded,
+    /// 롤백으로 철회된 버전
+    RolledBack,
+    /// 검증/드라이런/상태 이전 실패로 거부된 버전
+    Rejected,
+}
+
+impl DeploymentStatus {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Superseded => "superseded",
+            Self::RolledBack => "rolled_back",
+            Self::Rejected => "rejected",
+        }
+    }
+}
+
+impl fmt::Display for DeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DeploymentStatus {
+    type Err = DeploymentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "active" => Ok(Self::Active),
+            "superseded" => Ok(Self::Superseded),
+            "rolled_back" => Ok(Self::RolledBack),
+            "rejected" => Ok(Self::Rejected),
+            other => Err(DeploymentError::Serialization(format!(
+                "알 수 없는 배포 상태: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// 배포 대상 구현의 출처.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentSource {
+    /// 레지스트리 내장 전략 (설정만 변경)
+    Registry,
+    /// 동적 라이브러리 플러그인
+    Plugin,
+}
+
+impl DeploymentSource {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Registry => "registry",
+            Self::Plugin => "plugin",
+        }
+    }
+}
+
+impl fmt::Display for DeploymentSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DeploymentSource {
+    type Err = DeploymentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "registry" => Ok(Self::Registry),
+            "plugin" => Ok(Self::Plugin),
+            other => Err(DeploymentError::Serialization(format!(
+                "알 수 없는 배포 출처: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// 설정 변경 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// 새로 추가된 키
+    Added,
+    /// 삭제된 키
+    Removed,
+    /// 값이 바뀐 키
+    Changed,
+}
+
+/// 설정 변경 항목.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigChange {
+    /// 점(.)으로 구분한 키 경로 (예: `exit_config.stop_loss_pct`)
+    pub path: String,
+    /// 변경 종류
+    pub kind: ChangeKind,
+    /// 이전 값
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<Value>,
+    /// 새 값
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<Value>,
+}
+
+/// 두 설정 사이의 키 단위 변경 목록.
+///
+/// 객체는 키별로 재귀 비교하고, 배열과 스칼라는 값 전체를 비교합니다.
+pub fn diff_configs(old: &Value, new: &Value) -> Vec<ConfigChange> {
+    let mut changes = Vec::new();
+    diff_into("", old, new, &mut changes);
+    changes
+}
+
+fn diff_into(path: &str, old: &Value, new: &Value, changes: &mut Vec<ConfigChange>) {
+    let (Value::Object(old_map), Value::Object(new_map)) = (old, new) else {
+        if old != new {
+            changes.push(ConfigChange {
+                path: path.to_string(),
+                kind: ChangeKind::Changed,
+                old: Some(old.clone()),
+                new: Some(new.clone()),
+            });
+        }
+        return;
+    };
+
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+
+    for (key, old_value) in old_map {
+        match new_map.get(key) {
+            Some(new_value) => diff_into(&child(key), old_value, new_value, changes),
+            None => changes.push(ConfigChange {
+                path: child(key),
+                kind: ChangeKind::Removed,
+                old: Some(old_value.clone()),
+                new: None,
+            }),
+        }
+    }
+
+    for (key, new_value) in new_map {
+        if !old_map.contains_key(key) {
+            changes.push(ConfigChange {
+                path: child(key),
+                kind: ChangeKind::Added,
+                old: None,
+                new: Some(new_value.clone()),
+            });
+        }
+    }
+}
+
+/// SDUI 스키마의 커스텀 필드 기준으로 설정 값 검증.
+///
+/// 설정에 없는 필드는 기본값이 적용되므로 검사하지 않습니다.
+/// 위반 사항을 사람이 읽을 수 있는 메시지 목록으로 반환합니다 (비어 있으면 통과).
+pub fn validate_against_schema(schema: &StrategyUISchema, config: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if !config.is_object() {
+        errors.push("설정은 JSON 객체여야 합니다".to_string());
+        return errors;
+    }
+
+    for field in &schema.custom_fields {
+        let Some(value) = config.get(&field.name) else {
+            continue;
+        };
+        if value.is_null() {
+            continue;
+        }
+
+        let type_ok = match field.field_type {
+            FieldType::Integer => value.is_i64() || value.is_u64(),
+            FieldType::Number => value.is_number(),
+            FieldType::Boolean => value.is_boolean(),
+            FieldType::String | FieldType::Symbol | FieldType::Select => value.is_string(),
+            FieldType::MultiSelect | FieldType::Symbols => value.is_array(),
+            FieldType::MultiTimeframe | FieldType::RuleGroup => {
+                value.is_object() || value.is_array()
+            }
+        };
+        if !type_ok {
+            errors.push(format!(
+                "{}: {:?} 타입이 필요하지만 {}이(가) 주어짐",
+                field.name, field.field_type, value
+            ));
+            continue;
+        }
+
+        if let Some(number) = value.as_f64() {
+            if let Some(min) = field.min.filter(|min| number < *min) {
+                errors.push(format!(
+                    "{}: {}은(는) 최소값 {}보다 작음",
+                    field.name, number, min
+                ));
+            }
+            if let Some(max) = field.max.filter(|max| number > *max) {
+                errors.push(format!(
+                    "{}: {}은(는) 최대값 {}보다 큼",
+                    field.name, number, max
+                ));
+            }
+        }
+
+        if field.field_type == FieldType::Select && !field.options.is_empty() {
+            let selected = value.as_str().unwrap_or_default();
+            if !field.options.iter().any(|o| o == selected) {
+                errors.push(format!(
+                    "{}: '{}'은(는) 허용된 값 {:?}에 없음",
+                    field.name, selected, field.options
+                ));
+            }
+        }
+    }
+
+    errors
+}
+
+/// config의 `name` 필드를 분리 (전략 표시 이름으로 사용).
+pub fn split_custom_name(mut config: Value) -> (Value, Option<String>) {
+    let name = config
+        .as_object_mut()
+        .and_then(|obj| obj.remove("name"))
+        .and_then(|v| v.as_str().map(str::to_string));
+    (config, name)
+}
+
+/// 배포할 새 전략 버전.
+///
+/// 드라이런은 실제 교체될 인스턴스를 오염시키지 않도록 별도의 `shadow`
+/// 인스턴스로 수행합니다. `shadow`가 없으면 드라이런을 건너뜁니다.
+pub struct DeploymentCandidate {
+    /// 전략 타입 (레지스트리 ID 또는 플러그인 이름)
+    pub strategy_type: String,
+    /// 구현 출처
+    pub source: DeploymentSource,
+    /// 전략 설정 (`name` 제외)
+    pub config: Value,
+    /// config에서 분리한 표시 이름
+    pub custom_name: Option<String>,
+    /// 교체될 전략 인스턴스
+    pub strategy: Box<dyn Strategy>,
+    /// 드라이런 전용 인스턴스
+    pub shadow: Option<Box<dyn Strategy>>,
+}
+
+impl DeploymentCandidate {
+    /// 이미 생성한 인스턴스로 배포 대상 생성.
+    pub fn new(
+        strategy_type: impl Into<String>,
+        source: DeploymentSource,
+        config: Value,
+        strategy: Box<dyn Strategy>,
+    ) -> Self {
+        let (config, custom_name) = split_custom_name(config);
+        Self {
+            strategy_type: strategy_type.into(),
+            source,
+            config,
+            custom_name,
+            strategy,
+            shadow: None,
+        }
+    }
+
+    /// 레지스트리 내장 전략으로 배포 대상 생성 (드라이런 인스턴스 포함).
+    pub fn from_registry(strategy_type: &str, config: Value) -> Result<Self, DeploymentError> {
+        let strategy = StrategyRegistry::create_instance(strategy_type)
+            .map_err(DeploymentError::InvalidCandidate)?;
+        let shadow = StrategyRegistry::create_instance(strategy_type)
+            .map_err(DeploymentError::InvalidCandidate)?;
+
+        Ok(
+            Self::new(strategy_type, DeploymentSource::Registry, config, strategy)
+                .with_shadow(shadow),
+        )
+    }
+
+    /// 드라이런 전용 인스턴스 설정.
+    pub fn with_shadow(mut self, shadow: Box<dyn Strategy>) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// 레지스트리 SDUI 스키마 기준 설정 검증 (플러그인은 스키마가 없어 생략).
+    pub fn schema_errors(&self) -> Vec<String> {
+        if self.source != DeploymentSource::Registry {
+            return Vec::new();
+        }
+
+        StrategyRegistry::find(&self.strategy_type)
+            .and_then(|meta| meta.ui_schema_factory)
+            .map(|factory| validate_against_schema(&factory(), &self.config))
+            .unwrap_or_default()
+    }
+}
+
+/// 배포 옵션.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeploymentOptions {
+    /// 드라이런에 재생할 최근 시장 데이터 수 (0이면 생략)
+    #[serde(default = "default_dry_run_bars")]
+    pub dry_run_bars: usize,
+    /// 이 일치율보다 낮으면 배포 거부 (None이면 검사 안 함)
+    #[serde(default)]
+    pub min_agreement: Option<f64>,
+}
+
+fn default_dry_run_bars() -> usize {
+    200
+}
+
+impl Default for DeploymentOptions {
+    fn default() -> Self {
+        Self {
+            dry_run_bars: default_dry_run_bars(),
+            min_agreement: None,
+        }
+    }
+}
+
+/// 배포 사전 평가 결과 (교체 없이 검증, diff, 드라이런만 수행).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentPreview {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 현재 설정 대비 변경
+    pub changes: Vec<ConfigChange>,
+    /// 스키마/초기화 검증 실패 사유 (비어 있으면 통과)
+    pub validation_errors: Vec<String>,
+    /// 드라이런 결과
+    pub dry_run: Option<DryRunReport>,
+}
+
+impl DeploymentPreview {
+    /// 검증 통과 여부.
+    pub fn is_valid(&self) -> bool {
+        self.validation_errors.is_empty()
+    }
+}
+
+/// 드라이런에서 비교하는 신호 요약.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalDigest {
+    /// 재생한 데이터 내 위치 (0부터)
+    pub bar_index: usize,
+    /// 티커
+    pub ticker: String,
+    /// 방향
+    pub side: Side,
+    /// 신호 유형
+    pub signal_type: SignalType,
+}
+
+impl SignalDigest {
+    fn from_signal(bar_index: usize, signal: &Signal) -> Self {
+        Self {
+            bar_index,
+            ticker: signal.ticker.clone(),
+            side: signal.side,
+            signal_type: signal.signal_type,
+        }
+    }
+}
+
+/// 현재 버전과 새 버전의 드라이런 비교 결과.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DryRunReport {
+    /// 재생한 시장 데이터 수
+    pub bars: usize,
+    /// 현재 버전을 새 인스턴스로 재생할 수 있었는지 (불가하면 새 버전 신호만 집계)
+    pub baseline_available: bool,
+    /// 현재 버전 신호 수
+    pub baseline_signals: usize,
+    /// 새 버전 신호 수
+    pub candidate_signals: usize,
+    /// 양쪽이 같은 데이터에서 같은 신호를 낸 수
+    pub matched: usize,
+    /// 현재 버전만 낸 신호
+    pub only_baseline: Vec<SignalDigest>,
+    /// 새 버전만 낸 신호
+    pub only_candidate: Vec<SignalDigest>,
+    /// 재생 중 발생한 에러 (처음 몇 건)
+    pub errors: Vec<String>,
+}
+
+impl DryRunReport {
+    /// 재생 중 보관할 최대 에러 수.
+    const MAX_ERRORS: usize = 10;
+
+    /// 데이터별 신호 목록을 비교하여 보고서 생성.
+    ///
+    /// 같은 데이터 위치에서 (티커, 방향, 신호 유형)이 같으면 일치로 봅니다.
+    pub fn compare(baseline: Option<&[Vec<Signal>]>, candidate: &[Vec<Signal>]) -> Self {
+        let mut report = Self {
+            bars: candidate.len(),
+            baseline_available: baseline.is_some(),
+            ..Self::default()
+        };
+
+        for (index, candidate_signals) in candidate.iter().enumerate() {
+            let mut unmatched: Vec<SignalDigest> = candidate_signals
+                .iter()
+                .map(|s| SignalDigest::from_signal(index, s))
+                .collect();
+            report.candidate_signals += unmatched.len();
+
+            let Some(baseline) = baseline else {
+                continue;
+            };
+
+            for signal in baseline.get(index).into_iter().flatten() {
+                report.baseline_signals += 1;
+                let digest = SignalDigest::from_signal(index, signal);
+                match unmatched.iter().position(|d| *d == digest) {
+                    Some(pos) => {
+                        unmatched.swap_remove(pos);
+                        report.matched += 1;
+                    }
+                    None => report.only_baseline.push(digest),
+                }
+            }
+            report.only_candidate.extend(unmatched);
+        }
+
+        report
+    }
+
+    /// 재생 에러 기록 (최대 개수까지만 보관).
+    pub fn record_error(&mut self, error: impl Into<String>) {
+        if self.errors.len() < Self::MAX_ERRORS {
+            self.errors.push(error.into());
+        }
+    }
+
+    /// 신호 일치율 (0.0 ~ 1.0).
+    ///
+    /// 양쪽 모두 신호가 없으면 1.0, 현재 버전을 재생하지 못했으면 None입니다.
+    pub fn agreement(&self) -> Option<f64> {
+        if !self.baseline_available {
+            return None;
+        }
+        let total = self.matched + self.only_baseline.len() + self.only_candidate.len();
+        if total == 0 {
+            return Some(1.0);
+        }
+        Some(self.matched as f64 / total as f64)
+    }
+}
+
+/// 저장할 배포 (버전 할당 전).
+#[derive(Debug, Clone)]
+pub struct NewStrategyDeployment {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 전략 타입 (레지스트리 ID 또는 플러그인 이름)
+    pub strategy_type: String,
+    /// 전략 구현 버전 (`Strategy::version()`)
+    pub strategy_version: String,
+    /// 구현 출처
+    pub source: DeploymentSource,
+    /// 배포 상태
+    pub status: DeploymentStatus,
+    /// 전략 설정
+    pub config: Value,
+    /// 직전 버전 대비 설정 변경
+    pub changes: Vec<ConfigChange>,
+    /// 드라이런 결과
+    pub dry_run: Option<DryRunReport>,
+    /// 기존 `save_state` 데이터를 이어받았는지 여부
+    pub state_carried_over: bool,
+    /// 롤백 배포인 경우 되돌린 대상 버전
+    pub rollback_of: Option<i64>,
+    /// 거부 사유 등 메모
+    pub message: Option<String>,
+}
+
+/// 저장된 배포 버전.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyDeployment {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 전략별 배포 버전 (1부터 증가)
+    pub version: i64,
+    /// 전략 타입
+    pub strategy_type: String,
+    /// 전략 구현 버전
+    pub strategy_version: String,
+    /// 구현 출처
+    pub source: DeploymentSource,
+    /// 배포 상태
+    pub status: DeploymentStatus,
+    /// 전략 설정
+    pub config: Value,
+    /// 직전 버전 대비 설정 변경
+    pub changes: Vec<ConfigChange>,
+    /// 드라이런 결과
+    pub dry_run: Option<DryRunReport>,
+    /// 기존 상태를 이어받았는지 여부
+    pub state_carried_over: bool,
+    /// 롤백 배포인 경우 되돌린 대상 버전
+    pub rollback_of: Option<i64>,
+    /// 메모
+    pub message: Option<String>,
+    /// 생성 시각
+    pub created_at: DateTime<Utc>,
+}
+
+/// 전략 배포 이력 저장소.
+#[async_trait]
+pub trait StrategyDeploymentStore: Send + Sync {
+    /// 새 버전으로 배포 기록.
+    async fn save(
+        &self,
+        deployment: NewStrategyDeployment,
+    ) -> Result<StrategyDeployment, DeploymentError>;
+
+    /// 특정 버전 조회.
+    async fn get(
+        &self,
+        strategy_id: &str,
+        version: i64,
+    ) -> Result<Option<StrategyDeployment>, DeploymentError>;
+
+    /// 배포 목록 조회 (최신순).
+    async fn list(
+        &self,
+        strategy_id: &str,
+        limit: usize,
+    ) -> Result<Vec<StrategyDeployment>, DeploymentError>;
+
+    /// 배포 상태 변경.
+    async fn set_status(
+        &self,
+        strategy_id: &str,
+        version: i64,
+        status: DeploymentStatus,
+    ) -> Result<(), DeploymentError>;
+
+    /// 새 활성 버전 기록과 이전 활성 버전의 상태 변경을 한 번에 적용.
+    ///
+    /// `replaced_version`이 더 이상 활성 상태가 아니면 아무것도 기록하지 않고
+    /// `DeploymentError::Conflict`를 반환합니다.
+    async fn activate(
+        &self,
+        deployment: NewStrategyDeployment,
+        replaced_version: i64,
+        replaced_status: DeploymentStatus,
+    ) -> Result<StrategyDeployment, DeploymentError>;
+
+    /// 현재 활성 버전 조회.
+    async fn active(
+        &self,
+        strategy_id: &str,
+    ) -> Result<Option<StrategyDeployment>, DeploymentError> {
+        Ok(self
+            .list(strategy_id, usize::MAX)
+            .await?
+            .into_iter()
+            .find(|d| d.status == DeploymentStatus::Active))
+    }
+}
+
+/// 메모리 기반 배포 저장소.
+#[derive(Debug, Default)]
+pub struct InMemoryDeploymentStore {
+    deployments: RwLock<HashMap<String, Vec<StrategyDeployment>>>,
+}
+
+impl InMemoryDeploymentStore {
+    /// 새 저장소 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn insert(
+        deployments: &mut HashMap<String, Vec<StrategyDeployment>>,
+        deployment: NewStrategyDeployment,
+    ) -> StrategyDeployment {
+        let history = deployments
+            .entry(deployment.strategy_id.clone())
+            .or_default();
+        let version = history.last().map_or(1, |d| d.version + 1);
+
+        let stored = StrategyDeployment {
+            strategy_id: deployment.strategy_id,
+            version,
+            strategy_type: deployment.strategy_type,
+            strategy_version: deployment.strategy_version,
+            source: deployment.source,
+            status: deployment.status,
+            config: deployment.config,
+            changes: deployment.changes,
+            dry_run: deployment.dry_run,
+            state_carried_over: deployment.state_carried_over,
+            rollback_of: deployment.rollback_of,
+            message: deployment.message,
+            created_at: Utc::now(),
+        };
+        history.push(stored.clone());
+        stored
+    }
+}
+
+#[async_trait]
+impl StrategyDeploymentStore for InMemoryDeploymentStore {
+    async fn save(
+        &self,
+        deployment: NewStrategyDeployment,
+    ) -> Result<StrategyDeployment, DeploymentError> {
+        let mut deployments = self.deployments.write().await;
+        Ok(Self::insert(&mut deployments, deployment))
+    }
+
+    async fn get(
+        &self,
+        strategy_id: &str,
+        version: i64,
+    ) -> Result<Option<StrategyDeployment>, DeploymentError> {
+        Ok(self
+            .deployments
+            .read()
+            .await
+            .get(strategy_id)
+            .and_then(|h| h.iter().find(|d| d.version == version).cloned()))
+    }
+
+    async fn list(
+        &self,
+        strategy_id: &str,
+        limit: usize,
+    ) -> Result<Vec<StrategyDeployment>, DeploymentError> {
+        Ok(self
+            .deployments
+            .read()
+            .await
+            .get(strategy_id)
+            .map(|h| h.iter().rev().take(limit).cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn set_status(
+        &self,
+        strategy_id: &str,
+        version: i64,
+        status: DeploymentStatus,
+    ) -> Result<(), DeploymentError> {
+        let mut deployments = self.deployments.write().await;
+        let deployment = deployments
+            .get_mut(strategy_id)
+            .and_then(|h| h.iter_mut().find(|d| d.version == version))
+            .ok_or_else(|| DeploymentError::NotFound {
+                strategy_id: strategy_id.to_string(),
+                version,
+            })?;
+        deployment.status = status;
+        Ok(())
+    }
+
+    async fn activate(
+        &self,
+        deployment: NewStrategyDeployment,
+        replaced_version: i64,
+        replaced_status: DeploymentStatus,
+    ) -> Result<StrategyDeployment, DeploymentError> {
+        let mut deployments = self.deployments.write().await;
+        let replaced = deployments
+            .get_mut(&deployment.strategy_id)
+            .and_then(|h| h.iter_mut().find(|d| d.version == replaced_version))
+            .ok_or_else(|| DeploymentError::NotFound {
+                strategy_id: deployment.strategy_id.clone(),
+                version: replaced_version,
+            })?;
+        if replaced.status != DeploymentStatus::Active {
+            return Err(DeploymentError::Conflict(format!(
+                "{} v{}는 활성 버전이 아님",
+                deployment.strategy_id, replaced_version
+            )));
+        }
+        replaced.status = replaced_status;
+
+        Ok(Self::insert(&mut deployments, deployment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use trader_core::domain::FieldSchema;
+
+    use super::*;
+
+    #[test]
+    fn test_diff_configs_nested() {
+        let old = json!({
+            "ticker": "005930",
+            "period": 20,
+            "exit": { "stop_loss": 3.0, "trailing": true }
+        });
+        let new = json!({
+            "ticker": "005930",
+            "period": 30,
+            "exit": { "stop_loss": 3.0 },
+            "threshold": 0.5
+        });
+
+        let changes = diff_configs(&old, &new);
+        let summary: Vec<(&str, ChangeKind)> =
+            changes.iter().map(|c| (c.path.as_str(), c.kind)).collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("exit.trailing", ChangeKind::Removed),
+                ("period", ChangeKind::Changed),
+                ("threshold", ChangeKind::Added),
+            ]
+        );
+        assert!(diff_configs(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_validate_against_schema() {
+        let schema = StrategyUISchema::new("test", "테스트", "experimental")
+            .with_custom_field(FieldSchema {
+                name: "period".to_string(),
+                field_type: FieldType::Integer,
+                label: "기간".to_string(),
+                min: Some(2.0),
+                max: Some(200.0),
+                ..Default::default()
+            })
+            .with_custom_field(FieldSchema {
+                name: "mode".to_string(),
+                field_type: FieldType::Select,
+                label: "모드".to_string(),
+                options: vec!["fast".to_string(), "slow".to_string()],
+                ..Default::default()
+            });
+
+        assert!(validate_against_schema(&schema, &json!({ "period": 20 })).is_empty());
+
+        let errors = validate_against_schema(
+            &schema,
+            &json!({ "period": 500, "mode": "medium", "unknown": 1 }),
+        );
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("period"));
+        assert!(errors[1].starts_with("mode"));
+
+        assert_eq!(
+            validate_against_schema(&schema, &json!({ "period": "20" })).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_dry_run_report_agreement() {
+        let buy = Signal::entry("s", "AAA".to_string(), Side::Buy);
+        let sell = Signal::exit("s", "AAA".to_string(), Side::Sell);
+
+        let baseline = vec![vec![buy.clone()], vec![], vec![sell.clone()]];
+        let candidate = vec![vec![buy.clone()], vec![buy.clone()], vec![]];
+
+        let report = DryRunReport::compare(Some(&baseline), &candidate);
+        assert_eq!(report.bars, 3);
+        assert_eq!((report.baseline_signals, report.candidate_signals), (2, 2));
+        assert_eq!(report.matched, 1);
+        assert_eq!(report.only_baseline[0].bar_index, 2);
+        assert_eq!(report.only_candidate[0].bar_index, 1);
+        assert_eq!(report.agreement(), Some(1.0 / 3.0));
+
+        let candidate_only = DryRunReport::compare(None, &candidate);
+        assert_eq!(candidate_only.candidate_signals, 2);
+        assert_eq!(candidate_only.agreement(), None);
+
+        let quiet = DryRunReport::compare(Some(&[vec![]]), &[vec![]]);
+        assert_eq!(quiet.agreement(), Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_versions_and_status() {
+        let store = InMemoryDeploymentStore::new();
+        let new_deployment = |status| NewStrategyDeployment {
+            strategy_id: "a".to_string(),
+            strategy_type: "rsi".to_string(),
+            strategy_version: "1.0.0".to_string(),
+            source: DeploymentSource::Registry,
+            status,
+            config: json!({}),
+            changes: Vec::new(),
+            dry_run: None,
+            state_carried_over: false,
+            rollback_of: None,
+            message: None,
+        };
+
+        let v1 = store
+            .save(new_deployment(DeploymentStatus::Active))
+            .await
+            .unwrap();
+        store
+            .set_status("a", v1.version, DeploymentStatus::Superseded)
+            .await
+            .unwrap();
+        let v2 = store
+            .save(new_deployment(DeploymentStatus::Active))
+            .await
+            .unwrap();
+
+        assert_eq!((v1.version, v2.version), (1, 2));
+        assert_eq!(store.active("a").await.unwrap().unwrap().version, 2);
+        assert_eq!(
+            store.get("a", 1).await.unwrap().unwrap().status,
+            DeploymentStatus::Superseded
+        );
+        assert!(store
+            .set_status("a", 9, DeploymentStatus::Rejected)
+            .await
+            .is_err());
+
+        let v3 = store
+            .activate(
+                new_deployment(DeploymentStatus::Active),
+                v2.version,
+                DeploymentStatus::RolledBack,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            store.active("a").await.unwrap().unwrap().version,
+            v3.version
+        );
+        assert_eq!(
+            store.get("a", v2.version).await.unwrap().unwrap().status,
+            DeploymentStatus::RolledBack
+        );
+
+        // 이미 대체된 버전 기준 활성화는 아무것도 기록하지 않음
+        assert!(matches!(
+            store
+                .activate(
+                    new_deployment(DeploymentStatus::Active),
+                    v2.version,
+                    DeploymentStatus::Superseded,
+                )
+                .await,
+            Err(DeploymentError::Conflict(_))
+        ));
+        assert_eq!(store.list("a", 10).await.unwrap().len(), 3);
+        assert_eq!(
+            store.get("a", v2.version).await.unwrap().unwrap().status,
+            DeploymentStatus::RolledBack
+        );
+    }
+
+    #[test]
+    fn test_status_and_source_round_trip() {
+        for status in [
+            DeploymentStatus::Active,
+            DeploymentStatus::Superseded,
+            DeploymentStatus::RolledBack,
+            DeploymentStatus::Rejected,
+        ] {
+            assert_eq!(status.as_str().parse::<DeploymentStatus>().unwrap(), status);
+        }
+        for source in [DeploymentSource::Registry, DeploymentSource::Plugin] {
+            assert_eq!(source.as_str().parse::<DeploymentSource>().unwrap(), source);
+        }
+        assert!("unknown".parse::<DeploymentStatus>().is_err());
+    }
+}
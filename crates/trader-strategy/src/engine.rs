@@ -3,20 +3,39 @@
 //! 엔진은 전략 생명주기를 관리하고, 시장 데이터를 전략에 라우팅하며,
 //! 전략으로부터 트레이딩 신호를 수집합니다.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use thiserror::Error;
-use tokio::sync::{broadcast, mpsc, RwLock};
+use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 use trader_core::{
-    domain::{MarketDataType, SignalConflictError, StrategyContext},
-    Kline, MarketData, Order, Position, Signal, SignalType, Timeframe,
+    domain::{IndicatorUpdater, MarketDataType, SignalConflictError, StrategyContext},
+    Country, DecisionTrace, DecisionTraceQuery, Kline, MarketData, Order, Position, Signal,
+    SignalType, Timeframe, TraceStepKind, TradingCalendar,
+};
+use uuid::Uuid;
+
+use crate::{
+    checkpoint::{NewStrategySnapshot, SnapshotReason, SnapshotSummary, StrategySnapshot},
+    deployment::{
+        diff_configs, split_custom_name, DeploymentCandidate, DeploymentOptions, DeploymentPreview,
+        DeploymentSource, DeploymentStatus, DryRunReport, InMemoryDeploymentStore,
+        NewStrategyDeployment, StrategyDeployment, StrategyDeploymentStore,
+    },
+    schedule::{ScheduleClock, ScheduleEvent, TradingCalendars},
+    shadow::{ShadowComparison, ShadowConfig, ShadowFill, ShadowLedger, ShadowSummary},
+    trace::{
+        link_signal_traces, DecisionTraceRecorder, DecisionTraceStore, DEFAULT_TRACE_CAPACITY,
+    },
+    Strategy, StrategyRegistry, StrategyStateStore,
 };
-
-use crate::Strategy;
 
 /// Signal 충돌 이벤트.
 ///
@@ -82,6 +101,30 @@ pub enum EngineError {
 
     #[error("내부 에러: {0}")]
     InternalError(String),
+
+    #[error("상태 저장소가 설정되지 않음")]
+    StateStoreNotConfigured,
+
+    #[error("스냅샷을 찾을 수 없음: {0}")]
+    SnapshotNotFound(String),
+
+    #[error("체크포인트 실패: {0}")]
+    CheckpointFailed(String),
+
+    #[error("배포 거부: {0}")]
+    DeploymentRejected(String),
+
+    #[error("배포를 찾을 수 없음: {0}")]
+    DeploymentNotFound(String),
+
+    #[error("배포 실패: {0}")]
+    DeploymentFailed(String),
+
+    #[error("섀도 인스턴스를 찾을 수 없음: {0}")]
+    ShadowNotFound(String),
+
+    #[error("섀도 시작 거부: {0}")]
+    ShadowRejected(String),
 }
 
 /// 전략 인스턴스 래퍼.
@@ -98,6 +141,93 @@ pub struct StrategyInstance {
     custom_name: Option<String>,
     /// 전략 컨텍스트 (다중 타임프레임 데이터 등)
     context: Arc<RwLock<StrategyContext>>,
+    /// 마지막으로 저장/복원한 상태 (변경 없는 체크포인트 생략용)
+    last_checkpoint: Option<Vec<u8>>,
+    /// 배포로 확정된 전략 타입 (없으면 ID에서 추출)
+    strategy_type: Option<String>,
+    /// 드라이런용 최근 시장 데이터
+    recent_data: VecDeque<MarketData>,
+    /// 직전 배포 버전 (즉시 롤백용)
+    previous: Option<PreviousVersion>,
+    /// 일정 이벤트 커서
+    schedule_clock: ScheduleClock,
+    /// 구독 타임프레임 (None이면 모든 캔들 수신, 다중 타임프레임 전략은 설정을 따름)
+    timeframe: Option<Timeframe>,
+}
+
+impl StrategyInstance {
+    /// 구독 타임프레임이 아닌 캔들은 전략에 전달하지 않음.
+    fn accepts(&self, data: &MarketData) -> bool {
+        let MarketDataType::Kline(kline) = &data.data else {
+            return true;
+        };
+        if self.strategy.multi_timeframe_config().is_some() {
+            return true;
+        }
+        self.timeframe.map_or(true, |tf| tf == kline.timeframe)
+    }
+
+    /// `now`까지 발생한 일정 이벤트 (일정을 선언하지 않은 전략은 빈 목록).
+    fn due_schedule_events(
+        &mut self,
+        calendars: &TradingCalendars,
+        now: DateTime<Utc>,
+    ) -> Vec<ScheduleEvent> {
+        let Some(schedule) = self.strategy.schedule() else {
+            return Vec::new();
+        };
+        let calendar = calendars.get(schedule.country);
+        self.schedule_clock.advance(&schedule, &calendar, now)
+    }
+}
+
+/// 라이브 전략 옆에서 실행 중인 섀도 인스턴스.
+///
+/// 라이브 전략과 같은 시장 데이터를 받지만 신호는 신호 채널로 내보내지 않고
+/// 장부의 `SimulatedExecutor`로만 체결합니다.
+///
+/// 섀도 인스턴스의 컨텍스트는 라이브 컨텍스트의 복사본이며, 계좌/포지션은
+/// 섀도 가상 계좌 기준으로 유지됩니다.
+struct ShadowEntry {
+    instance: StrategyInstance,
+    ledger: ShadowLedger,
+}
+
+impl ShadowEntry {
+    /// 가상 체결을 섀도 컨텍스트와 섀도 전략에 반영.
+    async fn apply_fills(&mut self, shadow_id: &str, fills: Vec<ShadowFill>) {
+        self.ledger
+            .sync_context(&mut *self.instance.context.write().await);
+
+        for fill in fills {
+            match self.instance.strategy.on_order_filled(&fill.order).await {
+                Ok(()) => self.instance.stats.orders_filled += 1,
+                Err(e) => {
+                    self.instance.stats.last_error = Some(e.to_string());
+                    self.ledger.record_error(e.to_string());
+                    warn!(shadow_id = %shadow_id, error = %e, "Shadow strategy error handling order fill");
+                }
+            }
+            if let Err(e) = self
+                .instance
+                .strategy
+                .on_position_update(&fill.position)
+                .await
+            {
+                self.instance.stats.last_error = Some(e.to_string());
+                self.ledger.record_error(e.to_string());
+                warn!(shadow_id = %shadow_id, error = %e, "Shadow strategy error handling position update");
+            }
+        }
+    }
+}
+
+/// 교체된 직전 전략 버전.
+///
+/// 종료 훅을 호출하지 않고 그대로 보관하여 롤백 시 재초기화 없이 되돌립니다.
+struct PreviousVersion {
+    strategy: Box<dyn Strategy>,
+    version: i64,
 }
 
 /// 전략 통계.
@@ -158,6 +288,22 @@ pub struct EngineConfig {
     /// 신호 중복 제거 윈도우(밀리초)
     #[serde(default = "default_dedup_window")]
     pub dedup_window_ms: u64,
+
+    /// 주기적 상태 체크포인트 간격(초, 0이면 비활성화)
+    #[serde(default = "default_checkpoint_interval")]
+    pub checkpoint_interval_secs: u64,
+
+    /// 배포 드라이런용으로 전략별 보관할 최근 시장 데이터 수
+    #[serde(default = "default_deployment_history")]
+    pub deployment_history_size: usize,
+
+    /// 일정 이벤트 확인 간격(초, 0이면 비활성화)
+    #[serde(default = "default_schedule_tick")]
+    pub schedule_tick_secs: u64,
+
+    /// 보관할 최근 신호 결정 추적 수 (0이면 비활성화)
+    #[serde(default = "default_trace_capacity")]
+    pub decision_trace_capacity: usize,
 }
 
 fn default_max_strategies() -> usize {
@@ -175,6 +321,18 @@ fn default_true() -> bool {
 fn default_dedup_window() -> u64 {
     1000
 }
+fn default_checkpoint_interval() -> u64 {
+    60
+}
+fn default_deployment_history() -> usize {
+    500
+}
+fn default_schedule_tick() -> u64 {
+    30
+}
+fn default_trace_capacity() -> usize {
+    DEFAULT_TRACE_CAPACITY
+}
 
 /// 전략 config JSON에서 관심 종목(ticker) 목록을 추출.
 ///
@@ -237,6 +395,10 @@ impl Default for EngineConfig {
             broadcast_buffer_size: default_broadcast_buffer(),
             deduplicate_signals: default_true(),
             dedup_window_ms: default_dedup_window(),
+            checkpoint_interval_secs: default_checkpoint_interval(),
+            deployment_history_size: default_deployment_history(),
+            schedule_tick_secs: default_schedule_tick(),
+            decision_trace_capacity: default_trace_capacity(),
         }
     }
 }
@@ -271,6 +433,30 @@ pub struct StrategyEngine {
 
     /// 중복 제거를 위한 최근 신호 (signal_id -> timestamp)
     recent_signals: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+
+    /// 전략 상태 스냅샷 저장소 (없으면 체크포인트 비활성화)
+    state_store: Option<Arc<dyn StrategyStateStore>>,
+
+    /// 전략 배포 이력 저장소
+    deployment_store: Arc<dyn StrategyDeploymentStore>,
+
+    /// 배포/롤백 직렬화 (전략 잠금 밖에서 이력을 기록하는 동안 다른 배포 차단)
+    deployment_lock: Mutex<()>,
+
+    /// 섀도 인스턴스 (shadow_id -> 섀도)
+    shadows: Arc<RwLock<HashMap<String, ShadowEntry>>>,
+
+    /// 일정 이벤트용 국가별 거래 캘린더
+    calendars: RwLock<TradingCalendars>,
+
+    /// 최근 신호 결정 추적
+    decision_traces: RwLock<DecisionTraceStore>,
+
+    /// 결정 추적 영구 저장소 (없으면 메모리에만 보관)
+    trace_recorder: Option<Arc<dyn DecisionTraceRecorder>>,
+
+    /// 증분 지표 갱신기 (없으면 지표 스냅샷을 컨텍스트에 반영하지 않음)
+    indicator_updater: Option<Arc<dyn IndicatorUpdater>>,
 }
 
 impl StrategyEngine {
@@ -281,7 +467,6 @@ impl StrategyEngine {
         let (conflict_tx, conflict_rx) = mpsc::channel(100); // 충돌 이벤트 버퍼
 
         Self {
-            config,
             strategies: Arc::new(RwLock::new(HashMap::new())),
             market_data_tx,
             signal_tx,
@@ -290,6 +475,122 @@ impl StrategyEngine {
             conflict_rx: Some(conflict_rx),
             running: Arc::new(RwLock::new(false)),
             recent_signals: Arc::new(RwLock::new(HashMap::new())),
+            state_store: None,
+            deployment_store: Arc::new(InMemoryDeploymentStore::new()),
+            deployment_lock: Mutex::new(()),
+            shadows: Arc::new(RwLock::new(HashMap::new())),
+            calendars: RwLock::new(TradingCalendars::new()),
+            decision_traces: RwLock::new(DecisionTraceStore::new(config.decision_trace_capacity)),
+            trace_recorder: None,
+            indicator_updater: None,
+            config,
+        }
+    }
+
+    /// 전략 상태 스냅샷 저장소 설정.
+    ///
+    /// 설정하면 전략 등록/시작 시 최신 스냅샷을 복원하고,
+    /// 주기적으로 그리고 중지/종료 시 상태를 저장합니다.
+    pub fn with_state_store(mut self, store: Arc<dyn StrategyStateStore>) -> Self {
+        self.state_store = Some(store);
+        self
+    }
+
+    /// 전략 상태 스냅샷 저장소 설정 (엔진 생성 후).
+    pub fn set_state_store(&mut self, store: Arc<dyn StrategyStateStore>) {
+        self.state_store = Some(store);
+    }
+
+    /// 전략 배포 이력 저장소 설정 (기본값은 메모리 저장소).
+    pub fn with_deployment_store(mut self, store: Arc<dyn StrategyDeploymentStore>) -> Self {
+        self.deployment_store = store;
+        self
+    }
+
+    /// 전략 배포 이력 저장소 설정 (엔진 생성 후).
+    pub fn set_deployment_store(&mut self, store: Arc<dyn StrategyDeploymentStore>) {
+        self.deployment_store = store;
+    }
+
+    /// 결정 추적 영구 저장소 설정.
+    ///
+    /// 설정하면 수거한 추적을 메모리 보관소와 함께 백그라운드로 저장합니다.
+    pub fn with_trace_recorder(mut self, recorder: Arc<dyn DecisionTraceRecorder>) -> Self {
+        self.trace_recorder = Some(recorder);
+        self
+    }
+
+    /// 결정 추적 영구 저장소 설정 (엔진 생성 후).
+    pub fn set_trace_recorder(&mut self, recorder: Arc<dyn DecisionTraceRecorder>) {
+        self.trace_recorder = Some(recorder);
+    }
+
+    /// 증분 지표 갱신기 설정.
+    ///
+    /// 설정하면 캔들 데이터마다 지표 상태를 O(1)로 갱신하고, 전략 호출 전에
+    /// 실행 중인 전략의 컨텍스트에 최신 스냅샷을 반영합니다.
+    pub fn with_indicator_updater(mut self, updater: Arc<dyn IndicatorUpdater>) -> Self {
+        self.indicator_updater = Some(updater);
+        self
+    }
+
+    /// 증분 지표 갱신기 설정 (엔진 생성 후).
+    pub fn set_indicator_updater(&mut self, updater: Arc<dyn IndicatorUpdater>) {
+        self.indicator_updater = Some(updater);
+    }
+
+    /// 거래 캘린더 설정 (같은 국가는 교체).
+    pub fn with_calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendars.get_mut().insert(calendar);
+        self
+    }
+
+    /// 거래 캘린더 갱신 (실행 중 휴장일 재로딩용).
+    pub async fn set_calendar(&self, calendar: TradingCalendar) {
+        self.calendars.write().await.insert(calendar);
+    }
+
+    /// 국가 거래 캘린더 조회 (미설정 시 휴장일 없는 기본 캘린더).
+    pub async fn calendar(&self, country: Country) -> TradingCalendar {
+        self.calendars.read().await.get(country).into_owned()
+    }
+
+    /// 신호 ID로 결정 추적 조회.
+    pub async fn get_decision_trace(&self, signal_id: Uuid) -> Option<DecisionTrace> {
+        self.decision_traces
+            .read()
+            .await
+            .get_by_signal(signal_id)
+            .cloned()
+    }
+
+    /// 조건에 맞는 결정 추적을 최신순으로 최대 `limit`개 조회.
+    pub async fn search_decision_traces(
+        &self,
+        query: &DecisionTraceQuery,
+        limit: usize,
+    ) -> Vec<DecisionTrace> {
+        self.decision_traces.read().await.search(query, limit)
+    }
+
+    /// 일정 이벤트 확인 간격 (비활성화 시 None).
+    pub fn schedule_interval(&self) -> Option<Duration> {
+        match self.config.schedule_tick_secs {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+
+    /// 상태 저장소 설정 여부.
+    pub fn has_state_store(&self) -> bool {
+        self.state_store.is_some()
+    }
+
+    /// 주기적 체크포인트 간격 (비활성화 시 None).
+    pub fn checkpoint_interval(&self) -> Option<Duration> {
+        match self.config.checkpoint_interval_secs {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
         }
     }
 
@@ -353,17 +654,23 @@ impl StrategyEngine {
             shared_context.unwrap_or_else(|| Arc::new(RwLock::new(StrategyContext::default())));
         strategy.set_context(Arc::clone(&context));
 
-        strategies.insert(
-            id,
-            StrategyInstance {
-                strategy,
-                config,
-                running: false,
-                stats: StrategyStats::default(),
-                custom_name,
-                context,
-            },
-        );
+        let mut instance = StrategyInstance {
+            strategy,
+            config,
+            running: false,
+            stats: StrategyStats::default(),
+            custom_name,
+            context,
+            last_checkpoint: None,
+            strategy_type: None,
+            recent_data: VecDeque::new(),
+            previous: None,
+            schedule_clock: ScheduleClock::new(),
+            timeframe: None,
+        };
+        self.restore_instance(&id, &mut instance).await;
+
+        strategies.insert(id, instance);
 
         Ok(())
     }
@@ -384,6 +691,13 @@ impl StrategyEngine {
         strategies
             .remove(id)
             .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+        drop(strategies);
+
+        // 비교 대상이 사라진 섀도 정리
+        self.shadows
+            .write()
+            .await
+            .retain(|_, entry| entry.ledger.live_strategy_id() != id);
 
         info!(strategy_id = %id, "Unregistered strategy");
         Ok(())
@@ -411,6 +725,9 @@ impl StrategyEngine {
             .await
             .map_err(|e| EngineError::InitializationFailed(e.to_string()))?;
 
+        // 초기화가 내부 상태를 리셋하므로 최신 스냅샷을 다시 적용
+        self.restore_instance(id, instance).await;
+
         // config에서 관심 종목 추출 → watched_tickers에 등록
         let tickers = extract_tickers_from_config(&instance.config);
         if !tickers.is_empty() {
@@ -425,6 +742,8 @@ impl StrategyEngine {
 
         instance.running = true;
         instance.stats.started_at = Some(Utc::now());
+        // 중지 중에 지난 일정은 소급하지 않음
+        instance.schedule_clock.reset(Utc::now());
 
         info!(
             strategy_id = %id,
@@ -436,32 +755,56 @@ impl StrategyEngine {
     }
 
     /// 전략 중지.
+    ///
+    /// 종료 체크포인트는 잠금 안에서 직렬화하고 DB 저장은 잠금 해제 후 수행합니다.
     pub async fn stop_strategy(&self, id: &str) -> Result<(), EngineError> {
-        let mut strategies = self.strategies.write().await;
+        let pending = {
+            let mut strategies = self.strategies.write().await;
 
-        let instance = strategies
-            .get_mut(id)
-            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+            let instance = strategies
+                .get_mut(id)
+                .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
 
-        if !instance.running {
-            return Err(EngineError::NotRunning(id.to_string()));
-        }
+            if !instance.running {
+                return Err(EngineError::NotRunning(id.to_string()));
+            }
 
-        // 전략 종료
-        if let Err(e) = instance.strategy.shutdown().await {
-            warn!(
-                strategy_id = %id,
-                error = %e,
-                "Error during strategy shutdown"
-            );
-        }
+            // 종료 훅이 상태를 정리하기 전에 체크포인트 직렬화
+            let pending = match &self.state_store {
+                Some(_) => Self::prepare_checkpoint(id, instance, SnapshotReason::Stop, None),
+                None => Ok(None),
+            };
+
+            // 전략 종료
+            if let Err(e) = instance.strategy.shutdown().await {
+                warn!(
+                    strategy_id = %id,
+                    error = %e,
+                    "Error during strategy shutdown"
+                );
+            }
+
+            instance.running = false;
+
+            // 실행 시간 업데이트
+            if let Some(started) = instance.stats.started_at {
+                let runtime = Utc::now().signed_duration_since(started);
+                instance.stats.total_runtime_secs += runtime.num_seconds() as u64;
+            }
 
-        instance.running = false;
+            pending
+        };
 
-        // 실행 시간 업데이트
-        if let Some(started) = instance.stats.started_at {
-            let runtime = Utc::now().signed_duration_since(started);
-            instance.stats.total_runtime_secs += runtime.num_seconds() as u64;
+        let saved = match pending {
+            Ok(Some(pending)) => self.persist_pending(id, pending).await.map(|_| ()),
+            Ok(None) => Ok(()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = saved {
+            error!(strategy_id = %id, error = %e, "Failed to checkpoint stopped strategy");
+            if let Some(instance) = self.strategies.write().await.get_mut(id) {
+                instance.stats.last_error = Some(e.to_string());
+            }
         }
 
         info!(strategy_id = %id, "Stopped strategy");
@@ -507,21 +850,30 @@ impl StrategyEngine {
     pub async fn get_strategy_type(&self, id: &str) -> Result<String, EngineError> {
         let strategies = self.strategies.read().await;
 
-        if !strategies.contains_key(id) {
-            return Err(EngineError::StrategyNotFound(id.to_string()));
-        }
+        let instance = strategies
+            .get(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+        Ok(instance_strategy_type(id, instance))
+    }
+
+    /// 전략 구독 타임프레임 설정.
+    ///
+    /// 설정하면 다른 타임프레임 캔들은 전략에 전달하지 않습니다 (`None`이면 모두 전달).
+    /// 다중 타임프레임 전략은 `multi_timeframe_config()`의 타임프레임을 따릅니다.
+    pub async fn set_strategy_timeframe(
+        &self,
+        id: &str,
+        timeframe: Option<Timeframe>,
+    ) -> Result<(), EngineError> {
+        let mut strategies = self.strategies.write().await;
 
-        // ID 형식: {strategy_type}_{uuid}
-        let strategy_type = id
-            .rsplit('_')
-            .skip(1)
-            .collect::<Vec<_>>()
-            .into_iter()
-            .rev()
-            .collect::<Vec<_>>()
-            .join("_");
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
 
-        Ok(strategy_type)
+        instance.timeframe = timeframe;
+        Ok(())
     }
 
     /// 모든 전략 목록.
@@ -633,12 +985,46 @@ impl StrategyEngine {
     /// - Primary TF 데이터: 모든 TF 데이터와 함께 `on_multi_timeframe_data()` 호출
     pub async fn process_market_data(&self, data: MarketData) -> Result<Vec<Signal>, EngineError> {
         let mut all_signals = Vec::new();
+        // 섀도 비교용 라이브 전략별 신호 (섀도가 없으면 수집하지 않음)
+        let mut live_signals: Option<HashMap<String, Vec<Signal>>> =
+            if self.shadows.read().await.is_empty() {
+                None
+            } else {
+                Some(HashMap::new())
+            };
+        // 캔들이면 지표 상태를 한 번만 갱신하고 각 전략 컨텍스트에 반영
+        let indicator_snapshot = match (&self.indicator_updater, &data.data) {
+            (Some(updater), MarketDataType::Kline(kline)) => updater.on_kline(kline),
+            _ => None,
+        };
         let mut strategies = self.strategies.write().await;
 
         for (id, instance) in strategies.iter_mut() {
             if !instance.running {
                 continue;
             }
+            let mut emitted = Vec::new();
+
+            if let Some(snapshot) = &indicator_snapshot {
+                instance
+                    .context
+                    .write()
+                    .await
+                    .update_indicator_snapshot(snapshot.clone());
+            }
+
+            // 구독하지 않은 타임프레임 캔들은 지표 스냅샷만 반영
+            if !instance.accepts(&data) {
+                continue;
+            }
+
+            // 배포 드라이런용 최근 데이터 보관
+            if self.config.deployment_history_size > 0 {
+                if instance.recent_data.len() >= self.config.deployment_history_size {
+                    instance.recent_data.pop_front();
+                }
+                instance.recent_data.push_back(data.clone());
+            }
 
             // 다중 타임프레임 전략 처리
             let signals_result =
@@ -653,70 +1039,239 @@ impl StrategyEngine {
             match signals_result {
                 Ok(signals) => {
                     instance.stats.market_data_processed += 1;
+                    emitted = self.accept_signals(id, instance, &signals).await;
+                }
+                Err(e) => {
+                    instance.stats.last_error = Some(e.to_string());
+                    error!(
+                        strategy_id = %id,
+                        error = %e,
+                        "Strategy error processing market data"
+                    );
+                }
+            }
 
-                    // StrategyContext를 통한 Signal 충돌 검증
-                    let ctx = instance.context.read().await;
-                    let (valid_signals, conflicts) = ctx.filter_valid_signals(&signals);
+            if let Some(live_signals) = live_signals.as_mut() {
+                live_signals.insert(id.clone(), emitted.clone());
+            }
+            all_signals.extend(emitted);
+        }
+        drop(strategies);
 
-                    // 충돌 로깅 및 이벤트 전송
-                    for (signal, error) in conflicts {
-                        warn!(
-                            strategy_id = %id,
-                            ticker = %signal.ticker,
-                            signal_type = ?signal.signal_type,
-                            error = %error,
-                            "Signal conflict detected - filtered out"
-                        );
+        if let Some(live_signals) = live_signals {
+            self.process_shadows(&data, &live_signals).await;
+        }
 
-                        // 충돌 이벤트 채널로 전송 (WebSocket 알림용)
-                        let event = SignalConflictEvent::from_error(id, signal, &error);
-                        if let Err(e) = self.conflict_tx.try_send(event) {
-                            debug!(error = %e, "Failed to send conflict event (channel full or closed)");
-                        }
-                    }
+        let all_signals = self.dispatch_signals(all_signals).await;
+
+        // 시장 데이터도 브로드캐스트
+        let _ = self.market_data_tx.send(data);
+
+        Ok(all_signals)
+    }
+
+    /// 일정 이벤트 처리.
+    ///
+    /// 실행 중인 전략의 일정 커서를 `now`까지 전진시키고, 발생한 이벤트마다
+    /// `on_schedule()`을 호출합니다. 신호는 시장 데이터 신호와 같은 충돌 검증,
+    /// 중복 제거를 거쳐 신호 채널로 전송됩니다.
+    pub async fn process_schedules(&self, now: DateTime<Utc>) -> Result<Vec<Signal>, EngineError> {
+        let calendars = self.calendars.read().await;
+        let mut all_signals = Vec::new();
+        let mut live_signals: HashMap<String, Vec<Signal>> = HashMap::new();
+        let mut strategies = self.strategies.write().await;
 
-                    for signal in valid_signals {
-                        instance.stats.signals_generated += 1;
-                        instance.stats.last_signal_time = Some(Utc::now());
+        for (id, instance) in strategies.iter_mut() {
+            if !instance.running {
+                continue;
+            }
+            let mut emitted = Vec::new();
 
-                        debug!(
+            for event in instance.due_schedule_events(&calendars, now) {
+                debug!(strategy_id = %id, trigger = event.name(), "Schedule event fired");
+                match instance.strategy.on_schedule(&event).await {
+                    Ok(signals) => {
+                        emitted.extend(self.accept_signals(id, instance, &signals).await);
+                    }
+                    Err(e) => {
+                        instance.stats.last_error = Some(e.to_string());
+                        error!(
                             strategy_id = %id,
-                            signal_type = %signal.signal_type,
-                            ticker = %signal.ticker,
-                            side = ?signal.side,
-                            "Strategy generated signal"
+                            trigger = event.name(),
+                            error = %e,
+                            "Strategy error handling schedule event"
                         );
+                    }
+                }
+            }
+
+            live_signals.insert(id.clone(), emitted.clone());
+            all_signals.extend(emitted);
+        }
+        drop(strategies);
 
-                        all_signals.push(signal.clone());
+        // 섀도도 같은 일정으로 구동 (라이브 전략이 실행 중인 경우만)
+        let mut shadows = self.shadows.write().await;
+        for (shadow_id, entry) in shadows.iter_mut() {
+            let Some(live) = live_signals.get(entry.ledger.live_strategy_id()) else {
+                continue;
+            };
+
+            let mut shadow_signals = Vec::new();
+            for event in entry.instance.due_schedule_events(&calendars, now) {
+                match entry.instance.strategy.on_schedule(&event).await {
+                    Ok(signals) => {
+                        let ctx = entry.instance.context.read().await;
+                        let (valid_signals, _) = ctx.filter_valid_signals(&signals);
+                        entry.instance.stats.signals_generated += valid_signals.len() as u64;
+                        shadow_signals.extend(valid_signals.into_iter().cloned());
+                    }
+                    Err(e) => {
+                        entry.instance.stats.last_error = Some(e.to_string());
+                        entry.ledger.record_error(e.to_string());
+                        warn!(shadow_id = %shadow_id, error = %e, "Shadow strategy error handling schedule event");
                     }
                 }
-                Err(e) => {
-                    instance.stats.last_error = Some(e.to_string());
-                    error!(
-                        strategy_id = %id,
-                        error = %e,
-                        "Strategy error processing market data"
-                    );
+            }
+            entry.instance.strategy.take_decision_traces();
+
+            if !live.is_empty() || !shadow_signals.is_empty() {
+                let fills = entry
+                    .ledger
+                    .record_signals(now, live, &shadow_signals)
+                    .await;
+                entry.apply_fills(shadow_id, fills).await;
+            }
+        }
+        drop(shadows);
+
+        Ok(self.dispatch_signals(all_signals).await)
+    }
+
+    /// 결정 추적을 메모리에 보관하고, 영구 저장소가 있으면 백그라운드로 저장.
+    ///
+    /// 시장 데이터 처리 중 전략 잠금을 잡고 있으므로 저장을 기다리지 않습니다.
+    async fn record_decision_traces(&self, traces: Vec<DecisionTrace>) {
+        if traces.is_empty() {
+            return;
+        }
+        if let Some(recorder) = &self.trace_recorder {
+            let recorder = Arc::clone(recorder);
+            let persisted = traces.clone();
+            tokio::spawn(async move {
+                if let Err(e) = recorder.record(&persisted).await {
+                    warn!(error = %e, count = persisted.len(), "결정 추적 저장 실패");
                 }
+            });
+        }
+        self.decision_traces.write().await.record_all(traces);
+    }
+
+    /// 전략 신호를 컨텍스트 기준으로 검증하고 통계 갱신.
+    ///
+    /// 충돌 신호는 로그와 충돌 이벤트 채널로 알리고 제외합니다.
+    /// 전략이 기록한 결정 추적도 이때 수거하며, 충돌로 제외된 신호의 추적은 억제로 표시합니다.
+    async fn accept_signals(
+        &self,
+        id: &str,
+        instance: &mut StrategyInstance,
+        signals: &[Signal],
+    ) -> Vec<Signal> {
+        // StrategyContext를 통한 Signal 충돌 검증
+        let ctx = instance.context.read().await;
+        let (valid_signals, conflicts) = ctx.filter_valid_signals(signals);
+
+        // 다중 레그 그룹은 한 레그라도 충돌하면 그룹 전체를 제외
+        let broken_groups: Vec<&str> = conflicts
+            .iter()
+            .filter(|(signal, _)| signal.leg_count().is_some())
+            .filter_map(|(signal, _)| signal.group_id.as_deref())
+            .collect();
+        let (valid_signals, orphaned_legs): (Vec<&Signal>, Vec<&Signal>) =
+            valid_signals.into_iter().partition(|signal| {
+                signal.leg_count().is_none()
+                    || !signal
+                        .group_id
+                        .as_deref()
+                        .is_some_and(|g| broken_groups.contains(&g))
+            });
+
+        let mut traces = link_signal_traces(signals, instance.strategy.take_decision_traces());
+        for (signal, error) in &conflicts {
+            if let Some(trace) = traces.iter_mut().find(|t| t.signal_id == Some(signal.id)) {
+                trace.suppress(
+                    TraceStepKind::Conflict,
+                    "signal_conflict",
+                    error.to_string(),
+                );
+            }
+        }
+        for signal in &orphaned_legs {
+            warn!(
+                strategy_id = %id,
+                ticker = %signal.ticker,
+                group_id = ?signal.group_id,
+                "Leg group broken by conflict - filtered out"
+            );
+            if let Some(trace) = traces.iter_mut().find(|t| t.signal_id == Some(signal.id)) {
+                trace.suppress(
+                    TraceStepKind::Conflict,
+                    "leg_group_conflict",
+                    "같은 그룹의 다른 레그가 충돌로 제외됨",
+                );
+            }
+        }
+        self.record_decision_traces(traces).await;
+
+        // 충돌 로깅 및 이벤트 전송
+        for (signal, error) in conflicts {
+            warn!(
+                strategy_id = %id,
+                ticker = %signal.ticker,
+                signal_type = ?signal.signal_type,
+                error = %error,
+                "Signal conflict detected - filtered out"
+            );
+
+            // 충돌 이벤트 채널로 전송 (WebSocket 알림용)
+            let event = SignalConflictEvent::from_error(id, signal, &error);
+            if let Err(e) = self.conflict_tx.try_send(event) {
+                debug!(error = %e, "Failed to send conflict event (channel full or closed)");
             }
         }
 
+        let mut accepted = Vec::with_capacity(valid_signals.len());
+        for signal in valid_signals {
+            instance.stats.signals_generated += 1;
+            instance.stats.last_signal_time = Some(Utc::now());
+
+            debug!(
+                strategy_id = %id,
+                signal_type = %signal.signal_type,
+                ticker = %signal.ticker,
+                side = ?signal.side,
+                "Strategy generated signal"
+            );
+
+            accepted.push(signal.clone());
+        }
+        accepted
+    }
+
+    /// 중복 제거 후 출력 채널로 신호 전송.
+    async fn dispatch_signals(&self, mut signals: Vec<Signal>) -> Vec<Signal> {
         // 활성화된 경우 신호 중복 제거
         if self.config.deduplicate_signals {
-            all_signals = self.deduplicate_signals(all_signals).await;
+            signals = self.deduplicate_signals(signals).await;
         }
 
         // 출력 채널로 신호 전송
-        for signal in &all_signals {
+        for signal in &signals {
             if let Err(e) = self.signal_tx.send(signal.clone()).await {
                 error!(error = %e, "Failed to send signal to channel");
             }
         }
-
-        // 시장 데이터도 브로드캐스트
-        let _ = self.market_data_tx.send(data);
-
-        Ok(all_signals)
+        signals
     }
 
     /// 다중 타임프레임 데이터 처리.
@@ -809,16 +1364,66 @@ impl StrategyEngine {
             .await
     }
 
-    /// 중복 제거 윈도우 내 신호 중복 제거.
-    async fn deduplicate_signals(&self, signals: Vec<Signal>) -> Vec<Signal> {
-        let mut recent = self.recent_signals.write().await;
-        let now = Utc::now();
-        let window_ms = self.config.dedup_window_ms as i64;
+    /// 실행 중인 라이브 전략의 섀도에 같은 시장 데이터 전달.
+    ///
+    /// 섀도 신호는 신호 채널로 보내지 않고 장부에서 가상 체결만 합니다.
+    async fn process_shadows(
+        &self,
+        data: &MarketData,
+        live_signals: &HashMap<String, Vec<Signal>>,
+    ) {
+        let mut shadows = self.shadows.write().await;
 
-        // 오래된 항목 정리
-        recent.retain(|_, ts| now.signed_duration_since(*ts).num_milliseconds() < window_ms);
+        for (shadow_id, entry) in shadows.iter_mut() {
+            // 라이브 전략이 이 데이터를 처리한 경우에만 비교
+            let Some(live) = live_signals.get(entry.ledger.live_strategy_id()) else {
+                continue;
+            };
 
-        let mut unique_signals = Vec::new();
+            let result = match entry.instance.strategy.multi_timeframe_config() {
+                Some(mtf_config) => {
+                    self.process_multi_timeframe_data(&mut entry.instance, data, &mtf_config)
+                        .await
+                }
+                None => entry.instance.strategy.on_market_data(data).await,
+            };
+
+            // 섀도의 결정 추적은 보관하지 않음
+            entry.instance.strategy.take_decision_traces();
+
+            let signals = match result {
+                Ok(signals) => {
+                    entry.instance.stats.market_data_processed += 1;
+
+                    // 섀도 가상 계좌/포지션 기준으로 충돌 신호 제외
+                    let ctx = entry.instance.context.read().await;
+                    let (valid_signals, _) = ctx.filter_valid_signals(&signals);
+                    entry.instance.stats.signals_generated += valid_signals.len() as u64;
+                    valid_signals.into_iter().cloned().collect()
+                }
+                Err(e) => {
+                    entry.instance.stats.last_error = Some(e.to_string());
+                    entry.ledger.record_error(e.to_string());
+                    warn!(shadow_id = %shadow_id, error = %e, "Shadow strategy error processing market data");
+                    Vec::new()
+                }
+            };
+
+            let fills = entry.ledger.record_bar(data, live, &signals).await;
+            entry.apply_fills(shadow_id, fills).await;
+        }
+    }
+
+    /// 중복 제거 윈도우 내 신호 중복 제거.
+    async fn deduplicate_signals(&self, signals: Vec<Signal>) -> Vec<Signal> {
+        let mut recent = self.recent_signals.write().await;
+        let now = Utc::now();
+        let window_ms = self.config.dedup_window_ms as i64;
+
+        // 오래된 항목 정리
+        recent.retain(|_, ts| now.signed_duration_since(*ts).num_milliseconds() < window_ms);
+
+        let mut unique_signals = Vec::new();
 
         for signal in signals {
             let key = format!(
@@ -865,6 +1470,17 @@ impl StrategyEngine {
                 instance.stats.orders_filled += 1;
             }
         }
+        drop(strategies);
+
+        // 섀도 비교용 실제 체결 기록
+        if let Some(strategy_id) = order.strategy_id.as_deref() {
+            let mut shadows = self.shadows.write().await;
+            for entry in shadows.values_mut() {
+                if entry.ledger.live_strategy_id() == strategy_id {
+                    entry.ledger.record_live_fill(order);
+                }
+            }
+        }
 
         Ok(())
     }
@@ -905,6 +1521,21 @@ impl StrategyEngine {
 
         let mut market_data_rx = self.market_data_tx.subscribe();
 
+        // 주기적 체크포인트 타이머 (저장소 미설정 또는 간격 0이면 비활성화)
+        let checkpoint_period = self
+            .checkpoint_interval()
+            .filter(|_| self.has_state_store());
+        let timer_period =
+            checkpoint_period.unwrap_or(Duration::from_secs(default_checkpoint_interval()));
+        let mut checkpoint_timer =
+            tokio::time::interval_at(tokio::time::Instant::now() + timer_period, timer_period);
+
+        // 일정 이벤트 타이머 (간격 0이면 비활성화)
+        let schedule_period = self.schedule_interval();
+        let mut schedule_timer = tokio::time::interval(
+            schedule_period.unwrap_or(Duration::from_secs(default_schedule_tick())),
+        );
+
         loop {
             tokio::select! {
                 result = market_data_rx.recv() => {
@@ -923,6 +1554,14 @@ impl StrategyEngine {
                         }
                     }
                 }
+                _ = checkpoint_timer.tick(), if checkpoint_period.is_some() => {
+                    self.checkpoint_all(SnapshotReason::Periodic).await;
+                }
+                _ = schedule_timer.tick(), if schedule_period.is_some() => {
+                    if let Err(e) = self.process_schedules(Utc::now()).await {
+                        error!(error = %e, "Error processing schedule events");
+                    }
+                }
                 _ = self.wait_for_shutdown() => {
                     info!("Strategy engine shutdown requested");
                     break;
@@ -954,7 +1593,11 @@ impl StrategyEngine {
     }
 
     /// 엔진 종료 요청.
+    ///
+    /// 실행 중인 전략의 상태를 먼저 저장한 뒤 종료 플래그를 설정합니다.
     pub async fn shutdown(&self) {
+        self.checkpoint_all(SnapshotReason::Shutdown).await;
+
         let mut running = self.running.write().await;
         *running = false;
     }
@@ -1023,6 +1666,9 @@ impl StrategyEngine {
     }
 
     /// 전략 설정 업데이트 (핫 리로드).
+    ///
+    /// 검증/드라이런/롤백 없이 즉시 재초기화합니다.
+    /// 운영 중인 전략은 [`Self::deploy_strategy`]로 교체하는 것을 권장합니다.
     pub async fn update_strategy_config(&self, id: &str, config: Value) -> Result<(), EngineError> {
         let mut strategies = self.strategies.write().await;
 
@@ -1031,14 +1677,10 @@ impl StrategyEngine {
             .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
 
         // config에서 name 필드 추출하여 custom_name으로 저장
-        let mut config_for_strategy = config.clone();
-        if let Some(obj) = config_for_strategy.as_object_mut() {
-            if let Some(name_value) = obj.remove("name") {
-                if let Some(name_str) = name_value.as_str() {
-                    instance.custom_name = Some(name_str.to_string());
-                    info!(strategy_id = %id, name = %name_str, "Updated strategy custom name");
-                }
-            }
+        let (config_for_strategy, custom_name) = split_custom_name(config);
+        if let Some(name) = custom_name {
+            info!(strategy_id = %id, name = %name, "Updated strategy custom name");
+            instance.custom_name = Some(name);
         }
 
         // 새 설정 저장 (name 필드 제외)
@@ -1057,179 +1699,2164 @@ impl StrategyEngine {
 
         Ok(())
     }
-}
-
-/// 엔진 통계.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EngineStats {
-    /// 등록된 전략 총 수
-    pub total_strategies: usize,
-    /// 현재 실행 중인 전략 수
-    pub running_strategies: usize,
-    /// 모든 전략에서 생성된 총 신호 수
-    pub total_signals_generated: u64,
-    /// 총 체결된 주문 수
-    pub total_orders_filled: u64,
-    /// 처리된 총 시장 데이터 이벤트 수
-    pub total_market_data_processed: u64,
-}
 
-#[cfg(test)]
-mod tests {
-    use async_trait::async_trait;
+    // =========================================================================
+    // 상태 체크포인트
+    // =========================================================================
 
-    use super::*;
+    /// 저장소의 최신 스냅샷을 전략에 적용.
+    ///
+    /// 복원 실패는 전략 등록/시작을 막지 않고 경고와 `last_error`로만 남깁니다.
+    async fn restore_instance(&self, id: &str, instance: &mut StrategyInstance) {
+        let Some(store) = &self.state_store else {
+            return;
+        };
 
-    /// 간단한 테스트 전략.
-    struct TestStrategy {
-        name: String,
-        signal_count: u32,
-    }
+        let snapshot = match store.latest(id).await {
+            Ok(Some(snapshot)) => snapshot,
+            Ok(None) => return,
+            Err(e) => {
+                instance.stats.last_error = Some(e.to_string());
+                warn!(strategy_id = %id, error = %e, "Failed to load strategy snapshot");
+                return;
+            }
+        };
 
-    impl TestStrategy {
-        fn new(name: &str) -> Self {
-            Self {
-                name: name.to_string(),
-                signal_count: 0,
+        match instance.strategy.load_state(&snapshot.state) {
+            Ok(()) => {
+                instance.last_checkpoint = Some(snapshot.state);
+                debug!(
+                    strategy_id = %id,
+                    version = snapshot.version,
+                    "Restored strategy state"
+                );
+            }
+            Err(e) => {
+                instance.stats.last_error = Some(e.to_string());
+                warn!(
+                    strategy_id = %id,
+                    version = snapshot.version,
+                    error = %e,
+                    "Failed to restore strategy state"
+                );
             }
         }
     }
 
-    #[async_trait]
-    impl Strategy for TestStrategy {
-        fn name(&self) -> &str {
-            &self.name
+    /// 저장할 체크포인트 준비.
+    ///
+    /// 상태 저장을 지원하지 않는 전략(빈 상태)이나 마지막 저장 이후 변경이 없는
+    /// 상태는 건너뜁니다 (`Manual`/`Rollback`은 항상 저장).
+    fn prepare_checkpoint(
+        id: &str,
+        instance: &StrategyInstance,
+        reason: SnapshotReason,
+        source_version: Option<i64>,
+    ) -> Result<Option<NewStrategySnapshot>, EngineError> {
+        let state = instance
+            .strategy
+            .save_state()
+            .map_err(|e| EngineError::CheckpointFailed(e.to_string()))?;
+        if state.is_empty() {
+            return Ok(None);
         }
 
-        fn version(&self) -> &str {
-            "1.0.0"
+        let forced = matches!(reason, SnapshotReason::Manual | SnapshotReason::Rollback);
+        if !forced && instance.last_checkpoint.as_deref() == Some(state.as_slice()) {
+            return Ok(None);
         }
 
-        fn description(&self) -> &str {
-            "Test strategy"
+        Ok(Some(NewStrategySnapshot {
+            strategy_id: id.to_string(),
+            strategy_name: instance.strategy.name().to_string(),
+            strategy_version: instance.strategy.version().to_string(),
+            reason,
+            source_version,
+            state,
+            diagnostics: instance.strategy.get_state(),
+        }))
+    }
+
+    /// 준비된 체크포인트 저장.
+    async fn persist_checkpoint(
+        store: &dyn StrategyStateStore,
+        snapshot: NewStrategySnapshot,
+    ) -> Result<StrategySnapshot, EngineError> {
+        let snapshot = store
+            .save(snapshot)
+            .await
+            .map_err(|e| EngineError::CheckpointFailed(e.to_string()))?;
+
+        debug!(
+            strategy_id = %snapshot.strategy_id,
+            version = snapshot.version,
+            reason = %snapshot.reason,
+            "Saved strategy checkpoint"
+        );
+        Ok(snapshot)
+    }
+
+    /// 준비된 체크포인트를 잠금 밖에서 저장하고 인스턴스에 반영.
+    async fn persist_pending(
+        &self,
+        id: &str,
+        pending: NewStrategySnapshot,
+    ) -> Result<StrategySnapshot, EngineError> {
+        let store = self
+            .state_store
+            .as_ref()
+            .ok_or(EngineError::StateStoreNotConfigured)?;
+        let snapshot = Self::persist_checkpoint(store.as_ref(), pending).await?;
+
+        if let Some(instance) = self.strategies.write().await.get_mut(id) {
+            instance.last_checkpoint = Some(snapshot.state.clone());
         }
+        Ok(snapshot)
+    }
 
-        async fn initialize(
-            &mut self,
-            _config: Value,
-        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-            Ok(())
+    /// 전략 상태 체크포인트 저장.
+    ///
+    /// 저장할 상태가 없거나 변경이 없으면 `None`을 반환합니다.
+    pub async fn checkpoint_strategy(
+        &self,
+        id: &str,
+        reason: SnapshotReason,
+    ) -> Result<Option<StrategySnapshot>, EngineError> {
+        if self.state_store.is_none() {
+            return Err(EngineError::StateStoreNotConfigured);
         }
 
-        async fn on_market_data(
-            &mut self,
-            data: &MarketData,
-        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
-            self.signal_count += 1;
+        // 읽기 잠금에서 상태만 직렬화하고, DB 저장은 잠금 해제 후 수행
+        let pending = {
+            let strategies = self.strategies.read().await;
+            let instance = strategies
+                .get(id)
+                .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+            Self::prepare_checkpoint(id, instance, reason, None)?
+        };
 
-            // 10개 데이터 포인트마다 신호 생성
-            if self.signal_count % 10 == 0 {
-                let signal = Signal::entry(&self.name, data.ticker.clone(), trader_core::Side::Buy);
-                Ok(vec![signal])
-            } else {
-                Ok(vec![])
-            }
+        match pending {
+            Some(pending) => self.persist_pending(id, pending).await.map(Some),
+            None => Ok(None),
         }
+    }
 
-        async fn on_order_filled(
-            &mut self,
-            _order: &Order,
-        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-            Ok(())
-        }
+    /// 실행 중인 모든 전략의 상태 체크포인트 저장.
+    ///
+    /// 개별 실패는 로그로 남기고 계속 진행하며, 저장된 스냅샷 수를 반환합니다.
+    pub async fn checkpoint_all(&self, reason: SnapshotReason) -> usize {
+        let Some(store) = &self.state_store else {
+            return 0;
+        };
 
-        async fn on_position_update(
-            &mut self,
-            _position: &Position,
-        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-            Ok(())
+        // 읽기 잠금에서 상태만 직렬화하고, DB 저장은 잠금 해제 후 수행 (전략 처리 차단 방지)
+        let mut failures: Vec<(String, String)> = Vec::new();
+        let pending: Vec<NewStrategySnapshot> = {
+            let strategies = self.strategies.read().await;
+            strategies
+                .iter()
+                .filter(|(_, instance)| instance.running)
+                .filter_map(|(id, instance)| {
+                    match Self::prepare_checkpoint(id, instance, reason, None) {
+                        Ok(pending) => pending,
+                        Err(e) => {
+                            failures.push((id.clone(), e.to_string()));
+                            None
+                        }
+                    }
+                })
+                .collect()
+        };
+
+        let mut persisted = Vec::with_capacity(pending.len());
+        for snapshot in pending {
+            let id = snapshot.strategy_id.clone();
+            match Self::persist_checkpoint(store.as_ref(), snapshot).await {
+                Ok(snapshot) => persisted.push((id, snapshot.state)),
+                Err(e) => failures.push((id, e.to_string())),
+            }
         }
 
-        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-            Ok(())
+        let saved = persisted.len();
+        if !persisted.is_empty() || !failures.is_empty() {
+            let mut strategies = self.strategies.write().await;
+            for (id, state) in persisted {
+                if let Some(instance) = strategies.get_mut(&id) {
+                    instance.last_checkpoint = Some(state);
+                }
+            }
+            for (id, message) in failures {
+                error!(strategy_id = %id, error = %message, "Failed to checkpoint strategy");
+                if let Some(instance) = strategies.get_mut(&id) {
+                    instance.stats.last_error = Some(message);
+                }
+            }
         }
 
-        fn get_state(&self) -> Value {
-            serde_json::json!({
-                "signal_count": self.signal_count
-            })
+        if saved > 0 {
+            info!(saved, reason = %reason, "Strategy checkpoints saved");
         }
-    }
 
-    #[tokio::test]
-    async fn test_engine_register_strategy() {
-        let engine = StrategyEngine::new(EngineConfig::default());
+        saved
+    }
 
-        let strategy = Box::new(TestStrategy::new("test"));
-        engine
-            .register_strategy("test1", strategy, serde_json::json!({}), None, None)
+    /// 전략 스냅샷 목록 조회 (최신순).
+    pub async fn list_snapshots(
+        &self,
+        id: &str,
+        limit: usize,
+    ) -> Result<Vec<SnapshotSummary>, EngineError> {
+        let store = self
+            .state_store
+            .as_ref()
+            .ok_or(EngineError::StateStoreNotConfigured)?;
+
+        store
+            .list(id, limit)
             .await
-            .unwrap();
-
-        let strategies = engine.list_strategies().await;
-        assert_eq!(strategies.len(), 1);
-        assert!(strategies.contains(&"test1".to_string()));
+            .map_err(|e| EngineError::CheckpointFailed(e.to_string()))
     }
 
-    #[tokio::test]
-    async fn test_engine_register_strategy_with_custom_name() {
-        let engine = StrategyEngine::new(EngineConfig::default());
-
-        let strategy = Box::new(TestStrategy::new("test"));
-        engine
-            .register_strategy(
-                "test1",
-                strategy,
-                serde_json::json!({}),
-                Some("나의 커스텀 전략".to_string()),
-                None,
-            )
-            .await
-            .unwrap();
+    /// 전략 스냅샷 조회 (`version`이 None이면 최신).
+    pub async fn get_snapshot(
+        &self,
+        id: &str,
+        version: Option<i64>,
+    ) -> Result<StrategySnapshot, EngineError> {
+        let store = self
+            .state_store
+            .as_ref()
+            .ok_or(EngineError::StateStoreNotConfigured)?;
+
+        let snapshot = match version {
+            Some(version) => store.get(id, version).await,
+            None => store.latest(id).await,
+        }
+        .map_err(|e| EngineError::CheckpointFailed(e.to_string()))?;
 
-        let status = engine.get_strategy_status("test1").await.unwrap();
-        assert_eq!(status.name, "나의 커스텀 전략");
+        snapshot.ok_or_else(|| match version {
+            Some(version) => EngineError::SnapshotNotFound(format!("{} v{}", id, version)),
+            None => EngineError::SnapshotNotFound(id.to_string()),
+        })
     }
 
-    #[tokio::test]
-    async fn test_engine_start_stop_strategy() {
-        let engine = StrategyEngine::new(EngineConfig::default());
+    /// 전략 상태를 과거 스냅샷 버전으로 롤백.
+    ///
+    /// 해당 버전의 상태를 전략에 적용하고, 재시작 시에도 유지되도록
+    /// 같은 상태를 `Rollback` 사유의 새 버전으로 저장합니다.
+    pub async fn rollback_strategy(
+        &self,
+        id: &str,
+        version: i64,
+    ) -> Result<StrategySnapshot, EngineError> {
+        let target = self.get_snapshot(id, Some(version)).await?;
 
-        let strategy = Box::new(TestStrategy::new("test"));
-        engine
-            .register_strategy("test1", strategy, serde_json::json!({}), None, None)
-            .await
-            .unwrap();
+        let pending = {
+            let mut strategies = self.strategies.write().await;
+            let instance = strategies
+                .get_mut(id)
+                .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
 
-        // 전략 시작
-        engine.start_strategy("test1").await.unwrap();
+            instance
+                .strategy
+                .load_state(&target.state)
+                .map_err(|e| EngineError::CheckpointFailed(e.to_string()))?;
 
-        let status = engine.get_strategy_status("test1").await.unwrap();
-        assert!(status.running);
+            Self::prepare_checkpoint(id, instance, SnapshotReason::Rollback, Some(version))?
+                .ok_or_else(|| {
+                    EngineError::CheckpointFailed(format!("전략 {}의 상태를 저장할 수 없음", id))
+                })?
+        };
+        let snapshot = self.persist_pending(id, pending).await?;
 
-        // 전략 중지
-        engine.stop_strategy("test1").await.unwrap();
+        info!(
+            strategy_id = %id,
+            from_version = version,
+            new_version = snapshot.version,
+            "Rolled back strategy state"
+        );
 
-        let status = engine.get_strategy_status("test1").await.unwrap();
-        assert!(!status.running);
+        Ok(snapshot)
     }
 
-    #[tokio::test]
-    async fn test_duplicate_strategy_error() {
-        let engine = StrategyEngine::new(EngineConfig::default());
+    // =========================================================================
+    // 버전 배포
+    // =========================================================================
 
-        let strategy1 = Box::new(TestStrategy::new("test"));
-        let strategy2 = Box::new(TestStrategy::new("test"));
+    /// 새 전략 버전을 교체 없이 평가.
+    ///
+    /// 스키마 검증, 초기화 시험, 현재 설정 대비 diff, 최근 `dry_run_bars`개
+    /// 시장 데이터에 대한 드라이런 비교를 수행합니다.
+    pub async fn preview_deployment(
+        &self,
+        id: &str,
+        mut candidate: DeploymentCandidate,
+        dry_run_bars: usize,
+    ) -> Result<DeploymentPreview, EngineError> {
+        self.evaluate_candidate(id, &mut candidate, dry_run_bars)
+            .await
+    }
 
-        engine
-            .register_strategy("test1", strategy1, serde_json::json!({}), None, None)
+    /// 새 전략 버전 배포.
+    ///
+    /// 검증과 드라이런을 통과하면 실행 중인 전략의 `save_state()` 데이터를 새
+    /// 인스턴스에 `load_state()`로 이어받게 한 뒤 교체합니다. 이력 기록(새 버전
+    /// 활성화와 이전 버전 대체)은 한 번에 적용되며, 전략 쓰기 잠금은 기록 후
+    /// 메모리 교체 동안만 잡습니다. 거부된 배포도 `Rejected` 상태로 이력에 남습니다.
+    pub async fn deploy_strategy(
+        &self,
+        id: &str,
+        mut candidate: DeploymentCandidate,
+        options: DeploymentOptions,
+    ) -> Result<StrategyDeployment, EngineError> {
+        let _deploying = self.deployment_lock.lock().await;
+        let preview = self
+            .evaluate_candidate(id, &mut candidate, options.dry_run_bars)
+            .await?;
+
+        let agreement = preview.dry_run.as_ref().and_then(DryRunReport::agreement);
+        let rejection = if !preview.is_valid() {
+            Some(preview.validation_errors.join("; "))
+        } else {
+            match (options.min_agreement, agreement) {
+                (Some(min), Some(agreement)) if agreement < min => Some(format!(
+                    "드라이런 신호 일치율 {:.1}%가 기준 {:.1}% 미만",
+                    agreement * 100.0,
+                    min * 100.0
+                )),
+                _ => None,
+            }
+        };
+        if let Some(reason) = rejection {
+            return Err(self
+                .reject_deployment(id, &candidate, preview, reason)
+                .await);
+        }
+
+        // 상태 이전 시험 (읽기 잠금만 사용)
+        let (carried, baseline) = {
+            let strategies = self.strategies.read().await;
+            let instance = strategies
+                .get(id)
+                .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+            (
+                carry_over_state(instance, &mut candidate.strategy),
+                baseline_deployment(id, instance),
+            )
+        };
+        let state_carried_over = match carried {
+            Ok(carried) => carried,
+            Err(reason) => {
+                return Err(self
+                    .reject_deployment(id, &candidate, preview, reason)
+                    .await);
+            }
+        };
+
+        let previous_version = self.active_deployment_version(baseline).await?;
+        let deployment = self
+            .deployment_store
+            .activate(
+                NewStrategyDeployment {
+                    strategy_id: id.to_string(),
+                    strategy_type: candidate.strategy_type.clone(),
+                    strategy_version: candidate.strategy.version().to_string(),
+                    source: candidate.source,
+                    status: DeploymentStatus::Active,
+                    config: candidate.config.clone(),
+                    changes: preview.changes,
+                    dry_run: preview.dry_run,
+                    state_carried_over,
+                    rollback_of: None,
+                    message: None,
+                },
+                previous_version,
+                DeploymentStatus::Superseded,
+            )
             .await
-            .unwrap();
+            .map_err(|e| EngineError::DeploymentFailed(e.to_string()))?;
 
-        let result = engine
-            .register_strategy("test1", strategy2, serde_json::json!({}), None, None)
-            .await;
+        let mut strategies = self.strategies.write().await;
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+        // 시험 이후 처리된 시장 데이터까지 반영
+        if let Err(reason) = carry_over_state(instance, &mut candidate.strategy) {
+            warn!(strategy_id = %id, reason = %reason, "Deployed with state from before recording");
+        }
+        if let Some(name) = candidate.custom_name {
+            instance.custom_name = Some(name);
+        }
+        swap_version(
+            instance,
+            candidate.strategy,
+            candidate.config,
+            candidate.strategy_type,
+            previous_version,
+        )
+        .await;
 
-        assert!(matches!(result, Err(EngineError::StrategyAlreadyExists(_))));
+        info!(
+            strategy_id = %id,
+            version = deployment.version,
+            previous_version,
+            state_carried_over,
+            "Deployed strategy version"
+        );
+
+        Ok(deployment)
+    }
+
+    /// 이전 배포 버전으로 롤백.
+    ///
+    /// `version`이 None이면 현재 활성 버전 직전의 버전으로 되돌립니다.
+    /// 직전 버전 인스턴스가 메모리에 남아 있으면 그대로 재사용하고, 아니면
+    /// 기록된 설정으로 레지스트리에서 다시 생성합니다. 현재 상태는 되돌린
+    /// 버전으로 이어지며, 롤백 자체도 새 배포 버전으로 기록됩니다.
+    pub async fn rollback_deployment(
+        &self,
+        id: &str,
+        version: Option<i64>,
+    ) -> Result<StrategyDeployment, EngineError> {
+        let _deploying = self.deployment_lock.lock().await;
+
+        let history = self
+            .deployment_store
+            .list(id, usize::MAX)
+            .await
+            .map_err(|e| EngineError::DeploymentFailed(e.to_string()))?;
+        let active = history
+            .iter()
+            .find(|d| d.status == DeploymentStatus::Active)
+            .ok_or_else(|| EngineError::DeploymentNotFound(format!("{}의 활성 배포", id)))?;
+
+        let target = match version {
+            Some(version) => history.iter().find(|d| d.version == version),
+            None => history.iter().find(|d| {
+                d.version < active.version
+                    && !matches!(
+                        d.status,
+                        DeploymentStatus::Active | DeploymentStatus::Rejected
+                    )
+            }),
+        }
+        .ok_or_else(|| match version {
+            Some(version) => EngineError::DeploymentNotFound(format!("{} v{}", id, version)),
+            None => EngineError::DeploymentNotFound(format!("{}의 이전 배포", id)),
+        })?;
+
+        if target.version == active.version {
+            return Err(EngineError::DeploymentRejected(format!(
+                "v{}는 이미 활성 버전",
+                target.version
+            )));
+        }
+        if target.status == DeploymentStatus::Rejected {
+            return Err(EngineError::DeploymentRejected(format!(
+                "거부된 버전 v{}로는 롤백할 수 없음",
+                target.version
+            )));
+        }
+
+        // 직전 버전 인스턴스 재사용, 없으면 레지스트리에서 재생성 (잠금 밖에서 초기화)
+        let reused = {
+            let strategies = self.strategies.read().await;
+            let instance = strategies
+                .get(id)
+                .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+            instance
+                .previous
+                .as_ref()
+                .is_some_and(|previous| previous.version == target.version)
+        };
+        let recreated = if reused {
+            None
+        } else {
+            if target.source != DeploymentSource::Registry {
+                return Err(EngineError::DeploymentRejected(format!(
+                    "플러그인 버전 v{}는 메모리에 남아 있을 때만 롤백 가능",
+                    target.version
+                )));
+            }
+            let mut strategy = StrategyRegistry::create_instance(&target.strategy_type)
+                .map_err(EngineError::DeploymentFailed)?;
+            strategy
+                .initialize(target.config.clone())
+                .await
+                .map_err(|e| EngineError::InitializationFailed(e.to_string()))?;
+            Some(strategy)
+        };
+
+        // 상태 이전 시험 (메모리 작업만 쓰기 잠금 안에서)
+        let (mut restored, state_carried_over, changes) = {
+            let mut strategies = self.strategies.write().await;
+            let instance = strategies
+                .get_mut(id)
+                .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+            let mut restored = match recreated {
+                Some(strategy) => strategy,
+                None => take_previous(instance, target.version)?,
+            };
+            match carry_over_state(instance, &mut restored) {
+                Ok(carried) => (
+                    restored,
+                    carried,
+                    diff_configs(&instance.config, &target.config),
+                ),
+                Err(reason) => {
+                    if reused {
+                        restore_previous(instance, restored, target.version);
+                    }
+                    return Err(EngineError::DeploymentFailed(reason));
+                }
+            }
+        };
+
+        let recorded = self
+            .deployment_store
+            .activate(
+                NewStrategyDeployment {
+                    strategy_id: id.to_string(),
+                    strategy_type: target.strategy_type.clone(),
+                    strategy_version: restored.version().to_string(),
+                    source: target.source,
+                    status: DeploymentStatus::Active,
+                    config: target.config.clone(),
+                    changes,
+                    dry_run: None,
+                    state_carried_over,
+                    rollback_of: Some(target.version),
+                    message: None,
+                },
+                active.version,
+                DeploymentStatus::RolledBack,
+            )
+            .await
+            .map_err(|e| EngineError::DeploymentFailed(e.to_string()));
+
+        let mut strategies = self.strategies.write().await;
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+        let deployment = match recorded {
+            Ok(deployment) => deployment,
+            Err(e) => {
+                if reused {
+                    restore_previous(instance, restored, target.version);
+                }
+                return Err(e);
+            }
+        };
+        // 시험 이후 처리된 시장 데이터까지 반영
+        if let Err(reason) = carry_over_state(instance, &mut restored) {
+            warn!(strategy_id = %id, reason = %reason, "Rolled back with state from before recording");
+        }
+
+        swap_version(
+            instance,
+            restored,
+            target.config.clone(),
+            target.strategy_type.clone(),
+            active.version,
+        )
+        .await;
+
+        info!(
+            strategy_id = %id,
+            from_version = active.version,
+            to_version = target.version,
+            new_version = deployment.version,
+            "Rolled back strategy deployment"
+        );
+
+        Ok(deployment)
+    }
+
+    /// 전략 배포 이력 조회 (최신순).
+    pub async fn list_deployments(
+        &self,
+        id: &str,
+        limit: usize,
+    ) -> Result<Vec<StrategyDeployment>, EngineError> {
+        self.deployment_store
+            .list(id, limit)
+            .await
+            .map_err(|e| EngineError::DeploymentFailed(e.to_string()))
+    }
+
+    /// 특정 배포 버전 조회.
+    pub async fn get_deployment(
+        &self,
+        id: &str,
+        version: i64,
+    ) -> Result<StrategyDeployment, EngineError> {
+        self.deployment_store
+            .get(id, version)
+            .await
+            .map_err(|e| EngineError::DeploymentFailed(e.to_string()))?
+            .ok_or_else(|| EngineError::DeploymentNotFound(format!("{} v{}", id, version)))
+    }
+
+    /// 배포 대상 검증, diff, 드라이런.
+    async fn evaluate_candidate(
+        &self,
+        id: &str,
+        candidate: &mut DeploymentCandidate,
+        dry_run_bars: usize,
+    ) -> Result<DeploymentPreview, EngineError> {
+        // 재생 중 시장 데이터 처리를 막지 않도록 필요한 값만 복사
+        let (current_config, current_type, bars, context) = {
+            let strategies = self.strategies.read().await;
+            let instance = strategies
+                .get(id)
+                .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+            let skip = instance.recent_data.len().saturating_sub(dry_run_bars);
+            let bars: Vec<MarketData> = instance.recent_data.iter().skip(skip).cloned().collect();
+            let context = instance.context.read().await.clone();
+            (
+                instance.config.clone(),
+                instance_strategy_type(id, instance),
+                bars,
+                context,
+            )
+        };
+
+        let mut validation_errors = candidate.schema_errors();
+        if validation_errors.is_empty() {
+            if let Err(e) = candidate
+                .strategy
+                .initialize(candidate.config.clone())
+                .await
+            {
+                validation_errors.push(format!("초기화 실패: {}", e));
+            }
+        }
+
+        let dry_run = match candidate.shadow.take() {
+            Some(shadow) if validation_errors.is_empty() && !bars.is_empty() => {
+                let baseline = match StrategyRegistry::create_instance(&current_type) {
+                    Ok(strategy) => Some(
+                        self.replay(strategy, &current_config, &context, &bars)
+                            .await,
+                    ),
+                    Err(e) => {
+                        debug!(strategy_id = %id, error = %e, "Baseline not replayable");
+                        None
+                    }
+                };
+                let (candidate_signals, candidate_errors) = self
+                    .replay(shadow, &candidate.config, &context, &bars)
+                    .await;
+
+                let mut report = DryRunReport::compare(
+                    baseline.as_ref().map(|(signals, _)| signals.as_slice()),
+                    &candidate_signals,
+                );
+                for error in baseline
+                    .into_iter()
+                    .flat_map(|(_, errors)| errors)
+                    .chain(candidate_errors)
+                {
+                    report.record_error(error);
+                }
+                Some(report)
+            }
+            _ => None,
+        };
+
+        Ok(DeploymentPreview {
+            strategy_id: id.to_string(),
+            changes: diff_configs(&current_config, &candidate.config),
+            validation_errors,
+            dry_run,
+        })
+    }
+
+    /// 독립 컨텍스트에서 새 인스턴스로 시장 데이터 재생.
+    ///
+    /// 양쪽 버전이 같은 출발점에서 비교되도록 저장된 상태는 불러오지 않습니다.
+    async fn replay(
+        &self,
+        mut strategy: Box<dyn Strategy>,
+        config: &Value,
+        context: &StrategyContext,
+        bars: &[MarketData],
+    ) -> (Vec<Vec<Signal>>, Vec<String>) {
+        let context = Arc::new(RwLock::new(context.clone()));
+        strategy.set_context(Arc::clone(&context));
+
+        let mut errors = Vec::new();
+        if let Err(e) = strategy.initialize(config.clone()).await {
+            errors.push(format!("{}: 초기화 실패: {}", strategy.name(), e));
+            return (vec![Vec::new(); bars.len()], errors);
+        }
+
+        let mut instance = StrategyInstance {
+            strategy,
+            config: config.clone(),
+            running: true,
+            stats: StrategyStats::default(),
+            custom_name: None,
+            context,
+            last_checkpoint: None,
+            strategy_type: None,
+            recent_data: VecDeque::new(),
+            previous: None,
+            schedule_clock: ScheduleClock::new(),
+            timeframe: None,
+        };
+
+        let schedule = instance.strategy.schedule();
+        let calendar = match &schedule {
+            Some(schedule) => Some(self.calendar(schedule.country).await),
+            None => None,
+        };
+
+        let mut signals = Vec::with_capacity(bars.len());
+        for data in bars {
+            // 일정 이벤트는 캔들 경계 기준으로 백테스트와 같은 순서로 전달
+            let (before, during) = match (&schedule, &calendar, &data.data) {
+                (Some(schedule), Some(calendar), MarketDataType::Kline(kline)) => instance
+                    .schedule_clock
+                    .advance_bar(schedule, calendar, kline),
+                _ => Default::default(),
+            };
+
+            let mut bar_signals = Vec::new();
+            for event in &before {
+                match instance.strategy.on_schedule(event).await {
+                    Ok(event_signals) => bar_signals.extend(event_signals),
+                    Err(e) => errors.push(format!("{}: {}", instance.strategy.name(), e)),
+                }
+            }
+
+            let result = match instance.strategy.multi_timeframe_config() {
+                Some(mtf_config) => {
+                    self.process_multi_timeframe_data(&mut instance, data, &mtf_config)
+                        .await
+                }
+                None => instance.strategy.on_market_data(data).await,
+            };
+            match result {
+                Ok(data_signals) => bar_signals.extend(data_signals),
+                Err(e) => errors.push(format!("{}: {}", instance.strategy.name(), e)),
+            }
+
+            for event in &during {
+                match instance.strategy.on_schedule(event).await {
+                    Ok(event_signals) => bar_signals.extend(event_signals),
+                    Err(e) => errors.push(format!("{}: {}", instance.strategy.name(), e)),
+                }
+            }
+            // 드라이런 재생의 결정 추적은 보관하지 않음
+            instance.strategy.take_decision_traces();
+            signals.push(bar_signals);
+        }
+
+        (signals, errors)
+    }
+
+    /// 현재 활성 배포 버전 (배포 이력이 없으면 실행 중인 설정을 첫 버전으로 기록).
+    async fn active_deployment_version(
+        &self,
+        baseline: NewStrategyDeployment,
+    ) -> Result<i64, EngineError> {
+        let active = self
+            .deployment_store
+            .active(&baseline.strategy_id)
+            .await
+            .map_err(|e| EngineError::DeploymentFailed(e.to_string()))?;
+        if let Some(active) = active {
+            return Ok(active.version);
+        }
+
+        let baseline = self
+            .deployment_store
+            .save(baseline)
+            .await
+            .map_err(|e| EngineError::DeploymentFailed(e.to_string()))?;
+
+        Ok(baseline.version)
+    }
+
+    /// 거부된 배포를 기록하고 반환할 에러 생성.
+    async fn reject_deployment(
+        &self,
+        id: &str,
+        candidate: &DeploymentCandidate,
+        preview: DeploymentPreview,
+        reason: String,
+    ) -> EngineError {
+        let record = NewStrategyDeployment {
+            strategy_id: id.to_string(),
+            strategy_type: candidate.strategy_type.clone(),
+            strategy_version: candidate.strategy.version().to_string(),
+            source: candidate.source,
+            status: DeploymentStatus::Rejected,
+            config: candidate.config.clone(),
+            changes: preview.changes,
+            dry_run: preview.dry_run,
+            state_carried_over: false,
+            rollback_of: None,
+            message: Some(reason.clone()),
+        };
+
+        match self.deployment_store.save(record).await {
+            Ok(rejected) => {
+                warn!(strategy_id = %id, version = rejected.version, reason = %reason, "Strategy deployment rejected");
+            }
+            Err(e) => {
+                error!(strategy_id = %id, error = %e, "Failed to record rejected deployment");
+            }
+        }
+
+        EngineError::DeploymentRejected(reason)
+    }
+
+    // =========================================================================
+    // 섀도(페이퍼) 모드
+    // =========================================================================
+
+    /// 실행 중인 전략 옆에서 후보 설정을 섀도로 시작.
+    ///
+    /// 섀도는 라이브 컨텍스트의 독립 복사본과 라이브 전략의 `save_state()` 상태로
+    /// 출발하여 이후 같은 시장 데이터를 받습니다. 신호는 브로커로 가지 않고
+    /// `SimulatedExecutor`로 가상 체결되며, 라이브 신호도 같은 체결 모델로
+    /// 함께 체결하여 비교합니다. 섀도 컨텍스트의 계좌/포지션은 가상 계좌
+    /// 기준이고, 가상 체결은 섀도 전략의 `on_order_filled`/`on_position_update`로
+    /// 전달됩니다. 생성된 섀도 ID를 반환합니다.
+    pub async fn start_shadow(
+        &self,
+        live_id: &str,
+        mut candidate: DeploymentCandidate,
+        config: ShadowConfig,
+    ) -> Result<String, EngineError> {
+        let (context, state) = {
+            let strategies = self.strategies.read().await;
+            let instance = strategies
+                .get(live_id)
+                .ok_or_else(|| EngineError::StrategyNotFound(live_id.to_string()))?;
+            if !instance.running {
+                return Err(EngineError::NotRunning(live_id.to_string()));
+            }
+            let context = instance.context.read().await.clone();
+            let state = instance.strategy.save_state().unwrap_or_default();
+            (context, state)
+        };
+
+        let validation_errors = candidate.schema_errors();
+        if !validation_errors.is_empty() {
+            return Err(EngineError::ShadowRejected(validation_errors.join("; ")));
+        }
+
+        let context = Arc::new(RwLock::new(context));
+        candidate.strategy.set_context(Arc::clone(&context));
+        candidate
+            .strategy
+            .initialize(candidate.config.clone())
+            .await
+            .map_err(|e| EngineError::ShadowRejected(format!("초기화 실패: {}", e)))?;
+
+        // 호환되지 않는 상태면 초기 상태로 출발
+        if !state.is_empty() {
+            if let Err(e) = candidate.strategy.load_state(&state) {
+                debug!(strategy_id = %live_id, error = %e, "Shadow started without live state");
+            }
+        }
+
+        let mut shadows = self.shadows.write().await;
+        let shadow_id = (1..)
+            .map(|n| format!("{}_shadow_{}", live_id, n))
+            .find(|id| !shadows.contains_key(id))
+            .unwrap_or_default();
+
+        let ledger = ShadowLedger::new(
+            shadow_id.clone(),
+            live_id,
+            candidate.strategy_type.clone(),
+            candidate.config.clone(),
+            &config,
+        );
+        ledger.sync_context(&mut *context.write().await);
+        let instance = StrategyInstance {
+            strategy: candidate.strategy,
+            config: candidate.config,
+            running: true,
+            stats: StrategyStats {
+                started_at: Some(Utc::now()),
+                ..StrategyStats::default()
+            },
+            custom_name: candidate.custom_name,
+            context,
+            last_checkpoint: None,
+            strategy_type: Some(candidate.strategy_type),
+            recent_data: VecDeque::new(),
+            previous: None,
+            schedule_clock: ScheduleClock::starting_at(Utc::now()),
+            timeframe: None,
+        };
+        shadows.insert(shadow_id.clone(), ShadowEntry { instance, ledger });
+
+        info!(strategy_id = %live_id, shadow_id = %shadow_id, "Started shadow strategy");
+        Ok(shadow_id)
+    }
+
+    /// 섀도 중지 후 최종 비교 결과 반환.
+    pub async fn stop_shadow(&self, shadow_id: &str) -> Result<ShadowComparison, EngineError> {
+        let mut entry = self
+            .shadows
+            .write()
+            .await
+            .remove(shadow_id)
+            .ok_or_else(|| EngineError::ShadowNotFound(shadow_id.to_string()))?;
+
+        if let Err(e) = entry.instance.strategy.shutdown().await {
+            warn!(shadow_id = %shadow_id, error = %e, "Shadow strategy shutdown error");
+        }
+
+        info!(shadow_id = %shadow_id, "Stopped shadow strategy");
+        Ok(entry.ledger.comparison())
+    }
+
+    /// 섀도 목록 (`live_id`가 있으면 해당 라이브 전략의 섀도만, 시작 순).
+    pub async fn list_shadows(&self, live_id: Option<&str>) -> Vec<ShadowSummary> {
+        let shadows = self.shadows.read().await;
+        let mut summaries: Vec<ShadowSummary> = shadows
+            .values()
+            .filter(|entry| live_id.map_or(true, |id| entry.ledger.live_strategy_id() == id))
+            .map(|entry| entry.ledger.summary())
+            .collect();
+        summaries.sort_by_key(|summary| summary.started_at);
+        summaries
+    }
+
+    /// 라이브 대 섀도 신호/체결/손익 비교 조회.
+    pub async fn get_shadow_comparison(
+        &self,
+        shadow_id: &str,
+    ) -> Result<ShadowComparison, EngineError> {
+        self.shadows
+            .read()
+            .await
+            .get(shadow_id)
+            .map(|entry| entry.ledger.comparison())
+            .ok_or_else(|| EngineError::ShadowNotFound(shadow_id.to_string()))
+    }
+}
+
+/// 전략 ID에서 전략 타입 추출 (ID 형식: `{strategy_type}_{uuid}`).
+fn strategy_type_from_id(id: &str) -> String {
+    id.rsplit('_')
+        .skip(1)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// 인스턴스의 전략 타입 (배포로 확정된 타입 우선).
+fn instance_strategy_type(id: &str, instance: &StrategyInstance) -> String {
+    instance
+        .strategy_type
+        .clone()
+        .unwrap_or_else(|| strategy_type_from_id(id))
+}
+
+/// 실행 중인 전략의 `save_state()` 데이터를 새 인스턴스로 이전.
+///
+/// 새 인스턴스는 같은 컨텍스트를 공유합니다. 저장할 상태가 없으면 `false`.
+fn carry_over_state(
+    instance: &StrategyInstance,
+    target: &mut Box<dyn Strategy>,
+) -> Result<bool, String> {
+    target.set_context(Arc::clone(&instance.context));
+
+    let state = instance
+        .strategy
+        .save_state()
+        .map_err(|e| format!("현재 상태 직렬화 실패: {}", e))?;
+    if state.is_empty() {
+        return Ok(false);
+    }
+
+    target
+        .load_state(&state)
+        .map_err(|e| format!("상태 이전 실패: {}", e))?;
+    Ok(true)
+}
+
+/// 배포 이력이 없는 실행 중 전략의 첫 버전 기록.
+fn baseline_deployment(id: &str, instance: &StrategyInstance) -> NewStrategyDeployment {
+    NewStrategyDeployment {
+        strategy_id: id.to_string(),
+        strategy_type: instance_strategy_type(id, instance),
+        strategy_version: instance.strategy.version().to_string(),
+        source: DeploymentSource::Registry,
+        status: DeploymentStatus::Active,
+        config: instance.config.clone(),
+        changes: Vec::new(),
+        dry_run: None,
+        state_carried_over: false,
+        rollback_of: None,
+        message: Some("배포 이력 이전의 실행 버전".to_string()),
+    }
+}
+
+/// 롤백 대상인 직전 버전 구현 꺼내기.
+fn take_previous(
+    instance: &mut StrategyInstance,
+    version: i64,
+) -> Result<Box<dyn Strategy>, EngineError> {
+    match instance.previous.take() {
+        Some(previous) if previous.version == version => Ok(previous.strategy),
+        previous => {
+            instance.previous = previous;
+            Err(EngineError::DeploymentFailed(format!(
+                "직전 버전 v{} 인스턴스가 메모리에 없음",
+                version
+            )))
+        }
+    }
+}
+
+/// 롤백 실패 시 꺼낸 직전 버전 구현 되돌려 놓기.
+fn restore_previous(instance: &mut StrategyInstance, strategy: Box<dyn Strategy>, version: i64) {
+    instance.previous = Some(PreviousVersion { strategy, version });
+}
+
+/// 인스턴스의 전략 구현을 교체하고 이전 구현을 롤백용으로 보관.
+async fn swap_version(
+    instance: &mut StrategyInstance,
+    strategy: Box<dyn Strategy>,
+    config: Value,
+    strategy_type: String,
+    replaced_version: i64,
+) {
+    let replaced = std::mem::replace(&mut instance.strategy, strategy);
+    instance.config = config;
+    instance.strategy_type = Some(strategy_type);
+    instance.previous = Some(PreviousVersion {
+        strategy: replaced,
+        version: replaced_version,
+    });
+
+    // 새 설정의 관심 종목 등록
+    if instance.running {
+        let tickers = extract_tickers_from_config(&instance.config);
+        if !tickers.is_empty() {
+            instance.context.write().await.add_watched_tickers(&tickers);
+        }
+    }
+}
+
+/// 엔진 통계.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineStats {
+    /// 등록된 전략 총 수
+    pub total_strategies: usize,
+    /// 현재 실행 중인 전략 수
+    pub running_strategies: usize,
+    /// 모든 전략에서 생성된 총 신호 수
+    pub total_signals_generated: u64,
+    /// 총 체결된 주문 수
+    pub total_orders_filled: u64,
+    /// 처리된 총 시장 데이터 이벤트 수
+    pub total_market_data_processed: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// 간단한 테스트 전략.
+    struct TestStrategy {
+        name: String,
+        signal_count: u32,
+        schedule: Option<crate::StrategySchedule>,
+    }
+
+    impl TestStrategy {
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+                signal_count: 0,
+                schedule: None,
+            }
+        }
+
+        fn with_schedule(mut self, schedule: crate::StrategySchedule) -> Self {
+            self.schedule = Some(schedule);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl Strategy for TestStrategy {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Test strategy"
+        }
+
+        async fn initialize(
+            &mut self,
+            config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            if config.get("invalid").is_some() {
+                return Err("잘못된 설정".into());
+            }
+            self.signal_count = 0;
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            self.signal_count += 1;
+
+            // 10개 데이터 포인트마다 신호 생성
+            if self.signal_count.is_multiple_of(10) {
+                let signal = Signal::entry(&self.name, data.ticker.clone(), trader_core::Side::Buy);
+                Ok(vec![signal])
+            } else {
+                Ok(vec![])
+            }
+        }
+
+        fn schedule(&self) -> Option<crate::StrategySchedule> {
+            self.schedule.clone()
+        }
+
+        async fn on_schedule(
+            &mut self,
+            event: &ScheduleEvent,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            let signal = Signal::entry(&self.name, "BTC/USDT".to_string(), trader_core::Side::Buy)
+                .with_metadata("trigger", serde_json::json!(event.name()));
+            Ok(vec![signal])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({
+                "signal_count": self.signal_count
+            })
+        }
+
+        fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(serde_json::to_vec(&self.get_state())?)
+        }
+
+        fn load_state(
+            &mut self,
+            data: &[u8],
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            let state: Value = serde_json::from_slice(data)?;
+            self.signal_count = state["signal_count"].as_u64().ok_or("signal_count 없음")? as u32;
+            Ok(())
+        }
+    }
+
+    fn test_kline_data() -> MarketData {
+        let now = Utc::now();
+        let price = rust_decimal::Decimal::ONE;
+        MarketData::from_kline(
+            "test",
+            Kline::new(
+                "BTC/USDT".to_string(),
+                Timeframe::M1,
+                now,
+                price,
+                price,
+                price,
+                price,
+                price,
+                now,
+            ),
+        )
+    }
+
+    async fn checkpointed_engine(store: &Arc<crate::InMemoryStateStore>) -> StrategyEngine {
+        let engine = StrategyEngine::new(EngineConfig::default())
+            .with_state_store(Arc::clone(store) as Arc<dyn StrategyStateStore>);
+        engine
+            .register_strategy(
+                "test1",
+                Box::new(TestStrategy::new("test")),
+                serde_json::json!({}),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("test1").await.unwrap();
+        engine
+    }
+
+    async fn signal_count(engine: &StrategyEngine) -> u64 {
+        let status = engine.get_strategy_status("test1").await.unwrap();
+        status.state["signal_count"].as_u64().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_engine_register_strategy() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+
+        let strategy = Box::new(TestStrategy::new("test"));
+        engine
+            .register_strategy("test1", strategy, serde_json::json!({}), None, None)
+            .await
+            .unwrap();
+
+        let strategies = engine.list_strategies().await;
+        assert_eq!(strategies.len(), 1);
+        assert!(strategies.contains(&"test1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_engine_register_strategy_with_custom_name() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+
+        let strategy = Box::new(TestStrategy::new("test"));
+        engine
+            .register_strategy(
+                "test1",
+                strategy,
+                serde_json::json!({}),
+                Some("나의 커스텀 전략".to_string()),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let status = engine.get_strategy_status("test1").await.unwrap();
+        assert_eq!(status.name, "나의 커스텀 전략");
+    }
+
+    #[tokio::test]
+    async fn test_engine_start_stop_strategy() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+
+        let strategy = Box::new(TestStrategy::new("test"));
+        engine
+            .register_strategy("test1", strategy, serde_json::json!({}), None, None)
+            .await
+            .unwrap();
+
+        // 전략 시작
+        engine.start_strategy("test1").await.unwrap();
+
+        let status = engine.get_strategy_status("test1").await.unwrap();
+        assert!(status.running);
+
+        // 전략 중지
+        engine.stop_strategy("test1").await.unwrap();
+
+        let status = engine.get_strategy_status("test1").await.unwrap();
+        assert!(!status.running);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_strategy_error() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+
+        let strategy1 = Box::new(TestStrategy::new("test"));
+        let strategy2 = Box::new(TestStrategy::new("test"));
+
+        engine
+            .register_strategy("test1", strategy1, serde_json::json!({}), None, None)
+            .await
+            .unwrap();
+
+        let result = engine
+            .register_strategy("test1", strategy2, serde_json::json!({}), None, None)
+            .await;
+
+        assert!(matches!(result, Err(EngineError::StrategyAlreadyExists(_))));
+    }
+
+    #[tokio::test]
+    async fn test_state_restored_after_restart() {
+        let store = Arc::new(crate::InMemoryStateStore::new());
+
+        let engine = checkpointed_engine(&store).await;
+        for _ in 0..3 {
+            engine.process_market_data(test_kline_data()).await.unwrap();
+        }
+        engine.stop_strategy("test1").await.unwrap();
+
+        let snapshot = engine.get_snapshot("test1", None).await.unwrap();
+        assert_eq!(snapshot.version, 1);
+        assert_eq!(snapshot.reason, SnapshotReason::Stop);
+
+        // 새 엔진(재시작)에서 initialize()가 리셋한 상태를 스냅샷으로 복원
+        let restarted = checkpointed_engine(&store).await;
+        assert_eq!(signal_count(&restarted).await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_state_is_not_checkpointed() {
+        let store = Arc::new(crate::InMemoryStateStore::new());
+        let engine = checkpointed_engine(&store).await;
+
+        engine.process_market_data(test_kline_data()).await.unwrap();
+        assert_eq!(engine.checkpoint_all(SnapshotReason::Periodic).await, 1);
+        assert_eq!(engine.checkpoint_all(SnapshotReason::Periodic).await, 0);
+
+        // 종료 시에도 변경이 없으면 새 버전을 만들지 않음
+        engine.shutdown().await;
+        assert_eq!(store.count("test1").await, 1);
+
+        // 수동 체크포인트는 항상 저장
+        let manual = engine
+            .checkpoint_strategy("test1", SnapshotReason::Manual)
+            .await
+            .unwrap();
+        assert_eq!(manual.map(|s| s.version), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_rollback_strategy() {
+        let store = Arc::new(crate::InMemoryStateStore::new());
+        let engine = checkpointed_engine(&store).await;
+
+        engine.process_market_data(test_kline_data()).await.unwrap();
+        engine.checkpoint_all(SnapshotReason::Periodic).await;
+        for _ in 0..4 {
+            engine.process_market_data(test_kline_data()).await.unwrap();
+        }
+        engine.checkpoint_all(SnapshotReason::Periodic).await;
+        assert_eq!(signal_count(&engine).await, 5);
+
+        let snapshot = engine.rollback_strategy("test1", 1).await.unwrap();
+        assert_eq!(snapshot.version, 3);
+        assert_eq!(snapshot.reason, SnapshotReason::Rollback);
+        assert_eq!(snapshot.source_version, Some(1));
+        assert_eq!(signal_count(&engine).await, 1);
+
+        let versions: Vec<i64> = engine
+            .list_snapshots("test1", 10)
+            .await
+            .unwrap()
+            .iter()
+            .map(|s| s.version)
+            .collect();
+        assert_eq!(versions, vec![3, 2, 1]);
+
+        assert!(matches!(
+            engine.rollback_strategy("test1", 9).await,
+            Err(EngineError::SnapshotNotFound(_))
+        ));
+    }
+
+    /// 저장 중 해제 신호를 기다리는 스냅샷 저장소.
+    #[derive(Default)]
+    struct GatedStateStore {
+        inner: crate::InMemoryStateStore,
+        entered: tokio::sync::Notify,
+        release: tokio::sync::Notify,
+    }
+
+    #[async_trait]
+    impl StrategyStateStore for GatedStateStore {
+        async fn save(
+            &self,
+            snapshot: NewStrategySnapshot,
+        ) -> Result<StrategySnapshot, crate::CheckpointError> {
+            self.entered.notify_one();
+            self.release.notified().await;
+            self.inner.save(snapshot).await
+        }
+
+        async fn latest(
+            &self,
+            strategy_id: &str,
+        ) -> Result<Option<StrategySnapshot>, crate::CheckpointError> {
+            self.inner.latest(strategy_id).await
+        }
+
+        async fn get(
+            &self,
+            strategy_id: &str,
+            version: i64,
+        ) -> Result<Option<StrategySnapshot>, crate::CheckpointError> {
+            self.inner.get(strategy_id, version).await
+        }
+
+        async fn list(
+            &self,
+            strategy_id: &str,
+            limit: usize,
+        ) -> Result<Vec<SnapshotSummary>, crate::CheckpointError> {
+            self.inner.list(strategy_id, limit).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_does_not_block_processing() {
+        let store = Arc::new(GatedStateStore::default());
+        let engine = Arc::new(
+            StrategyEngine::new(EngineConfig::default())
+                .with_state_store(Arc::clone(&store) as Arc<dyn StrategyStateStore>),
+        );
+        engine
+            .register_strategy(
+                "test1",
+                Box::new(TestStrategy::new("test")),
+                serde_json::json!({}),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("test1").await.unwrap();
+        engine.process_market_data(test_kline_data()).await.unwrap();
+
+        let checkpoint = tokio::spawn({
+            let engine = Arc::clone(&engine);
+            async move { engine.checkpoint_all(SnapshotReason::Periodic).await }
+        });
+        store.entered.notified().await;
+
+        // 저장(DB I/O) 중에도 시장 데이터 처리가 막히지 않음
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            engine.process_market_data(test_kline_data()),
+        )
+        .await
+        .expect("checkpoint blocked market data processing")
+        .unwrap();
+
+        store.release.notify_one();
+        assert_eq!(checkpoint.await.unwrap(), 1);
+        // 저장된 상태(1회 처리 시점)와 현재 상태가 달라 다음 주기에 다시 저장
+        store.release.notify_one();
+        assert_eq!(engine.checkpoint_all(SnapshotReason::Periodic).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_manual_and_stop_checkpoints_do_not_block_processing() {
+        let store = Arc::new(GatedStateStore::default());
+        let engine = Arc::new(
+            StrategyEngine::new(EngineConfig::default())
+                .with_state_store(Arc::clone(&store) as Arc<dyn StrategyStateStore>),
+        );
+        for id in ["test1", "test2"] {
+            engine
+                .register_strategy(
+                    id,
+                    Box::new(TestStrategy::new("test")),
+                    serde_json::json!({}),
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            engine.start_strategy(id).await.unwrap();
+        }
+        engine.process_market_data(test_kline_data()).await.unwrap();
+
+        // 수동 체크포인트 저장 중에도 처리 가능
+        let manual = tokio::spawn({
+            let engine = Arc::clone(&engine);
+            async move {
+                engine
+                    .checkpoint_strategy("test1", SnapshotReason::Manual)
+                    .await
+            }
+        });
+        store.entered.notified().await;
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            engine.process_market_data(test_kline_data()),
+        )
+        .await
+        .expect("manual checkpoint blocked market data processing")
+        .unwrap();
+        store.release.notify_one();
+        assert!(manual.await.unwrap().unwrap().is_some());
+
+        // 중지 체크포인트 저장 중에도 처리 가능
+        let stop = tokio::spawn({
+            let engine = Arc::clone(&engine);
+            async move { engine.stop_strategy("test2").await }
+        });
+        store.entered.notified().await;
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            engine.process_market_data(test_kline_data()),
+        )
+        .await
+        .expect("stop checkpoint blocked market data processing")
+        .unwrap();
+        store.release.notify_one();
+        stop.await.unwrap().unwrap();
+
+        assert!(engine.get_strategy_status("test1").await.unwrap().running);
+        assert!(!engine.get_strategy_status("test2").await.unwrap().running);
+        let latest = store.inner.latest("test2").await.unwrap().unwrap();
+        assert_eq!(latest.reason, SnapshotReason::Stop);
+    }
+
+    #[tokio::test]
+    async fn test_snapshots_require_state_store() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+        engine
+            .register_strategy(
+                "test1",
+                Box::new(TestStrategy::new("test")),
+                serde_json::json!({}),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            engine.list_snapshots("test1", 10).await,
+            Err(EngineError::StateStoreNotConfigured)
+        ));
+        assert_eq!(engine.checkpoint_all(SnapshotReason::Periodic).await, 0);
+    }
+
+    fn test_candidate(name: &str, config: Value) -> DeploymentCandidate {
+        DeploymentCandidate::new(
+            "test",
+            DeploymentSource::Registry,
+            config,
+            Box::new(TestStrategy::new(name)),
+        )
+        .with_shadow(Box::new(TestStrategy::new(name)))
+    }
+
+    #[tokio::test]
+    async fn test_deploy_carries_state_and_rolls_back() {
+        let store = Arc::new(crate::InMemoryStateStore::new());
+        let engine = checkpointed_engine(&store).await;
+        for _ in 0..3 {
+            engine.process_market_data(test_kline_data()).await.unwrap();
+        }
+
+        let deployment = engine
+            .deploy_strategy(
+                "test1",
+                test_candidate("test_v2", serde_json::json!({ "period": 5 })),
+                DeploymentOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        // 배포 이력이 없던 실행 버전은 v1로 기록된 뒤 대체됨
+        assert_eq!(deployment.version, 2);
+        assert!(deployment.state_carried_over);
+        assert_eq!(deployment.changes[0].path, "period");
+        let dry_run = deployment.dry_run.unwrap();
+        assert_eq!(dry_run.bars, 3);
+        assert!(!dry_run.baseline_available);
+
+        let status = engine.get_strategy_status("test1").await.unwrap();
+        assert_eq!(status.name, "test_v2");
+        assert!(status.running);
+        assert_eq!(signal_count(&engine).await, 3);
+
+        engine.process_market_data(test_kline_data()).await.unwrap();
+
+        // 메모리에 남은 직전 인스턴스로 즉시 롤백, 현재 상태는 이어받음
+        let rollback = engine.rollback_deployment("test1", None).await.unwrap();
+        assert_eq!(rollback.version, 3);
+        assert_eq!(rollback.rollback_of, Some(1));
+        assert_eq!(
+            engine.get_strategy_status("test1").await.unwrap().name,
+            "test"
+        );
+        assert_eq!(signal_count(&engine).await, 4);
+
+        let statuses: Vec<(i64, DeploymentStatus)> = engine
+            .list_deployments("test1", 10)
+            .await
+            .unwrap()
+            .iter()
+            .map(|d| (d.version, d.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (3, DeploymentStatus::Active),
+                (2, DeploymentStatus::RolledBack),
+                (1, DeploymentStatus::Superseded),
+            ]
+        );
+    }
+
+    /// 활성화 기록 중 해제 신호를 기다리는 배포 저장소.
+    #[derive(Default)]
+    struct GatedDeploymentStore {
+        inner: InMemoryDeploymentStore,
+        entered: tokio::sync::Notify,
+        release: tokio::sync::Notify,
+    }
+
+    #[async_trait]
+    impl StrategyDeploymentStore for GatedDeploymentStore {
+        async fn save(
+            &self,
+            deployment: NewStrategyDeployment,
+        ) -> Result<StrategyDeployment, crate::DeploymentError> {
+            self.inner.save(deployment).await
+        }
+
+        async fn get(
+            &self,
+            strategy_id: &str,
+            version: i64,
+        ) -> Result<Option<StrategyDeployment>, crate::DeploymentError> {
+            self.inner.get(strategy_id, version).await
+        }
+
+        async fn list(
+            &self,
+            strategy_id: &str,
+            limit: usize,
+        ) -> Result<Vec<StrategyDeployment>, crate::DeploymentError> {
+            self.inner.list(strategy_id, limit).await
+        }
+
+        async fn set_status(
+            &self,
+            strategy_id: &str,
+            version: i64,
+            status: DeploymentStatus,
+        ) -> Result<(), crate::DeploymentError> {
+            self.inner.set_status(strategy_id, version, status).await
+        }
+
+        async fn activate(
+            &self,
+            deployment: NewStrategyDeployment,
+            replaced_version: i64,
+            replaced_status: DeploymentStatus,
+        ) -> Result<StrategyDeployment, crate::DeploymentError> {
+            self.entered.notify_one();
+            self.release.notified().await;
+            self.inner
+                .activate(deployment, replaced_version, replaced_status)
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deploy_records_outside_strategies_lock() {
+        let store = Arc::new(GatedDeploymentStore::default());
+        let engine = Arc::new(
+            StrategyEngine::new(EngineConfig::default())
+                .with_deployment_store(Arc::clone(&store) as Arc<dyn StrategyDeploymentStore>),
+        );
+        engine
+            .register_strategy(
+                "test1",
+                Box::new(TestStrategy::new("test")),
+                serde_json::json!({}),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("test1").await.unwrap();
+        engine.process_market_data(test_kline_data()).await.unwrap();
+
+        let deploy = tokio::spawn({
+            let engine = Arc::clone(&engine);
+            async move {
+                engine
+                    .deploy_strategy(
+                        "test1",
+                        test_candidate("test_v2", serde_json::json!({})),
+                        DeploymentOptions::default(),
+                    )
+                    .await
+            }
+        });
+        store.entered.notified().await;
+
+        // 이력 기록(DB I/O) 중에도 시장 데이터 처리가 막히지 않음
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            engine.process_market_data(test_kline_data()),
+        )
+        .await
+        .expect("deployment blocked market data processing")
+        .unwrap();
+        assert_eq!(
+            engine.get_strategy_status("test1").await.unwrap().name,
+            "test"
+        );
+
+        store.release.notify_one();
+        let deployment = deploy.await.unwrap().unwrap();
+        assert_eq!(deployment.version, 2);
+        assert_eq!(
+            store.inner.get("test1", 1).await.unwrap().unwrap().status,
+            DeploymentStatus::Superseded
+        );
+
+        // 기록 중 처리된 데이터의 상태까지 새 버전으로 이어짐
+        assert_eq!(
+            engine.get_strategy_status("test1").await.unwrap().name,
+            "test_v2"
+        );
+        assert_eq!(signal_count(&engine).await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_shadow_runs_beside_live_strategy() {
+        let store = Arc::new(crate::InMemoryStateStore::new());
+        let mut engine = checkpointed_engine(&store).await;
+        let mut signal_rx = engine.take_signal_receiver().unwrap();
+        engine
+            .get_strategy_context("test1")
+            .await
+            .unwrap()
+            .write()
+            .await
+            .update_account(trader_core::domain::StrategyAccountInfo {
+                available_balance: rust_decimal::Decimal::from(1_000_000),
+                ..Default::default()
+            });
+        for _ in 0..3 {
+            engine.process_market_data(test_kline_data()).await.unwrap();
+        }
+
+        let shadow_id = engine
+            .start_shadow(
+                "test1",
+                test_candidate("test_shadow", serde_json::json!({ "period": 5 })),
+                ShadowConfig::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(shadow_id, "test1_shadow_1");
+
+        // 라이브 상태(3)를 이어받아 10번째 데이터에서 양쪽 모두 신호
+        for _ in 0..7 {
+            engine.process_market_data(test_kline_data()).await.unwrap();
+        }
+
+        // 섀도 신호는 신호 채널로 나가지 않음
+        let mut sent = Vec::new();
+        while let Ok(signal) = signal_rx.try_recv() {
+            sent.push(signal.strategy_id);
+        }
+        assert_eq!(sent, vec!["test".to_string()]);
+
+        let comparison = engine.get_shadow_comparison(&shadow_id).await.unwrap();
+        assert_eq!(comparison.bars_processed, 7);
+        assert_eq!(comparison.agreement.matched, 1);
+        assert_eq!(comparison.live.trades, 1);
+        assert_eq!(comparison.shadow.trades, 1);
+        assert_eq!(comparison.timeline.len(), 7);
+
+        // 가상 체결은 섀도 전략과 섀도 컨텍스트에만 반영
+        {
+            let shadows = engine.shadows.read().await;
+            let entry = &shadows[&shadow_id];
+            assert_eq!(entry.instance.stats.orders_filled, 1);
+            let ctx = entry.instance.context.read().await;
+            let ticker = &comparison.shadow_signals[0].ticker;
+            assert!(ctx.get_position(ticker).is_some());
+            assert!(ctx.account.available_balance < ShadowConfig::default().initial_balance);
+        }
+        let live_ctx = engine.get_strategy_context("test1").await.unwrap();
+        assert!(live_ctx.read().await.positions.is_empty());
+
+        let summaries = engine.list_shadows(Some("test1")).await;
+        assert_eq!(summaries.len(), 1);
+        assert!(engine.list_shadows(Some("other")).await.is_empty());
+
+        let stopped = engine.stop_shadow(&shadow_id).await.unwrap();
+        assert_eq!(stopped.agreement.rate(), Some(1.0));
+        assert!(matches!(
+            engine.get_shadow_comparison(&shadow_id).await,
+            Err(EngineError::ShadowNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_schedule_events_drive_strategy() {
+        let mut engine = StrategyEngine::new(EngineConfig::default());
+        let mut signal_rx = engine.take_signal_receiver().unwrap();
+        let schedule = crate::StrategySchedule::new(Country::Global)
+            .with_trigger(crate::ScheduleTrigger::MarketOpen);
+        engine
+            .register_strategy(
+                "scheduled",
+                Box::new(TestStrategy::new("scheduled").with_schedule(schedule)),
+                serde_json::json!({}),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("scheduled").await.unwrap();
+        engine
+            .get_strategy_context("scheduled")
+            .await
+            .unwrap()
+            .write()
+            .await
+            .update_account(trader_core::domain::StrategyAccountInfo {
+                available_balance: rust_decimal::Decimal::from(1_000_000),
+                ..Default::default()
+            });
+
+        // 시작 직후에는 이벤트 없음
+        assert!(engine
+            .process_schedules(Utc::now())
+            .await
+            .unwrap()
+            .is_empty());
+
+        // 이틀 뒤: 개장 이벤트는 트리거당 최신 하나만 발생
+        let later = Utc::now() + chrono::Duration::days(2);
+        let signals = engine.process_schedules(later).await.unwrap();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].metadata["trigger"], "market_open");
+        assert_eq!(signal_rx.recv().await.unwrap().ticker, "BTC/USDT");
+        assert!(engine.process_schedules(later).await.unwrap().is_empty());
+
+        // 중지된 전략은 일정 이벤트를 받지 않음
+        engine.stop_strategy("scheduled").await.unwrap();
+        let much_later = later + chrono::Duration::days(2);
+        assert!(engine
+            .process_schedules(much_later)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_decision_traces_record_emitted_and_conflicting_signals() {
+        let store = Arc::new(crate::InMemoryStateStore::new());
+        let engine = checkpointed_engine(&store).await;
+
+        // 잔고가 없어 충돌로 제외된 신호도 억제 추적으로 남음
+        for _ in 0..10 {
+            engine.process_market_data(test_kline_data()).await.unwrap();
+        }
+        let suppressed = engine
+            .search_decision_traces(&DecisionTraceQuery::default(), 10)
+            .await;
+        assert_eq!(suppressed.len(), 1);
+        assert!(!suppressed[0].is_emitted());
+        assert_eq!(
+            suppressed[0].first_failure().unwrap().kind,
+            TraceStepKind::Conflict
+        );
+
+        engine
+            .get_strategy_context("test1")
+            .await
+            .unwrap()
+            .write()
+            .await
+            .update_account(trader_core::domain::StrategyAccountInfo {
+                available_balance: rust_decimal::Decimal::from(1_000_000),
+                ..Default::default()
+            });
+        let mut signals = Vec::new();
+        for _ in 0..10 {
+            signals.extend(engine.process_market_data(test_kline_data()).await.unwrap());
+        }
+        assert_eq!(signals.len(), 1);
+
+        let trace = engine.get_decision_trace(signals[0].id).await.unwrap();
+        assert!(trace.is_emitted());
+        assert_eq!(trace.ticker, "BTC/USDT");
+
+        let query = DecisionTraceQuery {
+            outcome: Some(trader_core::DecisionOutcome::Emitted),
+            ..Default::default()
+        };
+        assert_eq!(engine.search_decision_traces(&query, 10).await.len(), 1);
+    }
+
+    #[derive(Default)]
+    struct CollectingTraceRecorder {
+        traces: Mutex<Vec<DecisionTrace>>,
+        recorded: tokio::sync::Notify,
+    }
+
+    #[async_trait]
+    impl DecisionTraceRecorder for CollectingTraceRecorder {
+        async fn record(&self, traces: &[DecisionTrace]) -> Result<(), crate::TraceError> {
+            self.traces.lock().await.extend_from_slice(traces);
+            self.recorded.notify_one();
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decision_traces_are_persisted_to_recorder() {
+        let store = Arc::new(crate::InMemoryStateStore::new());
+        let recorder = Arc::new(CollectingTraceRecorder::default());
+        let mut engine = checkpointed_engine(&store).await;
+        engine.set_trace_recorder(Arc::clone(&recorder) as Arc<dyn DecisionTraceRecorder>);
+
+        for _ in 0..10 {
+            engine.process_market_data(test_kline_data()).await.unwrap();
+        }
+        recorder.recorded.notified().await;
+
+        let persisted = recorder.traces.lock().await.clone();
+        assert_eq!(persisted.len(), 1);
+        assert!(!persisted[0].is_emitted());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_deployment_is_rejected_and_recorded() {
+        let store = Arc::new(crate::InMemoryStateStore::new());
+        let engine = checkpointed_engine(&store).await;
+        engine.process_market_data(test_kline_data()).await.unwrap();
+
+        let preview = engine
+            .preview_deployment(
+                "test1",
+                test_candidate("broken", serde_json::json!({ "invalid": true })),
+                10,
+            )
+            .await
+            .unwrap();
+        assert!(!preview.is_valid());
+        assert!(preview.dry_run.is_none());
+
+        let result = engine
+            .deploy_strategy(
+                "test1",
+                test_candidate("broken", serde_json::json!({ "invalid": true })),
+                DeploymentOptions::default(),
+            )
+            .await;
+        assert!(matches!(result, Err(EngineError::DeploymentRejected(_))));
+
+        // 실행 중인 버전은 그대로
+        assert_eq!(
+            engine.get_strategy_status("test1").await.unwrap().name,
+            "test"
+        );
+        assert_eq!(signal_count(&engine).await, 1);
+
+        let history = engine.list_deployments("test1", 10).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].status, DeploymentStatus::Rejected);
+        assert!(history[0]
+            .message
+            .as_deref()
+            .unwrap()
+            .contains("잘못된 설정"));
+
+        assert!(matches!(
+            engine.rollback_deployment("test1", None).await,
+            Err(EngineError::DeploymentNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_deploy_rejected_below_min_agreement() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+        let config = serde_json::json!({ "side": "buy", "strength": 1.0 });
+        engine
+            .register_strategy(
+                "test_fixed_signal_1",
+                StrategyRegistry::create_instance("test_fixed_signal").unwrap(),
+                config.clone(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("test_fixed_signal_1").await.unwrap();
+        for _ in 0..3 {
+            engine.process_market_data(test_kline_data()).await.unwrap();
+        }
+
+        // 같은 설정은 신호가 완전히 일치
+        let same = engine
+            .preview_deployment(
+                "test_fixed_signal_1",
+                DeploymentCandidate::from_registry("test_fixed_signal", config).unwrap(),
+                10,
+            )
+            .await
+            .unwrap();
+        let report = same.dry_run.unwrap();
+        assert_eq!((report.bars, report.matched), (3, 3));
+        assert_eq!(report.agreement(), Some(1.0));
+
+        // 반대 방향 신호를 내는 설정은 일치율 기준 미달로 거부
+        let opposite = DeploymentCandidate::from_registry(
+            "test_fixed_signal",
+            serde_json::json!({ "side": "sell", "strength": 1.0 }),
+        )
+        .unwrap();
+        let result = engine
+            .deploy_strategy(
+                "test_fixed_signal_1",
+                opposite,
+                DeploymentOptions {
+                    dry_run_bars: 10,
+                    min_agreement: Some(0.8),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(EngineError::DeploymentRejected(_))));
+
+        let rejected = engine
+            .list_deployments("test_fixed_signal_1", 1)
+            .await
+            .unwrap();
+        let report = rejected[0].dry_run.as_ref().unwrap();
+        assert_eq!(report.only_candidate.len(), 3);
+        assert_eq!(report.agreement(), Some(0.0));
+    }
+
+    /// 캔들 수를 세어 close를 RSI 자리에 넣는 테스트용 갱신기.
+    struct CountingUpdater {
+        calls: std::sync::atomic::AtomicU64,
+    }
+
+    impl IndicatorUpdater for CountingUpdater {
+        fn on_kline(&self, kline: &Kline) -> Option<trader_core::domain::IndicatorSnapshot> {
+            let bars = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            Some(trader_core::domain::IndicatorSnapshot {
+                ticker: kline.ticker.clone(),
+                timeframe: kline.timeframe,
+                open_time: kline.open_time,
+                close: kline.close,
+                bars,
+                rsi: Some(kline.close),
+                macd: None,
+                macd_signal: None,
+                macd_histogram: None,
+                bb_upper: None,
+                bb_middle: None,
+                bb_lower: None,
+                bb_percent_b: None,
+                atr: None,
+                supertrend: None,
+                supertrend_uptrend: true,
+            })
+        }
+
+        fn snapshot(
+            &self,
+            _ticker: &str,
+            _timeframe: Timeframe,
+        ) -> Option<trader_core::domain::IndicatorSnapshot> {
+            None
+        }
+    }
+
+    #[tokio::test]
+    async fn test_klines_route_to_subscribed_timeframe() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+        for (id, timeframe) in [
+            ("minute", Some(Timeframe::M1)),
+            ("hourly", Some(Timeframe::H1)),
+            ("any", None),
+        ] {
+            engine
+                .register_strategy(
+                    id,
+                    Box::new(TestStrategy::new(id)),
+                    serde_json::json!({}),
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            engine.set_strategy_timeframe(id, timeframe).await.unwrap();
+            engine.start_strategy(id).await.unwrap();
+        }
+        assert!(matches!(
+            engine.set_strategy_timeframe("missing", None).await,
+            Err(EngineError::StrategyNotFound(_))
+        ));
+
+        let mut hourly = test_kline_data();
+        if let MarketDataType::Kline(kline) = &mut hourly.data {
+            kline.timeframe = Timeframe::H1;
+        }
+        engine.process_market_data(test_kline_data()).await.unwrap();
+        engine.process_market_data(test_kline_data()).await.unwrap();
+        engine.process_market_data(hourly).await.unwrap();
+
+        // 구독 타임프레임 캔들만 전달, 미지정 전략은 모두 수신
+        let statuses = engine.get_all_statuses().await;
+        assert_eq!(statuses["minute"].stats.market_data_processed, 2);
+        assert_eq!(statuses["hourly"].stats.market_data_processed, 1);
+        assert_eq!(statuses["any"].stats.market_data_processed, 3);
+    }
+
+    #[tokio::test]
+    async fn test_indicator_snapshot_reaches_strategy_context() {
+        let updater = Arc::new(CountingUpdater {
+            calls: std::sync::atomic::AtomicU64::new(0),
+        });
+        let engine = StrategyEngine::new(EngineConfig::default())
+            .with_indicator_updater(Arc::clone(&updater) as Arc<dyn IndicatorUpdater>);
+        for id in ["test1", "test2"] {
+            engine
+                .register_strategy(
+                    id,
+                    Box::new(TestStrategy::new(id)),
+                    serde_json::json!({}),
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            engine.start_strategy(id).await.unwrap();
+        }
+
+        engine.process_market_data(test_kline_data()).await.unwrap();
+        engine.process_market_data(test_kline_data()).await.unwrap();
+
+        // 전략 수와 무관하게 캔들당 한 번만 갱신
+        assert_eq!(updater.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+        for id in ["test1", "test2"] {
+            let context = engine.get_strategy_context(id).await.unwrap();
+            let ctx = context.read().await;
+            let snapshot = ctx
+                .get_indicator_snapshot("BTC/USDT", Timeframe::M1)
+                .unwrap();
+            assert_eq!(snapshot.bars, 2);
+            assert_eq!(snapshot.rsi, Some(rust_decimal::Decimal::ONE));
+        }
     }
 }
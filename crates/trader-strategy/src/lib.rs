@@ -4,6 +4,11 @@
 //! - 트레이딩 전략 구현을 위한 Strategy trait
 //! - 동적 전략 로딩을 위한 플러그인 로더
 //! - 전략 실행 엔진
+//! - 거래 캘린더 기반 일정 이벤트 (장 개장/마감, 월초/월말, cron)
+//! - 검증/드라이런/롤백을 거치는 전략 버전 배포
+//! - 라이브 전략 옆에서 후보 설정을 가상 체결로 실행하는 섀도 모드
+//! - 신호 발생/억제 이유를 기록하는 결정 추적
+//! - 샌드박스 스크립트 전략 런타임 (Rhai)
 //! - 내장 전략 (그리드 트레이딩, RSI 평균 회귀)
 //!
 //! # 예제
@@ -30,25 +35,59 @@
 //! }
 //! ```
 
+pub mod checkpoint;
+pub mod deployment;
 pub mod engine;
 pub mod macros;
 pub mod plugin;
 pub mod registry;
+pub mod schedule;
 pub mod schema_composer;
 pub mod schema_registry;
+pub mod scripting;
+pub mod shadow;
 pub mod strategies;
+pub mod trace;
 pub mod traits;
 
 // 주요 타입 재내보내기
+pub use checkpoint::{
+    CheckpointError, InMemoryStateStore, NewStrategySnapshot, SnapshotReason, SnapshotSummary,
+    StrategySnapshot, StrategyStateStore,
+};
+pub use deployment::{
+    diff_configs, ChangeKind, ConfigChange, DeploymentCandidate, DeploymentError,
+    DeploymentOptions, DeploymentPreview, DeploymentSource, DeploymentStatus, DryRunReport,
+    InMemoryDeploymentStore, NewStrategyDeployment, SignalDigest, StrategyDeployment,
+    StrategyDeploymentStore,
+};
 pub use engine::{
     extract_tickers_from_config, EngineConfig, EngineError, EngineStats, SignalConflictEvent,
     StrategyEngine, StrategyStats, StrategyStatus,
 };
 pub use plugin::{BuiltinStrategyFactory, LoaderConfig, PluginError, PluginLoader, PluginMetadata};
 pub use registry::{StrategyCategory, StrategyMeta, StrategyRegistry};
+pub use schedule::{
+    CronSchedule, ScheduleClock, ScheduleError, ScheduleEvent, ScheduleTrigger, StrategySchedule,
+    TradingCalendars,
+};
 pub use schema_composer::SchemaComposer;
 pub use schema_registry::FragmentRegistry;
-pub use strategies::{MeanReversionConfig, MeanReversionStrategy, MeanReversionVariant};
+pub use scripting::{ScriptError, ScriptHost, ScriptInfo, ScriptLimits};
+pub use shadow::{
+    LiveFill, ShadowAgreement, ShadowBook, ShadowComparison, ShadowConfig, ShadowFill,
+    ShadowLedger, ShadowPoint, ShadowSignal, ShadowSummary,
+};
+pub use strategies::{
+    EnsembleConfig, EnsembleStrategy, MeanReversionConfig, MeanReversionStrategy,
+    MeanReversionVariant, OptionOverlayConfig, OptionOverlayStrategy, PairsTradingConfig,
+    PairsTradingStrategy, RuleBasedConfig, RuleBasedStrategy, RuleNode, ScriptStrategy,
+    ScriptStrategyConfig,
+};
+pub use trace::{
+    link_signal_traces, DecisionTraceRecorder, DecisionTraceStore, TraceError,
+    DEFAULT_TRACE_CAPACITY,
+};
 // 프로시저 매크로 재내보내기
 pub use trader_strategy_macro::StrategyConfig;
 pub use traits::{Strategy, StrategyMetadata};
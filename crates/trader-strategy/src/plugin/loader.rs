@@ -2,12 +2,19 @@
 //!
 //! 동적 라이브러리(Windows의 .dll, Linux의 .so)에서 전략 플러그인을 로드합니다.
 //! 플러그인 업데이트 시 핫 리로딩을 지원합니다.
+//!
+//! 리로드는 라이브러리만 교체합니다. 실행 중인 전략을 새 구현으로 바꾸려면
+//! [`PluginLoader::deployment_candidate`]로 만든 배포 대상을
+//! `StrategyEngine::deploy_strategy`에 넘겨 기존 `save_state` 데이터를 이어받게 합니다.
 
 use std::{
     collections::HashMap,
     ffi::OsStr,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 
 use libloading::Library;
@@ -16,7 +23,10 @@ use thiserror::Error;
 use tokio::sync::RwLock;
 use tracing::{info, warn};
 
-use crate::Strategy;
+use crate::{
+    deployment::{DeploymentCandidate, DeploymentSource},
+    Strategy,
+};
 
 /// 플러그인 로더 에러.
 #[derive(Error, Debug)]
@@ -71,6 +81,9 @@ type DestroyStrategyFn = unsafe extern "C" fn(*mut dyn Strategy);
 /// get_metadata 함수 시그니처에 대한 타입 별칭.
 type GetMetadataFn = unsafe extern "C" fn() -> PluginMetadata;
 
+/// 리로드용 라이브러리 복사본 파일명 일련번호.
+static RELOAD_SEQ: AtomicU64 = AtomicU64::new(0);
+
 /// 로드된 플러그인.
 pub struct LoadedPlugin {
     /// 플러그인 파일 경로
@@ -93,11 +106,46 @@ impl LoadedPlugin {
     /// - 라이브러리가 올바른 시그니처를 가진 `create_strategy` 및 `get_metadata` 함수를 내보냄
     /// - 라이브러리가 호환 가능한 메모리 레이아웃으로 컴파일됨 (동일한 Rust 컴파일러 버전)
     pub unsafe fn load<P: AsRef<Path>>(path: P) -> Result<Self, PluginError> {
-        let path = path.as_ref().to_path_buf();
+        Self::load_from(path.as_ref(), path.as_ref())
+    }
+
+    /// 파일을 고유한 임시 경로로 복사한 뒤 로드.
+    ///
+    /// 동적 로더는 같은 경로의 라이브러리가 이미 열려 있으면 캐시된 핸들을
+    /// 돌려주므로, 리로드할 때마다 새 경로에서 열어야 바뀐 코드가 올라옵니다.
+    /// 유닉스에서는 로드 직후 복사본을 지우고(매핑은 유지됨), 그 외 플랫폼에서는
+    /// 임시 디렉토리에 남깁니다.
+    ///
+    /// # Safety
+    ///
+    /// [`LoadedPlugin::load`]와 같습니다.
+    pub unsafe fn load_copy<P: AsRef<Path>>(path: P) -> Result<Self, PluginError> {
+        let path = path.as_ref();
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| PluginError::InvalidPlugin(path.display().to_string()))?;
+        let copy = std::env::temp_dir().join(format!(
+            "trader-plugin-{}-{}-{}",
+            std::process::id(),
+            RELOAD_SEQ.fetch_add(1, Ordering::Relaxed),
+            file_name.to_string_lossy()
+        ));
+        std::fs::copy(path, &copy)?;
+
+        let loaded = Self::load_from(path, &copy);
+        if cfg!(unix) || loaded.is_err() {
+            let _ = std::fs::remove_file(&copy);
+        }
+        loaded
+    }
+
+    /// `library_path`의 라이브러리를 열고 `path`를 원본 경로로 기록.
+    unsafe fn load_from(path: &Path, library_path: &Path) -> Result<Self, PluginError> {
+        let path = path.to_path_buf();
 
-        info!(path = %path.display(), "Loading plugin");
+        info!(path = %path.display(), library = %library_path.display(), "Loading plugin");
 
-        let library = Library::new(&path)
+        let library = Library::new(library_path)
             .map_err(|e| PluginError::LoadError(format!("{}: {}", path.display(), e)))?;
 
         // Try to get metadata (optional)
@@ -251,6 +299,9 @@ pub struct PluginLoader {
 
     /// 이름별 로드된 플러그인
     plugins: Arc<RwLock<HashMap<String, LoadedPlugin>>>,
+
+    /// 리로드로 교체된 플러그인 (기존 인스턴스의 코드가 남아 있으므로 해제하지 않음)
+    retired: Arc<RwLock<Vec<LoadedPlugin>>>,
 }
 
 impl PluginLoader {
@@ -259,6 +310,7 @@ impl PluginLoader {
         Self {
             config,
             plugins: Arc::new(RwLock::new(HashMap::new())),
+            retired: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -284,6 +336,11 @@ impl PluginLoader {
         }
 
         let plugin = unsafe { LoadedPlugin::load(&full_path)? };
+        self.insert_plugin(plugin).await
+    }
+
+    /// 로드된 플러그인을 메타데이터 이름으로 등록.
+    async fn insert_plugin(&self, plugin: LoadedPlugin) -> Result<PluginMetadata, PluginError> {
         let metadata = plugin.metadata().clone();
         let name = metadata.name.clone();
 
@@ -325,6 +382,26 @@ impl PluginLoader {
         unsafe { plugin.create_strategy() }
     }
 
+    /// 로드된 플러그인으로 배포 대상 생성 (드라이런 인스턴스 포함).
+    pub async fn deployment_candidate(
+        &self,
+        plugin_name: &str,
+        config: serde_json::Value,
+    ) -> Result<DeploymentCandidate, PluginError> {
+        let plugins = self.plugins.read().await;
+
+        let plugin = plugins
+            .get(plugin_name)
+            .ok_or_else(|| PluginError::PluginNotFound(plugin_name.to_string()))?;
+
+        let (strategy, shadow) = unsafe { (plugin.create_strategy()?, plugin.create_strategy()?) };
+
+        Ok(
+            DeploymentCandidate::new(plugin_name, DeploymentSource::Plugin, config, strategy)
+                .with_shadow(shadow),
+        )
+    }
+
     /// 로드된 플러그인의 메타데이터 반환.
     pub async fn get_plugin_metadata(&self, name: &str) -> Result<PluginMetadata, PluginError> {
         let plugins = self.plugins.read().await;
@@ -384,18 +461,37 @@ impl PluginLoader {
     }
 
     /// 이름으로 플러그인 리로드.
+    ///
+    /// 새 라이브러리는 [`LoadedPlugin::load_copy`]로 고유한 경로에서 열어 바뀐
+    /// 코드가 로드되게 합니다. 이전 라이브러리는 해제하지 않고 보관하므로 기존
+    /// 버전으로 생성된 전략 인스턴스는 배포로 교체될 때까지 계속 동작합니다.
     pub async fn reload_plugin(&self, name: &str) -> Result<PluginMetadata, PluginError> {
-        let path = {
-            let plugins = self.plugins.read().await;
-            let plugin = plugins
-                .get(name)
-                .ok_or_else(|| PluginError::PluginNotFound(name.to_string()))?;
-            plugin.path().to_path_buf()
-        };
+        let previous = self
+            .plugins
+            .write()
+            .await
+            .remove(name)
+            .ok_or_else(|| PluginError::PluginNotFound(name.to_string()))?;
+        let path = previous.path().to_path_buf();
 
-        // Unload and reload
-        self.unload_plugin(name).await?;
-        self.load_plugin(&path).await
+        let result = match unsafe { LoadedPlugin::load_copy(&path) } {
+            Ok(plugin) => self.insert_plugin(plugin).await,
+            Err(e) => Err(e),
+        };
+        match &result {
+            Ok(metadata) => {
+                info!(plugin = %name, version = %metadata.version, "Plugin reloaded");
+                self.retired.write().await.push(previous);
+            }
+            Err(e) => {
+                warn!(plugin = %name, error = %e, "Plugin reload failed, keeping previous version");
+                self.plugins
+                    .write()
+                    .await
+                    .insert(name.to_string(), previous);
+            }
+        }
+        result
     }
 
     /// 플러그인이 로드되었는지 확인.
@@ -488,4 +584,67 @@ mod tests {
 
         assert_eq!(loader.plugin_count().await, 0);
     }
+
+    /// `plugin_generation()`이 `generation`을 반환하는 최소 C 라이브러리 빌드.
+    #[cfg(unix)]
+    fn build_fixture(dir: &Path, generation: u32) -> Option<PathBuf> {
+        let source = dir.join("fixture.c");
+        std::fs::write(
+            &source,
+            format!(
+                "void *create_strategy(void) {{ return 0; }}\n\
+                 unsigned int plugin_generation(void) {{ return {generation}; }}\n"
+            ),
+        )
+        .unwrap();
+
+        let library = dir.join(format!("fixture.{}", default_extension()));
+        let status = std::process::Command::new("cc")
+            .args(["-shared", "-fPIC", "-o"])
+            .arg(&library)
+            .arg(&source)
+            .status()
+            .ok()?;
+        status.success().then_some(library)
+    }
+
+    #[cfg(unix)]
+    fn generation(plugin: &LoadedPlugin) -> u32 {
+        unsafe {
+            let generation: libloading::Symbol<unsafe extern "C" fn() -> u32> =
+                plugin.library.get(b"plugin_generation").unwrap();
+            generation()
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_reload_runs_rebuilt_library() {
+        let dir = std::env::temp_dir().join(format!("trader-plugin-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let Some(library) = build_fixture(&dir, 1) else {
+            eprintln!("C 컴파일러가 없어 건너뜀");
+            return;
+        };
+
+        let loader = PluginLoader::new(LoaderConfig::default());
+        let metadata = loader.load_plugin(&library).await.unwrap();
+        assert_eq!(metadata.name, "fixture");
+        assert_eq!(generation(&loader.plugins.read().await["fixture"]), 1);
+
+        // 같은 경로에 다시 빌드한 라이브러리를 리로드하면 새 코드가 실행됨
+        build_fixture(&dir, 2).unwrap();
+        let metadata = loader.reload_plugin("fixture").await.unwrap();
+        assert_eq!(metadata.name, "fixture");
+        {
+            let plugins = loader.plugins.read().await;
+            assert_eq!(generation(&plugins["fixture"]), 2);
+            assert_eq!(plugins["fixture"].path(), library);
+        }
+        // 이전 라이브러리는 기존 인스턴스를 위해 그대로 유지
+        assert_eq!(generation(&loader.retired.read().await[0]), 1);
+
+        drop(loader);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -0,0 +1,609 @@
+//! 전략 일정(타이머/캘린더) 이벤트.
+//!
+//! 전략은 [`Strategy::schedule`](crate::Strategy::schedule)로 관심 있는 일정 트리거를
+//! 선언하고, 엔진은 시장 캘린더([`TradingCalendar`])를 기준으로 발생 시각을 계산하여
+//! [`Strategy::on_schedule`](crate::Strategy::on_schedule)을 호출합니다.
+//!
+//! 실거래에서는 `StrategyEngine`이 벽시계 기준으로, 백테스트에서는 `BacktestEngine`이
+//! 캔들 시각 기준으로 같은 [`ScheduleClock`]을 전진시키므로 양쪽이 동일하게 동작합니다.
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! fn schedule(&self) -> Option<StrategySchedule> {
+//!     Some(
+//!         StrategySchedule::new(Country::KR)
+//!             .with_trigger(ScheduleTrigger::MonthEnd)
+//!             .with_trigger(ScheduleTrigger::cron("weekly", "0 10 * * mon").unwrap()),
+//!     )
+//! }
+//! ```
+
+use std::{borrow::Cow, collections::HashMap, fmt, str::FromStr};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use trader_core::{Country, Kline, TradingCalendar};
+
+/// 한 번에 역방향으로 탐색하는 최대 일수.
+const MAX_LOOKBACK_DAYS: i64 = 366;
+
+/// 일정 설정 에러.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ScheduleError {
+    /// 잘못된 cron 표현식
+    #[error("잘못된 cron 표현식 '{expr}': {reason}")]
+    InvalidCron { expr: String, reason: String },
+}
+
+/// 일정 트리거.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduleTrigger {
+    /// 거래일 정규장 개장 시각
+    MarketOpen,
+    /// 거래일 정규장 마감 시각
+    MarketClose,
+    /// 월 첫 거래일 개장 시각
+    MonthStart,
+    /// 월 마지막 거래일 개장 시각 (당일 주문이 체결될 수 있도록 개장 시점에 발생)
+    MonthEnd,
+    /// cron 일정 (시장 현지 시각, 거래일에만 발생)
+    Cron {
+        /// 이벤트 구분용 이름
+        name: String,
+        /// 일정 표현식
+        schedule: CronSchedule,
+    },
+    /// 고정 간격 타이머 (UTC epoch 기준 정렬, 거래일 여부와 무관)
+    Every {
+        /// 이벤트 구분용 이름
+        name: String,
+        /// 간격(초)
+        seconds: u64,
+    },
+}
+
+impl ScheduleTrigger {
+    /// cron 트리거 생성.
+    pub fn cron(name: impl Into<String>, expr: &str) -> Result<Self, ScheduleError> {
+        Ok(Self::Cron {
+            name: name.into(),
+            schedule: expr.parse()?,
+        })
+    }
+
+    /// 고정 간격 트리거 생성.
+    pub fn every(name: impl Into<String>, seconds: u64) -> Self {
+        Self::Every {
+            name: name.into(),
+            seconds: seconds.max(1),
+        }
+    }
+
+    /// 트리거 이름 (전략이 이벤트를 구분하는 데 사용).
+    pub fn name(&self) -> &str {
+        match self {
+            ScheduleTrigger::MarketOpen => "market_open",
+            ScheduleTrigger::MarketClose => "market_close",
+            ScheduleTrigger::MonthStart => "month_start",
+            ScheduleTrigger::MonthEnd => "month_end",
+            ScheduleTrigger::Cron { name, .. } | ScheduleTrigger::Every { name, .. } => name,
+        }
+    }
+
+    /// `(after, until]` 구간에서 가장 늦은 발생 시각.
+    fn latest_between(
+        &self,
+        calendar: &TradingCalendar,
+        after: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        if let ScheduleTrigger::Every { seconds, .. } = self {
+            let step = (*seconds).max(1) as i64;
+            let latest = until.timestamp().div_euclid(step) * step;
+            let ts = Utc.timestamp_opt(latest, 0).single()?;
+            return (ts > after).then_some(ts);
+        }
+
+        let first_day = calendar
+            .local_date(after)
+            .max(calendar.local_date(until) - Duration::days(MAX_LOOKBACK_DAYS));
+        let mut day = calendar.local_date(until);
+
+        while day >= first_day {
+            if calendar.is_trading_day(day) {
+                let found = match self {
+                    ScheduleTrigger::MarketOpen => Some(calendar.market_open(day)),
+                    ScheduleTrigger::MarketClose => Some(calendar.market_close(day)),
+                    ScheduleTrigger::MonthStart => calendar
+                        .is_first_trading_day_of_month(day)
+                        .then(|| calendar.market_open(day)),
+                    ScheduleTrigger::MonthEnd => calendar
+                        .is_last_trading_day_of_month(day)
+                        .then(|| calendar.market_open(day)),
+                    ScheduleTrigger::Cron { schedule, .. } => {
+                        schedule.latest_on(calendar, day, after, until)
+                    }
+                    ScheduleTrigger::Every { .. } => None,
+                };
+                if let Some(ts) = found.filter(|ts| *ts > after && *ts <= until) {
+                    return Some(ts);
+                }
+            }
+            day -= Duration::days(1);
+        }
+        None
+    }
+}
+
+/// cron 일정 (`분 시 일 월 요일`).
+///
+/// - 시장 현지 시각 기준이며 거래일에만 발생합니다.
+/// - 각 필드는 `*`, 목록(`1,15`), 범위(`1-5`), 간격(`*/15`, `0-30/10`)을 지원합니다.
+/// - 일 필드는 `L`(월 마지막 거래일), `F`(월 첫 거래일)를 추가로 지원합니다.
+/// - 요일은 `0-7`(0, 7 = 일요일) 또는 `sun`..`sat`를 사용합니다.
+/// - 일/요일 필드가 모두 지정되면 두 조건을 모두 만족해야 합니다 (AND).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    expr: String,
+    minutes: u64,
+    hours: u32,
+    days: u32,
+    first_trading_day: bool,
+    last_trading_day: bool,
+    any_day: bool,
+    months: u16,
+    weekdays: u8,
+}
+
+impl CronSchedule {
+    /// 원본 표현식.
+    pub fn expression(&self) -> &str {
+        &self.expr
+    }
+
+    /// 해당 날짜가 일/월/요일 조건을 만족하는지 확인.
+    fn matches_date(&self, calendar: &TradingCalendar, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        if self.weekdays & (1 << date.weekday().num_days_from_sunday()) == 0 {
+            return false;
+        }
+        self.any_day
+            || self.days & (1 << date.day()) != 0
+            || (self.first_trading_day && calendar.is_first_trading_day_of_month(date))
+            || (self.last_trading_day && calendar.is_last_trading_day_of_month(date))
+    }
+
+    /// 해당 날짜에서 `(after, until]` 구간의 가장 늦은 발생 시각.
+    fn latest_on(
+        &self,
+        calendar: &TradingCalendar,
+        date: NaiveDate,
+        after: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        if !self.matches_date(calendar, date) {
+            return None;
+        }
+        for hour in (0..24).rev().filter(|h| self.hours & (1 << h) != 0) {
+            for minute in (0..60).rev().filter(|m| self.minutes & (1 << m) != 0) {
+                let time = NaiveTime::from_hms_opt(hour, minute, 0)?;
+                let ts = calendar.timezone.to_utc(date, time);
+                if ts <= after {
+                    return None;
+                }
+                if ts <= until {
+                    return Some(ts);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = ScheduleError;
+
+    fn from_str(expr: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: String| ScheduleError::InvalidCron {
+            expr: expr.to_string(),
+            reason,
+        };
+
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(invalid(format!("필드 5개 필요 (현재 {}개)", fields.len())));
+        };
+
+        let minutes = parse_field(minute, 0, 59, &[]).map_err(&invalid)?;
+        let hours = parse_field(hour, 0, 23, &[]).map_err(&invalid)? as u32;
+        let months = parse_field(month, 1, 12, &MONTH_NAMES).map_err(&invalid)? as u16;
+        let weekdays = parse_field(weekday, 0, 7, &WEEKDAY_NAMES).map_err(&invalid)?;
+        // 7(일요일)은 0으로 접기
+        let weekdays = ((weekdays | (weekdays >> 7)) & 0x7f) as u8;
+
+        let mut first_trading_day = false;
+        let mut last_trading_day = false;
+        let numeric: Vec<&str> = day
+            .split(',')
+            .filter(|item| match item.to_ascii_uppercase().as_str() {
+                "F" => {
+                    first_trading_day = true;
+                    false
+                }
+                "L" => {
+                    last_trading_day = true;
+                    false
+                }
+                _ => true,
+            })
+            .collect();
+        let any_day = day == "*";
+        let days = if numeric.is_empty() {
+            0
+        } else {
+            parse_field(&numeric.join(","), 1, 31, &[]).map_err(&invalid)? as u32
+        };
+
+        Ok(Self {
+            expr: expr.to_string(),
+            minutes,
+            hours,
+            days,
+            first_trading_day,
+            last_trading_day,
+            any_day,
+            months,
+            weekdays,
+        })
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expr)
+    }
+}
+
+impl Serialize for CronSchedule {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.expr)
+    }
+}
+
+impl<'de> Deserialize<'de> for CronSchedule {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let expr = String::deserialize(deserializer)?;
+        expr.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+const MONTH_NAMES: [(&str, u32); 12] = [
+    ("JAN", 1),
+    ("FEB", 2),
+    ("MAR", 3),
+    ("APR", 4),
+    ("MAY", 5),
+    ("JUN", 6),
+    ("JUL", 7),
+    ("AUG", 8),
+    ("SEP", 9),
+    ("OCT", 10),
+    ("NOV", 11),
+    ("DEC", 12),
+];
+
+const WEEKDAY_NAMES: [(&str, u32); 7] = [
+    ("SUN", 0),
+    ("MON", 1),
+    ("TUE", 2),
+    ("WED", 3),
+    ("THU", 4),
+    ("FRI", 5),
+    ("SAT", 6),
+];
+
+/// cron 필드를 비트마스크로 변환 (비트 n = 값 n 허용).
+fn parse_field(field: &str, min: u32, max: u32, names: &[(&str, u32)]) -> Result<u64, String> {
+    let value = |token: &str| -> Result<u32, String> {
+        let upper = token.to_ascii_uppercase();
+        let parsed = names
+            .iter()
+            .find(|(name, _)| *name == upper)
+            .map(|(_, v)| *v)
+            .or_else(|| token.parse().ok())
+            .ok_or_else(|| format!("알 수 없는 값 '{}'", token))?;
+        if parsed < min || parsed > max {
+            return Err(format!("'{}'은(는) {}..={} 범위 밖", token, min, max));
+        }
+        Ok(parsed)
+    };
+
+    let mut mask = 0u64;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or_else(|| format!("잘못된 간격 '{}'", step))?;
+                (range, step)
+            }
+            None => (item, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((a, b)) => (value(a)?, value(b)?),
+                // `5/10`은 5부터 최댓값까지
+                None if step > 1 => (value(range)?, max),
+                None => {
+                    let v = value(range)?;
+                    (v, v)
+                }
+            },
+        };
+        if start > end {
+            return Err(format!("잘못된 범위 '{}'", range));
+        }
+        for v in (start..=end).step_by(step as usize) {
+            mask |= 1 << v;
+        }
+    }
+    Ok(mask)
+}
+
+/// 발생한 일정 이벤트.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScheduleEvent {
+    /// 발생한 트리거
+    pub trigger: ScheduleTrigger,
+    /// 예정 발생 시각 (UTC)
+    pub timestamp: DateTime<Utc>,
+    /// 발생 시각의 시장 현지 날짜
+    pub trading_day: NaiveDate,
+    /// 발생 시각에 정규장이 열려 있는지 여부
+    pub market_open: bool,
+}
+
+impl ScheduleEvent {
+    /// 트리거 이름.
+    pub fn name(&self) -> &str {
+        self.trigger.name()
+    }
+}
+
+/// 전략이 선언하는 일정.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrategySchedule {
+    /// 기준 시장 (캘린더 선택)
+    pub country: Country,
+    /// 트리거 목록
+    pub triggers: Vec<ScheduleTrigger>,
+}
+
+impl StrategySchedule {
+    /// 트리거 없는 일정 생성.
+    pub fn new(country: Country) -> Self {
+        Self {
+            country,
+            triggers: Vec::new(),
+        }
+    }
+
+    /// 트리거 추가.
+    pub fn with_trigger(mut self, trigger: ScheduleTrigger) -> Self {
+        self.triggers.push(trigger);
+        self
+    }
+}
+
+/// 국가별 거래 캘린더 모음.
+///
+/// 등록되지 않은 국가는 [`TradingCalendar::for_country`] 기본값(휴장일 없음)을 사용합니다.
+#[derive(Debug, Clone, Default)]
+pub struct TradingCalendars {
+    calendars: HashMap<Country, TradingCalendar>,
+}
+
+impl TradingCalendars {
+    /// 빈 캘린더 모음 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 캘린더 등록 (같은 국가는 교체).
+    pub fn insert(&mut self, calendar: TradingCalendar) {
+        self.calendars.insert(calendar.country, calendar);
+    }
+
+    /// 국가 캘린더 조회 (미등록 시 기본 캘린더).
+    pub fn get(&self, country: Country) -> Cow<'_, TradingCalendar> {
+        match self.calendars.get(&country) {
+            Some(calendar) => Cow::Borrowed(calendar),
+            None => Cow::Owned(TradingCalendar::for_country(country)),
+        }
+    }
+}
+
+/// 일정 이벤트 발생 커서.
+///
+/// 마지막으로 확인한 시각 이후 `now`까지 발생한 이벤트를 계산합니다.
+/// 처음 호출은 커서만 설정하며 과거 이벤트를 소급하지 않습니다.
+/// 오래 멈춰 있던 경우에도 트리거당 가장 최근 이벤트 하나만 발생합니다.
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleClock {
+    last: Option<DateTime<Utc>>,
+}
+
+impl ScheduleClock {
+    /// 새 커서 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 지정 시각부터 시작하는 커서 생성.
+    pub fn starting_at(at: DateTime<Utc>) -> Self {
+        Self { last: Some(at) }
+    }
+
+    /// 마지막 확인 시각.
+    pub fn last(&self) -> Option<DateTime<Utc>> {
+        self.last
+    }
+
+    /// 커서를 지정 시각으로 재설정 (이전 이벤트는 발생시키지 않음).
+    pub fn reset(&mut self, at: DateTime<Utc>) {
+        self.last = Some(at);
+    }
+
+    /// `now`까지 전진하며 발생한 이벤트를 시각순으로 반환.
+    pub fn advance(
+        &mut self,
+        schedule: &StrategySchedule,
+        calendar: &TradingCalendar,
+        now: DateTime<Utc>,
+    ) -> Vec<ScheduleEvent> {
+        let Some(last) = self.last else {
+            self.last = Some(now);
+            return Vec::new();
+        };
+        if now <= last {
+            return Vec::new();
+        }
+        self.last = Some(now);
+
+        let mut events: Vec<ScheduleEvent> = schedule
+            .triggers
+            .iter()
+            .filter_map(|trigger| {
+                let timestamp = trigger.latest_between(calendar, last, now)?;
+                Some(ScheduleEvent {
+                    trigger: trigger.clone(),
+                    timestamp,
+                    trading_day: calendar.local_date(timestamp),
+                    market_open: calendar.is_market_open(timestamp),
+                })
+            })
+            .collect();
+        events.sort_by_key(|event| event.timestamp);
+        events
+    }
+
+    /// 캔들 하나만큼 전진하며 이벤트를 캔들 전/후로 나눠 반환.
+    ///
+    /// - 첫 번째: 캔들 시작 시각까지 발생 (해당 캔들 처리 전에 전달, 직전 캔들까지의 데이터 기준)
+    /// - 두 번째: 캔들 구간 중 발생 (해당 캔들 처리 후에 전달)
+    ///
+    /// 백테스트와 드라이런 재생이 실시간 타이머와 같은 시점의 데이터를 보도록 합니다.
+    pub fn advance_bar(
+        &mut self,
+        schedule: &StrategySchedule,
+        calendar: &TradingCalendar,
+        kline: &Kline,
+    ) -> (Vec<ScheduleEvent>, Vec<ScheduleEvent>) {
+        let before = self.advance(schedule, calendar, kline.open_time);
+        let during = self.advance(schedule, calendar, kline.close_time);
+        (before, during)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn names(events: &[ScheduleEvent]) -> Vec<&str> {
+        events.iter().map(|e| e.name()).collect()
+    }
+
+    #[test]
+    fn test_cron_parsing() {
+        let cron: CronSchedule = "*/15 9-15 L * mon-fri".parse().unwrap();
+        assert_eq!(cron.minutes, 1 | 1 << 15 | 1 << 30 | 1 << 45);
+        assert!(cron.last_trading_day && !cron.any_day);
+        assert_eq!(cron.weekdays, 0b0111110);
+
+        let sunday: CronSchedule = "0 0 * * 7".parse().unwrap();
+        assert_eq!(sunday.weekdays, 1);
+
+        assert!("0 9 * *".parse::<CronSchedule>().is_err());
+        assert!("60 9 * * *".parse::<CronSchedule>().is_err());
+        assert!("0 9 * * foo".parse::<CronSchedule>().is_err());
+
+        let json =
+            serde_json::to_value(ScheduleTrigger::cron("weekly", "0 10 * * mon").unwrap()).unwrap();
+        assert_eq!(json["schedule"], "0 10 * * mon");
+        let back: ScheduleTrigger = serde_json::from_value(json).unwrap();
+        assert_eq!(back.name(), "weekly");
+    }
+
+    #[test]
+    fn test_clock_fires_calendar_events() {
+        // 2024-05-31 (금) 월 마지막 거래일, 2024-06-03 (월) 첫 거래일
+        let calendar = TradingCalendar::krx();
+        let schedule = StrategySchedule::new(Country::KR)
+            .with_trigger(ScheduleTrigger::MarketOpen)
+            .with_trigger(ScheduleTrigger::MarketClose)
+            .with_trigger(ScheduleTrigger::MonthStart)
+            .with_trigger(ScheduleTrigger::MonthEnd);
+        let mut clock = ScheduleClock::new();
+
+        // 첫 호출은 커서만 설정
+        assert!(clock
+            .advance(&schedule, &calendar, utc(2024, 5, 30, 23, 0))
+            .is_empty());
+
+        // 5/31 09:00 KST = 00:00 UTC
+        let events = clock.advance(&schedule, &calendar, utc(2024, 5, 31, 1, 0));
+        assert_eq!(names(&events), vec!["market_open", "month_end"]);
+        assert!(events[0].market_open);
+        assert_eq!(
+            events[0].trading_day,
+            NaiveDate::from_ymd_opt(2024, 5, 31).unwrap()
+        );
+
+        // 주말을 건너 6/3 개장까지: 마감 + 월초 + 개장 (트리거당 최신 하나)
+        let events = clock.advance(&schedule, &calendar, utc(2024, 6, 3, 0, 30));
+        assert_eq!(
+            names(&events),
+            vec!["market_close", "market_open", "month_start"]
+        );
+        assert_eq!(events[0].timestamp, utc(2024, 5, 31, 6, 30));
+
+        // 같은 시각 재호출은 이벤트 없음
+        assert!(clock
+            .advance(&schedule, &calendar, utc(2024, 6, 3, 0, 30))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_cron_skips_holidays() {
+        let holiday = NaiveDate::from_ymd_opt(2024, 5, 6).unwrap();
+        let calendar = TradingCalendar::krx().with_holidays([holiday]);
+        let schedule = StrategySchedule::new(Country::KR)
+            .with_trigger(ScheduleTrigger::cron("morning", "30 10 * * *").unwrap())
+            .with_trigger(ScheduleTrigger::every("hourly", 3600));
+        let mut clock = ScheduleClock::new();
+        clock.advance(&schedule, &calendar, utc(2024, 5, 5, 0, 0));
+
+        // 휴장일(5/6) 10:30 KST에는 cron이 발생하지 않음
+        let events = clock.advance(&schedule, &calendar, utc(2024, 5, 6, 2, 0));
+        assert_eq!(names(&events), vec!["hourly"]);
+        assert_eq!(events[0].timestamp, utc(2024, 5, 6, 2, 0));
+        assert!(!events[0].market_open);
+
+        // 다음 거래일 10:30 KST = 01:30 UTC
+        let events = clock.advance(&schedule, &calendar, utc(2024, 5, 7, 1, 45));
+        assert_eq!(names(&events), vec!["hourly", "morning"]);
+        assert_eq!(events[1].timestamp, utc(2024, 5, 7, 1, 30));
+    }
+}
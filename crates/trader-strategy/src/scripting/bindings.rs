@@ -0,0 +1,397 @@
+//! 스크립트에 노출되는 타입과 함수.
+//!
+//! 스크립트는 `Market`(읽기 전용 시장 뷰)과 `Signal`(신호 빌더) 두 타입만 다룹니다.
+//! `Market`에는 setter를 등록하지 않으므로 스크립트가 호스트 데이터를 바꿀 수 없습니다.
+
+use std::sync::Arc;
+
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map, Position, INT};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use trader_core::{domain::IndicatorCalculator, Kline, Side, Signal, SignalType};
+
+type FnResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// 스크립트 전략이 생성하는 신호의 `strategy_id`.
+pub const SCRIPT_STRATEGY_ID: &str = "script";
+
+/// 보유 포지션 요약.
+#[derive(Debug, Clone)]
+pub struct PositionView {
+    pub side: Side,
+    pub quantity: f64,
+    pub entry_price: f64,
+    pub pnl_pct: f64,
+}
+
+/// 스크립트에 전달되는 읽기 전용 시장 뷰.
+///
+/// 현재 봉까지의 캔들 이력과 `StrategyContext`에서 추출한 분석 결과를 담습니다.
+#[derive(Debug, Clone)]
+pub struct ScriptMarket {
+    pub ticker: String,
+    pub bars: Arc<Vec<Kline>>,
+    pub params: Map,
+    pub position: Option<PositionView>,
+    pub global_score: Option<f64>,
+    pub route_state: Option<String>,
+    pub regime: Option<String>,
+}
+
+impl ScriptMarket {
+    fn current(&self) -> &Kline {
+        // 빈 이력으로 호출되지 않도록 호스트에서 보장
+        self.bars.last().expect("market view without bars")
+    }
+
+    fn closes(&self) -> Vec<Decimal> {
+        self.bars.iter().map(|k| k.close).collect()
+    }
+
+    fn highs(&self) -> Vec<Decimal> {
+        self.bars.iter().map(|k| k.high).collect()
+    }
+
+    fn lows(&self) -> Vec<Decimal> {
+        self.bars.iter().map(|k| k.low).collect()
+    }
+
+    fn tail(&self, n: INT, f: impl Fn(&Kline) -> Decimal) -> Array {
+        let n = n.max(0) as usize;
+        let skip = self.bars.len().saturating_sub(n);
+        self.bars
+            .iter()
+            .skip(skip)
+            .map(|k| Dynamic::from_float(to_f64(f(k))))
+            .collect()
+    }
+}
+
+/// 스크립트가 반환하는 신호.
+#[derive(Debug, Clone)]
+pub struct ScriptSignal {
+    pub signal_type: SignalType,
+    pub side: Side,
+    pub ticker: Option<String>,
+    pub strength: f64,
+    pub price: Option<f64>,
+    pub stop_loss: Option<f64>,
+    pub take_profit: Option<f64>,
+    pub reason: Option<String>,
+    pub metadata: Map,
+}
+
+impl ScriptSignal {
+    fn new(signal_type: SignalType, side: Side) -> Self {
+        Self {
+            signal_type,
+            side,
+            ticker: None,
+            strength: 1.0,
+            price: None,
+            stop_loss: None,
+            take_profit: None,
+            reason: None,
+            metadata: Map::new(),
+        }
+    }
+
+    /// 엔진 신호로 변환 (티커 미지정 시 현재 봉의 티커 사용).
+    pub fn into_signal(self, default_ticker: &str) -> Signal {
+        let ticker = self.ticker.unwrap_or_else(|| default_ticker.to_string());
+        let mut signal = Signal::new(SCRIPT_STRATEGY_ID, ticker, self.side, self.signal_type)
+            .with_strength(self.strength)
+            .with_prices(
+                self.price.and_then(Decimal::from_f64_retain),
+                self.stop_loss.and_then(Decimal::from_f64_retain),
+                self.take_profit.and_then(Decimal::from_f64_retain),
+            );
+        if let Some(reason) = self.reason {
+            signal = signal.with_metadata("reason", serde_json::Value::String(reason));
+        }
+        for (key, value) in self.metadata {
+            if let Ok(value) = rhai::serde::from_dynamic::<serde_json::Value>(&value) {
+                signal = signal.with_metadata(key.to_string(), value);
+            }
+        }
+        signal
+    }
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or(0.0)
+}
+
+fn opt_f64(value: Option<Decimal>) -> Dynamic {
+    value.map_or(Dynamic::UNIT, |v| Dynamic::from_float(to_f64(v)))
+}
+
+/// 정수/실수 인자를 f64로 변환.
+fn number(value: &Dynamic, name: &str) -> FnResult<f64> {
+    if let Ok(v) = value.as_float() {
+        return Ok(v);
+    }
+    if let Ok(v) = value.as_int() {
+        return Ok(v as f64);
+    }
+    Err(Box::new(EvalAltResult::ErrorMismatchDataType(
+        "number".to_string(),
+        format!("{} ({})", name, value.type_name()),
+        Position::NONE,
+    )))
+}
+
+/// 지표 계산기 조회 (`scripting::set_indicator_calculator`로 설정).
+fn indicators() -> FnResult<Arc<dyn IndicatorCalculator>> {
+    super::indicator_calculator()
+        .ok_or_else(|| "지표 계산기가 설정되지 않았습니다 (IndicatorEngine 미주입)".into())
+}
+
+fn period(value: INT) -> FnResult<usize> {
+    if value <= 0 {
+        return Err(format!("기간은 1 이상이어야 합니다: {}", value).into());
+    }
+    Ok(value as usize)
+}
+
+fn kline_map(kline: &Kline) -> Map {
+    let mut map = Map::new();
+    map.insert("open".into(), Dynamic::from_float(to_f64(kline.open)));
+    map.insert("high".into(), Dynamic::from_float(to_f64(kline.high)));
+    map.insert("low".into(), Dynamic::from_float(to_f64(kline.low)));
+    map.insert("close".into(), Dynamic::from_float(to_f64(kline.close)));
+    map.insert("volume".into(), Dynamic::from_float(to_f64(kline.volume)));
+    map.insert(
+        "time".into(),
+        Dynamic::from_int(kline.open_time.timestamp()),
+    );
+    map
+}
+
+fn side_name(side: Side) -> &'static str {
+    match side {
+        Side::Buy => "buy",
+        Side::Sell => "sell",
+    }
+}
+
+/// `Market`/`Signal` 타입과 신호 생성 함수를 엔진에 등록.
+pub fn register(engine: &mut Engine) {
+    register_market(engine);
+    register_signal(engine);
+}
+
+fn register_market(engine: &mut Engine) {
+    engine.register_type_with_name::<ScriptMarket>("Market");
+
+    // 현재 봉
+    engine
+        .register_get("ticker", |m: &mut ScriptMarket| m.ticker.clone())
+        .register_get("len", |m: &mut ScriptMarket| m.bars.len() as INT)
+        .register_get("time", |m: &mut ScriptMarket| {
+            m.current().open_time.timestamp()
+        })
+        .register_get("open", |m: &mut ScriptMarket| to_f64(m.current().open))
+        .register_get("high", |m: &mut ScriptMarket| to_f64(m.current().high))
+        .register_get("low", |m: &mut ScriptMarket| to_f64(m.current().low))
+        .register_get("close", |m: &mut ScriptMarket| to_f64(m.current().close))
+        .register_get("volume", |m: &mut ScriptMarket| to_f64(m.current().volume))
+        .register_get("params", |m: &mut ScriptMarket| m.params.clone());
+
+    // 과거 봉 (0 = 현재 봉)
+    engine.register_fn("bar", |m: &mut ScriptMarket, ago: INT| -> Dynamic {
+        let len = m.bars.len() as INT;
+        if ago < 0 || ago >= len {
+            return Dynamic::UNIT;
+        }
+        kline_map(&m.bars[(len - 1 - ago) as usize]).into()
+    });
+    engine
+        .register_fn("closes", |m: &mut ScriptMarket, n: INT| {
+            m.tail(n, |k| k.close)
+        })
+        .register_fn("highs", |m: &mut ScriptMarket, n: INT| {
+            m.tail(n, |k| k.high)
+        })
+        .register_fn("lows", |m: &mut ScriptMarket, n: INT| m.tail(n, |k| k.low))
+        .register_fn("volumes", |m: &mut ScriptMarket, n: INT| {
+            m.tail(n, |k| k.volume)
+        });
+
+    // 지표 (IndicatorEngine, 데이터 부족 시 ())
+    engine
+        .register_fn("sma", |m: &mut ScriptMarket, p: INT| -> FnResult<Dynamic> {
+            Ok(opt_f64(indicators()?.sma(&m.closes(), period(p)?)))
+        })
+        .register_fn("ema", |m: &mut ScriptMarket, p: INT| -> FnResult<Dynamic> {
+            Ok(opt_f64(indicators()?.ema(&m.closes(), period(p)?)))
+        })
+        .register_fn("rsi", |m: &mut ScriptMarket, p: INT| -> FnResult<Dynamic> {
+            Ok(opt_f64(indicators()?.rsi(&m.closes(), period(p)?)))
+        })
+        .register_fn("atr", |m: &mut ScriptMarket, p: INT| -> FnResult<Dynamic> {
+            Ok(opt_f64(indicators()?.atr(
+                &m.highs(),
+                &m.lows(),
+                &m.closes(),
+                period(p)?,
+            )))
+        })
+        .register_fn(
+            "bollinger",
+            |m: &mut ScriptMarket, p: INT, k: Dynamic| -> FnResult<Dynamic> {
+                let k = Decimal::from_f64_retain(number(&k, "k")?).unwrap_or(Decimal::TWO);
+                let bands = indicators()?.bollinger(&m.closes(), period(p)?, k);
+                Ok(bands.map_or(Dynamic::UNIT, |bb| {
+                    let mut map = Map::new();
+                    map.insert("upper".into(), Dynamic::from_float(to_f64(bb.upper)));
+                    map.insert("middle".into(), Dynamic::from_float(to_f64(bb.middle)));
+                    map.insert("lower".into(), Dynamic::from_float(to_f64(bb.lower)));
+                    map.insert(
+                        "width".into(),
+                        Dynamic::from_float(to_f64(bb.upper - bb.lower)),
+                    );
+                    map.into()
+                }))
+            },
+        )
+        .register_fn(
+            "macd",
+            |m: &mut ScriptMarket, fast: INT, slow: INT, signal: INT| -> FnResult<Dynamic> {
+                let result =
+                    indicators()?.macd(&m.closes(), period(fast)?, period(slow)?, period(signal)?);
+                Ok(result.map_or(Dynamic::UNIT, |macd| {
+                    let mut map = Map::new();
+                    map.insert("macd".into(), Dynamic::from_float(to_f64(macd.macd)));
+                    map.insert("signal".into(), Dynamic::from_float(to_f64(macd.signal)));
+                    map.insert(
+                        "histogram".into(),
+                        Dynamic::from_float(to_f64(macd.histogram)),
+                    );
+                    map.into()
+                }))
+            },
+        )
+        .register_fn(
+            "highest",
+            |m: &mut ScriptMarket, p: INT| -> FnResult<Dynamic> {
+                let p = period(p)?;
+                if m.bars.len() < p {
+                    return Ok(Dynamic::UNIT);
+                }
+                Ok(opt_f64(m.bars.iter().rev().take(p).map(|k| k.high).max()))
+            },
+        )
+        .register_fn(
+            "lowest",
+            |m: &mut ScriptMarket, p: INT| -> FnResult<Dynamic> {
+                let p = period(p)?;
+                if m.bars.len() < p {
+                    return Ok(Dynamic::UNIT);
+                }
+                Ok(opt_f64(m.bars.iter().rev().take(p).map(|k| k.low).min()))
+            },
+        );
+
+    // StrategyContext (없으면 ())
+    engine
+        .register_get("has_position", |m: &mut ScriptMarket| m.position.is_some())
+        .register_get("position", |m: &mut ScriptMarket| -> Dynamic {
+            m.position.as_ref().map_or(Dynamic::UNIT, |p| {
+                let mut map = Map::new();
+                map.insert("side".into(), side_name(p.side).into());
+                map.insert("quantity".into(), Dynamic::from_float(p.quantity));
+                map.insert("entry_price".into(), Dynamic::from_float(p.entry_price));
+                map.insert("pnl_pct".into(), Dynamic::from_float(p.pnl_pct));
+                map.into()
+            })
+        })
+        .register_get("score", |m: &mut ScriptMarket| {
+            m.global_score.map_or(Dynamic::UNIT, Dynamic::from_float)
+        })
+        .register_get("route_state", |m: &mut ScriptMarket| {
+            m.route_state.clone().map_or(Dynamic::UNIT, Dynamic::from)
+        })
+        .register_get("regime", |m: &mut ScriptMarket| {
+            m.regime.clone().map_or(Dynamic::UNIT, Dynamic::from)
+        });
+}
+
+fn register_signal(engine: &mut Engine) {
+    engine.register_type_with_name::<ScriptSignal>("Signal");
+
+    engine
+        .register_fn("buy", || ScriptSignal::new(SignalType::Entry, Side::Buy))
+        .register_fn("sell", || ScriptSignal::new(SignalType::Entry, Side::Sell))
+        .register_fn("exit", || ScriptSignal::new(SignalType::Exit, Side::Sell))
+        .register_fn("exit_short", || {
+            ScriptSignal::new(SignalType::Exit, Side::Buy)
+        })
+        .register_fn("alert", |message: &str| {
+            let mut signal = ScriptSignal::new(SignalType::Alert, Side::Buy);
+            signal.reason = Some(message.to_string());
+            signal
+        });
+
+    engine
+        .register_fn(
+            "strength",
+            |mut s: ScriptSignal, v: Dynamic| -> FnResult<ScriptSignal> {
+                s.strength = number(&v, "strength")?.clamp(0.0, 1.0);
+                Ok(s)
+            },
+        )
+        .register_fn(
+            "price",
+            |mut s: ScriptSignal, v: Dynamic| -> FnResult<ScriptSignal> {
+                s.price = Some(number(&v, "price")?);
+                Ok(s)
+            },
+        )
+        .register_fn(
+            "stop_loss",
+            |mut s: ScriptSignal, v: Dynamic| -> FnResult<ScriptSignal> {
+                s.stop_loss = Some(number(&v, "stop_loss")?);
+                Ok(s)
+            },
+        )
+        .register_fn(
+            "take_profit",
+            |mut s: ScriptSignal, v: Dynamic| -> FnResult<ScriptSignal> {
+                s.take_profit = Some(number(&v, "take_profit")?);
+                Ok(s)
+            },
+        )
+        .register_fn("ticker", |mut s: ScriptSignal, ticker: &str| {
+            s.ticker = Some(ticker.to_string());
+            s
+        })
+        .register_fn("reason", |mut s: ScriptSignal, reason: &str| {
+            s.reason = Some(reason.to_string());
+            s
+        })
+        .register_fn("meta", |mut s: ScriptSignal, key: &str, value: Dynamic| {
+            s.metadata.insert(key.into(), value);
+            s
+        });
+}
+
+/// 체결 주문을 스크립트용 맵으로 변환.
+pub fn fill_map(order: &trader_core::Order) -> Map {
+    let mut map = Map::new();
+    map.insert("ticker".into(), order.ticker.clone().into());
+    map.insert("side".into(), side_name(order.side).into());
+    map.insert(
+        "quantity".into(),
+        Dynamic::from_float(to_f64(order.filled_quantity)),
+    );
+    map.insert(
+        "price".into(),
+        opt_f64(order.average_fill_price.or(order.price)),
+    );
+    let status = serde_json::to_value(order.status)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    map.insert("status".into(), status.into());
+    map
+}
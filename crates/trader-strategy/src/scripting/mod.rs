@@ -0,0 +1,584 @@
+//! 샌드박스 스크립트 런타임.
+//!
+//! Rust 코드를 다시 빌드하지 않고 전략 로직을 교체할 수 있도록
+//! [Rhai](https://rhai.rs) 스크립트를 실행하는 호스트를 제공합니다.
+//! `plugin::loader`의 `.so` 로딩과 달리 스크립트는 호스트 프로세스 메모리에
+//! 직접 접근할 수 없고, 파일/네트워크/모듈 import가 모두 차단됩니다.
+//!
+//! # 스크립트 규약
+//!
+//! ```rhai
+//! // 선택: 초기화 시 1회 호출. `this`는 영속 상태 맵
+//! fn init(params) {
+//!     this.threshold = params.threshold ?? 30.0;
+//! }
+//!
+//! // 필수: 봉마다 호출. (), Signal 또는 Signal 배열 반환
+//! fn on_bar(market) {
+//!     let rsi = market.rsi(14);
+//!     if rsi == () { return; }
+//!     if rsi < this.threshold && !market.has_position {
+//!         return buy().strength(0.8).reason("RSI 과매도");
+//!     }
+//!     if rsi > 70.0 && market.has_position {
+//!         return exit();
+//!     }
+//! }
+//!
+//! // 선택: 주문 체결 시 호출
+//! fn on_fill(fill) {
+//!     this.fills = (this.fills ?? 0) + 1;
+//! }
+//! ```
+//!
+//! # 리소스 제한
+//!
+//! [`ScriptLimits`]로 호출당 연산 수/실행 시간, 호출 깊이, 문자열·배열·맵 크기를
+//! 제한합니다. 제한을 넘으면 [`ScriptError::LimitExceeded`]로 실행이 중단됩니다.
+//! 각 제한은 서버 상한([`ScriptLimits::maximum`])을 넘을 수 없으며, 스크립트 원문은
+//! [`MAX_SCRIPT_SIZE`] 바이트까지 허용합니다.
+//!
+//! # 지표
+//!
+//! `market.sma()`, `market.rsi()` 등 지표 함수는 [`set_indicator_calculator`]로 설정한
+//! [`IndicatorCalculator`](trader_core::domain::IndicatorCalculator)로 계산합니다.
+//! `trader-strategy`는 `trader-analytics`에 의존하지 않으므로 스크립트를 실행하는
+//! 프로세스(API 서버, CLI)가 시작 시 `IndicatorEngine`을 주입하며,
+//! 설정 전에 지표 함수를 호출하면 스크립트 실행 에러가 됩니다.
+
+pub mod bindings;
+
+use std::{
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant},
+};
+
+pub use bindings::{ScriptMarket, ScriptSignal, SCRIPT_STRATEGY_ID};
+use rhai::{
+    module_resolvers::DummyModuleResolver, Array, CallFnOptions, Dynamic, Engine, EvalAltResult,
+    Map, Scope, AST,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{debug, info};
+use trader_core::domain::IndicatorCalculator;
+
+/// 필수 진입 함수 이름.
+pub const ON_BAR_FN: &str = "on_bar";
+/// 선택 초기화 함수 이름.
+pub const INIT_FN: &str = "init";
+/// 선택 체결 콜백 이름.
+pub const ON_FILL_FN: &str = "on_fill";
+
+/// 스크립트 원문 최대 크기 (바이트).
+pub const MAX_SCRIPT_SIZE: usize = 64 * 1024;
+
+/// 실행 시간 검사 주기 (연산 수).
+const DEADLINE_CHECK_INTERVAL: u64 = 1024;
+
+/// 스크립트 지표 함수가 사용하는 계산기.
+static INDICATOR_CALCULATOR: RwLock<Option<Arc<dyn IndicatorCalculator>>> = RwLock::new(None);
+
+/// 스크립트 지표 함수가 사용할 계산기 설정 (프로세스 전역, 다시 호출하면 교체).
+pub fn set_indicator_calculator(calculator: Arc<dyn IndicatorCalculator>) {
+    *INDICATOR_CALCULATOR
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = Some(calculator);
+}
+
+/// 설정된 지표 계산기 조회.
+pub fn indicator_calculator() -> Option<Arc<dyn IndicatorCalculator>> {
+    INDICATOR_CALCULATOR
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// 스크립트 실행 에러.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ScriptError {
+    #[error("스크립트 컴파일 실패: {0}")]
+    Compile(String),
+
+    #[error("필수 함수 없음: fn {0}(market)")]
+    MissingFunction(String),
+
+    #[error("스크립트 실행 실패: {0}")]
+    Runtime(String),
+
+    #[error("스크립트 리소스 제한 초과: {0}")]
+    LimitExceeded(String),
+
+    #[error("잘못된 반환값: {0}")]
+    InvalidReturn(String),
+
+    #[error("상태 직렬화 실패: {0}")]
+    State(String),
+
+    #[error("잘못된 리소스 제한: {0}")]
+    InvalidLimits(String),
+
+    #[error("스크립트 크기 초과: {size}바이트 (최대 {max}바이트)")]
+    TooLarge { size: usize, max: usize },
+}
+
+impl From<Box<EvalAltResult>> for ScriptError {
+    fn from(err: Box<EvalAltResult>) -> Self {
+        match *err {
+            EvalAltResult::ErrorTooManyOperations(_)
+            | EvalAltResult::ErrorDataTooLarge(..)
+            | EvalAltResult::ErrorStackOverflow(_)
+            | EvalAltResult::ErrorTerminated(..) => Self::LimitExceeded(err.to_string()),
+            _ => Self::Runtime(err.to_string()),
+        }
+    }
+}
+
+/// 스크립트 리소스 제한.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScriptLimits {
+    /// 호출당 최대 연산 수
+    pub max_operations: u64,
+    /// 호출당 최대 실행 시간 (밀리초)
+    pub max_duration_ms: u64,
+    /// 최대 함수 호출 깊이
+    pub max_call_levels: usize,
+    /// 최대 표현식 중첩 깊이
+    pub max_expr_depth: usize,
+    /// 문자열 최대 길이 (바이트)
+    pub max_string_size: usize,
+    /// 배열 최대 원소 수
+    pub max_array_size: usize,
+    /// 맵 최대 항목 수
+    pub max_map_size: usize,
+}
+
+impl Default for ScriptLimits {
+    fn default() -> Self {
+        Self {
+            max_operations: 1_000_000,
+            max_duration_ms: 200,
+            max_call_levels: 32,
+            max_expr_depth: 64,
+            max_string_size: 64 * 1024,
+            max_array_size: 10_000,
+            max_map_size: 10_000,
+        }
+    }
+}
+
+impl ScriptLimits {
+    /// 서버가 허용하는 제한 상한.
+    pub fn maximum() -> Self {
+        Self {
+            max_operations: 10_000_000,
+            max_duration_ms: 1_000,
+            max_call_levels: 64,
+            max_expr_depth: 128,
+            max_string_size: 1024 * 1024,
+            max_array_size: 100_000,
+            max_map_size: 100_000,
+        }
+    }
+
+    /// 0(Rhai에서 무제한)이거나 상한을 넘는 제한이 있으면 에러.
+    pub fn validate(&self) -> Result<(), ScriptError> {
+        let max = Self::maximum();
+        let checks = [
+            ("max_operations", self.max_operations, max.max_operations),
+            ("max_duration_ms", self.max_duration_ms, max.max_duration_ms),
+            (
+                "max_call_levels",
+                self.max_call_levels as u64,
+                max.max_call_levels as u64,
+            ),
+            (
+                "max_expr_depth",
+                self.max_expr_depth as u64,
+                max.max_expr_depth as u64,
+            ),
+            (
+                "max_string_size",
+                self.max_string_size as u64,
+                max.max_string_size as u64,
+            ),
+            (
+                "max_array_size",
+                self.max_array_size as u64,
+                max.max_array_size as u64,
+            ),
+            (
+                "max_map_size",
+                self.max_map_size as u64,
+                max.max_map_size as u64,
+            ),
+        ];
+        let errors: Vec<String> = checks
+            .iter()
+            .filter(|(_, value, max)| *value == 0 || value > max)
+            .map(|(name, value, max)| format!("{}={}는 1..={} 범위여야 합니다", name, value, max))
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ScriptError::InvalidLimits(errors.join(", ")))
+        }
+    }
+
+    /// 0이거나 상한을 넘는 제한을 상한으로 맞춘 값.
+    ///
+    /// 검증 이전에 저장된 설정도 무제한으로 실행되지 않도록 엔진 생성 시 적용합니다.
+    pub fn clamped(&self) -> Self {
+        fn clamp<T: Copy + Default + PartialOrd>(value: T, max: T) -> T {
+            if value == T::default() || value > max {
+                max
+            } else {
+                value
+            }
+        }
+
+        let max = Self::maximum();
+        Self {
+            max_operations: clamp(self.max_operations, max.max_operations),
+            max_duration_ms: clamp(self.max_duration_ms, max.max_duration_ms),
+            max_call_levels: clamp(self.max_call_levels, max.max_call_levels),
+            max_expr_depth: clamp(self.max_expr_depth, max.max_expr_depth),
+            max_string_size: clamp(self.max_string_size, max.max_string_size),
+            max_array_size: clamp(self.max_array_size, max.max_array_size),
+            max_map_size: clamp(self.max_map_size, max.max_map_size),
+        }
+    }
+}
+
+/// 컴파일된 스크립트 정보.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptInfo {
+    /// 스크립트에 정의된 함수 이름
+    pub functions: Vec<String>,
+    /// `init(params)` 정의 여부
+    pub has_init: bool,
+    /// `on_fill(fill)` 정의 여부
+    pub has_on_fill: bool,
+}
+
+/// 샌드박스 스크립트 호스트.
+///
+/// 엔진 설정(제한, 차단 기능, 바인딩)과 컴파일된 AST를 함께 보관합니다.
+pub struct ScriptHost {
+    engine: Engine,
+    ast: AST,
+    info: ScriptInfo,
+    limits: ScriptLimits,
+    deadline: Arc<Mutex<Option<Instant>>>,
+}
+
+impl std::fmt::Debug for ScriptHost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScriptHost")
+            .field("info", &self.info)
+            .field("limits", &self.limits)
+            .finish()
+    }
+}
+
+impl ScriptHost {
+    /// 스크립트를 컴파일하고 필수 함수를 검증합니다.
+    ///
+    /// 제한은 [`ScriptLimits::clamped`]로 상한을 넘지 않게 맞춘 뒤 적용합니다.
+    pub fn compile(source: &str, limits: ScriptLimits) -> Result<Self, ScriptError> {
+        if source.len() > MAX_SCRIPT_SIZE {
+            return Err(ScriptError::TooLarge {
+                size: source.len(),
+                max: MAX_SCRIPT_SIZE,
+            });
+        }
+
+        let limits = limits.clamped();
+        let deadline = Arc::new(Mutex::new(None));
+        let engine = build_engine(&limits, Arc::clone(&deadline));
+        let ast = engine
+            .compile(source)
+            .map_err(|e| ScriptError::Compile(e.to_string()))?;
+
+        let has_fn = |name: &str, arity: usize| {
+            ast.iter_functions()
+                .any(|f| f.name == name && f.params.len() == arity)
+        };
+        if !has_fn(ON_BAR_FN, 1) {
+            return Err(ScriptError::MissingFunction(ON_BAR_FN.to_string()));
+        }
+
+        let info = ScriptInfo {
+            functions: ast.iter_functions().map(|f| f.name.to_string()).collect(),
+            has_init: has_fn(INIT_FN, 1),
+            has_on_fill: has_fn(ON_FILL_FN, 1),
+        };
+
+        Ok(Self {
+            engine,
+            ast,
+            info,
+            limits,
+            deadline,
+        })
+    }
+
+    /// 스크립트를 실행하지 않고 검증만 수행합니다.
+    pub fn validate(source: &str) -> Result<ScriptInfo, ScriptError> {
+        Self::compile(source, ScriptLimits::default()).map(|host| host.info)
+    }
+
+    /// 컴파일된 스크립트 정보.
+    pub fn info(&self) -> &ScriptInfo {
+        &self.info
+    }
+
+    /// 적용된 리소스 제한.
+    pub fn limits(&self) -> &ScriptLimits {
+        &self.limits
+    }
+
+    /// `init(params)` 호출 (정의되지 않았으면 무시).
+    pub fn call_init(&self, state: &mut Dynamic, params: Map) -> Result<(), ScriptError> {
+        if !self.info.has_init {
+            return Ok(());
+        }
+        self.call(state, INIT_FN, Dynamic::from_map(params))
+            .map(|_| ())
+    }
+
+    /// `on_bar(market)` 호출 후 반환된 신호 목록.
+    pub fn call_on_bar(
+        &self,
+        state: &mut Dynamic,
+        market: ScriptMarket,
+    ) -> Result<Vec<ScriptSignal>, ScriptError> {
+        let result = self.call(state, ON_BAR_FN, Dynamic::from(market))?;
+        collect_signals(result)
+    }
+
+    /// `on_fill(fill)` 호출 (정의되지 않았으면 무시).
+    pub fn call_on_fill(&self, state: &mut Dynamic, fill: Map) -> Result<(), ScriptError> {
+        if !self.info.has_on_fill {
+            return Ok(());
+        }
+        self.call(state, ON_FILL_FN, Dynamic::from_map(fill))
+            .map(|_| ())
+    }
+
+    fn call(&self, state: &mut Dynamic, name: &str, arg: Dynamic) -> Result<Dynamic, ScriptError> {
+        let deadline = Instant::now() + Duration::from_millis(self.limits.max_duration_ms);
+        *self.deadline.lock().unwrap_or_else(|e| e.into_inner()) = Some(deadline);
+
+        let options = CallFnOptions::new()
+            .eval_ast(false)
+            .rewind_scope(true)
+            .bind_this_ptr(state);
+        let result = self.engine.call_fn_with_options::<Dynamic>(
+            options,
+            &mut Scope::new(),
+            &self.ast,
+            name,
+            (arg,),
+        );
+
+        *self.deadline.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        result.map_err(ScriptError::from)
+    }
+}
+
+/// 제한과 바인딩을 적용한 엔진 생성.
+fn build_engine(limits: &ScriptLimits, deadline: Arc<Mutex<Option<Instant>>>) -> Engine {
+    let mut engine = Engine::new();
+
+    // 파일 기반 모듈 import와 동적 평가 차단
+    engine.set_module_resolver(DummyModuleResolver::new());
+    engine.disable_symbol("import").disable_symbol("eval");
+
+    engine
+        .set_max_operations(limits.max_operations)
+        .set_max_call_levels(limits.max_call_levels)
+        .set_max_expr_depths(limits.max_expr_depth, limits.max_expr_depth)
+        .set_max_string_size(limits.max_string_size)
+        .set_max_array_size(limits.max_array_size)
+        .set_max_map_size(limits.max_map_size)
+        .set_max_modules(0)
+        .set_strict_variables(true);
+
+    engine.on_progress(move |ops| {
+        if ops % DEADLINE_CHECK_INTERVAL != 0 {
+            return None;
+        }
+        let deadline = *deadline.lock().unwrap_or_else(|e| e.into_inner());
+        match deadline {
+            Some(deadline) if Instant::now() >= deadline => Some(Dynamic::from("실행 시간 초과")),
+            _ => None,
+        }
+    });
+
+    engine.on_print(|text| info!(target: "trader_strategy::script", "{}", text));
+    engine.on_debug(
+        |text, _source, pos| debug!(target: "trader_strategy::script", position = %pos, "{}", text),
+    );
+
+    bindings::register(&mut engine);
+    engine
+}
+
+/// `on_bar` 반환값을 신호 목록으로 변환.
+fn collect_signals(result: Dynamic) -> Result<Vec<ScriptSignal>, ScriptError> {
+    if result.is_unit() {
+        return Ok(Vec::new());
+    }
+    if result.is::<ScriptSignal>() {
+        return Ok(vec![result.cast::<ScriptSignal>()]);
+    }
+    if result.is_array() {
+        let items: Array = result.cast();
+        return items
+            .into_iter()
+            .filter(|item| !item.is_unit())
+            .map(|item| {
+                item.try_cast::<ScriptSignal>().ok_or_else(|| {
+                    ScriptError::InvalidReturn("배열에는 Signal만 포함할 수 있습니다".to_string())
+                })
+            })
+            .collect();
+    }
+    Err(ScriptError::InvalidReturn(format!(
+        "on_bar는 (), Signal 또는 Signal 배열을 반환해야 합니다 (받은 타입: {})",
+        result.type_name()
+    )))
+}
+
+/// 상태 맵을 JSON으로 변환.
+pub fn state_to_json(state: &Dynamic) -> Result<serde_json::Value, ScriptError> {
+    rhai::serde::from_dynamic(state).map_err(|e| ScriptError::State(e.to_string()))
+}
+
+/// JSON을 상태 맵으로 변환.
+pub fn state_from_json(value: &serde_json::Value) -> Result<Dynamic, ScriptError> {
+    let state = rhai::serde::to_dynamic(value).map_err(|e| ScriptError::State(e.to_string()))?;
+    if !state.is_map() {
+        return Err(ScriptError::State("상태는 맵이어야 합니다".to_string()));
+    }
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_requires_on_bar() {
+        let err = ScriptHost::validate("fn init(params) { }").unwrap_err();
+        assert_eq!(err, ScriptError::MissingFunction("on_bar".to_string()));
+
+        let info = ScriptHost::validate("fn on_bar(m) { } fn on_fill(f) { }").unwrap();
+        assert!(info.has_on_fill);
+        assert!(!info.has_init);
+
+        assert!(matches!(
+            ScriptHost::validate("fn on_bar(m) { let x = ; }"),
+            Err(ScriptError::Compile(_))
+        ));
+    }
+
+    #[test]
+    fn test_sandbox_blocks_import_and_eval() {
+        assert!(ScriptHost::validate(r#"import "os" as os; fn on_bar(m) { }"#).is_err());
+        assert!(ScriptHost::validate(r#"fn on_bar(m) { eval("1") }"#).is_err());
+    }
+
+    #[test]
+    fn test_operation_limit_stops_infinite_loop() {
+        let limits = ScriptLimits {
+            max_operations: 10_000,
+            ..Default::default()
+        };
+        let host = ScriptHost::compile("fn init(p) { loop { } } fn on_bar(m) { }", limits).unwrap();
+
+        let mut state = Dynamic::from_map(Map::new());
+        let err = host.call_init(&mut state, Map::new()).unwrap_err();
+        assert!(matches!(err, ScriptError::LimitExceeded(_)));
+    }
+
+    #[test]
+    fn test_limits_reject_unlimited_and_clamp_to_maximum() {
+        assert!(ScriptLimits::default().validate().is_ok());
+        assert!(ScriptLimits::maximum().validate().is_ok());
+
+        let unlimited = ScriptLimits {
+            max_operations: 0,
+            max_duration_ms: 60_000,
+            ..Default::default()
+        };
+        let err = unlimited.validate().unwrap_err();
+        assert!(matches!(&err, ScriptError::InvalidLimits(msg)
+            if msg.contains("max_operations") && msg.contains("max_duration_ms")));
+
+        // 검증 없이 저장된 설정도 상한으로 맞춰 실행
+        let host = ScriptHost::compile("fn on_bar(m) { }", unlimited).unwrap();
+        assert_eq!(
+            host.limits().max_operations,
+            ScriptLimits::maximum().max_operations
+        );
+        assert_eq!(
+            host.limits().max_duration_ms,
+            ScriptLimits::maximum().max_duration_ms
+        );
+        assert_eq!(
+            host.limits().max_array_size,
+            ScriptLimits::default().max_array_size
+        );
+    }
+
+    #[test]
+    fn test_script_size_limit() {
+        let source = format!("fn on_bar(m) {{ }}\n//{}", "x".repeat(MAX_SCRIPT_SIZE));
+        assert!(matches!(
+            ScriptHost::validate(&source),
+            Err(ScriptError::TooLarge {
+                max: MAX_SCRIPT_SIZE,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_memory_limit_stops_growing_array() {
+        let limits = ScriptLimits {
+            max_array_size: 100,
+            ..Default::default()
+        };
+        let host = ScriptHost::compile(
+            "fn init(p) { this.xs = []; for i in 0..1000 { this.xs.push(i); } } fn on_bar(m) { }",
+            limits,
+        )
+        .unwrap();
+
+        let mut state = Dynamic::from_map(Map::new());
+        let err = host.call_init(&mut state, Map::new()).unwrap_err();
+        assert!(matches!(err, ScriptError::LimitExceeded(_)));
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        let host = ScriptHost::compile(
+            "fn init(p) { this.count = 0; this.name = p.name; } fn on_bar(m) { }",
+            ScriptLimits::default(),
+        )
+        .unwrap();
+
+        let mut params = Map::new();
+        params.insert("name".into(), "rsi".into());
+        let mut state = Dynamic::from_map(Map::new());
+        host.call_init(&mut state, params).unwrap();
+
+        let json = state_to_json(&state).unwrap();
+        assert_eq!(json, serde_json::json!({ "count": 0, "name": "rsi" }));
+        let restored = state_from_json(&json).unwrap();
+        assert_eq!(state_to_json(&restored).unwrap(), json);
+    }
+}
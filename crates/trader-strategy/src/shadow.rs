@@ -0,0 +1,697 @@
+//! 섀도(페이퍼) 모드.
+//!
+//! 실행 중인 전략 옆에서 후보 설정을 같은 시장 데이터로 함께 실행하고,
+//! 후보의 신호는 브로커 대신 `SimulatedExecutor`로 가상 체결합니다.
+//!
+//! 비교가 같은 체결 모델 위에서 이루어지도록 라이브 전략의 신호도 별도의
+//! 미러 `SimulatedExecutor`로 체결하며, 실제 브로커 체결은 참고용으로 함께 기록합니다.
+//!
+//! 섀도의 가상 체결은 [`ShadowFill`]로 반환되어 섀도 전략의 `on_order_filled`/
+//! `on_position_update`에 전달되고, 섀도 전용 컨텍스트의 계좌/포지션도
+//! [`ShadowLedger::sync_context`]로 가상 계좌 기준으로 유지됩니다.
+
+use std::collections::{HashMap, VecDeque};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use trader_core::{
+    domain::{StrategyAccountInfo, StrategyContext, StrategyPositionInfo},
+    MarketData, Order, OrderRequest, OrderStatusType, Position, Side, Signal, SignalType,
+};
+use trader_execution::{ProcessorConfig, SignalProcessor, SimulatedExecutor, TradeResult};
+
+use crate::deployment::DryRunReport;
+
+/// 가상 체결 주문/포지션의 거래소 이름.
+const SHADOW_EXCHANGE: &str = "shadow";
+
+/// 섀도 실행 설정.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShadowConfig {
+    /// 가상 초기 자본 (라이브 미러와 섀도에 동일 적용)
+    #[serde(default = "default_initial_balance")]
+    pub initial_balance: Decimal,
+
+    /// 가상 체결 설정 (수수료, 슬리피지, 포지션 크기)
+    #[serde(default)]
+    pub processor: ProcessorConfig,
+
+    /// 보관할 신호/체결/손익 이력 수
+    #[serde(default = "default_history_limit")]
+    pub history_limit: usize,
+}
+
+fn default_initial_balance() -> Decimal {
+    Decimal::from(10_000_000)
+}
+
+fn default_history_limit() -> usize {
+    1000
+}
+
+impl Default for ShadowConfig {
+    fn default() -> Self {
+        Self {
+            initial_balance: default_initial_balance(),
+            processor: ProcessorConfig::default(),
+            history_limit: default_history_limit(),
+        }
+    }
+}
+
+/// 가상 체결된 신호 기록.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowSignal {
+    /// 시장 데이터 시각
+    pub timestamp: DateTime<Utc>,
+    /// 티커
+    pub ticker: String,
+    /// 방향
+    pub side: Side,
+    /// 신호 유형
+    pub signal_type: SignalType,
+    /// 신호 강도
+    pub strength: f64,
+    /// 체결 가격 (체결되지 않았으면 None)
+    pub fill_price: Option<Decimal>,
+    /// 체결 수량
+    pub quantity: Option<Decimal>,
+    /// 실현 손익 (청산 체결인 경우)
+    pub realized_pnl: Option<Decimal>,
+    /// 체결되지 않은 사유
+    pub rejected: Option<String>,
+}
+
+/// 라이브 전략의 실제 브로커 체결.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveFill {
+    /// 체결 알림 시각
+    pub timestamp: DateTime<Utc>,
+    /// 티커
+    pub ticker: String,
+    /// 방향
+    pub side: Side,
+    /// 체결 수량
+    pub quantity: Decimal,
+    /// 평균 체결가
+    pub price: Option<Decimal>,
+}
+
+/// 섀도 전략에 다시 전달할 가상 체결.
+#[derive(Debug, Clone)]
+pub struct ShadowFill {
+    /// 체결된 가상 주문
+    pub order: Order,
+    /// 체결 후 해당 티커의 가상 포지션 (청산되면 수량 0)
+    pub position: Position,
+}
+
+/// 시점별 라이브/섀도 손익.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowPoint {
+    /// 시장 데이터 시각
+    pub timestamp: DateTime<Utc>,
+    /// 라이브 미러 평가 자산
+    pub live_equity: Decimal,
+    /// 섀도 평가 자산
+    pub shadow_equity: Decimal,
+    /// 라이브 미러 실현 손익
+    pub live_realized_pnl: Decimal,
+    /// 섀도 실현 손익
+    pub shadow_realized_pnl: Decimal,
+}
+
+/// 한쪽(라이브 미러 또는 섀도)의 가상 계좌 요약.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowBook {
+    /// 발생 신호 수
+    pub signals: u64,
+    /// 가상 체결 수
+    pub trades: usize,
+    /// 실현 손익
+    pub realized_pnl: Decimal,
+    /// 미실현 손익
+    pub unrealized_pnl: Decimal,
+    /// 평가 자산
+    pub equity: Decimal,
+    /// 수익률(%)
+    pub return_pct: f64,
+    /// 보유 포지션 수
+    pub open_positions: usize,
+}
+
+/// 라이브와 섀도 신호 일치 통계 (같은 시장 데이터 기준).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShadowAgreement {
+    /// 양쪽이 같은 신호를 낸 수
+    pub matched: usize,
+    /// 라이브만 낸 신호 수
+    pub only_live: usize,
+    /// 섀도만 낸 신호 수
+    pub only_shadow: usize,
+}
+
+impl ShadowAgreement {
+    /// 신호 일치율 (양쪽 모두 신호가 없으면 None).
+    pub fn rate(&self) -> Option<f64> {
+        let total = self.matched + self.only_live + self.only_shadow;
+        (total > 0).then(|| self.matched as f64 / total as f64)
+    }
+}
+
+/// 섀도 실행 요약 (목록 조회용).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowSummary {
+    /// 섀도 ID
+    pub shadow_id: String,
+    /// 비교 대상 라이브 전략 ID
+    pub live_strategy_id: String,
+    /// 섀도 전략 타입
+    pub strategy_type: String,
+    /// 시작 시각
+    pub started_at: DateTime<Utc>,
+    /// 처리한 시장 데이터 수
+    pub bars_processed: u64,
+    /// 라이브 미러 평가 자산
+    pub live_equity: Decimal,
+    /// 섀도 평가 자산
+    pub shadow_equity: Decimal,
+    /// 신호 일치율
+    pub agreement: Option<f64>,
+}
+
+/// 라이브 대 섀도 비교 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowComparison {
+    /// 섀도 ID
+    pub shadow_id: String,
+    /// 비교 대상 라이브 전략 ID
+    pub live_strategy_id: String,
+    /// 섀도 전략 타입
+    pub strategy_type: String,
+    /// 섀도 설정
+    pub config: Value,
+    /// 시작 시각
+    pub started_at: DateTime<Utc>,
+    /// 처리한 시장 데이터 수
+    pub bars_processed: u64,
+    /// 라이브 미러 계좌
+    pub live: ShadowBook,
+    /// 섀도 계좌
+    pub shadow: ShadowBook,
+    /// 신호 일치 통계
+    pub agreement: ShadowAgreement,
+    /// 라이브 신호 (가상 체결 결과 포함)
+    pub live_signals: Vec<ShadowSignal>,
+    /// 섀도 신호
+    pub shadow_signals: Vec<ShadowSignal>,
+    /// 라이브 실제 브로커 체결
+    pub live_fills: Vec<LiveFill>,
+    /// 시점별 손익
+    pub timeline: Vec<ShadowPoint>,
+    /// 마지막 섀도 에러
+    pub last_error: Option<String>,
+}
+
+/// 섀도 실행 장부.
+///
+/// 전략 호출은 엔진이 담당하고, 여기서는 양쪽 신호의 가상 체결과 이력만 관리합니다.
+#[derive(Debug)]
+pub struct ShadowLedger {
+    shadow_id: String,
+    live_strategy_id: String,
+    strategy_type: String,
+    config: Value,
+    started_at: DateTime<Utc>,
+    initial_balance: Decimal,
+    history_limit: usize,
+    bars_processed: u64,
+    live_executor: SimulatedExecutor,
+    shadow_executor: SimulatedExecutor,
+    live_signal_count: u64,
+    shadow_signal_count: u64,
+    last_prices: HashMap<String, Decimal>,
+    agreement: ShadowAgreement,
+    live_signals: VecDeque<ShadowSignal>,
+    shadow_signals: VecDeque<ShadowSignal>,
+    live_fills: VecDeque<LiveFill>,
+    timeline: VecDeque<ShadowPoint>,
+    last_error: Option<String>,
+}
+
+impl ShadowLedger {
+    /// 새 장부 생성.
+    pub fn new(
+        shadow_id: impl Into<String>,
+        live_strategy_id: impl Into<String>,
+        strategy_type: impl Into<String>,
+        config: Value,
+        shadow_config: &ShadowConfig,
+    ) -> Self {
+        let executor = || {
+            SimulatedExecutor::new(
+                shadow_config.processor.clone(),
+                shadow_config.initial_balance,
+            )
+        };
+
+        Self {
+            shadow_id: shadow_id.into(),
+            live_strategy_id: live_strategy_id.into(),
+            strategy_type: strategy_type.into(),
+            config,
+            started_at: Utc::now(),
+            initial_balance: shadow_config.initial_balance,
+            history_limit: shadow_config.history_limit.max(1),
+            bars_processed: 0,
+            live_executor: executor(),
+            shadow_executor: executor(),
+            live_signal_count: 0,
+            shadow_signal_count: 0,
+            last_prices: HashMap::new(),
+            agreement: ShadowAgreement::default(),
+            live_signals: VecDeque::new(),
+            shadow_signals: VecDeque::new(),
+            live_fills: VecDeque::new(),
+            timeline: VecDeque::new(),
+            last_error: None,
+        }
+    }
+
+    /// 비교 대상 라이브 전략 ID.
+    pub fn live_strategy_id(&self) -> &str {
+        &self.live_strategy_id
+    }
+
+    /// 한 시장 데이터에 대한 양쪽 신호를 가상 체결하고 손익 이력 기록.
+    ///
+    /// 섀도 신호의 가상 체결 목록을 반환합니다.
+    pub async fn record_bar(
+        &mut self,
+        data: &MarketData,
+        live_signals: &[Signal],
+        shadow_signals: &[Signal],
+    ) -> Vec<ShadowFill> {
+        if let Some(price) = data.get_price() {
+            self.last_prices.insert(data.ticker.clone(), price);
+        }
+        self.bars_processed += 1;
+
+        let fills = self
+            .record_signals(data.timestamp, live_signals, shadow_signals)
+            .await;
+
+        let point = ShadowPoint {
+            timestamp: data.timestamp,
+            live_equity: self.live_executor.total_equity(&self.last_prices),
+            shadow_equity: self.shadow_executor.total_equity(&self.last_prices),
+            live_realized_pnl: self.live_executor.realized_pnl(),
+            shadow_realized_pnl: self.shadow_executor.realized_pnl(),
+        };
+        push_bounded(&mut self.timeline, point, self.history_limit);
+        fills
+    }
+
+    /// 시장 데이터 없이 발생한 신호(일정 이벤트 등) 기록.
+    ///
+    /// 마지막으로 관측한 가격으로 양쪽을 가상 체결하고 섀도 쪽 체결 목록을 반환합니다.
+    pub async fn record_signals(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        live_signals: &[Signal],
+        shadow_signals: &[Signal],
+    ) -> Vec<ShadowFill> {
+        let live = [live_signals.to_vec()];
+        let report = DryRunReport::compare(Some(&live[..]), &[shadow_signals.to_vec()]);
+        self.agreement.matched += report.matched;
+        self.agreement.only_live += report.only_baseline.len();
+        self.agreement.only_shadow += report.only_candidate.len();
+
+        for signal in live_signals {
+            self.live_signal_count += 1;
+            let (record, _) = execute(
+                &mut self.live_executor,
+                &self.last_prices,
+                signal,
+                timestamp,
+            )
+            .await;
+            push_bounded(&mut self.live_signals, record, self.history_limit);
+        }
+        let mut fills = Vec::new();
+        for signal in shadow_signals {
+            self.shadow_signal_count += 1;
+            let (record, trade) = execute(
+                &mut self.shadow_executor,
+                &self.last_prices,
+                signal,
+                timestamp,
+            )
+            .await;
+            push_bounded(&mut self.shadow_signals, record, self.history_limit);
+            if let Some(trade) = trade {
+                fills.push(self.shadow_fill(&trade));
+            }
+        }
+        fills
+    }
+
+    /// 섀도 컨텍스트의 계좌/포지션을 섀도 가상 계좌로 교체.
+    pub fn sync_context(&self, ctx: &mut StrategyContext) {
+        let positions = self
+            .shadow_executor
+            .positions()
+            .values()
+            .map(|p| {
+                let mut info =
+                    StrategyPositionInfo::new(p.symbol.clone(), p.side, p.quantity, p.entry_price);
+                info.created_at = p.entry_time;
+                if let Some(price) = self.last_prices.get(&p.symbol) {
+                    info.update_price(*price);
+                }
+                info
+            })
+            .collect();
+        ctx.update_positions(positions);
+
+        let account = StrategyAccountInfo {
+            total_balance: self.shadow_executor.total_equity(&self.last_prices),
+            available_balance: self.shadow_executor.balance(),
+            margin_used: Decimal::ZERO,
+            unrealized_pnl: self.shadow_executor.unrealized_pnl(&self.last_prices),
+            currency: ctx.account.currency.clone(),
+        };
+        ctx.update_account(account);
+    }
+
+    /// 가상 체결을 섀도 전략에 전달할 주문/포지션으로 변환.
+    fn shadow_fill(&self, trade: &TradeResult) -> ShadowFill {
+        let request = match trade.side {
+            Side::Buy => OrderRequest::market_buy(trade.symbol.clone(), trade.quantity),
+            Side::Sell => OrderRequest::market_sell(trade.symbol.clone(), trade.quantity),
+        }
+        .with_strategy(self.live_strategy_id.as_str());
+        let mut order = Order::from_request(request, SHADOW_EXCHANGE);
+        order.status = OrderStatusType::Filled;
+        order.filled_quantity = trade.quantity;
+        order.average_fill_price = Some(trade.price);
+        order.created_at = trade.timestamp;
+        order.updated_at = trade.timestamp;
+
+        let mut position = match self
+            .shadow_executor
+            .positions()
+            .values()
+            .find(|p| p.symbol == trade.symbol)
+        {
+            Some(p) => {
+                let mut position = Position::new(
+                    SHADOW_EXCHANGE,
+                    p.symbol.clone(),
+                    p.side,
+                    p.quantity,
+                    p.entry_price,
+                );
+                position.opened_at = p.entry_time;
+                position
+            }
+            None => {
+                // 청산 체결: 체결 반대 방향 포지션이 0이 됨
+                let mut position = Position::new(
+                    SHADOW_EXCHANGE,
+                    trade.symbol.clone(),
+                    trade.side.opposite(),
+                    Decimal::ZERO,
+                    trade.price,
+                );
+                position.closed_at = Some(trade.timestamp);
+                position
+            }
+        }
+        .with_strategy(self.live_strategy_id.as_str());
+        position.update_price(trade.price);
+        position.realized_pnl = trade.realized_pnl.unwrap_or_default();
+
+        ShadowFill { order, position }
+    }
+
+    /// 라이브 전략의 실제 브로커 체결 기록.
+    pub fn record_live_fill(&mut self, order: &Order) {
+        let fill = LiveFill {
+            timestamp: order.updated_at,
+            ticker: order.ticker.clone(),
+            side: order.side,
+            quantity: order.filled_quantity,
+            price: order.average_fill_price.or(order.price),
+        };
+        push_bounded(&mut self.live_fills, fill, self.history_limit);
+    }
+
+    /// 섀도 전략 에러 기록.
+    pub fn record_error(&mut self, error: impl Into<String>) {
+        self.last_error = Some(error.into());
+    }
+
+    /// 요약 생성.
+    pub fn summary(&self) -> ShadowSummary {
+        ShadowSummary {
+            shadow_id: self.shadow_id.clone(),
+            live_strategy_id: self.live_strategy_id.clone(),
+            strategy_type: self.strategy_type.clone(),
+            started_at: self.started_at,
+            bars_processed: self.bars_processed,
+            live_equity: self.live_executor.total_equity(&self.last_prices),
+            shadow_equity: self.shadow_executor.total_equity(&self.last_prices),
+            agreement: self.agreement.rate(),
+        }
+    }
+
+    /// 비교 결과 생성.
+    pub fn comparison(&self) -> ShadowComparison {
+        ShadowComparison {
+            shadow_id: self.shadow_id.clone(),
+            live_strategy_id: self.live_strategy_id.clone(),
+            strategy_type: self.strategy_type.clone(),
+            config: self.config.clone(),
+            started_at: self.started_at,
+            bars_processed: self.bars_processed,
+            live: self.book(&self.live_executor, self.live_signal_count),
+            shadow: self.book(&self.shadow_executor, self.shadow_signal_count),
+            agreement: self.agreement.clone(),
+            live_signals: self.live_signals.iter().cloned().collect(),
+            shadow_signals: self.shadow_signals.iter().cloned().collect(),
+            live_fills: self.live_fills.iter().cloned().collect(),
+            timeline: self.timeline.iter().cloned().collect(),
+            last_error: self.last_error.clone(),
+        }
+    }
+
+    fn book(&self, executor: &SimulatedExecutor, signals: u64) -> ShadowBook {
+        let equity = executor.total_equity(&self.last_prices);
+        let return_pct = if self.initial_balance.is_zero() {
+            0.0
+        } else {
+            ((equity - self.initial_balance) / self.initial_balance * Decimal::ONE_HUNDRED)
+                .to_f64()
+                .unwrap_or(0.0)
+        };
+
+        ShadowBook {
+            signals,
+            trades: executor.trades().len(),
+            realized_pnl: executor.realized_pnl(),
+            unrealized_pnl: executor.unrealized_pnl(&self.last_prices),
+            equity,
+            return_pct,
+            open_positions: executor.positions().len(),
+        }
+    }
+}
+
+/// 신호를 가상 체결하고 결과 기록과 체결 내역 생성.
+async fn execute(
+    executor: &mut SimulatedExecutor,
+    prices: &HashMap<String, Decimal>,
+    signal: &Signal,
+    timestamp: DateTime<Utc>,
+) -> (ShadowSignal, Option<TradeResult>) {
+    let mut record = ShadowSignal {
+        timestamp,
+        ticker: signal.ticker.clone(),
+        side: signal.side,
+        signal_type: signal.signal_type,
+        strength: signal.strength,
+        fill_price: None,
+        quantity: None,
+        realized_pnl: None,
+        rejected: None,
+    };
+
+    let Some(price) = prices
+        .get(&signal.ticker)
+        .copied()
+        .or(signal.suggested_price)
+    else {
+        record.rejected = Some("가격 정보 없음".to_string());
+        return (record, None);
+    };
+
+    match executor.process_signal(signal, price, timestamp).await {
+        Ok(Some(trade)) => {
+            record.fill_price = Some(trade.price);
+            record.quantity = Some(trade.quantity);
+            record.realized_pnl = trade.realized_pnl;
+            (record, Some(trade))
+        }
+        Ok(None) => {
+            record.rejected = Some("체결 대상 없음".to_string());
+            (record, None)
+        }
+        Err(e) => {
+            record.rejected = Some(e.to_string());
+            (record, None)
+        }
+    }
+}
+
+fn push_bounded<T>(queue: &mut VecDeque<T>, item: T, limit: usize) {
+    if queue.len() >= limit {
+        queue.pop_front();
+    }
+    queue.push_back(item);
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+    use trader_core::{Kline, Timeframe};
+
+    use super::*;
+
+    fn bar(close: Decimal) -> MarketData {
+        let now = Utc::now();
+        MarketData::from_kline(
+            "test",
+            Kline::new(
+                "005930".to_string(),
+                Timeframe::D1,
+                now,
+                close,
+                close,
+                close,
+                close,
+                dec!(1000),
+                now,
+            ),
+        )
+    }
+
+    fn ledger() -> ShadowLedger {
+        let config = ShadowConfig {
+            initial_balance: dec!(1_000_000),
+            processor: ProcessorConfig {
+                commission_rate: Decimal::ZERO,
+                slippage_rate: Decimal::ZERO,
+                ..ProcessorConfig::default()
+            },
+            history_limit: 2,
+        };
+        ShadowLedger::new("s1", "live", "test", json!({}), &config)
+    }
+
+    #[tokio::test]
+    async fn test_record_bar_tracks_both_books() {
+        let mut ledger = ledger();
+        let buy = Signal::entry("live", "005930".to_string(), Side::Buy);
+
+        // 라이브만 진입 → 섀도는 현금 유지
+        ledger.record_bar(&bar(dec!(100)), &[buy], &[]).await;
+        ledger.record_bar(&bar(dec!(110)), &[], &[]).await;
+
+        let comparison = ledger.comparison();
+        assert_eq!(comparison.bars_processed, 2);
+        assert_eq!(comparison.live.trades, 1);
+        assert_eq!(comparison.live.open_positions, 1);
+        // 20% 비중 진입 후 10% 상승 → 자산 2% 증가
+        assert_eq!(comparison.live.equity, dec!(1_020_000));
+        assert!((comparison.live.return_pct - 2.0).abs() < 1e-9);
+        assert_eq!(comparison.shadow.equity, dec!(1_000_000));
+        assert_eq!(comparison.agreement.only_live, 1);
+        assert_eq!(comparison.agreement.rate(), Some(0.0));
+        assert_eq!(comparison.timeline.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_agreement_and_history_limit() {
+        let mut ledger = ledger();
+        let buy = [Signal::entry("s", "005930".to_string(), Side::Buy)];
+        let exit = [Signal::exit("s", "005930".to_string(), Side::Sell)];
+
+        ledger.record_bar(&bar(dec!(100)), &buy, &buy).await;
+        ledger.record_bar(&bar(dec!(90)), &[], &[]).await;
+        ledger.record_bar(&bar(dec!(95)), &exit, &exit).await;
+
+        let comparison = ledger.comparison();
+        assert_eq!(comparison.agreement.matched, 2);
+        assert_eq!(comparison.agreement.rate(), Some(1.0));
+        assert_eq!(comparison.live.realized_pnl, comparison.shadow.realized_pnl);
+        assert!(comparison.shadow.realized_pnl < Decimal::ZERO);
+        // 이력은 최근 2개만 보관
+        assert_eq!(comparison.timeline.len(), 2);
+        assert_eq!(comparison.shadow.signals, 2);
+    }
+
+    #[tokio::test]
+    async fn test_shadow_fills_are_returned_and_synced() {
+        let mut ledger = ledger();
+        let buy = [Signal::entry("s", "005930".to_string(), Side::Buy)];
+        let exit = [Signal::exit("s", "005930".to_string(), Side::Sell)];
+
+        // 라이브 체결은 섀도 전략에 전달하지 않음
+        assert!(ledger
+            .record_bar(&bar(dec!(100)), &buy, &[])
+            .await
+            .is_empty());
+
+        let fills = ledger.record_bar(&bar(dec!(100)), &[], &buy).await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].order.status, OrderStatusType::Filled);
+        assert_eq!(fills[0].order.side, Side::Buy);
+        assert_eq!(fills[0].order.average_fill_price, Some(dec!(100)));
+        assert_eq!(fills[0].position.side, Side::Buy);
+        assert_eq!(fills[0].position.quantity, fills[0].order.filled_quantity);
+
+        let mut ctx = StrategyContext::default();
+        ledger.sync_context(&mut ctx);
+        assert_eq!(ctx.positions.len(), 1);
+        assert_eq!(
+            ctx.positions["005930"].quantity,
+            fills[0].order.filled_quantity
+        );
+        assert_eq!(ctx.account.available_balance, dec!(800_000));
+
+        let fills = ledger.record_bar(&bar(dec!(110)), &[], &exit).await;
+        assert_eq!(fills[0].position.quantity, Decimal::ZERO);
+        assert!(fills[0].position.realized_pnl > Decimal::ZERO);
+        ledger.sync_context(&mut ctx);
+        assert!(ctx.positions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_signal_without_price_is_not_filled() {
+        let mut ledger = ledger();
+        let other = Signal::entry("s", "000660".to_string(), Side::Buy);
+
+        ledger.record_bar(&bar(dec!(100)), &[], &[other]).await;
+
+        let comparison = ledger.comparison();
+        assert_eq!(comparison.shadow.trades, 0);
+        assert_eq!(
+            comparison.shadow_signals[0].rejected.as_deref(),
+            Some("가격 정보 없음")
+        );
+    }
+}
@@ -45,7 +45,7 @@ use tracing::{debug, info};
 use trader_core::{
     domain::{RouteState, StrategyContext},
     types::Timeframe,
-    MarketData, MarketDataType, Order, Position, Side, Signal, SignalType,
+    Country, MarketData, MarketDataType, Order, Position, Side, Signal, SignalType,
 };
 use trader_strategy_macro::StrategyConfig;
 
@@ -53,7 +53,10 @@ use super::common::{
     ExitConfig, MomentumCalculator, MomentumConfig, MomentumResult, PortfolioPosition,
     RebalanceCalculator, RebalanceConfig, TargetAllocation,
 };
-use crate::traits::Strategy;
+use crate::{
+    schedule::{ScheduleEvent, ScheduleTrigger, StrategySchedule},
+    traits::Strategy,
+};
 
 // ================================================================================================
 // 전략 변형 열거형
@@ -875,6 +878,8 @@ pub struct AssetAllocationStrategy {
     context: Option<Arc<RwLock<StrategyContext>>>,
     positions: HashMap<String, Decimal>,
     last_rebalance_ym: Option<String>,
+    /// 월초 일정 이벤트 이후 리밸런싱 대기 여부
+    rebalance_due: bool,
     rebalance_calculator: RebalanceCalculator,
     momentum_calculator: MomentumCalculator,
     current_mode: PortfolioMode,
@@ -889,6 +894,7 @@ impl AssetAllocationStrategy {
             context: None,
             positions: HashMap::new(),
             last_rebalance_ym: None,
+            rebalance_due: false,
             rebalance_calculator: RebalanceCalculator::new(RebalanceConfig::us_market()),
             momentum_calculator: MomentumCalculator::standard(),
             current_mode: PortfolioMode::Defensive,
@@ -1048,14 +1054,9 @@ impl AssetAllocationStrategy {
         true
     }
 
-    /// 리밸런싱 필요 여부 확인.
-    fn should_rebalance(&self, current_time: DateTime<Utc>) -> bool {
-        let current_ym = format!("{}_{}", current_time.year(), current_time.month());
-
-        match &self.last_rebalance_ym {
-            None => true,
-            Some(last_ym) => last_ym != &current_ym,
-        }
+    /// 리밸런싱 필요 여부 확인 (첫 실행 또는 월초 이벤트 이후).
+    fn should_rebalance(&self) -> bool {
+        self.rebalance_due || self.last_rebalance_ym.is_none()
     }
 
     /// 목표 비중 계산.
@@ -1136,7 +1137,7 @@ impl AssetAllocationStrategy {
         config: &AssetAllocationConfig,
         current_time: DateTime<Utc>,
     ) -> Vec<Signal> {
-        if !self.should_rebalance(current_time) {
+        if !self.should_rebalance() {
             return Vec::new();
         }
 
@@ -1217,6 +1218,7 @@ impl AssetAllocationStrategy {
 
         // 리밸런싱 시간 기록
         self.last_rebalance_ym = Some(format!("{}_{}", current_time.year(), current_time.month()));
+        self.rebalance_due = false;
         info!(
             "[AssetAllocation] 리밸런싱 완료: {} 신호 생성",
             signals.len()
@@ -1368,6 +1370,28 @@ impl Strategy for AssetAllocationStrategy {
         Ok(signals)
     }
 
+    fn schedule(&self) -> Option<StrategySchedule> {
+        let config = self.config.as_ref()?;
+        // 국내 ETF(숫자 코드) 현금 자산이면 국내 캘린더 사용
+        let country = if config.cash_ticker.chars().all(|c| c.is_ascii_digit()) {
+            Country::KR
+        } else {
+            Country::US
+        };
+        Some(StrategySchedule::new(country).with_trigger(ScheduleTrigger::MonthStart))
+    }
+
+    async fn on_schedule(
+        &mut self,
+        event: &ScheduleEvent,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        if event.trigger == ScheduleTrigger::MonthStart {
+            debug!(trading_day = %event.trading_day, "[AssetAllocation] 월초 리밸런싱 예약");
+            self.rebalance_due = true;
+        }
+        Ok(vec![])
+    }
+
     async fn on_order_filled(
         &mut self,
         order: &Order,
@@ -1404,6 +1428,7 @@ impl Strategy for AssetAllocationStrategy {
             "variant": self.config.as_ref().map(|c| format!("{:?}", c.variant)),
             "current_mode": format!("{:?}", self.current_mode),
             "last_rebalance_ym": self.last_rebalance_ym,
+            "rebalance_due": self.rebalance_due,
             "positions": self.positions.iter()
                 .map(|(k, v)| (k.clone(), v.to_string()))
                 .collect::<HashMap<_, _>>(),
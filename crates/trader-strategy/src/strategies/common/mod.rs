@@ -13,6 +13,10 @@
 //! - **position_sync**: 거래소 중립 포지션 상태 동기화
 //! - **global_score_utils**: GlobalScore 기반 종목 선택 및 포지션 가중치 계산
 //! - **screening_integration**: 스크리닝 결과 및 RouteState 전략 연동
+//!
+//! `signal_filters`, `risk_checks`, `screening_integration`의 검사는 `_traced` 변형
+//! (`SignalFilter::filter_traced`, `RiskManager::validate_entry_traced`,
+//! `trace_route_state`/`trace_global_score`)으로 호출할 때만 결정 추적에 기록됩니다.
 
 pub mod defaults;
 pub mod exit_config;
@@ -58,7 +62,7 @@ pub use rebalance::{
 pub use risk_checks::{DefaultRiskChecker, RiskCheckError, RiskChecker, RiskManager, RiskParams};
 pub use screening_integration::{
     get_tickers_by_global_score, get_tickers_by_route_state, get_tickers_by_state_and_score,
-    get_top_tickers_per_sector, ScreeningAware,
+    get_top_tickers_per_sector, trace_global_score, trace_route_state, ScreeningAware,
 };
 pub use serde_helpers::{deserialize_ticker, deserialize_ticker_opt, deserialize_tickers};
 pub use signal_filters::{
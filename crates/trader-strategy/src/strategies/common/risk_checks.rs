@@ -6,6 +6,7 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use trader_core::{DecisionTrace, TraceStepKind};
 
 /// 리스크 검증 에러.
 #[derive(Debug, Error)]
@@ -172,6 +173,52 @@ impl RiskManager {
         Ok(())
     }
 
+    /// 포지션 진입 전 종합 검증 (결정 추적 기록).
+    ///
+    /// 첫 실패에서 멈추지 않고 모든 검증의 값과 한도(`RiskParams` 기준)를
+    /// 기록한 뒤, 첫 번째 실패를 반환합니다.
+    pub fn validate_entry_traced(
+        &self,
+        position_value: Decimal,
+        total_capital: Decimal,
+        current_daily_loss: Decimal,
+        leverage: Decimal,
+        trace: &mut DecisionTrace,
+    ) -> Result<(), RiskCheckError> {
+        let checks = [
+            (
+                "max_position",
+                self.checker
+                    .check_position_size(position_value, total_capital, &self.params),
+                position_value,
+                total_capital * self.params.max_position_ratio,
+            ),
+            (
+                "daily_loss",
+                self.checker
+                    .check_daily_loss(current_daily_loss, total_capital, &self.params),
+                current_daily_loss,
+                total_capital * self.params.max_daily_loss_ratio,
+            ),
+            (
+                "leverage",
+                self.checker.check_leverage(leverage, &self.params),
+                leverage,
+                self.params.max_leverage,
+            ),
+        ];
+
+        let mut first_error = None;
+        for (name, result, value, limit) in checks {
+            trace.check(TraceStepKind::Risk, name, result.is_ok(), value, limit);
+            if let Err(e) = result {
+                first_error.get_or_insert(e);
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+
     /// 손절가 계산.
     pub fn calculate_stop_loss(&self, entry_price: Decimal, is_long: bool) -> Decimal {
         if is_long {
@@ -250,6 +297,28 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validate_entry_traced_records_all_checks() {
+        let manager = RiskManager::with_default_params();
+        let mut trace = DecisionTrace::new("test", "005930", chrono::Utc::now());
+
+        let result = manager.validate_entry_traced(
+            dec!(2000),  // 한도 1000 초과
+            dec!(10000), // 총 자본
+            dec!(100),   // 현재 일일 손실
+            dec!(1),     // 레버리지
+            &mut trace,
+        );
+
+        assert!(matches!(
+            result,
+            Err(RiskCheckError::ExceededMaxPosition { .. })
+        ));
+        assert_eq!(trace.steps.len(), 3);
+        assert_eq!(trace.first_failure().unwrap().name, "max_position");
+        assert_eq!(trace.steps[1].passed, Some(true));
+    }
+
     #[test]
     fn test_calculate_stop_loss() {
         let manager = RiskManager::with_default_params();
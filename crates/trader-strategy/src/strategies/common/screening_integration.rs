@@ -6,7 +6,9 @@
 //! StringResolver를 통해 조회합니다.
 
 use rust_decimal::{prelude::ToPrimitive, Decimal};
-use trader_core::domain::{RouteState, ScreeningResult, StrategyContext};
+use trader_core::domain::{
+    DecisionTrace, RouteState, ScreeningResult, StrategyContext, TraceStep, TraceStepKind,
+};
 
 /// 스크리닝 결과 활용 trait.
 ///
@@ -246,6 +248,61 @@ pub fn get_tickers_by_state_and_score<'a>(
         .unwrap_or_default()
 }
 
+/// 종목의 RouteState가 차단 상태가 아닌지 검사하고 결정 추적에 기록.
+///
+/// RouteState 정보가 없으면 통과로 기록합니다.
+///
+/// # 예시
+///
+/// ```rust,ignore
+/// // 과열 종목 진입 제한
+/// if !trace_route_state(&ctx, "005930", &[RouteState::Overheat], &mut trace) {
+///     return vec![];
+/// }
+/// ```
+pub fn trace_route_state(
+    context: &StrategyContext,
+    ticker: &str,
+    blocked: &[RouteState],
+    trace: &mut DecisionTrace,
+) -> bool {
+    let state = context.get_route_state(ticker).copied();
+    let passed = state.map_or(true, |s| !blocked.contains(&s));
+
+    let mut step = TraceStep::new(TraceStepKind::Screening, "route_state")
+        .with_passed(passed)
+        .with_value(state)
+        .with_threshold(blocked);
+    if state.is_none() {
+        step = step.with_detail("RouteState 없음");
+    }
+    trace.push(step);
+    passed
+}
+
+/// 종목의 GlobalScore가 최소 점수 이상인지 검사하고 결정 추적에 기록.
+///
+/// 점수가 없으면 실패로 기록합니다.
+pub fn trace_global_score(
+    context: &StrategyContext,
+    ticker: &str,
+    min_score: Decimal,
+    trace: &mut DecisionTrace,
+) -> bool {
+    let score = context.get_global_score(ticker).map(|s| s.overall_score);
+    let passed = score.is_some_and(|s| s >= min_score);
+
+    let mut step = TraceStep::new(TraceStepKind::Screening, "global_score")
+        .with_passed(passed)
+        .with_value(score)
+        .with_threshold(min_score);
+    if score.is_none() {
+        step = step.with_detail("GlobalScore 없음");
+    }
+    trace.push(step);
+    passed
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -353,4 +410,36 @@ mod tests {
         assert_eq!(filtered.len(), 1); // 005930만 해당
         assert_eq!(filtered[0].0, "005930"); // ticker 문자열 비교
     }
+
+    #[test]
+    fn test_trace_route_state_and_score() {
+        let mut context = StrategyContext::default();
+        context
+            .route_states
+            .insert("005930".to_string(), RouteState::Overheat);
+
+        let mut trace = DecisionTrace::new("test", "005930", Utc::now());
+        assert!(!trace_route_state(
+            &context,
+            "005930",
+            &[RouteState::Overheat],
+            &mut trace
+        ));
+        assert!(trace_route_state(
+            &context,
+            "000660",
+            &[RouteState::Overheat],
+            &mut trace
+        ));
+        assert!(!trace_global_score(
+            &context,
+            "005930",
+            dec!(60),
+            &mut trace
+        ));
+
+        assert_eq!(trace.steps.len(), 3);
+        assert_eq!(trace.first_failure().unwrap().name, "route_state");
+        assert_eq!(trace.steps[2].detail.as_deref(), Some("GlobalScore 없음"));
+    }
 }
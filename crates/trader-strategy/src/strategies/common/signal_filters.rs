@@ -11,12 +11,17 @@
 //! 2. **충돌 검증** (validate_signals_with_context)
 //!    - 미체결 주문, 중복 포지션 등 실행 가능성 확인
 //!    - Signal 생성 후, 반환 전에 적용
+//!
+//! 기술적 필터는 `filter_traced()`로 호출하면 통과 여부와 기준값이
+//! [`DecisionTrace`]에 기록됩니다.
 
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
-use trader_core::domain::{Signal, SignalConflictError, StrategyContext};
+use trader_core::domain::{
+    DecisionTrace, Signal, SignalConflictError, StrategyContext, TraceStep, TraceStepKind,
+};
 
 /// 신호 강도.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -51,6 +56,35 @@ pub trait SignalFilter: Send + Sync + std::fmt::Debug {
     /// # Returns
     /// 필터링된 신호
     fn filter(&self, signal: bool, context: &SignalContext) -> FilteredSignal;
+
+    /// 결정 추적에 기록할 필터 이름.
+    fn name(&self) -> &str {
+        "signal_filter"
+    }
+
+    /// 필터링 후 결과를 결정 추적에 기록합니다.
+    ///
+    /// 기본 구현은 통과 여부와 거부 사유만 기록합니다. 기준값이 있는 필터는
+    /// 관측 값과 기준값을 함께 기록하도록 재정의합니다.
+    fn filter_traced(
+        &self,
+        signal: bool,
+        context: &SignalContext,
+        trace: &mut DecisionTrace,
+    ) -> FilteredSignal {
+        let result = self.filter(signal, context);
+        trace.push(filter_step(self.name(), &result));
+        result
+    }
+}
+
+/// 필터 결과를 추적 단계로 변환.
+fn filter_step(name: &str, result: &FilteredSignal) -> TraceStep {
+    let step = TraceStep::new(TraceStepKind::Filter, name).with_passed(result.is_valid);
+    match &result.reason {
+        Some(reason) => step.with_detail(reason.clone()),
+        None => step,
+    }
 }
 
 /// 신호 필터링 컨텍스트.
@@ -72,6 +106,24 @@ pub struct SignalContext {
     pub trend: i8,
 }
 
+impl SignalContext {
+    /// 필터링에 사용한 지표 값을 결정 추적에 기록합니다.
+    pub fn record_indicators(&self, trace: &mut DecisionTrace) {
+        trace.indicator("price", self.current_price);
+        trace.indicator("volume", self.volume);
+        if let Some(avg_volume) = self.avg_volume {
+            trace.indicator("avg_volume", avg_volume);
+        }
+        if let Some(rsi) = self.rsi {
+            trace.indicator("rsi", rsi);
+        }
+        if let Some(macd_histogram) = self.macd_histogram {
+            trace.indicator("macd_histogram", macd_histogram);
+        }
+        trace.indicator("trend", self.trend);
+    }
+}
+
 /// 거래량 필터.
 ///
 /// 거래량이 평균보다 낮으면 신호를 거부합니다.
@@ -134,6 +186,25 @@ impl SignalFilter for VolumeFilter {
             }
         }
     }
+
+    fn name(&self) -> &str {
+        "volume_filter"
+    }
+
+    fn filter_traced(
+        &self,
+        signal: bool,
+        context: &SignalContext,
+        trace: &mut DecisionTrace,
+    ) -> FilteredSignal {
+        let result = self.filter(signal, context);
+        let mut step = filter_step(self.name(), &result).with_threshold(self.min_volume_ratio);
+        if let Some(avg_volume) = context.avg_volume.filter(|avg| !avg.is_zero()) {
+            step = step.with_value(context.volume / avg_volume);
+        }
+        trace.push(step);
+        result
+    }
 }
 
 /// 추세 필터.
@@ -186,6 +257,26 @@ impl SignalFilter for TrendFilter {
             reason: None,
         }
     }
+
+    fn name(&self) -> &str {
+        "trend_filter"
+    }
+
+    fn filter_traced(
+        &self,
+        signal: bool,
+        context: &SignalContext,
+        trace: &mut DecisionTrace,
+    ) -> FilteredSignal {
+        let result = self.filter(signal, context);
+        let mut step = filter_step(self.name(), &result);
+        if self.enabled {
+            // 상승 추세(> 0)일 때만 통과
+            step = step.with_value(context.trend).with_threshold(0);
+        }
+        trace.push(step);
+        result
+    }
 }
 
 /// 복합 필터.
@@ -237,6 +328,26 @@ impl SignalFilter for CompositeFilter {
             reason: None,
         }
     }
+
+    fn name(&self) -> &str {
+        "composite_filter"
+    }
+
+    /// 하위 필터를 순서대로 기록하고, 첫 거부에서 멈춥니다.
+    fn filter_traced(
+        &self,
+        signal: bool,
+        context: &SignalContext,
+        trace: &mut DecisionTrace,
+    ) -> FilteredSignal {
+        for filter in &self.filters {
+            let result = filter.filter_traced(signal, context, trace);
+            if !result.is_valid {
+                return result;
+            }
+        }
+        self.filter(signal, context)
+    }
 }
 
 impl Default for CompositeFilter {
@@ -369,6 +480,34 @@ mod tests {
         assert!(result.is_valid);
     }
 
+    #[test]
+    fn test_composite_filter_traced_stops_at_rejection() {
+        let filter = CompositeFilter::new()
+            .add_filter(Box::new(VolumeFilter::new(dec!(2.0))))
+            .add_filter(Box::new(TrendFilter::new(true)));
+
+        let context = SignalContext {
+            current_price: dec!(100),
+            volume: dec!(1500),
+            avg_volume: Some(dec!(1000)),
+            rsi: Some(dec!(28)),
+            macd_histogram: None,
+            trend: 1,
+        };
+
+        let mut trace = DecisionTrace::new("test", "005930", chrono::Utc::now());
+        context.record_indicators(&mut trace);
+        let result = filter.filter_traced(true, &context, &mut trace);
+
+        assert!(!result.is_valid);
+        let failure = trace.first_failure().unwrap();
+        assert_eq!(failure.name, "volume_filter");
+        assert_eq!(failure.value, Some(serde_json::json!(1.5)));
+        assert_eq!(failure.threshold, Some(serde_json::json!(2.0)));
+        // 거부 이후 필터는 기록하지 않음
+        assert!(trace.steps.iter().all(|s| s.name != "trend_filter"));
+    }
+
     #[test]
     fn test_confirmation_pattern() {
         let mut pattern = ConfirmationPattern::new(3);
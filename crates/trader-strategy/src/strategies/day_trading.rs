@@ -719,7 +719,7 @@ impl Default for DayTradingConfig {
 }
 
 /// 캔들 데이터.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct CandleData {
     open: Decimal,
     high: Decimal,
@@ -730,7 +730,7 @@ struct CandleData {
 }
 
 /// 포지션 상태.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct PositionState {
     side: Side,
     entry_price: Decimal,
@@ -739,6 +739,28 @@ struct PositionState {
     lowest_price: Decimal,
 }
 
+/// 체크포인트용 일간 트레이딩 상태 (포지션, 당일 진입 여부, 지표 윈도우, 통계).
+#[derive(Debug, Serialize, Deserialize)]
+struct DayTradingCheckpoint {
+    candles: VecDeque<CandleData>,
+    current_candle: Option<CandleData>,
+    position: Option<PositionState>,
+    prices: VecDeque<Decimal>,
+    volumes: VecDeque<Decimal>,
+    tr_history: VecDeque<Decimal>,
+    current_atr: Option<Decimal>,
+    prev_range: Option<Decimal>,
+    prev_short_sma: Option<Decimal>,
+    prev_long_sma: Option<Decimal>,
+    upper_breakout: Option<Decimal>,
+    lower_breakout: Option<Decimal>,
+    triggered_this_period: bool,
+    trades_count: u32,
+    wins: u32,
+    losses_count: u32,
+    total_pnl: Decimal,
+}
+
 /// 일간 트레이딩 통합 전략.
 pub struct DayTradingStrategy {
     config: Option<DayTradingConfig>,
@@ -1769,6 +1791,53 @@ impl Strategy for DayTradingStrategy {
         })
     }
 
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let checkpoint = DayTradingCheckpoint {
+            candles: self.candles.clone(),
+            current_candle: self.current_candle.clone(),
+            position: self.position.clone(),
+            prices: self.prices.clone(),
+            volumes: self.volumes.clone(),
+            tr_history: self.tr_history.clone(),
+            current_atr: self.current_atr,
+            prev_range: self.prev_range,
+            prev_short_sma: self.prev_short_sma,
+            prev_long_sma: self.prev_long_sma,
+            upper_breakout: self.upper_breakout,
+            lower_breakout: self.lower_breakout,
+            triggered_this_period: self.triggered_this_period,
+            trades_count: self.trades_count,
+            wins: self.wins,
+            losses_count: self.losses_count,
+            total_pnl: self.total_pnl,
+        };
+        Ok(serde_json::to_vec(&checkpoint)?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let checkpoint: DayTradingCheckpoint = serde_json::from_slice(data)?;
+
+        self.candles = checkpoint.candles;
+        self.current_candle = checkpoint.current_candle;
+        self.position = checkpoint.position;
+        self.prices = checkpoint.prices;
+        self.volumes = checkpoint.volumes;
+        self.tr_history = checkpoint.tr_history;
+        self.current_atr = checkpoint.current_atr;
+        self.prev_range = checkpoint.prev_range;
+        self.prev_short_sma = checkpoint.prev_short_sma;
+        self.prev_long_sma = checkpoint.prev_long_sma;
+        self.upper_breakout = checkpoint.upper_breakout;
+        self.lower_breakout = checkpoint.lower_breakout;
+        self.triggered_this_period = checkpoint.triggered_this_period;
+        self.trades_count = checkpoint.trades_count;
+        self.wins = checkpoint.wins;
+        self.losses_count = checkpoint.losses_count;
+        self.total_pnl = checkpoint.total_pnl;
+
+        Ok(())
+    }
+
     fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
         self.context = Some(context);
         info!("StrategyContext injected into DayTrading strategy");
@@ -540,7 +540,7 @@ impl From<InfinityBotConfig> for DcaConfig {
 // ================================================================================================
 
 /// 그리드 레벨 상태
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 enum GridLevelState {
     /// 매수 대기
     WaitingBuy,
@@ -549,7 +549,7 @@ enum GridLevelState {
 }
 
 /// 그리드 레벨
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct GridLevel {
     buy_price: Decimal,
     sell_price: Decimal,
@@ -557,7 +557,7 @@ struct GridLevel {
 }
 
 /// 분할 레벨 상태
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct SplitLevelState {
     is_bought: bool,
     entry_price: Decimal,
@@ -573,7 +573,7 @@ enum SplitAction {
 }
 
 /// 인피니티봇 라운드 정보
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct RoundInfo {
     round: usize,
     entry_price: Decimal,
@@ -584,7 +584,7 @@ struct RoundInfo {
 }
 
 /// 인피니티봇 상태
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct InfinityBotState {
     current_round: usize,
     rounds: Vec<RoundInfo>,
@@ -610,6 +610,21 @@ impl InfinityBotState {
     }
 }
 
+/// 체크포인트용 DCA 상태 (그리드 레벨, 분할 매수 단계, 인피니티봇 라운드).
+#[derive(Debug, Serialize, Deserialize)]
+struct DcaCheckpoint {
+    grid_levels: Vec<GridLevel>,
+    grid_base_price: Decimal,
+    grid_group_id: Option<String>,
+    candles_processed: usize,
+    split_states: Vec<SplitLevelState>,
+    split_entry_date: Option<String>,
+    split_group_id: Option<String>,
+    infinity_state: InfinityBotState,
+    infinity_group_id: Option<String>,
+    last_entry_price: Option<Decimal>,
+}
+
 // ================================================================================================
 // 전략 구현
 // ================================================================================================
@@ -1680,6 +1695,53 @@ impl Strategy for DcaStrategy {
         state
     }
 
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let checkpoint = DcaCheckpoint {
+            grid_levels: self.grid_levels.clone(),
+            grid_base_price: self.grid_base_price,
+            grid_group_id: self.grid_group_id.clone(),
+            candles_processed: self.candles_processed,
+            split_states: self.split_states.clone(),
+            split_entry_date: self.split_entry_date.clone(),
+            split_group_id: self.split_group_id.clone(),
+            infinity_state: self.infinity_state.clone(),
+            infinity_group_id: self.infinity_group_id.clone(),
+            last_entry_price: self.last_entry_price,
+        };
+        Ok(serde_json::to_vec(&checkpoint)?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let checkpoint: DcaCheckpoint = serde_json::from_slice(data)?;
+
+        // 분할 단계 수가 현재 설정과 다르면 잘못된 단계에 매핑되므로 거부
+        if let Some(config) = &self.config {
+            if config.variant == DcaVariant::MagicSplit
+                && checkpoint.split_states.len() != config.split_levels.len()
+            {
+                return Err(format!(
+                    "분할 단계 수 불일치: 스냅샷 {}개, 설정 {}개",
+                    checkpoint.split_states.len(),
+                    config.split_levels.len()
+                )
+                .into());
+            }
+        }
+
+        self.grid_levels = checkpoint.grid_levels;
+        self.grid_base_price = checkpoint.grid_base_price;
+        self.grid_group_id = checkpoint.grid_group_id;
+        self.candles_processed = checkpoint.candles_processed;
+        self.split_states = checkpoint.split_states;
+        self.split_entry_date = checkpoint.split_entry_date;
+        self.split_group_id = checkpoint.split_group_id;
+        self.infinity_state = checkpoint.infinity_state;
+        self.infinity_group_id = checkpoint.infinity_group_id;
+        self.last_entry_price = checkpoint.last_entry_price;
+
+        Ok(())
+    }
+
     fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
         self.context = Some(context);
         info!("[DCA] StrategyContext 주입 완료");
@@ -1807,4 +1869,43 @@ mod tests {
         let ret = state.current_return(dec!(1100));
         assert_eq!(ret, Some(dec!(10)));
     }
+
+    #[tokio::test]
+    async fn test_grid_state_survives_restart() {
+        let mut config = serde_json::to_value(GridTradingConfig {
+            ticker: "BTCUSDT".to_string(),
+            amount: dec!(100000),
+            spacing_pct: dec!(1),
+            levels: 5,
+            use_atr: false,
+            atr_period: 14,
+            exit_config: ExitConfig::for_grid_trading(),
+            max_positions: 10,
+            reset_threshold_pct: dec!(10),
+            warmup_candles: 5,
+        })
+        .unwrap();
+        config["variant"] = json!("grid");
+
+        let mut strategy = DcaStrategy::grid();
+        strategy.initialize(config.clone()).await.unwrap();
+        strategy.initialize_grid(dec!(100));
+        strategy.grid_levels[0].state = GridLevelState::WaitingSell;
+        strategy.candles_processed = 7;
+        let saved = strategy.save_state().unwrap();
+
+        // 재시작: initialize()가 그리드를 비운 뒤 스냅샷으로 복원
+        let mut restarted = DcaStrategy::grid();
+        restarted.initialize(config).await.unwrap();
+        assert!(restarted.grid_levels.is_empty());
+        restarted.load_state(&saved).unwrap();
+
+        assert_eq!(restarted.grid_base_price, dec!(100));
+        assert_eq!(restarted.grid_levels.len(), 5);
+        assert_eq!(restarted.grid_levels[0].state, GridLevelState::WaitingSell);
+        assert_eq!(restarted.grid_levels[1].state, GridLevelState::WaitingBuy);
+        assert_eq!(restarted.grid_group_id, strategy.grid_group_id);
+        assert_eq!(restarted.candles_processed, 7);
+        assert_eq!(restarted.save_state().unwrap(), saved);
+    }
 }
@@ -0,0 +1,1168 @@
+//! 앙상블(메타) 전략.
+//!
+//! 레지스트리에 등록된 여러 전략을 하위 전략으로 실행하고, 각 전략의 신호를
+//! 하나의 결정으로 결합합니다. 같은 종목에 대해 하위 전략끼리 반대 주문을 내는
+//! 대신 투표 결과 하나만 내보냅니다.
+//!
+//! # 결합 방식
+//!
+//! - `weighted_vote`: 방향별 가중치 합의 비율이 `threshold` 이상이면 채택
+//! - `unanimous`: 모든 하위 전략이 같은 방향일 때만 채택
+//! - `strength_average`: 방향(+1/-1) × 강도의 가중 평균 절댓값이 `threshold` 이상이면 채택
+//!
+//! # 적응형 가중치
+//!
+//! `adaptive.enabled`이면 `StrategyContext`의 전략별 신호 성과(`signal_performance`)
+//! 승률로 기본 가중치를 보정합니다 (승률 50% = 1배). 앙상블이 내보낸 신호는
+//! `metadata.ensemble.source_strategy_id`에 대표 신호를 낸 하위 전략 ID를 남기며,
+//! 신호 성과 동기화는 이 ID로 성과를 집계하므로 하위 전략의 승률이 계속 갱신됩니다.
+//!
+//! # 일정 이벤트
+//!
+//! 하위 전략이 선언한 일정(`schedule()`)을 합쳐 엔진에 선언하고, 일정 이벤트는
+//! 해당 트리거를 선언한 하위 전략에만 전달합니다. 월초 리밸런싱처럼 일정 이벤트에서
+//! 나온 신호는 그 트리거를 선언한 하위 전략끼리만 투표합니다. 기준 시장(캘린더)은
+//! 첫 번째로 일정을 선언한 하위 전략을 따르며, 다른 시장의 일정은 무시합니다.
+//!
+//! # 충돌 해소
+//!
+//! 컨텍스트가 주입되어 있으면 채택된 방향의 신호 중 `can_execute_signal`을 통과하는
+//! 신호를 대표 신호로 고릅니다. 실행 가능한 신호가 없으면 엔진에 전달하지 않습니다.
+//!
+//! # 설정 예시
+//!
+//! ```json
+//! {
+//!   "members": [
+//!     { "strategy": "rsi_multi_tf", "weight": 1.0, "config": { "ticker": "005930" } },
+//!     { "strategy": "candle_pattern", "weight": 0.5, "config": { "ticker": "005930" } },
+//!     { "strategy": "momentum_power", "config": {} }
+//!   ],
+//!   "method": "weighted_vote",
+//!   "threshold": 0.5,
+//!   "adaptive": { "enabled": true, "min_signals": 20 }
+//! }
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+use trader_core::{domain::StrategyContext, MarketData, Order, Position, Side, Signal, SignalType};
+
+use crate::{ScheduleEvent, Strategy, StrategyRegistry, StrategySchedule};
+
+/// 앙상블 전략 ID (중첩 방지용).
+const ENSEMBLE_STRATEGY_ID: &str = "ensemble";
+
+// ================================================================================================
+// 설정
+// ================================================================================================
+
+/// 앙상블 설정 오류.
+#[derive(Debug, Error)]
+pub enum EnsembleError {
+    /// 하위 전략이 없음
+    #[error("하위 전략이 하나 이상 필요합니다")]
+    NoMembers,
+
+    /// 앙상블 안에 앙상블을 넣을 수 없음
+    #[error("앙상블 전략은 하위 전략으로 사용할 수 없습니다")]
+    NestedEnsemble,
+
+    /// 레지스트리에 없는 전략
+    #[error("알 수 없는 전략: {0}")]
+    UnknownStrategy(String),
+
+    /// 하위 전략 이름 중복
+    #[error("하위 전략 이름 중복: {0}")]
+    DuplicateMember(String),
+
+    /// 가중치가 양수가 아님
+    #[error("하위 전략 {0}의 가중치는 0보다 커야 합니다")]
+    InvalidWeight(String),
+
+    /// 하위 전략 초기화 실패
+    #[error("하위 전략 {member} 초기화 실패: {message}")]
+    MemberInit { member: String, message: String },
+}
+
+/// 신호 결합 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CombineMethod {
+    /// 가중 투표
+    #[default]
+    WeightedVote,
+    /// 만장일치
+    Unanimous,
+    /// 강도 가중 평균
+    StrengthAverage,
+}
+
+/// 하위 전략 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnsembleMember {
+    /// 레지스트리 전략 ID (별칭 가능)
+    pub strategy: String,
+
+    /// 하위 전략 이름 (기본값: `strategy`, 같은 전략을 여러 번 쓸 때 구분용)
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// 하위 전략 설정 (하위 전략의 `initialize`에 그대로 전달)
+    #[serde(default = "default_member_config")]
+    pub config: Value,
+
+    /// 기본 가중치
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+
+    /// 신호 성과 조회 키 (기본값: `strategy`)
+    #[serde(default)]
+    pub performance_key: Option<String>,
+}
+
+impl EnsembleMember {
+    fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.strategy)
+    }
+}
+
+fn default_member_config() -> Value {
+    json!({})
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+/// 적응형 가중치 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdaptiveWeightConfig {
+    /// 활성화 여부
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 보정에 필요한 최소 신호 수 (미만이면 기본 가중치 사용)
+    #[serde(default = "default_min_signals")]
+    pub min_signals: u64,
+
+    /// 최소 배수
+    #[serde(default = "default_min_multiplier")]
+    pub min_multiplier: f64,
+
+    /// 최대 배수
+    #[serde(default = "default_max_multiplier")]
+    pub max_multiplier: f64,
+}
+
+impl Default for AdaptiveWeightConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_signals: default_min_signals(),
+            min_multiplier: default_min_multiplier(),
+            max_multiplier: default_max_multiplier(),
+        }
+    }
+}
+
+fn default_min_signals() -> u64 {
+    20
+}
+
+fn default_min_multiplier() -> f64 {
+    0.25
+}
+
+fn default_max_multiplier() -> f64 {
+    2.0
+}
+
+/// 앙상블 전략 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnsembleConfig {
+    /// 하위 전략 목록
+    pub members: Vec<EnsembleMember>,
+
+    /// 결합 방식
+    #[serde(default)]
+    pub method: CombineMethod,
+
+    /// 채택 임계값 (0.0 ~ 1.0, `unanimous`에서는 무시)
+    #[serde(default = "default_threshold")]
+    pub threshold: f64,
+
+    /// 적응형 가중치
+    #[serde(default)]
+    pub adaptive: AdaptiveWeightConfig,
+}
+
+fn default_threshold() -> f64 {
+    0.5
+}
+
+impl EnsembleConfig {
+    /// 하위 전략 구성 검증.
+    pub fn validate(&self) -> Result<(), EnsembleError> {
+        if self.members.is_empty() {
+            return Err(EnsembleError::NoMembers);
+        }
+        let mut names = Vec::with_capacity(self.members.len());
+        for member in &self.members {
+            let meta = StrategyRegistry::find(&member.strategy)
+                .ok_or_else(|| EnsembleError::UnknownStrategy(member.strategy.clone()))?;
+            if meta.id == ENSEMBLE_STRATEGY_ID {
+                return Err(EnsembleError::NestedEnsemble);
+            }
+            if !(member.weight.is_finite() && member.weight > 0.0) {
+                return Err(EnsembleError::InvalidWeight(member.display_name().into()));
+            }
+            if names.contains(&member.display_name()) {
+                return Err(EnsembleError::DuplicateMember(member.display_name().into()));
+            }
+            names.push(member.display_name());
+        }
+        Ok(())
+    }
+}
+
+// ================================================================================================
+// 신호 결합
+// ================================================================================================
+
+/// 하위 전략 한 곳의 종목별 투표.
+#[derive(Debug, Clone)]
+struct Vote {
+    member: usize,
+    signal: Signal,
+    weight: f64,
+}
+
+impl Vote {
+    fn direction(&self) -> f64 {
+        match self.signal.side {
+            Side::Buy => 1.0,
+            Side::Sell => -1.0,
+        }
+    }
+
+    fn score(&self) -> f64 {
+        self.weight * self.signal.strength
+    }
+}
+
+/// 결합 결과.
+#[derive(Debug, Clone, PartialEq)]
+struct Decision {
+    side: Side,
+    strength: f64,
+}
+
+/// 한 종목의 투표를 결합.
+///
+/// `total_weight`는 투표하지 않은 하위 전략을 포함한 전체 가중치 합입니다.
+fn combine(
+    method: CombineMethod,
+    threshold: f64,
+    votes: &[Vote],
+    member_count: usize,
+    total_weight: f64,
+) -> Option<Decision> {
+    if votes.is_empty() || total_weight <= 0.0 {
+        return None;
+    }
+    let side_weight = |side: Side| -> f64 {
+        votes
+            .iter()
+            .filter(|v| v.signal.side == side)
+            .map(|v| v.weight)
+            .sum()
+    };
+    let side_score = |side: Side| -> f64 {
+        votes
+            .iter()
+            .filter(|v| v.signal.side == side)
+            .map(Vote::score)
+            .sum()
+    };
+
+    match method {
+        CombineMethod::WeightedVote => {
+            let (buy, sell) = (side_weight(Side::Buy), side_weight(Side::Sell));
+            let side = if buy > sell {
+                Side::Buy
+            } else if sell > buy {
+                Side::Sell
+            } else {
+                return None;
+            };
+            let share = side_weight(side) / total_weight;
+            (share >= threshold).then(|| Decision {
+                side,
+                strength: (side_score(side) / total_weight).clamp(0.0, 1.0),
+            })
+        }
+        CombineMethod::Unanimous => {
+            let side = votes[0].signal.side;
+            let agreed = votes.len() == member_count && votes.iter().all(|v| v.signal.side == side);
+            agreed.then(|| Decision {
+                side,
+                strength: (side_score(side) / total_weight).clamp(0.0, 1.0),
+            })
+        }
+        CombineMethod::StrengthAverage => {
+            let net: f64 =
+                votes.iter().map(|v| v.direction() * v.score()).sum::<f64>() / total_weight;
+            if net == 0.0 || net.abs() < threshold {
+                return None;
+            }
+            Some(Decision {
+                side: if net > 0.0 { Side::Buy } else { Side::Sell },
+                strength: net.abs().clamp(0.0, 1.0),
+            })
+        }
+    }
+}
+
+/// 적응형 가중치 배수 (승률 50% = 1배).
+fn performance_multiplier(
+    adaptive: &AdaptiveWeightConfig,
+    total_signals: u64,
+    win_rate: Option<f64>,
+) -> f64 {
+    match win_rate {
+        Some(win_rate) if total_signals >= adaptive.min_signals => (win_rate / 50.0).clamp(
+            adaptive.min_multiplier,
+            adaptive.max_multiplier.max(adaptive.min_multiplier),
+        ),
+        _ => 1.0,
+    }
+}
+
+// ================================================================================================
+// 전략 구현
+// ================================================================================================
+
+/// 실행 중인 하위 전략.
+struct MemberInstance {
+    config: EnsembleMember,
+    strategy: Box<dyn Strategy>,
+    /// 하위 전략 신호에 기록된 전략 ID (성과 조회 폴백용)
+    signal_strategy_id: Option<String>,
+    /// 현재 적용 중인 가중치
+    effective_weight: f64,
+    signals: u64,
+    errors: u64,
+}
+
+/// 하위 전략 호출 결과.
+type MemberOutput = Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>>;
+
+/// 하위 전략 일정 병합.
+///
+/// 첫 번째로 일정을 선언한 하위 전략의 시장을 기준으로, 같은 시장의 트리거를 중복 없이 모읍니다.
+fn merge_schedules(schedules: &[(&str, Option<StrategySchedule>)]) -> Option<StrategySchedule> {
+    let mut merged: Option<StrategySchedule> = None;
+    for (member, schedule) in schedules {
+        let Some(schedule) = schedule else {
+            continue;
+        };
+        let merged = merged.get_or_insert_with(|| StrategySchedule::new(schedule.country));
+        if schedule.country != merged.country {
+            warn!(
+                member = member,
+                country = ?schedule.country,
+                base = ?merged.country,
+                "[Ensemble] 기준 시장과 다른 하위 전략 일정 무시"
+            );
+            continue;
+        }
+        for trigger in &schedule.triggers {
+            if !merged.triggers.contains(trigger) {
+                merged.triggers.push(trigger.clone());
+            }
+        }
+    }
+    merged
+}
+
+/// 앙상블 전략.
+pub struct EnsembleStrategy {
+    config: Option<EnsembleConfig>,
+    members: Vec<MemberInstance>,
+    /// 하위 전략 일정 병합 결과
+    schedule: Option<StrategySchedule>,
+    context: Option<Arc<RwLock<StrategyContext>>>,
+    signals_emitted: u64,
+    votes_rejected: u64,
+    conflicts_resolved: u64,
+}
+
+impl EnsembleStrategy {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            members: Vec::new(),
+            schedule: None,
+            context: None,
+            signals_emitted: 0,
+            votes_rejected: 0,
+            conflicts_resolved: 0,
+        }
+    }
+
+    /// 적응형 가중치 갱신.
+    async fn refresh_weights(&mut self) {
+        let Some(config) = self.config.as_ref() else {
+            return;
+        };
+        let ctx = match (config.adaptive.enabled, self.context.as_ref()) {
+            (true, Some(context)) => Some(context.read().await),
+            _ => None,
+        };
+
+        for member in &mut self.members {
+            let base = member.config.weight;
+            let performance = ctx.as_ref().and_then(|ctx| {
+                let key = member
+                    .config
+                    .performance_key
+                    .as_deref()
+                    .unwrap_or(&member.config.strategy);
+                ctx.get_signal_performance(key).or_else(|| {
+                    member
+                        .signal_strategy_id
+                        .as_deref()
+                        .and_then(|id| ctx.get_signal_performance(id))
+                })
+            });
+            member.effective_weight = match performance {
+                Some(p) => {
+                    base * performance_multiplier(&config.adaptive, p.total_signals, p.win_rate)
+                }
+                None => base,
+            };
+        }
+    }
+
+    /// 하위 전략 신호를 종목별로 투표해 결정 신호 생성.
+    ///
+    /// `outputs`는 이번 호출에 참여한 하위 전략(인덱스)과 그 결과이며,
+    /// 만장일치/가중치 비율은 참여한 하위 전략 기준으로 계산합니다.
+    async fn decide(
+        &mut self,
+        method: CombineMethod,
+        threshold: f64,
+        outputs: Vec<(usize, MemberOutput)>,
+    ) -> Vec<Signal> {
+        let mut output = Vec::new();
+        // ticker → 하위 전략별 투표 (하위 전략당 가장 강한 신호 하나)
+        let mut votes: BTreeMap<String, Vec<Vote>> = BTreeMap::new();
+        let member_count = outputs.len();
+        let total_weight: f64 = outputs
+            .iter()
+            .map(|(idx, _)| self.members[*idx].effective_weight)
+            .sum();
+
+        for (idx, result) in outputs {
+            let member = &mut self.members[idx];
+            let signals = match result {
+                Ok(signals) => signals,
+                Err(e) => {
+                    // 하위 전략 하나의 오류는 기권으로 처리
+                    member.errors += 1;
+                    warn!(member = member.config.display_name(), error = %e, "[Ensemble] 하위 전략 오류");
+                    continue;
+                }
+            };
+
+            for signal in signals {
+                member.signals += 1;
+                member.signal_strategy_id = Some(signal.strategy_id.clone());
+
+                if signal.signal_type == SignalType::Alert {
+                    output.push(
+                        signal
+                            .with_metadata("ensemble_member", json!(member.config.display_name())),
+                    );
+                    continue;
+                }
+
+                let ticker_votes = votes.entry(signal.ticker.clone()).or_default();
+                let vote = Vote {
+                    member: idx,
+                    signal,
+                    weight: member.effective_weight,
+                };
+                match ticker_votes.iter_mut().find(|v| v.member == idx) {
+                    Some(existing) if existing.signal.strength >= vote.signal.strength => {}
+                    Some(existing) => *existing = vote,
+                    None => ticker_votes.push(vote),
+                }
+            }
+        }
+
+        for (ticker, ticker_votes) in votes {
+            let Some(decision) =
+                combine(method, threshold, &ticker_votes, member_count, total_weight)
+            else {
+                self.votes_rejected += 1;
+                debug!(ticker = %ticker, votes = ticker_votes.len(), "[Ensemble] 채택 조건 미충족");
+                continue;
+            };
+            let Some(representative) = self.pick_representative(&ticker_votes, decision.side).await
+            else {
+                continue;
+            };
+
+            let ballot: Vec<Value> = ticker_votes
+                .iter()
+                .map(|v| {
+                    json!({
+                        "member": self.members[v.member].config.display_name(),
+                        "side": v.signal.side,
+                        "signal_type": v.signal.signal_type,
+                        "strength": v.signal.strength,
+                        "weight": v.weight,
+                    })
+                })
+                .collect();
+
+            let mut signal = representative.signal;
+            // 성과 추적은 원래 하위 전략으로 귀속 (source_strategy_id)
+            let source_strategy_id =
+                std::mem::replace(&mut signal.strategy_id, ENSEMBLE_STRATEGY_ID.to_string());
+            signal.strength = decision.strength;
+            signal.metadata.insert(
+                "ensemble".to_string(),
+                json!({
+                    "method": method,
+                    "source": self.members[representative.member].config.display_name(),
+                    "source_strategy_id": source_strategy_id,
+                    "votes": ballot,
+                }),
+            );
+            output.push(signal);
+        }
+
+        self.signals_emitted += output.len() as u64;
+        output
+    }
+
+    /// 채택된 방향의 대표 신호 선택.
+    ///
+    /// 컨텍스트가 있으면 실행 가능한 신호 중 점수가 가장 높은 신호를 고릅니다.
+    async fn pick_representative(&mut self, votes: &[Vote], side: Side) -> Option<Vote> {
+        let mut candidates: Vec<&Vote> = votes.iter().filter(|v| v.signal.side == side).collect();
+        candidates.sort_by(|a, b| b.score().total_cmp(&a.score()));
+
+        let Some(context) = self.context.as_ref() else {
+            return candidates.first().map(|v| (*v).clone());
+        };
+        let ctx = context.read().await;
+        let picked = candidates
+            .iter()
+            .find(|v| ctx.can_execute_signal(&v.signal).is_ok())
+            .map(|v| (*v).clone());
+        if picked.is_none() {
+            if let Some(first) = candidates.first() {
+                if let Err(e) = ctx.can_execute_signal(&first.signal) {
+                    debug!(ticker = %first.signal.ticker, error = %e, "[Ensemble] 실행 불가 신호 제외");
+                }
+            }
+            self.conflicts_resolved += 1;
+        }
+        picked
+    }
+}
+
+impl Default for EnsembleStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Strategy for EnsembleStrategy {
+    fn name(&self) -> &str {
+        "Ensemble"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "여러 전략의 신호를 투표로 결합하는 메타 전략"
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let config: EnsembleConfig = serde_json::from_value(config)?;
+        config.validate()?;
+
+        let mut members = Vec::with_capacity(config.members.len());
+        for member in &config.members {
+            let mut strategy = StrategyRegistry::create_instance(&member.strategy)
+                .map_err(|_| EnsembleError::UnknownStrategy(member.strategy.clone()))?;
+            strategy
+                .initialize(member.config.clone())
+                .await
+                .map_err(|e| EnsembleError::MemberInit {
+                    member: member.display_name().to_string(),
+                    message: e.to_string(),
+                })?;
+            if let Some(context) = self.context.as_ref() {
+                strategy.set_context(Arc::clone(context));
+            }
+            members.push(MemberInstance {
+                effective_weight: member.weight,
+                config: member.clone(),
+                strategy,
+                signal_strategy_id: None,
+                signals: 0,
+                errors: 0,
+            });
+        }
+
+        info!(
+            members = ?config.members.iter().map(EnsembleMember::display_name).collect::<Vec<_>>(),
+            method = ?config.method,
+            "[Ensemble] 앙상블 전략 초기화"
+        );
+
+        let schedules: Vec<(&str, Option<StrategySchedule>)> = members
+            .iter()
+            .map(|m| (m.config.display_name(), m.strategy.schedule()))
+            .collect();
+        self.schedule = merge_schedules(&schedules);
+
+        self.members = members;
+        self.config = Some(config);
+        self.signals_emitted = 0;
+        self.votes_rejected = 0;
+        self.conflicts_resolved = 0;
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let Some((method, threshold)) = self.config.as_ref().map(|c| (c.method, c.threshold))
+        else {
+            return Ok(vec![]);
+        };
+        self.refresh_weights().await;
+
+        let mut outputs = Vec::with_capacity(self.members.len());
+        for (idx, member) in self.members.iter_mut().enumerate() {
+            outputs.push((idx, member.strategy.on_market_data(data).await));
+        }
+        Ok(self.decide(method, threshold, outputs).await)
+    }
+
+    fn schedule(&self) -> Option<StrategySchedule> {
+        self.schedule.clone()
+    }
+
+    async fn on_schedule(
+        &mut self,
+        event: &ScheduleEvent,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let (Some((method, threshold)), Some(country)) = (
+            self.config.as_ref().map(|c| (c.method, c.threshold)),
+            self.schedule.as_ref().map(|s| s.country),
+        ) else {
+            return Ok(vec![]);
+        };
+        self.refresh_weights().await;
+
+        // 해당 트리거를 선언한 하위 전략에만 전달
+        let mut outputs = Vec::new();
+        for (idx, member) in self.members.iter_mut().enumerate() {
+            let subscribed = member.strategy.schedule().is_some_and(|schedule| {
+                schedule.country == country && schedule.triggers.contains(&event.trigger)
+            });
+            if subscribed {
+                outputs.push((idx, member.strategy.on_schedule(event).await));
+            }
+        }
+        Ok(self.decide(method, threshold, outputs).await)
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // 앙상블 포지션은 하나이므로 모든 하위 전략에 체결을 전달
+        for member in &mut self.members {
+            member.strategy.on_order_filled(order).await?;
+        }
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        for member in &mut self.members {
+            member.strategy.on_position_update(position).await?;
+        }
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        for member in &mut self.members {
+            if let Err(e) = member.strategy.shutdown().await {
+                warn!(member = member.config.display_name(), error = %e, "[Ensemble] 하위 전략 종료 실패");
+            }
+        }
+        info!(
+            signals = self.signals_emitted,
+            rejected = self.votes_rejected,
+            conflicts = self.conflicts_resolved,
+            "[Ensemble] 앙상블 전략 종료"
+        );
+        Ok(())
+    }
+
+    fn get_state(&self) -> Value {
+        json!({
+            "initialized": self.config.is_some(),
+            "method": self.config.as_ref().map(|c| c.method),
+            "members": self.members.iter().map(|m| json!({
+                "name": m.config.display_name(),
+                "strategy": m.config.strategy,
+                "weight": m.config.weight,
+                "effective_weight": m.effective_weight,
+                "signals": m.signals,
+                "errors": m.errors,
+                "state": m.strategy.get_state(),
+            })).collect::<Vec<_>>(),
+            "signals_emitted": self.signals_emitted,
+            "votes_rejected": self.votes_rejected,
+            "conflicts_resolved": self.conflicts_resolved,
+        })
+    }
+
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let mut states = HashMap::new();
+        for member in &self.members {
+            states.insert(
+                member.config.display_name().to_string(),
+                member.strategy.save_state()?,
+            );
+        }
+        Ok(serde_json::to_vec(&states)?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let states: HashMap<String, Vec<u8>> = serde_json::from_slice(data)?;
+        for member in &mut self.members {
+            match states.get(member.config.display_name()) {
+                Some(state) if !state.is_empty() => member.strategy.load_state(state)?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
+        for member in &mut self.members {
+            member.strategy.set_context(Arc::clone(&context));
+        }
+        self.context = Some(context);
+    }
+}
+
+// ================================================================================================
+// 전략 레지스트리 등록
+// ================================================================================================
+
+use crate::register_strategy;
+
+register_strategy! {
+    id: "ensemble",
+    aliases: ["meta_strategy", "strategy_ensemble"],
+    name: "앙상블 전략",
+    description: "여러 전략의 신호를 가중 투표·만장일치·강도 평균으로 결합합니다.",
+    timeframe: "1d",
+    tickers: [],
+    category: Daily,
+    markets: [Crypto, Stock],
+    type: EnsembleStrategy
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use rust_decimal::Decimal;
+    use trader_core::{
+        domain::{PendingOrder, StrategyPositionInfo, StrategySignalPerformance},
+        Country, Kline, OrderStatusType, Timeframe,
+    };
+
+    use super::*;
+    use crate::ScheduleTrigger;
+
+    /// 설정된 방향의 신호를 매 캔들마다 (`monthly`면 월초 일정마다) 내는 테스트 전략.
+    struct FixedSignalStrategy {
+        side: Option<Side>,
+        signal_type: SignalType,
+        strength: f64,
+        monthly: bool,
+    }
+
+    impl FixedSignalStrategy {
+        fn new() -> Self {
+            Self {
+                side: None,
+                signal_type: SignalType::Entry,
+                strength: 1.0,
+                monthly: false,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Strategy for FixedSignalStrategy {
+        fn name(&self) -> &str {
+            "FixedSignal"
+        }
+
+        fn version(&self) -> &str {
+            "0.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "테스트용 고정 신호 전략"
+        }
+
+        async fn initialize(
+            &mut self,
+            config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.side = serde_json::from_value(config["side"].clone())?;
+            if let Some(signal_type) = config.get("signal_type") {
+                self.signal_type = serde_json::from_value(signal_type.clone())?;
+            }
+            self.strength = config["strength"].as_f64().unwrap_or(1.0);
+            self.monthly = config["monthly"].as_bool().unwrap_or(false);
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            if self.monthly {
+                return Ok(vec![]);
+            }
+            Ok(self.signals(&data.ticker))
+        }
+
+        fn schedule(&self) -> Option<StrategySchedule> {
+            self.monthly.then(|| {
+                StrategySchedule::new(Country::KR).with_trigger(ScheduleTrigger::MonthStart)
+            })
+        }
+
+        async fn on_schedule(
+            &mut self,
+            _event: &ScheduleEvent,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(self.signals("005930"))
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            json!({ "side": self.side })
+        }
+    }
+
+    impl FixedSignalStrategy {
+        fn signals(&self, ticker: &str) -> Vec<Signal> {
+            self.side
+                .map(|side| {
+                    Signal::new("fixed", ticker.to_string(), side, self.signal_type)
+                        .with_strength(self.strength)
+                })
+                .into_iter()
+                .collect()
+        }
+    }
+
+    register_strategy! {
+        id: "test_fixed_signal",
+        aliases: [],
+        name: "테스트 고정 신호",
+        description: "앙상블 테스트용",
+        timeframe: "1d",
+        tickers: [],
+        category: Daily,
+        markets: [Stock],
+        type: FixedSignalStrategy
+    }
+
+    fn member(name: &str, side: Option<&str>, strength: f64, weight: f64) -> Value {
+        json!({
+            "strategy": "test_fixed_signal",
+            "name": name,
+            "weight": weight,
+            "config": { "side": side, "strength": strength },
+        })
+    }
+
+    fn kline() -> MarketData {
+        let open_time = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let price = Decimal::from(100);
+        let kline = Kline::new(
+            "005930".to_string(),
+            Timeframe::D1,
+            open_time,
+            price,
+            price,
+            price,
+            price,
+            Decimal::from(1000),
+            open_time,
+        );
+        MarketData::from_kline("test", kline)
+    }
+
+    async fn ensemble(config: Value) -> EnsembleStrategy {
+        let mut strategy = EnsembleStrategy::new();
+        strategy.initialize(config).await.unwrap();
+        strategy
+    }
+
+    #[tokio::test]
+    async fn test_weighted_vote() {
+        let members = json!([
+            member("a", Some("buy"), 0.9, 1.0),
+            member("b", Some("buy"), 0.6, 1.0),
+            member("c", Some("sell"), 1.0, 1.0),
+        ]);
+        let mut strategy = ensemble(json!({ "members": members })).await;
+        let signals = strategy.on_market_data(&kline()).await.unwrap();
+
+        // 반대 방향 신호 대신 하나의 결정만 출력
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(signals[0].strategy_id, "ensemble");
+        assert!((signals[0].strength - 0.5).abs() < 1e-9);
+        assert_eq!(signals[0].metadata["ensemble"]["source"], json!("a"));
+        assert_eq!(
+            signals[0].metadata["ensemble"]["source_strategy_id"],
+            json!("fixed")
+        );
+        assert_eq!(
+            signals[0].metadata["ensemble"]["votes"]
+                .as_array()
+                .unwrap()
+                .len(),
+            3
+        );
+
+        // 임계값을 높이면 2/3 득표로는 부족
+        let mut strict = ensemble(json!({ "members": members, "threshold": 0.7 })).await;
+        assert!(strict.on_market_data(&kline()).await.unwrap().is_empty());
+        assert_eq!(strict.get_state()["votes_rejected"], json!(1));
+    }
+
+    #[tokio::test]
+    async fn test_unanimous_and_strength_average() {
+        let abstain = json!([
+            member("a", Some("buy"), 1.0, 1.0),
+            member("b", None, 1.0, 1.0)
+        ]);
+        let mut strategy = ensemble(json!({ "members": abstain, "method": "unanimous" })).await;
+        assert!(strategy.on_market_data(&kline()).await.unwrap().is_empty());
+
+        let agreed = json!([
+            member("a", Some("sell"), 1.0, 1.0),
+            member("b", Some("sell"), 0.5, 1.0)
+        ]);
+        let mut strategy = ensemble(json!({ "members": agreed, "method": "unanimous" })).await;
+        let signals = strategy.on_market_data(&kline()).await.unwrap();
+        assert_eq!(signals[0].side, Side::Sell);
+        assert!((signals[0].strength - 0.75).abs() < 1e-9);
+
+        // (2 × 0.8 − 1 × 0.6) / 3 = 0.333
+        let mixed = json!([
+            member("a", Some("buy"), 0.8, 2.0),
+            member("b", Some("sell"), 0.6, 1.0)
+        ]);
+        let mut strategy = ensemble(json!({
+            "members": mixed,
+            "method": "strength_average",
+            "threshold": 0.3,
+        }))
+        .await;
+        let signals = strategy.on_market_data(&kline()).await.unwrap();
+        assert_eq!(signals[0].side, Side::Buy);
+        assert!((signals[0].strength - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_weights_from_signal_performance() {
+        let mut winner = member("winner", Some("sell"), 1.0, 1.0);
+        winner["performance_key"] = json!("winner_v1");
+        let mut loser = member("loser", Some("buy"), 1.0, 1.0);
+        loser["performance_key"] = json!("loser_v1");
+        let mut strategy = ensemble(json!({
+            "members": [winner, loser],
+            "adaptive": { "enabled": true, "min_signals": 10 },
+        }))
+        .await;
+
+        let mut ctx = StrategyContext::default();
+        ctx.account.available_balance = Decimal::from(1_000_000);
+        ctx.update_signal_performance(vec![
+            StrategySignalPerformance {
+                strategy_id: "winner_v1".to_string(),
+                total_signals: 40,
+                win_rate: Some(70.0),
+                avg_return_5d: Some(1.2),
+            },
+            StrategySignalPerformance {
+                strategy_id: "loser_v1".to_string(),
+                total_signals: 40,
+                win_rate: Some(30.0),
+                avg_return_5d: Some(-0.8),
+            },
+        ]);
+        strategy.set_context(Arc::new(RwLock::new(ctx)));
+
+        // 동일 가중치였다면 동률로 기권, 성과 보정으로 매도 채택 (70% → 1.4배, 30% → 0.6배)
+        let signals = strategy.on_market_data(&kline()).await.unwrap();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].side, Side::Sell);
+        let state = strategy.get_state();
+        assert!((state["members"][0]["effective_weight"].as_f64().unwrap() - 1.4).abs() < 1e-9);
+        assert!((state["members"][1]["effective_weight"].as_f64().unwrap() - 0.6).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_conflicts_resolved_against_context() {
+        let mut strategy = ensemble(json!({
+            "members": [
+                member("entry", Some("buy"), 1.0, 1.0),
+                {
+                    "strategy": "test_fixed_signal",
+                    "name": "add",
+                    "config": { "side": "buy", "strength": 0.5, "signal_type": "add_to_position" },
+                },
+            ],
+        }))
+        .await;
+
+        let mut ctx = StrategyContext::default();
+        ctx.account.available_balance = Decimal::from(1_000_000);
+        ctx.positions.insert(
+            "005930".to_string(),
+            StrategyPositionInfo::new(
+                "005930".to_string(),
+                Side::Buy,
+                Decimal::ONE,
+                Decimal::from(90),
+            ),
+        );
+        let context = Arc::new(RwLock::new(ctx));
+        strategy.set_context(Arc::clone(&context));
+
+        // 포지션 보유 중에는 중복 Entry 대신 실행 가능한 추가 매수 신호를 대표로 선택
+        let signals = strategy.on_market_data(&kline()).await.unwrap();
+        assert_eq!(signals[0].signal_type, SignalType::AddToPosition);
+
+        // 미체결 주문이 있으면 어떤 신호도 실행 불가 → 엔진 전달 전 제외
+        context.write().await.pending_orders.push(PendingOrder {
+            order_id: "1".to_string(),
+            ticker: "005930".to_string(),
+            side: Side::Buy,
+            price: Decimal::from(100),
+            quantity: Decimal::ONE,
+            filled_quantity: Decimal::ZERO,
+            status: OrderStatusType::Open,
+            created_at: Utc::now(),
+        });
+        assert!(strategy.on_market_data(&kline()).await.unwrap().is_empty());
+        assert_eq!(strategy.get_state()["conflicts_resolved"], json!(1));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_members_rejected() {
+        for config in [
+            json!({ "members": [] }),
+            json!({ "members": [{ "strategy": "ensemble" }] }),
+            json!({ "members": [{ "strategy": "no_such_strategy" }] }),
+            json!({ "members": [member("a", None, 1.0, 0.0)] }),
+            json!({ "members": [member("a", None, 1.0, 1.0), member("a", None, 1.0, 1.0)] }),
+        ] {
+            assert!(EnsembleStrategy::new().initialize(config).await.is_err());
+        }
+        assert!(StrategyRegistry::find("ensemble").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_schedule_forwarded_to_subscribed_members() {
+        let mut monthly = member("monthly", Some("buy"), 0.8, 1.0);
+        monthly["config"]["monthly"] = json!(true);
+        let members = json!([monthly, member("daily", None, 1.0, 1.0)]);
+        let mut strategy = ensemble(json!({ "members": members, "method": "unanimous" })).await;
+
+        // 하위 전략 일정이 앙상블 일정으로 선언됨
+        let schedule = strategy.schedule().unwrap();
+        assert_eq!(schedule.country, Country::KR);
+        assert_eq!(schedule.triggers, vec![ScheduleTrigger::MonthStart]);
+
+        // 캔들에서는 월초 전략이 신호를 내지 않음
+        assert!(strategy.on_market_data(&kline()).await.unwrap().is_empty());
+
+        // 월초 이벤트는 구독한 하위 전략끼리만 투표 (만장일치도 충족)
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let event = ScheduleEvent {
+            trigger: ScheduleTrigger::MonthStart,
+            timestamp,
+            trading_day: timestamp.date_naive(),
+            market_open: true,
+        };
+        let signals = strategy.on_schedule(&event).await.unwrap();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(signals[0].metadata["ensemble"]["source"], "monthly");
+
+        // 일정을 선언한 하위 전략이 없으면 일정도 없음
+        let bar_only = ensemble(json!({ "members": [member("daily", None, 1.0, 1.0)] })).await;
+        assert!(bar_only.schedule().is_none());
+    }
+}
@@ -11,7 +11,10 @@
 //!
 //! - `RouteState`: 진입 가능 여부 판단 (Armed, Attack만 허용)
 //! - `GlobalScore`: 종목 품질 필터링
+//! - RSI/볼린저: 엔진이 캔들마다 갱신하는 증분 지표 스냅샷을 우선 사용하고,
+//!   스냅샷이 없으면 `StructuralFeatures` 값을 사용
 //! - 손절/익절: 설정된 비율로 자동 청산
+//! - 결정 추적: 포지션이 없을 때마다 진입 판단 근거(지표, 필터)를 기록
 //!
 //! # Grid/MagicSplit 분리 안내
 //!
@@ -30,12 +33,13 @@ use tokio::sync::RwLock;
 use tracing::{debug, info};
 use trader_core::{
     domain::{RouteState, StrategyContext},
-    MarketData, MarketDataType, Order, Position, Side, Signal, SignalType,
+    DecisionTrace, MarketData, MarketDataType, Order, Position, Side, Signal, SignalType,
+    Timeframe,
 };
 use trader_strategy_macro::StrategyConfig;
 
 use crate::{
-    strategies::common::{adjust_strength_by_score, ExitConfig},
+    strategies::common::{adjust_strength_by_score, trace_route_state, ExitConfig},
     Strategy,
 };
 
@@ -475,10 +479,15 @@ pub struct MeanReversionStrategy {
     position: PositionState,
     cooldown_counter: usize,
     initialized: bool,
+    /// 마지막 캔들의 타임프레임 (증분 지표 스냅샷 조회용)
+    bar_timeframe: Option<Timeframe>,
 
     // RSI 상태
     rsi_calculator: RsiCalculator,
     prev_rsi: Option<Decimal>,
+
+    // 아직 수거되지 않은 결정 추적
+    decision_traces: Vec<DecisionTrace>,
 }
 
 impl MeanReversionStrategy {
@@ -491,8 +500,10 @@ impl MeanReversionStrategy {
             position: PositionState::default(),
             cooldown_counter: 0,
             initialized: false,
+            bar_timeframe: None,
             rsi_calculator: RsiCalculator::new(14),
             prev_rsi: None,
+            decision_traces: Vec::new(),
         }
     }
 
@@ -519,6 +530,13 @@ impl MeanReversionStrategy {
     fn get_rsi_from_context(&self, ticker: &str) -> Option<Decimal> {
         let ctx = self.context.as_ref()?;
         let ctx_lock = ctx.try_read().ok()?;
+        let snapshot_rsi = self
+            .bar_timeframe
+            .and_then(|tf| ctx_lock.get_indicator_snapshot(ticker, tf))
+            .and_then(|snapshot| snapshot.rsi);
+        if snapshot_rsi.is_some() {
+            return snapshot_rsi;
+        }
         let features = ctx_lock.structural_features.get(ticker)?;
         Some(features.rsi)
     }
@@ -529,6 +547,18 @@ impl MeanReversionStrategy {
     ) -> Option<(Decimal, Decimal, Decimal, Decimal)> {
         let ctx = self.context.as_ref()?;
         let ctx_lock = ctx.try_read().ok()?;
+        let snapshot = self
+            .bar_timeframe
+            .and_then(|tf| ctx_lock.get_indicator_snapshot(ticker, tf));
+        if let Some((Some(lower), Some(middle), Some(upper))) =
+            snapshot.map(|s| (s.bb_lower, s.bb_middle, s.bb_upper))
+        {
+            if middle > Decimal::ZERO {
+                // StructuralFeatures와 같은 단위 (중간 밴드 대비 %)
+                let width = (upper - lower) / middle * dec!(100);
+                return Some((lower, middle, upper, width));
+            }
+        }
         let features = ctx_lock.structural_features.get(ticker)?;
         Some((
             features.bb_lower,
@@ -542,7 +572,7 @@ impl MeanReversionStrategy {
     // 공통 헬퍼
     // ========================================================================
 
-    fn can_enter(&self) -> bool {
+    fn can_enter(&self, trace: &mut DecisionTrace) -> bool {
         let Some(config) = self.config.as_ref() else {
             return false;
         };
@@ -560,20 +590,22 @@ impl MeanReversionStrategy {
         };
 
         // RouteState 체크 - 지표 기반 전략에서 시장 과열 시 진입 제한
-        if let Some(route_state) = ctx_lock.get_route_state(&config.ticker) {
-            if route_state == &RouteState::Overheat {
-                debug!(
-                    ticker = %config.ticker,
-                    route_state = ?route_state,
-                    "시장 과열 - 진입 제한"
-                );
-                return false;
-            }
+        if !trace_route_state(&ctx_lock, &config.ticker, &[RouteState::Overheat], trace) {
+            debug!(ticker = %config.ticker, "시장 과열 - 진입 제한");
+            return false;
         }
 
         true
     }
 
+    /// 진입 판단 추적 시작.
+    fn entry_trace(&self, ticker: &str, timestamp: DateTime<Utc>) -> DecisionTrace {
+        let mut trace = DecisionTrace::new("mean_reversion", ticker, timestamp)
+            .with_intent(Side::Buy, SignalType::Entry);
+        trace.condition("cooldown", !self.is_in_cooldown());
+        trace
+    }
+
     fn get_adjusted_strength(&self, base_strength: f64) -> f64 {
         let Some(config) = self.config.as_ref() else {
             return base_strength;
@@ -618,7 +650,7 @@ impl MeanReversionStrategy {
     // RSI 로직
     // ========================================================================
 
-    fn generate_rsi_signals(&mut self, price: Decimal) -> Vec<Signal> {
+    fn generate_rsi_signals(&mut self, price: Decimal, timestamp: DateTime<Utc>) -> Vec<Signal> {
         let Some(config) = self.config.as_ref() else {
             return vec![];
         };
@@ -634,17 +666,19 @@ impl MeanReversionStrategy {
         let mut signals = vec![];
 
         // 진입 체크
-        if !self.has_position()
-            && !self.is_in_cooldown()
-            && self.can_enter()
-            && rsi < config.oversold
-        {
-            let base_strength = ((config.oversold - rsi) / config.oversold)
-                .to_f64()
-                .unwrap_or(0.5);
-            let strength = self.get_adjusted_strength(base_strength);
-            signals.push(
-                Signal::new(
+        if !self.has_position() {
+            let mut trace = self.entry_trace(&config.ticker, timestamp);
+            trace.indicator("price", price).indicator("rsi", rsi);
+
+            if trace.first_failure().is_none()
+                && self.can_enter(&mut trace)
+                && trace.filter("rsi_oversold", rsi < config.oversold, rsi, config.oversold)
+            {
+                let base_strength = ((config.oversold - rsi) / config.oversold)
+                    .to_f64()
+                    .unwrap_or(0.5);
+                let strength = self.get_adjusted_strength(base_strength);
+                let signal = Signal::new(
                     "mean_reversion",
                     config.ticker.clone(),
                     Side::Buy,
@@ -653,8 +687,11 @@ impl MeanReversionStrategy {
                 .with_strength(strength)
                 .with_prices(Some(price), None, None)
                 .with_metadata("variant", json!("rsi"))
-                .with_metadata("rsi", json!(rsi.to_string())),
-            );
+                .with_metadata("rsi", json!(rsi.to_string()));
+                trace.emit(&signal);
+                signals.push(signal);
+            }
+            self.decision_traces.push(trace);
         }
 
         // 청산 체크
@@ -725,7 +762,11 @@ impl MeanReversionStrategy {
     // Bollinger 로직
     // ========================================================================
 
-    fn generate_bollinger_signals(&mut self, price: Decimal) -> Vec<Signal> {
+    fn generate_bollinger_signals(
+        &mut self,
+        price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Vec<Signal> {
         let Some(config) = self.config.as_ref() else {
             return vec![];
         };
@@ -740,37 +781,63 @@ impl MeanReversionStrategy {
             }
         };
 
+        let mut entry_trace = (!self.has_position()).then(|| {
+            let mut trace = self.entry_trace(&config.ticker, timestamp);
+            trace
+                .indicator("price", price)
+                .indicator("bb_lower", lower)
+                .indicator("bb_middle", middle)
+                .indicator("bb_width", bandwidth);
+            trace
+        });
+
         // 밴드폭 체크
-        if bandwidth < config.min_bandwidth_pct {
+        let squeezed = bandwidth < config.min_bandwidth_pct;
+        if let Some(trace) = entry_trace.as_mut() {
+            trace.filter(
+                "min_bandwidth",
+                !squeezed,
+                bandwidth,
+                config.min_bandwidth_pct,
+            );
+        }
+        if squeezed {
             debug!(bandwidth = %bandwidth, "볼린저 스퀴즈 - 대기");
+            self.decision_traces.extend(entry_trace);
             return vec![];
         }
 
         let mut signals = vec![];
 
         // 진입 체크
-        if !self.has_position() && !self.is_in_cooldown() && self.can_enter() && price <= lower {
-            let rsi_ok = if config.use_rsi_confirmation {
-                self.prev_rsi.map(|r| r < dec!(30)).unwrap_or(false)
-            } else {
-                true
-            };
-
-            if rsi_ok {
+        if let Some(mut trace) = entry_trace {
+            let enter = trace.first_failure().is_none()
+                && self.can_enter(&mut trace)
+                && trace.filter("price_below_lower_band", price <= lower, price, lower)
+                && (!config.use_rsi_confirmation
+                    || trace.filter(
+                        "rsi_confirmation",
+                        self.prev_rsi.map(|r| r < dec!(30)).unwrap_or(false),
+                        self.prev_rsi,
+                        dec!(30),
+                    ));
+
+            if enter {
                 let strength = self.get_adjusted_strength(0.8);
-                signals.push(
-                    Signal::new(
-                        "mean_reversion",
-                        config.ticker.clone(),
-                        Side::Buy,
-                        SignalType::Entry,
-                    )
-                    .with_strength(strength)
-                    .with_prices(Some(price), None, None)
-                    .with_metadata("variant", json!("bollinger"))
-                    .with_metadata("lower_band", json!(lower.to_string())),
-                );
+                let signal = Signal::new(
+                    "mean_reversion",
+                    config.ticker.clone(),
+                    Side::Buy,
+                    SignalType::Entry,
+                )
+                .with_strength(strength)
+                .with_prices(Some(price), None, None)
+                .with_metadata("variant", json!("bollinger"))
+                .with_metadata("lower_band", json!(lower.to_string()));
+                trace.emit(&signal);
+                signals.push(signal);
             }
+            self.decision_traces.push(trace);
         }
 
         // 청산 체크
@@ -920,7 +987,10 @@ impl Strategy for MeanReversionStrategy {
         }
 
         let price = match &data.data {
-            MarketDataType::Kline(kline) => kline.close,
+            MarketDataType::Kline(kline) => {
+                self.bar_timeframe = Some(kline.timeframe);
+                kline.close
+            }
             MarketDataType::Ticker(ticker_data) => ticker_data.last,
             MarketDataType::Trade(trade) => trade.price,
             _ => return Ok(vec![]),
@@ -946,8 +1016,10 @@ impl Strategy for MeanReversionStrategy {
         }
 
         let signals = match variant {
-            MeanReversionVariant::Rsi => self.generate_rsi_signals(price),
-            MeanReversionVariant::Bollinger => self.generate_bollinger_signals(price),
+            MeanReversionVariant::Rsi => self.generate_rsi_signals(price, data.timestamp),
+            MeanReversionVariant::Bollinger => {
+                self.generate_bollinger_signals(price, data.timestamp)
+            }
         };
 
         Ok(signals)
@@ -1051,6 +1123,10 @@ impl Strategy for MeanReversionStrategy {
     fn exit_config(&self) -> Option<&ExitConfig> {
         self.config.as_ref().map(|c| &c.exit_config)
     }
+
+    fn take_decision_traces(&mut self) -> Vec<DecisionTrace> {
+        std::mem::take(&mut self.decision_traces)
+    }
 }
 
 // ================================================================================================
@@ -1086,3 +1162,96 @@ register_strategy! {
     factory: MeanReversionStrategy::bollinger,
     config: BollingerConfig
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use trader_core::{domain::IndicatorSnapshot, Kline};
+
+    use super::*;
+
+    fn kline(close: Decimal) -> MarketData {
+        let open_time = Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap();
+        MarketData::from_kline(
+            "test",
+            Kline::new(
+                "005930".to_string(),
+                Timeframe::M15,
+                open_time,
+                close,
+                close,
+                close,
+                close,
+                dec!(100),
+                open_time + chrono::Duration::minutes(15),
+            ),
+        )
+    }
+
+    fn snapshot(rsi: Decimal) -> IndicatorSnapshot {
+        IndicatorSnapshot {
+            ticker: "005930".to_string(),
+            timeframe: Timeframe::M15,
+            open_time: Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap(),
+            close: dec!(70000),
+            bars: 30,
+            rsi: Some(rsi),
+            macd: None,
+            macd_signal: None,
+            macd_histogram: None,
+            bb_upper: Some(dec!(72000)),
+            bb_middle: Some(dec!(70000)),
+            bb_lower: Some(dec!(68000)),
+            bb_percent_b: None,
+            atr: None,
+            supertrend: None,
+            supertrend_uptrend: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rsi_variant_reads_incremental_snapshot() {
+        let mut strategy = MeanReversionStrategy::rsi();
+        strategy
+            .initialize(json!({ "ticker": "005930", "oversold": 30 }))
+            .await
+            .unwrap();
+        let context = Arc::new(RwLock::new(StrategyContext::default()));
+        strategy.set_context(Arc::clone(&context));
+
+        // 스냅샷도 구조적 피처도 없으면 신호 없음
+        assert!(strategy
+            .on_market_data(&kline(dec!(70000)))
+            .await
+            .unwrap()
+            .is_empty());
+
+        context
+            .write()
+            .await
+            .update_indicator_snapshot(snapshot(dec!(20)));
+        let signals = strategy.on_market_data(&kline(dec!(70000))).await.unwrap();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(strategy.prev_rsi, Some(dec!(20)));
+    }
+
+    #[test]
+    fn test_bollinger_width_from_snapshot() {
+        let mut strategy = MeanReversionStrategy::bollinger();
+        let mut ctx = StrategyContext::default();
+        ctx.update_indicator_snapshot(snapshot(dec!(50)));
+        strategy.set_context(Arc::new(RwLock::new(ctx)));
+
+        // 캔들 타임프레임을 모르면 스냅샷을 고를 수 없음
+        assert!(strategy.get_bollinger_from_context("005930").is_none());
+
+        strategy.bar_timeframe = Some(Timeframe::M15);
+        let (lower, middle, upper, width) = strategy.get_bollinger_from_context("005930").unwrap();
+        assert_eq!(
+            (lower, middle, upper),
+            (dec!(68000), dec!(70000), dec!(72000))
+        );
+        assert_eq!(width.round_dp(4), dec!(5.7143));
+    }
+}
@@ -22,6 +22,11 @@
 //! - **Pension Bot**: 연금 자동화 정적+동적 자산배분.
 //! - **US 3X Leverage**: 미국 3배 레버리지/인버스 ETF 조합 전략.
 //! - **RSI Multi TF**: RSI 다중 타임프레임 전략.
+//! - **Script**: 샌드박스 Rhai 스크립트 전략.
+//! - **Rule Based**: JSON 조건 트리 기반 규칙 전략.
+//! - **Ensemble**: 여러 전략의 신호를 투표로 결합하는 메타 전략.
+//! - **Pairs Trading**: 공적분 페어 스프레드 통계적 차익거래 전략.
+//! - **Option Overlay**: 기초 전략 보유 종목에 커버드콜/보호적 풋을 얹는 옵션 오버레이.
 //!
 //! ## 한국 지수 전략
 //!
@@ -47,12 +52,17 @@ pub mod common;
 // 독립 전략
 pub mod candle_pattern;
 pub mod compound_momentum;
+pub mod ensemble;
 pub mod market_bothside;
 pub mod momentum_power;
 pub mod momentum_surge;
+pub mod option_overlay;
+pub mod pairs_trading;
 pub mod pension_bot;
 pub mod range_trading;
 pub mod rsi_multi_tf;
+pub mod rule_based;
+pub mod script;
 pub mod sector_vb;
 pub mod small_cap_quant;
 pub mod us_3x_leverage;
@@ -75,12 +85,22 @@ pub use dca::{
     DcaConfig, DcaStrategy, DcaVariant, GridTradingConfig, InfinityBotConfig, MagicSplitConfig,
     SplitLevel,
 };
+pub use ensemble::{
+    AdaptiveWeightConfig, CombineMethod, EnsembleConfig, EnsembleError, EnsembleMember,
+    EnsembleStrategy,
+};
 pub use market_bothside::*;
 pub use mean_reversion::{
     BollingerConfig, MeanReversionConfig, MeanReversionStrategy, MeanReversionVariant, RsiConfig,
 };
 pub use momentum_power::*;
 pub use momentum_surge::*;
+pub use option_overlay::{
+    OptionOverlayConfig, OptionOverlayError, OptionOverlayStrategy, OverlayMode, OverlayPosition,
+};
+pub use pairs_trading::{
+    HedgeMethod, PairsTradingConfig, PairsTradingState, PairsTradingStrategy, SpreadPosition,
+};
 pub use pension_bot::*;
 pub use range_trading::*;
 pub use rotation::{
@@ -89,10 +109,14 @@ pub use rotation::{
     WeightingMethod as RotationWeightingMethod,
 };
 pub use rsi_multi_tf::*;
+pub use rule_based::{
+    CompareOp, IndicatorKind, Operand, RuleBasedConfig, RuleBasedStrategy, RuleError, RuleNode,
+};
 pub use screening_based::{
     ScreeningBasedConfig, ScreeningBasedStrategy, ScreeningVariant,
     WeightingMethod as ScreeningWeightingMethod,
 };
+pub use script::{ScriptStrategy, ScriptStrategyConfig};
 pub use sector_vb::*;
 pub use small_cap_quant::*;
 pub use us_3x_leverage::*;
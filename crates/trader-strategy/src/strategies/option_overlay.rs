@@ -0,0 +1,917 @@
+//! 옵션 오버레이 전략 (커버드콜 / 보호적 풋).
+//!
+//! 레지스트리에 등록된 기초 전략(기본값: `all_weather`)을 그대로 실행하면서,
+//! 기초 전략이 보유한 종목 위에 옵션 포지션을 얹습니다. 기초 전략의 신호는
+//! 변경 없이 전달됩니다.
+//!
+//! # 모드
+//!
+//! - `covered_call`: 보유 수량만큼 외가격 콜 매도 (프리미엄 수취, 상승 여력 제한)
+//! - `protective_put`: 보유 수량만큼 외가격 풋 매수 (하락 방어, 프리미엄 지불)
+//!
+//! # 계약 관리
+//!
+//! - **행사가**: 현재가 × (1 ± `otm_pct`)를 `strike_step` 단위로 외가격 방향 반올림
+//! - **만기**: `expiry_rule` 월물 중 `min_days_to_expiry`일 이상 남은 가장 가까운 만기
+//! - **수량**: ⌊보유 수량 × `coverage_ratio` / `multiplier`⌋ 계약
+//! - **롤오버**: 만기일에 청산하고 다음 월물을 새로 진입
+//! - **기초자산 축소**: 기초 전략이 매도하거나 보유 수량이 옵션 수량보다 줄면 즉시 청산
+//!
+//! 옵션 신호는 `Signal::with_option_contract`로 계약을, `Signal::with_fixed_quantity`로
+//! 기초자산 단위 수량(계약 수 × 승수)을 전달합니다. 백테스트 엔진은 옵션 시세 대신
+//! 내재변동성 입력으로 합성 가격을 계산합니다.
+//!
+//! # 설정 예시
+//!
+//! ```json
+//! {
+//!   "base_strategy": "all_weather",
+//!   "base_config": {},
+//!   "mode": "covered_call",
+//!   "underlyings": ["SPY"],
+//!   "otm_pct": 0.05,
+//!   "strike_step": 1,
+//!   "min_days_to_expiry": 20,
+//!   "expiry_rule": "us_monthly",
+//!   "multiplier": 100
+//! }
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, info};
+use trader_core::{
+    domain::{MultiTimeframeConfig, StrategyContext},
+    DecisionTrace, ExerciseStyle, Kline, MarketData, MarketDataType, OptionContract,
+    OptionExpiryRule, OptionRight, Order, Position, Side, Signal, SignalType, Timeframe,
+};
+
+use crate::{
+    strategies::common::ExitConfig, ScheduleEvent, Strategy, StrategyRegistry, StrategySchedule,
+};
+
+/// 옵션 오버레이 전략 ID (중첩 방지용).
+const OPTION_OVERLAY_STRATEGY_ID: &str = "option_overlay";
+
+// ================================================================================================
+// 설정
+// ================================================================================================
+
+/// 옵션 오버레이 설정 오류.
+#[derive(Debug, Error)]
+pub enum OptionOverlayError {
+    /// 옵션 오버레이 안에 옵션 오버레이를 넣을 수 없음
+    #[error("옵션 오버레이 전략은 기초 전략으로 사용할 수 없습니다")]
+    NestedOverlay,
+
+    /// 레지스트리에 없는 전략
+    #[error("알 수 없는 전략: {0}")]
+    UnknownStrategy(String),
+
+    /// 잘못된 설정값
+    #[error("잘못된 설정: {0}")]
+    InvalidConfig(String),
+
+    /// 기초 전략 초기화 실패
+    #[error("기초 전략 {strategy} 초기화 실패: {message}")]
+    BaseInit { strategy: String, message: String },
+}
+
+/// 오버레이 모드.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayMode {
+    /// 커버드콜 (외가격 콜 매도)
+    #[default]
+    CoveredCall,
+    /// 보호적 풋 (외가격 풋 매수)
+    ProtectivePut,
+}
+
+impl OverlayMode {
+    fn right(&self) -> OptionRight {
+        match self {
+            OverlayMode::CoveredCall => OptionRight::Call,
+            OverlayMode::ProtectivePut => OptionRight::Put,
+        }
+    }
+
+    /// 옵션 진입 방향 (콜 매도 / 풋 매수).
+    fn open_side(&self) -> Side {
+        match self {
+            OverlayMode::CoveredCall => Side::Sell,
+            OverlayMode::ProtectivePut => Side::Buy,
+        }
+    }
+}
+
+/// 옵션 오버레이 전략 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptionOverlayConfig {
+    /// 기초 전략 ID (레지스트리 ID 또는 별칭)
+    #[serde(default = "default_base_strategy")]
+    pub base_strategy: String,
+
+    /// 기초 전략 설정 (기초 전략의 `initialize`에 그대로 전달)
+    #[serde(default = "default_base_config")]
+    pub base_config: Value,
+
+    /// 오버레이 모드
+    #[serde(default)]
+    pub mode: OverlayMode,
+
+    /// 오버레이 대상 종목 (비어 있으면 보유 종목 전체)
+    #[serde(default)]
+    pub underlyings: Vec<String>,
+
+    /// 외가격 비율 (0.05 = 현재가 대비 5%)
+    #[serde(default = "default_otm_pct")]
+    pub otm_pct: Decimal,
+
+    /// 행사가 단위 (없으면 소수점 2자리)
+    #[serde(default)]
+    pub strike_step: Option<Decimal>,
+
+    /// 최소 잔존일수
+    #[serde(default = "default_min_days_to_expiry")]
+    pub min_days_to_expiry: i64,
+
+    /// 월물 만기 규칙
+    #[serde(default)]
+    pub expiry_rule: OptionExpiryRule,
+
+    /// 권리 행사 방식
+    #[serde(default)]
+    pub exercise_style: ExerciseStyle,
+
+    /// 계약 승수 (미국 주식 옵션 100, KOSPI200 옵션 250000)
+    #[serde(default = "default_multiplier")]
+    pub multiplier: Decimal,
+
+    /// 보유 수량 대비 옵션 커버 비율 (1.0 = 전량)
+    #[serde(default = "default_coverage_ratio")]
+    pub coverage_ratio: Decimal,
+}
+
+fn default_base_strategy() -> String {
+    "all_weather".to_string()
+}
+
+fn default_base_config() -> Value {
+    json!({})
+}
+
+fn default_otm_pct() -> Decimal {
+    dec!(0.05)
+}
+
+fn default_min_days_to_expiry() -> i64 {
+    20
+}
+
+fn default_multiplier() -> Decimal {
+    dec!(100)
+}
+
+fn default_coverage_ratio() -> Decimal {
+    Decimal::ONE
+}
+
+impl Default for OptionOverlayConfig {
+    fn default() -> Self {
+        Self {
+            base_strategy: default_base_strategy(),
+            base_config: default_base_config(),
+            mode: OverlayMode::default(),
+            underlyings: Vec::new(),
+            otm_pct: default_otm_pct(),
+            strike_step: None,
+            min_days_to_expiry: default_min_days_to_expiry(),
+            expiry_rule: OptionExpiryRule::default(),
+            exercise_style: ExerciseStyle::default(),
+            multiplier: default_multiplier(),
+            coverage_ratio: default_coverage_ratio(),
+        }
+    }
+}
+
+impl OptionOverlayConfig {
+    /// 설정 검증.
+    pub fn validate(&self) -> Result<(), OptionOverlayError> {
+        let meta = StrategyRegistry::find(&self.base_strategy)
+            .ok_or_else(|| OptionOverlayError::UnknownStrategy(self.base_strategy.clone()))?;
+        if meta.id == OPTION_OVERLAY_STRATEGY_ID {
+            return Err(OptionOverlayError::NestedOverlay);
+        }
+        if self.otm_pct < Decimal::ZERO || self.otm_pct >= Decimal::ONE {
+            return Err(OptionOverlayError::InvalidConfig(
+                "otm_pct는 0 이상 1 미만이어야 합니다".to_string(),
+            ));
+        }
+        if self.multiplier <= Decimal::ZERO {
+            return Err(OptionOverlayError::InvalidConfig(
+                "multiplier는 0보다 커야 합니다".to_string(),
+            ));
+        }
+        if self.coverage_ratio <= Decimal::ZERO || self.coverage_ratio > Decimal::ONE {
+            return Err(OptionOverlayError::InvalidConfig(
+                "coverage_ratio는 0 초과 1 이하여야 합니다".to_string(),
+            ));
+        }
+        if self.min_days_to_expiry < 1 {
+            return Err(OptionOverlayError::InvalidConfig(
+                "min_days_to_expiry는 1 이상이어야 합니다".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn applies_to(&self, ticker: &str) -> bool {
+        self.underlyings.is_empty() || self.underlyings.iter().any(|u| u == ticker)
+    }
+
+    /// 현재가 기준 신규 계약.
+    fn contract_for(
+        &self,
+        underlying: &str,
+        spot: Decimal,
+        date: NaiveDate,
+    ) -> Option<OptionContract> {
+        let expiry = self
+            .expiry_rule
+            .next_expiry(date, self.min_days_to_expiry)?;
+        let right = self.mode.right();
+        let raw_strike = match right {
+            OptionRight::Call => spot * (Decimal::ONE + self.otm_pct),
+            OptionRight::Put => spot * (Decimal::ONE - self.otm_pct),
+        };
+        // 외가격 방향으로 반올림 (콜은 올림, 풋은 내림)
+        let strike = match self.strike_step.filter(|step| *step > Decimal::ZERO) {
+            Some(step) => {
+                let units = raw_strike / step;
+                let units = match right {
+                    OptionRight::Call => units.ceil(),
+                    OptionRight::Put => units.floor(),
+                };
+                units * step
+            }
+            None => raw_strike.round_dp(2),
+        };
+        if strike <= Decimal::ZERO {
+            return None;
+        }
+
+        Some(
+            OptionContract::new(underlying, right, strike, expiry)
+                .with_multiplier(self.multiplier)
+                .with_style(self.exercise_style),
+        )
+    }
+
+    /// 보유 수량으로 커버 가능한 옵션 수량 (기초자산 단위).
+    fn covered_quantity(&self, held: Decimal) -> Decimal {
+        (held * self.coverage_ratio / self.multiplier).floor() * self.multiplier
+    }
+}
+
+// ================================================================================================
+// 전략 구현
+// ================================================================================================
+
+/// 보유 중인 옵션 포지션.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverlayPosition {
+    /// 옵션 계약
+    pub contract: OptionContract,
+    /// 수량 (기초자산 단위 = 계약 수 × 승수)
+    pub quantity: Decimal,
+}
+
+/// 저장 상태.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SavedState {
+    base: Vec<u8>,
+    options: BTreeMap<String, OverlayPosition>,
+}
+
+/// 옵션 오버레이 전략.
+pub struct OptionOverlayStrategy {
+    config: Option<OptionOverlayConfig>,
+    base: Option<Box<dyn Strategy>>,
+    context: Option<Arc<RwLock<StrategyContext>>>,
+    /// 기초자산 롱 보유 수량 (티커 → 수량)
+    holdings: HashMap<String, Decimal>,
+    /// 기초자산별 옵션 포지션 (기초자산 티커 → 옵션)
+    options: BTreeMap<String, OverlayPosition>,
+    options_opened: u64,
+    options_closed: u64,
+}
+
+impl OptionOverlayStrategy {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            base: None,
+            context: None,
+            holdings: HashMap::new(),
+            options: BTreeMap::new(),
+            options_opened: 0,
+            options_closed: 0,
+        }
+    }
+
+    /// 보유 중인 옵션 포지션.
+    pub fn open_options(&self) -> impl Iterator<Item = &OverlayPosition> {
+        self.options.values()
+    }
+
+    fn is_option_ticker(&self, ticker: &str) -> bool {
+        self.options
+            .values()
+            .any(|position| position.contract.ticker() == ticker)
+    }
+
+    fn open_signal(&mut self, position: &OverlayPosition, mode: OverlayMode) -> Signal {
+        self.options_opened += 1;
+        let reason = match mode {
+            OverlayMode::CoveredCall => "covered_call_open",
+            OverlayMode::ProtectivePut => "protective_put_open",
+        };
+        Signal::entry(
+            OPTION_OVERLAY_STRATEGY_ID,
+            position.contract.ticker(),
+            mode.open_side(),
+        )
+        .with_option_contract(&position.contract)
+        .with_fixed_quantity(position.quantity)
+        .with_metadata("reason", json!(reason))
+        .with_metadata("underlying", json!(position.contract.underlying))
+    }
+
+    fn close_signal(
+        &mut self,
+        position: &OverlayPosition,
+        mode: OverlayMode,
+        reason: &str,
+    ) -> Signal {
+        self.options_closed += 1;
+        let side = match mode.open_side() {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+        Signal::exit(OPTION_OVERLAY_STRATEGY_ID, position.contract.ticker(), side)
+            .with_option_contract(&position.contract)
+            .with_fixed_quantity(position.quantity)
+            .with_metadata("reason", json!(reason))
+            .with_metadata("underlying", json!(position.contract.underlying))
+    }
+
+    /// 기초 전략 신호에 옵션 신호를 덧붙입니다.
+    fn apply_overlay(&mut self, data: &MarketData, base_signals: Vec<Signal>) -> Vec<Signal> {
+        let Some(config) = self.config.clone() else {
+            return base_signals;
+        };
+        let mode = config.mode;
+        let mut output = Vec::new();
+
+        // 1. 기초 전략이 매도하는 종목의 옵션 청산
+        for signal in &base_signals {
+            let reduces = signal.side == Side::Sell
+                && matches!(
+                    signal.signal_type,
+                    SignalType::Exit | SignalType::ReducePosition
+                );
+            if !reduces {
+                continue;
+            }
+            if let Some(position) = self.options.remove(&signal.ticker) {
+                output.push(self.close_signal(&position, mode, "underlying_reduced"));
+            }
+        }
+
+        let ticker = data.ticker.as_str();
+        let MarketDataType::Kline(kline) = &data.data else {
+            output.extend(base_signals);
+            return output;
+        };
+        if !config.applies_to(ticker) {
+            output.extend(base_signals);
+            return output;
+        }
+        let date = data.timestamp.date_naive();
+        let held = self.holdings.get(ticker).copied().unwrap_or_default();
+
+        // 2. 만기 롤오버 / 보유 축소 청산
+        if let Some(position) = self.options.get(ticker) {
+            let reason = if position.contract.is_expired(date) {
+                Some("expiry_roll")
+            } else if held < position.quantity {
+                Some("underlying_reduced")
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                let position = self.options.remove(ticker).expect("checked above");
+                debug!(ticker, option = %position.contract, reason, "[OptionOverlay] 옵션 청산");
+                output.push(self.close_signal(&position, mode, reason));
+            }
+        }
+
+        // 3. 신규 진입 (이번 봉에 기초 전략이 매도하면 보류)
+        let base_selling = base_signals
+            .iter()
+            .any(|s| s.ticker == ticker && s.side == Side::Sell);
+        if !self.options.contains_key(ticker) && !base_selling {
+            let quantity = config.covered_quantity(held);
+            let contract = config.contract_for(ticker, kline.close, date);
+            if let (true, Some(contract)) = (quantity > Decimal::ZERO, contract) {
+                let position = OverlayPosition { contract, quantity };
+                debug!(ticker, option = %position.contract, quantity = %quantity, "[OptionOverlay] 옵션 진입");
+                output.push(self.open_signal(&position, mode));
+                self.options.insert(ticker.to_string(), position);
+            }
+        }
+
+        output.extend(base_signals);
+        output
+    }
+}
+
+impl Default for OptionOverlayStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Strategy for OptionOverlayStrategy {
+    fn name(&self) -> &str {
+        "Option Overlay"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "기초 전략 보유 종목에 커버드콜/보호적 풋을 얹는 옵션 오버레이 전략"
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let config: OptionOverlayConfig = serde_json::from_value(config)?;
+        config.validate()?;
+
+        let mut base = StrategyRegistry::create_instance(&config.base_strategy)
+            .map_err(|_| OptionOverlayError::UnknownStrategy(config.base_strategy.clone()))?;
+        base.initialize(config.base_config.clone())
+            .await
+            .map_err(|e| OptionOverlayError::BaseInit {
+                strategy: config.base_strategy.clone(),
+                message: e.to_string(),
+            })?;
+        if let Some(context) = self.context.as_ref() {
+            base.set_context(Arc::clone(context));
+        }
+
+        info!(
+            base = %config.base_strategy,
+            mode = ?config.mode,
+            otm_pct = %config.otm_pct,
+            "[OptionOverlay] 옵션 오버레이 전략 초기화"
+        );
+
+        self.base = Some(base);
+        self.config = Some(config);
+        self.holdings.clear();
+        self.options.clear();
+        self.options_opened = 0;
+        self.options_closed = 0;
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let base_signals = match self.base.as_mut() {
+            Some(base) => base.on_market_data(data).await?,
+            None => return Ok(vec![]),
+        };
+        Ok(self.apply_overlay(data, base_signals))
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if self.is_option_ticker(&order.ticker) {
+            return Ok(());
+        }
+        match self.base.as_mut() {
+            Some(base) => base.on_order_filled(order).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn on_position_update(
+        &mut self,
+        position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // 옵션 포지션은 기초 전략에 전달하지 않음
+        if self.is_option_ticker(&position.ticker) {
+            return Ok(());
+        }
+        if position.side == Side::Buy {
+            self.holdings
+                .insert(position.ticker.clone(), position.quantity);
+        }
+        match self.base.as_mut() {
+            Some(base) => base.on_position_update(position).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(base) = self.base.as_mut() {
+            base.shutdown().await?;
+        }
+        info!(
+            opened = self.options_opened,
+            closed = self.options_closed,
+            open = self.options.len(),
+            "[OptionOverlay] 옵션 오버레이 전략 종료"
+        );
+        Ok(())
+    }
+
+    fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
+        if let Some(base) = self.base.as_mut() {
+            base.set_context(Arc::clone(&context));
+        }
+        self.context = Some(context);
+    }
+
+    fn exit_config(&self) -> Option<&ExitConfig> {
+        self.base.as_ref().and_then(|base| base.exit_config())
+    }
+
+    fn multi_timeframe_config(&self) -> Option<MultiTimeframeConfig> {
+        self.base
+            .as_ref()
+            .and_then(|base| base.multi_timeframe_config())
+    }
+
+    async fn on_multi_timeframe_data(
+        &mut self,
+        primary_data: &MarketData,
+        secondary_data: &HashMap<Timeframe, Vec<Kline>>,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let base_signals = match self.base.as_mut() {
+            Some(base) => {
+                base.on_multi_timeframe_data(primary_data, secondary_data)
+                    .await?
+            }
+            None => return Ok(vec![]),
+        };
+        Ok(self.apply_overlay(primary_data, base_signals))
+    }
+
+    fn schedule(&self) -> Option<StrategySchedule> {
+        self.base.as_ref().and_then(|base| base.schedule())
+    }
+
+    async fn on_schedule(
+        &mut self,
+        event: &ScheduleEvent,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        match self.base.as_mut() {
+            Some(base) => base.on_schedule(event).await,
+            None => Ok(vec![]),
+        }
+    }
+
+    fn take_decision_traces(&mut self) -> Vec<DecisionTrace> {
+        self.base
+            .as_mut()
+            .map(|base| base.take_decision_traces())
+            .unwrap_or_default()
+    }
+
+    fn get_state(&self) -> Value {
+        json!({
+            "initialized": self.config.is_some(),
+            "base_strategy": self.config.as_ref().map(|c| c.base_strategy.clone()),
+            "mode": self.config.as_ref().map(|c| c.mode),
+            "holdings": self.holdings,
+            "options": self.options,
+            "options_opened": self.options_opened,
+            "options_closed": self.options_closed,
+            "base": self.base.as_ref().map(|base| base.get_state()),
+        })
+    }
+
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let state = SavedState {
+            base: match self.base.as_ref() {
+                Some(base) => base.save_state()?,
+                None => Vec::new(),
+            },
+            options: self.options.clone(),
+        };
+        Ok(serde_json::to_vec(&state)?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let state: SavedState = serde_json::from_slice(data)?;
+        if let (Some(base), false) = (self.base.as_mut(), state.base.is_empty()) {
+            base.load_state(&state.base)?;
+        }
+        self.options = state.options;
+        Ok(())
+    }
+}
+
+// ================================================================================================
+// 전략 레지스트리 등록
+// ================================================================================================
+
+use crate::register_strategy;
+
+register_strategy! {
+    id: "option_overlay",
+    aliases: ["covered_call", "protective_put"],
+    name: "옵션 오버레이",
+    description: "자산배분 등 기초 전략의 보유 종목에 커버드콜 또는 보호적 풋을 얹습니다.",
+    timeframe: "1d",
+    tickers: [],
+    category: Daily,
+    markets: [Stock, Options],
+    type: OptionOverlayStrategy
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use uuid::Uuid;
+
+    use super::*;
+
+    /// 종가가 `exit_below` 미만이면 청산 신호를 내는 테스트 기초 전략.
+    struct HoldStrategy {
+        exit_below: Option<Decimal>,
+        positions: usize,
+    }
+
+    #[async_trait]
+    impl Strategy for HoldStrategy {
+        fn name(&self) -> &str {
+            "Hold"
+        }
+
+        fn version(&self) -> &str {
+            "0.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "테스트용 보유 전략"
+        }
+
+        async fn initialize(
+            &mut self,
+            config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.exit_below = serde_json::from_value(config["exit_below"].clone())?;
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            let MarketDataType::Kline(kline) = &data.data else {
+                return Ok(vec![]);
+            };
+            Ok(self
+                .exit_below
+                .filter(|level| kline.close < *level)
+                .map(|_| Signal::exit("hold", data.ticker.clone(), Side::Sell))
+                .into_iter()
+                .collect())
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.positions += 1;
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            json!({ "positions": self.positions })
+        }
+    }
+
+    impl HoldStrategy {
+        fn new() -> Self {
+            Self {
+                exit_below: None,
+                positions: 0,
+            }
+        }
+    }
+
+    register_strategy! {
+        id: "test_overlay_hold",
+        aliases: [],
+        name: "테스트 보유 전략",
+        description: "옵션 오버레이 테스트용",
+        timeframe: "1d",
+        tickers: [],
+        category: Daily,
+        markets: [Stock],
+        type: HoldStrategy
+    }
+
+    fn bar(ticker: &str, at: DateTime<Utc>, close: Decimal) -> MarketData {
+        let kline = Kline::new(
+            ticker.to_string(),
+            Timeframe::D1,
+            at,
+            close,
+            close,
+            close,
+            close,
+            dec!(1000),
+            at,
+        );
+        MarketData::from_kline("test", kline)
+    }
+
+    fn position(ticker: &str, quantity: Decimal) -> Position {
+        let now = Utc::now();
+        Position {
+            id: Uuid::new_v4(),
+            exchange: "test".to_string(),
+            ticker: ticker.to_string(),
+            side: Side::Buy,
+            quantity,
+            entry_price: dec!(100),
+            current_price: dec!(100),
+            unrealized_pnl: Decimal::ZERO,
+            realized_pnl: Decimal::ZERO,
+            strategy_id: None,
+            opened_at: now,
+            updated_at: now,
+            closed_at: None,
+            metadata: Value::Null,
+        }
+    }
+
+    async fn overlay(config: Value) -> OptionOverlayStrategy {
+        let mut strategy = OptionOverlayStrategy::new();
+        strategy.initialize(config).await.unwrap();
+        strategy
+    }
+
+    #[tokio::test]
+    async fn test_covered_call_opens_and_rolls_at_expiry() {
+        let mut strategy = overlay(json!({
+            "base_strategy": "test_overlay_hold",
+            "underlyings": ["SPY"],
+            "otm_pct": 0.05,
+            "strike_step": 5,
+        }))
+        .await;
+
+        // 보유 전에는 옵션 진입 없음
+        let day1 = Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap();
+        assert!(strategy
+            .on_market_data(&bar("SPY", day1, dec!(441)))
+            .await
+            .unwrap()
+            .is_empty());
+
+        // 250주 보유 → 2계약 (200주) 커버드콜
+        strategy
+            .on_position_update(&position("SPY", dec!(250)))
+            .await
+            .unwrap();
+        let signals = strategy
+            .on_market_data(&bar("SPY", day1, dec!(441)))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+        let call = &signals[0];
+        let contract = call.option_contract().unwrap();
+        assert_eq!(
+            (call.side, call.signal_type),
+            (Side::Sell, SignalType::Entry)
+        );
+        assert_eq!(contract.right, OptionRight::Call);
+        assert_eq!(contract.strike, dec!(465)); // 441 × 1.05 = 463.05 → 465
+                                                // 10월물(10/16)은 잔존 15일 → 11월물
+        assert_eq!(
+            contract.expiry,
+            NaiveDate::from_ymd_opt(2026, 11, 20).unwrap()
+        );
+        assert_eq!(call.fixed_quantity(), Some(dec!(200)));
+
+        // 옵션 포지션 갱신은 기초 전략에 전달하지 않음
+        strategy
+            .on_position_update(&position(&contract.ticker(), dec!(200)))
+            .await
+            .unwrap();
+        assert_eq!(strategy.get_state()["base"]["positions"], json!(1));
+
+        // 만기일: 청산 후 다음 월물로 롤오버
+        let expiry = Utc.with_ymd_and_hms(2026, 11, 20, 0, 0, 0).unwrap();
+        let signals = strategy
+            .on_market_data(&bar("SPY", expiry, dec!(470)))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 2);
+        assert_eq!(signals[0].signal_type, SignalType::Exit);
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(signals[0].metadata["reason"], json!("expiry_roll"));
+        let rolled = signals[1].option_contract().unwrap();
+        assert_eq!(
+            rolled.expiry,
+            NaiveDate::from_ymd_opt(2026, 12, 18).unwrap()
+        );
+        assert_eq!(rolled.strike, dec!(495)); // 470 × 1.05 = 493.5 → 495
+    }
+
+    #[tokio::test]
+    async fn test_closes_option_when_underlying_sold() {
+        let mut strategy = overlay(json!({
+            "base_strategy": "test_overlay_hold",
+            "base_config": { "exit_below": 90 },
+            "mode": "protective_put",
+        }))
+        .await;
+        strategy
+            .on_position_update(&position("005930", dec!(100)))
+            .await
+            .unwrap();
+
+        let day1 = Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap();
+        let signals = strategy
+            .on_market_data(&bar("005930", day1, dec!(100)))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(signals[0].option_contract().unwrap().strike, dec!(95.00));
+
+        // 기초 전략 청산 → 풋 매도 청산 + 기초 신호 그대로 전달
+        let day2 = Utc.with_ymd_and_hms(2026, 10, 2, 0, 0, 0).unwrap();
+        let signals = strategy
+            .on_market_data(&bar("005930", day2, dec!(85)))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 2);
+        assert_eq!(signals[0].side, Side::Sell);
+        assert_eq!(signals[0].metadata["reason"], json!("underlying_reduced"));
+        assert_eq!(signals[1].strategy_id, "hold");
+        assert_eq!(strategy.open_options().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_config_rejected() {
+        for config in [
+            json!({ "base_strategy": "option_overlay" }),
+            json!({ "base_strategy": "no_such_strategy" }),
+            json!({ "base_strategy": "test_overlay_hold", "coverage_ratio": 1.5 }),
+            json!({ "base_strategy": "test_overlay_hold", "multiplier": 0 }),
+        ] {
+            let mut strategy = OptionOverlayStrategy::new();
+            assert!(strategy.initialize(config).await.is_err());
+        }
+    }
+}
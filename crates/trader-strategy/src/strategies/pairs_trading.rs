@@ -0,0 +1,893 @@
+//! Pairs Trading (통계적 차익거래) 전략
+//!
+//! ## 핵심 아이디어
+//!
+//! 공적분 관계에 있는 두 종목의 스프레드(`A - β·B - α`)가
+//! 평균에서 크게 벗어나면 스프레드를 매매하고, 평균으로 회귀하면 청산합니다.
+//!
+//! ## 진입/청산 조건
+//!
+//! 1. **공적분 검정**: Engle-Granger 검정 통과 + 반감기 상한 이내일 때만 진입
+//! 2. **스프레드 매수**: Z-Score ≤ -entry_z → A 매수 + B 매도
+//! 3. **스프레드 매도**: Z-Score ≥ entry_z → A 매도 + B 매수
+//! 4. **청산**: |Z-Score| ≤ exit_z (평균회귀), |Z-Score| ≥ stop_z (손절), 공적분 소멸
+//!
+//! ## 헤지 비율
+//!
+//! - `ols`: 최근 lookback 구간 OLS 추정 (재검정 주기마다 갱신)
+//! - `kalman`: 칼만 필터로 매 봉 갱신 (OLS 추정치로 초기화)
+//!
+//! 두 레그는 `Signal::with_legs`로 묶여 Executor에서 함께 체결되거나 함께 거부됩니다.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::sync::RwLock;
+use tracing::{debug, info};
+use trader_core::{
+    domain::StrategyContext, engle_granger, spread_series, zscore, KalmanHedgeRatio, MarketData,
+    MarketDataType, Order, Position, Side, Signal, SignalType, Significance,
+};
+use trader_strategy_macro::StrategyConfig;
+
+use crate::Strategy;
+
+// ============================================================================
+// 설정 (Config)
+// ============================================================================
+
+/// 헤지 비율 추정 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HedgeMethod {
+    /// 구간 OLS
+    #[default]
+    Ols,
+    /// 칼만 필터 (시변 헤지 비율)
+    Kalman,
+}
+
+/// 페어 트레이딩 전략 설정
+#[derive(Debug, Clone, Serialize, Deserialize, StrategyConfig)]
+#[strategy(
+    id = "pairs_trading",
+    name = "페어 트레이딩",
+    description = "공적분 페어의 스프레드 Z-Score 기반 통계적 차익거래 전략",
+    category = "Daily"
+)]
+pub struct PairsTradingConfig {
+    /// 종속 종목 (A, 스프레드의 y)
+    #[serde(default = "default_ticker_a")]
+    #[schema(
+        label = "종목 A",
+        field_type = "symbol",
+        default = "005930",
+        section = "asset"
+    )]
+    pub ticker_a: String,
+
+    /// 헤지 종목 (B, 스프레드의 x)
+    #[serde(default = "default_ticker_b")]
+    #[schema(
+        label = "종목 B",
+        field_type = "symbol",
+        default = "000660",
+        section = "asset"
+    )]
+    pub ticker_b: String,
+
+    /// 헤지 비율 추정 방식
+    #[serde(default)]
+    #[schema(label = "헤지 비율 추정", field_type = "select", options = ["ols", "kalman"], default = "ols", section = "indicator")]
+    pub hedge_method: HedgeMethod,
+
+    /// 공적분/Z-Score 계산 기간 (기본: 60봉)
+    #[serde(default = "default_lookback")]
+    #[schema(
+        label = "계산 기간 (봉)",
+        min = 30,
+        max = 500,
+        default = 60,
+        section = "indicator"
+    )]
+    pub lookback: usize,
+
+    /// 진입 Z-Score (기본: 2.0)
+    #[serde(default = "default_entry_z")]
+    #[schema(
+        label = "진입 Z-Score",
+        min = 0.5,
+        max = 5,
+        default = 2.0,
+        section = "indicator"
+    )]
+    pub entry_z: f64,
+
+    /// 청산 Z-Score (기본: 0.5)
+    #[serde(default = "default_exit_z")]
+    #[schema(
+        label = "청산 Z-Score",
+        min = 0,
+        max = 3,
+        default = 0.5,
+        section = "indicator"
+    )]
+    pub exit_z: f64,
+
+    /// 손절 Z-Score (기본: 4.0)
+    #[serde(default = "default_stop_z")]
+    #[schema(
+        label = "손절 Z-Score",
+        min = 1,
+        max = 10,
+        default = 4.0,
+        section = "risk"
+    )]
+    pub stop_z: f64,
+
+    /// 공적분 유의수준
+    #[serde(default)]
+    #[schema(label = "유의수준", field_type = "select", options = ["one_percent", "five_percent", "ten_percent"], default = "five_percent", section = "filter")]
+    pub significance: Significance,
+
+    /// 최대 반감기 (봉, 기본: 30)
+    #[serde(default = "default_max_half_life")]
+    #[schema(
+        label = "최대 반감기 (봉)",
+        min = 1,
+        max = 250,
+        default = 30,
+        section = "filter"
+    )]
+    pub max_half_life: f64,
+
+    /// 공적분 재검정 주기 (봉, 기본: 20)
+    #[serde(default = "default_recheck_interval")]
+    #[schema(
+        label = "재검정 주기 (봉)",
+        min = 1,
+        max = 250,
+        default = 20,
+        section = "filter"
+    )]
+    pub recheck_interval: usize,
+
+    /// 칼만 필터 상태 잡음 비율 (기본: 0.0001)
+    #[serde(default = "default_kalman_delta")]
+    #[schema(
+        label = "칼만 상태 잡음",
+        min = 0.000001,
+        max = 0.1,
+        default = 0.0001,
+        section = "indicator"
+    )]
+    pub kalman_delta: f64,
+
+    /// 칼만 필터 관측 잡음 분산 (기본: 0.001)
+    #[serde(default = "default_kalman_observation_variance")]
+    #[schema(
+        label = "칼만 관측 잡음",
+        min = 0.000001,
+        max = 10,
+        default = 0.001,
+        section = "indicator"
+    )]
+    pub kalman_observation_variance: f64,
+}
+
+fn default_ticker_a() -> String {
+    "005930".to_string()
+}
+fn default_ticker_b() -> String {
+    "000660".to_string()
+}
+fn default_lookback() -> usize {
+    60
+}
+fn default_entry_z() -> f64 {
+    2.0
+}
+fn default_exit_z() -> f64 {
+    0.5
+}
+fn default_stop_z() -> f64 {
+    4.0
+}
+fn default_max_half_life() -> f64 {
+    30.0
+}
+fn default_recheck_interval() -> usize {
+    20
+}
+fn default_kalman_delta() -> f64 {
+    1e-4
+}
+fn default_kalman_observation_variance() -> f64 {
+    1e-3
+}
+
+impl Default for PairsTradingConfig {
+    fn default() -> Self {
+        Self {
+            ticker_a: default_ticker_a(),
+            ticker_b: default_ticker_b(),
+            hedge_method: HedgeMethod::default(),
+            lookback: default_lookback(),
+            entry_z: default_entry_z(),
+            exit_z: default_exit_z(),
+            stop_z: default_stop_z(),
+            significance: Significance::default(),
+            max_half_life: default_max_half_life(),
+            recheck_interval: default_recheck_interval(),
+            kalman_delta: default_kalman_delta(),
+            kalman_observation_variance: default_kalman_observation_variance(),
+        }
+    }
+}
+
+// ============================================================================
+// 전략 상태
+// ============================================================================
+
+/// 스프레드 포지션.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SpreadPosition {
+    /// 포지션 없음
+    #[default]
+    Flat,
+    /// 스프레드 매수 (A 매수 + B 매도)
+    Long,
+    /// 스프레드 매도 (A 매도 + B 매수)
+    Short,
+}
+
+/// 전략 상태
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PairsTradingState {
+    /// 현재 스프레드 포지션
+    pub position: SpreadPosition,
+    /// 최근 공적분 검정 통과 여부
+    pub cointegrated: bool,
+    /// 헤지 비율 (β)
+    pub hedge_ratio: Option<f64>,
+    /// 절편 (α)
+    pub intercept: Option<f64>,
+    /// 스프레드 반감기 (봉)
+    pub half_life: Option<f64>,
+    /// 최근 ADF 통계량
+    pub adf_statistic: Option<f64>,
+    /// 최근 Z-Score
+    pub last_zscore: Option<f64>,
+    /// 마지막 검정 이후 경과 봉 수
+    pub bars_since_test: usize,
+    /// 레그 주문 횟수 (진입 + 청산)
+    pub trades_count: u32,
+}
+
+// ============================================================================
+// 전략 구현
+// ============================================================================
+
+/// Pairs Trading Strategy
+pub struct PairsTradingStrategy {
+    config: Option<PairsTradingConfig>,
+    state: PairsTradingState,
+    /// 정렬된 종가 이력 (시각, A, B)
+    history: VecDeque<(DateTime<Utc>, f64, f64)>,
+    /// 한쪽 레그만 도착한 봉 (시각 → (A, B))
+    pending: BTreeMap<DateTime<Utc>, (Option<Decimal>, Option<Decimal>)>,
+    /// 칼만 필터 (hedge_method = kalman)
+    kalman: Option<KalmanHedgeRatio>,
+    context: Option<Arc<RwLock<StrategyContext>>>,
+}
+
+impl PairsTradingStrategy {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            state: PairsTradingState::default(),
+            history: VecDeque::new(),
+            pending: BTreeMap::new(),
+            kalman: None,
+            context: None,
+        }
+    }
+
+    /// 시장 데이터 티커가 설정 티커와 일치하는지 (예: "005930/KRW" ↔ "005930")
+    fn matches_ticker(data_ticker: &str, ticker: &str) -> bool {
+        data_ticker == ticker || data_ticker.split('/').next() == Some(ticker)
+    }
+
+    /// 레그 종가를 기록하고, 두 레그가 모두 도착하면 정렬된 봉을 반환합니다.
+    fn align(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        is_a: bool,
+        close: Decimal,
+    ) -> Option<(Decimal, Decimal)> {
+        // 이미 처리한 시각 이전 데이터는 무시
+        if self
+            .history
+            .back()
+            .is_some_and(|(last, _, _)| timestamp <= *last)
+        {
+            return None;
+        }
+
+        let entry = self.pending.entry(timestamp).or_default();
+        if is_a {
+            entry.0 = Some(close);
+        } else {
+            entry.1 = Some(close);
+        }
+
+        let (Some(a), Some(b)) = *entry else {
+            return None;
+        };
+
+        // 정렬된 시각 이전의 짝 없는 봉은 폐기
+        self.pending = self.pending.split_off(&timestamp);
+        self.pending.remove(&timestamp);
+        Some((a, b))
+    }
+
+    /// Engle-Granger 재검정.
+    fn run_cointegration_test(&mut self, config: &PairsTradingConfig, y: &[f64], x: &[f64]) {
+        self.state.bars_since_test = 0;
+
+        let Some(result) = engle_granger(y, x, None) else {
+            self.state.cointegrated = false;
+            return;
+        };
+
+        self.state.cointegrated = result.is_cointegrated(config.significance)
+            && result
+                .half_life
+                .is_some_and(|hl| hl <= config.max_half_life);
+        self.state.adf_statistic = Some(result.adf.statistic);
+        self.state.half_life = result.half_life;
+
+        match config.hedge_method {
+            HedgeMethod::Ols => {
+                self.state.hedge_ratio = Some(result.hedge_ratio);
+                self.state.intercept = Some(result.intercept);
+            }
+            HedgeMethod::Kalman => {
+                if self.kalman.is_none() {
+                    self.kalman = Some(
+                        KalmanHedgeRatio::new(
+                            config.kalman_delta,
+                            config.kalman_observation_variance,
+                        )
+                        .with_initial_state(result.hedge_ratio, result.intercept),
+                    );
+                }
+            }
+        }
+
+        debug!(
+            ticker_a = %config.ticker_a,
+            ticker_b = %config.ticker_b,
+            adf = result.adf.statistic,
+            critical = result.adf.critical_values.at(config.significance),
+            half_life = ?result.half_life,
+            cointegrated = self.state.cointegrated,
+            "공적분 재검정"
+        );
+    }
+
+    /// 현재 스프레드 Z-Score 계산 (헤지 비율 갱신 포함).
+    fn current_zscore(&mut self, y: &[f64], x: &[f64]) -> Option<f64> {
+        match self.kalman.as_mut() {
+            Some(filter) => {
+                let estimate = filter.update(*x.last()?, *y.last()?);
+                self.state.hedge_ratio = Some(estimate.hedge_ratio);
+                self.state.intercept = Some(estimate.intercept);
+                estimate.zscore()
+            }
+            None => {
+                let spread = spread_series(y, x, self.state.hedge_ratio?, self.state.intercept?);
+                zscore(&spread)
+            }
+        }
+    }
+
+    /// 정렬된 봉으로 신호를 평가합니다.
+    fn evaluate(
+        &mut self,
+        config: &PairsTradingConfig,
+        timestamp: DateTime<Utc>,
+        price_a: Decimal,
+        price_b: Decimal,
+    ) -> Vec<Signal> {
+        let (Some(a), Some(b)) = (price_a.to_f64(), price_b.to_f64()) else {
+            return vec![];
+        };
+        self.history.push_back((timestamp, a, b));
+        while self.history.len() > config.lookback {
+            self.history.pop_front();
+        }
+        if self.history.len() < config.lookback {
+            return vec![];
+        }
+
+        let y: Vec<f64> = self.history.iter().map(|(_, a, _)| *a).collect();
+        let x: Vec<f64> = self.history.iter().map(|(_, _, b)| *b).collect();
+
+        if self.state.adf_statistic.is_none()
+            || self.state.bars_since_test >= config.recheck_interval
+        {
+            self.run_cointegration_test(config, &y, &x);
+        }
+        self.state.bars_since_test += 1;
+
+        let Some(z) = self.current_zscore(&y, &x) else {
+            return vec![];
+        };
+        self.state.last_zscore = Some(z);
+
+        match self.state.position {
+            SpreadPosition::Flat => {
+                if !self.state.cointegrated {
+                    return vec![];
+                }
+                let target = if z <= -config.entry_z {
+                    SpreadPosition::Long
+                } else if z >= config.entry_z {
+                    SpreadPosition::Short
+                } else {
+                    return vec![];
+                };
+
+                info!(
+                    ticker_a = %config.ticker_a,
+                    ticker_b = %config.ticker_b,
+                    zscore = z,
+                    position = ?target,
+                    "스프레드 진입"
+                );
+                self.state.position = target;
+                self.leg_signals(
+                    config,
+                    SignalType::Entry,
+                    target,
+                    price_a,
+                    price_b,
+                    z,
+                    "entry",
+                )
+            }
+            held => {
+                // 스프레드 매수는 z가 음수 방향, 매도는 양수 방향으로 벌어진 상태
+                let signed = if held == SpreadPosition::Long { -z } else { z };
+                let reason = if signed <= config.exit_z {
+                    "mean_reverted"
+                } else if signed >= config.stop_z {
+                    "stop_loss"
+                } else if !self.state.cointegrated {
+                    "cointegration_lost"
+                } else {
+                    return vec![];
+                };
+
+                info!(
+                    ticker_a = %config.ticker_a,
+                    ticker_b = %config.ticker_b,
+                    zscore = z,
+                    reason = reason,
+                    "스프레드 청산"
+                );
+                self.state.position = SpreadPosition::Flat;
+                self.leg_signals(config, SignalType::Exit, held, price_a, price_b, z, reason)
+            }
+        }
+    }
+
+    /// 두 레그 신호 생성.
+    ///
+    /// 레그 강도는 명목금액 비율(`A : |β|·B`)로 설정하여 헤지 비율을 반영합니다.
+    #[allow(clippy::too_many_arguments)]
+    fn leg_signals(
+        &mut self,
+        config: &PairsTradingConfig,
+        signal_type: SignalType,
+        spread: SpreadPosition,
+        price_a: Decimal,
+        price_b: Decimal,
+        z: f64,
+        reason: &str,
+    ) -> Vec<Signal> {
+        let hedge_ratio = self.state.hedge_ratio.unwrap_or(1.0);
+        let notional_a = price_a.to_f64().unwrap_or(0.0);
+        let notional_b = hedge_ratio.abs() * price_b.to_f64().unwrap_or(0.0);
+        let larger = notional_a.max(notional_b);
+        let (strength_a, strength_b) = if larger > 0.0 {
+            (notional_a / larger, notional_b / larger)
+        } else {
+            (1.0, 1.0)
+        };
+
+        // 스프레드 매수 = A 매수 + B 매도, 청산은 반대 방향
+        let side_a = match (spread, signal_type) {
+            (SpreadPosition::Long, SignalType::Entry)
+            | (SpreadPosition::Short, SignalType::Exit) => Side::Buy,
+            _ => Side::Sell,
+        };
+
+        self.state.trades_count += 1;
+        let group_id = format!(
+            "pairs:{}:{}:{}",
+            config.ticker_a, config.ticker_b, self.state.trades_count
+        );
+
+        let leg = |ticker: &str, side: Side, price: Decimal, strength: f64, role: &str| {
+            Signal::new("pairs_trading", ticker.to_string(), side, signal_type)
+                .with_strength(strength)
+                .with_prices(Some(price), None, None)
+                .with_legs(group_id.clone(), 2)
+                .with_metadata("reason", json!(reason))
+                .with_metadata("leg", json!(role))
+                .with_metadata("zscore", json!(z))
+                .with_metadata("hedge_ratio", json!(hedge_ratio))
+                .with_metadata("spread_position", json!(spread))
+        };
+
+        vec![
+            leg(&config.ticker_a, side_a, price_a, strength_a, "a"),
+            leg(
+                &config.ticker_b,
+                side_a.opposite(),
+                price_b,
+                strength_b,
+                "b",
+            ),
+        ]
+    }
+}
+
+impl Default for PairsTradingStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// Strategy Trait 구현
+// ============================================================================
+
+#[async_trait]
+impl Strategy for PairsTradingStrategy {
+    fn name(&self) -> &str {
+        "PairsTrading"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "공적분 페어 스프레드 통계적 차익거래 전략"
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let cfg: PairsTradingConfig = serde_json::from_value(config)?;
+
+        if cfg.ticker_a == cfg.ticker_b {
+            return Err("페어의 두 종목이 같습니다".into());
+        }
+        if cfg.exit_z >= cfg.entry_z || cfg.entry_z >= cfg.stop_z {
+            return Err("Z-Score 기준은 exit_z < entry_z < stop_z 이어야 합니다".into());
+        }
+
+        info!(
+            ticker_a = %cfg.ticker_a,
+            ticker_b = %cfg.ticker_b,
+            hedge_method = ?cfg.hedge_method,
+            lookback = cfg.lookback,
+            "전략 초기화"
+        );
+
+        self.config = Some(cfg);
+        self.state = PairsTradingState::default();
+        self.history.clear();
+        self.pending.clear();
+        self.kalman = None;
+
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let config = match &self.config {
+            Some(c) => c.clone(),
+            None => return Ok(vec![]),
+        };
+
+        let is_a = if Self::matches_ticker(&data.ticker, &config.ticker_a) {
+            true
+        } else if Self::matches_ticker(&data.ticker, &config.ticker_b) {
+            false
+        } else {
+            return Ok(vec![]);
+        };
+
+        let MarketDataType::Kline(kline) = &data.data else {
+            return Ok(vec![]);
+        };
+
+        let Some((price_a, price_b)) = self.align(kline.open_time, is_a, kline.close) else {
+            return Ok(vec![]);
+        };
+
+        Ok(self.evaluate(&config, kline.open_time, price_a, price_b))
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        info!(
+            ticker = %order.ticker,
+            side = ?order.side,
+            qty = %order.quantity,
+            "주문 체결"
+        );
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let Some(config) = &self.config else {
+            return Ok(());
+        };
+
+        // 레그 그룹이 거부되어 포지션이 없으면 스프레드 상태 초기화
+        let is_leg = Self::matches_ticker(&position.ticker, &config.ticker_a)
+            || Self::matches_ticker(&position.ticker, &config.ticker_b);
+        if is_leg && position.quantity.is_zero() && self.state.position != SpreadPosition::Flat {
+            debug!(ticker = %position.ticker, "레그 포지션 없음 - 스프레드 상태 초기화");
+            self.state.position = SpreadPosition::Flat;
+        }
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        info!(trades = self.state.trades_count, "전략 종료");
+        Ok(())
+    }
+
+    fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
+        self.context = Some(context);
+        debug!("StrategyContext 주입 완료");
+    }
+
+    fn get_state(&self) -> Value {
+        json!({
+            "config": self.config,
+            "state": self.state,
+            "history_len": self.history.len(),
+            "has_context": self.context.is_some(),
+        })
+    }
+}
+
+// ============================================================================
+// 레지스트리 등록
+// ============================================================================
+
+use crate::register_strategy;
+
+register_strategy! {
+    id: "pairs_trading",
+    aliases: ["stat_arb"],
+    name: "Pairs Trading",
+    description: "공적분 페어 트레이딩 - 스프레드 Z-Score 진입/청산, 두 레그 동시 체결",
+    timeframe: "1d",
+    tickers: ["005930", "000660"],
+    category: Daily,
+    markets: [Stock],
+    type: PairsTradingStrategy,
+    config: PairsTradingConfig
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+    use trader_core::{Kline, Timeframe};
+
+    use super::*;
+
+    fn kline(ticker: &str, day: i64, close: f64) -> MarketData {
+        let open_time = DateTime::from_timestamp(1_700_000_000, 0).unwrap() + Duration::days(day);
+        let close = Decimal::from_f64_retain(close).unwrap();
+        let kline = Kline::new(
+            ticker.to_string(),
+            Timeframe::D1,
+            open_time,
+            close,
+            close,
+            close,
+            close,
+            dec!(1000),
+            open_time + Duration::days(1),
+        );
+        MarketData::from_kline("test", kline)
+    }
+
+    /// B는 랜덤워크, A = 2·B + 10 + AR(1) 평균회귀 잡음.
+    fn pair_prices(days: usize) -> Vec<(f64, f64)> {
+        let mut seed: u64 = 42;
+        let mut uniform = move || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+        };
+
+        let (mut b, mut noise) = (100.0, 0.0);
+        (0..days)
+            .map(|_| {
+                b += uniform() * 2.0;
+                noise = 0.7 * noise + uniform() * 3.0;
+                (2.0 * b + 10.0 + noise, b)
+            })
+            .collect()
+    }
+
+    async fn strategy_with(config: Value) -> PairsTradingStrategy {
+        let mut strategy = PairsTradingStrategy::new();
+        strategy.initialize(config).await.unwrap();
+        strategy
+    }
+
+    #[test]
+    fn test_config_default() {
+        let config = PairsTradingConfig::default();
+        assert_eq!(config.hedge_method, HedgeMethod::Ols);
+        assert_eq!(config.lookback, 60);
+        assert_eq!(config.significance, Significance::FivePercent);
+        assert!(config.exit_z < config.entry_z && config.entry_z < config.stop_z);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_thresholds_rejected() {
+        let mut strategy = PairsTradingStrategy::new();
+        let result = strategy
+            .initialize(json!({ "entry_z": 1.0, "exit_z": 1.5 }))
+            .await;
+        assert!(result.is_err());
+
+        let result = strategy
+            .initialize(json!({ "ticker_a": "KO", "ticker_b": "KO" }))
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_legs_aligned_by_timestamp() {
+        let mut strategy =
+            strategy_with(json!({ "ticker_a": "KO", "ticker_b": "PEP", "lookback": 30 })).await;
+
+        // 한쪽 레그만 도착하면 대기
+        strategy
+            .on_market_data(&kline("KO", 0, 60.0))
+            .await
+            .unwrap();
+        assert!(strategy.history.is_empty());
+
+        // 다른 종목은 무시
+        strategy
+            .on_market_data(&kline("MSFT", 0, 300.0))
+            .await
+            .unwrap();
+        assert!(strategy.history.is_empty());
+
+        strategy
+            .on_market_data(&kline("PEP/USD", 0, 170.0))
+            .await
+            .unwrap();
+        assert_eq!(strategy.history.len(), 1);
+        assert!(strategy.pending.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_emits_paired_legs_and_exits_together() {
+        let mut strategy = strategy_with(json!({
+            "ticker_a": "KO",
+            "ticker_b": "PEP",
+            "lookback": 60,
+            "entry_z": 1.5,
+            "exit_z": 0.3,
+            "stop_z": 6.0
+        }))
+        .await;
+
+        let mut entries = Vec::new();
+        let mut exits = Vec::new();
+        for (day, (a, b)) in pair_prices(200).into_iter().enumerate() {
+            let day = day as i64;
+            strategy.on_market_data(&kline("KO", day, a)).await.unwrap();
+            let signals = strategy
+                .on_market_data(&kline("PEP", day, b))
+                .await
+                .unwrap();
+            for signal in signals {
+                if signal.is_entry() {
+                    entries.push(signal);
+                } else {
+                    exits.push(signal);
+                }
+            }
+        }
+
+        assert!(strategy.state.cointegrated);
+        let hedge = strategy.state.hedge_ratio.unwrap();
+        assert!((hedge - 2.0).abs() < 0.2, "hedge ratio {}", hedge);
+
+        assert!(!entries.is_empty());
+        assert!(!exits.is_empty());
+        for legs in entries.chunks(2).chain(exits.chunks(2)) {
+            assert_eq!(legs.len(), 2);
+            assert_eq!(legs[0].group_id, legs[1].group_id);
+            assert_eq!(legs[0].leg_count(), Some(2));
+            assert_eq!(legs[0].side, legs[1].side.opposite());
+            assert_eq!(legs[0].ticker, "KO");
+            assert_eq!(legs[1].ticker, "PEP");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_kalman_hedge_ratio() {
+        let mut strategy = strategy_with(json!({
+            "ticker_a": "KO",
+            "ticker_b": "PEP",
+            "hedge_method": "kalman",
+            "lookback": 60
+        }))
+        .await;
+
+        for (day, (a, b)) in pair_prices(120).into_iter().enumerate() {
+            let day = day as i64;
+            strategy.on_market_data(&kline("KO", day, a)).await.unwrap();
+            strategy
+                .on_market_data(&kline("PEP", day, b))
+                .await
+                .unwrap();
+        }
+
+        assert!(strategy.kalman.is_some());
+        let hedge = strategy.state.hedge_ratio.unwrap();
+        assert!((hedge - 2.0).abs() < 0.3, "hedge ratio {}", hedge);
+        assert!(strategy.state.last_zscore.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_rejected_group_resets_position() {
+        let mut strategy = strategy_with(json!({ "ticker_a": "KO", "ticker_b": "PEP" })).await;
+        strategy.state.position = SpreadPosition::Long;
+
+        let position = Position::new("test", "KO".to_string(), Side::Buy, Decimal::ZERO, dec!(60));
+        strategy.on_position_update(&position).await.unwrap();
+        assert_eq!(strategy.state.position, SpreadPosition::Flat);
+    }
+}
@@ -13,6 +13,8 @@
 //!
 //! ## 월간 리밸런싱 로직
 //!
+//! 리밸런싱 시점은 월초 일정 이벤트(국내 거래 캘린더의 월 첫 거래일)로 결정합니다.
+//!
 //! 1. 각 자산의 모멘텀 스코어 계산
 //! 2. 평균 모멘텀으로 목표 비중 조절
 //! 3. 남은 현금을 단기자금과 상위 모멘텀 종목에 분배
@@ -21,7 +23,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
-use chrono::{Datelike, Utc};
+use chrono::{DateTime, Datelike, Utc};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
@@ -30,11 +32,12 @@ use tokio::sync::RwLock;
 use tracing::{debug, info};
 use trader_core::{
     domain::{RouteState, StrategyContext},
-    MarketData, MarketDataType, Order, Position, Side, Signal,
+    Country, MarketData, MarketDataType, Order, Position, Side, Signal,
 };
 use trader_strategy_macro::StrategyConfig;
 
 use crate::{
+    schedule::{ScheduleEvent, ScheduleTrigger, StrategySchedule},
     strategies::common::{
         rebalance::{
             PortfolioPosition, RebalanceCalculator, RebalanceConfig, RebalanceOrderSide,
@@ -394,6 +397,8 @@ pub struct PensionBotStrategy {
     config: Option<PensionBotConfig>,
     asset_data: HashMap<String, AssetMomentum>,
     last_rebalance_month: Option<u32>,
+    /// 월초 일정 이벤트 이후 리밸런싱 대기 여부
+    rebalance_due: bool,
     context: Option<Arc<RwLock<StrategyContext>>>,
 }
 
@@ -403,6 +408,7 @@ impl PensionBotStrategy {
             config: None,
             asset_data: HashMap::new(),
             last_rebalance_month: None,
+            rebalance_due: false,
             context: None,
         }
     }
@@ -508,12 +514,9 @@ impl PensionBotStrategy {
             .collect()
     }
 
-    /// 리밸런싱 필요 여부
-    fn should_rebalance(&self, current_month: u32) -> bool {
-        match self.last_rebalance_month {
-            None => true,
-            Some(last) => current_month != last,
-        }
+    /// 리밸런싱 필요 여부 (첫 실행 또는 월초 이벤트 이후)
+    fn should_rebalance(&self) -> bool {
+        self.rebalance_due || self.last_rebalance_month.is_none()
     }
 
     /// 특정 티커에 대해 진입 가능 여부 확인.
@@ -546,7 +549,7 @@ impl PensionBotStrategy {
     }
 
     /// 리밸런싱 시그널 생성
-    fn generate_rebalance_signals(&mut self) -> Vec<Signal> {
+    fn generate_rebalance_signals(&mut self, timestamp: DateTime<Utc>) -> Vec<Signal> {
         let config = match self.config.as_ref() {
             Some(c) => c.clone(),
             None => return Vec::new(),
@@ -614,8 +617,8 @@ impl PensionBotStrategy {
             signals.push(signal);
         }
 
-        let current_month = Utc::now().month();
-        self.last_rebalance_month = Some(current_month);
+        self.last_rebalance_month = Some(timestamp.month());
+        self.rebalance_due = false;
 
         info!(signals = signals.len(), "Pension Bot: 리밸런싱 시그널 생성");
 
@@ -677,9 +680,8 @@ impl Strategy for PensionBotStrategy {
             }
         }
 
-        // 월간 리밸런싱 체크
-        let current_month = timestamp.month();
-        if !self.should_rebalance(current_month) {
+        // 월간 리밸런싱 체크 (월초 일정 이벤트로 설정)
+        if !self.should_rebalance() {
             return Ok(vec![]);
         }
 
@@ -690,10 +692,25 @@ impl Strategy for PensionBotStrategy {
         }
 
         // 리밸런싱 시그널 생성
-        let signals = self.generate_rebalance_signals();
+        let signals = self.generate_rebalance_signals(timestamp);
         Ok(signals)
     }
 
+    fn schedule(&self) -> Option<StrategySchedule> {
+        Some(StrategySchedule::new(Country::KR).with_trigger(ScheduleTrigger::MonthStart))
+    }
+
+    async fn on_schedule(
+        &mut self,
+        event: &ScheduleEvent,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        if event.trigger == ScheduleTrigger::MonthStart {
+            debug!(trading_day = %event.trading_day, "Pension Bot: 월초 리밸런싱 예약");
+            self.rebalance_due = true;
+        }
+        Ok(vec![])
+    }
+
     async fn on_order_filled(
         &mut self,
         _order: &Order,
@@ -717,6 +734,7 @@ impl Strategy for PensionBotStrategy {
         json!({
             "name": self.name(),
             "last_rebalance_month": self.last_rebalance_month,
+            "rebalance_due": self.rebalance_due,
             "asset_count": self.asset_data.len(),
             "assets": self.asset_data.values()
                 .map(|m| json!({
@@ -794,7 +812,23 @@ mod tests {
         let strategy = PensionBotStrategy::new();
 
         // 첫 실행에서는 리밸런싱 필요
-        assert!(strategy.should_rebalance(1));
+        assert!(strategy.should_rebalance());
+    }
+
+    #[tokio::test]
+    async fn test_month_start_schedules_rebalance() {
+        let mut strategy = PensionBotStrategy::new();
+        strategy.last_rebalance_month = Some(5);
+        assert!(!strategy.should_rebalance());
+
+        let event = ScheduleEvent {
+            trigger: ScheduleTrigger::MonthStart,
+            timestamp: Utc::now(),
+            trading_day: Utc::now().date_naive(),
+            market_open: true,
+        };
+        assert!(strategy.on_schedule(&event).await.unwrap().is_empty());
+        assert!(strategy.should_rebalance());
     }
 
     #[test]
@@ -47,11 +47,12 @@ use tracing::{debug, info, warn};
 use trader_core::{
     domain::{RouteState, StrategyContext},
     types::Timeframe,
-    MarketData, MarketDataType, Order, Position, Side, Signal, SignalType,
+    Country, MarketData, MarketDataType, Order, Position, Side, Signal, SignalType,
 };
 use trader_strategy_macro::StrategyConfig;
 
 use crate::{
+    schedule::{ScheduleEvent, ScheduleTrigger, StrategySchedule},
     strategies::common::{
         adjust_strength_by_score,
         rebalance::{
@@ -1114,6 +1115,21 @@ struct RankedAsset {
     rank: usize,
 }
 
+/// 체크포인트용 로테이션 상태 (보유 종목, 리밸런싱 이력, 현금).
+#[derive(Debug, Serialize, Deserialize)]
+struct RotationCheckpoint {
+    current_holdings: HashSet<String>,
+    positions: HashMap<String, Decimal>,
+    /// 자산별 보유 수량 (유니버스 데이터는 initialize()에서 재생성)
+    asset_holdings: HashMap<String, Decimal>,
+    last_rebalance: Option<String>,
+    current_day: u32,
+    #[serde(default)]
+    rebalance_due: bool,
+    cash_balance: Decimal,
+    trades_count: u32,
+}
+
 // ============================================================================
 // 로테이션 전략 (Rotation Strategy)
 // ============================================================================
@@ -1143,6 +1159,9 @@ pub struct RotationStrategy {
     /// 현재 날짜 (day of year)
     current_day: u32,
 
+    /// 일정 이벤트로 예약된 리밸런싱 대기 여부
+    rebalance_due: bool,
+
     /// 리밸런싱 계산기
     rebalance_calculator: Option<RebalanceCalculator>,
 
@@ -1169,6 +1188,7 @@ impl RotationStrategy {
             positions: HashMap::new(),
             last_rebalance: None,
             current_day: 0,
+            rebalance_due: false,
             rebalance_calculator: None,
             cash_balance: Decimal::ZERO,
             initialized: false,
@@ -1188,6 +1208,7 @@ impl RotationStrategy {
             positions: HashMap::new(),
             last_rebalance: None,
             current_day: 0,
+            rebalance_due: false,
             rebalance_calculator: Some(RebalanceCalculator::new(rebalance_config)),
             cash_balance: Decimal::ZERO,
             initialized: false,
@@ -1496,35 +1517,27 @@ impl RotationStrategy {
     // 리밸런싱 체크
     // ========================================================================
 
-    /// 리밸런싱 필요 여부 확인.
-    fn should_rebalance(&self, timestamp: DateTime<Utc>) -> bool {
-        let Some(config) = self.config.as_ref() else {
-            return false;
-        };
+    /// 리밸런싱 필요 여부 확인 (첫 실행 또는 일정 이벤트로 예약된 경우).
+    fn should_rebalance(&self) -> bool {
+        self.config.is_some() && (self.rebalance_due || self.last_rebalance.is_none())
+    }
 
-        match &config.rebalance_frequency {
-            RebalanceFrequency::Monthly => {
-                let current_ym = format!("{}_{}", timestamp.year(), timestamp.month());
-                match &self.last_rebalance {
-                    None => true,
-                    Some(last) => last != &current_ym,
-                }
-            }
-            RebalanceFrequency::Days(days) => match &self.last_rebalance {
-                None => true,
-                Some(last) => {
-                    if let Ok(last_day) = last.parse::<u32>() {
-                        let days_passed = if self.current_day >= last_day {
-                            self.current_day - last_day
-                        } else {
-                            365 - last_day + self.current_day
-                        };
-                        days_passed >= *days
+    /// 일수 기반 리밸런싱 주기 도래 여부.
+    fn rebalance_period_elapsed(&self, days: u32) -> bool {
+        match &self.last_rebalance {
+            None => true,
+            Some(last) => {
+                if let Ok(last_day) = last.parse::<u32>() {
+                    let days_passed = if self.current_day >= last_day {
+                        self.current_day - last_day
                     } else {
-                        true
-                    }
+                        365 - last_day + self.current_day
+                    };
+                    days_passed >= days
+                } else {
+                    true
                 }
-            },
+            }
         }
     }
 
@@ -1542,6 +1555,7 @@ impl RotationStrategy {
                 self.last_rebalance = Some(self.current_day.to_string());
             }
         }
+        self.rebalance_due = false;
     }
 
     // ========================================================================
@@ -1589,7 +1603,7 @@ impl RotationStrategy {
             return Vec::new();
         };
 
-        if !self.should_rebalance(timestamp) {
+        if !self.should_rebalance() {
             return Vec::new();
         }
 
@@ -1927,6 +1941,43 @@ impl Strategy for RotationStrategy {
         Ok(vec![])
     }
 
+    fn schedule(&self) -> Option<StrategySchedule> {
+        let config = self.config.as_ref()?;
+        let country = match config.market {
+            MarketType::US => Country::US,
+            MarketType::KR => Country::KR,
+        };
+        let trigger = match config.rebalance_frequency {
+            RebalanceFrequency::Monthly => ScheduleTrigger::MonthStart,
+            RebalanceFrequency::Days(_) => ScheduleTrigger::MarketOpen,
+        };
+        Some(StrategySchedule::new(country).with_trigger(trigger))
+    }
+
+    async fn on_schedule(
+        &mut self,
+        event: &ScheduleEvent,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let Some(config) = self.config.as_ref() else {
+            return Ok(vec![]);
+        };
+
+        let due = match (&config.rebalance_frequency, &event.trigger) {
+            (RebalanceFrequency::Monthly, ScheduleTrigger::MonthStart) => true,
+            (RebalanceFrequency::Days(days), ScheduleTrigger::MarketOpen) => {
+                self.current_day = event.trading_day.ordinal();
+                self.rebalance_period_elapsed(*days)
+            }
+            _ => false,
+        };
+
+        if due {
+            debug!(trading_day = %event.trading_day, "[Rotation] 리밸런싱 예약");
+            self.rebalance_due = true;
+        }
+        Ok(vec![])
+    }
+
     async fn on_order_filled(
         &mut self,
         order: &Order,
@@ -2010,6 +2061,7 @@ impl Strategy for RotationStrategy {
             "holdings_count": self.current_holdings.len(),
             "current_holdings": self.current_holdings.iter().collect::<Vec<_>>(),
             "last_rebalance": self.last_rebalance,
+            "rebalance_due": self.rebalance_due,
             "trades_count": self.trades_count,
             "cash_balance": self.cash_balance.to_string(),
             "positions": self.positions.iter()
@@ -2018,6 +2070,46 @@ impl Strategy for RotationStrategy {
         })
     }
 
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let checkpoint = RotationCheckpoint {
+            current_holdings: self.current_holdings.clone(),
+            positions: self.positions.clone(),
+            asset_holdings: self
+                .asset_data
+                .iter()
+                .filter(|(_, data)| data.holdings > Decimal::ZERO)
+                .map(|(ticker, data)| (ticker.clone(), data.holdings))
+                .collect(),
+            last_rebalance: self.last_rebalance.clone(),
+            current_day: self.current_day,
+            rebalance_due: self.rebalance_due,
+            cash_balance: self.cash_balance,
+            trades_count: self.trades_count,
+        };
+        Ok(serde_json::to_vec(&checkpoint)?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let checkpoint: RotationCheckpoint = serde_json::from_slice(data)?;
+
+        for (ticker, holdings) in &checkpoint.asset_holdings {
+            match self.asset_data.get_mut(ticker) {
+                Some(data) => data.holdings = *holdings,
+                None => warn!(ticker = %ticker, "[Rotation] 유니버스에 없는 보유 종목 복원"),
+            }
+        }
+
+        self.current_holdings = checkpoint.current_holdings;
+        self.positions = checkpoint.positions;
+        self.last_rebalance = checkpoint.last_rebalance;
+        self.current_day = checkpoint.current_day;
+        self.rebalance_due = checkpoint.rebalance_due;
+        self.cash_balance = checkpoint.cash_balance;
+        self.trades_count = checkpoint.trades_count;
+
+        Ok(())
+    }
+
     fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
         self.context = Some(context);
         info!("[Rotation] StrategyContext 주입 완료");
@@ -2159,4 +2251,54 @@ mod tests {
         assert_eq!(MarketType::US.quote_currency(), "USD");
         assert_eq!(MarketType::KR.quote_currency(), "KRW");
     }
+
+    #[tokio::test]
+    async fn test_holdings_survive_restart() {
+        let mut strategy = RotationStrategy::sector_momentum();
+        strategy.initialize(json!({})).await.unwrap();
+        strategy.current_holdings.insert("XLK".to_string());
+        strategy.positions.insert("XLK".to_string(), dec!(12));
+        strategy.asset_data.get_mut("XLK").unwrap().holdings = dec!(12);
+        strategy.last_rebalance = Some("2026_10".to_string());
+        strategy.cash_balance = dec!(5000);
+        strategy.trades_count = 4;
+        let saved = strategy.save_state().unwrap();
+
+        let mut restarted = RotationStrategy::sector_momentum();
+        restarted.initialize(json!({})).await.unwrap();
+        restarted.load_state(&saved).unwrap();
+
+        assert!(restarted.current_holdings.contains("XLK"));
+        assert_eq!(restarted.positions.get("XLK"), Some(&dec!(12)));
+        assert_eq!(restarted.asset_data["XLK"].holdings, dec!(12));
+        assert_eq!(restarted.last_rebalance.as_deref(), Some("2026_10"));
+        assert_eq!(restarted.cash_balance, dec!(5000));
+        assert_eq!(restarted.trades_count, 4);
+    }
+
+    #[tokio::test]
+    async fn test_schedule_marks_rebalance_due() {
+        let mut strategy = RotationStrategy::sector_momentum();
+        strategy.initialize(json!({})).await.unwrap();
+
+        let schedule = strategy.schedule().unwrap();
+        assert_eq!(schedule.country, Country::US);
+        assert_eq!(schedule.triggers, vec![ScheduleTrigger::MonthStart]);
+
+        strategy.last_rebalance = Some("2026_9".to_string());
+        assert!(!strategy.should_rebalance());
+
+        let trading_day = chrono::NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let event = ScheduleEvent {
+            trigger: ScheduleTrigger::MonthStart,
+            timestamp: Utc::now(),
+            trading_day,
+            market_open: true,
+        };
+        strategy.on_schedule(&event).await.unwrap();
+        assert!(strategy.should_rebalance());
+
+        strategy.update_rebalance_info(Utc::now());
+        assert!(!strategy.should_rebalance());
+    }
 }
@@ -0,0 +1,1113 @@
+//! 규칙 기반 전략.
+//!
+//! 코드 없이 JSON 조건 트리로 진입/청산 규칙을 정의하는 전략입니다.
+//! 조건은 지표(SMA, RSI, 볼린저 등), `StructuralFeatures`, `RouteState`,
+//! `MarketRegime`, GlobalScore를 피연산자로 사용할 수 있습니다.
+//!
+//! 규칙 필드는 SDUI 스키마에서 `rule_group` 타입으로 노출되므로
+//! 프론트엔드는 조건 트리 편집기로 규칙을 시각적으로 구성할 수 있습니다.
+//!
+//! # 설정 예시
+//!
+//! ```json
+//! {
+//!   "tickers": ["005930"],
+//!   "entry_rules": {
+//!     "type": "all",
+//!     "rules": [
+//!       { "type": "compare",
+//!         "left": { "kind": "indicator", "indicator": "rsi", "period": 14 },
+//!         "op": "lt",
+//!         "right": { "kind": "value", "value": 30 } },
+//!       { "type": "route_state", "in": ["ARMED", "ATTACK"] }
+//!     ]
+//!   },
+//!   "exit_rules": {
+//!     "type": "compare",
+//!     "left": { "kind": "price" },
+//!     "op": "cross_below",
+//!     "right": { "kind": "indicator", "indicator": "sma", "period": 20 }
+//!   }
+//! }
+//! ```
+//!
+//! # 평가 규칙
+//!
+//! - 데이터가 부족하거나 컨텍스트 값이 없으면 해당 조건은 거짓입니다.
+//! - `cross_above`/`cross_below`는 직전 캔들(컨텍스트는 직전 스냅샷)과 비교합니다.
+//! - 비어 있는 규칙 그룹은 신호를 생성하지 않습니다.
+
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, info};
+use trader_core::{
+    domain::{MarketRegime, RouteState, StrategyContext, StructuralFeatures},
+    Kline, MarketData, MarketDataType, Order, Position, Side, Signal, SignalType,
+};
+use trader_strategy_macro::StrategyConfig;
+
+use crate::{
+    strategies::common::{
+        calculate_atr, calculate_bollinger_bands, calculate_ema, calculate_macd, calculate_rsi,
+        calculate_sma, deserialize_tickers, ExitConfig,
+    },
+    Strategy,
+};
+
+/// 조건 트리 최대 깊이.
+const MAX_RULE_DEPTH: usize = 16;
+
+// ================================================================================================
+// 조건 트리
+// ================================================================================================
+
+/// 규칙 검증 오류.
+#[derive(Debug, Error)]
+pub enum RuleError {
+    /// 지표 파라미터가 잘못됨
+    #[error("지표 {indicator:?} 파라미터 오류: {reason}")]
+    InvalidIndicator {
+        indicator: IndicatorKind,
+        reason: String,
+    },
+
+    /// 조건 트리가 너무 깊음
+    #[error("조건 트리 깊이가 최대값({MAX_RULE_DEPTH})을 초과했습니다")]
+    TooDeep,
+}
+
+/// 조건 트리 노드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RuleNode {
+    /// 모든 하위 조건이 참
+    All { rules: Vec<RuleNode> },
+    /// 하나 이상의 하위 조건이 참
+    Any { rules: Vec<RuleNode> },
+    /// 하위 조건의 부정
+    Not { rule: Box<RuleNode> },
+    /// 두 피연산자 비교
+    Compare {
+        left: Operand,
+        op: CompareOp,
+        right: Operand,
+    },
+    /// 현재 RouteState가 목록에 포함
+    RouteState {
+        #[serde(rename = "in")]
+        states: Vec<RouteState>,
+    },
+    /// 현재 MarketRegime이 목록에 포함
+    MarketRegime {
+        #[serde(rename = "in")]
+        regimes: Vec<MarketRegime>,
+    },
+}
+
+impl Default for RuleNode {
+    fn default() -> Self {
+        RuleNode::All { rules: Vec::new() }
+    }
+}
+
+impl RuleNode {
+    /// 조건이 하나도 없는 그룹인지 확인.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            RuleNode::All { rules } | RuleNode::Any { rules } => {
+                rules.iter().all(RuleNode::is_empty)
+            }
+            RuleNode::Not { rule } => rule.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// 트리 깊이와 지표 파라미터 검증.
+    pub fn validate(&self) -> Result<(), RuleError> {
+        self.validate_at(1)
+    }
+
+    fn validate_at(&self, depth: usize) -> Result<(), RuleError> {
+        if depth > MAX_RULE_DEPTH {
+            return Err(RuleError::TooDeep);
+        }
+        match self {
+            RuleNode::All { rules } | RuleNode::Any { rules } => rules
+                .iter()
+                .try_for_each(|rule| rule.validate_at(depth + 1)),
+            RuleNode::Not { rule } => rule.validate_at(depth + 1),
+            RuleNode::Compare { left, right, .. } => {
+                left.validate()?;
+                right.validate()
+            }
+            RuleNode::RouteState { .. } | RuleNode::MarketRegime { .. } => Ok(()),
+        }
+    }
+
+    /// 조건 평가.
+    pub fn evaluate(&self, input: &RuleInput<'_>) -> bool {
+        match self {
+            RuleNode::All { rules } => {
+                !rules.is_empty() && rules.iter().all(|rule| rule.evaluate(input))
+            }
+            RuleNode::Any { rules } => rules.iter().any(|rule| rule.evaluate(input)),
+            RuleNode::Not { rule } => !rule.evaluate(input),
+            RuleNode::Compare { left, op, right } => op.evaluate(left, right, input),
+            RuleNode::RouteState { states } => input
+                .current
+                .route_state
+                .is_some_and(|state| states.contains(&state)),
+            RuleNode::MarketRegime { regimes } => input
+                .current
+                .regime
+                .is_some_and(|regime| regimes.contains(&regime)),
+        }
+    }
+}
+
+/// 비교 연산자.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareOp {
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Eq,
+    Ne,
+    /// 직전 값은 이하, 현재 값은 초과
+    CrossAbove,
+    /// 직전 값은 이상, 현재 값은 미만
+    CrossBelow,
+}
+
+impl CompareOp {
+    fn evaluate(self, left: &Operand, right: &Operand, input: &RuleInput<'_>) -> bool {
+        let (Some(l), Some(r)) = (left.evaluate(input, 0), right.evaluate(input, 0)) else {
+            return false;
+        };
+        match self {
+            CompareOp::Gt => l > r,
+            CompareOp::Gte => l >= r,
+            CompareOp::Lt => l < r,
+            CompareOp::Lte => l <= r,
+            CompareOp::Eq => l == r,
+            CompareOp::Ne => l != r,
+            CompareOp::CrossAbove | CompareOp::CrossBelow => {
+                let (Some(prev_l), Some(prev_r)) =
+                    (left.evaluate(input, 1), right.evaluate(input, 1))
+                else {
+                    return false;
+                };
+                if self == CompareOp::CrossAbove {
+                    prev_l <= prev_r && l > r
+                } else {
+                    prev_l >= prev_r && l < r
+                }
+            }
+        }
+    }
+}
+
+/// 비교 피연산자.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Operand {
+    /// 상수
+    Value { value: Decimal },
+    /// 캔들 가격
+    Price {
+        #[serde(default)]
+        field: PriceField,
+    },
+    /// 종가 기반 기술적 지표
+    Indicator {
+        indicator: IndicatorKind,
+        #[serde(default = "default_period")]
+        period: usize,
+        #[serde(default = "default_std_dev")]
+        std_dev: Decimal,
+        #[serde(default = "default_fast")]
+        fast: usize,
+        #[serde(default = "default_slow")]
+        slow: usize,
+        #[serde(default = "default_signal")]
+        signal: usize,
+    },
+    /// StructuralFeatures 값
+    Feature { name: FeatureField },
+    /// GlobalScore 종합 점수
+    GlobalScore,
+}
+
+fn default_period() -> usize {
+    14
+}
+
+fn default_std_dev() -> Decimal {
+    dec!(2)
+}
+
+fn default_fast() -> usize {
+    12
+}
+
+fn default_slow() -> usize {
+    26
+}
+
+fn default_signal() -> usize {
+    9
+}
+
+impl Operand {
+    fn validate(&self) -> Result<(), RuleError> {
+        let Operand::Indicator {
+            indicator,
+            period,
+            fast,
+            slow,
+            signal,
+            ..
+        } = self
+        else {
+            return Ok(());
+        };
+        let invalid = |reason: &str| RuleError::InvalidIndicator {
+            indicator: *indicator,
+            reason: reason.to_string(),
+        };
+        if indicator.is_macd() {
+            if *fast == 0 || *signal == 0 || fast >= slow {
+                return Err(invalid("fast < slow, signal > 0 이어야 합니다"));
+            }
+        } else if *period == 0 {
+            return Err(invalid("period는 1 이상이어야 합니다"));
+        }
+        Ok(())
+    }
+
+    /// `ago`봉 전 시점의 값 계산 (0 = 현재).
+    fn evaluate(&self, input: &RuleInput<'_>, ago: usize) -> Option<Decimal> {
+        match self {
+            Operand::Value { value } => Some(*value),
+            Operand::Price { field } => input.bars_ago(ago)?.last().map(|bar| field.of(bar)),
+            Operand::Indicator {
+                indicator,
+                period,
+                std_dev,
+                fast,
+                slow,
+                signal,
+            } => {
+                let bars = input.bars_ago(ago)?;
+                let closes: Vec<Decimal> = bars.iter().map(|b| b.close).collect();
+                match indicator {
+                    IndicatorKind::Sma => calculate_sma(&closes, *period),
+                    IndicatorKind::Ema => calculate_ema(&closes, *period),
+                    IndicatorKind::Rsi => calculate_rsi(&closes, *period),
+                    IndicatorKind::Atr => {
+                        let highs: Vec<Decimal> = bars.iter().map(|b| b.high).collect();
+                        let lows: Vec<Decimal> = bars.iter().map(|b| b.low).collect();
+                        calculate_atr(&highs, &lows, &closes, *period)
+                    }
+                    IndicatorKind::BollingerUpper
+                    | IndicatorKind::BollingerMiddle
+                    | IndicatorKind::BollingerLower => {
+                        let bands = calculate_bollinger_bands(&closes, *period, *std_dev)?;
+                        Some(match indicator {
+                            IndicatorKind::BollingerUpper => bands.upper,
+                            IndicatorKind::BollingerMiddle => bands.middle,
+                            _ => bands.lower,
+                        })
+                    }
+                    IndicatorKind::Macd
+                    | IndicatorKind::MacdSignal
+                    | IndicatorKind::MacdHistogram => {
+                        let macd = calculate_macd(&closes, *fast, *slow, *signal)?;
+                        Some(match indicator {
+                            IndicatorKind::Macd => macd.macd,
+                            IndicatorKind::MacdSignal => macd.signal,
+                            _ => macd.histogram,
+                        })
+                    }
+                    IndicatorKind::Highest => window(bars, *period)?.iter().map(|b| b.high).max(),
+                    IndicatorKind::Lowest => window(bars, *period)?.iter().map(|b| b.low).min(),
+                }
+            }
+            Operand::Feature { name } => input
+                .snapshot_ago(ago)?
+                .features
+                .as_ref()
+                .map(|f| name.of(f)),
+            Operand::GlobalScore => input.snapshot_ago(ago)?.global_score,
+        }
+    }
+}
+
+/// 최근 `period`개 캔들 (부족하면 None).
+fn window(bars: &[Kline], period: usize) -> Option<&[Kline]> {
+    bars.len().checked_sub(period).map(|start| &bars[start..])
+}
+
+/// 캔들 가격 필드.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceField {
+    Open,
+    High,
+    Low,
+    #[default]
+    Close,
+    Volume,
+}
+
+impl PriceField {
+    fn of(self, bar: &Kline) -> Decimal {
+        match self {
+            PriceField::Open => bar.open,
+            PriceField::High => bar.high,
+            PriceField::Low => bar.low,
+            PriceField::Close => bar.close,
+            PriceField::Volume => bar.volume,
+        }
+    }
+}
+
+/// 지원 지표.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndicatorKind {
+    Sma,
+    Ema,
+    Rsi,
+    Atr,
+    BollingerUpper,
+    BollingerMiddle,
+    BollingerLower,
+    Macd,
+    MacdSignal,
+    MacdHistogram,
+    /// 기간 내 최고가
+    Highest,
+    /// 기간 내 최저가
+    Lowest,
+}
+
+impl IndicatorKind {
+    fn is_macd(self) -> bool {
+        matches!(
+            self,
+            IndicatorKind::Macd | IndicatorKind::MacdSignal | IndicatorKind::MacdHistogram
+        )
+    }
+}
+
+/// StructuralFeatures 필드.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureField {
+    LowTrend,
+    VolQuality,
+    RangePos,
+    DistMa20,
+    BbWidth,
+    BbUpper,
+    BbMiddle,
+    BbLower,
+    Rsi,
+}
+
+impl FeatureField {
+    fn of(self, features: &StructuralFeatures) -> Decimal {
+        match self {
+            FeatureField::LowTrend => features.low_trend,
+            FeatureField::VolQuality => features.vol_quality,
+            FeatureField::RangePos => features.range_pos,
+            FeatureField::DistMa20 => features.dist_ma20,
+            FeatureField::BbWidth => features.bb_width,
+            FeatureField::BbUpper => features.bb_upper,
+            FeatureField::BbMiddle => features.bb_middle,
+            FeatureField::BbLower => features.bb_lower,
+            FeatureField::Rsi => features.rsi,
+        }
+    }
+}
+
+/// 캔들 시점의 컨텍스트 값.
+#[derive(Debug, Clone, Default)]
+pub struct ContextSnapshot {
+    pub route_state: Option<RouteState>,
+    pub regime: Option<MarketRegime>,
+    pub global_score: Option<Decimal>,
+    pub features: Option<StructuralFeatures>,
+}
+
+impl ContextSnapshot {
+    /// `StrategyContext`에서 티커의 값 추출 ("005930/KRW" → "005930" 폴백).
+    pub fn from_context(ctx: &StrategyContext, ticker: &str) -> Self {
+        let base = ticker.split('/').next().unwrap_or(ticker);
+        Self {
+            route_state: ctx
+                .get_route_state(ticker)
+                .or_else(|| ctx.get_route_state(base))
+                .copied(),
+            regime: ctx
+                .get_market_regime(ticker)
+                .or_else(|| ctx.get_market_regime(base))
+                .copied(),
+            global_score: ctx
+                .get_global_score(ticker)
+                .or_else(|| ctx.get_global_score(base))
+                .map(|s| s.overall_score),
+            features: ctx
+                .get_features(ticker)
+                .or_else(|| ctx.get_features(base))
+                .cloned(),
+        }
+    }
+}
+
+/// 조건 평가 입력.
+pub struct RuleInput<'a> {
+    /// 캔들 이력 (마지막이 현재 캔들)
+    pub bars: &'a [Kline],
+    /// 현재 컨텍스트 값
+    pub current: &'a ContextSnapshot,
+    /// 직전 캔들 시점의 컨텍스트 값
+    pub previous: Option<&'a ContextSnapshot>,
+}
+
+impl RuleInput<'_> {
+    fn bars_ago(&self, ago: usize) -> Option<&[Kline]> {
+        let len = self.bars.len().checked_sub(ago)?;
+        (len > 0).then(|| &self.bars[..len])
+    }
+
+    fn snapshot_ago(&self, ago: usize) -> Option<&ContextSnapshot> {
+        match ago {
+            0 => Some(self.current),
+            1 => self.previous,
+            _ => None,
+        }
+    }
+}
+
+// ================================================================================================
+// 설정
+// ================================================================================================
+
+/// 규칙 기반 전략 설정.
+#[derive(Debug, Clone, Serialize, Deserialize, StrategyConfig)]
+#[strategy(
+    id = "rule_based",
+    name = "규칙 기반 전략",
+    description = "지표/구조적 피처/RouteState 조건 트리로 진입·청산 규칙을 구성",
+    category = "Daily"
+)]
+pub struct RuleBasedConfig {
+    /// 대상 티커
+    #[serde(default, deserialize_with = "deserialize_tickers")]
+    #[schema(label = "거래 종목", field_type = "symbols", section = "asset")]
+    pub tickers: Vec<String>,
+
+    /// 진입 조건 (참이면 매수)
+    #[serde(default)]
+    #[schema(
+        label = "진입 규칙",
+        field_type = "rule_group",
+        options = ["value", "price", "indicator", "feature", "global_score", "route_state", "market_regime"],
+        section = "entry"
+    )]
+    pub entry_rules: RuleNode,
+
+    /// 청산 조건 (포지션 보유 중 참이면 매도)
+    #[serde(default)]
+    #[schema(
+        label = "청산 규칙",
+        field_type = "rule_group",
+        options = ["value", "price", "indicator", "feature", "global_score", "route_state", "market_regime"],
+        section = "exit"
+    )]
+    pub exit_rules: RuleNode,
+
+    /// 청산 후 재진입 대기 캔들 수
+    #[serde(default)]
+    #[schema(
+        label = "쿨다운 캔들 수",
+        field_type = "integer",
+        min = 0,
+        max = 100,
+        default = 0,
+        section = "timing"
+    )]
+    pub cooldown_candles: usize,
+
+    /// 티커별 보관할 최대 캔들 수
+    #[serde(default = "default_max_history")]
+    #[schema(
+        label = "캔들 보관 수",
+        field_type = "integer",
+        min = 50,
+        max = 2000,
+        default = 300,
+        section = "indicator"
+    )]
+    pub max_history: usize,
+
+    /// 청산 설정
+    #[serde(default = "ExitConfig::default")]
+    #[fragment("risk.exit_config")]
+    pub exit_config: ExitConfig,
+}
+
+fn default_max_history() -> usize {
+    300
+}
+
+// ================================================================================================
+// 전략 구현
+// ================================================================================================
+
+/// 티커별 상태.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct TickerState {
+    /// 보유 수량
+    quantity: Decimal,
+    /// 평균 진입가
+    entry_price: Decimal,
+    /// 남은 쿨다운 캔들 수
+    cooldown: usize,
+}
+
+/// 티커별 컨텍스트 스냅샷 (현재 봉, 직전 봉).
+#[derive(Debug, Clone, Default)]
+struct BarSnapshots {
+    /// 현재 봉의 최신 스냅샷
+    current: Option<ContextSnapshot>,
+    /// 직전 봉이 마지막으로 갱신된 시점의 스냅샷
+    previous: Option<ContextSnapshot>,
+}
+
+/// 규칙 기반 전략.
+pub struct RuleBasedStrategy {
+    config: Option<RuleBasedConfig>,
+    history: HashMap<String, Vec<Kline>>,
+    snapshots: HashMap<String, BarSnapshots>,
+    tickers: HashMap<String, TickerState>,
+    context: Option<Arc<RwLock<StrategyContext>>>,
+}
+
+impl RuleBasedStrategy {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            history: HashMap::new(),
+            snapshots: HashMap::new(),
+            tickers: HashMap::new(),
+            context: None,
+        }
+    }
+
+    fn is_target(&self, ticker: &str) -> bool {
+        self.config
+            .as_ref()
+            .is_some_and(|c| c.tickers.is_empty() || c.tickers.iter().any(|t| t == ticker))
+    }
+
+    /// 캔들 이력에 추가 (같은 봉 갱신 시 교체).
+    ///
+    /// 새 봉이 열렸으면 `true`를 반환합니다.
+    fn push_bar(&mut self, ticker: &str, kline: &Kline, max_history: usize) -> bool {
+        let bars = self.history.entry(ticker.to_string()).or_default();
+        let new_bar = match bars.last_mut() {
+            Some(last) if last.open_time == kline.open_time => {
+                *last = kline.clone();
+                false
+            }
+            _ => {
+                bars.push(kline.clone());
+                true
+            }
+        };
+        if bars.len() > max_history {
+            let excess = bars.len() - max_history;
+            bars.drain(..excess);
+        }
+        new_bar
+    }
+
+    async fn snapshot(&self, ticker: &str) -> ContextSnapshot {
+        match self.context.as_ref() {
+            Some(context) => ContextSnapshot::from_context(&*context.read().await, ticker),
+            None => ContextSnapshot::default(),
+        }
+    }
+
+    fn signal(ticker: &str, side: Side, signal_type: SignalType, price: Decimal) -> Signal {
+        Signal::new("rule_based", ticker.to_string(), side, signal_type)
+            .with_strength(1.0)
+            .with_prices(Some(price), None, None)
+    }
+
+    /// 손절/익절 확인 (진입가 대비 %).
+    fn check_exit_config(
+        config: &ExitConfig,
+        state: &TickerState,
+        price: Decimal,
+    ) -> Option<&'static str> {
+        if state.entry_price.is_zero() {
+            return None;
+        }
+        if let Some(sl_pct) = config.stop_loss() {
+            if price <= state.entry_price * (dec!(1) - sl_pct / dec!(100)) {
+                return Some("stop_loss");
+            }
+        }
+        if let Some(tp_pct) = config.take_profit() {
+            if price >= state.entry_price * (dec!(1) + tp_pct / dec!(100)) {
+                return Some("take_profit");
+            }
+        }
+        None
+    }
+}
+
+impl Default for RuleBasedStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Strategy for RuleBasedStrategy {
+    fn name(&self) -> &str {
+        "RuleBased"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "JSON 조건 트리로 정의하는 규칙 기반 전략"
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let config: RuleBasedConfig = serde_json::from_value(config)?;
+        config.entry_rules.validate()?;
+        config.exit_rules.validate()?;
+
+        info!(
+            tickers = ?config.tickers,
+            "[RuleBased] 규칙 기반 전략 초기화"
+        );
+
+        self.history.clear();
+        self.snapshots.clear();
+        self.tickers.clear();
+        self.config = Some(config);
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let MarketDataType::Kline(kline) = &data.data else {
+            return Ok(vec![]);
+        };
+        if !self.is_target(&data.ticker) {
+            return Ok(vec![]);
+        }
+        let Some(max_history) = self.config.as_ref().map(|c| c.max_history.max(2)) else {
+            return Ok(vec![]);
+        };
+
+        let new_bar = self.push_bar(&data.ticker, kline, max_history);
+        let current = self.snapshot(&data.ticker).await;
+        // 같은 봉의 갱신은 현재 스냅샷만 교체하고 직전 봉 스냅샷은 유지
+        let snapshots = self.snapshots.entry(data.ticker.clone()).or_default();
+        if new_bar {
+            snapshots.previous = snapshots.current.take();
+        }
+        snapshots.current = Some(current.clone());
+        let previous = snapshots.previous.clone();
+
+        let Some(config) = self.config.as_ref() else {
+            return Ok(vec![]);
+        };
+        let input = RuleInput {
+            bars: &self.history[&data.ticker],
+            current: &current,
+            previous: previous.as_ref(),
+        };
+        let state = self.tickers.entry(data.ticker.clone()).or_default();
+        if new_bar {
+            state.cooldown = state.cooldown.saturating_sub(1);
+        }
+        let price = kline.close;
+
+        if state.quantity > Decimal::ZERO {
+            let reason = Self::check_exit_config(&config.exit_config, state, price).or_else(|| {
+                (!config.exit_rules.is_empty() && config.exit_rules.evaluate(&input))
+                    .then_some("exit_rules")
+            });
+            if let Some(reason) = reason {
+                debug!(ticker = %data.ticker, reason, "[RuleBased] 청산 조건 충족");
+                return Ok(vec![Self::signal(
+                    &data.ticker,
+                    Side::Sell,
+                    SignalType::Exit,
+                    price,
+                )
+                .with_metadata("reason", json!(reason))]);
+            }
+        } else if state.cooldown == 0
+            && !config.entry_rules.is_empty()
+            && config.entry_rules.evaluate(&input)
+        {
+            debug!(ticker = %data.ticker, "[RuleBased] 진입 조건 충족");
+            return Ok(vec![Self::signal(
+                &data.ticker,
+                Side::Buy,
+                SignalType::Entry,
+                price,
+            )
+            .with_metadata("reason", json!("entry_rules"))]);
+        }
+
+        Ok(vec![])
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let Some(fill_price) = order.average_fill_price else {
+            return Ok(());
+        };
+        let cooldown = self.config.as_ref().map_or(0, |c| c.cooldown_candles);
+        let state = self.tickers.entry(order.ticker.clone()).or_default();
+
+        match order.side {
+            Side::Buy => {
+                let total = state.quantity + order.quantity;
+                if !total.is_zero() {
+                    state.entry_price =
+                        (state.entry_price * state.quantity + fill_price * order.quantity) / total;
+                }
+                state.quantity = total;
+            }
+            Side::Sell => {
+                state.quantity -= order.quantity;
+                if state.quantity <= Decimal::ZERO {
+                    state.quantity = Decimal::ZERO;
+                    state.entry_price = Decimal::ZERO;
+                    state.cooldown = cooldown;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if !self.is_target(&position.ticker) {
+            return Ok(());
+        }
+        let state = self.tickers.entry(position.ticker.clone()).or_default();
+        state.quantity = position.quantity.max(Decimal::ZERO);
+        state.entry_price = if state.quantity.is_zero() {
+            Decimal::ZERO
+        } else {
+            position.entry_price
+        };
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        info!("[RuleBased] 규칙 기반 전략 종료");
+        Ok(())
+    }
+
+    fn get_state(&self) -> Value {
+        json!({
+            "initialized": self.config.is_some(),
+            "tickers": self.tickers,
+            "bars": self.history.iter()
+                .map(|(ticker, bars)| (ticker.clone(), bars.len()))
+                .collect::<HashMap<_, _>>(),
+        })
+    }
+
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(serde_json::to_vec(&self.tickers)?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.tickers = serde_json::from_slice(data)?;
+        Ok(())
+    }
+
+    fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
+        self.context = Some(context);
+    }
+
+    fn exit_config(&self) -> Option<&ExitConfig> {
+        self.config.as_ref().map(|c| &c.exit_config)
+    }
+}
+
+// ================================================================================================
+// 전략 레지스트리 등록
+// ================================================================================================
+
+use crate::register_strategy;
+
+register_strategy! {
+    id: "rule_based",
+    aliases: ["rules", "rule_builder"],
+    name: "규칙 기반 전략",
+    description: "지표/구조적 피처/RouteState 조건 트리로 진입·청산 규칙을 구성",
+    timeframe: "1d",
+    tickers: [],
+    category: Daily,
+    markets: [Crypto, Stock],
+    type: RuleBasedStrategy,
+    config: RuleBasedConfig
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use trader_core::{FieldType, OrderRequest, Timeframe};
+
+    use super::*;
+    use crate::StrategyRegistry;
+
+    fn kline(day: i64, close: i64) -> MarketData {
+        let open_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::days(day);
+        let price = Decimal::from(close);
+        let kline = Kline::new(
+            "005930".to_string(),
+            Timeframe::D1,
+            open_time,
+            price,
+            price,
+            price,
+            price,
+            Decimal::from(1000),
+            open_time + Duration::days(1),
+        );
+        MarketData::from_kline("test", kline)
+    }
+
+    fn config() -> Value {
+        json!({
+            "tickers": ["005930"],
+            "entry_rules": {
+                "type": "all",
+                "rules": [
+                    { "type": "compare",
+                      "left": { "kind": "price" },
+                      "op": "cross_above",
+                      "right": { "kind": "indicator", "indicator": "sma", "period": 3 } },
+                    { "type": "route_state", "in": ["ARMED", "ATTACK"] }
+                ]
+            },
+            "exit_rules": {
+                "type": "compare",
+                "left": { "kind": "price" },
+                "op": "lt",
+                "right": { "kind": "indicator", "indicator": "sma", "period": 3 }
+            },
+            "exit_config": { "stop_loss": { "enabled": true, "pct": 5.0 } }
+        })
+    }
+
+    fn filled(side: Side, price: i64) -> Order {
+        let request = match side {
+            Side::Buy => OrderRequest::market_buy("005930".to_string(), Decimal::ONE),
+            Side::Sell => OrderRequest::market_sell("005930".to_string(), Decimal::ONE),
+        };
+        let mut order = Order::from_request(request, "test");
+        order.average_fill_price = Some(Decimal::from(price));
+        order
+    }
+
+    async fn feed(strategy: &mut RuleBasedStrategy, day: i64, close: i64) -> Vec<Signal> {
+        strategy.on_market_data(&kline(day, close)).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_entry_and_exit_rules() {
+        let mut strategy = RuleBasedStrategy::new();
+        strategy.initialize(config()).await.unwrap();
+        let context = Arc::new(RwLock::new(StrategyContext::default()));
+        strategy.set_context(Arc::clone(&context));
+
+        for day in 0..3 {
+            assert!(feed(&mut strategy, day, 100).await.is_empty());
+        }
+
+        // RouteState가 없으면 교차가 발생해도 진입하지 않음
+        assert!(feed(&mut strategy, 3, 110).await.is_empty());
+
+        context
+            .write()
+            .await
+            .route_states
+            .insert("005930".to_string(), RouteState::Armed);
+        assert!(feed(&mut strategy, 4, 100).await.is_empty());
+        let signals = feed(&mut strategy, 5, 120).await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(signals[0].signal_type, SignalType::Entry);
+
+        strategy
+            .on_order_filled(&filled(Side::Buy, 120))
+            .await
+            .unwrap();
+        assert!(feed(&mut strategy, 6, 118).await.is_empty());
+
+        // 종가가 SMA 아래로 내려가면 청산 규칙 충족
+        let signals = feed(&mut strategy, 7, 116).await;
+        assert_eq!(signals[0].signal_type, SignalType::Exit);
+        assert_eq!(signals[0].metadata["reason"], json!("exit_rules"));
+    }
+
+    #[tokio::test]
+    async fn test_exit_config_stop_loss_and_state() {
+        let mut strategy = RuleBasedStrategy::new();
+        let mut cfg = config();
+        cfg["exit_rules"] = json!({ "type": "all", "rules": [] });
+        strategy.initialize(cfg.clone()).await.unwrap();
+        strategy
+            .on_order_filled(&filled(Side::Buy, 100))
+            .await
+            .unwrap();
+
+        // 체크포인트 복원 후에도 진입가 기준 손절 유지
+        let saved = strategy.save_state().unwrap();
+        let mut restarted = RuleBasedStrategy::new();
+        restarted.initialize(cfg).await.unwrap();
+        restarted.load_state(&saved).unwrap();
+
+        assert!(feed(&mut restarted, 0, 97).await.is_empty());
+        let signals = feed(&mut restarted, 1, 94).await;
+        assert_eq!(signals[0].side, Side::Sell);
+        assert_eq!(signals[0].metadata["reason"], json!("stop_loss"));
+        assert!(restarted.exit_config().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_intrabar_updates_keep_snapshot_and_cooldown() {
+        let mut strategy = RuleBasedStrategy::new();
+        let mut cfg = config();
+        cfg["cooldown_candles"] = json!(2);
+        strategy.initialize(cfg).await.unwrap();
+        strategy
+            .on_order_filled(&filled(Side::Buy, 100))
+            .await
+            .unwrap();
+        strategy
+            .on_order_filled(&filled(Side::Sell, 100))
+            .await
+            .unwrap();
+
+        // 같은 봉이 여러 번 갱신되어도 쿨다운과 직전 봉 스냅샷은 그대로
+        for close in [100, 101, 102] {
+            feed(&mut strategy, 0, close).await;
+        }
+        assert_eq!(strategy.tickers["005930"].cooldown, 1);
+        assert!(strategy.snapshots["005930"].previous.is_none());
+
+        feed(&mut strategy, 1, 103).await;
+        feed(&mut strategy, 1, 104).await;
+        assert_eq!(strategy.tickers["005930"].cooldown, 0);
+        assert!(strategy.snapshots["005930"].previous.is_some());
+    }
+
+    #[test]
+    fn test_features_and_scores_from_context() {
+        let bars: Vec<Kline> = Vec::new();
+        let current = ContextSnapshot {
+            regime: Some(MarketRegime::StrongUptrend),
+            global_score: Some(dec!(80)),
+            ..Default::default()
+        };
+        let input = RuleInput {
+            bars: &bars,
+            current: &current,
+            previous: None,
+        };
+
+        let rule: RuleNode = serde_json::from_value(json!({
+            "type": "any",
+            "rules": [
+                { "type": "compare",
+                  "left": { "kind": "global_score" }, "op": "gte",
+                  "right": { "kind": "value", "value": 70 } },
+                { "type": "compare",
+                  "left": { "kind": "feature", "name": "rsi" }, "op": "lt",
+                  "right": { "kind": "value", "value": 30 } }
+            ]
+        }))
+        .unwrap();
+        assert!(rule.evaluate(&input));
+
+        let regime: RuleNode = serde_json::from_value(json!({
+            "type": "not",
+            "rule": { "type": "market_regime", "in": ["DOWNTREND", "CORRECTION"] }
+        }))
+        .unwrap();
+        assert!(regime.evaluate(&input));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_rules_rejected() {
+        let mut cfg = config();
+        cfg["entry_rules"] = json!({
+            "type": "compare",
+            "left": { "kind": "indicator", "indicator": "macd", "fast": 26, "slow": 12 },
+            "op": "gt",
+            "right": { "kind": "value", "value": 0 }
+        });
+        assert!(RuleBasedStrategy::new().initialize(cfg).await.is_err());
+
+        let mut deep = RuleNode::default();
+        for _ in 0..MAX_RULE_DEPTH {
+            deep = RuleNode::Not {
+                rule: Box::new(deep),
+            };
+        }
+        assert!(matches!(deep.validate(), Err(RuleError::TooDeep)));
+    }
+
+    #[test]
+    fn test_registered_with_rule_schema() {
+        let meta = StrategyRegistry::find("rule_based").expect("rule_based 등록");
+        let schema = (meta.ui_schema_factory.unwrap())();
+        let rule_fields: Vec<_> = schema
+            .custom_fields
+            .iter()
+            .filter(|f| f.field_type == FieldType::RuleGroup)
+            .map(|f| f.name.as_str())
+            .collect();
+        assert_eq!(rule_fields, vec!["entry_rules", "exit_rules"]);
+        assert!(schema.fragments.iter().any(|f| f.id == "risk.exit_config"));
+    }
+}
@@ -0,0 +1,634 @@
+//! 스크립트 전략.
+//!
+//! 설정의 `script` 필드에 담긴 Rhai 스크립트를 [`ScriptHost`] 샌드박스에서 실행합니다.
+//! 스크립트 원문은 다른 전략 설정과 마찬가지로 `strategies.config`에 저장되므로
+//! 전략 API로 수정할 수 있고, `BacktestEngine`에서도 별도 처리 없이 실행됩니다.
+//!
+//! 스크립트 호출은 런타임 워커를 막지 않도록 `spawn_blocking` 스레드에서 실행되며,
+//! 호출당 실행 시간은 [`ScriptLimits::max_duration_ms`]로 제한됩니다.
+//!
+//! # 설정 예시
+//!
+//! ```json
+//! {
+//!   "script": "fn on_bar(market) { if market.rsi(14) < 30.0 { return buy(); } }",
+//!   "tickers": ["005930"],
+//!   "params": { "threshold": 30 },
+//!   "limits": { "max_operations": 500000 }
+//! }
+//! ```
+
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use rhai::{Dynamic, Map};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+use trader_core::{
+    domain::StrategyContext, Kline, MarketData, MarketDataType, Order, Position, Signal,
+};
+
+use crate::{
+    scripting::{
+        bindings::{fill_map, PositionView},
+        state_from_json, state_to_json, ScriptError, ScriptHost, ScriptLimits, ScriptMarket,
+    },
+    strategies::common::deserialize_tickers,
+    Strategy,
+};
+
+/// 스크립트 전략 설정.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScriptStrategyConfig {
+    /// Rhai 스크립트 원문
+    pub script: String,
+
+    /// 대상 티커 (빈 값 = 수신하는 모든 티커)
+    #[serde(default, deserialize_with = "deserialize_tickers")]
+    pub tickers: Vec<String>,
+
+    /// `init(params)`와 `market.params`로 전달되는 사용자 파라미터
+    #[serde(default)]
+    pub params: serde_json::Map<String, Value>,
+
+    /// 리소스 제한
+    #[serde(default)]
+    pub limits: ScriptLimits,
+
+    /// 티커별 보관할 최대 캔들 수
+    #[serde(default = "default_max_history")]
+    pub max_history: usize,
+}
+
+fn default_max_history() -> usize {
+    500
+}
+
+/// 체크포인트 데이터.
+#[derive(Debug, Serialize, Deserialize)]
+struct ScriptCheckpoint {
+    /// 스크립트 상태 맵 (`this`)
+    state: Value,
+    /// 상태를 만든 스크립트 원문 해시
+    script_hash: u64,
+    signals_emitted: u64,
+}
+
+/// 스크립트 원문 해시 (체크포인트와 현재 스크립트 비교용).
+fn script_hash(source: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    source.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// 스크립트 함수를 블로킹 스레드에서 실행하고 상태를 돌려받음.
+///
+/// 실행 스레드가 패닉하면 상태는 빈 맵으로 초기화됩니다.
+async fn run_blocking<T, F>(
+    host: Arc<ScriptHost>,
+    mut state: Dynamic,
+    call: F,
+) -> (Dynamic, Result<T, ScriptError>)
+where
+    T: Send + 'static,
+    F: FnOnce(&ScriptHost, &mut Dynamic) -> Result<T, ScriptError> + Send + 'static,
+{
+    let task = tokio::task::spawn_blocking(move || {
+        let result = call(&host, &mut state);
+        (state, result)
+    });
+    match task.await {
+        Ok(done) => done,
+        Err(e) => (
+            Dynamic::from_map(Map::new()),
+            Err(ScriptError::Runtime(format!(
+                "스크립트 실행 스레드 실패: {}",
+                e
+            ))),
+        ),
+    }
+}
+
+/// 스크립트 전략.
+pub struct ScriptStrategy {
+    config: Option<ScriptStrategyConfig>,
+    host: Option<Arc<ScriptHost>>,
+    params: Map,
+    state: Dynamic,
+    history: HashMap<String, Arc<Vec<Kline>>>,
+    signals_emitted: u64,
+    errors: u64,
+    last_error: Option<String>,
+    context: Option<Arc<RwLock<StrategyContext>>>,
+}
+
+impl ScriptStrategy {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            host: None,
+            params: Map::new(),
+            state: Dynamic::from_map(Map::new()),
+            history: HashMap::new(),
+            signals_emitted: 0,
+            errors: 0,
+            last_error: None,
+            context: None,
+        }
+    }
+
+    fn is_target(&self, ticker: &str) -> bool {
+        self.config
+            .as_ref()
+            .is_some_and(|c| c.tickers.is_empty() || c.tickers.iter().any(|t| t == ticker))
+    }
+
+    /// 캔들 이력에 추가 (같은 봉 갱신 시 교체).
+    fn push_bar(&mut self, ticker: &str, kline: &Kline, max_history: usize) -> Arc<Vec<Kline>> {
+        let history = self.history.entry(ticker.to_string()).or_default();
+        // 스크립트 호출이 끝나면 참조가 하나만 남으므로 복사 없이 수정됨
+        let bars = Arc::make_mut(history);
+        match bars.last_mut() {
+            Some(last) if last.open_time == kline.open_time => *last = kline.clone(),
+            _ => bars.push(kline.clone()),
+        }
+        if bars.len() > max_history {
+            let excess = bars.len() - max_history;
+            bars.drain(..excess);
+        }
+        Arc::clone(history)
+    }
+
+    /// `StrategyContext`에서 스크립트용 시장 뷰 구성.
+    async fn market_view(&self, ticker: &str, bars: Arc<Vec<Kline>>) -> ScriptMarket {
+        let mut market = ScriptMarket {
+            ticker: ticker.to_string(),
+            bars,
+            params: self.params.clone(),
+            position: None,
+            global_score: None,
+            route_state: None,
+            regime: None,
+        };
+
+        let Some(context) = self.context.as_ref() else {
+            return market;
+        };
+        let ctx = context.read().await;
+        // 컨텍스트는 "005930/KRW" 대신 "005930"으로 저장되기도 함
+        let base = ticker.split('/').next().unwrap_or(ticker);
+
+        market.position = ctx
+            .get_position(ticker)
+            .or_else(|| ctx.get_position(base))
+            .filter(|p| !p.quantity.is_zero())
+            .map(|p| PositionView {
+                side: p.side,
+                quantity: p.quantity.to_f64().unwrap_or(0.0),
+                entry_price: p.avg_entry_price.to_f64().unwrap_or(0.0),
+                pnl_pct: p.unrealized_pnl_pct.to_f64().unwrap_or(0.0),
+            });
+        market.global_score = ctx
+            .get_global_score(ticker)
+            .or_else(|| ctx.get_global_score(base))
+            .and_then(|s| s.overall_score.to_f64());
+        market.route_state = ctx
+            .get_route_state(ticker)
+            .or_else(|| ctx.get_route_state(base))
+            .map(|r| r.to_string());
+        market.regime = ctx
+            .get_market_regime(ticker)
+            .or_else(|| ctx.get_market_regime(base))
+            .map(|r| r.to_string());
+        market
+    }
+
+    fn record_error(&mut self, err: ScriptError) -> Box<dyn std::error::Error + Send + Sync> {
+        self.errors += 1;
+        self.last_error = Some(err.to_string());
+        Box::new(err)
+    }
+}
+
+impl Default for ScriptStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Strategy for ScriptStrategy {
+    fn name(&self) -> &str {
+        "Script"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "샌드박스에서 실행되는 Rhai 스크립트 전략"
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let config: ScriptStrategyConfig = serde_json::from_value(config)?;
+        let host = Arc::new(ScriptHost::compile(&config.script, config.limits.clone())?);
+
+        let params: Map =
+            rhai::serde::to_dynamic(Value::Object(config.params.clone()))?.cast::<Map>();
+        let init_params = params.clone();
+        let (state, result) = run_blocking(
+            Arc::clone(&host),
+            Dynamic::from_map(Map::new()),
+            move |host, state| host.call_init(state, init_params),
+        )
+        .await;
+        result?;
+
+        info!(
+            functions = ?host.info().functions,
+            tickers = ?config.tickers,
+            "[Script] 스크립트 전략 초기화"
+        );
+
+        self.host = Some(host);
+        self.params = params;
+        self.state = state;
+        self.history.clear();
+        self.signals_emitted = 0;
+        self.errors = 0;
+        self.last_error = None;
+        self.config = Some(config);
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let MarketDataType::Kline(kline) = &data.data else {
+            return Ok(vec![]);
+        };
+        if !self.is_target(&data.ticker) {
+            return Ok(vec![]);
+        }
+        let max_history = match self.config.as_ref() {
+            Some(config) => config.max_history.max(1),
+            None => return Ok(vec![]),
+        };
+
+        let bars = self.push_bar(&data.ticker, kline, max_history);
+        let market = self.market_view(&data.ticker, bars).await;
+
+        let Some(host) = self.host.clone() else {
+            return Ok(vec![]);
+        };
+        let (state, result) =
+            run_blocking(host, std::mem::take(&mut self.state), move |host, state| {
+                host.call_on_bar(state, market)
+            })
+            .await;
+        self.state = state;
+        let signals = match result {
+            Ok(signals) => signals,
+            Err(e) => return Err(self.record_error(e)),
+        };
+
+        self.signals_emitted += signals.len() as u64;
+        Ok(signals
+            .into_iter()
+            .map(|s| s.into_signal(&data.ticker))
+            .collect())
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let Some(host) = self.host.clone() else {
+            return Ok(());
+        };
+        let fill = fill_map(order);
+        let (state, result) =
+            run_blocking(host, std::mem::take(&mut self.state), move |host, state| {
+                host.call_on_fill(state, fill)
+            })
+            .await;
+        self.state = state;
+        if let Err(e) = result {
+            return Err(self.record_error(e));
+        }
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        _position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        info!(
+            signals = self.signals_emitted,
+            errors = self.errors,
+            "[Script] 스크립트 전략 종료"
+        );
+        Ok(())
+    }
+
+    fn get_state(&self) -> Value {
+        json!({
+            "initialized": self.host.is_some(),
+            "functions": self.host.as_ref().map(|h| h.info().functions.clone()),
+            "tickers": self.config.as_ref().map(|c| c.tickers.clone()),
+            "bars": self.history.iter()
+                .map(|(ticker, bars)| (ticker.clone(), bars.len()))
+                .collect::<HashMap<_, _>>(),
+            "signals_emitted": self.signals_emitted,
+            "errors": self.errors,
+            "last_error": self.last_error,
+            "state": state_to_json(&self.state).unwrap_or(Value::Null),
+        })
+    }
+
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let Some(config) = self.config.as_ref() else {
+            return Ok(vec![]);
+        };
+        let checkpoint = ScriptCheckpoint {
+            state: state_to_json(&self.state)?,
+            script_hash: script_hash(&config.script),
+            signals_emitted: self.signals_emitted,
+        };
+        Ok(serde_json::to_vec(&checkpoint)?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let checkpoint: ScriptCheckpoint = serde_json::from_slice(data)?;
+
+        if let Some(config) = self.config.as_ref() {
+            if script_hash(&config.script) != checkpoint.script_hash {
+                warn!("[Script] 스크립트가 변경된 이후의 상태를 복원합니다");
+            }
+        }
+
+        self.state = state_from_json(&checkpoint.state)?;
+        self.signals_emitted = checkpoint.signals_emitted;
+        Ok(())
+    }
+
+    fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
+        self.context = Some(context);
+    }
+}
+
+// ============================================================================
+// 전략 레지스트리 등록
+// ============================================================================
+
+use crate::register_strategy;
+
+register_strategy! {
+    id: "script",
+    aliases: ["rhai_script"],
+    name: "스크립트 전략",
+    description: "샌드박스에서 실행되는 Rhai 스크립트로 매매 신호를 생성합니다.",
+    timeframe: "1d",
+    tickers: [],
+    category: Daily,
+    markets: [Crypto, Stock],
+    type: ScriptStrategy
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use rust_decimal::Decimal;
+    use trader_core::{
+        domain::{BollingerValue, IndicatorCalculator, MacdValue, StrategyPositionInfo},
+        Side, SignalType, Timeframe,
+    };
+
+    use super::*;
+    use crate::{scripting::set_indicator_calculator, strategies::common::calculate_sma};
+
+    const CROSS_SCRIPT: &str = r#"
+        fn init(params) {
+            this.period = params.period;
+            this.bars = 0;
+        }
+
+        fn on_bar(market) {
+            this.bars += 1;
+            let sma = market.sma(this.period);
+            if sma == () { return; }
+            if market.close > sma && !market.has_position {
+                return buy().strength(0.7).reason("above sma").meta("sma", sma);
+            }
+            if market.close < sma && market.has_position {
+                return exit();
+            }
+        }
+    "#;
+
+    fn kline(ticker: &str, day: i64, close: i64) -> MarketData {
+        let open_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::days(day);
+        let price = Decimal::from(close);
+        let kline = Kline::new(
+            ticker.to_string(),
+            Timeframe::D1,
+            open_time,
+            price,
+            price,
+            price,
+            price,
+            Decimal::from(1000),
+            open_time + Duration::days(1),
+        );
+        MarketData::from_kline("test", kline)
+    }
+
+    /// 테스트용 계산기 (SMA만 제공, 실제 서버는 `IndicatorEngine` 주입).
+    struct SmaOnly;
+
+    impl IndicatorCalculator for SmaOnly {
+        fn sma(&self, closes: &[Decimal], period: usize) -> Option<Decimal> {
+            calculate_sma(closes, period)
+        }
+
+        fn ema(&self, _closes: &[Decimal], _period: usize) -> Option<Decimal> {
+            None
+        }
+
+        fn rsi(&self, _closes: &[Decimal], _period: usize) -> Option<Decimal> {
+            None
+        }
+
+        fn atr(
+            &self,
+            _highs: &[Decimal],
+            _lows: &[Decimal],
+            _closes: &[Decimal],
+            _period: usize,
+        ) -> Option<Decimal> {
+            None
+        }
+
+        fn bollinger(
+            &self,
+            _closes: &[Decimal],
+            _period: usize,
+            _k: Decimal,
+        ) -> Option<BollingerValue> {
+            None
+        }
+
+        fn macd(
+            &self,
+            _closes: &[Decimal],
+            _fast: usize,
+            _slow: usize,
+            _signal: usize,
+        ) -> Option<MacdValue> {
+            None
+        }
+    }
+
+    async fn strategy(script: &str) -> ScriptStrategy {
+        set_indicator_calculator(Arc::new(SmaOnly));
+        let mut strategy = ScriptStrategy::new();
+        strategy
+            .initialize(json!({
+                "script": script,
+                "tickers": ["005930"],
+                "params": { "period": 3 },
+            }))
+            .await
+            .unwrap();
+        strategy
+    }
+
+    #[tokio::test]
+    async fn test_script_emits_signals_with_context() {
+        let mut strategy = strategy(CROSS_SCRIPT).await;
+        let context = Arc::new(RwLock::new(StrategyContext::default()));
+        strategy.set_context(Arc::clone(&context));
+
+        for (day, close) in [100, 100, 100].into_iter().enumerate() {
+            let signals = strategy
+                .on_market_data(&kline("005930", day as i64, close))
+                .await;
+            assert!(signals.unwrap().is_empty());
+        }
+
+        let signals = strategy
+            .on_market_data(&kline("005930", 3, 110))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].signal_type, SignalType::Entry);
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(signals[0].ticker, "005930");
+        assert_eq!(signals[0].strength, 0.7);
+        assert_eq!(signals[0].metadata["reason"], json!("above sma"));
+
+        // 포지션이 생기면 청산 조건으로 전환
+        context.write().await.positions.insert(
+            "005930".to_string(),
+            StrategyPositionInfo::new(
+                "005930".to_string(),
+                Side::Buy,
+                Decimal::ONE,
+                Decimal::from(110),
+            ),
+        );
+        let signals = strategy
+            .on_market_data(&kline("005930", 4, 90))
+            .await
+            .unwrap();
+        assert_eq!(signals[0].signal_type, SignalType::Exit);
+
+        // 대상이 아닌 티커는 무시
+        let ignored = strategy
+            .on_market_data(&kline("000660", 5, 90))
+            .await
+            .unwrap();
+        assert!(ignored.is_empty());
+        assert_eq!(strategy.get_state()["state"]["bars"], json!(5));
+    }
+
+    #[tokio::test]
+    async fn test_state_survives_restart() {
+        let mut strategy = strategy(CROSS_SCRIPT).await;
+        for day in 0..4 {
+            strategy
+                .on_market_data(&kline("005930", day, 100))
+                .await
+                .unwrap();
+        }
+        let saved = strategy.save_state().unwrap();
+
+        let mut restarted = self::strategy(CROSS_SCRIPT).await;
+        restarted.load_state(&saved).unwrap();
+        assert_eq!(restarted.get_state()["state"]["bars"], json!(4));
+    }
+
+    #[test]
+    fn test_registered_for_backtest() {
+        let strategy = crate::StrategyRegistry::create_instance("script").unwrap();
+        assert_eq!(strategy.name(), "Script");
+    }
+
+    #[tokio::test]
+    async fn test_runtime_error_is_reported() {
+        let mut strategy = strategy("fn on_bar(market) { market.close = 1.0; }").await;
+        assert!(strategy
+            .on_market_data(&kline("005930", 0, 100))
+            .await
+            .is_err());
+        assert_eq!(strategy.get_state()["errors"], json!(1));
+
+        let mut invalid = ScriptStrategy::new();
+        assert!(invalid
+            .initialize(json!({ "script": "fn init(p) { }" }))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_long_script_does_not_block_runtime() {
+        let mut strategy = ScriptStrategy::new();
+        strategy
+            .initialize(json!({
+                "script": "fn on_bar(market) { loop { } }",
+                "limits": { "max_operations": 10_000_000, "max_duration_ms": 300 }
+            }))
+            .await
+            .unwrap();
+
+        let data = kline("005930", 0, 100);
+        let ((result, finished), ticked) = tokio::join!(
+            async {
+                let result = strategy.on_market_data(&data).await;
+                (result, std::time::Instant::now())
+            },
+            async {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                std::time::Instant::now()
+            }
+        );
+
+        // 스크립트가 도는 동안에도 같은 런타임의 다른 작업이 진행됨
+        assert!(ticked < finished);
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("리소스 제한"), "{}", err);
+    }
+}
@@ -0,0 +1,169 @@
+//! 신호 결정 추적 보관소.
+//!
+//! 전략이 `Strategy::take_decision_traces()`로 넘긴 추적을 최근 N개까지 보관하고,
+//! 신호 ID 또는 검색 조건(전략, 종목, 날짜, 결과)으로 조회합니다.
+//!
+//! 추적을 기록하지 않는 전략의 신호에도 [`link_signal_traces`]가 기본 추적을 만들어
+//! 모든 발생 신호를 신호 ID로 조회할 수 있게 합니다.
+//!
+//! 메모리 보관소는 재시작 시 비워지므로, 엔진에 [`DecisionTraceRecorder`]를 설정하면
+//! 수거한 추적을 영구 저장소(API 서버에서는 `decision_traces` 테이블)에도 기록합니다.
+
+use std::collections::{HashSet, VecDeque};
+
+use async_trait::async_trait;
+use thiserror::Error;
+use trader_core::{DecisionTrace, DecisionTraceQuery, Signal};
+use uuid::Uuid;
+
+/// 보관 한도 기본값.
+pub const DEFAULT_TRACE_CAPACITY: usize = 10_000;
+
+/// 발생 신호마다 추적이 있도록 보완.
+///
+/// 전략이 반환한 추적 중 신호와 연결된 것이 없으면 [`DecisionTrace::for_signal`]로
+/// 기본 추적을 추가합니다.
+pub fn link_signal_traces(
+    signals: &[Signal],
+    mut traces: Vec<DecisionTrace>,
+) -> Vec<DecisionTrace> {
+    let linked: HashSet<Uuid> = traces.iter().filter_map(|t| t.signal_id).collect();
+    traces.extend(
+        signals
+            .iter()
+            .filter(|signal| !linked.contains(&signal.id))
+            .map(DecisionTrace::for_signal),
+    );
+    traces
+}
+
+/// 결정 추적 저장 에러.
+#[derive(Error, Debug)]
+pub enum TraceError {
+    #[error("저장소 에러: {0}")]
+    Storage(String),
+}
+
+/// 결정 추적 영구 저장소.
+///
+/// 엔진은 전략에서 수거한 추적을 메모리 보관소와 함께 이 저장소에 기록합니다.
+#[async_trait]
+pub trait DecisionTraceRecorder: Send + Sync {
+    /// 추적 일괄 저장.
+    async fn record(&self, traces: &[DecisionTrace]) -> Result<(), TraceError>;
+}
+
+/// 최근 결정 추적 보관소 (한도 초과 시 오래된 것부터 제거).
+#[derive(Debug)]
+pub struct DecisionTraceStore {
+    capacity: usize,
+    traces: VecDeque<DecisionTrace>,
+}
+
+impl DecisionTraceStore {
+    /// 보관 한도를 지정해 생성 (0이면 보관하지 않음).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            traces: VecDeque::new(),
+        }
+    }
+
+    /// 추적 추가.
+    pub fn record(&mut self, trace: DecisionTrace) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.traces.len() >= self.capacity {
+            self.traces.pop_front();
+        }
+        self.traces.push_back(trace);
+    }
+
+    /// 여러 추적 추가.
+    pub fn record_all(&mut self, traces: impl IntoIterator<Item = DecisionTrace>) {
+        for trace in traces {
+            self.record(trace);
+        }
+    }
+
+    /// 보관 중인 추적 수.
+    pub fn len(&self) -> usize {
+        self.traces.len()
+    }
+
+    /// 보관 중인 추적이 없는지 확인.
+    pub fn is_empty(&self) -> bool {
+        self.traces.is_empty()
+    }
+
+    /// 추적 ID로 조회.
+    pub fn get(&self, trace_id: Uuid) -> Option<&DecisionTrace> {
+        self.traces.iter().find(|t| t.id == trace_id)
+    }
+
+    /// 신호 ID로 조회.
+    pub fn get_by_signal(&self, signal_id: Uuid) -> Option<&DecisionTrace> {
+        self.traces
+            .iter()
+            .rev()
+            .find(|t| t.signal_id == Some(signal_id))
+    }
+
+    /// 조건에 맞는 추적을 최신순으로 최대 `limit`개 반환.
+    pub fn search(&self, query: &DecisionTraceQuery, limit: usize) -> Vec<DecisionTrace> {
+        self.traces
+            .iter()
+            .rev()
+            .filter(|t| query.matches(t))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Default for DecisionTraceStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_TRACE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use trader_core::{DecisionOutcome, Side};
+
+    use super::*;
+
+    #[test]
+    fn test_store_evicts_oldest_and_searches_newest_first() {
+        let mut store = DecisionTraceStore::new(2);
+        for ticker in ["005930", "000660", "005930"] {
+            store.record(DecisionTrace::new("s1", ticker, Utc::now()));
+        }
+
+        assert_eq!(store.len(), 2);
+        let query = DecisionTraceQuery {
+            ticker: Some("005930".to_string()),
+            outcome: Some(DecisionOutcome::Suppressed),
+            ..Default::default()
+        };
+        assert_eq!(store.search(&query, 10).len(), 1);
+    }
+
+    #[test]
+    fn test_link_signal_traces_adds_missing() {
+        let traced = Signal::entry("s1", "005930".to_string(), Side::Buy);
+        let untraced = Signal::exit("s1", "000660".to_string(), Side::Sell);
+
+        let mut trace = DecisionTrace::new("s1", "005930", Utc::now());
+        trace.emit(&traced);
+        let traces = link_signal_traces(&[traced.clone(), untraced.clone()], vec![trace]);
+
+        let mut store = DecisionTraceStore::default();
+        store.record_all(traces);
+        assert_eq!(store.len(), 2);
+        assert!(store.get_by_signal(traced.id).is_some());
+        assert!(store.get_by_signal(untraced.id).unwrap().is_emitted());
+    }
+}
@@ -6,11 +6,14 @@ use async_trait::async_trait;
 use serde_json::Value;
 use tokio::sync::RwLock;
 use trader_core::{
-    domain::MultiTimeframeConfig, Kline, MarketData, Order, Position, Signal, StrategyContext,
-    Timeframe,
+    domain::MultiTimeframeConfig, DecisionTrace, Kline, MarketData, Order, Position, Signal,
+    StrategyContext, Timeframe,
 };
 
-use crate::strategies::common::ExitConfig;
+use crate::{
+    schedule::{ScheduleEvent, StrategySchedule},
+    strategies::common::ExitConfig,
+};
 
 /// 트레이딩 전략 구현을 위한 Strategy trait.
 ///
@@ -151,6 +154,57 @@ pub trait Strategy: Send + Sync {
         self.on_market_data(primary_data).await
     }
 
+    // =========================================================================
+    // 일정 이벤트 (타이머/캘린더)
+    // =========================================================================
+
+    /// 일정 트리거 선언.
+    ///
+    /// `Some`을 반환하면 엔진이 해당 시장 캘린더(휴장일 반영) 기준으로
+    /// 트리거 발생 시 `on_schedule()`을 호출합니다. 실거래와 백테스트 모두
+    /// 같은 규칙으로 이벤트를 발생시키므로 캔들 타임스탬프로 달력을 흉내 낼 필요가 없습니다.
+    ///
+    /// # 예시
+    ///
+    /// ```rust,ignore
+    /// fn schedule(&self) -> Option<StrategySchedule> {
+    ///     Some(StrategySchedule::new(Country::KR).with_trigger(ScheduleTrigger::MonthStart))
+    /// }
+    /// ```
+    fn schedule(&self) -> Option<StrategySchedule> {
+        None
+    }
+
+    /// 일정 이벤트 발생 시 호출.
+    ///
+    /// 반환한 신호는 `on_market_data()` 신호와 같은 검증/중복 제거를 거칩니다.
+    async fn on_schedule(
+        &mut self,
+        _event: &ScheduleEvent,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(vec![])
+    }
+
+    // =========================================================================
+    // 결정 추적 (설명 가능성)
+    // =========================================================================
+
+    /// 마지막 호출 이후 기록한 결정 추적을 꺼내 반환.
+    ///
+    /// 엔진과 백테스트는 `on_market_data()`/`on_schedule()` 호출 직후 이 메서드로
+    /// 추적을 수집해 신호 ID별로 보관합니다. 신호를 만든 추적은
+    /// `DecisionTrace::emit()`으로 신호와 연결하고, 억제된 평가도 그대로 반환합니다.
+    ///
+    /// 기본 구현은 추적을 기록하지 않는 전략용입니다.
+    ///
+    /// 내장 전략 중 단계별 추적을 기록하는 것은 평균회귀 계열(`MeanReversionStrategy`)과
+    /// 이를 기반 전략으로 위임하는 `OptionOverlayStrategy`뿐입니다. 다른 전략의 발생
+    /// 신호에는 엔진이 신호 `reason`만 담은 기본 추적을 만들며, 전략 내부에서 억제된
+    /// 평가는 남지 않습니다 (엔진 충돌 검증으로 제외된 신호만 억제로 기록).
+    fn take_decision_traces(&mut self) -> Vec<DecisionTrace> {
+        Vec::new()
+    }
+
     /// 현재 전략 상태를 JSON으로 반환 (디버깅/모니터링용).
     fn get_state(&self) -> Value;
 
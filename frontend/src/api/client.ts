@@ -160,12 +160,22 @@ export interface HoldingInfo {
   profitLoss: string;
   profitLossRate: string;
   market: string;
+  /** 거래 통화 (KRW, USD, USDT 등) */
+  currency: string;
+  /** 기준 통화 평가금액 (환율이 없으면 생략) */
+  evalAmountBase?: string;
+  /** 기준 통화 평가손익 (환율이 없으면 생략) */
+  profitLossBase?: string;
 }
 
 export interface HoldingsResponse {
   /** 전체 보유 종목 (시장 구분은 각 HoldingInfo.market 필드 사용) */
   holdings: HoldingInfo[];
   totalCount: number;
+  /** 기준 통화 */
+  baseCurrency: string;
+  /** 기준 통화 총 평가금액 (환율이 없는 종목이 있으면 생략) */
+  totalEvalAmountBase?: string;
 }
 
 /** 보유 종목 조회 (활성 계정 기준) */
@@ -1,5 +1,7 @@
 // 자동 생성된 타입
 export type { ClearCacheResponse } from './ClearCacheResponse';
+export type { CurrencyPnLItem } from './CurrencyPnLItem';
+export type { CurrencyPnLResponse } from './CurrencyPnLResponse';
 export type { DailyPnLItem } from './DailyPnLItem';
 export type { DailyPnLQuery } from './DailyPnLQuery';
 export type { DailyPnLResponse } from './DailyPnLResponse';
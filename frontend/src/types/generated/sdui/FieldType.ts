@@ -3,4 +3,4 @@
 /**
  * 필드 타입.
  */
-export type FieldType = "integer" | "number" | "boolean" | "string" | "select" | "multi_select" | "symbol" | "symbols" | "multi_timeframe";
+export type FieldType = "integer" | "number" | "boolean" | "string" | "select" | "multi_select" | "symbol" | "symbols" | "multi_timeframe" | "rule_group";
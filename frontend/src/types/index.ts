@@ -65,6 +65,15 @@ export interface PortfolioSummary {
   dailyPnlPercent: number;
   cashBalance: number;
   marginUsed: number;
+  /** 계좌 통화 (위 금액의 통화) */
+  currency: string;
+  /** 기준 통화 */
+  baseCurrency: string;
+  /** 적용 환율 (1 계좌 통화 = N 기준 통화) */
+  fxRate?: number;
+  totalValueBase?: number;
+  totalPnlBase?: number;
+  cashBalanceBase?: number;
 }
 
 export interface MarketStatus {
@@ -0,0 +1,65 @@
+-- 스마트 주문 라우팅 기록 마이그레이션
+-- 크로스 거래소 라우팅 결정과 실현 체결가를 사후 분석용으로 저장합니다.
+
+-- 1. 라우팅 결정 테이블
+CREATE TABLE IF NOT EXISTS order_routing_decisions (
+    id UUID PRIMARY KEY,
+    base_asset VARCHAR(20) NOT NULL,
+    side VARCHAR(10) NOT NULL,
+    requested_quantity DECIMAL(30, 15) NOT NULL,
+    allocated_quantity DECIMAL(30, 15) NOT NULL,
+    unfilled_quantity DECIMAL(30, 15) NOT NULL DEFAULT 0,
+    base_currency VARCHAR(10) NOT NULL,
+    expected_avg_price DECIMAL(30, 15),
+    expected_fee DECIMAL(30, 15) NOT NULL DEFAULT 0,
+    allocations JSONB NOT NULL DEFAULT '[]',
+    venue_quotes JSONB NOT NULL DEFAULT '[]',
+    orders JSONB NOT NULL DEFAULT '[]',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 2. 라우팅 체결 테이블
+CREATE TABLE IF NOT EXISTS order_routing_fills (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    decision_id UUID NOT NULL REFERENCES order_routing_decisions(id) ON DELETE CASCADE,
+    venue VARCHAR(50) NOT NULL,
+    quantity DECIMAL(30, 15) NOT NULL,
+    price DECIMAL(30, 15) NOT NULL,
+    price_base DECIMAL(30, 15) NOT NULL,
+    filled_at TIMESTAMPTZ NOT NULL
+);
+
+-- 3. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_order_routing_decisions_asset ON order_routing_decisions(base_asset, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_order_routing_fills_decision ON order_routing_fills(decision_id);
+CREATE INDEX IF NOT EXISTS idx_order_routing_fills_venue ON order_routing_fills(venue, filled_at DESC);
+
+-- 4. 거래소별 실현 슬리피지 분석 뷰
+CREATE OR REPLACE VIEW v_order_routing_slippage AS
+SELECT
+    d.id AS decision_id,
+    d.base_asset,
+    d.side,
+    d.base_currency,
+    d.expected_avg_price,
+    SUM(f.quantity) AS filled_quantity,
+    SUM(f.price_base * f.quantity) / NULLIF(SUM(f.quantity), 0) AS realised_avg_price,
+    CASE
+        WHEN d.expected_avg_price IS NULL OR d.expected_avg_price = 0 THEN NULL
+        WHEN d.side = 'BUY' THEN
+            (SUM(f.price_base * f.quantity) / NULLIF(SUM(f.quantity), 0) - d.expected_avg_price)
+            / d.expected_avg_price * 10000
+        ELSE
+            (d.expected_avg_price - SUM(f.price_base * f.quantity) / NULLIF(SUM(f.quantity), 0))
+            / d.expected_avg_price * 10000
+    END AS slippage_bps,
+    d.created_at
+FROM order_routing_decisions d
+LEFT JOIN order_routing_fills f ON f.decision_id = d.id
+GROUP BY d.id;
+
+-- 5. 코멘트
+COMMENT ON TABLE order_routing_decisions IS '크로스 거래소 스마트 주문 라우팅 결정';
+COMMENT ON TABLE order_routing_fills IS '라우팅 주문의 거래소별 실현 체결';
+COMMENT ON COLUMN order_routing_decisions.expected_avg_price IS '기준 통화 예상 평균가 (수수료 제외)';
+COMMENT ON COLUMN order_routing_fills.price_base IS '기준 통화 환산 체결가';
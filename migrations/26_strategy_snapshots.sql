@@ -0,0 +1,27 @@
+-- 전략 상태 스냅샷 마이그레이션
+-- StrategyEngine이 주기적으로, 그리고 중지/종료 시 저장하는 전략 내부 상태를 버전별로 보관합니다.
+
+-- 1. 스냅샷 테이블
+CREATE TABLE IF NOT EXISTS strategy_snapshots (
+    strategy_id VARCHAR(100) NOT NULL,
+    version BIGINT NOT NULL,
+    strategy_name VARCHAR(200) NOT NULL,
+    strategy_version VARCHAR(20) NOT NULL,
+    reason VARCHAR(20) NOT NULL,
+    source_version BIGINT,
+    state BYTEA NOT NULL,
+    diagnostics JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (strategy_id, version)
+);
+
+-- 2. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_strategy_snapshots_created ON strategy_snapshots(created_at DESC);
+
+-- 3. 코멘트
+COMMENT ON TABLE strategy_snapshots IS '전략 상태 체크포인트 (재시작 복원 및 롤백용)';
+COMMENT ON COLUMN strategy_snapshots.version IS '전략별 1부터 증가하는 스냅샷 버전';
+COMMENT ON COLUMN strategy_snapshots.reason IS 'periodic, stop, shutdown, manual, rollback';
+COMMENT ON COLUMN strategy_snapshots.source_version IS '롤백 스냅샷의 원본 버전';
+COMMENT ON COLUMN strategy_snapshots.state IS 'Strategy::save_state() 직렬화 결과';
+COMMENT ON COLUMN strategy_snapshots.diagnostics IS '스냅샷 시점의 Strategy::get_state()';
@@ -0,0 +1,33 @@
+-- 전략 버전 배포 이력 마이그레이션
+-- StrategyEngine::deploy_strategy / rollback_deployment가 기록하는 설정·플러그인 버전을 보관합니다.
+
+-- 1. 배포 테이블
+CREATE TABLE IF NOT EXISTS strategy_deployments (
+    strategy_id VARCHAR(100) NOT NULL,
+    version BIGINT NOT NULL,
+    strategy_type VARCHAR(100) NOT NULL,
+    strategy_version VARCHAR(20) NOT NULL,
+    source VARCHAR(20) NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    config JSONB NOT NULL DEFAULT '{}',
+    changes JSONB NOT NULL DEFAULT '[]',
+    dry_run JSONB,
+    state_carried_over BOOLEAN NOT NULL DEFAULT FALSE,
+    rollback_of BIGINT,
+    message TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (strategy_id, version)
+);
+
+-- 2. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_strategy_deployments_active
+    ON strategy_deployments(strategy_id) WHERE status = 'active';
+
+-- 3. 코멘트
+COMMENT ON TABLE strategy_deployments IS '전략 설정/플러그인 버전 배포 이력 (검증, 드라이런, 롤백)';
+COMMENT ON COLUMN strategy_deployments.version IS '전략별 1부터 증가하는 배포 버전';
+COMMENT ON COLUMN strategy_deployments.source IS 'registry, plugin';
+COMMENT ON COLUMN strategy_deployments.status IS 'active, superseded, rolled_back, rejected';
+COMMENT ON COLUMN strategy_deployments.changes IS '직전 버전 대비 설정 변경 목록';
+COMMENT ON COLUMN strategy_deployments.dry_run IS '최근 시장 데이터 재생 신호 비교 결과';
+COMMENT ON COLUMN strategy_deployments.rollback_of IS '롤백 배포가 되돌린 대상 버전';
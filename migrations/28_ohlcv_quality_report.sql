@@ -0,0 +1,32 @@
+-- OHLCV 데이터 품질 리포트 마이그레이션
+-- trader-collector의 check-quality 명령이 심볼/타임프레임별 캔들 품질 검사 결과를 저장합니다.
+-- 백테스트는 이 리포트를 조회하여 품질이 낮은 데이터 사용 시 경고합니다.
+
+-- 1. 리포트 테이블 (심볼/타임프레임별 최신 1건)
+CREATE TABLE IF NOT EXISTS ohlcv_quality_report (
+    symbol VARCHAR(50) NOT NULL,
+    timeframe VARCHAR(10) NOT NULL,
+    checked_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    range_start TIMESTAMPTZ,
+    range_end TIMESTAMPTZ,
+    total_bars INTEGER NOT NULL DEFAULT 0,
+    zero_volume_bars INTEGER NOT NULL DEFAULT 0,
+    invalid_ohlc_bars INTEGER NOT NULL DEFAULT 0,
+    duplicate_bars INTEGER NOT NULL DEFAULT 0,
+    missing_days INTEGER NOT NULL DEFAULT 0,
+    spike_bars INTEGER NOT NULL DEFAULT 0,
+    repaired_bars INTEGER NOT NULL DEFAULT 0,
+    repair_sources TEXT[] NOT NULL DEFAULT '{}',
+    score DOUBLE PRECISION NOT NULL,
+    issues JSONB NOT NULL DEFAULT '[]',
+    PRIMARY KEY (symbol, timeframe)
+);
+
+-- 2. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_ohlcv_quality_report_score ON ohlcv_quality_report(score);
+
+-- 3. 코멘트
+COMMENT ON TABLE ohlcv_quality_report IS 'OHLCV 시계열 품질 검사 결과 (거래량0, OHLC 오류, 중복, 누락 거래일, 급등락)';
+COMMENT ON COLUMN ohlcv_quality_report.score IS '품질 점수 (0~100, 90 미만이면 백테스트 경고)';
+COMMENT ON COLUMN ohlcv_quality_report.repaired_bars IS '대체 프로바이더(Yahoo → KIS → 네이버)로 복구한 캔들 수';
+COMMENT ON COLUMN ohlcv_quality_report.issues IS '문제 상세 (유형별 일부 샘플)';
@@ -0,0 +1,126 @@
+-- 펀더멘털 시점(Point-in-Time) 이력 마이그레이션
+-- symbol_fundamental은 심볼당 최신값 1건만 유지하므로 백테스트가 미래의 PER/PBR/ROE를 사용하게 됩니다.
+-- symbol_fundamental이 갱신될 때마다 수집일(as_of_date) 기준 스냅샷을 이력 테이블에 남기고,
+-- 백테스트는 "해당 시점까지 알려진" 최신 스냅샷만 조회합니다.
+
+-- 1. 이력 테이블 (심볼/수집일별 1건, 같은 날 재수집 시 교체)
+CREATE TABLE IF NOT EXISTS symbol_fundamental_history (
+    symbol_info_id UUID NOT NULL REFERENCES symbol_info(id) ON DELETE CASCADE,
+    as_of_date DATE NOT NULL,                       -- 값을 알 수 있게 된 날 (수집일)
+    report_date DATE,                               -- 값이 가리키는 결산 기준일 (최근 종료 회계연도 말)
+
+    market_cap DECIMAL(30, 2),
+    per DECIMAL(12, 4),
+    pbr DECIMAL(12, 4),
+    psr DECIMAL(12, 4),
+    eps DECIMAL(20, 4),
+    bps DECIMAL(20, 4),
+    dividend_yield DECIMAL(12, 4),
+    roe DECIMAL(12, 4),
+    roa DECIMAL(12, 4),
+    operating_margin DECIMAL(12, 4),
+    net_profit_margin DECIMAL(12, 4),
+    debt_ratio DECIMAL(12, 4),
+    revenue_growth_yoy DECIMAL(12, 4),
+    earnings_growth_yoy DECIMAL(12, 4),
+
+    data_source VARCHAR(50),
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (symbol_info_id, as_of_date)
+);
+
+-- 2. 인덱스 생성 (시점 조회: as_of_date <= 기준일 중 최신)
+CREATE INDEX IF NOT EXISTS idx_symbol_fundamental_history_as_of
+ON symbol_fundamental_history(symbol_info_id, as_of_date DESC);
+
+-- 3. 결산 기준일 계산 함수 (기준일 이전에 종료된 가장 최근 회계연도 말일)
+CREATE OR REPLACE FUNCTION fundamental_report_date(
+    p_fiscal_year_end VARCHAR,
+    p_as_of DATE
+) RETURNS DATE AS $$
+DECLARE
+    v_month INTEGER;
+    v_end DATE;
+BEGIN
+    v_month := COALESCE(NULLIF(p_fiscal_year_end, '')::INTEGER, 12);
+    IF v_month < 1 OR v_month > 12 THEN
+        v_month := 12;
+    END IF;
+
+    v_end := (make_date(EXTRACT(YEAR FROM p_as_of)::INTEGER, v_month, 1)
+              + INTERVAL '1 month' - INTERVAL '1 day')::DATE;
+    IF v_end >= p_as_of THEN
+        v_end := (v_end - INTERVAL '1 year')::DATE;
+    END IF;
+    RETURN v_end;
+EXCEPTION WHEN invalid_text_representation THEN
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE;
+
+-- 4. symbol_fundamental 갱신 시 이력 스냅샷 기록 트리거
+CREATE OR REPLACE FUNCTION record_symbol_fundamental_history()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_as_of DATE := (COALESCE(NEW.fetched_at, NOW()) AT TIME ZONE 'Asia/Seoul')::DATE;
+BEGIN
+    INSERT INTO symbol_fundamental_history (
+        symbol_info_id, as_of_date, report_date,
+        market_cap, per, pbr, psr, eps, bps, dividend_yield,
+        roe, roa, operating_margin, net_profit_margin, debt_ratio,
+        revenue_growth_yoy, earnings_growth_yoy, data_source, recorded_at
+    ) VALUES (
+        NEW.symbol_info_id, v_as_of, fundamental_report_date(NEW.fiscal_year_end, v_as_of),
+        NEW.market_cap, NEW.per, NEW.pbr, NEW.psr, NEW.eps, NEW.bps, NEW.dividend_yield,
+        NEW.roe, NEW.roa, NEW.operating_margin, NEW.net_profit_margin, NEW.debt_ratio,
+        NEW.revenue_growth_yoy, NEW.earnings_growth_yoy, NEW.data_source, NOW()
+    )
+    ON CONFLICT (symbol_info_id, as_of_date) DO UPDATE SET
+        report_date = EXCLUDED.report_date,
+        market_cap = EXCLUDED.market_cap,
+        per = EXCLUDED.per,
+        pbr = EXCLUDED.pbr,
+        psr = EXCLUDED.psr,
+        eps = EXCLUDED.eps,
+        bps = EXCLUDED.bps,
+        dividend_yield = EXCLUDED.dividend_yield,
+        roe = EXCLUDED.roe,
+        roa = EXCLUDED.roa,
+        operating_margin = EXCLUDED.operating_margin,
+        net_profit_margin = EXCLUDED.net_profit_margin,
+        debt_ratio = EXCLUDED.debt_ratio,
+        revenue_growth_yoy = EXCLUDED.revenue_growth_yoy,
+        earnings_growth_yoy = EXCLUDED.earnings_growth_yoy,
+        data_source = EXCLUDED.data_source,
+        recorded_at = NOW();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trigger_record_symbol_fundamental_history ON symbol_fundamental;
+CREATE TRIGGER trigger_record_symbol_fundamental_history
+    AFTER INSERT OR UPDATE ON symbol_fundamental
+    FOR EACH ROW
+    EXECUTE FUNCTION record_symbol_fundamental_history();
+
+-- 5. 기존 최신값을 수집일 기준 첫 스냅샷으로 적재
+INSERT INTO symbol_fundamental_history (
+    symbol_info_id, as_of_date, report_date,
+    market_cap, per, pbr, psr, eps, bps, dividend_yield,
+    roe, roa, operating_margin, net_profit_margin, debt_ratio,
+    revenue_growth_yoy, earnings_growth_yoy, data_source
+)
+SELECT
+    sf.symbol_info_id,
+    (COALESCE(sf.fetched_at, sf.updated_at, NOW()) AT TIME ZONE 'Asia/Seoul')::DATE,
+    fundamental_report_date(sf.fiscal_year_end, (COALESCE(sf.fetched_at, sf.updated_at, NOW()) AT TIME ZONE 'Asia/Seoul')::DATE),
+    sf.market_cap, sf.per, sf.pbr, sf.psr, sf.eps, sf.bps, sf.dividend_yield,
+    sf.roe, sf.roa, sf.operating_margin, sf.net_profit_margin, sf.debt_ratio,
+    sf.revenue_growth_yoy, sf.earnings_growth_yoy, sf.data_source
+FROM symbol_fundamental sf
+ON CONFLICT (symbol_info_id, as_of_date) DO NOTHING;
+
+-- 6. 코멘트
+COMMENT ON TABLE symbol_fundamental_history IS '펀더멘털 시점 이력 (symbol_fundamental 갱신 시 트리거로 기록, 백테스트 look-ahead 방지용)';
+COMMENT ON COLUMN symbol_fundamental_history.as_of_date IS '값을 알 수 있게 된 날 (수집일, KST). 백테스트는 as_of_date <= 시뮬레이션 일자만 사용';
+COMMENT ON COLUMN symbol_fundamental_history.report_date IS '값이 가리키는 결산 기준일 (fiscal_year_end 기준 최근 종료 회계연도 말일)';
@@ -0,0 +1,113 @@
+-- DART 전자공시 마이그레이션
+-- 금융감독원 DART OpenAPI에서 수집한 공시 목록, 분기 재무제표, 대량보유 보고를 저장합니다.
+-- 유상증자/CB 발행/최대주주 변경 등 주요사항 공시는 API 서버가 signal_alert_rule 규칙으로 알림을 보냅니다.
+
+-- 1. DART 고유번호 매핑 (corp_code ↔ 종목코드, 상장사만)
+CREATE TABLE IF NOT EXISTS dart_corp_code (
+    corp_code VARCHAR(8) PRIMARY KEY,
+    ticker VARCHAR(20) NOT NULL,
+    corp_name VARCHAR(200) NOT NULL,
+    modified_date DATE,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_dart_corp_code_ticker ON dart_corp_code(ticker);
+
+-- 2. 공시 목록 (접수번호별 1건)
+CREATE TABLE IF NOT EXISTS dart_disclosure (
+    rcept_no VARCHAR(14) PRIMARY KEY,               -- 접수번호
+    corp_code VARCHAR(8) NOT NULL,
+    corp_name VARCHAR(200) NOT NULL,
+    ticker VARCHAR(20),                             -- 비상장사는 NULL
+    corp_cls VARCHAR(1),                            -- Y: 유가증권, K: 코스닥, N: 코넥스, E: 기타
+    report_name VARCHAR(500) NOT NULL,
+    disclosure_type VARCHAR(40) NOT NULL,           -- capital_increase, convertible_bond, largest_shareholder_change 등
+    receipt_date DATE NOT NULL,
+    filer_name VARCHAR(200),
+    remark VARCHAR(50),
+    alerted_at TIMESTAMPTZ,                         -- 알림 처리 시각 (NULL이면 미처리)
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_dart_disclosure_ticker_date
+ON dart_disclosure(ticker, receipt_date DESC);
+
+CREATE INDEX IF NOT EXISTS idx_dart_disclosure_type_date
+ON dart_disclosure(disclosure_type, receipt_date DESC);
+
+-- 알림 대기 공시 조회용 (부분 인덱스)
+CREATE INDEX IF NOT EXISTS idx_dart_disclosure_pending_alert
+ON dart_disclosure(receipt_date)
+WHERE alerted_at IS NULL;
+
+-- 3. 분기 재무제표 (단일회사 주요계정)
+CREATE TABLE IF NOT EXISTS dart_financial_statement (
+    corp_code VARCHAR(8) NOT NULL,
+    ticker VARCHAR(20),
+    fiscal_year INTEGER NOT NULL,                   -- 사업연도
+    report_code VARCHAR(5) NOT NULL,                -- 11013: 1분기, 11012: 반기, 11014: 3분기, 11011: 사업보고서
+    consolidated BOOLEAN NOT NULL,                  -- 연결재무제표 여부
+    rcept_no VARCHAR(14) NOT NULL,
+    currency VARCHAR(10) NOT NULL DEFAULT 'KRW',
+
+    revenue DECIMAL(30, 2),
+    operating_income DECIMAL(30, 2),
+    net_income DECIMAL(30, 2),
+    total_assets DECIMAL(30, 2),
+    total_liabilities DECIMAL(30, 2),
+    total_equity DECIMAL(30, 2),
+
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (corp_code, fiscal_year, report_code, consolidated)
+);
+
+CREATE INDEX IF NOT EXISTS idx_dart_financial_statement_ticker
+ON dart_financial_statement(ticker, fiscal_year DESC, report_code);
+
+-- 4. 대량보유상황보고 (5% 이상 주주 지분 변동)
+CREATE TABLE IF NOT EXISTS dart_major_shareholder_change (
+    rcept_no VARCHAR(14) PRIMARY KEY,
+    corp_code VARCHAR(8) NOT NULL,
+    ticker VARCHAR(20),
+    receipt_date DATE NOT NULL,
+    report_type VARCHAR(20),                        -- 일반/약식
+    reporter VARCHAR(200) NOT NULL,                 -- 대표보고자
+    shares DECIMAL(30, 0),
+    shares_change DECIMAL(30, 0),
+    ownership_pct DECIMAL(12, 4),
+    ownership_change_pct DECIMAL(12, 4),
+    reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_dart_major_shareholder_ticker_date
+ON dart_major_shareholder_change(ticker, receipt_date DESC);
+
+-- 5. 기본 공시 알림 규칙
+INSERT INTO signal_alert_rule (rule_name, description, filter_conditions)
+VALUES
+    (
+        'dart_material_disclosure',
+        'DART 주요사항 공시 알림 (유상증자, CB/BW 발행, 최대주주 변경)',
+        '{
+            "min_strength": 0.0,
+            "strategy_ids": ["dart_disclosure"],
+            "entry_only": false,
+            "disclosure_types": [
+                "capital_increase",
+                "convertible_bond",
+                "bond_with_warrant",
+                "largest_shareholder_change"
+            ],
+            "description": "희석 또는 지배구조 변화를 일으키는 공시가 접수되면 알림"
+        }'::jsonb
+    )
+ON CONFLICT (rule_name) DO NOTHING;
+
+-- 6. 코멘트
+COMMENT ON TABLE dart_corp_code IS 'DART 고유번호(corp_code) ↔ 종목코드 매핑 (corpCode.xml, 상장사만)';
+COMMENT ON TABLE dart_disclosure IS 'DART 공시 목록 (trader-collector sync-dart가 수집, 주요사항 공시는 API 서버가 알림)';
+COMMENT ON COLUMN dart_disclosure.disclosure_type IS '보고서명 기준 공시 유형 (signal_alert_rule.filter_conditions.disclosure_types와 매칭)';
+COMMENT ON COLUMN dart_disclosure.alerted_at IS '알림 처리 시각 (규칙 매칭 여부와 무관하게 처리되면 기록)';
+COMMENT ON TABLE dart_financial_statement IS 'DART 단일회사 주요계정 (분기/반기/사업보고서, 당기 금액)';
+COMMENT ON TABLE dart_major_shareholder_change IS 'DART 주식등의 대량보유상황보고 (5% 이상 주주 지분 변동)';
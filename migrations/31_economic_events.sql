@@ -0,0 +1,30 @@
+-- 경제 이벤트 캘린더 마이그레이션
+-- FOMC, CPI, 한국은행 금통위, 옵션 만기일, 지수 정기변경일 등 변동성 이벤트 일정을 저장합니다.
+-- trader-collector sync-economic-calendar가 ICS/CSV 피드와 규칙 기반 정기 일정을 수집하며,
+-- 전략 컨텍스트, 리스크 관리자의 이벤트 블랙아웃, 백테스트에서 사용합니다.
+
+-- 1. 경제 이벤트 (유형/국가/시각별 1건)
+CREATE TABLE IF NOT EXISTS economic_event (
+    id BIGSERIAL PRIMARY KEY,
+    kind VARCHAR(30) NOT NULL,                      -- fomc, cpi, bok_rate_decision, employment, options_expiry, index_rebalance, other
+    title VARCHAR(300) NOT NULL,
+    country VARCHAR(10) NOT NULL,                   -- KR, US, ..., GLOBAL
+    impact VARCHAR(10) NOT NULL DEFAULT 'medium',   -- low, medium, high
+    scheduled_at TIMESTAMPTZ NOT NULL,
+    source VARCHAR(500),                            -- 피드 URL/파일 경로 또는 'recurring'
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT uq_economic_event UNIQUE (kind, country, scheduled_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_economic_event_scheduled_at
+ON economic_event(scheduled_at);
+
+CREATE INDEX IF NOT EXISTS idx_economic_event_impact_scheduled_at
+ON economic_event(impact, scheduled_at);
+
+-- 2. 코멘트
+COMMENT ON TABLE economic_event IS '경제 이벤트 캘린더 (trader-collector sync-economic-calendar가 수집)';
+COMMENT ON COLUMN economic_event.impact IS '영향도 (RiskConfig.event_blackout.min_impact 이상이면 블랙아웃 대상)';
+COMMENT ON COLUMN economic_event.source IS '출처 (피드 URL/파일 경로, 규칙 기반 정기 일정은 recurring)';
@@ -0,0 +1,31 @@
+-- 수집기 작업 실행 이력 마이그레이션
+-- trader-collector 작업 오케스트레이터(symbol_sync → ohlcv_collect → indicator_sync →
+-- global_score_sync → screening_refresh 등 DAG)의 작업 실행을 시도 단위로 기록합니다.
+-- API 서버(/api/v1/monitoring/collector/*)가 작업 상태 조회에 사용합니다.
+
+-- 1. 작업 실행 이력 (재시도는 attempt를 증가시켜 별도 행으로 기록)
+CREATE TABLE IF NOT EXISTS collector_job_run (
+    id BIGSERIAL PRIMARY KEY,
+    job_name VARCHAR(50) NOT NULL,                  -- symbol_sync, ohlcv_collect, indicator_sync, ...
+    trigger VARCHAR(20) NOT NULL,                   -- schedule, dependency, manual
+    status VARCHAR(20) NOT NULL DEFAULT 'running',  -- running, success, failed, skipped, interrupted
+    attempt INTEGER NOT NULL DEFAULT 1,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    finished_at TIMESTAMPTZ,
+    rows_touched BIGINT NOT NULL DEFAULT 0,
+    error_count BIGINT NOT NULL DEFAULT 0,
+    error_message TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_collector_job_run_job_started
+ON collector_job_run(job_name, started_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_collector_job_run_status
+ON collector_job_run(status, started_at DESC);
+
+-- 2. 코멘트
+COMMENT ON TABLE collector_job_run IS '수집기 작업 실행 이력 (trader-collector 작업 오케스트레이터가 기록)';
+COMMENT ON COLUMN collector_job_run.trigger IS '실행 원인 (schedule: cron 일정, dependency: 선행 작업 완료, manual: CLI 수동 실행)';
+COMMENT ON COLUMN collector_job_run.attempt IS '시도 횟수 (실패 시 지수 백오프 후 재시도)';
+COMMENT ON COLUMN collector_job_run.rows_touched IS '삽입/갱신된 행 수 (작업별 통계 기준)';
+COMMENT ON COLUMN collector_job_run.error_count IS '처리 중 개별 오류 수 (부분 실패 포함)';
@@ -0,0 +1,26 @@
+-- 증분 지표 상태 마이그레이션
+-- trader-analytics::indicators::incremental 의 종목/타임프레임별 롤링 상태
+-- (EMA 시드, Wilder 평균, ATR, SuperTrend 밴드 등)를 저장합니다.
+-- 수집기(indicator_sync)가 새 캔들만 반영해 갱신하고, API 서버는 시작 시 복원하여
+-- 실시간 StrategyEngine과 /api/v1/analytics/indicators/state 에서 공유합니다.
+
+-- 1. 지표 상태 (종목/타임프레임당 1행)
+CREATE TABLE IF NOT EXISTS indicator_state (
+    ticker VARCHAR(20) NOT NULL,
+    timeframe VARCHAR(10) NOT NULL,              -- 1m, 1h, 1d, ...
+    config_key VARCHAR(100) NOT NULL,            -- 지표 파라미터 식별 키
+    bars BIGINT NOT NULL DEFAULT 0,
+    last_open_time TIMESTAMPTZ,
+    state JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (ticker, timeframe)
+);
+
+CREATE INDEX IF NOT EXISTS idx_indicator_state_updated
+ON indicator_state(updated_at DESC);
+
+-- 2. 코멘트
+COMMENT ON TABLE indicator_state IS '증분 지표 롤링 상태 (캔들 단위 O(1) 갱신, 수집기와 전략 엔진이 공유)';
+COMMENT ON COLUMN indicator_state.config_key IS '지표 파라미터 키 (현재 설정과 다르면 전체 이력으로 다시 워밍업)';
+COMMENT ON COLUMN indicator_state.last_open_time IS '마지막으로 반영된 캔들 시작 시각 (이후 캔들만 반영)';
+COMMENT ON COLUMN indicator_state.state IS 'IndicatorState 직렬화 값 (Decimal은 문자열로 저장)';
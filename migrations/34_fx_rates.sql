@@ -0,0 +1,25 @@
+-- 환율 이력 마이그레이션
+-- 다중 통화 계좌(KRW 주식, USD 주식, USDT 암호화폐)의 기준 통화 평가에 사용하는
+-- 일자별 환율을 저장합니다. 수집기(macro_data_sync, fx-backfill)가 Yahoo Finance
+-- 종가를 기록하고, 거래소 시세(예: Upbit KRW-USDT)는 source = 'provider'로 기록합니다.
+-- API 서버(포트폴리오 요약, 매매일지, 자산 곡선)와 백테스트가 기간별로 조회합니다.
+
+-- 1. 일자별 환율 (1 base = rate quote)
+CREATE TABLE IF NOT EXISTS fx_rates (
+    base_currency VARCHAR(10) NOT NULL,          -- USD, USDT, JPY, ...
+    quote_currency VARCHAR(10) NOT NULL,         -- KRW, USD, ...
+    rate_date DATE NOT NULL,
+    rate NUMERIC(24, 10) NOT NULL CHECK (rate > 0),
+    source VARCHAR(20) NOT NULL DEFAULT 'macro', -- macro, provider, manual
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (base_currency, quote_currency, rate_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_fx_rates_date
+ON fx_rates(rate_date DESC);
+
+-- 2. 코멘트
+COMMENT ON TABLE fx_rates IS '일자별 환율 이력 (기준 통화 평가 및 환차손익 분리용)';
+COMMENT ON COLUMN fx_rates.rate IS '1 base_currency = rate quote_currency (예: USD/KRW 1350)';
+COMMENT ON COLUMN fx_rates.rate_date IS '환율 기준일 (해당 일자 종가, 없는 날은 직전 환율 사용)';
+COMMENT ON COLUMN fx_rates.source IS '출처 (macro: Yahoo Finance 매크로 데이터, provider: 거래소 시세, manual: 수동 입력)';
@@ -0,0 +1,25 @@
+-- 계층형 저장소 마이그레이션
+-- 보존 기간이 지난 분봉(ohlcv)과 체결 틱(trade_ticks)을 Parquet 아카이브로 옮긴 구간을
+-- 기록합니다. 수집기(storage_tiering)가 분봉을 1시간/일봉으로 롤업하고 원본을
+-- 아카이브한 뒤 DB에서 삭제하며, OhlcvCache는 이 목록으로 아카이브 조회 여부를 판단합니다.
+
+-- 1. 아카이브 구간 목록
+CREATE TABLE IF NOT EXISTS storage_archive_manifest (
+    dataset VARCHAR(20) NOT NULL,                -- ohlcv, trade_ticks
+    market VARCHAR(20) NOT NULL,                 -- Parquet 파티션 시장 (KR, US, CRYPTO, ...)
+    symbol VARCHAR(50) NOT NULL,
+    timeframe VARCHAR(10) NOT NULL,              -- ohlcv 타임프레임 (1m, 5m, ...), 틱은 'tick'
+    range_start TIMESTAMPTZ NOT NULL,            -- 구간 시작 (포함)
+    range_end TIMESTAMPTZ NOT NULL,              -- 구간 종료 (미포함)
+    row_count BIGINT NOT NULL DEFAULT 0,
+    archived_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (dataset, symbol, timeframe, range_start)
+);
+
+CREATE INDEX IF NOT EXISTS idx_storage_archive_manifest_range
+ON storage_archive_manifest(dataset, symbol, timeframe, range_end);
+
+-- 2. 코멘트
+COMMENT ON TABLE storage_archive_manifest IS 'DB에서 Parquet 아카이브로 이동한 시계열 구간 (계층형 저장소)';
+COMMENT ON COLUMN storage_archive_manifest.market IS '아카이브 파티션 경로의 market 값 ({dataset}/market={market}/symbol={symbol}/year={year})';
+COMMENT ON COLUMN storage_archive_manifest.range_end IS '구간 종료 시각 (미포함), 다음 실행은 이 시각 이후 데이터만 아카이브';
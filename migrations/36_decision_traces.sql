@@ -0,0 +1,31 @@
+-- 신호 결정 추적 마이그레이션
+-- StrategyEngine이 전략에서 수거한 결정 추적(발생/억제 이유)을 보관합니다.
+-- 메모리 보관소는 재시작 시 비워지므로, API 서버는 이 테이블에서 추적을 조회합니다.
+
+-- 1. 결정 추적 테이블
+CREATE TABLE IF NOT EXISTS decision_traces (
+    id UUID PRIMARY KEY,
+    strategy_id VARCHAR(100) NOT NULL,
+    ticker VARCHAR(50) NOT NULL,
+    evaluated_at TIMESTAMPTZ NOT NULL,
+    outcome VARCHAR(20) NOT NULL,
+    signal_id UUID,
+    side VARCHAR(10),
+    trace JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 2. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_decision_traces_strategy
+    ON decision_traces(strategy_id, evaluated_at DESC);
+CREATE INDEX IF NOT EXISTS idx_decision_traces_ticker
+    ON decision_traces(ticker, evaluated_at DESC);
+CREATE INDEX IF NOT EXISTS idx_decision_traces_signal
+    ON decision_traces(signal_id) WHERE signal_id IS NOT NULL;
+
+-- 3. 코멘트
+COMMENT ON TABLE decision_traces IS '전략 신호 결정 추적 (지표 값, 필터 통과 여부, 발생/억제 결과)';
+COMMENT ON COLUMN decision_traces.evaluated_at IS '평가 시각 (데이터 기준)';
+COMMENT ON COLUMN decision_traces.outcome IS 'emitted, suppressed';
+COMMENT ON COLUMN decision_traces.side IS '신호 방향 (발생 시 또는 평가 의도): BUY, SELL';
+COMMENT ON COLUMN decision_traces.trace IS '평가 단계를 포함한 DecisionTrace 전체';
@@ -0,0 +1,26 @@
+-- 김치 프리미엄 이력 마이그레이션
+-- 김치 프리미엄 모니터가 샘플링 간격마다 적재한 스냅샷을 보관합니다.
+-- 메모리 이력은 재시작 시 비워지므로, 시작 시 이 테이블에서 복원하고 이력 API도 이 테이블을 조회합니다.
+
+-- 1. 프리미엄 이력 테이블
+CREATE TABLE IF NOT EXISTS kimchi_premium_history (
+    venue VARCHAR(20) NOT NULL,
+    base VARCHAR(20) NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL,
+    domestic_price_krw NUMERIC(30, 8) NOT NULL,
+    foreign_price_usd NUMERIC(30, 8) NOT NULL,
+    usd_krw NUMERIC(20, 4) NOT NULL,
+    foreign_price_krw NUMERIC(30, 8) NOT NULL,
+    premium_pct NUMERIC(12, 4) NOT NULL,
+    PRIMARY KEY (venue, base, recorded_at)
+);
+
+-- 2. 인덱스 생성 (보존 기간 정리용)
+CREATE INDEX IF NOT EXISTS idx_kimchi_premium_history_recorded
+    ON kimchi_premium_history(recorded_at);
+
+-- 3. 코멘트
+COMMENT ON TABLE kimchi_premium_history IS '김치 프리미엄 스냅샷 이력 (국내 거래소-바이낸스 가격 괴리)';
+COMMENT ON COLUMN kimchi_premium_history.venue IS '국내 거래소: upbit, bithumb';
+COMMENT ON COLUMN kimchi_premium_history.recorded_at IS '계산 시각 (샘플링 간격마다 1건)';
+COMMENT ON COLUMN kimchi_premium_history.premium_pct IS '프리미엄 (%) = (국내가 / (해외가 × USD/KRW) - 1) × 100';